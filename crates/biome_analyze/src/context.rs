@@ -151,6 +151,14 @@ where
             .expect("Source type is not registered")
     }
 
+    /// Returns the service of type `T` if it's registered.
+    ///
+    /// Unlike the services of the query, this service may be missing,
+    /// for example the manifest of a file that doesn't belong to a package.
+    pub fn get_service<T: 'static>(&self) -> Option<&T> {
+        self.bag.get_service::<T>()
+    }
+
     /// The file path of the current file
     pub fn file_path(&self) -> &Path {
         self.file_path
@@ -77,6 +77,10 @@ pub struct AnalyzerOptions {
 
     /// The file that is being analyzed
     pub file_path: PathBuf,
+
+    /// The explanation of the suppression comments created by the code actions, it's
+    /// `<explanation>` when it's [None]
+    pub suppression_reason: Option<String>,
 }
 
 impl AnalyzerOptions {
@@ -40,6 +40,8 @@ pub struct RuleMetadata {
     pub sources: &'static [RuleSource],
     /// The source kind of the rule
     pub source_kind: Option<RuleSourceKind>,
+    /// The domains that the rule belongs to
+    pub domains: &'static [RuleDomain],
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -100,6 +102,8 @@ pub enum RuleSource {
     EslintImportAccess(&'static str),
     /// Rules from [Eslint Plugin Jest](https://github.com/jest-community/eslint-plugin-jest)
     EslintJest(&'static str),
+    /// Rules from [Eslint Plugin Next](https://nextjs.org/docs/app/building-your-application/configuring/eslint)
+    EslintNext(&'static str),
     /// Rules from [Eslint Plugin JSX A11y](https://github.com/jsx-eslint/eslint-plugin-jsx-a11y)
     EslintJsxA11y(&'static str),
     /// Rules from [Eslint Plugin React](https://github.com/jsx-eslint/eslint-plugin-react)
@@ -112,14 +116,22 @@ pub enum RuleSource {
     EslintSonarJs(&'static str),
     /// Rules from [Eslint Plugin Stylistic](https://eslint.style)
     EslintStylistic(&'static str),
+    /// Rules from [Eslint Plugin Storybook](https://github.com/storybookjs/eslint-plugin-storybook)
+    EslintStorybook(&'static str),
+    /// Rules from [Eslint Plugin Svelte](https://sveltejs.github.io/eslint-plugin-svelte/)
+    EslintSvelte(&'static str),
     /// Rules from [Eslint Plugin Typescript](https://typescript-eslint.io)
     EslintTypeScript(&'static str),
     /// Rules from [Eslint Plugin Unicorn](https://github.com/sindresorhus/eslint-plugin-unicorn)
     EslintUnicorn(&'static str),
+    /// Rules from [Eslint Plugin Vue](https://eslint.vuejs.org/)
+    EslintVue(&'static str),
     /// Rules from [Eslint Plugin Mysticatea](https://github.com/mysticatea/eslint-plugin)
     EslintMysticatea(&'static str),
     /// Rules from [Eslint Plugin Barrel Files](https://github.com/thepassle/eslint-plugin-barrel-files)
     EslintBarrelFiles(&'static str),
+    /// Rules from [GraphQL-ESLint](https://the-guild.dev/graphql/eslint/docs)
+    EslintGraphql(&'static str),
     /// Rules from [Stylelint](https://github.com/stylelint/stylelint)
     Stylelint(&'static str),
 }
@@ -138,16 +150,21 @@ impl std::fmt::Display for RuleSource {
             RuleSource::EslintImport(_) => write!(f, "eslint-plugin-import"),
             RuleSource::EslintImportAccess(_) => write!(f, "eslint-plugin-import-access"),
             RuleSource::EslintJest(_) => write!(f, "eslint-plugin-jest"),
+            RuleSource::EslintNext(_) => write!(f, "@next/eslint-plugin-next"),
             RuleSource::EslintJsxA11y(_) => write!(f, "eslint-plugin-jsx-a11y"),
             RuleSource::EslintReact(_) => write!(f, "eslint-plugin-react"),
             RuleSource::EslintReactHooks(_) => write!(f, "eslint-plugin-react-hooks"),
             RuleSource::EslintSolid(_) => write!(f, "eslint-plugin-solid"),
             RuleSource::EslintSonarJs(_) => write!(f, "eslint-plugin-sonarjs"),
             RuleSource::EslintStylistic(_) => write!(f, "eslint-plugin-stylistic"),
+            RuleSource::EslintStorybook(_) => write!(f, "eslint-plugin-storybook"),
+            RuleSource::EslintSvelte(_) => write!(f, "eslint-plugin-svelte"),
             RuleSource::EslintTypeScript(_) => write!(f, "typescript-eslint"),
             RuleSource::EslintUnicorn(_) => write!(f, "eslint-plugin-unicorn"),
+            RuleSource::EslintVue(_) => write!(f, "eslint-plugin-vue"),
             RuleSource::EslintMysticatea(_) => write!(f, "@mysticatea/eslint-plugin"),
             RuleSource::EslintBarrelFiles(_) => write!(f, "eslint-plugin-barrel-files"),
+            RuleSource::EslintGraphql(_) => write!(f, "GraphQL-ESLint"),
             RuleSource::Stylelint(_) => write!(f, "Stylelint"),
         }
     }
@@ -183,6 +200,7 @@ impl RuleSource {
             | Self::EslintImport(rule_name)
             | Self::EslintImportAccess(rule_name)
             | Self::EslintJest(rule_name)
+            | Self::EslintNext(rule_name)
             | Self::EslintJsxA11y(rule_name)
             | Self::EslintReact(rule_name)
             | Self::EslintReactHooks(rule_name)
@@ -190,9 +208,13 @@ impl RuleSource {
             | Self::EslintSolid(rule_name)
             | Self::EslintSonarJs(rule_name)
             | Self::EslintStylistic(rule_name)
+            | Self::EslintStorybook(rule_name)
+            | Self::EslintSvelte(rule_name)
             | Self::EslintUnicorn(rule_name)
+            | Self::EslintVue(rule_name)
             | Self::EslintMysticatea(rule_name)
             | Self::EslintBarrelFiles(rule_name)
+            | Self::EslintGraphql(rule_name)
             | Self::Stylelint(rule_name) => rule_name,
         }
     }
@@ -203,6 +225,7 @@ impl RuleSource {
             Self::EslintImport(rule_name) => format!("import/{rule_name}"),
             Self::EslintImportAccess(rule_name) => format!("import-access/{rule_name}"),
             Self::EslintJest(rule_name) => format!("jest/{rule_name}"),
+            Self::EslintNext(rule_name) => format!("@next/next/{rule_name}"),
             Self::EslintJsxA11y(rule_name) => format!("jsx-a11y/{rule_name}"),
             Self::EslintReact(rule_name) => format!("react/{rule_name}"),
             Self::EslintReactHooks(rule_name) => format!("react-hooks/{rule_name}"),
@@ -210,9 +233,13 @@ impl RuleSource {
             Self::EslintSolid(rule_name) => format!("solidjs/{rule_name}"),
             Self::EslintSonarJs(rule_name) => format!("sonarjs/{rule_name}"),
             Self::EslintStylistic(rule_name) => format!("@stylistic/{rule_name}"),
+            Self::EslintStorybook(rule_name) => format!("storybook/{rule_name}"),
+            Self::EslintSvelte(rule_name) => format!("svelte/{rule_name}"),
             Self::EslintUnicorn(rule_name) => format!("unicorn/{rule_name}"),
+            Self::EslintVue(rule_name) => format!("vue/{rule_name}"),
             Self::EslintMysticatea(rule_name) => format!("@mysticatea/{rule_name}"),
             Self::EslintBarrelFiles(rule_name) => format!("barrel-files/{rule_name}"),
+            Self::EslintGraphql(rule_name) => format!("@graphql-eslint/{rule_name}"),
             Self::Stylelint(rule_name) => format!("stylelint/{rule_name}"),
         }
     }
@@ -224,6 +251,7 @@ impl RuleSource {
             Self::EslintImport(rule_name) => format!("https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/{rule_name}.md"),
             Self::EslintImportAccess(_) => "https://github.com/uhyo/eslint-plugin-import-access".to_string(),
             Self::EslintJest(rule_name) => format!("https://github.com/jest-community/eslint-plugin-jest/blob/main/docs/rules/{rule_name}.md"),
+            Self::EslintNext(rule_name) => format!("https://nextjs.org/docs/messages/{rule_name}"),
             Self::EslintJsxA11y(rule_name) => format!("https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/{rule_name}.md"),
             Self::EslintReact(rule_name) => format!("https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/{rule_name}.md"),
             Self::EslintReactHooks(_) =>  "https://github.com/facebook/react/blob/main/packages/eslint-plugin-react-hooks/README.md".to_string(),
@@ -231,9 +259,13 @@ impl RuleSource {
             Self::EslintSolid(rule_name) => format!("https://github.com/solidjs-community/eslint-plugin-solid/blob/main/docs/{rule_name}.md"),
             Self::EslintSonarJs(rule_name) => format!("https://github.com/SonarSource/eslint-plugin-sonarjs/blob/HEAD/docs/rules/{rule_name}.md"),
             Self::EslintStylistic(rule_name) => format!("https://eslint.style/rules/default/{rule_name}"),
+            Self::EslintStorybook(rule_name) => format!("https://github.com/storybookjs/eslint-plugin-storybook/blob/main/docs/rules/{rule_name}.md"),
+            Self::EslintSvelte(rule_name) => format!("https://sveltejs.github.io/eslint-plugin-svelte/rules/{rule_name}/"),
             Self::EslintUnicorn(rule_name) => format!("https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/{rule_name}.md"),
+            Self::EslintVue(rule_name) => format!("https://eslint.vuejs.org/rules/{rule_name}"),
             Self::EslintMysticatea(rule_name) => format!("https://github.com/mysticatea/eslint-plugin/blob/master/docs/rules/{rule_name}.md"),
             Self::EslintBarrelFiles(rule_name) => format!("https://github.com/thepassle/eslint-plugin-barrel-files/blob/main/docs/rules/{rule_name}.md"),
+            Self::EslintGraphql(rule_name) => format!("https://the-guild.dev/graphql/eslint/rules/{rule_name}"),
             Self::Stylelint(rule_name) => format!("https://github.com/stylelint/stylelint/blob/main/lib/rules/{rule_name}/README.md"),
         }
     }
@@ -274,6 +306,81 @@ impl RuleSourceKind {
     }
 }
 
+/// A domain groups the rules that are specific to a framework or a library.
+///
+/// The rules of a domain are enabled when the project depends on one of the
+/// packages of the domain, unless the configuration disables them.
+/// The domains that don't have packages, such as [RuleDomain::PackageJson],
+/// only group their rules, which must be enabled by the configuration.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum RuleDomain {
+    /// Rules for [Next.js](https://nextjs.org/)
+    Next,
+    /// Rules for the `package.json` manifests of the packages
+    PackageJson,
+    /// Rules for [Solid](https://www.solidjs.com/)
+    Solid,
+    /// Rules for [Storybook](https://storybook.js.org/)
+    Storybook,
+    /// Rules for [Svelte](https://svelte.dev/)
+    Svelte,
+    /// Rules for test frameworks, such as [Jest](https://jestjs.io/) and [Vitest](https://vitest.dev/)
+    Test,
+    /// Rules for the configuration files of [TypeScript](https://www.typescriptlang.org/), such as `tsconfig.json`
+    Tsconfig,
+    /// Rules for [Vue](https://vuejs.org/)
+    Vue,
+}
+
+impl RuleDomain {
+    /// The packages that enable the domain when the project depends on them.
+    ///
+    /// A package that ends with `/*` stands for all the packages of its scope, such as `@storybook/*`.
+    pub const fn manifest_dependencies(&self) -> &'static [&'static str] {
+        match self {
+            Self::Next => &["next"],
+            Self::PackageJson => &[],
+            Self::Solid => &["solid-js"],
+            Self::Storybook => &["storybook", "@storybook/*"],
+            Self::Svelte => &["svelte"],
+            Self::Test => &["jest", "mocha", "vitest"],
+            Self::Tsconfig => &[],
+            Self::Vue => &["vue"],
+        }
+    }
+
+    /// The globs of the files that enable the domain, even when the project doesn't depend on its packages.
+    pub const fn globs(&self) -> &'static [&'static str] {
+        match self {
+            Self::Next => &[],
+            Self::PackageJson => &[],
+            Self::Solid => &[],
+            Self::Storybook => &[],
+            Self::Svelte => &[],
+            Self::Test => &["*.test.*", "*.spec.*", "**/__tests__/**"],
+            Self::Tsconfig => &[],
+            Self::Vue => &[],
+        }
+    }
+}
+
+impl Display for RuleDomain {
+    fn fmt(&self, fmt: &mut biome_console::fmt::Formatter) -> std::io::Result<()> {
+        match self {
+            Self::Next => fmt.write_str("next"),
+            Self::PackageJson => fmt.write_str("packageJson"),
+            Self::Solid => fmt.write_str("solid"),
+            Self::Storybook => fmt.write_str("storybook"),
+            Self::Svelte => fmt.write_str("svelte"),
+            Self::Test => fmt.write_str("test"),
+            Self::Tsconfig => fmt.write_str("tsconfig"),
+            Self::Vue => fmt.write_str("vue"),
+        }
+    }
+}
+
 impl RuleMetadata {
     pub const fn new(
         version: &'static str,
@@ -291,6 +398,7 @@ impl RuleMetadata {
             fix_kind: FixKind::None,
             sources: &[],
             source_kind: None,
+            domains: &[],
         }
     }
 
@@ -322,6 +430,11 @@ impl RuleMetadata {
         self
     }
 
+    pub const fn domains(mut self, domains: &'static [RuleDomain]) -> Self {
+        self.domains = domains;
+        self
+    }
+
     pub const fn language(mut self, language: &'static str) -> Self {
         self.language = language;
         self
@@ -622,6 +735,7 @@ pub trait Rule: RuleMeta + Sized {
         ctx: &RuleContext<Self>,
         text_range: &TextRange,
         apply_suppression_comment: SuppressionCommentEmitter<RuleLanguage<Self>>,
+        suppression_reason: Option<&str>,
     ) -> Option<SuppressAction<RuleLanguage<Self>>>
     where
         Self: 'static,
@@ -642,6 +756,7 @@ pub trait Rule: RuleMeta + Sized {
                 mutation: &mut mutation,
                 token_offset: token,
                 diagnostic_text_range: text_range,
+                suppression_reason: suppression_reason.unwrap_or("<explanation>"),
             });
 
             Some(SuppressAction {
@@ -24,6 +24,47 @@ pub fn is_aria_property_valid(property: &str) -> bool {
     AriaPropertiesEnum::from_str(property).is_ok()
 }
 
+/// The ARIA properties supported by all the roles.
+///
+/// Source: https://www.w3.org/TR/wai-aria-1.2/#global_states
+const GLOBAL_ARIA_PROPERTIES: [&str; 21] = [
+    "aria-atomic",
+    "aria-busy",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-live",
+    "aria-owns",
+    "aria-relevant",
+    "aria-roledescription",
+];
+
+/// It checks if an ARIA property is global, that is supported by all the roles
+///
+/// ## Examples
+///
+/// ```
+/// use biome_aria::is_global_aria_property;
+///
+/// assert!(is_global_aria_property("aria-label"));
+/// assert!(!is_global_aria_property("aria-checked"));
+/// ```
+pub fn is_global_aria_property(property: &str) -> bool {
+    GLOBAL_ARIA_PROPERTIES.contains(&property)
+}
+
 /// It checks if an ARIA property type is valid
 ///
 /// ## Examples
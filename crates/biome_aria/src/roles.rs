@@ -1,4 +1,4 @@
-use crate::{define_role, is_aria_property_valid};
+use crate::{define_role, is_aria_property_valid, is_global_aria_property};
 use biome_aria_metadata::AriaPropertiesEnum;
 use rustc_hash::FxHashMap;
 use std::fmt::Debug;
@@ -17,7 +17,7 @@ pub trait AriaRoleDefinition: Debug {
     /// let checkbox_role = roles.get_role("checkbox").unwrap();
     ///
     /// let properties = checkbox_role.properties();
-    /// assert_eq!(properties.len(), 2);
+    /// assert_eq!(properties.len(), 4);
     /// ```
     fn properties(&self) -> Iter<(&str, bool)>;
 
@@ -57,6 +57,30 @@ pub trait AriaRoleDefinition: Debug {
         false
     }
 
+    /// Given a [aria property](ARIA_PROPERTIES) as input, it checks if it's supported
+    /// by the current role.
+    ///
+    /// The global properties are supported by all the roles.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_aria::AriaRoles;
+    /// let roles = AriaRoles::default();
+    ///
+    /// let checkbox_role = roles.get_role("checkbox").unwrap();
+    ///
+    /// assert!(checkbox_role.is_property_supported("aria-checked"));
+    /// assert!(checkbox_role.is_property_supported("aria-label"));
+    /// assert!(!checkbox_role.is_property_supported("aria-valuenow"));
+    /// ```
+    fn is_property_supported(&self, property_to_check: &str) -> bool {
+        is_global_aria_property(property_to_check)
+            || self
+                .properties()
+                .any(|(property, _)| *property == property_to_check)
+    }
+
     /// Whether the current role is interactive
     fn is_interactive(&self) -> bool {
         self.roles().any(|role| *role == "widget")
@@ -71,7 +95,7 @@ pub trait AriaRoleDefinition: Debug {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#button
     ButtonRole {
-        PROPS: [("aria-expanded", false), ("aria-expanded", false)],
+        PROPS: [("aria-expanded", false), ("aria-pressed", false)],
         ROLES: ["roletype", "widget", "command"],
         CONCEPTS: &[("button", &[]), ("input", &[("type", "button")])],
     }
@@ -80,7 +104,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#checkbox
     CheckboxRole {
-        PROPS: [("aria-checked", true), ("aria-readonly", false)],
+        PROPS: [
+            ("aria-checked", true),
+            ("aria-expanded", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
+        ],
         ROLES: ["switch", "menuitemcheckbox", "widget"],
         CONCEPTS: &[("input", &[("type", "checkbox")])],
     }
@@ -88,7 +117,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#radio
     RadioRole {
-        PROPS: [("aria-checked", true), ("aria-readonly", false)],
+        PROPS: [
+            ("aria-checked", true),
+            ("aria-posinset", false),
+            ("aria-readonly", false),
+            ("aria-setsize", false),
+        ],
         ROLES: ["menuitemradio", "widget"],
         CONCEPTS: &[("input", &[("type", "radio")])],
     }
@@ -96,7 +130,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#switch
     SwitchRole {
-        PROPS: [("aria-checked", true)],
+        PROPS: [
+            ("aria-checked", true),
+            ("aria-expanded", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
+        ],
         ROLES: ["checkbox", "widget"],
     }
 }
@@ -104,7 +143,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#option
     OptionRole {
-        PROPS: [("aria-selected", true)],
+        PROPS: [
+            ("aria-checked", false),
+            ("aria-posinset", false),
+            ("aria-selected", true),
+            ("aria-setsize", false),
+        ],
         ROLES: ["treeitem", "widget"],
         CONCEPTS: &[("option", &[])],
     }
@@ -113,7 +157,14 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#combobox
     ComboBoxRole {
-        PROPS: [("aria-controls", true), ("aria-expanded", true)],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-autocomplete", false),
+            ("aria-controls", true),
+            ("aria-expanded", true),
+            ("aria-readonly", false),
+            ("aria-required", false),
+        ],
         ROLES: ["select", "widget"],
         CONCEPTS: &[("select", &[])],
     }
@@ -121,7 +172,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#heading
     HeadingRole {
-        PROPS:  [("aria-level", true)],
+        PROPS: [("aria-level", true)],
         ROLES:  ["sectionhead"],
         CONCEPTS: &[("h1", &[]), ("h2", &[]), ("h3", &[]), ("h4", &[]), ("h5", &[]), ("h6", &[])],
     }
@@ -129,10 +180,14 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#spinbutton
     SpinButtonRole {
-        PROPS:  [
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
             ("aria-valuemax", true),
             ("aria-valuemin", true),
             ("aria-valuenow", true),
+            ("aria-valuetext", false),
         ],
         ROLES: ["composite", "input", "range", "widget"],
         CONCEPTS: &[("hr", &[])],
@@ -141,10 +196,13 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#checkbox
     SliderRole {
-        PROPS:  [
+        PROPS: [
+            ("aria-orientation", false),
+            ("aria-readonly", false),
             ("aria-valuemax", true),
             ("aria-valuemin", true),
             ("aria-valuenow", true),
+            ("aria-valuetext", false),
         ],
         ROLES: ["input", "range", "widget"],
     }
@@ -152,10 +210,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#separator
     SeparatorRole {
-        PROPS:  [
+        PROPS: [
+            ("aria-orientation", false),
             ("aria-valuemax", true),
             ("aria-valuemin", true),
             ("aria-valuenow", true),
+            ("aria-valuetext", false),
         ],
         ROLES: ["structure", "widget"],
         CONCEPTS: &[("hr", &[])],
@@ -165,12 +225,13 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#scrollbar
     ScollbarRole {
-        PROPS:  [
+        PROPS: [
+            ("aria-controls", true),
+            ("aria-orientation", true),
             ("aria-valuemax", true),
             ("aria-valuemin", true),
             ("aria-valuenow", true),
-            ("aria-orientation", true),
-            ("aria-controls", true),
+            ("aria-valuetext", false),
         ],
         ROLES: ["range", "widget"],
     }
@@ -179,7 +240,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#article
     ArticleRole {
-        PROPS: [],
+        PROPS: [("aria-posinset", false), ("aria-setsize", false)],
         ROLES: ["document"],
         CONCEPTS: &[("article", &[])],
     }
@@ -188,7 +249,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#dialog
     DialogRole {
-        PROPS: [("aria-label", false), ("aria-labelledby", false)],
+        PROPS: [("aria-label", false), ("aria-labelledby", false), ("aria-modal", false)],
         ROLES: ["window"],
         CONCEPTS: &[("dialog", &[])],
     }
@@ -205,7 +266,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#alertdialog
     AlertDialogRole {
-        PROPS: [],
+        PROPS: [("aria-modal", false)],
         ROLES: ["structure"],
         CONCEPTS: &[("alert", &[])],
     }
@@ -213,7 +274,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#application
     ApplicationRole {
-        PROPS: [],
+        PROPS: [("aria-activedescendant", false), ("aria-expanded", false)],
         ROLES: ["alert", "dialog"],
     }
 }
@@ -243,7 +304,17 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#columnheader
     ColumnHeaderRole {
-        PROPS: [("aria-sort", false)],
+        PROPS: [
+            ("aria-colindex", false),
+            ("aria-colspan", false),
+            ("aria-expanded", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
+            ("aria-rowindex", false),
+            ("aria-rowspan", false),
+            ("aria-selected", false),
+            ("aria-sort", false),
+        ],
         ROLES: ["cell", "gridcell", "sectionhead"],
         CONCEPTS: &[("th", &[("scope", "col")])],
     }
@@ -287,7 +358,14 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#grid
     GridRole {
-        PROPS: [("aria-level", false), ("aria-multiselectable", false), ("aria-readonly", false)],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-colcount", false),
+            ("aria-level", false),
+            ("aria-multiselectable", false),
+            ("aria-readonly", false),
+            ("aria-rowcount", false),
+        ],
         ROLES: ["composite", "table"],
         CONCEPTS: &[("table", &[])],
     }
@@ -296,7 +374,16 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#gridcell
     GridCellRole {
-        PROPS: [("aria-readonly", false), ("aria-required", false), ("aria-selected", false)],
+        PROPS: [
+            ("aria-colindex", false),
+            ("aria-colspan", false),
+            ("aria-expanded", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
+            ("aria-rowindex", false),
+            ("aria-rowspan", false),
+            ("aria-selected", false),
+        ],
         ROLES: ["cell", "widget"],
         CONCEPTS: &[("td", &[])],
     }
@@ -341,7 +428,14 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#listbox
     ListBoxRole {
-        PROPS: [],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-expanded", false),
+            ("aria-multiselectable", false),
+            ("aria-orientation", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
+        ],
         ROLES: ["select", "widget"],
         CONCEPTS: &[("select", &[])],
     }
@@ -350,7 +444,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#listitem
     ListItemRole {
-        PROPS: [],
+        PROPS: [("aria-level", false), ("aria-posinset", false), ("aria-setsize", false)],
         ROLES: ["section"],
         CONCEPTS: &[("li", &[])],
     }
@@ -376,7 +470,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#menubar
     MenubarRole {
-        PROPS: [],
+        PROPS: [("aria-activedescendant", false), ("aria-orientation", false)],
         ROLES: ["toolbar"],
     }
 }
@@ -384,7 +478,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#menu
     MenuRole {
-        PROPS: [("aria-posinset", false), ("aria-setsize", false)],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-orientation", false),
+            ("aria-posinset", false),
+            ("aria-setsize", false),
+        ],
         ROLES: ["select"],
     }
 }
@@ -392,7 +491,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#menuitem
     MenuItemRole {
-        PROPS: [("aria-posinset", false), ("aria-setsize", false)],
+        PROPS: [("aria-expanded", false), ("aria-posinset", false), ("aria-setsize", false)],
         ROLES: ["command", "widget"],
     }
 }
@@ -400,7 +499,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#menuitemcheckbox
     MenuItemCheckboxRole {
-        PROPS: [("aria-checked", true)],
+        PROPS: [
+            ("aria-checked", true),
+            ("aria-expanded", false),
+            ("aria-posinset", false),
+            ("aria-setsize", false),
+        ],
         ROLES: ["checkbox", "menuitem", "widget"],
     }
 }
@@ -408,7 +512,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#menuitemradio
     MenuItemRadioRole {
-        PROPS: [("aria-checked", true)],
+        PROPS: [
+            ("aria-checked", true),
+            ("aria-expanded", false),
+            ("aria-posinset", false),
+            ("aria-setsize", false),
+        ],
         ROLES: ["radio", "menuitemcheckbox", "widget"],
     }
 }
@@ -433,14 +542,25 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#progressbar
     ProgressBarRole {
-        PROPS: [("aria-valuenow", true), ("aria-valuemin", true), ("aria-valuemax", true)],
+        PROPS: [
+            ("aria-valuemax", true),
+            ("aria-valuemin", true),
+            ("aria-valuenow", true),
+            ("aria-valuetext", false),
+        ],
         ROLES: ["range", "widget"],
     }
 }
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#radiogroup
     RadiogroupRole {
-        PROPS: [("aria-readonly", false), ("aria-required", false)],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-expanded", false),
+            ("aria-orientation", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
+        ],
         ROLES: ["range"],
     }
 }
@@ -448,7 +568,16 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#row
     RowRole {
-        PROPS: [("aria-colindex", false), ("aria-level", false), ("aria-rowindex", false), ("aria-selected", false)],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-colindex", false),
+            ("aria-expanded", false),
+            ("aria-level", false),
+            ("aria-posinset", false),
+            ("aria-rowindex", false),
+            ("aria-selected", false),
+            ("aria-setsize", false),
+        ],
         ROLES: ["group", "widget"],
         CONCEPTS: &[("tr", &[])],
     }
@@ -466,7 +595,17 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#rowheader
     RowHeaderRole {
-        PROPS: [("aria-sort", false)],
+        PROPS: [
+            ("aria-colindex", false),
+            ("aria-colspan", false),
+            ("aria-expanded", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
+            ("aria-rowindex", false),
+            ("aria-rowspan", false),
+            ("aria-selected", false),
+            ("aria-sort", false),
+        ],
         ROLES: ["cell", "gridcell", "sectionhead"],
         CONCEPTS: &[("th", &[("scope", "row")])],
     }
@@ -500,7 +639,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#tab
     TabRole {
-        PROPS: [("aria-posinset", false), ("aria-selected", false), ("aria-setsize", false)],
+        PROPS: [
+            ("aria-expanded", false),
+            ("aria-posinset", false),
+            ("aria-selected", false),
+            ("aria-setsize", false),
+        ],
         ROLES: ["sectionhead", "widget"],
     }
 }
@@ -508,7 +652,11 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.2/#tablist
     TabListRole {
-        PROPS: [("aria-multiselectable", false), ("aria-orientation", false)],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-multiselectable", false),
+            ("aria-orientation", false),
+        ],
         ROLES: ["composite"],
     }
 }
@@ -558,7 +706,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#toolbar
     ToolbarRole {
-        PROPS: [("aria-orientation", false)],
+        PROPS: [("aria-activedescendant", false), ("aria-orientation", false)],
         ROLES: ["group"],
     }
 }
@@ -574,7 +722,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#tree
     TreeRole {
-        PROPS: [("aria-multiselectable", false), ("aria-required", false)],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-multiselectable", false),
+            ("aria-orientation", false),
+            ("aria-required", false),
+        ],
         ROLES: ["select"],
     }
 }
@@ -582,7 +735,15 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#treegrid
     TreeGridRole {
-        PROPS: [],
+        PROPS: [
+            ("aria-activedescendant", false),
+            ("aria-colcount", false),
+            ("aria-multiselectable", false),
+            ("aria-orientation", false),
+            ("aria-readonly", false),
+            ("aria-required", false),
+            ("aria-rowcount", false),
+        ],
         ROLES: ["grid", "tree"],
     }
 }
@@ -590,7 +751,15 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.1/#treeitem
     TreeItemRole {
-        PROPS: [("aria-expanded", false), ("aria-haspopup", false)],
+        PROPS: [
+            ("aria-checked", false),
+            ("aria-expanded", false),
+            ("aria-haspopup", false),
+            ("aria-level", false),
+            ("aria-posinset", false),
+            ("aria-selected", false),
+            ("aria-setsize", false),
+        ],
         ROLES: ["listitem", "option"],
     }
 }
@@ -660,7 +829,7 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.2/#document
     DocumentRole {
-        PROPS: [],
+        PROPS: [("aria-expanded", false)],
         ROLES: ["structure"],
     }
 }
@@ -721,7 +890,7 @@ define_role! {
 define_role! {
     /// https://w3c.github.io/graphics-aria/#graphics-document
     GraphicsDocumentRole {
-        PROPS: [],
+        PROPS: [("aria-expanded", false)],
         ROLES: ["document"],
         CONCEPTS: &[("graphics-object", &[]), ("img", &[]), ("article", &[])],
     }
@@ -730,7 +899,7 @@ define_role! {
 define_role! {
     /// https://w3c.github.io/graphics-aria/#graphics-object
     GraphicsObjectRole {
-        PROPS: [],
+        PROPS: [("aria-expanded", false)],
         ROLES: ["group"],
         CONCEPTS: &[("graphics-document", &[]), ("group", &[]), ("img", &[]), ("graphics-symbol", &[])],
     }
@@ -782,7 +951,12 @@ define_role! {
 define_role! {
     /// https://www.w3.org/TR/wai-aria-1.2/#meter
     MeterRole {
-        PROPS: [],
+        PROPS: [
+            ("aria-valuemax", false),
+            ("aria-valuemin", false),
+            ("aria-valuenow", false),
+            ("aria-valuetext", false),
+        ],
         ROLES: ["range"],
         CONCEPTS: &[("meter", &[])],
     }
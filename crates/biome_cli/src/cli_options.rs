@@ -1,4 +1,5 @@
 use crate::logging::LoggingKind;
+use crate::reporter::template::ReporterTemplate;
 use crate::LoggingLevel;
 use biome_configuration::ConfigurationPathHint;
 use biome_diagnostics::Severity;
@@ -51,11 +52,15 @@ pub struct CliOptions {
     /// Allows to change how diagnostics and summary are reported.
     #[bpaf(
         long("reporter"),
-        argument("json|json-pretty"),
+        argument("json|json-pretty|sarif|junit|gitlab|checkstyle|template"),
         fallback(CliReporter::default())
     )]
     pub reporter: CliReporter,
 
+    /// The template used by `--reporter=template` to print each diagnostic on a line. The fields `{path}`, `{line}`, `{column}`, `{rule}`, `{severity}` and `{message}` are replaced by the values of the diagnostic. Defaults to `{path}:{line}:{column} {severity} {rule}: {message}`.
+    #[bpaf(long("reporter-template"), argument("TEMPLATE"), optional)]
+    pub reporter_template: Option<ReporterTemplate>,
+
     #[bpaf(
         long("log-level"),
         argument("none|debug|info|warn|error"),
@@ -125,6 +130,16 @@ pub enum CliReporter {
     Json,
     /// Reports information using the JSON format, formatted.
     JsonPretty,
+    /// Reports the diagnostics using the SARIF format
+    Sarif,
+    /// Reports the diagnostics using the JUnit XML format
+    Junit,
+    /// Reports the diagnostics using the Code Quality format of GitLab
+    GitLab,
+    /// Reports the diagnostics using the Checkstyle XML format
+    Checkstyle,
+    /// Reports each diagnostic on a line, using the template of `--reporter-template`
+    Template,
 }
 
 impl FromStr for CliReporter {
@@ -134,6 +149,11 @@ impl FromStr for CliReporter {
         match s {
             "json" => Ok(Self::Json),
             "json-pretty" => Ok(Self::JsonPretty),
+            "sarif" => Ok(Self::Sarif),
+            "junit" => Ok(Self::Junit),
+            "gitlab" => Ok(Self::GitLab),
+            "checkstyle" => Ok(Self::Checkstyle),
+            "template" => Ok(Self::Template),
             _ => Err(format!(
                 "value {s:?} is not valid for the --reporter argument"
             )),
@@ -147,6 +167,11 @@ impl Display for CliReporter {
             CliReporter::Default => f.write_str("default"),
             CliReporter::Json => f.write_str("json"),
             CliReporter::JsonPretty => f.write_str("json-pretty"),
+            CliReporter::Sarif => f.write_str("sarif"),
+            CliReporter::Junit => f.write_str("junit"),
+            CliReporter::GitLab => f.write_str("gitlab"),
+            CliReporter::Checkstyle => f.write_str("checkstyle"),
+            CliReporter::Template => f.write_str("template"),
         }
     }
 }
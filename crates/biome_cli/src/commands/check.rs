@@ -1,7 +1,10 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{
     get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+    StdinLanguage,
 };
+use crate::execute::baseline::BaselineSettings;
+use crate::execute::cache::CacheSettings;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -17,20 +20,28 @@ use biome_service::{
     workspace::{FixFileMode, UpdateSettingsParams},
 };
 use std::ffi::OsString;
+use std::path::PathBuf;
 
 pub(crate) struct CheckCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) interactive: bool,
     pub(crate) cli_options: CliOptions,
     pub(crate) configuration: Option<PartialConfiguration>,
     pub(crate) paths: Vec<OsString>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_language: Option<StdinLanguage>,
     pub(crate) formatter_enabled: Option<bool>,
     pub(crate) linter_enabled: Option<bool>,
     pub(crate) organize_imports_enabled: Option<bool>,
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<PathBuf>,
+    pub(crate) baseline: Option<PathBuf>,
+    pub(crate) write_baseline: Option<PathBuf>,
 }
 
 /// Handler for the "check" command of the Biome CLI
@@ -41,14 +52,21 @@ pub(crate) fn check(
     let CheckCommandPayload {
         apply,
         apply_unsafe,
+        interactive,
         cli_options,
         configuration,
         mut paths,
         stdin_file_path,
+        stdin_language,
         linter_enabled,
         organize_imports_enabled,
         formatter_enabled,
         since,
+        watch,
+        cache,
+        cache_location,
+        baseline,
+        write_baseline,
         staged,
         changed,
     } = payload;
@@ -67,6 +85,37 @@ pub(crate) fn check(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
+    if interactive && fix_file_mode.is_none() {
+        return Err(CliDiagnostic::missing_argument("--apply", "check"));
+    }
+    if interactive && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "interactive",
+            "stdin-file-path",
+        ));
+    }
+    // The files that didn't change since the previous run wouldn't be reviewed
+    if interactive && cache {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "interactive",
+            "cache",
+        ));
+    }
+
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "watch",
+            "stdin-file-path",
+        ));
+    }
+    if watch && write_baseline.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "watch",
+            "write-baseline",
+        ));
+    }
+    let baseline = BaselineSettings::from_arguments(&*session.app.fs, baseline, write_baseline)?;
+
     let loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
     validate_configuration_diagnostics(
@@ -122,7 +171,12 @@ pub(crate) fn check(
     let (vcs_base_path, gitignore_matches) =
         fs_configuration.retrieve_gitignore_matches(&session.app.fs, vcs_base_path.as_deref())?;
 
-    let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "check")?;
+    let stdin = get_stdin(
+        stdin_file_path,
+        stdin_language,
+        &mut *session.app.console,
+        "check",
+    )?;
 
     if let Some(_paths) =
         get_files_to_process(since, changed, staged, &session.app.fs, &fs_configuration)?
@@ -130,6 +184,17 @@ pub(crate) fn check(
         paths = _paths;
     }
 
+    let cache = if cache {
+        CacheSettings::new(
+            &*session.app.fs,
+            cache_location,
+            &fs_configuration,
+            &gitignore_matches,
+        )
+    } else {
+        None
+    };
+
     session
         .app
         .workspace
@@ -153,7 +218,11 @@ pub(crate) fn check(
             fix_file_mode,
             stdin,
         })
-        .set_report(&cli_options),
+        .set_report(&cli_options)
+        .set_watch(watch)
+        .set_interactive(interactive)
+        .set_cache(cache)
+        .set_baseline(baseline),
         session,
         &cli_options,
         paths,
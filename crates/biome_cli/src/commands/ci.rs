@@ -1,6 +1,7 @@
 use crate::changed::get_changed_files;
 use crate::cli_options::CliOptions;
 use crate::commands::validate_configuration_diagnostics;
+use crate::execute::baseline::BaselineSettings;
 use crate::{execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution};
 use biome_configuration::{organize_imports::PartialOrganizeImports, PartialConfiguration};
 use biome_configuration::{PartialFormatterConfiguration, PartialLinterConfiguration};
@@ -10,6 +11,7 @@ use biome_service::configuration::{
 };
 use biome_service::workspace::{RegisterProjectFolderParams, UpdateSettingsParams};
 use std::ffi::OsString;
+use std::path::PathBuf;
 
 pub(crate) struct CiCommandPayload {
     pub(crate) formatter_enabled: Option<bool>,
@@ -20,6 +22,8 @@ pub(crate) struct CiCommandPayload {
     pub(crate) cli_options: CliOptions,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) baseline: Option<PathBuf>,
+    pub(crate) write_baseline: Option<PathBuf>,
 }
 
 /// Handler for the "ci" command of the Biome CLI
@@ -33,9 +37,13 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         mut paths,
         since,
         changed,
+        baseline,
+        write_baseline,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
+    let baseline = BaselineSettings::from_arguments(&*session.app.fs, baseline, write_baseline)?;
+
     let loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
 
@@ -125,7 +133,9 @@ pub(crate) fn ci(session: CliSession, payload: CiCommandPayload) -> Result<(), C
         })?;
 
     execute_mode(
-        Execution::new_ci().set_report(&cli_options),
+        Execution::new_ci()
+            .set_report(&cli_options)
+            .set_baseline(baseline),
         session,
         &cli_options,
         paths,
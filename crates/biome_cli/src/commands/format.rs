@@ -1,8 +1,10 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{
     get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+    StdinLanguage,
 };
 use crate::diagnostics::DeprecatedArgument;
+use crate::execute::cache::CacheSettings;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -14,11 +16,17 @@ use biome_configuration::{
 use biome_console::{markup, ConsoleExt};
 use biome_deserialize::Merge;
 use biome_diagnostics::PrintDiagnostic;
+use biome_fs::{BiomePath, OpenOptions};
+use biome_rowan::TextRange;
 use biome_service::configuration::{
     load_configuration, LoadedConfiguration, PartialConfigurationExt,
 };
-use biome_service::workspace::{RegisterProjectFolderParams, UpdateSettingsParams};
+use biome_service::workspace::{
+    FileGuard, OpenFileParams, RegisterProjectFolderParams, UpdateSettingsParams,
+};
 use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 pub(crate) struct FormatCommandPayload {
     pub(crate) javascript_formatter: Option<PartialJavascriptFormatter>,
@@ -28,12 +36,46 @@ pub(crate) struct FormatCommandPayload {
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
     pub(crate) files_configuration: Option<PartialFilesConfiguration>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_language: Option<StdinLanguage>,
+    pub(crate) range: Option<FormatRange>,
+    pub(crate) debug_ir: Option<PathBuf>,
     pub(crate) write: bool,
+    pub(crate) verify: bool,
     pub(crate) cli_options: CliOptions,
     pub(crate) paths: Vec<OsString>,
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<PathBuf>,
+}
+
+/// The byte range passed to the `--range` argument, written as `START..END`
+#[derive(Debug, Clone, Copy)]
+pub struct FormatRange(TextRange);
+
+impl FromStr for FormatRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || format!("value {s:?} is not valid for the --range argument, expected START..END");
+        let (start, end) = s.split_once("..").ok_or_else(invalid)?;
+        let start = start.parse::<u32>().map_err(|_| invalid())?;
+        let end = end.parse::<u32>().map_err(|_| invalid())?;
+        if start > end {
+            return Err(format!("the start of the range {s:?} is after its end"));
+        }
+
+        Ok(Self(TextRange::new(start.into(), end.into())))
+    }
+}
+
+impl From<FormatRange> for TextRange {
+    fn from(value: FormatRange) -> Self {
+        value.0
+    }
 }
 
 /// Handler for the "format" command of the Biome CLI
@@ -48,16 +90,39 @@ pub(crate) fn format(
         mut paths,
         cli_options,
         stdin_file_path,
+        stdin_language,
+        range,
+        debug_ir,
         files_configuration,
         write,
+        verify,
         mut json_formatter,
         mut css_formatter,
         since,
+        watch,
+        cache,
+        cache_location,
         staged,
         changed,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
+    if range.is_some() && stdin_file_path.is_none() {
+        return Err(CliDiagnostic::missing_argument("stdin-file-path", "format"));
+    }
+    if verify && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "verify",
+            "stdin-file-path",
+        ));
+    }
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "watch",
+            "stdin-file-path",
+        ));
+    }
+
     let loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
     validate_configuration_diagnostics(
@@ -186,6 +251,17 @@ pub(crate) fn format(
         paths = _paths;
     }
 
+    let cache = if cache {
+        CacheSettings::new(
+            &*session.app.fs,
+            cache_location,
+            &configuration,
+            &gitignore_matches,
+        )
+    } else {
+        None
+    };
+
     session
         .app
         .workspace
@@ -204,14 +280,49 @@ pub(crate) fn format(
             gitignore_matches,
         })?;
 
-    let stdin = get_stdin(stdin_file_path, console, "format")?;
+    if let Some(debug_ir) = debug_ir {
+        return print_formatter_ir(session, &debug_ir);
+    }
+
+    let stdin = get_stdin(stdin_file_path, stdin_language, console, "format")?;
 
     let execution = Execution::new(TraversalMode::Format {
         ignore_errors: cli_options.skip_errors,
         write,
+        verify,
         stdin,
+        range: range.map(TextRange::from),
     })
-    .set_report(&cli_options);
+    .set_report(&cli_options)
+    .set_watch(watch)
+    .set_cache(cache);
 
     execute_mode(execution, session, &cli_options, paths)
 }
+
+/// Prints the IR of the formatter for the file at `path`, the document that the printer lays out
+fn print_formatter_ir(session: CliSession, path: &Path) -> Result<(), CliDiagnostic> {
+    let mut file = session
+        .app
+        .fs
+        .open_with_options(path, OpenOptions::default().read(true))?;
+    let mut content = String::new();
+    file.read_to_string(&mut content)?;
+
+    let file_guard = FileGuard::open(
+        &*session.app.workspace,
+        OpenFileParams {
+            path: BiomePath::new(path),
+            content,
+            version: 0,
+            document_file_source: None,
+        },
+    )?;
+    let formatter_ir = file_guard.get_formatter_ir()?;
+
+    session.app.console.log(markup! {
+        {formatter_ir}
+    });
+
+    Ok(())
+}
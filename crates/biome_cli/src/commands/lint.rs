@@ -1,7 +1,10 @@
 use crate::cli_options::CliOptions;
 use crate::commands::{
     get_files_to_process, get_stdin, resolve_manifest, validate_configuration_diagnostics,
+    StdinLanguage,
 };
+use crate::execute::baseline::BaselineSettings;
+use crate::execute::cache::CacheSettings;
 use crate::{
     execute_mode, setup_cli_subscriber, CliDiagnostic, CliSession, Execution, TraversalMode,
 };
@@ -16,10 +19,12 @@ use biome_service::configuration::{
 };
 use biome_service::workspace::{FixFileMode, RegisterProjectFolderParams, UpdateSettingsParams};
 use std::ffi::OsString;
+use std::path::PathBuf;
 
 pub(crate) struct LintCommandPayload {
     pub(crate) apply: bool,
     pub(crate) apply_unsafe: bool,
+    pub(crate) suppress: Option<Option<String>>,
     pub(crate) cli_options: CliOptions,
     pub(crate) linter_configuration: Option<PartialLinterConfiguration>,
     pub(crate) vcs_configuration: Option<PartialVcsConfiguration>,
@@ -27,9 +32,15 @@ pub(crate) struct LintCommandPayload {
     pub(crate) paths: Vec<OsString>,
     pub(crate) rule: Option<RuleSelector>,
     pub(crate) stdin_file_path: Option<String>,
+    pub(crate) stdin_language: Option<StdinLanguage>,
     pub(crate) staged: bool,
     pub(crate) changed: bool,
     pub(crate) since: Option<String>,
+    pub(crate) watch: bool,
+    pub(crate) cache: bool,
+    pub(crate) cache_location: Option<PathBuf>,
+    pub(crate) baseline: Option<PathBuf>,
+    pub(crate) write_baseline: Option<PathBuf>,
 }
 
 /// Handler for the "lint" command of the Biome CLI
@@ -37,16 +48,23 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
     let LintCommandPayload {
         apply,
         apply_unsafe,
+        suppress,
         cli_options,
         mut linter_configuration,
         mut paths,
         rule,
         stdin_file_path,
+        stdin_language,
         vcs_configuration,
         files_configuration,
         staged,
         changed,
         since,
+        watch,
+        cache,
+        cache_location,
+        baseline,
+        write_baseline,
     } = payload;
     setup_cli_subscriber(cli_options.log_level, cli_options.log_kind);
 
@@ -55,6 +73,13 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
             "--apply",
             "--apply-unsafe",
         ));
+    } else if suppress.is_some() && (apply || apply_unsafe) {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "--suppress",
+            if apply { "--apply" } else { "--apply-unsafe" },
+        ));
+    } else if suppress.is_some() {
+        Some(FixFileMode::ApplySuppressions)
     } else if !apply && !apply_unsafe {
         None
     } else if apply && !apply_unsafe {
@@ -63,6 +88,20 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         Some(FixFileMode::SafeAndUnsafeFixes)
     };
 
+    if watch && stdin_file_path.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "watch",
+            "stdin-file-path",
+        ));
+    }
+    if watch && write_baseline.is_some() {
+        return Err(CliDiagnostic::incompatible_arguments(
+            "watch",
+            "write-baseline",
+        ));
+    }
+    let baseline = BaselineSettings::from_arguments(&*session.app.fs, baseline, write_baseline)?;
+
     let loaded_configuration =
         load_configuration(&session.app.fs, cli_options.as_configuration_path_hint())?;
     validate_configuration_diagnostics(
@@ -107,7 +146,23 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
         paths = _paths;
     }
 
-    let stdin = get_stdin(stdin_file_path, &mut *session.app.console, "lint")?;
+    let stdin = get_stdin(
+        stdin_file_path,
+        stdin_language,
+        &mut *session.app.console,
+        "lint",
+    )?;
+
+    let cache = if cache {
+        CacheSettings::new(
+            &*session.app.fs,
+            cache_location,
+            &fs_configuration,
+            &gitignore_matches,
+        )
+    } else {
+        None
+    };
 
     session
         .app
@@ -132,8 +187,12 @@ pub(crate) fn lint(session: CliSession, payload: LintCommandPayload) -> Result<(
             fix_file_mode,
             stdin,
             rule,
+            suppression_reason: suppress.flatten(),
         })
-        .set_report(&cli_options),
+        .set_report(&cli_options)
+        .set_watch(watch)
+        .set_cache(cache)
+        .set_baseline(baseline),
         session,
         &cli_options,
         paths,
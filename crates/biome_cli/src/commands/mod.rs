@@ -1,5 +1,6 @@
 use crate::changed::{get_changed_files, get_staged_files};
 use crate::cli_options::{cli_options, CliOptions, ColorsArg};
+use crate::commands::format::FormatRange;
 use crate::diagnostics::DeprecatedConfigurationFile;
 use crate::execute::Stdin;
 use crate::logging::LoggingKind;
@@ -19,11 +20,13 @@ use biome_diagnostics::{Diagnostic, PrintDiagnostic};
 use biome_fs::{BiomePath, FileSystem};
 use biome_service::configuration::LoadedConfiguration;
 use biome_service::documentation::Doc;
-use biome_service::workspace::{OpenProjectParams, UpdateProjectParams};
+use biome_service::project::{find_tailwind_config, find_workspace_packages};
+use biome_service::workspace::{DocumentFileSource, OpenProjectParams, UpdateProjectParams};
 use biome_service::{DynRef, WorkspaceError};
-use bpaf::Bpaf;
+use bpaf::{construct, long, Bpaf, Parser};
 use std::ffi::OsString;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 pub(crate) mod check;
 pub(crate) mod ci;
@@ -82,6 +85,11 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        /// Asks before each fix of `--apply` or `--apply-unsafe` is applied, and prints the changes of the fix.
+        ///
+        /// The answers are `y` to apply the fix, `n` to skip it, `a` to apply all the fixes of its rule and `q` to stop the review.
+        #[bpaf(long("interactive"), switch)]
+        interactive: bool,
         /// Allow to enable or disable the formatter check.
         #[bpaf(
             long("formatter-enabled"),
@@ -113,6 +121,15 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// The language of the code piped from `stdin`, when the extension of `--stdin-file-path` doesn't tell it.
+        ///
+        /// It accepts a language identifier such as `javascript`, `typescriptreact` or `json`, or an extension such as `ts`.
+        /// Without this option, the language of a path without extension is guessed from the code.
+        ///
+        /// Example: `biome check --stdin-file-path=cli --stdin-language=ts < cli`
+        #[bpaf(long("stdin-language"), argument("LANGUAGE"), hide_usage)]
+        stdin_language: Option<StdinLanguage>,
+
         /// When set to true, only the files that have been staged (the ones prepared to be committed)
         /// will be linted.
         #[bpaf(long("staged"), switch)]
@@ -128,6 +145,33 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Keeps running, and processes again the files that change.
+        ///
+        /// The configuration file isn't read again when it changes.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Stores the diagnostics of the files in a cache file, and reads them from it when the
+        /// files didn't change since the previous run.
+        ///
+        /// The cache is discarded when the configuration or the version of Biome change.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The path of the cache file used by `--cache`. Defaults to `.biomecache`.
+        #[bpaf(long("cache-location"), argument("PATH"))]
+        cache_location: Option<PathBuf>,
+
+        /// Suppresses the diagnostics that are recorded in the baseline file, so only the new
+        /// diagnostics are reported.
+        #[bpaf(long("baseline"), argument("PATH"))]
+        baseline: Option<PathBuf>,
+
+        /// Records the diagnostics in the baseline file, to be passed later to `--baseline`. The
+        /// recorded diagnostics aren't reported.
+        #[bpaf(long("write-baseline"), argument("PATH"))]
+        write_baseline: Option<PathBuf>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -141,6 +185,8 @@ pub enum BiomeCommand {
         /// Apply safe fixes and unsafe fixes, formatting and import sorting
         #[bpaf(long("apply-unsafe"), switch)]
         apply_unsafe: bool,
+        #[bpaf(external(suppress), optional)]
+        suppress: Option<Option<String>>,
         #[bpaf(external(partial_linter_configuration), hide_usage, optional)]
         linter_configuration: Option<PartialLinterConfiguration>,
 
@@ -174,6 +220,15 @@ pub enum BiomeCommand {
         /// Example: `echo 'let a;' | biome lint --stdin-file-path=file.js`
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
+
+        /// The language of the code piped from `stdin`, when the extension of `--stdin-file-path` doesn't tell it.
+        ///
+        /// It accepts a language identifier such as `javascript`, `typescriptreact` or `json`, or an extension such as `ts`.
+        /// Without this option, the language of a path without extension is guessed from the code.
+        ///
+        /// Example: `biome lint --stdin-file-path=cli --stdin-language=ts < cli`
+        #[bpaf(long("stdin-language"), argument("LANGUAGE"), hide_usage)]
+        stdin_language: Option<StdinLanguage>,
         /// When set to true, only the files that have been staged (the ones prepared to be committed)
         /// will be linted.
         #[bpaf(long("staged"), switch)]
@@ -186,6 +241,28 @@ pub enum BiomeCommand {
         /// flag and the `defaultBranch` is not set in your biome.json
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
+        /// Keeps running, and processes again the files that change.
+        ///
+        /// The configuration file isn't read again when it changes.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+        /// Stores the diagnostics of the files in a cache file, and reads them from it when the
+        /// files didn't change since the previous run.
+        ///
+        /// The cache is discarded when the configuration or the version of Biome change.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+        /// The path of the cache file used by `--cache`. Defaults to `.biomecache`.
+        #[bpaf(long("cache-location"), argument("PATH"))]
+        cache_location: Option<PathBuf>,
+        /// Suppresses the diagnostics that are recorded in the baseline file, so only the new
+        /// diagnostics are reported.
+        #[bpaf(long("baseline"), argument("PATH"))]
+        baseline: Option<PathBuf>,
+        /// Records the diagnostics in the baseline file, to be passed later to `--baseline`. The
+        /// recorded diagnostics aren't reported.
+        #[bpaf(long("write-baseline"), argument("PATH"))]
+        write_baseline: Option<PathBuf>,
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -218,6 +295,31 @@ pub enum BiomeCommand {
         #[bpaf(long("stdin-file-path"), argument("PATH"), hide_usage)]
         stdin_file_path: Option<String>,
 
+        /// The language of the code piped from `stdin`, when the extension of `--stdin-file-path` doesn't tell it.
+        ///
+        /// It accepts a language identifier such as `javascript`, `typescriptreact` or `json`, or an extension such as `ts`.
+        /// Without this option, the language of a path without extension is guessed from the code.
+        ///
+        /// Example: `biome format --stdin-file-path=cli --stdin-language=ts < cli`
+        #[bpaf(long("stdin-language"), argument("LANGUAGE"), hide_usage)]
+        stdin_language: Option<StdinLanguage>,
+
+        /// Formats only the code between the byte offsets `START` and `END`.
+        ///
+        /// It requires `--stdin-file-path`, only the replaced span is printed.
+        ///
+        /// The span covers the nodes that the range touches.
+        ///
+        /// Example: `biome format --stdin-file-path=a.js --range=0..9 < a.js`
+        #[bpaf(long("range"), argument("START..END"), hide_usage)]
+        range: Option<FormatRange>,
+
+        /// Prints the IR of the formatter for a file, instead of formatting it.
+        ///
+        /// The IR shows the groups, indents and line breaks the printer lays out.
+        #[bpaf(long("debug-ir"), argument("PATH"), hide_usage)]
+        debug_ir: Option<PathBuf>,
+
         #[bpaf(external, hide_usage)]
         cli_options: CliOptions,
 
@@ -225,6 +327,12 @@ pub enum BiomeCommand {
         #[bpaf(switch)]
         write: bool,
 
+        /// Formats the files a second time, and reports the unstable ones.
+        ///
+        /// A file is unstable when the formatter changes its own output.
+        #[bpaf(long("verify"), switch)]
+        verify: bool,
+
         /// When set to true, only the files that have been staged (the ones prepared to be committed)
         /// will be linted.
         #[bpaf(long("staged"), switch)]
@@ -240,6 +348,23 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Keeps running, and processes again the files that change.
+        ///
+        /// The configuration file isn't read again when it changes.
+        #[bpaf(long("watch"), switch)]
+        watch: bool,
+
+        /// Stores the diagnostics of the files in a cache file, and reads them from it when the
+        /// files didn't change since the previous run.
+        ///
+        /// The cache is discarded when the configuration or the version of Biome change.
+        #[bpaf(long("cache"), switch)]
+        cache: bool,
+
+        /// The path of the cache file used by `--cache`. Defaults to `.biomecache`.
+        #[bpaf(long("cache-location"), argument("PATH"))]
+        cache_location: Option<PathBuf>,
+
         /// Single file, single path or list of paths.
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -274,6 +399,16 @@ pub enum BiomeCommand {
         #[bpaf(long("since"), argument("REF"))]
         since: Option<String>,
 
+        /// Suppresses the diagnostics that are recorded in the baseline file, so only the new
+        /// diagnostics are reported.
+        #[bpaf(long("baseline"), argument("PATH"))]
+        baseline: Option<PathBuf>,
+
+        /// Records the diagnostics in the baseline file, to be passed later to `--baseline`. The
+        /// recorded diagnostics aren't reported.
+        #[bpaf(long("write-baseline"), argument("PATH"))]
+        write_baseline: Option<PathBuf>,
+
         /// Single file, single path or list of paths
         #[bpaf(positional("PATH"), many)]
         paths: Vec<OsString>,
@@ -397,6 +532,23 @@ pub enum MigrateSubCommand {
     },
 }
 
+/// Parses `--suppress[=REASON]`, the reason is only accepted in the same word as the flag so
+/// `--suppress src` keeps `src` as a path
+fn suppress() -> impl Parser<Option<String>> {
+    let reason = long("suppress")
+        .help(
+            "Insert suppression comments for the diagnostics of the files, instead of applying their fixes, \
+             with REASON as their explanation. Use it with `--rule` to suppress only the diagnostics of a rule or rule group.",
+        )
+        .argument::<String>("REASON")
+        .adjacent()
+        .map(Some);
+    let no_reason = long("suppress")
+        .help("Insert suppression comments for the diagnostics of the files, without explanation.")
+        .req_flag(None);
+    construct!([reason, no_reason])
+}
+
 impl MigrateSubCommand {
     pub const fn is_prettier(&self) -> bool {
         matches!(self, MigrateSubCommand::Prettier)
@@ -513,24 +665,107 @@ fn resolve_manifest(cli_session: &CliSession) -> Result<(), WorkspaceError> {
     )?;
 
     if let Some(result) = result {
+        let tsconfig_path = result.file_path.with_file_name("tsconfig.json");
+        let tailwind_config = find_tailwind_config(fs, &result.file_path);
+        let workspace_packages = find_workspace_packages(fs, &result.file_path, &result.content);
         let biome_path = BiomePath::new(result.file_path);
         workspace.open_project(OpenProjectParams {
             path: biome_path.clone(),
             content: result.content,
             version: 0,
         })?;
-        workspace.update_current_project(UpdateProjectParams { path: biome_path })?;
+        let tsconfig_path = match fs.read_file_from_path(&tsconfig_path) {
+            Ok(content) => {
+                // The paths of the traversed files are relative to the working directory.
+                let working_directory = fs.working_directory().unwrap_or_default();
+                let tsconfig_path = BiomePath::new(
+                    tsconfig_path
+                        .strip_prefix(&working_directory)
+                        .unwrap_or(&tsconfig_path),
+                );
+                workspace.open_project(OpenProjectParams {
+                    path: tsconfig_path.clone(),
+                    content,
+                    version: 0,
+                })?;
+                Some(tsconfig_path)
+            }
+            Err(_) => None,
+        };
+        let tailwind_config_path = match tailwind_config {
+            Some(tailwind_config) => {
+                // The paths of the traversed files are relative to the working directory.
+                let working_directory = fs.working_directory().unwrap_or_default();
+                let tailwind_config_path = BiomePath::new(
+                    tailwind_config
+                        .file_path
+                        .strip_prefix(&working_directory)
+                        .unwrap_or(&tailwind_config.file_path),
+                );
+                workspace.open_project(OpenProjectParams {
+                    path: tailwind_config_path.clone(),
+                    content: tailwind_config.content,
+                    version: 0,
+                })?;
+                Some(tailwind_config_path)
+            }
+            None => None,
+        };
+        let mut workspace_package_paths = Vec::with_capacity(workspace_packages.len());
+        for workspace_package in workspace_packages {
+            let path = BiomePath::new(workspace_package.file_path);
+            workspace.open_project(OpenProjectParams {
+                path: path.clone(),
+                content: workspace_package.content,
+                version: 0,
+            })?;
+            workspace_package_paths.push(path);
+        }
+        workspace.update_current_project(UpdateProjectParams {
+            path: biome_path,
+            tsconfig_path,
+            tailwind_config_path,
+            workspace_package_paths,
+        })?;
     }
 
     Ok(())
 }
 
+/// The language passed to the `--stdin-language` argument, written as a language identifier or as an extension
+#[derive(Debug, Clone, Copy)]
+pub struct StdinLanguage(DocumentFileSource);
+
+impl FromStr for StdinLanguage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match DocumentFileSource::from_language_id(s).or(DocumentFileSource::from_extension(s)) {
+            DocumentFileSource::Unknown => Err(format!(
+                "value {s:?} is not a language supported by the --stdin-language argument"
+            )),
+            file_source => Ok(Self(file_source)),
+        }
+    }
+}
+
+impl From<StdinLanguage> for DocumentFileSource {
+    fn from(value: StdinLanguage) -> Self {
+        value.0
+    }
+}
+
 /// Computes [Stdin] if the CLI has the necessary information.
 ///
+/// The language of the code is `stdin_language` if it's passed, otherwise it's guessed from
+/// the code when the path doesn't have an extension.
+///
 /// ## Errors
 /// - If the user didn't provide anything via `stdin` but the option `--stdin-file-path` is passed.
+/// - If the option `--stdin-language` is passed without `--stdin-file-path`.
 pub(crate) fn get_stdin(
     stdin_file_path: Option<String>,
+    stdin_language: Option<StdinLanguage>,
     console: &mut dyn Console,
     command_name: &str,
 ) -> Result<Option<Stdin>, CliDiagnostic> {
@@ -538,11 +773,25 @@ pub(crate) fn get_stdin(
         let input_code = console.read();
         if let Some(input_code) = input_code {
             let path = PathBuf::from(stdin_file_path);
-            Some((path, input_code).into())
+            let file_source = match stdin_language {
+                Some(language) => Some(language.into()),
+                None if path.extension().is_none()
+                    && DocumentFileSource::from_path(&path) == DocumentFileSource::Unknown =>
+                {
+                    Some(DocumentFileSource::from_content(&input_code))
+                }
+                None => None,
+            };
+            Some(Stdin::new(path, input_code, file_source))
         } else {
             // we provided the argument without a piped stdin, we bail
             return Err(CliDiagnostic::missing_argument("stdin", command_name));
         }
+    } else if stdin_language.is_some() {
+        return Err(CliDiagnostic::missing_argument(
+            "stdin-file-path",
+            command_name,
+        ));
     } else {
         None
     };
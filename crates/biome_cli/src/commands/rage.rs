@@ -220,6 +220,8 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Line ending", markup!({DebugDisplay(formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplay(formatter_configuration.line_width.get())}))}
                             {KeyValuePair("Attribute position", markup!({DebugDisplay(formatter_configuration.attribute_position)}))}
+                            {KeyValuePair("Max empty lines", markup!({DebugDisplay(formatter_configuration.max_empty_lines.get())}))}
+                            {KeyValuePair("Use .editorconfig", markup!({DebugDisplay(formatter_configuration.use_editorconfig)}))}
                             {KeyValuePair("Ignore", markup!({DebugDisplay(formatter_configuration.ignore.iter().collect::<Vec<_>>())}))}
                             {KeyValuePair("Include", markup!({DebugDisplay(formatter_configuration.include.iter().collect::<Vec<_>>())}))}
                         ).fmt(fmt)?;
@@ -236,12 +238,20 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Arrow parentheses", markup!({DebugDisplay(javascript_formatter_configuration.arrow_parentheses)}))}
                             {KeyValuePair("Bracket spacing", markup!({DebugDisplay(javascript_formatter_configuration.bracket_spacing)}))}
                             {KeyValuePair("Bracket same line", markup!({DebugDisplay(javascript_formatter_configuration.bracket_same_line)}))}
+                            {KeyValuePair("Single attribute per line", markup!({DebugDisplay(javascript_formatter_configuration.single_attribute_per_line)}))}
                             {KeyValuePair("Quote style", markup!({DebugDisplay(javascript_formatter_configuration.quote_style)}))}
                             {KeyValuePair("Indent style", markup!({DebugDisplayOption(javascript_formatter_configuration.indent_style)}))}
                             {KeyValuePair("Indent width", markup!({DebugDisplayOption(javascript_formatter_configuration.indent_width)}))}
                             {KeyValuePair("Line ending", markup!({DebugDisplayOption(javascript_formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplayOption(javascript_formatter_configuration.line_width.map(|lw| lw.get()))}))}
                             {KeyValuePair("Attribute position", markup!({DebugDisplay(javascript_formatter_configuration.attribute_position)}))}
+                            {KeyValuePair("Object wrap", markup!({DebugDisplay(javascript_formatter_configuration.object_wrap)}))}
+                            {KeyValuePair("Operator position", markup!({DebugDisplay(javascript_formatter_configuration.operator_position)}))}
+                            {KeyValuePair("Format JSDoc", markup!({DebugDisplay(javascript_formatter_configuration.format_jsdoc)}))}
+                            {KeyValuePair("Decorator placement", markup!({DebugDisplay(javascript_formatter_configuration.decorator_placement)}))}
+                            {KeyValuePair("Ternary style", markup!({DebugDisplay(javascript_formatter_configuration.ternary_style)}))}
+                            {KeyValuePair("Member chain min calls", markup!({DebugDisplay(javascript_formatter_configuration.member_chain_min_calls)}))}
+                            {KeyValuePair("Inline short member chains", markup!({DebugDisplay(javascript_formatter_configuration.inline_short_member_chains)}))}
                         )
                         .fmt(fmt)?;
 
@@ -255,6 +265,8 @@ impl Display for RageConfiguration<'_, '_> {
                             {KeyValuePair("Line ending", markup!({DebugDisplayOption(json_formatter_configuration.line_ending)}))}
                             {KeyValuePair("Line width", markup!({DebugDisplayOption(json_formatter_configuration.line_width.map(|lw| lw.get()))}))}
                             {KeyValuePair("Trailing Commas", markup!({DebugDisplayOption(json_formatter_configuration.trailing_commas)}))}
+                            {KeyValuePair("Array wrap", markup!({DebugDisplayOption(json_formatter_configuration.array_wrap)}))}
+                            {KeyValuePair("Sort package.json", markup!({DebugDisplayOption(json_formatter_configuration.sort_package_json)}))}
                         ).fmt(fmt)?;
                     }
 
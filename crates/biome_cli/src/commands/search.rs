@@ -76,7 +76,7 @@ pub(crate) fn search(
     })?;
 
     let console = &mut *session.app.console;
-    let stdin = get_stdin(stdin_file_path, console, "search")?;
+    let stdin = get_stdin(stdin_file_path, None, console, "search")?;
 
     let pattern = workspace
         .parse_pattern(ParsePatternParams { pattern })?
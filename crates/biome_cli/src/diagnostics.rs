@@ -54,6 +54,8 @@ pub enum CliDiagnostic {
     NoFilesWereProcessed(NoFilesWereProcessed),
     /// Errors thrown when running the `biome migrate` command
     MigrateError(MigrationDiagnostic),
+    /// Returned when the baseline file of `--baseline` can't be read
+    InvalidBaseline(InvalidBaseline),
     /// Emitted during the reporting phase
     Report(ReportDiagnostic),
 }
@@ -245,6 +247,20 @@ pub struct MigrationDiagnostic {
     pub reason: String,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "flags/invalid",
+    severity = Error,
+    message(
+        description = "The baseline file {path} can't be read: {reason}",
+        message("The baseline file "<Emphasis>{self.path}</Emphasis>" can't be read: "{self.reason})
+    )
+)]
+pub struct InvalidBaseline {
+    path: String,
+    reason: String,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
     category = "internalError/fs",
@@ -430,6 +446,14 @@ impl CliDiagnostic {
         })
     }
 
+    /// Emitted when the baseline file of `--baseline` can't be read or parsed
+    pub fn invalid_baseline(path: impl Into<String>, reason: impl Into<String>) -> Self {
+        Self::InvalidBaseline(InvalidBaseline {
+            path: path.into(),
+            reason: reason.into(),
+        })
+    }
+
     /// Emitted when an argument value is greater than the allowed value
     pub fn overflown_argument(argument: impl Into<String>, maximum: u16) -> Self {
         Self::OverflowNumberArgument(OverflowNumberArgument {
@@ -0,0 +1,212 @@
+//! The baseline of `--baseline` and `--write-baseline`, which records the diagnostics of a
+//! project, so only the diagnostics that aren't recorded make the command fail.
+//!
+//! A diagnostic of the baseline is identified by its file, its category and the fingerprint of
+//! its code, so it's still matched when the lines of the file move. A diagnostic is suppressed as
+//! many times as it's recorded in the baseline.
+
+use crate::reporter::portable_path;
+use crate::CliDiagnostic;
+use biome_diagnostics::{Error, PrintDescription};
+use biome_fs::{BiomePath, FileSystem, OpenOptions};
+use biome_service::workspace::{CloseFileParams, FormatFileParams, OpenFileParams};
+use biome_service::Workspace;
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+use std::hash::Hasher;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// The settings of `--baseline` and `--write-baseline`
+#[derive(Debug, Clone)]
+pub(crate) enum BaselineSettings {
+    /// The diagnostics of the baseline file are suppressed
+    Read(Vec<BaselineEntry>),
+    /// The diagnostics are suppressed and written to the baseline file at this path
+    Write(PathBuf),
+}
+
+impl BaselineSettings {
+    /// Reads the baseline file of `--baseline`, or returns the path of `--write-baseline`
+    pub(crate) fn from_arguments(
+        fs: &dyn FileSystem,
+        baseline: Option<PathBuf>,
+        write_baseline: Option<PathBuf>,
+    ) -> Result<Option<Self>, CliDiagnostic> {
+        match (baseline, write_baseline) {
+            (Some(_), Some(_)) => Err(CliDiagnostic::incompatible_arguments(
+                "baseline",
+                "write-baseline",
+            )),
+            (Some(path), None) => {
+                let invalid_baseline = |reason: String| {
+                    CliDiagnostic::invalid_baseline(path.display().to_string(), reason)
+                };
+                let mut file = fs
+                    .open_with_options(&path, OpenOptions::default().read(true))
+                    .map_err(|error| invalid_baseline(error.to_string()))?;
+                let mut content = String::new();
+                file.read_to_string(&mut content)
+                    .map_err(|error| invalid_baseline(error.to_string()))?;
+                let baseline_file = serde_json::from_str::<BaselineFile>(&content)
+                    .map_err(|error| invalid_baseline(error.to_string()))?;
+
+                Ok(Some(Self::Read(baseline_file.diagnostics)))
+            }
+            (None, Some(path)) => Ok(Some(Self::Write(path))),
+            (None, None) => Ok(None),
+        }
+    }
+}
+
+/// The content of a baseline file
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct BaselineFile {
+    diagnostics: Vec<BaselineEntry>,
+}
+
+/// A diagnostic recorded in the baseline
+#[derive(Debug, Clone, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BaselineEntry {
+    path: String,
+    category: String,
+    /// The hash of the code of the diagnostic, or of its description when it doesn't have code
+    fingerprint: String,
+    /// The description of the diagnostic, it isn't used to match the diagnostics
+    #[serde(default)]
+    message: String,
+}
+
+impl BaselineEntry {
+    fn new(path: &str, content: &str, diagnostic: &Error) -> Option<Self> {
+        let category = diagnostic.category()?;
+        let message = PrintDescription(diagnostic).to_string();
+
+        let mut hasher = FxHasher::default();
+        let code = diagnostic
+            .location()
+            .span
+            .and_then(|span| content.get(usize::from(span.start())..usize::from(span.end())));
+        hasher.write(code.unwrap_or(&message).as_bytes());
+
+        Some(Self {
+            path: portable_path(path),
+            category: category.name().to_string(),
+            fingerprint: format!("{:016x}", hasher.finish()),
+            message,
+        })
+    }
+
+    /// The fields that identify the diagnostic
+    fn key(&self) -> (String, String, String) {
+        (
+            self.path.clone(),
+            self.category.clone(),
+            self.fingerprint.clone(),
+        )
+    }
+}
+
+/// The baseline used by a traversal
+#[derive(Debug)]
+pub(crate) struct Baseline {
+    /// The number of times each diagnostic of the baseline file can still be suppressed
+    suppressed: Mutex<FxHashMap<(String, String, String), usize>>,
+    /// The path of `--write-baseline`, and the diagnostics to write in it
+    recorded: Option<(PathBuf, Mutex<Vec<BaselineEntry>>)>,
+}
+
+impl Baseline {
+    pub(crate) fn new(settings: &BaselineSettings) -> Self {
+        match settings {
+            BaselineSettings::Read(entries) => {
+                let mut suppressed = FxHashMap::default();
+                for entry in entries {
+                    *suppressed.entry(entry.key()).or_default() += 1;
+                }
+                Self {
+                    suppressed: Mutex::new(suppressed),
+                    recorded: None,
+                }
+            }
+            BaselineSettings::Write(path) => Self {
+                suppressed: Mutex::default(),
+                recorded: Some((path.clone(), Mutex::default())),
+            },
+        }
+    }
+
+    /// Removes the diagnostics of the file `name` that are recorded in the baseline. When the
+    /// baseline is written, all the diagnostics are recorded and removed.
+    pub(crate) fn filter(&self, name: &str, content: &str, diagnostics: Vec<Error>) -> Vec<Error> {
+        let mut suppressed = self.suppressed.lock().unwrap();
+        diagnostics
+            .into_iter()
+            .filter(|diagnostic| {
+                let Some(entry) = BaselineEntry::new(name, content, diagnostic) else {
+                    return true;
+                };
+                if let Some((_, recorded)) = &self.recorded {
+                    recorded.lock().unwrap().push(entry);
+                    return false;
+                }
+
+                match suppressed.get_mut(&entry.key()) {
+                    Some(count) if *count > 0 => {
+                        *count -= 1;
+                        false
+                    }
+                    _ => true,
+                }
+            })
+            .collect()
+    }
+
+    /// Writes the recorded diagnostics to the file of `--write-baseline`. The file is formatted
+    /// with the options of the JSON files of the project, so the formatter doesn't report it.
+    pub(crate) fn save(
+        &self,
+        fs: &dyn FileSystem,
+        workspace: &dyn Workspace,
+    ) -> Result<(), CliDiagnostic> {
+        let Some((path, recorded)) = &self.recorded else {
+            return Ok(());
+        };
+        // The files are traversed in parallel, the diagnostics are sorted so the file is stable
+        let mut diagnostics = recorded.lock().unwrap().clone();
+        diagnostics.sort();
+
+        let content = serde_json::to_string_pretty(&BaselineFile { diagnostics })
+            .map_err(|error| CliDiagnostic::io_error(error.into()))?;
+        let biome_path = BiomePath::new(path);
+        workspace.open_file(OpenFileParams {
+            path: biome_path.clone(),
+            content: content.clone(),
+            version: 0,
+            document_file_source: None,
+        })?;
+        let printed = workspace.format_file(FormatFileParams {
+            path: biome_path.clone(),
+        });
+        workspace.close_file(CloseFileParams { path: biome_path })?;
+        // The file is written as is when the formatter is disabled for it
+        let content = match printed {
+            Ok(printed) => printed.into_code(),
+            Err(_) => content + "\n",
+        };
+
+        let mut file = fs
+            .open_with_options(
+                path,
+                OpenOptions::default()
+                    .write(true)
+                    .create(true)
+                    .truncate(true),
+            )
+            .map_err(CliDiagnostic::io_error)?;
+        file.set_content(content.as_bytes())
+            .map_err(CliDiagnostic::io_error)
+    }
+}
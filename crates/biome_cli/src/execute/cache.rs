@@ -0,0 +1,393 @@
+//! The cache of `--cache`, which stores the diagnostics of the files, so the files that didn't
+//! change since the previous run aren't processed again.
+//!
+//! The results of each command are stored in their own section of the cache file. A section is
+//! discarded when the version of Biome, the configuration, the files of the project that the rules
+//! read or the options of the command change, because the diagnostics of all the files may change
+//! with them.
+//!
+//! Only the files that are processed without changes, diffs or errors are cached: they emit the
+//! same diagnostics as long as their content doesn't change. The commands that lint the files
+//! never cache the JSON and CSS files, because their diagnostics also depend on other files: the
+//! schemas and the configurations that the JSON files reference, and the markup of the project
+//! for the CSS files.
+
+use super::process_file::Message;
+use super::TraversalMode;
+use crate::VERSION;
+use biome_configuration::PartialConfiguration;
+use biome_diagnostics::serde::Diagnostic;
+use biome_diagnostics::{Error, Resource};
+use biome_fs::{AutoSearchResult, FileSystem, OpenOptions};
+use biome_service::project::{find_tailwind_config, find_workspace_packages};
+use biome_service::workspace::DocumentFileSource;
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::hash::Hasher;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// The cache file used when `--cache-location` isn't passed
+const DEFAULT_CACHE_LOCATION: &str = ".biomecache";
+
+/// The settings of `--cache` and `--cache-location`
+#[derive(Debug, Clone)]
+pub(crate) struct CacheSettings {
+    /// The path of the cache file
+    location: PathBuf,
+    /// The hash of the version of Biome, of the configuration used by the command and of the
+    /// files of the project that the rules read
+    configuration_hash: u64,
+}
+
+impl CacheSettings {
+    /// Returns [None] when the configuration can't be serialized, the cache can't tell whether
+    /// it changed
+    pub(crate) fn new(
+        fs: &dyn FileSystem,
+        location: Option<PathBuf>,
+        configuration: &PartialConfiguration,
+        gitignore_matches: &[String],
+    ) -> Option<Self> {
+        let mut hasher = FxHasher::default();
+        hasher.write(VERSION.as_bytes());
+        let configuration = serde_json::to_string(configuration).ok()?;
+        hasher.write(configuration.as_bytes());
+        for pattern in gitignore_matches {
+            hasher.write(pattern.as_bytes());
+        }
+        for AutoSearchResult { content, file_path } in project_files(fs) {
+            hasher.write(file_path.as_os_str().as_encoded_bytes());
+            hasher.write(content.as_bytes());
+        }
+
+        Some(Self {
+            location: location.unwrap_or_else(|| PathBuf::from(DEFAULT_CACHE_LOCATION)),
+            configuration_hash: hasher.finish(),
+        })
+    }
+
+    pub(crate) fn location(&self) -> &Path {
+        self.location.as_path()
+    }
+}
+
+/// The content of the cache file
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheFile {
+    /// The results of the files, for each command
+    sections: BTreeMap<String, CacheSection>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CacheSection {
+    /// The hash of the configuration and of the options of the command that created the section
+    key: String,
+    files: BTreeMap<String, CachedFile>,
+}
+
+/// The results of a file
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CachedFile {
+    /// The hash of the content of the file
+    hash: String,
+    diagnostics: Vec<Diagnostic>,
+    skipped_suggested_fixes: u32,
+}
+
+impl CachedFile {
+    fn new(hash: String) -> Self {
+        Self {
+            hash,
+            diagnostics: Vec::new(),
+            skipped_suggested_fixes: 0,
+        }
+    }
+
+    /// The messages that the processing of the file sent
+    fn to_messages(&self, name: String, content: String) -> Vec<Message> {
+        let mut messages = Vec::new();
+        if self.skipped_suggested_fixes > 0 {
+            messages.push(Message::SkippedFixes {
+                file_name: name.clone(),
+                skipped_suggested_fixes: self.skipped_suggested_fixes,
+            });
+        }
+        if !self.diagnostics.is_empty() {
+            messages.push(Message::Diagnostics {
+                name,
+                content,
+                diagnostics: self.diagnostics.iter().cloned().map(Error::from).collect(),
+                skipped_diagnostics: 0,
+            });
+        }
+        messages
+    }
+}
+
+/// The state of a file during the traversal
+#[derive(Debug)]
+enum CacheEntry {
+    /// The file is being processed, its results are collected
+    Pending(CachedFile),
+    /// The results of the file are known, from the previous run or from this one
+    Done(CachedFile),
+    /// The results of the file can't be cached, its entry is removed
+    Uncacheable,
+}
+
+/// The cache used by a traversal
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    /// The content of the cache file, without the section of the traversal
+    file: CacheFile,
+    /// The name of the section of the traversal
+    section_name: String,
+    /// The key of the section of the traversal
+    key: String,
+    /// Whether the traversal lints the files, the JSON and CSS files aren't cached then
+    lints: bool,
+    /// The results of the files of the previous run that are still valid
+    previous: BTreeMap<String, CachedFile>,
+    /// The files handled by the traversal
+    entries: Mutex<FxHashMap<String, CacheEntry>>,
+}
+
+impl ResultCache {
+    /// Reads the cache file, and keeps the results of the previous run if the configuration and
+    /// the options of the command didn't change
+    pub(crate) fn load(
+        fs: &dyn FileSystem,
+        settings: &CacheSettings,
+        traversal_mode: &TraversalMode,
+    ) -> Self {
+        // A cache file that can't be read or parsed is replaced
+        let mut file = read_file(fs, settings.location())
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .unwrap_or_default();
+
+        let mut hasher = FxHasher::default();
+        hasher.write_u64(settings.configuration_hash);
+        hasher.write(format!("{traversal_mode:?}").as_bytes());
+        let key = format!("{:016x}", hasher.finish());
+
+        let section_name = traversal_mode.to_string();
+        let previous = file
+            .sections
+            .remove(&section_name)
+            .filter(|section| section.key == key)
+            .map(|section| section.files)
+            .unwrap_or_default();
+
+        Self {
+            file,
+            section_name,
+            key,
+            lints: !matches!(traversal_mode, TraversalMode::Format { .. }),
+            previous,
+            entries: Mutex::default(),
+        }
+    }
+
+    /// Returns the messages of the file if it didn't change since the previous run. Otherwise,
+    /// the results of the file are collected while it's processed.
+    pub(crate) fn replay(&self, fs: &dyn FileSystem, path: &Path) -> Option<Vec<Message>> {
+        let name = path.display().to_string();
+        if self.lints
+            && matches!(
+                DocumentFileSource::from_path(path),
+                DocumentFileSource::Json(_) | DocumentFileSource::Css(_)
+            )
+        {
+            self.insert(name, CacheEntry::Uncacheable);
+            return None;
+        }
+        let Some(content) = read_file(fs, path) else {
+            self.insert(name, CacheEntry::Uncacheable);
+            return None;
+        };
+
+        let hash = hash_content(&content);
+        match self.previous.get(&name) {
+            Some(cached_file) if cached_file.hash == hash => {
+                let messages = cached_file.to_messages(name.clone(), content);
+                self.insert(name, CacheEntry::Done(cached_file.clone()));
+                Some(messages)
+            }
+            _ => {
+                self.insert(name, CacheEntry::Pending(CachedFile::new(hash)));
+                None
+            }
+        }
+    }
+
+    /// Collects the results of a file from a message sent while it's processed
+    pub(crate) fn record(&self, message: Message) -> Message {
+        let mut entries = self.entries.lock().unwrap();
+        match message {
+            Message::Diagnostics {
+                name,
+                content,
+                diagnostics,
+                skipped_diagnostics,
+            } => {
+                let diagnostics = match entries.get_mut(&name) {
+                    // The diagnostics that weren't pulled would be lost
+                    Some(entry)
+                        if skipped_diagnostics > 0 && matches!(entry, CacheEntry::Pending(_)) =>
+                    {
+                        *entry = CacheEntry::Uncacheable;
+                        diagnostics
+                    }
+                    Some(CacheEntry::Pending(cached_file)) => {
+                        let diagnostics = diagnostics
+                            .into_iter()
+                            .map(Diagnostic::new)
+                            .collect::<Vec<_>>();
+                        cached_file.diagnostics.extend(diagnostics.iter().cloned());
+                        diagnostics.into_iter().map(Error::from).collect()
+                    }
+                    _ => diagnostics,
+                };
+                Message::Diagnostics {
+                    name,
+                    content,
+                    diagnostics,
+                    skipped_diagnostics,
+                }
+            }
+            Message::SkippedFixes {
+                file_name,
+                skipped_suggested_fixes,
+            } => {
+                if let Some(CacheEntry::Pending(cached_file)) = entries.get_mut(&file_name) {
+                    cached_file.skipped_suggested_fixes += skipped_suggested_fixes;
+                }
+                Message::SkippedFixes {
+                    file_name,
+                    skipped_suggested_fixes,
+                }
+            }
+            Message::Diff { ref file_name, .. } => {
+                if let Some(entry) = entries.get_mut(file_name) {
+                    *entry = CacheEntry::Uncacheable;
+                }
+                message
+            }
+            Message::Error(ref error) => {
+                if let Some(Resource::File(path)) = error.location().resource {
+                    if let Some(entry) = entries.get_mut(path) {
+                        *entry = CacheEntry::Uncacheable;
+                    }
+                }
+                message
+            }
+            Message::Failure => message,
+        }
+    }
+
+    /// Keeps the results of the file if it was processed without changes or errors
+    pub(crate) fn finish(&self, path: &Path, unchanged: bool) {
+        let mut entries = self.entries.lock().unwrap();
+        let Some(entry) = entries.get_mut(&path.display().to_string()) else {
+            return;
+        };
+        *entry = match std::mem::replace(entry, CacheEntry::Uncacheable) {
+            CacheEntry::Pending(cached_file) if unchanged => CacheEntry::Done(cached_file),
+            CacheEntry::Done(cached_file) => CacheEntry::Done(cached_file),
+            _ => CacheEntry::Uncacheable,
+        };
+    }
+
+    /// Writes the cache file, with the results of the files of the traversal. The results of the
+    /// files that weren't traversed are kept, unless the files don't exist anymore.
+    pub(crate) fn save(self, fs: &dyn FileSystem, settings: &CacheSettings) -> io::Result<()> {
+        let Self {
+            mut file,
+            section_name,
+            key,
+            lints: _,
+            mut previous,
+            entries,
+        } = self;
+
+        for (name, entry) in entries.into_inner().unwrap() {
+            match entry {
+                CacheEntry::Done(cached_file) => {
+                    previous.insert(name, cached_file);
+                }
+                CacheEntry::Pending(_) | CacheEntry::Uncacheable => {
+                    previous.remove(&name);
+                }
+            }
+        }
+        previous.retain(|name, _| fs.path_exists(Path::new(name)));
+        file.sections.insert(
+            section_name,
+            CacheSection {
+                key,
+                files: previous,
+            },
+        );
+
+        let content = serde_json::to_string(&file)?;
+        let mut cache_file = fs.open_with_options(
+            settings.location(),
+            OpenOptions::default()
+                .write(true)
+                .create(true)
+                .truncate(true),
+        )?;
+        cache_file.set_content(content.as_bytes())
+    }
+
+    fn insert(&self, name: String, entry: CacheEntry) {
+        self.entries.lock().unwrap().insert(name, entry);
+    }
+}
+
+/// The files of the project that the rules read besides the linted files: the `package.json`
+/// file, the `tsconfig.json` and Tailwind CSS configuration files next to it, the `package.json`
+/// files of the packages of the workspace it depends on, and the `.editorconfig` file
+fn project_files(fs: &dyn FileSystem) -> Vec<AutoSearchResult> {
+    let working_directory = fs.working_directory().unwrap_or_default();
+    let mut files = Vec::new();
+    if let Ok(Some(manifest)) = fs.auto_search(&working_directory, &["package.json"], false) {
+        let tsconfig_path = manifest.file_path.with_file_name("tsconfig.json");
+        if let Ok(content) = fs.read_file_from_path(&tsconfig_path) {
+            files.push(AutoSearchResult {
+                content,
+                file_path: tsconfig_path,
+            });
+        }
+        files.extend(find_tailwind_config(fs, &manifest.file_path));
+        files.extend(find_workspace_packages(
+            fs,
+            &manifest.file_path,
+            &manifest.content,
+        ));
+        files.push(manifest);
+    }
+    if let Ok(Some(editorconfig)) = fs.auto_search(&working_directory, &[".editorconfig"], false) {
+        files.push(editorconfig);
+    }
+    files
+}
+
+fn read_file(fs: &dyn FileSystem, path: &Path) -> Option<String> {
+    let mut file = fs
+        .open_with_options(path, OpenOptions::default().read(true))
+        .ok()?;
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+fn hash_content(content: &str) -> String {
+    let mut hasher = FxHasher::default();
+    hasher.write(content.as_bytes());
+    format!("{:016x}", hasher.finish())
+}
@@ -1,4 +1,6 @@
+use biome_console::MarkupBuf;
 use biome_diagnostics::adapters::{IoError, StdError};
+use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{
     Advices, Category, Diagnostic, DiagnosticExt, DiagnosticTags, Error, Visit,
 };
@@ -43,6 +45,19 @@ pub(crate) struct FormatDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+    category = "format",
+    severity = Error,
+    message = "The formatter changed its own output, the second formatting printed the following content:"
+)]
+pub(crate) struct UnstableFormatDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[advice]
+    pub(crate) diff: ContentDiffAdvice,
+}
+
 #[derive(Debug, Diagnostic)]
 #[diagnostic(
 	category = "organizeImports",
@@ -69,6 +84,20 @@ pub(crate) struct MigrateDiffDiagnostic {
     pub(crate) diff: ContentDiffAdvice,
 }
 
+#[derive(Debug, Diagnostic)]
+#[diagnostic(
+	severity = Information,
+	message = "The rule proposes the following fix:"
+)]
+pub(crate) struct FixReviewDiagnostic {
+    #[location(resource)]
+    pub(crate) file_name: String,
+    #[category]
+    pub(crate) category: &'static Category,
+    #[advice]
+    pub(crate) fix: CodeSuggestionAdvice<MarkupBuf>,
+}
+
 #[derive(Debug)]
 pub(crate) struct ContentDiffAdvice {
     pub(crate) old: String,
@@ -14,6 +14,40 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_this_in_static.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@next/next/google-font-preconnect" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_google_font_preconnect
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "@next/next/no-head-element" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_head_element.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "@next/next/no-img-element" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_img_element.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "@next/next/no-sync-scripts" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_sync_scripts.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@stylistic/jsx-self-closing-comp" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group
@@ -28,6 +62,14 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/await-thenable" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_await_thenable.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/ban-types" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group.no_banned_types.get_or_insert(Default::default());
@@ -63,6 +105,46 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_literal_keys.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/explicit-function-return-type" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_explicit_function_return_type
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "@typescript-eslint/explicit-member-accessibility" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_explicit_member_accessibility
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "@typescript-eslint/explicit-module-boundary-types" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_explicit_function_return_type
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "@typescript-eslint/max-params" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_excessive_parameters
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/naming-convention" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -74,6 +156,14 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-deprecated" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_deprecated_apis.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-dupe-class-members" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group
@@ -131,6 +221,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_precision_loss.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-magic-numbers" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_magic_numbers.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-misused-new" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group
@@ -176,6 +278,16 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-unnecessary-condition" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unnecessary_condition
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-unnecessary-type-constraint" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group
@@ -190,6 +302,16 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/no-unsafe-type-assertion" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unsafe_type_assertion
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/no-unused-vars" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group.no_unused_variables.get_or_insert(Default::default());
@@ -279,6 +401,16 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_optional_chain.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "@typescript-eslint/prefer-readonly" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_readonly_class_members
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "@typescript-eslint/require-await" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group.use_await.get_or_insert(Default::default());
@@ -380,6 +512,20 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_default_export.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "import/no-internal-modules" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_package_internal_imports
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "import/no-nodejs-modules" => {
             if !options.include_nursery {
                 return false;
@@ -388,6 +534,16 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_nodejs_modules.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jest/consistent-test-it" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_consistent_test_function
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jest/max-nested-describe" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group
@@ -395,6 +551,14 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jest/no-conditional-in-test" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_conditional_tests.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jest/no-disabled-tests" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -441,20 +605,24 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_focused_tests.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
-        "jest/no-standalone-expect" => {
-            if !options.include_inspired {
-                results.has_inspired_rules = true;
-                return false;
-            }
+        "jest/no-identical-title" => {
             if !options.include_nursery {
                 return false;
             }
             let group = rules.nursery.get_or_insert_with(Default::default);
             let rule = group
-                .no_misplaced_assertion
+                .no_duplicate_test_titles
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jest/no-standalone-expect" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_standalone_expect.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jsx-a11y/alt-text" => {
             let group = rules.a11y.get_or_insert_with(Default::default);
             let rule = group.use_alt_text.get_or_insert(Default::default());
@@ -501,6 +669,16 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jsx-a11y/autocomplete-valid" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_valid_autocomplete
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jsx-a11y/click-events-have-key-events" => {
             let group = rules.a11y.get_or_insert_with(Default::default);
             let rule = group
@@ -605,6 +783,16 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_redundant_roles.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jsx-a11y/no-static-element-interactions" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_static_element_interactions
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jsx-a11y/role-has-required-aria-props" => {
             let group = rules.a11y.get_or_insert_with(Default::default);
             let rule = group
@@ -612,6 +800,16 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "jsx-a11y/role-supports-aria-props" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_aria_props_supported_by_role
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "jsx-a11y/scope" => {
             let group = rules.a11y.get_or_insert_with(Default::default);
             let rule = group.no_header_scope.get_or_insert(Default::default());
@@ -622,6 +820,26 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_positive_tabindex.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "max-lines-per-function" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_excessively_long_functions
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "max-params" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_excessive_parameters
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-array-constructor" => {
             if !options.include_nursery {
                 return false;
@@ -637,6 +855,14 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-await-in-loop" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_await_in_loop.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-case-declarations" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group
@@ -820,6 +1046,14 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_global_assign.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-implicit-coercion" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_implicit_coercion.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-import-assign" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group.no_import_assign.get_or_insert(Default::default());
@@ -865,6 +1099,18 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_precision_loss.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "no-magic-numbers" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_magic_numbers.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "no-misleading-character-class" => {
             let group = rules.suspicious.get_or_insert_with(Default::default);
             let rule = group
@@ -1143,6 +1389,14 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_numeric_literals.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "prefer-object-spread" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_object_spread.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "prefer-regex-literals" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group.use_regex_literals.get_or_insert(Default::default());
@@ -1186,6 +1440,16 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_implicit_boolean.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "react/jsx-curly-brace-presence" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_consistent_curly_braces
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "react/jsx-fragments" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group.use_fragment_syntax.get_or_insert(Default::default());
@@ -1210,6 +1474,16 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "react/jsx-no-leaked-render" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_leaked_conditional_rendering
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "react/jsx-no-target-blank" => {
             let group = rules.a11y.get_or_insert_with(Default::default);
             let rule = group.no_blank_target.get_or_insert(Default::default());
@@ -1244,6 +1518,40 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "react/no-direct-mutation-state" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_direct_state_mutation
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "react/no-unstable-nested-components" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unstable_nested_components
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "react/no-unused-prop-types" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_unused_component_props
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "react/void-dom-elements-no-children" => {
             let group = rules.correctness.get_or_insert_with(Default::default);
             let rule = group
@@ -1261,6 +1569,16 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_yield.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "solidjs/no-destructure" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_solid_destructured_props
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "solidjs/no-react-specific-props" => {
             if !options.include_nursery {
                 return false;
@@ -1271,6 +1589,20 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "solidjs/reactivity" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_solid_untracked_reads
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "sonarjs/cognitive-complexity" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group
@@ -1278,6 +1610,58 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "storybook/await-interactions" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_await_story_interactions
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "storybook/default-exports" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_story_default_export
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "storybook/no-redundant-story-name" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_redundant_story_name
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "storybook/prefer-pascal-case" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_story_name_pascal_case
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "svelte/valid-compile" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_valid_runes.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/explicit-length-check" => {
             if !options.include_inspired {
                 results.has_inspired_rules = true;
@@ -1318,6 +1702,14 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.no_for_each.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "unicorn/no-document-cookie" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_document_cookie.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/no-for-loop" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group.use_for_of.get_or_insert(Default::default());
@@ -1345,11 +1737,51 @@ pub(crate) fn migrate_eslint_any_rule(
                 .get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "unicorn/no-useless-undefined" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_useless_undefined.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "unicorn/numeric-separators-style" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_numeric_separators
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/prefer-array-flat-map" => {
             let group = rules.complexity.get_or_insert_with(Default::default);
             let rule = group.use_flat_map.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "unicorn/prefer-at" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.use_at_index.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "unicorn/prefer-module" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .no_global_dirname_filename
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         "unicorn/prefer-node-protocol" => {
             let group = rules.style.get_or_insert_with(Default::default);
             let rule = group
@@ -1380,6 +1812,28 @@ pub(crate) fn migrate_eslint_any_rule(
             let rule = group.use_valid_typeof.get_or_insert(Default::default());
             rule.set_level(rule_severity.into());
         }
+        "vue/define-macros-order" => {
+            if !options.include_inspired {
+                results.has_inspired_rules = true;
+                return false;
+            }
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group
+                .use_define_props_order
+                .get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
+        "vue/no-async-in-computed-properties" => {
+            if !options.include_nursery {
+                return false;
+            }
+            let group = rules.nursery.get_or_insert_with(Default::default);
+            let rule = group.no_async_in_computed.get_or_insert(Default::default());
+            rule.set_level(rule_severity.into());
+        }
         _ => {
             return false;
         }
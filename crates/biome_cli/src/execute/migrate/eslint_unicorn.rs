@@ -22,6 +22,7 @@ impl From<FilenameCaseOptions> for use_filenaming_convention::FilenamingConventi
             filename_cases: filename_cases.unwrap_or_else(|| {
                 use_filenaming_convention::FilenameCases::from_iter([val.case.into()])
             }),
+            match_default_export: false,
         }
     }
 }
@@ -8,7 +8,9 @@ use biome_formatter::{
     AttributePosition, LineEnding, LineWidth, LineWidthFromIntError, QuoteStyle,
 };
 use biome_fs::{FileSystem, OpenOptions};
-use biome_js_formatter::context::{ArrowParentheses, QuoteProperties, Semicolons, TrailingCommas};
+use biome_js_formatter::context::{
+    ArrowParentheses, ObjectWrap, QuoteProperties, Semicolons, TrailingCommas,
+};
 use biome_json_parser::JsonParserOptions;
 use biome_service::DynRef;
 use std::path::Path;
@@ -56,6 +58,10 @@ pub(crate) struct PrettierConfiguration {
     arrow_parens: ArrowParens,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: EndOfLine,
+    /// https://prettier.io/docs/en/options#object-wrap
+    object_wrap: ObjectWrap,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: bool,
     /// https://prettier.io/docs/en/configuration.html#configuration-overrides
     overrides: Vec<Override>,
 }
@@ -75,6 +81,8 @@ impl Default for PrettierConfiguration {
             jsx_single_quote: false,
             arrow_parens: ArrowParens::default(),
             end_of_line: EndOfLine::default(),
+            object_wrap: ObjectWrap::default(),
+            single_attribute_per_line: false,
             overrides: vec![],
         }
     }
@@ -113,6 +121,10 @@ pub(crate) struct OverrideOptions {
     arrow_parens: Option<ArrowParens>,
     /// https://prettier.io/docs/en/options#end-of-line
     end_of_line: Option<EndOfLine>,
+    /// https://prettier.io/docs/en/options#object-wrap
+    object_wrap: Option<ObjectWrap>,
+    /// https://prettier.io/docs/en/options#single-attribute-per-line
+    single_attribute_per_line: Option<bool>,
 }
 
 #[derive(Clone, Debug, Default, Deserializable, Eq, PartialEq)]
@@ -203,6 +215,8 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
             indent_style: Some(indent_style),
             line_ending: Some(value.end_of_line.into()),
             attribute_position: Some(AttributePosition::default()),
+            max_empty_lines: None,
+            use_editorconfig: None,
             format_with_errors: Some(false),
             ignore: None,
             include: None,
@@ -238,6 +252,7 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
 
             // js ones
             bracket_same_line: Some(value.bracket_line),
+            single_attribute_per_line: Some(value.single_attribute_per_line),
             arrow_parentheses: Some(value.arrow_parens.into()),
             semicolons: Some(semicolons),
             trailing_commas: Some(value.trailing_comma.into()),
@@ -248,6 +263,14 @@ impl TryFrom<PrettierConfiguration> for biome_configuration::PartialConfiguratio
             bracket_spacing: Some(value.bracket_spacing),
             jsx_quote_style: Some(jsx_quote_style),
             attribute_position: Some(AttributePosition::default()),
+            object_wrap: Some(value.object_wrap),
+            operator_position: None,
+            format_jsdoc: None,
+            decorator_placement: None,
+            ternary_style: None,
+            member_chain_min_calls: None,
+            inline_short_member_chains: None,
+            embedded_language_tags: None,
         };
         let js_config = biome_configuration::PartialJavascriptConfiguration {
             formatter: Some(js_formatter),
@@ -307,6 +330,8 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
             && options.trailing_comma.is_none()
             && options.quote_props.is_none()
             && options.bracket_spacing.is_none()
+            && options.object_wrap.is_none()
+            && options.single_attribute_per_line.is_none()
         {
             // no js option are set
             return Ok(result);
@@ -335,6 +360,7 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
         });
         let js_formatter = biome_configuration::PartialJavascriptFormatter {
             bracket_same_line: options.bracket_line,
+            single_attribute_per_line: options.single_attribute_per_line,
             arrow_parentheses: options.arrow_parens.map(|arrow_parens| arrow_parens.into()),
             semicolons,
             trailing_commas: options
@@ -344,6 +370,7 @@ impl TryFrom<Override> for biome_configuration::OverridePattern {
             quote_properties: options.quote_props.map(|quote_props| quote_props.into()),
             bracket_spacing: options.bracket_spacing,
             jsx_quote_style,
+            object_wrap: options.object_wrap,
             ..Default::default()
         };
         let js_config = biome_configuration::PartialJavascriptConfiguration {
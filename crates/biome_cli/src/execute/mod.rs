@@ -1,15 +1,26 @@
+pub(crate) mod baseline;
+pub(crate) mod cache;
 mod diagnostics;
 mod migrate;
 mod process_file;
+mod review;
 mod std_in;
 pub(crate) mod traverse;
+mod watch;
 
 use crate::cli_options::{CliOptions, CliReporter};
 use crate::commands::MigrateSubCommand;
 use crate::diagnostics::ReportDiagnostic;
+use crate::execute::baseline::BaselineSettings;
+use crate::execute::cache::CacheSettings;
 use crate::execute::migrate::MigratePayload;
 use crate::execute::traverse::traverse;
+use crate::reporter::checkstyle::{CheckstyleReporter, CheckstyleReporterVisitor};
+use crate::reporter::gitlab::{GitLabReporter, GitLabReporterVisitor};
 use crate::reporter::json::{JsonReporter, JsonReporterVisitor};
+use crate::reporter::junit::{JunitReporter, JunitReporterVisitor};
+use crate::reporter::sarif::{SarifReporter, SarifReporterVisitor};
+use crate::reporter::template::{TemplateReporter, TemplateReporterVisitor};
 use crate::reporter::terminal::{ConsoleReporter, ConsoleReporterVisitor};
 use crate::{CliDiagnostic, CliSession, DiagnosticsPayload, Reporter};
 use biome_configuration::linter::RuleSelector;
@@ -17,8 +28,10 @@ use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::adapters::SerdeJsonError;
 use biome_diagnostics::{category, Category};
 use biome_fs::BiomePath;
+use biome_rowan::TextRange;
 use biome_service::workspace::{
-    FeatureName, FeaturesBuilder, FixFileMode, FormatFileParams, OpenFileParams, PatternId,
+    DocumentFileSource, FeatureName, FeaturesBuilder, FileFeaturesResult, FixFileMode,
+    FormatFileParams, OpenFileParams, PatternId,
 };
 use std::ffi::OsString;
 use std::fmt::{Display, Formatter};
@@ -35,6 +48,18 @@ pub struct Execution {
 
     /// The maximum number of diagnostics that can be printed in console
     max_diagnostics: u16,
+
+    /// Whether the files are processed again when they change
+    watch: bool,
+
+    /// Whether the fixes are reviewed by the user before they're applied
+    interactive: bool,
+
+    /// The cache of the results of the files, when `--cache` is passed
+    cache: Option<CacheSettings>,
+
+    /// The baseline of the diagnostics, when `--baseline` or `--write-baseline` is passed
+    baseline: Option<BaselineSettings>,
 }
 
 impl Execution {
@@ -43,10 +68,16 @@ impl Execution {
             traversal_mode: TraversalMode::Format {
                 ignore_errors: false,
                 write: false,
+                verify: false,
                 stdin: None,
+                range: None,
             },
             report_mode: ReportMode::default(),
             max_diagnostics: 0,
+            watch: false,
+            interactive: false,
+            cache: None,
+            baseline: None,
         }
     }
 
@@ -69,6 +100,22 @@ impl Execution {
             TraversalMode::Search { .. } => FeaturesBuilder::new().with_search().build(),
         }
     }
+
+    /// Whether the traversal processes a file that has these features
+    pub(crate) fn can_process(&self, file_features: &FileFeaturesResult) -> bool {
+        match self.traversal_mode {
+            TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
+                file_features.supports_lint()
+                    || file_features.supports_format()
+                    || file_features.supports_organize_imports()
+            }
+            TraversalMode::Format { .. } => file_features.supports_format(),
+            TraversalMode::Lint { .. } => file_features.supports_lint(),
+            // Imagine if Biome can't handle its own configuration file...
+            TraversalMode::Migrate { .. } => true,
+            TraversalMode::Search { .. } => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -83,9 +130,19 @@ pub struct Stdin(
     PathBuf,
     /// The content of the file
     String,
+    /// The language of the file, when it isn't the one of its path
+    Option<DocumentFileSource>,
 );
 
 impl Stdin {
+    pub(crate) fn new(
+        path: PathBuf,
+        content: String,
+        file_source: Option<DocumentFileSource>,
+    ) -> Self {
+        Self(path, content, file_source)
+    }
+
     fn as_path(&self) -> &Path {
         self.0.as_path()
     }
@@ -93,11 +150,9 @@ impl Stdin {
     fn as_content(&self) -> &str {
         self.1.as_str()
     }
-}
 
-impl From<(PathBuf, String)> for Stdin {
-    fn from((path, content): (PathBuf, String)) -> Self {
-        Self(path, content)
+    fn file_source(&self) -> Option<DocumentFileSource> {
+        self.2
     }
 }
 
@@ -131,6 +186,8 @@ pub enum TraversalMode {
         /// - When a rule is passed, its severity level is set to `error' if it is a recommended rule, or `warn' otherwise.
         /// - When a rule group is passed, the `recommended` flag is enabled, but if the `all` flag is enabled.
         rule: Option<RuleSelector>,
+        /// The explanation of the suppression comments inserted by `--suppress`
+        suppression_reason: Option<String>,
     },
     /// This mode is enabled when running the command `biome ci`
     CI {
@@ -143,10 +200,16 @@ pub enum TraversalMode {
         ignore_errors: bool,
         /// It writes the new content on file
         write: bool,
+        /// It formats the output a second time, and reports the files whose second output differs
+        verify: bool,
         /// An optional tuple.
         /// 1. The virtual path to the file
         /// 2. The content of the file
         stdin: Option<Stdin>,
+        /// The range of the content of `stdin` to format.
+        ///
+        /// When it's set, only the formatted code of the span that replaces the range is printed.
+        range: Option<TextRange>,
     },
     /// This mode is enabled when running the command `biome migrate`
     Migrate {
@@ -193,6 +256,16 @@ pub enum ReportMode {
     Terminal,
     /// Reports information in JSON format
     Json { pretty: bool },
+    /// Reports the diagnostics in SARIF format
+    Sarif,
+    /// Reports the diagnostics in JUnit XML format
+    Junit,
+    /// Reports the diagnostics in the Code Quality format of GitLab
+    GitLab,
+    /// Reports the diagnostics in Checkstyle XML format
+    Checkstyle,
+    /// Reports the diagnostics with the template of `--reporter-template`
+    Template,
 }
 
 impl From<CliReporter> for ReportMode {
@@ -201,6 +274,11 @@ impl From<CliReporter> for ReportMode {
             CliReporter::Default => Self::Terminal,
             CliReporter::Json => Self::Json { pretty: false },
             CliReporter::JsonPretty => Self::Json { pretty: true },
+            CliReporter::Sarif => Self::Sarif,
+            CliReporter::Junit => Self::Junit,
+            CliReporter::GitLab => Self::GitLab,
+            CliReporter::Checkstyle => Self::Checkstyle,
+            CliReporter::Template => Self::Template,
         }
     }
 }
@@ -211,6 +289,10 @@ impl Execution {
             report_mode: ReportMode::default(),
             traversal_mode: mode,
             max_diagnostics: 20,
+            watch: false,
+            interactive: false,
+            cache: None,
+            baseline: None,
         }
     }
 
@@ -230,6 +312,10 @@ impl Execution {
                 },
             },
             max_diagnostics: 20,
+            watch: false,
+            interactive: false,
+            cache: None,
+            baseline: None,
         }
     }
 
@@ -239,6 +325,30 @@ impl Execution {
         self
     }
 
+    /// It sets whether the files are processed again when they change
+    pub(crate) fn set_watch(mut self, watch: bool) -> Self {
+        self.watch = watch;
+        self
+    }
+
+    /// It sets whether the fixes are reviewed by the user before they're applied
+    pub(crate) fn set_interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// It sets the cache of the results of the files
+    pub(crate) fn set_cache(mut self, cache: Option<CacheSettings>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// It sets the baseline of the diagnostics
+    pub(crate) fn set_baseline(mut self, baseline: Option<BaselineSettings>) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
     pub(crate) fn traversal_mode(&self) -> &TraversalMode {
         &self.traversal_mode
     }
@@ -259,6 +369,18 @@ impl Execution {
         }
     }
 
+    /// The explanation of the suppression comments, when running [TraversalMode::Lint] with `--suppress`
+    pub(crate) fn as_suppression_reason(&self) -> Option<&str> {
+        if let TraversalMode::Lint {
+            suppression_reason, ..
+        } = &self.traversal_mode
+        {
+            suppression_reason.as_deref()
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn as_diagnostic_category(&self) -> &'static Category {
         match self.traversal_mode {
             TraversalMode::Check { .. } => category!("check"),
@@ -309,6 +431,22 @@ impl Execution {
         )
     }
 
+    pub(crate) const fn is_watch(&self) -> bool {
+        self.watch
+    }
+
+    pub(crate) const fn is_interactive(&self) -> bool {
+        self.interactive
+    }
+
+    pub(crate) fn as_cache_settings(&self) -> Option<&CacheSettings> {
+        self.cache.as_ref()
+    }
+
+    pub(crate) fn as_baseline_settings(&self) -> Option<&BaselineSettings> {
+        self.baseline.as_ref()
+    }
+
     pub(crate) const fn is_format(&self) -> bool {
         matches!(self.traversal_mode, TraversalMode::Format { .. })
     }
@@ -360,6 +498,7 @@ pub fn execute_mode(
             &execution,
             biome_path,
             stdin.as_content(),
+            stdin.file_source(),
             cli_options.verbose,
         )
     } else if let TraversalMode::Migrate {
@@ -378,88 +517,182 @@ pub fn execute_mode(
             sub_command,
         };
         migrate::run(payload)
+    } else if execution.is_watch() {
+        watch::run(&execution, &mut session, cli_options, paths)
     } else {
-        let (summary_result, diagnostics) = traverse(&execution, &mut session, cli_options, paths)?;
-        let console = session.app.console;
-        let errors = summary_result.errors;
-        let skipped = summary_result.skipped;
-        let processed = summary_result.changed + summary_result.unchanged;
-
-        let should_exit_on_warnings = summary_result.warnings > 0 && cli_options.error_on_warnings;
-
-        match execution.report_mode {
-            ReportMode::Terminal => {
-                let reporter = ConsoleReporter {
-                    summary: summary_result,
-                    diagnostics_payload: DiagnosticsPayload {
-                        verbose: cli_options.verbose,
-                        diagnostic_level: cli_options.diagnostic_level,
-                        diagnostics,
-                    },
-                    execution: execution.clone(),
-                };
-                reporter.write(&mut ConsoleReporterVisitor(console))?;
-            }
-            ReportMode::Json { pretty } => {
-                console.error(markup!{
-                    <Warn>"The "<Emphasis>"--json"</Emphasis>" option is "<Underline>"unstable/experimental"</Underline>" and its output might change between patches/minor releases."</Warn>
+        traverse_and_report(&execution, &mut session, cli_options, paths)
+    }
+}
+
+/// Traverses the `paths`, then reports the diagnostics and the summary of the traversal
+fn traverse_and_report(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let (summary_result, diagnostics) = traverse(execution, session, cli_options, paths)?;
+    let console = &mut *session.app.console;
+    let errors = summary_result.errors;
+    let skipped = summary_result.skipped;
+    let processed = summary_result.changed + summary_result.unchanged;
+
+    let should_exit_on_warnings = summary_result.warnings > 0 && cli_options.error_on_warnings;
+
+    match execution.report_mode {
+        ReportMode::Terminal => {
+            let reporter = ConsoleReporter {
+                summary: summary_result,
+                diagnostics_payload: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            reporter.write(&mut ConsoleReporterVisitor(console))?;
+        }
+        ReportMode::Json { pretty } => {
+            console.error(markup!{
+                <Warn>"The "<Emphasis>"--json"</Emphasis>" option is "<Underline>"unstable/experimental"</Underline>" and its output might change between patches/minor releases."</Warn>
+            });
+            let reporter = JsonReporter {
+                summary: summary_result,
+                diagnostics: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            let mut buffer = JsonReporterVisitor::new(summary_result);
+            reporter.write(&mut buffer)?;
+            if pretty {
+                let content = serde_json::to_string(&buffer).map_err(|error| {
+                    CliDiagnostic::Report(ReportDiagnostic::Serialization(SerdeJsonError::from(
+                        error,
+                    )))
+                })?;
+                let report_file = BiomePath::new("_report_output.json");
+                session.app.workspace.open_file(OpenFileParams {
+                    content,
+                    path: report_file.clone(),
+                    version: 0,
+                    document_file_source: None,
+                })?;
+                let code = session.app.workspace.format_file(FormatFileParams {
+                    path: report_file.clone(),
+                })?;
+                console.log(markup! {
+                    {code.as_code()}
+                });
+            } else {
+                console.log(markup! {
+                    {buffer}
                 });
-                let reporter = JsonReporter {
-                    summary: summary_result,
-                    diagnostics: DiagnosticsPayload {
-                        verbose: cli_options.verbose,
-                        diagnostic_level: cli_options.diagnostic_level,
-                        diagnostics,
-                    },
-                    execution: execution.clone(),
-                };
-                let mut buffer = JsonReporterVisitor::new(summary_result);
-                reporter.write(&mut buffer)?;
-                if pretty {
-                    let content = serde_json::to_string(&buffer).map_err(|error| {
-                        CliDiagnostic::Report(ReportDiagnostic::Serialization(
-                            SerdeJsonError::from(error),
-                        ))
-                    })?;
-                    let report_file = BiomePath::new("_report_output.json");
-                    session.app.workspace.open_file(OpenFileParams {
-                        content,
-                        path: report_file.clone(),
-                        version: 0,
-                        document_file_source: None,
-                    })?;
-                    let code = session.app.workspace.format_file(FormatFileParams {
-                        path: report_file.clone(),
-                    })?;
-                    console.log(markup! {
-                        {code.as_code()}
-                    });
-                } else {
-                    console.log(markup! {
-                        {buffer}
-                    });
-                }
             }
         }
+        ReportMode::Sarif => {
+            let reporter = SarifReporter {
+                summary: summary_result,
+                diagnostics: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            let mut buffer = SarifReporterVisitor::default();
+            reporter.write(&mut buffer)?;
+            console.log(markup! {
+                {buffer}
+            });
+        }
+        ReportMode::Junit => {
+            let reporter = JunitReporter {
+                summary: summary_result,
+                diagnostics: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            let mut buffer = JunitReporterVisitor::default();
+            reporter.write(&mut buffer)?;
+            console.log(markup! {
+                {buffer}
+            });
+        }
+        ReportMode::GitLab => {
+            let reporter = GitLabReporter {
+                summary: summary_result,
+                diagnostics: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            let mut buffer = GitLabReporterVisitor::default();
+            reporter.write(&mut buffer)?;
+            console.log(markup! {
+                {buffer}
+            });
+        }
+        ReportMode::Checkstyle => {
+            let reporter = CheckstyleReporter {
+                summary: summary_result,
+                diagnostics: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            let mut buffer = CheckstyleReporterVisitor::default();
+            reporter.write(&mut buffer)?;
+            console.log(markup! {
+                {buffer}
+            });
+        }
+        ReportMode::Template => {
+            let reporter = TemplateReporter {
+                summary: summary_result,
+                diagnostics: DiagnosticsPayload {
+                    verbose: cli_options.verbose,
+                    diagnostic_level: cli_options.diagnostic_level,
+                    diagnostics,
+                },
+                execution: execution.clone(),
+            };
+            let mut buffer = TemplateReporterVisitor::new(
+                cli_options.reporter_template.clone().unwrap_or_default(),
+            );
+            reporter.write(&mut buffer)?;
+            console.log(markup! {
+                {buffer}
+            });
+        }
+    }
 
-        // Processing emitted error diagnostics, exit with a non-zero code
-        if processed.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
-            Err(CliDiagnostic::no_files_processed())
-        } else if errors > 0 || should_exit_on_warnings {
-            let category = execution.as_diagnostic_category();
-            if should_exit_on_warnings {
-                if execution.is_check_apply() {
-                    Err(CliDiagnostic::apply_warnings(category))
-                } else {
-                    Err(CliDiagnostic::check_warnings(category))
-                }
-            } else if execution.is_check_apply() {
-                Err(CliDiagnostic::apply_error(category))
+    // Processing emitted error diagnostics, exit with a non-zero code
+    if processed.saturating_sub(skipped) == 0 && !cli_options.no_errors_on_unmatched {
+        Err(CliDiagnostic::no_files_processed())
+    } else if errors > 0 || should_exit_on_warnings {
+        let category = execution.as_diagnostic_category();
+        if should_exit_on_warnings {
+            if execution.is_check_apply() {
+                Err(CliDiagnostic::apply_warnings(category))
             } else {
-                Err(CliDiagnostic::check_error(category))
+                Err(CliDiagnostic::check_warnings(category))
             }
+        } else if execution.is_check_apply() {
+            Err(CliDiagnostic::apply_error(category))
         } else {
-            Ok(())
+            Err(CliDiagnostic::check_error(category))
         }
+    } else {
+        Ok(())
     }
 }
@@ -43,6 +43,8 @@ impl FileStatus {
 #[derive(Debug)]
 pub(crate) enum Message {
     SkippedFixes {
+        /// The file whose fixes were skipped
+        file_name: String,
         /// Suggested fixes skipped during the lint traversal
         skipped_suggested_fixes: u32,
     },
@@ -72,6 +74,8 @@ impl Message {
 pub(crate) enum DiffKind {
     Format,
     OrganizeImports,
+    /// The formatter changed its own output, `old` is the first output and `new` the second one
+    UnstableFormat,
 }
 
 impl<D> From<D> for Message
@@ -5,10 +5,8 @@ use crate::execute::process_file::{
 };
 use crate::execute::TraversalMode;
 use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Error, Severity};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::RuleCategories;
 use std::path::Path;
-use std::sync::atomic::Ordering;
 use tracing::debug;
 
 pub(crate) fn format<'ctx>(ctx: &'ctx SharedTraversalOptions<'ctx, '_>, path: &Path) -> FileResult {
@@ -22,31 +20,34 @@ pub(crate) fn format_with_guard<'ctx>(
 ) -> FileResult {
     tracing::info_span!("Processes formatting", path =? workspace_file.path.display()).in_scope(
         move || {
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
+            let max_diagnostics = ctx.max_diagnostics();
             debug!("Pulling diagnostics from parsed file");
             let diagnostics_result = workspace_file
                 .guard()
-                .pull_diagnostics(RuleCategories::SYNTAX, max_diagnostics.into(), None)
+                .pull_diagnostics(RuleCategories::SYNTAX, max_diagnostics, None)
                 .with_file_path_and_code(
                     workspace_file.path.display().to_string(),
                     category!("format"),
                 )?;
 
             let input = workspace_file.input()?;
-            let (should_write, ignore_errors) = match ctx.execution.traversal_mode {
+            let (should_write, ignore_errors, should_verify) = match ctx.execution.traversal_mode {
                 TraversalMode::Format {
                     write,
                     ignore_errors,
+                    verify,
                     ..
-                } => (write, ignore_errors),
+                } => (write, ignore_errors, verify),
 
                 _ => (
                     ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe(),
                     false,
+                    false,
                 ),
             };
             debug!("Should write the file to disk? {}", should_write);
             debug!("Should ignore errors? {}", ignore_errors);
+            debug!("Should verify the output? {}", should_verify);
 
             if diagnostics_result.errors > 0 && ignore_errors {
                 return Err(Message::from(
@@ -79,39 +80,24 @@ pub(crate) fn format_with_guard<'ctx>(
                     category!("format"),
                 )?;
 
-            let mut output = printed.into_code();
+            let output = printed.into_code();
 
             if ignore_errors {
                 return Ok(FileStatus::Ignored);
             }
 
-            match workspace_file.as_extension() {
-                Some("astro") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = AstroFileHandler::output(input.as_str(), output.as_str());
+            if output != input {
+                if should_write {
+                    workspace_file.update_file(output.as_str())?;
+                } else if should_verify {
+                    workspace_file.update_content(output.as_str())?;
                 }
-                Some("vue") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = VueFileHandler::output(input.as_str(), output.as_str());
+                // The workspace file now contains the output, it's formatted a second time
+                if should_verify {
+                    verify_output(ctx, workspace_file, &output)?;
                 }
 
-                Some("svelte") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                }
-                _ => {}
-            }
-
-            if output != input {
-                if should_write {
-                    workspace_file.update_file(output)?;
-                } else {
+                if !should_write {
                     return Ok(FileStatus::Message(Message::Diff {
                         file_name: workspace_file.path.display().to_string(),
                         old: input,
@@ -126,3 +112,33 @@ pub(crate) fn format_with_guard<'ctx>(
         },
     )
 }
+
+/// Formats the content of the workspace file, the output of the first formatting, a second time.
+///
+/// When the second output differs, the formatter isn't stable for this file:
+/// the diff between the two outputs is reported.
+fn verify_output<'ctx>(
+    ctx: &'ctx SharedTraversalOptions<'ctx, '_>,
+    workspace_file: &WorkspaceFile,
+    output: &str,
+) -> Result<(), Error> {
+    let second_output = workspace_file
+        .guard()
+        .format_file()
+        .with_file_path_and_code(
+            workspace_file.path.display().to_string(),
+            category!("format"),
+        )?
+        .into_code();
+
+    if second_output != output {
+        ctx.push_message(Message::Diff {
+            file_name: workspace_file.path.display().to_string(),
+            old: output.to_string(),
+            new: second_output,
+            diff_kind: DiffKind::UnstableFormat,
+        });
+    }
+
+    Ok(())
+}
@@ -3,10 +3,8 @@ use crate::execute::process_file::workspace_file::WorkspaceFile;
 use crate::execute::process_file::{FileResult, FileStatus, Message, SharedTraversalOptions};
 use crate::TraversalMode;
 use biome_diagnostics::{category, Error};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::RuleCategories;
 use std::path::Path;
-use std::sync::atomic::Ordering;
 
 /// Lints a single file and returns a [FileResult]
 pub(crate) fn lint<'ctx>(ctx: &'ctx SharedTraversalOptions<'ctx, '_>, path: &Path) -> FileResult {
@@ -22,51 +20,55 @@ pub(crate) fn lint_with_guard<'ctx>(
         move || {
             let mut input = workspace_file.input()?;
             let mut changed = false;
+            let rule = if let TraversalMode::Lint { rule, .. } = ctx.execution.traversal_mode() {
+                *rule
+            } else {
+                None
+            };
             if let Some(fix_mode) = ctx.execution.as_fix_file_mode() {
-                let fix_result = workspace_file
-                    .guard()
-                    .fix_file(*fix_mode, false)
-                    .with_file_path_and_code(
-                        workspace_file.path.display().to_string(),
-                        category!("lint"),
-                    )?;
+                if let Some(review) = &ctx.review {
+                    // The fixes accepted by the user are written one by one
+                    review.review(workspace_file, *fix_mode)?;
+                    let output = workspace_file.input()?;
+                    if output != input {
+                        changed = true;
+                        input = output;
+                    }
+                } else {
+                    let fix_result = workspace_file
+                        .guard()
+                        .fix_file(
+                            *fix_mode,
+                            false,
+                            rule,
+                            ctx.execution.as_suppression_reason().map(String::from),
+                        )
+                        .with_file_path_and_code(
+                            workspace_file.path.display().to_string(),
+                            category!("lint"),
+                        )?;
 
-                ctx.push_message(Message::SkippedFixes {
-                    skipped_suggested_fixes: fix_result.skipped_suggested_fixes,
-                });
+                    ctx.push_message(Message::SkippedFixes {
+                        file_name: workspace_file.path.display().to_string(),
+                        skipped_suggested_fixes: fix_result.skipped_suggested_fixes,
+                    });
 
-                let mut output = fix_result.code;
+                    let output = fix_result.code;
 
-                match workspace_file.as_extension() {
-                    Some("astro") => {
-                        output = AstroFileHandler::output(input.as_str(), output.as_str());
-                    }
-                    Some("vue") => {
-                        output = VueFileHandler::output(input.as_str(), output.as_str());
+                    if output != input {
+                        changed = true;
+                        workspace_file.update_file(output)?;
+                        input = workspace_file.input()?;
                     }
-                    Some("svelte") => {
-                        output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                    }
-                    _ => {}
-                }
-                if output != input {
-                    changed = true;
-                    workspace_file.update_file(output)?;
-                    input = workspace_file.input()?;
                 }
             }
 
-            let max_diagnostics = ctx.remaining_diagnostics.load(Ordering::Relaxed);
-            let rule = if let TraversalMode::Lint { rule, .. } = ctx.execution.traversal_mode() {
-                *rule
-            } else {
-                None
-            };
+            let max_diagnostics = ctx.max_diagnostics();
             let pull_diagnostics_result = workspace_file
                 .guard()
                 .pull_diagnostics(
                     RuleCategories::LINT | RuleCategories::SYNTAX,
-                    max_diagnostics.into(),
+                    max_diagnostics,
                     rule,
                 )
                 .with_file_path_and_code(
@@ -78,13 +80,6 @@ pub(crate) fn lint_with_guard<'ctx>(
                 && pull_diagnostics_result.skipped_diagnostics == 0;
 
             if !no_diagnostics {
-                let input = match workspace_file.as_extension() {
-                    Some("astro") => AstroFileHandler::input(input.as_str()).to_string(),
-                    Some("vue") => VueFileHandler::input(input.as_str()).to_string(),
-                    Some("svelte") => SvelteFileHandler::input(input.as_str()).to_string(),
-                    _ => input,
-                };
-
                 ctx.push_message(Message::Diagnostics {
                     name: workspace_file.path.display().to_string(),
                     content: input,
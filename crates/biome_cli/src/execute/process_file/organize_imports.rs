@@ -4,7 +4,6 @@ use crate::execute::process_file::{
     DiffKind, FileResult, FileStatus, Message, SharedTraversalOptions,
 };
 use biome_diagnostics::category;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 
 /// Lints a single file and returns a [FileResult]
 pub(crate) fn organize_imports_with_guard<'ctx>(
@@ -22,30 +21,7 @@ pub(crate) fn organize_imports_with_guard<'ctx>(
                 )?;
 
             let input = workspace_file.input()?;
-            let mut output = sorted.code;
-
-            match workspace_file.as_extension() {
-                Some("astro") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = AstroFileHandler::output(input.as_str(), output.as_str());
-                }
-                Some("vue") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = VueFileHandler::output(input.as_str(), output.as_str());
-                }
-
-                Some("svelte") => {
-                    if output.is_empty() {
-                        return Ok(FileStatus::Unchanged);
-                    }
-                    output = SvelteFileHandler::output(input.as_str(), output.as_str());
-                }
-                _ => {}
-            }
+            let output = sorted.code;
 
             if output != input {
                 if ctx.execution.is_check_apply() || ctx.execution.is_check_apply_unsafe() {
@@ -59,8 +59,13 @@ impl<'ctx, 'app> WorkspaceFile<'ctx, 'app> {
         self.guard().get_file_content()
     }
 
-    pub(crate) fn as_extension(&self) -> Option<&str> {
-        self.path.extension().and_then(|s| s.to_str())
+    /// It updates the content of the workspace file with `new_content`, without writing it on disk
+    pub(crate) fn update_content(
+        &mut self,
+        new_content: impl Into<String>,
+    ) -> Result<(), WorkspaceError> {
+        self.guard
+            .change_file(self.file.file_version() + 1, new_content.into())
     }
 
     /// It updates the workspace file with `new_content`
@@ -0,0 +1,245 @@
+//! The review of `--interactive`, which prints the fixes of the files one by one, so only the
+//! fixes that the user accepts are applied.
+//!
+//! The files are processed in parallel, the review of a file holds the state of the review until
+//! all its fixes are answered, so the questions of different files aren't mixed.
+
+use crate::execute::diagnostics::{FixReviewDiagnostic, ResultExt};
+use crate::execute::process_file::workspace_file::WorkspaceFile;
+use biome_analyze::ActionCategory;
+use biome_console::{markup, Console, ConsoleExt};
+use biome_diagnostics::advice::CodeSuggestionAdvice;
+use biome_diagnostics::{category, Applicability, Category, Error, PrintDiagnostic};
+use biome_rowan::{TextRange, TextSize};
+use biome_service::workspace::FixFileMode;
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use crossbeam::channel::{bounded, unbounded, Receiver, Sender};
+use rustc_hash::FxHashSet;
+use std::sync::Mutex;
+
+/// A fix, identified by the category of its rule and the text that it removes and inserts
+type FixKey = (&'static Category, Vec<(bool, String)>);
+
+/// The answers to the question printed after each fix
+#[derive(Debug, Clone, Copy)]
+enum Answer {
+    /// The fix is applied
+    Accept,
+    /// The fix isn't applied, and it isn't proposed again for this file
+    Skip,
+    /// The fix and all the fixes of the same rule are applied, without asking
+    AcceptRule,
+    /// The fix and the fixes that follow aren't applied
+    Quit,
+}
+
+impl Answer {
+    fn parse(line: &str) -> Option<Self> {
+        match line.trim() {
+            "y" => Some(Self::Accept),
+            "n" => Some(Self::Skip),
+            "a" => Some(Self::AcceptRule),
+            "q" => Some(Self::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// A fix printed by the review thread, with the channel where the answer of the user is sent
+pub(crate) struct Question {
+    diagnostic: FixReviewDiagnostic,
+    answer: Sender<Answer>,
+}
+
+/// Prints the questions of the review and reads the answers of the user. It runs on its own
+/// thread, until all the files are reviewed.
+pub(crate) fn answer_questions(console: &mut dyn Console, questions: Receiver<Question>) {
+    for question in questions {
+        console.log(markup! {{PrintDiagnostic::simple(&question.diagnostic)}});
+        let answer = loop {
+            console.append(markup! {
+                <Emphasis>"Apply this fix? [y]es, [n]o, [a]ll the fixes of the rule, [q]uit: "</Emphasis>
+            });
+            // The review stops when there's nothing left to read, e.g. when stdin is closed
+            let Some(line) = console.read_line() else {
+                break Answer::Quit;
+            };
+            if let Some(answer) = Answer::parse(&line) {
+                break answer;
+            }
+        };
+        question.answer.send(answer).ok();
+    }
+}
+
+#[derive(Debug, Default)]
+struct ReviewState {
+    /// The rules whose fixes are applied without asking
+    accepted_rules: FxHashSet<&'static Category>,
+    /// Whether the user stopped the review
+    quit: bool,
+}
+
+/// The review of the fixes, shared by the files of the traversal
+pub(crate) struct FixReview {
+    /// Channel sending the questions to the review thread
+    questions: Sender<Question>,
+    state: Mutex<ReviewState>,
+}
+
+impl FixReview {
+    /// Creates the review, and the channel that the review thread reads with [answer_questions]
+    pub(crate) fn new() -> (Self, Receiver<Question>) {
+        let (sender, receiver) = unbounded();
+        let review = Self {
+            questions: sender,
+            state: Mutex::default(),
+        };
+        (review, receiver)
+    }
+
+    fn ask(&self, diagnostic: FixReviewDiagnostic) -> Answer {
+        let (sender, receiver) = bounded(1);
+        let question = Question {
+            diagnostic,
+            answer: sender,
+        };
+        if self.questions.send(question).is_err() {
+            return Answer::Quit;
+        }
+        receiver.recv().unwrap_or(Answer::Quit)
+    }
+
+    /// Proposes the fixes of the file one at a time, and applies the ones that the user accepts.
+    /// The fixes are computed again after each accepted fix, because it changes the file.
+    pub(crate) fn review(
+        &self,
+        workspace_file: &mut WorkspaceFile,
+        fix_file_mode: FixFileMode,
+    ) -> Result<(), Error> {
+        let mut state = self.state.lock().unwrap();
+        let file_name = workspace_file.path.display().to_string();
+        // The fixes that were skipped, they aren't proposed again
+        let mut skipped: FxHashSet<FixKey> = FxHashSet::default();
+
+        while !state.quit {
+            let content = workspace_file
+                .input()
+                .with_file_path_and_code(file_name.clone(), category!("lint"))?;
+            let actions = workspace_file
+                .guard()
+                .pull_actions(TextRange::new(TextSize::from(0), TextSize::of(&content)))
+                .with_file_path_and_code(file_name.clone(), category!("lint"))?
+                .actions;
+
+            let fix = actions.into_iter().find_map(|action| {
+                let (group, rule) = action.rule_name?;
+                let applicability = action.suggestion.applicability;
+                let is_applied = match fix_file_mode {
+                    FixFileMode::SafeFixes => applicability == Applicability::Always,
+                    FixFileMode::SafeAndUnsafeFixes => true,
+                    FixFileMode::ApplySuppressions => false,
+                };
+                if !is_applied || !matches!(action.category, ActionCategory::QuickFix) {
+                    return None;
+                }
+
+                let category: &'static Category = format!("lint/{group}/{rule}").parse().ok()?;
+                let edit = action.suggestion.suggestion;
+                let key = (category, changed_text(&edit));
+                if skipped.contains(&key) {
+                    return None;
+                }
+                let new_content = apply_edit(&edit, &content)?;
+
+                Some((
+                    key,
+                    new_content,
+                    CodeSuggestionAdvice {
+                        applicability,
+                        msg: action.suggestion.msg,
+                        suggestion: edit,
+                    },
+                ))
+            });
+            let Some((key, new_content, advice)) = fix else {
+                break;
+            };
+
+            let category = key.0;
+            if !state.accepted_rules.contains(category) {
+                let answer = self.ask(FixReviewDiagnostic {
+                    file_name: file_name.clone(),
+                    category,
+                    fix: advice,
+                });
+                match answer {
+                    Answer::Accept => {}
+                    Answer::AcceptRule => {
+                        state.accepted_rules.insert(category);
+                    }
+                    Answer::Skip => {
+                        skipped.insert(key);
+                        continue;
+                    }
+                    Answer::Quit => {
+                        state.quit = true;
+                        break;
+                    }
+                }
+            }
+
+            workspace_file.update_file(new_content)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The text that the edit removes and inserts
+fn changed_text(edit: &TextEdit) -> Vec<(bool, String)> {
+    edit.iter()
+        .filter_map(|op| match op {
+            CompressedOp::DiffOp(op @ DiffOp::Insert { .. }) => {
+                Some((true, op.text(edit).to_string()))
+            }
+            CompressedOp::DiffOp(op @ DiffOp::Delete { .. }) => {
+                Some((false, op.text(edit).to_string()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Applies the edit of a fix to the content of the file. It returns [None] when the edit wasn't
+/// computed from this content, e.g. for a script embedded in another file.
+fn apply_edit(edit: &TextEdit, content: &str) -> Option<String> {
+    let mut output = String::new();
+    let mut position = 0;
+    for op in edit {
+        match op {
+            CompressedOp::DiffOp(op @ (DiffOp::Equal { .. } | DiffOp::Delete { .. })) => {
+                let text = op.text(edit);
+                if !content.get(position..)?.starts_with(text) {
+                    return None;
+                }
+                if matches!(op, DiffOp::Equal { .. }) {
+                    output.push_str(text);
+                }
+                position += text.len();
+            }
+            CompressedOp::DiffOp(op @ DiffOp::Insert { .. }) => {
+                output.push_str(op.text(edit));
+            }
+            CompressedOp::EqualLines { line_count } => {
+                let lines = content.get(position..)?.split_inclusive('\n');
+                for line in lines.take(line_count.get() as usize + 1) {
+                    output.push_str(line);
+                    position += line.len();
+                }
+            }
+        }
+    }
+
+    (position == content.len()).then_some(output)
+}
@@ -7,11 +7,10 @@ use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::Diagnostic;
 use biome_diagnostics::PrintDiagnostic;
 use biome_fs::BiomePath;
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
-    ChangeFileParams, DropPatternParams, FeaturesBuilder, FixFileParams, FormatFileParams,
-    OpenFileParams, OrganizeImportsParams, PullDiagnosticsParams, RuleCategories,
-    SupportsFeatureParams,
+    ChangeFileParams, DocumentFileSource, DropPatternParams, FeaturesBuilder, FixFileParams,
+    FormatFileParams, FormatRangeParams, OpenFileParams, OrganizeImportsParams,
+    PullDiagnosticsParams, RuleCategories, SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
@@ -21,6 +20,7 @@ pub(crate) fn run<'a>(
     mode: &'a Execution,
     biome_path: BiomePath,
     content: &'a str,
+    document_file_source: Option<DocumentFileSource>,
     verbose: bool,
 ) -> Result<(), CliDiagnostic> {
     let workspace = &*session.app.workspace;
@@ -28,6 +28,13 @@ pub(crate) fn run<'a>(
     let mut version = 0;
 
     if mode.is_format() {
+        // The file is opened first, so its features are the ones of its language
+        workspace.open_file(OpenFileParams {
+            path: biome_path.clone(),
+            version: 0,
+            content: content.into(),
+            document_file_source,
+        })?;
         let file_features = workspace.file_features(SupportsFeatureParams {
             path: biome_path.clone(),
             features: FeaturesBuilder::new().with_formatter().build(),
@@ -46,23 +53,19 @@ pub(crate) fn run<'a>(
             return Ok(());
         };
         if file_features.supports_format() {
-            workspace.open_file(OpenFileParams {
-                path: biome_path.clone(),
-                version: 0,
-                content: content.into(),
-                document_file_source: None,
-            })?;
-            let printed = workspace.format_file(FormatFileParams {
-                path: biome_path.clone(),
-            })?;
-
-            let code = printed.into_code();
-            let output = match biome_path.extension_as_str() {
-                Some("astro") => AstroFileHandler::output(content, code.as_str()),
-                Some("vue") => VueFileHandler::output(content, code.as_str()),
-                Some("svelte") => SvelteFileHandler::output(content, code.as_str()),
-                _ => code,
+            let printed = match mode.traversal_mode() {
+                TraversalMode::Format {
+                    range: Some(range), ..
+                } => workspace.format_range(FormatRangeParams {
+                    path: biome_path.clone(),
+                    range: *range,
+                })?,
+                _ => workspace.format_file(FormatFileParams {
+                    path: biome_path.clone(),
+                })?,
             };
+
+            let output = printed.into_code();
             console.append(markup! {
                 {output}
             });
@@ -82,7 +85,7 @@ pub(crate) fn run<'a>(
             path: biome_path.clone(),
             version: 0,
             content: content.into(),
-            document_file_source: None,
+            document_file_source,
         })?;
         // apply fix file of the linter
         let file_features = workspace.file_features(SupportsFeatureParams {
@@ -108,20 +111,21 @@ pub(crate) fn run<'a>(
             return Ok(());
         };
 
+        let rule = if let TraversalMode::Lint { rule, .. } = mode.traversal_mode() {
+            *rule
+        } else {
+            None
+        };
         if let Some(fix_file_mode) = mode.as_fix_file_mode() {
             if file_features.supports_lint() {
                 let fix_file_result = workspace.fix_file(FixFileParams {
                     fix_file_mode: *fix_file_mode,
                     path: biome_path.clone(),
                     should_format: mode.is_check() && file_features.supports_format(),
+                    rule,
+                    suppression_reason: mode.as_suppression_reason().map(String::from),
                 })?;
-                let code = fix_file_result.code;
-                let output = match biome_path.extension_as_str() {
-                    Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                    Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
-                    Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                    _ => code,
-                };
+                let output = fix_file_result.code;
                 if output != new_content {
                     version += 1;
                     workspace.change_file(ChangeFileParams {
@@ -137,13 +141,7 @@ pub(crate) fn run<'a>(
                 let result = workspace.organize_imports(OrganizeImportsParams {
                     path: biome_path.clone(),
                 })?;
-                let code = result.code;
-                let output = match biome_path.extension_as_str() {
-                    Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                    Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
-                    Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                    _ => code,
-                };
+                let output = result.code;
                 if output != new_content {
                     version += 1;
                     workspace.change_file(ChangeFileParams {
@@ -156,11 +154,6 @@ pub(crate) fn run<'a>(
             }
         }
 
-        let rule = if let TraversalMode::Lint { rule, .. } = mode.traversal_mode() {
-            *rule
-        } else {
-            None
-        };
         if !mode.is_check_apply_unsafe() {
             let result = workspace.pull_diagnostics(PullDiagnosticsParams {
                 categories: RuleCategories::LINT | RuleCategories::SYNTAX,
@@ -175,13 +168,7 @@ pub(crate) fn run<'a>(
             let printed = workspace.format_file(FormatFileParams {
                 path: biome_path.clone(),
             })?;
-            let code = printed.into_code();
-            let output = match biome_path.extension_as_str() {
-                Some("astro") => AstroFileHandler::output(&new_content, code.as_str()),
-                Some("vue") => VueFileHandler::output(&new_content, code.as_str()),
-                Some("svelte") => SvelteFileHandler::output(&new_content, code.as_str()),
-                _ => code,
-            };
+            let output = printed.into_code();
             if mode.is_check_apply() || mode.is_check_apply_unsafe() {
                 if output != new_content {
                     new_content = Cow::Owned(output);
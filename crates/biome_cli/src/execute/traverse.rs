@@ -1,12 +1,16 @@
+use super::baseline::Baseline;
+use super::cache::ResultCache;
 use super::process_file::{process_file, DiffKind, FileStatus, Message};
+use super::review::{answer_questions, FixReview};
 use super::{Execution, TraversalMode};
 use crate::cli_options::CliOptions;
 use crate::execute::diagnostics::{
     CIFormatDiffDiagnostic, CIOrganizeImportsDiffDiagnostic, ContentDiffAdvice,
-    FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic,
+    FormatDiffDiagnostic, OrganizeImportsDiffDiagnostic, PanicDiagnostic, UnstableFormatDiagnostic,
 };
 use crate::reporter::TraversalSummary;
 use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
 use biome_diagnostics::DiagnosticTags;
 use biome_diagnostics::{category, DiagnosticExt, Error, Resource, Severity};
 use biome_fs::{BiomePath, FileSystem, PathInterner};
@@ -70,17 +74,32 @@ pub(crate) fn traverse(
     let max_diagnostics = execution.get_max_diagnostics();
     let remaining_diagnostics = AtomicU16::new(max_diagnostics);
 
+    let cache = execution
+        .as_cache_settings()
+        .map(|settings| ResultCache::load(fs, settings, execution.traversal_mode()));
+
     let printer = DiagnosticsPrinter::new(execution)
         .with_verbose(cli_options.verbose)
         .with_diagnostic_level(cli_options.diagnostic_level)
         .with_max_diagnostics(max_diagnostics);
 
+    let (review, questions) = execution.is_interactive().then(FixReview::new).unzip();
+    let console = &mut *session.app.console;
+
     let (duration, diagnostics) = thread::scope(|s| {
         let handler = thread::Builder::new()
             .name(String::from("biome::console"))
             .spawn_scoped(s, || printer.run(receiver, recv_files))
             .expect("failed to spawn console thread");
 
+        // The review thread stops once the traversal context, that contains the review, is dropped
+        if let Some(questions) = questions {
+            thread::Builder::new()
+                .name(String::from("biome::review"))
+                .spawn_scoped(s, move || answer_questions(console, questions))
+                .expect("failed to spawn review thread");
+        }
+
         // The traversal context is scoped to ensure all the channels it
         // contains are properly closed once the traversal finishes
         let elapsed = traverse_inputs(
@@ -96,6 +115,8 @@ pub(crate) fn traverse(
                 skipped: &skipped,
                 messages: sender,
                 remaining_diagnostics: &remaining_diagnostics,
+                cache: cache.as_ref(),
+                review,
             },
         );
         // wait for the main thread to finish
@@ -111,6 +132,18 @@ pub(crate) fn traverse(
         });
     }
 
+    if let Some((cache, settings)) = cache.zip(execution.as_cache_settings()) {
+        if let Err(error) = cache.save(fs, settings) {
+            session.app.console.error(markup! {
+                <Warn>"Biome couldn't write the cache file "<Emphasis>{settings.location().display().to_string()}</Emphasis>": "{error.to_string()}</Warn>
+            });
+        }
+    }
+
+    if let Some(baseline) = &printer.baseline {
+        baseline.save(fs, &*session.app.workspace)?;
+    }
+
     let errors = printer.errors();
     let warnings = printer.warnings();
     let changed = changed.load(Ordering::Relaxed);
@@ -183,6 +216,8 @@ struct DiagnosticsPrinter<'ctx> {
     not_printed_diagnostics: AtomicU32,
     printed_diagnostics: AtomicU32,
     total_skipped_suggested_fixes: AtomicU32,
+    /// Suppresses the diagnostics of `--baseline`, or records them for `--write-baseline`
+    baseline: Option<Baseline>,
 }
 
 impl<'ctx> DiagnosticsPrinter<'ctx> {
@@ -198,6 +233,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             not_printed_diagnostics: AtomicU32::new(0),
             printed_diagnostics: AtomicU32::new(0),
             total_skipped_suggested_fixes: AtomicU32::new(0),
+            baseline: execution.as_baseline_settings().map(Baseline::new),
         }
     }
 
@@ -273,6 +309,7 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
             match msg {
                 Message::SkippedFixes {
                     skipped_suggested_fixes,
+                    ..
                 } => {
                     self.total_skipped_suggested_fixes
                         .fetch_add(skipped_suggested_fixes, Ordering::Relaxed);
@@ -334,6 +371,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     self.not_printed_diagnostics
                         .fetch_add(skipped_diagnostics, Ordering::Relaxed);
 
+                    let diagnostics = match &self.baseline {
+                        Some(baseline) => baseline.filter(&name, &content, diagnostics),
+                        None => diagnostics,
+                    };
+
                     // is CI mode we want to print all the diagnostics
                     if self.execution.is_ci() {
                         for diag in diagnostics {
@@ -381,8 +423,11 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                     new,
                     diff_kind,
                 } => {
-                    // A diff is an error in CI mode and in format check mode
-                    let is_error = self.execution.is_ci() || !self.execution.is_format_write();
+                    // A diff is an error in CI mode and in format check mode,
+                    // an unstable output of the formatter is always an error
+                    let is_error = matches!(diff_kind, DiffKind::UnstableFormat)
+                        || self.execution.is_ci()
+                        || !self.execution.is_format_write();
                     if is_error {
                         self.errors.fetch_add(1, Ordering::Relaxed);
                     }
@@ -423,6 +468,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     };
                                     diagnostics_to_print.push(diag.with_severity(severity))
                                 }
+                                DiffKind::UnstableFormat => {
+                                    let diag = UnstableFormatDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(diag.with_severity(severity))
+                                }
                             };
                         } else {
                             match diff_kind {
@@ -446,6 +501,16 @@ impl<'ctx> DiagnosticsPrinter<'ctx> {
                                     };
                                     diagnostics_to_print.push(diag.with_severity(severity))
                                 }
+                                DiffKind::UnstableFormat => {
+                                    let diag = UnstableFormatDiagnostic {
+                                        file_name: file_name.clone(),
+                                        diff: ContentDiffAdvice {
+                                            old: old.clone(),
+                                            new: new.clone(),
+                                        },
+                                    };
+                                    diagnostics_to_print.push(diag.with_severity(severity))
+                                }
                             };
                         }
                     }
@@ -477,6 +542,10 @@ pub(crate) struct TraversalOptions<'ctx, 'app> {
     /// The approximate number of diagnostics the console will print before
     /// folding the rest into the "skipped diagnostics" counter
     pub(crate) remaining_diagnostics: &'ctx AtomicU16,
+    /// The cache of the results of the files, when `--cache` is passed
+    cache: Option<&'ctx ResultCache>,
+    /// The review of the fixes, when `--interactive` is passed
+    pub(crate) review: Option<FixReview>,
 }
 
 impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
@@ -487,9 +556,23 @@ impl<'ctx, 'app> TraversalOptions<'ctx, 'app> {
         self.unchanged.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// The number of diagnostics to pull from a file. All of them are pulled when a baseline is
+    /// used, because the baseline records or suppresses the diagnostics that aren't printed too.
+    pub(crate) fn max_diagnostics(&self) -> u32 {
+        if self.execution.as_baseline_settings().is_some() {
+            u32::MAX
+        } else {
+            self.remaining_diagnostics.load(Ordering::Relaxed).into()
+        }
+    }
+
     /// Send a message to the display thread
     pub(crate) fn push_message(&self, msg: impl Into<Message>) {
-        self.messages.send(msg.into()).ok();
+        let mut msg = msg.into();
+        if let Some(cache) = self.cache {
+            msg = cache.record(msg);
+        }
+        self.messages.send(msg).ok();
     }
 
     pub(crate) fn miss_handler_err(&self, err: WorkspaceError, biome_path: &BiomePath) {
@@ -568,18 +651,7 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
                 return false;
             }
         };
-        match self.execution.traversal_mode() {
-            TraversalMode::Check { .. } | TraversalMode::CI { .. } => {
-                file_features.supports_lint()
-                    || file_features.supports_format()
-                    || file_features.supports_organize_imports()
-            }
-            TraversalMode::Format { .. } => file_features.supports_format(),
-            TraversalMode::Lint { .. } => file_features.supports_lint(),
-            // Imagine if Biome can't handle its own configuration file...
-            TraversalMode::Migrate { .. } => true,
-            TraversalMode::Search { .. } => false,
-        }
+        self.execution.can_process(&file_features)
     }
 
     fn handle_file(&self, path: &Path) {
@@ -591,7 +663,23 @@ impl<'ctx, 'app> TraversalContext for TraversalOptions<'ctx, 'app> {
 /// in a [catch_unwind] block and emit diagnostics in case of error (either the
 /// traversal function returns Err or panics)
 fn handle_file(ctx: &TraversalOptions, path: &Path) {
-    match catch_unwind(move || process_file(ctx, path)) {
+    if let Some(cache) = ctx.cache {
+        if let Some(messages) = cache.replay(ctx.fs, path) {
+            // The file didn't change since the previous run, its results are the cached ones
+            ctx.increment_unchanged();
+            for message in messages {
+                ctx.push_message(message);
+            }
+            return;
+        }
+    }
+
+    let result = catch_unwind(move || process_file(ctx, path));
+    if let Some(cache) = ctx.cache {
+        cache.finish(path, matches!(result, Ok(Ok(FileStatus::Unchanged))));
+    }
+
+    match result {
         Ok(Ok(FileStatus::Changed)) => {
             ctx.increment_changed();
         }
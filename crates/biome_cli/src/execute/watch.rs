@@ -0,0 +1,192 @@
+//! The `--watch` mode of the commands `check`, `lint` and `format`.
+//!
+//! After a first run on all the files, the modification times of the files are polled every
+//! [POLL_INTERVAL] with the traversal of the file system, and the files that changed are
+//! processed again.
+//!
+//! The changes of the configuration file aren't picked up, the command must be started again.
+
+use super::{traverse_and_report, Execution};
+use crate::cli_options::CliOptions;
+use crate::reporter::terminal::Files;
+use crate::{CliDiagnostic, CliSession};
+use biome_console::{markup, ConsoleExt};
+use biome_diagnostics::{Error, PrintDiagnostic};
+use biome_fs::{BiomePath, FileSystem, PathInterner, TraversalContext, TraversalScope};
+use biome_service::workspace::{IsPathIgnoredParams, SupportsFeatureParams};
+use biome_service::Workspace;
+use rustc_hash::FxHashMap;
+use std::env::current_dir;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// The time between two polls of the file system
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Runs the traversal of `paths`, then runs it again on the files that change, until the process is stopped
+pub(crate) fn run(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) -> Result<(), CliDiagnostic> {
+    let inputs = if paths.is_empty() {
+        vec![current_dir()
+            .map_err(CliDiagnostic::io_error)?
+            .into_os_string()]
+    } else {
+        paths.clone()
+    };
+
+    report_run(execution, session, cli_options, paths);
+    session.app.console.log(markup! {
+        <Info>"Watching for changes, press Ctrl+C to stop."</Info>
+    });
+
+    watch_changes(execution, session, cli_options, &inputs)
+}
+
+/// Polls the modification times of the files of `inputs`, and runs the traversal of the files that change
+fn watch_changes(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    inputs: &[OsString],
+) -> ! {
+    let mut files = modification_times(execution, session, inputs);
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current_files = modification_times(execution, session, inputs);
+        let mut changed_files = current_files
+            .iter()
+            .filter(|(path, modified)| files.get(*path) != Some(*modified))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        files = current_files;
+        if changed_files.is_empty() {
+            continue;
+        }
+        changed_files.sort();
+
+        session.app.console.log(markup! {
+            "\n"<Info>"Changes detected in "{Files(changed_files.len())}", running again."</Info>
+        });
+        report_run(
+            execution,
+            session,
+            cli_options,
+            changed_files.iter().cloned().map(OsString::from).collect(),
+        );
+
+        // The files written by the run, when fixes are applied, haven't been changed by the user
+        for path in changed_files {
+            if let Some(modified) = modification_time(&path) {
+                files.insert(path, modified);
+            }
+        }
+    }
+}
+
+/// Runs the traversal of `paths` and prints its report, the errors don't stop the watch mode
+fn report_run(
+    execution: &Execution,
+    session: &mut CliSession,
+    cli_options: &CliOptions,
+    paths: Vec<OsString>,
+) {
+    if let Err(error) = traverse_and_report(execution, session, cli_options, paths) {
+        // The summary of the run already tells the number of errors
+        if !matches!(error, CliDiagnostic::CheckError(_)) {
+            session.app.console.error(markup! {
+                {PrintDiagnostic::simple(&error)}
+            });
+        }
+    }
+}
+
+/// Returns the modification times of the files of `inputs` that are processed by the `execution`
+fn modification_times(
+    execution: &Execution,
+    session: &CliSession,
+    inputs: &[OsString],
+) -> FxHashMap<PathBuf, SystemTime> {
+    let fs = &*session.app.fs;
+    let (interner, _) = PathInterner::new();
+    let ctx = WatchContext {
+        fs,
+        workspace: &*session.app.workspace,
+        execution,
+        interner,
+        files: Mutex::default(),
+    };
+
+    fs.traversal(Box::new(|scope: &dyn TraversalScope| {
+        for input in inputs {
+            scope.spawn(&ctx, PathBuf::from(input));
+        }
+    }));
+
+    ctx.files.into_inner().unwrap()
+}
+
+fn modification_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// The context of a traversal that collects the modification times of the files, instead of processing them
+struct WatchContext<'ctx, 'app> {
+    fs: &'app dyn FileSystem,
+    workspace: &'ctx dyn Workspace,
+    execution: &'ctx Execution,
+    interner: PathInterner,
+    files: Mutex<FxHashMap<PathBuf, SystemTime>>,
+}
+
+impl TraversalContext for WatchContext<'_, '_> {
+    fn interner(&self) -> &PathInterner {
+        &self.interner
+    }
+
+    fn push_diagnostic(&self, _error: Error) {
+        // The diagnostics are emitted by the traversal that processes the files
+    }
+
+    fn can_handle(&self, biome_path: &BiomePath) -> bool {
+        let path = biome_path.as_path();
+        if self.fs.path_is_dir(path) || self.fs.path_is_symlink(path) {
+            return !self
+                .workspace
+                .is_path_ignored(IsPathIgnoredParams {
+                    biome_path: biome_path.clone(),
+                    features: self.execution.to_features(),
+                })
+                .unwrap_or(true);
+        }
+
+        self.fs.path_is_file(path)
+            && self
+                .workspace
+                .file_features(SupportsFeatureParams {
+                    path: biome_path.clone(),
+                    features: self.execution.to_features(),
+                })
+                .is_ok_and(|file_features| {
+                    !file_features.is_protected() && self.execution.can_process(&file_features)
+                })
+    }
+
+    fn handle_file(&self, path: &Path) {
+        if let Some(modified) = modification_time(path) {
+            self.files
+                .lock()
+                .unwrap()
+                .insert(path.to_path_buf(), modified);
+        }
+    }
+}
@@ -82,61 +82,89 @@ impl<'app> CliSession<'app> {
             BiomeCommand::Check {
                 apply,
                 apply_unsafe,
+                interactive,
                 cli_options,
                 configuration,
                 paths,
                 stdin_file_path,
+                stdin_language,
                 linter_enabled,
                 organize_imports_enabled,
                 formatter_enabled,
                 staged,
                 changed,
                 since,
+                watch,
+                cache,
+                cache_location,
+                baseline,
+                write_baseline,
             } => commands::check::check(
                 self,
                 CheckCommandPayload {
                     apply_unsafe,
                     apply,
+                    interactive,
                     cli_options,
                     configuration,
                     paths,
                     stdin_file_path,
+                    stdin_language,
                     linter_enabled,
                     organize_imports_enabled,
                     formatter_enabled,
                     staged,
                     changed,
                     since,
+                    watch,
+                    cache,
+                    cache_location,
+                    baseline,
+                    write_baseline,
                 },
             ),
             BiomeCommand::Lint {
                 apply,
                 apply_unsafe,
+                suppress,
                 cli_options,
                 linter_configuration,
                 paths,
                 rule,
                 stdin_file_path,
+                stdin_language,
                 vcs_configuration,
                 files_configuration,
                 staged,
                 changed,
                 since,
+                watch,
+                cache,
+                cache_location,
+                baseline,
+                write_baseline,
             } => commands::lint::lint(
                 self,
                 LintCommandPayload {
                     apply_unsafe,
                     apply,
+                    suppress,
                     cli_options,
                     linter_configuration,
                     paths,
                     rule,
                     stdin_file_path,
+                    stdin_language,
                     vcs_configuration,
                     files_configuration,
                     staged,
                     changed,
                     since,
+                    watch,
+                    cache,
+                    cache_location,
+                    baseline,
+                    write_baseline,
                 },
             ),
             BiomeCommand::Ci {
@@ -148,6 +176,8 @@ impl<'app> CliSession<'app> {
                 cli_options,
                 changed,
                 since,
+                baseline,
+                write_baseline,
             } => commands::ci::ci(
                 self,
                 CiCommandPayload {
@@ -159,13 +189,19 @@ impl<'app> CliSession<'app> {
                     cli_options,
                     changed,
                     since,
+                    baseline,
+                    write_baseline,
                 },
             ),
             BiomeCommand::Format {
                 javascript_formatter,
                 formatter_configuration,
                 stdin_file_path,
+                stdin_language,
+                range,
+                debug_ir,
                 write,
+                verify,
                 cli_options,
                 paths,
                 vcs_configuration,
@@ -175,13 +211,20 @@ impl<'app> CliSession<'app> {
                 staged,
                 changed,
                 since,
+                watch,
+                cache,
+                cache_location,
             } => commands::format::format(
                 self,
                 FormatCommandPayload {
                     javascript_formatter,
                     formatter_configuration,
                     stdin_file_path,
+                    stdin_language,
+                    range,
+                    debug_ir,
                     write,
+                    verify,
                     cli_options,
                     paths,
                     vcs_configuration,
@@ -191,6 +234,9 @@ impl<'app> CliSession<'app> {
                     staged,
                     changed,
                     since,
+                    watch,
+                    cache,
+                    cache_location,
                 },
             ),
             BiomeCommand::Explain { doc } => commands::explain::explain(self, doc),
@@ -0,0 +1,140 @@
+//! The reporter that prints the diagnostics using the Checkstyle XML format, which is read by
+//! many CI plugins and review tools, such as reviewdog and the Warnings plugin of Jenkins.
+
+use crate::reporter::{line_and_column, portable_path, XmlEscape};
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::Formatter;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity};
+use rustc_hash::FxHashMap;
+use std::io;
+
+pub struct CheckstyleReporter {
+    pub execution: Execution,
+    pub diagnostics: DiagnosticsPayload,
+    pub summary: TraversalSummary,
+}
+
+impl Reporter for CheckstyleReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_summary(&self.execution, self.summary)?;
+        visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CheckstyleReporterVisitor {
+    /// The files, in the order of their first diagnostic
+    files: Vec<CheckstyleFile>,
+    /// The index of each file in `files`
+    file_indexes: FxHashMap<String, usize>,
+}
+
+#[derive(Debug)]
+struct CheckstyleFile {
+    name: String,
+    errors: Vec<CheckstyleError>,
+}
+
+#[derive(Debug)]
+struct CheckstyleError {
+    /// The line and the column of the diagnostic in its file
+    position: Option<(usize, usize)>,
+    severity: &'static str,
+    message: String,
+    /// The category of the diagnostic
+    source: &'static str,
+}
+
+impl CheckstyleReporterVisitor {
+    fn push_diagnostic(&mut self, execution: &Execution, diagnostic: &Error) {
+        // Checkstyle only reports the diagnostics of the files
+        let location = diagnostic.location();
+        let Some(Resource::File(path)) = location.resource else {
+            return;
+        };
+        let name = portable_path(path);
+        let position = location
+            .span
+            .zip(location.source_code)
+            .map(|(span, source_code)| line_and_column(source_code.text, span.start().into()));
+
+        let index = match self.file_indexes.get(&name) {
+            Some(index) => *index,
+            None => {
+                let index = self.files.len();
+                self.file_indexes.insert(name.clone(), index);
+                self.files.push(CheckstyleFile {
+                    name,
+                    errors: Vec::new(),
+                });
+                index
+            }
+        };
+        self.files[index].errors.push(CheckstyleError {
+            position,
+            severity: match diagnostic.severity() {
+                Severity::Fatal | Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Information | Severity::Hint => "info",
+            },
+            message: PrintDescription(diagnostic).to_string(),
+            source: diagnostic
+                .category()
+                .unwrap_or_else(|| execution.as_diagnostic_category())
+                .name(),
+        });
+    }
+}
+
+impl biome_console::fmt::Display for CheckstyleReporterVisitor {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        writeln!(fmt, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(fmt, r#"<checkstyle version="4.3">"#)?;
+        for file in &self.files {
+            writeln!(fmt, r#"  <file name="{}">"#, XmlEscape(&file.name))?;
+            for error in &file.errors {
+                write!(fmt, "    <error")?;
+                if let Some((line, column)) = error.position {
+                    write!(fmt, r#" line="{line}" column="{column}""#)?;
+                }
+                writeln!(
+                    fmt,
+                    r#" severity="{}" message="{}" source="{}" />"#,
+                    error.severity,
+                    XmlEscape(&error.message),
+                    XmlEscape(error.source)
+                )?;
+            }
+            writeln!(fmt, "  </file>")?;
+        }
+        write!(fmt, "</checkstyle>")
+    }
+}
+
+impl ReporterVisitor for CheckstyleReporterVisitor {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+    ) -> io::Result<()> {
+        // Checkstyle doesn't have a summary, the tools compute it from the errors
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in &payload.diagnostics {
+            if diagnostic.severity() >= payload.diagnostic_level
+                && (payload.verbose || !diagnostic.tags().is_verbose())
+            {
+                self.push_diagnostic(execution, diagnostic);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+//! The reporter that prints the diagnostics using the [Code Quality] format of GitLab, so the merge
+//! requests show them next to the lines that emit them.
+//!
+//! GitLab compares the issues of two reports using their fingerprints. The fingerprint of an issue
+//! is computed from its rule, its file and its code, so it doesn't change when the lines of the
+//! file move.
+//!
+//! [Code Quality]: https://docs.gitlab.com/ee/ci/testing/code_quality.html#implement-a-custom-tool
+
+use crate::reporter::{line_and_column, portable_path};
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::Formatter;
+use biome_diagnostics::{Error, PrintDescription, Resource, Severity};
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::Serialize;
+use std::hash::Hasher;
+use std::io;
+
+pub struct GitLabReporter {
+    pub execution: Execution,
+    pub diagnostics: DiagnosticsPayload,
+    pub summary: TraversalSummary,
+}
+
+impl Reporter for GitLabReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_summary(&self.execution, self.summary)?;
+        visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct GitLabReporterVisitor {
+    issues: Vec<GitLabIssue>,
+    /// The number of issues that have the same rule, file and code, for each hash of them
+    occurrences: FxHashMap<u64, usize>,
+}
+
+impl GitLabReporterVisitor {
+    fn push_diagnostic(&mut self, execution: &Execution, diagnostic: &Error) {
+        // GitLab only shows the issues of the files
+        let location = diagnostic.location();
+        let Some(Resource::File(path)) = location.resource else {
+            return;
+        };
+        let path = portable_path(path);
+
+        let check_name = diagnostic
+            .category()
+            .unwrap_or_else(|| execution.as_diagnostic_category())
+            .name();
+        let description = PrintDescription(diagnostic).to_string();
+
+        let mut hasher = FxHasher::default();
+        hasher.write(check_name.as_bytes());
+        hasher.write(path.as_bytes());
+        let begin = match (location.span, location.source_code) {
+            (Some(span), Some(source_code)) => {
+                let start = usize::from(span.start());
+                hasher.write(source_code.text[start..usize::from(span.end())].as_bytes());
+                line_and_column(source_code.text, start).0
+            }
+            _ => {
+                hasher.write(description.as_bytes());
+                1
+            }
+        };
+
+        // The issues that have the same code in the same file are told apart by their order
+        let occurrence = self.occurrences.entry(hasher.finish()).or_default();
+        if *occurrence > 0 {
+            hasher.write_usize(*occurrence);
+        }
+        *occurrence += 1;
+
+        self.issues.push(GitLabIssue {
+            description,
+            check_name,
+            fingerprint: format!("{:016x}", hasher.finish()),
+            severity: match diagnostic.severity() {
+                Severity::Fatal => "blocker",
+                Severity::Error => "major",
+                Severity::Warning => "minor",
+                Severity::Information | Severity::Hint => "info",
+            },
+            location: GitLabLocation {
+                path,
+                lines: GitLabLines { begin },
+            },
+        });
+    }
+}
+
+impl biome_console::fmt::Display for GitLabReporterVisitor {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(&self.issues)?;
+        fmt.write_str(content.as_str())
+    }
+}
+
+impl ReporterVisitor for GitLabReporterVisitor {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+    ) -> io::Result<()> {
+        // The report of GitLab is a list of issues, without summary
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in &payload.diagnostics {
+            if diagnostic.severity() >= payload.diagnostic_level
+                && (payload.verbose || !diagnostic.tags().is_verbose())
+            {
+                self.push_diagnostic(execution, diagnostic);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabIssue {
+    description: String,
+    check_name: &'static str,
+    fingerprint: String,
+    severity: &'static str,
+    location: GitLabLocation,
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabLocation {
+    path: String,
+    lines: GitLabLines,
+}
+
+#[derive(Debug, Serialize)]
+struct GitLabLines {
+    begin: usize,
+}
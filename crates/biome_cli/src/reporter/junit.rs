@@ -0,0 +1,161 @@
+//! The reporter that prints the diagnostics using the JUnit XML format, so the CI services that
+//! display test results, such as Jenkins and GitLab CI, can display them.
+//!
+//! Each file is a test suite, and each diagnostic is a failed test case of the suite of its file.
+
+use crate::reporter::{line_and_column, XmlEscape};
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::Formatter;
+use biome_diagnostics::{Error, PrintDescription, Resource};
+use rustc_hash::FxHashMap;
+use std::io;
+
+pub struct JunitReporter {
+    pub execution: Execution,
+    pub diagnostics: DiagnosticsPayload,
+    pub summary: TraversalSummary,
+}
+
+impl Reporter for JunitReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_summary(&self.execution, self.summary)?;
+        visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct JunitReporterVisitor {
+    /// The test suites, in the order of their first diagnostic
+    suites: Vec<TestSuite>,
+    /// The index of each test suite in `suites`
+    suite_indexes: FxHashMap<String, usize>,
+}
+
+#[derive(Debug)]
+struct TestSuite {
+    name: String,
+    cases: Vec<TestCase>,
+}
+
+/// A failed test case, created from a diagnostic
+#[derive(Debug)]
+struct TestCase {
+    /// The category of the diagnostic
+    name: &'static str,
+    /// The severity of the diagnostic
+    kind: String,
+    /// The description of the diagnostic
+    message: String,
+    /// The line and the column of the diagnostic in its file
+    position: Option<(usize, usize)>,
+}
+
+impl JunitReporterVisitor {
+    fn push_diagnostic(&mut self, execution: &Execution, diagnostic: &Error) {
+        let location = diagnostic.location();
+        // The diagnostics that aren't emitted for a file are grouped in the suite of the command
+        let suite_name = match location.resource {
+            Some(Resource::File(path)) => path.to_string(),
+            _ => execution.traversal_mode().to_string(),
+        };
+        let position = location
+            .span
+            .zip(location.source_code)
+            .map(|(span, source_code)| line_and_column(source_code.text, span.start().into()));
+
+        let index = match self.suite_indexes.get(&suite_name) {
+            Some(index) => *index,
+            None => {
+                let index = self.suites.len();
+                self.suite_indexes.insert(suite_name.clone(), index);
+                self.suites.push(TestSuite {
+                    name: suite_name,
+                    cases: Vec::new(),
+                });
+                index
+            }
+        };
+        self.suites[index].cases.push(TestCase {
+            name: diagnostic
+                .category()
+                .unwrap_or_else(|| execution.as_diagnostic_category())
+                .name(),
+            kind: diagnostic.severity().to_string(),
+            message: PrintDescription(diagnostic).to_string(),
+            position,
+        });
+    }
+}
+
+impl biome_console::fmt::Display for JunitReporterVisitor {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let failures = self
+            .suites
+            .iter()
+            .map(|suite| suite.cases.len())
+            .sum::<usize>();
+
+        writeln!(fmt, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            fmt,
+            r#"<testsuites name="Biome" tests="{failures}" failures="{failures}">"#
+        )?;
+        for suite in &self.suites {
+            let failures = suite.cases.len();
+            writeln!(
+                fmt,
+                r#"  <testsuite name="{}" tests="{failures}" failures="{failures}">"#,
+                XmlEscape(&suite.name)
+            )?;
+            for case in &suite.cases {
+                writeln!(
+                    fmt,
+                    r#"    <testcase name="{}" classname="{}">"#,
+                    XmlEscape(case.name),
+                    XmlEscape(&suite.name)
+                )?;
+                write!(
+                    fmt,
+                    r#"      <failure type="{}" message="{}">"#,
+                    XmlEscape(&case.kind),
+                    XmlEscape(&case.message)
+                )?;
+                if let Some((line, column)) = case.position {
+                    write!(fmt, "line {line}, col {column}, ")?;
+                }
+                writeln!(fmt, "{}</failure>", XmlEscape(&case.message))?;
+                writeln!(fmt, "    </testcase>")?;
+            }
+            writeln!(fmt, "  </testsuite>")?;
+        }
+        write!(fmt, "</testsuites>")
+    }
+}
+
+impl ReporterVisitor for JunitReporterVisitor {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+    ) -> io::Result<()> {
+        // The test suites only contain the failures, their counts are computed from the diagnostics
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in &payload.diagnostics {
+            if diagnostic.severity() >= payload.diagnostic_level
+                && (payload.verbose || !diagnostic.tags().is_verbose())
+            {
+                self.push_diagnostic(execution, diagnostic);
+            }
+        }
+        Ok(())
+    }
+}
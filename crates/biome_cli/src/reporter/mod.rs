@@ -1,9 +1,15 @@
+pub(crate) mod checkstyle;
+pub(crate) mod gitlab;
 pub(crate) mod json;
+pub(crate) mod junit;
+pub(crate) mod sarif;
+pub(crate) mod template;
 pub(crate) mod terminal;
 
 use crate::execute::Execution;
 use biome_diagnostics::{Error, Severity};
 use serde::Serialize;
+use std::fmt::Write as _;
 use std::io;
 use std::time::Duration;
 
@@ -51,3 +57,41 @@ pub trait ReporterVisitor {
         payload: DiagnosticsPayload,
     ) -> io::Result<()>;
 }
+
+/// Returns the line and the column of the byte `offset` in `source`.
+///
+/// The lines and the columns start at 1, and the columns count Unicode code points.
+pub(crate) fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let before = &source[..offset];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// Returns `path` relative to the working directory, with forward slashes on every platform
+pub(crate) fn portable_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    path.strip_prefix("./").map(str::to_string).unwrap_or(path)
+}
+
+/// Escapes the characters of a text that can't appear in the content or the attributes of an XML element
+pub(crate) struct XmlEscape<'a>(pub(crate) &'a str);
+
+impl std::fmt::Display for XmlEscape<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for char in self.0.chars() {
+            match char {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                '\n' => f.write_str("&#10;")?,
+                char => f.write_char(char)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,448 @@
+//! The reporter that prints the diagnostics using the [SARIF 2.1.0] format, so they can be uploaded to
+//! GitHub Code Scanning and to the other static analysis dashboards.
+//!
+//! [SARIF 2.1.0]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+
+use crate::reporter::{line_and_column, portable_path};
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::{Formatter, Termcolor};
+use biome_console::markup;
+use biome_diagnostics::termcolor::NoColor;
+use biome_diagnostics::{
+    Category, Error, LogCategory, PrintDescription, Resource, Severity, Visit,
+};
+use biome_service::documentation::Doc;
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use rustc_hash::{FxHashMap, FxHasher};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::hash::Hasher;
+use std::io;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_VERSION: &str = "2.1.0";
+
+/// The key of the fingerprint computed by Biome, its version changes when its computation changes
+const FINGERPRINT_KEY: &str = "biome/v1";
+
+pub struct SarifReporter {
+    pub execution: Execution,
+    pub diagnostics: DiagnosticsPayload,
+    pub summary: TraversalSummary,
+}
+
+impl Reporter for SarifReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_summary(&self.execution, self.summary)?;
+        visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct SarifReporterVisitor {
+    /// The rules of the diagnostics, in the order of their first diagnostic
+    rules: Vec<SarifRule>,
+    /// The index of each rule in `rules`
+    rule_indexes: FxHashMap<&'static str, usize>,
+    results: Vec<SarifResult>,
+}
+
+impl SarifReporterVisitor {
+    /// Returns the log that contains all the diagnostics reported to this visitor
+    fn to_log(&self) -> SarifLog {
+        SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: [SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "Biome",
+                        information_uri: "https://biomejs.dev",
+                        version: crate::VERSION,
+                        rules: &self.rules,
+                    },
+                },
+                column_kind: "unicodeCodePoints",
+                results: &self.results,
+            }],
+        }
+    }
+
+    /// Returns the index of the rule that emits the diagnostics of `category`
+    fn rule_index(&mut self, category: &'static Category) -> usize {
+        if let Some(index) = self.rule_indexes.get(category.name()) {
+            return *index;
+        }
+
+        let short_description = category
+            .name()
+            .strip_prefix("lint/")
+            .and_then(|name| name.rsplit('/').next())
+            .and_then(|rule_name| match rule_name.parse::<Doc>() {
+                Ok(Doc::Rule(metadata)) => metadata
+                    .docs
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .map(|line| SarifText {
+                        text: line.to_string(),
+                    }),
+                _ => None,
+            });
+        let index = self.rules.len();
+        self.rules.push(SarifRule {
+            id: category.name(),
+            short_description,
+            help_uri: category.link(),
+        });
+        self.rule_indexes.insert(category.name(), index);
+        index
+    }
+
+    fn push_diagnostic(&mut self, diagnostic: &Error) {
+        let (rule_id, rule_index) = match diagnostic.category() {
+            Some(category) => (Some(category.name()), Some(self.rule_index(category))),
+            None => (None, None),
+        };
+
+        let location = diagnostic.location();
+        let path = match location.resource {
+            Some(Resource::File(path)) => Some(path),
+            _ => None,
+        };
+        let source = location.source_code.map(|source_code| source_code.text);
+
+        let mut locations = Vec::new();
+        let mut fingerprint = FxHasher::default();
+        fingerprint.write(rule_id.unwrap_or_default().as_bytes());
+        if let Some(path) = path {
+            fingerprint.write(path.as_bytes());
+            let region = match (location.span, source) {
+                (Some(span), Some(source)) => {
+                    let start = usize::from(span.start());
+                    let end = usize::from(span.end());
+                    fingerprint.write(source[start..end].as_bytes());
+                    Some(SarifRegion::from_range(source, start, end))
+                }
+                _ => None,
+            };
+            locations.push(SarifLocation {
+                physical_location: SarifPhysicalLocation {
+                    artifact_location: SarifArtifactLocation::new(path),
+                    region,
+                },
+            });
+        }
+
+        let mut fixes = Vec::new();
+        if let (Some(path), Some(source)) = (path, source) {
+            let mut visitor = FixesVisitor::default();
+            // The advices are only read in memory, the visitor doesn't fail
+            let _ = diagnostic.advices(&mut visitor);
+            for (description, diff) in visitor.fixes {
+                fixes.push(SarifFix {
+                    description: SarifText { text: description },
+                    artifact_changes: vec![SarifArtifactChange {
+                        artifact_location: SarifArtifactLocation::new(path),
+                        replacements: replacements(source, &diff),
+                    }],
+                });
+            }
+        }
+
+        self.results.push(SarifResult {
+            rule_id,
+            rule_index,
+            level: match diagnostic.severity() {
+                Severity::Fatal | Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Information | Severity::Hint => "note",
+            },
+            message: SarifText {
+                text: PrintDescription(diagnostic).to_string(),
+            },
+            locations,
+            partial_fingerprints: BTreeMap::from([(
+                FINGERPRINT_KEY,
+                format!("{:016x}", fingerprint.finish()),
+            )]),
+            fixes,
+        });
+    }
+}
+
+impl biome_console::fmt::Display for SarifReporterVisitor {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        let content = serde_json::to_string_pretty(&self.to_log())?;
+        fmt.write_str(content.as_str())
+    }
+}
+
+impl ReporterVisitor for SarifReporterVisitor {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+    ) -> io::Result<()> {
+        // SARIF doesn't have a summary, the tools compute it from the results
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        _execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in &payload.diagnostics {
+            if diagnostic.severity() >= payload.diagnostic_level
+                && (payload.verbose || !diagnostic.tags().is_verbose())
+            {
+                self.push_diagnostic(diagnostic);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Collects the code fixes from the advices of a diagnostic, where the description of a fix
+/// is the log that precedes its diff
+#[derive(Default)]
+struct FixesVisitor {
+    description: Option<String>,
+    fixes: Vec<(String, TextEdit)>,
+}
+
+impl Visit for FixesVisitor {
+    fn record_log(
+        &mut self,
+        category: LogCategory,
+        text: &dyn biome_console::fmt::Display,
+    ) -> io::Result<()> {
+        self.description = match category {
+            LogCategory::Info => Some(markup_to_string(text)),
+            _ => None,
+        };
+        Ok(())
+    }
+
+    fn record_diff(&mut self, diff: &TextEdit) -> io::Result<()> {
+        if let Some(description) = self.description.take() {
+            self.fixes.push((description, diff.clone()));
+        }
+        Ok(())
+    }
+}
+
+fn markup_to_string(text: &dyn biome_console::fmt::Display) -> String {
+    let mut buffer = Vec::new();
+    let mut write = Termcolor(NoColor::new(&mut buffer));
+    let mut fmt = Formatter::new(&mut write);
+    fmt.write_markup(markup! { {text} })
+        .expect("to have written in the buffer");
+
+    String::from_utf8(buffer).expect("to have convert a buffer into a String")
+}
+
+/// Converts the `diff` of the whole `source` to the replacements of the ranges that change
+fn replacements(source: &str, diff: &TextEdit) -> Vec<SarifReplacement> {
+    let mut replacements = Vec::new();
+    // The byte offset in `source` of the current operation
+    let mut offset = 0;
+    // The deleted range and the inserted text of the replacement being built
+    let mut current: Option<(usize, usize, String)> = None;
+
+    for op in diff {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                replacements.extend(
+                    current
+                        .take()
+                        .map(|replacement| SarifReplacement::new(source, replacement)),
+                );
+                offset += usize::from(range.len());
+            }
+            CompressedOp::EqualLines { line_count } => {
+                replacements.extend(
+                    current
+                        .take()
+                        .map(|replacement| SarifReplacement::new(source, replacement)),
+                );
+                let line_break_count = line_count.get() as usize + 1;
+                offset += source[offset..]
+                    .split_inclusive('\n')
+                    .take(line_break_count)
+                    .map(str::len)
+                    .sum::<usize>();
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let replacement = current.get_or_insert_with(|| (offset, offset, String::new()));
+                offset += usize::from(range.len());
+                replacement.1 = offset;
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                current
+                    .get_or_insert_with(|| (offset, offset, String::new()))
+                    .2
+                    .push_str(diff.get_text(*range));
+            }
+        }
+    }
+    replacements.extend(current.map(|replacement| SarifReplacement::new(source, replacement)));
+
+    replacements
+}
+
+#[derive(Debug, Serialize)]
+struct SarifLog<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun<'a>; 1],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun<'a> {
+    tool: SarifTool<'a>,
+    column_kind: &'static str,
+    results: &'a [SarifResult],
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool<'a> {
+    driver: SarifDriver<'a>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver<'a> {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: &'a [SarifRule],
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_description: Option<SarifText>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<&'static str>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifText {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_index: Option<usize>,
+    level: &'static str,
+    message: SarifText,
+    locations: Vec<SarifLocation>,
+    partial_fingerprints: BTreeMap<&'static str, String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fixes: Vec<SarifFix>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+impl SarifArtifactLocation {
+    fn new(path: &str) -> Self {
+        Self {
+            uri: portable_path(path),
+        }
+    }
+}
+
+/// A region of a file, its lines and columns start at 1 and its columns count Unicode code points
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+impl SarifRegion {
+    fn from_range(source: &str, start: usize, end: usize) -> Self {
+        let (start_line, start_column) = line_and_column(source, start);
+        let (end_line, end_column) = line_and_column(source, end);
+        Self {
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifFix {
+    description: SarifText,
+    artifact_changes: Vec<SarifArtifactChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifArtifactChange {
+    artifact_location: SarifArtifactLocation,
+    replacements: Vec<SarifReplacement>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifReplacement {
+    deleted_region: SarifCharRegion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inserted_content: Option<SarifText>,
+}
+
+impl SarifReplacement {
+    fn new(source: &str, (start, end, inserted): (usize, usize, String)) -> Self {
+        let char_offset = source[..start].chars().count();
+        Self {
+            deleted_region: SarifCharRegion {
+                char_offset,
+                char_length: source[start..end].chars().count(),
+            },
+            inserted_content: (!inserted.is_empty()).then_some(SarifText { text: inserted }),
+        }
+    }
+}
+
+/// A region of a file given by its offset and length in Unicode code points
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifCharRegion {
+    char_offset: usize,
+    char_length: usize,
+}
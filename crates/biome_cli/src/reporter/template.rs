@@ -0,0 +1,209 @@
+//! The reporter that prints each diagnostic on a line, using a template given by the user, so the
+//! output matches the format of the logs of other tools.
+
+use crate::reporter::line_and_column;
+use crate::{DiagnosticsPayload, Execution, Reporter, ReporterVisitor, TraversalSummary};
+use biome_console::fmt::Formatter;
+use biome_diagnostics::{Error, PrintDescription, Resource};
+use std::io;
+use std::str::FromStr;
+
+/// The template used when `--reporter-template` isn't passed
+const DEFAULT_TEMPLATE: &str = "{path}:{line}:{column} {severity} {rule}: {message}";
+
+/// A field of a diagnostic that can be printed by a template
+#[derive(Debug, Clone, Copy)]
+enum TemplateField {
+    Path,
+    Line,
+    Column,
+    Rule,
+    Severity,
+    Message,
+}
+
+impl TemplateField {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "path" => Some(Self::Path),
+            "line" => Some(Self::Line),
+            "column" => Some(Self::Column),
+            "rule" => Some(Self::Rule),
+            "severity" => Some(Self::Severity),
+            "message" => Some(Self::Message),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TemplatePart {
+    Text(String),
+    Field(TemplateField),
+}
+
+/// The template of `--reporter-template`, where the fields are written between braces, e.g. `{path}`.
+///
+/// The braces that aren't part of a field are written twice: `{{` and `}}`.
+#[derive(Debug, Clone)]
+pub struct ReporterTemplate(Vec<TemplatePart>);
+
+impl Default for ReporterTemplate {
+    fn default() -> Self {
+        DEFAULT_TEMPLATE
+            .parse()
+            .expect("the default template to be valid")
+    }
+}
+
+impl FromStr for ReporterTemplate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = Vec::new();
+        let mut text = String::new();
+        let mut chars = s.chars();
+        while let Some(char) = chars.next() {
+            match char {
+                '{' if chars.as_str().starts_with('{') => {
+                    chars.next();
+                    text.push('{');
+                }
+                '}' if chars.as_str().starts_with('}') => {
+                    chars.next();
+                    text.push('}');
+                }
+                '{' => {
+                    let Some((name, rest)) = chars.as_str().split_once('}') else {
+                        return Err(
+                            "the template has a `{` that isn't closed, write `{{` to print a brace"
+                                .to_string(),
+                        );
+                    };
+                    let Some(field) = TemplateField::from_name(name) else {
+                        return Err(format!(
+                            "unknown field `{{{name}}}`, the fields are `{{path}}`, `{{line}}`, `{{column}}`, `{{rule}}`, `{{severity}}` and `{{message}}`"
+                        ));
+                    };
+                    if !text.is_empty() {
+                        parts.push(TemplatePart::Text(std::mem::take(&mut text)));
+                    }
+                    parts.push(TemplatePart::Field(field));
+                    chars = rest.chars();
+                }
+                char => text.push(char),
+            }
+        }
+        if !text.is_empty() {
+            parts.push(TemplatePart::Text(text));
+        }
+
+        Ok(Self(parts))
+    }
+}
+
+pub struct TemplateReporter {
+    pub execution: Execution,
+    pub diagnostics: DiagnosticsPayload,
+    pub summary: TraversalSummary,
+}
+
+impl Reporter for TemplateReporter {
+    fn write(self, visitor: &mut dyn ReporterVisitor) -> io::Result<()> {
+        visitor.report_summary(&self.execution, self.summary)?;
+        visitor.report_diagnostics(&self.execution, self.diagnostics)?;
+
+        Ok(())
+    }
+}
+
+pub(crate) struct TemplateReporterVisitor {
+    template: ReporterTemplate,
+    /// The diagnostics printed with the template
+    lines: Vec<String>,
+}
+
+impl TemplateReporterVisitor {
+    pub(crate) fn new(template: ReporterTemplate) -> Self {
+        Self {
+            template,
+            lines: Vec::new(),
+        }
+    }
+
+    fn push_diagnostic(&mut self, execution: &Execution, diagnostic: &Error) {
+        let location = diagnostic.location();
+        // The fields that the diagnostic doesn't have are printed as empty strings
+        let path = match location.resource {
+            Some(Resource::File(path)) => path,
+            _ => "",
+        };
+        let position = location
+            .span
+            .zip(location.source_code)
+            .map(|(span, source_code)| line_and_column(source_code.text, span.start().into()));
+
+        let mut line = String::new();
+        for part in &self.template.0 {
+            match part {
+                TemplatePart::Text(text) => line.push_str(text),
+                TemplatePart::Field(TemplateField::Path) => line.push_str(path),
+                TemplatePart::Field(TemplateField::Line) => {
+                    if let Some((line_number, _)) = position {
+                        line.push_str(&line_number.to_string());
+                    }
+                }
+                TemplatePart::Field(TemplateField::Column) => {
+                    if let Some((_, column)) = position {
+                        line.push_str(&column.to_string());
+                    }
+                }
+                TemplatePart::Field(TemplateField::Rule) => line.push_str(
+                    diagnostic
+                        .category()
+                        .unwrap_or_else(|| execution.as_diagnostic_category())
+                        .name(),
+                ),
+                TemplatePart::Field(TemplateField::Severity) => {
+                    line.push_str(&diagnostic.severity().to_string())
+                }
+                TemplatePart::Field(TemplateField::Message) => {
+                    line.push_str(&PrintDescription(diagnostic).to_string())
+                }
+            }
+        }
+        self.lines.push(line);
+    }
+}
+
+impl biome_console::fmt::Display for TemplateReporterVisitor {
+    fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
+        fmt.write_str(&self.lines.join("\n"))
+    }
+}
+
+impl ReporterVisitor for TemplateReporterVisitor {
+    fn report_summary(
+        &mut self,
+        _execution: &Execution,
+        _summary: TraversalSummary,
+    ) -> io::Result<()> {
+        // Only the diagnostics are printed, so the output can be read line by line
+        Ok(())
+    }
+
+    fn report_diagnostics(
+        &mut self,
+        execution: &Execution,
+        payload: DiagnosticsPayload,
+    ) -> io::Result<()> {
+        for diagnostic in &payload.diagnostics {
+            if diagnostic.severity() >= payload.diagnostic_level
+                && (payload.verbose || !diagnostic.tags().is_verbose())
+            {
+                self.push_diagnostic(execution, diagnostic);
+            }
+        }
+        Ok(())
+    }
+}
@@ -74,7 +74,7 @@ impl<'a> ReporterVisitor for ConsoleReporterVisitor<'a> {
     }
 }
 
-struct Files(usize);
+pub(crate) struct Files(pub(crate) usize);
 
 impl fmt::Display for Files {
     fn fmt(&self, fmt: &mut Formatter) -> io::Result<()> {
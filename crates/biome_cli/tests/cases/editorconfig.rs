@@ -0,0 +1,116 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const EDITORCONFIG: &str = r#"root = true
+
+[*]
+indent_style = space
+indent_size = 8
+
+[*.ts]
+indent_size = 4
+"#;
+
+const UNFORMATTED: &str = "function f() {\nreturn 1;\n}\n";
+
+#[test]
+fn should_use_editorconfig() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let editorconfig = Path::new(".editorconfig");
+    fs.insert(editorconfig.into(), EDITORCONFIG.as_bytes());
+
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "formatter": {
+    "useEditorconfig": true,
+    "indentWidth": 2
+  }
+}
+"#
+        .as_bytes(),
+    );
+
+    let js_file = Path::new("src/index.js");
+    fs.insert(js_file.into(), UNFORMATTED.as_bytes());
+
+    let ts_file = Path::new("src/index.ts");
+    fs.insert(ts_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                js_file.as_os_str().to_str().unwrap(),
+                ts_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    // The options of `biome.json` take precedence over the `[*]` section,
+    // the other sections take precedence over the options of `biome.json`
+    assert_file_contents(&fs, js_file, "function f() {\n  return 1;\n}\n");
+    assert_file_contents(&fs, ts_file, "function f() {\n    return 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_use_editorconfig",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn should_not_use_editorconfig_by_default() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let editorconfig = Path::new(".editorconfig");
+    fs.insert(editorconfig.into(), EDITORCONFIG.as_bytes());
+
+    let file_path = Path::new("biome.json");
+    fs.insert(file_path.into(), r#"{}"#.as_bytes());
+
+    let js_file = Path::new("src/index.js");
+    fs.insert(js_file.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                js_file.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, js_file, "function f() {\n\treturn 1;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "should_not_use_editorconfig_by_default",
+        fs,
+        console,
+        result,
+    ));
+}
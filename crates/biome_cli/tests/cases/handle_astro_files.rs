@@ -21,7 +21,9 @@ import { something } from "file.astro";
 
 statement();
 ---
-<div></div>"#;
+
+<div></div>
+"#;
 
 const ASTRO_FILE_DEBUGGER_BEFORE: &str = r#"---
 debugger;
@@ -82,7 +84,9 @@ debugger;
 statement();
 var foo = "";
 ---
-<div></div>"#;
+
+<div></div>
+"#;
 
 const ASTRO_FILE_CHECK_APPLY_UNSAFE_AFTER: &str = r#"---
 import { something } from "file.astro";
@@ -90,7 +94,15 @@ import { a } from "mod";
 statement();
 const foo = "";
 ---
-<div></div>"#;
+
+<div></div>
+"#;
+
+const ASTRO_FILE_TEMPLATE_EXPRESSIONS: &str = r#"---
+const value = 0;
+---
+<p>{value as any}</p>
+<p>{value}</p>"#;
 
 #[test]
 fn format_astro_files() {
@@ -176,7 +188,7 @@ fn format_empty_astro_files_write() {
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
-    assert_file_contents(&fs, astro_file_path, "<div></div>");
+    assert_file_contents(&fs, astro_file_path, "<div></div>\n");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -667,3 +679,33 @@ fn check_stdin_apply_unsafe_successfully() {
         result,
     ));
 }
+
+#[test]
+fn lint_astro_template_expressions() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let astro_file_path = Path::new("file.astro");
+    fs.insert(
+        astro_file_path.into(),
+        ASTRO_FILE_TEMPLATE_EXPRESSIONS.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), astro_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, astro_file_path, ASTRO_FILE_TEMPLATE_EXPRESSIONS);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_astro_template_expressions",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,138 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const STYLED_COMPONENT_UNFORMATTED: &str = r#"const Button = styled.div`
+color:red;
+  background: ${ color };
+`;
+"#;
+
+const STYLED_COMPONENT_FORMATTED: &str = r#"const Button = styled.div`
+	color: red;
+	background: ${color};
+`;
+"#;
+
+const CUSTOM_TAG_UNFORMATTED: &str = r#"const style = tw`color:red;`;
+const other = css`color:red;`;
+"#;
+
+const CUSTOM_TAG_FORMATTED: &str = r#"const style = tw`
+	color: red;
+`;
+const other = css`color:red;`;
+"#;
+
+const GRAPHQL_UNFORMATTED: &str = r#"const query = gql`query  User { user(id: 1) { name } }`;
+const withFragment = graphql`query { user { ...UserFragment } } ${UserFragment}`;
+"#;
+
+const GRAPHQL_FORMATTED: &str = r#"const query = gql`
+	query User {
+		user(id: 1) {
+			name
+		}
+	}
+`;
+const withFragment = graphql`query { user { ...UserFragment } } ${UserFragment}`;
+"#;
+
+#[test]
+fn format_css_of_styled_components() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), STYLED_COMPONENT_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--write", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, STYLED_COMPONENT_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_css_of_styled_components",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_embedded_language_tags_of_configuration() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config_path = Path::new("biome.json");
+    fs.insert(
+        config_path.into(),
+        r#"{
+  "javascript": {
+    "formatter": {
+      "embeddedLanguageTags": { "css": ["tw"] }
+    }
+  }
+}
+"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), CUSTOM_TAG_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--write", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, CUSTOM_TAG_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_embedded_language_tags_of_configuration",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_graphql_of_gql_tags() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("file.js");
+    fs.insert(file_path.into(), GRAPHQL_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["format", "--write", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, GRAPHQL_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_graphql_of_gql_tags",
+        fs,
+        console,
+        result,
+    ));
+}
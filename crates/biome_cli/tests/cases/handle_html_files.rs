@@ -0,0 +1,95 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const HTML_FILE_UNFORMATTED: &str = r#"<script>
+const a    = 1;
+</script>
+"#;
+
+const HTML_FILE_FORMATTED: &str = "<script>\n\tconst a = 1;\n</script>\n";
+
+const HTML_FILE_USELESS_RENAME_BEFORE: &str = r#"<script type="module">
+import {a as a} from "mod";
+</script>
+"#;
+
+const HTML_FILE_USELESS_RENAME_AFTER: &str = r#"<script type="module">
+import {a} from "mod";
+</script>
+"#;
+
+#[test]
+fn format_html_files_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let html_file_path = Path::new("file.html");
+    fs.insert(html_file_path.into(), HTML_FILE_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                html_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, html_file_path, HTML_FILE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_html_files_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_and_fix_html_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let html_file_path = Path::new("file.html");
+    fs.insert(
+        html_file_path.into(),
+        HTML_FILE_USELESS_RENAME_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--apply",
+                html_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, html_file_path, HTML_FILE_USELESS_RENAME_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_and_fix_html_files",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -0,0 +1,106 @@
+use crate::run_cli;
+use crate::snap_test::{assert_cli_snapshot, assert_file_contents, SnapshotPayload};
+use biome_console::BufferConsole;
+use biome_fs::MemoryFileSystem;
+use biome_service::DynRef;
+use bpaf::Args;
+use std::path::Path;
+
+const MDX_FILE_UNFORMATTED: &str = r#"import {    Chart } from "./chart.jsx";
+
+# Sales
+
+<Chart   year={2024} />
+"#;
+
+const MDX_FILE_FORMATTED: &str = r#"import { Chart } from "./chart.jsx";
+
+# Sales
+
+<Chart year={2024} />
+"#;
+
+const MDX_FILE_USELESS_RENAME_BEFORE: &str = r#"import {Chart as Chart} from './chart.jsx';
+
+# Sales
+
+<Chart year={2024} />
+"#;
+
+const MDX_FILE_USELESS_RENAME_AFTER: &str = r#"import {Chart} from './chart.jsx';
+
+# Sales
+
+<Chart year={2024} />
+"#;
+
+#[test]
+fn format_mdx_files_write() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let mdx_file_path = Path::new("file.mdx");
+    fs.insert(mdx_file_path.into(), MDX_FILE_UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                "format",
+                "--write",
+                mdx_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, mdx_file_path, MDX_FILE_FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_mdx_files_write",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_and_fix_mdx_files() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let mdx_file_path = Path::new("file.mdx");
+    fs.insert(
+        mdx_file_path.into(),
+        MDX_FILE_USELESS_RENAME_BEFORE.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--apply",
+                mdx_file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, mdx_file_path, MDX_FILE_USELESS_RENAME_AFTER);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_and_fix_mdx_files",
+        fs,
+        console,
+        result,
+    ));
+}
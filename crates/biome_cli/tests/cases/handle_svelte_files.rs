@@ -30,7 +30,8 @@ const SVELTE_TS_CONTEXT_MODULE_FILE_FORMATTED: &str = r#"<script context="module
 import Button from "./components/Button.svelte";
 const hello: string = "world";
 </script>
-<div></div>"#;
+<div></div>
+"#;
 
 const SVELTE_CARRIAGE_RETURN_LINE_FEED_FILE_UNFORMATTED: &str =
     "<script>\r\n  const a    = \"b\";\r\n</script>\r\n<div></div>";
@@ -66,7 +67,8 @@ debugger;
 statement();
 var foo = "";
 </script>
-<div></div>"#;
+<div></div>
+"#;
 
 const SVELTE_TS_FILE_CHECK_APPLY_UNSAFE_AFTER: &str = r#"<script context="module" lang="ts">
 import Button from "./components/Button.svelte";
@@ -74,7 +76,40 @@ import { Form } from "./components/Form.svelte";
 statement();
 const foo = "";
 </script>
-<div></div>"#;
+<div></div>
+"#;
+
+const SVELTE_TS_FILE_TEMPLATE_EXPRESSIONS: &str = r#"<script lang="ts">
+let value = 0;
+</script>
+<p>{value as any}</p>
+<p>{value}</p>"#;
+
+const SVELTE_FILE_NON_REACTIVE_UPDATES: &str = r#"<script>
+const { step } = $props();
+let count = 0;
+let total = $state(0);
+const user = { name: "" };
+let clicks = 0;
+let label = "Count";
+label = "Clicks";
+
+function rename(name) {
+	user.name = name;
+	clicks++;
+}
+</script>
+<button onclick={() => { count += step; }}>{label}: {count}</button>
+<button onclick={() => total++}>{total}</button>
+<input oninput={(event) => rename(event.target.value)} value={user.name}>"#;
+
+const SVELTE_LEGACY_FILE_NON_REACTIVE_UPDATES: &str = r#"<script>
+let count = 0;
+</script>
+<button on:click={() => count++}>{count}</button>"#;
+
+const NON_REACTIVE_UPDATES_CONFIG: &str =
+    r#"{ "linter": { "rules": { "nursery": { "noNonReactiveUpdates": "error" } } } }"#;
 
 #[test]
 fn sorts_imports_check() {
@@ -565,3 +600,97 @@ fn check_stdin_apply_unsafe_successfully() {
         result,
     ));
 }
+
+#[test]
+fn lint_svelte_template_expressions() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_TS_FILE_TEMPLATE_EXPRESSIONS.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), svelte_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, svelte_file_path, SVELTE_TS_FILE_TEMPLATE_EXPRESSIONS);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_template_expressions",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_svelte_non_reactive_updates() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        NON_REACTIVE_UPDATES_CONFIG.as_bytes(),
+    );
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_FILE_NON_REACTIVE_UPDATES.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), svelte_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_non_reactive_updates",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_svelte_legacy_non_reactive_updates() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        NON_REACTIVE_UPDATES_CONFIG.as_bytes(),
+    );
+    let svelte_file_path = Path::new("file.svelte");
+    fs.insert(
+        svelte_file_path.into(),
+        SVELTE_LEGACY_FILE_NON_REACTIVE_UPDATES.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), svelte_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_svelte_legacy_non_reactive_updates",
+        fs,
+        console,
+        result,
+    ));
+}
@@ -18,7 +18,8 @@ const VUE_IMPLICIT_JS_FILE_FORMATTED: &str = r#"<script>
 import { something } from "file.vue";
 statement();
 </script>
-<template></template>"#;
+<template></template>
+"#;
 
 const VUE_EXPLICIT_JS_FILE_UNFORMATTED: &str = r#"<script lang="js">
 import {    something } from "file.vue";
@@ -30,7 +31,8 @@ const VUE_EXPLICIT_JS_FILE_FORMATTED: &str = r#"<script lang="js">
 import { something } from "file.vue";
 statement();
 </script>
-<template></template>"#;
+<template></template>
+"#;
 
 const VUE_TS_FILE_UNFORMATTED: &str = r#"<script setup lang="ts">
 import     { type     something } from "file.vue";
@@ -42,7 +44,8 @@ const VUE_TS_FILE_FORMATTED: &str = r#"<script setup lang="ts">
 import { type something } from "file.vue";
 const hello: string = "world";
 </script>
-<template></template>"#;
+<template></template>
+"#;
 
 const VUE_JS_FILE_NOT_LINTED: &str = r#"<script setup lang="js">
 a == b;
@@ -109,7 +112,8 @@ import { Button } from "./components/Button.vue";
 
 delete a.c;
 </script>
-<template></template>"#;
+<template></template>
+"#;
 
 const VUE_TS_FILE_CHECK_APPLY_UNSAFE_AFTER: &str = r#"<script setup lang="ts">
 import * as vueUse from "vue-use";
@@ -117,7 +121,8 @@ import { Button } from "./components/Button.vue";
 
 a.c = undefined;
 </script>
-<template></template>"#;
+<template></template>
+"#;
 
 const VUE_TS_FILE_SETUP_GLOBALS: &str = r#"<script setup lang="ts">
 // These are magic vue macros, and should be treated as globals.
@@ -141,6 +146,11 @@ const slots = defineSlots<{
 </script>
 <template></template>"#;
 
+const VUE_TEMPLATE_NOT_LINTED: &str = r#"<template>
+    <div v-if>Hidden</div>
+    <img :alt="description" src="image.png">
+</template>"#;
+
 #[test]
 fn format_vue_implicit_js_files() {
     let mut fs = MemoryFileSystem::default();
@@ -298,7 +308,7 @@ fn format_empty_vue_js_files_write() {
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
-    assert_file_contents(&fs, vue_file_path, "<template></template>");
+    assert_file_contents(&fs, vue_file_path, "<template></template>\n");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -393,7 +403,7 @@ fn format_empty_vue_ts_files_write() {
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
-    assert_file_contents(&fs, vue_file_path, "<template></template>");
+    assert_file_contents(&fs, vue_file_path, "<template></template>\n");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -518,6 +528,36 @@ fn lint_vue_ts_files() {
     ));
 }
 
+#[test]
+fn lint_vue_template() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "nursery": { "useValidVueDirectives": "error" } } } }"#
+            .as_bytes(),
+    );
+    let vue_file_path = Path::new("file.vue");
+    fs.insert(vue_file_path.into(), VUE_TEMPLATE_NOT_LINTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), vue_file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_vue_template",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn sorts_imports_check() {
     let mut fs = MemoryFileSystem::default();
@@ -6,7 +6,11 @@ mod config_extends;
 mod config_path;
 mod cts_files;
 mod diagnostics;
+mod editorconfig;
 mod handle_astro_files;
+mod handle_embedded_templates;
+mod handle_html_files;
+mod handle_mdx_files;
 mod handle_svelte_files;
 mod handle_vue_files;
 mod included_files;
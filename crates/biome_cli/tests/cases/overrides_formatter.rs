@@ -24,6 +24,15 @@ const FORMATTED_LINE_WIDTH: &str = "const a = [\"loreum\", \"ipsum\"];\n";
 const FORMATTED_WITH_SINGLE_QUOTES: &str = "const a = ['loreum', 'ipsum'];\n";
 const FORMATTED_WITH_NO_SEMICOLONS: &str = "const a = [\"loreum\", \"ipsum\"]\n";
 
+const UNFORMATTED_JSON_ARRAY: &str = r#"["lorem", "ipsum", "first", "second"]"#;
+const FORMATTED_JSON_ONE_PER_LINE: &str =
+    "[\n\t\"lorem\",\n\t\"ipsum\",\n\t\"first\",\n\t\"second\"\n]\n";
+const FORMATTED_JSON_FILL: &str = "[\n\t\"lorem\", \"ipsum\",\n\t\"first\", \"second\"\n]\n";
+
+const UNFORMATTED_PACKAGE_JSON: &str = r#"{ "version": "1.0.0", "name": "app", "dependencies": { "zod": "^3.0.0", "react": "^18.0.0" } }"#;
+const FORMATTED_PACKAGE_JSON: &str = "{\n\t\"version\": \"1.0.0\",\n\t\"name\": \"app\",\n\t\"dependencies\": { \"zod\": \"^3.0.0\", \"react\": \"^18.0.0\" }\n}\n";
+const SORTED_PACKAGE_JSON: &str = "{\n\t\"name\": \"app\",\n\t\"version\": \"1.0.0\",\n\t\"dependencies\": { \"react\": \"^18.0.0\", \"zod\": \"^3.0.0\" }\n}\n";
+
 const CSS_UNFORMATTED_QUOTES: &str =
     r#"[class='foo'] { background-image: url("/path/to/file.jpg")}"#;
 const CSS_FORMATTED_SINGLE_QUOTES_AND_SPACES: &str =
@@ -519,3 +528,106 @@ fn does_not_change_formatting_language_settings_2() {
         result,
     ));
 }
+
+#[test]
+fn does_include_file_with_different_array_wrap() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "json": { "formatter": { "lineWidth": 20 } },
+  "overrides": [
+    { "include": ["fixtures.json"], "json": { "formatter": { "arrayWrap": "fill" } } }
+  ]
+}
+
+"#
+        .as_bytes(),
+    );
+
+    let data = Path::new("data.json");
+    fs.insert(data.into(), UNFORMATTED_JSON_ARRAY.as_bytes());
+
+    let fixtures = Path::new("fixtures.json");
+    fs.insert(fixtures.into(), UNFORMATTED_JSON_ARRAY.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                data.as_os_str().to_str().unwrap(),
+                fixtures.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, data, FORMATTED_JSON_ONE_PER_LINE);
+    assert_file_contents(&fs, fixtures, FORMATTED_JSON_FILL);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_include_file_with_different_array_wrap",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_include_file_with_sorted_package_json() {
+    let mut console = BufferConsole::default();
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("biome.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+  "overrides": [
+    { "include": ["packages/**"], "json": { "formatter": { "sortPackageJson": true } } }
+  ]
+}
+
+"#
+        .as_bytes(),
+    );
+
+    let root = Path::new("package.json");
+    fs.insert(root.into(), UNFORMATTED_PACKAGE_JSON.as_bytes());
+
+    let package = Path::new("packages/a/package.json");
+    fs.insert(package.into(), UNFORMATTED_PACKAGE_JSON.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                root.as_os_str().to_str().unwrap(),
+                package.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, root, FORMATTED_PACKAGE_JSON);
+    assert_file_contents(&fs, package, SORTED_PACKAGE_JSON);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_include_file_with_sorted_package_json",
+        fs,
+        console,
+        result,
+    ));
+}
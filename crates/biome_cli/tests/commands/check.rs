@@ -1604,6 +1604,54 @@ import * as something from "../something";
     ));
 }
 
+#[test]
+fn applies_organize_imports_with_custom_groups() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config = r#"{
+    "javascript": {
+        "organizeImports": {
+            "groups": [[":NODE:"], [":PACKAGE:"], [":PATH:"]],
+            "blankLinesBetweenGroups": true
+        }
+    }
+}"#;
+    let file_path = Path::new("biome.json");
+    fs.insert(file_path.into(), config.as_bytes());
+
+    let file_path = Path::new("check.js");
+    let content = r#"import * as something from "../something";
+import { lorem } from "foo";
+import fs from "node:fs";
+"#;
+    let expected = r#"import fs from "node:fs";
+
+import { lorem } from "foo";
+
+import * as something from "../something";
+"#;
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(["check", "--apply", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, expected);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "applies_organize_imports_with_custom_groups",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn shows_organize_imports_diff_on_check() {
     let mut fs = MemoryFileSystem::default();
@@ -2343,6 +2391,30 @@ fn check_stdin_returns_text_if_content_is_not_changed() {
         result,
     ));
 }
+
+#[test]
+fn check_watch_with_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push("console.log(\"\");\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), ("--watch"), ("--stdin-file-path"), ("mock.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_watch_with_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
 #[test]
 fn should_apply_correct_file_source() {
     let mut fs = MemoryFileSystem::default();
@@ -2922,3 +2994,266 @@ fn lint_error_without_file_paths() {
         result,
     ));
 }
+
+#[test]
+fn check_baseline_suppresses_the_recorded_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--write-baseline=baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(Path::new("baseline.json"))
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert!(buffer.contains("lint/suspicious/noDebugger"), "{buffer}");
+
+    // Only the diagnostic that isn't in the baseline is reported
+    fs.insert(file_path.into(), "debugger;\ndebugger;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--baseline=baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let error_count = console
+        .out_buffer
+        .iter()
+        .filter(|m| m.level == LogLevel::Error)
+        .filter(|m| {
+            let content = format!("{:?}", m.content);
+            content.contains("suspicious/noDebugger")
+        })
+        .count();
+    assert_eq!(error_count, 1);
+}
+
+#[test]
+fn check_write_baseline_records_the_diagnostics_that_are_not_printed() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("check.js");
+    fs.insert(
+        file_path.into(),
+        "debugger;\ndebugger;\ndebugger;\n".as_bytes(),
+    );
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--max-diagnostics=1",
+                "--write-baseline=baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--max-diagnostics=1",
+                "--baseline=baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn check_write_baseline_formats_the_baseline_file() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--write-baseline=baseline.json",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), "baseline.json"].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn check_baseline_not_found() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("check"), "--baseline=baseline.json"].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "check_baseline_not_found",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn check_interactive_applies_accepted_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "const a = 1;\ndebugger;\n".as_bytes());
+    console.in_buffer.push("y\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--apply-unsafe",
+                "--interactive",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, "const a = 1;\n");
+}
+
+#[test]
+fn check_interactive_keeps_skipped_fixes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "const a = 1;\ndebugger;\n".as_bytes());
+    console.in_buffer.push("n\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--apply-unsafe",
+                "--interactive",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, "const a = 1;\ndebugger;\n");
+}
+
+#[test]
+fn check_interactive_applies_all_the_fixes_of_the_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("check.js");
+    fs.insert(
+        file_path.into(),
+        "const a = 1;\ndebugger;\ndebugger;\n".as_bytes(),
+    );
+    console.in_buffer.push("a\n".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--apply-unsafe",
+                "--interactive",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+    assert_file_contents(&fs, file_path, "const a = 1;\n");
+}
+
+#[test]
+fn check_interactive_requires_apply() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "const a = 1;\ndebugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("check"),
+                "--interactive",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+}
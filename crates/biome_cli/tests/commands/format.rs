@@ -53,7 +53,8 @@ const SVELTE_IMPLICIT_JS_FILE_FORMATTED: &str = r#"<script>
 import { something } from "file.svelte";
 statement();
 </script>
-<div></div>"#;
+<div></div>
+"#;
 
 const SVELTE_EXPLICIT_JS_FILE_UNFORMATTED: &str = r#"<script lang="js">
 import {    something } from "file.svelte";
@@ -65,7 +66,8 @@ const SVELTE_EXPLICIT_JS_FILE_FORMATTED: &str = r#"<script lang="js">
 import { something } from "file.svelte";
 statement();
 </script>
-<div></div>"#;
+<div></div>
+"#;
 
 const SVELTE_TS_FILE_UNFORMATTED: &str = r#"<script setup lang="ts">
 import     { type     something } from "file.svelte";
@@ -77,7 +79,8 @@ const SVELTE_TS_FILE_FORMATTED: &str = r#"<script setup lang="ts">
 import { type something } from "file.svelte";
 const hello: string = "world";
 </script>
-<div></div>"#;
+<div></div>
+"#;
 
 const APPLY_TRAILING_COMMAS_BEFORE: &str = r#"
 const a = [
@@ -1331,6 +1334,272 @@ fn format_stdin_with_errors() {
     ));
 }
 
+#[test]
+fn format_stdin_range() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console
+        .in_buffer
+        .push("let  a = 1;\nfunction f() {return{}}".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--stdin-file-path"),
+                ("mock.js"),
+                ("--range"),
+                ("0..11"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, "let a = 1;");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_stdin_range",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_range_without_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--range"), ("0..11")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_range_without_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_debug_ir() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), "a;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--debug-ir"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "a;\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_debug_ir",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_verify() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let file_path = Path::new("format.js");
+    fs.insert(file_path.into(), UNFORMATTED.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--write"),
+                ("--verify"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, FORMATTED);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_verify",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_verify_with_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console
+        .in_buffer
+        .push("function f() {return{}}".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--verify"), ("--stdin-file-path"), ("mock.js")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_verify_with_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_stdin_without_extension() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console.in_buffer.push(r#"{ "a": [1,2] }"#.to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--stdin-file-path"), ("data")].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, "{ \"a\": [1, 2] }\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_stdin_without_extension",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_stdin_with_stdin_language() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    console
+        .in_buffer
+        .push("function f(a:string) {return a}".to_string());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("format"),
+                ("--stdin-file-path"),
+                ("script"),
+                ("--stdin-language"),
+                ("typescript"),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let message = console
+        .out_buffer
+        .first()
+        .expect("Console should have written a message");
+
+    let content = markup_to_string(markup! {
+        {message.content}
+    });
+
+    assert_eq!(content, "function f(a: string) {\n\treturn a;\n}\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_stdin_with_stdin_language",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn format_stdin_language_without_stdin_file_path() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("format"), ("--stdin-language"), ("typescript")].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "format_stdin_language_without_stdin_file_path",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn does_not_format_if_disabled() {
     let mut fs = MemoryFileSystem::default();
@@ -3424,7 +3693,7 @@ fn format_empty_svelte_js_files_write() {
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
-    assert_file_contents(&fs, svelte_file_path, "<div></div>");
+    assert_file_contents(&fs, svelte_file_path, "<div></div>\n");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
@@ -3525,7 +3794,7 @@ fn format_empty_svelte_ts_files_write() {
 
     assert!(result.is_ok(), "run_cli returned {result:?}");
 
-    assert_file_contents(&fs, svelte_file_path, "<div></div>");
+    assert_file_contents(&fs, svelte_file_path, "<div></div>\n");
 
     assert_cli_snapshot(SnapshotPayload::new(
         module_path!(),
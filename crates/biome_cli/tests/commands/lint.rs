@@ -403,6 +403,105 @@ function f() { arguments; }
     ));
 }
 
+#[test]
+fn apply_unsafe_fix_promoted_to_safe() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config = Path::new("biome.json");
+    fs.insert(
+        config.into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "style": {
+                "noUnusedTemplateLiteral": { "level": "error", "fix": "safe" }
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), "const x = `hello`;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, "const x = \"hello\";\n");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_unsafe_fix_promoted_to_safe",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn apply_safe_fix_demoted_to_unsafe() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let config = Path::new("biome.json");
+    fs.insert(
+        config.into(),
+        r#"{
+    "linter": {
+        "rules": {
+            "style": {
+                "useConst": { "level": "error", "fix": "unsafe" }
+            }
+        }
+    }
+}"#
+        .as_bytes(),
+    );
+
+    let source = "let a = 1;\nconsole.log(a);\n";
+    let file_path = Path::new("fix.js");
+    fs.insert(file_path.into(), source.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                ("--apply"),
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_file_contents(&fs, file_path, source);
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "apply_safe_fix_demoted_to_unsafe",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn no_lint_if_linter_is_disabled_when_run_apply() {
     let mut fs = MemoryFileSystem::default();
@@ -3254,6 +3353,171 @@ import "lodash";
     ));
 }
 
+#[test]
+fn enables_domain_rules_from_dependencies() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let package_json = r#"{
+  "dependencies": { "next": "latest", "react": "latest" }
+}"#;
+    let package_json_path = Path::new("package.json");
+    fs.insert(package_json_path.into(), package_json.as_bytes());
+
+    let file_path = Path::new("page.jsx");
+    fs.insert(
+        file_path.into(),
+        r#"export default function Page() {
+	return <img alt="Foo" src="/foo.png" />;
+}
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "enables_domain_rules_from_dependencies",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn enables_domain_rules_from_globs() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let test_path = Path::new("src/sum.test.js");
+    fs.insert(test_path.into(), "expect(sum(1, 2)).toBe(3);\n".as_bytes());
+
+    let nested_test_path = Path::new("src/__tests__/sum.js");
+    fs.insert(
+        nested_test_path.into(),
+        "expect(sum(1, 2)).toBe(3);\n".as_bytes(),
+    );
+
+    let source_path = Path::new("src/sum.js");
+    fs.insert(
+        source_path.into(),
+        "expect(sum(1, 2)).toBe(3);\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                test_path.as_os_str().to_str().unwrap(),
+                nested_test_path.as_os_str().to_str().unwrap(),
+                source_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "enables_domain_rules_from_globs",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn enables_domain_rules_from_scoped_dependencies() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let package_json = r#"{
+  "devDependencies": { "@storybook/react": "latest" }
+}"#;
+    let package_json_path = Path::new("package.json");
+    fs.insert(package_json_path.into(), package_json.as_bytes());
+
+    let file_path = Path::new("Button.stories.js");
+    fs.insert(
+        file_path.into(),
+        "export const Primary = { args: { primary: true } };\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "enables_domain_rules_from_scoped_dependencies",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn does_not_enable_disabled_domain_rules() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let configuration = r#"{
+  "linter": {
+    "rules": {
+      "nursery": {
+        "noImgElement": "off"
+      }
+    }
+  }
+}"#;
+    let configuration_path = Path::new("biome.json");
+    fs.insert(configuration_path.into(), configuration.as_bytes());
+
+    let package_json = r#"{
+  "dependencies": { "next": "latest", "react": "latest" }
+}"#;
+    let package_json_path = Path::new("package.json");
+    fs.insert(package_json_path.into(), package_json.as_bytes());
+
+    let file_path = Path::new("page.jsx");
+    fs.insert(
+        file_path.into(),
+        r#"export default function Page() {
+	return <img alt="Foo" src="/foo.png" />;
+}
+"#,
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "does_not_enable_disabled_domain_rules",
+        fs,
+        console,
+        result,
+    ));
+}
+
 #[test]
 fn should_lint_error_without_file_paths() {
     let mut fs = MemoryFileSystem::default();
@@ -3590,3 +3854,580 @@ fn lint_rule_filter_group_with_disabled_rule() {
         result,
     ));
 }
+
+#[test]
+fn lint_reporter_sarif() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let content = "debugger; delete obj.prop;";
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--rule=suspicious/noDebugger",
+                "--reporter=sarif",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reporter_sarif",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reporter_junit() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let content = "debugger; delete obj.prop;";
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--rule=suspicious/noDebugger",
+                "--reporter=junit",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reporter_junit",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reporter_gitlab() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let content = "debugger; delete obj.prop;";
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--rule=suspicious/noDebugger",
+                "--reporter=gitlab",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reporter_gitlab",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reporter_checkstyle() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let content = "debugger; delete obj.prop;";
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--rule=suspicious/noDebugger",
+                "--reporter=checkstyle",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reporter_checkstyle",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reporter_template() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let content = "debugger; delete obj.prop;";
+
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), content.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--rule=suspicious/noDebugger",
+                "--reporter=template",
+                "--reporter-template=::{severity} file={path},line={line},col={column}::{message}",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reporter_template",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_reporter_template_unknown_field() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--reporter=template",
+                "--reporter-template",
+                "{file}",
+                "check.js",
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_reporter_template_unknown_field",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_cache_reports_the_cached_diagnostics() {
+    let mut fs = MemoryFileSystem::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;".as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(Path::new(".biomecache"))
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert!(buffer.contains("check.js"), "{buffer}");
+    assert!(buffer.contains("lint/suspicious/noDebugger"), "{buffer}");
+
+    // The diagnostic is changed in the cache, so the second run only reports it if it reads
+    // the cache instead of linting the file again
+    let buffer = buffer.replace(
+        "This is an unexpected use of the",
+        "This is a cached use of the",
+    );
+    fs.insert(Path::new(".biomecache").into(), buffer.as_bytes());
+
+    let mut console = BufferConsole::default();
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    let messages = console
+        .out_buffer
+        .iter()
+        .filter(|m| m.level == LogLevel::Error)
+        .map(|m| format!("{:?}", m.content))
+        .collect::<Vec<_>>();
+    assert!(
+        messages
+            .iter()
+            .any(|message| message.contains("This is a cached use of the")),
+        "{messages:?}"
+    );
+    assert!(
+        messages
+            .iter()
+            .all(|message| !message.contains("This is an unexpected use of the")),
+        "{messages:?}"
+    );
+}
+
+#[test]
+fn lint_cache_is_discarded_when_the_configuration_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn lint_cache_is_discarded_when_the_manifest_changes() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "nursery": { "noUndeclaredDependencies": "error" } } } }"#
+            .as_bytes(),
+    );
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "dependencies": {} }"#.as_bytes(),
+    );
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), r#"import "react";"#.as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    fs.insert(
+        Path::new("package.json").into(),
+        r#"{ "dependencies": { "react": "^18.0.0" } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--cache", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+}
+
+#[test]
+fn lint_suppress_inserts_suppression_comments() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "const a = 1;\ndebugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--suppress=legacy code",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        "const a = 1;\n// biome-ignore lint/suspicious/noDebugger: legacy code\ndebugger;\n"
+    );
+}
+
+#[test]
+fn lint_suppress_only_the_given_rule() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("check.js");
+    fs.insert(
+        file_path.into(),
+        "const a = 1;\ndebugger;\nif (a == 2) {}\n".as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--suppress",
+                "--rule=suspicious/noDebugger",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        "const a = 1;\n// biome-ignore lint/suspicious/noDebugger: <explanation>\ndebugger;\nif (a == 2) {}\n"
+    );
+}
+
+#[test]
+fn lint_suppress_without_reason() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("check.js");
+    fs.insert(file_path.into(), "debugger;\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--suppress",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    let mut buffer = String::new();
+    fs.open(file_path)
+        .unwrap()
+        .read_to_string(&mut buffer)
+        .unwrap();
+
+    assert_eq!(
+        buffer,
+        "// biome-ignore lint/suspicious/noDebugger: <explanation>\ndebugger;\n"
+    );
+}
+
+#[test]
+fn lint_suppress_unsupported_language() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+    let file_path = Path::new("config.yaml");
+    fs.insert(file_path.into(), "a: 1\n".as_bytes());
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--suppress",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_err(), "run_cli returned {result:?}");
+
+    assert_cli_snapshot(SnapshotPayload::new(
+        module_path!(),
+        "lint_suppress_unsupported_language",
+        fs,
+        console,
+        result,
+    ));
+}
+
+#[test]
+fn lint_apply_sorts_package_json_keys() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "nursery": { "useSortedPackageJsonKeys": "error" } } } }"#
+            .as_bytes(),
+    );
+    let file_path = Path::new("package.json");
+    fs.insert(
+        file_path.into(),
+        r#"{
+	"version": "1.0.0",
+	"name": "my-package",
+	"dependencies": { "react": "^18.0.0", "@biomejs/biome": "^1.9.0" }
+}
+"#
+        .as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--apply", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"{
+	"name": "my-package",
+	"version": "1.0.0",
+	"dependencies": { "@biomejs/biome": "^1.9.0", "react": "^18.0.0" }
+}
+"#,
+    );
+}
+
+#[test]
+fn lint_apply_unsafe_renames_deprecated_tsconfig_options() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "nursery": { "noDeprecatedTsconfigOptions": "error" } } } }"#
+            .as_bytes(),
+    );
+    let file_path = Path::new("tsconfig.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "compilerOptions": { "out": "dist/bundle.js" } }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from(
+            [
+                ("lint"),
+                "--apply-unsafe",
+                file_path.as_os_str().to_str().unwrap(),
+            ]
+            .as_slice(),
+        ),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"{ "compilerOptions": { "outFile": "dist/bundle.js" } }"#,
+    );
+}
+
+#[test]
+fn lint_apply_sorts_json_keys() {
+    let mut fs = MemoryFileSystem::default();
+    let mut console = BufferConsole::default();
+
+    fs.insert(
+        Path::new("biome.json").into(),
+        r#"{ "linter": { "rules": { "nursery": { "useSortedKeys": "error" } } } }"#.as_bytes(),
+    );
+    let file_path = Path::new("file.json");
+    fs.insert(
+        file_path.into(),
+        r#"{ "vite": "^5.0.0", "react": "^18.0.0" }"#.as_bytes(),
+    );
+
+    let result = run_cli(
+        DynRef::Borrowed(&mut fs),
+        &mut console,
+        Args::from([("lint"), "--apply", file_path.as_os_str().to_str().unwrap()].as_slice()),
+    );
+
+    assert!(result.is_ok(), "run_cli returned {result:?}");
+
+    assert_file_contents(
+        &fs,
+        file_path,
+        r#"{ "react": "^18.0.0", "vite": "^5.0.0" }"#,
+    );
+}
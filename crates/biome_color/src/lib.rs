@@ -0,0 +1,329 @@
+//! Parse CSS colors and compute their contrast ratio as defined by the [WCAG].
+//!
+//! [WCAG]: https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+
+mod named;
+
+/// A color in the sRGB color space.
+///
+/// The channels are stored as numbers between `0` and `1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Color {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub alpha: f64,
+}
+
+impl Color {
+    /// Creates an opaque color from its 8-bit channels.
+    pub fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self {
+            red: f64::from(red) / 255.0,
+            green: f64::from(green) / 255.0,
+            blue: f64::from(blue) / 255.0,
+            alpha: 1.0,
+        }
+    }
+
+    /// Parses a CSS color.
+    ///
+    /// The hexadecimal notations, the named colors, and the functions `rgb()`, `rgba()`, `hsl()` and `hsla()` are supported.
+    /// Returns `None` for the other values, including the keywords such as `currentcolor`
+    /// that depend on the context of the color.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_color::Color;
+    ///
+    /// assert_eq!(Color::parse("#fff"), Some(Color::from_rgb(255, 255, 255)));
+    /// assert_eq!(Color::parse("rgb(255 0 0)"), Some(Color::from_rgb(255, 0, 0)));
+    /// assert_eq!(Color::parse("Navy"), Some(Color::from_rgb(0, 0, 128)));
+    /// assert_eq!(Color::parse("currentcolor"), None);
+    /// ```
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        if let Some(hex) = value.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+        if let Some((name, arguments)) = value.split_once('(') {
+            let arguments = arguments.strip_suffix(')')?;
+            return match name.trim_end() {
+                "rgb" | "rgba" => parse_rgb(arguments),
+                "hsl" | "hsla" => parse_hsl(arguments),
+                _ => None,
+            };
+        }
+        if value == "transparent" {
+            return Some(Self {
+                alpha: 0.0,
+                ..Self::from_rgb(0, 0, 0)
+            });
+        }
+        let (red, green, blue) = named::named_color(&value)?;
+        Some(Self::from_rgb(red, green, blue))
+    }
+
+    /// Returns `true` if the color doesn't have any transparency.
+    pub fn is_opaque(&self) -> bool {
+        self.alpha >= 1.0
+    }
+
+    /// Returns the opaque color obtained by painting the current color over `background`.
+    ///
+    /// `background` is assumed to be opaque.
+    pub fn blend_over(&self, background: &Self) -> Self {
+        let blend = |channel: f64, background_channel: f64| {
+            channel * self.alpha + background_channel * (1.0 - self.alpha)
+        };
+        Self {
+            red: blend(self.red, background.red),
+            green: blend(self.green, background.green),
+            blue: blend(self.blue, background.blue),
+            alpha: 1.0,
+        }
+    }
+
+    /// Returns the relative luminance of the color, between `0` for black and `1` for white.
+    ///
+    /// The transparency of the color is ignored.
+    ///
+    /// See https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+    pub fn relative_luminance(&self) -> f64 {
+        let linearize = |channel: f64| {
+            if channel <= 0.04045 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linearize(self.red)
+            + 0.7152 * linearize(self.green)
+            + 0.0722 * linearize(self.blue)
+    }
+
+    /// Returns the contrast ratio between two colors, between `1` and `21`.
+    ///
+    /// The transparency of the colors is ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_color::Color;
+    ///
+    /// let black = Color::from_rgb(0, 0, 0);
+    /// let white = Color::from_rgb(255, 255, 255);
+    /// assert_eq!(black.contrast_ratio(&white), 21.0);
+    /// assert_eq!(white.contrast_ratio(&white), 1.0);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Self) -> f64 {
+        let luminance = self.relative_luminance();
+        let other_luminance = other.relative_luminance();
+        let (lighter, darker) = if luminance > other_luminance {
+            (luminance, other_luminance)
+        } else {
+            (other_luminance, luminance)
+        };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |index: usize| u8::from_str_radix(&hex[index..index + 1], 16).ok();
+    let pair = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16).ok();
+    let (red, green, blue, alpha) = match hex.len() {
+        3 | 4 => (
+            digit(0)? * 17,
+            digit(1)? * 17,
+            digit(2)? * 17,
+            if hex.len() == 4 { digit(3)? * 17 } else { 255 },
+        ),
+        6 | 8 => (
+            pair(0)?,
+            pair(2)?,
+            pair(4)?,
+            if hex.len() == 8 { pair(6)? } else { 255 },
+        ),
+        _ => return None,
+    };
+    Some(Color {
+        alpha: f64::from(alpha) / 255.0,
+        ..Color::from_rgb(red, green, blue)
+    })
+}
+
+/// Splits the arguments of a color function, in the legacy syntax `1, 2, 3, 0.5`
+/// or in the modern syntax `1 2 3 / 0.5`.
+fn split_arguments(arguments: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    if arguments.contains(',') {
+        let mut arguments: Vec<_> = arguments.split(',').map(str::trim).collect();
+        let alpha = if arguments.len() == 4 {
+            arguments.pop()
+        } else {
+            None
+        };
+        Some((arguments, alpha))
+    } else {
+        let (channels, alpha) = match arguments.split_once('/') {
+            Some((channels, alpha)) => (channels, Some(alpha.trim())),
+            None => (arguments, None),
+        };
+        Some((channels.split_ascii_whitespace().collect(), alpha))
+    }
+}
+
+fn parse_rgb(arguments: &str) -> Option<Color> {
+    let (channels, alpha) = split_arguments(arguments)?;
+    let [red, green, blue] = channels.as_slice() else {
+        return None;
+    };
+    let channel = |value: &str| match value.strip_suffix('%') {
+        Some(percentage) => Some(percentage.parse::<f64>().ok()? / 100.0),
+        None => Some(value.parse::<f64>().ok()? / 255.0),
+    };
+    Some(Color {
+        red: channel(red)?.clamp(0.0, 1.0),
+        green: channel(green)?.clamp(0.0, 1.0),
+        blue: channel(blue)?.clamp(0.0, 1.0),
+        alpha: parse_alpha(alpha)?,
+    })
+}
+
+fn parse_hsl(arguments: &str) -> Option<Color> {
+    let (channels, alpha) = split_arguments(arguments)?;
+    let [hue, saturation, lightness] = channels.as_slice() else {
+        return None;
+    };
+    let hue = parse_hue(hue)?;
+    let percentage = |value: &str| {
+        let value = value.strip_suffix('%').unwrap_or(value);
+        Some((value.parse::<f64>().ok()? / 100.0).clamp(0.0, 1.0))
+    };
+    let saturation = percentage(saturation)?;
+    let lightness = percentage(lightness)?;
+    // See https://www.w3.org/TR/css-color-4/#hsl-to-rgb
+    let channel = |offset: f64| {
+        let k = (offset + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    Some(Color {
+        red: channel(0.0),
+        green: channel(8.0),
+        blue: channel(4.0),
+        alpha: parse_alpha(alpha)?,
+    })
+}
+
+/// Parses a hue and returns it in degrees between `0` and `360`.
+fn parse_hue(value: &str) -> Option<f64> {
+    let degrees = if let Some(value) = value.strip_suffix("deg") {
+        value.parse::<f64>().ok()?
+    } else if let Some(value) = value.strip_suffix("grad") {
+        value.parse::<f64>().ok()? * 0.9
+    } else if let Some(value) = value.strip_suffix("rad") {
+        value.parse::<f64>().ok()?.to_degrees()
+    } else if let Some(value) = value.strip_suffix("turn") {
+        value.parse::<f64>().ok()? * 360.0
+    } else {
+        value.parse::<f64>().ok()?
+    };
+    Some(degrees.rem_euclid(360.0))
+}
+
+fn parse_alpha(value: Option<&str>) -> Option<f64> {
+    let Some(value) = value else {
+        return Some(1.0);
+    };
+    let alpha = match value.strip_suffix('%') {
+        Some(percentage) => percentage.parse::<f64>().ok()? / 100.0,
+        None => value.parse::<f64>().ok()?,
+    };
+    Some(alpha.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rgb(value: &str, red: u8, green: u8, blue: u8) {
+        let color = Color::parse(value).unwrap_or_else(|| panic!("{value} should be parsed"));
+        let expected = Color::from_rgb(red, green, blue);
+        for (channel, expected_channel) in [
+            (color.red, expected.red),
+            (color.green, expected.green),
+            (color.blue, expected.blue),
+        ] {
+            assert!(
+                (channel - expected_channel).abs() < 0.005,
+                "{value} should be rgb({red}, {green}, {blue}), found {color:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn parses_hex_colors() {
+        assert_rgb("#0f8", 0, 255, 136);
+        assert_rgb("#00ff88", 0, 255, 136);
+        assert_rgb("#00FF8880", 0, 255, 136);
+        assert_eq!(
+            Color::parse("#00ff8880").map(|color| color.is_opaque()),
+            Some(false)
+        );
+        assert_eq!(Color::parse("#00ff8"), None);
+        assert_eq!(Color::parse("#zzz"), None);
+    }
+
+    #[test]
+    fn parses_rgb_functions() {
+        assert_rgb("rgb(0, 128, 255)", 0, 128, 255);
+        assert_rgb("rgba(0, 128, 255, 0.5)", 0, 128, 255);
+        assert_rgb("rgb(0 128 255 / 50%)", 0, 128, 255);
+        assert_rgb("rgb(0% 50% 100%)", 0, 128, 255);
+        assert_eq!(
+            Color::parse("rgb(0 0 0 / 0.5)").map(|color| color.alpha),
+            Some(0.5)
+        );
+        assert_eq!(Color::parse("rgb(0 0)"), None);
+        assert_eq!(Color::parse("rgb(var(--red) 0 0)"), None);
+    }
+
+    #[test]
+    fn parses_hsl_functions() {
+        assert_rgb("hsl(0, 100%, 50%)", 255, 0, 0);
+        assert_rgb("hsl(120deg 100% 25%)", 0, 128, 0);
+        assert_rgb("hsla(0.5turn, 100%, 50%, 0.3)", 0, 255, 255);
+        assert_rgb("hsl(240 100% 50%)", 0, 0, 255);
+        assert_rgb("hsl(0 0% 100%)", 255, 255, 255);
+    }
+
+    #[test]
+    fn parses_named_colors() {
+        assert_rgb("rebeccapurple", 102, 51, 153);
+        assert_rgb("White", 255, 255, 255);
+        assert_eq!(
+            Color::parse("transparent").map(|color| color.alpha),
+            Some(0.0)
+        );
+        assert_eq!(Color::parse("inherit"), None);
+    }
+
+    #[test]
+    fn computes_contrast_ratios() {
+        let white = Color::from_rgb(255, 255, 255);
+        let gray = Color::from_rgb(118, 118, 118);
+        let ratio = gray.contrast_ratio(&white);
+        assert!((ratio - 4.54).abs() < 0.01, "{ratio}");
+        let translucent_black = Color {
+            alpha: 0.5,
+            ..Color::from_rgb(0, 0, 0)
+        };
+        let blended = translucent_black.blend_over(&white);
+        assert!(blended.is_opaque());
+        assert!((blended.red - 0.5).abs() < f64::EPSILON);
+    }
+}
@@ -28,6 +28,10 @@ pub struct CssParser {
     /// Allow comments to appear on incorrect lines in `.css` files
     #[partial(bpaf(hide))]
     pub allow_wrong_line_comments: bool,
+
+    /// Enables the directives and the functions of Tailwind CSS, such as `@apply` or `theme()`
+    #[partial(bpaf(hide))]
+    pub tailwind: bool,
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -62,6 +66,14 @@ pub struct CssFormatter {
 
     #[partial(bpaf(long("css-formatter-quote-style"), argument("double|single"), optional))]
     pub quote_style: QuoteStyle,
+
+    /// Whether to keep the rules that have a single declaration on one line, such as `a { color: red; }`. Defaults to false.
+    #[partial(bpaf(
+        long("css-formatter-collapse-single-declarations"),
+        argument("true|false"),
+        optional
+    ))]
+    pub collapse_single_declarations: bool,
 }
 
 impl Default for CssFormatter {
@@ -74,6 +86,7 @@ impl Default for CssFormatter {
             line_ending: Default::default(),
             line_width: Default::default(),
             quote_style: Default::default(),
+            collapse_single_declarations: Default::default(),
         }
     }
 }
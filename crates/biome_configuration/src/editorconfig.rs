@@ -8,13 +8,27 @@
 //! | indent_size          | indent_width |
 //! | end_of_line          | line_ending  |
 //! | max_line_length      | line_width   |
+//!
+//! `insert_final_newline` and `trim_trailing_whitespace` can only be `true`: Biome always inserts
+//! a final newline and removes the trailing whitespace.
+//!
+//! The `[*]` section sets the options of the formatter, the other sections are mapped to overrides,
+//! in the order of the file. The options of the configuration file take precedence over the ones
+//! of the `.editorconfig` file, except for the sections that match only some files,
+//! which take precedence over the options of the configuration file but not over its overrides:
+//!
+//! 1. the overrides of the configuration file;
+//! 2. the sections of `.editorconfig` other than `[*]`;
+//! 3. the options of the configuration file;
+//! 4. the `[*]` section of `.editorconfig`.
 
-use std::{collections::HashMap, str::FromStr};
+use std::str::FromStr;
 
+use biome_console::markup;
 use biome_deserialize::StringSet;
 use biome_diagnostics::Diagnostic;
 use biome_formatter::{LineEnding, LineWidth};
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 use serde::{Deserialize, Deserializer};
 
 use crate::{
@@ -33,8 +47,9 @@ pub fn parse_str(s: &str) -> serde_ini::de::Result<EditorConfig> {
 pub struct EditorConfig {
     #[serde(deserialize_with = "deserialize_bool_from_string")]
     root: bool,
+    /// The sections of the file, in their order
     #[serde(flatten)]
-    options: HashMap<String, EditorConfigOptions>,
+    options: IndexMap<String, EditorConfigOptions>,
 }
 
 impl EditorConfig {
@@ -47,16 +62,18 @@ impl EditorConfig {
         let diagnostics = self.validate();
 
         let mut config = PartialConfiguration {
-            formatter: self.options.remove("*").map(|o| o.to_biome()),
+            formatter: self.options.shift_remove("*").map(|o| o.to_biome()),
             ..Default::default()
         };
         let overrides: Vec<_> = self
             .options
             .into_iter()
-            .map(|(k, v)| OverridePattern {
-                include: Some(StringSet::new(IndexSet::from([k]))),
-                formatter: Some(v.to_biome_override()),
-                ..Default::default()
+            .filter_map(|(k, v)| {
+                Some(OverridePattern {
+                    include: Some(StringSet::new(to_biome_patterns(&k)?)),
+                    formatter: Some(v.to_biome_override()),
+                    ..Default::default()
+                })
             })
             .collect();
         config.overrides = Some(Overrides(overrides));
@@ -71,7 +88,7 @@ impl EditorConfig {
         errors.extend(
             self.options
                 .keys()
-                .filter(|k| k.contains('{') || k.contains('}'))
+                .filter(|k| to_biome_patterns(k).is_none())
                 .map(|pattern| EditorConfigValidationError::UnknownGlobPattern {
                     pattern: pattern.clone(),
                 }),
@@ -81,6 +98,71 @@ impl EditorConfig {
     }
 }
 
+/// Converts the glob pattern of a section to the patterns of an override,
+/// returns [None] when the pattern isn't supported.
+///
+/// - the lists of alternatives, such as `*.{js,ts}`, are expanded to a pattern per alternative;
+/// - the patterns without `/` match the files of any directory, they're prefixed by `**/`;
+/// - the patterns that start with `/` are relative to the directory of the file, the `/` is removed;
+/// - `**` that isn't a whole path component, such as in `lib/**.js`, is replaced by `*`.
+///
+/// The nested lists and the ranges of numbers, such as `{1..3}`, aren't supported.
+fn to_biome_patterns(pattern: &str) -> Option<IndexSet<String>> {
+    let patterns = expand_alternatives(pattern)?;
+
+    Some(
+        patterns
+            .into_iter()
+            .map(|pattern| {
+                let pattern = if let Some(pattern) = pattern.strip_prefix('/') {
+                    pattern.to_string()
+                } else if pattern.contains('/') {
+                    pattern
+                } else {
+                    format!("**/{pattern}")
+                };
+                replace_partial_recursive_wildcards(&pattern)
+            })
+            .collect(),
+    )
+}
+
+/// Expands the lists of alternatives of a pattern: `*.{js,ts}` becomes `*.js` and `*.ts`
+fn expand_alternatives(pattern: &str) -> Option<Vec<String>> {
+    let Some(start) = pattern.find('{') else {
+        return (!pattern.contains('}')).then(|| vec![pattern.to_string()]);
+    };
+    let end = start + pattern[start..].find('}')?;
+    let alternatives = &pattern[start + 1..end];
+    if alternatives.contains('{') || alternatives.contains("..") {
+        return None;
+    }
+
+    let prefix = &pattern[..start];
+    let mut patterns = Vec::new();
+    for suffix in expand_alternatives(&pattern[end + 1..])? {
+        for alternative in alternatives.split(',') {
+            patterns.push(format!("{prefix}{alternative}{suffix}"));
+        }
+    }
+    Some(patterns)
+}
+
+/// Replaces the `**` that match a part of a path component by `*`, which also matches `/`
+fn replace_partial_recursive_wildcards(pattern: &str) -> String {
+    pattern
+        .split('/')
+        .map(|component| {
+            if component == "**" {
+                component.to_string()
+            } else {
+                component.replace("**", "*")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 #[serde(default)]
 pub struct EditorConfigOptions {
@@ -93,6 +175,9 @@ pub struct EditorConfigOptions {
     // Not a biome option, but we need it to emit a diagnostic when this is set to false.
     #[serde(deserialize_with = "deserialize_optional_bool_from_string")]
     insert_final_newline: Option<bool>,
+    // Not a biome option, but we need it to emit a diagnostic when this is set to false.
+    #[serde(deserialize_with = "deserialize_optional_bool_from_string")]
+    trim_trailing_whitespace: Option<bool>,
 }
 
 impl EditorConfigOptions {
@@ -125,6 +210,13 @@ impl EditorConfigOptions {
                 message: "Biome always inserts a final newline.",
             });
         }
+        // `trim_trailing_whitespace = false` results in formatting behavior that is incompatible with biome
+        if let Some(false) = self.trim_trailing_whitespace {
+            errors.push(EditorConfigValidationError::Incompatible {
+                key: "trim_trailing_whitespace",
+                message: "Biome always removes the trailing whitespace.",
+            });
+        }
         errors
     }
 }
@@ -168,6 +260,10 @@ where
     D: Deserializer<'de>,
 {
     let s = String::deserialize(deserializer)?;
+    // `off` uses the line width of the configuration file, or the default one
+    if s == "off" {
+        return Ok(None);
+    }
     LineWidth::from_str(s.as_str())
         .map_err(serde::de::Error::custom)
         .map(Some)
@@ -210,6 +306,17 @@ impl Diagnostic for EditorConfigValidationError {
         }
         Ok(())
     }
+
+    fn message(&self, fmt: &mut biome_console::fmt::Formatter<'_>) -> std::io::Result<()> {
+        match self {
+            EditorConfigValidationError::Incompatible { key, message } => fmt.write_markup(
+                markup! {"The key "<Emphasis>{key}</Emphasis>" of .editorconfig is incompatible with Biome: "{message}},
+            ),
+            EditorConfigValidationError::UnknownGlobPattern { pattern } => fmt.write_markup(
+                markup! {"Biome doesn't know how to handle the glob pattern "<Emphasis>{pattern}</Emphasis>" of .editorconfig, the section is ignored."},
+            ),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -305,16 +412,92 @@ insert_final_newline = false
         let input = r#"
 root = true
 
-[{package.json,.travis.yml}]
+[file{1..3}.js]
 indent_style = space
 "#;
 
         let conf = parse_str(input).expect("Failed to parse editorconfig");
-        let (_, errors) = conf.to_biome();
+        let (conf, errors) = conf.to_biome();
         assert_eq!(errors.len(), 1);
         assert!(matches!(
             errors[0],
             EditorConfigValidationError::UnknownGlobPattern { .. }
         ));
+        let overrides = conf
+            .and_then(|conf| conf.overrides)
+            .expect("Overrides not set");
+        assert!(overrides.0.is_empty());
+    }
+
+    #[test]
+    fn should_emit_diagnostic_trim_trailing_whitespace() {
+        let input = r#"
+root = true
+
+[*]
+trim_trailing_whitespace = false
+"#;
+
+        let conf = parse_str(input).expect("Failed to parse editorconfig");
+        let (_, errors) = conf.to_biome();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            EditorConfigValidationError::Incompatible {
+                key: "trim_trailing_whitespace",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn should_convert_sections_to_biome_overrides() {
+        let input = r#"
+root = true
+
+[*]
+indent_style = tab
+max_line_length = off
+
+[*.{js,ts}]
+indent_style = space
+indent_size = 2
+
+[/lib/**.js]
+indent_size = 4
+
+[Makefile]
+max_line_length = 100
+"#;
+
+        let conf = parse_str(input).expect("Failed to parse editorconfig");
+        let (conf, errors) = conf.to_biome();
+        assert!(errors.is_empty());
+        let conf = conf.expect("Failed to convert editorconfig to biome");
+        let formatter = conf.formatter.expect("Formatter not set");
+        assert_eq!(formatter.indent_style, Some(PlainIndentStyle::Tab));
+        assert_eq!(formatter.line_width, None);
+
+        let overrides = conf.overrides.expect("Overrides not set").0;
+        let includes: Vec<Vec<&str>> = overrides
+            .iter()
+            .map(|pattern| {
+                pattern.include.as_ref().map_or(vec![], |include| {
+                    include.iter().map(String::as_str).collect()
+                })
+            })
+            .collect();
+        assert_eq!(
+            includes,
+            [
+                vec!["**/*.js", "**/*.ts"],
+                vec!["lib/*.js"],
+                vec!["**/Makefile"]
+            ]
+        );
+
+        let formatter = overrides[1].formatter.as_ref().expect("Formatter not set");
+        assert_eq!(formatter.indent_width, Some(4));
+        assert_eq!(formatter.indent_style, None);
     }
 }
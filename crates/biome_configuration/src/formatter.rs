@@ -1,6 +1,6 @@
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
-use biome_formatter::{AttributePosition, IndentStyle, LineEnding, LineWidth};
+use biome_formatter::{AttributePosition, IndentStyle, LineEnding, LineWidth, MaxEmptyLines};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -45,6 +45,15 @@ pub struct FormatterConfiguration {
     #[partial(bpaf(long("attribute-position"), argument("multiline|auto"), optional))]
     pub attribute_position: AttributePosition,
 
+    /// How many consecutive empty lines are kept. Defaults to 1.
+    #[partial(bpaf(long("max-empty-lines"), argument("NUMBER"), optional))]
+    pub max_empty_lines: MaxEmptyLines,
+
+    /// Whether to read the formatter options from the `.editorconfig` file of the project.
+    /// The options of the configuration file take precedence. Defaults to false.
+    #[partial(bpaf(hide))]
+    pub use_editorconfig: bool,
+
     /// A list of Unix shell style patterns. The formatter will ignore files/folders that will
     /// match these patterns.
     #[partial(bpaf(hide))]
@@ -71,6 +80,8 @@ impl PartialFormatterConfiguration {
             line_ending: self.line_ending.unwrap_or_default(),
             line_width: self.line_width.unwrap_or_default(),
             attribute_position: self.attribute_position.unwrap_or_default(),
+            max_empty_lines: self.max_empty_lines.unwrap_or_default(),
+            use_editorconfig: self.use_editorconfig.unwrap_or_default(),
             ignore: self.ignore.clone().unwrap_or_default(),
             include: self.include.clone().unwrap_or_default(),
         }
@@ -88,6 +99,8 @@ impl Default for FormatterConfiguration {
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
             attribute_position: AttributePosition::default(),
+            max_empty_lines: MaxEmptyLines::default(),
+            use_editorconfig: false,
             ignore: Default::default(),
             include: Default::default(),
         }
@@ -0,0 +1,112 @@
+use crate::PlainIndentStyle;
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{LineEnding, LineWidth};
+use biome_graphql_formatter::context::{
+    ArgumentsIndent, BlankLinesBetweenDefinitions, DescriptionQuoteStyle,
+};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to GraphQL files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(default, deny_unknown_fields))]
+pub struct GraphqlConfiguration {
+    /// Formatting options
+    #[partial(type, bpaf(external(partial_graphql_formatter), optional))]
+    pub formatter: GraphqlFormatter,
+
+    /// Linting options
+    #[partial(type, bpaf(external(partial_graphql_linter), optional))]
+    pub linter: GraphqlLinter,
+}
+
+/// Options that changes how the GraphQL formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct GraphqlFormatter {
+    /// Control the formatter for GraphQL files.
+    #[partial(bpaf(long("graphql-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The indent style applied to GraphQL files.
+    #[partial(bpaf(
+        long("graphql-formatter-indent-style"),
+        argument("tab|space"),
+        optional
+    ))]
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// The size of the indentation applied to GraphQL files. Default to 2.
+    #[partial(bpaf(long("graphql-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<u8>,
+
+    /// The type of line ending applied to GraphQL files.
+    #[partial(bpaf(
+        long("graphql-formatter-line-ending"),
+        argument("lf|crlf|cr"),
+        optional
+    ))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to GraphQL files. Defaults to 80.
+    #[partial(bpaf(long("graphql-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// How the arguments and the variable definitions that don't fit on a line are printed:
+    /// indented like the selections of a selection set, or aligned after the opening parenthesis. Defaults to "indent".
+    #[partial(bpaf(
+        long("graphql-formatter-arguments-indent"),
+        argument("indent|align"),
+        optional
+    ))]
+    pub arguments_indent: ArgumentsIndent,
+
+    /// The type of quotes used by the descriptions. Defaults to "preserve".
+    #[partial(bpaf(
+        long("graphql-formatter-description-quote-style"),
+        argument("preserve|block|double"),
+        optional
+    ))]
+    pub description_quote_style: DescriptionQuoteStyle,
+
+    /// Whether the definitions are separated by a blank line. Defaults to "preserve".
+    #[partial(bpaf(
+        long("graphql-formatter-blank-lines-between-definitions"),
+        argument("preserve|always|never"),
+        optional
+    ))]
+    pub blank_lines_between_definitions: BlankLinesBetweenDefinitions,
+}
+
+impl Default for GraphqlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            arguments_indent: Default::default(),
+            description_quote_style: Default::default(),
+            blank_lines_between_definitions: Default::default(),
+        }
+    }
+}
+
+/// Options that changes how the GraphQL linter behaves
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct GraphqlLinter {
+    /// The path of the schema that the operations are validated against,
+    /// relative to the configuration file.
+    /// The files with the extension `.json` contain the result of an introspection query,
+    /// the other files contain a document written in the Schema Definition Language.
+    #[partial(bpaf(long("graphql-linter-schema"), argument("PATH"), optional))]
+    pub schema: Option<String>,
+}
@@ -0,0 +1,64 @@
+use crate::PlainIndentStyle;
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{AttributePosition, LineEnding, LineWidth};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to HTML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(default, deny_unknown_fields))]
+pub struct HtmlConfiguration {
+    /// Formatting options
+    #[partial(type, bpaf(external(partial_html_formatter), optional))]
+    pub formatter: HtmlFormatter,
+}
+
+/// Options that changes how the HTML formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct HtmlFormatter {
+    /// Control the formatter for HTML files.
+    #[partial(bpaf(long("html-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The indent style applied to HTML files.
+    #[partial(bpaf(long("html-formatter-indent-style"), argument("tab|space"), optional))]
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// The size of the indentation applied to HTML files. Default to 2.
+    #[partial(bpaf(long("html-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<u8>,
+
+    /// The type of line ending applied to HTML files.
+    #[partial(bpaf(long("html-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to HTML files. Defaults to 80.
+    #[partial(bpaf(long("html-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// The attribute position style in HTML elements. Defaults to auto.
+    #[partial(bpaf(
+        long("html-formatter-attribute-position"),
+        argument("multiline|auto"),
+        optional
+    ))]
+    pub attribute_position: AttributePosition,
+}
+
+impl Default for HtmlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            attribute_position: Default::default(),
+        }
+    }
+}
@@ -2,10 +2,12 @@ use crate::PlainIndentStyle;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{AttributePosition, LineEnding, LineWidth, QuoteStyle};
 use biome_js_formatter::context::{
-    trailing_commas::TrailingCommas, ArrowParentheses, QuoteProperties, Semicolons,
+    trailing_commas::TrailingCommas, ArrowParentheses, DecoratorPlacement, ObjectWrap,
+    OperatorPosition, QuoteProperties, Semicolons, TernaryStyle,
 };
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Formatting options specific to the JavaScript files
 #[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -47,6 +49,10 @@ pub struct JavascriptFormatter {
     #[partial(bpaf(long("bracket-same-line"), argument("true|false"), optional))]
     pub bracket_same_line: bool,
 
+    /// Whether each JSX attribute goes on its own line. Defaults to false.
+    #[partial(bpaf(long("single-attribute-per-line"), argument("true|false"), optional))]
+    pub single_attribute_per_line: bool,
+
     /// Control the formatter for JavaScript (and its super languages) files.
     #[partial(bpaf(long("javascript-formatter-enabled"), argument("true|false"), optional))]
     pub enabled: bool,
@@ -99,6 +105,43 @@ pub struct JavascriptFormatter {
         optional
     ))]
     pub attribute_position: AttributePosition,
+
+    /// How to wrap object literals. Defaults to "preserve".
+    #[partial(bpaf(long("object-wrap"), argument("preserve|collapse"), optional))]
+    pub object_wrap: ObjectWrap,
+
+    /// Where to print the binary operators. Defaults to "end".
+    #[partial(bpaf(long("operator-position"), argument("start|end"), optional))]
+    pub operator_position: OperatorPosition,
+
+    /// Whether to format the JSDoc comments. Defaults to false.
+    #[partial(bpaf(long("format-jsdoc"), argument("true|false"), optional))]
+    pub format_jsdoc: bool,
+
+    /// Where to print decorators. Defaults to "preserve".
+    #[partial(bpaf(
+        long("decorator-placement"),
+        argument("preserve|own-line|inline"),
+        optional
+    ))]
+    pub decorator_placement: DecoratorPlacement,
+
+    /// How to lay out the nested ternaries. Defaults to "nested".
+    #[partial(bpaf(long("ternary-style"), argument("nested|chain"), optional))]
+    pub ternary_style: TernaryStyle,
+
+    /// Minimum number of calls to break a member chain. Defaults to 0.
+    #[partial(bpaf(long("member-chain-min-calls"), argument("NUMBER"), optional))]
+    pub member_chain_min_calls: u8,
+
+    /// Keep the member chains that fit inline. Defaults to false.
+    #[partial(bpaf(long("inline-short-member-chains"), argument("true|false"), optional))]
+    pub inline_short_member_chains: bool,
+
+    /// The tags of the template literals whose content is formatted with the formatter of another language,
+    /// such as the CSS of `` styled.div`color: red;` ``.
+    #[partial(bpaf(hide))]
+    pub embedded_language_tags: EmbeddedLanguageTags,
 }
 
 impl PartialJavascriptFormatter {
@@ -113,6 +156,7 @@ impl PartialJavascriptFormatter {
             arrow_parentheses: self.arrow_parentheses.unwrap_or_default(),
             bracket_spacing: self.bracket_spacing.unwrap_or_default(),
             bracket_same_line: self.bracket_same_line.unwrap_or_default(),
+            single_attribute_per_line: self.single_attribute_per_line.unwrap_or_default(),
             indent_style: self.indent_style,
             indent_size: self.indent_size,
             indent_width: self.indent_width,
@@ -120,6 +164,14 @@ impl PartialJavascriptFormatter {
             line_width: self.line_width,
             quote_style: self.quote_style.unwrap_or_default(),
             attribute_position: self.attribute_position.unwrap_or_default(),
+            object_wrap: self.object_wrap.unwrap_or_default(),
+            operator_position: self.operator_position.unwrap_or_default(),
+            format_jsdoc: self.format_jsdoc.unwrap_or_default(),
+            decorator_placement: self.decorator_placement.unwrap_or_default(),
+            ternary_style: self.ternary_style.unwrap_or_default(),
+            member_chain_min_calls: self.member_chain_min_calls.unwrap_or_default(),
+            inline_short_member_chains: self.inline_short_member_chains.unwrap_or_default(),
+            embedded_language_tags: self.embedded_language_tags.clone().unwrap_or_default(),
         }
     }
 }
@@ -136,6 +188,7 @@ impl Default for JavascriptFormatter {
             arrow_parentheses: Default::default(),
             bracket_spacing: true,
             bracket_same_line: Default::default(),
+            single_attribute_per_line: Default::default(),
             indent_style: Default::default(),
             indent_size: Default::default(),
             indent_width: Default::default(),
@@ -143,6 +196,67 @@ impl Default for JavascriptFormatter {
             line_width: Default::default(),
             quote_style: Default::default(),
             attribute_position: Default::default(),
+            object_wrap: Default::default(),
+            operator_position: Default::default(),
+            format_jsdoc: Default::default(),
+            decorator_placement: Default::default(),
+            ternary_style: Default::default(),
+            member_chain_min_calls: Default::default(),
+            inline_short_member_chains: Default::default(),
+            embedded_language_tags: Default::default(),
         }
     }
 }
+
+/// The tags of the template literals whose content is formatted as another language.
+///
+/// A tag is either the code of the tag, such as `css`, or a pattern where `*` matches
+/// any sequence of characters, such as `styled.*`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct EmbeddedLanguageTags {
+    /// The tags of the CSS templates. Defaults to `css`, `keyframes`, `createGlobalStyle`,
+    /// `injectGlobal`, `styled.*` and `styled(*)`.
+    pub css: Vec<String>,
+
+    /// The tags of the HTML templates. Defaults to `html`.
+    pub html: Vec<String>,
+
+    /// The tags of the GraphQL templates. Defaults to `graphql` and `gql`.
+    pub graphql: Vec<String>,
+}
+
+impl Default for EmbeddedLanguageTags {
+    fn default() -> Self {
+        Self {
+            css: [
+                "css",
+                "keyframes",
+                "createGlobalStyle",
+                "injectGlobal",
+                "styled.*",
+                "styled(*)",
+            ]
+            .map(String::from)
+            .to_vec(),
+            html: vec!["html".to_string()],
+            graphql: ["graphql", "gql"].map(String::from).to_vec(),
+        }
+    }
+}
+
+impl FromStr for EmbeddedLanguageTags {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl biome_deserialize::Merge for EmbeddedLanguageTags {
+    fn merge_with(&mut self, other: Self) {
+        // The tags of a language replace the default ones, so they can't be combined.
+        *self = other;
+    }
+}
@@ -6,7 +6,8 @@ use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use bpaf::Bpaf;
 pub use formatter::{
-    partial_javascript_formatter, JavascriptFormatter, PartialJavascriptFormatter,
+    partial_javascript_formatter, EmbeddedLanguageTags, JavascriptFormatter,
+    PartialJavascriptFormatter,
 };
 use serde::{Deserialize, Serialize};
 
@@ -38,11 +39,66 @@ pub struct JavascriptConfiguration {
     pub organize_imports: JavascriptOrganizeImports,
 }
 
-#[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
 #[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
 #[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
-#[partial(serde(default, deny_unknown_fields))]
-pub struct JavascriptOrganizeImports {}
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct JavascriptOrganizeImports {
+    /// The groups of imports, in the order in which they are sorted.
+    ///
+    /// Each group is a list of matchers. A matcher is either a predefined group,
+    /// such as `:NODE:`, `:BUN:`, `:URL:`, `:PACKAGE:`, `:ALIAS:` or `:PATH:`,
+    /// or a pattern of import sources where `*` matches any sequence of characters, such as `*.css`.
+    /// An import belongs to the first group that matches its source.
+    /// The imports that don't match any group are placed after all the groups.
+    #[partial(bpaf(hide))]
+    pub groups: ImportGroups,
+
+    /// Requires a blank line between two groups of imports, and removes the blank lines
+    /// between the imports of the same group.
+    #[partial(bpaf(hide))]
+    pub blank_lines_between_groups: bool,
+
+    /// Merges the imports of the same source in a single import. Defaults to `true`.
+    #[partial(bpaf(hide))]
+    pub merge_imports: bool,
+
+    /// Merges the type-only imports in the value imports of the same source,
+    /// as inline `type` specifiers. Defaults to `false`.
+    #[partial(bpaf(hide))]
+    pub merge_type_imports: bool,
+}
+
+impl Default for JavascriptOrganizeImports {
+    fn default() -> Self {
+        Self {
+            groups: Default::default(),
+            blank_lines_between_groups: false,
+            merge_imports: true,
+            merge_type_imports: false,
+        }
+    }
+}
+
+/// The groups of imports used to organize the imports.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ImportGroups(pub Vec<Vec<String>>);
+
+impl FromStr for ImportGroups {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+impl biome_deserialize::Merge for ImportGroups {
+    fn merge_with(&mut self, other: Self) {
+        // The groups are ordered, so they can't be combined.
+        *self = other;
+    }
+}
 
 /// Options that changes how the JavaScript parser behaves
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -1,9 +1,11 @@
 use crate::PlainIndentStyle;
+use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge, Partial};
 use biome_formatter::{LineEnding, LineWidth};
-use biome_json_formatter::context::TrailingCommas;
+use biome_json_formatter::context::{ArrayWrap, TrailingCommas};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 /// Options applied to JSON files
 #[derive(Clone, Debug, Default, Deserialize, Eq, Partial, PartialEq, Serialize)]
@@ -18,6 +20,50 @@ pub struct JsonConfiguration {
     /// Formatting options
     #[partial(type, bpaf(external(partial_json_formatter), optional))]
     pub formatter: JsonFormatter,
+
+    /// The JSON schemas that validate the files matching their patterns
+    #[partial(bpaf(hide))]
+    pub schemas: JsonSchemas,
+}
+
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct JsonSchemas(#[bpaf(hide)] pub Vec<JsonSchemaMapping>);
+
+impl FromStr for JsonSchemas {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
+}
+
+/// A JSON schema and the files that it validates
+#[derive(
+    Bpaf, Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize,
+)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+pub struct JsonSchemaMapping {
+    /// A list of Unix shell style patterns. The schema validates the files that match these
+    /// patterns.
+    #[bpaf(hide)]
+    pub include: StringSet,
+
+    /// The path of the schema, relative to the configuration file.
+    #[bpaf(hide)]
+    pub path: String,
+}
+
+impl FromStr for JsonSchemaMapping {
+    type Err = String;
+
+    fn from_str(_s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::default())
+    }
 }
 
 /// Options that changes how the JSON parser behaves
@@ -68,6 +114,22 @@ pub struct JsonFormatter {
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
     #[partial(bpaf(long("json-formatter-trailing-commas"), argument("none|all"), optional))]
     pub trailing_commas: Option<TrailingCommas>,
+
+    /// How to wrap arrays. Defaults to "auto".
+    #[partial(bpaf(
+        long("json-formatter-array-wrap"),
+        argument("auto|fill|one-per-line"),
+        optional
+    ))]
+    pub array_wrap: Option<ArrayWrap>,
+
+    /// Whether to sort the keys of `package.json`.
+    #[partial(bpaf(
+        long("json-formatter-sort-package-json"),
+        argument("true|false"),
+        optional
+    ))]
+    pub sort_package_json: Option<bool>,
 }
 
 impl PartialJsonFormatter {
@@ -80,6 +142,8 @@ impl PartialJsonFormatter {
             line_ending: self.line_ending,
             line_width: self.line_width,
             trailing_commas: self.trailing_commas,
+            array_wrap: self.array_wrap,
+            sort_package_json: self.sort_package_json,
         }
     }
 }
@@ -94,6 +158,8 @@ impl Default for JsonFormatter {
             line_ending: Default::default(),
             line_width: Default::default(),
             trailing_commas: Default::default(),
+            array_wrap: Default::default(),
+            sort_package_json: Default::default(),
         }
     }
 }
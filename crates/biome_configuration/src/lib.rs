@@ -7,12 +7,17 @@ pub mod diagnostics;
 pub mod editorconfig;
 pub mod formatter;
 pub mod generated;
+pub mod graphql;
+pub mod html;
 pub mod javascript;
 pub mod json;
 pub mod linter;
+pub mod markdown;
 pub mod organize_imports;
 mod overrides;
+pub mod toml;
 pub mod vcs;
+pub mod yaml;
 
 pub use crate::diagnostics::CantLoadExtendFile;
 pub use crate::diagnostics::ConfigurationDiagnostic;
@@ -30,18 +35,31 @@ pub use formatter::{
     partial_formatter_configuration, FormatterConfiguration, PartialFormatterConfiguration,
     PlainIndentStyle,
 };
+pub use graphql::{
+    partial_graphql_configuration, GraphqlConfiguration, GraphqlFormatter, GraphqlLinter,
+    PartialGraphqlConfiguration, PartialGraphqlFormatter, PartialGraphqlLinter,
+};
+pub use html::{
+    partial_html_configuration, HtmlConfiguration, HtmlFormatter, PartialHtmlConfiguration,
+    PartialHtmlFormatter,
+};
 pub use javascript::{
-    partial_javascript_configuration, JavascriptConfiguration, JavascriptFormatter,
-    PartialJavascriptConfiguration, PartialJavascriptFormatter,
+    partial_javascript_configuration, EmbeddedLanguageTags, ImportGroups, JavascriptConfiguration,
+    JavascriptFormatter, JavascriptOrganizeImports, PartialJavascriptConfiguration,
+    PartialJavascriptFormatter,
 };
 pub use json::{
-    partial_json_configuration, JsonConfiguration, JsonFormatter, PartialJsonConfiguration,
-    PartialJsonFormatter,
+    partial_json_configuration, JsonConfiguration, JsonFormatter, JsonSchemaMapping, JsonSchemas,
+    PartialJsonConfiguration, PartialJsonFormatter,
 };
 pub use linter::{
     partial_linter_configuration, LinterConfiguration, PartialLinterConfiguration,
     RuleConfiguration, RulePlainConfiguration, RuleWithOptions, Rules,
 };
+pub use markdown::{
+    partial_markdown_configuration, MarkdownConfiguration, MarkdownFormatter,
+    PartialMarkdownConfiguration, PartialMarkdownFormatter,
+};
 pub use overrides::{
     OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, OverridePattern, Overrides,
@@ -50,6 +68,14 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::num::NonZeroU64;
 use std::path::PathBuf;
+pub use toml::{
+    partial_toml_configuration, PartialTomlConfiguration, PartialTomlFormatter, TomlConfiguration,
+    TomlFormatter,
+};
+pub use yaml::{
+    partial_yaml_configuration, PartialYamlConfiguration, PartialYamlFormatter, YamlConfiguration,
+    YamlFormatter,
+};
 
 /// Limit the size of files to 1.0 MiB by default
 pub const DEFAULT_FILE_SIZE_LIMIT: NonZeroU64 =
@@ -102,6 +128,26 @@ pub struct Configuration {
     #[partial(type, bpaf(external(partial_css_configuration), optional, hide))]
     pub css: CssConfiguration,
 
+    /// Specific configuration for the GraphQL language
+    #[partial(type, bpaf(external(partial_graphql_configuration), optional, hide))]
+    pub graphql: GraphqlConfiguration,
+
+    /// Specific configuration for the YAML language
+    #[partial(type, bpaf(external(partial_yaml_configuration), optional, hide))]
+    pub yaml: YamlConfiguration,
+
+    /// Specific configuration for the TOML language
+    #[partial(type, bpaf(external(partial_toml_configuration), optional, hide))]
+    pub toml: TomlConfiguration,
+
+    /// Specific configuration for the Markdown language
+    #[partial(type, bpaf(external(partial_markdown_configuration), optional, hide))]
+    pub markdown: MarkdownConfiguration,
+
+    /// Specific configuration for the HTML language
+    #[partial(type, bpaf(external(partial_html_configuration), optional, hide))]
+    pub html: HtmlConfiguration,
+
     /// A list of paths to other JSON files, used to extends the current configuration.
     #[partial(bpaf(hide))]
     pub extends: StringSet,
@@ -8,9 +8,13 @@ use biome_css_analyze::options::*;
 use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic};
 use biome_deserialize_macros::{Deserializable, Merge};
 use biome_diagnostics::{Category, Severity};
+use biome_graphql_analyze::options::*;
 use biome_js_analyze::options::*;
 use biome_json_analyze::options::*;
+use biome_markdown_analyze::options::*;
 use biome_rowan::TextRange;
+use biome_toml_analyze::options::*;
+use biome_yaml_analyze::options::*;
 use indexmap::IndexSet;
 #[cfg(feature = "schema")]
 use schemars::JsonSchema;
@@ -496,6 +500,35 @@ impl Rules {
         }
         enabled_rules.difference(&disabled_rules).copied().collect()
     }
+    #[doc = r" It returns the rules that are explicitly disabled by the configuration."]
+    pub fn as_disabled_rules(&self) -> IndexSet<RuleFilter> {
+        let mut disabled_rules = IndexSet::new();
+        if let Some(group) = self.a11y.as_ref() {
+            disabled_rules.extend(&group.get_disabled_rules());
+        }
+        if let Some(group) = self.complexity.as_ref() {
+            disabled_rules.extend(&group.get_disabled_rules());
+        }
+        if let Some(group) = self.correctness.as_ref() {
+            disabled_rules.extend(&group.get_disabled_rules());
+        }
+        if let Some(group) = self.nursery.as_ref() {
+            disabled_rules.extend(&group.get_disabled_rules());
+        }
+        if let Some(group) = self.performance.as_ref() {
+            disabled_rules.extend(&group.get_disabled_rules());
+        }
+        if let Some(group) = self.security.as_ref() {
+            disabled_rules.extend(&group.get_disabled_rules());
+        }
+        if let Some(group) = self.style.as_ref() {
+            disabled_rules.extend(&group.get_disabled_rules());
+        }
+        if let Some(group) = self.suspicious.as_ref() {
+            disabled_rules.extend(&group.get_disabled_rules());
+        }
+        disabled_rules
+    }
 }
 #[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, Merge, PartialEq, Serialize)]
 #[deserializable(with_validator)]
@@ -3302,9 +3335,21 @@ pub struct Nursery {
     #[doc = r" It enables ALL rules for this group."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub all: Option<bool>,
+    #[doc = "Disallow asynchronous actions in Vue computed properties."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_async_in_computed: Option<RuleConfiguration<NoAsyncInComputed>>,
+    #[doc = "Disallow await inside loops."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_await_in_loop: Option<RuleConfiguration<NoAwaitInLoop>>,
     #[doc = "WIP: This rule hasn't been implemented yet."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_color_invalid_hex: Option<RuleConfiguration<NoColorInvalidHex>>,
+    #[doc = "Disallow conditional logic in tests."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_conditional_tests: Option<RuleConfiguration<NoConditionalTests>>,
+    #[doc = "Disallow the compiler options that contradict each other."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_conflicting_tsconfig_options: Option<RuleConfiguration<NoConflictingTsconfigOptions>>,
     #[doc = "Disallow the use of console."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_console: Option<RuleConfiguration<NoConsole>>,
@@ -3314,6 +3359,21 @@ pub struct Nursery {
     #[doc = "Disallow CSS empty blocks."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_css_empty_block: Option<RuleConfiguration<NoCssEmptyBlock>>,
+    #[doc = "Disallow the use of deprecated APIs."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deprecated_apis: Option<RuleConfiguration<NoDeprecatedApis>>,
+    #[doc = "Disallow the compiler options deprecated by TypeScript."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deprecated_tsconfig_options: Option<RuleConfiguration<NoDeprecatedTsconfigOptions>>,
+    #[doc = "Disallow the use of the fields and the enum values that are deprecated."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_deprecated_usage: Option<RuleConfiguration<NoDeprecatedUsage>>,
+    #[doc = "Disallow direct mutations of props, state, and store values in React components and hooks."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_direct_state_mutation: Option<RuleConfiguration<NoDirectStateMutation>>,
+    #[doc = "Disallow direct use of document.cookie."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_document_cookie: Option<RuleConfiguration<NoDocumentCookie>>,
     #[doc = "Disallow using a callback in asynchronous tests and hooks."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_done_callback: Option<RuleConfiguration<NoDoneCallback>>,
@@ -3326,19 +3386,62 @@ pub struct Nursery {
     #[doc = "Disallow duplicate names within font families."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_font_names: Option<RuleConfiguration<NoDuplicateFontNames>>,
+    #[doc = "Disallow the fragments whose name is already used by another fragment of the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_fragment_name: Option<RuleConfiguration<NoDuplicateFragmentName>>,
     #[doc = "Disallow two keys with the same name inside a JSON object."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_json_keys: Option<RuleConfiguration<NoDuplicateJsonKeys>>,
+    #[doc = "Disallow the operations whose name is already used by another operation of the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_operation_name: Option<RuleConfiguration<NoDuplicateOperationName>>,
+    #[doc = "Disallow the packages listed both in dependencies and devDependencies."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_package_json_dependencies:
+        Option<RuleConfiguration<NoDuplicatePackageJsonDependencies>>,
     #[doc = "Disallow duplicate selectors within keyframe blocks."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_duplicate_selectors_keyframe_block:
         Option<RuleConfiguration<NoDuplicateSelectorsKeyframeBlock>>,
+    #[doc = "Disallow tests and test suites with the same title in the same test suite."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_test_titles: Option<RuleConfiguration<NoDuplicateTestTitles>>,
+    #[doc = "Disallow the keys and the tables that are defined more than once in a TOML document."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_duplicate_toml_keys: Option<RuleConfiguration<NoDuplicateTomlKeys>>,
     #[doc = "Disallow variables from evolving into any type through reassignments."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_evolving_any: Option<RuleConfiguration<NoEvolvingAny>>,
+    #[doc = "Disallow rules that are nested too deeply."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_excessive_nesting_depth: Option<RuleConfiguration<NoExcessiveNestingDepth>>,
+    #[doc = "Enforce a maximum number of parameters in a function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_excessive_parameters: Option<RuleConfiguration<NoExcessiveParameters>>,
+    #[doc = "Enforce a maximum number of lines in a function."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_excessively_long_functions: Option<RuleConfiguration<NoExcessivelyLongFunctions>>,
     #[doc = "Disallow to use unnecessary callback on flatMap."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_flat_map_identity: Option<RuleConfiguration<NoFlatMapIdentity>>,
+    #[doc = "Disallow the use of __dirname and __filename in ECMAScript modules."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_global_dirname_filename: Option<RuleConfiguration<NoGlobalDirnameFilename>>,
+    #[doc = "Disallow hardcoded colors in declarations."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_hardcoded_colors: Option<RuleConfiguration<NoHardcodedColors>>,
+    #[doc = "Prevent usage of \\<head> element in a Next.js project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_head_element: Option<RuleConfiguration<NoHeadElement>>,
+    #[doc = "Disallow selectors whose specificity is higher than a maximum."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_high_specificity_selectors: Option<RuleConfiguration<NoHighSpecificitySelectors>>,
+    #[doc = "Prevent usage of \\<img> element in a Next.js project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_img_element: Option<RuleConfiguration<NoImgElement>>,
+    #[doc = "Disallow shorthand type conversions."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_implicit_coercion: Option<RuleConfiguration<NoImplicitCoercion>>,
     #[doc = "Disallow invalid !important within keyframe declarations"]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_important_in_keyframe: Option<RuleConfiguration<NoImportantInKeyframe>>,
@@ -3346,21 +3449,75 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_invalid_position_at_import_rule:
         Option<RuleConfiguration<NoInvalidPositionAtImportRule>>,
+    #[doc = "Disallow invalid utilities in the @apply directive of Tailwind CSS."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_tailwind_apply: Option<RuleConfiguration<NoInvalidTailwindApply>>,
+    #[doc = "Disallow the date-times that don't exist or that aren't written in the TOML format."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_invalid_toml_dates: Option<RuleConfiguration<NoInvalidTomlDates>>,
+    #[doc = "Disallow the values that don't match the JSON Schema of the document."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_json_schema_violations: Option<RuleConfiguration<NoJsonSchemaViolations>>,
+    #[doc = "Disallow values that may render 0 or NaN in JSX conditional rendering."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_leaked_conditional_rendering: Option<RuleConfiguration<NoLeakedConditionalRendering>>,
+    #[doc = "Disallow magic numbers."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_magic_numbers: Option<RuleConfiguration<NoMagicNumbers>>,
     #[doc = "Checks that the assertion function, for example expect, is placed inside an it() function call."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_misplaced_assertion: Option<RuleConfiguration<NoMisplacedAssertion>>,
     #[doc = "Forbid the use of Node.js builtin modules."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_nodejs_modules: Option<RuleConfiguration<NoNodejsModules>>,
+    #[doc = "Disallow updating a variable of a Svelte component that the markup uses, when the variable isn't reactive."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_non_reactive_updates: Option<RuleConfiguration<NoNonReactiveUpdates>>,
+    #[doc = "Disallow imports of the internal files of the other packages of the workspace."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_package_internal_imports: Option<RuleConfiguration<NoPackageInternalImports>>,
     #[doc = "Prevents React-specific JSX properties from being used."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_react_specific_props: Option<RuleConfiguration<NoReactSpecificProps>>,
+    #[doc = "Disallow story names that are equal to the names that Storybook generates."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_redundant_story_name: Option<RuleConfiguration<NoRedundantStoryName>>,
     #[doc = "Disallow specified modules when loaded by import or require."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_restricted_imports: Option<RuleConfiguration<NoRestrictedImports>>,
+    #[doc = "Disallow destructuring props in Solid components."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_solid_destructured_props: Option<RuleConfiguration<NoSolidDestructuredProps>>,
+    #[doc = "Disallow reading Solid signals without calling them."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_solid_uncalled_signals: Option<RuleConfiguration<NoSolidUncalledSignals>>,
+    #[doc = "Disallow reading reactive values outside a tracking scope in Solid components."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_solid_untracked_reads: Option<RuleConfiguration<NoSolidUntrackedReads>>,
+    #[doc = "Disallow using expect outside of tests."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_standalone_expect: Option<RuleConfiguration<NoStandaloneExpect>>,
+    #[doc = "Disallow the mouse and keyboard event handlers on the static elements without a role."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_static_element_interactions: Option<RuleConfiguration<NoStaticElementInteractions>>,
+    #[doc = "Prevent the usage of synchronous scripts."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_sync_scripts: Option<RuleConfiguration<NoSyncScripts>>,
+    #[doc = "Disallow class names that aren't declared by the stylesheets of the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_undeclared_class_names: Option<RuleConfiguration<NoUndeclaredClassNames>>,
     #[doc = "Disallow the use of dependencies that aren't specified in the package.json."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_undeclared_dependencies: Option<RuleConfiguration<NoUndeclaredDependencies>>,
+    #[doc = "Disallow the reference links whose label doesn't match a link definition."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_undefined_markdown_references: Option<RuleConfiguration<NoUndefinedMarkdownReferences>>,
+    #[doc = "Disallow the arguments that aren't defined by the field or the directive."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_argument: Option<RuleConfiguration<NoUnknownArgument>>,
+    #[doc = "Disallow the selection of fields that don't exist on the type."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_field: Option<RuleConfiguration<NoUnknownField>>,
     #[doc = "Disallow unknown CSS value functions."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_function: Option<RuleConfiguration<NoUnknownFunction>>,
@@ -3374,50 +3531,190 @@ pub struct Nursery {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_selector_pseudo_element:
         Option<RuleConfiguration<NoUnknownSelectorPseudoElement>>,
+    #[doc = "Disallow the compiler options that TypeScript doesn't know."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unknown_tsconfig_options: Option<RuleConfiguration<NoUnknownTsconfigOptions>>,
     #[doc = "Disallow unknown CSS units."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unknown_unit: Option<RuleConfiguration<NoUnknownUnit>>,
     #[doc = "Disallow unmatchable An+B selectors."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_unmatchable_anb_selector: Option<RuleConfiguration<NoUnmatchableAnbSelector>>,
+    #[doc = "Disallow conditions that are always truthy, always falsy, or never nullish according to their types."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unnecessary_condition: Option<RuleConfiguration<NoUnnecessaryCondition>>,
+    #[doc = "Disallow the configuration files extended by extends that don't exist."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unresolved_tsconfig_extends: Option<RuleConfiguration<NoUnresolvedTsconfigExtends>>,
+    #[doc = "Disallow type assertions that widen a value or cross unrelated types."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unsafe_type_assertion: Option<RuleConfiguration<NoUnsafeTypeAssertion>>,
+    #[doc = "Disallow defining components inside other components."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unstable_nested_components: Option<RuleConfiguration<NoUnstableNestedComponents>>,
+    #[doc = "Disallow props that are declared in the props type of a component but never used."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_component_props: Option<RuleConfiguration<NoUnusedComponentProps>>,
+    #[doc = "Disallow the ref attributes of a Vue template that are never used."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_refs: Option<RuleConfiguration<biome_html_analyze::options::NoUnusedRefs>>,
+    #[doc = "Disallow class and identifier selectors that aren't referenced by the markup of the project."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_selectors: Option<RuleConfiguration<NoUnusedSelectors>>,
+    #[doc = "Disallow discarding the unsubscriber of a Svelte store subscription."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_unused_store_subscriptions: Option<RuleConfiguration<NoUnusedStoreSubscriptions>>,
     #[doc = "Disallow unnecessary concatenation of string or template literals."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_useless_string_concat: Option<RuleConfiguration<NoUselessStringConcat>>,
+    #[doc = "Disallow useless undefined."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_useless_undefined: Option<RuleConfiguration<NoUselessUndefined>>,
     #[doc = "Disallow initializing variables to undefined."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_useless_undefined_initialization:
         Option<RuleConfiguration<NoUselessUndefinedInitialization>>,
+    #[doc = "Disallow vendor prefixes on properties and values that have a standard equivalent."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_vendor_prefix: Option<RuleConfiguration<NoVendorPrefix>>,
+    #[doc = "Enforce the length units allowed in the declarations."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_allowed_length_units: Option<RuleConfiguration<UseAllowedLengthUnits>>,
+    #[doc = "Enforce that ARIA properties are supported by the role of the element."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_aria_props_supported_by_role: Option<RuleConfiguration<UseAriaPropsSupportedByRole>>,
     #[doc = "Disallow Array constructors."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_array_literals: Option<RuleConfiguration<UseArrayLiterals>>,
+    #[doc = "Use at() instead of integer index access."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_at_index: Option<RuleConfiguration<UseAtIndex>>,
+    #[doc = "Enforce awaiting the interactions of the play functions of stories."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_await_story_interactions: Option<RuleConfiguration<UseAwaitStoryInteractions>>,
+    #[doc = "Enforce that await is only applied to values that may be thenables."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_await_thenable: Option<RuleConfiguration<UseAwaitThenable>>,
+    #[doc = "Enforce a sufficient contrast between the text color and the background color of the inline styles."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_color_contrast: Option<RuleConfiguration<UseColorContrast>>,
     #[doc = "Enforce the use of new for all builtins, except String, Number, Boolean, Symbol and BigInt."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_consistent_builtin_instantiation:
         Option<RuleConfiguration<UseConsistentBuiltinInstantiation>>,
+    #[doc = "Enforce a consistent use of curly braces around the string props and the string children of JSX elements."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_curly_braces: Option<RuleConfiguration<UseConsistentCurlyBraces>>,
+    #[doc = "Enforce using the same function, it or test, to declare tests."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_consistent_test_function: Option<RuleConfiguration<UseConsistentTestFunction>>,
+    #[doc = "Enforce naming conventions for the class names, the keyframes names, and the custom properties."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_css_naming_convention: Option<RuleConfiguration<UseCssNamingConvention>>,
     #[doc = "Require the default clause in switch statements."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_default_switch_clause: Option<RuleConfiguration<UseDefaultSwitchClause>>,
+    #[doc = "Enforce declaring defineProps and defineEmits at the top of Vue \\<script setup>."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_define_props_order: Option<RuleConfiguration<UseDefinePropsOrder>>,
+    #[doc = "Require explicit return types on functions and class methods."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_explicit_function_return_type: Option<RuleConfiguration<UseExplicitFunctionReturnType>>,
     #[doc = "Enforce explicitly comparing the length, size, byteLength or byteOffset property of a value."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_explicit_length_check: Option<RuleConfiguration<UseExplicitLengthCheck>>,
+    #[doc = "Require or disallow the accessibility modifiers on the class members."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_explicit_member_accessibility:
+        Option<RuleConfiguration<UseExplicitMemberAccessibility>>,
     #[doc = "Elements with an interactive role and interaction handlers must be focusable."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_focusable_interactive: Option<RuleConfiguration<UseFocusableInteractive>>,
     #[doc = "Disallow a missing generic family keyword within font families."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_generic_font_names: Option<RuleConfiguration<UseGenericFontNames>>,
+    #[doc = "Ensure the preconnect attribute is used when using Google Fonts."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_google_font_preconnect: Option<RuleConfiguration<UseGoogleFontPreconnect>>,
+    #[doc = "Enforce naming conventions for the names defined in GraphQL documents."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_graphql_naming_convention: Option<RuleConfiguration<UseGraphqlNamingConvention>>,
     #[doc = "Disallows package private imports."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_import_restrictions: Option<RuleConfiguration<UseImportRestrictions>>,
+    #[doc = "Enforce the use of logical properties and values instead of physical ones."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_logical_properties: Option<RuleConfiguration<UseLogicalProperties>>,
+    #[doc = "Require the operations to have a name."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_named_operation: Option<RuleConfiguration<UseNamedOperation>>,
+    #[doc = "Enforce the use of numeric separators in long numeric literals."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_numeric_separators: Option<RuleConfiguration<UseNumericSeparators>>,
+    #[doc = "Prefer object spread over Object.assign() when constructing a new object."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_object_spread: Option<RuleConfiguration<UseObjectSpread>>,
+    #[doc = "Require private class members that are never reassigned to be marked as readonly."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_readonly_class_members: Option<RuleConfiguration<UseReadonlyClassMembers>>,
+    #[doc = "Require the arguments that the field or the directive can't omit."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_required_arguments: Option<RuleConfiguration<UseRequiredArguments>>,
+    #[doc = "Require the fields of package.json that describe how a package can be used."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_required_package_json_fields: Option<RuleConfiguration<UseRequiredPackageJsonFields>>,
+    #[doc = "Enforce the use of shorthand properties instead of their four longhand properties."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_shorthand_properties: Option<RuleConfiguration<UseShorthandProperties>>,
     #[doc = "Enforce the sorting of CSS utility classes."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_sorted_classes: Option<RuleConfiguration<UseSortedClasses>>,
+    #[doc = "Enforce the order of the keys of the objects."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_keys: Option<RuleConfiguration<UseSortedKeys>>,
+    #[doc = "Enforce a conventional order of the fields of package.json, and sort the dependencies."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_package_json_keys: Option<RuleConfiguration<UseSortedPackageJsonKeys>>,
+    #[doc = "Enforce the order of the declarations of a block."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_properties: Option<RuleConfiguration<UseSortedProperties>>,
+    #[doc = "Enforce the order of the keys of the TOML tables."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_toml_keys: Option<RuleConfiguration<UseSortedTomlKeys>>,
+    #[doc = "Enforce the order of the keys of the YAML mappings."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_sorted_yaml_keys: Option<RuleConfiguration<UseSortedYamlKeys>>,
+    #[doc = "Require a default export in stories files."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_story_default_export: Option<RuleConfiguration<UseStoryDefaultExport>>,
+    #[doc = "Enforce PascalCase for the names of the stories."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_story_name_pascal_case: Option<RuleConfiguration<UseStoryNamePascalCase>>,
     #[doc = "Require new when throwing an error."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_throw_new_error: Option<RuleConfiguration<UseThrowNewError>>,
     #[doc = "Require all regex literals to be declared at the top level."]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub use_top_level_regex: Option<RuleConfiguration<UseTopLevelRegex>>,
+    #[doc = "Prefer the path aliases of the tsconfig.json over deep relative imports."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_tsconfig_path_aliases: Option<RuleConfiguration<UseTsconfigPathAliases>>,
+    #[doc = "Enforce that the autocomplete attribute has a valid value."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_autocomplete: Option<RuleConfiguration<UseValidAutocomplete>>,
+    #[doc = "Enforce valid semantic versions and version ranges in package.json."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_package_json_versions: Option<RuleConfiguration<UseValidPackageJsonVersions>>,
+    #[doc = "Enforce the valid usage of Svelte runes."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_runes: Option<RuleConfiguration<UseValidRunes>>,
+    #[doc = "Require the variables to be declared by the operation, with a type allowed where they're used."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_variables: Option<RuleConfiguration<UseValidVariables>>,
+    #[doc = "Enforce valid usage of the built-in directives of a Vue template."]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_valid_vue_directives:
+        Option<RuleConfiguration<biome_html_analyze::options::UseValidVueDirectives>>,
 }
 impl DeserializableValidator for Nursery {
     fn validate(
@@ -3436,43 +3733,130 @@ impl DeserializableValidator for Nursery {
 impl Nursery {
     const GROUP_NAME: &'static str = "nursery";
     pub(crate) const GROUP_RULES: &'static [&'static str] = &[
+        "noAsyncInComputed",
+        "noAwaitInLoop",
         "noColorInvalidHex",
+        "noConditionalTests",
+        "noConflictingTsconfigOptions",
         "noConsole",
         "noConstantMathMinMaxClamp",
         "noCssEmptyBlock",
+        "noDeprecatedApis",
+        "noDeprecatedTsconfigOptions",
+        "noDeprecatedUsage",
+        "noDirectStateMutation",
+        "noDocumentCookie",
         "noDoneCallback",
         "noDuplicateAtImportRules",
         "noDuplicateElseIf",
         "noDuplicateFontNames",
+        "noDuplicateFragmentName",
         "noDuplicateJsonKeys",
+        "noDuplicateOperationName",
+        "noDuplicatePackageJsonDependencies",
         "noDuplicateSelectorsKeyframeBlock",
+        "noDuplicateTestTitles",
+        "noDuplicateTomlKeys",
         "noEvolvingAny",
+        "noExcessiveNestingDepth",
+        "noExcessiveParameters",
+        "noExcessivelyLongFunctions",
         "noFlatMapIdentity",
+        "noGlobalDirnameFilename",
+        "noHardcodedColors",
+        "noHeadElement",
+        "noHighSpecificitySelectors",
+        "noImgElement",
+        "noImplicitCoercion",
         "noImportantInKeyframe",
         "noInvalidPositionAtImportRule",
+        "noInvalidTailwindApply",
+        "noInvalidTomlDates",
+        "noJsonSchemaViolations",
+        "noLeakedConditionalRendering",
+        "noMagicNumbers",
         "noMisplacedAssertion",
         "noNodejsModules",
+        "noNonReactiveUpdates",
+        "noPackageInternalImports",
         "noReactSpecificProps",
+        "noRedundantStoryName",
         "noRestrictedImports",
+        "noSolidDestructuredProps",
+        "noSolidUncalledSignals",
+        "noSolidUntrackedReads",
+        "noStandaloneExpect",
+        "noStaticElementInteractions",
+        "noSyncScripts",
+        "noUndeclaredClassNames",
         "noUndeclaredDependencies",
+        "noUndefinedMarkdownReferences",
+        "noUnknownArgument",
+        "noUnknownField",
         "noUnknownFunction",
         "noUnknownMediaFeatureName",
         "noUnknownProperty",
         "noUnknownSelectorPseudoElement",
+        "noUnknownTsconfigOptions",
         "noUnknownUnit",
         "noUnmatchableAnbSelector",
+        "noUnnecessaryCondition",
+        "noUnresolvedTsconfigExtends",
+        "noUnsafeTypeAssertion",
+        "noUnstableNestedComponents",
+        "noUnusedComponentProps",
+        "noUnusedRefs",
+        "noUnusedSelectors",
+        "noUnusedStoreSubscriptions",
         "noUselessStringConcat",
+        "noUselessUndefined",
         "noUselessUndefinedInitialization",
+        "noVendorPrefix",
+        "useAllowedLengthUnits",
+        "useAriaPropsSupportedByRole",
         "useArrayLiterals",
+        "useAtIndex",
+        "useAwaitStoryInteractions",
+        "useAwaitThenable",
+        "useColorContrast",
         "useConsistentBuiltinInstantiation",
+        "useConsistentCurlyBraces",
+        "useConsistentTestFunction",
+        "useCssNamingConvention",
         "useDefaultSwitchClause",
+        "useDefinePropsOrder",
+        "useExplicitFunctionReturnType",
         "useExplicitLengthCheck",
+        "useExplicitMemberAccessibility",
         "useFocusableInteractive",
         "useGenericFontNames",
+        "useGoogleFontPreconnect",
+        "useGraphqlNamingConvention",
         "useImportRestrictions",
+        "useLogicalProperties",
+        "useNamedOperation",
+        "useNumericSeparators",
+        "useObjectSpread",
+        "useReadonlyClassMembers",
+        "useRequiredArguments",
+        "useRequiredPackageJsonFields",
+        "useShorthandProperties",
         "useSortedClasses",
+        "useSortedKeys",
+        "useSortedPackageJsonKeys",
+        "useSortedProperties",
+        "useSortedTomlKeys",
+        "useSortedYamlKeys",
+        "useStoryDefaultExport",
+        "useStoryNamePascalCase",
         "useThrowNewError",
         "useTopLevelRegex",
+        "useTsconfigPathAliases",
+        "useValidAutocomplete",
+        "useValidPackageJsonVersions",
+        "useValidRunes",
+        "useValidVariables",
+        "useValidVueDirectives",
     ];
     const RECOMMENDED_RULES: &'static [&'static str] = &[
         "noCssEmptyBlock",
@@ -3494,23 +3878,23 @@ impl Nursery {
         "useGenericFontNames",
     ];
     const RECOMMENDED_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]),
-        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]),
     ];
     const ALL_RULES_AS_FILTERS: &'static [RuleFilter<'static>] = &[
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]),
@@ -3550,6 +3934,93 @@ impl Nursery {
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]),
         RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[108]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[109]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[110]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[111]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[112]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[113]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[114]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[115]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[116]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[117]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[118]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[119]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[120]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[121]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[122]),
+        RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[123]),
     ];
     #[doc = r" Retrieves the recommended rules"]
     pub(crate) fn is_recommended_true(&self) -> bool {
@@ -3566,378 +4037,1248 @@ impl Nursery {
     }
     pub(crate) fn get_enabled_rules(&self) -> IndexSet<RuleFilter> {
         let mut index_set = IndexSet::new();
-        if let Some(rule) = self.no_color_invalid_hex.as_ref() {
+        if let Some(rule) = self.no_async_in_computed.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
             }
         }
-        if let Some(rule) = self.no_console.as_ref() {
+        if let Some(rule) = self.no_await_in_loop.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
             }
         }
-        if let Some(rule) = self.no_constant_math_min_max_clamp.as_ref() {
+        if let Some(rule) = self.no_color_invalid_hex.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
             }
         }
-        if let Some(rule) = self.no_css_empty_block.as_ref() {
+        if let Some(rule) = self.no_conditional_tests.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
             }
         }
-        if let Some(rule) = self.no_done_callback.as_ref() {
+        if let Some(rule) = self.no_conflicting_tsconfig_options.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
             }
         }
-        if let Some(rule) = self.no_duplicate_at_import_rules.as_ref() {
+        if let Some(rule) = self.no_console.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+        if let Some(rule) = self.no_constant_math_min_max_clamp.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
             }
         }
-        if let Some(rule) = self.no_duplicate_font_names.as_ref() {
+        if let Some(rule) = self.no_css_empty_block.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+        if let Some(rule) = self.no_deprecated_apis.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
             }
         }
-        if let Some(rule) = self.no_duplicate_selectors_keyframe_block.as_ref() {
+        if let Some(rule) = self.no_deprecated_tsconfig_options.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
             }
         }
-        if let Some(rule) = self.no_evolving_any.as_ref() {
+        if let Some(rule) = self.no_deprecated_usage.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
             }
         }
-        if let Some(rule) = self.no_flat_map_identity.as_ref() {
+        if let Some(rule) = self.no_direct_state_mutation.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
             }
         }
-        if let Some(rule) = self.no_important_in_keyframe.as_ref() {
+        if let Some(rule) = self.no_document_cookie.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
             }
         }
-        if let Some(rule) = self.no_invalid_position_at_import_rule.as_ref() {
+        if let Some(rule) = self.no_done_callback.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
             }
         }
-        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
+        if let Some(rule) = self.no_duplicate_at_import_rules.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
             }
         }
-        if let Some(rule) = self.no_nodejs_modules.as_ref() {
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
             }
         }
-        if let Some(rule) = self.no_react_specific_props.as_ref() {
+        if let Some(rule) = self.no_duplicate_font_names.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.no_duplicate_fragment_name.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
             }
         }
-        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
             }
         }
-        if let Some(rule) = self.no_unknown_function.as_ref() {
+        if let Some(rule) = self.no_duplicate_operation_name.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
             }
         }
-        if let Some(rule) = self.no_unknown_media_feature_name.as_ref() {
+        if let Some(rule) = self.no_duplicate_package_json_dependencies.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
             }
         }
-        if let Some(rule) = self.no_unknown_property.as_ref() {
+        if let Some(rule) = self.no_duplicate_selectors_keyframe_block.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
             }
         }
-        if let Some(rule) = self.no_unknown_selector_pseudo_element.as_ref() {
+        if let Some(rule) = self.no_duplicate_test_titles.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
             }
         }
-        if let Some(rule) = self.no_unknown_unit.as_ref() {
+        if let Some(rule) = self.no_duplicate_toml_keys.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
             }
         }
-        if let Some(rule) = self.no_unmatchable_anb_selector.as_ref() {
+        if let Some(rule) = self.no_evolving_any.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
             }
         }
-        if let Some(rule) = self.no_useless_string_concat.as_ref() {
+        if let Some(rule) = self.no_excessive_nesting_depth.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined_initialization.as_ref() {
+        if let Some(rule) = self.no_excessive_parameters.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
             }
         }
-        if let Some(rule) = self.use_array_literals.as_ref() {
+        if let Some(rule) = self.no_excessively_long_functions.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
             }
         }
-        if let Some(rule) = self.use_consistent_builtin_instantiation.as_ref() {
+        if let Some(rule) = self.no_flat_map_identity.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
             }
         }
-        if let Some(rule) = self.use_default_switch_clause.as_ref() {
+        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
             }
         }
-        if let Some(rule) = self.use_explicit_length_check.as_ref() {
+        if let Some(rule) = self.no_hardcoded_colors.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
             }
         }
-        if let Some(rule) = self.use_focusable_interactive.as_ref() {
+        if let Some(rule) = self.no_head_element.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
             }
         }
-        if let Some(rule) = self.use_generic_font_names.as_ref() {
+        if let Some(rule) = self.no_high_specificity_selectors.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.no_img_element.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.no_implicit_coercion.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
             }
         }
-        if let Some(rule) = self.use_throw_new_error.as_ref() {
+        if let Some(rule) = self.no_important_in_keyframe.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
             }
         }
-        if let Some(rule) = self.use_top_level_regex.as_ref() {
+        if let Some(rule) = self.no_invalid_position_at_import_rule.as_ref() {
             if rule.is_enabled() {
                 index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
             }
         }
-        index_set
-    }
-    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
-        let mut index_set = IndexSet::new();
-        if let Some(rule) = self.no_color_invalid_hex.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+        if let Some(rule) = self.no_invalid_tailwind_apply.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
             }
         }
-        if let Some(rule) = self.no_console.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+        if let Some(rule) = self.no_invalid_toml_dates.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
             }
         }
-        if let Some(rule) = self.no_constant_math_min_max_clamp.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+        if let Some(rule) = self.no_json_schema_violations.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
             }
         }
-        if let Some(rule) = self.no_css_empty_block.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+        if let Some(rule) = self.no_leaked_conditional_rendering.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
             }
         }
-        if let Some(rule) = self.no_done_callback.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+        if let Some(rule) = self.no_magic_numbers.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
             }
         }
-        if let Some(rule) = self.no_duplicate_at_import_rules.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
             }
         }
-        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+        if let Some(rule) = self.no_nodejs_modules.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
             }
         }
-        if let Some(rule) = self.no_duplicate_font_names.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+        if let Some(rule) = self.no_non_reactive_updates.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
             }
         }
-        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
-            if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+        if let Some(rule) = self.no_package_internal_imports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
             }
         }
-        if let Some(rule) = self.no_duplicate_selectors_keyframe_block.as_ref() {
+        if let Some(rule) = self.no_react_specific_props.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
+            }
+        }
+        if let Some(rule) = self.no_redundant_story_name.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
+            }
+        }
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+            }
+        }
+        if let Some(rule) = self.no_solid_destructured_props.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+            }
+        }
+        if let Some(rule) = self.no_solid_uncalled_signals.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+            }
+        }
+        if let Some(rule) = self.no_solid_untracked_reads.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+            }
+        }
+        if let Some(rule) = self.no_standalone_expect.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+            }
+        }
+        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+            }
+        }
+        if let Some(rule) = self.no_sync_scripts.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+            }
+        }
+        if let Some(rule) = self.no_undeclared_class_names.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+            }
+        }
+        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
+            }
+        }
+        if let Some(rule) = self.no_undefined_markdown_references.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_argument.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_field.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_function.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_media_feature_name.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_property.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_selector_pseudo_element.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_tsconfig_options.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_unit.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]));
+            }
+        }
+        if let Some(rule) = self.no_unmatchable_anb_selector.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]));
+            }
+        }
+        if let Some(rule) = self.no_unnecessary_condition.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]));
+            }
+        }
+        if let Some(rule) = self.no_unresolved_tsconfig_extends.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]));
+            }
+        }
+        if let Some(rule) = self.no_unsafe_type_assertion.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]));
+            }
+        }
+        if let Some(rule) = self.no_unstable_nested_components.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]));
+            }
+        }
+        if let Some(rule) = self.no_unused_component_props.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]));
+            }
+        }
+        if let Some(rule) = self.no_unused_refs.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]));
+            }
+        }
+        if let Some(rule) = self.no_unused_selectors.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]));
+            }
+        }
+        if let Some(rule) = self.no_unused_store_subscriptions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]));
+            }
+        }
+        if let Some(rule) = self.no_useless_string_concat.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]));
+            }
+        }
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]));
+            }
+        }
+        if let Some(rule) = self.no_useless_undefined_initialization.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]));
+            }
+        }
+        if let Some(rule) = self.no_vendor_prefix.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]));
+            }
+        }
+        if let Some(rule) = self.use_allowed_length_units.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]));
+            }
+        }
+        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]));
+            }
+        }
+        if let Some(rule) = self.use_array_literals.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]));
+            }
+        }
+        if let Some(rule) = self.use_at_index.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]));
+            }
+        }
+        if let Some(rule) = self.use_await_story_interactions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]));
+            }
+        }
+        if let Some(rule) = self.use_await_thenable.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]));
+            }
+        }
+        if let Some(rule) = self.use_color_contrast.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_builtin_instantiation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_test_function.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]));
+            }
+        }
+        if let Some(rule) = self.use_css_naming_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]));
+            }
+        }
+        if let Some(rule) = self.use_default_switch_clause.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]));
+            }
+        }
+        if let Some(rule) = self.use_define_props_order.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]));
+            }
+        }
+        if let Some(rule) = self.use_explicit_function_return_type.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]));
+            }
+        }
+        if let Some(rule) = self.use_explicit_length_check.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]));
+            }
+        }
+        if let Some(rule) = self.use_explicit_member_accessibility.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]));
+            }
+        }
+        if let Some(rule) = self.use_focusable_interactive.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]));
+            }
+        }
+        if let Some(rule) = self.use_generic_font_names.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]));
+            }
+        }
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]));
+            }
+        }
+        if let Some(rule) = self.use_graphql_naming_convention.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]));
+            }
+        }
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]));
+            }
+        }
+        if let Some(rule) = self.use_logical_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]));
+            }
+        }
+        if let Some(rule) = self.use_named_operation.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]));
+            }
+        }
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]));
+            }
+        }
+        if let Some(rule) = self.use_object_spread.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]));
+            }
+        }
+        if let Some(rule) = self.use_readonly_class_members.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]));
+            }
+        }
+        if let Some(rule) = self.use_required_arguments.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]));
+            }
+        }
+        if let Some(rule) = self.use_required_package_json_fields.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]));
+            }
+        }
+        if let Some(rule) = self.use_shorthand_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[108]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[109]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_package_json_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[110]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_properties.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[111]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_toml_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[112]));
+            }
+        }
+        if let Some(rule) = self.use_sorted_yaml_keys.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[113]));
+            }
+        }
+        if let Some(rule) = self.use_story_default_export.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[114]));
+            }
+        }
+        if let Some(rule) = self.use_story_name_pascal_case.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[115]));
+            }
+        }
+        if let Some(rule) = self.use_throw_new_error.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[116]));
+            }
+        }
+        if let Some(rule) = self.use_top_level_regex.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[117]));
+            }
+        }
+        if let Some(rule) = self.use_tsconfig_path_aliases.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[118]));
+            }
+        }
+        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[119]));
+            }
+        }
+        if let Some(rule) = self.use_valid_package_json_versions.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[120]));
+            }
+        }
+        if let Some(rule) = self.use_valid_runes.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[121]));
+            }
+        }
+        if let Some(rule) = self.use_valid_variables.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[122]));
+            }
+        }
+        if let Some(rule) = self.use_valid_vue_directives.as_ref() {
+            if rule.is_enabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[123]));
+            }
+        }
+        index_set
+    }
+    pub(crate) fn get_disabled_rules(&self) -> IndexSet<RuleFilter> {
+        let mut index_set = IndexSet::new();
+        if let Some(rule) = self.no_async_in_computed.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[0]));
+            }
+        }
+        if let Some(rule) = self.no_await_in_loop.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[1]));
+            }
+        }
+        if let Some(rule) = self.no_color_invalid_hex.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[2]));
+            }
+        }
+        if let Some(rule) = self.no_conditional_tests.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[3]));
+            }
+        }
+        if let Some(rule) = self.no_conflicting_tsconfig_options.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[4]));
+            }
+        }
+        if let Some(rule) = self.no_console.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[5]));
+            }
+        }
+        if let Some(rule) = self.no_constant_math_min_max_clamp.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[6]));
+            }
+        }
+        if let Some(rule) = self.no_css_empty_block.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[7]));
+            }
+        }
+        if let Some(rule) = self.no_deprecated_apis.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[8]));
+            }
+        }
+        if let Some(rule) = self.no_deprecated_tsconfig_options.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+            }
+        }
+        if let Some(rule) = self.no_deprecated_usage.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+            }
+        }
+        if let Some(rule) = self.no_direct_state_mutation.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+            }
+        }
+        if let Some(rule) = self.no_document_cookie.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+            }
+        }
+        if let Some(rule) = self.no_done_callback.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_at_import_rules.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_else_if.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_font_names.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_fragment_name.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_json_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_operation_name.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_package_json_dependencies.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_selectors_keyframe_block.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_test_titles.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+            }
+        }
+        if let Some(rule) = self.no_duplicate_toml_keys.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+            }
+        }
+        if let Some(rule) = self.no_evolving_any.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+            }
+        }
+        if let Some(rule) = self.no_excessive_nesting_depth.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+            }
+        }
+        if let Some(rule) = self.no_excessive_parameters.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+            }
+        }
+        if let Some(rule) = self.no_excessively_long_functions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+            }
+        }
+        if let Some(rule) = self.no_flat_map_identity.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+            }
+        }
+        if let Some(rule) = self.no_global_dirname_filename.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+            }
+        }
+        if let Some(rule) = self.no_hardcoded_colors.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+            }
+        }
+        if let Some(rule) = self.no_head_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+            }
+        }
+        if let Some(rule) = self.no_high_specificity_selectors.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+            }
+        }
+        if let Some(rule) = self.no_img_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+            }
+        }
+        if let Some(rule) = self.no_implicit_coercion.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+            }
+        }
+        if let Some(rule) = self.no_important_in_keyframe.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+            }
+        }
+        if let Some(rule) = self.no_invalid_position_at_import_rule.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
+            }
+        }
+        if let Some(rule) = self.no_invalid_tailwind_apply.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[37]));
+            }
+        }
+        if let Some(rule) = self.no_invalid_toml_dates.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[38]));
+            }
+        }
+        if let Some(rule) = self.no_json_schema_violations.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[39]));
+            }
+        }
+        if let Some(rule) = self.no_leaked_conditional_rendering.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[40]));
+            }
+        }
+        if let Some(rule) = self.no_magic_numbers.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[41]));
+            }
+        }
+        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[42]));
+            }
+        }
+        if let Some(rule) = self.no_nodejs_modules.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[43]));
+            }
+        }
+        if let Some(rule) = self.no_non_reactive_updates.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[44]));
+            }
+        }
+        if let Some(rule) = self.no_package_internal_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[45]));
+            }
+        }
+        if let Some(rule) = self.no_react_specific_props.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[46]));
+            }
+        }
+        if let Some(rule) = self.no_redundant_story_name.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[47]));
+            }
+        }
+        if let Some(rule) = self.no_restricted_imports.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[48]));
+            }
+        }
+        if let Some(rule) = self.no_solid_destructured_props.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[49]));
+            }
+        }
+        if let Some(rule) = self.no_solid_uncalled_signals.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[50]));
+            }
+        }
+        if let Some(rule) = self.no_solid_untracked_reads.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[51]));
+            }
+        }
+        if let Some(rule) = self.no_standalone_expect.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[52]));
+            }
+        }
+        if let Some(rule) = self.no_static_element_interactions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[53]));
+            }
+        }
+        if let Some(rule) = self.no_sync_scripts.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[54]));
+            }
+        }
+        if let Some(rule) = self.no_undeclared_class_names.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[55]));
+            }
+        }
+        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[56]));
+            }
+        }
+        if let Some(rule) = self.no_undefined_markdown_references.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[57]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_argument.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[58]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_field.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[59]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_function.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[60]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_media_feature_name.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[61]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_property.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[62]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_selector_pseudo_element.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[63]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_tsconfig_options.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[64]));
+            }
+        }
+        if let Some(rule) = self.no_unknown_unit.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[65]));
+            }
+        }
+        if let Some(rule) = self.no_unmatchable_anb_selector.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[66]));
+            }
+        }
+        if let Some(rule) = self.no_unnecessary_condition.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[67]));
+            }
+        }
+        if let Some(rule) = self.no_unresolved_tsconfig_extends.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[68]));
+            }
+        }
+        if let Some(rule) = self.no_unsafe_type_assertion.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[69]));
+            }
+        }
+        if let Some(rule) = self.no_unstable_nested_components.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[70]));
+            }
+        }
+        if let Some(rule) = self.no_unused_component_props.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[71]));
+            }
+        }
+        if let Some(rule) = self.no_unused_refs.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[72]));
+            }
+        }
+        if let Some(rule) = self.no_unused_selectors.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[73]));
+            }
+        }
+        if let Some(rule) = self.no_unused_store_subscriptions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[74]));
+            }
+        }
+        if let Some(rule) = self.no_useless_string_concat.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[75]));
+            }
+        }
+        if let Some(rule) = self.no_useless_undefined.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[76]));
+            }
+        }
+        if let Some(rule) = self.no_useless_undefined_initialization.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[77]));
+            }
+        }
+        if let Some(rule) = self.no_vendor_prefix.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[78]));
+            }
+        }
+        if let Some(rule) = self.use_allowed_length_units.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[79]));
+            }
+        }
+        if let Some(rule) = self.use_aria_props_supported_by_role.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[80]));
+            }
+        }
+        if let Some(rule) = self.use_array_literals.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[81]));
+            }
+        }
+        if let Some(rule) = self.use_at_index.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[82]));
+            }
+        }
+        if let Some(rule) = self.use_await_story_interactions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[83]));
+            }
+        }
+        if let Some(rule) = self.use_await_thenable.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[84]));
+            }
+        }
+        if let Some(rule) = self.use_color_contrast.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[85]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_builtin_instantiation.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[86]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_curly_braces.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[87]));
+            }
+        }
+        if let Some(rule) = self.use_consistent_test_function.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[88]));
+            }
+        }
+        if let Some(rule) = self.use_css_naming_convention.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[89]));
+            }
+        }
+        if let Some(rule) = self.use_default_switch_clause.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[9]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[90]));
             }
         }
-        if let Some(rule) = self.no_evolving_any.as_ref() {
+        if let Some(rule) = self.use_define_props_order.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[10]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[91]));
             }
         }
-        if let Some(rule) = self.no_flat_map_identity.as_ref() {
+        if let Some(rule) = self.use_explicit_function_return_type.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[11]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[92]));
             }
         }
-        if let Some(rule) = self.no_important_in_keyframe.as_ref() {
+        if let Some(rule) = self.use_explicit_length_check.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[12]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[93]));
             }
         }
-        if let Some(rule) = self.no_invalid_position_at_import_rule.as_ref() {
+        if let Some(rule) = self.use_explicit_member_accessibility.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[13]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[94]));
             }
         }
-        if let Some(rule) = self.no_misplaced_assertion.as_ref() {
+        if let Some(rule) = self.use_focusable_interactive.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[14]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[95]));
             }
         }
-        if let Some(rule) = self.no_nodejs_modules.as_ref() {
+        if let Some(rule) = self.use_generic_font_names.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[15]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[96]));
             }
         }
-        if let Some(rule) = self.no_react_specific_props.as_ref() {
+        if let Some(rule) = self.use_google_font_preconnect.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[16]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[97]));
             }
         }
-        if let Some(rule) = self.no_restricted_imports.as_ref() {
+        if let Some(rule) = self.use_graphql_naming_convention.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[17]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[98]));
             }
         }
-        if let Some(rule) = self.no_undeclared_dependencies.as_ref() {
+        if let Some(rule) = self.use_import_restrictions.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[18]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[99]));
             }
         }
-        if let Some(rule) = self.no_unknown_function.as_ref() {
+        if let Some(rule) = self.use_logical_properties.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[19]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[100]));
             }
         }
-        if let Some(rule) = self.no_unknown_media_feature_name.as_ref() {
+        if let Some(rule) = self.use_named_operation.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[20]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[101]));
             }
         }
-        if let Some(rule) = self.no_unknown_property.as_ref() {
+        if let Some(rule) = self.use_numeric_separators.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[21]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[102]));
             }
         }
-        if let Some(rule) = self.no_unknown_selector_pseudo_element.as_ref() {
+        if let Some(rule) = self.use_object_spread.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[22]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[103]));
             }
         }
-        if let Some(rule) = self.no_unknown_unit.as_ref() {
+        if let Some(rule) = self.use_readonly_class_members.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[23]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[104]));
             }
         }
-        if let Some(rule) = self.no_unmatchable_anb_selector.as_ref() {
+        if let Some(rule) = self.use_required_arguments.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[24]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[105]));
             }
         }
-        if let Some(rule) = self.no_useless_string_concat.as_ref() {
+        if let Some(rule) = self.use_required_package_json_fields.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[25]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[106]));
             }
         }
-        if let Some(rule) = self.no_useless_undefined_initialization.as_ref() {
+        if let Some(rule) = self.use_shorthand_properties.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[26]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[107]));
             }
         }
-        if let Some(rule) = self.use_array_literals.as_ref() {
+        if let Some(rule) = self.use_sorted_classes.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[27]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[108]));
             }
         }
-        if let Some(rule) = self.use_consistent_builtin_instantiation.as_ref() {
+        if let Some(rule) = self.use_sorted_keys.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[28]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[109]));
             }
         }
-        if let Some(rule) = self.use_default_switch_clause.as_ref() {
+        if let Some(rule) = self.use_sorted_package_json_keys.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[29]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[110]));
             }
         }
-        if let Some(rule) = self.use_explicit_length_check.as_ref() {
+        if let Some(rule) = self.use_sorted_properties.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[30]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[111]));
             }
         }
-        if let Some(rule) = self.use_focusable_interactive.as_ref() {
+        if let Some(rule) = self.use_sorted_toml_keys.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[31]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[112]));
             }
         }
-        if let Some(rule) = self.use_generic_font_names.as_ref() {
+        if let Some(rule) = self.use_sorted_yaml_keys.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[32]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[113]));
             }
         }
-        if let Some(rule) = self.use_import_restrictions.as_ref() {
+        if let Some(rule) = self.use_story_default_export.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[33]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[114]));
             }
         }
-        if let Some(rule) = self.use_sorted_classes.as_ref() {
+        if let Some(rule) = self.use_story_name_pascal_case.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[34]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[115]));
             }
         }
         if let Some(rule) = self.use_throw_new_error.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[35]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[116]));
             }
         }
         if let Some(rule) = self.use_top_level_regex.as_ref() {
             if rule.is_disabled() {
-                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[36]));
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[117]));
+            }
+        }
+        if let Some(rule) = self.use_tsconfig_path_aliases.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[118]));
+            }
+        }
+        if let Some(rule) = self.use_valid_autocomplete.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[119]));
+            }
+        }
+        if let Some(rule) = self.use_valid_package_json_versions.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[120]));
+            }
+        }
+        if let Some(rule) = self.use_valid_runes.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[121]));
+            }
+        }
+        if let Some(rule) = self.use_valid_variables.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[122]));
+            }
+        }
+        if let Some(rule) = self.use_valid_vue_directives.as_ref() {
+            if rule.is_disabled() {
+                index_set.insert(RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[123]));
             }
         }
         index_set
@@ -3976,10 +5317,26 @@ impl Nursery {
         rule_name: &str,
     ) -> Option<(RulePlainConfiguration, Option<RuleOptions>)> {
         match rule_name {
+            "noAsyncInComputed" => self
+                .no_async_in_computed
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noAwaitInLoop" => self
+                .no_await_in_loop
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noColorInvalidHex" => self
                 .no_color_invalid_hex
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noConditionalTests" => self
+                .no_conditional_tests
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noConflictingTsconfigOptions" => self
+                .no_conflicting_tsconfig_options
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noConsole" => self
                 .no_console
                 .as_ref()
@@ -3992,6 +5349,26 @@ impl Nursery {
                 .no_css_empty_block
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDeprecatedApis" => self
+                .no_deprecated_apis
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDeprecatedTsconfigOptions" => self
+                .no_deprecated_tsconfig_options
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDeprecatedUsage" => self
+                .no_deprecated_usage
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDirectStateMutation" => self
+                .no_direct_state_mutation
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDocumentCookie" => self
+                .no_document_cookie
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDoneCallback" => self
                 .no_done_callback
                 .as_ref()
@@ -4008,22 +5385,78 @@ impl Nursery {
                 .no_duplicate_font_names
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateFragmentName" => self
+                .no_duplicate_fragment_name
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDuplicateJsonKeys" => self
                 .no_duplicate_json_keys
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateOperationName" => self
+                .no_duplicate_operation_name
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicatePackageJsonDependencies" => self
+                .no_duplicate_package_json_dependencies
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noDuplicateSelectorsKeyframeBlock" => self
                 .no_duplicate_selectors_keyframe_block
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateTestTitles" => self
+                .no_duplicate_test_titles
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noDuplicateTomlKeys" => self
+                .no_duplicate_toml_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noEvolvingAny" => self
                 .no_evolving_any
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noExcessiveNestingDepth" => self
+                .no_excessive_nesting_depth
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noExcessiveParameters" => self
+                .no_excessive_parameters
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noExcessivelyLongFunctions" => self
+                .no_excessively_long_functions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noFlatMapIdentity" => self
                 .no_flat_map_identity
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noGlobalDirnameFilename" => self
+                .no_global_dirname_filename
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noHardcodedColors" => self
+                .no_hardcoded_colors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noHeadElement" => self
+                .no_head_element
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noHighSpecificitySelectors" => self
+                .no_high_specificity_selectors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noImgElement" => self
+                .no_img_element
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noImplicitCoercion" => self
+                .no_implicit_coercion
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noImportantInKeyframe" => self
                 .no_important_in_keyframe
                 .as_ref()
@@ -4032,6 +5465,26 @@ impl Nursery {
                 .no_invalid_position_at_import_rule
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidTailwindApply" => self
+                .no_invalid_tailwind_apply
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noInvalidTomlDates" => self
+                .no_invalid_toml_dates
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noJsonSchemaViolations" => self
+                .no_json_schema_violations
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noLeakedConditionalRendering" => self
+                .no_leaked_conditional_rendering
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noMagicNumbers" => self
+                .no_magic_numbers
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noMisplacedAssertion" => self
                 .no_misplaced_assertion
                 .as_ref()
@@ -4040,18 +5493,70 @@ impl Nursery {
                 .no_nodejs_modules
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noNonReactiveUpdates" => self
+                .no_non_reactive_updates
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noPackageInternalImports" => self
+                .no_package_internal_imports
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noReactSpecificProps" => self
                 .no_react_specific_props
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noRedundantStoryName" => self
+                .no_redundant_story_name
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noRestrictedImports" => self
                 .no_restricted_imports
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noSolidDestructuredProps" => self
+                .no_solid_destructured_props
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noSolidUncalledSignals" => self
+                .no_solid_uncalled_signals
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noSolidUntrackedReads" => self
+                .no_solid_untracked_reads
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noStandaloneExpect" => self
+                .no_standalone_expect
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noStaticElementInteractions" => self
+                .no_static_element_interactions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noSyncScripts" => self
+                .no_sync_scripts
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUndeclaredClassNames" => self
+                .no_undeclared_class_names
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUndeclaredDependencies" => self
                 .no_undeclared_dependencies
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUndefinedMarkdownReferences" => self
+                .no_undefined_markdown_references
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownArgument" => self
+                .no_unknown_argument
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownField" => self
+                .no_unknown_field
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnknownFunction" => self
                 .no_unknown_function
                 .as_ref()
@@ -4068,6 +5573,10 @@ impl Nursery {
                 .no_unknown_selector_pseudo_element
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnknownTsconfigOptions" => self
+                .no_unknown_tsconfig_options
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUnknownUnit" => self
                 .no_unknown_unit
                 .as_ref()
@@ -4076,30 +5585,118 @@ impl Nursery {
                 .no_unmatchable_anb_selector
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUnnecessaryCondition" => self
+                .no_unnecessary_condition
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnresolvedTsconfigExtends" => self
+                .no_unresolved_tsconfig_extends
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnsafeTypeAssertion" => self
+                .no_unsafe_type_assertion
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnstableNestedComponents" => self
+                .no_unstable_nested_components
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedComponentProps" => self
+                .no_unused_component_props
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedRefs" => self
+                .no_unused_refs
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedSelectors" => self
+                .no_unused_selectors
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "noUnusedStoreSubscriptions" => self
+                .no_unused_store_subscriptions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUselessStringConcat" => self
                 .no_useless_string_concat
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noUselessUndefined" => self
+                .no_useless_undefined
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "noUselessUndefinedInitialization" => self
                 .no_useless_undefined_initialization
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "noVendorPrefix" => self
+                .no_vendor_prefix
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useAllowedLengthUnits" => self
+                .use_allowed_length_units
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useAriaPropsSupportedByRole" => self
+                .use_aria_props_supported_by_role
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useArrayLiterals" => self
                 .use_array_literals
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useAtIndex" => self
+                .use_at_index
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useAwaitStoryInteractions" => self
+                .use_await_story_interactions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useAwaitThenable" => self
+                .use_await_thenable
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useColorContrast" => self
+                .use_color_contrast
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useConsistentBuiltinInstantiation" => self
                 .use_consistent_builtin_instantiation
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentCurlyBraces" => self
+                .use_consistent_curly_braces
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useConsistentTestFunction" => self
+                .use_consistent_test_function
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useCssNamingConvention" => self
+                .use_css_naming_convention
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useDefaultSwitchClause" => self
                 .use_default_switch_clause
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useDefinePropsOrder" => self
+                .use_define_props_order
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useExplicitFunctionReturnType" => self
+                .use_explicit_function_return_type
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useExplicitLengthCheck" => self
                 .use_explicit_length_check
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useExplicitMemberAccessibility" => self
+                .use_explicit_member_accessibility
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useFocusableInteractive" => self
                 .use_focusable_interactive
                 .as_ref()
@@ -4108,14 +5705,82 @@ impl Nursery {
                 .use_generic_font_names
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useGoogleFontPreconnect" => self
+                .use_google_font_preconnect
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useGraphqlNamingConvention" => self
+                .use_graphql_naming_convention
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useImportRestrictions" => self
                 .use_import_restrictions
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useLogicalProperties" => self
+                .use_logical_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useNamedOperation" => self
+                .use_named_operation
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useNumericSeparators" => self
+                .use_numeric_separators
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useObjectSpread" => self
+                .use_object_spread
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useReadonlyClassMembers" => self
+                .use_readonly_class_members
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useRequiredArguments" => self
+                .use_required_arguments
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useRequiredPackageJsonFields" => self
+                .use_required_package_json_fields
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useShorthandProperties" => self
+                .use_shorthand_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useSortedClasses" => self
                 .use_sorted_classes
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedKeys" => self
+                .use_sorted_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedPackageJsonKeys" => self
+                .use_sorted_package_json_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedProperties" => self
+                .use_sorted_properties
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedTomlKeys" => self
+                .use_sorted_toml_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useSortedYamlKeys" => self
+                .use_sorted_yaml_keys
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useStoryDefaultExport" => self
+                .use_story_default_export
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useStoryNamePascalCase" => self
+                .use_story_name_pascal_case
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             "useThrowNewError" => self
                 .use_throw_new_error
                 .as_ref()
@@ -4124,16 +5789,60 @@ impl Nursery {
                 .use_top_level_regex
                 .as_ref()
                 .map(|conf| (conf.level(), conf.get_options())),
+            "useTsconfigPathAliases" => self
+                .use_tsconfig_path_aliases
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useValidAutocomplete" => self
+                .use_valid_autocomplete
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useValidPackageJsonVersions" => self
+                .use_valid_package_json_versions
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useValidRunes" => self
+                .use_valid_runes
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useValidVariables" => self
+                .use_valid_variables
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
+            "useValidVueDirectives" => self
+                .use_valid_vue_directives
+                .as_ref()
+                .map(|conf| (conf.level(), conf.get_options())),
             _ => None,
         }
     }
     pub(crate) fn set_severity(&mut self, rule_name: &str, severity: RulePlainConfiguration) {
         match rule_name {
+            "noAsyncInComputed" => {
+                if let Some(rule_conf) = &mut self.no_async_in_computed {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noAwaitInLoop" => {
+                if let Some(rule_conf) = &mut self.no_await_in_loop {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noColorInvalidHex" => {
                 if let Some(rule_conf) = &mut self.no_color_invalid_hex {
                     rule_conf.set_level(severity);
                 }
             }
+            "noConditionalTests" => {
+                if let Some(rule_conf) = &mut self.no_conditional_tests {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noConflictingTsconfigOptions" => {
+                if let Some(rule_conf) = &mut self.no_conflicting_tsconfig_options {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noConsole" => {
                 if let Some(rule_conf) = &mut self.no_console {
                     rule_conf.set_level(severity);
@@ -4149,6 +5858,31 @@ impl Nursery {
                     rule_conf.set_level(severity);
                 }
             }
+            "noDeprecatedApis" => {
+                if let Some(rule_conf) = &mut self.no_deprecated_apis {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noDeprecatedTsconfigOptions" => {
+                if let Some(rule_conf) = &mut self.no_deprecated_tsconfig_options {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noDeprecatedUsage" => {
+                if let Some(rule_conf) = &mut self.no_deprecated_usage {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noDirectStateMutation" => {
+                if let Some(rule_conf) = &mut self.no_direct_state_mutation {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noDocumentCookie" => {
+                if let Some(rule_conf) = &mut self.no_document_cookie {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noDoneCallback" => {
                 if let Some(rule_conf) = &mut self.no_done_callback {
                     rule_conf.set_level(severity);
@@ -4169,26 +5903,96 @@ impl Nursery {
                     rule_conf.set_level(severity);
                 }
             }
+            "noDuplicateFragmentName" => {
+                if let Some(rule_conf) = &mut self.no_duplicate_fragment_name {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noDuplicateJsonKeys" => {
                 if let Some(rule_conf) = &mut self.no_duplicate_json_keys {
                     rule_conf.set_level(severity);
                 }
             }
+            "noDuplicateOperationName" => {
+                if let Some(rule_conf) = &mut self.no_duplicate_operation_name {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noDuplicatePackageJsonDependencies" => {
+                if let Some(rule_conf) = &mut self.no_duplicate_package_json_dependencies {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noDuplicateSelectorsKeyframeBlock" => {
                 if let Some(rule_conf) = &mut self.no_duplicate_selectors_keyframe_block {
                     rule_conf.set_level(severity);
                 }
             }
+            "noDuplicateTestTitles" => {
+                if let Some(rule_conf) = &mut self.no_duplicate_test_titles {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noDuplicateTomlKeys" => {
+                if let Some(rule_conf) = &mut self.no_duplicate_toml_keys {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noEvolvingAny" => {
                 if let Some(rule_conf) = &mut self.no_evolving_any {
                     rule_conf.set_level(severity);
                 }
             }
+            "noExcessiveNestingDepth" => {
+                if let Some(rule_conf) = &mut self.no_excessive_nesting_depth {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noExcessiveParameters" => {
+                if let Some(rule_conf) = &mut self.no_excessive_parameters {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noExcessivelyLongFunctions" => {
+                if let Some(rule_conf) = &mut self.no_excessively_long_functions {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noFlatMapIdentity" => {
                 if let Some(rule_conf) = &mut self.no_flat_map_identity {
                     rule_conf.set_level(severity);
                 }
             }
+            "noGlobalDirnameFilename" => {
+                if let Some(rule_conf) = &mut self.no_global_dirname_filename {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noHardcodedColors" => {
+                if let Some(rule_conf) = &mut self.no_hardcoded_colors {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noHeadElement" => {
+                if let Some(rule_conf) = &mut self.no_head_element {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noHighSpecificitySelectors" => {
+                if let Some(rule_conf) = &mut self.no_high_specificity_selectors {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noImgElement" => {
+                if let Some(rule_conf) = &mut self.no_img_element {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noImplicitCoercion" => {
+                if let Some(rule_conf) = &mut self.no_implicit_coercion {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noImportantInKeyframe" => {
                 if let Some(rule_conf) = &mut self.no_important_in_keyframe {
                     rule_conf.set_level(severity);
@@ -4199,6 +6003,31 @@ impl Nursery {
                     rule_conf.set_level(severity);
                 }
             }
+            "noInvalidTailwindApply" => {
+                if let Some(rule_conf) = &mut self.no_invalid_tailwind_apply {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noInvalidTomlDates" => {
+                if let Some(rule_conf) = &mut self.no_invalid_toml_dates {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noJsonSchemaViolations" => {
+                if let Some(rule_conf) = &mut self.no_json_schema_violations {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noLeakedConditionalRendering" => {
+                if let Some(rule_conf) = &mut self.no_leaked_conditional_rendering {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noMagicNumbers" => {
+                if let Some(rule_conf) = &mut self.no_magic_numbers {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noMisplacedAssertion" => {
                 if let Some(rule_conf) = &mut self.no_misplaced_assertion {
                     rule_conf.set_level(severity);
@@ -4209,21 +6038,86 @@ impl Nursery {
                     rule_conf.set_level(severity);
                 }
             }
+            "noNonReactiveUpdates" => {
+                if let Some(rule_conf) = &mut self.no_non_reactive_updates {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noPackageInternalImports" => {
+                if let Some(rule_conf) = &mut self.no_package_internal_imports {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noReactSpecificProps" => {
                 if let Some(rule_conf) = &mut self.no_react_specific_props {
                     rule_conf.set_level(severity);
                 }
             }
+            "noRedundantStoryName" => {
+                if let Some(rule_conf) = &mut self.no_redundant_story_name {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noRestrictedImports" => {
                 if let Some(rule_conf) = &mut self.no_restricted_imports {
                     rule_conf.set_level(severity);
                 }
             }
+            "noSolidDestructuredProps" => {
+                if let Some(rule_conf) = &mut self.no_solid_destructured_props {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noSolidUncalledSignals" => {
+                if let Some(rule_conf) = &mut self.no_solid_uncalled_signals {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noSolidUntrackedReads" => {
+                if let Some(rule_conf) = &mut self.no_solid_untracked_reads {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noStandaloneExpect" => {
+                if let Some(rule_conf) = &mut self.no_standalone_expect {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noStaticElementInteractions" => {
+                if let Some(rule_conf) = &mut self.no_static_element_interactions {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noSyncScripts" => {
+                if let Some(rule_conf) = &mut self.no_sync_scripts {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUndeclaredClassNames" => {
+                if let Some(rule_conf) = &mut self.no_undeclared_class_names {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noUndeclaredDependencies" => {
                 if let Some(rule_conf) = &mut self.no_undeclared_dependencies {
                     rule_conf.set_level(severity);
                 }
             }
+            "noUndefinedMarkdownReferences" => {
+                if let Some(rule_conf) = &mut self.no_undefined_markdown_references {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnknownArgument" => {
+                if let Some(rule_conf) = &mut self.no_unknown_argument {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnknownField" => {
+                if let Some(rule_conf) = &mut self.no_unknown_field {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noUnknownFunction" => {
                 if let Some(rule_conf) = &mut self.no_unknown_function {
                     rule_conf.set_level(severity);
@@ -4244,6 +6138,11 @@ impl Nursery {
                     rule_conf.set_level(severity);
                 }
             }
+            "noUnknownTsconfigOptions" => {
+                if let Some(rule_conf) = &mut self.no_unknown_tsconfig_options {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noUnknownUnit" => {
                 if let Some(rule_conf) = &mut self.no_unknown_unit {
                     rule_conf.set_level(severity);
@@ -4254,36 +6153,146 @@ impl Nursery {
                     rule_conf.set_level(severity);
                 }
             }
+            "noUnnecessaryCondition" => {
+                if let Some(rule_conf) = &mut self.no_unnecessary_condition {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnresolvedTsconfigExtends" => {
+                if let Some(rule_conf) = &mut self.no_unresolved_tsconfig_extends {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnsafeTypeAssertion" => {
+                if let Some(rule_conf) = &mut self.no_unsafe_type_assertion {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnstableNestedComponents" => {
+                if let Some(rule_conf) = &mut self.no_unstable_nested_components {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnusedComponentProps" => {
+                if let Some(rule_conf) = &mut self.no_unused_component_props {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnusedRefs" => {
+                if let Some(rule_conf) = &mut self.no_unused_refs {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnusedSelectors" => {
+                if let Some(rule_conf) = &mut self.no_unused_selectors {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "noUnusedStoreSubscriptions" => {
+                if let Some(rule_conf) = &mut self.no_unused_store_subscriptions {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noUselessStringConcat" => {
                 if let Some(rule_conf) = &mut self.no_useless_string_concat {
                     rule_conf.set_level(severity);
                 }
             }
+            "noUselessUndefined" => {
+                if let Some(rule_conf) = &mut self.no_useless_undefined {
+                    rule_conf.set_level(severity);
+                }
+            }
             "noUselessUndefinedInitialization" => {
                 if let Some(rule_conf) = &mut self.no_useless_undefined_initialization {
                     rule_conf.set_level(severity);
                 }
             }
+            "noVendorPrefix" => {
+                if let Some(rule_conf) = &mut self.no_vendor_prefix {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useAllowedLengthUnits" => {
+                if let Some(rule_conf) = &mut self.use_allowed_length_units {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useAriaPropsSupportedByRole" => {
+                if let Some(rule_conf) = &mut self.use_aria_props_supported_by_role {
+                    rule_conf.set_level(severity);
+                }
+            }
             "useArrayLiterals" => {
                 if let Some(rule_conf) = &mut self.use_array_literals {
                     rule_conf.set_level(severity);
                 }
             }
+            "useAtIndex" => {
+                if let Some(rule_conf) = &mut self.use_at_index {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useAwaitStoryInteractions" => {
+                if let Some(rule_conf) = &mut self.use_await_story_interactions {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useAwaitThenable" => {
+                if let Some(rule_conf) = &mut self.use_await_thenable {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useColorContrast" => {
+                if let Some(rule_conf) = &mut self.use_color_contrast {
+                    rule_conf.set_level(severity);
+                }
+            }
             "useConsistentBuiltinInstantiation" => {
                 if let Some(rule_conf) = &mut self.use_consistent_builtin_instantiation {
                     rule_conf.set_level(severity);
                 }
             }
+            "useConsistentCurlyBraces" => {
+                if let Some(rule_conf) = &mut self.use_consistent_curly_braces {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useConsistentTestFunction" => {
+                if let Some(rule_conf) = &mut self.use_consistent_test_function {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useCssNamingConvention" => {
+                if let Some(rule_conf) = &mut self.use_css_naming_convention {
+                    rule_conf.set_level(severity);
+                }
+            }
             "useDefaultSwitchClause" => {
                 if let Some(rule_conf) = &mut self.use_default_switch_clause {
                     rule_conf.set_level(severity);
                 }
             }
+            "useDefinePropsOrder" => {
+                if let Some(rule_conf) = &mut self.use_define_props_order {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useExplicitFunctionReturnType" => {
+                if let Some(rule_conf) = &mut self.use_explicit_function_return_type {
+                    rule_conf.set_level(severity);
+                }
+            }
             "useExplicitLengthCheck" => {
                 if let Some(rule_conf) = &mut self.use_explicit_length_check {
                     rule_conf.set_level(severity);
                 }
             }
+            "useExplicitMemberAccessibility" => {
+                if let Some(rule_conf) = &mut self.use_explicit_member_accessibility {
+                    rule_conf.set_level(severity);
+                }
+            }
             "useFocusableInteractive" => {
                 if let Some(rule_conf) = &mut self.use_focusable_interactive {
                     rule_conf.set_level(severity);
@@ -4294,16 +6303,101 @@ impl Nursery {
                     rule_conf.set_level(severity);
                 }
             }
+            "useGoogleFontPreconnect" => {
+                if let Some(rule_conf) = &mut self.use_google_font_preconnect {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useGraphqlNamingConvention" => {
+                if let Some(rule_conf) = &mut self.use_graphql_naming_convention {
+                    rule_conf.set_level(severity);
+                }
+            }
             "useImportRestrictions" => {
                 if let Some(rule_conf) = &mut self.use_import_restrictions {
                     rule_conf.set_level(severity);
                 }
             }
+            "useLogicalProperties" => {
+                if let Some(rule_conf) = &mut self.use_logical_properties {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useNamedOperation" => {
+                if let Some(rule_conf) = &mut self.use_named_operation {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useNumericSeparators" => {
+                if let Some(rule_conf) = &mut self.use_numeric_separators {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useObjectSpread" => {
+                if let Some(rule_conf) = &mut self.use_object_spread {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useReadonlyClassMembers" => {
+                if let Some(rule_conf) = &mut self.use_readonly_class_members {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useRequiredArguments" => {
+                if let Some(rule_conf) = &mut self.use_required_arguments {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useRequiredPackageJsonFields" => {
+                if let Some(rule_conf) = &mut self.use_required_package_json_fields {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useShorthandProperties" => {
+                if let Some(rule_conf) = &mut self.use_shorthand_properties {
+                    rule_conf.set_level(severity);
+                }
+            }
             "useSortedClasses" => {
                 if let Some(rule_conf) = &mut self.use_sorted_classes {
                     rule_conf.set_level(severity);
                 }
             }
+            "useSortedKeys" => {
+                if let Some(rule_conf) = &mut self.use_sorted_keys {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useSortedPackageJsonKeys" => {
+                if let Some(rule_conf) = &mut self.use_sorted_package_json_keys {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useSortedProperties" => {
+                if let Some(rule_conf) = &mut self.use_sorted_properties {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useSortedTomlKeys" => {
+                if let Some(rule_conf) = &mut self.use_sorted_toml_keys {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useSortedYamlKeys" => {
+                if let Some(rule_conf) = &mut self.use_sorted_yaml_keys {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useStoryDefaultExport" => {
+                if let Some(rule_conf) = &mut self.use_story_default_export {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useStoryNamePascalCase" => {
+                if let Some(rule_conf) = &mut self.use_story_name_pascal_case {
+                    rule_conf.set_level(severity);
+                }
+            }
             "useThrowNewError" => {
                 if let Some(rule_conf) = &mut self.use_throw_new_error {
                     rule_conf.set_level(severity);
@@ -4314,6 +6408,36 @@ impl Nursery {
                     rule_conf.set_level(severity);
                 }
             }
+            "useTsconfigPathAliases" => {
+                if let Some(rule_conf) = &mut self.use_tsconfig_path_aliases {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useValidAutocomplete" => {
+                if let Some(rule_conf) = &mut self.use_valid_autocomplete {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useValidPackageJsonVersions" => {
+                if let Some(rule_conf) = &mut self.use_valid_package_json_versions {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useValidRunes" => {
+                if let Some(rule_conf) = &mut self.use_valid_runes {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useValidVariables" => {
+                if let Some(rule_conf) = &mut self.use_valid_variables {
+                    rule_conf.set_level(severity);
+                }
+            }
+            "useValidVueDirectives" => {
+                if let Some(rule_conf) = &mut self.use_valid_vue_directives {
+                    rule_conf.set_level(severity);
+                }
+            }
             _ => {}
         }
     }
@@ -0,0 +1,60 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{LineEnding, LineWidth};
+use biome_markdown_formatter::context::ProseWrap;
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to Markdown files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(default, deny_unknown_fields))]
+pub struct MarkdownConfiguration {
+    /// Formatting options
+    #[partial(type, bpaf(external(partial_markdown_formatter), optional))]
+    pub formatter: MarkdownFormatter,
+}
+
+/// Options that changes how the Markdown formatter behaves.
+///
+/// There's no indent style: the content of the list items is always aligned with spaces.
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct MarkdownFormatter {
+    /// Control the formatter for Markdown files.
+    #[partial(bpaf(long("markdown-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The type of line ending applied to Markdown files.
+    #[partial(bpaf(
+        long("markdown-formatter-line-ending"),
+        argument("lf|crlf|cr"),
+        optional
+    ))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to Markdown files. Defaults to 80.
+    #[partial(bpaf(long("markdown-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// How the lines of the paragraphs are wrapped. Defaults to "preserve".
+    #[partial(bpaf(
+        long("markdown-formatter-prose-wrap"),
+        argument("preserve|always|never"),
+        optional
+    ))]
+    pub prose_wrap: ProseWrap,
+}
+
+impl Default for MarkdownFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            prose_wrap: Default::default(),
+        }
+    }
+}
@@ -7,7 +7,7 @@ use crate::{
 };
 use biome_deserialize::StringSet;
 use biome_deserialize_macros::{Deserializable, Merge};
-use biome_formatter::{AttributePosition, LineEnding, LineWidth};
+use biome_formatter::{AttributePosition, LineEnding, LineWidth, MaxEmptyLines};
 use bpaf::Bpaf;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
@@ -131,6 +131,11 @@ pub struct OverrideFormatterConfiguration {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[bpaf(long("attribute-position"), argument("multiline|auto"), optional)]
     pub attribute_position: Option<AttributePosition>,
+
+    /// How many consecutive empty lines are kept. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[bpaf(long("max-empty-lines"), argument("NUMBER"), optional)]
+    pub max_empty_lines: Option<MaxEmptyLines>,
 }
 
 #[derive(
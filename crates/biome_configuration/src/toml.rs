@@ -0,0 +1,64 @@
+use crate::PlainIndentStyle;
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{LineEnding, LineWidth, QuoteStyle};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to TOML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(default, deny_unknown_fields))]
+pub struct TomlConfiguration {
+    /// Formatting options
+    #[partial(type, bpaf(external(partial_toml_formatter), optional))]
+    pub formatter: TomlFormatter,
+}
+
+/// Options that changes how the TOML formatter behaves
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct TomlFormatter {
+    /// Control the formatter for TOML files.
+    #[partial(bpaf(long("toml-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The indent style applied to TOML files.
+    #[partial(bpaf(long("toml-formatter-indent-style"), argument("tab|space"), optional))]
+    pub indent_style: Option<PlainIndentStyle>,
+
+    /// The size of the indentation applied to TOML files. Default to 2.
+    #[partial(bpaf(long("toml-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<u8>,
+
+    /// The type of line ending applied to TOML files.
+    #[partial(bpaf(long("toml-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to TOML files. Defaults to 80.
+    #[partial(bpaf(long("toml-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// The type of quotes used by the strings and the quoted keys of TOML files. Defaults to double.
+    #[partial(bpaf(
+        long("toml-formatter-quote-style"),
+        argument("double|single"),
+        optional
+    ))]
+    pub quote_style: QuoteStyle,
+}
+
+impl Default for TomlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_style: Default::default(),
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            quote_style: Default::default(),
+        }
+    }
+}
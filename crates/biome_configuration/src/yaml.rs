@@ -0,0 +1,60 @@
+use biome_deserialize_macros::{Deserializable, Merge, Partial};
+use biome_formatter::{LineEnding, LineWidth, QuoteStyle};
+use bpaf::Bpaf;
+use serde::{Deserialize, Serialize};
+
+/// Options applied to YAML files
+#[derive(Clone, Default, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(default, deny_unknown_fields))]
+pub struct YamlConfiguration {
+    /// Formatting options
+    #[partial(type, bpaf(external(partial_yaml_formatter), optional))]
+    pub formatter: YamlFormatter,
+}
+
+/// Options that changes how the YAML formatter behaves.
+///
+/// There's no indent style: YAML files are always indented with spaces.
+#[derive(Clone, Debug, Deserialize, Eq, Partial, PartialEq, Serialize)]
+#[partial(derive(Bpaf, Clone, Deserializable, Eq, Merge, PartialEq))]
+#[partial(cfg_attr(feature = "schema", derive(schemars::JsonSchema)))]
+#[partial(serde(rename_all = "camelCase", default, deny_unknown_fields))]
+pub struct YamlFormatter {
+    /// Control the formatter for YAML files.
+    #[partial(bpaf(long("yaml-formatter-enabled"), argument("true|false"), optional))]
+    pub enabled: bool,
+
+    /// The size of the indentation applied to YAML files. Default to 2.
+    #[partial(bpaf(long("yaml-formatter-indent-width"), argument("NUMBER"), optional))]
+    pub indent_width: Option<u8>,
+
+    /// The type of line ending applied to YAML files.
+    #[partial(bpaf(long("yaml-formatter-line-ending"), argument("lf|crlf|cr"), optional))]
+    pub line_ending: Option<LineEnding>,
+
+    /// What's the max width of a line applied to YAML files. Defaults to 80.
+    #[partial(bpaf(long("yaml-formatter-line-width"), argument("NUMBER"), optional))]
+    pub line_width: Option<LineWidth>,
+
+    /// The type of quotes used by the quoted scalars of YAML files. Defaults to double.
+    #[partial(bpaf(
+        long("yaml-formatter-quote-style"),
+        argument("double|single"),
+        optional
+    ))]
+    pub quote_style: QuoteStyle,
+}
+
+impl Default for YamlFormatter {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            indent_width: Default::default(),
+            line_ending: Default::default(),
+            line_width: Default::default(),
+            quote_style: Default::default(),
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use std::io;
-use std::io::{IsTerminal, Read, Write};
+use std::io::{BufRead, IsTerminal, Read, Write};
 use std::panic::RefUnwindSafe;
 use termcolor::{ColorChoice, StandardStream};
 use write::Termcolor;
@@ -36,6 +36,9 @@ pub trait Console: Send + Sync + RefUnwindSafe {
 
     /// It reads from a source, and if this source contains something, it's converted into a [String]
     fn read(&mut self) -> Option<String>;
+
+    /// It reads a line typed by the user, it returns [None] when there's nothing left to read
+    fn read_line(&mut self) -> Option<String>;
 }
 
 /// Extension trait for [Console] providing convenience printing methods
@@ -181,6 +184,16 @@ impl Console for EnvConsole {
             None
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // The question printed before the answer doesn't end with a newline
+        self.out.flush().ok()?;
+        let mut buffer = String::new();
+        match self.r#in.lock().read_line(&mut buffer) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(buffer),
+        }
+    }
 }
 
 /// Implementation of [Console] storing all printed messages to a memory buffer
@@ -228,6 +241,15 @@ impl Console for BufferConsole {
             Some(self.in_buffer[0].clone())
         }
     }
+
+    fn read_line(&mut self) -> Option<String> {
+        // Unlike `read`, each message is read once, as the answer to a prompt
+        if self.in_buffer.is_empty() {
+            None
+        } else {
+            Some(self.in_buffer.remove(0))
+        }
+    }
 }
 
 /// A horizontal line with the given print width
@@ -5021,14 +5021,414 @@ pub const MEDIA_FEATURE_NAMES: [&str; 60] = [
     "width",
 ];
 
+// https://drafts.csswg.org/css-logical/#box
+// The physical properties, sorted, and their equivalent in the horizontal-tb writing mode.
+pub const PHYSICAL_PROPERTIES: [(&str, &str); 40] = [
+    ("border-bottom", "border-block-end"),
+    ("border-bottom-color", "border-block-end-color"),
+    ("border-bottom-left-radius", "border-end-start-radius"),
+    ("border-bottom-right-radius", "border-end-end-radius"),
+    ("border-bottom-style", "border-block-end-style"),
+    ("border-bottom-width", "border-block-end-width"),
+    ("border-left", "border-inline-start"),
+    ("border-left-color", "border-inline-start-color"),
+    ("border-left-style", "border-inline-start-style"),
+    ("border-left-width", "border-inline-start-width"),
+    ("border-right", "border-inline-end"),
+    ("border-right-color", "border-inline-end-color"),
+    ("border-right-style", "border-inline-end-style"),
+    ("border-right-width", "border-inline-end-width"),
+    ("border-top", "border-block-start"),
+    ("border-top-color", "border-block-start-color"),
+    ("border-top-left-radius", "border-start-start-radius"),
+    ("border-top-right-radius", "border-start-end-radius"),
+    ("border-top-style", "border-block-start-style"),
+    ("border-top-width", "border-block-start-width"),
+    ("bottom", "inset-block-end"),
+    ("left", "inset-inline-start"),
+    ("margin-bottom", "margin-block-end"),
+    ("margin-left", "margin-inline-start"),
+    ("margin-right", "margin-inline-end"),
+    ("margin-top", "margin-block-start"),
+    ("padding-bottom", "padding-block-end"),
+    ("padding-left", "padding-inline-start"),
+    ("padding-right", "padding-inline-end"),
+    ("padding-top", "padding-block-start"),
+    ("right", "inset-inline-end"),
+    ("scroll-margin-bottom", "scroll-margin-block-end"),
+    ("scroll-margin-left", "scroll-margin-inline-start"),
+    ("scroll-margin-right", "scroll-margin-inline-end"),
+    ("scroll-margin-top", "scroll-margin-block-start"),
+    ("scroll-padding-bottom", "scroll-padding-block-end"),
+    ("scroll-padding-left", "scroll-padding-inline-start"),
+    ("scroll-padding-right", "scroll-padding-inline-end"),
+    ("scroll-padding-top", "scroll-padding-block-start"),
+    ("top", "inset-block-start"),
+];
+
+// https://drafts.csswg.org/css-logical/#directional-keywords
+// The properties, the physical values, and their equivalent in the horizontal-tb writing mode.
+pub const PHYSICAL_VALUES: [(&str, &str, &str); 6] = [
+    ("clear", "left", "inline-start"),
+    ("clear", "right", "inline-end"),
+    ("float", "left", "inline-start"),
+    ("float", "right", "inline-end"),
+    ("text-align", "left", "start"),
+    ("text-align", "right", "end"),
+];
+
+// The standard values and functions that the browsers supported with a vendor prefix, such as `-webkit-sticky`.
+pub const VENDOR_PREFIXED_STANDARD_VALUES: [&str; 12] = [
+    "calc",
+    "fit-content",
+    "flex",
+    "grab",
+    "grabbing",
+    "image-set",
+    "inline-flex",
+    "max-content",
+    "min-content",
+    "sticky",
+    "zoom-in",
+    "zoom-out",
+];
+
+// The order of the properties of the SMACSS methodology: box, border, background, text, and other properties.
+pub const SMACSS_PROPERTY_ORDER: [&str; 152] = [
+    // Box
+    "display",
+    "position",
+    "top",
+    "right",
+    "bottom",
+    "left",
+    "inset",
+    "z-index",
+    "flex",
+    "flex-basis",
+    "flex-direction",
+    "flex-flow",
+    "flex-grow",
+    "flex-shrink",
+    "flex-wrap",
+    "align-content",
+    "align-items",
+    "align-self",
+    "justify-content",
+    "justify-items",
+    "justify-self",
+    "order",
+    "gap",
+    "row-gap",
+    "column-gap",
+    "grid",
+    "grid-area",
+    "grid-template",
+    "grid-template-areas",
+    "grid-template-columns",
+    "grid-template-rows",
+    "grid-auto-columns",
+    "grid-auto-flow",
+    "grid-auto-rows",
+    "grid-column",
+    "grid-column-start",
+    "grid-column-end",
+    "grid-row",
+    "grid-row-start",
+    "grid-row-end",
+    "columns",
+    "column-count",
+    "column-width",
+    "float",
+    "clear",
+    "box-sizing",
+    "width",
+    "min-width",
+    "max-width",
+    "height",
+    "min-height",
+    "max-height",
+    "margin",
+    "margin-top",
+    "margin-right",
+    "margin-bottom",
+    "margin-left",
+    "padding",
+    "padding-top",
+    "padding-right",
+    "padding-bottom",
+    "padding-left",
+    "overflow",
+    "overflow-x",
+    "overflow-y",
+    "visibility",
+    // Border
+    "border",
+    "border-width",
+    "border-style",
+    "border-color",
+    "border-top",
+    "border-top-width",
+    "border-top-style",
+    "border-top-color",
+    "border-right",
+    "border-right-width",
+    "border-right-style",
+    "border-right-color",
+    "border-bottom",
+    "border-bottom-width",
+    "border-bottom-style",
+    "border-bottom-color",
+    "border-left",
+    "border-left-width",
+    "border-left-style",
+    "border-left-color",
+    "border-radius",
+    "border-top-left-radius",
+    "border-top-right-radius",
+    "border-bottom-right-radius",
+    "border-bottom-left-radius",
+    "border-image",
+    "outline",
+    "outline-width",
+    "outline-style",
+    "outline-color",
+    "outline-offset",
+    "box-shadow",
+    // Background
+    "background",
+    "background-color",
+    "background-image",
+    "background-repeat",
+    "background-position",
+    "background-size",
+    "background-attachment",
+    "background-clip",
+    "background-origin",
+    // Text
+    "color",
+    "font",
+    "font-family",
+    "font-size",
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "line-height",
+    "letter-spacing",
+    "word-spacing",
+    "text-align",
+    "text-decoration",
+    "text-indent",
+    "text-overflow",
+    "text-transform",
+    "text-shadow",
+    "white-space",
+    "word-break",
+    "overflow-wrap",
+    "vertical-align",
+    "list-style",
+    "list-style-type",
+    "list-style-position",
+    "list-style-image",
+    // Other
+    "opacity",
+    "cursor",
+    "pointer-events",
+    "content",
+    "quotes",
+    "transform",
+    "transform-origin",
+    "transition",
+    "transition-property",
+    "transition-duration",
+    "transition-timing-function",
+    "transition-delay",
+    "animation",
+    "animation-name",
+    "animation-duration",
+    "animation-timing-function",
+    "animation-delay",
+    "animation-iteration-count",
+    "animation-direction",
+    "animation-fill-mode",
+    "animation-play-state",
+];
+
+// https://drafts.csswg.org/css-color/#named-colors
+pub const NAMED_COLORS: [&str; 148] = [
+    "aliceblue",
+    "antiquewhite",
+    "aqua",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanchedalmond",
+    "blue",
+    "blueviolet",
+    "brown",
+    "burlywood",
+    "cadetblue",
+    "chartreuse",
+    "chocolate",
+    "coral",
+    "cornflowerblue",
+    "cornsilk",
+    "crimson",
+    "cyan",
+    "darkblue",
+    "darkcyan",
+    "darkgoldenrod",
+    "darkgray",
+    "darkgreen",
+    "darkgrey",
+    "darkkhaki",
+    "darkmagenta",
+    "darkolivegreen",
+    "darkorange",
+    "darkorchid",
+    "darkred",
+    "darksalmon",
+    "darkseagreen",
+    "darkslateblue",
+    "darkslategray",
+    "darkslategrey",
+    "darkturquoise",
+    "darkviolet",
+    "deeppink",
+    "deepskyblue",
+    "dimgray",
+    "dimgrey",
+    "dodgerblue",
+    "firebrick",
+    "floralwhite",
+    "forestgreen",
+    "fuchsia",
+    "gainsboro",
+    "ghostwhite",
+    "gold",
+    "goldenrod",
+    "gray",
+    "green",
+    "greenyellow",
+    "grey",
+    "honeydew",
+    "hotpink",
+    "indianred",
+    "indigo",
+    "ivory",
+    "khaki",
+    "lavender",
+    "lavenderblush",
+    "lawngreen",
+    "lemonchiffon",
+    "lightblue",
+    "lightcoral",
+    "lightcyan",
+    "lightgoldenrodyellow",
+    "lightgray",
+    "lightgreen",
+    "lightgrey",
+    "lightpink",
+    "lightsalmon",
+    "lightseagreen",
+    "lightskyblue",
+    "lightslategray",
+    "lightslategrey",
+    "lightsteelblue",
+    "lightyellow",
+    "lime",
+    "limegreen",
+    "linen",
+    "magenta",
+    "maroon",
+    "mediumaquamarine",
+    "mediumblue",
+    "mediumorchid",
+    "mediumpurple",
+    "mediumseagreen",
+    "mediumslateblue",
+    "mediumspringgreen",
+    "mediumturquoise",
+    "mediumvioletred",
+    "midnightblue",
+    "mintcream",
+    "mistyrose",
+    "moccasin",
+    "navajowhite",
+    "navy",
+    "oldlace",
+    "olive",
+    "olivedrab",
+    "orange",
+    "orangered",
+    "orchid",
+    "palegoldenrod",
+    "palegreen",
+    "paleturquoise",
+    "palevioletred",
+    "papayawhip",
+    "peachpuff",
+    "peru",
+    "pink",
+    "plum",
+    "powderblue",
+    "purple",
+    "rebeccapurple",
+    "red",
+    "rosybrown",
+    "royalblue",
+    "saddlebrown",
+    "salmon",
+    "sandybrown",
+    "seagreen",
+    "seashell",
+    "sienna",
+    "silver",
+    "skyblue",
+    "slateblue",
+    "slategray",
+    "slategrey",
+    "snow",
+    "springgreen",
+    "steelblue",
+    "tan",
+    "teal",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "whitesmoke",
+    "yellow",
+    "yellowgreen",
+];
+
+// https://drafts.csswg.org/css-color/#color-syntax
+pub const COLOR_FUNCTIONS: [&str; 10] = [
+    "color", "hsl", "hsla", "hwb", "lab", "lch", "oklab", "oklch", "rgb", "rgba",
+];
+
+// https://drafts.csswg.org/css-values-4/#lengths
+pub const LENGTH_UNITS: [&str; 51] = [
+    "cap", "ch", "cm", "cqb", "cqh", "cqi", "cqmax", "cqmin", "cqw", "dvb", "dvh", "dvi", "dvmax",
+    "dvmin", "dvw", "em", "ex", "ic", "in", "lh", "lvb", "lvh", "lvi", "lvmax", "lvmin", "lvw",
+    "mm", "mozmm", "pc", "pt", "px", "q", "rcap", "rch", "rem", "rex", "ric", "rlh", "rpx", "svb",
+    "svh", "svi", "svmax", "svmin", "svw", "vb", "vh", "vi", "vmax", "vmin", "vw",
+];
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashSet;
 
     use super::{
-        FUNCTION_KEYWORDS, KNOWN_EDGE_PROPERTIES, KNOWN_EXPLORER_PROPERTIES,
+        COLOR_FUNCTIONS, FUNCTION_KEYWORDS, KNOWN_EDGE_PROPERTIES, KNOWN_EXPLORER_PROPERTIES,
         KNOWN_FIREFOX_PROPERTIES, KNOWN_PROPERTIES, KNOWN_SAFARI_PROPERTIES,
-        KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES, MEDIA_FEATURE_NAMES,
+        KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES, LENGTH_UNITS,
+        MEDIA_FEATURE_NAMES, NAMED_COLORS, PHYSICAL_PROPERTIES, SMACSS_PROPERTY_ORDER,
+        VENDOR_PREFIXED_STANDARD_VALUES,
     };
 
     #[test]
@@ -5100,4 +5500,46 @@ mod tests {
             assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
         }
     }
+
+    #[test]
+    fn test_physical_properties_order() {
+        for items in PHYSICAL_PROPERTIES.windows(2) {
+            assert!(items[0].0 < items[1].0, "{} < {}", items[0].0, items[1].0);
+        }
+    }
+
+    #[test]
+    fn test_vendor_prefixed_standard_values_order() {
+        for items in VENDOR_PREFIXED_STANDARD_VALUES.windows(2) {
+            assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+        }
+    }
+
+    #[test]
+    fn test_named_colors_order() {
+        for items in NAMED_COLORS.windows(2) {
+            assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+        }
+    }
+
+    #[test]
+    fn test_color_functions_order() {
+        for items in COLOR_FUNCTIONS.windows(2) {
+            assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+        }
+    }
+
+    #[test]
+    fn test_length_units_order() {
+        for items in LENGTH_UNITS.windows(2) {
+            assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+        }
+    }
+
+    #[test]
+    fn test_smacss_property_order_unique() {
+        let mut set = HashSet::new();
+        let has_duplicates = SMACSS_PROPERTY_ORDER.iter().any(|&x| !set.insert(x));
+        assert!(!has_duplicates);
+    }
 }
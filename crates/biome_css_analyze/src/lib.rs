@@ -1,16 +1,22 @@
 mod keywords;
 mod lint;
 pub mod options;
+pub mod project;
 mod registry;
+mod specificity;
 mod utils;
 
 pub use crate::registry::visit_registry;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+    MetadataRegistry, RuleAction, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
 };
 use biome_css_syntax::CssLanguage;
 use biome_diagnostics::Error;
+use biome_project::StyleIndex;
+use std::sync::Arc;
+
+pub(crate) type CssRuleAction = RuleAction<CssLanguage>;
 
 /// Return the static [MetadataRegistry] for the JSON analyzer rules
 pub fn metadata() -> &'static MetadataRegistry {
@@ -27,18 +33,20 @@ pub fn metadata() -> &'static MetadataRegistry {
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
-/// then call `emit_signal` when an analysis rule emits a diagnostic or action
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// The selectors are compared with the markup of the project recorded by the `style_index`, if any
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<CssLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    style_index: Option<Arc<StyleIndex>>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<CssLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(root, filter, |_| {}, options, style_index, emit_signal)
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -52,6 +60,7 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    style_index: Option<Arc<StyleIndex>>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -67,13 +76,17 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
         return (None, diagnostics);
     }
 
+    if let Some(style_index) = style_index {
+        services.insert_service(style_index);
+    }
+
     let mut analyzer = biome_analyze::Analyzer::new(
         metadata(),
         biome_analyze::InspectMatcher::new(registry, inspect_matcher),
@@ -136,6 +149,7 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
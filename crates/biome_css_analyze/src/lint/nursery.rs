@@ -7,15 +7,26 @@ pub mod no_css_empty_block;
 pub mod no_duplicate_at_import_rules;
 pub mod no_duplicate_font_names;
 pub mod no_duplicate_selectors_keyframe_block;
+pub mod no_excessive_nesting_depth;
+pub mod no_hardcoded_colors;
+pub mod no_high_specificity_selectors;
 pub mod no_important_in_keyframe;
 pub mod no_invalid_position_at_import_rule;
+pub mod no_invalid_tailwind_apply;
 pub mod no_unknown_function;
 pub mod no_unknown_media_feature_name;
 pub mod no_unknown_property;
 pub mod no_unknown_selector_pseudo_element;
 pub mod no_unknown_unit;
 pub mod no_unmatchable_anb_selector;
+pub mod no_unused_selectors;
+pub mod no_vendor_prefix;
+pub mod use_allowed_length_units;
+pub mod use_css_naming_convention;
 pub mod use_generic_font_names;
+pub mod use_logical_properties;
+pub mod use_shorthand_properties;
+pub mod use_sorted_properties;
 
 declare_group! {
     pub Nursery {
@@ -26,15 +37,26 @@ declare_group! {
             self :: no_duplicate_at_import_rules :: NoDuplicateAtImportRules ,
             self :: no_duplicate_font_names :: NoDuplicateFontNames ,
             self :: no_duplicate_selectors_keyframe_block :: NoDuplicateSelectorsKeyframeBlock ,
+            self :: no_excessive_nesting_depth :: NoExcessiveNestingDepth ,
+            self :: no_hardcoded_colors :: NoHardcodedColors ,
+            self :: no_high_specificity_selectors :: NoHighSpecificitySelectors ,
             self :: no_important_in_keyframe :: NoImportantInKeyframe ,
             self :: no_invalid_position_at_import_rule :: NoInvalidPositionAtImportRule ,
+            self :: no_invalid_tailwind_apply :: NoInvalidTailwindApply ,
             self :: no_unknown_function :: NoUnknownFunction ,
             self :: no_unknown_media_feature_name :: NoUnknownMediaFeatureName ,
             self :: no_unknown_property :: NoUnknownProperty ,
             self :: no_unknown_selector_pseudo_element :: NoUnknownSelectorPseudoElement ,
             self :: no_unknown_unit :: NoUnknownUnit ,
             self :: no_unmatchable_anb_selector :: NoUnmatchableAnbSelector ,
+            self :: no_unused_selectors :: NoUnusedSelectors ,
+            self :: no_vendor_prefix :: NoVendorPrefix ,
+            self :: use_allowed_length_units :: UseAllowedLengthUnits ,
+            self :: use_css_naming_convention :: UseCssNamingConvention ,
             self :: use_generic_font_names :: UseGenericFontNames ,
+            self :: use_logical_properties :: UseLogicalProperties ,
+            self :: use_shorthand_properties :: UseShorthandProperties ,
+            self :: use_sorted_properties :: UseSortedProperties ,
         ]
      }
 }
@@ -0,0 +1,140 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_syntax::{stmt_ext::CssBlockLike, CssSyntaxKind};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{AstNode, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow rules that are nested too deeply.
+    ///
+    /// Deeply nested rules are hard to read, and they produce selectors with a high specificity
+    /// that are tied to the structure of the document.
+    ///
+    /// The nesting depth of a rule is the number of rules and at-rules with a block that contain it.
+    /// The rules at the root of a stylesheet have a depth of `0`.
+    ///
+    /// Only the outermost rule that exceeds the maximum depth is reported,
+    /// its nested rules aren't reported again.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .a {
+    ///   .b {
+    ///     .c {
+    ///       .d {
+    ///         .e {
+    ///           color: red;
+    ///         }
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .a {
+    ///   .b {
+    ///     .c {
+    ///       .d {
+    ///         color: red;
+    ///       }
+    ///     }
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxDepth`
+    ///
+    /// The maximum nesting depth of a rule. The default is `3`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "maxDepth": 2
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoExcessiveNestingDepth {
+        version: "next",
+        name: "noExcessiveNestingDepth",
+        language: "css",
+        sources: &[RuleSource::Stylelint("max-nesting-depth")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noExcessiveNestingDepth`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoExcessiveNestingDepthOptions {
+    /// The maximum nesting depth of a rule.
+    pub max_depth: u8,
+}
+
+impl Default for NoExcessiveNestingDepthOptions {
+    fn default() -> Self {
+        Self { max_depth: 3 }
+    }
+}
+
+impl Rule for NoExcessiveNestingDepth {
+    type Query = Ast<CssBlockLike>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = NoExcessiveNestingDepthOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let block = ctx.query();
+        let depth = block
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .filter(|ancestor| CssBlockLike::can_cast(ancestor.kind()))
+            .count();
+        // The nested rules of a reported rule are deeper, so they aren't reported again.
+        if depth != usize::from(ctx.options().max_depth) + 1 {
+            return None;
+        }
+        let mut rule = block.syntax().parent()?;
+        if let Some(at_rule) = rule
+            .parent()
+            .filter(|parent| parent.kind() == CssSyntaxKind::CSS_AT_RULE)
+        {
+            rule = at_rule;
+        }
+        // Report the prelude of the rule up to its opening curly brace.
+        Some(TextRange::new(
+            rule.text_trimmed_range().start(),
+            block.l_curly_token().ok()?.text_trimmed_range().end(),
+        ))
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        let max_depth = ctx.options().max_depth;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "This rule is nested deeper than the maximum depth of "{max_depth}"."
+                },
+            )
+            .note(markup! {
+                "Deeply nested rules are hard to read and produce selectors with a high specificity. Move the rule closer to the root of the stylesheet."
+            }),
+        )
+    }
+}
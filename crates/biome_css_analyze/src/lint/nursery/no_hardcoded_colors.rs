@@ -0,0 +1,283 @@
+use crate::utils::{is_color_function, is_named_color};
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    AnyCssExpression, AnyCssFunction, AnyCssValue, CssFunction, CssGenericProperty, CssSyntaxKind,
+    CssSyntaxToken, T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_rowan::{AstNode, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+declare_rule! {
+    /// Disallow hardcoded colors in declarations.
+    ///
+    /// The colors of a design system are usually stored in custom properties, such as
+    /// `--color-primary`. A color literal in a declaration bypasses the palette,
+    /// so it doesn't follow the changes of the palette and the themes.
+    ///
+    /// The rule reports the hexadecimal colors, such as `#3366ff`, the color functions,
+    /// such as `rgb()` or `oklch()`, and the named colors, such as `red`.
+    /// The keywords `transparent` and `currentColor`, the declarations of the custom properties,
+    /// and the fallback values of `var()` are ignored.
+    ///
+    /// When a color is exactly the color of a custom property of the option `palette`,
+    /// the code action replaces the color with this custom property.
+    /// The hexadecimal colors are compared without their case, and `#fff` is equal to `#ffffff`.
+    /// The code action is unsafe, because the custom property can be redefined in the document.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a { color: #3366ff; }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a { background: rgb(0 0 0 / 50%); }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a { border: 1px solid red; }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// :root { --color-primary: #3366ff; }
+    /// ```
+    ///
+    /// ```css
+    /// a { color: var(--color-primary); }
+    /// ```
+    ///
+    /// ```css
+    /// a { border-color: currentColor; background: transparent; }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `allow`
+    ///
+    /// The colors that can be written as literals.
+    ///
+    /// ### `palette`
+    ///
+    /// The custom properties of the palette, with their color.
+    /// A color that matches a custom property of the palette is replaced by the code action.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "allow": ["white", "#000"],
+    ///         "palette": {
+    ///             "--color-primary": "#3366ff",
+    ///             "--color-danger": "#d32f2f"
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoHardcodedColors {
+        version: "next",
+        name: "noHardcodedColors",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `noHardcodedColors`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoHardcodedColorsOptions {
+    /// The colors that can be written as literals.
+    pub allow: Vec<String>,
+    /// The custom properties of the palette, with their color.
+    pub palette: BTreeMap<String, String>,
+}
+
+pub struct HardcodedColor {
+    value: AnyCssValue,
+    /// The custom property of the palette that has the same color.
+    custom_property: Option<String>,
+}
+
+impl Rule for NoHardcodedColors {
+    type Query = Ast<CssGenericProperty>;
+    type State = HardcodedColor;
+    type Signals = Vec<Self::State>;
+    type Options = NoHardcodedColorsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        // The custom properties define the palette, their names are parsed as regular identifiers.
+        if node.name().is_ok_and(|name| name.text().starts_with("--")) {
+            return Vec::new();
+        }
+        let options = ctx.options();
+        node.value()
+            .syntax()
+            .descendants()
+            .filter_map(AnyCssValue::cast)
+            .filter(is_hardcoded_color)
+            .filter_map(|value| {
+                let color = normalize_color(&value.syntax().text_trimmed().to_string());
+                if options
+                    .allow
+                    .iter()
+                    .any(|allowed| normalize_color(allowed) == color)
+                {
+                    return None;
+                }
+                let custom_property = options
+                    .palette
+                    .iter()
+                    .find(|(name, palette_color)| {
+                        name.starts_with("--") && normalize_color(palette_color) == color
+                    })
+                    .map(|(name, _)| name.clone());
+                Some(HardcodedColor {
+                    value,
+                    custom_property,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let color = state.value.syntax().text_trimmed().to_string();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.value.range(),
+            markup! {
+                "The color "<Emphasis>{color}</Emphasis>" is hardcoded."
+            },
+        );
+        let diagnostic = match &state.custom_property {
+            Some(custom_property) => diagnostic.note(markup! {
+                "This color is the color of the custom property "<Emphasis>{custom_property}</Emphasis>" of the palette."
+            }),
+            None => diagnostic.note(markup! {
+                "Use a custom property of the palette with "<Emphasis>"var()"</Emphasis>", or add this color to the allowed colors."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let custom_property = state.custom_property.as_ref()?;
+        let mut mutation = ctx.root().begin();
+        let function = make::css_function(
+            make::css_identifier(CssSyntaxToken::new_detached(
+                CssSyntaxKind::IDENT,
+                "var",
+                [],
+                [],
+            )),
+            CssSyntaxToken::new_detached(T!['('], "(", [], []),
+            make::css_parameter_list(
+                [make::css_parameter(
+                    AnyCssExpression::CssListOfComponentValuesExpression(
+                        make::css_list_of_component_values_expression(
+                            make::css_component_value_list([AnyCssValue::CssDashedIdentifier(
+                                make::css_dashed_identifier(CssSyntaxToken::new_detached(
+                                    CssSyntaxKind::IDENT,
+                                    custom_property,
+                                    [],
+                                    [],
+                                )),
+                            )]),
+                        ),
+                    ),
+                )],
+                [],
+            ),
+            CssSyntaxToken::new_detached(T![')'], ")", [], []),
+        );
+        mutation.replace_node(
+            state.value.clone(),
+            AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)),
+        );
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use the custom property "<Emphasis>{custom_property}</Emphasis>" instead." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `value` is a color literal outside of the fallback of `var()`.
+fn is_hardcoded_color(value: &AnyCssValue) -> bool {
+    let is_color = match value {
+        AnyCssValue::CssColor(_) => true,
+        AnyCssValue::CssIdentifier(identifier) => {
+            // The name of a function, such as `tan()`, isn't a color.
+            value
+                .syntax()
+                .parent()
+                .is_some_and(|parent| parent.kind() != CssSyntaxKind::CSS_FUNCTION)
+                && identifier
+                    .value_token()
+                    .is_ok_and(|token| is_named_color(token.text_trimmed()))
+        }
+        AnyCssValue::AnyCssFunction(AnyCssFunction::CssFunction(function)) => {
+            function_name(function).is_some_and(|name| is_color_function(&name))
+        }
+        _ => false,
+    };
+    // The colors of a color function are reported with the function,
+    // for example `rgb(from red r g b)`.
+    is_color
+        && !value
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .filter_map(CssFunction::cast)
+            .any(|function| {
+                function_name(&function).is_some_and(|name| {
+                    name.eq_ignore_ascii_case("var") || is_color_function(&name)
+                })
+            })
+}
+
+fn function_name(function: &CssFunction) -> Option<String> {
+    Some(
+        function
+            .name()
+            .ok()?
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            .to_string(),
+    )
+}
+
+/// Returns `color` in lowercase, with single spaces, and with the short hexadecimal colors expanded.
+fn normalize_color(color: &str) -> String {
+    let color = color
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_lowercase()
+        .replace(", ", ",")
+        .replace(" ,", ",")
+        .replace("( ", "(")
+        .replace(" )", ")");
+    match color.strip_prefix('#') {
+        Some(hex) if matches!(hex.len(), 3 | 4) => std::iter::once('#')
+            .chain(hex.chars().flat_map(|c| [c, c]))
+            .collect(),
+        _ => color,
+    }
+}
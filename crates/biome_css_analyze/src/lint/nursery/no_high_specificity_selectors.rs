@@ -0,0 +1,128 @@
+use crate::specificity::{AnyCssQualifiedRule, Specificity};
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_syntax::CssSyntaxNode;
+use biome_deserialize_macros::Deserializable;
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow selectors whose specificity is higher than a maximum.
+    ///
+    /// A selector with a high specificity, such as `#header .nav ul li a`, can only be overridden
+    /// by a selector with an even higher specificity or by `!important`.
+    /// The specificities of a project then escalate, and the styles become hard to change.
+    ///
+    /// The specificity of a selector is made of the number of identifiers, the number of classes,
+    /// attributes, and pseudo-classes, and the number of types and pseudo-elements.
+    /// The specificities are compared in this order, so `1,0,0` is higher than `0,9,9`.
+    /// The specificity of the arguments of `:is()`, `:not()`, and `:has()` is the highest
+    /// specificity among them, while `:where()` has no specificity.
+    /// The specificity of a nested selector includes the specificity of its parent selector.
+    ///
+    /// The diagnostic reports the specificity of the selector.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// #header .nav {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .a .b .c .d .e {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// .card {
+    ///   .title .link:hover .icon {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .nav .link:hover {}
+    /// ```
+    ///
+    /// ```css
+    /// :where(#header) .nav {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxSpecificity`
+    ///
+    /// The highest specificity that is allowed, as three numbers separated by commas.
+    /// The default is `0,4,0`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "maxSpecificity": "0,3,0"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoHighSpecificitySelectors {
+        version: "next",
+        name: "noHighSpecificitySelectors",
+        language: "css",
+        sources: &[RuleSource::Stylelint("selector-max-specificity")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noHighSpecificitySelectors`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoHighSpecificitySelectorsOptions {
+    /// The highest specificity that is allowed, such as `0,4,0`.
+    pub max_specificity: Specificity,
+}
+
+impl Default for NoHighSpecificitySelectorsOptions {
+    fn default() -> Self {
+        Self {
+            max_specificity: Specificity(0, 4, 0),
+        }
+    }
+}
+
+impl Rule for NoHighSpecificitySelectors {
+    type Query = Ast<AnyCssQualifiedRule>;
+    type State = (CssSyntaxNode, Specificity);
+    type Signals = Vec<Self::State>;
+    type Options = NoHighSpecificitySelectorsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let max_specificity = ctx.options().max_specificity;
+        ctx.query()
+            .selector_specificities()
+            .into_iter()
+            .filter(|(_, specificity)| *specificity > max_specificity)
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (selector, specificity) = state;
+        let specificity = specificity.to_string();
+        let max_specificity = ctx.options().max_specificity.to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                selector.text_trimmed_range(),
+                markup! {
+                    "This selector has the specificity "<Emphasis>{specificity}</Emphasis>", which is higher than the maximum "<Emphasis>{max_specificity}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "A selector with a high specificity is hard to override. Use fewer identifiers and classes, or wrap a part of the selector in "<Emphasis>":where()"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,185 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::TwApplyAtRule;
+use biome_rowan::TextRange;
+use std::collections::HashSet;
+
+declare_rule! {
+    /// Disallow invalid utilities in the `@apply` directive of Tailwind CSS.
+    ///
+    /// The `@apply` directive inlines existing utility classes in a custom CSS rule.
+    /// Tailwind CSS fails to compile the stylesheet when one of the utilities can't be applied.
+    ///
+    /// This rule reports the utilities that are:
+    /// - written as a selector, such as `.font-bold`;
+    /// - preceded or followed by an empty variant, such as `hover:` or `:underline`;
+    /// - using an arbitrary value with unbalanced or empty brackets, such as `w-[100px` or `w-[]`;
+    /// - `group` and `peer`, which can't be used with `@apply`;
+    /// - applied twice in the same directive.
+    ///
+    /// This rule only applies when the directives of Tailwind CSS are enabled with the `css.parser.tailwind` option.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,ignore
+    /// .btn {
+    ///     @apply .font-bold py-2;
+    /// }
+    /// ```
+    ///
+    /// ```css,ignore
+    /// .btn {
+    ///     @apply hover: bg-blue-700;
+    /// }
+    /// ```
+    ///
+    /// ```css,ignore
+    /// .btn {
+    ///     @apply px-4 py-2 px-4;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css,ignore
+    /// .btn {
+    ///     @apply font-bold py-2 px-4 hover:bg-blue-700 w-[calc(100%_-_2rem)];
+    /// }
+    /// ```
+    ///
+    pub NoInvalidTailwindApply {
+        version: "next",
+        name: "noInvalidTailwindApply",
+        language: "css",
+        recommended: false,
+    }
+}
+
+pub enum InvalidUtilityKind {
+    Selector,
+    EmptyVariant,
+    InvalidArbitraryValue,
+    NotApplicable,
+    Duplicate,
+}
+
+pub struct InvalidUtility {
+    name: String,
+    kind: InvalidUtilityKind,
+    range: TextRange,
+}
+
+impl Rule for NoInvalidTailwindApply {
+    type Query = Ast<TwApplyAtRule>;
+    type State = InvalidUtility;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Vec<Self::State> {
+        let node = ctx.query();
+        let mut seen = HashSet::new();
+        let mut invalid_utilities = vec![];
+
+        for class in node.classes() {
+            let Ok(value_token) = class.value_token() else {
+                continue;
+            };
+            let name = value_token.text_trimmed();
+
+            let kind = if let Some(kind) = find_invalid_utility_kind(name) {
+                kind
+            } else if !seen.insert(name.to_string()) {
+                InvalidUtilityKind::Duplicate
+            } else {
+                continue;
+            };
+
+            invalid_utilities.push(InvalidUtility {
+                name: name.to_string(),
+                kind,
+                range: value_token.text_trimmed_range(),
+            });
+        }
+
+        invalid_utilities
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                "The utility "<Emphasis>{name}</Emphasis>" can't be applied."
+            },
+        );
+
+        let diagnostic = match state.kind {
+            InvalidUtilityKind::Selector => diagnostic.note(markup! {
+                "The utilities of "<Emphasis>"@apply"</Emphasis>" are class names, remove the leading dot."
+            }),
+            InvalidUtilityKind::EmptyVariant => diagnostic.note(markup! {
+                "A variant must be directly followed by a utility, without whitespace between them."
+            }),
+            InvalidUtilityKind::InvalidArbitraryValue => diagnostic.note(markup! {
+                "The arbitrary value of this utility has unbalanced or empty brackets."
+            }),
+            InvalidUtilityKind::NotApplicable => diagnostic.note(markup! {
+                "Tailwind CSS doesn't allow "<Emphasis>"group"</Emphasis>" and "<Emphasis>"peer"</Emphasis>" to be used with "<Emphasis>"@apply"</Emphasis>", add them to the markup instead."
+            }),
+            InvalidUtilityKind::Duplicate => diagnostic.note(markup! {
+                "This utility is already applied by this directive, remove it."
+            }),
+        };
+
+        Some(diagnostic)
+    }
+}
+
+fn find_invalid_utility_kind(name: &str) -> Option<InvalidUtilityKind> {
+    if name.starts_with('.') {
+        return Some(InvalidUtilityKind::Selector);
+    }
+
+    let bytes = name.as_bytes();
+    let mut depth = 0usize;
+    // Splits the variants and the utility, ignoring the colons of the arbitrary values.
+    let mut parts = vec![];
+    let mut start = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        match byte {
+            b'[' if bytes.get(index + 1) == Some(&b']') => {
+                return Some(InvalidUtilityKind::InvalidArbitraryValue)
+            }
+            b'[' => depth += 1,
+            b']' if depth == 0 => return Some(InvalidUtilityKind::InvalidArbitraryValue),
+            b']' => depth -= 1,
+            b':' if depth == 0 => {
+                parts.push(&name[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth > 0 {
+        return Some(InvalidUtilityKind::InvalidArbitraryValue);
+    }
+    parts.push(&name[start..]);
+
+    if parts.iter().any(|part| part.is_empty()) {
+        return Some(InvalidUtilityKind::EmptyVariant);
+    }
+
+    // The `!` modifier makes the utility important, such as `!font-bold`.
+    let utility = parts.last()?.trim_start_matches('!');
+    if matches!(utility, "group" | "peer")
+        || utility.starts_with("group/")
+        || utility.starts_with("peer/")
+    {
+        return Some(InvalidUtilityKind::NotApplicable);
+    }
+
+    None
+}
@@ -0,0 +1,120 @@
+use crate::project::identifier_text;
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_css_syntax::AnyCssSubSelector;
+use biome_project::StyleIndex;
+use biome_rowan::{AstNode, TextRange};
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow class and identifier selectors that aren't referenced by the markup of the project.
+    ///
+    /// A selector that matches no element is dead code: it increases the size of the stylesheets,
+    /// and it's often the leftover of a component that was renamed or removed.
+    ///
+    /// The rule compares the class selectors and the identifier selectors with the static values of the
+    /// `className`, `class` and `id` attributes of the JSX files of the project.
+    /// The values computed at runtime can't be known, so the rule only reports the selectors
+    /// when the project contains at least one markup file, and the class names built dynamically,
+    /// such as `` `button-${size}` ``, aren't recognized.
+    ///
+    /// ## Examples
+    ///
+    /// In the following examples, the project contains the JSX file:
+    ///
+    /// ```jsx,ignore
+    /// <button className="button primary" id="submit" />
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,ignore
+    /// .button-secondary {}
+    /// ```
+    ///
+    /// ```css,ignore
+    /// #cancel {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css,ignore
+    /// .button.primary:hover {}
+    /// ```
+    ///
+    /// ```css,ignore
+    /// #submit {}
+    /// ```
+    ///
+    pub NoUnusedSelectors {
+        version: "next",
+        name: "noUnusedSelectors",
+        language: "css",
+        recommended: false,
+    }
+}
+
+/// The kind of a selector that references the markup.
+pub enum UnusedSelectorKind {
+    Class,
+    Id,
+}
+
+pub struct UnusedSelector {
+    kind: UnusedSelectorKind,
+    name: String,
+    range: TextRange,
+}
+
+impl Rule for NoUnusedSelectors {
+    type Query = Ast<AnyCssSubSelector>;
+    type State = UnusedSelector;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let style_index = ctx.get_service::<Arc<StyleIndex>>()?;
+        if !style_index.has_markup() {
+            return None;
+        }
+        match ctx.query() {
+            AnyCssSubSelector::CssClassSelector(selector) => {
+                let name = identifier_text(&selector.name().ok()?)?;
+                (!style_index.is_class_referenced(&name)).then(|| UnusedSelector {
+                    kind: UnusedSelectorKind::Class,
+                    name,
+                    range: selector.range(),
+                })
+            }
+            AnyCssSubSelector::CssIdSelector(selector) => {
+                let name = identifier_text(&selector.name().ok()?)?;
+                (!style_index.is_id_referenced(&name)).then(|| UnusedSelector {
+                    kind: UnusedSelectorKind::Id,
+                    name,
+                    range: selector.range(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let UnusedSelector { kind, name, range } = state;
+        let kind = match kind {
+            UnusedSelectorKind::Class => "class",
+            UnusedSelectorKind::Id => "identifier",
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                *range,
+                markup! {
+                    "The "{kind}" "<Emphasis>{name}</Emphasis>" isn't used by the markup of the project."
+                },
+            )
+            .note(markup! {
+                "This selector doesn't match any element. Remove it, or use it in the markup."
+            }),
+        )
+    }
+}
@@ -0,0 +1,211 @@
+use crate::utils::{is_standard_property, is_vendor_prefixed_standard_value, vender_prefix};
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssFunction, AnyCssGenericComponentValue, AnyCssValue,
+    CssGenericProperty, CssSyntaxKind, CssSyntaxToken,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_rowan::BatchMutationExt;
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow vendor prefixes on properties and values that have a standard equivalent.
+    ///
+    /// The browsers support the standard version of most of the properties and values that
+    /// used to require a vendor prefix, such as `-webkit-transition` or `position: -webkit-sticky`.
+    /// The prefixed versions are dead code for the browsers that support the standard version.
+    ///
+    /// The rule only reports the prefixed properties whose name without the prefix is a standard
+    /// property, and the prefixed values and functions whose name without the prefix is a standard
+    /// value, such as `sticky`, `fit-content`, or `calc()`.
+    ///
+    /// The code action removes the prefix. It's unsafe, because the styles change for the browsers
+    /// that only support the prefixed version.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a { -webkit-transition: color 1s; }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a { position: -webkit-sticky; }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a { width: -moz-calc(100% - 1rem); }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a { transition: color 1s; }
+    /// ```
+    ///
+    /// ```css
+    /// a { -webkit-text-stroke: 1px black; }
+    /// ```
+    ///
+    /// ```css
+    /// a { display: -webkit-box; }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `allow`
+    ///
+    /// The prefixed properties and values that are allowed,
+    /// because the browsers that you support still require them.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "allow": ["-webkit-line-clamp", "-webkit-fit-content"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoVendorPrefix {
+        version: "next",
+        name: "noVendorPrefix",
+        language: "css",
+        sources: &[
+            RuleSource::Stylelint("property-no-vendor-prefix"),
+            RuleSource::Stylelint("value-no-vendor-prefix"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `noVendorPrefix`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoVendorPrefixOptions {
+    /// The prefixed properties and values that are allowed.
+    pub allow: Vec<String>,
+}
+
+pub enum PrefixedKind {
+    Property,
+    Value,
+}
+
+pub struct PrefixedName {
+    kind: PrefixedKind,
+    /// The name of the property, or the value or the name of the function.
+    token: CssSyntaxToken,
+    prefix_len: usize,
+}
+
+impl Rule for NoVendorPrefix {
+    type Query = Ast<CssGenericProperty>;
+    type State = PrefixedName;
+    type Signals = Vec<Self::State>;
+    type Options = NoVendorPrefixOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let allow = &ctx.options().allow;
+        let prefixed_name = |kind: PrefixedKind, token: CssSyntaxToken| {
+            let name = token.text_trimmed().to_lowercase();
+            let prefix_len = vender_prefix(&name).len();
+            if prefix_len == 0
+                || allow
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(&name))
+            {
+                return None;
+            }
+            let is_standard = match kind {
+                PrefixedKind::Property => is_standard_property(&name[prefix_len..]),
+                PrefixedKind::Value => is_vendor_prefixed_standard_value(&name[prefix_len..]),
+            };
+            is_standard.then_some(PrefixedName {
+                kind,
+                token,
+                prefix_len,
+            })
+        };
+        let mut signals = Vec::new();
+        if let Ok(AnyCssDeclarationName::CssIdentifier(name)) = node.name() {
+            if let Some(signal) = name
+                .value_token()
+                .ok()
+                .and_then(|token| prefixed_name(PrefixedKind::Property, token))
+            {
+                signals.push(signal);
+            }
+        }
+        for value in node.value() {
+            let identifier = match value {
+                AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(
+                    identifier,
+                )) => identifier,
+                AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::AnyCssFunction(
+                    AnyCssFunction::CssFunction(function),
+                )) => {
+                    let Ok(name) = function.name() else {
+                        continue;
+                    };
+                    name
+                }
+                _ => continue,
+            };
+            if let Some(signal) = identifier
+                .value_token()
+                .ok()
+                .and_then(|token| prefixed_name(PrefixedKind::Value, token))
+            {
+                signals.push(signal);
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let prefixed = state.token.text_trimmed();
+        let standard = &prefixed[state.prefix_len..];
+        let kind = match state.kind {
+            PrefixedKind::Property => "property",
+            PrefixedKind::Value => "value",
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.token.text_trimmed_range(),
+                markup! {
+                    "The vendor prefix of the "{kind}" "<Emphasis>{prefixed}</Emphasis>" is unnecessary."
+                },
+            )
+            .note(markup! {
+                "Use the standard "{kind}" "<Emphasis>{standard}</Emphasis>", or allow the prefix if the browsers that you support still require it."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let standard = &state.token.text_trimmed()[state.prefix_len..];
+        mutation.replace_token_transfer_trivia(
+            state.token.clone(),
+            CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, standard, [], []),
+        );
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Remove the vendor prefix." }.to_owned(),
+            mutation,
+        ))
+    }
+}
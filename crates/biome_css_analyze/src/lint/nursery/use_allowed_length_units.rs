@@ -0,0 +1,286 @@
+use crate::utils::is_length_unit;
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_css_syntax::{CssGenericProperty, CssRegularDimension, CssSyntaxKind, CssSyntaxToken};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_rowan::{AstNode, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+declare_rule! {
+    /// Enforce the length units allowed in the declarations.
+    ///
+    /// A design system usually sizes the texts and the spacings with `rem`, so that they follow
+    /// the font size chosen by the user, and keeps `px` for the details that shouldn't scale,
+    /// such as the borders.
+    ///
+    /// By default, the rule reports the lengths in `px` of every property.
+    /// The option `properties` sets the length units allowed in some properties:
+    /// the other length units are reported in these properties.
+    ///
+    /// When `rem` is allowed in the property, the code action converts a length in `px` to `rem`
+    /// with the option `rootFontSize`, and the diagnostic reports the converted length.
+    /// The code action is unsafe, because the root font size of the document can be different.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a { font-size: 24px; }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a { padding: 8px calc(1rem + 4px); }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a { font-size: 1.5rem; }
+    /// ```
+    ///
+    /// ```css
+    /// a { width: 50%; transition: opacity 200ms; }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `rootFontSize`
+    ///
+    /// The size of the root font in pixels, used to convert `px` to `rem`. `16` by default.
+    ///
+    /// ### `properties`
+    ///
+    /// The length units allowed in a property. A name that ends with `*` matches all the properties
+    /// that start with this name, such as `border*` for `border` and `border-top-width`.
+    /// The exact names take precedence over the patterns, and the longest pattern wins.
+    ///
+    /// The following configuration allows only `rem` in the font sizes, and only `px` in the borders:
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "rootFontSize": 10,
+    ///         "properties": {
+    ///             "font-size": ["rem"],
+    ///             "border*": ["px"]
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseAllowedLengthUnits {
+        version: "next",
+        name: "useAllowedLengthUnits",
+        language: "css",
+        sources: &[RuleSource::Stylelint("declaration-property-unit-allowed-list")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useAllowedLengthUnits`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseAllowedLengthUnitsOptions {
+    /// The size of the root font in pixels, used to convert `px` to `rem`.
+    pub root_font_size: u16,
+    /// The length units allowed in a property. A name that ends with `*` matches all the properties that start with this name.
+    pub properties: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for UseAllowedLengthUnitsOptions {
+    fn default() -> Self {
+        Self {
+            root_font_size: 16,
+            properties: BTreeMap::new(),
+        }
+    }
+}
+
+impl UseAllowedLengthUnitsOptions {
+    /// Returns the length units allowed in `property`, or `None` if the property isn't configured.
+    fn allowed_units(&self, property: &str) -> Option<&[String]> {
+        if let Some(units) = self.properties.get(property) {
+            return Some(units);
+        }
+        self.properties
+            .iter()
+            .filter_map(|(name, units)| {
+                let prefix = name.strip_suffix('*')?;
+                property
+                    .starts_with(prefix)
+                    .then_some((prefix.len(), units))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, units)| units.as_slice())
+    }
+}
+
+pub struct DisallowedLength {
+    dimension: CssRegularDimension,
+    property: String,
+    unit: String,
+    /// The allowed units of the property, if the property is configured.
+    allowed_units: Option<Vec<String>>,
+    /// The length converted to `rem`.
+    rem: Option<String>,
+}
+
+impl Rule for UseAllowedLengthUnits {
+    type Query = Ast<CssGenericProperty>;
+    type State = DisallowedLength;
+    type Signals = Vec<Self::State>;
+    type Options = UseAllowedLengthUnitsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        let Ok(name) = node.name() else {
+            return Vec::new();
+        };
+        let property = name.syntax().text_trimmed().to_string().to_lowercase();
+        let allowed_units = options.allowed_units(&property);
+        let is_allowed = |unit: &str| match allowed_units {
+            Some(units) => units
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(unit)),
+            None => unit != "px",
+        };
+        node.value()
+            .syntax()
+            .descendants()
+            .filter_map(CssRegularDimension::cast)
+            .filter_map(|dimension| {
+                let unit = dimension
+                    .unit_token()
+                    .ok()?
+                    .text_trimmed()
+                    .to_ascii_lowercase();
+                if !is_length_unit(&unit) || is_allowed(&unit) {
+                    return None;
+                }
+                let rem = if unit == "px" && is_allowed("rem") && options.root_font_size > 0 {
+                    let value = dimension.value_token().ok()?;
+                    let pixels = value.text_trimmed().parse::<f64>().ok()?;
+                    Some(format_number(pixels / f64::from(options.root_font_size)))
+                } else {
+                    None
+                };
+                Some(DisallowedLength {
+                    dimension,
+                    property: property.clone(),
+                    unit,
+                    allowed_units: allowed_units.map(<[String]>::to_vec),
+                    rem,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let unit = &state.unit;
+        let property = &state.property;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.dimension.range(),
+            markup! {
+                "The unit "<Emphasis>{unit}</Emphasis>" isn't allowed in the property "<Emphasis>{property}</Emphasis>"."
+            },
+        );
+        let diagnostic = if let Some(rem) = &state.rem {
+            let length = state.dimension.syntax().text_trimmed().to_string();
+            let root_font_size = ctx.options().root_font_size;
+            diagnostic.note(markup! {
+                <Emphasis>{length}</Emphasis>" is "<Emphasis>{rem}"rem"</Emphasis>" with a root font size of "{root_font_size}"px."
+            })
+        } else if let Some(allowed_units) = &state.allowed_units {
+            let allowed_units = allowed_units.join(", ");
+            diagnostic.note(markup! {
+                "The allowed units of this property are "<Emphasis>{allowed_units}</Emphasis>"."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Use a relative unit, such as "<Emphasis>"rem"</Emphasis>", instead."
+            })
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let rem = state.rem.as_ref()?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(
+            state.dimension.value_token().ok()?,
+            CssSyntaxToken::new_detached(CssSyntaxKind::CSS_NUMBER_LITERAL, rem, [], []),
+        );
+        mutation.replace_token_transfer_trivia(
+            state.dimension.unit_token().ok()?,
+            CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, "rem", [], []),
+        );
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use "<Emphasis>{rem}"rem"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Formats `value` with at most four decimals, without the trailing zeros.
+fn format_number(value: f64) -> String {
+    let formatted = format!("{value:.4}");
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    match formatted {
+        "-0" => "0".to_string(),
+        _ => formatted.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(1.5), "1.5");
+        assert_eq!(format_number(1.0), "1");
+        assert_eq!(format_number(0.0625), "0.0625");
+        assert_eq!(format_number(10.0 / 12.0), "0.8333");
+        assert_eq!(format_number(-0.5), "-0.5");
+        assert_eq!(format_number(0.0), "0");
+    }
+
+    #[test]
+    fn test_allowed_units() {
+        let options = UseAllowedLengthUnitsOptions {
+            properties: BTreeMap::from([
+                ("border*".to_string(), vec!["px".to_string()]),
+                ("border-radius".to_string(), vec!["rem".to_string()]),
+                ("border-top*".to_string(), vec!["em".to_string()]),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.allowed_units("border-width"),
+            Some(&["px".to_string()][..])
+        );
+        assert_eq!(
+            options.allowed_units("border-radius"),
+            Some(&["rem".to_string()][..])
+        );
+        assert_eq!(
+            options.allowed_units("border-top-width"),
+            Some(&["em".to_string()][..])
+        );
+        assert_eq!(options.allowed_units("font-size"), None);
+    }
+}
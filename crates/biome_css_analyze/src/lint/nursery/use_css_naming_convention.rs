@@ -0,0 +1,547 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssKeyframeName, CssClassSelector, CssGenericProperty,
+    CssKeyframesAtRule, CssSyntaxToken,
+};
+use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic, RestrictedRegex};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{declare_node_union, TextRange, TextSize};
+use biome_string_case::{Case, Cases};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Enforce naming conventions for the class names, the keyframes names, and the custom properties.
+    ///
+    /// By default, the names are in [`kebab-case`].
+    /// The dashes of the custom properties, such as `--main-color`, aren't part of their name.
+    /// You can also enforce [BEM](https://getbem.com/naming/) or custom conventions with the [rule options](#options).
+    ///
+    /// The names that include an escape sequence, such as `.md\:flex`, are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// .buttonPrimary {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// @keyframes fade_in {}
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a { --mainColor: red; }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// .button-primary {
+    ///   --main-color: red;
+    ///   animation: fade-in 1s;
+    /// }
+    ///
+    /// @keyframes fade-in {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides several options that are detailed in the following subsections.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "bem": {
+    ///             "element": "__",
+    ///             "modifier": "_"
+    ///         },
+    ///         "conventions": [
+    ///             {
+    ///                 "selector": {
+    ///                     "kind": "class"
+    ///                 },
+    ///                 "format": "BEM"
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### conventions
+    ///
+    /// The `conventions` option allows applying custom conventions.
+    /// The option takes an array of conventions.
+    /// Every convention is an object that includes a `selector`, a `format` or a regular expression to `match`,
+    /// and an optional `message` displayed when the convention isn't fulfilled.
+    ///
+    /// The `selector` includes a `kind` that selects the names:
+    ///
+    /// - `any`: all the names (the default)
+    /// - `class`: the names of the class selectors
+    /// - `keyframes`: the names of the `@keyframes` at-rules
+    /// - `customProperty`: the names of the custom properties, without their dashes
+    ///
+    /// The supported formats are [`kebab-case`], [`camelCase`], [`snake_case`], and `BEM`.
+    ///
+    /// The `match` option takes a regular expression that the name must match.
+    /// The regular expression is implicitly delimited by `^` and `$`,
+    /// and it supports the same syntaxes as the option `match` of the rule [useNamingConvention](https://biomejs.dev/linter/rules/use-naming-convention#regular-expression-syntax).
+    /// When a convention includes both a `format` and `match`, the name must fulfill both.
+    ///
+    /// A name is checked against the first convention that selects it.
+    /// When no convention selects the name, the default convention applies.
+    ///
+    /// For example, the following configuration requires the names of the classes of the components
+    /// to start with the prefix `c-`:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "conventions": [
+    ///             {
+    ///                 "selector": {
+    ///                     "kind": "class"
+    ///                 },
+    ///                 "format": "kebab-case",
+    ///                 "match": "(?:c|u|is)-.+",
+    ///                 "message": "The classes are prefixed by their layer: c- for the components, u- for the utilities, and is- for the states."
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### bem
+    ///
+    /// The separators of the `BEM` format.
+    /// A `BEM` name is made of a block, an optional element, and an optional modifier,
+    /// such as `card__title--large`. Every part is in [`kebab-case`].
+    ///
+    /// - `element`: the separator between the block and the element. Default: `__`
+    /// - `modifier`: the separator before the modifier. Default: `--`
+    ///
+    /// [`kebab-case`]: https://en.wikipedia.org/wiki/Letter_case#Kebab_case
+    /// [`camelCase`]: https://en.wikipedia.org/wiki/Camel_case
+    /// [`snake_case`]: https://en.wikipedia.org/wiki/Snake_case
+    pub UseCssNamingConvention {
+        version: "next",
+        name: "useCssNamingConvention",
+        language: "css",
+        sources: &[
+            RuleSource::Stylelint("selector-class-pattern"),
+            RuleSource::Stylelint("keyframes-name-pattern"),
+            RuleSource::Stylelint("custom-property-pattern"),
+        ],
+        recommended: false,
+    }
+}
+
+impl Rule for UseCssNamingConvention {
+    type Query = Ast<AnyCssNamedItem>;
+    type State = State;
+    type Signals = Option<Self::State>;
+    type Options = CssNamingConventionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        let (kind, name_token, prefix_len) = node.name()?;
+        let name = &name_token.text_trimmed()[prefix_len..];
+        if name.is_empty() || name.contains('\\') {
+            return None;
+        }
+        let convention = options
+            .conventions
+            .iter()
+            .position(|convention| convention.selector.kind.contains(kind));
+        let (format, matching) = match convention {
+            Some(index) => {
+                let convention = &options.conventions[index];
+                (convention.format, convention.matching.as_ref())
+            }
+            None => (Some(Format::Kebab), None),
+        };
+        let violation = if format.is_some_and(|format| !format.is_fulfilled(name, &options.bem)) {
+            Violation::Format
+        } else if matching.is_some_and(|regex| !regex.is_match(name)) {
+            Violation::Match
+        } else {
+            return None;
+        };
+        Some(State {
+            kind,
+            name_range: TextRange::at(
+                name_token.text_trimmed_range().start() + TextSize::from(prefix_len as u32),
+                TextSize::from(name.len() as u32),
+            ),
+            convention,
+            violation,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let State {
+            kind,
+            name_range,
+            convention,
+            violation,
+        } = state;
+        let options = ctx.options();
+        let convention = convention.map(|index| &options.conventions[index]);
+        let diagnostic = match violation {
+            Violation::Format => {
+                let format = convention
+                    .and_then(|convention| convention.format)
+                    .unwrap_or(Format::Kebab);
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    name_range,
+                    markup! {
+                        "This "<Emphasis>{format_args!("{kind}")}</Emphasis>" name should be in "<Emphasis>{format_args!("{format}")}</Emphasis>"."
+                    },
+                );
+                if format == Format::Bem {
+                    let BemSeparators { element, modifier } = &options.bem;
+                    diagnostic.note(markup! {
+                        "A BEM name is made of a block, an optional element, and an optional modifier in kebab-case, such as "<Emphasis>"block"{element}"element"{modifier}"modifier"</Emphasis>"."
+                    })
+                } else {
+                    diagnostic
+                }
+            }
+            Violation::Match => {
+                let regex = convention?.matching.as_ref()?.as_source();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    name_range,
+                    markup! {
+                        "This "<Emphasis>{format_args!("{kind}")}</Emphasis>" name should match the regular expression "<Emphasis>{regex}</Emphasis>"."
+                    },
+                )
+            }
+        };
+        Some(
+            match convention.and_then(|convention| convention.message.as_ref()) {
+                Some(message) => diagnostic.note(markup! { {message} }),
+                None => diagnostic,
+            },
+        )
+    }
+}
+
+declare_node_union! {
+    pub AnyCssNamedItem = CssClassSelector | CssKeyframesAtRule | CssGenericProperty
+}
+
+impl AnyCssNamedItem {
+    /// Returns the kind of the name, its token, and the length of the prefix of the token
+    /// that isn't part of the name.
+    fn name(&self) -> Option<(Kind, CssSyntaxToken, usize)> {
+        match self {
+            Self::CssClassSelector(selector) => {
+                Some((Kind::Class, selector.name().ok()?.value_token().ok()?, 0))
+            }
+            Self::CssKeyframesAtRule(rule) => match rule.name().ok()? {
+                AnyCssKeyframeName::CssCustomIdentifier(name) => {
+                    Some((Kind::Keyframes, name.value_token().ok()?, 0))
+                }
+                AnyCssKeyframeName::CssString(_) => None,
+            },
+            Self::CssGenericProperty(property) => {
+                // The names of the custom properties are parsed as regular identifiers.
+                let name = match property.name().ok()? {
+                    AnyCssDeclarationName::CssDashedIdentifier(name) => name.value_token().ok()?,
+                    AnyCssDeclarationName::CssIdentifier(name) => name.value_token().ok()?,
+                };
+                name.text_trimmed()
+                    .starts_with("--")
+                    .then_some((Kind::CustomProperty, name, 2))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct State {
+    // Kind of the name that doesn't follow the convention.
+    kind: Kind,
+    // Range of the name without the dashes of a custom property.
+    name_range: TextRange,
+    // Index of the custom convention which is not fulfilled, or `None` for the default convention.
+    convention: Option<usize>,
+    violation: Violation,
+}
+
+#[derive(Debug)]
+pub enum Violation {
+    /// The name isn't in the format of the convention.
+    Format,
+    /// The name doesn't match the regular expression of the convention.
+    Match,
+}
+
+/// Rule's options.
+#[derive(
+    Clone, Debug, Default, Deserializable, Eq, PartialEq, serde::Deserialize, serde::Serialize,
+)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CssNamingConventionOptions {
+    /// Separators of the BEM format.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub bem: BemSeparators,
+
+    /// Custom conventions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conventions: Vec<Convention>,
+}
+
+fn is_default<T: Default + Eq>(value: &T) -> bool {
+    value == &T::default()
+}
+
+#[derive(Clone, Debug, Deserializable, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(default, deny_unknown_fields)]
+pub struct BemSeparators {
+    /// Separator between the block and the element
+    pub element: String,
+
+    /// Separator before the modifier
+    pub modifier: String,
+}
+impl Default for BemSeparators {
+    fn default() -> Self {
+        Self {
+            element: "__".to_string(),
+            modifier: "--".to_string(),
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Default, Deserializable, Eq, PartialEq, serde::Deserialize, serde::Serialize,
+)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(JsonSchema),
+    schemars(rename = "CssConvention")
+)]
+#[serde(deny_unknown_fields)]
+#[deserializable(with_validator)]
+pub struct Convention {
+    /// Names concerned by this convention
+    #[serde(default, skip_serializing_if = "is_default")]
+    selector: Selector,
+
+    /// String case to enforce
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    format: Option<Format>,
+
+    /// Regular expression to enforce
+    #[serde(default, rename = "match", skip_serializing_if = "Option::is_none")]
+    matching: Option<RestrictedRegex>,
+
+    /// Message displayed when the convention is not fulfilled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+impl DeserializableValidator for Convention {
+    fn validate(
+        &mut self,
+        _name: &str,
+        range: TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> bool {
+        if self.format.is_none() && self.matching.is_none() {
+            diagnostics.push(
+                DeserializationDiagnostic::new(
+                    "At least one field among `format` and `match` must be set.",
+                )
+                .with_range(range),
+            );
+            false
+        } else {
+            true
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Deserializable, Eq, PartialEq, serde::Deserialize, serde::Serialize,
+)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(JsonSchema),
+    schemars(rename = "CssSelector")
+)]
+#[serde(deny_unknown_fields)]
+struct Selector {
+    /// Name kind
+    #[serde(default, skip_serializing_if = "is_default")]
+    kind: Kind,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserializable,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema), schemars(rename = "CssKind"))]
+#[serde(rename_all = "camelCase")]
+pub enum Kind {
+    /// All names
+    #[default]
+    Any,
+    /// Names of the class selectors
+    Class,
+    /// Names of the `@keyframes` at-rules
+    Keyframes,
+    /// Names of the custom properties, without their dashes
+    CustomProperty,
+}
+
+impl Kind {
+    pub fn contains(self, other: Self) -> bool {
+        self == other || self == Self::Any
+    }
+}
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            Self::Any => "name",
+            Self::Class => "class",
+            Self::Keyframes => "keyframes",
+            Self::CustomProperty => "custom property",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Deserializable, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize,
+)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(JsonSchema),
+    schemars(rename = "CssFormat")
+)]
+pub enum Format {
+    #[serde(rename = "kebab-case")]
+    Kebab,
+
+    #[serde(rename = "camelCase")]
+    Camel,
+
+    #[serde(rename = "snake_case")]
+    Snake,
+
+    #[serde(rename = "BEM")]
+    Bem,
+}
+
+impl Format {
+    /// Returns `true` if `name` is in this format.
+    fn is_fulfilled(self, name: &str, bem: &BemSeparators) -> bool {
+        let case = match self {
+            Self::Kebab => Case::Kebab,
+            Self::Camel => Case::Camel,
+            Self::Snake => Case::Snake,
+            Self::Bem => return is_bem(name, bem),
+        };
+        Cases::from(case).contains(Case::identify(name, false))
+    }
+}
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            Self::Kebab => "kebab-case",
+            Self::Camel => "camelCase",
+            Self::Snake => "snake_case",
+            Self::Bem => "BEM",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+/// Returns `true` if `name` is made of a block, an optional element, and an optional modifier
+/// in kebab-case, separated by the separators of `bem`.
+fn is_bem(name: &str, bem: &BemSeparators) -> bool {
+    // When both separators start at the same position, the longer one is used.
+    let mut separators = [(bem.element.as_str(), true), (bem.modifier.as_str(), false)];
+    separators.sort_by_key(|(separator, _)| std::cmp::Reverse(separator.len()));
+    let mut parts = Vec::new();
+    // `true` for the separator of an element, `false` for the separator of a modifier.
+    let mut is_element_separators = Vec::new();
+    let mut start = 0;
+    let mut index = 0;
+    while index < name.len() {
+        let rest = &name[index..];
+        match separators
+            .iter()
+            .find(|(separator, _)| !separator.is_empty() && rest.starts_with(separator))
+        {
+            Some((separator, is_element)) => {
+                parts.push(&name[start..index]);
+                is_element_separators.push(*is_element);
+                index += separator.len();
+                start = index;
+            }
+            None => index += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    parts.push(&name[start..]);
+    matches!(
+        is_element_separators.as_slice(),
+        [] | [true | false] | [true, false]
+    ) && parts
+        .iter()
+        .all(|part| Cases::from(Case::Kebab).contains(Case::identify(part, false)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_bem() {
+        let bem = BemSeparators::default();
+        assert!(is_bem("card", &bem));
+        assert!(is_bem("search-form__input", &bem));
+        assert!(is_bem("card--large", &bem));
+        assert!(is_bem("card__title--large", &bem));
+
+        assert!(!is_bem("cardTitle", &bem));
+        assert!(!is_bem("card__title__text", &bem));
+        assert!(!is_bem("card--large--dark", &bem));
+        assert!(!is_bem("card__", &bem));
+
+        let bem = BemSeparators {
+            element: "-".to_string(),
+            modifier: "_".to_string(),
+        };
+        assert!(is_bem("card-title_large", &bem));
+        assert!(!is_bem("card-title-text", &bem));
+
+        let bem = BemSeparators {
+            element: "__".to_string(),
+            modifier: "_".to_string(),
+        };
+        assert!(is_bem("card__title_large", &bem));
+        assert!(is_bem("card_large", &bem));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::utils::{logical_property, logical_value};
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssValue, CssGenericProperty,
+    CssSyntaxKind, CssSyntaxToken,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_rowan::BatchMutationExt;
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Enforce the use of logical properties and values instead of physical ones.
+    ///
+    /// The physical properties and values, such as `margin-left` or `text-align: left`,
+    /// refer to the sides of the screen. The logical properties and values, such as
+    /// `margin-inline-start` or `text-align: start`, refer to the flow of the content,
+    /// so they adapt to the writing modes and to the languages written from right to left.
+    ///
+    /// The code action replaces a physical property or value with its equivalent in
+    /// the horizontal writing mode from left to right. It's unsafe, because the styles
+    /// change for the documents that use another direction or writing mode.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a { margin-left: 1rem; }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a { border-top-left-radius: 4px; }
+    /// ```
+    ///
+    /// ```css,expect_diagnostic
+    /// a { text-align: left; }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a { margin-inline-start: 1rem; }
+    /// ```
+    ///
+    /// ```css
+    /// a { float: inline-end; }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignore`
+    ///
+    /// The properties that the rule ignores, including the properties with a physical value.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "ignore": ["top", "text-align"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseLogicalProperties {
+        version: "next",
+        name: "useLogicalProperties",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useLogicalProperties`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseLogicalPropertiesOptions {
+    /// The properties that the rule ignores.
+    pub ignore: Vec<String>,
+}
+
+pub enum PhysicalKind {
+    Property,
+    Value,
+}
+
+pub struct PhysicalDeclaration {
+    kind: PhysicalKind,
+    /// The name of the property, or its physical value.
+    token: CssSyntaxToken,
+    logical: &'static str,
+}
+
+impl Rule for UseLogicalProperties {
+    type Query = Ast<CssGenericProperty>;
+    type State = PhysicalDeclaration;
+    type Signals = Option<Self::State>;
+    type Options = UseLogicalPropertiesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let AnyCssDeclarationName::CssIdentifier(name) = node.name().ok()? else {
+            return None;
+        };
+        let name_token = name.value_token().ok()?;
+        let property = name_token.text_trimmed().to_lowercase();
+        if ctx
+            .options()
+            .ignore
+            .iter()
+            .any(|ignored| ignored.eq_ignore_ascii_case(&property))
+        {
+            return None;
+        }
+        if let Some(logical) = logical_property(&property) {
+            return Some(PhysicalDeclaration {
+                kind: PhysicalKind::Property,
+                token: name_token,
+                logical,
+            });
+        }
+        node.value().into_iter().find_map(|value| {
+            let AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(value)) = value
+            else {
+                return None;
+            };
+            let value_token = value.value_token().ok()?;
+            let logical = logical_value(&property, &value_token.text_trimmed().to_lowercase())?;
+            Some(PhysicalDeclaration {
+                kind: PhysicalKind::Value,
+                token: value_token,
+                logical,
+            })
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let physical = state.token.text_trimmed();
+        let logical = state.logical;
+        let kind = match state.kind {
+            PhysicalKind::Property => "property",
+            PhysicalKind::Value => "value",
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.token.text_trimmed_range(),
+                markup! {
+                    "The physical "{kind}" "<Emphasis>{physical}</Emphasis>" doesn't adapt to the writing mode."
+                },
+            )
+            .note(markup! {
+                "Use the logical "{kind}" "<Emphasis>{logical}</Emphasis>" instead."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(
+            state.token.clone(),
+            CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, state.logical, [], []),
+        );
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use "<Emphasis>{state.logical}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,411 @@
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_css_factory::make;
+use biome_css_syntax::{
+    stmt_ext::CssBlockLike, AnyCssDeclarationName, AnyCssGenericComponentValue, AnyCssProperty,
+    AnyCssValue, CssDeclarationWithSemicolon, CssFunction, CssGenericProperty, CssSyntaxKind,
+    CssSyntaxToken,
+};
+use biome_diagnostics::Applicability;
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind};
+
+declare_rule! {
+    /// Enforce the use of shorthand properties instead of their four longhand properties.
+    ///
+    /// When a block declares the four sides of a box property, such as `margin-top`,
+    /// `margin-right`, `margin-bottom`, and `margin-left`, the declarations can be merged
+    /// into the shorthand property, such as `margin`.
+    ///
+    /// The rule checks the shorthand properties `margin`, `padding`, `inset`, `scroll-margin`,
+    /// `scroll-padding`, `border-width`, `border-style`, and `border-color`.
+    ///
+    /// The longhand properties aren't reported when merging them could change the styles:
+    /// - one of them is declared twice in the block;
+    /// - their `!important` flags are different;
+    /// - a value uses a CSS-wide keyword, such as `inherit`, or a custom property with `var()`;
+    /// - another declaration of the same property, such as `margin` or `margin-block-start`,
+    ///   is between them.
+    ///
+    /// The code action replaces the first longhand property with the shorthand property,
+    /// written with the fewest values, and removes the other longhand properties.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   margin-top: 1px;
+    ///   margin-right: 2px;
+    ///   margin-bottom: 1px;
+    ///   margin-left: 2px;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a { margin: 1px 2px; }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   margin-top: 1px;
+    ///   margin-right: 2px;
+    ///   margin-bottom: inherit;
+    ///   margin-left: 2px;
+    /// }
+    /// ```
+    ///
+    pub UseShorthandProperties {
+        version: "next",
+        name: "useShorthandProperties",
+        language: "css",
+        sources: &[RuleSource::Stylelint("declaration-block-no-redundant-longhand-properties")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+struct Shorthand {
+    name: &'static str,
+    /// The longhand properties, in the order of the values of the shorthand property:
+    /// top, right, bottom, and left.
+    longhands: [&'static str; 4],
+    /// The other properties that set the same longhand properties.
+    related: &'static [&'static str],
+    /// The prefixes of the logical properties that set the same longhand properties.
+    related_prefixes: &'static [&'static str],
+}
+
+const BORDER_SIDES: &[&str] = &[
+    "border",
+    "border-top",
+    "border-right",
+    "border-bottom",
+    "border-left",
+];
+
+const SHORTHANDS: [Shorthand; 8] = [
+    Shorthand {
+        name: "margin",
+        longhands: ["margin-top", "margin-right", "margin-bottom", "margin-left"],
+        related: &["margin"],
+        related_prefixes: &["margin-block", "margin-inline"],
+    },
+    Shorthand {
+        name: "padding",
+        longhands: [
+            "padding-top",
+            "padding-right",
+            "padding-bottom",
+            "padding-left",
+        ],
+        related: &["padding"],
+        related_prefixes: &["padding-block", "padding-inline"],
+    },
+    Shorthand {
+        name: "inset",
+        longhands: ["top", "right", "bottom", "left"],
+        related: &[],
+        related_prefixes: &["inset"],
+    },
+    Shorthand {
+        name: "scroll-margin",
+        longhands: [
+            "scroll-margin-top",
+            "scroll-margin-right",
+            "scroll-margin-bottom",
+            "scroll-margin-left",
+        ],
+        related: &["scroll-margin"],
+        related_prefixes: &["scroll-margin-block", "scroll-margin-inline"],
+    },
+    Shorthand {
+        name: "scroll-padding",
+        longhands: [
+            "scroll-padding-top",
+            "scroll-padding-right",
+            "scroll-padding-bottom",
+            "scroll-padding-left",
+        ],
+        related: &["scroll-padding"],
+        related_prefixes: &["scroll-padding-block", "scroll-padding-inline"],
+    },
+    Shorthand {
+        name: "border-width",
+        longhands: [
+            "border-top-width",
+            "border-right-width",
+            "border-bottom-width",
+            "border-left-width",
+        ],
+        related: BORDER_SIDES,
+        related_prefixes: &["border-width", "border-block", "border-inline"],
+    },
+    Shorthand {
+        name: "border-style",
+        longhands: [
+            "border-top-style",
+            "border-right-style",
+            "border-bottom-style",
+            "border-left-style",
+        ],
+        related: BORDER_SIDES,
+        related_prefixes: &["border-style", "border-block", "border-inline"],
+    },
+    Shorthand {
+        name: "border-color",
+        longhands: [
+            "border-top-color",
+            "border-right-color",
+            "border-bottom-color",
+            "border-left-color",
+        ],
+        related: BORDER_SIDES,
+        related_prefixes: &["border-color", "border-block", "border-inline"],
+    },
+];
+
+impl Shorthand {
+    fn is_related(&self, property: &str) -> bool {
+        self.related.contains(&property)
+            || self
+                .related_prefixes
+                .iter()
+                .any(|prefix| property.starts_with(prefix))
+    }
+}
+
+pub struct MergeableLonghands {
+    shorthand: &'static str,
+    /// The declarations of the longhand properties, in source order.
+    declarations: Vec<CssDeclarationWithSemicolon>,
+    /// The values of the shorthand property, written with the fewest values.
+    values: Vec<AnyCssGenericComponentValue>,
+}
+
+impl Rule for UseShorthandProperties {
+    type Query = Ast<CssBlockLike>;
+    type State = MergeableLonghands;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let block = ctx.query();
+        let Some(list) = block
+            .syntax()
+            .children()
+            .find(|child| child.kind().is_list())
+        else {
+            return Vec::new();
+        };
+        let declarations: Vec<_> = list
+            .children()
+            .filter_map(CssDeclarationWithSemicolon::cast)
+            .filter_map(|declaration| {
+                let property = generic_property(&declaration)?;
+                let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+                    return None;
+                };
+                let name = name.value_token().ok()?.text_trimmed().to_lowercase();
+                Some((name, declaration))
+            })
+            .collect();
+        SHORTHANDS
+            .iter()
+            .filter_map(|shorthand| find_mergeable_longhands(shorthand, &declarations))
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let shorthand = state.shorthand;
+        let first = state.declarations.first()?;
+        let value = state
+            .values
+            .iter()
+            .map(|value| value.syntax().text_trimmed().to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                first.declaration().ok()?.range(),
+                markup! {
+                    "The longhand properties of "<Emphasis>{shorthand}</Emphasis>" can be merged into the shorthand property."
+                },
+            )
+            .note(markup! {
+                "Use "<Emphasis>{shorthand}": "{value}</Emphasis>" instead of the four declarations."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let (first, others) = state.declarations.split_first()?;
+        // The comments of the removed declarations would be lost.
+        if others
+            .iter()
+            .any(|declaration| declaration.syntax().has_comments_descendants())
+        {
+            return None;
+        }
+        let property = generic_property(first)?;
+        let mut values = Vec::with_capacity(state.values.len());
+        for (index, value) in state.values.iter().enumerate() {
+            let mut value = value.clone().trim_trivia()?;
+            if index > 0 {
+                let token = value.syntax().first_token()?;
+                let spaced = token.with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]);
+                value = AnyCssGenericComponentValue::cast(
+                    value
+                        .into_syntax()
+                        .replace_child(token.into(), spaced.into())?,
+                )?;
+            }
+            values.push(value);
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(
+            property.name().ok()?.syntax().first_token()?,
+            CssSyntaxToken::new_detached(CssSyntaxKind::IDENT, state.shorthand, [], []),
+        );
+        mutation.replace_node(
+            property.value(),
+            make::css_generic_component_value_list(values),
+        );
+        for declaration in others {
+            mutation.remove_node(declaration.clone());
+        }
+        let shorthand = state.shorthand;
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Merge the declarations into "<Emphasis>{shorthand}</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn generic_property(declaration: &CssDeclarationWithSemicolon) -> Option<CssGenericProperty> {
+    match declaration.declaration().ok()?.property().ok()? {
+        AnyCssProperty::CssGenericProperty(property) => Some(property),
+        _ => None,
+    }
+}
+
+/// Returns the declarations of the four longhand properties of `shorthand`,
+/// if they can be safely merged.
+fn find_mergeable_longhands(
+    shorthand: &Shorthand,
+    declarations: &[(String, CssDeclarationWithSemicolon)],
+) -> Option<MergeableLonghands> {
+    let mut positions = [0; 4];
+    for (side, longhand) in shorthand.longhands.iter().enumerate() {
+        let mut found = declarations
+            .iter()
+            .enumerate()
+            .filter(|(_, (name, _))| name == longhand);
+        let (position, _) = found.next()?;
+        if found.next().is_some() {
+            return None;
+        }
+        positions[side] = position;
+    }
+    let start = *positions.iter().min()?;
+    let end = *positions.iter().max()?;
+    if declarations[start..=end]
+        .iter()
+        .any(|(name, _)| shorthand.is_related(name))
+    {
+        return None;
+    }
+    let is_important = declarations[start]
+        .1
+        .declaration()
+        .ok()?
+        .important()
+        .is_some();
+    let mut sides = Vec::with_capacity(4);
+    for position in positions {
+        let declaration = declarations[position].1.declaration().ok()?;
+        if declaration.important().is_some() != is_important {
+            return None;
+        }
+        let property = generic_property(&declarations[position].1)?;
+        let list = property.value();
+        if list.len() != 1 {
+            return None;
+        }
+        let value = list.first()?;
+        if !is_mergeable_value(&value) {
+            return None;
+        }
+        sides.push(value);
+    }
+    let values = shortest_values(sides);
+    Some(MergeableLonghands {
+        shorthand: shorthand.name,
+        declarations: (start..=end)
+            .filter(|position| positions.contains(position))
+            .map(|position| declarations[position].1.clone())
+            .collect(),
+        values,
+    })
+}
+
+/// Returns `false` for the CSS-wide keywords and the values that use custom properties.
+fn is_mergeable_value(value: &AnyCssGenericComponentValue) -> bool {
+    if let AnyCssGenericComponentValue::AnyCssValue(AnyCssValue::CssIdentifier(identifier)) = value
+    {
+        let is_css_wide_keyword = identifier.value_token().is_ok_and(|token| {
+            matches!(
+                token.text_trimmed().to_lowercase().as_str(),
+                "inherit" | "initial" | "unset" | "revert" | "revert-layer"
+            )
+        });
+        if is_css_wide_keyword {
+            return false;
+        }
+    }
+    !value
+        .syntax()
+        .descendants()
+        .filter_map(CssFunction::cast)
+        .any(|function| {
+            function
+                .name()
+                .and_then(|name| name.value_token())
+                .is_ok_and(|token| {
+                    matches!(token.text_trimmed().to_lowercase().as_str(), "var" | "env")
+                })
+        })
+}
+
+/// Removes the values of the sides that can be omitted: left if it's equal to right,
+/// then bottom if it's equal to top, then right if it's equal to top.
+fn shortest_values(
+    mut sides: Vec<AnyCssGenericComponentValue>,
+) -> Vec<AnyCssGenericComponentValue> {
+    let is_equal = |a: &AnyCssGenericComponentValue, b: &AnyCssGenericComponentValue| {
+        a.syntax()
+            .text_trimmed()
+            .to_string()
+            .eq_ignore_ascii_case(&b.syntax().text_trimmed().to_string())
+    };
+    if is_equal(&sides[3], &sides[1]) {
+        sides.truncate(3);
+        if is_equal(&sides[2], &sides[0]) {
+            sides.truncate(2);
+            if is_equal(&sides[1], &sides[0]) {
+                sides.truncate(1);
+            }
+        }
+    }
+    sides
+}
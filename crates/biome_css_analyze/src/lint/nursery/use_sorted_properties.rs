@@ -0,0 +1,285 @@
+use crate::keywords::SMACSS_PROPERTY_ORDER;
+use crate::utils::vender_prefix;
+use crate::CssRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_css_syntax::{
+    stmt_ext::CssBlockLike, AnyCssDeclarationName, AnyCssProperty, CssDeclarationWithSemicolon,
+    CssSyntaxToken,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_rowan::{AstNode, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Enforce the order of the declarations of a block.
+    ///
+    /// The declarations of a block are easier to find and to compare when all the blocks
+    /// of a project follow the same order.
+    ///
+    /// By default, the declarations are sorted in alphabetical order.
+    /// The vendor prefixes are ignored, and a prefixed property is placed before its standard version.
+    ///
+    /// The custom properties, the nested rules, and the at-rules split the declarations of a block
+    /// in groups that are sorted separately.
+    ///
+    /// The code action moves the declarations with their comments.
+    /// It's unsafe, because the order of a shorthand property and its longhand properties
+    /// changes the style, for example `padding-left: 0; padding: 1rem;`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```css,expect_diagnostic
+    /// a {
+    ///   color: red;
+    ///   background: white;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```css
+    /// a {
+    ///   background: white;
+    ///   color: red;
+    /// }
+    /// ```
+    ///
+    /// ```css
+    /// a {
+    ///   -webkit-transition: color 1s;
+    ///   transition: color 1s;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `order`
+    ///
+    /// The order of the declarations:
+    ///
+    /// - `alphabetical`: the properties are sorted in alphabetical order (default);
+    /// - `smacss`: the properties are grouped like in the SMACSS methodology:
+    ///   box, border, background, text, and other properties;
+    /// - `custom`: the properties are sorted in the order of the option `customOrder`.
+    ///
+    /// With the orders `smacss` and `custom`, the properties that aren't listed follow the listed
+    /// properties, in their original order.
+    ///
+    /// ### `customOrder`
+    ///
+    /// The order of the properties when `order` is `custom`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "order": "custom",
+    ///         "customOrder": ["position", "display", "width", "height", "color"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseSortedProperties {
+        version: "next",
+        name: "useSortedProperties",
+        language: "css",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useSortedProperties`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseSortedPropertiesOptions {
+    /// The order of the declarations.
+    pub order: PropertyOrder,
+    /// The order of the properties when `order` is `custom`.
+    pub custom_order: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyOrder {
+    /// The properties are sorted in alphabetical order.
+    #[default]
+    Alphabetical,
+    /// The properties are grouped like in the SMACSS methodology: box, border, background, text, and other properties.
+    Smacss,
+    /// The properties are sorted in the order of the option `customOrder`.
+    Custom,
+}
+
+/// Consecutive declarations that aren't sorted.
+pub struct UnsortedDeclarations {
+    /// The declarations, in their original order.
+    declarations: Vec<CssDeclarationWithSemicolon>,
+    /// The indexes of the declarations, in the expected order.
+    order: Vec<usize>,
+}
+
+impl Rule for UseSortedProperties {
+    type Query = Ast<CssBlockLike>;
+    type State = Vec<UnsortedDeclarations>;
+    type Signals = Option<Self::State>;
+    type Options = UseSortedPropertiesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let block = ctx.query();
+        let list = block
+            .syntax()
+            .children()
+            .find(|child| child.kind().is_list())?;
+        let options = ctx.options();
+        let mut unsorted = Vec::new();
+        let mut group = Vec::new();
+        for item in list.children() {
+            let declaration = CssDeclarationWithSemicolon::cast(item)
+                .filter(|declaration| property_name(declaration).is_some());
+            match declaration {
+                Some(declaration) => group.push(declaration),
+                None => unsorted.extend(sort(std::mem::take(&mut group), options)),
+            }
+        }
+        unsorted.extend(sort(group, options));
+        (!unsorted.is_empty()).then_some(unsorted)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let first = state.first()?;
+        let (position, index) = first
+            .order
+            .iter()
+            .enumerate()
+            .find(|(position, index)| *position != **index)?;
+        let expected = &first.declarations[*index];
+        let preceding = property_name(&first.declarations[position])?;
+        let expected_name = property_name(expected)?;
+        let expected_name = expected_name.text_trimmed();
+        let preceding = preceding.text_trimmed();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            expected.declaration().ok()?.range(),
+            markup! {
+                "The declaration of "<Emphasis>{expected_name}</Emphasis>" should come before the declaration of "<Emphasis>{preceding}</Emphasis>"."
+            },
+        );
+        let diagnostic = match ctx.options().order {
+            PropertyOrder::Alphabetical => diagnostic.note(markup! {
+                "The declarations of a block should follow the alphabetical order."
+            }),
+            PropertyOrder::Smacss => diagnostic.note(markup! {
+                "The declarations of a block should follow the SMACSS order: box, border, background, text, and other properties."
+            }),
+            PropertyOrder::Custom => diagnostic.note(markup! {
+                "The declarations of a block should follow the order of the option "<Emphasis>"customOrder"</Emphasis>"."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<CssRuleAction> {
+        let mut mutation = ctx.root().begin();
+        for unsorted in state {
+            // Only the last declaration of a block can omit its semicolon.
+            let last = unsorted.order.len() - 1;
+            let has_missing_semicolon = unsorted.order[..last]
+                .iter()
+                .any(|index| unsorted.declarations[*index].semicolon_token().is_none());
+            if has_missing_semicolon {
+                return None;
+            }
+            for (position, index) in unsorted.order.iter().enumerate() {
+                if position != *index {
+                    mutation.replace_node_discard_trivia(
+                        unsorted.declarations[position].clone(),
+                        unsorted.declarations[*index].clone(),
+                    );
+                }
+            }
+        }
+        Some(CssRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Sort the declarations." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the name of the property of `declaration`, unless it's a custom property.
+fn property_name(declaration: &CssDeclarationWithSemicolon) -> Option<CssSyntaxToken> {
+    let AnyCssProperty::CssGenericProperty(property) =
+        declaration.declaration().ok()?.property().ok()?
+    else {
+        return None;
+    };
+    let AnyCssDeclarationName::CssIdentifier(name) = property.name().ok()? else {
+        return None;
+    };
+    // The names of the custom properties are parsed as regular identifiers.
+    let name = name.value_token().ok()?;
+    (!name.text_trimmed().starts_with("--")).then_some(name)
+}
+
+/// Returns the consecutive `declarations` with their expected order, if they aren't sorted.
+fn sort(
+    declarations: Vec<CssDeclarationWithSemicolon>,
+    options: &UseSortedPropertiesOptions,
+) -> Option<UnsortedDeclarations> {
+    let names: Vec<String> = declarations
+        .iter()
+        .map(|declaration| {
+            property_name(declaration)
+                .map(|name| name.text_trimmed().to_lowercase())
+                .unwrap_or_default()
+        })
+        .collect();
+    let mut order: Vec<usize> = (0..declarations.len()).collect();
+    // The sort is stable, so the declarations of the same property keep their order.
+    order.sort_by_key(|index| sort_key(&names[*index], options));
+    let is_sorted = order
+        .iter()
+        .enumerate()
+        .all(|(position, index)| position == *index);
+    (!is_sorted).then_some(UnsortedDeclarations {
+        declarations,
+        order,
+    })
+}
+
+/// Returns the key of a property, in lowercase, for the order of `options`.
+///
+/// A prefixed property is placed before its standard version.
+/// The properties that aren't listed by the order follow the listed ones, in their original order.
+fn sort_key<'a>(name: &'a str, options: &UseSortedPropertiesOptions) -> (usize, &'a str, bool) {
+    let unprefixed = &name[vender_prefix(name).len()..];
+    let is_standard = unprefixed.len() == name.len();
+    let position = match options.order {
+        PropertyOrder::Alphabetical => return (0, unprefixed, is_standard),
+        PropertyOrder::Smacss => SMACSS_PROPERTY_ORDER
+            .iter()
+            .position(|property| *property == unprefixed),
+        PropertyOrder::Custom => options
+            .custom_order
+            .iter()
+            .position(|property| property.eq_ignore_ascii_case(name))
+            .or_else(|| {
+                options
+                    .custom_order
+                    .iter()
+                    .position(|property| property.eq_ignore_ascii_case(unprefixed))
+            }),
+    };
+    match position {
+        Some(position) => (position, "", is_standard),
+        None => (usize::MAX, "", false),
+    }
+}
@@ -10,8 +10,13 @@ pub type NoDuplicateAtImportRules = < lint :: nursery :: no_duplicate_at_import_
 pub type NoDuplicateFontNames =
     <lint::nursery::no_duplicate_font_names::NoDuplicateFontNames as biome_analyze::Rule>::Options;
 pub type NoDuplicateSelectorsKeyframeBlock = < lint :: nursery :: no_duplicate_selectors_keyframe_block :: NoDuplicateSelectorsKeyframeBlock as biome_analyze :: Rule > :: Options ;
+pub type NoExcessiveNestingDepth = < lint :: nursery :: no_excessive_nesting_depth :: NoExcessiveNestingDepth as biome_analyze :: Rule > :: Options ;
+pub type NoHardcodedColors =
+    <lint::nursery::no_hardcoded_colors::NoHardcodedColors as biome_analyze::Rule>::Options;
+pub type NoHighSpecificitySelectors = < lint :: nursery :: no_high_specificity_selectors :: NoHighSpecificitySelectors as biome_analyze :: Rule > :: Options ;
 pub type NoImportantInKeyframe = < lint :: nursery :: no_important_in_keyframe :: NoImportantInKeyframe as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidPositionAtImportRule = < lint :: nursery :: no_invalid_position_at_import_rule :: NoInvalidPositionAtImportRule as biome_analyze :: Rule > :: Options ;
+pub type NoInvalidTailwindApply = < lint :: nursery :: no_invalid_tailwind_apply :: NoInvalidTailwindApply as biome_analyze :: Rule > :: Options ;
 pub type NoUnknownFunction =
     <lint::nursery::no_unknown_function::NoUnknownFunction as biome_analyze::Rule>::Options;
 pub type NoUnknownMediaFeatureName = < lint :: nursery :: no_unknown_media_feature_name :: NoUnknownMediaFeatureName as biome_analyze :: Rule > :: Options ;
@@ -21,5 +26,16 @@ pub type NoUnknownSelectorPseudoElement = < lint :: nursery :: no_unknown_select
 pub type NoUnknownUnit =
     <lint::nursery::no_unknown_unit::NoUnknownUnit as biome_analyze::Rule>::Options;
 pub type NoUnmatchableAnbSelector = < lint :: nursery :: no_unmatchable_anb_selector :: NoUnmatchableAnbSelector as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedSelectors =
+    <lint::nursery::no_unused_selectors::NoUnusedSelectors as biome_analyze::Rule>::Options;
+pub type NoVendorPrefix =
+    <lint::nursery::no_vendor_prefix::NoVendorPrefix as biome_analyze::Rule>::Options;
+pub type UseAllowedLengthUnits = < lint :: nursery :: use_allowed_length_units :: UseAllowedLengthUnits as biome_analyze :: Rule > :: Options ;
+pub type UseCssNamingConvention = < lint :: nursery :: use_css_naming_convention :: UseCssNamingConvention as biome_analyze :: Rule > :: Options ;
 pub type UseGenericFontNames =
     <lint::nursery::use_generic_font_names::UseGenericFontNames as biome_analyze::Rule>::Options;
+pub type UseLogicalProperties =
+    <lint::nursery::use_logical_properties::UseLogicalProperties as biome_analyze::Rule>::Options;
+pub type UseShorthandProperties = < lint :: nursery :: use_shorthand_properties :: UseShorthandProperties as biome_analyze :: Rule > :: Options ;
+pub type UseSortedProperties =
+    <lint::nursery::use_sorted_properties::UseSortedProperties as biome_analyze::Rule>::Options;
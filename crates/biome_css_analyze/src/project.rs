@@ -0,0 +1,48 @@
+//! The class names and the identifiers that a stylesheet contributes to the [biome_project::StyleIndex] of a project.
+
+use biome_css_syntax::{AnyCssSubSelector, CssCustomIdentifier, CssRoot};
+use biome_project::StyleSymbols;
+use biome_rowan::AstNode;
+
+/// Returns the class names and the identifiers declared by the selectors of a stylesheet.
+///
+/// ## Examples
+///
+/// ```
+/// use biome_css_analyze::project::declared_style_symbols;
+/// use biome_css_parser::{parse_css, CssParserOptions};
+///
+/// let root = parse_css(".button:hover, #app .title {}", CssParserOptions::default()).tree();
+/// let symbols = declared_style_symbols(&root);
+/// assert!(symbols.classes.contains("button"));
+/// assert!(symbols.classes.contains("title"));
+/// assert!(symbols.ids.contains("app"));
+/// ```
+pub fn declared_style_symbols(root: &CssRoot) -> StyleSymbols {
+    let mut symbols = StyleSymbols::default();
+    for selector in root
+        .syntax()
+        .descendants()
+        .filter_map(AnyCssSubSelector::cast)
+    {
+        match selector {
+            AnyCssSubSelector::CssClassSelector(selector) => {
+                if let Some(name) = selector.name().ok().as_ref().and_then(identifier_text) {
+                    symbols.classes.insert(name);
+                }
+            }
+            AnyCssSubSelector::CssIdSelector(selector) => {
+                if let Some(name) = selector.name().ok().as_ref().and_then(identifier_text) {
+                    symbols.ids.insert(name);
+                }
+            }
+            _ => {}
+        }
+    }
+    symbols
+}
+
+/// Returns the text of the name of a class selector or an identifier selector.
+pub(crate) fn identifier_text(identifier: &CssCustomIdentifier) -> Option<String> {
+    Some(identifier.value_token().ok()?.text_trimmed().to_string())
+}
@@ -0,0 +1,355 @@
+//! The specificity of the selectors, see <https://drafts.csswg.org/selectors/#specificity-rules>.
+
+use biome_css_syntax::{
+    AnyCssCompoundSelector, AnyCssPseudoClass, AnyCssPseudoClassNthSelector, AnyCssPseudoElement,
+    AnyCssRelativeSelector, AnyCssSelector, AnyCssSimpleSelector, AnyCssSubSelector,
+    CssCompoundSelector, CssNestedQualifiedRule, CssQualifiedRule, CssSelectorList, CssSyntaxNode,
+    T,
+};
+use biome_deserialize_macros::Deserializable;
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, Direction};
+use std::fmt::{Display, Formatter};
+use std::ops::Add;
+
+/// The pseudo-elements that can be written with a single colon, such as `a:before`.
+const LEGACY_PSEUDO_ELEMENTS: [&str; 4] = ["after", "before", "first-letter", "first-line"];
+
+/// The specificity of a selector: the number of identifiers, of classes, and of types.
+///
+/// The specificities are compared in this order.
+/// In the configuration, a specificity is written as the three numbers separated by commas, such as `0,4,0`.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserializable,
+    Eq,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct Specificity(pub u32, pub u32, pub u32);
+
+impl Specificity {
+    const ID: Self = Self(1, 0, 0);
+    const CLASS: Self = Self(0, 1, 0);
+    const TYPE: Self = Self(0, 0, 1);
+}
+
+impl Add for Specificity {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1, self.2 + rhs.2)
+    }
+}
+
+impl Display for Specificity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{},{}", self.0, self.1, self.2)
+    }
+}
+
+impl From<Specificity> for String {
+    fn from(value: Specificity) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<String> for Specificity {
+    type Error = &'static str;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl TryFrom<&str> for Specificity {
+    type Error = &'static str;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut numbers = value.split(',').map(|number| number.trim().parse::<u32>());
+        match (
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+        ) {
+            (Some(Ok(ids)), Some(Ok(classes)), Some(Ok(types)), None) => {
+                Ok(Self(ids, classes, types))
+            }
+            _ => Err("The specificity must be three numbers separated by commas, such as `0,4,0`."),
+        }
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Specificity {
+    fn schema_name() -> String {
+        "Specificity".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+declare_node_union! {
+    pub AnyCssQualifiedRule = CssQualifiedRule | CssNestedQualifiedRule
+}
+
+impl AnyCssQualifiedRule {
+    /// Returns the selectors of the prelude of the rule with their specificity.
+    ///
+    /// The specificity of the selectors of a nested rule includes the specificity of the parent rule.
+    pub fn selector_specificities(&self) -> Vec<(CssSyntaxNode, Specificity)> {
+        match self {
+            Self::CssQualifiedRule(rule) => rule
+                .prelude()
+                .iter()
+                .flatten()
+                .map(|selector| {
+                    let specificity = selector_specificity(&selector, Specificity::default());
+                    (selector.into_syntax(), specificity)
+                })
+                .collect(),
+            Self::CssNestedQualifiedRule(rule) => {
+                let nesting = self.parent_specificity();
+                rule.prelude()
+                    .iter()
+                    .flatten()
+                    .filter_map(|selector| match selector {
+                        AnyCssRelativeSelector::CssRelativeSelector(selector) => {
+                            let inner = selector.selector().ok()?;
+                            let mut specificity = selector_specificity(&inner, nesting);
+                            // A selector without `&` is relative to the parent rule.
+                            if !has_nesting_selector(selector.syntax()) {
+                                specificity = specificity + nesting;
+                            }
+                            Some((selector.into_syntax(), specificity))
+                        }
+                        AnyCssRelativeSelector::CssBogusSelector(_) => None,
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    /// Returns the highest specificity of the selectors of the parent rule,
+    /// which is the specificity of the nesting selector `&`.
+    fn parent_specificity(&self) -> Specificity {
+        self.syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(AnyCssQualifiedRule::cast)
+            .and_then(|parent| {
+                parent
+                    .selector_specificities()
+                    .into_iter()
+                    .map(|(_, specificity)| specificity)
+                    .max()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn has_nesting_selector(node: &CssSyntaxNode) -> bool {
+    node.descendants_tokens(Direction::Next)
+        .any(|token| token.kind() == T![&])
+}
+
+/// Returns the specificity of `selector`. The nesting selector `&` has the specificity `nesting`.
+pub fn selector_specificity(selector: &AnyCssSelector, nesting: Specificity) -> Specificity {
+    match selector {
+        AnyCssSelector::CssComplexSelector(selector) => {
+            let left = selector.left().map_or(Specificity::default(), |left| {
+                selector_specificity(&left, nesting)
+            });
+            let right = selector.right().map_or(Specificity::default(), |right| {
+                selector_specificity(&right, nesting)
+            });
+            left + right
+        }
+        AnyCssSelector::CssCompoundSelector(selector) => compound_specificity(selector, nesting),
+        AnyCssSelector::CssBogusSelector(_) => Specificity::default(),
+    }
+}
+
+fn compound_specificity(selector: &CssCompoundSelector, nesting: Specificity) -> Specificity {
+    let mut specificity = Specificity::default();
+    if selector.nesting_selector_token().is_some() {
+        specificity = specificity + nesting;
+    }
+    if let Some(AnyCssSimpleSelector::CssTypeSelector(_)) = selector.simple_selector() {
+        specificity = specificity + Specificity::TYPE;
+    }
+    selector
+        .sub_selectors()
+        .into_iter()
+        .fold(specificity, |specificity, sub_selector| {
+            specificity + sub_selector_specificity(&sub_selector, nesting)
+        })
+}
+
+fn sub_selector_specificity(selector: &AnyCssSubSelector, nesting: Specificity) -> Specificity {
+    match selector {
+        AnyCssSubSelector::CssIdSelector(_) => Specificity::ID,
+        AnyCssSubSelector::CssClassSelector(_) | AnyCssSubSelector::CssAttributeSelector(_) => {
+            Specificity::CLASS
+        }
+        AnyCssSubSelector::CssPseudoClassSelector(selector) => {
+            selector.class().map_or(Specificity::default(), |class| {
+                pseudo_class_specificity(&class, nesting)
+            })
+        }
+        AnyCssSubSelector::CssPseudoElementSelector(selector) => match selector.element() {
+            Ok(AnyCssPseudoElement::CssPseudoElementFunctionSelector(element)) => {
+                Specificity::TYPE
+                    + element
+                        .selector()
+                        .map_or(Specificity::default(), |selector| {
+                            selector_specificity(&selector, nesting)
+                        })
+            }
+            _ => Specificity::TYPE,
+        },
+        AnyCssSubSelector::CssBogusSubSelector(_) => Specificity::default(),
+    }
+}
+
+fn pseudo_class_specificity(class: &AnyCssPseudoClass, nesting: Specificity) -> Specificity {
+    match class {
+        AnyCssPseudoClass::CssPseudoClassIdentifier(class) => {
+            let is_legacy_pseudo_element = class.name().is_ok_and(|name| {
+                name.value_token().is_ok_and(|token| {
+                    LEGACY_PSEUDO_ELEMENTS.contains(&token.text_trimmed().to_lowercase().as_str())
+                })
+            });
+            if is_legacy_pseudo_element {
+                Specificity::TYPE
+            } else {
+                Specificity::CLASS
+            }
+        }
+        // `:global()` and `:local()` of the CSS modules only scope their selector.
+        AnyCssPseudoClass::CssPseudoClassFunctionSelector(class) => {
+            class.selector().map_or(Specificity::default(), |selector| {
+                selector_specificity(&selector, nesting)
+            })
+        }
+        AnyCssPseudoClass::CssPseudoClassFunctionSelectorList(class) => {
+            let is_where = class
+                .name()
+                .is_ok_and(|name| name.text_trimmed().eq_ignore_ascii_case("where"));
+            if is_where {
+                Specificity::default()
+            } else {
+                selector_list_specificity(&class.selectors(), nesting)
+            }
+        }
+        AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelectorList(class) => class
+            .compound_selectors()
+            .iter()
+            .flatten()
+            .filter_map(|selector| match selector {
+                AnyCssCompoundSelector::CssCompoundSelector(selector) => {
+                    Some(compound_specificity(&selector, nesting))
+                }
+                AnyCssCompoundSelector::CssBogusSelector(_) => None,
+            })
+            .max()
+            .unwrap_or_default(),
+        AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelector(class) => {
+            Specificity::CLASS
+                + match class.selector() {
+                    Ok(AnyCssCompoundSelector::CssCompoundSelector(selector)) => {
+                        compound_specificity(&selector, nesting)
+                    }
+                    _ => Specificity::default(),
+                }
+        }
+        AnyCssPseudoClass::CssPseudoClassFunctionRelativeSelectorList(class) => class
+            .relative_selectors()
+            .iter()
+            .flatten()
+            .filter_map(|selector| match selector {
+                AnyCssRelativeSelector::CssRelativeSelector(selector) => selector
+                    .selector()
+                    .ok()
+                    .map(|selector| selector_specificity(&selector, nesting)),
+                AnyCssRelativeSelector::CssBogusSelector(_) => None,
+            })
+            .max()
+            .unwrap_or_default(),
+        AnyCssPseudoClass::CssPseudoClassFunctionNth(class) => {
+            let of_selectors = match class.selector() {
+                Ok(AnyCssPseudoClassNthSelector::CssPseudoClassNthSelector(selector)) => {
+                    selector.of_selector().map(|of_selector| {
+                        selector_list_specificity(&of_selector.selectors(), nesting)
+                    })
+                }
+                _ => None,
+            };
+            Specificity::CLASS + of_selectors.unwrap_or_default()
+        }
+        AnyCssPseudoClass::CssPseudoClassFunctionIdentifier(_)
+        | AnyCssPseudoClass::CssPseudoClassFunctionValueList(_) => Specificity::CLASS,
+        AnyCssPseudoClass::CssBogusPseudoClass(_) => Specificity::default(),
+    }
+}
+
+/// Returns the highest specificity of the selectors of `list`.
+fn selector_list_specificity(list: &CssSelectorList, nesting: Specificity) -> Specificity {
+    list.iter()
+        .flatten()
+        .map(|selector| selector_specificity(&selector, nesting))
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_css_parser::{parse_css, CssParserOptions};
+
+    fn specificities(source: &str) -> Vec<String> {
+        let root = parse_css(source, CssParserOptions::default()).tree();
+        root.syntax()
+            .descendants()
+            .filter_map(AnyCssQualifiedRule::cast)
+            .flat_map(|rule| rule.selector_specificities())
+            .map(|(_, specificity)| specificity.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_selector_specificity() {
+        assert_eq!(specificities("* {}"), ["0,0,0"]);
+        assert_eq!(specificities("a, .b, #c {}"), ["0,0,1", "0,1,0", "1,0,0"]);
+        assert_eq!(specificities("ul li > a[href]:hover {}"), ["0,2,3"]);
+        assert_eq!(specificities("a::before, a:before {}"), ["0,0,2", "0,0,2"]);
+        assert_eq!(specificities(":is(#a, .b) :where(#c) {}"), ["1,0,0"]);
+        assert_eq!(specificities(":not(.a.b) :has(> #c) {}"), ["1,2,0"]);
+        assert_eq!(specificities("li:nth-child(2n of .item) {}"), ["0,2,1"]);
+    }
+
+    #[test]
+    fn test_nested_selector_specificity() {
+        assert_eq!(
+            specificities("#a, .b { .c {} &:hover {} & > & {} }"),
+            ["1,0,0", "0,1,0", "1,1,0", "1,1,0", "2,0,0"]
+        );
+    }
+
+    #[test]
+    fn test_specificity_from_str() {
+        assert_eq!(Specificity::try_from("0, 4,0"), Ok(Specificity(0, 4, 0)));
+        assert!(Specificity::try_from("0,4").is_err());
+        assert!(Specificity::try_from("0,4,0,0").is_err());
+        assert!(Specificity::try_from("a,b,c").is_err());
+    }
+}
@@ -1,12 +1,14 @@
 use crate::keywords::{
-    BASIC_KEYWORDS, FONT_FAMILY_KEYWORDS, FONT_SIZE_KEYWORDS, FONT_STRETCH_KEYWORDS,
-    FONT_STYLE_KEYWORDS, FONT_VARIANTS_KEYWORDS, FONT_WEIGHT_ABSOLUTE_KEYWORDS,
-    FONT_WEIGHT_NUMERIC_KEYWORDS, FUNCTION_KEYWORDS, KNOWN_CHROME_PROPERTIES,
-    KNOWN_EDGE_PROPERTIES, KNOWN_EXPLORER_PROPERTIES, KNOWN_FIREFOX_PROPERTIES, KNOWN_PROPERTIES,
-    KNOWN_SAFARI_PROPERTIES, KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES,
-    LEVEL_ONE_AND_TWO_PSEUDO_ELEMENTS, LINE_HEIGHT_KEYWORDS, MEDIA_FEATURE_NAMES,
-    OTHER_PSEUDO_ELEMENTS, SHADOW_TREE_PSEUDO_ELEMENTS, SYSTEM_FAMILY_NAME_KEYWORDS,
-    VENDOR_PREFIXES, VENDOR_SPECIFIC_PSEUDO_ELEMENTS,
+    BASIC_KEYWORDS, COLOR_FUNCTIONS, FONT_FAMILY_KEYWORDS, FONT_SIZE_KEYWORDS,
+    FONT_STRETCH_KEYWORDS, FONT_STYLE_KEYWORDS, FONT_VARIANTS_KEYWORDS,
+    FONT_WEIGHT_ABSOLUTE_KEYWORDS, FONT_WEIGHT_NUMERIC_KEYWORDS, FUNCTION_KEYWORDS,
+    KNOWN_CHROME_PROPERTIES, KNOWN_EDGE_PROPERTIES, KNOWN_EXPLORER_PROPERTIES,
+    KNOWN_FIREFOX_PROPERTIES, KNOWN_PROPERTIES, KNOWN_SAFARI_PROPERTIES,
+    KNOWN_SAMSUNG_INTERNET_PROPERTIES, KNOWN_US_BROWSER_PROPERTIES, LENGTH_UNITS,
+    LEVEL_ONE_AND_TWO_PSEUDO_ELEMENTS, LINE_HEIGHT_KEYWORDS, MEDIA_FEATURE_NAMES, NAMED_COLORS,
+    OTHER_PSEUDO_ELEMENTS, PHYSICAL_PROPERTIES, PHYSICAL_VALUES, SHADOW_TREE_PSEUDO_ELEMENTS,
+    SYSTEM_FAMILY_NAME_KEYWORDS, VENDOR_PREFIXED_STANDARD_VALUES, VENDOR_PREFIXES,
+    VENDOR_SPECIFIC_PSEUDO_ELEMENTS,
 };
 use biome_css_syntax::{AnyCssGenericComponentValue, AnyCssValue, CssGenericComponentValueList};
 use biome_rowan::{AstNode, SyntaxNodeCast};
@@ -144,6 +146,11 @@ pub fn is_known_properties(prop: &str) -> bool {
         || KNOWN_US_BROWSER_PROPERTIES.binary_search(&prop).is_ok()
 }
 
+/// Check if the input string is a property of the CSS specifications, excluding the browser specific properties.
+pub fn is_standard_property(prop: &str) -> bool {
+    KNOWN_PROPERTIES.binary_search(&prop).is_ok()
+}
+
 pub fn vendor_prefixed(props: &str) -> bool {
     props.starts_with("-webkit-")
         || props.starts_with("-moz-")
@@ -151,6 +158,13 @@ pub fn vendor_prefixed(props: &str) -> bool {
         || props.starts_with("-o-")
 }
 
+/// Check if the input string is a standard value or function that used to require a vendor prefix.
+pub fn is_vendor_prefixed_standard_value(value: &str) -> bool {
+    VENDOR_PREFIXED_STANDARD_VALUES
+        .binary_search(&value)
+        .is_ok()
+}
+
 /// Check if the input string is a media feature name.
 pub fn is_media_feature_name(prop: &str) -> bool {
     let input = prop.to_lowercase();
@@ -174,3 +188,40 @@ pub fn is_media_feature_name(prop: &str) -> bool {
     }
     false
 }
+
+/// Returns the logical property equivalent to the physical property `prop`, if any.
+pub fn logical_property(prop: &str) -> Option<&'static str> {
+    PHYSICAL_PROPERTIES
+        .binary_search_by(|(physical, _)| (*physical).cmp(prop))
+        .ok()
+        .map(|index| PHYSICAL_PROPERTIES[index].1)
+}
+
+/// Returns the logical value equivalent to the physical value `value` of the property `prop`, if any.
+pub fn logical_value(prop: &str, value: &str) -> Option<&'static str> {
+    PHYSICAL_VALUES
+        .iter()
+        .find(|(property, physical, _)| *property == prop && *physical == value)
+        .map(|(_, _, logical)| *logical)
+}
+
+/// Check if the value is a named color, such as `red` or `rebeccapurple`.
+pub fn is_named_color(value: &str) -> bool {
+    NAMED_COLORS
+        .binary_search(&value.to_lowercase().as_str())
+        .is_ok()
+}
+
+/// Check if the function returns a color, such as `rgb()` or `oklch()`.
+pub fn is_color_function(name: &str) -> bool {
+    COLOR_FUNCTIONS
+        .binary_search(&name.to_lowercase().as_str())
+        .is_ok()
+}
+
+/// Check if the unit is a length unit, such as `px` or `rem`.
+pub fn is_length_unit(unit: &str) -> bool {
+    LENGTH_UNITS
+        .binary_search(&unit.to_lowercase().as_str())
+        .is_ok()
+}
@@ -3,13 +3,24 @@ use biome_css_parser::{parse_css, CssParserOptions};
 use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_js_analyze::project::referenced_style_symbols;
+use biome_js_parser::{parse, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+use biome_project::StyleIndex;
 use biome_rowan::AstNode;
 use biome_test_utils::{
-    assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
-    has_bogus_nodes_or_empty_slots, parse_test_path, register_leak_checker, scripts_from_json,
-    write_analyzer_snapshot, CheckActionType,
+    assert_errors_are_absent, code_fix_to_string, create_analyzer_options,
+    create_css_parser_options, diagnostic_to_string, has_bogus_nodes_or_empty_slots,
+    parse_test_path, register_leak_checker, scripts_from_json, write_analyzer_snapshot,
+    CheckActionType,
+};
+use std::sync::Arc;
+use std::{
+    ffi::OsStr,
+    fs::{read_dir, read_to_string},
+    path::Path,
+    slice,
 };
-use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{css,json,jsonc}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{css,json,jsonc}", crate::run_suppression_test, "module"}
@@ -55,7 +66,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
                 file_name,
                 input_file,
                 CheckActionType::Lint,
-                CssParserOptions::default(),
+                create_css_parser_options(input_file),
             );
         }
 
@@ -72,7 +83,7 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
             file_name,
             input_file,
             CheckActionType::Lint,
-            CssParserOptions::default(),
+            create_css_parser_options(input_file),
         )
     };
 
@@ -88,6 +99,27 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     }
 }
 
+/// Indexes the JSX files in the directory of the test file, which form the markup of the project of the test.
+///
+/// Returns `None` if the directory doesn't contain any JSX file.
+fn index_markup(input_file: &Path) -> Option<Arc<StyleIndex>> {
+    let mut style_index = StyleIndex::default();
+    for entry in read_dir(input_file.parent()?).ok()? {
+        let path = entry.ok()?.path();
+        if path.extension().and_then(OsStr::to_str) != Some("jsx") {
+            continue;
+        }
+        let content = read_to_string(&path)
+            .unwrap_or_else(|err| panic!("failed to read {:?}: {:?}", path, err));
+        let root = parse(&content, JsFileSource::jsx(), JsParserOptions::default()).tree();
+        style_index.add_markup(
+            Path::new(path.file_name()?),
+            referenced_style_symbols(&root),
+        );
+    }
+    style_index.has_markup().then(|| Arc::new(style_index))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn analyze_and_snap(
     snapshot: &mut String,
@@ -105,8 +137,9 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let style_index = index_markup(input_file);
 
-    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, |event| {
+    let (_, errors) = biome_css_analyze::analyze(&root, filter, &options, style_index, |event| {
         if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if check_action_type.is_suppression() {
@@ -243,7 +276,7 @@ pub(crate) fn _run_suppression_test(input: &'static str, _: &str, _: &str, _: &s
         file_name,
         input_file,
         CheckActionType::Suppression,
-        CssParserOptions::default(),
+        create_css_parser_options(input_file),
     );
 
     insta::with_settings!({
@@ -0,0 +1,5 @@
+export const SignupForm = ({ isPrimary }) => (
+	<form id="signup" className="form">
+		<button className={isPrimary ? "button primary" : "button"} />
+	</form>
+);
@@ -2030,6 +2030,378 @@ pub fn css_url_value_raw(value_token: SyntaxToken) -> CssUrlValueRaw {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn less_declaration(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+) -> LessDeclarationBuilder {
+    LessDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        semicolon_token: None,
+    }
+}
+pub struct LessDeclarationBuilder {
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessDeclaration {
+        LessDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_identifier(at_token: SyntaxToken, name: CssIdentifier) -> LessIdentifier {
+    LessIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(at_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn less_keyword_argument(
+    name: LessIdentifier,
+    colon_token: SyntaxToken,
+    value: AnyCssExpression,
+) -> LessKeywordArgument {
+    LessKeywordArgument::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_KEYWORD_ARGUMENT,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn less_mixin_arguments(
+    l_paren_token: SyntaxToken,
+    items: CssParameterList,
+    r_paren_token: SyntaxToken,
+) -> LessMixinArguments {
+    LessMixinArguments::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_ARGUMENTS,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(items.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn less_mixin_call(name: AnyLessMixinName) -> LessMixinCallBuilder {
+    LessMixinCallBuilder {
+        name,
+        arguments: None,
+        important: None,
+        semicolon_token: None,
+    }
+}
+pub struct LessMixinCallBuilder {
+    name: AnyLessMixinName,
+    arguments: Option<LessMixinArguments>,
+    important: Option<CssDeclarationImportant>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl LessMixinCallBuilder {
+    pub fn with_arguments(mut self, arguments: LessMixinArguments) -> Self {
+        self.arguments = Some(arguments);
+        self
+    }
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> LessMixinCall {
+        LessMixinCall::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_CALL,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                self.arguments
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_definition(
+    name: AnyLessMixinName,
+    arguments: LessMixinArguments,
+    block: AnyCssDeclarationOrRuleBlock,
+) -> LessMixinDefinitionBuilder {
+    LessMixinDefinitionBuilder {
+        name,
+        arguments,
+        block,
+        guard: None,
+    }
+}
+pub struct LessMixinDefinitionBuilder {
+    name: AnyLessMixinName,
+    arguments: LessMixinArguments,
+    block: AnyCssDeclarationOrRuleBlock,
+    guard: Option<LessMixinGuard>,
+}
+impl LessMixinDefinitionBuilder {
+    pub fn with_guard(mut self, guard: LessMixinGuard) -> Self {
+        self.guard = Some(guard);
+        self
+    }
+    pub fn build(self) -> LessMixinDefinition {
+        LessMixinDefinition::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::LESS_MIXIN_DEFINITION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Node(self.arguments.into_syntax())),
+                self.guard
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn less_mixin_guard(
+    when_token: SyntaxToken,
+    conditions: CssGenericComponentValueList,
+) -> LessMixinGuard {
+    LessMixinGuard::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_MIXIN_GUARD,
+        [
+            Some(SyntaxElement::Token(when_token)),
+            Some(SyntaxElement::Node(conditions.into_syntax())),
+        ],
+    ))
+}
+pub fn less_parenthesized_value(
+    l_paren_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    r_paren_token: SyntaxToken,
+) -> LessParenthesizedValue {
+    LessParenthesizedValue::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::LESS_PARENTHESIZED_VALUE,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn scss_at_rule(
+    name: CssIdentifier,
+    prelude: CssGenericComponentValueList,
+) -> ScssAtRuleBuilder {
+    ScssAtRuleBuilder {
+        name,
+        prelude,
+        block: None,
+        semicolon_token: None,
+    }
+}
+pub struct ScssAtRuleBuilder {
+    name: CssIdentifier,
+    prelude: CssGenericComponentValueList,
+    block: Option<AnyCssDeclarationOrRuleBlock>,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssAtRuleBuilder {
+    pub fn with_block(mut self, block: AnyCssDeclarationOrRuleBlock) -> Self {
+        self.block = Some(block);
+        self
+    }
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssAtRule {
+        ScssAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_AT_RULE,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Node(self.prelude.into_syntax())),
+                self.block
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_declaration(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+) -> ScssDeclarationBuilder {
+    ScssDeclarationBuilder {
+        name,
+        colon_token,
+        value,
+        modifiers,
+        semicolon_token: None,
+    }
+}
+pub struct ScssDeclarationBuilder {
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: CssGenericComponentValueList,
+    modifiers: ScssVariableModifierList,
+    semicolon_token: Option<SyntaxToken>,
+}
+impl ScssDeclarationBuilder {
+    pub fn with_semicolon_token(mut self, semicolon_token: SyntaxToken) -> Self {
+        self.semicolon_token = Some(semicolon_token);
+        self
+    }
+    pub fn build(self) -> ScssDeclaration {
+        ScssDeclaration::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::SCSS_DECLARATION,
+            [
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                Some(SyntaxElement::Node(self.value.into_syntax())),
+                Some(SyntaxElement::Node(self.modifiers.into_syntax())),
+                self.semicolon_token
+                    .map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn scss_identifier(dollar_token: SyntaxToken, name: CssIdentifier) -> ScssIdentifier {
+    ScssIdentifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_IDENTIFIER,
+        [
+            Some(SyntaxElement::Token(dollar_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_keyword_argument(
+    name: ScssIdentifier,
+    colon_token: SyntaxToken,
+    value: AnyCssExpression,
+) -> ScssKeywordArgument {
+    ScssKeywordArgument::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_KEYWORD_ARGUMENT,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(colon_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn scss_variable_modifier(
+    excl_token: SyntaxToken,
+    value_token: SyntaxToken,
+) -> ScssVariableModifier {
+    ScssVariableModifier::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER,
+        [
+            Some(SyntaxElement::Token(excl_token)),
+            Some(SyntaxElement::Token(value_token)),
+        ],
+    ))
+}
+pub fn tw_apply_at_rule(
+    apply_token: SyntaxToken,
+    classes: TwApplyClassList,
+    semicolon_token: SyntaxToken,
+) -> TwApplyAtRuleBuilder {
+    TwApplyAtRuleBuilder {
+        apply_token,
+        classes,
+        semicolon_token,
+        important: None,
+    }
+}
+pub struct TwApplyAtRuleBuilder {
+    apply_token: SyntaxToken,
+    classes: TwApplyClassList,
+    semicolon_token: SyntaxToken,
+    important: Option<CssDeclarationImportant>,
+}
+impl TwApplyAtRuleBuilder {
+    pub fn with_important(mut self, important: CssDeclarationImportant) -> Self {
+        self.important = Some(important);
+        self
+    }
+    pub fn build(self) -> TwApplyAtRule {
+        TwApplyAtRule::unwrap_cast(SyntaxNode::new_detached(
+            CssSyntaxKind::TW_APPLY_AT_RULE,
+            [
+                Some(SyntaxElement::Token(self.apply_token)),
+                Some(SyntaxElement::Node(self.classes.into_syntax())),
+                self.important
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.semicolon_token)),
+            ],
+        ))
+    }
+}
+pub fn tw_function(
+    name: CssIdentifier,
+    l_paren_token: SyntaxToken,
+    value: AnyCssUrlValue,
+    r_paren_token: SyntaxToken,
+) -> TwFunction {
+    TwFunction::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::TW_FUNCTION,
+        [
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn tw_screen_at_rule(
+    screen_token: SyntaxToken,
+    name: CssCustomIdentifier,
+    block: AnyCssRuleBlock,
+) -> TwScreenAtRule {
+    TwScreenAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::TW_SCREEN_AT_RULE,
+        [
+            Some(SyntaxElement::Token(screen_token)),
+            Some(SyntaxElement::Node(name.into_syntax())),
+            Some(SyntaxElement::Node(block.into_syntax())),
+        ],
+    ))
+}
+pub fn tw_tailwind_at_rule(
+    tailwind_token: SyntaxToken,
+    layer: CssIdentifier,
+    semicolon_token: SyntaxToken,
+) -> TwTailwindAtRule {
+    TwTailwindAtRule::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::TW_TAILWIND_AT_RULE,
+        [
+            Some(SyntaxElement::Token(tailwind_token)),
+            Some(SyntaxElement::Node(layer.into_syntax())),
+            Some(SyntaxElement::Token(semicolon_token)),
+        ],
+    ))
+}
 pub fn css_component_value_list<I>(items: I) -> CssComponentValueList
 where
     I: IntoIterator<Item = AnyCssValue>,
@@ -2438,6 +2810,30 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn scss_variable_modifier_list<I>(items: I) -> ScssVariableModifierList
+where
+    I: IntoIterator<Item = ScssVariableModifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    ScssVariableModifierList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn tw_apply_class_list<I>(items: I) -> TwApplyClassList
+where
+    I: IntoIterator<Item = CssCustomIdentifier>,
+    I::IntoIter: ExactSizeIterator,
+{
+    TwApplyClassList::unwrap_cast(SyntaxNode::new_detached(
+        CssSyntaxKind::TW_APPLY_CLASS_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn css_bogus<I>(slots: I) -> CssBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
@@ -2,7 +2,7 @@ use crate::CssCommentStyle;
 use biome_formatter::{prelude::*, AttributePosition, IndentWidth, QuoteStyle};
 use biome_formatter::{
     CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
-    TransformSourceMap,
+    MaxEmptyLines, TransformSourceMap,
 };
 
 use crate::comments::{CssComments, FormatCssLeadingComment};
@@ -61,8 +61,10 @@ pub struct CssFormatOptions {
     indent_width: IndentWidth,
     line_ending: LineEnding,
     line_width: LineWidth,
+    max_empty_lines: MaxEmptyLines,
     quote_style: QuoteStyle,
     attribute_position: AttributePosition,
+    collapse_single_declarations: bool,
     _file_source: CssFileSource,
 }
 
@@ -74,8 +76,10 @@ impl CssFormatOptions {
             indent_width: IndentWidth::default(),
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
+            max_empty_lines: MaxEmptyLines::default(),
             quote_style: QuoteStyle::default(),
             attribute_position: AttributePosition::default(),
+            collapse_single_declarations: false,
         }
     }
 
@@ -99,11 +103,21 @@ impl CssFormatOptions {
         self
     }
 
+    pub fn with_max_empty_lines(mut self, max_empty_lines: MaxEmptyLines) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
     pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
         self.quote_style = quote_style;
         self
     }
 
+    pub fn with_collapse_single_declarations(mut self, collapse_single_declarations: bool) -> Self {
+        self.collapse_single_declarations = collapse_single_declarations;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -120,13 +134,26 @@ impl CssFormatOptions {
         self.line_width = line_width;
     }
 
+    pub fn set_max_empty_lines(&mut self, max_empty_lines: MaxEmptyLines) {
+        self.max_empty_lines = max_empty_lines;
+    }
+
     pub fn set_quote_style(&mut self, quote_style: QuoteStyle) {
         self.quote_style = quote_style;
     }
 
+    pub fn set_collapse_single_declarations(&mut self, collapse_single_declarations: bool) {
+        self.collapse_single_declarations = collapse_single_declarations;
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
+
+    /// Whether the rules that have a single declaration are printed on one line when they fit
+    pub fn collapse_single_declarations(&self) -> bool {
+        self.collapse_single_declarations
+    }
 }
 
 impl FormatOptions for CssFormatOptions {
@@ -146,6 +173,10 @@ impl FormatOptions for CssFormatOptions {
         self.line_width
     }
 
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        self.max_empty_lines
+    }
+
     fn as_print_options(&self) -> PrinterOptions {
         PrinterOptions::from(self)
     }
@@ -161,6 +192,12 @@ impl fmt::Display for CssFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.get())?;
-        writeln!(f, "Quote style: {}", self.quote_style)
+        writeln!(f, "Max empty lines: {}", self.max_empty_lines)?;
+        writeln!(f, "Quote style: {}", self.quote_style)?;
+        writeln!(
+            f,
+            "Collapse single declarations: {}",
+            self.collapse_single_declarations
+        )
     }
 }
@@ -27,6 +27,10 @@ impl FormatRule<AnyCssAtRule> for FormatAnyCssAtRule {
             AnyCssAtRule::CssScopeAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssStartingStyleAtRule(node) => node.format().fmt(f),
             AnyCssAtRule::CssSupportsAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::ScssAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::TwApplyAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::TwScreenAtRule(node) => node.format().fmt(f),
+            AnyCssAtRule::TwTailwindAtRule(node) => node.format().fmt(f),
         }
     }
 }
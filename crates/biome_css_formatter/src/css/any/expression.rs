@@ -11,6 +11,8 @@ impl FormatRule<AnyCssExpression> for FormatAnyCssExpression {
             AnyCssExpression::CssBinaryExpression(node) => node.format().fmt(f),
             AnyCssExpression::CssListOfComponentValuesExpression(node) => node.format().fmt(f),
             AnyCssExpression::CssParenthesizedExpression(node) => node.format().fmt(f),
+            AnyCssExpression::LessKeywordArgument(node) => node.format().fmt(f),
+            AnyCssExpression::ScssKeywordArgument(node) => node.format().fmt(f),
         }
     }
 }
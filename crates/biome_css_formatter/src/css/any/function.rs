@@ -10,6 +10,7 @@ impl FormatRule<AnyCssFunction> for FormatAnyCssFunction {
         match node {
             AnyCssFunction::CssFunction(node) => node.format().fmt(f),
             AnyCssFunction::CssUrlFunction(node) => node.format().fmt(f),
+            AnyCssFunction::TwFunction(node) => node.format().fmt(f),
         }
     }
 }
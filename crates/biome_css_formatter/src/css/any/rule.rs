@@ -12,6 +12,10 @@ impl FormatRule<AnyCssRule> for FormatAnyCssRule {
             AnyCssRule::CssBogusRule(node) => node.format().fmt(f),
             AnyCssRule::CssNestedQualifiedRule(node) => node.format().fmt(f),
             AnyCssRule::CssQualifiedRule(node) => node.format().fmt(f),
+            AnyCssRule::LessDeclaration(node) => node.format().fmt(f),
+            AnyCssRule::LessMixinCall(node) => node.format().fmt(f),
+            AnyCssRule::LessMixinDefinition(node) => node.format().fmt(f),
+            AnyCssRule::ScssDeclaration(node) => node.format().fmt(f),
         }
     }
 }
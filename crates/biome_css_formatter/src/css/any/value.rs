@@ -17,6 +17,9 @@ impl FormatRule<AnyCssValue> for FormatAnyCssValue {
             AnyCssValue::CssNumber(node) => node.format().fmt(f),
             AnyCssValue::CssRatio(node) => node.format().fmt(f),
             AnyCssValue::CssString(node) => node.format().fmt(f),
+            AnyCssValue::LessIdentifier(node) => node.format().fmt(f),
+            AnyCssValue::LessParenthesizedValue(node) => node.format().fmt(f),
+            AnyCssValue::ScssIdentifier(node) => node.format().fmt(f),
         }
     }
 }
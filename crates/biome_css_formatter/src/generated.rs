@@ -4868,6 +4868,678 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlValueRaw {
         )
     }
 }
+impl FormatRule<biome_css_syntax::LessDeclaration>
+    for crate::less::auxiliary::declaration::FormatLessDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessDeclaration,
+        crate::less::auxiliary::declaration::FormatLessDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::declaration::FormatLessDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessDeclaration,
+        crate::less::auxiliary::declaration::FormatLessDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::declaration::FormatLessDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessIdentifier>
+    for crate::less::value::identifier::FormatLessIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessIdentifier,
+        crate::less::value::identifier::FormatLessIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::value::identifier::FormatLessIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessIdentifier,
+        crate::less::value::identifier::FormatLessIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::value::identifier::FormatLessIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessKeywordArgument>
+    for crate::less::auxiliary::keyword_argument::FormatLessKeywordArgument
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessKeywordArgument,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessKeywordArgument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessKeywordArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessKeywordArgument,
+        crate::less::auxiliary::keyword_argument::FormatLessKeywordArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::keyword_argument::FormatLessKeywordArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessKeywordArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessKeywordArgument,
+        crate::less::auxiliary::keyword_argument::FormatLessKeywordArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::keyword_argument::FormatLessKeywordArgument::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinArguments>
+    for crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinArguments,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinArguments>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinArguments {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinArguments,
+        crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinArguments {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinArguments,
+        crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_arguments::FormatLessMixinArguments::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinCall>
+    for crate::less::auxiliary::mixin_call::FormatLessMixinCall
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinCall,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinCall>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinCall {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinCall,
+        crate::less::auxiliary::mixin_call::FormatLessMixinCall,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_call::FormatLessMixinCall::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinCall {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinCall,
+        crate::less::auxiliary::mixin_call::FormatLessMixinCall,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_call::FormatLessMixinCall::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinDefinition>
+    for crate::less::auxiliary::mixin_definition::FormatLessMixinDefinition
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinDefinition,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinDefinition>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinDefinition {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinDefinition,
+        crate::less::auxiliary::mixin_definition::FormatLessMixinDefinition,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_definition::FormatLessMixinDefinition::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinDefinition {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinDefinition,
+        crate::less::auxiliary::mixin_definition::FormatLessMixinDefinition,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_definition::FormatLessMixinDefinition::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessMixinGuard>
+    for crate::less::auxiliary::mixin_guard::FormatLessMixinGuard
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessMixinGuard,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessMixinGuard>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessMixinGuard {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessMixinGuard,
+        crate::less::auxiliary::mixin_guard::FormatLessMixinGuard,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_guard::FormatLessMixinGuard::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessMixinGuard {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessMixinGuard,
+        crate::less::auxiliary::mixin_guard::FormatLessMixinGuard,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::mixin_guard::FormatLessMixinGuard::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::LessParenthesizedValue>
+    for crate::less::auxiliary::parenthesized_value::FormatLessParenthesizedValue
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::LessParenthesizedValue,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::LessParenthesizedValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::LessParenthesizedValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::LessParenthesizedValue,
+        crate::less::auxiliary::parenthesized_value::FormatLessParenthesizedValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::auxiliary::parenthesized_value::FormatLessParenthesizedValue::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::LessParenthesizedValue {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::LessParenthesizedValue,
+        crate::less::auxiliary::parenthesized_value::FormatLessParenthesizedValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::auxiliary::parenthesized_value::FormatLessParenthesizedValue::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssAtRule>
+    for crate::scss::statements::at_rule::FormatScssAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::ScssAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssAtRule,
+        crate::scss::statements::at_rule::FormatScssAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::statements::at_rule::FormatScssAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssAtRule,
+        crate::scss::statements::at_rule::FormatScssAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::statements::at_rule::FormatScssAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssDeclaration>
+    for crate::scss::auxiliary::declaration::FormatScssDeclaration
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssDeclaration,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssDeclaration>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssDeclaration {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssDeclaration,
+        crate::scss::auxiliary::declaration::FormatScssDeclaration,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::declaration::FormatScssDeclaration::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssDeclaration {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssDeclaration,
+        crate::scss::auxiliary::declaration::FormatScssDeclaration,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::declaration::FormatScssDeclaration::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssIdentifier>
+    for crate::scss::value::identifier::FormatScssIdentifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssIdentifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssIdentifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssIdentifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssIdentifier,
+        crate::scss::value::identifier::FormatScssIdentifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::value::identifier::FormatScssIdentifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssIdentifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssIdentifier,
+        crate::scss::value::identifier::FormatScssIdentifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::value::identifier::FormatScssIdentifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssKeywordArgument>
+    for crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssKeywordArgument,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssKeywordArgument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssKeywordArgument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssKeywordArgument,
+        crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssKeywordArgument {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssKeywordArgument,
+        crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::keyword_argument::FormatScssKeywordArgument::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::ScssVariableModifier>
+    for crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::ScssVariableModifier,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::ScssVariableModifier>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifier {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssVariableModifier,
+        crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifier {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssVariableModifier,
+        crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::auxiliary::variable_modifier::FormatScssVariableModifier::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::TwApplyAtRule>
+    for crate::tailwind::statements::apply_at_rule::FormatTwApplyAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::TwApplyAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::TwApplyAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::TwApplyAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::TwApplyAtRule,
+        crate::tailwind::statements::apply_at_rule::FormatTwApplyAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::tailwind::statements::apply_at_rule::FormatTwApplyAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::TwApplyAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::TwApplyAtRule,
+        crate::tailwind::statements::apply_at_rule::FormatTwApplyAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::tailwind::statements::apply_at_rule::FormatTwApplyAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::TwFunction>
+    for crate::tailwind::auxiliary::function::FormatTwFunction
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_css_syntax::TwFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::TwFunction>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::TwFunction {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::TwFunction,
+        crate::tailwind::auxiliary::function::FormatTwFunction,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::tailwind::auxiliary::function::FormatTwFunction::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::TwFunction {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::TwFunction,
+        crate::tailwind::auxiliary::function::FormatTwFunction,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::tailwind::auxiliary::function::FormatTwFunction::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::TwScreenAtRule>
+    for crate::tailwind::statements::screen_at_rule::FormatTwScreenAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::TwScreenAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::TwScreenAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::TwScreenAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::TwScreenAtRule,
+        crate::tailwind::statements::screen_at_rule::FormatTwScreenAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::tailwind::statements::screen_at_rule::FormatTwScreenAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::TwScreenAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::TwScreenAtRule,
+        crate::tailwind::statements::screen_at_rule::FormatTwScreenAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::tailwind::statements::screen_at_rule::FormatTwScreenAtRule::default(),
+        )
+    }
+}
+impl FormatRule<biome_css_syntax::TwTailwindAtRule>
+    for crate::tailwind::statements::tailwind_at_rule::FormatTwTailwindAtRule
+{
+    type Context = CssFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_css_syntax::TwTailwindAtRule,
+        f: &mut CssFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_css_syntax::TwTailwindAtRule>::fmt(self, node, f)
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::TwTailwindAtRule {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::TwTailwindAtRule,
+        crate::tailwind::statements::tailwind_at_rule::FormatTwTailwindAtRule,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::tailwind::statements::tailwind_at_rule::FormatTwTailwindAtRule::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::TwTailwindAtRule {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::TwTailwindAtRule,
+        crate::tailwind::statements::tailwind_at_rule::FormatTwTailwindAtRule,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::tailwind::statements::tailwind_at_rule::FormatTwTailwindAtRule::default(),
+        )
+    }
+}
 impl AsFormat<CssFormatContext> for biome_css_syntax::CssComponentValueList {
     type Format<'a> = FormatRefWithRule<
         'a,
@@ -5527,6 +6199,60 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::CssUrlModifierList {
         )
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifierList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::ScssVariableModifierList,
+        crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::ScssVariableModifierList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::ScssVariableModifierList,
+        crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::scss::lists::variable_modifier_list::FormatScssVariableModifierList::default(),
+        )
+    }
+}
+impl AsFormat<CssFormatContext> for biome_css_syntax::TwApplyClassList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::TwApplyClassList,
+        crate::tailwind::lists::apply_class_list::FormatTwApplyClassList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::tailwind::lists::apply_class_list::FormatTwApplyClassList::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::TwApplyClassList {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::TwApplyClassList,
+        crate::tailwind::lists::apply_class_list::FormatTwApplyClassList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::tailwind::lists::apply_class_list::FormatTwApplyClassList::default(),
+        )
+    }
+}
 impl FormatRule<biome_css_syntax::CssBogus> for crate::css::bogus::bogus::FormatCssBogus {
     type Context = CssFormatContext;
     #[inline(always)]
@@ -8166,3 +8892,30 @@ impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyCssValue {
         FormatOwnedWithRule::new(self, crate::css::any::value::FormatAnyCssValue::default())
     }
 }
+impl AsFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_css_syntax::AnyLessMixinName,
+        crate::less::any::mixin_name::FormatAnyLessMixinName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::less::any::mixin_name::FormatAnyLessMixinName::default(),
+        )
+    }
+}
+impl IntoFormat<CssFormatContext> for biome_css_syntax::AnyLessMixinName {
+    type Format = FormatOwnedWithRule<
+        biome_css_syntax::AnyLessMixinName,
+        crate::less::any::mixin_name::FormatAnyLessMixinName,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::less::any::mixin_name::FormatAnyLessMixinName::default(),
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinDefinition, LessMixinDefinitionFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinDefinition;
+impl FormatNodeRule<LessMixinDefinition> for FormatLessMixinDefinition {
+    fn fmt_fields(&self, node: &LessMixinDefinition, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinDefinitionFields {
+            name,
+            arguments,
+            guard,
+            block,
+        } = node.as_fields();
+
+        write!(f, [name.format(), arguments.format()])?;
+
+        if let Some(guard) = guard {
+            write!(f, [space(), guard.format()])?;
+        }
+
+        write!(f, [space(), block.format()])
+    }
+}
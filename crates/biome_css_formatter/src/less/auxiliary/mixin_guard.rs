@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessMixinGuard, LessMixinGuardFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessMixinGuard;
+impl FormatNodeRule<LessMixinGuard> for FormatLessMixinGuard {
+    fn fmt_fields(&self, node: &LessMixinGuard, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessMixinGuardFields {
+            when_token,
+            conditions,
+        } = node.as_fields();
+
+        // The conditions of a guard are expressions of Less, such as
+        // `(@width > 0) and (iscolor(@color))`, whose operators aren't
+        // formatted yet, so they are kept as written.
+        write!(
+            f,
+            [
+                when_token.format(),
+                space(),
+                format_verbatim_node(conditions.syntax())
+            ]
+        )
+    }
+}
@@ -0,0 +1,9 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod declaration;
+pub(crate) mod keyword_argument;
+pub(crate) mod mixin_arguments;
+pub(crate) mod mixin_call;
+pub(crate) mod mixin_definition;
+pub(crate) mod mixin_guard;
+pub(crate) mod parenthesized_value;
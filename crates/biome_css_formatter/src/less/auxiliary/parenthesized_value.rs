@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessParenthesizedValue, LessParenthesizedValueFields};
+use biome_formatter::{format_args, write};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessParenthesizedValue;
+impl FormatNodeRule<LessParenthesizedValue> for FormatLessParenthesizedValue {
+    fn fmt_fields(&self, node: &LessParenthesizedValue, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessParenthesizedValueFields {
+            l_paren_token,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [group(&format_args![
+                l_paren_token.format(),
+                soft_block_indent(&value.format()),
+                r_paren_token.format()
+            ])]
+        )
+    }
+}
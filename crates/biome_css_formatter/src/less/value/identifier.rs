@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_css_syntax::{LessIdentifier, LessIdentifierFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatLessIdentifier;
+impl FormatNodeRule<LessIdentifier> for FormatLessIdentifier {
+    fn fmt_fields(&self, node: &LessIdentifier, f: &mut CssFormatter) -> FormatResult<()> {
+        let LessIdentifierFields { at_token, name } = node.as_fields();
+
+        // The variables of Less are case-sensitive, so the name is written
+        // as-is instead of being formatted as a lowercase `CssIdentifier`.
+        let name = name?;
+        f.comments().mark_suppression_checked(name.syntax());
+        write!(f, [at_token.format(), name.value_token().format()])
+    }
+}
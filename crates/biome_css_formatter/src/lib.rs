@@ -3,8 +3,11 @@ pub mod context;
 mod css;
 mod cst;
 mod generated;
+mod less;
 mod prelude;
+mod scss;
 mod separated;
+mod tailwind;
 mod utils;
 
 use std::borrow::Cow;
@@ -0,0 +1,5 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod declaration;
+pub(crate) mod keyword_argument;
+pub(crate) mod variable_modifier;
@@ -0,0 +1,6 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod auxiliary;
+pub(crate) mod lists;
+pub(crate) mod statements;
+pub(crate) mod value;
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssAtRule, ScssAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssAtRule;
+impl FormatNodeRule<ScssAtRule> for FormatScssAtRule {
+    fn fmt_fields(&self, node: &ScssAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssAtRuleFields {
+            name,
+            prelude,
+            block,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(f, [name.format()])?;
+
+        // The prelude of a directive is an expression of SCSS, such as
+        // `$i from 1 through $count` or `$value == null`, whose operators
+        // aren't formatted yet, so it's kept as written.
+        if !prelude.is_empty() {
+            write!(f, [space(), format_verbatim_node(prelude.syntax())])?;
+        }
+
+        match block {
+            Some(block) => write!(f, [space(), block.format()]),
+            None if semicolon_token.is_some() => write!(f, [semicolon_token.format()]),
+            None => write!(f, [text(";")]),
+        }
+    }
+}
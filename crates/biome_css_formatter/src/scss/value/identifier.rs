@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use biome_css_syntax::{ScssIdentifier, ScssIdentifierFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatScssIdentifier;
+impl FormatNodeRule<ScssIdentifier> for FormatScssIdentifier {
+    fn fmt_fields(&self, node: &ScssIdentifier, f: &mut CssFormatter) -> FormatResult<()> {
+        let ScssIdentifierFields { dollar_token, name } = node.as_fields();
+
+        // Unlike the regular CSS identifiers, the variables of SCSS are
+        // case-sensitive, so the name is written as-is instead of being
+        // formatted as a lowercase `CssIdentifier`.
+        let name = name?;
+        f.comments().mark_suppression_checked(name.syntax());
+        write!(f, [dollar_token.format(), name.value_token().format()])
+    }
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use biome_css_syntax::{TwFunction, TwFunctionFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTwFunction;
+impl FormatNodeRule<TwFunction> for FormatTwFunction {
+    fn fmt_fields(&self, node: &TwFunction, f: &mut CssFormatter) -> FormatResult<()> {
+        let TwFunctionFields {
+            name,
+            l_paren_token,
+            value,
+            r_paren_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                name.format(),
+                l_paren_token.format(),
+                value.format(),
+                r_paren_token.format()
+            ]
+        )
+    }
+}
@@ -0,0 +1,12 @@
+use crate::prelude::*;
+use biome_css_syntax::TwApplyClassList;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTwApplyClassList;
+impl FormatRule<TwApplyClassList> for FormatTwApplyClassList {
+    type Context = CssFormatContext;
+    fn fmt(&self, node: &TwApplyClassList, f: &mut CssFormatter) -> FormatResult<()> {
+        f.join_with(&soft_line_break_or_space())
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
@@ -0,0 +1,5 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod apply_at_rule;
+pub(crate) mod screen_at_rule;
+pub(crate) mod tailwind_at_rule;
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use biome_css_syntax::{TwScreenAtRule, TwScreenAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTwScreenAtRule;
+impl FormatNodeRule<TwScreenAtRule> for FormatTwScreenAtRule {
+    fn fmt_fields(&self, node: &TwScreenAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let TwScreenAtRuleFields {
+            screen_token,
+            name,
+            block,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                screen_token.format(),
+                space(),
+                name.format(),
+                space(),
+                block.format()
+            ]
+        )
+    }
+}
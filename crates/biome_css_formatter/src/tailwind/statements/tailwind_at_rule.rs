@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use biome_css_syntax::{TwTailwindAtRule, TwTailwindAtRuleFields};
+use biome_formatter::write;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTwTailwindAtRule;
+impl FormatNodeRule<TwTailwindAtRule> for FormatTwTailwindAtRule {
+    fn fmt_fields(&self, node: &TwTailwindAtRule, f: &mut CssFormatter) -> FormatResult<()> {
+        let TwTailwindAtRuleFields {
+            tailwind_token,
+            layer,
+            semicolon_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                tailwind_token.format(),
+                space(),
+                layer.format(),
+                semicolon_token.format()
+            ]
+        )
+    }
+}
@@ -3,6 +3,7 @@ use biome_formatter::{CstFormatContext, Format, FormatResult};
 
 use crate::CssFormatter;
 use biome_css_syntax::stmt_ext::CssBlockLike;
+use biome_css_syntax::{AnyCssDeclarationOrAtRule, AnyCssDeclarationOrRule};
 use biome_formatter::write;
 
 #[derive(Debug, Copy, Clone)]
@@ -40,6 +41,33 @@ impl<'a> FormatCssBlockLike<'a> {
             }
         }
     }
+
+    /// Returns `true` if the block contains a single declaration, such as `{ color: red; }`
+    fn is_single_declaration(&self) -> bool {
+        match self.block {
+            CssBlockLike::CssDeclarationOrAtRuleBlock(block) => {
+                let items = block.items();
+                items.len() == 1
+                    && matches!(
+                        items.first(),
+                        Some(AnyCssDeclarationOrAtRule::CssDeclarationWithSemicolon(_))
+                    )
+            }
+            CssBlockLike::CssDeclarationBlock(block) => block.declarations().len() == 1,
+            CssBlockLike::CssDeclarationOrRuleBlock(block) => {
+                let items = block.items();
+                items.len() == 1
+                    && matches!(
+                        items.first(),
+                        Some(AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(_))
+                    )
+            }
+            CssBlockLike::CssKeyframesBlock(_)
+            | CssBlockLike::CssRuleBlock(_)
+            | CssBlockLike::CssFontFeatureValuesBlock(_)
+            | CssBlockLike::CssPageAtRuleBlock(_) => false,
+        }
+    }
 }
 
 impl<'a> Format<CssFormatContext> for FormatCssBlockLike<'a> {
@@ -63,6 +91,18 @@ impl<'a> Format<CssFormatContext> for FormatCssBlockLike<'a> {
             } else {
                 write!(f, [soft_line_break()])?;
             }
+        } else if f.options().collapse_single_declarations()
+            && self.is_single_declaration()
+            && !self.block.syntax().has_comments_descendants()
+        {
+            // The declaration stays on the line of the selector when it fits,
+            // `{ color: red; }`, the group breaks otherwise
+            write!(
+                f,
+                [group(&soft_space_or_block_indent(&format_with(|f| {
+                    self.write_items(f)
+                })))]
+            )?;
         } else {
             write!(
                 f,
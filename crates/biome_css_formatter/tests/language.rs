@@ -10,7 +10,17 @@ use biome_service::settings::{ServiceLanguage, Settings};
 
 #[derive(Default)]
 pub struct CssTestFormatLanguage {
-    _source_type: CssFileSource,
+    source_type: CssFileSource,
+    tailwind: bool,
+}
+
+impl CssTestFormatLanguage {
+    pub fn new(source_type: CssFileSource, tailwind: bool) -> Self {
+        CssTestFormatLanguage {
+            source_type,
+            tailwind,
+        }
+    }
 }
 
 impl TestFormatLanguage for CssTestFormatLanguage {
@@ -19,7 +29,16 @@ impl TestFormatLanguage for CssTestFormatLanguage {
     type FormatLanguage = CssFormatLanguage;
 
     fn parse(&self, text: &str) -> AnyParse {
-        let parse = parse_css(text, CssParserOptions::default());
+        let mut options = CssParserOptions::default();
+        if self.source_type.is_scss() {
+            options = options.scss();
+        } else if self.source_type.is_less() {
+            options = options.less();
+        }
+        if self.tailwind {
+            options = options.tailwind();
+        }
+        let parse = parse_css(text, options);
 
         AnyParse::new(parse.syntax().as_send().unwrap(), parse.into_diagnostics())
     }
@@ -1,6 +1,7 @@
 use std::{env, path::Path};
 
 use biome_css_formatter::context::CssFormatOptions;
+use biome_css_syntax::CssFileSource;
 use biome_formatter::IndentStyle;
 use biome_formatter_test::test_prettier_snapshot::{PrettierSnapshot, PrettierTestFile};
 
@@ -20,7 +21,7 @@ fn test_snapshot(input: &'static str, _: &str, _: &str, _: &str) {
     let options = CssFormatOptions::default()
         .with_indent_style(IndentStyle::Space)
         .with_indent_width(2.into());
-    let language = language::CssTestFormatLanguage::default();
+    let language = language::CssTestFormatLanguage::new(CssFileSource::css(), false);
     let snapshot = PrettierSnapshot::new(test_file, language, options);
 
     snapshot.test()
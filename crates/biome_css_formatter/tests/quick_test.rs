@@ -1,6 +1,7 @@
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_formatter::format_node;
 use biome_css_parser::{parse_css, CssParserOptions};
+use biome_css_syntax::CssFileSource;
 use biome_formatter::{IndentStyle, LineWidth};
 use biome_formatter_test::check_reformat::CheckReformat;
 
@@ -36,7 +37,7 @@ fn quick_test() {
     let result = doc.print().unwrap();
 
     let root = &parse.syntax();
-    let language = language::CssTestFormatLanguage::default();
+    let language = language::CssTestFormatLanguage::new(CssFileSource::css(), false);
 
     println!("{}", doc.into_document());
     eprintln!("{}", result.as_code());
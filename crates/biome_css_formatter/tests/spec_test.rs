@@ -1,4 +1,5 @@
 use biome_css_formatter::context::CssFormatOptions;
+use biome_css_syntax::CssFileSource;
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use std::path::Path;
 
@@ -30,8 +31,16 @@ pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _f
         return;
     };
 
+    let source_type: CssFileSource = test_file.input_file().as_path().try_into().unwrap();
+
     let options = CssFormatOptions::default();
-    let language = language::CssTestFormatLanguage::default();
+    // The directives of Tailwind CSS are written in regular CSS files.
+    let tailwind = test_file
+        .input_file()
+        .as_path()
+        .components()
+        .any(|component| component.as_os_str() == "tailwind");
+    let language = language::CssTestFormatLanguage::new(source_type, tailwind);
 
     let snapshot = SpecSnapshot::new(test_file, test_directory, language, options);
 
@@ -36,6 +36,11 @@ pub enum CssLexContext {
     /// support #000 #000f #ffffff #ffffffff
     /// https://drafts.csswg.org/css-color/#typedef-hex-color
     Color,
+
+    /// Applied when lexing the utilities of the `@apply` directive of Tailwind CSS.
+    /// Consumes a utility with its variants and its arbitrary values as a single identifier,
+    /// such as `md:hover:bg-[#0ea5e9]/50`, until encountering a whitespace, ";", "{" or "}".
+    TailwindUtility,
 }
 
 impl LexContext for CssLexContext {
@@ -119,6 +124,7 @@ impl<'src> Lexer<'src> for CssLexer<'src> {
                 CssLexContext::PseudoNthSelector => self.consume_pseudo_nth_selector_token(current),
                 CssLexContext::UrlRawValue => self.consume_url_raw_value_token(current),
                 CssLexContext::Color => self.consume_color_token(current),
+                CssLexContext::TailwindUtility => self.consume_tailwind_utility_token(current),
             },
             None => EOF,
         };
@@ -311,6 +317,7 @@ impl<'src> CssLexer<'src> {
                 self.advance(1);
                 self.consume_byte(T!["$="])
             }
+            IDT if current == b'$' && self.config.scss => self.consume_byte(T![$]),
             IDT | UNI | BSL if self.is_ident_start() => self.consume_identifier(),
 
             MUL => self.consume_mul(),
@@ -416,6 +423,43 @@ impl<'src> CssLexer<'src> {
         CSS_URL_VALUE_RAW_LITERAL
     }
 
+    fn consume_tailwind_utility_token(&mut self, current: u8) -> CssSyntaxKind {
+        match lookup_byte(current) {
+            WHS | SEM | BEO | BEC => self.consume_token(current),
+            SLH if matches!(self.peek_byte(), Some(b'*' | b'/')) => self.consume_token(current),
+            // `!important` ends the utilities, but `!font-bold` is an important utility.
+            EXL if self.is_important_ahead() => self.consume_token(current),
+            _ => self.consume_tailwind_utility(),
+        }
+    }
+
+    fn consume_tailwind_utility(&mut self) -> CssSyntaxKind {
+        // The arbitrary values, such as `grid-cols-[1fr_auto]`, can contain any character
+        // but a whitespace, which is written as an underscore.
+        let mut brackets = 0usize;
+        while let Some(chr) = self.current_byte() {
+            match lookup_byte(chr) {
+                WHS => break,
+                SEM | BEO | BEC if brackets == 0 => break,
+                BTO => brackets += 1,
+                BTC => brackets = brackets.saturating_sub(1),
+                BSL if self.is_valid_escape_at(1) => {
+                    self.advance(1);
+                }
+                _ => {}
+            }
+            self.advance(1);
+        }
+
+        IDENT
+    }
+
+    /// Checks if the `!` at the current position starts an `!important`.
+    fn is_important_ahead(&self) -> bool {
+        let rest = self.source[self.position + 1..].trim_start();
+        rest.len() >= 9 && rest.as_bytes()[..9].eq_ignore_ascii_case(b"important")
+    }
+
     fn consume_pseudo_nth_selector_token(&mut self, current: u8) -> CssSyntaxKind {
         match current {
             b'-' => self.consume_byte(T![-]),
@@ -843,6 +887,11 @@ impl<'src> CssLexer<'src> {
             b"domain" => DOMAIN_KW,
             b"media-document" => MEDIA_DOCUMENT_KW,
             b"regexp" => REGEXP_KW,
+            b"when" => WHEN_KW,
+            b"tailwind" => TAILWIND_KW,
+            b"apply" => APPLY_KW,
+            b"screen" => SCREEN_KW,
+            b"theme" => THEME_KW,
             _ => IDENT,
         }
     }
@@ -1030,7 +1079,11 @@ impl<'src> CssLexer<'src> {
                     COMMENT
                 }
             }
-            Some(b'/') if self.config.allow_wrong_line_comments => {
+            Some(b'/')
+                if self.config.allow_wrong_line_comments
+                    || self.config.scss
+                    || self.config.less =>
+            {
                 self.advance(2);
 
                 while let Some(chr) = self.current_byte() {
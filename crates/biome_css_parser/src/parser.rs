@@ -12,11 +12,21 @@ pub(crate) struct CssParser<'source> {
     context: ParserContext<CssSyntaxKind>,
     source: CssTokenSource<'source>,
     state: CssParserState,
+    options: CssParserOptions,
 }
 
 #[derive(Default, Debug, Clone)]
 pub struct CssParserOptions {
     pub allow_wrong_line_comments: bool,
+    /// Enables the syntax of SCSS: the variables, the `//` comments,
+    /// and the directives such as `@mixin`, `@include`, or `@if`.
+    pub scss: bool,
+    /// Enables the syntax of Less: the variables, the `//` comments,
+    /// the mixins and their guards.
+    pub less: bool,
+    /// Enables the directives and the functions of Tailwind CSS,
+    /// such as `@tailwind`, `@apply`, `@screen`, or `theme()`.
+    pub tailwind: bool,
 }
 
 impl CssParserOptions {
@@ -24,17 +34,37 @@ impl CssParserOptions {
         self.allow_wrong_line_comments = true;
         self
     }
+
+    pub fn scss(mut self) -> Self {
+        self.scss = true;
+        self
+    }
+
+    pub fn less(mut self) -> Self {
+        self.less = true;
+        self
+    }
+
+    pub fn tailwind(mut self) -> Self {
+        self.tailwind = true;
+        self
+    }
 }
 
 impl<'source> CssParser<'source> {
     pub fn new(source: &'source str, config: CssParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: CssTokenSource::from_str(source, config),
+            source: CssTokenSource::from_str(source, config.clone()),
             state: CssParserState::new(),
+            options: config,
         }
     }
 
+    pub(crate) fn options(&self) -> &CssParserOptions {
+        &self.options
+    }
+
     /// Re-lexes the current token in the specified context. Returns the kind
     /// of the re-lexed token (can be the same as before if the context doesn't make a difference for the current token)
     #[allow(dead_code)] //TODO remote this once we actually don't use it
@@ -2,6 +2,7 @@ use super::parse_error::expected_media_query;
 use crate::parser::CssParser;
 use crate::syntax::at_rule::feature::parse_any_query_feature;
 use crate::syntax::block::parse_rule_block;
+use crate::syntax::tailwind::{is_at_tailwind_function, parse_tailwind_function};
 use crate::syntax::{is_at_identifier, is_nth_at_identifier, parse_regular_identifier};
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::{CssSyntaxKind, T};
@@ -75,7 +76,9 @@ impl ParseSeparatedList for MediaQueryList {
 
 #[inline]
 fn parse_any_media_query(p: &mut CssParser) -> ParsedSyntax {
-    if is_at_media_type_query(p) {
+    if is_at_tailwind_function(p) {
+        parse_tailwind_function(p)
+    } else if is_at_media_type_query(p) {
         parse_any_media_type_query(p)
     } else {
         let m = p.start();
@@ -16,6 +16,7 @@ mod page;
 mod parse_error;
 mod property;
 mod scope;
+mod scss;
 mod starting_style;
 mod supports;
 
@@ -43,11 +44,13 @@ use crate::syntax::at_rule::media::{is_at_media_at_rule, parse_media_at_rule};
 use crate::syntax::at_rule::namespace::{is_at_namespace_at_rule, parse_namespace_at_rule};
 use crate::syntax::at_rule::page::{is_at_page_at_rule, parse_page_at_rule};
 use crate::syntax::at_rule::scope::{is_at_scope_at_rule, parse_scope_at_rule};
+use crate::syntax::at_rule::scss::{is_at_scss_at_rule, parse_scss_at_rule};
 use crate::syntax::at_rule::starting_style::{
     is_at_starting_style_at_rule, parse_starting_style_at_rule,
 };
 use crate::syntax::at_rule::supports::{is_at_supports_at_rule, parse_supports_at_rule};
 use crate::syntax::parse_error::expected_any_at_rule;
+use crate::syntax::tailwind::{is_at_tailwind_at_rule, parse_tailwind_at_rule};
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::T;
 use biome_parser::prelude::ParsedSyntax::{Absent, Present};
@@ -120,6 +123,10 @@ pub(crate) fn parse_any_at_rule(p: &mut CssParser) -> ParsedSyntax {
         parse_document_at_rule(p)
     } else if is_at_property_at_rule(p) {
         parse_property_at_rule(p)
+    } else if is_at_tailwind_at_rule(p) {
+        parse_tailwind_at_rule(p)
+    } else if is_at_scss_at_rule(p) {
+        parse_scss_at_rule(p)
     } else {
         Absent
     }
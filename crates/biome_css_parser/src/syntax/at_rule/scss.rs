@@ -0,0 +1,95 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_error::expected_component_value;
+use crate::syntax::property::parse_generic_component_value;
+use crate::syntax::{is_at_identifier, parse_regular_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+
+/// Checks if the current token in the parser is the name of a directive of SCSS.
+///
+/// Every at-rule that isn't known by CSS is parsed as a directive when the SCSS syntax is enabled,
+/// the same way Sass accepts the at-rules that it doesn't know.
+#[inline]
+pub(crate) fn is_at_scss_at_rule(p: &mut CssParser) -> bool {
+    p.options().scss && is_at_identifier(p)
+}
+
+/// Parses a directive of SCSS, such as `@mixin`, `@include`, `@if` or `@each`.
+///
+/// The prelude of the directive is parsed as a list of component values,
+/// and it's followed by a block of declarations and rules, or by a semicolon.
+///
+/// # Examples
+///
+/// ```scss
+/// @use "sass:math";
+///
+/// @mixin theme($theme: DarkGray) {
+///     background: $theme;
+/// }
+///
+/// .alert {
+///     @include theme($theme: DarkRed);
+///
+///     @if $rounded == true {
+///         border-radius: 4px;
+///     } @else {
+///         border-radius: 0;
+///     }
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_scss_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_at_rule(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_regular_identifier(p).ok();
+    ScssAtRulePreludeList.parse_list(p);
+
+    if p.at(T!['{']) {
+        parse_declaration_or_rule_list_block(p);
+    } else if !p.at(T!['}']) && !p.at(EOF) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, SCSS_AT_RULE))
+}
+
+const SCSS_AT_RULE_PRELUDE_END_SET: TokenSet<CssSyntaxKind> = token_set![T!['{'], T!['}'], T![;]];
+
+struct ScssAtRulePreludeList;
+
+impl ParseNodeList for ScssAtRulePreludeList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_GENERIC_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_generic_component_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(SCSS_AT_RULE_PRELUDE_END_SET)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_PROPERTY_VALUE, SCSS_AT_RULE_PRELUDE_END_SET),
+            expected_component_value,
+        )
+    }
+}
@@ -1,7 +1,11 @@
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::ParseBlockBody;
+use crate::syntax::less::{
+    is_at_less_declaration, is_at_less_mixin, parse_less_declaration, parse_less_mixin,
+};
 use crate::syntax::parse_error::expected_any_declaration_or_at_rule;
+use crate::syntax::scss::{is_at_scss_declaration, parse_scss_declaration};
 use crate::syntax::{
     is_at_declaration, is_at_nested_qualified_rule, parse_declaration_with_semicolon,
     parse_nested_qualified_rule,
@@ -35,7 +39,12 @@ impl ParseBlockBody for DeclarationOrRuleListBlock {
 
 #[inline]
 fn is_at_declaration_or_rule_item(p: &mut CssParser) -> bool {
-    is_at_at_rule(p) || is_at_nested_qualified_rule(p) || is_at_declaration(p)
+    is_at_less_declaration(p)
+        || is_at_at_rule(p)
+        || is_at_less_mixin(p)
+        || is_at_nested_qualified_rule(p)
+        || is_at_declaration(p)
+        || is_at_scss_declaration(p)
 }
 
 struct DeclarationOrRuleListParseRecovery;
@@ -56,10 +65,17 @@ impl ParseNodeList for DeclarationOrRuleList {
     const LIST_KIND: Self::Kind = CSS_DECLARATION_OR_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        // The variables of Less start with an `@`, so they are checked before the at-rules.
+        if is_at_less_declaration(p) {
+            parse_less_declaration(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
         } else if is_at_declaration(p) {
             parse_declaration_with_semicolon(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
+        } else if is_at_less_mixin(p) {
+            parse_less_mixin(p)
         } else if is_at_nested_qualified_rule(p) {
             parse_nested_qualified_rule(p)
         } else {
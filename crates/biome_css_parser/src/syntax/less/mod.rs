@@ -0,0 +1,247 @@
+use crate::parser::CssParser;
+use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::parse_declaration_important;
+use crate::syntax::parse_error::{expected_component_value, expected_identifier};
+use crate::syntax::property::{parse_generic_component_value, GenericComponentValueList};
+use crate::syntax::selector::{parse_class_selector, parse_id_selector};
+use crate::syntax::value::function::{parse_any_expression, ParameterList};
+use crate::syntax::value::parse_error::expected_expression;
+use crate::syntax::{is_nth_at_identifier, parse_regular_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::{ParseNodeList, ParseSeparatedList};
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+/// Checks if the current position is at a variable of Less, such as `@primary-color`.
+///
+/// The variables of Less start with an `@`, like the at-rules, so they are only
+/// recognized when the syntax of Less is enabled.
+#[inline]
+pub(crate) fn is_at_less_identifier(p: &mut CssParser) -> bool {
+    p.options().less && p.at(T![@]) && is_nth_at_identifier(p, 1)
+}
+
+/// Parses a variable of Less.
+///
+/// ```less
+/// @primary-color
+/// ```
+#[inline]
+pub(crate) fn parse_less_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![@]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, LESS_IDENTIFIER))
+}
+
+#[inline]
+pub(crate) fn is_at_less_declaration(p: &mut CssParser) -> bool {
+    // `@page :first` is an at-rule, not the declaration of a variable.
+    is_at_less_identifier(p) && !p.nth_at(1, T![page]) && p.nth_at(2, T![:])
+}
+
+/// Parses the declaration of a variable of Less.
+///
+/// The declarations are accepted at the root of the stylesheet and in the blocks.
+///
+/// ```less
+/// @primary-color: #333;
+/// ```
+#[inline]
+pub(crate) fn parse_less_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_less_identifier(p).ok();
+    p.bump(T![:]);
+    GenericComponentValueList.parse_list(p);
+
+    // Like the declarations of CSS, the semicolon is optional before a closing brace.
+    if !p.at(T!['}']) && !p.at(EOF) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, LESS_DECLARATION))
+}
+
+#[inline]
+pub(crate) fn is_at_less_keyword_argument(p: &mut CssParser) -> bool {
+    is_at_less_identifier(p) && p.nth_at(2, T![:])
+}
+
+/// Parses a named argument of a mixin, or a parameter with a default value.
+///
+/// ```less
+/// .button(@radius: 4px);
+/// ```
+#[inline]
+pub(crate) fn parse_less_keyword_argument(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_keyword_argument(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_less_identifier(p).ok();
+    p.bump(T![:]);
+    parse_any_expression(p).or_add_diagnostic(p, expected_expression);
+
+    Present(m.complete(p, LESS_KEYWORD_ARGUMENT))
+}
+
+#[inline]
+pub(crate) fn is_at_less_parenthesized_value(p: &mut CssParser) -> bool {
+    p.options().less && p.at(T!['('])
+}
+
+/// Parses a value between parentheses, such as an operation or the condition of a guard.
+///
+/// ```less
+/// @width: (@gutter * 2);
+/// ```
+#[inline]
+pub(crate) fn parse_less_parenthesized_value(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_parenthesized_value(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    GenericComponentValueList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_PARENTHESIZED_VALUE))
+}
+
+/// Checks if the current position is at the definition or the call of a mixin of Less.
+///
+/// A mixin is a class or an id selector that is directly followed by its arguments,
+/// or a class or an id selector that is followed by a semicolon.
+#[inline]
+pub(crate) fn is_at_less_mixin(p: &mut CssParser) -> bool {
+    p.options().less
+        && (p.at(T![.]) || p.at(T![#]))
+        && is_nth_at_identifier(p, 1)
+        && (p.nth_at(2, T!['(']) || p.nth_at(2, T![;]))
+}
+
+/// Parses the definition or the call of a mixin of Less.
+///
+/// A mixin with arguments that is followed by a guard or by a block is a definition,
+/// every other mixin is a call.
+///
+/// # Examples
+///
+/// ```less
+/// .bordered(@width: 2px, @color: black) when (@width > 0) {
+///     border: @width solid @color;
+/// }
+///
+/// #header {
+///     .bordered(4px, black);
+///     .rounded;
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_less_mixin(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_less_mixin(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    if p.at(T![.]) {
+        parse_class_selector(p).ok();
+    } else {
+        parse_id_selector(p).ok();
+    }
+
+    let has_arguments = parse_less_mixin_arguments(p).is_present();
+
+    if has_arguments && (p.at(T![when]) || p.at(T!['{'])) {
+        parse_less_mixin_guard(p).ok();
+        parse_declaration_or_rule_list_block(p);
+
+        return Present(m.complete(p, LESS_MIXIN_DEFINITION));
+    }
+
+    parse_declaration_important(p).ok();
+
+    if !p.at(T!['}']) && !p.at(EOF) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, LESS_MIXIN_CALL))
+}
+
+#[inline]
+fn parse_less_mixin_arguments(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T!['(']) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T!['(']);
+    ParameterList.parse_list(p);
+    p.expect(T![')']);
+
+    Present(m.complete(p, LESS_MIXIN_ARGUMENTS))
+}
+
+#[inline]
+fn parse_less_mixin_guard(p: &mut CssParser) -> ParsedSyntax {
+    if !p.at(T![when]) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![when]);
+    LessMixinGuardConditionList.parse_list(p);
+
+    Present(m.complete(p, LESS_MIXIN_GUARD))
+}
+
+const LESS_MIXIN_GUARD_END_SET: TokenSet<CssSyntaxKind> = token_set![T!['{'], T!['}'], T![;]];
+
+struct LessMixinGuardConditionList;
+
+impl ParseNodeList for LessMixinGuardConditionList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = CSS_GENERIC_COMPONENT_VALUE_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_generic_component_value(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(LESS_MIXIN_GUARD_END_SET)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS_PROPERTY_VALUE, LESS_MIXIN_GUARD_END_SET),
+            expected_component_value,
+        )
+    }
+}
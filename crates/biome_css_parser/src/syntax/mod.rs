@@ -1,16 +1,27 @@
 mod at_rule;
 mod block;
+mod less;
 mod parse_error;
 mod property;
+mod scss;
 mod selector;
+mod tailwind;
 mod value;
 
 use crate::lexer::CssLexContext;
 use crate::parser::CssParser;
 use crate::syntax::at_rule::{is_at_at_rule, parse_at_rule};
 use crate::syntax::block::parse_declaration_or_rule_list_block;
+use crate::syntax::less::{
+    is_at_less_declaration, is_at_less_identifier, is_at_less_mixin,
+    is_at_less_parenthesized_value, parse_less_declaration, parse_less_identifier,
+    parse_less_mixin, parse_less_parenthesized_value,
+};
 use crate::syntax::parse_error::expected_any_rule;
 use crate::syntax::property::{is_at_any_property, parse_any_property};
+use crate::syntax::scss::{
+    is_at_scss_declaration, is_at_scss_identifier, parse_scss_declaration, parse_scss_identifier,
+};
 use crate::syntax::selector::is_nth_at_selector;
 use crate::syntax::selector::relative_selector::{is_at_relative_selector, RelativeSelectorList};
 use crate::syntax::selector::SelectorList;
@@ -47,7 +58,11 @@ impl RuleList {
 
 #[inline]
 pub(crate) fn is_at_rule_list_element(p: &mut CssParser) -> bool {
-    is_at_at_rule(p) || is_at_qualified_rule(p)
+    is_at_less_declaration(p)
+        || is_at_at_rule(p)
+        || is_at_scss_declaration(p)
+        || is_at_less_mixin(p)
+        || is_at_qualified_rule(p)
 }
 
 struct RuleListParseRecovery;
@@ -68,8 +83,15 @@ impl ParseNodeList for RuleList {
     const LIST_KIND: Self::Kind = CSS_RULE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        if is_at_at_rule(p) {
+        // The variables of Less start with an `@`, so they are checked before the at-rules.
+        if is_at_less_declaration(p) {
+            parse_less_declaration(p)
+        } else if is_at_at_rule(p) {
             parse_at_rule(p)
+        } else if is_at_scss_declaration(p) {
+            parse_scss_declaration(p)
+        } else if is_at_less_mixin(p) {
+            parse_less_mixin(p)
         } else if is_at_qualified_rule(p) {
             parse_qualified_rule(p)
         } else {
@@ -222,7 +244,7 @@ fn is_at_declaration_important(p: &mut CssParser) -> bool {
 }
 
 #[inline]
-fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
+pub(crate) fn parse_declaration_important(p: &mut CssParser) -> ParsedSyntax {
     if !is_at_declaration_important(p) {
         return Absent;
     }
@@ -242,6 +264,9 @@ pub(crate) fn is_at_any_value(p: &mut CssParser) -> bool {
         || is_at_dashed_identifier(p)
         || is_at_ratio(p)
         || is_at_color(p)
+        || is_at_scss_identifier(p)
+        || is_at_less_identifier(p)
+        || is_at_less_parenthesized_value(p)
 }
 
 #[inline]
@@ -262,6 +287,12 @@ pub(crate) fn parse_any_value(p: &mut CssParser) -> ParsedSyntax {
         parse_regular_number(p)
     } else if is_at_color(p) {
         parse_color(p)
+    } else if is_at_scss_identifier(p) {
+        parse_scss_identifier(p)
+    } else if is_at_less_identifier(p) {
+        parse_less_identifier(p)
+    } else if is_at_less_parenthesized_value(p) {
+        parse_less_parenthesized_value(p)
     } else {
         Absent
     }
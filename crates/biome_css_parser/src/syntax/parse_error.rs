@@ -227,3 +227,15 @@ pub(crate) fn expected_component_value(p: &CssParser, range: TextRange) -> Parse
     )
     .into_diagnostic(p)
 }
+
+pub(crate) fn expected_scss_variable_modifier(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["!default", "!global"], range, p)
+}
+
+pub(crate) fn expected_tailwind_utility(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("utility", range, p)
+}
+
+pub(crate) fn expected_tailwind_path(p: &CssParser, range: TextRange) -> ParseDiagnostic {
+    expected_any(&["path", "string"], range, p)
+}
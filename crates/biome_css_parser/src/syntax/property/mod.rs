@@ -1,5 +1,6 @@
 use crate::parser::CssParser;
 use crate::syntax::parse_error::expected_component_value;
+use crate::syntax::scss::SCSS_OPERATOR_SET;
 use crate::syntax::{is_at_any_value, is_at_identifier, parse_any_value, parse_regular_identifier};
 use biome_css_syntax::CssSyntaxKind::*;
 use biome_css_syntax::{CssSyntaxKind, T};
@@ -96,6 +97,7 @@ const GENERIC_DELIMITER_SET: TokenSet<CssSyntaxKind> = token_set![T![,], T![/]];
 #[inline]
 pub(crate) fn is_at_generic_delimiter(p: &mut CssParser) -> bool {
     p.at_ts(GENERIC_DELIMITER_SET)
+        || ((p.options().scss || p.options().less) && p.at_ts(SCSS_OPERATOR_SET))
 }
 
 #[inline]
@@ -105,6 +107,6 @@ pub(crate) fn parse_generic_delimiter(p: &mut CssParser) -> ParsedSyntax {
     }
 
     let m = p.start();
-    p.bump_ts(GENERIC_DELIMITER_SET);
+    p.bump_ts(GENERIC_DELIMITER_SET.union(SCSS_OPERATOR_SET));
     Present(m.complete(p, CSS_GENERIC_DELIMITER))
 }
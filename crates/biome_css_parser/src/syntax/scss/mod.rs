@@ -0,0 +1,171 @@
+use crate::parser::CssParser;
+use crate::syntax::parse_error::{expected_identifier, expected_scss_variable_modifier};
+use crate::syntax::property::GenericComponentValueList;
+use crate::syntax::value::function::parse_any_expression;
+use crate::syntax::value::parse_error::expected_expression;
+use crate::syntax::{is_nth_at_identifier, parse_regular_identifier};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+/// The operators of SCSS that are parsed as delimiters of a component value list,
+/// in addition to the `,` and `/` of CSS. Less uses the same operators.
+///
+/// ```scss
+/// $width: $gutter * 2 + 1px;
+/// @if $theme == dark { }
+/// ```
+pub(crate) const SCSS_OPERATOR_SET: TokenSet<CssSyntaxKind> = token_set![
+    T![+],
+    T![-],
+    T![*],
+    T![%],
+    T![=],
+    T![!],
+    T![<],
+    T![>],
+    T![<=],
+    T![>=],
+    T![.]
+];
+
+/// Checks if the current position is at a variable of SCSS, such as `$primary-color`.
+///
+/// The `$` token is only produced by the lexer when the SCSS syntax is enabled.
+#[inline]
+pub(crate) fn is_at_scss_identifier(p: &mut CssParser) -> bool {
+    is_nth_at_scss_identifier(p, 0)
+}
+
+#[inline]
+pub(crate) fn is_nth_at_scss_identifier(p: &mut CssParser, n: usize) -> bool {
+    p.nth_at(n, T![$])
+}
+
+/// Parses a variable of SCSS.
+///
+/// ```scss
+/// $primary-color
+/// ```
+#[inline]
+pub(crate) fn parse_scss_identifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_identifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![$]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+
+    Present(m.complete(p, SCSS_IDENTIFIER))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_declaration(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p) && is_nth_at_identifier(p, 1) && p.nth_at(2, T![:])
+}
+
+/// Parses the declaration of a variable of SCSS, with its optional `!default` and `!global` flags.
+///
+/// The declarations are accepted at the root of the stylesheet and in the blocks.
+///
+/// ```scss
+/// $primary-color: #333 !default;
+/// ```
+#[inline]
+pub(crate) fn parse_scss_declaration(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_declaration(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+    p.bump(T![:]);
+    GenericComponentValueList.parse_list(p);
+    ScssVariableModifierList.parse_list(p);
+
+    // Like the declarations of CSS, the semicolon is optional before a closing brace.
+    if !p.at(T!['}']) && !p.at(EOF) {
+        p.expect(T![;]);
+    }
+
+    Present(m.complete(p, SCSS_DECLARATION))
+}
+
+struct ScssVariableModifierList;
+
+impl ParseNodeList for ScssVariableModifierList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = SCSS_VARIABLE_MODIFIER_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_scss_variable_modifier(p)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        !p.at(T![!])
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, token_set![T![;], T!['}']]),
+            expected_scss_variable_modifier,
+        )
+    }
+}
+
+#[inline]
+fn is_at_scss_variable_modifier(p: &mut CssParser) -> bool {
+    p.at(T![!]) && (p.nth_at(1, T![default]) || p.nth_at(1, T![global]))
+}
+
+#[inline]
+fn parse_scss_variable_modifier(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_variable_modifier(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    p.bump(T![!]);
+    p.bump_ts(token_set![T![default], T![global]]);
+
+    Present(m.complete(p, SCSS_VARIABLE_MODIFIER))
+}
+
+#[inline]
+pub(crate) fn is_at_scss_keyword_argument(p: &mut CssParser) -> bool {
+    is_at_scss_identifier(p) && is_nth_at_identifier(p, 1) && p.nth_at(2, T![:])
+}
+
+/// Parses a keyword argument of a mixin or a function, or a parameter with a default value.
+///
+/// ```scss
+/// @include button($radius: 4px);
+/// ```
+#[inline]
+pub(crate) fn parse_scss_keyword_argument(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_scss_keyword_argument(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_scss_identifier(p).ok();
+    p.bump(T![:]);
+    parse_any_expression(p).or_add_diagnostic(p, expected_expression);
+
+    Present(m.complete(p, SCSS_KEYWORD_ARGUMENT))
+}
@@ -0,0 +1,157 @@
+use crate::lexer::CssLexContext;
+use crate::parser::CssParser;
+use crate::syntax::block::parse_rule_block;
+use crate::syntax::parse_error::{
+    expected_identifier, expected_tailwind_path, expected_tailwind_utility,
+};
+use crate::syntax::value::url::parse_url_value;
+use crate::syntax::{
+    parse_custom_identifier_with_keywords, parse_declaration_important, parse_regular_identifier,
+};
+use biome_css_syntax::CssSyntaxKind::*;
+use biome_css_syntax::{CssSyntaxKind, T};
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax;
+use biome_parser::prelude::ParsedSyntax::{Absent, Present};
+use biome_parser::{token_set, Parser, TokenSet};
+
+/// Checks if the current token in the parser is the name of a directive of Tailwind CSS.
+///
+/// The `@layer` directive isn't checked here, because it's already parsed as a cascade layer.
+#[inline]
+pub(crate) fn is_at_tailwind_at_rule(p: &mut CssParser) -> bool {
+    p.options().tailwind && (p.at(T![tailwind]) || p.at(T![apply]) || p.at(T![screen]))
+}
+
+/// Parses a directive of Tailwind CSS.
+///
+/// # Examples
+///
+/// ```css
+/// @tailwind utilities;
+///
+/// .btn {
+///     @apply px-4 py-2 hover:bg-blue-700 !important;
+/// }
+///
+/// @screen md {
+///     .sidebar {
+///         display: none;
+///     }
+/// }
+/// ```
+#[inline]
+pub(crate) fn parse_tailwind_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_tailwind_at_rule(p) {
+        return Absent;
+    }
+
+    match p.cur() {
+        T![tailwind] => parse_tailwind_tailwind_at_rule(p),
+        T![apply] => parse_tailwind_apply_at_rule(p),
+        _ => parse_tailwind_screen_at_rule(p),
+    }
+}
+
+#[inline]
+fn parse_tailwind_tailwind_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    p.bump(T![tailwind]);
+    parse_regular_identifier(p).or_add_diagnostic(p, expected_identifier);
+    p.expect(T![;]);
+
+    Present(m.complete(p, TW_TAILWIND_AT_RULE))
+}
+
+#[inline]
+fn parse_tailwind_apply_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    // The utilities are lexed as a whole, so the variants and the arbitrary values,
+    // such as `hover:bg-[#0ea5e9]`, aren't split into several tokens.
+    p.bump_with_context(T![apply], CssLexContext::TailwindUtility);
+    TailwindApplyClassList.parse_list(p);
+    parse_declaration_important(p).ok();
+    p.expect(T![;]);
+
+    Present(m.complete(p, TW_APPLY_AT_RULE))
+}
+
+#[inline]
+fn parse_tailwind_screen_at_rule(p: &mut CssParser) -> ParsedSyntax {
+    let m = p.start();
+
+    p.bump_with_context(T![screen], CssLexContext::TailwindUtility);
+    parse_custom_identifier_with_keywords(p, CssLexContext::Regular, true)
+        .or_add_diagnostic(p, expected_identifier);
+    parse_rule_block(p);
+
+    Present(m.complete(p, TW_SCREEN_AT_RULE))
+}
+
+const TAILWIND_APPLY_CLASS_LIST_END_SET: TokenSet<CssSyntaxKind> =
+    token_set![T![!], T![;], T!['{'], T!['}']];
+
+struct TailwindApplyClassList;
+
+impl ParseNodeList for TailwindApplyClassList {
+    type Kind = CssSyntaxKind;
+    type Parser<'source> = CssParser<'source>;
+    const LIST_KIND: Self::Kind = TW_APPLY_CLASS_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        parse_custom_identifier_with_keywords(p, CssLexContext::TailwindUtility, true)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at_ts(TAILWIND_APPLY_CLASS_LIST_END_SET)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(CSS_BOGUS, TAILWIND_APPLY_CLASS_LIST_END_SET),
+            expected_tailwind_utility,
+        )
+    }
+}
+
+/// Checks if the current position is at a function of Tailwind CSS, `theme()` or `screen()`.
+#[inline]
+pub(crate) fn is_at_tailwind_function(p: &mut CssParser) -> bool {
+    p.options().tailwind && (p.at(T![theme]) || p.at(T![screen])) && p.nth_at(1, T!['('])
+}
+
+/// Parses a function of Tailwind CSS.
+///
+/// The argument of the functions is a path in the configuration of Tailwind CSS,
+/// so it's lexed like the value of a `url()`.
+///
+/// ```css
+/// .content-area {
+///     height: calc(100vh - theme(spacing.12));
+/// }
+///
+/// @media screen(sm) {}
+/// ```
+#[inline]
+pub(crate) fn parse_tailwind_function(p: &mut CssParser) -> ParsedSyntax {
+    if !is_at_tailwind_function(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    parse_regular_identifier(p).ok();
+    p.bump_with_context(T!['('], CssLexContext::UrlRawValue);
+    parse_url_value(p).or_add_diagnostic(p, expected_tailwind_path);
+    p.expect(T![')']);
+
+    Present(m.complete(p, TW_FUNCTION))
+}
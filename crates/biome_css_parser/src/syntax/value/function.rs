@@ -1,7 +1,10 @@
 use super::parse_error::expected_expression;
 use super::url::{is_at_url_function, parse_url_function};
 use crate::parser::CssParser;
+use crate::syntax::less::{is_at_less_keyword_argument, parse_less_keyword_argument};
 use crate::syntax::parse_error::expected_declaration_item;
+use crate::syntax::scss::{is_at_scss_keyword_argument, parse_scss_keyword_argument};
+use crate::syntax::tailwind::{is_at_tailwind_function, parse_tailwind_function};
 use crate::syntax::{
     is_at_any_value, is_nth_at_identifier, parse_regular_identifier, CssComponentValueList,
 };
@@ -19,7 +22,7 @@ use biome_parser::{token_set, Parser, TokenSet};
 /// It's used to quickly determine if the parser is positioned at a relevant function.
 #[inline]
 pub(crate) fn is_at_any_function(p: &mut CssParser) -> bool {
-    is_at_url_function(p) || is_at_function(p)
+    is_at_tailwind_function(p) || is_at_url_function(p) || is_at_function(p)
 }
 
 /// Parses any recognized CSS function at the current position in the `CssParser`.
@@ -33,7 +36,9 @@ pub(crate) fn parse_any_function(p: &mut CssParser) -> ParsedSyntax {
         return Absent;
     }
 
-    if is_at_url_function(p) {
+    if is_at_tailwind_function(p) {
+        parse_tailwind_function(p)
+    } else if is_at_url_function(p) {
         parse_url_function(p)
     } else {
         parse_function(p)
@@ -187,7 +192,11 @@ pub(crate) fn parse_any_expression(p: &mut CssParser) -> ParsedSyntax {
         return Absent;
     }
 
-    let param = if is_at_parenthesized(p) {
+    let param = if is_at_scss_keyword_argument(p) {
+        parse_scss_keyword_argument(p)
+    } else if is_at_less_keyword_argument(p) {
+        parse_less_keyword_argument(p)
+    } else if is_at_parenthesized(p) {
         parse_parenthesized_expression(p)
     } else {
         parse_list_of_component_values_expression(p)
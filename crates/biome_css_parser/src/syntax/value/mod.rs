@@ -1,4 +1,4 @@
 pub(crate) mod dimension;
 pub(crate) mod function;
-mod parse_error;
+pub(crate) mod parse_error;
 pub(crate) mod url;
@@ -36,12 +36,28 @@ pub fn run(test_case: &str, _snapshot_name: &str, test_directory: &str, outcome_
     let content = fs::read_to_string(test_case_path)
         .expect("Expected test path to be a readable file in UTF8 encoding");
 
-    let parse_config = CssParserOptions::default().allow_wrong_line_comments();
+    let language = test_case_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or("css");
+    let mut parse_config = CssParserOptions::default().allow_wrong_line_comments();
+    match language {
+        "scss" => parse_config = parse_config.scss(),
+        "less" => parse_config = parse_config.less(),
+        _ => {}
+    }
+    // The directives of Tailwind CSS are written in regular CSS files.
+    if test_case_path
+        .components()
+        .any(|component| component.as_os_str() == "tailwind")
+    {
+        parse_config = parse_config.tailwind();
+    }
     let parsed = parse_css(&content, parse_config);
     let formatted_ast = format!("{:#?}", parsed.tree());
 
     let mut snapshot = String::new();
-    writeln!(snapshot, "\n## Input\n\n```css\n{content}\n```\n\n").unwrap();
+    writeln!(snapshot, "\n## Input\n\n```{language}\n{content}\n```\n\n").unwrap();
 
     writeln!(
         snapshot,
@@ -6,15 +6,13 @@ use std::{ffi::OsStr, path::Path};
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
 pub struct CssFileSource {
-    // Unused until we potentially support postcss/less/sass
-    #[allow(unused)]
     variant: CssVariant,
 }
 
 /// The style of CSS contained in the file.
 ///
-/// Currently, Biome only supports plain CSS, and aims to be compatible with
-/// the latest Recommendation level standards.
+/// Biome aims to be compatible with the latest Recommendation level standards of plain CSS,
+/// and it partially supports the syntax of SCSS and Less.
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
@@ -22,6 +20,10 @@ pub struct CssFileSource {
 enum CssVariant {
     #[default]
     Standard,
+    /// The SCSS syntax of Sass, used by the files with the extension `.scss`.
+    Scss,
+    /// The syntax of Less, used by the files with the extension `.less`.
+    Less,
 }
 
 impl CssFileSource {
@@ -31,6 +33,26 @@ impl CssFileSource {
         }
     }
 
+    pub fn scss() -> Self {
+        Self {
+            variant: CssVariant::Scss,
+        }
+    }
+
+    pub fn less() -> Self {
+        Self {
+            variant: CssVariant::Less,
+        }
+    }
+
+    pub const fn is_scss(&self) -> bool {
+        matches!(self.variant, CssVariant::Scss)
+    }
+
+    pub const fn is_less(&self) -> bool {
+        matches!(self.variant, CssVariant::Less)
+    }
+
     /// Try to return the CSS file source corresponding to this file name from well-known files
     pub fn try_from_well_known(file_name: &str) -> Result<Self, FileSourceError> {
         // TODO: to be implemented
@@ -41,6 +63,8 @@ impl CssFileSource {
     pub fn try_from_extension(extension: &str) -> Result<Self, FileSourceError> {
         match extension {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
+            "less" => Ok(Self::less()),
             _ => Err(FileSourceError::UnknownExtension(
                 Default::default(),
                 extension.into(),
@@ -57,6 +81,8 @@ impl CssFileSource {
     pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
         match language_id {
             "css" => Ok(Self::css()),
+            "scss" => Ok(Self::scss()),
+            "less" => Ok(Self::less()),
             _ => Err(FileSourceError::UnknownLanguageId(language_id.into())),
         }
     }
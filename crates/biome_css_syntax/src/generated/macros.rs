@@ -593,6 +593,74 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssUrlValueRaw::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::LESS_DECLARATION => {
+                    let $pattern = unsafe { $crate::LessDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::LessIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_KEYWORD_ARGUMENT => {
+                    let $pattern = unsafe { $crate::LessKeywordArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_ARGUMENTS => {
+                    let $pattern = unsafe { $crate::LessMixinArguments::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_CALL => {
+                    let $pattern = unsafe { $crate::LessMixinCall::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_DEFINITION => {
+                    let $pattern = unsafe { $crate::LessMixinDefinition::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_MIXIN_GUARD => {
+                    let $pattern = unsafe { $crate::LessMixinGuard::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::LESS_PARENTHESIZED_VALUE => {
+                    let $pattern = unsafe { $crate::LessParenthesizedValue::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_AT_RULE => {
+                    let $pattern = unsafe { $crate::ScssAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_DECLARATION => {
+                    let $pattern = unsafe { $crate::ScssDeclaration::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_IDENTIFIER => {
+                    let $pattern = unsafe { $crate::ScssIdentifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_KEYWORD_ARGUMENT => {
+                    let $pattern = unsafe { $crate::ScssKeywordArgument::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER => {
+                    let $pattern = unsafe { $crate::ScssVariableModifier::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::TW_APPLY_AT_RULE => {
+                    let $pattern = unsafe { $crate::TwApplyAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::TW_FUNCTION => {
+                    let $pattern = unsafe { $crate::TwFunction::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::TW_SCREEN_AT_RULE => {
+                    let $pattern = unsafe { $crate::TwScreenAtRule::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::TW_TAILWIND_AT_RULE => {
+                    let $pattern = unsafe { $crate::TwTailwindAtRule::new_unchecked(node) };
+                    $body
+                }
                 $crate::CssSyntaxKind::CSS_BOGUS => {
                     let $pattern = unsafe { $crate::CssBogus::new_unchecked(node) };
                     $body
@@ -787,6 +855,14 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::CssUrlModifierList::new_unchecked(node) };
                     $body
                 }
+                $crate::CssSyntaxKind::SCSS_VARIABLE_MODIFIER_LIST => {
+                    let $pattern = unsafe { $crate::ScssVariableModifierList::new_unchecked(node) };
+                    $body
+                }
+                $crate::CssSyntaxKind::TW_APPLY_CLASS_LIST => {
+                    let $pattern = unsafe { $crate::TwApplyClassList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
@@ -5889,1958 +5889,3553 @@ pub struct CssUrlValueRawFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LessDeclaration {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LessDeclaration {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> LessDeclarationFields {
+        LessDeclarationFields {
+            name: self.name(),
+            colon_token: self.colon_token(),
+            value: self.value(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn name(&self) -> SyntaxResult<LessIdentifier> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> CssGenericComponentValueList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for LessDeclaration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LessDeclarationFields {
+    pub name: SyntaxResult<LessIdentifier>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub value: CssGenericComponentValueList,
+    pub semicolon_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LessIdentifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LessIdentifier {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> LessIdentifierFields {
+        LessIdentifierFields {
+            at_token: self.at_token(),
+            name: self.name(),
+        }
+    }
+    pub fn at_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for LessIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LessIdentifierFields {
+    pub at_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LessKeywordArgument {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LessKeywordArgument {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> LessKeywordArgumentFields {
+        LessKeywordArgumentFields {
+            name: self.name(),
+            colon_token: self.colon_token(),
+            value: self.value(),
+        }
+    }
+    pub fn name(&self) -> SyntaxResult<LessIdentifier> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> SyntaxResult<AnyCssExpression> {
+        support::required_node(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for LessKeywordArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LessKeywordArgumentFields {
+    pub name: SyntaxResult<LessIdentifier>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<AnyCssExpression>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LessMixinArguments {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LessMixinArguments {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> LessMixinArgumentsFields {
+        LessMixinArgumentsFields {
+            l_paren_token: self.l_paren_token(),
+            items: self.items(),
+            r_paren_token: self.r_paren_token(),
+        }
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn items(&self) -> CssParameterList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for LessMixinArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LessMixinArgumentsFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub items: CssParameterList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LessMixinCall {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LessMixinCall {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> LessMixinCallFields {
+        LessMixinCallFields {
+            name: self.name(),
+            arguments: self.arguments(),
+            important: self.important(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn name(&self) -> SyntaxResult<AnyLessMixinName> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn arguments(&self) -> Option<LessMixinArguments> {
+        support::node(&self.syntax, 1usize)
+    }
+    pub fn important(&self) -> Option<CssDeclarationImportant> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for LessMixinCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LessMixinCallFields {
+    pub name: SyntaxResult<AnyLessMixinName>,
+    pub arguments: Option<LessMixinArguments>,
+    pub important: Option<CssDeclarationImportant>,
+    pub semicolon_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LessMixinDefinition {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LessMixinDefinition {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> LessMixinDefinitionFields {
+        LessMixinDefinitionFields {
+            name: self.name(),
+            arguments: self.arguments(),
+            guard: self.guard(),
+            block: self.block(),
+        }
+    }
+    pub fn name(&self) -> SyntaxResult<AnyLessMixinName> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn arguments(&self) -> SyntaxResult<LessMixinArguments> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn guard(&self) -> Option<LessMixinGuard> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn block(&self) -> SyntaxResult<AnyCssDeclarationOrRuleBlock> {
+        support::required_node(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for LessMixinDefinition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LessMixinDefinitionFields {
+    pub name: SyntaxResult<AnyLessMixinName>,
+    pub arguments: SyntaxResult<LessMixinArguments>,
+    pub guard: Option<LessMixinGuard>,
+    pub block: SyntaxResult<AnyCssDeclarationOrRuleBlock>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LessMixinGuard {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LessMixinGuard {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> LessMixinGuardFields {
+        LessMixinGuardFields {
+            when_token: self.when_token(),
+            conditions: self.conditions(),
+        }
+    }
+    pub fn when_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn conditions(&self) -> CssGenericComponentValueList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for LessMixinGuard {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LessMixinGuardFields {
+    pub when_token: SyntaxResult<SyntaxToken>,
+    pub conditions: CssGenericComponentValueList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct LessParenthesizedValue {
+    pub(crate) syntax: SyntaxNode,
+}
+impl LessParenthesizedValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> LessParenthesizedValueFields {
+        LessParenthesizedValueFields {
+            l_paren_token: self.l_paren_token(),
+            value: self.value(),
+            r_paren_token: self.r_paren_token(),
+        }
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn value(&self) -> CssGenericComponentValueList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for LessParenthesizedValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct LessParenthesizedValueFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub value: CssGenericComponentValueList,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssAtRuleFields {
+        ScssAtRuleFields {
+            name: self.name(),
+            prelude: self.prelude(),
+            block: self.block(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn prelude(&self) -> CssGenericComponentValueList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn block(&self) -> Option<AnyCssDeclarationOrRuleBlock> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for ScssAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ScssAtRuleFields {
+    pub name: SyntaxResult<CssIdentifier>,
+    pub prelude: CssGenericComponentValueList,
+    pub block: Option<AnyCssDeclarationOrRuleBlock>,
+    pub semicolon_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssDeclaration {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssDeclaration {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssDeclarationFields {
+        ScssDeclarationFields {
+            name: self.name(),
+            colon_token: self.colon_token(),
+            value: self.value(),
+            modifiers: self.modifiers(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn name(&self) -> SyntaxResult<ScssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> CssGenericComponentValueList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn modifiers(&self) -> ScssVariableModifierList {
+        support::list(&self.syntax, 3usize)
+    }
+    pub fn semicolon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 4usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for ScssDeclaration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ScssDeclarationFields {
+    pub name: SyntaxResult<ScssIdentifier>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub value: CssGenericComponentValueList,
+    pub modifiers: ScssVariableModifierList,
+    pub semicolon_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssIdentifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssIdentifier {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssIdentifierFields {
+        ScssIdentifierFields {
+            dollar_token: self.dollar_token(),
+            name: self.name(),
+        }
+    }
+    pub fn dollar_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for ScssIdentifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ScssIdentifierFields {
+    pub dollar_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssIdentifier>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssKeywordArgument {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssKeywordArgument {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssKeywordArgumentFields {
+        ScssKeywordArgumentFields {
+            name: self.name(),
+            colon_token: self.colon_token(),
+            value: self.value(),
+        }
+    }
+    pub fn name(&self) -> SyntaxResult<ScssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> SyntaxResult<AnyCssExpression> {
+        support::required_node(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for ScssKeywordArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ScssKeywordArgumentFields {
+    pub name: SyntaxResult<ScssIdentifier>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<AnyCssExpression>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ScssVariableModifier {
+    pub(crate) syntax: SyntaxNode,
+}
+impl ScssVariableModifier {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> ScssVariableModifierFields {
+        ScssVariableModifierFields {
+            excl_token: self.excl_token(),
+            value: self.value(),
+        }
+    }
+    pub fn excl_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn value(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for ScssVariableModifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ScssVariableModifierFields {
+    pub excl_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TwApplyAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TwApplyAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TwApplyAtRuleFields {
+        TwApplyAtRuleFields {
+            apply_token: self.apply_token(),
+            classes: self.classes(),
+            important: self.important(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn apply_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn classes(&self) -> TwApplyClassList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn important(&self) -> Option<CssDeclarationImportant> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn semicolon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for TwApplyAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TwApplyAtRuleFields {
+    pub apply_token: SyntaxResult<SyntaxToken>,
+    pub classes: TwApplyClassList,
+    pub important: Option<CssDeclarationImportant>,
+    pub semicolon_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TwFunction {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TwFunction {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TwFunctionFields {
+        TwFunctionFields {
+            name: self.name(),
+            l_paren_token: self.l_paren_token(),
+            value: self.value(),
+            r_paren_token: self.r_paren_token(),
+        }
+    }
+    pub fn name(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> SyntaxResult<AnyCssUrlValue> {
+        support::required_node(&self.syntax, 2usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for TwFunction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TwFunctionFields {
+    pub name: SyntaxResult<CssIdentifier>,
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<AnyCssUrlValue>,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TwScreenAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TwScreenAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TwScreenAtRuleFields {
+        TwScreenAtRuleFields {
+            screen_token: self.screen_token(),
+            name: self.name(),
+            block: self.block(),
+        }
+    }
+    pub fn screen_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn name(&self) -> SyntaxResult<CssCustomIdentifier> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn block(&self) -> SyntaxResult<AnyCssRuleBlock> {
+        support::required_node(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for TwScreenAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TwScreenAtRuleFields {
+    pub screen_token: SyntaxResult<SyntaxToken>,
+    pub name: SyntaxResult<CssCustomIdentifier>,
+    pub block: SyntaxResult<AnyCssRuleBlock>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct TwTailwindAtRule {
+    pub(crate) syntax: SyntaxNode,
+}
+impl TwTailwindAtRule {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> TwTailwindAtRuleFields {
+        TwTailwindAtRuleFields {
+            tailwind_token: self.tailwind_token(),
+            layer: self.layer(),
+            semicolon_token: self.semicolon_token(),
+        }
+    }
+    pub fn tailwind_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn layer(&self) -> SyntaxResult<CssIdentifier> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn semicolon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for TwTailwindAtRule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TwTailwindAtRuleFields {
+    pub tailwind_token: SyntaxResult<SyntaxToken>,
+    pub layer: SyntaxResult<CssIdentifier>,
+    pub semicolon_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssAtRule {
+    CssBogusAtRule(CssBogusAtRule),
+    CssCharsetAtRule(CssCharsetAtRule),
+    CssColorProfileAtRule(CssColorProfileAtRule),
+    CssContainerAtRule(CssContainerAtRule),
+    CssCounterStyleAtRule(CssCounterStyleAtRule),
+    CssDocumentAtRule(CssDocumentAtRule),
+    CssFontFaceAtRule(CssFontFaceAtRule),
+    CssFontFeatureValuesAtRule(CssFontFeatureValuesAtRule),
+    CssFontPaletteValuesAtRule(CssFontPaletteValuesAtRule),
+    CssImportAtRule(CssImportAtRule),
+    CssKeyframesAtRule(CssKeyframesAtRule),
+    CssLayerAtRule(CssLayerAtRule),
+    CssMediaAtRule(CssMediaAtRule),
+    CssNamespaceAtRule(CssNamespaceAtRule),
+    CssPageAtRule(CssPageAtRule),
+    CssPropertyAtRule(CssPropertyAtRule),
+    CssScopeAtRule(CssScopeAtRule),
+    CssStartingStyleAtRule(CssStartingStyleAtRule),
+    CssSupportsAtRule(CssSupportsAtRule),
+    ScssAtRule(ScssAtRule),
+    TwApplyAtRule(TwApplyAtRule),
+    TwScreenAtRule(TwScreenAtRule),
+    TwTailwindAtRule(TwTailwindAtRule),
+}
+impl AnyCssAtRule {
+    pub fn as_css_bogus_at_rule(&self) -> Option<&CssBogusAtRule> {
+        match &self {
+            AnyCssAtRule::CssBogusAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_charset_at_rule(&self) -> Option<&CssCharsetAtRule> {
+        match &self {
+            AnyCssAtRule::CssCharsetAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_color_profile_at_rule(&self) -> Option<&CssColorProfileAtRule> {
+        match &self {
+            AnyCssAtRule::CssColorProfileAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_at_rule(&self) -> Option<&CssContainerAtRule> {
+        match &self {
+            AnyCssAtRule::CssContainerAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_counter_style_at_rule(&self) -> Option<&CssCounterStyleAtRule> {
+        match &self {
+            AnyCssAtRule::CssCounterStyleAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_document_at_rule(&self) -> Option<&CssDocumentAtRule> {
+        match &self {
+            AnyCssAtRule::CssDocumentAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_font_face_at_rule(&self) -> Option<&CssFontFaceAtRule> {
+        match &self {
+            AnyCssAtRule::CssFontFaceAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_font_feature_values_at_rule(&self) -> Option<&CssFontFeatureValuesAtRule> {
+        match &self {
+            AnyCssAtRule::CssFontFeatureValuesAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_font_palette_values_at_rule(&self) -> Option<&CssFontPaletteValuesAtRule> {
+        match &self {
+            AnyCssAtRule::CssFontPaletteValuesAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_import_at_rule(&self) -> Option<&CssImportAtRule> {
+        match &self {
+            AnyCssAtRule::CssImportAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_at_rule(&self) -> Option<&CssKeyframesAtRule> {
+        match &self {
+            AnyCssAtRule::CssKeyframesAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_layer_at_rule(&self) -> Option<&CssLayerAtRule> {
+        match &self {
+            AnyCssAtRule::CssLayerAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_at_rule(&self) -> Option<&CssMediaAtRule> {
+        match &self {
+            AnyCssAtRule::CssMediaAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_namespace_at_rule(&self) -> Option<&CssNamespaceAtRule> {
+        match &self {
+            AnyCssAtRule::CssNamespaceAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_page_at_rule(&self) -> Option<&CssPageAtRule> {
+        match &self {
+            AnyCssAtRule::CssPageAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_property_at_rule(&self) -> Option<&CssPropertyAtRule> {
+        match &self {
+            AnyCssAtRule::CssPropertyAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_scope_at_rule(&self) -> Option<&CssScopeAtRule> {
+        match &self {
+            AnyCssAtRule::CssScopeAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_starting_style_at_rule(&self) -> Option<&CssStartingStyleAtRule> {
+        match &self {
+            AnyCssAtRule::CssStartingStyleAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_supports_at_rule(&self) -> Option<&CssSupportsAtRule> {
+        match &self {
+            AnyCssAtRule::CssSupportsAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_at_rule(&self) -> Option<&ScssAtRule> {
+        match &self {
+            AnyCssAtRule::ScssAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_tw_apply_at_rule(&self) -> Option<&TwApplyAtRule> {
+        match &self {
+            AnyCssAtRule::TwApplyAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_tw_screen_at_rule(&self) -> Option<&TwScreenAtRule> {
+        match &self {
+            AnyCssAtRule::TwScreenAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_tw_tailwind_at_rule(&self) -> Option<&TwTailwindAtRule> {
+        match &self {
+            AnyCssAtRule::TwTailwindAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssAttributeMatcherValue {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
+}
+impl AnyCssAttributeMatcherValue {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssAttributeMatcherValue::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssAttributeMatcherValue::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssCompoundSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssCompoundSelector(CssCompoundSelector),
+}
+impl AnyCssCompoundSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+        match &self {
+            AnyCssCompoundSelector::CssBogusSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_compound_selector(&self) -> Option<&CssCompoundSelector> {
+        match &self {
+            AnyCssCompoundSelector::CssCompoundSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssContainerAndCombinableQuery {
+    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
+    CssContainerAndQuery(CssContainerAndQuery),
+}
+impl AnyCssContainerAndCombinableQuery {
+    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
+        match &self {
+            AnyCssContainerAndCombinableQuery::AnyCssContainerQueryInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_and_query(&self) -> Option<&CssContainerAndQuery> {
+        match &self {
+            AnyCssContainerAndCombinableQuery::CssContainerAndQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssContainerOrCombinableQuery {
+    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
+    CssContainerOrQuery(CssContainerOrQuery),
+}
+impl AnyCssContainerOrCombinableQuery {
+    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
+        match &self {
+            AnyCssContainerOrCombinableQuery::AnyCssContainerQueryInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_or_query(&self) -> Option<&CssContainerOrQuery> {
+        match &self {
+            AnyCssContainerOrCombinableQuery::CssContainerOrQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssContainerQuery {
+    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
+    CssContainerAndQuery(CssContainerAndQuery),
+    CssContainerNotQuery(CssContainerNotQuery),
+    CssContainerOrQuery(CssContainerOrQuery),
+}
+impl AnyCssContainerQuery {
+    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
+        match &self {
+            AnyCssContainerQuery::AnyCssContainerQueryInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_and_query(&self) -> Option<&CssContainerAndQuery> {
+        match &self {
+            AnyCssContainerQuery::CssContainerAndQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_not_query(&self) -> Option<&CssContainerNotQuery> {
+        match &self {
+            AnyCssContainerQuery::CssContainerNotQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_or_query(&self) -> Option<&CssContainerOrQuery> {
+        match &self {
+            AnyCssContainerQuery::CssContainerOrQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssContainerQueryInParens {
+    CssContainerQueryInParens(CssContainerQueryInParens),
+    CssContainerSizeFeatureInParens(CssContainerSizeFeatureInParens),
+    CssContainerStyleQueryInParens(CssContainerStyleQueryInParens),
+}
+impl AnyCssContainerQueryInParens {
+    pub fn as_css_container_query_in_parens(&self) -> Option<&CssContainerQueryInParens> {
+        match &self {
+            AnyCssContainerQueryInParens::CssContainerQueryInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_size_feature_in_parens(
+        &self,
+    ) -> Option<&CssContainerSizeFeatureInParens> {
+        match &self {
+            AnyCssContainerQueryInParens::CssContainerSizeFeatureInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_style_query_in_parens(
+        &self,
+    ) -> Option<&CssContainerStyleQueryInParens> {
+        match &self {
+            AnyCssContainerQueryInParens::CssContainerStyleQueryInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssContainerStyleAndCombinableQuery {
+    CssContainerStyleAndQuery(CssContainerStyleAndQuery),
+    CssContainerStyleInParens(CssContainerStyleInParens),
+}
+impl AnyCssContainerStyleAndCombinableQuery {
+    pub fn as_css_container_style_and_query(&self) -> Option<&CssContainerStyleAndQuery> {
+        match &self {
+            AnyCssContainerStyleAndCombinableQuery::CssContainerStyleAndQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
+        match &self {
+            AnyCssContainerStyleAndCombinableQuery::CssContainerStyleInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssContainerStyleInParens {
+    AnyCssContainerStyleQuery(AnyCssContainerStyleQuery),
+    CssDeclaration(CssDeclaration),
+}
+impl AnyCssContainerStyleInParens {
+    pub fn as_any_css_container_style_query(&self) -> Option<&AnyCssContainerStyleQuery> {
+        match &self {
+            AnyCssContainerStyleInParens::AnyCssContainerStyleQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
+        match &self {
+            AnyCssContainerStyleInParens::CssDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssContainerStyleOrCombinableQuery {
+    CssContainerStyleInParens(CssContainerStyleInParens),
+    CssContainerStyleOrQuery(CssContainerStyleOrQuery),
+}
+impl AnyCssContainerStyleOrCombinableQuery {
+    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
+        match &self {
+            AnyCssContainerStyleOrCombinableQuery::CssContainerStyleInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_style_or_query(&self) -> Option<&CssContainerStyleOrQuery> {
+        match &self {
+            AnyCssContainerStyleOrCombinableQuery::CssContainerStyleOrQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssContainerStyleQuery {
+    CssContainerStyleAndQuery(CssContainerStyleAndQuery),
+    CssContainerStyleInParens(CssContainerStyleInParens),
+    CssContainerStyleNotQuery(CssContainerStyleNotQuery),
+    CssContainerStyleOrQuery(CssContainerStyleOrQuery),
+    CssDeclaration(CssDeclaration),
+}
+impl AnyCssContainerStyleQuery {
+    pub fn as_css_container_style_and_query(&self) -> Option<&CssContainerStyleAndQuery> {
+        match &self {
+            AnyCssContainerStyleQuery::CssContainerStyleAndQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
+        match &self {
+            AnyCssContainerStyleQuery::CssContainerStyleInParens(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_style_not_query(&self) -> Option<&CssContainerStyleNotQuery> {
+        match &self {
+            AnyCssContainerStyleQuery::CssContainerStyleNotQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_container_style_or_query(&self) -> Option<&CssContainerStyleOrQuery> {
+        match &self {
+            AnyCssContainerStyleQuery::CssContainerStyleOrQuery(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
+        match &self {
+            AnyCssContainerStyleQuery::CssDeclaration(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssCustomIdentifier {
+    CssBogusCustomIdentifier(CssBogusCustomIdentifier),
+    CssCustomIdentifier(CssCustomIdentifier),
+}
+impl AnyCssCustomIdentifier {
+    pub fn as_css_bogus_custom_identifier(&self) -> Option<&CssBogusCustomIdentifier> {
+        match &self {
+            AnyCssCustomIdentifier::CssBogusCustomIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
+        match &self {
+            AnyCssCustomIdentifier::CssCustomIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDeclarationBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssDeclarationBlock(CssDeclarationBlock),
+}
+impl AnyCssDeclarationBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+        match &self {
+            AnyCssDeclarationBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration_block(&self) -> Option<&CssDeclarationBlock> {
+        match &self {
+            AnyCssDeclarationBlock::CssDeclarationBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDeclarationName {
+    CssDashedIdentifier(CssDashedIdentifier),
+    CssIdentifier(CssIdentifier),
+}
+impl AnyCssDeclarationName {
+    pub fn as_css_dashed_identifier(&self) -> Option<&CssDashedIdentifier> {
+        match &self {
+            AnyCssDeclarationName::CssDashedIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssDeclarationName::CssIdentifier(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDeclarationOrAtRule {
+    CssAtRule(CssAtRule),
+    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
+}
+impl AnyCssDeclarationOrAtRule {
+    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
+        match &self {
+            AnyCssDeclarationOrAtRule::CssAtRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
+        match &self {
+            AnyCssDeclarationOrAtRule::CssDeclarationWithSemicolon(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDeclarationOrAtRuleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssDeclarationOrAtRuleBlock(CssDeclarationOrAtRuleBlock),
+}
+impl AnyCssDeclarationOrAtRuleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+        match &self {
+            AnyCssDeclarationOrAtRuleBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration_or_at_rule_block(&self) -> Option<&CssDeclarationOrAtRuleBlock> {
+        match &self {
+            AnyCssDeclarationOrAtRuleBlock::CssDeclarationOrAtRuleBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDeclarationOrRule {
+    AnyCssRule(AnyCssRule),
+    CssBogus(CssBogus),
+    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
+}
+impl AnyCssDeclarationOrRule {
+    pub fn as_any_css_rule(&self) -> Option<&AnyCssRule> {
+        match &self {
+            AnyCssDeclarationOrRule::AnyCssRule(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_bogus(&self) -> Option<&CssBogus> {
+        match &self {
+            AnyCssDeclarationOrRule::CssBogus(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
+        match &self {
+            AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDeclarationOrRuleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssDeclarationOrRuleBlock(CssDeclarationOrRuleBlock),
+}
+impl AnyCssDeclarationOrRuleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+        match &self {
+            AnyCssDeclarationOrRuleBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_declaration_or_rule_block(&self) -> Option<&CssDeclarationOrRuleBlock> {
+        match &self {
+            AnyCssDeclarationOrRuleBlock::CssDeclarationOrRuleBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDimension {
+    CssPercentage(CssPercentage),
+    CssRegularDimension(CssRegularDimension),
+    CssUnknownDimension(CssUnknownDimension),
+}
+impl AnyCssDimension {
+    pub fn as_css_percentage(&self) -> Option<&CssPercentage> {
+        match &self {
+            AnyCssDimension::CssPercentage(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_regular_dimension(&self) -> Option<&CssRegularDimension> {
+        match &self {
+            AnyCssDimension::CssRegularDimension(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_unknown_dimension(&self) -> Option<&CssUnknownDimension> {
+        match &self {
+            AnyCssDimension::CssUnknownDimension(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssDocumentMatcher {
+    CssBogusDocumentMatcher(CssBogusDocumentMatcher),
+    CssDocumentCustomMatcher(CssDocumentCustomMatcher),
+    CssUrlFunction(CssUrlFunction),
+}
+impl AnyCssDocumentMatcher {
+    pub fn as_css_bogus_document_matcher(&self) -> Option<&CssBogusDocumentMatcher> {
+        match &self {
+            AnyCssDocumentMatcher::CssBogusDocumentMatcher(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_document_custom_matcher(&self) -> Option<&CssDocumentCustomMatcher> {
+        match &self {
+            AnyCssDocumentMatcher::CssDocumentCustomMatcher(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
+        match &self {
+            AnyCssDocumentMatcher::CssUrlFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssExpression {
+    CssBinaryExpression(CssBinaryExpression),
+    CssListOfComponentValuesExpression(CssListOfComponentValuesExpression),
+    CssParenthesizedExpression(CssParenthesizedExpression),
+    LessKeywordArgument(LessKeywordArgument),
+    ScssKeywordArgument(ScssKeywordArgument),
+}
+impl AnyCssExpression {
+    pub fn as_css_binary_expression(&self) -> Option<&CssBinaryExpression> {
+        match &self {
+            AnyCssExpression::CssBinaryExpression(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_list_of_component_values_expression(
+        &self,
+    ) -> Option<&CssListOfComponentValuesExpression> {
+        match &self {
+            AnyCssExpression::CssListOfComponentValuesExpression(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_parenthesized_expression(&self) -> Option<&CssParenthesizedExpression> {
+        match &self {
+            AnyCssExpression::CssParenthesizedExpression(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_less_keyword_argument(&self) -> Option<&LessKeywordArgument> {
+        match &self {
+            AnyCssExpression::LessKeywordArgument(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_scss_keyword_argument(&self) -> Option<&ScssKeywordArgument> {
+        match &self {
+            AnyCssExpression::ScssKeywordArgument(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssFontFamilyName {
+    CssBogusFontFamilyName(CssBogusFontFamilyName),
+    CssFontFamilyName(CssFontFamilyName),
+    CssString(CssString),
+}
+impl AnyCssFontFamilyName {
+    pub fn as_css_bogus_font_family_name(&self) -> Option<&CssBogusFontFamilyName> {
+        match &self {
+            AnyCssFontFamilyName::CssBogusFontFamilyName(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_font_family_name(&self) -> Option<&CssFontFamilyName> {
+        match &self {
+            AnyCssFontFamilyName::CssFontFamilyName(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_string(&self) -> Option<&CssString> {
+        match &self {
+            AnyCssFontFamilyName::CssString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssAtRule {
-    CssBogusAtRule(CssBogusAtRule),
-    CssCharsetAtRule(CssCharsetAtRule),
-    CssColorProfileAtRule(CssColorProfileAtRule),
-    CssContainerAtRule(CssContainerAtRule),
-    CssCounterStyleAtRule(CssCounterStyleAtRule),
-    CssDocumentAtRule(CssDocumentAtRule),
-    CssFontFaceAtRule(CssFontFaceAtRule),
-    CssFontFeatureValuesAtRule(CssFontFeatureValuesAtRule),
-    CssFontPaletteValuesAtRule(CssFontPaletteValuesAtRule),
-    CssImportAtRule(CssImportAtRule),
-    CssKeyframesAtRule(CssKeyframesAtRule),
-    CssLayerAtRule(CssLayerAtRule),
-    CssMediaAtRule(CssMediaAtRule),
-    CssNamespaceAtRule(CssNamespaceAtRule),
-    CssPageAtRule(CssPageAtRule),
-    CssPropertyAtRule(CssPropertyAtRule),
-    CssScopeAtRule(CssScopeAtRule),
-    CssStartingStyleAtRule(CssStartingStyleAtRule),
-    CssSupportsAtRule(CssSupportsAtRule),
+pub enum AnyCssFontFeatureValuesBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssFontFeatureValuesBlock(CssFontFeatureValuesBlock),
 }
-impl AnyCssAtRule {
-    pub fn as_css_bogus_at_rule(&self) -> Option<&CssBogusAtRule> {
+impl AnyCssFontFeatureValuesBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssAtRule::CssBogusAtRule(item) => Some(item),
+            AnyCssFontFeatureValuesBlock::CssBogusBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_charset_at_rule(&self) -> Option<&CssCharsetAtRule> {
+    pub fn as_css_font_feature_values_block(&self) -> Option<&CssFontFeatureValuesBlock> {
         match &self {
-            AnyCssAtRule::CssCharsetAtRule(item) => Some(item),
+            AnyCssFontFeatureValuesBlock::CssFontFeatureValuesBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_color_profile_at_rule(&self) -> Option<&CssColorProfileAtRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssFontFeatureValuesItem {
+    CssBogusFontFeatureValuesItem(CssBogusFontFeatureValuesItem),
+    CssFontFeatureValuesItem(CssFontFeatureValuesItem),
+}
+impl AnyCssFontFeatureValuesItem {
+    pub fn as_css_bogus_font_feature_values_item(&self) -> Option<&CssBogusFontFeatureValuesItem> {
         match &self {
-            AnyCssAtRule::CssColorProfileAtRule(item) => Some(item),
+            AnyCssFontFeatureValuesItem::CssBogusFontFeatureValuesItem(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_at_rule(&self) -> Option<&CssContainerAtRule> {
+    pub fn as_css_font_feature_values_item(&self) -> Option<&CssFontFeatureValuesItem> {
         match &self {
-            AnyCssAtRule::CssContainerAtRule(item) => Some(item),
+            AnyCssFontFeatureValuesItem::CssFontFeatureValuesItem(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_counter_style_at_rule(&self) -> Option<&CssCounterStyleAtRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssFunction {
+    CssFunction(CssFunction),
+    CssUrlFunction(CssUrlFunction),
+    TwFunction(TwFunction),
+}
+impl AnyCssFunction {
+    pub fn as_css_function(&self) -> Option<&CssFunction> {
         match &self {
-            AnyCssAtRule::CssCounterStyleAtRule(item) => Some(item),
+            AnyCssFunction::CssFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_document_at_rule(&self) -> Option<&CssDocumentAtRule> {
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
         match &self {
-            AnyCssAtRule::CssDocumentAtRule(item) => Some(item),
+            AnyCssFunction::CssUrlFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_font_face_at_rule(&self) -> Option<&CssFontFaceAtRule> {
+    pub fn as_tw_function(&self) -> Option<&TwFunction> {
         match &self {
-            AnyCssAtRule::CssFontFaceAtRule(item) => Some(item),
+            AnyCssFunction::TwFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_font_feature_values_at_rule(&self) -> Option<&CssFontFeatureValuesAtRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssGenericComponentValue {
+    AnyCssValue(AnyCssValue),
+    CssGenericDelimiter(CssGenericDelimiter),
+}
+impl AnyCssGenericComponentValue {
+    pub fn as_any_css_value(&self) -> Option<&AnyCssValue> {
         match &self {
-            AnyCssAtRule::CssFontFeatureValuesAtRule(item) => Some(item),
+            AnyCssGenericComponentValue::AnyCssValue(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_font_palette_values_at_rule(&self) -> Option<&CssFontPaletteValuesAtRule> {
+    pub fn as_css_generic_delimiter(&self) -> Option<&CssGenericDelimiter> {
         match &self {
-            AnyCssAtRule::CssFontPaletteValuesAtRule(item) => Some(item),
+            AnyCssGenericComponentValue::CssGenericDelimiter(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_import_at_rule(&self) -> Option<&CssImportAtRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssImportLayer {
+    CssImportAnonymousLayer(CssImportAnonymousLayer),
+    CssImportNamedLayer(CssImportNamedLayer),
+}
+impl AnyCssImportLayer {
+    pub fn as_css_import_anonymous_layer(&self) -> Option<&CssImportAnonymousLayer> {
         match &self {
-            AnyCssAtRule::CssImportAtRule(item) => Some(item),
+            AnyCssImportLayer::CssImportAnonymousLayer(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_keyframes_at_rule(&self) -> Option<&CssKeyframesAtRule> {
+    pub fn as_css_import_named_layer(&self) -> Option<&CssImportNamedLayer> {
         match &self {
-            AnyCssAtRule::CssKeyframesAtRule(item) => Some(item),
+            AnyCssImportLayer::CssImportNamedLayer(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_layer_at_rule(&self) -> Option<&CssLayerAtRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssImportSupportsCondition {
+    AnyCssSupportsCondition(AnyCssSupportsCondition),
+    CssDeclaration(CssDeclaration),
+}
+impl AnyCssImportSupportsCondition {
+    pub fn as_any_css_supports_condition(&self) -> Option<&AnyCssSupportsCondition> {
         match &self {
-            AnyCssAtRule::CssLayerAtRule(item) => Some(item),
+            AnyCssImportSupportsCondition::AnyCssSupportsCondition(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_at_rule(&self) -> Option<&CssMediaAtRule> {
+    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
         match &self {
-            AnyCssAtRule::CssMediaAtRule(item) => Some(item),
+            AnyCssImportSupportsCondition::CssDeclaration(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_namespace_at_rule(&self) -> Option<&CssNamespaceAtRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssImportUrl {
+    CssString(CssString),
+    CssUrlFunction(CssUrlFunction),
+}
+impl AnyCssImportUrl {
+    pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssAtRule::CssNamespaceAtRule(item) => Some(item),
+            AnyCssImportUrl::CssString(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_page_at_rule(&self) -> Option<&CssPageAtRule> {
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
         match &self {
-            AnyCssAtRule::CssPageAtRule(item) => Some(item),
+            AnyCssImportUrl::CssUrlFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_property_at_rule(&self) -> Option<&CssPropertyAtRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssKeyframeName {
+    CssCustomIdentifier(CssCustomIdentifier),
+    CssString(CssString),
+}
+impl AnyCssKeyframeName {
+    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
         match &self {
-            AnyCssAtRule::CssPropertyAtRule(item) => Some(item),
+            AnyCssKeyframeName::CssCustomIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_scope_at_rule(&self) -> Option<&CssScopeAtRule> {
+    pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssAtRule::CssScopeAtRule(item) => Some(item),
+            AnyCssKeyframeName::CssString(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_starting_style_at_rule(&self) -> Option<&CssStartingStyleAtRule> {
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssKeyframesBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssKeyframesBlock(CssKeyframesBlock),
+}
+impl AnyCssKeyframesBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssAtRule::CssStartingStyleAtRule(item) => Some(item),
+            AnyCssKeyframesBlock::CssBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_block(&self) -> Option<&CssKeyframesBlock> {
+        match &self {
+            AnyCssKeyframesBlock::CssKeyframesBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssKeyframesItem {
+    CssBogusKeyframesItem(CssBogusKeyframesItem),
+    CssKeyframesItem(CssKeyframesItem),
+}
+impl AnyCssKeyframesItem {
+    pub fn as_css_bogus_keyframes_item(&self) -> Option<&CssBogusKeyframesItem> {
+        match &self {
+            AnyCssKeyframesItem::CssBogusKeyframesItem(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_item(&self) -> Option<&CssKeyframesItem> {
+        match &self {
+            AnyCssKeyframesItem::CssKeyframesItem(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssKeyframesSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssKeyframesIdentSelector(CssKeyframesIdentSelector),
+    CssKeyframesPercentageSelector(CssKeyframesPercentageSelector),
+}
+impl AnyCssKeyframesSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+        match &self {
+            AnyCssKeyframesSelector::CssBogusSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_ident_selector(&self) -> Option<&CssKeyframesIdentSelector> {
+        match &self {
+            AnyCssKeyframesSelector::CssKeyframesIdentSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_keyframes_percentage_selector(&self) -> Option<&CssKeyframesPercentageSelector> {
+        match &self {
+            AnyCssKeyframesSelector::CssKeyframesPercentageSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyCssLayer {
+    CssBogusLayer(CssBogusLayer),
+    CssLayerDeclaration(CssLayerDeclaration),
+    CssLayerReference(CssLayerReference),
+}
+impl AnyCssLayer {
+    pub fn as_css_bogus_layer(&self) -> Option<&CssBogusLayer> {
+        match &self {
+            AnyCssLayer::CssBogusLayer(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_layer_declaration(&self) -> Option<&CssLayerDeclaration> {
+        match &self {
+            AnyCssLayer::CssLayerDeclaration(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_supports_at_rule(&self) -> Option<&CssSupportsAtRule> {
+    pub fn as_css_layer_reference(&self) -> Option<&CssLayerReference> {
         match &self {
-            AnyCssAtRule::CssSupportsAtRule(item) => Some(item),
+            AnyCssLayer::CssLayerReference(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssAttributeMatcherValue {
-    CssIdentifier(CssIdentifier),
-    CssString(CssString),
+pub enum AnyCssMediaAndCombinableCondition {
+    AnyCssMediaInParens(AnyCssMediaInParens),
+    CssMediaAndCondition(CssMediaAndCondition),
 }
-impl AnyCssAttributeMatcherValue {
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+impl AnyCssMediaAndCombinableCondition {
+    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
         match &self {
-            AnyCssAttributeMatcherValue::CssIdentifier(item) => Some(item),
+            AnyCssMediaAndCombinableCondition::AnyCssMediaInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
+    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
         match &self {
-            AnyCssAttributeMatcherValue::CssString(item) => Some(item),
+            AnyCssMediaAndCombinableCondition::CssMediaAndCondition(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssCompoundSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssCompoundSelector(CssCompoundSelector),
+pub enum AnyCssMediaCondition {
+    AnyCssMediaInParens(AnyCssMediaInParens),
+    CssMediaAndCondition(CssMediaAndCondition),
+    CssMediaNotCondition(CssMediaNotCondition),
+    CssMediaOrCondition(CssMediaOrCondition),
 }
-impl AnyCssCompoundSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+impl AnyCssMediaCondition {
+    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
         match &self {
-            AnyCssCompoundSelector::CssBogusSelector(item) => Some(item),
+            AnyCssMediaCondition::AnyCssMediaInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_compound_selector(&self) -> Option<&CssCompoundSelector> {
+    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
         match &self {
-            AnyCssCompoundSelector::CssCompoundSelector(item) => Some(item),
+            AnyCssMediaCondition::CssMediaAndCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_not_condition(&self) -> Option<&CssMediaNotCondition> {
+        match &self {
+            AnyCssMediaCondition::CssMediaNotCondition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_media_or_condition(&self) -> Option<&CssMediaOrCondition> {
+        match &self {
+            AnyCssMediaCondition::CssMediaOrCondition(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssContainerAndCombinableQuery {
-    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
-    CssContainerAndQuery(CssContainerAndQuery),
+pub enum AnyCssMediaInParens {
+    CssMediaConditionInParens(CssMediaConditionInParens),
+    CssMediaFeatureInParens(CssMediaFeatureInParens),
 }
-impl AnyCssContainerAndCombinableQuery {
-    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
+impl AnyCssMediaInParens {
+    pub fn as_css_media_condition_in_parens(&self) -> Option<&CssMediaConditionInParens> {
         match &self {
-            AnyCssContainerAndCombinableQuery::AnyCssContainerQueryInParens(item) => Some(item),
+            AnyCssMediaInParens::CssMediaConditionInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_and_query(&self) -> Option<&CssContainerAndQuery> {
+    pub fn as_css_media_feature_in_parens(&self) -> Option<&CssMediaFeatureInParens> {
         match &self {
-            AnyCssContainerAndCombinableQuery::CssContainerAndQuery(item) => Some(item),
+            AnyCssMediaInParens::CssMediaFeatureInParens(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssContainerOrCombinableQuery {
-    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
-    CssContainerOrQuery(CssContainerOrQuery),
+pub enum AnyCssMediaOrCombinableCondition {
+    AnyCssMediaInParens(AnyCssMediaInParens),
+    CssMediaOrCondition(CssMediaOrCondition),
 }
-impl AnyCssContainerOrCombinableQuery {
-    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
+impl AnyCssMediaOrCombinableCondition {
+    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
         match &self {
-            AnyCssContainerOrCombinableQuery::AnyCssContainerQueryInParens(item) => Some(item),
+            AnyCssMediaOrCombinableCondition::AnyCssMediaInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_or_query(&self) -> Option<&CssContainerOrQuery> {
+    pub fn as_css_media_or_condition(&self) -> Option<&CssMediaOrCondition> {
         match &self {
-            AnyCssContainerOrCombinableQuery::CssContainerOrQuery(item) => Some(item),
+            AnyCssMediaOrCombinableCondition::CssMediaOrCondition(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssContainerQuery {
-    AnyCssContainerQueryInParens(AnyCssContainerQueryInParens),
-    CssContainerAndQuery(CssContainerAndQuery),
-    CssContainerNotQuery(CssContainerNotQuery),
-    CssContainerOrQuery(CssContainerOrQuery),
+pub enum AnyCssMediaQuery {
+    AnyCssMediaTypeQuery(AnyCssMediaTypeQuery),
+    CssBogusMediaQuery(CssBogusMediaQuery),
+    CssMediaConditionQuery(CssMediaConditionQuery),
+    TwFunction(TwFunction),
 }
-impl AnyCssContainerQuery {
-    pub fn as_any_css_container_query_in_parens(&self) -> Option<&AnyCssContainerQueryInParens> {
+impl AnyCssMediaQuery {
+    pub fn as_any_css_media_type_query(&self) -> Option<&AnyCssMediaTypeQuery> {
         match &self {
-            AnyCssContainerQuery::AnyCssContainerQueryInParens(item) => Some(item),
+            AnyCssMediaQuery::AnyCssMediaTypeQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_and_query(&self) -> Option<&CssContainerAndQuery> {
+    pub fn as_css_bogus_media_query(&self) -> Option<&CssBogusMediaQuery> {
         match &self {
-            AnyCssContainerQuery::CssContainerAndQuery(item) => Some(item),
+            AnyCssMediaQuery::CssBogusMediaQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_not_query(&self) -> Option<&CssContainerNotQuery> {
+    pub fn as_css_media_condition_query(&self) -> Option<&CssMediaConditionQuery> {
         match &self {
-            AnyCssContainerQuery::CssContainerNotQuery(item) => Some(item),
+            AnyCssMediaQuery::CssMediaConditionQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_or_query(&self) -> Option<&CssContainerOrQuery> {
+    pub fn as_tw_function(&self) -> Option<&TwFunction> {
         match &self {
-            AnyCssContainerQuery::CssContainerOrQuery(item) => Some(item),
+            AnyCssMediaQuery::TwFunction(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssContainerQueryInParens {
-    CssContainerQueryInParens(CssContainerQueryInParens),
-    CssContainerSizeFeatureInParens(CssContainerSizeFeatureInParens),
-    CssContainerStyleQueryInParens(CssContainerStyleQueryInParens),
+pub enum AnyCssMediaTypeCondition {
+    AnyCssMediaInParens(AnyCssMediaInParens),
+    CssMediaAndCondition(CssMediaAndCondition),
+    CssMediaNotCondition(CssMediaNotCondition),
 }
-impl AnyCssContainerQueryInParens {
-    pub fn as_css_container_query_in_parens(&self) -> Option<&CssContainerQueryInParens> {
+impl AnyCssMediaTypeCondition {
+    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
         match &self {
-            AnyCssContainerQueryInParens::CssContainerQueryInParens(item) => Some(item),
+            AnyCssMediaTypeCondition::AnyCssMediaInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_size_feature_in_parens(
-        &self,
-    ) -> Option<&CssContainerSizeFeatureInParens> {
+    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
         match &self {
-            AnyCssContainerQueryInParens::CssContainerSizeFeatureInParens(item) => Some(item),
+            AnyCssMediaTypeCondition::CssMediaAndCondition(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_style_query_in_parens(
-        &self,
-    ) -> Option<&CssContainerStyleQueryInParens> {
+    pub fn as_css_media_not_condition(&self) -> Option<&CssMediaNotCondition> {
         match &self {
-            AnyCssContainerQueryInParens::CssContainerStyleQueryInParens(item) => Some(item),
+            AnyCssMediaTypeCondition::CssMediaNotCondition(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssContainerStyleAndCombinableQuery {
-    CssContainerStyleAndQuery(CssContainerStyleAndQuery),
-    CssContainerStyleInParens(CssContainerStyleInParens),
+pub enum AnyCssMediaTypeQuery {
+    CssMediaAndTypeQuery(CssMediaAndTypeQuery),
+    CssMediaTypeQuery(CssMediaTypeQuery),
 }
-impl AnyCssContainerStyleAndCombinableQuery {
-    pub fn as_css_container_style_and_query(&self) -> Option<&CssContainerStyleAndQuery> {
+impl AnyCssMediaTypeQuery {
+    pub fn as_css_media_and_type_query(&self) -> Option<&CssMediaAndTypeQuery> {
         match &self {
-            AnyCssContainerStyleAndCombinableQuery::CssContainerStyleAndQuery(item) => Some(item),
+            AnyCssMediaTypeQuery::CssMediaAndTypeQuery(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
+    pub fn as_css_media_type_query(&self) -> Option<&CssMediaTypeQuery> {
         match &self {
-            AnyCssContainerStyleAndCombinableQuery::CssContainerStyleInParens(item) => Some(item),
+            AnyCssMediaTypeQuery::CssMediaTypeQuery(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssContainerStyleInParens {
-    AnyCssContainerStyleQuery(AnyCssContainerStyleQuery),
-    CssDeclaration(CssDeclaration),
+pub enum AnyCssNamespacePrefix {
+    CssNamedNamespacePrefix(CssNamedNamespacePrefix),
+    CssUniversalNamespacePrefix(CssUniversalNamespacePrefix),
 }
-impl AnyCssContainerStyleInParens {
-    pub fn as_any_css_container_style_query(&self) -> Option<&AnyCssContainerStyleQuery> {
+impl AnyCssNamespacePrefix {
+    pub fn as_css_named_namespace_prefix(&self) -> Option<&CssNamedNamespacePrefix> {
         match &self {
-            AnyCssContainerStyleInParens::AnyCssContainerStyleQuery(item) => Some(item),
+            AnyCssNamespacePrefix::CssNamedNamespacePrefix(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
+    pub fn as_css_universal_namespace_prefix(&self) -> Option<&CssUniversalNamespacePrefix> {
         match &self {
-            AnyCssContainerStyleInParens::CssDeclaration(item) => Some(item),
+            AnyCssNamespacePrefix::CssUniversalNamespacePrefix(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssContainerStyleOrCombinableQuery {
-    CssContainerStyleInParens(CssContainerStyleInParens),
-    CssContainerStyleOrQuery(CssContainerStyleOrQuery),
+pub enum AnyCssNamespaceUrl {
+    CssString(CssString),
+    CssUrlFunction(CssUrlFunction),
 }
-impl AnyCssContainerStyleOrCombinableQuery {
-    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
+impl AnyCssNamespaceUrl {
+    pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssContainerStyleOrCombinableQuery::CssContainerStyleInParens(item) => Some(item),
+            AnyCssNamespaceUrl::CssString(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_container_style_or_query(&self) -> Option<&CssContainerStyleOrQuery> {
+    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
         match &self {
-            AnyCssContainerStyleOrCombinableQuery::CssContainerStyleOrQuery(item) => Some(item),
+            AnyCssNamespaceUrl::CssUrlFunction(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssContainerStyleQuery {
-    CssContainerStyleAndQuery(CssContainerStyleAndQuery),
-    CssContainerStyleInParens(CssContainerStyleInParens),
-    CssContainerStyleNotQuery(CssContainerStyleNotQuery),
-    CssContainerStyleOrQuery(CssContainerStyleOrQuery),
-    CssDeclaration(CssDeclaration),
+pub enum AnyCssPageAtRuleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssPageAtRuleBlock(CssPageAtRuleBlock),
 }
-impl AnyCssContainerStyleQuery {
-    pub fn as_css_container_style_and_query(&self) -> Option<&CssContainerStyleAndQuery> {
-        match &self {
-            AnyCssContainerStyleQuery::CssContainerStyleAndQuery(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_container_style_in_parens(&self) -> Option<&CssContainerStyleInParens> {
-        match &self {
-            AnyCssContainerStyleQuery::CssContainerStyleInParens(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_container_style_not_query(&self) -> Option<&CssContainerStyleNotQuery> {
-        match &self {
-            AnyCssContainerStyleQuery::CssContainerStyleNotQuery(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_container_style_or_query(&self) -> Option<&CssContainerStyleOrQuery> {
+impl AnyCssPageAtRuleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssContainerStyleQuery::CssContainerStyleOrQuery(item) => Some(item),
+            AnyCssPageAtRuleBlock::CssBogusBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
+    pub fn as_css_page_at_rule_block(&self) -> Option<&CssPageAtRuleBlock> {
         match &self {
-            AnyCssContainerStyleQuery::CssDeclaration(item) => Some(item),
+            AnyCssPageAtRuleBlock::CssPageAtRuleBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssCustomIdentifier {
-    CssBogusCustomIdentifier(CssBogusCustomIdentifier),
-    CssCustomIdentifier(CssCustomIdentifier),
+pub enum AnyCssPageAtRuleItem {
+    CssAtRule(CssAtRule),
+    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
+    CssMarginAtRule(CssMarginAtRule),
 }
-impl AnyCssCustomIdentifier {
-    pub fn as_css_bogus_custom_identifier(&self) -> Option<&CssBogusCustomIdentifier> {
+impl AnyCssPageAtRuleItem {
+    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
         match &self {
-            AnyCssCustomIdentifier::CssBogusCustomIdentifier(item) => Some(item),
+            AnyCssPageAtRuleItem::CssAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
+    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
         match &self {
-            AnyCssCustomIdentifier::CssCustomIdentifier(item) => Some(item),
+            AnyCssPageAtRuleItem::CssDeclarationWithSemicolon(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_margin_at_rule(&self) -> Option<&CssMarginAtRule> {
+        match &self {
+            AnyCssPageAtRuleItem::CssMarginAtRule(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssDeclarationBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssDeclarationBlock(CssDeclarationBlock),
+pub enum AnyCssPageSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssPageSelector(CssPageSelector),
 }
-impl AnyCssDeclarationBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+impl AnyCssPageSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
         match &self {
-            AnyCssDeclarationBlock::CssBogusBlock(item) => Some(item),
+            AnyCssPageSelector::CssBogusSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration_block(&self) -> Option<&CssDeclarationBlock> {
+    pub fn as_css_page_selector(&self) -> Option<&CssPageSelector> {
         match &self {
-            AnyCssDeclarationBlock::CssDeclarationBlock(item) => Some(item),
+            AnyCssPageSelector::CssPageSelector(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssDeclarationName {
-    CssDashedIdentifier(CssDashedIdentifier),
-    CssIdentifier(CssIdentifier),
+pub enum AnyCssPageSelectorPseudo {
+    CssBogusPageSelectorPseudo(CssBogusPageSelectorPseudo),
+    CssPageSelectorPseudo(CssPageSelectorPseudo),
 }
-impl AnyCssDeclarationName {
-    pub fn as_css_dashed_identifier(&self) -> Option<&CssDashedIdentifier> {
+impl AnyCssPageSelectorPseudo {
+    pub fn as_css_bogus_page_selector_pseudo(&self) -> Option<&CssBogusPageSelectorPseudo> {
         match &self {
-            AnyCssDeclarationName::CssDashedIdentifier(item) => Some(item),
+            AnyCssPageSelectorPseudo::CssBogusPageSelectorPseudo(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+    pub fn as_css_page_selector_pseudo(&self) -> Option<&CssPageSelectorPseudo> {
         match &self {
-            AnyCssDeclarationName::CssIdentifier(item) => Some(item),
+            AnyCssPageSelectorPseudo::CssPageSelectorPseudo(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssDeclarationOrAtRule {
-    CssAtRule(CssAtRule),
-    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
+pub enum AnyCssProperty {
+    CssBogusProperty(CssBogusProperty),
+    CssGenericProperty(CssGenericProperty),
 }
-impl AnyCssDeclarationOrAtRule {
-    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
+impl AnyCssProperty {
+    pub fn as_css_bogus_property(&self) -> Option<&CssBogusProperty> {
         match &self {
-            AnyCssDeclarationOrAtRule::CssAtRule(item) => Some(item),
+            AnyCssProperty::CssBogusProperty(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
+    pub fn as_css_generic_property(&self) -> Option<&CssGenericProperty> {
         match &self {
-            AnyCssDeclarationOrAtRule::CssDeclarationWithSemicolon(item) => Some(item),
+            AnyCssProperty::CssGenericProperty(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssDeclarationOrAtRuleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssDeclarationOrAtRuleBlock(CssDeclarationOrAtRuleBlock),
+pub enum AnyCssPseudoClass {
+    CssBogusPseudoClass(CssBogusPseudoClass),
+    CssPseudoClassFunctionCompoundSelector(CssPseudoClassFunctionCompoundSelector),
+    CssPseudoClassFunctionCompoundSelectorList(CssPseudoClassFunctionCompoundSelectorList),
+    CssPseudoClassFunctionIdentifier(CssPseudoClassFunctionIdentifier),
+    CssPseudoClassFunctionNth(CssPseudoClassFunctionNth),
+    CssPseudoClassFunctionRelativeSelectorList(CssPseudoClassFunctionRelativeSelectorList),
+    CssPseudoClassFunctionSelector(CssPseudoClassFunctionSelector),
+    CssPseudoClassFunctionSelectorList(CssPseudoClassFunctionSelectorList),
+    CssPseudoClassFunctionValueList(CssPseudoClassFunctionValueList),
+    CssPseudoClassIdentifier(CssPseudoClassIdentifier),
 }
-impl AnyCssDeclarationOrAtRuleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+impl AnyCssPseudoClass {
+    pub fn as_css_bogus_pseudo_class(&self) -> Option<&CssBogusPseudoClass> {
         match &self {
-            AnyCssDeclarationOrAtRuleBlock::CssBogusBlock(item) => Some(item),
+            AnyCssPseudoClass::CssBogusPseudoClass(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration_or_at_rule_block(&self) -> Option<&CssDeclarationOrAtRuleBlock> {
+    pub fn as_css_pseudo_class_function_compound_selector(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionCompoundSelector> {
         match &self {
-            AnyCssDeclarationOrAtRuleBlock::CssDeclarationOrAtRuleBlock(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelector(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssDeclarationOrRule {
-    AnyCssRule(AnyCssRule),
-    CssBogus(CssBogus),
-    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
-}
-impl AnyCssDeclarationOrRule {
-    pub fn as_any_css_rule(&self) -> Option<&AnyCssRule> {
+    pub fn as_css_pseudo_class_function_compound_selector_list(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionCompoundSelectorList> {
         match &self {
-            AnyCssDeclarationOrRule::AnyCssRule(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelectorList(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_bogus(&self) -> Option<&CssBogus> {
+    pub fn as_css_pseudo_class_function_identifier(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionIdentifier> {
         match &self {
-            AnyCssDeclarationOrRule::CssBogus(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassFunctionIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
+    pub fn as_css_pseudo_class_function_nth(&self) -> Option<&CssPseudoClassFunctionNth> {
         match &self {
-            AnyCssDeclarationOrRule::CssDeclarationWithSemicolon(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassFunctionNth(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssDeclarationOrRuleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssDeclarationOrRuleBlock(CssDeclarationOrRuleBlock),
-}
-impl AnyCssDeclarationOrRuleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+    pub fn as_css_pseudo_class_function_relative_selector_list(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionRelativeSelectorList> {
         match &self {
-            AnyCssDeclarationOrRuleBlock::CssBogusBlock(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassFunctionRelativeSelectorList(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration_or_rule_block(&self) -> Option<&CssDeclarationOrRuleBlock> {
+    pub fn as_css_pseudo_class_function_selector(&self) -> Option<&CssPseudoClassFunctionSelector> {
         match &self {
-            AnyCssDeclarationOrRuleBlock::CssDeclarationOrRuleBlock(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassFunctionSelector(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssDimension {
-    CssPercentage(CssPercentage),
-    CssRegularDimension(CssRegularDimension),
-    CssUnknownDimension(CssUnknownDimension),
-}
-impl AnyCssDimension {
-    pub fn as_css_percentage(&self) -> Option<&CssPercentage> {
+    pub fn as_css_pseudo_class_function_selector_list(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionSelectorList> {
         match &self {
-            AnyCssDimension::CssPercentage(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassFunctionSelectorList(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_regular_dimension(&self) -> Option<&CssRegularDimension> {
+    pub fn as_css_pseudo_class_function_value_list(
+        &self,
+    ) -> Option<&CssPseudoClassFunctionValueList> {
         match &self {
-            AnyCssDimension::CssRegularDimension(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassFunctionValueList(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_unknown_dimension(&self) -> Option<&CssUnknownDimension> {
+    pub fn as_css_pseudo_class_identifier(&self) -> Option<&CssPseudoClassIdentifier> {
         match &self {
-            AnyCssDimension::CssUnknownDimension(item) => Some(item),
+            AnyCssPseudoClass::CssPseudoClassIdentifier(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssDocumentMatcher {
-    CssBogusDocumentMatcher(CssBogusDocumentMatcher),
-    CssDocumentCustomMatcher(CssDocumentCustomMatcher),
-    CssUrlFunction(CssUrlFunction),
+pub enum AnyCssPseudoClassNth {
+    CssPseudoClassNth(CssPseudoClassNth),
+    CssPseudoClassNthIdentifier(CssPseudoClassNthIdentifier),
+    CssPseudoClassNthNumber(CssPseudoClassNthNumber),
 }
-impl AnyCssDocumentMatcher {
-    pub fn as_css_bogus_document_matcher(&self) -> Option<&CssBogusDocumentMatcher> {
+impl AnyCssPseudoClassNth {
+    pub fn as_css_pseudo_class_nth(&self) -> Option<&CssPseudoClassNth> {
         match &self {
-            AnyCssDocumentMatcher::CssBogusDocumentMatcher(item) => Some(item),
+            AnyCssPseudoClassNth::CssPseudoClassNth(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_document_custom_matcher(&self) -> Option<&CssDocumentCustomMatcher> {
+    pub fn as_css_pseudo_class_nth_identifier(&self) -> Option<&CssPseudoClassNthIdentifier> {
         match &self {
-            AnyCssDocumentMatcher::CssDocumentCustomMatcher(item) => Some(item),
+            AnyCssPseudoClassNth::CssPseudoClassNthIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
+    pub fn as_css_pseudo_class_nth_number(&self) -> Option<&CssPseudoClassNthNumber> {
         match &self {
-            AnyCssDocumentMatcher::CssUrlFunction(item) => Some(item),
+            AnyCssPseudoClassNth::CssPseudoClassNthNumber(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssExpression {
-    CssBinaryExpression(CssBinaryExpression),
-    CssListOfComponentValuesExpression(CssListOfComponentValuesExpression),
-    CssParenthesizedExpression(CssParenthesizedExpression),
+pub enum AnyCssPseudoClassNthSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssPseudoClassNthSelector(CssPseudoClassNthSelector),
 }
-impl AnyCssExpression {
-    pub fn as_css_binary_expression(&self) -> Option<&CssBinaryExpression> {
-        match &self {
-            AnyCssExpression::CssBinaryExpression(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_list_of_component_values_expression(
-        &self,
-    ) -> Option<&CssListOfComponentValuesExpression> {
+impl AnyCssPseudoClassNthSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
         match &self {
-            AnyCssExpression::CssListOfComponentValuesExpression(item) => Some(item),
+            AnyCssPseudoClassNthSelector::CssBogusSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_parenthesized_expression(&self) -> Option<&CssParenthesizedExpression> {
+    pub fn as_css_pseudo_class_nth_selector(&self) -> Option<&CssPseudoClassNthSelector> {
         match &self {
-            AnyCssExpression::CssParenthesizedExpression(item) => Some(item),
+            AnyCssPseudoClassNthSelector::CssPseudoClassNthSelector(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssFontFamilyName {
-    CssBogusFontFamilyName(CssBogusFontFamilyName),
-    CssFontFamilyName(CssFontFamilyName),
-    CssString(CssString),
+pub enum AnyCssPseudoElement {
+    CssBogusPseudoElement(CssBogusPseudoElement),
+    CssPseudoElementFunctionIdentifier(CssPseudoElementFunctionIdentifier),
+    CssPseudoElementFunctionSelector(CssPseudoElementFunctionSelector),
+    CssPseudoElementIdentifier(CssPseudoElementIdentifier),
 }
-impl AnyCssFontFamilyName {
-    pub fn as_css_bogus_font_family_name(&self) -> Option<&CssBogusFontFamilyName> {
+impl AnyCssPseudoElement {
+    pub fn as_css_bogus_pseudo_element(&self) -> Option<&CssBogusPseudoElement> {
         match &self {
-            AnyCssFontFamilyName::CssBogusFontFamilyName(item) => Some(item),
+            AnyCssPseudoElement::CssBogusPseudoElement(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_font_family_name(&self) -> Option<&CssFontFamilyName> {
+    pub fn as_css_pseudo_element_function_identifier(
+        &self,
+    ) -> Option<&CssPseudoElementFunctionIdentifier> {
         match &self {
-            AnyCssFontFamilyName::CssFontFamilyName(item) => Some(item),
+            AnyCssPseudoElement::CssPseudoElementFunctionIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
+    pub fn as_css_pseudo_element_function_selector(
+        &self,
+    ) -> Option<&CssPseudoElementFunctionSelector> {
         match &self {
-            AnyCssFontFamilyName::CssString(item) => Some(item),
+            AnyCssPseudoElement::CssPseudoElementFunctionSelector(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_pseudo_element_identifier(&self) -> Option<&CssPseudoElementIdentifier> {
+        match &self {
+            AnyCssPseudoElement::CssPseudoElementIdentifier(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssFontFeatureValuesBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssFontFeatureValuesBlock(CssFontFeatureValuesBlock),
+pub enum AnyCssPseudoValue {
+    CssIdentifier(CssIdentifier),
+    CssString(CssString),
 }
-impl AnyCssFontFeatureValuesBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+impl AnyCssPseudoValue {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
         match &self {
-            AnyCssFontFeatureValuesBlock::CssBogusBlock(item) => Some(item),
+            AnyCssPseudoValue::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_font_feature_values_block(&self) -> Option<&CssFontFeatureValuesBlock> {
+    pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssFontFeatureValuesBlock::CssFontFeatureValuesBlock(item) => Some(item),
+            AnyCssPseudoValue::CssString(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssFontFeatureValuesItem {
-    CssBogusFontFeatureValuesItem(CssBogusFontFeatureValuesItem),
-    CssFontFeatureValuesItem(CssFontFeatureValuesItem),
+pub enum AnyCssQueryFeature {
+    CssQueryFeatureBoolean(CssQueryFeatureBoolean),
+    CssQueryFeaturePlain(CssQueryFeaturePlain),
+    CssQueryFeatureRange(CssQueryFeatureRange),
+    CssQueryFeatureRangeInterval(CssQueryFeatureRangeInterval),
+    CssQueryFeatureReverseRange(CssQueryFeatureReverseRange),
 }
-impl AnyCssFontFeatureValuesItem {
-    pub fn as_css_bogus_font_feature_values_item(&self) -> Option<&CssBogusFontFeatureValuesItem> {
+impl AnyCssQueryFeature {
+    pub fn as_css_query_feature_boolean(&self) -> Option<&CssQueryFeatureBoolean> {
         match &self {
-            AnyCssFontFeatureValuesItem::CssBogusFontFeatureValuesItem(item) => Some(item),
+            AnyCssQueryFeature::CssQueryFeatureBoolean(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_font_feature_values_item(&self) -> Option<&CssFontFeatureValuesItem> {
+    pub fn as_css_query_feature_plain(&self) -> Option<&CssQueryFeaturePlain> {
         match &self {
-            AnyCssFontFeatureValuesItem::CssFontFeatureValuesItem(item) => Some(item),
+            AnyCssQueryFeature::CssQueryFeaturePlain(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssFunction {
-    CssFunction(CssFunction),
-    CssUrlFunction(CssUrlFunction),
-}
-impl AnyCssFunction {
-    pub fn as_css_function(&self) -> Option<&CssFunction> {
+    pub fn as_css_query_feature_range(&self) -> Option<&CssQueryFeatureRange> {
+        match &self {
+            AnyCssQueryFeature::CssQueryFeatureRange(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_query_feature_range_interval(&self) -> Option<&CssQueryFeatureRangeInterval> {
         match &self {
-            AnyCssFunction::CssFunction(item) => Some(item),
+            AnyCssQueryFeature::CssQueryFeatureRangeInterval(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
+    pub fn as_css_query_feature_reverse_range(&self) -> Option<&CssQueryFeatureReverseRange> {
         match &self {
-            AnyCssFunction::CssUrlFunction(item) => Some(item),
+            AnyCssQueryFeature::CssQueryFeatureReverseRange(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssGenericComponentValue {
-    AnyCssValue(AnyCssValue),
-    CssGenericDelimiter(CssGenericDelimiter),
+pub enum AnyCssQueryFeatureValue {
+    AnyCssDimension(AnyCssDimension),
+    AnyCssFunction(AnyCssFunction),
+    CssIdentifier(CssIdentifier),
+    CssNumber(CssNumber),
+    CssRatio(CssRatio),
 }
-impl AnyCssGenericComponentValue {
-    pub fn as_any_css_value(&self) -> Option<&AnyCssValue> {
+impl AnyCssQueryFeatureValue {
+    pub fn as_any_css_dimension(&self) -> Option<&AnyCssDimension> {
         match &self {
-            AnyCssGenericComponentValue::AnyCssValue(item) => Some(item),
+            AnyCssQueryFeatureValue::AnyCssDimension(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_generic_delimiter(&self) -> Option<&CssGenericDelimiter> {
+    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
         match &self {
-            AnyCssGenericComponentValue::CssGenericDelimiter(item) => Some(item),
+            AnyCssQueryFeatureValue::AnyCssFunction(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssImportLayer {
-    CssImportAnonymousLayer(CssImportAnonymousLayer),
-    CssImportNamedLayer(CssImportNamedLayer),
-}
-impl AnyCssImportLayer {
-    pub fn as_css_import_anonymous_layer(&self) -> Option<&CssImportAnonymousLayer> {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
         match &self {
-            AnyCssImportLayer::CssImportAnonymousLayer(item) => Some(item),
+            AnyCssQueryFeatureValue::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_import_named_layer(&self) -> Option<&CssImportNamedLayer> {
+    pub fn as_css_number(&self) -> Option<&CssNumber> {
         match &self {
-            AnyCssImportLayer::CssImportNamedLayer(item) => Some(item),
+            AnyCssQueryFeatureValue::CssNumber(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
+        match &self {
+            AnyCssQueryFeatureValue::CssRatio(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssImportSupportsCondition {
-    AnyCssSupportsCondition(AnyCssSupportsCondition),
-    CssDeclaration(CssDeclaration),
+pub enum AnyCssRelativeSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssRelativeSelector(CssRelativeSelector),
 }
-impl AnyCssImportSupportsCondition {
-    pub fn as_any_css_supports_condition(&self) -> Option<&AnyCssSupportsCondition> {
+impl AnyCssRelativeSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
         match &self {
-            AnyCssImportSupportsCondition::AnyCssSupportsCondition(item) => Some(item),
+            AnyCssRelativeSelector::CssBogusSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration(&self) -> Option<&CssDeclaration> {
+    pub fn as_css_relative_selector(&self) -> Option<&CssRelativeSelector> {
         match &self {
-            AnyCssImportSupportsCondition::CssDeclaration(item) => Some(item),
+            AnyCssRelativeSelector::CssRelativeSelector(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssImportUrl {
-    CssString(CssString),
-    CssUrlFunction(CssUrlFunction),
+pub enum AnyCssRule {
+    CssAtRule(CssAtRule),
+    CssBogusRule(CssBogusRule),
+    CssNestedQualifiedRule(CssNestedQualifiedRule),
+    CssQualifiedRule(CssQualifiedRule),
+    LessDeclaration(LessDeclaration),
+    LessMixinCall(LessMixinCall),
+    LessMixinDefinition(LessMixinDefinition),
+    ScssDeclaration(ScssDeclaration),
 }
-impl AnyCssImportUrl {
-    pub fn as_css_string(&self) -> Option<&CssString> {
+impl AnyCssRule {
+    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
         match &self {
-            AnyCssImportUrl::CssString(item) => Some(item),
+            AnyCssRule::CssAtRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
+    pub fn as_css_bogus_rule(&self) -> Option<&CssBogusRule> {
         match &self {
-            AnyCssImportUrl::CssUrlFunction(item) => Some(item),
+            AnyCssRule::CssBogusRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssKeyframeName {
-    CssCustomIdentifier(CssCustomIdentifier),
-    CssString(CssString),
-}
-impl AnyCssKeyframeName {
-    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
+    pub fn as_css_nested_qualified_rule(&self) -> Option<&CssNestedQualifiedRule> {
         match &self {
-            AnyCssKeyframeName::CssCustomIdentifier(item) => Some(item),
+            AnyCssRule::CssNestedQualifiedRule(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
+    pub fn as_css_qualified_rule(&self) -> Option<&CssQualifiedRule> {
         match &self {
-            AnyCssKeyframeName::CssString(item) => Some(item),
+            AnyCssRule::CssQualifiedRule(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssKeyframesBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssKeyframesBlock(CssKeyframesBlock),
-}
-impl AnyCssKeyframesBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+    pub fn as_less_declaration(&self) -> Option<&LessDeclaration> {
         match &self {
-            AnyCssKeyframesBlock::CssBogusBlock(item) => Some(item),
+            AnyCssRule::LessDeclaration(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_keyframes_block(&self) -> Option<&CssKeyframesBlock> {
+    pub fn as_less_mixin_call(&self) -> Option<&LessMixinCall> {
         match &self {
-            AnyCssKeyframesBlock::CssKeyframesBlock(item) => Some(item),
+            AnyCssRule::LessMixinCall(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssKeyframesItem {
-    CssBogusKeyframesItem(CssBogusKeyframesItem),
-    CssKeyframesItem(CssKeyframesItem),
-}
-impl AnyCssKeyframesItem {
-    pub fn as_css_bogus_keyframes_item(&self) -> Option<&CssBogusKeyframesItem> {
+    pub fn as_less_mixin_definition(&self) -> Option<&LessMixinDefinition> {
         match &self {
-            AnyCssKeyframesItem::CssBogusKeyframesItem(item) => Some(item),
+            AnyCssRule::LessMixinDefinition(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_keyframes_item(&self) -> Option<&CssKeyframesItem> {
+    pub fn as_scss_declaration(&self) -> Option<&ScssDeclaration> {
         match &self {
-            AnyCssKeyframesItem::CssKeyframesItem(item) => Some(item),
+            AnyCssRule::ScssDeclaration(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssKeyframesSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssKeyframesIdentSelector(CssKeyframesIdentSelector),
-    CssKeyframesPercentageSelector(CssKeyframesPercentageSelector),
+pub enum AnyCssRuleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssRuleBlock(CssRuleBlock),
 }
-impl AnyCssKeyframesSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
-        match &self {
-            AnyCssKeyframesSelector::CssBogusSelector(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_keyframes_ident_selector(&self) -> Option<&CssKeyframesIdentSelector> {
+impl AnyCssRuleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssKeyframesSelector::CssKeyframesIdentSelector(item) => Some(item),
+            AnyCssRuleBlock::CssBogusBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_keyframes_percentage_selector(&self) -> Option<&CssKeyframesPercentageSelector> {
+    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
         match &self {
-            AnyCssKeyframesSelector::CssKeyframesPercentageSelector(item) => Some(item),
+            AnyCssRuleBlock::CssRuleBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssLayer {
-    CssBogusLayer(CssBogusLayer),
-    CssLayerDeclaration(CssLayerDeclaration),
-    CssLayerReference(CssLayerReference),
+pub enum AnyCssScopeRange {
+    CssBogusScopeRange(CssBogusScopeRange),
+    CssScopeRangeEnd(CssScopeRangeEnd),
+    CssScopeRangeInterval(CssScopeRangeInterval),
+    CssScopeRangeStart(CssScopeRangeStart),
 }
-impl AnyCssLayer {
-    pub fn as_css_bogus_layer(&self) -> Option<&CssBogusLayer> {
-        match &self {
-            AnyCssLayer::CssBogusLayer(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_layer_declaration(&self) -> Option<&CssLayerDeclaration> {
+impl AnyCssScopeRange {
+    pub fn as_css_bogus_scope_range(&self) -> Option<&CssBogusScopeRange> {
         match &self {
-            AnyCssLayer::CssLayerDeclaration(item) => Some(item),
+            AnyCssScopeRange::CssBogusScopeRange(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_layer_reference(&self) -> Option<&CssLayerReference> {
+    pub fn as_css_scope_range_end(&self) -> Option<&CssScopeRangeEnd> {
         match &self {
-            AnyCssLayer::CssLayerReference(item) => Some(item),
+            AnyCssScopeRange::CssScopeRangeEnd(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssMediaAndCombinableCondition {
-    AnyCssMediaInParens(AnyCssMediaInParens),
-    CssMediaAndCondition(CssMediaAndCondition),
-}
-impl AnyCssMediaAndCombinableCondition {
-    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
+    pub fn as_css_scope_range_interval(&self) -> Option<&CssScopeRangeInterval> {
         match &self {
-            AnyCssMediaAndCombinableCondition::AnyCssMediaInParens(item) => Some(item),
+            AnyCssScopeRange::CssScopeRangeInterval(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
+    pub fn as_css_scope_range_start(&self) -> Option<&CssScopeRangeStart> {
         match &self {
-            AnyCssMediaAndCombinableCondition::CssMediaAndCondition(item) => Some(item),
+            AnyCssScopeRange::CssScopeRangeStart(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssMediaCondition {
-    AnyCssMediaInParens(AnyCssMediaInParens),
-    CssMediaAndCondition(CssMediaAndCondition),
-    CssMediaNotCondition(CssMediaNotCondition),
-    CssMediaOrCondition(CssMediaOrCondition),
+pub enum AnyCssSelector {
+    CssBogusSelector(CssBogusSelector),
+    CssComplexSelector(CssComplexSelector),
+    CssCompoundSelector(CssCompoundSelector),
 }
-impl AnyCssMediaCondition {
-    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
-        match &self {
-            AnyCssMediaCondition::AnyCssMediaInParens(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
+impl AnyCssSelector {
+    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
         match &self {
-            AnyCssMediaCondition::CssMediaAndCondition(item) => Some(item),
+            AnyCssSelector::CssBogusSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_not_condition(&self) -> Option<&CssMediaNotCondition> {
+    pub fn as_css_complex_selector(&self) -> Option<&CssComplexSelector> {
         match &self {
-            AnyCssMediaCondition::CssMediaNotCondition(item) => Some(item),
+            AnyCssSelector::CssComplexSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_or_condition(&self) -> Option<&CssMediaOrCondition> {
+    pub fn as_css_compound_selector(&self) -> Option<&CssCompoundSelector> {
         match &self {
-            AnyCssMediaCondition::CssMediaOrCondition(item) => Some(item),
+            AnyCssSelector::CssCompoundSelector(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssMediaInParens {
-    CssMediaConditionInParens(CssMediaConditionInParens),
-    CssMediaFeatureInParens(CssMediaFeatureInParens),
+pub enum AnyCssSimpleSelector {
+    CssTypeSelector(CssTypeSelector),
+    CssUniversalSelector(CssUniversalSelector),
 }
-impl AnyCssMediaInParens {
-    pub fn as_css_media_condition_in_parens(&self) -> Option<&CssMediaConditionInParens> {
+impl AnyCssSimpleSelector {
+    pub fn as_css_type_selector(&self) -> Option<&CssTypeSelector> {
         match &self {
-            AnyCssMediaInParens::CssMediaConditionInParens(item) => Some(item),
+            AnyCssSimpleSelector::CssTypeSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_feature_in_parens(&self) -> Option<&CssMediaFeatureInParens> {
+    pub fn as_css_universal_selector(&self) -> Option<&CssUniversalSelector> {
         match &self {
-            AnyCssMediaInParens::CssMediaFeatureInParens(item) => Some(item),
+            AnyCssSimpleSelector::CssUniversalSelector(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssMediaOrCombinableCondition {
-    AnyCssMediaInParens(AnyCssMediaInParens),
-    CssMediaOrCondition(CssMediaOrCondition),
+pub enum AnyCssStartingStyleBlock {
+    CssBogusBlock(CssBogusBlock),
+    CssDeclarationBlock(CssDeclarationBlock),
+    CssRuleBlock(CssRuleBlock),
 }
-impl AnyCssMediaOrCombinableCondition {
-    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
+impl AnyCssStartingStyleBlock {
+    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
         match &self {
-            AnyCssMediaOrCombinableCondition::AnyCssMediaInParens(item) => Some(item),
+            AnyCssStartingStyleBlock::CssBogusBlock(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_or_condition(&self) -> Option<&CssMediaOrCondition> {
+    pub fn as_css_declaration_block(&self) -> Option<&CssDeclarationBlock> {
         match &self {
-            AnyCssMediaOrCombinableCondition::CssMediaOrCondition(item) => Some(item),
+            AnyCssStartingStyleBlock::CssDeclarationBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
+        match &self {
+            AnyCssStartingStyleBlock::CssRuleBlock(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssMediaQuery {
-    AnyCssMediaTypeQuery(AnyCssMediaTypeQuery),
-    CssBogusMediaQuery(CssBogusMediaQuery),
-    CssMediaConditionQuery(CssMediaConditionQuery),
+pub enum AnyCssSubSelector {
+    CssAttributeSelector(CssAttributeSelector),
+    CssBogusSubSelector(CssBogusSubSelector),
+    CssClassSelector(CssClassSelector),
+    CssIdSelector(CssIdSelector),
+    CssPseudoClassSelector(CssPseudoClassSelector),
+    CssPseudoElementSelector(CssPseudoElementSelector),
 }
-impl AnyCssMediaQuery {
-    pub fn as_any_css_media_type_query(&self) -> Option<&AnyCssMediaTypeQuery> {
+impl AnyCssSubSelector {
+    pub fn as_css_attribute_selector(&self) -> Option<&CssAttributeSelector> {
         match &self {
-            AnyCssMediaQuery::AnyCssMediaTypeQuery(item) => Some(item),
+            AnyCssSubSelector::CssAttributeSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_bogus_media_query(&self) -> Option<&CssBogusMediaQuery> {
+    pub fn as_css_bogus_sub_selector(&self) -> Option<&CssBogusSubSelector> {
         match &self {
-            AnyCssMediaQuery::CssBogusMediaQuery(item) => Some(item),
+            AnyCssSubSelector::CssBogusSubSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_condition_query(&self) -> Option<&CssMediaConditionQuery> {
+    pub fn as_css_class_selector(&self) -> Option<&CssClassSelector> {
         match &self {
-            AnyCssMediaQuery::CssMediaConditionQuery(item) => Some(item),
+            AnyCssSubSelector::CssClassSelector(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssMediaTypeCondition {
-    AnyCssMediaInParens(AnyCssMediaInParens),
-    CssMediaAndCondition(CssMediaAndCondition),
-    CssMediaNotCondition(CssMediaNotCondition),
-}
-impl AnyCssMediaTypeCondition {
-    pub fn as_any_css_media_in_parens(&self) -> Option<&AnyCssMediaInParens> {
+    pub fn as_css_id_selector(&self) -> Option<&CssIdSelector> {
         match &self {
-            AnyCssMediaTypeCondition::AnyCssMediaInParens(item) => Some(item),
+            AnyCssSubSelector::CssIdSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_and_condition(&self) -> Option<&CssMediaAndCondition> {
+    pub fn as_css_pseudo_class_selector(&self) -> Option<&CssPseudoClassSelector> {
         match &self {
-            AnyCssMediaTypeCondition::CssMediaAndCondition(item) => Some(item),
+            AnyCssSubSelector::CssPseudoClassSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_not_condition(&self) -> Option<&CssMediaNotCondition> {
+    pub fn as_css_pseudo_element_selector(&self) -> Option<&CssPseudoElementSelector> {
         match &self {
-            AnyCssMediaTypeCondition::CssMediaNotCondition(item) => Some(item),
+            AnyCssSubSelector::CssPseudoElementSelector(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssMediaTypeQuery {
-    CssMediaAndTypeQuery(CssMediaAndTypeQuery),
-    CssMediaTypeQuery(CssMediaTypeQuery),
+pub enum AnyCssSupportsAndCombinableCondition {
+    AnyCssSupportsInParens(AnyCssSupportsInParens),
+    CssSupportsAndCondition(CssSupportsAndCondition),
 }
-impl AnyCssMediaTypeQuery {
-    pub fn as_css_media_and_type_query(&self) -> Option<&CssMediaAndTypeQuery> {
+impl AnyCssSupportsAndCombinableCondition {
+    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
         match &self {
-            AnyCssMediaTypeQuery::CssMediaAndTypeQuery(item) => Some(item),
+            AnyCssSupportsAndCombinableCondition::AnyCssSupportsInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_media_type_query(&self) -> Option<&CssMediaTypeQuery> {
+    pub fn as_css_supports_and_condition(&self) -> Option<&CssSupportsAndCondition> {
         match &self {
-            AnyCssMediaTypeQuery::CssMediaTypeQuery(item) => Some(item),
+            AnyCssSupportsAndCombinableCondition::CssSupportsAndCondition(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssNamespacePrefix {
-    CssNamedNamespacePrefix(CssNamedNamespacePrefix),
-    CssUniversalNamespacePrefix(CssUniversalNamespacePrefix),
+pub enum AnyCssSupportsCondition {
+    AnyCssSupportsInParens(AnyCssSupportsInParens),
+    CssSupportsAndCondition(CssSupportsAndCondition),
+    CssSupportsNotCondition(CssSupportsNotCondition),
+    CssSupportsOrCondition(CssSupportsOrCondition),
 }
-impl AnyCssNamespacePrefix {
-    pub fn as_css_named_namespace_prefix(&self) -> Option<&CssNamedNamespacePrefix> {
+impl AnyCssSupportsCondition {
+    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
         match &self {
-            AnyCssNamespacePrefix::CssNamedNamespacePrefix(item) => Some(item),
+            AnyCssSupportsCondition::AnyCssSupportsInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_universal_namespace_prefix(&self) -> Option<&CssUniversalNamespacePrefix> {
+    pub fn as_css_supports_and_condition(&self) -> Option<&CssSupportsAndCondition> {
         match &self {
-            AnyCssNamespacePrefix::CssUniversalNamespacePrefix(item) => Some(item),
+            AnyCssSupportsCondition::CssSupportsAndCondition(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssNamespaceUrl {
-    CssString(CssString),
-    CssUrlFunction(CssUrlFunction),
-}
-impl AnyCssNamespaceUrl {
-    pub fn as_css_string(&self) -> Option<&CssString> {
+    pub fn as_css_supports_not_condition(&self) -> Option<&CssSupportsNotCondition> {
         match &self {
-            AnyCssNamespaceUrl::CssString(item) => Some(item),
+            AnyCssSupportsCondition::CssSupportsNotCondition(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_url_function(&self) -> Option<&CssUrlFunction> {
+    pub fn as_css_supports_or_condition(&self) -> Option<&CssSupportsOrCondition> {
         match &self {
-            AnyCssNamespaceUrl::CssUrlFunction(item) => Some(item),
+            AnyCssSupportsCondition::CssSupportsOrCondition(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPageAtRuleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssPageAtRuleBlock(CssPageAtRuleBlock),
+pub enum AnyCssSupportsInParens {
+    AnyCssValue(AnyCssValue),
+    CssFunction(CssFunction),
+    CssSupportsConditionInParens(CssSupportsConditionInParens),
+    CssSupportsFeatureDeclaration(CssSupportsFeatureDeclaration),
+    CssSupportsFeatureSelector(CssSupportsFeatureSelector),
 }
-impl AnyCssPageAtRuleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
+impl AnyCssSupportsInParens {
+    pub fn as_any_css_value(&self) -> Option<&AnyCssValue> {
         match &self {
-            AnyCssPageAtRuleBlock::CssBogusBlock(item) => Some(item),
+            AnyCssSupportsInParens::AnyCssValue(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_page_at_rule_block(&self) -> Option<&CssPageAtRuleBlock> {
+    pub fn as_css_function(&self) -> Option<&CssFunction> {
         match &self {
-            AnyCssPageAtRuleBlock::CssPageAtRuleBlock(item) => Some(item),
+            AnyCssSupportsInParens::CssFunction(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPageAtRuleItem {
-    CssAtRule(CssAtRule),
-    CssDeclarationWithSemicolon(CssDeclarationWithSemicolon),
-    CssMarginAtRule(CssMarginAtRule),
-}
-impl AnyCssPageAtRuleItem {
-    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
+    pub fn as_css_supports_condition_in_parens(&self) -> Option<&CssSupportsConditionInParens> {
         match &self {
-            AnyCssPageAtRuleItem::CssAtRule(item) => Some(item),
+            AnyCssSupportsInParens::CssSupportsConditionInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_declaration_with_semicolon(&self) -> Option<&CssDeclarationWithSemicolon> {
+    pub fn as_css_supports_feature_declaration(&self) -> Option<&CssSupportsFeatureDeclaration> {
         match &self {
-            AnyCssPageAtRuleItem::CssDeclarationWithSemicolon(item) => Some(item),
+            AnyCssSupportsInParens::CssSupportsFeatureDeclaration(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_margin_at_rule(&self) -> Option<&CssMarginAtRule> {
+    pub fn as_css_supports_feature_selector(&self) -> Option<&CssSupportsFeatureSelector> {
         match &self {
-            AnyCssPageAtRuleItem::CssMarginAtRule(item) => Some(item),
+            AnyCssSupportsInParens::CssSupportsFeatureSelector(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPageSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssPageSelector(CssPageSelector),
+pub enum AnyCssSupportsOrCombinableCondition {
+    AnyCssSupportsInParens(AnyCssSupportsInParens),
+    CssSupportsOrCondition(CssSupportsOrCondition),
 }
-impl AnyCssPageSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+impl AnyCssSupportsOrCombinableCondition {
+    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
         match &self {
-            AnyCssPageSelector::CssBogusSelector(item) => Some(item),
+            AnyCssSupportsOrCombinableCondition::AnyCssSupportsInParens(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_page_selector(&self) -> Option<&CssPageSelector> {
+    pub fn as_css_supports_or_condition(&self) -> Option<&CssSupportsOrCondition> {
         match &self {
-            AnyCssPageSelector::CssPageSelector(item) => Some(item),
+            AnyCssSupportsOrCombinableCondition::CssSupportsOrCondition(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPageSelectorPseudo {
-    CssBogusPageSelectorPseudo(CssBogusPageSelectorPseudo),
-    CssPageSelectorPseudo(CssPageSelectorPseudo),
+pub enum AnyCssUrlModifier {
+    CssBogusUrlModifier(CssBogusUrlModifier),
+    CssFunction(CssFunction),
+    CssIdentifier(CssIdentifier),
 }
-impl AnyCssPageSelectorPseudo {
-    pub fn as_css_bogus_page_selector_pseudo(&self) -> Option<&CssBogusPageSelectorPseudo> {
+impl AnyCssUrlModifier {
+    pub fn as_css_bogus_url_modifier(&self) -> Option<&CssBogusUrlModifier> {
         match &self {
-            AnyCssPageSelectorPseudo::CssBogusPageSelectorPseudo(item) => Some(item),
+            AnyCssUrlModifier::CssBogusUrlModifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_page_selector_pseudo(&self) -> Option<&CssPageSelectorPseudo> {
+    pub fn as_css_function(&self) -> Option<&CssFunction> {
         match &self {
-            AnyCssPageSelectorPseudo::CssPageSelectorPseudo(item) => Some(item),
+            AnyCssUrlModifier::CssFunction(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
+        match &self {
+            AnyCssUrlModifier::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssProperty {
-    CssBogusProperty(CssBogusProperty),
-    CssGenericProperty(CssGenericProperty),
+pub enum AnyCssUrlValue {
+    CssString(CssString),
+    CssUrlValueRaw(CssUrlValueRaw),
 }
-impl AnyCssProperty {
-    pub fn as_css_bogus_property(&self) -> Option<&CssBogusProperty> {
+impl AnyCssUrlValue {
+    pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssProperty::CssBogusProperty(item) => Some(item),
+            AnyCssUrlValue::CssString(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_generic_property(&self) -> Option<&CssGenericProperty> {
+    pub fn as_css_url_value_raw(&self) -> Option<&CssUrlValueRaw> {
         match &self {
-            AnyCssProperty::CssGenericProperty(item) => Some(item),
+            AnyCssUrlValue::CssUrlValueRaw(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPseudoClass {
-    CssBogusPseudoClass(CssBogusPseudoClass),
-    CssPseudoClassFunctionCompoundSelector(CssPseudoClassFunctionCompoundSelector),
-    CssPseudoClassFunctionCompoundSelectorList(CssPseudoClassFunctionCompoundSelectorList),
-    CssPseudoClassFunctionIdentifier(CssPseudoClassFunctionIdentifier),
-    CssPseudoClassFunctionNth(CssPseudoClassFunctionNth),
-    CssPseudoClassFunctionRelativeSelectorList(CssPseudoClassFunctionRelativeSelectorList),
-    CssPseudoClassFunctionSelector(CssPseudoClassFunctionSelector),
-    CssPseudoClassFunctionSelectorList(CssPseudoClassFunctionSelectorList),
-    CssPseudoClassFunctionValueList(CssPseudoClassFunctionValueList),
-    CssPseudoClassIdentifier(CssPseudoClassIdentifier),
+pub enum AnyCssValue {
+    AnyCssDimension(AnyCssDimension),
+    AnyCssFunction(AnyCssFunction),
+    CssColor(CssColor),
+    CssCustomIdentifier(CssCustomIdentifier),
+    CssDashedIdentifier(CssDashedIdentifier),
+    CssIdentifier(CssIdentifier),
+    CssNumber(CssNumber),
+    CssRatio(CssRatio),
+    CssString(CssString),
+    LessIdentifier(LessIdentifier),
+    LessParenthesizedValue(LessParenthesizedValue),
+    ScssIdentifier(ScssIdentifier),
 }
-impl AnyCssPseudoClass {
-    pub fn as_css_bogus_pseudo_class(&self) -> Option<&CssBogusPseudoClass> {
-        match &self {
-            AnyCssPseudoClass::CssBogusPseudoClass(item) => Some(item),
-            _ => None,
-        }
-    }
-    pub fn as_css_pseudo_class_function_compound_selector(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionCompoundSelector> {
+impl AnyCssValue {
+    pub fn as_any_css_dimension(&self) -> Option<&AnyCssDimension> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelector(item) => Some(item),
+            AnyCssValue::AnyCssDimension(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_compound_selector_list(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionCompoundSelectorList> {
+    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionCompoundSelectorList(item) => Some(item),
+            AnyCssValue::AnyCssFunction(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_identifier(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionIdentifier> {
+    pub fn as_css_color(&self) -> Option<&CssColor> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionIdentifier(item) => Some(item),
+            AnyCssValue::CssColor(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_nth(&self) -> Option<&CssPseudoClassFunctionNth> {
+    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionNth(item) => Some(item),
+            AnyCssValue::CssCustomIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_relative_selector_list(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionRelativeSelectorList> {
+    pub fn as_css_dashed_identifier(&self) -> Option<&CssDashedIdentifier> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionRelativeSelectorList(item) => Some(item),
+            AnyCssValue::CssDashedIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_selector(&self) -> Option<&CssPseudoClassFunctionSelector> {
+    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionSelector(item) => Some(item),
+            AnyCssValue::CssIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_selector_list(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionSelectorList> {
+    pub fn as_css_number(&self) -> Option<&CssNumber> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionSelectorList(item) => Some(item),
+            AnyCssValue::CssNumber(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_function_value_list(
-        &self,
-    ) -> Option<&CssPseudoClassFunctionValueList> {
+    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassFunctionValueList(item) => Some(item),
+            AnyCssValue::CssRatio(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_identifier(&self) -> Option<&CssPseudoClassIdentifier> {
+    pub fn as_css_string(&self) -> Option<&CssString> {
         match &self {
-            AnyCssPseudoClass::CssPseudoClassIdentifier(item) => Some(item),
+            AnyCssValue::CssString(item) => Some(item),
             _ => None,
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPseudoClassNth {
-    CssPseudoClassNth(CssPseudoClassNth),
-    CssPseudoClassNthIdentifier(CssPseudoClassNthIdentifier),
-    CssPseudoClassNthNumber(CssPseudoClassNthNumber),
-}
-impl AnyCssPseudoClassNth {
-    pub fn as_css_pseudo_class_nth(&self) -> Option<&CssPseudoClassNth> {
+    pub fn as_less_identifier(&self) -> Option<&LessIdentifier> {
         match &self {
-            AnyCssPseudoClassNth::CssPseudoClassNth(item) => Some(item),
+            AnyCssValue::LessIdentifier(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_nth_identifier(&self) -> Option<&CssPseudoClassNthIdentifier> {
+    pub fn as_less_parenthesized_value(&self) -> Option<&LessParenthesizedValue> {
         match &self {
-            AnyCssPseudoClassNth::CssPseudoClassNthIdentifier(item) => Some(item),
+            AnyCssValue::LessParenthesizedValue(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_nth_number(&self) -> Option<&CssPseudoClassNthNumber> {
+    pub fn as_scss_identifier(&self) -> Option<&ScssIdentifier> {
         match &self {
-            AnyCssPseudoClassNth::CssPseudoClassNthNumber(item) => Some(item),
+            AnyCssValue::ScssIdentifier(item) => Some(item),
             _ => None,
         }
     }
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPseudoClassNthSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssPseudoClassNthSelector(CssPseudoClassNthSelector),
+pub enum AnyLessMixinName {
+    CssClassSelector(CssClassSelector),
+    CssIdSelector(CssIdSelector),
 }
-impl AnyCssPseudoClassNthSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
+impl AnyLessMixinName {
+    pub fn as_css_class_selector(&self) -> Option<&CssClassSelector> {
         match &self {
-            AnyCssPseudoClassNthSelector::CssBogusSelector(item) => Some(item),
+            AnyLessMixinName::CssClassSelector(item) => Some(item),
             _ => None,
         }
     }
-    pub fn as_css_pseudo_class_nth_selector(&self) -> Option<&CssPseudoClassNthSelector> {
+    pub fn as_css_id_selector(&self) -> Option<&CssIdSelector> {
         match &self {
-            AnyCssPseudoClassNthSelector::CssPseudoClassNthSelector(item) => Some(item),
+            AnyLessMixinName::CssIdSelector(item) => Some(item),
             _ => None,
         }
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPseudoElement {
-    CssBogusPseudoElement(CssBogusPseudoElement),
-    CssPseudoElementFunctionIdentifier(CssPseudoElementFunctionIdentifier),
-    CssPseudoElementFunctionSelector(CssPseudoElementFunctionSelector),
-    CssPseudoElementIdentifier(CssPseudoElementIdentifier),
-}
-impl AnyCssPseudoElement {
-    pub fn as_css_bogus_pseudo_element(&self) -> Option<&CssBogusPseudoElement> {
-        match &self {
-            AnyCssPseudoElement::CssBogusPseudoElement(item) => Some(item),
-            _ => None,
+impl AstNode for CssAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_AT_RULE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_pseudo_element_function_identifier(
-        &self,
-    ) -> Option<&CssPseudoElementFunctionIdentifier> {
-        match &self {
-            AnyCssPseudoElement::CssPseudoElementFunctionIdentifier(item) => Some(item),
-            _ => None,
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAtRule")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field("rule", &support::DebugSyntaxResult(self.rule()))
+            .finish()
+    }
+}
+impl From<CssAtRule> for SyntaxNode {
+    fn from(n: CssAtRule) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAtRule> for SyntaxElement {
+    fn from(n: CssAtRule) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssAttributeMatcher {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MATCHER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_ATTRIBUTE_MATCHER
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_pseudo_element_function_selector(
-        &self,
-    ) -> Option<&CssPseudoElementFunctionSelector> {
-        match &self {
-            AnyCssPseudoElement::CssPseudoElementFunctionSelector(item) => Some(item),
-            _ => None,
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAttributeMatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAttributeMatcher")
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("modifier", &support::DebugOptionalElement(self.modifier()))
+            .finish()
+    }
+}
+impl From<CssAttributeMatcher> for SyntaxNode {
+    fn from(n: CssAttributeMatcher) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAttributeMatcher> for SyntaxElement {
+    fn from(n: CssAttributeMatcher) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssAttributeMatcherValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MATCHER_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_ATTRIBUTE_MATCHER_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_pseudo_element_identifier(&self) -> Option<&CssPseudoElementIdentifier> {
-        match &self {
-            AnyCssPseudoElement::CssPseudoElementIdentifier(item) => Some(item),
-            _ => None,
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAttributeMatcherValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAttributeMatcherValue")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .finish()
+    }
+}
+impl From<CssAttributeMatcherValue> for SyntaxNode {
+    fn from(n: CssAttributeMatcherValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAttributeMatcherValue> for SyntaxElement {
+    fn from(n: CssAttributeMatcherValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssAttributeName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_NAME as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_ATTRIBUTE_NAME
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssPseudoValue {
-    CssIdentifier(CssIdentifier),
-    CssString(CssString),
+impl std::fmt::Debug for CssAttributeName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAttributeName")
+            .field(
+                "namespace",
+                &support::DebugOptionalElement(self.namespace()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .finish()
+    }
 }
-impl AnyCssPseudoValue {
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssPseudoValue::CssIdentifier(item) => Some(item),
-            _ => None,
+impl From<CssAttributeName> for SyntaxNode {
+    fn from(n: CssAttributeName) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssAttributeName> for SyntaxElement {
+    fn from(n: CssAttributeName) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssAttributeSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_ATTRIBUTE_SELECTOR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssPseudoValue::CssString(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssAttributeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssAttributeSelector")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("matcher", &support::DebugOptionalElement(self.matcher()))
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .finish()
+    }
+}
+impl From<CssAttributeSelector> for SyntaxNode {
+    fn from(n: CssAttributeSelector) -> SyntaxNode {
+        n.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssQueryFeature {
-    CssQueryFeatureBoolean(CssQueryFeatureBoolean),
-    CssQueryFeaturePlain(CssQueryFeaturePlain),
-    CssQueryFeatureRange(CssQueryFeatureRange),
-    CssQueryFeatureRangeInterval(CssQueryFeatureRangeInterval),
-    CssQueryFeatureReverseRange(CssQueryFeatureReverseRange),
+impl From<CssAttributeSelector> for SyntaxElement {
+    fn from(n: CssAttributeSelector) -> SyntaxElement {
+        n.syntax.into()
+    }
 }
-impl AnyCssQueryFeature {
-    pub fn as_css_query_feature_boolean(&self) -> Option<&CssQueryFeatureBoolean> {
-        match &self {
-            AnyCssQueryFeature::CssQueryFeatureBoolean(item) => Some(item),
-            _ => None,
-        }
+impl AstNode for CssBinaryExpression {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BINARY_EXPRESSION as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_BINARY_EXPRESSION
     }
-    pub fn as_css_query_feature_plain(&self) -> Option<&CssQueryFeaturePlain> {
-        match &self {
-            AnyCssQueryFeature::CssQueryFeaturePlain(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_query_feature_range(&self) -> Option<&CssQueryFeatureRange> {
-        match &self {
-            AnyCssQueryFeature::CssQueryFeatureRange(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_query_feature_range_interval(&self) -> Option<&CssQueryFeatureRangeInterval> {
-        match &self {
-            AnyCssQueryFeature::CssQueryFeatureRangeInterval(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
-    pub fn as_css_query_feature_reverse_range(&self) -> Option<&CssQueryFeatureReverseRange> {
-        match &self {
-            AnyCssQueryFeature::CssQueryFeatureReverseRange(item) => Some(item),
-            _ => None,
-        }
+}
+impl std::fmt::Debug for CssBinaryExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssBinaryExpression")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field(
+                "operator_token",
+                &support::DebugSyntaxResult(self.operator_token()),
+            )
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssQueryFeatureValue {
-    AnyCssDimension(AnyCssDimension),
-    AnyCssFunction(AnyCssFunction),
-    CssIdentifier(CssIdentifier),
-    CssNumber(CssNumber),
-    CssRatio(CssRatio),
+impl From<CssBinaryExpression> for SyntaxNode {
+    fn from(n: CssBinaryExpression) -> SyntaxNode {
+        n.syntax
+    }
 }
-impl AnyCssQueryFeatureValue {
-    pub fn as_any_css_dimension(&self) -> Option<&AnyCssDimension> {
-        match &self {
-            AnyCssQueryFeatureValue::AnyCssDimension(item) => Some(item),
-            _ => None,
-        }
+impl From<CssBinaryExpression> for SyntaxElement {
+    fn from(n: CssBinaryExpression) -> SyntaxElement {
+        n.syntax.into()
     }
-    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
-        match &self {
-            AnyCssQueryFeatureValue::AnyCssFunction(item) => Some(item),
-            _ => None,
-        }
+}
+impl AstNode for CssCharsetAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CHARSET_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CHARSET_AT_RULE
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssQueryFeatureValue::CssIdentifier(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_number(&self) -> Option<&CssNumber> {
-        match &self {
-            AnyCssQueryFeatureValue::CssNumber(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
-        match &self {
-            AnyCssQueryFeatureValue::CssRatio(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssRelativeSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssRelativeSelector(CssRelativeSelector),
-}
-impl AnyCssRelativeSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
-        match &self {
-            AnyCssRelativeSelector::CssBogusSelector(item) => Some(item),
-            _ => None,
-        }
+impl std::fmt::Debug for CssCharsetAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssCharsetAtRule")
+            .field(
+                "charset_token",
+                &support::DebugSyntaxResult(self.charset_token()),
+            )
+            .field("encoding", &support::DebugSyntaxResult(self.encoding()))
+            .field(
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
+            )
+            .finish()
     }
-    pub fn as_css_relative_selector(&self) -> Option<&CssRelativeSelector> {
-        match &self {
-            AnyCssRelativeSelector::CssRelativeSelector(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssCharsetAtRule> for SyntaxNode {
+    fn from(n: CssCharsetAtRule) -> SyntaxNode {
+        n.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssRule {
-    CssAtRule(CssAtRule),
-    CssBogusRule(CssBogusRule),
-    CssNestedQualifiedRule(CssNestedQualifiedRule),
-    CssQualifiedRule(CssQualifiedRule),
+impl From<CssCharsetAtRule> for SyntaxElement {
+    fn from(n: CssCharsetAtRule) -> SyntaxElement {
+        n.syntax.into()
+    }
 }
-impl AnyCssRule {
-    pub fn as_css_at_rule(&self) -> Option<&CssAtRule> {
-        match &self {
-            AnyCssRule::CssAtRule(item) => Some(item),
-            _ => None,
-        }
+impl AstNode for CssClassSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CLASS_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CLASS_SELECTOR
     }
-    pub fn as_css_bogus_rule(&self) -> Option<&CssBogusRule> {
-        match &self {
-            AnyCssRule::CssBogusRule(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_nested_qualified_rule(&self) -> Option<&CssNestedQualifiedRule> {
-        match &self {
-            AnyCssRule::CssNestedQualifiedRule(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_qualified_rule(&self) -> Option<&CssQualifiedRule> {
-        match &self {
-            AnyCssRule::CssQualifiedRule(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssRuleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssRuleBlock(CssRuleBlock),
+impl std::fmt::Debug for CssClassSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssClassSelector")
+            .field("dot_token", &support::DebugSyntaxResult(self.dot_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .finish()
+    }
 }
-impl AnyCssRuleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssRuleBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
-        }
+impl From<CssClassSelector> for SyntaxNode {
+    fn from(n: CssClassSelector) -> SyntaxNode {
+        n.syntax
     }
-    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
-        match &self {
-            AnyCssRuleBlock::CssRuleBlock(item) => Some(item),
-            _ => None,
+}
+impl From<CssClassSelector> for SyntaxElement {
+    fn from(n: CssClassSelector) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssColor {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COLOR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssScopeRange {
-    CssBogusScopeRange(CssBogusScopeRange),
-    CssScopeRangeEnd(CssScopeRangeEnd),
-    CssScopeRangeInterval(CssScopeRangeInterval),
-    CssScopeRangeStart(CssScopeRangeStart),
-}
-impl AnyCssScopeRange {
-    pub fn as_css_bogus_scope_range(&self) -> Option<&CssBogusScopeRange> {
-        match &self {
-            AnyCssScopeRange::CssBogusScopeRange(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_scope_range_end(&self) -> Option<&CssScopeRangeEnd> {
-        match &self {
-            AnyCssScopeRange::CssScopeRangeEnd(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
-    pub fn as_css_scope_range_interval(&self) -> Option<&CssScopeRangeInterval> {
-        match &self {
-            AnyCssScopeRange::CssScopeRangeInterval(item) => Some(item),
-            _ => None,
-        }
+}
+impl std::fmt::Debug for CssColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssColor")
+            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
     }
-    pub fn as_css_scope_range_start(&self) -> Option<&CssScopeRangeStart> {
-        match &self {
-            AnyCssScopeRange::CssScopeRangeStart(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssColor> for SyntaxNode {
+    fn from(n: CssColor) -> SyntaxNode {
+        n.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssSelector {
-    CssBogusSelector(CssBogusSelector),
-    CssComplexSelector(CssComplexSelector),
-    CssCompoundSelector(CssCompoundSelector),
+impl From<CssColor> for SyntaxElement {
+    fn from(n: CssColor) -> SyntaxElement {
+        n.syntax.into()
+    }
 }
-impl AnyCssSelector {
-    pub fn as_css_bogus_selector(&self) -> Option<&CssBogusSelector> {
-        match &self {
-            AnyCssSelector::CssBogusSelector(item) => Some(item),
-            _ => None,
-        }
+impl AstNode for CssColorProfileAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR_PROFILE_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COLOR_PROFILE_AT_RULE
     }
-    pub fn as_css_complex_selector(&self) -> Option<&CssComplexSelector> {
-        match &self {
-            AnyCssSelector::CssComplexSelector(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_compound_selector(&self) -> Option<&CssCompoundSelector> {
-        match &self {
-            AnyCssSelector::CssCompoundSelector(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssSimpleSelector {
-    CssTypeSelector(CssTypeSelector),
-    CssUniversalSelector(CssUniversalSelector),
-}
-impl AnyCssSimpleSelector {
-    pub fn as_css_type_selector(&self) -> Option<&CssTypeSelector> {
-        match &self {
-            AnyCssSimpleSelector::CssTypeSelector(item) => Some(item),
-            _ => None,
-        }
+impl std::fmt::Debug for CssColorProfileAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssColorProfileAtRule")
+            .field(
+                "color_profile_token",
+                &support::DebugSyntaxResult(self.color_profile_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
+            .finish()
     }
-    pub fn as_css_universal_selector(&self) -> Option<&CssUniversalSelector> {
-        match &self {
-            AnyCssSimpleSelector::CssUniversalSelector(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssColorProfileAtRule> for SyntaxNode {
+    fn from(n: CssColorProfileAtRule) -> SyntaxNode {
+        n.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssStartingStyleBlock {
-    CssBogusBlock(CssBogusBlock),
-    CssDeclarationBlock(CssDeclarationBlock),
-    CssRuleBlock(CssRuleBlock),
+impl From<CssColorProfileAtRule> for SyntaxElement {
+    fn from(n: CssColorProfileAtRule) -> SyntaxElement {
+        n.syntax.into()
+    }
 }
-impl AnyCssStartingStyleBlock {
-    pub fn as_css_bogus_block(&self) -> Option<&CssBogusBlock> {
-        match &self {
-            AnyCssStartingStyleBlock::CssBogusBlock(item) => Some(item),
-            _ => None,
-        }
+impl AstNode for CssComplexSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPLEX_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COMPLEX_SELECTOR
     }
-    pub fn as_css_declaration_block(&self) -> Option<&CssDeclarationBlock> {
-        match &self {
-            AnyCssStartingStyleBlock::CssDeclarationBlock(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_rule_block(&self) -> Option<&CssRuleBlock> {
-        match &self {
-            AnyCssStartingStyleBlock::CssRuleBlock(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssSubSelector {
-    CssAttributeSelector(CssAttributeSelector),
-    CssBogusSubSelector(CssBogusSubSelector),
-    CssClassSelector(CssClassSelector),
-    CssIdSelector(CssIdSelector),
-    CssPseudoClassSelector(CssPseudoClassSelector),
-    CssPseudoElementSelector(CssPseudoElementSelector),
+impl std::fmt::Debug for CssComplexSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssComplexSelector")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("combinator", &support::DebugSyntaxResult(self.combinator()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
 }
-impl AnyCssSubSelector {
-    pub fn as_css_attribute_selector(&self) -> Option<&CssAttributeSelector> {
-        match &self {
-            AnyCssSubSelector::CssAttributeSelector(item) => Some(item),
-            _ => None,
-        }
+impl From<CssComplexSelector> for SyntaxNode {
+    fn from(n: CssComplexSelector) -> SyntaxNode {
+        n.syntax
     }
-    pub fn as_css_bogus_sub_selector(&self) -> Option<&CssBogusSubSelector> {
-        match &self {
-            AnyCssSubSelector::CssBogusSubSelector(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssComplexSelector> for SyntaxElement {
+    fn from(n: CssComplexSelector) -> SyntaxElement {
+        n.syntax.into()
     }
-    pub fn as_css_class_selector(&self) -> Option<&CssClassSelector> {
-        match &self {
-            AnyCssSubSelector::CssClassSelector(item) => Some(item),
-            _ => None,
-        }
+}
+impl AstNode for CssCompoundSelector {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOUND_SELECTOR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_COMPOUND_SELECTOR
     }
-    pub fn as_css_id_selector(&self) -> Option<&CssIdSelector> {
-        match &self {
-            AnyCssSubSelector::CssIdSelector(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_pseudo_class_selector(&self) -> Option<&CssPseudoClassSelector> {
-        match &self {
-            AnyCssSubSelector::CssPseudoClassSelector(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_pseudo_element_selector(&self) -> Option<&CssPseudoElementSelector> {
-        match &self {
-            AnyCssSubSelector::CssPseudoElementSelector(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssSupportsAndCombinableCondition {
-    AnyCssSupportsInParens(AnyCssSupportsInParens),
-    CssSupportsAndCondition(CssSupportsAndCondition),
-}
-impl AnyCssSupportsAndCombinableCondition {
-    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
-        match &self {
-            AnyCssSupportsAndCombinableCondition::AnyCssSupportsInParens(item) => Some(item),
-            _ => None,
-        }
+impl std::fmt::Debug for CssCompoundSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssCompoundSelector")
+            .field(
+                "nesting_selector_token",
+                &support::DebugOptionalElement(self.nesting_selector_token()),
+            )
+            .field(
+                "simple_selector",
+                &support::DebugOptionalElement(self.simple_selector()),
+            )
+            .field("sub_selectors", &self.sub_selectors())
+            .finish()
     }
-    pub fn as_css_supports_and_condition(&self) -> Option<&CssSupportsAndCondition> {
-        match &self {
-            AnyCssSupportsAndCombinableCondition::CssSupportsAndCondition(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssCompoundSelector> for SyntaxNode {
+    fn from(n: CssCompoundSelector) -> SyntaxNode {
+        n.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssSupportsCondition {
-    AnyCssSupportsInParens(AnyCssSupportsInParens),
-    CssSupportsAndCondition(CssSupportsAndCondition),
-    CssSupportsNotCondition(CssSupportsNotCondition),
-    CssSupportsOrCondition(CssSupportsOrCondition),
+impl From<CssCompoundSelector> for SyntaxElement {
+    fn from(n: CssCompoundSelector) -> SyntaxElement {
+        n.syntax.into()
+    }
 }
-impl AnyCssSupportsCondition {
-    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
-        match &self {
-            AnyCssSupportsCondition::AnyCssSupportsInParens(item) => Some(item),
-            _ => None,
-        }
+impl AstNode for CssContainerAndQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_AND_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_AND_QUERY
     }
-    pub fn as_css_supports_and_condition(&self) -> Option<&CssSupportsAndCondition> {
-        match &self {
-            AnyCssSupportsCondition::CssSupportsAndCondition(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_supports_not_condition(&self) -> Option<&CssSupportsNotCondition> {
-        match &self {
-            AnyCssSupportsCondition::CssSupportsNotCondition(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_supports_or_condition(&self) -> Option<&CssSupportsOrCondition> {
-        match &self {
-            AnyCssSupportsCondition::CssSupportsOrCondition(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssSupportsInParens {
-    AnyCssValue(AnyCssValue),
-    CssFunction(CssFunction),
-    CssSupportsConditionInParens(CssSupportsConditionInParens),
-    CssSupportsFeatureDeclaration(CssSupportsFeatureDeclaration),
-    CssSupportsFeatureSelector(CssSupportsFeatureSelector),
+impl std::fmt::Debug for CssContainerAndQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerAndQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
 }
-impl AnyCssSupportsInParens {
-    pub fn as_any_css_value(&self) -> Option<&AnyCssValue> {
-        match &self {
-            AnyCssSupportsInParens::AnyCssValue(item) => Some(item),
-            _ => None,
-        }
+impl From<CssContainerAndQuery> for SyntaxNode {
+    fn from(n: CssContainerAndQuery) -> SyntaxNode {
+        n.syntax
     }
-    pub fn as_css_function(&self) -> Option<&CssFunction> {
-        match &self {
-            AnyCssSupportsInParens::CssFunction(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssContainerAndQuery> for SyntaxElement {
+    fn from(n: CssContainerAndQuery) -> SyntaxElement {
+        n.syntax.into()
     }
-    pub fn as_css_supports_condition_in_parens(&self) -> Option<&CssSupportsConditionInParens> {
-        match &self {
-            AnyCssSupportsInParens::CssSupportsConditionInParens(item) => Some(item),
-            _ => None,
-        }
+}
+impl AstNode for CssContainerAtRule {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_AT_RULE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_AT_RULE
     }
-    pub fn as_css_supports_feature_declaration(&self) -> Option<&CssSupportsFeatureDeclaration> {
-        match &self {
-            AnyCssSupportsInParens::CssSupportsFeatureDeclaration(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_supports_feature_selector(&self) -> Option<&CssSupportsFeatureSelector> {
-        match &self {
-            AnyCssSupportsInParens::CssSupportsFeatureSelector(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssSupportsOrCombinableCondition {
-    AnyCssSupportsInParens(AnyCssSupportsInParens),
-    CssSupportsOrCondition(CssSupportsOrCondition),
-}
-impl AnyCssSupportsOrCombinableCondition {
-    pub fn as_any_css_supports_in_parens(&self) -> Option<&AnyCssSupportsInParens> {
-        match &self {
-            AnyCssSupportsOrCombinableCondition::AnyCssSupportsInParens(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
-    pub fn as_css_supports_or_condition(&self) -> Option<&CssSupportsOrCondition> {
-        match &self {
-            AnyCssSupportsOrCombinableCondition::CssSupportsOrCondition(item) => Some(item),
-            _ => None,
-        }
+}
+impl std::fmt::Debug for CssContainerAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerAtRule")
+            .field(
+                "container_token",
+                &support::DebugSyntaxResult(self.container_token()),
+            )
+            .field("name", &support::DebugOptionalElement(self.name()))
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
+            .finish()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssUrlModifier {
-    CssBogusUrlModifier(CssBogusUrlModifier),
-    CssFunction(CssFunction),
-    CssIdentifier(CssIdentifier),
+impl From<CssContainerAtRule> for SyntaxNode {
+    fn from(n: CssContainerAtRule) -> SyntaxNode {
+        n.syntax
+    }
 }
-impl AnyCssUrlModifier {
-    pub fn as_css_bogus_url_modifier(&self) -> Option<&CssBogusUrlModifier> {
-        match &self {
-            AnyCssUrlModifier::CssBogusUrlModifier(item) => Some(item),
-            _ => None,
-        }
+impl From<CssContainerAtRule> for SyntaxElement {
+    fn from(n: CssContainerAtRule) -> SyntaxElement {
+        n.syntax.into()
     }
-    pub fn as_css_function(&self) -> Option<&CssFunction> {
-        match &self {
-            AnyCssUrlModifier::CssFunction(item) => Some(item),
-            _ => None,
-        }
+}
+impl AstNode for CssContainerNotQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_NOT_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_NOT_QUERY
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssUrlModifier::CssIdentifier(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-}
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssUrlValue {
-    CssString(CssString),
-    CssUrlValueRaw(CssUrlValueRaw),
-}
-impl AnyCssUrlValue {
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssUrlValue::CssString(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_url_value_raw(&self) -> Option<&CssUrlValueRaw> {
-        match &self {
-            AnyCssUrlValue::CssUrlValueRaw(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyCssValue {
-    AnyCssDimension(AnyCssDimension),
-    AnyCssFunction(AnyCssFunction),
-    CssColor(CssColor),
-    CssCustomIdentifier(CssCustomIdentifier),
-    CssDashedIdentifier(CssDashedIdentifier),
-    CssIdentifier(CssIdentifier),
-    CssNumber(CssNumber),
-    CssRatio(CssRatio),
-    CssString(CssString),
+impl std::fmt::Debug for CssContainerNotQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerNotQuery")
+            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .finish()
+    }
 }
-impl AnyCssValue {
-    pub fn as_any_css_dimension(&self) -> Option<&AnyCssDimension> {
-        match &self {
-            AnyCssValue::AnyCssDimension(item) => Some(item),
-            _ => None,
-        }
+impl From<CssContainerNotQuery> for SyntaxNode {
+    fn from(n: CssContainerNotQuery) -> SyntaxNode {
+        n.syntax
     }
-    pub fn as_any_css_function(&self) -> Option<&AnyCssFunction> {
-        match &self {
-            AnyCssValue::AnyCssFunction(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssContainerNotQuery> for SyntaxElement {
+    fn from(n: CssContainerNotQuery) -> SyntaxElement {
+        n.syntax.into()
     }
-    pub fn as_css_color(&self) -> Option<&CssColor> {
-        match &self {
-            AnyCssValue::CssColor(item) => Some(item),
-            _ => None,
-        }
+}
+impl AstNode for CssContainerOrQuery {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_OR_QUERY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_OR_QUERY
     }
-    pub fn as_css_custom_identifier(&self) -> Option<&CssCustomIdentifier> {
-        match &self {
-            AnyCssValue::CssCustomIdentifier(item) => Some(item),
-            _ => None,
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
         }
     }
-    pub fn as_css_dashed_identifier(&self) -> Option<&CssDashedIdentifier> {
-        match &self {
-            AnyCssValue::CssDashedIdentifier(item) => Some(item),
-            _ => None,
-        }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
     }
-    pub fn as_css_identifier(&self) -> Option<&CssIdentifier> {
-        match &self {
-            AnyCssValue::CssIdentifier(item) => Some(item),
-            _ => None,
-        }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
     }
-    pub fn as_css_number(&self) -> Option<&CssNumber> {
-        match &self {
-            AnyCssValue::CssNumber(item) => Some(item),
-            _ => None,
-        }
+}
+impl std::fmt::Debug for CssContainerOrQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerOrQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
     }
-    pub fn as_css_ratio(&self) -> Option<&CssRatio> {
-        match &self {
-            AnyCssValue::CssRatio(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssContainerOrQuery> for SyntaxNode {
+    fn from(n: CssContainerOrQuery) -> SyntaxNode {
+        n.syntax
     }
-    pub fn as_css_string(&self) -> Option<&CssString> {
-        match &self {
-            AnyCssValue::CssString(item) => Some(item),
-            _ => None,
-        }
+}
+impl From<CssContainerOrQuery> for SyntaxElement {
+    fn from(n: CssContainerOrQuery) -> SyntaxElement {
+        n.syntax.into()
     }
 }
-impl AstNode for CssAtRule {
+impl AstNode for CssContainerQueryInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_QUERY_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_AT_RULE
+        kind == CSS_CONTAINER_QUERY_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -7856,30 +9451,37 @@ impl AstNode for CssAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAtRule {
+impl std::fmt::Debug for CssContainerQueryInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAtRule")
-            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
-            .field("rule", &support::DebugSyntaxResult(self.rule()))
+        f.debug_struct("CssContainerQueryInParens")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssAtRule> for SyntaxNode {
-    fn from(n: CssAtRule) -> SyntaxNode {
+impl From<CssContainerQueryInParens> for SyntaxNode {
+    fn from(n: CssContainerQueryInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAtRule> for SyntaxElement {
-    fn from(n: CssAtRule) -> SyntaxElement {
+impl From<CssContainerQueryInParens> for SyntaxElement {
+    fn from(n: CssContainerQueryInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssAttributeMatcher {
+impl AstNode for CssContainerSizeFeatureInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MATCHER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_SIZE_FEATURE_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ATTRIBUTE_MATCHER
+        kind == CSS_CONTAINER_SIZE_FEATURE_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -7895,31 +9497,37 @@ impl AstNode for CssAttributeMatcher {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAttributeMatcher {
+impl std::fmt::Debug for CssContainerSizeFeatureInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeMatcher")
-            .field("operator", &support::DebugSyntaxResult(self.operator()))
-            .field("value", &support::DebugSyntaxResult(self.value()))
-            .field("modifier", &support::DebugOptionalElement(self.modifier()))
+        f.debug_struct("CssContainerSizeFeatureInParens")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("feature", &support::DebugSyntaxResult(self.feature()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssAttributeMatcher> for SyntaxNode {
-    fn from(n: CssAttributeMatcher) -> SyntaxNode {
+impl From<CssContainerSizeFeatureInParens> for SyntaxNode {
+    fn from(n: CssContainerSizeFeatureInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAttributeMatcher> for SyntaxElement {
-    fn from(n: CssAttributeMatcher) -> SyntaxElement {
+impl From<CssContainerSizeFeatureInParens> for SyntaxElement {
+    fn from(n: CssContainerSizeFeatureInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssAttributeMatcherValue {
+impl AstNode for CssContainerStyleAndQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_MATCHER_VALUE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_AND_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ATTRIBUTE_MATCHER_VALUE
+        kind == CSS_CONTAINER_STYLE_AND_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -7935,29 +9543,31 @@ impl AstNode for CssAttributeMatcherValue {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAttributeMatcherValue {
+impl std::fmt::Debug for CssContainerStyleAndQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeMatcherValue")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssContainerStyleAndQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssAttributeMatcherValue> for SyntaxNode {
-    fn from(n: CssAttributeMatcherValue) -> SyntaxNode {
+impl From<CssContainerStyleAndQuery> for SyntaxNode {
+    fn from(n: CssContainerStyleAndQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAttributeMatcherValue> for SyntaxElement {
-    fn from(n: CssAttributeMatcherValue) -> SyntaxElement {
+impl From<CssContainerStyleAndQuery> for SyntaxElement {
+    fn from(n: CssContainerStyleAndQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssAttributeName {
+impl AstNode for CssContainerStyleInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ATTRIBUTE_NAME
+        kind == CSS_CONTAINER_STYLE_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -7973,33 +9583,37 @@ impl AstNode for CssAttributeName {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAttributeName {
+impl std::fmt::Debug for CssContainerStyleInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeName")
+        f.debug_struct("CssContainerStyleInParens")
             .field(
-                "namespace",
-                &support::DebugOptionalElement(self.namespace()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssAttributeName> for SyntaxNode {
-    fn from(n: CssAttributeName) -> SyntaxNode {
+impl From<CssContainerStyleInParens> for SyntaxNode {
+    fn from(n: CssContainerStyleInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAttributeName> for SyntaxElement {
-    fn from(n: CssAttributeName) -> SyntaxElement {
+impl From<CssContainerStyleInParens> for SyntaxElement {
+    fn from(n: CssContainerStyleInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssAttributeSelector {
+impl AstNode for CssContainerStyleNotQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ATTRIBUTE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_NOT_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ATTRIBUTE_SELECTOR
+        kind == CSS_CONTAINER_STYLE_NOT_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8015,38 +9629,30 @@ impl AstNode for CssAttributeSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssAttributeSelector {
+impl std::fmt::Debug for CssContainerStyleNotQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssAttributeSelector")
-            .field(
-                "l_brack_token",
-                &support::DebugSyntaxResult(self.l_brack_token()),
-            )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("matcher", &support::DebugOptionalElement(self.matcher()))
-            .field(
-                "r_brack_token",
-                &support::DebugSyntaxResult(self.r_brack_token()),
-            )
+        f.debug_struct("CssContainerStyleNotQuery")
+            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
+            .field("query", &support::DebugSyntaxResult(self.query()))
             .finish()
     }
 }
-impl From<CssAttributeSelector> for SyntaxNode {
-    fn from(n: CssAttributeSelector) -> SyntaxNode {
+impl From<CssContainerStyleNotQuery> for SyntaxNode {
+    fn from(n: CssContainerStyleNotQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssAttributeSelector> for SyntaxElement {
-    fn from(n: CssAttributeSelector) -> SyntaxElement {
+impl From<CssContainerStyleNotQuery> for SyntaxElement {
+    fn from(n: CssContainerStyleNotQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssBinaryExpression {
+impl AstNode for CssContainerStyleOrQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_BINARY_EXPRESSION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_OR_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_BINARY_EXPRESSION
+        kind == CSS_CONTAINER_STYLE_OR_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8062,34 +9668,81 @@ impl AstNode for CssBinaryExpression {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssBinaryExpression {
+impl std::fmt::Debug for CssContainerStyleOrQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssBinaryExpression")
+        f.debug_struct("CssContainerStyleOrQuery")
             .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
+            .finish()
+    }
+}
+impl From<CssContainerStyleOrQuery> for SyntaxNode {
+    fn from(n: CssContainerStyleOrQuery) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<CssContainerStyleOrQuery> for SyntaxElement {
+    fn from(n: CssContainerStyleOrQuery) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for CssContainerStyleQueryInParens {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_QUERY_IN_PARENS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == CSS_CONTAINER_STYLE_QUERY_IN_PARENS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for CssContainerStyleQueryInParens {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssContainerStyleQueryInParens")
             .field(
-                "operator_token",
-                &support::DebugSyntaxResult(self.operator_token()),
+                "style_token",
+                &support::DebugSyntaxResult(self.style_token()),
+            )
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssBinaryExpression> for SyntaxNode {
-    fn from(n: CssBinaryExpression) -> SyntaxNode {
+impl From<CssContainerStyleQueryInParens> for SyntaxNode {
+    fn from(n: CssContainerStyleQueryInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssBinaryExpression> for SyntaxElement {
-    fn from(n: CssBinaryExpression) -> SyntaxElement {
+impl From<CssContainerStyleQueryInParens> for SyntaxElement {
+    fn from(n: CssContainerStyleQueryInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssCharsetAtRule {
+impl AstNode for CssCounterStyleAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CHARSET_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COUNTER_STYLE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CHARSET_AT_RULE
+        kind == CSS_COUNTER_STYLE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8105,37 +9758,34 @@ impl AstNode for CssCharsetAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssCharsetAtRule {
+impl std::fmt::Debug for CssCounterStyleAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCharsetAtRule")
-            .field(
-                "charset_token",
-                &support::DebugSyntaxResult(self.charset_token()),
-            )
-            .field("encoding", &support::DebugSyntaxResult(self.encoding()))
+        f.debug_struct("CssCounterStyleAtRule")
             .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
+                "counter_style_token",
+                &support::DebugSyntaxResult(self.counter_style_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssCharsetAtRule> for SyntaxNode {
-    fn from(n: CssCharsetAtRule) -> SyntaxNode {
+impl From<CssCounterStyleAtRule> for SyntaxNode {
+    fn from(n: CssCounterStyleAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssCharsetAtRule> for SyntaxElement {
-    fn from(n: CssCharsetAtRule) -> SyntaxElement {
+impl From<CssCounterStyleAtRule> for SyntaxElement {
+    fn from(n: CssCounterStyleAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssClassSelector {
+impl AstNode for CssCustomIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CLASS_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CUSTOM_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CLASS_SELECTOR
+        kind == CSS_CUSTOM_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8151,30 +9801,32 @@ impl AstNode for CssClassSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssClassSelector {
+impl std::fmt::Debug for CssCustomIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssClassSelector")
-            .field("dot_token", &support::DebugSyntaxResult(self.dot_token()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssCustomIdentifier")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssClassSelector> for SyntaxNode {
-    fn from(n: CssClassSelector) -> SyntaxNode {
+impl From<CssCustomIdentifier> for SyntaxNode {
+    fn from(n: CssCustomIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssClassSelector> for SyntaxElement {
-    fn from(n: CssClassSelector) -> SyntaxElement {
+impl From<CssCustomIdentifier> for SyntaxElement {
+    fn from(n: CssCustomIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssColor {
+impl AstNode for CssDashedIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DASHED_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COLOR
+        kind == CSS_DASHED_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8190,10 +9842,9 @@ impl AstNode for CssColor {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssColor {
+impl std::fmt::Debug for CssDashedIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssColor")
-            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+        f.debug_struct("CssDashedIdentifier")
             .field(
                 "value_token",
                 &support::DebugSyntaxResult(self.value_token()),
@@ -8201,22 +9852,22 @@ impl std::fmt::Debug for CssColor {
             .finish()
     }
 }
-impl From<CssColor> for SyntaxNode {
-    fn from(n: CssColor) -> SyntaxNode {
+impl From<CssDashedIdentifier> for SyntaxNode {
+    fn from(n: CssDashedIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssColor> for SyntaxElement {
-    fn from(n: CssColor) -> SyntaxElement {
+impl From<CssDashedIdentifier> for SyntaxElement {
+    fn from(n: CssDashedIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssColorProfileAtRule {
+impl AstNode for CssDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COLOR_PROFILE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COLOR_PROFILE_AT_RULE
+        kind == CSS_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8232,34 +9883,33 @@ impl AstNode for CssColorProfileAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssColorProfileAtRule {
+impl std::fmt::Debug for CssDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssColorProfileAtRule")
+        f.debug_struct("CssDeclaration")
+            .field("property", &support::DebugSyntaxResult(self.property()))
             .field(
-                "color_profile_token",
-                &support::DebugSyntaxResult(self.color_profile_token()),
+                "important",
+                &support::DebugOptionalElement(self.important()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssColorProfileAtRule> for SyntaxNode {
-    fn from(n: CssColorProfileAtRule) -> SyntaxNode {
+impl From<CssDeclaration> for SyntaxNode {
+    fn from(n: CssDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssColorProfileAtRule> for SyntaxElement {
-    fn from(n: CssColorProfileAtRule) -> SyntaxElement {
+impl From<CssDeclaration> for SyntaxElement {
+    fn from(n: CssDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssComplexSelector {
+impl AstNode for CssDeclarationBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPLEX_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPLEX_SELECTOR
+        kind == CSS_DECLARATION_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8275,31 +9925,37 @@ impl AstNode for CssComplexSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssComplexSelector {
+impl std::fmt::Debug for CssDeclarationBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssComplexSelector")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("combinator", &support::DebugSyntaxResult(self.combinator()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssDeclarationBlock")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("declarations", &self.declarations())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
             .finish()
     }
 }
-impl From<CssComplexSelector> for SyntaxNode {
-    fn from(n: CssComplexSelector) -> SyntaxNode {
+impl From<CssDeclarationBlock> for SyntaxNode {
+    fn from(n: CssDeclarationBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssComplexSelector> for SyntaxElement {
-    fn from(n: CssComplexSelector) -> SyntaxElement {
+impl From<CssDeclarationBlock> for SyntaxElement {
+    fn from(n: CssDeclarationBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssCompoundSelector {
+impl AstNode for CssDeclarationImportant {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COMPOUND_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_IMPORTANT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COMPOUND_SELECTOR
+        kind == CSS_DECLARATION_IMPORTANT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8315,37 +9971,33 @@ impl AstNode for CssCompoundSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssCompoundSelector {
+impl std::fmt::Debug for CssDeclarationImportant {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCompoundSelector")
-            .field(
-                "nesting_selector_token",
-                &support::DebugOptionalElement(self.nesting_selector_token()),
-            )
+        f.debug_struct("CssDeclarationImportant")
+            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
             .field(
-                "simple_selector",
-                &support::DebugOptionalElement(self.simple_selector()),
+                "important_token",
+                &support::DebugSyntaxResult(self.important_token()),
             )
-            .field("sub_selectors", &self.sub_selectors())
             .finish()
     }
 }
-impl From<CssCompoundSelector> for SyntaxNode {
-    fn from(n: CssCompoundSelector) -> SyntaxNode {
+impl From<CssDeclarationImportant> for SyntaxNode {
+    fn from(n: CssDeclarationImportant) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssCompoundSelector> for SyntaxElement {
-    fn from(n: CssCompoundSelector) -> SyntaxElement {
+impl From<CssDeclarationImportant> for SyntaxElement {
+    fn from(n: CssDeclarationImportant) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerAndQuery {
+impl AstNode for CssDeclarationOrAtRuleBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_AND_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_AT_RULE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_AND_QUERY
+        kind == CSS_DECLARATION_OR_AT_RULE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8361,31 +10013,37 @@ impl AstNode for CssContainerAndQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerAndQuery {
+impl std::fmt::Debug for CssDeclarationOrAtRuleBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerAndQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssDeclarationOrAtRuleBlock")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
             .finish()
     }
 }
-impl From<CssContainerAndQuery> for SyntaxNode {
-    fn from(n: CssContainerAndQuery) -> SyntaxNode {
+impl From<CssDeclarationOrAtRuleBlock> for SyntaxNode {
+    fn from(n: CssDeclarationOrAtRuleBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerAndQuery> for SyntaxElement {
-    fn from(n: CssContainerAndQuery) -> SyntaxElement {
+impl From<CssDeclarationOrAtRuleBlock> for SyntaxElement {
+    fn from(n: CssDeclarationOrAtRuleBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerAtRule {
+impl AstNode for CssDeclarationOrRuleBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_RULE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_AT_RULE
+        kind == CSS_DECLARATION_OR_RULE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8401,35 +10059,37 @@ impl AstNode for CssContainerAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerAtRule {
+impl std::fmt::Debug for CssDeclarationOrRuleBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerAtRule")
+        f.debug_struct("CssDeclarationOrRuleBlock")
             .field(
-                "container_token",
-                &support::DebugSyntaxResult(self.container_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
-            .field("name", &support::DebugOptionalElement(self.name()))
-            .field("query", &support::DebugSyntaxResult(self.query()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerAtRule> for SyntaxNode {
-    fn from(n: CssContainerAtRule) -> SyntaxNode {
+impl From<CssDeclarationOrRuleBlock> for SyntaxNode {
+    fn from(n: CssDeclarationOrRuleBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerAtRule> for SyntaxElement {
-    fn from(n: CssContainerAtRule) -> SyntaxElement {
+impl From<CssDeclarationOrRuleBlock> for SyntaxElement {
+    fn from(n: CssDeclarationOrRuleBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerNotQuery {
+impl AstNode for CssDeclarationWithSemicolon {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_NOT_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_WITH_SEMICOLON as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_NOT_QUERY
+        kind == CSS_DECLARATION_WITH_SEMICOLON
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8445,30 +10105,36 @@ impl AstNode for CssContainerNotQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerNotQuery {
+impl std::fmt::Debug for CssDeclarationWithSemicolon {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerNotQuery")
-            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("CssDeclarationWithSemicolon")
+            .field(
+                "declaration",
+                &support::DebugSyntaxResult(self.declaration()),
+            )
+            .field(
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
+            )
             .finish()
     }
 }
-impl From<CssContainerNotQuery> for SyntaxNode {
-    fn from(n: CssContainerNotQuery) -> SyntaxNode {
+impl From<CssDeclarationWithSemicolon> for SyntaxNode {
+    fn from(n: CssDeclarationWithSemicolon) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerNotQuery> for SyntaxElement {
-    fn from(n: CssContainerNotQuery) -> SyntaxElement {
+impl From<CssDeclarationWithSemicolon> for SyntaxElement {
+    fn from(n: CssDeclarationWithSemicolon) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerOrQuery {
+impl AstNode for CssDocumentAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_OR_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_OR_QUERY
+        kind == CSS_DOCUMENT_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8484,31 +10150,34 @@ impl AstNode for CssContainerOrQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerOrQuery {
+impl std::fmt::Debug for CssDocumentAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerOrQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssDocumentAtRule")
+            .field(
+                "document_token",
+                &support::DebugSyntaxResult(self.document_token()),
+            )
+            .field("matchers", &self.matchers())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerOrQuery> for SyntaxNode {
-    fn from(n: CssContainerOrQuery) -> SyntaxNode {
+impl From<CssDocumentAtRule> for SyntaxNode {
+    fn from(n: CssDocumentAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerOrQuery> for SyntaxElement {
-    fn from(n: CssContainerOrQuery) -> SyntaxElement {
+impl From<CssDocumentAtRule> for SyntaxElement {
+    fn from(n: CssDocumentAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerQueryInParens {
+impl AstNode for CssDocumentCustomMatcher {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_QUERY_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_CUSTOM_MATCHER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_QUERY_IN_PARENS
+        kind == CSS_DOCUMENT_CUSTOM_MATCHER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8524,14 +10193,15 @@ impl AstNode for CssContainerQueryInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerQueryInParens {
+impl std::fmt::Debug for CssDocumentCustomMatcher {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerQueryInParens")
+        f.debug_struct("CssDocumentCustomMatcher")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("query", &support::DebugSyntaxResult(self.query()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -8539,22 +10209,22 @@ impl std::fmt::Debug for CssContainerQueryInParens {
             .finish()
     }
 }
-impl From<CssContainerQueryInParens> for SyntaxNode {
-    fn from(n: CssContainerQueryInParens) -> SyntaxNode {
+impl From<CssDocumentCustomMatcher> for SyntaxNode {
+    fn from(n: CssDocumentCustomMatcher) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerQueryInParens> for SyntaxElement {
-    fn from(n: CssContainerQueryInParens) -> SyntaxElement {
+impl From<CssDocumentCustomMatcher> for SyntaxElement {
+    fn from(n: CssDocumentCustomMatcher) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerSizeFeatureInParens {
+impl AstNode for CssFontFaceAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_SIZE_FEATURE_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FACE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_SIZE_FEATURE_IN_PARENS
+        kind == CSS_FONT_FACE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8570,37 +10240,33 @@ impl AstNode for CssContainerSizeFeatureInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerSizeFeatureInParens {
+impl std::fmt::Debug for CssFontFaceAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerSizeFeatureInParens")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("feature", &support::DebugSyntaxResult(self.feature()))
+        f.debug_struct("CssFontFaceAtRule")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "font_face_token",
+                &support::DebugSyntaxResult(self.font_face_token()),
             )
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerSizeFeatureInParens> for SyntaxNode {
-    fn from(n: CssContainerSizeFeatureInParens) -> SyntaxNode {
+impl From<CssFontFaceAtRule> for SyntaxNode {
+    fn from(n: CssFontFaceAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerSizeFeatureInParens> for SyntaxElement {
-    fn from(n: CssContainerSizeFeatureInParens) -> SyntaxElement {
+impl From<CssFontFaceAtRule> for SyntaxElement {
+    fn from(n: CssFontFaceAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleAndQuery {
+impl AstNode for CssFontFamilyName {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_AND_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FAMILY_NAME as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_AND_QUERY
+        kind == CSS_FONT_FAMILY_NAME
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8616,31 +10282,29 @@ impl AstNode for CssContainerStyleAndQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleAndQuery {
+impl std::fmt::Debug for CssFontFamilyName {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleAndQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssFontFamilyName")
+            .field("names", &self.names())
             .finish()
     }
 }
-impl From<CssContainerStyleAndQuery> for SyntaxNode {
-    fn from(n: CssContainerStyleAndQuery) -> SyntaxNode {
+impl From<CssFontFamilyName> for SyntaxNode {
+    fn from(n: CssFontFamilyName) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleAndQuery> for SyntaxElement {
-    fn from(n: CssContainerStyleAndQuery) -> SyntaxElement {
+impl From<CssFontFamilyName> for SyntaxElement {
+    fn from(n: CssFontFamilyName) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleInParens {
+impl AstNode for CssFontFeatureValuesAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_IN_PARENS
+        kind == CSS_FONT_FEATURE_VALUES_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8656,37 +10320,34 @@ impl AstNode for CssContainerStyleInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleInParens {
+impl std::fmt::Debug for CssFontFeatureValuesAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleInParens")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("CssFontFeatureValuesAtRule")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "font_feature_values_token",
+                &support::DebugSyntaxResult(self.font_feature_values_token()),
             )
+            .field("names", &self.names())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerStyleInParens> for SyntaxNode {
-    fn from(n: CssContainerStyleInParens) -> SyntaxNode {
+impl From<CssFontFeatureValuesAtRule> for SyntaxNode {
+    fn from(n: CssFontFeatureValuesAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleInParens> for SyntaxElement {
-    fn from(n: CssContainerStyleInParens) -> SyntaxElement {
+impl From<CssFontFeatureValuesAtRule> for SyntaxElement {
+    fn from(n: CssFontFeatureValuesAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleNotQuery {
+impl AstNode for CssFontFeatureValuesBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_NOT_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_NOT_QUERY
+        kind == CSS_FONT_FEATURE_VALUES_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8702,30 +10363,37 @@ impl AstNode for CssContainerStyleNotQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleNotQuery {
+impl std::fmt::Debug for CssFontFeatureValuesBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleNotQuery")
-            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("CssFontFeatureValuesBlock")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
             .finish()
     }
 }
-impl From<CssContainerStyleNotQuery> for SyntaxNode {
-    fn from(n: CssContainerStyleNotQuery) -> SyntaxNode {
+impl From<CssFontFeatureValuesBlock> for SyntaxNode {
+    fn from(n: CssFontFeatureValuesBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleNotQuery> for SyntaxElement {
-    fn from(n: CssContainerStyleNotQuery) -> SyntaxElement {
+impl From<CssFontFeatureValuesBlock> for SyntaxElement {
+    fn from(n: CssFontFeatureValuesBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleOrQuery {
+impl AstNode for CssFontFeatureValuesItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_OR_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_OR_QUERY
+        kind == CSS_FONT_FEATURE_VALUES_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8741,31 +10409,31 @@ impl AstNode for CssContainerStyleOrQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleOrQuery {
+impl std::fmt::Debug for CssFontFeatureValuesItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleOrQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssFontFeatureValuesItem")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerStyleOrQuery> for SyntaxNode {
-    fn from(n: CssContainerStyleOrQuery) -> SyntaxNode {
+impl From<CssFontFeatureValuesItem> for SyntaxNode {
+    fn from(n: CssFontFeatureValuesItem) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleOrQuery> for SyntaxElement {
-    fn from(n: CssContainerStyleOrQuery) -> SyntaxElement {
+impl From<CssFontFeatureValuesItem> for SyntaxElement {
+    fn from(n: CssFontFeatureValuesItem) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssContainerStyleQueryInParens {
+impl AstNode for CssFontPaletteValuesAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CONTAINER_STYLE_QUERY_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_PALETTE_VALUES_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CONTAINER_STYLE_QUERY_IN_PARENS
+        kind == CSS_FONT_PALETTE_VALUES_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8781,41 +10449,34 @@ impl AstNode for CssContainerStyleQueryInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssContainerStyleQueryInParens {
+impl std::fmt::Debug for CssFontPaletteValuesAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssContainerStyleQueryInParens")
-            .field(
-                "style_token",
-                &support::DebugSyntaxResult(self.style_token()),
-            )
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("CssFontPaletteValuesAtRule")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "font_palette_values_token",
+                &support::DebugSyntaxResult(self.font_palette_values_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssContainerStyleQueryInParens> for SyntaxNode {
-    fn from(n: CssContainerStyleQueryInParens) -> SyntaxNode {
+impl From<CssFontPaletteValuesAtRule> for SyntaxNode {
+    fn from(n: CssFontPaletteValuesAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssContainerStyleQueryInParens> for SyntaxElement {
-    fn from(n: CssContainerStyleQueryInParens) -> SyntaxElement {
+impl From<CssFontPaletteValuesAtRule> for SyntaxElement {
+    fn from(n: CssFontPaletteValuesAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssCounterStyleAtRule {
+impl AstNode for CssFunction {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_COUNTER_STYLE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FUNCTION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_COUNTER_STYLE_AT_RULE
+        kind == CSS_FUNCTION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8831,34 +10492,38 @@ impl AstNode for CssCounterStyleAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssCounterStyleAtRule {
+impl std::fmt::Debug for CssFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCounterStyleAtRule")
+        f.debug_struct("CssFunction")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "counter_style_token",
-                &support::DebugSyntaxResult(self.counter_style_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssCounterStyleAtRule> for SyntaxNode {
-    fn from(n: CssCounterStyleAtRule) -> SyntaxNode {
+impl From<CssFunction> for SyntaxNode {
+    fn from(n: CssFunction) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssCounterStyleAtRule> for SyntaxElement {
-    fn from(n: CssCounterStyleAtRule) -> SyntaxElement {
+impl From<CssFunction> for SyntaxElement {
+    fn from(n: CssFunction) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssCustomIdentifier {
+impl AstNode for CssGenericDelimiter {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_CUSTOM_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_DELIMITER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_CUSTOM_IDENTIFIER
+        kind == CSS_GENERIC_DELIMITER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8874,32 +10539,29 @@ impl AstNode for CssCustomIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssCustomIdentifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssCustomIdentifier")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+impl std::fmt::Debug for CssGenericDelimiter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssGenericDelimiter")
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssCustomIdentifier> for SyntaxNode {
-    fn from(n: CssCustomIdentifier) -> SyntaxNode {
+impl From<CssGenericDelimiter> for SyntaxNode {
+    fn from(n: CssGenericDelimiter) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssCustomIdentifier> for SyntaxElement {
-    fn from(n: CssCustomIdentifier) -> SyntaxElement {
+impl From<CssGenericDelimiter> for SyntaxElement {
+    fn from(n: CssGenericDelimiter) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDashedIdentifier {
+impl AstNode for CssGenericProperty {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DASHED_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_PROPERTY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DASHED_IDENTIFIER
+        kind == CSS_GENERIC_PROPERTY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8915,32 +10577,34 @@ impl AstNode for CssDashedIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDashedIdentifier {
+impl std::fmt::Debug for CssGenericProperty {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDashedIdentifier")
+        f.debug_struct("CssGenericProperty")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
+            .field("value", &self.value())
             .finish()
     }
 }
-impl From<CssDashedIdentifier> for SyntaxNode {
-    fn from(n: CssDashedIdentifier) -> SyntaxNode {
+impl From<CssGenericProperty> for SyntaxNode {
+    fn from(n: CssGenericProperty) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDashedIdentifier> for SyntaxElement {
-    fn from(n: CssDashedIdentifier) -> SyntaxElement {
+impl From<CssGenericProperty> for SyntaxElement {
+    fn from(n: CssGenericProperty) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclaration {
+impl AstNode for CssIdSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ID_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION
+        kind == CSS_ID_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8956,33 +10620,30 @@ impl AstNode for CssDeclaration {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclaration {
+impl std::fmt::Debug for CssIdSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclaration")
-            .field("property", &support::DebugSyntaxResult(self.property()))
-            .field(
-                "important",
-                &support::DebugOptionalElement(self.important()),
-            )
+        f.debug_struct("CssIdSelector")
+            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssDeclaration> for SyntaxNode {
-    fn from(n: CssDeclaration) -> SyntaxNode {
+impl From<CssIdSelector> for SyntaxNode {
+    fn from(n: CssIdSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclaration> for SyntaxElement {
-    fn from(n: CssDeclaration) -> SyntaxElement {
+impl From<CssIdSelector> for SyntaxElement {
+    fn from(n: CssIdSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationBlock {
+impl AstNode for CssIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_BLOCK
+        kind == CSS_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -8998,37 +10659,32 @@ impl AstNode for CssDeclarationBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationBlock {
+impl std::fmt::Debug for CssIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("declarations", &self.declarations())
+        f.debug_struct("CssIdentifier")
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
             .finish()
     }
 }
-impl From<CssDeclarationBlock> for SyntaxNode {
-    fn from(n: CssDeclarationBlock) -> SyntaxNode {
+impl From<CssIdentifier> for SyntaxNode {
+    fn from(n: CssIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationBlock> for SyntaxElement {
-    fn from(n: CssDeclarationBlock) -> SyntaxElement {
+impl From<CssIdentifier> for SyntaxElement {
+    fn from(n: CssIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationImportant {
+impl AstNode for CssImportAnonymousLayer {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_IMPORTANT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_ANONYMOUS_LAYER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_IMPORTANT
+        kind == CSS_IMPORT_ANONYMOUS_LAYER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9044,33 +10700,32 @@ impl AstNode for CssDeclarationImportant {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationImportant {
+impl std::fmt::Debug for CssImportAnonymousLayer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationImportant")
-            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+        f.debug_struct("CssImportAnonymousLayer")
             .field(
-                "important_token",
-                &support::DebugSyntaxResult(self.important_token()),
+                "layer_token",
+                &support::DebugSyntaxResult(self.layer_token()),
             )
             .finish()
     }
 }
-impl From<CssDeclarationImportant> for SyntaxNode {
-    fn from(n: CssDeclarationImportant) -> SyntaxNode {
+impl From<CssImportAnonymousLayer> for SyntaxNode {
+    fn from(n: CssImportAnonymousLayer) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationImportant> for SyntaxElement {
-    fn from(n: CssDeclarationImportant) -> SyntaxElement {
+impl From<CssImportAnonymousLayer> for SyntaxElement {
+    fn from(n: CssImportAnonymousLayer) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationOrAtRuleBlock {
+impl AstNode for CssImportAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_AT_RULE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_OR_AT_RULE_BLOCK
+        kind == CSS_IMPORT_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9086,37 +10741,40 @@ impl AstNode for CssDeclarationOrAtRuleBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationOrAtRuleBlock {
+impl std::fmt::Debug for CssImportAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationOrAtRuleBlock")
+        f.debug_struct("CssImportAtRule")
             .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
+                "import_token",
+                &support::DebugSyntaxResult(self.import_token()),
             )
-            .field("items", &self.items())
+            .field("url", &support::DebugSyntaxResult(self.url()))
+            .field("layer", &support::DebugOptionalElement(self.layer()))
+            .field("supports", &support::DebugOptionalElement(self.supports()))
+            .field("media", &self.media())
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssDeclarationOrAtRuleBlock> for SyntaxNode {
-    fn from(n: CssDeclarationOrAtRuleBlock) -> SyntaxNode {
+impl From<CssImportAtRule> for SyntaxNode {
+    fn from(n: CssImportAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationOrAtRuleBlock> for SyntaxElement {
-    fn from(n: CssDeclarationOrAtRuleBlock) -> SyntaxElement {
+impl From<CssImportAtRule> for SyntaxElement {
+    fn from(n: CssImportAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationOrRuleBlock {
+impl AstNode for CssImportNamedLayer {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_OR_RULE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_NAMED_LAYER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_OR_RULE_BLOCK
+        kind == CSS_IMPORT_NAMED_LAYER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9132,37 +10790,41 @@ impl AstNode for CssDeclarationOrRuleBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationOrRuleBlock {
+impl std::fmt::Debug for CssImportNamedLayer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationOrRuleBlock")
+        f.debug_struct("CssImportNamedLayer")
             .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
+                "layer_token",
+                &support::DebugSyntaxResult(self.layer_token()),
             )
-            .field("items", &self.items())
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("name", &self.name())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssDeclarationOrRuleBlock> for SyntaxNode {
-    fn from(n: CssDeclarationOrRuleBlock) -> SyntaxNode {
+impl From<CssImportNamedLayer> for SyntaxNode {
+    fn from(n: CssImportNamedLayer) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationOrRuleBlock> for SyntaxElement {
-    fn from(n: CssDeclarationOrRuleBlock) -> SyntaxElement {
+impl From<CssImportNamedLayer> for SyntaxElement {
+    fn from(n: CssImportNamedLayer) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDeclarationWithSemicolon {
+impl AstNode for CssImportSupports {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DECLARATION_WITH_SEMICOLON as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_SUPPORTS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DECLARATION_WITH_SEMICOLON
+        kind == CSS_IMPORT_SUPPORTS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9178,36 +10840,41 @@ impl AstNode for CssDeclarationWithSemicolon {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDeclarationWithSemicolon {
+impl std::fmt::Debug for CssImportSupports {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDeclarationWithSemicolon")
+        f.debug_struct("CssImportSupports")
             .field(
-                "declaration",
-                &support::DebugSyntaxResult(self.declaration()),
+                "supports_token",
+                &support::DebugSyntaxResult(self.supports_token()),
             )
             .field(
-                "semicolon_token",
-                &support::DebugOptionalElement(self.semicolon_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssDeclarationWithSemicolon> for SyntaxNode {
-    fn from(n: CssDeclarationWithSemicolon) -> SyntaxNode {
+impl From<CssImportSupports> for SyntaxNode {
+    fn from(n: CssImportSupports) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDeclarationWithSemicolon> for SyntaxElement {
-    fn from(n: CssDeclarationWithSemicolon) -> SyntaxElement {
+impl From<CssImportSupports> for SyntaxElement {
+    fn from(n: CssImportSupports) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDocumentAtRule {
+impl AstNode for CssKeyframesAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DOCUMENT_AT_RULE
+        kind == CSS_KEYFRAMES_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9223,34 +10890,34 @@ impl AstNode for CssDocumentAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDocumentAtRule {
+impl std::fmt::Debug for CssKeyframesAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDocumentAtRule")
+        f.debug_struct("CssKeyframesAtRule")
             .field(
-                "document_token",
-                &support::DebugSyntaxResult(self.document_token()),
+                "keyframes_token",
+                &support::DebugSyntaxResult(self.keyframes_token()),
             )
-            .field("matchers", &self.matchers())
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssDocumentAtRule> for SyntaxNode {
-    fn from(n: CssDocumentAtRule) -> SyntaxNode {
+impl From<CssKeyframesAtRule> for SyntaxNode {
+    fn from(n: CssKeyframesAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDocumentAtRule> for SyntaxElement {
-    fn from(n: CssDocumentAtRule) -> SyntaxElement {
+impl From<CssKeyframesAtRule> for SyntaxElement {
+    fn from(n: CssKeyframesAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssDocumentCustomMatcher {
+impl AstNode for CssKeyframesBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_DOCUMENT_CUSTOM_MATCHER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_DOCUMENT_CUSTOM_MATCHER
+        kind == CSS_KEYFRAMES_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9266,38 +10933,37 @@ impl AstNode for CssDocumentCustomMatcher {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssDocumentCustomMatcher {
+impl std::fmt::Debug for CssKeyframesBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssDocumentCustomMatcher")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssKeyframesBlock")
             .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
             )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field("items", &self.items())
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<CssDocumentCustomMatcher> for SyntaxNode {
-    fn from(n: CssDocumentCustomMatcher) -> SyntaxNode {
+impl From<CssKeyframesBlock> for SyntaxNode {
+    fn from(n: CssKeyframesBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssDocumentCustomMatcher> for SyntaxElement {
-    fn from(n: CssDocumentCustomMatcher) -> SyntaxElement {
+impl From<CssKeyframesBlock> for SyntaxElement {
+    fn from(n: CssKeyframesBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFaceAtRule {
+impl AstNode for CssKeyframesIdentSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FACE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_IDENT_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FACE_AT_RULE
+        kind == CSS_KEYFRAMES_IDENT_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9313,33 +10979,29 @@ impl AstNode for CssFontFaceAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFaceAtRule {
+impl std::fmt::Debug for CssKeyframesIdentSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFaceAtRule")
-            .field(
-                "font_face_token",
-                &support::DebugSyntaxResult(self.font_face_token()),
-            )
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssKeyframesIdentSelector")
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssFontFaceAtRule> for SyntaxNode {
-    fn from(n: CssFontFaceAtRule) -> SyntaxNode {
+impl From<CssKeyframesIdentSelector> for SyntaxNode {
+    fn from(n: CssKeyframesIdentSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFaceAtRule> for SyntaxElement {
-    fn from(n: CssFontFaceAtRule) -> SyntaxElement {
+impl From<CssKeyframesIdentSelector> for SyntaxElement {
+    fn from(n: CssKeyframesIdentSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFamilyName {
+impl AstNode for CssKeyframesItem {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FAMILY_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_ITEM as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FAMILY_NAME
+        kind == CSS_KEYFRAMES_ITEM
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9355,29 +11017,30 @@ impl AstNode for CssFontFamilyName {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFamilyName {
+impl std::fmt::Debug for CssKeyframesItem {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFamilyName")
-            .field("names", &self.names())
+        f.debug_struct("CssKeyframesItem")
+            .field("selectors", &self.selectors())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssFontFamilyName> for SyntaxNode {
-    fn from(n: CssFontFamilyName) -> SyntaxNode {
+impl From<CssKeyframesItem> for SyntaxNode {
+    fn from(n: CssKeyframesItem) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFamilyName> for SyntaxElement {
-    fn from(n: CssFontFamilyName) -> SyntaxElement {
+impl From<CssKeyframesItem> for SyntaxElement {
+    fn from(n: CssKeyframesItem) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFeatureValuesAtRule {
+impl AstNode for CssKeyframesPercentageSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_PERCENTAGE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FEATURE_VALUES_AT_RULE
+        kind == CSS_KEYFRAMES_PERCENTAGE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9393,34 +11056,29 @@ impl AstNode for CssFontFeatureValuesAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFeatureValuesAtRule {
+impl std::fmt::Debug for CssKeyframesPercentageSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFeatureValuesAtRule")
-            .field(
-                "font_feature_values_token",
-                &support::DebugSyntaxResult(self.font_feature_values_token()),
-            )
-            .field("names", &self.names())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssKeyframesPercentageSelector")
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssFontFeatureValuesAtRule> for SyntaxNode {
-    fn from(n: CssFontFeatureValuesAtRule) -> SyntaxNode {
+impl From<CssKeyframesPercentageSelector> for SyntaxNode {
+    fn from(n: CssKeyframesPercentageSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFeatureValuesAtRule> for SyntaxElement {
-    fn from(n: CssFontFeatureValuesAtRule) -> SyntaxElement {
+impl From<CssKeyframesPercentageSelector> for SyntaxElement {
+    fn from(n: CssKeyframesPercentageSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFeatureValuesBlock {
+impl AstNode for CssLayerAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FEATURE_VALUES_BLOCK
+        kind == CSS_LAYER_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9436,37 +11094,33 @@ impl AstNode for CssFontFeatureValuesBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFeatureValuesBlock {
+impl std::fmt::Debug for CssLayerAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFeatureValuesBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("items", &self.items())
+        f.debug_struct("CssLayerAtRule")
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "layer_token",
+                &support::DebugSyntaxResult(self.layer_token()),
             )
+            .field("layer", &support::DebugSyntaxResult(self.layer()))
             .finish()
     }
 }
-impl From<CssFontFeatureValuesBlock> for SyntaxNode {
-    fn from(n: CssFontFeatureValuesBlock) -> SyntaxNode {
+impl From<CssLayerAtRule> for SyntaxNode {
+    fn from(n: CssLayerAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFeatureValuesBlock> for SyntaxElement {
-    fn from(n: CssFontFeatureValuesBlock) -> SyntaxElement {
+impl From<CssLayerAtRule> for SyntaxElement {
+    fn from(n: CssLayerAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontFeatureValuesItem {
+impl AstNode for CssLayerDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_FEATURE_VALUES_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_FEATURE_VALUES_ITEM
+        kind == CSS_LAYER_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9482,31 +11136,30 @@ impl AstNode for CssFontFeatureValuesItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontFeatureValuesItem {
+impl std::fmt::Debug for CssLayerDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontFeatureValuesItem")
-            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssLayerDeclaration")
+            .field("references", &self.references())
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssFontFeatureValuesItem> for SyntaxNode {
-    fn from(n: CssFontFeatureValuesItem) -> SyntaxNode {
+impl From<CssLayerDeclaration> for SyntaxNode {
+    fn from(n: CssLayerDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontFeatureValuesItem> for SyntaxElement {
-    fn from(n: CssFontFeatureValuesItem) -> SyntaxElement {
+impl From<CssLayerDeclaration> for SyntaxElement {
+    fn from(n: CssLayerDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFontPaletteValuesAtRule {
+impl AstNode for CssLayerReference {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FONT_PALETTE_VALUES_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_REFERENCE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FONT_PALETTE_VALUES_AT_RULE
+        kind == CSS_LAYER_REFERENCE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9522,34 +11175,34 @@ impl AstNode for CssFontPaletteValuesAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFontPaletteValuesAtRule {
+impl std::fmt::Debug for CssLayerReference {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFontPaletteValuesAtRule")
+        f.debug_struct("CssLayerReference")
+            .field("references", &self.references())
             .field(
-                "font_palette_values_token",
-                &support::DebugSyntaxResult(self.font_palette_values_token()),
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssFontPaletteValuesAtRule> for SyntaxNode {
-    fn from(n: CssFontPaletteValuesAtRule) -> SyntaxNode {
+impl From<CssLayerReference> for SyntaxNode {
+    fn from(n: CssLayerReference) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFontPaletteValuesAtRule> for SyntaxElement {
-    fn from(n: CssFontPaletteValuesAtRule) -> SyntaxElement {
+impl From<CssLayerReference> for SyntaxElement {
+    fn from(n: CssLayerReference) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssFunction {
+impl AstNode for CssListOfComponentValuesExpression {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_FUNCTION as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_FUNCTION
+        kind == CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9565,38 +11218,29 @@ impl AstNode for CssFunction {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssFunction {
+impl std::fmt::Debug for CssListOfComponentValuesExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssFunction")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("items", &self.items())
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssListOfComponentValuesExpression")
+            .field("css_component_value_list", &self.css_component_value_list())
             .finish()
     }
 }
-impl From<CssFunction> for SyntaxNode {
-    fn from(n: CssFunction) -> SyntaxNode {
+impl From<CssListOfComponentValuesExpression> for SyntaxNode {
+    fn from(n: CssListOfComponentValuesExpression) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssFunction> for SyntaxElement {
-    fn from(n: CssFunction) -> SyntaxElement {
+impl From<CssListOfComponentValuesExpression> for SyntaxElement {
+    fn from(n: CssListOfComponentValuesExpression) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssGenericDelimiter {
+impl AstNode for CssMarginAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_DELIMITER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MARGIN_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_GENERIC_DELIMITER
+        kind == CSS_MARGIN_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9612,29 +11256,31 @@ impl AstNode for CssGenericDelimiter {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssGenericDelimiter {
+impl std::fmt::Debug for CssMarginAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssGenericDelimiter")
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssMarginAtRule")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssGenericDelimiter> for SyntaxNode {
-    fn from(n: CssGenericDelimiter) -> SyntaxNode {
+impl From<CssMarginAtRule> for SyntaxNode {
+    fn from(n: CssMarginAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssGenericDelimiter> for SyntaxElement {
-    fn from(n: CssGenericDelimiter) -> SyntaxElement {
+impl From<CssMarginAtRule> for SyntaxElement {
+    fn from(n: CssMarginAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssGenericProperty {
+impl AstNode for CssMediaAndCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_GENERIC_PROPERTY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AND_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_GENERIC_PROPERTY
+        kind == CSS_MEDIA_AND_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9650,34 +11296,31 @@ impl AstNode for CssGenericProperty {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssGenericProperty {
+impl std::fmt::Debug for CssMediaAndCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssGenericProperty")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
-            )
-            .field("value", &self.value())
+        f.debug_struct("CssMediaAndCondition")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssGenericProperty> for SyntaxNode {
-    fn from(n: CssGenericProperty) -> SyntaxNode {
+impl From<CssMediaAndCondition> for SyntaxNode {
+    fn from(n: CssMediaAndCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssGenericProperty> for SyntaxElement {
-    fn from(n: CssGenericProperty) -> SyntaxElement {
+impl From<CssMediaAndCondition> for SyntaxElement {
+    fn from(n: CssMediaAndCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssIdSelector {
+impl AstNode for CssMediaAndTypeQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ID_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AND_TYPE_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ID_SELECTOR
+        kind == CSS_MEDIA_AND_TYPE_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9693,30 +11336,31 @@ impl AstNode for CssIdSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssIdSelector {
+impl std::fmt::Debug for CssMediaAndTypeQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssIdSelector")
-            .field("hash_token", &support::DebugSyntaxResult(self.hash_token()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssMediaAndTypeQuery")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssIdSelector> for SyntaxNode {
-    fn from(n: CssIdSelector) -> SyntaxNode {
+impl From<CssMediaAndTypeQuery> for SyntaxNode {
+    fn from(n: CssMediaAndTypeQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssIdSelector> for SyntaxElement {
-    fn from(n: CssIdSelector) -> SyntaxElement {
+impl From<CssMediaAndTypeQuery> for SyntaxElement {
+    fn from(n: CssMediaAndTypeQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssIdentifier {
+impl AstNode for CssMediaAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IDENTIFIER
+        kind == CSS_MEDIA_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9732,32 +11376,34 @@ impl AstNode for CssIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssIdentifier {
+impl std::fmt::Debug for CssMediaAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssIdentifier")
+        f.debug_struct("CssMediaAtRule")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "media_token",
+                &support::DebugSyntaxResult(self.media_token()),
             )
+            .field("queries", &self.queries())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssIdentifier> for SyntaxNode {
-    fn from(n: CssIdentifier) -> SyntaxNode {
+impl From<CssMediaAtRule> for SyntaxNode {
+    fn from(n: CssMediaAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssIdentifier> for SyntaxElement {
-    fn from(n: CssIdentifier) -> SyntaxElement {
+impl From<CssMediaAtRule> for SyntaxElement {
+    fn from(n: CssMediaAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssImportAnonymousLayer {
+impl AstNode for CssMediaConditionInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_ANONYMOUS_LAYER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_CONDITION_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IMPORT_ANONYMOUS_LAYER
+        kind == CSS_MEDIA_CONDITION_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9773,32 +11419,37 @@ impl AstNode for CssImportAnonymousLayer {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssImportAnonymousLayer {
+impl std::fmt::Debug for CssMediaConditionInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssImportAnonymousLayer")
+        f.debug_struct("CssMediaConditionInParens")
             .field(
-                "layer_token",
-                &support::DebugSyntaxResult(self.layer_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssImportAnonymousLayer> for SyntaxNode {
-    fn from(n: CssImportAnonymousLayer) -> SyntaxNode {
+impl From<CssMediaConditionInParens> for SyntaxNode {
+    fn from(n: CssMediaConditionInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssImportAnonymousLayer> for SyntaxElement {
-    fn from(n: CssImportAnonymousLayer) -> SyntaxElement {
+impl From<CssMediaConditionInParens> for SyntaxElement {
+    fn from(n: CssMediaConditionInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssImportAtRule {
+impl AstNode for CssMediaConditionQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_CONDITION_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IMPORT_AT_RULE
+        kind == CSS_MEDIA_CONDITION_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9814,40 +11465,29 @@ impl AstNode for CssImportAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssImportAtRule {
+impl std::fmt::Debug for CssMediaConditionQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssImportAtRule")
-            .field(
-                "import_token",
-                &support::DebugSyntaxResult(self.import_token()),
-            )
-            .field("url", &support::DebugSyntaxResult(self.url()))
-            .field("layer", &support::DebugOptionalElement(self.layer()))
-            .field("supports", &support::DebugOptionalElement(self.supports()))
-            .field("media", &self.media())
-            .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
-            )
+        f.debug_struct("CssMediaConditionQuery")
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
             .finish()
     }
 }
-impl From<CssImportAtRule> for SyntaxNode {
-    fn from(n: CssImportAtRule) -> SyntaxNode {
+impl From<CssMediaConditionQuery> for SyntaxNode {
+    fn from(n: CssMediaConditionQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssImportAtRule> for SyntaxElement {
-    fn from(n: CssImportAtRule) -> SyntaxElement {
+impl From<CssMediaConditionQuery> for SyntaxElement {
+    fn from(n: CssMediaConditionQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssImportNamedLayer {
+impl AstNode for CssMediaFeatureInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_NAMED_LAYER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_FEATURE_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IMPORT_NAMED_LAYER
+        kind == CSS_MEDIA_FEATURE_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9863,18 +11503,14 @@ impl AstNode for CssImportNamedLayer {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssImportNamedLayer {
+impl std::fmt::Debug for CssMediaFeatureInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssImportNamedLayer")
-            .field(
-                "layer_token",
-                &support::DebugSyntaxResult(self.layer_token()),
-            )
+        f.debug_struct("CssMediaFeatureInParens")
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("name", &self.name())
+            .field("feature", &support::DebugSyntaxResult(self.feature()))
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -9882,22 +11518,22 @@ impl std::fmt::Debug for CssImportNamedLayer {
             .finish()
     }
 }
-impl From<CssImportNamedLayer> for SyntaxNode {
-    fn from(n: CssImportNamedLayer) -> SyntaxNode {
+impl From<CssMediaFeatureInParens> for SyntaxNode {
+    fn from(n: CssMediaFeatureInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssImportNamedLayer> for SyntaxElement {
-    fn from(n: CssImportNamedLayer) -> SyntaxElement {
+impl From<CssMediaFeatureInParens> for SyntaxElement {
+    fn from(n: CssMediaFeatureInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssImportSupports {
+impl AstNode for CssMediaNotCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_IMPORT_SUPPORTS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_NOT_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_IMPORT_SUPPORTS
+        kind == CSS_MEDIA_NOT_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9913,41 +11549,30 @@ impl AstNode for CssImportSupports {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssImportSupports {
+impl std::fmt::Debug for CssMediaNotCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssImportSupports")
-            .field(
-                "supports_token",
-                &support::DebugSyntaxResult(self.supports_token()),
-            )
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
+        f.debug_struct("CssMediaNotCondition")
+            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
             .field("condition", &support::DebugSyntaxResult(self.condition()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
             .finish()
     }
 }
-impl From<CssImportSupports> for SyntaxNode {
-    fn from(n: CssImportSupports) -> SyntaxNode {
+impl From<CssMediaNotCondition> for SyntaxNode {
+    fn from(n: CssMediaNotCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssImportSupports> for SyntaxElement {
-    fn from(n: CssImportSupports) -> SyntaxElement {
+impl From<CssMediaNotCondition> for SyntaxElement {
+    fn from(n: CssMediaNotCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesAtRule {
+impl AstNode for CssMediaOrCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_OR_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_AT_RULE
+        kind == CSS_MEDIA_OR_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -9963,34 +11588,31 @@ impl AstNode for CssKeyframesAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesAtRule {
+impl std::fmt::Debug for CssMediaOrCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesAtRule")
-            .field(
-                "keyframes_token",
-                &support::DebugSyntaxResult(self.keyframes_token()),
-            )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssMediaOrCondition")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssKeyframesAtRule> for SyntaxNode {
-    fn from(n: CssKeyframesAtRule) -> SyntaxNode {
+impl From<CssMediaOrCondition> for SyntaxNode {
+    fn from(n: CssMediaOrCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesAtRule> for SyntaxElement {
-    fn from(n: CssKeyframesAtRule) -> SyntaxElement {
+impl From<CssMediaOrCondition> for SyntaxElement {
+    fn from(n: CssMediaOrCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesBlock {
+impl AstNode for CssMediaType {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_TYPE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_BLOCK
+        kind == CSS_MEDIA_TYPE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10006,37 +11628,29 @@ impl AstNode for CssKeyframesBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesBlock {
+impl std::fmt::Debug for CssMediaType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("items", &self.items())
-            .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
-            )
+        f.debug_struct("CssMediaType")
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssKeyframesBlock> for SyntaxNode {
-    fn from(n: CssKeyframesBlock) -> SyntaxNode {
+impl From<CssMediaType> for SyntaxNode {
+    fn from(n: CssMediaType) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesBlock> for SyntaxElement {
-    fn from(n: CssKeyframesBlock) -> SyntaxElement {
+impl From<CssMediaType> for SyntaxElement {
+    fn from(n: CssMediaType) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesIdentSelector {
+impl AstNode for CssMediaTypeQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_IDENT_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_TYPE_QUERY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_IDENT_SELECTOR
+        kind == CSS_MEDIA_TYPE_QUERY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10052,29 +11666,30 @@ impl AstNode for CssKeyframesIdentSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesIdentSelector {
+impl std::fmt::Debug for CssMediaTypeQuery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesIdentSelector")
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+        f.debug_struct("CssMediaTypeQuery")
+            .field("modifier", &support::DebugOptionalElement(self.modifier()))
+            .field("ty", &support::DebugSyntaxResult(self.ty()))
             .finish()
     }
 }
-impl From<CssKeyframesIdentSelector> for SyntaxNode {
-    fn from(n: CssKeyframesIdentSelector) -> SyntaxNode {
+impl From<CssMediaTypeQuery> for SyntaxNode {
+    fn from(n: CssMediaTypeQuery) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesIdentSelector> for SyntaxElement {
-    fn from(n: CssKeyframesIdentSelector) -> SyntaxElement {
+impl From<CssMediaTypeQuery> for SyntaxElement {
+    fn from(n: CssMediaTypeQuery) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesItem {
+impl AstNode for CssNamedNamespacePrefix {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_ITEM as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMED_NAMESPACE_PREFIX as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_ITEM
+        kind == CSS_NAMED_NAMESPACE_PREFIX
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10090,30 +11705,29 @@ impl AstNode for CssKeyframesItem {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesItem {
+impl std::fmt::Debug for CssNamedNamespacePrefix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesItem")
-            .field("selectors", &self.selectors())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssNamedNamespacePrefix")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssKeyframesItem> for SyntaxNode {
-    fn from(n: CssKeyframesItem) -> SyntaxNode {
+impl From<CssNamedNamespacePrefix> for SyntaxNode {
+    fn from(n: CssNamedNamespacePrefix) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesItem> for SyntaxElement {
-    fn from(n: CssKeyframesItem) -> SyntaxElement {
+impl From<CssNamedNamespacePrefix> for SyntaxElement {
+    fn from(n: CssNamedNamespacePrefix) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssKeyframesPercentageSelector {
+impl AstNode for CssNamespace {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_KEYFRAMES_PERCENTAGE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMESPACE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_KEYFRAMES_PERCENTAGE_SELECTOR
+        kind == CSS_NAMESPACE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10129,29 +11743,33 @@ impl AstNode for CssKeyframesPercentageSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssKeyframesPercentageSelector {
+impl std::fmt::Debug for CssNamespace {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssKeyframesPercentageSelector")
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+        f.debug_struct("CssNamespace")
+            .field("prefix", &support::DebugOptionalElement(self.prefix()))
+            .field(
+                "bitwise_or_token",
+                &support::DebugSyntaxResult(self.bitwise_or_token()),
+            )
             .finish()
     }
 }
-impl From<CssKeyframesPercentageSelector> for SyntaxNode {
-    fn from(n: CssKeyframesPercentageSelector) -> SyntaxNode {
+impl From<CssNamespace> for SyntaxNode {
+    fn from(n: CssNamespace) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssKeyframesPercentageSelector> for SyntaxElement {
-    fn from(n: CssKeyframesPercentageSelector) -> SyntaxElement {
+impl From<CssNamespace> for SyntaxElement {
+    fn from(n: CssNamespace) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssLayerAtRule {
+impl AstNode for CssNamespaceAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMESPACE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LAYER_AT_RULE
+        kind == CSS_NAMESPACE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10167,33 +11785,38 @@ impl AstNode for CssLayerAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssLayerAtRule {
+impl std::fmt::Debug for CssNamespaceAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssLayerAtRule")
+        f.debug_struct("CssNamespaceAtRule")
             .field(
-                "layer_token",
-                &support::DebugSyntaxResult(self.layer_token()),
+                "namespace_token",
+                &support::DebugSyntaxResult(self.namespace_token()),
+            )
+            .field("prefix", &support::DebugOptionalElement(self.prefix()))
+            .field("url", &support::DebugSyntaxResult(self.url()))
+            .field(
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
-            .field("layer", &support::DebugSyntaxResult(self.layer()))
             .finish()
     }
 }
-impl From<CssLayerAtRule> for SyntaxNode {
-    fn from(n: CssLayerAtRule) -> SyntaxNode {
+impl From<CssNamespaceAtRule> for SyntaxNode {
+    fn from(n: CssNamespaceAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssLayerAtRule> for SyntaxElement {
-    fn from(n: CssLayerAtRule) -> SyntaxElement {
+impl From<CssNamespaceAtRule> for SyntaxElement {
+    fn from(n: CssNamespaceAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssLayerDeclaration {
+impl AstNode for CssNestedQualifiedRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_DECLARATION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTED_QUALIFIED_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LAYER_DECLARATION
+        kind == CSS_NESTED_QUALIFIED_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10209,30 +11832,30 @@ impl AstNode for CssLayerDeclaration {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssLayerDeclaration {
+impl std::fmt::Debug for CssNestedQualifiedRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssLayerDeclaration")
-            .field("references", &self.references())
+        f.debug_struct("CssNestedQualifiedRule")
+            .field("prelude", &self.prelude())
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssLayerDeclaration> for SyntaxNode {
-    fn from(n: CssLayerDeclaration) -> SyntaxNode {
+impl From<CssNestedQualifiedRule> for SyntaxNode {
+    fn from(n: CssNestedQualifiedRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssLayerDeclaration> for SyntaxElement {
-    fn from(n: CssLayerDeclaration) -> SyntaxElement {
+impl From<CssNestedQualifiedRule> for SyntaxElement {
+    fn from(n: CssNestedQualifiedRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssLayerReference {
+impl AstNode for CssNthOffset {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_LAYER_REFERENCE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NTH_OFFSET as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LAYER_REFERENCE
+        kind == CSS_NTH_OFFSET
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10248,34 +11871,30 @@ impl AstNode for CssLayerReference {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssLayerReference {
+impl std::fmt::Debug for CssNthOffset {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssLayerReference")
-            .field("references", &self.references())
-            .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
-            )
+        f.debug_struct("CssNthOffset")
+            .field("sign", &support::DebugSyntaxResult(self.sign()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssLayerReference> for SyntaxNode {
-    fn from(n: CssLayerReference) -> SyntaxNode {
+impl From<CssNthOffset> for SyntaxNode {
+    fn from(n: CssNthOffset) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssLayerReference> for SyntaxElement {
-    fn from(n: CssLayerReference) -> SyntaxElement {
+impl From<CssNthOffset> for SyntaxElement {
+    fn from(n: CssNthOffset) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssListOfComponentValuesExpression {
+impl AstNode for CssNumber {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NUMBER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION
+        kind == CSS_NUMBER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10291,29 +11910,32 @@ impl AstNode for CssListOfComponentValuesExpression {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssListOfComponentValuesExpression {
+impl std::fmt::Debug for CssNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssListOfComponentValuesExpression")
-            .field("css_component_value_list", &self.css_component_value_list())
+        f.debug_struct("CssNumber")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssListOfComponentValuesExpression> for SyntaxNode {
-    fn from(n: CssListOfComponentValuesExpression) -> SyntaxNode {
+impl From<CssNumber> for SyntaxNode {
+    fn from(n: CssNumber) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssListOfComponentValuesExpression> for SyntaxElement {
-    fn from(n: CssListOfComponentValuesExpression) -> SyntaxElement {
+impl From<CssNumber> for SyntaxElement {
+    fn from(n: CssNumber) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMarginAtRule {
+impl AstNode for CssPageAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MARGIN_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MARGIN_AT_RULE
+        kind == CSS_PAGE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10329,31 +11951,31 @@ impl AstNode for CssMarginAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMarginAtRule {
+impl std::fmt::Debug for CssPageAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMarginAtRule")
-            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssPageAtRule")
+            .field("page_token", &support::DebugSyntaxResult(self.page_token()))
+            .field("selectors", &self.selectors())
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssMarginAtRule> for SyntaxNode {
-    fn from(n: CssMarginAtRule) -> SyntaxNode {
+impl From<CssPageAtRule> for SyntaxNode {
+    fn from(n: CssPageAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMarginAtRule> for SyntaxElement {
-    fn from(n: CssMarginAtRule) -> SyntaxElement {
+impl From<CssPageAtRule> for SyntaxElement {
+    fn from(n: CssPageAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaAndCondition {
+impl AstNode for CssPageAtRuleBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AND_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_AND_CONDITION
+        kind == CSS_PAGE_AT_RULE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10369,31 +11991,37 @@ impl AstNode for CssMediaAndCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaAndCondition {
+impl std::fmt::Debug for CssPageAtRuleBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaAndCondition")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssPageAtRuleBlock")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaAndCondition> for SyntaxNode {
-    fn from(n: CssMediaAndCondition) -> SyntaxNode {
+impl From<CssPageAtRuleBlock> for SyntaxNode {
+    fn from(n: CssPageAtRuleBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaAndCondition> for SyntaxElement {
-    fn from(n: CssMediaAndCondition) -> SyntaxElement {
+impl From<CssPageAtRuleBlock> for SyntaxElement {
+    fn from(n: CssPageAtRuleBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaAndTypeQuery {
+impl AstNode for CssPageSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AND_TYPE_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_AND_TYPE_QUERY
+        kind == CSS_PAGE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10409,31 +12037,30 @@ impl AstNode for CssMediaAndTypeQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaAndTypeQuery {
+impl std::fmt::Debug for CssPageSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaAndTypeQuery")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssPageSelector")
+            .field("ty", &support::DebugOptionalElement(self.ty()))
+            .field("pseudos", &self.pseudos())
             .finish()
     }
 }
-impl From<CssMediaAndTypeQuery> for SyntaxNode {
-    fn from(n: CssMediaAndTypeQuery) -> SyntaxNode {
+impl From<CssPageSelector> for SyntaxNode {
+    fn from(n: CssPageSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaAndTypeQuery> for SyntaxElement {
-    fn from(n: CssMediaAndTypeQuery) -> SyntaxElement {
+impl From<CssPageSelector> for SyntaxElement {
+    fn from(n: CssPageSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaAtRule {
+impl AstNode for CssPageSelectorPseudo {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR_PSEUDO as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_AT_RULE
+        kind == CSS_PAGE_SELECTOR_PSEUDO
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10449,34 +12076,33 @@ impl AstNode for CssMediaAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaAtRule {
+impl std::fmt::Debug for CssPageSelectorPseudo {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaAtRule")
+        f.debug_struct("CssPageSelectorPseudo")
             .field(
-                "media_token",
-                &support::DebugSyntaxResult(self.media_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("queries", &self.queries())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssMediaAtRule> for SyntaxNode {
-    fn from(n: CssMediaAtRule) -> SyntaxNode {
+impl From<CssPageSelectorPseudo> for SyntaxNode {
+    fn from(n: CssPageSelectorPseudo) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaAtRule> for SyntaxElement {
-    fn from(n: CssMediaAtRule) -> SyntaxElement {
+impl From<CssPageSelectorPseudo> for SyntaxElement {
+    fn from(n: CssPageSelectorPseudo) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaConditionInParens {
+impl AstNode for CssParameter {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_CONDITION_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARAMETER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_CONDITION_IN_PARENS
+        kind == CSS_PARAMETER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10492,37 +12118,32 @@ impl AstNode for CssMediaConditionInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaConditionInParens {
+impl std::fmt::Debug for CssParameter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaConditionInParens")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+        f.debug_struct("CssParameter")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "any_css_expression",
+                &support::DebugSyntaxResult(self.any_css_expression()),
             )
             .finish()
     }
 }
-impl From<CssMediaConditionInParens> for SyntaxNode {
-    fn from(n: CssMediaConditionInParens) -> SyntaxNode {
+impl From<CssParameter> for SyntaxNode {
+    fn from(n: CssParameter) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaConditionInParens> for SyntaxElement {
-    fn from(n: CssMediaConditionInParens) -> SyntaxElement {
+impl From<CssParameter> for SyntaxElement {
+    fn from(n: CssParameter) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaConditionQuery {
+impl AstNode for CssParenthesizedExpression {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_CONDITION_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARENTHESIZED_EXPRESSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_CONDITION_QUERY
+        kind == CSS_PARENTHESIZED_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10538,29 +12159,40 @@ impl AstNode for CssMediaConditionQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaConditionQuery {
+impl std::fmt::Debug for CssParenthesizedExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaConditionQuery")
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+        f.debug_struct("CssParenthesizedExpression")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field(
+                "expression",
+                &support::DebugOptionalElement(self.expression()),
+            )
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaConditionQuery> for SyntaxNode {
-    fn from(n: CssMediaConditionQuery) -> SyntaxNode {
+impl From<CssParenthesizedExpression> for SyntaxNode {
+    fn from(n: CssParenthesizedExpression) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaConditionQuery> for SyntaxElement {
-    fn from(n: CssMediaConditionQuery) -> SyntaxElement {
+impl From<CssParenthesizedExpression> for SyntaxElement {
+    fn from(n: CssParenthesizedExpression) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaFeatureInParens {
+impl AstNode for CssPercentage {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_FEATURE_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PERCENTAGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_FEATURE_IN_PARENS
+        kind == CSS_PERCENTAGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10576,37 +12208,36 @@ impl AstNode for CssMediaFeatureInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaFeatureInParens {
+impl std::fmt::Debug for CssPercentage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaFeatureInParens")
+        f.debug_struct("CssPercentage")
             .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
-            .field("feature", &support::DebugSyntaxResult(self.feature()))
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "percent_token",
+                &support::DebugSyntaxResult(self.percent_token()),
             )
             .finish()
     }
 }
-impl From<CssMediaFeatureInParens> for SyntaxNode {
-    fn from(n: CssMediaFeatureInParens) -> SyntaxNode {
+impl From<CssPercentage> for SyntaxNode {
+    fn from(n: CssPercentage) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaFeatureInParens> for SyntaxElement {
-    fn from(n: CssMediaFeatureInParens) -> SyntaxElement {
+impl From<CssPercentage> for SyntaxElement {
+    fn from(n: CssPercentage) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaNotCondition {
+impl AstNode for CssPropertyAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_NOT_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PROPERTY_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_NOT_CONDITION
+        kind == CSS_PROPERTY_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10622,30 +12253,35 @@ impl AstNode for CssMediaNotCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaNotCondition {
+impl std::fmt::Debug for CssPropertyAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaNotCondition")
-            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+        f.debug_struct("CssPropertyAtRule")
+            .field(
+                "property_token",
+                &support::DebugSyntaxResult(self.property_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssMediaNotCondition> for SyntaxNode {
-    fn from(n: CssMediaNotCondition) -> SyntaxNode {
+impl From<CssPropertyAtRule> for SyntaxNode {
+    fn from(n: CssPropertyAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaNotCondition> for SyntaxElement {
-    fn from(n: CssMediaNotCondition) -> SyntaxElement {
+impl From<CssPropertyAtRule> for SyntaxElement {
+    fn from(n: CssPropertyAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaOrCondition {
+impl AstNode for CssPseudoClassFunctionCompoundSelector {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_OR_CONDITION as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_OR_CONDITION
+        kind == CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10661,31 +12297,39 @@ impl AstNode for CssMediaOrCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaOrCondition {
+impl std::fmt::Debug for CssPseudoClassFunctionCompoundSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaOrCondition")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssPseudoClassFunctionCompoundSelector")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaOrCondition> for SyntaxNode {
-    fn from(n: CssMediaOrCondition) -> SyntaxNode {
+impl From<CssPseudoClassFunctionCompoundSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionCompoundSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaOrCondition> for SyntaxElement {
-    fn from(n: CssMediaOrCondition) -> SyntaxElement {
+impl From<CssPseudoClassFunctionCompoundSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionCompoundSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaType {
+impl AstNode for CssPseudoClassFunctionCompoundSelectorList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_TYPE as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_TYPE
+        kind == CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10701,29 +12345,38 @@ impl AstNode for CssMediaType {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaType {
+impl std::fmt::Debug for CssPseudoClassFunctionCompoundSelectorList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaType")
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssPseudoClassFunctionCompoundSelectorList")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("compound_selectors", &self.compound_selectors())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaType> for SyntaxNode {
-    fn from(n: CssMediaType) -> SyntaxNode {
+impl From<CssPseudoClassFunctionCompoundSelectorList> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionCompoundSelectorList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaType> for SyntaxElement {
-    fn from(n: CssMediaType) -> SyntaxElement {
+impl From<CssPseudoClassFunctionCompoundSelectorList> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionCompoundSelectorList) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssMediaTypeQuery {
+impl AstNode for CssPseudoClassFunctionIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_MEDIA_TYPE_QUERY as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_MEDIA_TYPE_QUERY
+        kind == CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10739,30 +12392,38 @@ impl AstNode for CssMediaTypeQuery {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssMediaTypeQuery {
+impl std::fmt::Debug for CssPseudoClassFunctionIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssMediaTypeQuery")
-            .field("modifier", &support::DebugOptionalElement(self.modifier()))
-            .field("ty", &support::DebugSyntaxResult(self.ty()))
+        f.debug_struct("CssPseudoClassFunctionIdentifier")
+            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("ident", &support::DebugSyntaxResult(self.ident()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssMediaTypeQuery> for SyntaxNode {
-    fn from(n: CssMediaTypeQuery) -> SyntaxNode {
+impl From<CssPseudoClassFunctionIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssMediaTypeQuery> for SyntaxElement {
-    fn from(n: CssMediaTypeQuery) -> SyntaxElement {
+impl From<CssPseudoClassFunctionIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNamedNamespacePrefix {
+impl AstNode for CssPseudoClassFunctionNth {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMED_NAMESPACE_PREFIX as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_NTH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NAMED_NAMESPACE_PREFIX
+        kind == CSS_PSEUDO_CLASS_FUNCTION_NTH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10778,29 +12439,39 @@ impl AstNode for CssNamedNamespacePrefix {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNamedNamespacePrefix {
+impl std::fmt::Debug for CssPseudoClassFunctionNth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNamedNamespacePrefix")
+        f.debug_struct("CssPseudoClassFunctionNth")
             .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssNamedNamespacePrefix> for SyntaxNode {
-    fn from(n: CssNamedNamespacePrefix) -> SyntaxNode {
+impl From<CssPseudoClassFunctionNth> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionNth) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNamedNamespacePrefix> for SyntaxElement {
-    fn from(n: CssNamedNamespacePrefix) -> SyntaxElement {
+impl From<CssPseudoClassFunctionNth> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionNth) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNamespace {
+impl AstNode for CssPseudoClassFunctionRelativeSelectorList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMESPACE as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NAMESPACE
+        kind == CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10816,33 +12487,38 @@ impl AstNode for CssNamespace {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNamespace {
+impl std::fmt::Debug for CssPseudoClassFunctionRelativeSelectorList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNamespace")
-            .field("prefix", &support::DebugOptionalElement(self.prefix()))
+        f.debug_struct("CssPseudoClassFunctionRelativeSelectorList")
+            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
             .field(
-                "bitwise_or_token",
-                &support::DebugSyntaxResult(self.bitwise_or_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("relative_selectors", &self.relative_selectors())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssNamespace> for SyntaxNode {
-    fn from(n: CssNamespace) -> SyntaxNode {
+impl From<CssPseudoClassFunctionRelativeSelectorList> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionRelativeSelectorList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNamespace> for SyntaxElement {
-    fn from(n: CssNamespace) -> SyntaxElement {
+impl From<CssPseudoClassFunctionRelativeSelectorList> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionRelativeSelectorList) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNamespaceAtRule {
+impl AstNode for CssPseudoClassFunctionSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NAMESPACE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NAMESPACE_AT_RULE
+        kind == CSS_PSEUDO_CLASS_FUNCTION_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10858,38 +12534,39 @@ impl AstNode for CssNamespaceAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNamespaceAtRule {
+impl std::fmt::Debug for CssPseudoClassFunctionSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNamespaceAtRule")
+        f.debug_struct("CssPseudoClassFunctionSelector")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "namespace_token",
-                &support::DebugSyntaxResult(self.namespace_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("prefix", &support::DebugOptionalElement(self.prefix()))
-            .field("url", &support::DebugSyntaxResult(self.url()))
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .field(
-                "semicolon_token",
-                &support::DebugSyntaxResult(self.semicolon_token()),
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssNamespaceAtRule> for SyntaxNode {
-    fn from(n: CssNamespaceAtRule) -> SyntaxNode {
+impl From<CssPseudoClassFunctionSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNamespaceAtRule> for SyntaxElement {
-    fn from(n: CssNamespaceAtRule) -> SyntaxElement {
+impl From<CssPseudoClassFunctionSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNestedQualifiedRule {
+impl AstNode for CssPseudoClassFunctionSelectorList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NESTED_QUALIFIED_RULE as u16));
+    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
+        CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST as u16,
+    ));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NESTED_QUALIFIED_RULE
+        kind == CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10905,30 +12582,38 @@ impl AstNode for CssNestedQualifiedRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNestedQualifiedRule {
+impl std::fmt::Debug for CssPseudoClassFunctionSelectorList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNestedQualifiedRule")
-            .field("prelude", &self.prelude())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssPseudoClassFunctionSelectorList")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selectors", &self.selectors())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssNestedQualifiedRule> for SyntaxNode {
-    fn from(n: CssNestedQualifiedRule) -> SyntaxNode {
+impl From<CssPseudoClassFunctionSelectorList> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionSelectorList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNestedQualifiedRule> for SyntaxElement {
-    fn from(n: CssNestedQualifiedRule) -> SyntaxElement {
+impl From<CssPseudoClassFunctionSelectorList> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionSelectorList) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNthOffset {
+impl AstNode for CssPseudoClassFunctionValueList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NTH_OFFSET as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NTH_OFFSET
+        kind == CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10944,30 +12629,38 @@ impl AstNode for CssNthOffset {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNthOffset {
+impl std::fmt::Debug for CssPseudoClassFunctionValueList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNthOffset")
-            .field("sign", &support::DebugSyntaxResult(self.sign()))
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssPseudoClassFunctionValueList")
+            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("values", &self.values())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssNthOffset> for SyntaxNode {
-    fn from(n: CssNthOffset) -> SyntaxNode {
+impl From<CssPseudoClassFunctionValueList> for SyntaxNode {
+    fn from(n: CssPseudoClassFunctionValueList) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNthOffset> for SyntaxElement {
-    fn from(n: CssNthOffset) -> SyntaxElement {
+impl From<CssPseudoClassFunctionValueList> for SyntaxElement {
+    fn from(n: CssPseudoClassFunctionValueList) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssNumber {
+impl AstNode for CssPseudoClassIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_NUMBER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_NUMBER
+        kind == CSS_PSEUDO_CLASS_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -10983,32 +12676,29 @@ impl AstNode for CssNumber {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssNumber {
+impl std::fmt::Debug for CssPseudoClassIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssNumber")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
+        f.debug_struct("CssPseudoClassIdentifier")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssNumber> for SyntaxNode {
-    fn from(n: CssNumber) -> SyntaxNode {
+impl From<CssPseudoClassIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoClassIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssNumber> for SyntaxElement {
-    fn from(n: CssNumber) -> SyntaxElement {
+impl From<CssPseudoClassIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoClassIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPageAtRule {
+impl AstNode for CssPseudoClassNth {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_AT_RULE
+        kind == CSS_PSEUDO_CLASS_NTH
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11024,31 +12714,35 @@ impl AstNode for CssPageAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPageAtRule {
+impl std::fmt::Debug for CssPseudoClassNth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPageAtRule")
-            .field("page_token", &support::DebugSyntaxResult(self.page_token()))
-            .field("selectors", &self.selectors())
-            .field("block", &support::DebugSyntaxResult(self.block()))
+        f.debug_struct("CssPseudoClassNth")
+            .field("sign", &support::DebugOptionalElement(self.sign()))
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .field(
+                "symbol_token",
+                &support::DebugSyntaxResult(self.symbol_token()),
+            )
+            .field("offset", &support::DebugOptionalElement(self.offset()))
             .finish()
     }
 }
-impl From<CssPageAtRule> for SyntaxNode {
-    fn from(n: CssPageAtRule) -> SyntaxNode {
+impl From<CssPseudoClassNth> for SyntaxNode {
+    fn from(n: CssPseudoClassNth) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPageAtRule> for SyntaxElement {
-    fn from(n: CssPageAtRule) -> SyntaxElement {
+impl From<CssPseudoClassNth> for SyntaxElement {
+    fn from(n: CssPseudoClassNth) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPageAtRuleBlock {
+impl AstNode for CssPseudoClassNthIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_AT_RULE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_AT_RULE_BLOCK
+        kind == CSS_PSEUDO_CLASS_NTH_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11064,37 +12758,29 @@ impl AstNode for CssPageAtRuleBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPageAtRuleBlock {
+impl std::fmt::Debug for CssPseudoClassNthIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPageAtRuleBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("items", &self.items())
-            .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
-            )
+        f.debug_struct("CssPseudoClassNthIdentifier")
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssPageAtRuleBlock> for SyntaxNode {
-    fn from(n: CssPageAtRuleBlock) -> SyntaxNode {
+impl From<CssPseudoClassNthIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoClassNthIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPageAtRuleBlock> for SyntaxElement {
-    fn from(n: CssPageAtRuleBlock) -> SyntaxElement {
+impl From<CssPseudoClassNthIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoClassNthIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPageSelector {
+impl AstNode for CssPseudoClassNthNumber {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_NUMBER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_SELECTOR
+        kind == CSS_PSEUDO_CLASS_NTH_NUMBER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11110,30 +12796,30 @@ impl AstNode for CssPageSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPageSelector {
+impl std::fmt::Debug for CssPseudoClassNthNumber {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPageSelector")
-            .field("ty", &support::DebugOptionalElement(self.ty()))
-            .field("pseudos", &self.pseudos())
+        f.debug_struct("CssPseudoClassNthNumber")
+            .field("sign", &support::DebugOptionalElement(self.sign()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssPageSelector> for SyntaxNode {
-    fn from(n: CssPageSelector) -> SyntaxNode {
+impl From<CssPseudoClassNthNumber> for SyntaxNode {
+    fn from(n: CssPseudoClassNthNumber) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPageSelector> for SyntaxElement {
-    fn from(n: CssPageSelector) -> SyntaxElement {
+impl From<CssPseudoClassNthNumber> for SyntaxElement {
+    fn from(n: CssPseudoClassNthNumber) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPageSelectorPseudo {
+impl AstNode for CssPseudoClassNthSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PAGE_SELECTOR_PSEUDO as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PAGE_SELECTOR_PSEUDO
+        kind == CSS_PSEUDO_CLASS_NTH_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11149,33 +12835,33 @@ impl AstNode for CssPageSelectorPseudo {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPageSelectorPseudo {
+impl std::fmt::Debug for CssPseudoClassNthSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPageSelectorPseudo")
+        f.debug_struct("CssPseudoClassNthSelector")
+            .field("nth", &support::DebugSyntaxResult(self.nth()))
             .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
+                "of_selector",
+                &support::DebugOptionalElement(self.of_selector()),
             )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssPageSelectorPseudo> for SyntaxNode {
-    fn from(n: CssPageSelectorPseudo) -> SyntaxNode {
+impl From<CssPseudoClassNthSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassNthSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPageSelectorPseudo> for SyntaxElement {
-    fn from(n: CssPageSelectorPseudo) -> SyntaxElement {
+impl From<CssPseudoClassNthSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassNthSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssParameter {
+impl AstNode for CssPseudoClassOfNthSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARAMETER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_OF_NTH_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PARAMETER
+        kind == CSS_PSEUDO_CLASS_OF_NTH_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11191,32 +12877,30 @@ impl AstNode for CssParameter {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssParameter {
+impl std::fmt::Debug for CssPseudoClassOfNthSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssParameter")
-            .field(
-                "any_css_expression",
-                &support::DebugSyntaxResult(self.any_css_expression()),
-            )
+        f.debug_struct("CssPseudoClassOfNthSelector")
+            .field("of_token", &support::DebugSyntaxResult(self.of_token()))
+            .field("selectors", &self.selectors())
             .finish()
     }
 }
-impl From<CssParameter> for SyntaxNode {
-    fn from(n: CssParameter) -> SyntaxNode {
+impl From<CssPseudoClassOfNthSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassOfNthSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssParameter> for SyntaxElement {
-    fn from(n: CssParameter) -> SyntaxElement {
+impl From<CssPseudoClassOfNthSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassOfNthSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssParenthesizedExpression {
+impl AstNode for CssPseudoClassSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PARENTHESIZED_EXPRESSION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PARENTHESIZED_EXPRESSION
+        kind == CSS_PSEUDO_CLASS_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11232,40 +12916,33 @@ impl AstNode for CssParenthesizedExpression {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssParenthesizedExpression {
+impl std::fmt::Debug for CssPseudoClassSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssParenthesizedExpression")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field(
-                "expression",
-                &support::DebugOptionalElement(self.expression()),
-            )
+        f.debug_struct("CssPseudoClassSelector")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
+            .field("class", &support::DebugSyntaxResult(self.class()))
             .finish()
     }
 }
-impl From<CssParenthesizedExpression> for SyntaxNode {
-    fn from(n: CssParenthesizedExpression) -> SyntaxNode {
+impl From<CssPseudoClassSelector> for SyntaxNode {
+    fn from(n: CssPseudoClassSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssParenthesizedExpression> for SyntaxElement {
-    fn from(n: CssParenthesizedExpression) -> SyntaxElement {
+impl From<CssPseudoClassSelector> for SyntaxElement {
+    fn from(n: CssPseudoClassSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPercentage {
+impl AstNode for CssPseudoElementFunctionIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PERCENTAGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PERCENTAGE
+        kind == CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11281,36 +12958,38 @@ impl AstNode for CssPercentage {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPercentage {
+impl std::fmt::Debug for CssPseudoElementFunctionIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPercentage")
+        f.debug_struct("CssPseudoElementFunctionIdentifier")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
             )
+            .field("ident", &support::DebugSyntaxResult(self.ident()))
             .field(
-                "percent_token",
-                &support::DebugSyntaxResult(self.percent_token()),
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssPercentage> for SyntaxNode {
-    fn from(n: CssPercentage) -> SyntaxNode {
+impl From<CssPseudoElementFunctionIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoElementFunctionIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPercentage> for SyntaxElement {
-    fn from(n: CssPercentage) -> SyntaxElement {
+impl From<CssPseudoElementFunctionIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoElementFunctionIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPropertyAtRule {
+impl AstNode for CssPseudoElementFunctionSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PROPERTY_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PROPERTY_AT_RULE
+        kind == CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11326,35 +13005,38 @@ impl AstNode for CssPropertyAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPropertyAtRule {
+impl std::fmt::Debug for CssPseudoElementFunctionSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPropertyAtRule")
+        f.debug_struct("CssPseudoElementFunctionSelector")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "property_token",
-                &support::DebugSyntaxResult(self.property_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssPropertyAtRule> for SyntaxNode {
-    fn from(n: CssPropertyAtRule) -> SyntaxNode {
+impl From<CssPseudoElementFunctionSelector> for SyntaxNode {
+    fn from(n: CssPseudoElementFunctionSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPropertyAtRule> for SyntaxElement {
-    fn from(n: CssPropertyAtRule) -> SyntaxElement {
+impl From<CssPseudoElementFunctionSelector> for SyntaxElement {
+    fn from(n: CssPseudoElementFunctionSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionCompoundSelector {
+impl AstNode for CssPseudoElementIdentifier {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR
+        kind == CSS_PSEUDO_ELEMENT_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11370,39 +13052,29 @@ impl AstNode for CssPseudoClassFunctionCompoundSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionCompoundSelector {
+impl std::fmt::Debug for CssPseudoElementIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionCompoundSelector")
+        f.debug_struct("CssPseudoElementIdentifier")
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionCompoundSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionCompoundSelector) -> SyntaxNode {
+impl From<CssPseudoElementIdentifier> for SyntaxNode {
+    fn from(n: CssPseudoElementIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionCompoundSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionCompoundSelector) -> SyntaxElement {
+impl From<CssPseudoElementIdentifier> for SyntaxElement {
+    fn from(n: CssPseudoElementIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionCompoundSelectorList {
+impl AstNode for CssPseudoElementSelector {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_COMPOUND_SELECTOR_LIST
+        kind == CSS_PSEUDO_ELEMENT_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11418,38 +13090,33 @@ impl AstNode for CssPseudoClassFunctionCompoundSelectorList {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionCompoundSelectorList {
+impl std::fmt::Debug for CssPseudoElementSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionCompoundSelectorList")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("compound_selectors", &self.compound_selectors())
+        f.debug_struct("CssPseudoElementSelector")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "double_colon_token",
+                &support::DebugSyntaxResult(self.double_colon_token()),
             )
+            .field("element", &support::DebugSyntaxResult(self.element()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionCompoundSelectorList> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionCompoundSelectorList) -> SyntaxNode {
+impl From<CssPseudoElementSelector> for SyntaxNode {
+    fn from(n: CssPseudoElementSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionCompoundSelectorList> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionCompoundSelectorList) -> SyntaxElement {
+impl From<CssPseudoElementSelector> for SyntaxElement {
+    fn from(n: CssPseudoElementSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionIdentifier {
+impl AstNode for CssQualifiedRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUALIFIED_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_IDENTIFIER
+        kind == CSS_QUALIFIED_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11465,38 +13132,30 @@ impl AstNode for CssPseudoClassFunctionIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionIdentifier {
+impl std::fmt::Debug for CssQualifiedRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionIdentifier")
-            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("ident", &support::DebugSyntaxResult(self.ident()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssQualifiedRule")
+            .field("prelude", &self.prelude())
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionIdentifier) -> SyntaxNode {
+impl From<CssQualifiedRule> for SyntaxNode {
+    fn from(n: CssQualifiedRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionIdentifier) -> SyntaxElement {
+impl From<CssQualifiedRule> for SyntaxElement {
+    fn from(n: CssQualifiedRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionNth {
+impl AstNode for CssQueryFeatureBoolean {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_NTH as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_BOOLEAN as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_NTH
+        kind == CSS_QUERY_FEATURE_BOOLEAN
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11512,39 +13171,29 @@ impl AstNode for CssPseudoClassFunctionNth {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionNth {
+impl std::fmt::Debug for CssQueryFeatureBoolean {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionNth")
+        f.debug_struct("CssQueryFeatureBoolean")
             .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionNth> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionNth) -> SyntaxNode {
+impl From<CssQueryFeatureBoolean> for SyntaxNode {
+    fn from(n: CssQueryFeatureBoolean) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionNth> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionNth) -> SyntaxElement {
+impl From<CssQueryFeatureBoolean> for SyntaxElement {
+    fn from(n: CssQueryFeatureBoolean) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionRelativeSelectorList {
+impl AstNode for CssQueryFeaturePlain {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_PLAIN as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_RELATIVE_SELECTOR_LIST
+        kind == CSS_QUERY_FEATURE_PLAIN
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11560,38 +13209,34 @@ impl AstNode for CssPseudoClassFunctionRelativeSelectorList {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionRelativeSelectorList {
+impl std::fmt::Debug for CssQueryFeaturePlain {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionRelativeSelectorList")
-            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("relative_selectors", &self.relative_selectors())
+        f.debug_struct("CssQueryFeaturePlain")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionRelativeSelectorList> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionRelativeSelectorList) -> SyntaxNode {
+impl From<CssQueryFeaturePlain> for SyntaxNode {
+    fn from(n: CssQueryFeaturePlain) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionRelativeSelectorList> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionRelativeSelectorList) -> SyntaxElement {
+impl From<CssQueryFeaturePlain> for SyntaxElement {
+    fn from(n: CssQueryFeaturePlain) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionSelector {
+impl AstNode for CssQueryFeatureRange {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_SELECTOR
+        kind == CSS_QUERY_FEATURE_RANGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11607,39 +13252,31 @@ impl AstNode for CssPseudoClassFunctionSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionSelector {
+impl std::fmt::Debug for CssQueryFeatureRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionSelector")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssQueryFeatureRange")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("comparison", &support::DebugSyntaxResult(self.comparison()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionSelector) -> SyntaxNode {
+impl From<CssQueryFeatureRange> for SyntaxNode {
+    fn from(n: CssQueryFeatureRange) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionSelector) -> SyntaxElement {
+impl From<CssQueryFeatureRange> for SyntaxElement {
+    fn from(n: CssQueryFeatureRange) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionSelectorList {
+impl AstNode for CssQueryFeatureRangeComparison {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = SyntaxKindSet::from_raw(RawSyntaxKind(
-        CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST as u16,
-    ));
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE_COMPARISON as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_SELECTOR_LIST
+        kind == CSS_QUERY_FEATURE_RANGE_COMPARISON
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11655,38 +13292,29 @@ impl AstNode for CssPseudoClassFunctionSelectorList {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionSelectorList {
+impl std::fmt::Debug for CssQueryFeatureRangeComparison {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionSelectorList")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selectors", &self.selectors())
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssQueryFeatureRangeComparison")
+            .field("operator", &support::DebugSyntaxResult(self.operator()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionSelectorList> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionSelectorList) -> SyntaxNode {
+impl From<CssQueryFeatureRangeComparison> for SyntaxNode {
+    fn from(n: CssQueryFeatureRangeComparison) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionSelectorList> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionSelectorList) -> SyntaxElement {
+impl From<CssQueryFeatureRangeComparison> for SyntaxElement {
+    fn from(n: CssQueryFeatureRangeComparison) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassFunctionValueList {
+impl AstNode for CssQueryFeatureRangeInterval {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE_INTERVAL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_FUNCTION_VALUE_LIST
+        kind == CSS_QUERY_FEATURE_RANGE_INTERVAL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11702,38 +13330,39 @@ impl AstNode for CssPseudoClassFunctionValueList {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassFunctionValueList {
+impl std::fmt::Debug for CssQueryFeatureRangeInterval {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassFunctionValueList")
-            .field("name_token", &support::DebugSyntaxResult(self.name_token()))
+        f.debug_struct("CssQueryFeatureRangeInterval")
+            .field("left", &support::DebugSyntaxResult(self.left()))
             .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
+                "left_comparison",
+                &support::DebugSyntaxResult(self.left_comparison()),
             )
-            .field("values", &self.values())
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "right_comparison",
+                &support::DebugSyntaxResult(self.right_comparison()),
             )
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssPseudoClassFunctionValueList> for SyntaxNode {
-    fn from(n: CssPseudoClassFunctionValueList) -> SyntaxNode {
+impl From<CssQueryFeatureRangeInterval> for SyntaxNode {
+    fn from(n: CssQueryFeatureRangeInterval) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassFunctionValueList> for SyntaxElement {
-    fn from(n: CssPseudoClassFunctionValueList) -> SyntaxElement {
+impl From<CssQueryFeatureRangeInterval> for SyntaxElement {
+    fn from(n: CssQueryFeatureRangeInterval) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassIdentifier {
+impl AstNode for CssQueryFeatureReverseRange {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_REVERSE_RANGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_IDENTIFIER
+        kind == CSS_QUERY_FEATURE_REVERSE_RANGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11749,29 +13378,31 @@ impl AstNode for CssPseudoClassIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassIdentifier {
+impl std::fmt::Debug for CssQueryFeatureReverseRange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassIdentifier")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssQueryFeatureReverseRange")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("comparison", &support::DebugSyntaxResult(self.comparison()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssPseudoClassIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoClassIdentifier) -> SyntaxNode {
+impl From<CssQueryFeatureReverseRange> for SyntaxNode {
+    fn from(n: CssQueryFeatureReverseRange) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoClassIdentifier) -> SyntaxElement {
+impl From<CssQueryFeatureReverseRange> for SyntaxElement {
+    fn from(n: CssQueryFeatureReverseRange) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassNth {
+impl AstNode for CssRatio {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RATIO as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_NTH
+        kind == CSS_RATIO
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11787,35 +13418,37 @@ impl AstNode for CssPseudoClassNth {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassNth {
+impl std::fmt::Debug for CssRatio {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassNth")
-            .field("sign", &support::DebugOptionalElement(self.sign()))
-            .field("value", &support::DebugOptionalElement(self.value()))
+        f.debug_struct("CssRatio")
+            .field("numerator", &support::DebugSyntaxResult(self.numerator()))
             .field(
-                "symbol_token",
-                &support::DebugSyntaxResult(self.symbol_token()),
+                "slash_token",
+                &support::DebugSyntaxResult(self.slash_token()),
+            )
+            .field(
+                "denominator",
+                &support::DebugSyntaxResult(self.denominator()),
             )
-            .field("offset", &support::DebugOptionalElement(self.offset()))
             .finish()
     }
 }
-impl From<CssPseudoClassNth> for SyntaxNode {
-    fn from(n: CssPseudoClassNth) -> SyntaxNode {
+impl From<CssRatio> for SyntaxNode {
+    fn from(n: CssRatio) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassNth> for SyntaxElement {
-    fn from(n: CssPseudoClassNth) -> SyntaxElement {
+impl From<CssRatio> for SyntaxElement {
+    fn from(n: CssRatio) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassNthIdentifier {
+impl AstNode for CssRegularDimension {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_REGULAR_DIMENSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_NTH_IDENTIFIER
+        kind == CSS_REGULAR_DIMENSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11831,29 +13464,33 @@ impl AstNode for CssPseudoClassNthIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassNthIdentifier {
+impl std::fmt::Debug for CssRegularDimension {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassNthIdentifier")
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssRegularDimension")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .field("unit_token", &support::DebugSyntaxResult(self.unit_token()))
             .finish()
     }
 }
-impl From<CssPseudoClassNthIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoClassNthIdentifier) -> SyntaxNode {
+impl From<CssRegularDimension> for SyntaxNode {
+    fn from(n: CssRegularDimension) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassNthIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoClassNthIdentifier) -> SyntaxElement {
+impl From<CssRegularDimension> for SyntaxElement {
+    fn from(n: CssRegularDimension) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassNthNumber {
+impl AstNode for CssRelativeSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_NUMBER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RELATIVE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_NTH_NUMBER
+        kind == CSS_RELATIVE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11869,30 +13506,33 @@ impl AstNode for CssPseudoClassNthNumber {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassNthNumber {
+impl std::fmt::Debug for CssRelativeSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassNthNumber")
-            .field("sign", &support::DebugOptionalElement(self.sign()))
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssRelativeSelector")
+            .field(
+                "combinator",
+                &support::DebugOptionalElement(self.combinator()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
             .finish()
     }
 }
-impl From<CssPseudoClassNthNumber> for SyntaxNode {
-    fn from(n: CssPseudoClassNthNumber) -> SyntaxNode {
+impl From<CssRelativeSelector> for SyntaxNode {
+    fn from(n: CssRelativeSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassNthNumber> for SyntaxElement {
-    fn from(n: CssPseudoClassNthNumber) -> SyntaxElement {
+impl From<CssRelativeSelector> for SyntaxElement {
+    fn from(n: CssRelativeSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassNthSelector {
+impl AstNode for CssRoot {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_NTH_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ROOT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_NTH_SELECTOR
+        kind == CSS_ROOT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11908,33 +13548,34 @@ impl AstNode for CssPseudoClassNthSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassNthSelector {
+impl std::fmt::Debug for CssRoot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassNthSelector")
-            .field("nth", &support::DebugSyntaxResult(self.nth()))
+        f.debug_struct("CssRoot")
             .field(
-                "of_selector",
-                &support::DebugOptionalElement(self.of_selector()),
+                "bom_token",
+                &support::DebugOptionalElement(self.bom_token()),
             )
+            .field("rules", &self.rules())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
             .finish()
     }
 }
-impl From<CssPseudoClassNthSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassNthSelector) -> SyntaxNode {
+impl From<CssRoot> for SyntaxNode {
+    fn from(n: CssRoot) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassNthSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassNthSelector) -> SyntaxElement {
+impl From<CssRoot> for SyntaxElement {
+    fn from(n: CssRoot) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassOfNthSelector {
+impl AstNode for CssRuleBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_OF_NTH_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RULE_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_OF_NTH_SELECTOR
+        kind == CSS_RULE_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11950,30 +13591,37 @@ impl AstNode for CssPseudoClassOfNthSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassOfNthSelector {
+impl std::fmt::Debug for CssRuleBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassOfNthSelector")
-            .field("of_token", &support::DebugSyntaxResult(self.of_token()))
-            .field("selectors", &self.selectors())
+        f.debug_struct("CssRuleBlock")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("rules", &self.rules())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
             .finish()
     }
 }
-impl From<CssPseudoClassOfNthSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassOfNthSelector) -> SyntaxNode {
+impl From<CssRuleBlock> for SyntaxNode {
+    fn from(n: CssRuleBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassOfNthSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassOfNthSelector) -> SyntaxElement {
+impl From<CssRuleBlock> for SyntaxElement {
+    fn from(n: CssRuleBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoClassSelector {
+impl AstNode for CssScopeAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_CLASS_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_CLASS_SELECTOR
+        kind == CSS_SCOPE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -11989,33 +13637,34 @@ impl AstNode for CssPseudoClassSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoClassSelector {
+impl std::fmt::Debug for CssScopeAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoClassSelector")
+        f.debug_struct("CssScopeAtRule")
             .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
+                "scope_token",
+                &support::DebugSyntaxResult(self.scope_token()),
             )
-            .field("class", &support::DebugSyntaxResult(self.class()))
+            .field("range", &support::DebugOptionalElement(self.range()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssPseudoClassSelector> for SyntaxNode {
-    fn from(n: CssPseudoClassSelector) -> SyntaxNode {
+impl From<CssScopeAtRule> for SyntaxNode {
+    fn from(n: CssScopeAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoClassSelector> for SyntaxElement {
-    fn from(n: CssPseudoClassSelector) -> SyntaxElement {
+impl From<CssScopeAtRule> for SyntaxElement {
+    fn from(n: CssScopeAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoElementFunctionIdentifier {
+impl AstNode for CssScopeEdge {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_EDGE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_ELEMENT_FUNCTION_IDENTIFIER
+        kind == CSS_SCOPE_EDGE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12031,15 +13680,14 @@ impl AstNode for CssPseudoElementFunctionIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoElementFunctionIdentifier {
+impl std::fmt::Debug for CssScopeEdge {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoElementFunctionIdentifier")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssScopeEdge")
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("ident", &support::DebugSyntaxResult(self.ident()))
+            .field("selectors", &self.selectors())
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -12047,22 +13695,22 @@ impl std::fmt::Debug for CssPseudoElementFunctionIdentifier {
             .finish()
     }
 }
-impl From<CssPseudoElementFunctionIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoElementFunctionIdentifier) -> SyntaxNode {
+impl From<CssScopeEdge> for SyntaxNode {
+    fn from(n: CssScopeEdge) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoElementFunctionIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoElementFunctionIdentifier) -> SyntaxElement {
+impl From<CssScopeEdge> for SyntaxElement {
+    fn from(n: CssScopeEdge) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoElementFunctionSelector {
+impl AstNode for CssScopeRangeEnd {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_END as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_ELEMENT_FUNCTION_SELECTOR
+        kind == CSS_SCOPE_RANGE_END
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12078,38 +13726,30 @@ impl AstNode for CssPseudoElementFunctionSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoElementFunctionSelector {
+impl std::fmt::Debug for CssScopeRangeEnd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoElementFunctionSelector")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("CssScopeRangeEnd")
+            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
+            .field("end", &support::DebugSyntaxResult(self.end()))
             .finish()
     }
 }
-impl From<CssPseudoElementFunctionSelector> for SyntaxNode {
-    fn from(n: CssPseudoElementFunctionSelector) -> SyntaxNode {
+impl From<CssScopeRangeEnd> for SyntaxNode {
+    fn from(n: CssScopeRangeEnd) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoElementFunctionSelector> for SyntaxElement {
-    fn from(n: CssPseudoElementFunctionSelector) -> SyntaxElement {
+impl From<CssScopeRangeEnd> for SyntaxElement {
+    fn from(n: CssScopeRangeEnd) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoElementIdentifier {
+impl AstNode for CssScopeRangeInterval {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_IDENTIFIER as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_INTERVAL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_ELEMENT_IDENTIFIER
+        kind == CSS_SCOPE_RANGE_INTERVAL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12125,29 +13765,31 @@ impl AstNode for CssPseudoElementIdentifier {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoElementIdentifier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoElementIdentifier")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+impl std::fmt::Debug for CssScopeRangeInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CssScopeRangeInterval")
+            .field("start", &support::DebugSyntaxResult(self.start()))
+            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
+            .field("end", &support::DebugSyntaxResult(self.end()))
             .finish()
     }
 }
-impl From<CssPseudoElementIdentifier> for SyntaxNode {
-    fn from(n: CssPseudoElementIdentifier) -> SyntaxNode {
+impl From<CssScopeRangeInterval> for SyntaxNode {
+    fn from(n: CssScopeRangeInterval) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoElementIdentifier> for SyntaxElement {
-    fn from(n: CssPseudoElementIdentifier) -> SyntaxElement {
+impl From<CssScopeRangeInterval> for SyntaxElement {
+    fn from(n: CssScopeRangeInterval) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssPseudoElementSelector {
+impl AstNode for CssScopeRangeStart {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_PSEUDO_ELEMENT_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_START as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_PSEUDO_ELEMENT_SELECTOR
+        kind == CSS_SCOPE_RANGE_START
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12163,33 +13805,29 @@ impl AstNode for CssPseudoElementSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssPseudoElementSelector {
+impl std::fmt::Debug for CssScopeRangeStart {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssPseudoElementSelector")
-            .field(
-                "double_colon_token",
-                &support::DebugSyntaxResult(self.double_colon_token()),
-            )
-            .field("element", &support::DebugSyntaxResult(self.element()))
+        f.debug_struct("CssScopeRangeStart")
+            .field("start", &support::DebugSyntaxResult(self.start()))
             .finish()
     }
 }
-impl From<CssPseudoElementSelector> for SyntaxNode {
-    fn from(n: CssPseudoElementSelector) -> SyntaxNode {
+impl From<CssScopeRangeStart> for SyntaxNode {
+    fn from(n: CssScopeRangeStart) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssPseudoElementSelector> for SyntaxElement {
-    fn from(n: CssPseudoElementSelector) -> SyntaxElement {
+impl From<CssScopeRangeStart> for SyntaxElement {
+    fn from(n: CssScopeRangeStart) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQualifiedRule {
+impl AstNode for CssStartingStyleAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUALIFIED_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_STARTING_STYLE_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUALIFIED_RULE
+        kind == CSS_STARTING_STYLE_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12205,30 +13843,33 @@ impl AstNode for CssQualifiedRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQualifiedRule {
+impl std::fmt::Debug for CssStartingStyleAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQualifiedRule")
-            .field("prelude", &self.prelude())
+        f.debug_struct("CssStartingStyleAtRule")
+            .field(
+                "starting_style_token",
+                &support::DebugSyntaxResult(self.starting_style_token()),
+            )
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssQualifiedRule> for SyntaxNode {
-    fn from(n: CssQualifiedRule) -> SyntaxNode {
+impl From<CssStartingStyleAtRule> for SyntaxNode {
+    fn from(n: CssStartingStyleAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQualifiedRule> for SyntaxElement {
-    fn from(n: CssQualifiedRule) -> SyntaxElement {
+impl From<CssStartingStyleAtRule> for SyntaxElement {
+    fn from(n: CssStartingStyleAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureBoolean {
+impl AstNode for CssString {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_BOOLEAN as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_STRING as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_BOOLEAN
+        kind == CSS_STRING
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12244,29 +13885,32 @@ impl AstNode for CssQueryFeatureBoolean {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureBoolean {
+impl std::fmt::Debug for CssString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureBoolean")
-            .field("name", &support::DebugSyntaxResult(self.name()))
+        f.debug_struct("CssString")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssQueryFeatureBoolean> for SyntaxNode {
-    fn from(n: CssQueryFeatureBoolean) -> SyntaxNode {
+impl From<CssString> for SyntaxNode {
+    fn from(n: CssString) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureBoolean> for SyntaxElement {
-    fn from(n: CssQueryFeatureBoolean) -> SyntaxElement {
+impl From<CssString> for SyntaxElement {
+    fn from(n: CssString) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeaturePlain {
+impl AstNode for CssSupportsAndCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_PLAIN as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_AND_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_PLAIN
+        kind == CSS_SUPPORTS_AND_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12282,34 +13926,31 @@ impl AstNode for CssQueryFeaturePlain {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeaturePlain {
+impl std::fmt::Debug for CssSupportsAndCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeaturePlain")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "colon_token",
-                &support::DebugSyntaxResult(self.colon_token()),
-            )
-            .field("value", &support::DebugSyntaxResult(self.value()))
+        f.debug_struct("CssSupportsAndCondition")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssQueryFeaturePlain> for SyntaxNode {
-    fn from(n: CssQueryFeaturePlain) -> SyntaxNode {
+impl From<CssSupportsAndCondition> for SyntaxNode {
+    fn from(n: CssSupportsAndCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeaturePlain> for SyntaxElement {
-    fn from(n: CssQueryFeaturePlain) -> SyntaxElement {
+impl From<CssSupportsAndCondition> for SyntaxElement {
+    fn from(n: CssSupportsAndCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureRange {
+impl AstNode for CssSupportsAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_RANGE
+        kind == CSS_SUPPORTS_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12325,31 +13966,34 @@ impl AstNode for CssQueryFeatureRange {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureRange {
+impl std::fmt::Debug for CssSupportsAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureRange")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("comparison", &support::DebugSyntaxResult(self.comparison()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssSupportsAtRule")
+            .field(
+                "supports_token",
+                &support::DebugSyntaxResult(self.supports_token()),
+            )
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssQueryFeatureRange> for SyntaxNode {
-    fn from(n: CssQueryFeatureRange) -> SyntaxNode {
+impl From<CssSupportsAtRule> for SyntaxNode {
+    fn from(n: CssSupportsAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureRange> for SyntaxElement {
-    fn from(n: CssQueryFeatureRange) -> SyntaxElement {
+impl From<CssSupportsAtRule> for SyntaxElement {
+    fn from(n: CssSupportsAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureRangeComparison {
+impl AstNode for CssSupportsConditionInParens {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE_COMPARISON as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_CONDITION_IN_PARENS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_RANGE_COMPARISON
+        kind == CSS_SUPPORTS_CONDITION_IN_PARENS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12365,29 +14009,37 @@ impl AstNode for CssQueryFeatureRangeComparison {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureRangeComparison {
+impl std::fmt::Debug for CssSupportsConditionInParens {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureRangeComparison")
-            .field("operator", &support::DebugSyntaxResult(self.operator()))
+        f.debug_struct("CssSupportsConditionInParens")
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssQueryFeatureRangeComparison> for SyntaxNode {
-    fn from(n: CssQueryFeatureRangeComparison) -> SyntaxNode {
+impl From<CssSupportsConditionInParens> for SyntaxNode {
+    fn from(n: CssSupportsConditionInParens) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureRangeComparison> for SyntaxElement {
-    fn from(n: CssQueryFeatureRangeComparison) -> SyntaxElement {
+impl From<CssSupportsConditionInParens> for SyntaxElement {
+    fn from(n: CssSupportsConditionInParens) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureRangeInterval {
+impl AstNode for CssSupportsFeatureDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_RANGE_INTERVAL as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_FEATURE_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_RANGE_INTERVAL
+        kind == CSS_SUPPORTS_FEATURE_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12403,39 +14055,40 @@ impl AstNode for CssQueryFeatureRangeInterval {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureRangeInterval {
+impl std::fmt::Debug for CssSupportsFeatureDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureRangeInterval")
-            .field("left", &support::DebugSyntaxResult(self.left()))
+        f.debug_struct("CssSupportsFeatureDeclaration")
             .field(
-                "left_comparison",
-                &support::DebugSyntaxResult(self.left_comparison()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "right_comparison",
-                &support::DebugSyntaxResult(self.right_comparison()),
+                "declaration",
+                &support::DebugSyntaxResult(self.declaration()),
+            )
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssQueryFeatureRangeInterval> for SyntaxNode {
-    fn from(n: CssQueryFeatureRangeInterval) -> SyntaxNode {
+impl From<CssSupportsFeatureDeclaration> for SyntaxNode {
+    fn from(n: CssSupportsFeatureDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureRangeInterval> for SyntaxElement {
-    fn from(n: CssQueryFeatureRangeInterval) -> SyntaxElement {
+impl From<CssSupportsFeatureDeclaration> for SyntaxElement {
+    fn from(n: CssSupportsFeatureDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssQueryFeatureReverseRange {
+impl AstNode for CssSupportsFeatureSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_QUERY_FEATURE_REVERSE_RANGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_FEATURE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_QUERY_FEATURE_REVERSE_RANGE
+        kind == CSS_SUPPORTS_FEATURE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12451,31 +14104,41 @@ impl AstNode for CssQueryFeatureReverseRange {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssQueryFeatureReverseRange {
+impl std::fmt::Debug for CssSupportsFeatureSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssQueryFeatureReverseRange")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("comparison", &support::DebugSyntaxResult(self.comparison()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("CssSupportsFeatureSelector")
+            .field(
+                "selector_token",
+                &support::DebugSyntaxResult(self.selector_token()),
+            )
+            .field(
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
             .finish()
     }
 }
-impl From<CssQueryFeatureReverseRange> for SyntaxNode {
-    fn from(n: CssQueryFeatureReverseRange) -> SyntaxNode {
+impl From<CssSupportsFeatureSelector> for SyntaxNode {
+    fn from(n: CssSupportsFeatureSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssQueryFeatureReverseRange> for SyntaxElement {
-    fn from(n: CssQueryFeatureReverseRange) -> SyntaxElement {
+impl From<CssSupportsFeatureSelector> for SyntaxElement {
+    fn from(n: CssSupportsFeatureSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRatio {
+impl AstNode for CssSupportsNotCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RATIO as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_NOT_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_RATIO
+        kind == CSS_SUPPORTS_NOT_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12491,37 +14154,30 @@ impl AstNode for CssRatio {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRatio {
+impl std::fmt::Debug for CssSupportsNotCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRatio")
-            .field("numerator", &support::DebugSyntaxResult(self.numerator()))
-            .field(
-                "slash_token",
-                &support::DebugSyntaxResult(self.slash_token()),
-            )
-            .field(
-                "denominator",
-                &support::DebugSyntaxResult(self.denominator()),
-            )
+        f.debug_struct("CssSupportsNotCondition")
+            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
+            .field("query", &support::DebugSyntaxResult(self.query()))
             .finish()
     }
 }
-impl From<CssRatio> for SyntaxNode {
-    fn from(n: CssRatio) -> SyntaxNode {
+impl From<CssSupportsNotCondition> for SyntaxNode {
+    fn from(n: CssSupportsNotCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRatio> for SyntaxElement {
-    fn from(n: CssRatio) -> SyntaxElement {
+impl From<CssSupportsNotCondition> for SyntaxElement {
+    fn from(n: CssSupportsNotCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRegularDimension {
+impl AstNode for CssSupportsOrCondition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_REGULAR_DIMENSION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_OR_CONDITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_REGULAR_DIMENSION
+        kind == CSS_SUPPORTS_OR_CONDITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12537,33 +14193,31 @@ impl AstNode for CssRegularDimension {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRegularDimension {
+impl std::fmt::Debug for CssSupportsOrCondition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRegularDimension")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
-            .field("unit_token", &support::DebugSyntaxResult(self.unit_token()))
+        f.debug_struct("CssSupportsOrCondition")
+            .field("left", &support::DebugSyntaxResult(self.left()))
+            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
+            .field("right", &support::DebugSyntaxResult(self.right()))
             .finish()
     }
 }
-impl From<CssRegularDimension> for SyntaxNode {
-    fn from(n: CssRegularDimension) -> SyntaxNode {
+impl From<CssSupportsOrCondition> for SyntaxNode {
+    fn from(n: CssSupportsOrCondition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRegularDimension> for SyntaxElement {
-    fn from(n: CssRegularDimension) -> SyntaxElement {
+impl From<CssSupportsOrCondition> for SyntaxElement {
+    fn from(n: CssSupportsOrCondition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRelativeSelector {
+impl AstNode for CssTypeSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RELATIVE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_TYPE_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_RELATIVE_SELECTOR
+        kind == CSS_TYPE_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12579,33 +14233,33 @@ impl AstNode for CssRelativeSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRelativeSelector {
+impl std::fmt::Debug for CssTypeSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRelativeSelector")
+        f.debug_struct("CssTypeSelector")
             .field(
-                "combinator",
-                &support::DebugOptionalElement(self.combinator()),
+                "namespace",
+                &support::DebugOptionalElement(self.namespace()),
             )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+            .field("ident", &support::DebugSyntaxResult(self.ident()))
             .finish()
     }
 }
-impl From<CssRelativeSelector> for SyntaxNode {
-    fn from(n: CssRelativeSelector) -> SyntaxNode {
+impl From<CssTypeSelector> for SyntaxNode {
+    fn from(n: CssTypeSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRelativeSelector> for SyntaxElement {
-    fn from(n: CssRelativeSelector) -> SyntaxElement {
+impl From<CssTypeSelector> for SyntaxElement {
+    fn from(n: CssTypeSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRoot {
+impl AstNode for CssUniversalNamespacePrefix {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_ROOT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNIVERSAL_NAMESPACE_PREFIX as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_ROOT
+        kind == CSS_UNIVERSAL_NAMESPACE_PREFIX
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12621,34 +14275,29 @@ impl AstNode for CssRoot {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRoot {
+impl std::fmt::Debug for CssUniversalNamespacePrefix {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRoot")
-            .field(
-                "bom_token",
-                &support::DebugOptionalElement(self.bom_token()),
-            )
-            .field("rules", &self.rules())
-            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+        f.debug_struct("CssUniversalNamespacePrefix")
+            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
             .finish()
     }
 }
-impl From<CssRoot> for SyntaxNode {
-    fn from(n: CssRoot) -> SyntaxNode {
+impl From<CssUniversalNamespacePrefix> for SyntaxNode {
+    fn from(n: CssUniversalNamespacePrefix) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRoot> for SyntaxElement {
-    fn from(n: CssRoot) -> SyntaxElement {
+impl From<CssUniversalNamespacePrefix> for SyntaxElement {
+    fn from(n: CssUniversalNamespacePrefix) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssRuleBlock {
+impl AstNode for CssUniversalSelector {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_RULE_BLOCK as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNIVERSAL_SELECTOR as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_RULE_BLOCK
+        kind == CSS_UNIVERSAL_SELECTOR
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12664,37 +14313,33 @@ impl AstNode for CssRuleBlock {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssRuleBlock {
+impl std::fmt::Debug for CssUniversalSelector {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssRuleBlock")
-            .field(
-                "l_curly_token",
-                &support::DebugSyntaxResult(self.l_curly_token()),
-            )
-            .field("rules", &self.rules())
+        f.debug_struct("CssUniversalSelector")
             .field(
-                "r_curly_token",
-                &support::DebugSyntaxResult(self.r_curly_token()),
+                "namespace",
+                &support::DebugOptionalElement(self.namespace()),
             )
+            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
             .finish()
     }
 }
-impl From<CssRuleBlock> for SyntaxNode {
-    fn from(n: CssRuleBlock) -> SyntaxNode {
+impl From<CssUniversalSelector> for SyntaxNode {
+    fn from(n: CssUniversalSelector) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssRuleBlock> for SyntaxElement {
-    fn from(n: CssRuleBlock) -> SyntaxElement {
+impl From<CssUniversalSelector> for SyntaxElement {
+    fn from(n: CssUniversalSelector) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeAtRule {
+impl AstNode for CssUnknownDimension {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_DIMENSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_AT_RULE
+        kind == CSS_UNKNOWN_DIMENSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12710,34 +14355,33 @@ impl AstNode for CssScopeAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeAtRule {
+impl std::fmt::Debug for CssUnknownDimension {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeAtRule")
+        f.debug_struct("CssUnknownDimension")
             .field(
-                "scope_token",
-                &support::DebugSyntaxResult(self.scope_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
-            .field("range", &support::DebugOptionalElement(self.range()))
-            .field("block", &support::DebugSyntaxResult(self.block()))
+            .field("unit_token", &support::DebugSyntaxResult(self.unit_token()))
             .finish()
     }
 }
-impl From<CssScopeAtRule> for SyntaxNode {
-    fn from(n: CssScopeAtRule) -> SyntaxNode {
+impl From<CssUnknownDimension> for SyntaxNode {
+    fn from(n: CssUnknownDimension) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeAtRule> for SyntaxElement {
-    fn from(n: CssScopeAtRule) -> SyntaxElement {
+impl From<CssUnknownDimension> for SyntaxElement {
+    fn from(n: CssUnknownDimension) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeEdge {
+impl AstNode for CssUrlFunction {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_EDGE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_FUNCTION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_EDGE
+        kind == CSS_URL_FUNCTION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12753,14 +14397,16 @@ impl AstNode for CssScopeEdge {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeEdge {
+impl std::fmt::Debug for CssUrlFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeEdge")
+        f.debug_struct("CssUrlFunction")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("selectors", &self.selectors())
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .field("modifiers", &self.modifiers())
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -12768,22 +14414,22 @@ impl std::fmt::Debug for CssScopeEdge {
             .finish()
     }
 }
-impl From<CssScopeEdge> for SyntaxNode {
-    fn from(n: CssScopeEdge) -> SyntaxNode {
+impl From<CssUrlFunction> for SyntaxNode {
+    fn from(n: CssUrlFunction) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeEdge> for SyntaxElement {
-    fn from(n: CssScopeEdge) -> SyntaxElement {
+impl From<CssUrlFunction> for SyntaxElement {
+    fn from(n: CssUrlFunction) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeRangeEnd {
+impl AstNode for CssUrlValueRaw {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_END as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_VALUE_RAW as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_RANGE_END
+        kind == CSS_URL_VALUE_RAW
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12799,30 +14445,32 @@ impl AstNode for CssScopeRangeEnd {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeRangeEnd {
+impl std::fmt::Debug for CssUrlValueRaw {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeRangeEnd")
-            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
-            .field("end", &support::DebugSyntaxResult(self.end()))
+        f.debug_struct("CssUrlValueRaw")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
             .finish()
     }
 }
-impl From<CssScopeRangeEnd> for SyntaxNode {
-    fn from(n: CssScopeRangeEnd) -> SyntaxNode {
+impl From<CssUrlValueRaw> for SyntaxNode {
+    fn from(n: CssUrlValueRaw) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeRangeEnd> for SyntaxElement {
-    fn from(n: CssScopeRangeEnd) -> SyntaxElement {
+impl From<CssUrlValueRaw> for SyntaxElement {
+    fn from(n: CssUrlValueRaw) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeRangeInterval {
+impl AstNode for LessDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_INTERVAL as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(LESS_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_RANGE_INTERVAL
+        kind == LESS_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12838,31 +14486,38 @@ impl AstNode for CssScopeRangeInterval {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeRangeInterval {
+impl std::fmt::Debug for LessDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeRangeInterval")
-            .field("start", &support::DebugSyntaxResult(self.start()))
-            .field("to_token", &support::DebugSyntaxResult(self.to_token()))
-            .field("end", &support::DebugSyntaxResult(self.end()))
+        f.debug_struct("LessDeclaration")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
+            )
+            .field("value", &self.value())
+            .field(
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
+            )
             .finish()
     }
 }
-impl From<CssScopeRangeInterval> for SyntaxNode {
-    fn from(n: CssScopeRangeInterval) -> SyntaxNode {
+impl From<LessDeclaration> for SyntaxNode {
+    fn from(n: LessDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeRangeInterval> for SyntaxElement {
-    fn from(n: CssScopeRangeInterval) -> SyntaxElement {
+impl From<LessDeclaration> for SyntaxElement {
+    fn from(n: LessDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssScopeRangeStart {
+impl AstNode for LessIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SCOPE_RANGE_START as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(LESS_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SCOPE_RANGE_START
+        kind == LESS_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12878,29 +14533,30 @@ impl AstNode for CssScopeRangeStart {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssScopeRangeStart {
+impl std::fmt::Debug for LessIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssScopeRangeStart")
-            .field("start", &support::DebugSyntaxResult(self.start()))
+        f.debug_struct("LessIdentifier")
+            .field("at_token", &support::DebugSyntaxResult(self.at_token()))
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssScopeRangeStart> for SyntaxNode {
-    fn from(n: CssScopeRangeStart) -> SyntaxNode {
+impl From<LessIdentifier> for SyntaxNode {
+    fn from(n: LessIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssScopeRangeStart> for SyntaxElement {
-    fn from(n: CssScopeRangeStart) -> SyntaxElement {
+impl From<LessIdentifier> for SyntaxElement {
+    fn from(n: LessIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssStartingStyleAtRule {
+impl AstNode for LessKeywordArgument {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_STARTING_STYLE_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(LESS_KEYWORD_ARGUMENT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_STARTING_STYLE_AT_RULE
+        kind == LESS_KEYWORD_ARGUMENT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12916,33 +14572,34 @@ impl AstNode for CssStartingStyleAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssStartingStyleAtRule {
+impl std::fmt::Debug for LessKeywordArgument {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssStartingStyleAtRule")
+        f.debug_struct("LessKeywordArgument")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "starting_style_token",
-                &support::DebugSyntaxResult(self.starting_style_token()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("block", &support::DebugSyntaxResult(self.block()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssStartingStyleAtRule> for SyntaxNode {
-    fn from(n: CssStartingStyleAtRule) -> SyntaxNode {
+impl From<LessKeywordArgument> for SyntaxNode {
+    fn from(n: LessKeywordArgument) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssStartingStyleAtRule> for SyntaxElement {
-    fn from(n: CssStartingStyleAtRule) -> SyntaxElement {
+impl From<LessKeywordArgument> for SyntaxElement {
+    fn from(n: LessKeywordArgument) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssString {
+impl AstNode for LessMixinArguments {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_STRING as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(LESS_MIXIN_ARGUMENTS as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_STRING
+        kind == LESS_MIXIN_ARGUMENTS
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12958,32 +14615,37 @@ impl AstNode for CssString {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssString {
+impl std::fmt::Debug for LessMixinArguments {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssString")
+        f.debug_struct("LessMixinArguments")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("items", &self.items())
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
             .finish()
     }
 }
-impl From<CssString> for SyntaxNode {
-    fn from(n: CssString) -> SyntaxNode {
+impl From<LessMixinArguments> for SyntaxNode {
+    fn from(n: LessMixinArguments) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssString> for SyntaxElement {
-    fn from(n: CssString) -> SyntaxElement {
+impl From<LessMixinArguments> for SyntaxElement {
+    fn from(n: LessMixinArguments) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsAndCondition {
+impl AstNode for LessMixinCall {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_AND_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(LESS_MIXIN_CALL as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_AND_CONDITION
+        kind == LESS_MIXIN_CALL
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -12999,31 +14661,41 @@ impl AstNode for CssSupportsAndCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsAndCondition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsAndCondition")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("and_token", &support::DebugSyntaxResult(self.and_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+impl std::fmt::Debug for LessMixinCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LessMixinCall")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "arguments",
+                &support::DebugOptionalElement(self.arguments()),
+            )
+            .field(
+                "important",
+                &support::DebugOptionalElement(self.important()),
+            )
+            .field(
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
+            )
             .finish()
     }
 }
-impl From<CssSupportsAndCondition> for SyntaxNode {
-    fn from(n: CssSupportsAndCondition) -> SyntaxNode {
+impl From<LessMixinCall> for SyntaxNode {
+    fn from(n: LessMixinCall) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsAndCondition> for SyntaxElement {
-    fn from(n: CssSupportsAndCondition) -> SyntaxElement {
+impl From<LessMixinCall> for SyntaxElement {
+    fn from(n: LessMixinCall) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsAtRule {
+impl AstNode for LessMixinDefinition {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_AT_RULE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(LESS_MIXIN_DEFINITION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_AT_RULE
+        kind == LESS_MIXIN_DEFINITION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13039,34 +14711,32 @@ impl AstNode for CssSupportsAtRule {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsAtRule {
+impl std::fmt::Debug for LessMixinDefinition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsAtRule")
-            .field(
-                "supports_token",
-                &support::DebugSyntaxResult(self.supports_token()),
-            )
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
+        f.debug_struct("LessMixinDefinition")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("arguments", &support::DebugSyntaxResult(self.arguments()))
+            .field("guard", &support::DebugOptionalElement(self.guard()))
             .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssSupportsAtRule> for SyntaxNode {
-    fn from(n: CssSupportsAtRule) -> SyntaxNode {
+impl From<LessMixinDefinition> for SyntaxNode {
+    fn from(n: LessMixinDefinition) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsAtRule> for SyntaxElement {
-    fn from(n: CssSupportsAtRule) -> SyntaxElement {
+impl From<LessMixinDefinition> for SyntaxElement {
+    fn from(n: LessMixinDefinition) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsConditionInParens {
+impl AstNode for LessMixinGuard {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_CONDITION_IN_PARENS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(LESS_MIXIN_GUARD as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_CONDITION_IN_PARENS
+        kind == LESS_MIXIN_GUARD
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13082,37 +14752,30 @@ impl AstNode for CssSupportsConditionInParens {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsConditionInParens {
+impl std::fmt::Debug for LessMixinGuard {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsConditionInParens")
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("condition", &support::DebugSyntaxResult(self.condition()))
-            .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
-            )
+        f.debug_struct("LessMixinGuard")
+            .field("when_token", &support::DebugSyntaxResult(self.when_token()))
+            .field("conditions", &self.conditions())
             .finish()
     }
 }
-impl From<CssSupportsConditionInParens> for SyntaxNode {
-    fn from(n: CssSupportsConditionInParens) -> SyntaxNode {
+impl From<LessMixinGuard> for SyntaxNode {
+    fn from(n: LessMixinGuard) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsConditionInParens> for SyntaxElement {
-    fn from(n: CssSupportsConditionInParens) -> SyntaxElement {
+impl From<LessMixinGuard> for SyntaxElement {
+    fn from(n: LessMixinGuard) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsFeatureDeclaration {
+impl AstNode for LessParenthesizedValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_FEATURE_DECLARATION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(LESS_PARENTHESIZED_VALUE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_FEATURE_DECLARATION
+        kind == LESS_PARENTHESIZED_VALUE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13128,17 +14791,14 @@ impl AstNode for CssSupportsFeatureDeclaration {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsFeatureDeclaration {
+impl std::fmt::Debug for LessParenthesizedValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsFeatureDeclaration")
+        f.debug_struct("LessParenthesizedValue")
             .field(
                 "l_paren_token",
                 &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field(
-                "declaration",
-                &support::DebugSyntaxResult(self.declaration()),
-            )
+            .field("value", &self.value())
             .field(
                 "r_paren_token",
                 &support::DebugSyntaxResult(self.r_paren_token()),
@@ -13146,22 +14806,22 @@ impl std::fmt::Debug for CssSupportsFeatureDeclaration {
             .finish()
     }
 }
-impl From<CssSupportsFeatureDeclaration> for SyntaxNode {
-    fn from(n: CssSupportsFeatureDeclaration) -> SyntaxNode {
+impl From<LessParenthesizedValue> for SyntaxNode {
+    fn from(n: LessParenthesizedValue) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsFeatureDeclaration> for SyntaxElement {
-    fn from(n: CssSupportsFeatureDeclaration) -> SyntaxElement {
+impl From<LessParenthesizedValue> for SyntaxElement {
+    fn from(n: LessParenthesizedValue) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsFeatureSelector {
+impl AstNode for ScssAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_FEATURE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_FEATURE_SELECTOR
+        kind == SCSS_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13177,41 +14837,35 @@ impl AstNode for CssSupportsFeatureSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsFeatureSelector {
+impl std::fmt::Debug for ScssAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsFeatureSelector")
-            .field(
-                "selector_token",
-                &support::DebugSyntaxResult(self.selector_token()),
-            )
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("selector", &support::DebugSyntaxResult(self.selector()))
+        f.debug_struct("ScssAtRule")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("prelude", &self.prelude())
+            .field("block", &support::DebugOptionalElement(self.block()))
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssSupportsFeatureSelector> for SyntaxNode {
-    fn from(n: CssSupportsFeatureSelector) -> SyntaxNode {
+impl From<ScssAtRule> for SyntaxNode {
+    fn from(n: ScssAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsFeatureSelector> for SyntaxElement {
-    fn from(n: CssSupportsFeatureSelector) -> SyntaxElement {
+impl From<ScssAtRule> for SyntaxElement {
+    fn from(n: ScssAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsNotCondition {
+impl AstNode for ScssDeclaration {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_NOT_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_DECLARATION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_NOT_CONDITION
+        kind == SCSS_DECLARATION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13227,30 +14881,39 @@ impl AstNode for CssSupportsNotCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsNotCondition {
+impl std::fmt::Debug for ScssDeclaration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsNotCondition")
-            .field("not_token", &support::DebugSyntaxResult(self.not_token()))
-            .field("query", &support::DebugSyntaxResult(self.query()))
+        f.debug_struct("ScssDeclaration")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
+            )
+            .field("value", &self.value())
+            .field("modifiers", &self.modifiers())
+            .field(
+                "semicolon_token",
+                &support::DebugOptionalElement(self.semicolon_token()),
+            )
             .finish()
     }
 }
-impl From<CssSupportsNotCondition> for SyntaxNode {
-    fn from(n: CssSupportsNotCondition) -> SyntaxNode {
+impl From<ScssDeclaration> for SyntaxNode {
+    fn from(n: ScssDeclaration) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsNotCondition> for SyntaxElement {
-    fn from(n: CssSupportsNotCondition) -> SyntaxElement {
+impl From<ScssDeclaration> for SyntaxElement {
+    fn from(n: ScssDeclaration) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssSupportsOrCondition {
+impl AstNode for ScssIdentifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_SUPPORTS_OR_CONDITION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_IDENTIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_SUPPORTS_OR_CONDITION
+        kind == SCSS_IDENTIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13266,31 +14929,33 @@ impl AstNode for CssSupportsOrCondition {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssSupportsOrCondition {
+impl std::fmt::Debug for ScssIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssSupportsOrCondition")
-            .field("left", &support::DebugSyntaxResult(self.left()))
-            .field("or_token", &support::DebugSyntaxResult(self.or_token()))
-            .field("right", &support::DebugSyntaxResult(self.right()))
+        f.debug_struct("ScssIdentifier")
+            .field(
+                "dollar_token",
+                &support::DebugSyntaxResult(self.dollar_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .finish()
     }
 }
-impl From<CssSupportsOrCondition> for SyntaxNode {
-    fn from(n: CssSupportsOrCondition) -> SyntaxNode {
+impl From<ScssIdentifier> for SyntaxNode {
+    fn from(n: ScssIdentifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssSupportsOrCondition> for SyntaxElement {
-    fn from(n: CssSupportsOrCondition) -> SyntaxElement {
+impl From<ScssIdentifier> for SyntaxElement {
+    fn from(n: ScssIdentifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssTypeSelector {
+impl AstNode for ScssKeywordArgument {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_TYPE_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_KEYWORD_ARGUMENT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_TYPE_SELECTOR
+        kind == SCSS_KEYWORD_ARGUMENT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13306,33 +14971,34 @@ impl AstNode for CssTypeSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssTypeSelector {
+impl std::fmt::Debug for ScssKeywordArgument {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssTypeSelector")
+        f.debug_struct("ScssKeywordArgument")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "namespace",
-                &support::DebugOptionalElement(self.namespace()),
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
             )
-            .field("ident", &support::DebugSyntaxResult(self.ident()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssTypeSelector> for SyntaxNode {
-    fn from(n: CssTypeSelector) -> SyntaxNode {
+impl From<ScssKeywordArgument> for SyntaxNode {
+    fn from(n: ScssKeywordArgument) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssTypeSelector> for SyntaxElement {
-    fn from(n: CssTypeSelector) -> SyntaxElement {
+impl From<ScssKeywordArgument> for SyntaxElement {
+    fn from(n: ScssKeywordArgument) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUniversalNamespacePrefix {
+impl AstNode for ScssVariableModifier {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNIVERSAL_NAMESPACE_PREFIX as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_VARIABLE_MODIFIER as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNIVERSAL_NAMESPACE_PREFIX
+        kind == SCSS_VARIABLE_MODIFIER
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13348,29 +15014,30 @@ impl AstNode for CssUniversalNamespacePrefix {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUniversalNamespacePrefix {
+impl std::fmt::Debug for ScssVariableModifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUniversalNamespacePrefix")
-            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
+        f.debug_struct("ScssVariableModifier")
+            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<CssUniversalNamespacePrefix> for SyntaxNode {
-    fn from(n: CssUniversalNamespacePrefix) -> SyntaxNode {
+impl From<ScssVariableModifier> for SyntaxNode {
+    fn from(n: ScssVariableModifier) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUniversalNamespacePrefix> for SyntaxElement {
-    fn from(n: CssUniversalNamespacePrefix) -> SyntaxElement {
+impl From<ScssVariableModifier> for SyntaxElement {
+    fn from(n: ScssVariableModifier) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUniversalSelector {
+impl AstNode for TwApplyAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNIVERSAL_SELECTOR as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(TW_APPLY_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNIVERSAL_SELECTOR
+        kind == TW_APPLY_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13386,33 +15053,41 @@ impl AstNode for CssUniversalSelector {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUniversalSelector {
+impl std::fmt::Debug for TwApplyAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUniversalSelector")
+        f.debug_struct("TwApplyAtRule")
             .field(
-                "namespace",
-                &support::DebugOptionalElement(self.namespace()),
+                "apply_token",
+                &support::DebugSyntaxResult(self.apply_token()),
+            )
+            .field("classes", &self.classes())
+            .field(
+                "important",
+                &support::DebugOptionalElement(self.important()),
+            )
+            .field(
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
-            .field("star_token", &support::DebugSyntaxResult(self.star_token()))
             .finish()
     }
 }
-impl From<CssUniversalSelector> for SyntaxNode {
-    fn from(n: CssUniversalSelector) -> SyntaxNode {
+impl From<TwApplyAtRule> for SyntaxNode {
+    fn from(n: TwApplyAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUniversalSelector> for SyntaxElement {
-    fn from(n: CssUniversalSelector) -> SyntaxElement {
+impl From<TwApplyAtRule> for SyntaxElement {
+    fn from(n: TwApplyAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUnknownDimension {
+impl AstNode for TwFunction {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_UNKNOWN_DIMENSION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(TW_FUNCTION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_UNKNOWN_DIMENSION
+        kind == TW_FUNCTION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13428,33 +15103,38 @@ impl AstNode for CssUnknownDimension {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUnknownDimension {
+impl std::fmt::Debug for TwFunction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUnknownDimension")
+        f.debug_struct("TwFunction")
+            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
+            )
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .field(
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
             )
-            .field("unit_token", &support::DebugSyntaxResult(self.unit_token()))
             .finish()
     }
 }
-impl From<CssUnknownDimension> for SyntaxNode {
-    fn from(n: CssUnknownDimension) -> SyntaxNode {
+impl From<TwFunction> for SyntaxNode {
+    fn from(n: TwFunction) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUnknownDimension> for SyntaxElement {
-    fn from(n: CssUnknownDimension) -> SyntaxElement {
+impl From<TwFunction> for SyntaxElement {
+    fn from(n: TwFunction) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUrlFunction {
+impl AstNode for TwScreenAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_FUNCTION as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(TW_SCREEN_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_URL_FUNCTION
+        kind == TW_SCREEN_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13470,39 +15150,34 @@ impl AstNode for CssUrlFunction {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUrlFunction {
+impl std::fmt::Debug for TwScreenAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUrlFunction")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "l_paren_token",
-                &support::DebugSyntaxResult(self.l_paren_token()),
-            )
-            .field("value", &support::DebugOptionalElement(self.value()))
-            .field("modifiers", &self.modifiers())
+        f.debug_struct("TwScreenAtRule")
             .field(
-                "r_paren_token",
-                &support::DebugSyntaxResult(self.r_paren_token()),
+                "screen_token",
+                &support::DebugSyntaxResult(self.screen_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("block", &support::DebugSyntaxResult(self.block()))
             .finish()
     }
 }
-impl From<CssUrlFunction> for SyntaxNode {
-    fn from(n: CssUrlFunction) -> SyntaxNode {
+impl From<TwScreenAtRule> for SyntaxNode {
+    fn from(n: TwScreenAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUrlFunction> for SyntaxElement {
-    fn from(n: CssUrlFunction) -> SyntaxElement {
+impl From<TwScreenAtRule> for SyntaxElement {
+    fn from(n: TwScreenAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for CssUrlValueRaw {
+impl AstNode for TwTailwindAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(CSS_URL_VALUE_RAW as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(TW_TAILWIND_AT_RULE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == CSS_URL_VALUE_RAW
+        kind == TW_TAILWIND_AT_RULE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -13518,23 +15193,28 @@ impl AstNode for CssUrlValueRaw {
         self.syntax
     }
 }
-impl std::fmt::Debug for CssUrlValueRaw {
+impl std::fmt::Debug for TwTailwindAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("CssUrlValueRaw")
+        f.debug_struct("TwTailwindAtRule")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "tailwind_token",
+                &support::DebugSyntaxResult(self.tailwind_token()),
+            )
+            .field("layer", &support::DebugSyntaxResult(self.layer()))
+            .field(
+                "semicolon_token",
+                &support::DebugSyntaxResult(self.semicolon_token()),
             )
             .finish()
     }
 }
-impl From<CssUrlValueRaw> for SyntaxNode {
-    fn from(n: CssUrlValueRaw) -> SyntaxNode {
+impl From<TwTailwindAtRule> for SyntaxNode {
+    fn from(n: TwTailwindAtRule) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<CssUrlValueRaw> for SyntaxElement {
-    fn from(n: CssUrlValueRaw) -> SyntaxElement {
+impl From<TwTailwindAtRule> for SyntaxElement {
+    fn from(n: TwTailwindAtRule) -> SyntaxElement {
         n.syntax.into()
     }
 }
@@ -13633,6 +15313,26 @@ impl From<CssSupportsAtRule> for AnyCssAtRule {
         AnyCssAtRule::CssSupportsAtRule(node)
     }
 }
+impl From<ScssAtRule> for AnyCssAtRule {
+    fn from(node: ScssAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::ScssAtRule(node)
+    }
+}
+impl From<TwApplyAtRule> for AnyCssAtRule {
+    fn from(node: TwApplyAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::TwApplyAtRule(node)
+    }
+}
+impl From<TwScreenAtRule> for AnyCssAtRule {
+    fn from(node: TwScreenAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::TwScreenAtRule(node)
+    }
+}
+impl From<TwTailwindAtRule> for AnyCssAtRule {
+    fn from(node: TwTailwindAtRule) -> AnyCssAtRule {
+        AnyCssAtRule::TwTailwindAtRule(node)
+    }
+}
 impl AstNode for AnyCssAtRule {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = CssBogusAtRule::KIND_SET
@@ -13653,7 +15353,11 @@ impl AstNode for AnyCssAtRule {
         .union(CssPropertyAtRule::KIND_SET)
         .union(CssScopeAtRule::KIND_SET)
         .union(CssStartingStyleAtRule::KIND_SET)
-        .union(CssSupportsAtRule::KIND_SET);
+        .union(CssSupportsAtRule::KIND_SET)
+        .union(ScssAtRule::KIND_SET)
+        .union(TwApplyAtRule::KIND_SET)
+        .union(TwScreenAtRule::KIND_SET)
+        .union(TwTailwindAtRule::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
@@ -13676,6 +15380,10 @@ impl AstNode for AnyCssAtRule {
                 | CSS_SCOPE_AT_RULE
                 | CSS_STARTING_STYLE_AT_RULE
                 | CSS_SUPPORTS_AT_RULE
+                | SCSS_AT_RULE
+                | TW_APPLY_AT_RULE
+                | TW_SCREEN_AT_RULE
+                | TW_TAILWIND_AT_RULE
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -13715,6 +15423,10 @@ impl AstNode for AnyCssAtRule {
                 AnyCssAtRule::CssStartingStyleAtRule(CssStartingStyleAtRule { syntax })
             }
             CSS_SUPPORTS_AT_RULE => AnyCssAtRule::CssSupportsAtRule(CssSupportsAtRule { syntax }),
+            SCSS_AT_RULE => AnyCssAtRule::ScssAtRule(ScssAtRule { syntax }),
+            TW_APPLY_AT_RULE => AnyCssAtRule::TwApplyAtRule(TwApplyAtRule { syntax }),
+            TW_SCREEN_AT_RULE => AnyCssAtRule::TwScreenAtRule(TwScreenAtRule { syntax }),
+            TW_TAILWIND_AT_RULE => AnyCssAtRule::TwTailwindAtRule(TwTailwindAtRule { syntax }),
             _ => return None,
         };
         Some(res)
@@ -13740,6 +15452,10 @@ impl AstNode for AnyCssAtRule {
             AnyCssAtRule::CssScopeAtRule(it) => &it.syntax,
             AnyCssAtRule::CssStartingStyleAtRule(it) => &it.syntax,
             AnyCssAtRule::CssSupportsAtRule(it) => &it.syntax,
+            AnyCssAtRule::ScssAtRule(it) => &it.syntax,
+            AnyCssAtRule::TwApplyAtRule(it) => &it.syntax,
+            AnyCssAtRule::TwScreenAtRule(it) => &it.syntax,
+            AnyCssAtRule::TwTailwindAtRule(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -13763,6 +15479,10 @@ impl AstNode for AnyCssAtRule {
             AnyCssAtRule::CssScopeAtRule(it) => it.syntax,
             AnyCssAtRule::CssStartingStyleAtRule(it) => it.syntax,
             AnyCssAtRule::CssSupportsAtRule(it) => it.syntax,
+            AnyCssAtRule::ScssAtRule(it) => it.syntax,
+            AnyCssAtRule::TwApplyAtRule(it) => it.syntax,
+            AnyCssAtRule::TwScreenAtRule(it) => it.syntax,
+            AnyCssAtRule::TwTailwindAtRule(it) => it.syntax,
         }
     }
 }
@@ -13788,6 +15508,10 @@ impl std::fmt::Debug for AnyCssAtRule {
             AnyCssAtRule::CssScopeAtRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssAtRule::CssStartingStyleAtRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssAtRule::CssSupportsAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::ScssAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::TwApplyAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::TwScreenAtRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssAtRule::TwTailwindAtRule(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -13813,6 +15537,10 @@ impl From<AnyCssAtRule> for SyntaxNode {
             AnyCssAtRule::CssScopeAtRule(it) => it.into(),
             AnyCssAtRule::CssStartingStyleAtRule(it) => it.into(),
             AnyCssAtRule::CssSupportsAtRule(it) => it.into(),
+            AnyCssAtRule::ScssAtRule(it) => it.into(),
+            AnyCssAtRule::TwApplyAtRule(it) => it.into(),
+            AnyCssAtRule::TwScreenAtRule(it) => it.into(),
+            AnyCssAtRule::TwTailwindAtRule(it) => it.into(),
         }
     }
 }
@@ -15265,17 +16993,31 @@ impl From<CssParenthesizedExpression> for AnyCssExpression {
         AnyCssExpression::CssParenthesizedExpression(node)
     }
 }
+impl From<LessKeywordArgument> for AnyCssExpression {
+    fn from(node: LessKeywordArgument) -> AnyCssExpression {
+        AnyCssExpression::LessKeywordArgument(node)
+    }
+}
+impl From<ScssKeywordArgument> for AnyCssExpression {
+    fn from(node: ScssKeywordArgument) -> AnyCssExpression {
+        AnyCssExpression::ScssKeywordArgument(node)
+    }
+}
 impl AstNode for AnyCssExpression {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = CssBinaryExpression::KIND_SET
         .union(CssListOfComponentValuesExpression::KIND_SET)
-        .union(CssParenthesizedExpression::KIND_SET);
+        .union(CssParenthesizedExpression::KIND_SET)
+        .union(LessKeywordArgument::KIND_SET)
+        .union(ScssKeywordArgument::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
             CSS_BINARY_EXPRESSION
                 | CSS_LIST_OF_COMPONENT_VALUES_EXPRESSION
                 | CSS_PARENTHESIZED_EXPRESSION
+                | LESS_KEYWORD_ARGUMENT
+                | SCSS_KEYWORD_ARGUMENT
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -15291,6 +17033,12 @@ impl AstNode for AnyCssExpression {
             CSS_PARENTHESIZED_EXPRESSION => {
                 AnyCssExpression::CssParenthesizedExpression(CssParenthesizedExpression { syntax })
             }
+            LESS_KEYWORD_ARGUMENT => {
+                AnyCssExpression::LessKeywordArgument(LessKeywordArgument { syntax })
+            }
+            SCSS_KEYWORD_ARGUMENT => {
+                AnyCssExpression::ScssKeywordArgument(ScssKeywordArgument { syntax })
+            }
             _ => return None,
         };
         Some(res)
@@ -15300,6 +17048,8 @@ impl AstNode for AnyCssExpression {
             AnyCssExpression::CssBinaryExpression(it) => &it.syntax,
             AnyCssExpression::CssListOfComponentValuesExpression(it) => &it.syntax,
             AnyCssExpression::CssParenthesizedExpression(it) => &it.syntax,
+            AnyCssExpression::LessKeywordArgument(it) => &it.syntax,
+            AnyCssExpression::ScssKeywordArgument(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -15307,6 +17057,8 @@ impl AstNode for AnyCssExpression {
             AnyCssExpression::CssBinaryExpression(it) => it.syntax,
             AnyCssExpression::CssListOfComponentValuesExpression(it) => it.syntax,
             AnyCssExpression::CssParenthesizedExpression(it) => it.syntax,
+            AnyCssExpression::LessKeywordArgument(it) => it.syntax,
+            AnyCssExpression::ScssKeywordArgument(it) => it.syntax,
         }
     }
 }
@@ -15316,6 +17068,8 @@ impl std::fmt::Debug for AnyCssExpression {
             AnyCssExpression::CssBinaryExpression(it) => std::fmt::Debug::fmt(it, f),
             AnyCssExpression::CssListOfComponentValuesExpression(it) => std::fmt::Debug::fmt(it, f),
             AnyCssExpression::CssParenthesizedExpression(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssExpression::LessKeywordArgument(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssExpression::ScssKeywordArgument(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -15325,6 +17079,8 @@ impl From<AnyCssExpression> for SyntaxNode {
             AnyCssExpression::CssBinaryExpression(it) => it.into(),
             AnyCssExpression::CssListOfComponentValuesExpression(it) => it.into(),
             AnyCssExpression::CssParenthesizedExpression(it) => it.into(),
+            AnyCssExpression::LessKeywordArgument(it) => it.into(),
+            AnyCssExpression::ScssKeywordArgument(it) => it.into(),
         }
     }
 }
@@ -15565,16 +17321,24 @@ impl From<CssUrlFunction> for AnyCssFunction {
         AnyCssFunction::CssUrlFunction(node)
     }
 }
+impl From<TwFunction> for AnyCssFunction {
+    fn from(node: TwFunction) -> AnyCssFunction {
+        AnyCssFunction::TwFunction(node)
+    }
+}
 impl AstNode for AnyCssFunction {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = CssFunction::KIND_SET.union(CssUrlFunction::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = CssFunction::KIND_SET
+        .union(CssUrlFunction::KIND_SET)
+        .union(TwFunction::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, CSS_FUNCTION | CSS_URL_FUNCTION)
+        matches!(kind, CSS_FUNCTION | CSS_URL_FUNCTION | TW_FUNCTION)
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
             CSS_FUNCTION => AnyCssFunction::CssFunction(CssFunction { syntax }),
             CSS_URL_FUNCTION => AnyCssFunction::CssUrlFunction(CssUrlFunction { syntax }),
+            TW_FUNCTION => AnyCssFunction::TwFunction(TwFunction { syntax }),
             _ => return None,
         };
         Some(res)
@@ -15583,12 +17347,14 @@ impl AstNode for AnyCssFunction {
         match self {
             AnyCssFunction::CssFunction(it) => &it.syntax,
             AnyCssFunction::CssUrlFunction(it) => &it.syntax,
+            AnyCssFunction::TwFunction(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             AnyCssFunction::CssFunction(it) => it.syntax,
             AnyCssFunction::CssUrlFunction(it) => it.syntax,
+            AnyCssFunction::TwFunction(it) => it.syntax,
         }
     }
 }
@@ -15597,6 +17363,7 @@ impl std::fmt::Debug for AnyCssFunction {
         match self {
             AnyCssFunction::CssFunction(it) => std::fmt::Debug::fmt(it, f),
             AnyCssFunction::CssUrlFunction(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssFunction::TwFunction(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -15605,6 +17372,7 @@ impl From<AnyCssFunction> for SyntaxNode {
         match n {
             AnyCssFunction::CssFunction(it) => it.into(),
             AnyCssFunction::CssUrlFunction(it) => it.into(),
+            AnyCssFunction::TwFunction(it) => it.into(),
         }
     }
 }
@@ -16537,14 +18305,20 @@ impl From<CssMediaConditionQuery> for AnyCssMediaQuery {
         AnyCssMediaQuery::CssMediaConditionQuery(node)
     }
 }
+impl From<TwFunction> for AnyCssMediaQuery {
+    fn from(node: TwFunction) -> AnyCssMediaQuery {
+        AnyCssMediaQuery::TwFunction(node)
+    }
+}
 impl AstNode for AnyCssMediaQuery {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = AnyCssMediaTypeQuery::KIND_SET
         .union(CssBogusMediaQuery::KIND_SET)
-        .union(CssMediaConditionQuery::KIND_SET);
+        .union(CssMediaConditionQuery::KIND_SET)
+        .union(TwFunction::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
-            CSS_BOGUS_MEDIA_QUERY | CSS_MEDIA_CONDITION_QUERY => true,
+            CSS_BOGUS_MEDIA_QUERY | CSS_MEDIA_CONDITION_QUERY | TW_FUNCTION => true,
             k if AnyCssMediaTypeQuery::can_cast(k) => true,
             _ => false,
         }
@@ -16557,6 +18331,7 @@ impl AstNode for AnyCssMediaQuery {
             CSS_MEDIA_CONDITION_QUERY => {
                 AnyCssMediaQuery::CssMediaConditionQuery(CssMediaConditionQuery { syntax })
             }
+            TW_FUNCTION => AnyCssMediaQuery::TwFunction(TwFunction { syntax }),
             _ => {
                 if let Some(any_css_media_type_query) = AnyCssMediaTypeQuery::cast(syntax) {
                     return Some(AnyCssMediaQuery::AnyCssMediaTypeQuery(
@@ -16572,6 +18347,7 @@ impl AstNode for AnyCssMediaQuery {
         match self {
             AnyCssMediaQuery::CssBogusMediaQuery(it) => &it.syntax,
             AnyCssMediaQuery::CssMediaConditionQuery(it) => &it.syntax,
+            AnyCssMediaQuery::TwFunction(it) => &it.syntax,
             AnyCssMediaQuery::AnyCssMediaTypeQuery(it) => it.syntax(),
         }
     }
@@ -16579,6 +18355,7 @@ impl AstNode for AnyCssMediaQuery {
         match self {
             AnyCssMediaQuery::CssBogusMediaQuery(it) => it.syntax,
             AnyCssMediaQuery::CssMediaConditionQuery(it) => it.syntax,
+            AnyCssMediaQuery::TwFunction(it) => it.syntax,
             AnyCssMediaQuery::AnyCssMediaTypeQuery(it) => it.into_syntax(),
         }
     }
@@ -16589,6 +18366,7 @@ impl std::fmt::Debug for AnyCssMediaQuery {
             AnyCssMediaQuery::AnyCssMediaTypeQuery(it) => std::fmt::Debug::fmt(it, f),
             AnyCssMediaQuery::CssBogusMediaQuery(it) => std::fmt::Debug::fmt(it, f),
             AnyCssMediaQuery::CssMediaConditionQuery(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssMediaQuery::TwFunction(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -16598,6 +18376,7 @@ impl From<AnyCssMediaQuery> for SyntaxNode {
             AnyCssMediaQuery::AnyCssMediaTypeQuery(it) => it.into(),
             AnyCssMediaQuery::CssBogusMediaQuery(it) => it.into(),
             AnyCssMediaQuery::CssMediaConditionQuery(it) => it.into(),
+            AnyCssMediaQuery::TwFunction(it) => it.into(),
         }
     }
 }
@@ -18014,9 +19793,29 @@ impl From<CssNestedQualifiedRule> for AnyCssRule {
         AnyCssRule::CssNestedQualifiedRule(node)
     }
 }
-impl From<CssQualifiedRule> for AnyCssRule {
-    fn from(node: CssQualifiedRule) -> AnyCssRule {
-        AnyCssRule::CssQualifiedRule(node)
+impl From<CssQualifiedRule> for AnyCssRule {
+    fn from(node: CssQualifiedRule) -> AnyCssRule {
+        AnyCssRule::CssQualifiedRule(node)
+    }
+}
+impl From<LessDeclaration> for AnyCssRule {
+    fn from(node: LessDeclaration) -> AnyCssRule {
+        AnyCssRule::LessDeclaration(node)
+    }
+}
+impl From<LessMixinCall> for AnyCssRule {
+    fn from(node: LessMixinCall) -> AnyCssRule {
+        AnyCssRule::LessMixinCall(node)
+    }
+}
+impl From<LessMixinDefinition> for AnyCssRule {
+    fn from(node: LessMixinDefinition) -> AnyCssRule {
+        AnyCssRule::LessMixinDefinition(node)
+    }
+}
+impl From<ScssDeclaration> for AnyCssRule {
+    fn from(node: ScssDeclaration) -> AnyCssRule {
+        AnyCssRule::ScssDeclaration(node)
     }
 }
 impl AstNode for AnyCssRule {
@@ -18024,11 +19823,22 @@ impl AstNode for AnyCssRule {
     const KIND_SET: SyntaxKindSet<Language> = CssAtRule::KIND_SET
         .union(CssBogusRule::KIND_SET)
         .union(CssNestedQualifiedRule::KIND_SET)
-        .union(CssQualifiedRule::KIND_SET);
+        .union(CssQualifiedRule::KIND_SET)
+        .union(LessDeclaration::KIND_SET)
+        .union(LessMixinCall::KIND_SET)
+        .union(LessMixinDefinition::KIND_SET)
+        .union(ScssDeclaration::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
-            CSS_AT_RULE | CSS_BOGUS_RULE | CSS_NESTED_QUALIFIED_RULE | CSS_QUALIFIED_RULE
+            CSS_AT_RULE
+                | CSS_BOGUS_RULE
+                | CSS_NESTED_QUALIFIED_RULE
+                | CSS_QUALIFIED_RULE
+                | LESS_DECLARATION
+                | LESS_MIXIN_CALL
+                | LESS_MIXIN_DEFINITION
+                | SCSS_DECLARATION
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -18039,6 +19849,12 @@ impl AstNode for AnyCssRule {
                 AnyCssRule::CssNestedQualifiedRule(CssNestedQualifiedRule { syntax })
             }
             CSS_QUALIFIED_RULE => AnyCssRule::CssQualifiedRule(CssQualifiedRule { syntax }),
+            LESS_DECLARATION => AnyCssRule::LessDeclaration(LessDeclaration { syntax }),
+            LESS_MIXIN_CALL => AnyCssRule::LessMixinCall(LessMixinCall { syntax }),
+            LESS_MIXIN_DEFINITION => {
+                AnyCssRule::LessMixinDefinition(LessMixinDefinition { syntax })
+            }
+            SCSS_DECLARATION => AnyCssRule::ScssDeclaration(ScssDeclaration { syntax }),
             _ => return None,
         };
         Some(res)
@@ -18049,6 +19865,10 @@ impl AstNode for AnyCssRule {
             AnyCssRule::CssBogusRule(it) => &it.syntax,
             AnyCssRule::CssNestedQualifiedRule(it) => &it.syntax,
             AnyCssRule::CssQualifiedRule(it) => &it.syntax,
+            AnyCssRule::LessDeclaration(it) => &it.syntax,
+            AnyCssRule::LessMixinCall(it) => &it.syntax,
+            AnyCssRule::LessMixinDefinition(it) => &it.syntax,
+            AnyCssRule::ScssDeclaration(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -18057,6 +19877,10 @@ impl AstNode for AnyCssRule {
             AnyCssRule::CssBogusRule(it) => it.syntax,
             AnyCssRule::CssNestedQualifiedRule(it) => it.syntax,
             AnyCssRule::CssQualifiedRule(it) => it.syntax,
+            AnyCssRule::LessDeclaration(it) => it.syntax,
+            AnyCssRule::LessMixinCall(it) => it.syntax,
+            AnyCssRule::LessMixinDefinition(it) => it.syntax,
+            AnyCssRule::ScssDeclaration(it) => it.syntax,
         }
     }
 }
@@ -18067,6 +19891,10 @@ impl std::fmt::Debug for AnyCssRule {
             AnyCssRule::CssBogusRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssRule::CssNestedQualifiedRule(it) => std::fmt::Debug::fmt(it, f),
             AnyCssRule::CssQualifiedRule(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssRule::LessDeclaration(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssRule::LessMixinCall(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssRule::LessMixinDefinition(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssRule::ScssDeclaration(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -18077,6 +19905,10 @@ impl From<AnyCssRule> for SyntaxNode {
             AnyCssRule::CssBogusRule(it) => it.into(),
             AnyCssRule::CssNestedQualifiedRule(it) => it.into(),
             AnyCssRule::CssQualifiedRule(it) => it.into(),
+            AnyCssRule::LessDeclaration(it) => it.into(),
+            AnyCssRule::LessMixinCall(it) => it.into(),
+            AnyCssRule::LessMixinDefinition(it) => it.into(),
+            AnyCssRule::ScssDeclaration(it) => it.into(),
         }
     }
 }
@@ -19098,6 +20930,21 @@ impl From<CssString> for AnyCssValue {
         AnyCssValue::CssString(node)
     }
 }
+impl From<LessIdentifier> for AnyCssValue {
+    fn from(node: LessIdentifier) -> AnyCssValue {
+        AnyCssValue::LessIdentifier(node)
+    }
+}
+impl From<LessParenthesizedValue> for AnyCssValue {
+    fn from(node: LessParenthesizedValue) -> AnyCssValue {
+        AnyCssValue::LessParenthesizedValue(node)
+    }
+}
+impl From<ScssIdentifier> for AnyCssValue {
+    fn from(node: ScssIdentifier) -> AnyCssValue {
+        AnyCssValue::ScssIdentifier(node)
+    }
+}
 impl AstNode for AnyCssValue {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = AnyCssDimension::KIND_SET
@@ -19108,7 +20955,10 @@ impl AstNode for AnyCssValue {
         .union(CssIdentifier::KIND_SET)
         .union(CssNumber::KIND_SET)
         .union(CssRatio::KIND_SET)
-        .union(CssString::KIND_SET);
+        .union(CssString::KIND_SET)
+        .union(LessIdentifier::KIND_SET)
+        .union(LessParenthesizedValue::KIND_SET)
+        .union(ScssIdentifier::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         match kind {
             CSS_COLOR
@@ -19117,7 +20967,10 @@ impl AstNode for AnyCssValue {
             | CSS_IDENTIFIER
             | CSS_NUMBER
             | CSS_RATIO
-            | CSS_STRING => true,
+            | CSS_STRING
+            | LESS_IDENTIFIER
+            | LESS_PARENTHESIZED_VALUE
+            | SCSS_IDENTIFIER => true,
             k if AnyCssDimension::can_cast(k) => true,
             k if AnyCssFunction::can_cast(k) => true,
             _ => false,
@@ -19136,6 +20989,11 @@ impl AstNode for AnyCssValue {
             CSS_NUMBER => AnyCssValue::CssNumber(CssNumber { syntax }),
             CSS_RATIO => AnyCssValue::CssRatio(CssRatio { syntax }),
             CSS_STRING => AnyCssValue::CssString(CssString { syntax }),
+            LESS_IDENTIFIER => AnyCssValue::LessIdentifier(LessIdentifier { syntax }),
+            LESS_PARENTHESIZED_VALUE => {
+                AnyCssValue::LessParenthesizedValue(LessParenthesizedValue { syntax })
+            }
+            SCSS_IDENTIFIER => AnyCssValue::ScssIdentifier(ScssIdentifier { syntax }),
             _ => {
                 if let Some(any_css_dimension) = AnyCssDimension::cast(syntax.clone()) {
                     return Some(AnyCssValue::AnyCssDimension(any_css_dimension));
@@ -19157,6 +21015,9 @@ impl AstNode for AnyCssValue {
             AnyCssValue::CssNumber(it) => &it.syntax,
             AnyCssValue::CssRatio(it) => &it.syntax,
             AnyCssValue::CssString(it) => &it.syntax,
+            AnyCssValue::LessIdentifier(it) => &it.syntax,
+            AnyCssValue::LessParenthesizedValue(it) => &it.syntax,
+            AnyCssValue::ScssIdentifier(it) => &it.syntax,
             AnyCssValue::AnyCssDimension(it) => it.syntax(),
             AnyCssValue::AnyCssFunction(it) => it.syntax(),
         }
@@ -19170,6 +21031,9 @@ impl AstNode for AnyCssValue {
             AnyCssValue::CssNumber(it) => it.syntax,
             AnyCssValue::CssRatio(it) => it.syntax,
             AnyCssValue::CssString(it) => it.syntax,
+            AnyCssValue::LessIdentifier(it) => it.syntax,
+            AnyCssValue::LessParenthesizedValue(it) => it.syntax,
+            AnyCssValue::ScssIdentifier(it) => it.syntax,
             AnyCssValue::AnyCssDimension(it) => it.into_syntax(),
             AnyCssValue::AnyCssFunction(it) => it.into_syntax(),
         }
@@ -19187,6 +21051,9 @@ impl std::fmt::Debug for AnyCssValue {
             AnyCssValue::CssNumber(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssRatio(it) => std::fmt::Debug::fmt(it, f),
             AnyCssValue::CssString(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::LessIdentifier(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::LessParenthesizedValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyCssValue::ScssIdentifier(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -19202,6 +21069,9 @@ impl From<AnyCssValue> for SyntaxNode {
             AnyCssValue::CssNumber(it) => it.into(),
             AnyCssValue::CssRatio(it) => it.into(),
             AnyCssValue::CssString(it) => it.into(),
+            AnyCssValue::LessIdentifier(it) => it.into(),
+            AnyCssValue::LessParenthesizedValue(it) => it.into(),
+            AnyCssValue::ScssIdentifier(it) => it.into(),
         }
     }
 }
@@ -19211,6 +21081,66 @@ impl From<AnyCssValue> for SyntaxElement {
         node.into()
     }
 }
+impl From<CssClassSelector> for AnyLessMixinName {
+    fn from(node: CssClassSelector) -> AnyLessMixinName {
+        AnyLessMixinName::CssClassSelector(node)
+    }
+}
+impl From<CssIdSelector> for AnyLessMixinName {
+    fn from(node: CssIdSelector) -> AnyLessMixinName {
+        AnyLessMixinName::CssIdSelector(node)
+    }
+}
+impl AstNode for AnyLessMixinName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        CssClassSelector::KIND_SET.union(CssIdSelector::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, CSS_CLASS_SELECTOR | CSS_ID_SELECTOR)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            CSS_CLASS_SELECTOR => AnyLessMixinName::CssClassSelector(CssClassSelector { syntax }),
+            CSS_ID_SELECTOR => AnyLessMixinName::CssIdSelector(CssIdSelector { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyLessMixinName::CssClassSelector(it) => &it.syntax,
+            AnyLessMixinName::CssIdSelector(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyLessMixinName::CssClassSelector(it) => it.syntax,
+            AnyLessMixinName::CssIdSelector(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyLessMixinName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyLessMixinName::CssClassSelector(it) => std::fmt::Debug::fmt(it, f),
+            AnyLessMixinName::CssIdSelector(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyLessMixinName> for SyntaxNode {
+    fn from(n: AnyLessMixinName) -> SyntaxNode {
+        match n {
+            AnyLessMixinName::CssClassSelector(it) => it.into(),
+            AnyLessMixinName::CssIdSelector(it) => it.into(),
+        }
+    }
+}
+impl From<AnyLessMixinName> for SyntaxElement {
+    fn from(n: AnyLessMixinName) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
 impl std::fmt::Display for AnyCssAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -19561,6 +21491,11 @@ impl std::fmt::Display for AnyCssValue {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyLessMixinName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for CssAtRule {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -20231,6 +22166,91 @@ impl std::fmt::Display for CssUrlValueRaw {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for LessDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for LessIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for LessKeywordArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for LessMixinArguments {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for LessMixinCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for LessMixinDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for LessMixinGuard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for LessParenthesizedValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssDeclaration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssIdentifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssKeywordArgument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for ScssVariableModifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TwApplyAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TwFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TwScreenAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for TwTailwindAtRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct CssBogus {
@@ -23560,6 +25580,172 @@ impl IntoIterator for CssUrlModifierList {
         self.iter()
     }
 }
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct ScssVariableModifierList {
+    syntax_list: SyntaxList,
+}
+impl ScssVariableModifierList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for ScssVariableModifierList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SCSS_VARIABLE_MODIFIER_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SCSS_VARIABLE_MODIFIER_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<ScssVariableModifierList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(ScssVariableModifierList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for ScssVariableModifierList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for ScssVariableModifierList {
+    type Language = Language;
+    type Node = ScssVariableModifier;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for ScssVariableModifierList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ScssVariableModifierList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &ScssVariableModifierList {
+    type Item = ScssVariableModifier;
+    type IntoIter = AstNodeListIterator<Language, ScssVariableModifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for ScssVariableModifierList {
+    type Item = ScssVariableModifier;
+    type IntoIter = AstNodeListIterator<Language, ScssVariableModifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TwApplyClassList {
+    syntax_list: SyntaxList,
+}
+impl TwApplyClassList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for TwApplyClassList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(TW_APPLY_CLASS_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == TW_APPLY_CLASS_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<TwApplyClassList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(TwApplyClassList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for TwApplyClassList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for TwApplyClassList {
+    type Language = Language;
+    type Node = CssCustomIdentifier;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for TwApplyClassList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("TwApplyClassList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &TwApplyClassList {
+    type Item = CssCustomIdentifier;
+    type IntoIter = AstNodeListIterator<Language, CssCustomIdentifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for TwApplyClassList {
+    type Item = CssCustomIdentifier;
+    type IntoIter = AstNodeListIterator<Language, CssCustomIdentifier>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 #[derive(Clone)]
 pub struct DebugSyntaxElementChildren(pub SyntaxElementChildren);
 impl Debug for DebugSyntaxElementChildren {
@@ -33,6 +33,7 @@ mod diagnostics;
 mod impls;
 pub mod json;
 mod merge;
+pub mod restricted_regex;
 pub mod string_set;
 mod validator;
 
@@ -41,6 +42,7 @@ pub use biome_rowan::TextRange;
 pub use diagnostics::{DeserializationAdvice, DeserializationDiagnostic, VisitableType};
 pub use impls::*;
 pub use merge::Merge;
+pub use restricted_regex::RestrictedRegex;
 use std::fmt::Debug;
 pub use string_set::StringSet;
 pub use validator::*;
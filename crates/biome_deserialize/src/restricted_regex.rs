@@ -1,6 +1,6 @@
-use std::ops::Deref;
-
+use crate as biome_deserialize;
 use biome_deserialize_macros::Deserializable;
+use std::ops::Deref;
 
 /// A restricted regular expression only supports widespread syntaxes:
 ///
@@ -56,7 +56,7 @@ impl TryFrom<&str> for RestrictedRegex {
         regex::Regex::new(&format!("^(?:{value})$")).map(RestrictedRegex)
     }
 }
-#[cfg(feature = "schemars")]
+#[cfg(feature = "schema")]
 impl schemars::JsonSchema for RestrictedRegex {
     fn schema_name() -> String {
         "Regex".to_string()
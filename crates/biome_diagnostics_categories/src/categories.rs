@@ -110,46 +110,133 @@ define_categories! {
     "lint/correctness/useValidForDirection": "https://biomejs.dev/linter/rules/use-valid-for-direction",
     "lint/correctness/useYield": "https://biomejs.dev/linter/rules/use-yield",
     "lint/nursery/colorNoInvalidHex": "https://biomejs.dev/linter/rules/color-no-invalid-hex",
+    "lint/nursery/noAsyncInComputed": "https://biomejs.dev/linter/rules/no-async-in-computed",
+    "lint/nursery/noAwaitInLoop": "https://biomejs.dev/linter/rules/no-await-in-loop",
     "lint/nursery/noColorInvalidHex": "https://biomejs.dev/linter/rules/no-color-invalid-hex",
+    "lint/nursery/noConditionalTests": "https://biomejs.dev/linter/rules/no-conditional-tests",
+    "lint/nursery/noConflictingTsconfigOptions": "https://biomejs.dev/linter/rules/no-conflicting-tsconfig-options",
     "lint/nursery/noConsole": "https://biomejs.dev/linter/rules/no-console",
     "lint/nursery/noConstantMathMinMaxClamp": "https://biomejs.dev/linter/rules/no-constant-math-min-max-clamp",
     "lint/nursery/noCssEmptyBlock": "https://biomejs.dev/linter/rules/no-css-empty-block",
+    "lint/nursery/noDeprecatedApis": "https://biomejs.dev/linter/rules/no-deprecated-apis",
+    "lint/nursery/noDeprecatedTsconfigOptions": "https://biomejs.dev/linter/rules/no-deprecated-tsconfig-options",
+    "lint/nursery/noDeprecatedUsage": "https://biomejs.dev/linter/rules/no-deprecated-usage",
+    "lint/nursery/noDirectStateMutation": "https://biomejs.dev/linter/rules/no-direct-state-mutation",
+    "lint/nursery/noDocumentCookie": "https://biomejs.dev/linter/rules/no-document-cookie",
     "lint/nursery/noDoneCallback": "https://biomejs.dev/linter/rules/no-done-callback",
     "lint/nursery/noDuplicateAtImportRules": "https://biomejs.dev/linter/rules/no-duplicate-at-import-rules",
     "lint/nursery/noDuplicateElseIf": "https://biomejs.dev/linter/rules/no-duplicate-else-if",
     "lint/nursery/noDuplicateFontNames": "https://biomejs.dev/linter/rules/no-font-family-duplicate-names",
+    "lint/nursery/noDuplicateFragmentName": "https://biomejs.dev/linter/rules/no-duplicate-fragment-name",
     "lint/nursery/noDuplicateJsonKeys": "https://biomejs.dev/linter/rules/no-duplicate-json-keys",
+    "lint/nursery/noDuplicateOperationName": "https://biomejs.dev/linter/rules/no-duplicate-operation-name",
+    "lint/nursery/noDuplicatePackageJsonDependencies": "https://biomejs.dev/linter/rules/no-duplicate-package-json-dependencies",
     "lint/nursery/noDuplicateSelectorsKeyframeBlock": "https://biomejs.dev/linter/rules/no-duplicate-selectors-keyframe-block",
+    "lint/nursery/noDuplicateTestTitles": "https://biomejs.dev/linter/rules/no-duplicate-test-titles",
+    "lint/nursery/noDuplicateTomlKeys": "https://biomejs.dev/linter/rules/no-duplicate-toml-keys",
     "lint/nursery/noEvolvingAny": "https://biomejs.dev/linter/rules/no-evolving-any",
+    "lint/nursery/noExcessiveNestingDepth": "https://biomejs.dev/linter/rules/no-excessive-nesting-depth",
+    "lint/nursery/noExcessiveParameters": "https://biomejs.dev/linter/rules/no-excessive-parameters",
+    "lint/nursery/noExcessivelyLongFunctions": "https://biomejs.dev/linter/rules/no-excessively-long-functions",
     "lint/nursery/noFlatMapIdentity": "https://biomejs.dev/linter/rules/no-flat-map-identity",
+    "lint/nursery/noGlobalDirnameFilename": "https://biomejs.dev/linter/rules/no-global-dirname-filename",
+    "lint/nursery/noHardcodedColors": "https://biomejs.dev/linter/rules/no-hardcoded-colors",
+    "lint/nursery/noHeadElement": "https://biomejs.dev/linter/rules/no-head-element",
+    "lint/nursery/noHighSpecificitySelectors": "https://biomejs.dev/linter/rules/no-high-specificity-selectors",
+    "lint/nursery/noImgElement": "https://biomejs.dev/linter/rules/no-img-element",
+    "lint/nursery/noImplicitCoercion": "https://biomejs.dev/linter/rules/no-implicit-coercion",
     "lint/nursery/noImportantInKeyframe": "https://biomejs.dev/linter/rules/no-important-in-keyframe",
     "lint/nursery/noInvalidPositionAtImportRule": "https://biomejs.dev/linter/rules/no-invalid-position-at-import-rule",
+    "lint/nursery/noInvalidTailwindApply": "https://biomejs.dev/linter/rules/no-invalid-tailwind-apply",
+    "lint/nursery/noInvalidTomlDates": "https://biomejs.dev/linter/rules/no-invalid-toml-dates",
+    "lint/nursery/noJsonSchemaViolations": "https://biomejs.dev/linter/rules/no-json-schema-violations",
+    "lint/nursery/noLeakedConditionalRendering": "https://biomejs.dev/linter/rules/no-leaked-conditional-rendering",
+    "lint/nursery/noMagicNumbers": "https://biomejs.dev/linter/rules/no-magic-numbers",
     "lint/nursery/noMisplacedAssertion": "https://biomejs.dev/linter/rules/no-misplaced-assertion",
     "lint/nursery/noMissingGenericFamilyKeyword": "https://biomejs.dev/linter/rules/no-missing-generic-family-keyword",
     "lint/nursery/noNodejsModules": "https://biomejs.dev/linter/rules/no-nodejs-modules",
+    "lint/nursery/noNonReactiveUpdates": "https://biomejs.dev/linter/rules/no-non-reactive-updates",
+    "lint/nursery/noPackageInternalImports": "https://biomejs.dev/linter/rules/no-package-internal-imports",
     "lint/nursery/noReactSpecificProps": "https://biomejs.dev/linter/rules/no-react-specific-props",
+    "lint/nursery/noRedundantStoryName": "https://biomejs.dev/linter/rules/no-redundant-story-name",
     "lint/nursery/noRestrictedImports": "https://biomejs.dev/linter/rules/no-restricted-imports",
+    "lint/nursery/noSolidDestructuredProps": "https://biomejs.dev/linter/rules/no-solid-destructured-props",
+    "lint/nursery/noSolidUncalledSignals": "https://biomejs.dev/linter/rules/no-solid-uncalled-signals",
+    "lint/nursery/noSolidUntrackedReads": "https://biomejs.dev/linter/rules/no-solid-untracked-reads",
+    "lint/nursery/noStandaloneExpect": "https://biomejs.dev/linter/rules/no-standalone-expect",
+    "lint/nursery/noStaticElementInteractions": "https://biomejs.dev/linter/rules/no-static-element-interactions",
+    "lint/nursery/noSyncScripts": "https://biomejs.dev/linter/rules/no-sync-scripts",
     "lint/nursery/noTypeOnlyImportAttributes": "https://biomejs.dev/linter/rules/no-type-only-import-attributes",
+    "lint/nursery/noUndeclaredClassNames": "https://biomejs.dev/linter/rules/no-undeclared-class-names",
     "lint/nursery/noUndeclaredDependencies": "https://biomejs.dev/linter/rules/no-undeclared-dependencies",
+    "lint/nursery/noUndefinedMarkdownReferences": "https://biomejs.dev/linter/rules/no-undefined-markdown-references",
+    "lint/nursery/noUnknownArgument": "https://biomejs.dev/linter/rules/no-unknown-argument",
+    "lint/nursery/noUnknownField": "https://biomejs.dev/linter/rules/no-unknown-field",
     "lint/nursery/noUnknownFunction": "https://biomejs.dev/linter/rules/no-unknown-function",
     "lint/nursery/noUnknownMediaFeatureName": "https://biomejs.dev/linter/rules/no-unknown-media-feature-name",
     "lint/nursery/noUnknownProperty": "https://biomejs.dev/linter/rules/no-unknown-property",
     "lint/nursery/noUnknownSelectorPseudoElement": "https://biomejs.dev/linter/rules/no-unknown-selector-pseudo-element",
+    "lint/nursery/noUnknownTsconfigOptions": "https://biomejs.dev/linter/rules/no-unknown-tsconfig-options",
     "lint/nursery/noUnknownUnit": "https://biomejs.dev/linter/rules/no-unknown-unit",
     "lint/nursery/noUnmatchableAnbSelector": "https://biomejs.dev/linter/rules/no-unmatchable-anb-selector",
+    "lint/nursery/noUnnecessaryCondition": "https://biomejs.dev/linter/rules/no-unnecessary-condition",
+    "lint/nursery/noUnresolvedTsconfigExtends": "https://biomejs.dev/linter/rules/no-unresolved-tsconfig-extends",
+    "lint/nursery/noUnsafeTypeAssertion": "https://biomejs.dev/linter/rules/no-unsafe-type-assertion",
+    "lint/nursery/noUnstableNestedComponents": "https://biomejs.dev/linter/rules/no-unstable-nested-components",
+    "lint/nursery/noUnusedComponentProps": "https://biomejs.dev/linter/rules/no-unused-component-props",
+    "lint/nursery/noUnusedRefs": "https://biomejs.dev/linter/rules/no-unused-refs",
+    "lint/nursery/noUnusedSelectors": "https://biomejs.dev/linter/rules/no-unused-selectors",
+    "lint/nursery/noUnusedStoreSubscriptions": "https://biomejs.dev/linter/rules/no-unused-store-subscriptions",
     "lint/nursery/noUselessStringConcat": "https://biomejs.dev/linter/rules/no-useless-string-concat",
+    "lint/nursery/noUselessUndefined": "https://biomejs.dev/linter/rules/no-useless-undefined",
     "lint/nursery/noUselessUndefinedInitialization": "https://biomejs.dev/linter/rules/no-useless-undefined-initialization",
+    "lint/nursery/noVendorPrefix": "https://biomejs.dev/linter/rules/no-vendor-prefix",
+    "lint/nursery/useAllowedLengthUnits": "https://biomejs.dev/linter/rules/use-allowed-length-units",
+    "lint/nursery/useAriaPropsSupportedByRole": "https://biomejs.dev/linter/rules/use-aria-props-supported-by-role",
     "lint/nursery/useArrayLiterals": "https://biomejs.dev/linter/rules/use-array-literals",
+    "lint/nursery/useAtIndex": "https://biomejs.dev/linter/rules/use-at-index",
+    "lint/nursery/useAwaitStoryInteractions": "https://biomejs.dev/linter/rules/use-await-story-interactions",
+    "lint/nursery/useAwaitThenable": "https://biomejs.dev/linter/rules/use-await-thenable",
     "lint/nursery/useBiomeSuppressionComment": "https://biomejs.dev/linter/rules/use-biome-suppression-comment",
+    "lint/nursery/useColorContrast": "https://biomejs.dev/linter/rules/use-color-contrast",
     "lint/nursery/useConsistentBuiltinInstantiation": "https://biomejs.dev/linter/rules/use-consistent-new-builtin",
+    "lint/nursery/useConsistentCurlyBraces": "https://biomejs.dev/linter/rules/use-consistent-curly-braces",
+    "lint/nursery/useConsistentTestFunction": "https://biomejs.dev/linter/rules/use-consistent-test-function",
+    "lint/nursery/useCssNamingConvention": "https://biomejs.dev/linter/rules/use-css-naming-convention",
     "lint/nursery/useDefaultSwitchClause": "https://biomejs.dev/linter/rules/use-default-switch-clause",
+    "lint/nursery/useDefinePropsOrder": "https://biomejs.dev/linter/rules/use-define-props-order",
+    "lint/nursery/useExplicitFunctionReturnType": "https://biomejs.dev/linter/rules/use-explicit-function-return-type",
     "lint/nursery/useExplicitLengthCheck": "https://biomejs.dev/linter/rules/use-explicit-length-check",
+    "lint/nursery/useExplicitMemberAccessibility": "https://biomejs.dev/linter/rules/use-explicit-member-accessibility",
     "lint/nursery/useFocusableInteractive": "https://biomejs.dev/linter/rules/use-focusable-interactive",
     "lint/nursery/useGenericFontNames": "https://biomejs.dev/linter/rules/use-generic-font-names",
+    "lint/nursery/useGoogleFontPreconnect": "https://biomejs.dev/linter/rules/use-google-font-preconnect",
+    "lint/nursery/useGraphqlNamingConvention": "https://biomejs.dev/linter/rules/use-graphql-naming-convention",
     "lint/nursery/useImportRestrictions": "https://biomejs.dev/linter/rules/use-import-restrictions",
+    "lint/nursery/useLogicalProperties": "https://biomejs.dev/linter/rules/use-logical-properties",
+    "lint/nursery/useNamedOperation": "https://biomejs.dev/linter/rules/use-named-operation",
+    "lint/nursery/useNumericSeparators": "https://biomejs.dev/linter/rules/use-numeric-separators",
+    "lint/nursery/useObjectSpread": "https://biomejs.dev/linter/rules/use-object-spread",
+    "lint/nursery/useReadonlyClassMembers": "https://biomejs.dev/linter/rules/use-readonly-class-members",
+    "lint/nursery/useRequiredArguments": "https://biomejs.dev/linter/rules/use-required-arguments",
+    "lint/nursery/useRequiredPackageJsonFields": "https://biomejs.dev/linter/rules/use-required-package-json-fields",
+    "lint/nursery/useShorthandProperties": "https://biomejs.dev/linter/rules/use-shorthand-properties",
     "lint/nursery/useSortedClasses": "https://biomejs.dev/linter/rules/use-sorted-classes",
+    "lint/nursery/useSortedKeys": "https://biomejs.dev/linter/rules/use-sorted-keys",
+    "lint/nursery/useSortedPackageJsonKeys": "https://biomejs.dev/linter/rules/use-sorted-package-json-keys",
+    "lint/nursery/useSortedTomlKeys": "https://biomejs.dev/linter/rules/use-sorted-toml-keys",
+    "lint/nursery/useSortedYamlKeys": "https://biomejs.dev/linter/rules/use-sorted-yaml-keys",
+    "lint/nursery/useSortedProperties": "https://biomejs.dev/linter/rules/use-sorted-properties",
+    "lint/nursery/useStoryDefaultExport": "https://biomejs.dev/linter/rules/use-story-default-export",
+    "lint/nursery/useStoryNamePascalCase": "https://biomejs.dev/linter/rules/use-story-name-pascal-case",
     "lint/nursery/useThrowNewError": "https://biomejs.dev/linter/rules/use-throw-new-error",
     "lint/nursery/useTopLevelRegex": "https://biomejs.dev/linter/rules/use-top-level-regex",
+    "lint/nursery/useTsconfigPathAliases": "https://biomejs.dev/linter/rules/use-tsconfig-path-aliases",
+    "lint/nursery/useValidAutocomplete": "https://biomejs.dev/linter/rules/use-valid-autocomplete",
+    "lint/nursery/useValidPackageJsonVersions": "https://biomejs.dev/linter/rules/use-valid-package-json-versions",
+    "lint/nursery/useValidRunes": "https://biomejs.dev/linter/rules/use-valid-runes",
+    "lint/nursery/useValidVariables": "https://biomejs.dev/linter/rules/use-valid-variables",
+    "lint/nursery/useValidVueDirectives": "https://biomejs.dev/linter/rules/use-valid-vue-directives",
     "lint/performance/noAccumulatingSpread": "https://biomejs.dev/linter/rules/no-accumulating-spread",
     "lint/performance/noBarrelFile": "https://biomejs.dev/linter/rules/no-barrel-file",
     "lint/performance/noDelete": "https://biomejs.dev/linter/rules/no-delete",
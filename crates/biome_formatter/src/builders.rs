@@ -1,7 +1,10 @@
 use crate::format_element::tag::{Condition, Tag};
 use crate::prelude::tag::{DedentMode, GroupMode, LabelId};
 use crate::prelude::*;
-use crate::{format_element, write, Argument, Arguments, GroupId, TextRange, TextSize};
+use crate::{
+    format_element, write, Argument, Arguments, FormatContext, FormatOptions, GroupId, TextRange,
+    TextSize,
+};
 use crate::{Buffer, VecBuffer};
 use biome_rowan::{Language, SyntaxNode, SyntaxToken, TextLen, TokenText};
 use std::borrow::Cow;
@@ -129,7 +132,42 @@ pub const fn hard_line_break() -> Line {
 /// ```
 #[inline]
 pub const fn empty_line() -> Line {
-    Line::new(LineMode::Empty)
+    Line::new(LineMode::Empty(NonZeroU8::MIN))
+}
+
+/// Forced empty lines. Inserts enough line breaks in the output for the previous and next element
+/// to be separated by `count` empty lines, or a hard line break when `count` is `0`.
+///
+/// The number of empty lines to keep from the source is given by [crate::MaxEmptyLines::empty_lines_before].
+///
+/// # Examples
+///
+/// ```
+/// use biome_formatter::{format, format_args};
+/// use biome_formatter::prelude::*;
+///
+/// fn main() -> FormatResult<()> {
+/// let elements = format!(SimpleFormatContext::default(), [
+///     text("a,"),
+///     empty_lines(2),
+///     text("b,"),
+///     empty_lines(0),
+///     text("c")
+/// ])?;
+///
+/// assert_eq!(
+///     "a,\n\n\nb,\nc",
+///     elements.print()?.as_code()
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub const fn empty_lines(count: u8) -> Line {
+    match NonZeroU8::new(count) {
+        Some(count) => Line::new(LineMode::Empty(count)),
+        None => hard_line_break(),
+    }
 }
 
 /// A line break if the enclosing `Group` doesn't fit on a single line, a space otherwise.
@@ -2492,6 +2530,7 @@ pub struct JoinNodesBuilder<'fmt, 'buf, Separator, Context> {
 impl<'fmt, 'buf, Separator, Context> JoinNodesBuilder<'fmt, 'buf, Separator, Context>
 where
     Separator: Format<Context>,
+    Context: FormatContext,
 {
     pub(super) fn new(separator: Separator, fmt: &'fmt mut Formatter<'buf, Context>) -> Self {
         Self {
@@ -2507,10 +2546,10 @@ where
     pub fn entry<L: Language>(&mut self, node: &SyntaxNode<L>, content: &dyn Format<Context>) {
         self.result = self.result.and_then(|_| {
             if self.has_elements {
-                if get_lines_before(node) > 1 {
-                    write!(self.fmt, [empty_line()])?;
-                } else {
-                    self.separator.fmt(self.fmt)?;
+                let max_empty_lines = self.fmt.options().max_empty_lines();
+                match max_empty_lines.empty_lines_before(get_lines_before(node)) {
+                    0 => self.separator.fmt(self.fmt)?,
+                    count => write!(self.fmt, [empty_lines(count)])?,
                 }
             }
 
@@ -9,6 +9,7 @@ use crate::{TagKind, TextSize};
 use biome_rowan::static_assert;
 use biome_rowan::TokenText;
 use std::hash::{Hash, Hasher};
+use std::num::NonZeroU8;
 use std::ops::Deref;
 use std::rc::Rc;
 
@@ -100,8 +101,8 @@ pub enum LineMode {
     Soft,
     /// See [crate::builders::hard_line_break] for documentation.
     Hard,
-    /// See [crate::builders::empty_line] for documentation.
-    Empty,
+    /// See [crate::builders::empty_line] and [crate::builders::empty_lines] for documentation.
+    Empty(NonZeroU8),
 }
 
 impl LineMode {
@@ -245,7 +246,9 @@ impl FormatElements for FormatElement {
         match self {
             FormatElement::ExpandParent => true,
             FormatElement::Tag(Tag::StartGroup(group)) => !group.mode().is_flat(),
-            FormatElement::Line(line_mode) => matches!(line_mode, LineMode::Hard | LineMode::Empty),
+            FormatElement::Line(line_mode) => {
+                matches!(line_mode, LineMode::Hard | LineMode::Empty(_))
+            }
             FormatElement::StaticText { text } => text.contains('\n'),
             FormatElement::DynamicText { text, .. } => text.contains('\n'),
             FormatElement::LocatedTokenText { slice, .. } => slice.contains('\n'),
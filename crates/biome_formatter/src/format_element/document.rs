@@ -5,7 +5,8 @@ use crate::prelude::*;
 use crate::{format, write, AttributePosition};
 use crate::{
     BufferExtensions, Format, FormatContext, FormatElement, FormatOptions, FormatResult, Formatter,
-    IndentStyle, IndentWidth, LineEnding, LineWidth, PrinterOptions, TransformSourceMap,
+    IndentStyle, IndentWidth, LineEnding, LineWidth, MaxEmptyLines, PrinterOptions,
+    TransformSourceMap,
 };
 use biome_rowan::TextSize;
 use rustc_hash::FxHashMap;
@@ -114,7 +115,7 @@ impl Document {
                     FormatElement::DynamicText { text, .. } => text.contains('\n'),
                     FormatElement::LocatedTokenText { slice, .. } => slice.contains('\n'),
                     FormatElement::ExpandParent
-                    | FormatElement::Line(LineMode::Hard | LineMode::Empty) => true,
+                    | FormatElement::Line(LineMode::Hard | LineMode::Empty(_)) => true,
                     _ => false,
                 };
 
@@ -203,6 +204,10 @@ impl FormatOptions for IrFormatOptions {
         AttributePosition::default()
     }
 
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        MaxEmptyLines::default()
+    }
+
     fn as_print_options(&self) -> PrinterOptions {
         PrinterOptions {
             indent_width: self.indent_width(),
@@ -272,9 +277,19 @@ impl Format<IrFormatContext> for &[FormatElement] {
                     LineMode::Hard => {
                         write!(f, [text("hard_line_break")])?;
                     }
-                    LineMode::Empty => {
+                    LineMode::Empty(count) if count.get() == 1 => {
                         write!(f, [text("empty_line")])?;
                     }
+                    LineMode::Empty(count) => {
+                        write!(
+                            f,
+                            [
+                                text("empty_lines("),
+                                dynamic_text(&count.to_string(), TextSize::default()),
+                                text(")")
+                            ]
+                        )?;
+                    }
                 },
                 FormatElement::ExpandParent => {
                     write!(f, [text("expand_parent")])?;
@@ -354,6 +354,144 @@ impl From<LineWidth> for u16 {
     }
 }
 
+/// Validated value for the `max_empty_lines` formatter options
+///
+/// The allowed range of values is 0..=10
+#[derive(Clone, Copy, Debug, Eq, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct MaxEmptyLines(u8);
+
+impl MaxEmptyLines {
+    /// Minimum allowed value for a valid [MaxEmptyLines]
+    pub const MIN: u8 = 0;
+    /// Maximum allowed value for a valid [MaxEmptyLines]
+    pub const MAX: u8 = 10;
+
+    /// Return the numeric value for this [MaxEmptyLines]
+    pub fn get(&self) -> u8 {
+        self.0
+    }
+
+    /// Returns how many of the empty lines between two elements are kept,
+    /// `lines_before` being the number of line breaks that separate them in the source.
+    pub fn empty_lines_before(&self, lines_before: usize) -> u8 {
+        lines_before.saturating_sub(1).min(usize::from(self.0)) as u8
+    }
+}
+
+impl Default for MaxEmptyLines {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl Deserializable for MaxEmptyLines {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let value_text = TextNumber::deserialize(value, name, diagnostics)?;
+        if let Ok(value) = value_text.parse::<Self>() {
+            return Some(value);
+        }
+        diagnostics.push(DeserializationDiagnostic::new_out_of_bound_integer(
+            Self::MIN,
+            Self::MAX,
+            value.range(),
+        ));
+        None
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MaxEmptyLines {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value: u8 = serde::Deserialize::deserialize(deserializer)?;
+        let max_empty_lines = MaxEmptyLines::try_from(value).map_err(serde::de::Error::custom)?;
+        Ok(max_empty_lines)
+    }
+}
+
+/// Error type returned when parsing a [MaxEmptyLines] from a string fails
+pub enum ParseMaxEmptyLinesError {
+    /// The string could not be parsed as a valid [u8]
+    ParseError(ParseIntError),
+    /// The [u8] value of the string is not a valid [MaxEmptyLines]
+    TryFromIntError(MaxEmptyLinesFromIntError),
+}
+
+impl Debug for ParseMaxEmptyLinesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::fmt::Display for ParseMaxEmptyLinesError {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseMaxEmptyLinesError::ParseError(err) => std::fmt::Display::fmt(err, fmt),
+            ParseMaxEmptyLinesError::TryFromIntError(err) => std::fmt::Display::fmt(err, fmt),
+        }
+    }
+}
+
+impl FromStr for MaxEmptyLines {
+    type Err = ParseMaxEmptyLinesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = u8::from_str(s).map_err(ParseMaxEmptyLinesError::ParseError)?;
+        let value = Self::try_from(value).map_err(ParseMaxEmptyLinesError::TryFromIntError)?;
+        Ok(value)
+    }
+}
+
+/// Error type returned when converting a u8 to a [MaxEmptyLines] fails
+#[derive(Clone, Copy, Debug)]
+pub struct MaxEmptyLinesFromIntError(pub u8);
+
+impl TryFrom<u8> for MaxEmptyLines {
+    type Error = MaxEmptyLinesFromIntError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if (Self::MIN..=Self::MAX).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err(MaxEmptyLinesFromIntError(value))
+        }
+    }
+}
+
+impl std::fmt::Display for MaxEmptyLinesFromIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "The maximum number of empty lines should be between {} and {}",
+            MaxEmptyLines::MIN,
+            MaxEmptyLines::MAX,
+        )
+    }
+}
+
+impl std::fmt::Display for MaxEmptyLines {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl From<MaxEmptyLines> for u8 {
+    fn from(value: MaxEmptyLines) -> Self {
+        value.0
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -510,6 +648,9 @@ pub trait FormatOptions {
     /// The attribute position.
     fn attribute_position(&self) -> AttributePosition;
 
+    /// How many consecutive empty lines are kept. Defaults to 1.
+    fn max_empty_lines(&self) -> MaxEmptyLines;
+
     /// Derives the print options from the these format options
     fn as_print_options(&self) -> PrinterOptions;
 }
@@ -559,6 +700,7 @@ pub struct SimpleFormatOptions {
     pub line_width: LineWidth,
     pub line_ending: LineEnding,
     pub attribute_position: AttributePosition,
+    pub max_empty_lines: MaxEmptyLines,
 }
 
 impl FormatOptions for SimpleFormatOptions {
@@ -582,6 +724,10 @@ impl FormatOptions for SimpleFormatOptions {
         self.attribute_position
     }
 
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        self.max_empty_lines
+    }
+
     fn as_print_options(&self) -> PrinterOptions {
         PrinterOptions::default()
             .with_indent_style(self.indent_style)
@@ -5,8 +5,8 @@ use crate::format_element::tag::VerbatimKind;
 use crate::prelude::*;
 use crate::{
     comments::{CommentKind, CommentStyle},
-    write, Argument, Arguments, CstFormatContext, FormatRefWithRule, GroupId, SourceComment,
-    TextRange,
+    write, Argument, Arguments, CstFormatContext, FormatOptions, FormatRefWithRule, GroupId,
+    SourceComment, TextRange,
 };
 use biome_rowan::{Language, SyntaxNode, SyntaxToken, TextSize};
 #[cfg(debug_assertions)]
@@ -89,12 +89,20 @@ where
                                 write!(f, [hard_line_break()])?;
                             }
                         }
-                        _ => write!(f, [empty_line()])?,
+                        lines => {
+                            let max_empty_lines = f.options().max_empty_lines();
+                            let count = max_empty_lines.empty_lines_before(lines as usize);
+                            write!(f, [empty_lines(count)])?
+                        }
                     };
                 }
                 CommentKind::Line => match comment.lines_after() {
                     0 | 1 => write!(f, [hard_line_break()])?,
-                    _ => write!(f, [empty_line()])?,
+                    lines => {
+                        let max_empty_lines = f.options().max_empty_lines();
+                        let count = max_empty_lines.empty_lines_before(lines as usize);
+                        write!(f, [empty_lines(count)])?
+                    }
                 },
             }
 
@@ -130,6 +138,7 @@ where
             FormatTrailingComments::Comments(comments) => comments,
         };
 
+        let max_empty_lines = f.options().max_empty_lines();
         let mut total_lines_before = 0;
         let mut previous_comment: Option<&SourceComment<Context::Language>> = None;
 
@@ -178,7 +187,10 @@ where
                                     }
                                 }
                                 1 => write!(f, [hard_line_break()])?,
-                                _ => write!(f, [empty_line()])?,
+                                lines => {
+                                    let count = max_empty_lines.empty_lines_before(lines as usize);
+                                    write!(f, [empty_lines(count)])?
+                                }
                             };
 
                             write!(f, [format_comment])
@@ -596,7 +608,11 @@ impl<L: Language> FormatSkippedTokenTrivia<'_, L> {
                             match lines {
                                 0 => write!(f, [space()])?,
                                 1 => write!(f, [hard_line_break()])?,
-                                _ => write!(f, [empty_line()])?,
+                                lines => {
+                                    let max_empty_lines = f.options().max_empty_lines();
+                                    let count = max_empty_lines.empty_lines_before(lines as usize);
+                                    write!(f, [empty_lines(count)])?
+                                }
                             };
                         }
 
@@ -635,7 +651,11 @@ impl<L: Language> FormatSkippedTokenTrivia<'_, L> {
             match dangling_comments.first().unwrap().lines_before {
                 0 => write!(f, [space()])?,
                 1 => write!(f, [hard_line_break()])?,
-                _ => write!(f, [empty_line()])?,
+                lines => {
+                    let max_empty_lines = f.options().max_empty_lines();
+                    let count = max_empty_lines.empty_lines_before(lines as usize);
+                    write!(f, [empty_lines(count)])?
+                }
             }
 
             write!(
@@ -0,0 +1,186 @@
+mod lint;
+pub mod options;
+pub mod project;
+mod registry;
+pub mod schema;
+mod utils;
+
+use crate::project::GraphqlProjectIndex;
+pub use crate::registry::visit_registry;
+use crate::schema::GraphqlSchema;
+use biome_analyze::{
+    AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
+    MetadataRegistry, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+};
+use biome_diagnostics::Error;
+use biome_graphql_syntax::GraphqlLanguage;
+use std::sync::Arc;
+
+/// Return the static [MetadataRegistry] for the GraphQL analyzer rules
+pub fn metadata() -> &'static MetadataRegistry {
+    lazy_static::lazy_static! {
+        static ref METADATA: MetadataRegistry = {
+            let mut metadata = MetadataRegistry::default();
+            visit_registry(&mut metadata);
+            metadata
+        };
+    }
+
+    &METADATA
+}
+
+/// Run the analyzer on the provided `root`: this process will use the given `filter`
+/// to selectively restrict analysis to specific rules / a specific source range,
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// The operations are validated against the `schema`, if any, and the names of the
+/// operations and the fragments are compared with the other documents of the `project`, if any
+pub fn analyze<'a, F, B>(
+    root: &LanguageRoot<GraphqlLanguage>,
+    filter: AnalysisFilter,
+    options: &'a AnalyzerOptions,
+    schema: Option<Arc<GraphqlSchema>>,
+    project: Option<Arc<GraphqlProjectIndex>>,
+    emit_signal: F,
+) -> (Option<B>, Vec<Error>)
+where
+    F: FnMut(&dyn AnalyzerSignal<GraphqlLanguage>) -> ControlFlow<B> + 'a,
+    B: 'a,
+{
+    analyze_with_inspect_matcher(root, filter, |_| {}, options, schema, project, emit_signal)
+}
+
+/// Run the analyzer on the provided `root`: this process will use the given `filter`
+/// to selectively restrict analysis to specific rules / a specific source range,
+/// then call `emit_signal` when an analysis rule emits a diagnostic or action.
+/// Additionally, this function takes a `inspect_matcher` function that can be
+/// used to inspect the "query matches" emitted by the analyzer before they are
+/// processed by the lint rules registry
+pub fn analyze_with_inspect_matcher<'a, V, F, B>(
+    root: &LanguageRoot<GraphqlLanguage>,
+    filter: AnalysisFilter,
+    inspect_matcher: V,
+    options: &'a AnalyzerOptions,
+    schema: Option<Arc<GraphqlSchema>>,
+    project: Option<Arc<GraphqlProjectIndex>>,
+    mut emit_signal: F,
+) -> (Option<B>, Vec<Error>)
+where
+    V: FnMut(&MatchQueryParams<GraphqlLanguage>) + 'a,
+    F: FnMut(&dyn AnalyzerSignal<GraphqlLanguage>) -> ControlFlow<B> + 'a,
+    B: 'a,
+{
+    fn parse_linter_suppression_comment(
+        _text: &str,
+    ) -> Vec<Result<SuppressionKind, SuppressionDiagnostic>> {
+        vec![]
+    }
+    let mut registry = RuleRegistry::builder(&filter, root);
+    visit_registry(&mut registry);
+
+    let (registry, mut services, diagnostics, visitors) = registry.build();
+
+    // Bail if we can't parse a rule option
+    if !diagnostics.is_empty() {
+        return (None, diagnostics);
+    }
+
+    if let Some(schema) = schema {
+        services.insert_service(schema);
+    }
+    if let Some(project) = project {
+        services.insert_service(project);
+    }
+
+    let mut analyzer = biome_analyze::Analyzer::new(
+        metadata(),
+        biome_analyze::InspectMatcher::new(registry, inspect_matcher),
+        parse_linter_suppression_comment,
+        |_| {},
+        &mut emit_signal,
+    );
+
+    for ((phase, _), visitor) in visitors {
+        analyzer.add_visitor(phase, visitor);
+    }
+
+    (
+        analyzer.run(biome_analyze::AnalyzerContext {
+            root: root.clone(),
+            range: filter.range,
+            services,
+            options,
+        }),
+        diagnostics,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_analyze::{AnalyzerOptions, Never, RuleFilter};
+    use biome_console::fmt::{Formatter, Termcolor};
+    use biome_console::{markup, Markup};
+    use biome_diagnostics::termcolor::NoColor;
+    use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic, Severity};
+    use biome_graphql_parser::parse_graphql;
+    use biome_graphql_syntax::TextRange;
+    use std::slice;
+    use std::sync::Arc;
+
+    use crate::schema::GraphqlSchema;
+    use crate::{analyze, AnalysisFilter, ControlFlow};
+
+    #[ignore]
+    #[test]
+    fn quick_test() {
+        fn markup_to_string(markup: Markup) -> String {
+            let mut buffer = Vec::new();
+            let mut write = Termcolor(NoColor::new(&mut buffer));
+            let mut fmt = Formatter::new(&mut write);
+            fmt.write_markup(markup).unwrap();
+
+            String::from_utf8(buffer).unwrap()
+        }
+
+        const SCHEMA: &str = r#"type Query { user(id: ID!): User } type User { name: String }"#;
+        const SOURCE: &str = r#"query { user(id: "1") { name } }"#;
+
+        let parsed = parse_graphql(SOURCE);
+        let schema = GraphqlSchema::from_sdl(SCHEMA).unwrap();
+
+        let mut error_ranges: Vec<TextRange> = Vec::new();
+        let rule_filter = RuleFilter::Rule("nursery", "noUnknownField");
+        let options = AnalyzerOptions::default();
+        analyze(
+            &parsed.tree(),
+            AnalysisFilter {
+                enabled_rules: Some(slice::from_ref(&rule_filter)),
+                ..AnalysisFilter::default()
+            },
+            &options,
+            Some(Arc::new(schema)),
+            None,
+            |signal| {
+                if let Some(diag) = signal.diagnostic() {
+                    error_ranges.push(diag.location().span.unwrap());
+                    let error = diag
+                        .with_severity(Severity::Warning)
+                        .with_file_path("ahahah")
+                        .with_file_source_code(SOURCE);
+                    let text = markup_to_string(markup! {
+                        {PrintDiagnostic::verbose(&error)}
+                    });
+                    eprintln!("{text}");
+                }
+
+                for action in signal.actions() {
+                    let new_code = action.mutation.commit();
+                    eprintln!("{new_code}");
+                }
+
+                ControlFlow::<Never>::Continue(())
+            },
+        );
+
+        assert_eq!(error_ranges.as_slice(), &[]);
+    }
+}
@@ -0,0 +1,30 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub mod no_deprecated_usage;
+pub mod no_duplicate_fragment_name;
+pub mod no_duplicate_operation_name;
+pub mod no_unknown_argument;
+pub mod no_unknown_field;
+pub mod use_graphql_naming_convention;
+pub mod use_named_operation;
+pub mod use_required_arguments;
+pub mod use_valid_variables;
+
+declare_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_deprecated_usage :: NoDeprecatedUsage ,
+            self :: no_duplicate_fragment_name :: NoDuplicateFragmentName ,
+            self :: no_duplicate_operation_name :: NoDuplicateOperationName ,
+            self :: no_unknown_argument :: NoUnknownArgument ,
+            self :: no_unknown_field :: NoUnknownField ,
+            self :: use_graphql_naming_convention :: UseGraphqlNamingConvention ,
+            self :: use_named_operation :: UseNamedOperation ,
+            self :: use_required_arguments :: UseRequiredArguments ,
+            self :: use_valid_variables :: UseValidVariables ,
+        ]
+     }
+}
@@ -0,0 +1,134 @@
+use crate::schema::{name_text, GraphqlSchema};
+use crate::utils::{expected_value_type, field_definition};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_graphql_syntax::{GraphqlEnumValue, GraphqlField};
+use biome_rowan::{declare_node_union, AstNode};
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow the use of the fields and the enum values that are deprecated.
+    ///
+    /// The schema marks the fields and the enum values that shouldn't be used anymore with the directive `@deprecated`,
+    /// usually before removing them.
+    /// The diagnostic shows the reason of the deprecation, which often names the replacement.
+    ///
+    /// The rule validates the operations against the GraphQL schema configured with `graphql.linter.schema`.
+    /// It doesn't report anything when the schema isn't configured.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples use the schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   users(role: Role): [User!]!
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    ///   login: String @deprecated(reason: "Use `name`.")
+    /// }
+    ///
+    /// enum Role {
+    ///   ADMIN
+    ///   SUPERUSER @deprecated
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query GetUsers {
+    ///   users {
+    ///     login
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query GetSuperusers {
+    ///   users(role: SUPERUSER) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query GetAdmins {
+    ///   users(role: ADMIN) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoDeprecatedUsage {
+        version: "next",
+        name: "noDeprecatedUsage",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("no-deprecated")],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyDeprecatableUsage = GraphqlField | GraphqlEnumValue
+}
+
+pub struct RuleState {
+    /// The deprecated field or enum value, such as `User.login` or `Role.SUPERUSER`
+    usage: String,
+    reason: String,
+}
+
+impl Rule for NoDeprecatedUsage {
+    type Query = Ast<AnyDeprecatableUsage>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let schema = ctx.get_service::<Arc<GraphqlSchema>>()?;
+        match ctx.query() {
+            AnyDeprecatableUsage::GraphqlField(field) => {
+                let (parent_type, definition) = field_definition(schema, field)?;
+                Some(RuleState {
+                    usage: format!("{}.{}", parent_type.name, definition.name),
+                    reason: definition.deprecation_reason.clone()?,
+                })
+            }
+            AnyDeprecatableUsage::GraphqlEnumValue(value) => {
+                // The values of the enum definitions are ignored, because they aren't values of an argument
+                let (expected_type, _) = expected_value_type(schema, value.syntax())?;
+                let enum_type = schema.get_type(expected_type.named_type())?;
+                let definition = enum_type.enum_value(&name_text(&value.graphql_name().ok()?)?)?;
+                Some(RuleState {
+                    usage: format!("{}.{}", enum_type.name, definition.name),
+                    reason: definition.deprecation_reason.clone()?,
+                })
+            }
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState { usage, reason } = state;
+        let (range, kind) = match ctx.query() {
+            AnyDeprecatableUsage::GraphqlField(field) => (field.name().ok()?.range(), "field"),
+            AnyDeprecatableUsage::GraphqlEnumValue(value) => (value.range(), "enum value"),
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The "{kind}" "<Emphasis>{usage}</Emphasis>" is deprecated."
+                },
+            )
+            .note(markup! {
+                "The reason of the deprecation is: "{reason}
+            }),
+        )
+    }
+}
@@ -0,0 +1,100 @@
+use crate::project::{find_duplicate_definition, DuplicateDefinition, GraphqlProjectIndex};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_graphql_syntax::{AnyGraphqlDefinition, GraphqlFragmentDefinition};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow the fragments whose name is already used by another fragment of the project.
+    ///
+    /// The fragments are spread by their name, such as `...UserFields`.
+    /// The tools that collect the documents of a project, such as the code generators,
+    /// can't resolve a spread when several fragments have the same name, even in different documents.
+    ///
+    /// The rule compares the fragment with the previous fragments of the same document,
+    /// and with the fragments of the other GraphQL documents of the project.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// fragment UserFields on User {
+    ///   name
+    /// }
+    ///
+    /// fragment UserFields on User {
+    ///   email
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// fragment UserNameFields on User {
+    ///   name
+    /// }
+    ///
+    /// fragment UserEmailFields on User {
+    ///   email
+    /// }
+    /// ```
+    ///
+    pub NoDuplicateFragmentName {
+        version: "next",
+        name: "noDuplicateFragmentName",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("unique-fragment-name")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoDuplicateFragmentName {
+    type Query = Ast<GraphqlFragmentDefinition>;
+    type State = DuplicateDefinition;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let project = ctx.get_service::<Arc<GraphqlProjectIndex>>();
+        find_duplicate_definition(
+            &ctx.root(),
+            ctx.file_path(),
+            project.map(Arc::as_ref),
+            &AnyGraphqlDefinition::cast_ref(node.syntax())?,
+        )
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let DuplicateDefinition {
+            name,
+            first_definition,
+            other_documents,
+        } = state;
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            node.name().ok()?.range(),
+            markup! {
+                "The fragment name "<Emphasis>{name}</Emphasis>" is already used."
+            },
+        );
+        if let Some(first_definition) = first_definition {
+            diagnostic = diagnostic.detail(
+                first_definition,
+                markup! { "A fragment with the same name is defined here." },
+            );
+        }
+        if !other_documents.is_empty() {
+            diagnostic = diagnostic.footer_list(
+                markup! { "The other documents that define a fragment with the same name:" },
+                other_documents,
+            );
+        }
+        Some(diagnostic.note(markup! {
+            "The spreads can't tell which fragment they refer to. Rename one of the fragments."
+        }))
+    }
+}
@@ -0,0 +1,108 @@
+use crate::project::{find_duplicate_definition, DuplicateDefinition, GraphqlProjectIndex};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_graphql_syntax::{AnyGraphqlDefinition, GraphqlOperationDefinition};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow the operations whose name is already used by another operation of the project.
+    ///
+    /// The name of an operation identifies it in the logs and the metrics of the server,
+    /// and in the persisted queries of the clients.
+    /// Two operations with the same name can't be told apart, even when they're written in different documents.
+    ///
+    /// The rule compares the operation with the previous operations of the same document,
+    /// and with the operations of the other GraphQL documents of the project.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query GetUser {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    ///
+    /// query GetUser {
+    ///   user {
+    ///     email
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query GetUserName {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    ///
+    /// query GetUserEmail {
+    ///   user {
+    ///     email
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoDuplicateOperationName {
+        version: "next",
+        name: "noDuplicateOperationName",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("unique-operation-name")],
+        recommended: false,
+    }
+}
+
+impl Rule for NoDuplicateOperationName {
+    type Query = Ast<GraphqlOperationDefinition>;
+    type State = DuplicateDefinition;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let project = ctx.get_service::<Arc<GraphqlProjectIndex>>();
+        find_duplicate_definition(
+            &ctx.root(),
+            ctx.file_path(),
+            project.map(Arc::as_ref),
+            &AnyGraphqlDefinition::cast_ref(node.syntax())?,
+        )
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let DuplicateDefinition {
+            name,
+            first_definition,
+            other_documents,
+        } = state;
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            node.name()?.range(),
+            markup! {
+                "The operation name "<Emphasis>{name}</Emphasis>" is already used."
+            },
+        );
+        if let Some(first_definition) = first_definition {
+            diagnostic = diagnostic.detail(
+                first_definition,
+                markup! { "An operation with the same name is defined here." },
+            );
+        }
+        if !other_documents.is_empty() {
+            diagnostic = diagnostic.footer_list(
+                markup! { "The other documents that define an operation with the same name:" },
+                other_documents,
+            );
+        }
+        Some(diagnostic.note(markup! {
+            "The servers and the clients identify the operations by their name. Rename one of the operations."
+        }))
+    }
+}
@@ -0,0 +1,109 @@
+use crate::schema::{name_text, GraphqlSchema};
+use crate::utils::{argument_owner, arguments_owner_definition};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_graphql_syntax::GraphqlArgument;
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow the arguments that aren't defined by the field or the directive.
+    ///
+    /// The rule validates the operations against the GraphQL schema configured with `graphql.linter.schema`.
+    /// It doesn't report any argument when the schema isn't configured,
+    /// or when the field or the directive isn't defined by the schema.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples use the schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   user(id: "1", name: "Alice") {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   user(id: "1") {
+    ///     name @include(when: true)
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user(id: "1") {
+    ///     name @include(if: true)
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownArgument {
+        version: "next",
+        name: "noUnknownArgument",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("known-argument-names")],
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    argument_name: String,
+    /// The field or the directive, such as `Query.user` or `@include`
+    owner: String,
+}
+
+impl Rule for NoUnknownArgument {
+    type Query = Ast<GraphqlArgument>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let schema = ctx.get_service::<Arc<GraphqlSchema>>()?;
+        let node = ctx.query();
+        let argument_name = name_text(&node.name().ok()?)?;
+        let owner = arguments_owner_definition(schema, &argument_owner(node)?)?;
+        owner.argument(&argument_name).is_none().then(|| RuleState {
+            argument_name,
+            owner: owner.describe(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let RuleState {
+            argument_name,
+            owner,
+        } = state;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.name().ok()?.range(),
+                markup! {
+                    "The argument "<Emphasis>{argument_name}</Emphasis>" isn't defined by "<Emphasis>{owner}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Remove the argument, or check its spelling."
+            }),
+        )
+    }
+}
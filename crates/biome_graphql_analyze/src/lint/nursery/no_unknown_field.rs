@@ -0,0 +1,117 @@
+use crate::schema::{name_text, GraphqlSchema, TypeKind};
+use crate::utils::{enclosing_selection_set, selection_set_type};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_graphql_syntax::GraphqlField;
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow the selection of fields that don't exist on the type.
+    ///
+    /// The rule validates the operations against the GraphQL schema configured with `graphql.linter.schema`.
+    /// It doesn't report any field when the schema isn't configured.
+    ///
+    /// The introspection field `__typename` can be selected on every type,
+    /// and the introspection fields `__schema` and `__type` can be selected on the query type.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples use the schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   user(id: "1") {
+    ///     email
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user(id: "1") {
+    ///     __typename
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownField {
+        version: "next",
+        name: "noUnknownField",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("fields-on-correct-type")],
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    field_name: String,
+    type_name: String,
+}
+
+impl Rule for NoUnknownField {
+    type Query = Ast<GraphqlField>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let schema = ctx.get_service::<Arc<GraphqlSchema>>()?;
+        let node = ctx.query();
+        let field_name = name_text(&node.name().ok()?)?;
+        let parent_type = selection_set_type(schema, &enclosing_selection_set(node.syntax())?)?;
+        let is_known_field = match field_name.as_str() {
+            "__typename" => true,
+            "__schema" | "__type" => schema.is_query_type(parent_type),
+            // The leaf types don't have fields, this is reported by the parser of the server
+            _ => !parent_type.kind.is_composite() || parent_type.field(&field_name).is_some(),
+        };
+        (!is_known_field).then(|| RuleState {
+            field_name,
+            type_name: parent_type.name.clone(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let RuleState {
+            field_name,
+            type_name,
+        } = state;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            node.name().ok()?.range(),
+            markup! {
+                "The field "<Emphasis>{field_name}</Emphasis>" doesn't exist on the type "<Emphasis>{type_name}</Emphasis>"."
+            },
+        );
+        let schema = ctx.get_service::<Arc<GraphqlSchema>>()?;
+        let is_union = schema
+            .get_type(type_name)
+            .is_some_and(|ty| ty.kind == TypeKind::Union);
+        Some(if is_union {
+            diagnostic.note(markup! {
+                "The fields of the members of a union can only be selected in an inline fragment, such as "<Emphasis>"... on Member { field }"</Emphasis>"."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "Check the spelling of the field, or update the schema if the field was recently added."
+            })
+        })
+    }
+}
@@ -0,0 +1,646 @@
+use std::ops::Deref;
+
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic};
+use biome_deserialize_macros::Deserializable;
+use biome_graphql_syntax::{
+    GraphqlDirectiveDefinition, GraphqlEnumTypeDefinition, GraphqlEnumValueDefinition,
+    GraphqlFieldDefinition, GraphqlFragmentDefinition, GraphqlInputObjectTypeDefinition,
+    GraphqlInputValueDefinition, GraphqlInterfaceTypeDefinition, GraphqlName,
+    GraphqlObjectTypeDefinition, GraphqlOperationDefinition, GraphqlScalarTypeDefinition,
+    GraphqlSyntaxKind, GraphqlUnionTypeDefinition, GraphqlVariableDefinition,
+};
+use biome_rowan::{declare_node_union, AstNode, TextRange, TextSize};
+use biome_string_case::{Case, Cases};
+use smallvec::SmallVec;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Enforce naming conventions for the names defined in GraphQL documents.
+    ///
+    /// The following section describes the default conventions enforced by the rule.
+    /// They follow the conventions of the [GraphQL specification](https://spec.graphql.org/October2021/) and of most GraphQL servers.
+    /// You can also enforce custom conventions with the [rule options](#options).
+    ///
+    /// The names can be prefixed and suffixed by underscores `_`.
+    ///
+    /// ## Naming conventions
+    ///
+    /// | Declaration                                                  | Format            |
+    /// | ------------------------------------------------------------ | ----------------- |
+    /// | Objects, interfaces, unions, enums, scalars, input objects   | [`PascalCase`]    |
+    /// | Fields of the objects, the interfaces, and the input objects | [`camelCase`]     |
+    /// | Arguments of the fields and the directives                   | [`camelCase`]     |
+    /// | Enum values                                                  | [`CONSTANT_CASE`] |
+    /// | Directives                                                   | [`camelCase`]     |
+    /// | Operations and fragments                                     | [`PascalCase`]    |
+    /// | Variables                                                    | [`camelCase`]     |
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type user_profile {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// type User {
+    ///   first_name: String
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// enum Role {
+    ///   admin
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query get_user($UserId: ID!) {
+    ///   user(id: $UserId) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// type UserProfile {
+    ///   firstName(format: NameFormat): String
+    /// }
+    ///
+    /// enum NameFormat {
+    ///   FULL
+    ///   INITIALS_ONLY
+    /// }
+    ///
+    /// directive @cacheControl(maxAge: Int) on FIELD_DEFINITION
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The rule provides several options that are detailed in the following subsections.
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "strictCase": false,
+    ///         "conventions": [
+    ///             {
+    ///                 "selector": {
+    ///                     "kind": "enumValue"
+    ///                 },
+    ///                 "formats": ["PascalCase"]
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### strictCase
+    ///
+    /// When this option is set to `true`, it forbids consecutive uppercase characters in [`camelCase`] and [`PascalCase`].
+    /// For instance, when the option is set to `true`, `HTTPServer` or `userID` will throw an error.
+    /// These names should be renamed to `HttpServer` and `userId`.
+    ///
+    /// When the option is set to `false`, consecutive uppercase characters are allowed.
+    ///
+    /// Default: `true`
+    ///
+    /// ### conventions
+    ///
+    /// The `conventions` option allows applying custom conventions.
+    /// The option takes an array of conventions.
+    /// Every convention is an object that includes a `selector`, the string `formats` to enforce,
+    /// and an optional `message` displayed when the convention isn't fulfilled.
+    ///
+    /// The `selector` includes a `kind` that selects the declarations:
+    ///
+    /// - `any`: all the declarations (the default)
+    /// - `type`: all the type definitions. It includes:
+    ///   - `objectType`
+    ///   - `interfaceType`
+    ///   - `unionType`
+    ///   - `enumType`
+    ///   - `scalarType`
+    ///   - `inputObjectType`
+    /// - `field`: the fields of the objects and the interfaces
+    /// - `inputField`: the fields of the input objects
+    /// - `argument`: the arguments of the fields and the directives
+    /// - `enumValue`
+    /// - `directive`
+    /// - `operation`
+    /// - `fragment`
+    /// - `variable`
+    ///
+    /// The supported formats are [`camelCase`], [`CONSTANT_CASE`], [`PascalCase`], and [`snake_case`].
+    ///
+    /// A name is checked against the first convention that selects its declaration.
+    /// When no convention selects the declaration, the default convention applies.
+    ///
+    /// For example, the following configuration allows the fields of the input objects in `snake_case`,
+    /// the format used by the database of a project:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "conventions": [
+    ///             {
+    ///                 "selector": {
+    ///                     "kind": "inputField"
+    ///                 },
+    ///                 "formats": ["camelCase", "snake_case"],
+    ///                 "message": "The fields of the inputs are in camelCase, or in snake_case when they map a column of the database."
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`camelCase`]: https://en.wikipedia.org/wiki/Camel_case
+    /// [`PascalCase`]: https://en.wikipedia.org/wiki/Camel_case
+    /// [`CONSTANT_CASE`]: https://en.wikipedia.org/wiki/Snake_case
+    /// [`snake_case`]: https://en.wikipedia.org/wiki/Snake_case
+    pub UseGraphqlNamingConvention {
+        version: "next",
+        name: "useGraphqlNamingConvention",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("naming-convention")],
+        recommended: false,
+    }
+}
+
+impl Rule for UseGraphqlNamingConvention {
+    type Query = Ast<AnyGraphqlNamedDeclaration>;
+    type State = State;
+    type Signals = Option<Self::State>;
+    type Options = GraphqlNamingConventionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let options = ctx.options();
+        let kind = node.kind()?;
+        let name_token = node.name()?.value_token().ok()?;
+        let (prefix_len, name) = trim_underscores(name_token.text_trimmed());
+        if name.is_empty() {
+            return None;
+        }
+        let (formats, message) = match options
+            .conventions
+            .iter()
+            .find(|convention| convention.selector.kind.contains(kind))
+        {
+            Some(convention) => (convention.formats.clone(), convention.message.clone()),
+            None => (kind.default_formats(), None),
+        };
+        let actual_case = Case::identify(name, options.strict_case);
+        if (*formats | Case::Uni).contains(actual_case) {
+            return None;
+        }
+        Some(State {
+            kind,
+            name_range: TextRange::at(
+                name_token.text_trimmed_range().start() + TextSize::from(prefix_len as u32),
+                TextSize::from(name.len() as u32),
+            ),
+            formats,
+            message,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let State {
+            kind,
+            name_range,
+            formats,
+            message,
+        } = state;
+        let options = ctx.options();
+        let node = ctx.query();
+        let name_token = node.name()?.value_token().ok()?;
+        let trimmed_info = if name_range.len() < name_token.text_trimmed_range().len() {
+            " part"
+        } else {
+            ""
+        };
+        let (_, trimmed_name) = trim_underscores(name_token.text_trimmed());
+        let actual_case = Case::identify(trimmed_name, false);
+        let diagnostic = if options.strict_case
+            && (formats.contains(Case::Camel) || formats.contains(Case::Pascal))
+            && matches!(actual_case, Case::Camel | Case::Pascal)
+            && Case::identify(trimmed_name, true) == Case::Unknown
+        {
+            RuleDiagnostic::new(
+                rule_category!(),
+                name_range,
+                markup! {
+                    "Two consecutive uppercase characters are not allowed in "{format_args!("{actual_case}")}" because "<Emphasis>"strictCase"</Emphasis>" is set to `true`."
+                },
+            ).note(markup! {
+                "If you want to use consecutive uppercase characters in "{format_args!("{actual_case}")}", then set the "<Emphasis>"strictCase"</Emphasis>" option to `false`.\nSee the rule "<Hyperlink href="https://biomejs.dev/linter/rules/use-graphql-naming-convention#options">"options"</Hyperlink>" for more details."
+            })
+        } else {
+            let expected_case_names = formats
+                .into_iter()
+                .map(|case| case.to_string())
+                .collect::<SmallVec<[_; 4]>>()
+                .join(" or ");
+            RuleDiagnostic::new(
+                rule_category!(),
+                name_range,
+                markup! {
+                    "This "<Emphasis>{format_args!("{kind}")}</Emphasis>" name"{trimmed_info}" should be in "<Emphasis>{expected_case_names}</Emphasis>"."
+                },
+            )
+        };
+        Some(match message {
+            Some(message) => diagnostic.note(markup! { {message} }),
+            None => diagnostic,
+        })
+    }
+}
+
+declare_node_union! {
+    /// The declarations that define a name.
+    pub AnyGraphqlNamedDeclaration =
+        GraphqlObjectTypeDefinition
+        | GraphqlInterfaceTypeDefinition
+        | GraphqlUnionTypeDefinition
+        | GraphqlEnumTypeDefinition
+        | GraphqlScalarTypeDefinition
+        | GraphqlInputObjectTypeDefinition
+        | GraphqlFieldDefinition
+        | GraphqlInputValueDefinition
+        | GraphqlEnumValueDefinition
+        | GraphqlDirectiveDefinition
+        | GraphqlOperationDefinition
+        | GraphqlFragmentDefinition
+        | GraphqlVariableDefinition
+}
+
+impl AnyGraphqlNamedDeclaration {
+    fn name(&self) -> Option<GraphqlName> {
+        match self {
+            Self::GraphqlObjectTypeDefinition(definition) => definition.name().ok(),
+            Self::GraphqlInterfaceTypeDefinition(definition) => definition.name().ok(),
+            Self::GraphqlUnionTypeDefinition(definition) => definition.name().ok(),
+            Self::GraphqlEnumTypeDefinition(definition) => definition.name().ok(),
+            Self::GraphqlScalarTypeDefinition(definition) => definition.name().ok(),
+            Self::GraphqlInputObjectTypeDefinition(definition) => definition.name().ok(),
+            Self::GraphqlFieldDefinition(definition) => definition.name().ok(),
+            Self::GraphqlInputValueDefinition(definition) => definition.name().ok(),
+            Self::GraphqlEnumValueDefinition(definition) => {
+                definition.value().ok()?.graphql_name().ok()
+            }
+            Self::GraphqlDirectiveDefinition(definition) => definition.name().ok(),
+            Self::GraphqlOperationDefinition(definition) => definition.name(),
+            Self::GraphqlFragmentDefinition(definition) => definition.name().ok(),
+            Self::GraphqlVariableDefinition(definition) => definition.variable().ok()?.name().ok(),
+        }
+    }
+
+    fn kind(&self) -> Option<Kind> {
+        Some(match self {
+            Self::GraphqlObjectTypeDefinition(_) => Kind::ObjectType,
+            Self::GraphqlInterfaceTypeDefinition(_) => Kind::InterfaceType,
+            Self::GraphqlUnionTypeDefinition(_) => Kind::UnionType,
+            Self::GraphqlEnumTypeDefinition(_) => Kind::EnumType,
+            Self::GraphqlScalarTypeDefinition(_) => Kind::ScalarType,
+            Self::GraphqlInputObjectTypeDefinition(_) => Kind::InputObjectType,
+            Self::GraphqlFieldDefinition(_) => Kind::Field,
+            Self::GraphqlInputValueDefinition(definition) => {
+                // The parent of the definition is the list of the arguments or of the fields
+                match definition.syntax().grand_parent()?.kind() {
+                    GraphqlSyntaxKind::GRAPHQL_ARGUMENTS_DEFINITION => Kind::Argument,
+                    GraphqlSyntaxKind::GRAPHQL_INPUT_FIELDS_DEFINITION => Kind::InputField,
+                    _ => return None,
+                }
+            }
+            Self::GraphqlEnumValueDefinition(_) => Kind::EnumValue,
+            Self::GraphqlDirectiveDefinition(_) => Kind::Directive,
+            Self::GraphqlOperationDefinition(_) => Kind::Operation,
+            Self::GraphqlFragmentDefinition(_) => Kind::Fragment,
+            Self::GraphqlVariableDefinition(_) => Kind::Variable,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct State {
+    // Kind of the declaration whose name doesn't follow the convention.
+    kind: Kind,
+    // Range of the name without the leading and trailing underscores.
+    name_range: TextRange,
+    formats: Formats,
+    // Message of the custom convention which is not fulfilled.
+    message: Option<String>,
+}
+
+/// Rule's options.
+#[derive(Debug, Clone, Deserializable, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GraphqlNamingConventionOptions {
+    /// If `false`, then consecutive uppercase are allowed in _camel_ and _pascal_ cases.
+    /// This does not affect other [Case].
+    #[serde(default = "enabled", skip_serializing_if = "is_enabled")]
+    pub strict_case: bool,
+
+    /// Custom conventions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conventions: Vec<Convention>,
+}
+impl Default for GraphqlNamingConventionOptions {
+    fn default() -> Self {
+        Self {
+            strict_case: true,
+            conventions: Vec::new(),
+        }
+    }
+}
+
+const fn enabled() -> bool {
+    true
+}
+const fn is_enabled(value: &bool) -> bool {
+    *value
+}
+fn is_default<T: Default + Eq>(value: &T) -> bool {
+    value == &T::default()
+}
+
+#[derive(
+    Clone, Debug, Default, Deserializable, Eq, PartialEq, serde::Deserialize, serde::Serialize,
+)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(JsonSchema),
+    schemars(rename = "GraphqlConvention")
+)]
+#[serde(deny_unknown_fields)]
+#[deserializable(with_validator)]
+pub struct Convention {
+    /// Declarations concerned by this convention
+    #[serde(default, skip_serializing_if = "is_default")]
+    selector: Selector,
+
+    /// String cases to enforce
+    #[serde(default, skip_serializing_if = "is_default")]
+    formats: Formats,
+
+    /// Message displayed when the convention is not fulfilled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+impl DeserializableValidator for Convention {
+    fn validate(
+        &mut self,
+        _name: &str,
+        range: TextRange,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> bool {
+        if self.formats.is_empty() {
+            diagnostics.push(
+                DeserializationDiagnostic::new("The field `formats` must include a format.")
+                    .with_range(range),
+            );
+            false
+        } else {
+            true
+        }
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Deserializable, Eq, PartialEq, serde::Deserialize, serde::Serialize,
+)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(JsonSchema),
+    schemars(rename = "GraphqlSelector")
+)]
+#[serde(deny_unknown_fields)]
+struct Selector {
+    /// Declaration kind
+    #[serde(default, skip_serializing_if = "is_default")]
+    kind: Kind,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserializable,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(JsonSchema),
+    schemars(rename = "GraphqlKind")
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Kind {
+    /// All kinds
+    #[default]
+    Any,
+    /// All type definitions: objects, interfaces, unions, enums, scalars, and input objects
+    Type,
+    ObjectType,
+    InterfaceType,
+    UnionType,
+    EnumType,
+    ScalarType,
+    InputObjectType,
+    /// Fields of the objects and the interfaces
+    Field,
+    /// Fields of the input objects
+    InputField,
+    /// Arguments of the fields and the directives
+    Argument,
+    EnumValue,
+    Directive,
+    Operation,
+    Fragment,
+    Variable,
+}
+
+impl Kind {
+    pub fn contains(self, other: Self) -> bool {
+        self == other
+            || matches!(
+                (self, other),
+                (Self::Any, _)
+                    | (
+                        Self::Type,
+                        Self::ObjectType
+                            | Self::InterfaceType
+                            | Self::UnionType
+                            | Self::EnumType
+                            | Self::ScalarType
+                            | Self::InputObjectType
+                    )
+            )
+    }
+
+    fn default_formats(self) -> Formats {
+        let case = match self {
+            Self::Any
+            | Self::Type
+            | Self::ObjectType
+            | Self::InterfaceType
+            | Self::UnionType
+            | Self::EnumType
+            | Self::ScalarType
+            | Self::InputObjectType
+            | Self::Operation
+            | Self::Fragment => Case::Pascal,
+            Self::Field | Self::InputField | Self::Argument | Self::Directive | Self::Variable => {
+                Case::Camel
+            }
+            Self::EnumValue => Case::Constant,
+        };
+        Formats(case.into())
+    }
+}
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            Self::Any => "declaration",
+            Self::Type => "type",
+            Self::ObjectType => "object type",
+            Self::InterfaceType => "interface type",
+            Self::UnionType => "union type",
+            Self::EnumType => "enum type",
+            Self::ScalarType => "scalar type",
+            Self::InputObjectType => "input object type",
+            Self::Field => "field",
+            Self::InputField => "input field",
+            Self::Argument => "argument",
+            Self::EnumValue => "enum value",
+            Self::Directive => "directive",
+            Self::Operation => "operation",
+            Self::Fragment => "fragment",
+            Self::Variable => "variable",
+        };
+        write!(f, "{}", repr)
+    }
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserializable,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[cfg_attr(
+    feature = "schemars",
+    derive(JsonSchema),
+    schemars(rename = "GraphqlFormat")
+)]
+pub enum Format {
+    #[serde(rename = "camelCase")]
+    Camel,
+
+    #[serde(rename = "CONSTANT_CASE")]
+    Constant,
+
+    #[serde(rename = "PascalCase")]
+    #[default]
+    Pascal,
+
+    #[serde(rename = "snake_case")]
+    Snake,
+}
+
+impl From<Format> for Case {
+    fn from(value: Format) -> Self {
+        match value {
+            Format::Camel => Case::Camel,
+            Format::Constant => Case::Constant,
+            Format::Pascal => Case::Pascal,
+            Format::Snake => Case::Snake,
+        }
+    }
+}
+impl TryFrom<Case> for Format {
+    type Error = &'static str;
+
+    fn try_from(value: Case) -> Result<Self, Self::Error> {
+        match value {
+            Case::Camel => Ok(Format::Camel),
+            Case::Constant => Ok(Format::Constant),
+            Case::Pascal => Ok(Format::Pascal),
+            Case::Snake => Ok(Format::Snake),
+            Case::Kebab
+            | Case::Lower
+            | Case::NumberableCapital
+            | Case::Uni
+            | Case::Upper
+            | Case::Unknown => Err("Unsupported case"),
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Default, Deserializable, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize,
+)]
+#[serde(from = "SmallVec<[Format; 4]>", into = "SmallVec<[Format; 4]>")]
+pub struct Formats(Cases);
+
+impl Deref for Formats {
+    type Target = Cases;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl From<SmallVec<[Format; 4]>> for Formats {
+    fn from(values: SmallVec<[Format; 4]>) -> Self {
+        Self(values.into_iter().map(|format| format.into()).collect())
+    }
+}
+impl From<Formats> for SmallVec<[Format; 4]> {
+    fn from(value: Formats) -> Self {
+        value
+            .0
+            .into_iter()
+            .filter_map(|case| case.try_into().ok())
+            .collect()
+    }
+}
+#[cfg(feature = "schemars")]
+impl JsonSchema for Formats {
+    fn schema_name() -> String {
+        "GraphqlFormats".to_string()
+    }
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <std::collections::HashSet<Format>>::json_schema(gen)
+    }
+}
+
+/// Trims the underscores from `name` and returns the length of the trimmed prefix.
+fn trim_underscores(name: &str) -> (usize, &str) {
+    let prefix_len = name.bytes().take_while(|c| *c == b'_').count();
+    let name = name[prefix_len..].trim_end_matches('_');
+    (prefix_len, name)
+}
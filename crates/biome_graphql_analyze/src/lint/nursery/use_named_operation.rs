@@ -0,0 +1,96 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_graphql_syntax::{AnyGraphqlOperationDefinition, GraphqlSyntaxKind};
+use biome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Require the operations to have a name.
+    ///
+    /// The name of an operation identifies it in the logs and the metrics of the server,
+    /// and in the developer tools of the clients.
+    /// The anonymous operations, including the queries written with the shorthand syntax `{ ... }`,
+    /// are hard to track down.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query GetUserName {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseNamedOperation {
+        version: "next",
+        name: "useNamedOperation",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("no-anonymous-operations")],
+        recommended: false,
+    }
+}
+
+impl Rule for UseNamedOperation {
+    type Query = Ast<AnyGraphqlOperationDefinition>;
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        match ctx.query() {
+            AnyGraphqlOperationDefinition::GraphqlOperationDefinition(operation) => {
+                if operation.name().is_some() {
+                    return None;
+                }
+                Some(operation.ty().ok()?.range())
+            }
+            AnyGraphqlOperationDefinition::GraphqlSelectionSet(selection_set) => {
+                // Only the selection sets of the document are queries written with the shorthand syntax
+                if selection_set.syntax().parent()?.kind()
+                    != GraphqlSyntaxKind::GRAPHQL_DEFINITION_LIST
+                {
+                    return None;
+                }
+                Some(selection_set.l_curly_token().ok()?.text_trimmed_range())
+            }
+        }
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This operation doesn't have a name."
+                },
+            )
+            .note(markup! {
+                "The anonymous operations are hard to identify in the logs and the metrics of the server."
+            })
+            .note(markup! {
+                "Add a name that describes the operation, such as "<Emphasis>"query GetUserName { ... }"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,142 @@
+use crate::schema::{name_text, GraphqlSchema, InputValueDefinition};
+use crate::utils::{arguments_owner_definition, AnyGraphqlArgumentsOwner};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_graphql_syntax::GraphqlArguments;
+use biome_rowan::{AstNode, TextRange};
+use std::sync::Arc;
+
+declare_rule! {
+    /// Require the arguments that the field or the directive can't omit.
+    ///
+    /// An argument is required when its type is non-null, such as `ID!`, and it doesn't have a default value.
+    ///
+    /// The rule validates the operations against the GraphQL schema configured with `graphql.linter.schema`.
+    /// It doesn't report anything when the schema isn't configured.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples use the schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user(id: ID!, format: String! = "short"): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   user {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   user(id: "1") {
+    ///     name @skip
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query {
+    ///   user(id: "1") {
+    ///     name @skip(if: false)
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseRequiredArguments {
+        version: "next",
+        name: "useRequiredArguments",
+        language: "graphql",
+        sources: &[RuleSource::EslintGraphql("provided-required-arguments")],
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    /// The range of the name of the field or the directive
+    range: TextRange,
+    /// The field or the directive, such as `Query.user` or `@include`
+    owner: String,
+    argument: InputValueDefinition,
+}
+
+impl Rule for UseRequiredArguments {
+    type Query = Ast<AnyGraphqlArgumentsOwner>;
+    type State = RuleState;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(schema) = ctx.get_service::<Arc<GraphqlSchema>>() else {
+            return Vec::new();
+        };
+        let node = ctx.query();
+        let (name, arguments) = match node {
+            AnyGraphqlArgumentsOwner::GraphqlField(field) => (field.name(), field.arguments()),
+            AnyGraphqlArgumentsOwner::GraphqlDirective(directive) => {
+                (directive.name(), directive.arguments())
+            }
+        };
+        let (Ok(name), Some(owner)) = (name, arguments_owner_definition(schema, node)) else {
+            return Vec::new();
+        };
+        let provided_arguments = provided_argument_names(arguments.as_ref());
+        owner
+            .arguments()
+            .iter()
+            .filter(|argument| {
+                argument.is_required() && !provided_arguments.contains(&argument.name)
+            })
+            .map(|argument| RuleState {
+                range: name.range(),
+                owner: owner.describe(),
+                argument: argument.clone(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let RuleState {
+            range,
+            owner,
+            argument,
+        } = state;
+        let argument_name = &argument.name;
+        let argument_type = argument.ty.to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    <Emphasis>{owner}</Emphasis>" requires the argument "<Emphasis>{argument_name}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The argument has the non-null type "<Emphasis>{argument_type}</Emphasis>" and doesn't have a default value."
+            }),
+        )
+    }
+}
+
+fn provided_argument_names(arguments: Option<&GraphqlArguments>) -> Vec<String> {
+    arguments.map_or_else(Vec::new, |arguments| {
+        arguments
+            .arguments()
+            .into_iter()
+            .filter_map(|argument| name_text(&argument.name().ok()?))
+            .collect()
+    })
+}
@@ -0,0 +1,208 @@
+use crate::schema::{name_text, GraphqlSchema, TypeReference};
+use crate::utils::expected_value_type;
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_graphql_syntax::{
+    AnyGraphqlValue, GraphqlFragmentDefinition, GraphqlOperationDefinition, GraphqlVariable,
+    GraphqlVariableDefinition,
+};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Require the variables to be declared by the operation, with a type allowed where they're used.
+    ///
+    /// Every variable used in an operation must be declared in its variable definitions, such as `query ($id: ID!)`.
+    /// When the GraphQL schema is configured with `graphql.linter.schema`,
+    /// the rule also checks that the type of the variable can be used where the variable is passed.
+    /// For example, a variable of type `String` can't be passed to an argument of type `ID`,
+    /// and a nullable variable can't be passed to a non-null argument, unless one of them has a default value.
+    ///
+    /// The variables used in the fragments are ignored,
+    /// because they are declared by the operations that include the fragments.
+    ///
+    /// ## Examples
+    ///
+    /// The following examples use the schema:
+    ///
+    /// ```graphql
+    /// type Query {
+    ///   user(id: ID!): User
+    /// }
+    ///
+    /// type User {
+    ///   name: String
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query ($id: String!) {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql,expect_diagnostic
+    /// query ($id: ID) {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```graphql
+    /// query ($id: ID!) {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// ```graphql
+    /// query ($id: ID = "1") {
+    ///   user(id: $id) {
+    ///     name
+    ///   }
+    /// }
+    /// ```
+    ///
+    pub UseValidVariables {
+        version: "next",
+        name: "useValidVariables",
+        language: "graphql",
+        sources: &[
+            RuleSource::EslintGraphql("no-undefined-variables"),
+            RuleSource::EslintGraphql("variables-in-allowed-position"),
+        ],
+        recommended: false,
+    }
+}
+
+pub enum RuleState {
+    /// The operation doesn't declare the variable
+    Undeclared { name: String },
+    /// The type of the variable isn't allowed at this position
+    IncompatibleType {
+        name: String,
+        variable_type: TypeReference,
+        expected_type: TypeReference,
+    },
+}
+
+impl Rule for UseValidVariables {
+    type Query = Ast<GraphqlVariable>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if GraphqlVariableDefinition::can_cast(node.syntax().parent()?.kind()) {
+            return None;
+        }
+        let name = name_text(&node.name().ok()?)?;
+        let operation = node.syntax().ancestors().find_map(|ancestor| {
+            if GraphqlFragmentDefinition::can_cast(ancestor.kind()) {
+                // Stop the search: the variable is declared by the operations that use the fragment
+                Some(None)
+            } else {
+                GraphqlOperationDefinition::cast(ancestor).map(Some)
+            }
+        });
+        let operation = match operation {
+            Some(Some(operation)) => operation,
+            Some(None) => return None,
+            // The shorthand syntax of the queries doesn't declare any variable
+            None => return Some(RuleState::Undeclared { name }),
+        };
+        let Some(definition) = find_variable_definition(&operation, &name) else {
+            return Some(RuleState::Undeclared { name });
+        };
+        let schema = ctx.get_service::<Arc<GraphqlSchema>>()?;
+        let (expected_type, location_has_default_value) =
+            expected_value_type(schema, node.syntax())?;
+        let variable_type = TypeReference::from_ast(&definition.ty().ok()?)?;
+        let has_default_value = definition.default().is_some_and(|default| {
+            !matches!(default.value(), Ok(AnyGraphqlValue::GraphqlNullValue(_)))
+        });
+        // A nullable variable is allowed at a non-null position if a default value is provided
+        // See https://spec.graphql.org/October2021/#IsVariableUsageAllowed()
+        let is_allowed = if expected_type.is_non_null()
+            && !variable_type.is_non_null()
+            && (has_default_value || location_has_default_value)
+        {
+            variable_type.is_compatible_with(expected_type.nullable())
+        } else {
+            variable_type.is_compatible_with(&expected_type)
+        };
+        (!is_allowed).then_some(RuleState::IncompatibleType {
+            name,
+            variable_type,
+            expected_type,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(match state {
+            RuleState::Undeclared { name } => RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "The variable "<Emphasis>"$"{name}</Emphasis>" isn't declared by the operation."
+                },
+            )
+            .note(markup! {
+                "Declare the variable in the variable definitions of the operation, such as "<Emphasis>"query ($"{name}": Type)"</Emphasis>"."
+            }),
+            RuleState::IncompatibleType {
+                name,
+                variable_type,
+                expected_type,
+            } => {
+                let variable_type = variable_type.to_string();
+                let expected_type = expected_type.to_string();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    node.range(),
+                    markup! {
+                        "The variable "<Emphasis>"$"{name}</Emphasis>" of type "<Emphasis>{variable_type}</Emphasis>" can't be used where the type "<Emphasis>{expected_type}</Emphasis>" is expected."
+                    },
+                )
+                .note(markup! {
+                    "Change the type of the variable to "<Emphasis>{expected_type}</Emphasis>"."
+                })
+            }
+        })
+    }
+}
+
+fn find_variable_definition(
+    operation: &GraphqlOperationDefinition,
+    name: &str,
+) -> Option<GraphqlVariableDefinition> {
+    operation
+        .variables()?
+        .elements()
+        .into_iter()
+        .find(|definition| {
+            definition
+                .variable()
+                .ok()
+                .and_then(|variable| name_text(&variable.name().ok()?))
+                .is_some_and(|definition_name| definition_name == name)
+        })
+}
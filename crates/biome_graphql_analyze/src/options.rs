@@ -0,0 +1,19 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoDeprecatedUsage =
+    <lint::nursery::no_deprecated_usage::NoDeprecatedUsage as biome_analyze::Rule>::Options;
+pub type NoDuplicateFragmentName = < lint :: nursery :: no_duplicate_fragment_name :: NoDuplicateFragmentName as biome_analyze :: Rule > :: Options ;
+pub type NoDuplicateOperationName = < lint :: nursery :: no_duplicate_operation_name :: NoDuplicateOperationName as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownArgument =
+    <lint::nursery::no_unknown_argument::NoUnknownArgument as biome_analyze::Rule>::Options;
+pub type NoUnknownField =
+    <lint::nursery::no_unknown_field::NoUnknownField as biome_analyze::Rule>::Options;
+pub type UseGraphqlNamingConvention = < lint :: nursery :: use_graphql_naming_convention :: UseGraphqlNamingConvention as biome_analyze :: Rule > :: Options ;
+pub type UseNamedOperation =
+    <lint::nursery::use_named_operation::UseNamedOperation as biome_analyze::Rule>::Options;
+pub type UseRequiredArguments =
+    <lint::nursery::use_required_arguments::UseRequiredArguments as biome_analyze::Rule>::Options;
+pub type UseValidVariables =
+    <lint::nursery::use_valid_variables::UseValidVariables as biome_analyze::Rule>::Options;
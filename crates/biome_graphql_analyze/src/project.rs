@@ -0,0 +1,196 @@
+//! The index of the GraphQL documents of a project.
+//!
+//! The operations and the fragments are referenced by their name, for example by the clients
+//! that persist the queries, so their names must be unique across all the documents of the project.
+//! The index records the names defined by each document, so that the rules can find the
+//! definitions of the other documents while analyzing a single document.
+
+use crate::schema::name_text;
+use biome_graphql_syntax::{AnyGraphqlDefinition, AnyGraphqlOperationDefinition, GraphqlRoot};
+use biome_rowan::{AstNode, TextRange};
+use rustc_hash::FxHashMap;
+use std::path::{Path, PathBuf};
+
+/// The kind of executable definition that is referenced by its name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DefinitionKind {
+    Operation,
+    Fragment,
+}
+
+impl DefinitionKind {
+    /// Returns the kind and the name of a definition, if the definition is named.
+    pub fn of_definition(definition: &AnyGraphqlDefinition) -> Option<(Self, String)> {
+        match definition {
+            AnyGraphqlDefinition::AnyGraphqlOperationDefinition(
+                AnyGraphqlOperationDefinition::GraphqlOperationDefinition(operation),
+            ) => Some((Self::Operation, name_text(&operation.name()?)?)),
+            AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => {
+                Some((Self::Fragment, name_text(&fragment.name().ok()?)?))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The location of a named definition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DefinitionLocation {
+    /// The path of the document
+    pub path: PathBuf,
+    /// The range of the name of the definition
+    pub range: TextRange,
+}
+
+/// The named operations and fragments of the GraphQL documents of a project.
+#[derive(Debug, Default)]
+pub struct GraphqlProjectIndex {
+    operations: FxHashMap<String, Vec<DefinitionLocation>>,
+    fragments: FxHashMap<String, Vec<DefinitionLocation>>,
+}
+
+impl GraphqlProjectIndex {
+    /// Records the named operations and fragments of a document.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_graphql_analyze::project::{DefinitionKind, GraphqlProjectIndex};
+    /// use biome_graphql_parser::parse_graphql;
+    /// use std::path::Path;
+    ///
+    /// let mut index = GraphqlProjectIndex::default();
+    /// index.add_document(Path::new("a.graphql"), &parse_graphql("query GetUser { user { name } }").tree());
+    /// index.add_document(Path::new("b.graphql"), &parse_graphql("query GetUser { user { id } }").tree());
+    /// assert_eq!(index.definitions(DefinitionKind::Operation, "GetUser").len(), 2);
+    /// assert!(index.definitions(DefinitionKind::Fragment, "GetUser").is_empty());
+    /// ```
+    pub fn add_document(&mut self, path: &Path, root: &GraphqlRoot) {
+        for definition in root.definitions() {
+            let Some((kind, name)) = DefinitionKind::of_definition(&definition) else {
+                continue;
+            };
+            let Some(range) = definition_name_range(&definition) else {
+                continue;
+            };
+            let definitions = match kind {
+                DefinitionKind::Operation => &mut self.operations,
+                DefinitionKind::Fragment => &mut self.fragments,
+            };
+            definitions
+                .entry(name)
+                .or_default()
+                .push(DefinitionLocation {
+                    path: path.to_path_buf(),
+                    range,
+                });
+        }
+    }
+
+    /// Removes the definitions of a document, for example before recording its new content.
+    pub fn remove_document(&mut self, path: &Path) {
+        for definitions in [&mut self.operations, &mut self.fragments] {
+            definitions.retain(|_, locations| {
+                locations.retain(|location| location.path != path);
+                !locations.is_empty()
+            });
+        }
+    }
+
+    /// Returns the locations of the definitions of the given kind with the given name.
+    pub fn definitions(&self, kind: DefinitionKind, name: &str) -> &[DefinitionLocation] {
+        let definitions = match kind {
+            DefinitionKind::Operation => &self.operations,
+            DefinitionKind::Fragment => &self.fragments,
+        };
+        definitions.get(name).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Returns the range of the name of an operation or a fragment.
+pub(crate) fn definition_name_range(definition: &AnyGraphqlDefinition) -> Option<TextRange> {
+    match definition {
+        AnyGraphqlDefinition::AnyGraphqlOperationDefinition(
+            AnyGraphqlOperationDefinition::GraphqlOperationDefinition(operation),
+        ) => Some(operation.name()?.range()),
+        AnyGraphqlDefinition::GraphqlFragmentDefinition(fragment) => {
+            Some(fragment.name().ok()?.range())
+        }
+        _ => None,
+    }
+}
+
+/// A definition whose name is used by other definitions of the same kind.
+pub struct DuplicateDefinition {
+    pub(crate) name: String,
+    /// The range of the name of the first definition of the document with the same name
+    pub(crate) first_definition: Option<TextRange>,
+    /// The paths of the other documents of the project that define the same name
+    pub(crate) other_documents: Vec<String>,
+}
+
+/// Finds the definitions with the same kind and the same name as `definition`,
+/// among the previous definitions of the document and the other documents of the project.
+pub(crate) fn find_duplicate_definition(
+    root: &GraphqlRoot,
+    path: &Path,
+    project: Option<&GraphqlProjectIndex>,
+    definition: &AnyGraphqlDefinition,
+) -> Option<DuplicateDefinition> {
+    let (kind, name) = DefinitionKind::of_definition(definition)?;
+    let first_definition = root
+        .definitions()
+        .into_iter()
+        .take_while(|previous| previous != definition)
+        .find(|previous| {
+            DefinitionKind::of_definition(previous).is_some_and(|(previous_kind, previous_name)| {
+                previous_kind == kind && previous_name == name
+            })
+        })
+        .and_then(|previous| definition_name_range(&previous));
+    let mut other_documents: Vec<_> = project
+        .map(|project| project.definitions(kind, &name))
+        .unwrap_or_default()
+        .iter()
+        .filter(|location| location.path != path)
+        .map(|location| location.path.display().to_string())
+        .collect();
+    other_documents.sort_unstable();
+    other_documents.dedup();
+    (first_definition.is_some() || !other_documents.is_empty()).then_some(DuplicateDefinition {
+        name,
+        first_definition,
+        other_documents,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_graphql_parser::parse_graphql;
+
+    #[test]
+    fn removes_the_definitions_of_a_document() {
+        let mut index = GraphqlProjectIndex::default();
+        let first = Path::new("first.graphql");
+        let second = Path::new("second.graphql");
+        index.add_document(
+            first,
+            &parse_graphql("query GetUser { user { name } } fragment UserFields on User { name }")
+                .tree(),
+        );
+        index.add_document(
+            second,
+            &parse_graphql("query GetUser { user { id } }").tree(),
+        );
+
+        index.remove_document(first);
+
+        let operations = index.definitions(DefinitionKind::Operation, "GetUser");
+        assert_eq!(operations.len(), 1);
+        assert_eq!(operations[0].path, second);
+        assert!(index
+            .definitions(DefinitionKind::Fragment, "UserFields")
+            .is_empty());
+    }
+}
@@ -0,0 +1,1013 @@
+//! The GraphQL schema that the operations are validated against.
+//!
+//! A schema is loaded either from a document written in the Schema Definition Language (SDL),
+//! or from the JSON result of an introspection query.
+
+use biome_graphql_parser::{parse_graphql, ParseDiagnostic};
+use biome_graphql_syntax::{
+    AnyGraphqlDefinition, AnyGraphqlEnumTypeExtension, AnyGraphqlInputObjectTypeExtension,
+    AnyGraphqlInterfaceTypeExtension, AnyGraphqlObjectTypeExtension, AnyGraphqlPrimitiveType,
+    AnyGraphqlSchemaExtension, AnyGraphqlType, AnyGraphqlValue, GraphqlArgumentsDefinition,
+    GraphqlDirectiveList, GraphqlEnumValuesDefinition, GraphqlFieldsDefinition,
+    GraphqlInputFieldsDefinition, GraphqlInputValueDefinition, GraphqlName,
+    GraphqlRootOperationTypeDefinitionList, GraphqlStringValue,
+};
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// The scalars that every schema provides.
+const BUILT_IN_SCALARS: [&str; 5] = ["Boolean", "Float", "ID", "Int", "String"];
+
+/// The reason of a deprecation when the directive `@deprecated` doesn't specify one.
+const DEFAULT_DEPRECATION_REASON: &str = "No longer supported";
+
+/// The type of operation that a root type resolves.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OperationKind {
+    Query,
+    Mutation,
+    Subscription,
+}
+
+impl OperationKind {
+    /// Returns the kind of operation from the keyword that starts the operation.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "query" => Some(Self::Query),
+            "mutation" => Some(Self::Mutation),
+            "subscription" => Some(Self::Subscription),
+            _ => None,
+        }
+    }
+
+    /// The name of the root type when the schema doesn't declare it.
+    const fn default_root_type(self) -> &'static str {
+        match self {
+            Self::Query => "Query",
+            Self::Mutation => "Mutation",
+            Self::Subscription => "Subscription",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+}
+
+impl TypeKind {
+    /// Returns `true` if fields can be selected on the type.
+    pub const fn is_composite(self) -> bool {
+        matches!(self, Self::Object | Self::Interface | Self::Union)
+    }
+}
+
+/// A reference to a type, such as `[String!]!`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypeReference {
+    Named(String),
+    List(Box<TypeReference>),
+    NonNull(Box<TypeReference>),
+}
+
+impl TypeReference {
+    /// Converts a type written in a GraphQL document.
+    pub fn from_ast(ty: &AnyGraphqlType) -> Option<Self> {
+        match ty {
+            AnyGraphqlType::AnyGraphqlPrimitiveType(ty) => Self::from_primitive_type(ty),
+            AnyGraphqlType::GraphqlNonNullType(ty) => Some(Self::NonNull(Box::new(
+                Self::from_primitive_type(&ty.base().ok()?)?,
+            ))),
+            AnyGraphqlType::GraphqlBogusType(_) => None,
+        }
+    }
+
+    fn from_primitive_type(ty: &AnyGraphqlPrimitiveType) -> Option<Self> {
+        match ty {
+            AnyGraphqlPrimitiveType::GraphqlNamedType(ty) => {
+                Some(Self::Named(name_text(&ty.name().ok()?)?))
+            }
+            AnyGraphqlPrimitiveType::GraphqlListType(ty) => {
+                Some(Self::List(Box::new(Self::from_ast(&ty.element().ok()?)?)))
+            }
+        }
+    }
+
+    /// Returns the name of the type without the lists and the non-null wrappers.
+    pub fn named_type(&self) -> &str {
+        match self {
+            Self::Named(name) => name,
+            Self::List(ty) | Self::NonNull(ty) => ty.named_type(),
+        }
+    }
+
+    pub const fn is_non_null(&self) -> bool {
+        matches!(self, Self::NonNull(_))
+    }
+
+    /// Returns the type without the non-null wrapper.
+    pub fn nullable(&self) -> &Self {
+        match self {
+            Self::NonNull(ty) => ty,
+            ty => ty,
+        }
+    }
+
+    /// Returns the type of the elements if the type is a list.
+    pub fn list_element(&self) -> Option<&Self> {
+        match self.nullable() {
+            Self::List(ty) => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if a value of this type can be used where `expected` is expected.
+    ///
+    /// See https://spec.graphql.org/October2021/#AreTypesCompatible()
+    pub fn is_compatible_with(&self, expected: &Self) -> bool {
+        match (self, expected) {
+            (Self::NonNull(ty), Self::NonNull(expected)) => ty.is_compatible_with(expected),
+            (_, Self::NonNull(_)) => false,
+            (Self::NonNull(ty), expected) => ty.is_compatible_with(expected),
+            (Self::List(ty), Self::List(expected)) => ty.is_compatible_with(expected),
+            (Self::Named(name), Self::Named(expected)) => name == expected,
+            _ => false,
+        }
+    }
+}
+
+impl Display for TypeReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Named(name) => write!(f, "{name}"),
+            Self::List(ty) => write!(f, "[{ty}]"),
+            Self::NonNull(ty) => write!(f, "{ty}!"),
+        }
+    }
+}
+
+/// An argument, or a field of an input object.
+#[derive(Clone, Debug)]
+pub struct InputValueDefinition {
+    pub name: String,
+    pub ty: TypeReference,
+    pub has_default_value: bool,
+}
+
+impl InputValueDefinition {
+    /// Returns `true` if the value must be provided.
+    pub fn is_required(&self) -> bool {
+        self.ty.is_non_null() && !self.has_default_value
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct FieldDefinition {
+    pub name: String,
+    pub arguments: Vec<InputValueDefinition>,
+    pub ty: TypeReference,
+    /// The reason of the deprecation, if the field is deprecated
+    pub deprecation_reason: Option<String>,
+}
+
+impl FieldDefinition {
+    pub fn argument(&self, name: &str) -> Option<&InputValueDefinition> {
+        self.arguments.iter().find(|argument| argument.name == name)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EnumValueDefinition {
+    pub name: String,
+    /// The reason of the deprecation, if the value is deprecated
+    pub deprecation_reason: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TypeDefinition {
+    pub name: String,
+    pub kind: TypeKind,
+    /// The fields of an object or an interface
+    pub fields: Vec<FieldDefinition>,
+    /// The fields of an input object
+    pub input_fields: Vec<InputValueDefinition>,
+    /// The values of an enum
+    pub enum_values: Vec<EnumValueDefinition>,
+}
+
+impl TypeDefinition {
+    fn new(name: String, kind: TypeKind) -> Self {
+        Self {
+            name,
+            kind,
+            fields: Vec::new(),
+            input_fields: Vec::new(),
+            enum_values: Vec::new(),
+        }
+    }
+
+    pub fn field(&self, name: &str) -> Option<&FieldDefinition> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    pub fn input_field(&self, name: &str) -> Option<&InputValueDefinition> {
+        self.input_fields.iter().find(|field| field.name == name)
+    }
+
+    pub fn enum_value(&self, name: &str) -> Option<&EnumValueDefinition> {
+        self.enum_values.iter().find(|value| value.name == name)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct DirectiveDefinition {
+    pub name: String,
+    pub arguments: Vec<InputValueDefinition>,
+}
+
+impl DirectiveDefinition {
+    pub fn argument(&self, name: &str) -> Option<&InputValueDefinition> {
+        self.arguments.iter().find(|argument| argument.name == name)
+    }
+}
+
+#[derive(Debug)]
+pub enum SchemaError {
+    /// The SDL document contains syntax errors
+    InvalidSdl(Vec<ParseDiagnostic>),
+    /// The introspection result isn't valid JSON, or doesn't have the expected shape
+    InvalidIntrospection(serde_json::Error),
+}
+
+impl Display for SchemaError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSdl(_) => write!(f, "the GraphQL schema contains syntax errors"),
+            Self::InvalidIntrospection(error) => {
+                write!(f, "the introspection result is invalid: {error}")
+            }
+        }
+    }
+}
+
+/// The types and the directives of a GraphQL schema.
+#[derive(Debug, Default)]
+pub struct GraphqlSchema {
+    types: FxHashMap<String, TypeDefinition>,
+    directives: FxHashMap<String, DirectiveDefinition>,
+    query_type: Option<String>,
+    mutation_type: Option<String>,
+    subscription_type: Option<String>,
+}
+
+impl GraphqlSchema {
+    /// Loads a schema from the content of a file.
+    ///
+    /// The files with the extension `.json` contain an introspection result,
+    /// the other files contain an SDL document.
+    pub fn from_file(path: &Path, content: &str) -> Result<Self, SchemaError> {
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            Self::from_introspection(content)
+        } else {
+            Self::from_sdl(content)
+        }
+    }
+
+    /// Loads a schema from a document written in the Schema Definition Language.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_graphql_analyze::schema::{GraphqlSchema, OperationKind};
+    ///
+    /// let schema = GraphqlSchema::from_sdl("type Query { user(id: ID!): User } type User { name: String }").unwrap();
+    /// let query = schema.root_type(OperationKind::Query).unwrap();
+    /// assert_eq!(query.field("user").unwrap().ty.to_string(), "User");
+    /// assert!(query.field("user").unwrap().argument("id").unwrap().is_required());
+    /// ```
+    pub fn from_sdl(source: &str) -> Result<Self, SchemaError> {
+        let parse = parse_graphql(source);
+        if parse.has_errors() {
+            return Err(SchemaError::InvalidSdl(parse.into_diagnostics()));
+        }
+        let mut schema = Self::default();
+        for definition in parse.tree().definitions() {
+            schema.add_definition(&definition);
+        }
+        schema.add_built_ins();
+        Ok(schema)
+    }
+
+    /// Loads a schema from the JSON result of an introspection query.
+    ///
+    /// Both the complete response `{ "data": { "__schema": ... } }` and its `data` are accepted.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_graphql_analyze::schema::{GraphqlSchema, OperationKind};
+    ///
+    /// let schema = GraphqlSchema::from_introspection(r#"{
+    ///     "__schema": {
+    ///         "queryType": { "name": "Query" },
+    ///         "types": [{
+    ///             "kind": "OBJECT",
+    ///             "name": "Query",
+    ///             "fields": [{ "name": "version", "args": [], "type": { "kind": "SCALAR", "name": "String" } }]
+    ///         }]
+    ///     }
+    /// }"#).unwrap();
+    /// assert!(schema.root_type(OperationKind::Query).unwrap().field("version").is_some());
+    /// ```
+    pub fn from_introspection(source: &str) -> Result<Self, SchemaError> {
+        let result: IntrospectionResult =
+            serde_json::from_str(source).map_err(SchemaError::InvalidIntrospection)?;
+        let introspection = match result {
+            IntrospectionResult::Response { data } => data.schema,
+            IntrospectionResult::Data { schema } => schema,
+        };
+        let mut schema = Self {
+            query_type: introspection.query_type.map(|ty| ty.name),
+            mutation_type: introspection.mutation_type.map(|ty| ty.name),
+            subscription_type: introspection.subscription_type.map(|ty| ty.name),
+            ..Self::default()
+        };
+        for ty in introspection.types {
+            let Some(kind) = ty.kind.to_type_kind() else {
+                continue;
+            };
+            let mut definition = TypeDefinition::new(ty.name, kind);
+            definition.fields = ty
+                .fields
+                .into_iter()
+                .flatten()
+                .filter_map(|field| {
+                    Some(FieldDefinition {
+                        name: field.name,
+                        arguments: IntrospectionInputValue::convert_all(field.args),
+                        ty: field.ty.to_type_reference()?,
+                        deprecation_reason: introspection_deprecation_reason(
+                            field.is_deprecated,
+                            field.deprecation_reason,
+                        ),
+                    })
+                })
+                .collect();
+            definition.input_fields =
+                IntrospectionInputValue::convert_all(ty.input_fields.unwrap_or_default());
+            definition.enum_values = ty
+                .enum_values
+                .into_iter()
+                .flatten()
+                .map(|value| EnumValueDefinition {
+                    name: value.name,
+                    deprecation_reason: introspection_deprecation_reason(
+                        value.is_deprecated,
+                        value.deprecation_reason,
+                    ),
+                })
+                .collect();
+            schema.types.insert(definition.name.clone(), definition);
+        }
+        for directive in introspection.directives {
+            schema.directives.insert(
+                directive.name.clone(),
+                DirectiveDefinition {
+                    name: directive.name,
+                    arguments: IntrospectionInputValue::convert_all(directive.args),
+                },
+            );
+        }
+        schema.add_built_ins();
+        Ok(schema)
+    }
+
+    pub fn get_type(&self, name: &str) -> Option<&TypeDefinition> {
+        self.types.get(name)
+    }
+
+    pub fn get_directive(&self, name: &str) -> Option<&DirectiveDefinition> {
+        self.directives.get(name)
+    }
+
+    /// Returns the type that resolves the operations of the given kind.
+    pub fn root_type(&self, kind: OperationKind) -> Option<&TypeDefinition> {
+        let name = match kind {
+            OperationKind::Query => self.query_type.as_deref(),
+            OperationKind::Mutation => self.mutation_type.as_deref(),
+            OperationKind::Subscription => self.subscription_type.as_deref(),
+        };
+        self.get_type(name.unwrap_or(kind.default_root_type()))
+    }
+
+    /// Returns `true` if the type resolves the queries.
+    pub fn is_query_type(&self, ty: &TypeDefinition) -> bool {
+        self.root_type(OperationKind::Query)
+            .is_some_and(|query_type| query_type.name == ty.name)
+    }
+
+    fn add_definition(&mut self, definition: &AnyGraphqlDefinition) {
+        match definition {
+            AnyGraphqlDefinition::GraphqlSchemaDefinition(definition) => {
+                self.set_root_types(&definition.root_operation_type());
+            }
+            AnyGraphqlDefinition::AnyGraphqlSchemaExtension(
+                AnyGraphqlSchemaExtension::GraphqlSchemaExtensionWithRootOperationType(extension),
+            ) => {
+                self.set_root_types(&extension.root_operation_type());
+            }
+            AnyGraphqlDefinition::GraphqlScalarTypeDefinition(definition) => {
+                self.add_type(definition.name().ok(), TypeKind::Scalar);
+            }
+            AnyGraphqlDefinition::GraphqlObjectTypeDefinition(definition) => {
+                let name = definition.name().ok();
+                self.add_type(name.clone(), TypeKind::Object);
+                self.add_fields(name, definition.fields());
+            }
+            AnyGraphqlDefinition::AnyGraphqlObjectTypeExtension(
+                AnyGraphqlObjectTypeExtension::GraphqlObjectTypeExtensionWithFields(extension),
+            ) => {
+                self.add_fields(extension.name().ok(), extension.fields().ok());
+            }
+            AnyGraphqlDefinition::GraphqlInterfaceTypeDefinition(definition) => {
+                let name = definition.name().ok();
+                self.add_type(name.clone(), TypeKind::Interface);
+                self.add_fields(name, definition.fields());
+            }
+            AnyGraphqlDefinition::AnyGraphqlInterfaceTypeExtension(
+                AnyGraphqlInterfaceTypeExtension::GraphqlInterfaceTypeExtensionWithFields(
+                    extension,
+                ),
+            ) => {
+                self.add_fields(extension.name().ok(), extension.fields().ok());
+            }
+            AnyGraphqlDefinition::GraphqlUnionTypeDefinition(definition) => {
+                self.add_type(definition.name().ok(), TypeKind::Union);
+            }
+            AnyGraphqlDefinition::GraphqlEnumTypeDefinition(definition) => {
+                let name = definition.name().ok();
+                self.add_type(name.clone(), TypeKind::Enum);
+                self.add_enum_values(name, definition.enum_values());
+            }
+            AnyGraphqlDefinition::AnyGraphqlEnumTypeExtension(
+                AnyGraphqlEnumTypeExtension::GraphqlEnumTypeExtensionWithValues(extension),
+            ) => {
+                self.add_enum_values(extension.name().ok(), extension.enum_values().ok());
+            }
+            AnyGraphqlDefinition::GraphqlInputObjectTypeDefinition(definition) => {
+                let name = definition.name().ok();
+                self.add_type(name.clone(), TypeKind::InputObject);
+                self.add_input_fields(name, definition.input_fields());
+            }
+            AnyGraphqlDefinition::AnyGraphqlInputObjectTypeExtension(
+                AnyGraphqlInputObjectTypeExtension::GraphqlInputObjectTypeExtensionWithFields(
+                    extension,
+                ),
+            ) => {
+                self.add_input_fields(extension.name().ok(), extension.input_fields().ok());
+            }
+            AnyGraphqlDefinition::GraphqlDirectiveDefinition(definition) => {
+                let Some(name) = definition.name().ok().as_ref().and_then(name_text) else {
+                    return;
+                };
+                let arguments = input_value_definitions(definition.arguments());
+                self.directives
+                    .insert(name.clone(), DirectiveDefinition { name, arguments });
+            }
+            _ => {}
+        }
+    }
+
+    fn set_root_types(&mut self, root_types: &GraphqlRootOperationTypeDefinitionList) {
+        for root_type in root_types {
+            let kind = root_type
+                .operation_type()
+                .ok()
+                .and_then(|ty| ty.value_token().ok())
+                .and_then(|keyword| OperationKind::from_keyword(keyword.text_trimmed()));
+            let name = root_type
+                .named_type()
+                .ok()
+                .and_then(|ty| ty.name().ok())
+                .as_ref()
+                .and_then(name_text);
+            match kind {
+                Some(OperationKind::Query) => self.query_type = name,
+                Some(OperationKind::Mutation) => self.mutation_type = name,
+                Some(OperationKind::Subscription) => self.subscription_type = name,
+                None => {}
+            }
+        }
+    }
+
+    fn add_type(&mut self, name: Option<GraphqlName>, kind: TypeKind) {
+        let Some(name) = name.as_ref().and_then(name_text) else {
+            return;
+        };
+        // The fields of an extension may precede the definition of the type
+        self.types
+            .entry(name.clone())
+            .and_modify(|ty| ty.kind = kind)
+            .or_insert_with(|| TypeDefinition::new(name, kind));
+    }
+
+    fn type_entry(
+        &mut self,
+        name: Option<GraphqlName>,
+        kind: TypeKind,
+    ) -> Option<&mut TypeDefinition> {
+        let name = name.as_ref().and_then(name_text)?;
+        Some(
+            self.types
+                .entry(name.clone())
+                .or_insert_with(|| TypeDefinition::new(name, kind)),
+        )
+    }
+
+    fn add_fields(&mut self, name: Option<GraphqlName>, fields: Option<GraphqlFieldsDefinition>) {
+        let Some(fields) = fields else {
+            return;
+        };
+        let Some(ty) = self.type_entry(name, TypeKind::Object) else {
+            return;
+        };
+        ty.fields
+            .extend(fields.fields().into_iter().filter_map(|field| {
+                Some(FieldDefinition {
+                    name: name_text(&field.name().ok()?)?,
+                    arguments: input_value_definitions(field.arguments()),
+                    ty: TypeReference::from_ast(&field.ty().ok()?)?,
+                    deprecation_reason: deprecation_reason(&field.directives()),
+                })
+            }));
+    }
+
+    fn add_enum_values(
+        &mut self,
+        name: Option<GraphqlName>,
+        values: Option<GraphqlEnumValuesDefinition>,
+    ) {
+        let Some(values) = values else {
+            return;
+        };
+        let Some(ty) = self.type_entry(name, TypeKind::Enum) else {
+            return;
+        };
+        ty.enum_values
+            .extend(values.values().into_iter().filter_map(|value| {
+                Some(EnumValueDefinition {
+                    name: name_text(&value.value().ok()?.graphql_name().ok()?)?,
+                    deprecation_reason: deprecation_reason(&value.directives()),
+                })
+            }));
+    }
+
+    fn add_input_fields(
+        &mut self,
+        name: Option<GraphqlName>,
+        fields: Option<GraphqlInputFieldsDefinition>,
+    ) {
+        let Some(fields) = fields else {
+            return;
+        };
+        let Some(ty) = self.type_entry(name, TypeKind::InputObject) else {
+            return;
+        };
+        ty.input_fields.extend(
+            fields
+                .fields()
+                .into_iter()
+                .filter_map(|field| input_value_definition(&field)),
+        );
+    }
+
+    fn add_built_ins(&mut self) {
+        for scalar in BUILT_IN_SCALARS {
+            self.types
+                .entry(scalar.to_string())
+                .or_insert_with(|| TypeDefinition::new(scalar.to_string(), TypeKind::Scalar));
+        }
+        let skip_or_include = |name: &str| DirectiveDefinition {
+            name: name.to_string(),
+            arguments: vec![InputValueDefinition {
+                name: "if".to_string(),
+                ty: TypeReference::NonNull(Box::new(TypeReference::Named("Boolean".to_string()))),
+                has_default_value: false,
+            }],
+        };
+        let built_in_directives = [
+            skip_or_include("skip"),
+            skip_or_include("include"),
+            DirectiveDefinition {
+                name: "deprecated".to_string(),
+                arguments: vec![InputValueDefinition {
+                    name: "reason".to_string(),
+                    ty: TypeReference::Named("String".to_string()),
+                    has_default_value: true,
+                }],
+            },
+            DirectiveDefinition {
+                name: "specifiedBy".to_string(),
+                arguments: vec![InputValueDefinition {
+                    name: "url".to_string(),
+                    ty: TypeReference::NonNull(Box::new(TypeReference::Named(
+                        "String".to_string(),
+                    ))),
+                    has_default_value: false,
+                }],
+            },
+        ];
+        for directive in built_in_directives {
+            self.directives
+                .entry(directive.name.clone())
+                .or_insert(directive);
+        }
+    }
+}
+
+/// Returns the text of a name.
+pub(crate) fn name_text(name: &GraphqlName) -> Option<String> {
+    Some(name.value_token().ok()?.text_trimmed().to_string())
+}
+
+/// Returns the text of a string value, without the quotes.
+pub(crate) fn string_value_text(value: &GraphqlStringValue) -> Option<String> {
+    let token = value.graphql_string_literal_token().ok()?;
+    let text = token.text_trimmed();
+    let content = match text.strip_prefix("\"\"\"") {
+        Some(block) => block.strip_suffix("\"\"\"")?.trim(),
+        None => text.strip_prefix('"')?.strip_suffix('"')?,
+    };
+    Some(content.to_string())
+}
+
+/// Returns the reason of the deprecation if the directives contain `@deprecated`.
+fn deprecation_reason(directives: &GraphqlDirectiveList) -> Option<String> {
+    let directive = directives.into_iter().find(|directive| {
+        directive
+            .name()
+            .ok()
+            .and_then(|name| name_text(&name))
+            .is_some_and(|name| name == "deprecated")
+    })?;
+    let reason = directive.arguments().and_then(|arguments| {
+        arguments.arguments().into_iter().find_map(|argument| {
+            if name_text(&argument.name().ok()?)? != "reason" {
+                return None;
+            }
+            match argument.value().ok()? {
+                AnyGraphqlValue::GraphqlStringValue(value) => string_value_text(&value),
+                _ => None,
+            }
+        })
+    });
+    Some(reason.unwrap_or_else(|| DEFAULT_DEPRECATION_REASON.to_string()))
+}
+
+fn introspection_deprecation_reason(is_deprecated: bool, reason: Option<String>) -> Option<String> {
+    is_deprecated.then(|| reason.unwrap_or_else(|| DEFAULT_DEPRECATION_REASON.to_string()))
+}
+
+fn input_value_definitions(
+    arguments: Option<GraphqlArgumentsDefinition>,
+) -> Vec<InputValueDefinition> {
+    arguments.map_or_else(Vec::new, |arguments| {
+        arguments
+            .arguments()
+            .into_iter()
+            .filter_map(|argument| input_value_definition(&argument))
+            .collect()
+    })
+}
+
+fn input_value_definition(
+    definition: &GraphqlInputValueDefinition,
+) -> Option<InputValueDefinition> {
+    Some(InputValueDefinition {
+        name: name_text(&definition.name().ok()?)?,
+        ty: TypeReference::from_ast(&definition.ty().ok()?)?,
+        has_default_value: definition.default().is_some(),
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IntrospectionResult {
+    Response {
+        data: IntrospectionData,
+    },
+    Data {
+        #[serde(rename = "__schema")]
+        schema: IntrospectionSchema,
+    },
+}
+
+#[derive(Deserialize)]
+struct IntrospectionData {
+    #[serde(rename = "__schema")]
+    schema: IntrospectionSchema,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionSchema {
+    query_type: Option<IntrospectionNamedType>,
+    mutation_type: Option<IntrospectionNamedType>,
+    subscription_type: Option<IntrospectionNamedType>,
+    types: Vec<IntrospectionType>,
+    #[serde(default)]
+    directives: Vec<IntrospectionDirective>,
+}
+
+#[derive(Deserialize)]
+struct IntrospectionNamedType {
+    name: String,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum IntrospectionTypeKind {
+    Scalar,
+    Object,
+    Interface,
+    Union,
+    Enum,
+    InputObject,
+    List,
+    NonNull,
+}
+
+impl IntrospectionTypeKind {
+    const fn to_type_kind(self) -> Option<TypeKind> {
+        match self {
+            Self::Scalar => Some(TypeKind::Scalar),
+            Self::Object => Some(TypeKind::Object),
+            Self::Interface => Some(TypeKind::Interface),
+            Self::Union => Some(TypeKind::Union),
+            Self::Enum => Some(TypeKind::Enum),
+            Self::InputObject => Some(TypeKind::InputObject),
+            Self::List | Self::NonNull => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionType {
+    kind: IntrospectionTypeKind,
+    name: String,
+    fields: Option<Vec<IntrospectionField>>,
+    input_fields: Option<Vec<IntrospectionInputValue>>,
+    enum_values: Option<Vec<IntrospectionEnumValue>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionField {
+    name: String,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+    #[serde(rename = "type")]
+    ty: IntrospectionTypeReference,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionEnumValue {
+    name: String,
+    #[serde(default)]
+    is_deprecated: bool,
+    deprecation_reason: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionInputValue {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IntrospectionTypeReference,
+    default_value: Option<String>,
+}
+
+impl IntrospectionInputValue {
+    fn convert_all(values: Vec<Self>) -> Vec<InputValueDefinition> {
+        values
+            .into_iter()
+            .filter_map(|value| {
+                Some(InputValueDefinition {
+                    name: value.name,
+                    ty: value.ty.to_type_reference()?,
+                    has_default_value: value.default_value.is_some(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct IntrospectionTypeReference {
+    kind: IntrospectionTypeKind,
+    name: Option<String>,
+    of_type: Option<Box<IntrospectionTypeReference>>,
+}
+
+impl IntrospectionTypeReference {
+    fn to_type_reference(&self) -> Option<TypeReference> {
+        match self.kind {
+            IntrospectionTypeKind::List => Some(TypeReference::List(Box::new(
+                self.of_type.as_ref()?.to_type_reference()?,
+            ))),
+            IntrospectionTypeKind::NonNull => Some(TypeReference::NonNull(Box::new(
+                self.of_type.as_ref()?.to_type_reference()?,
+            ))),
+            _ => Some(TypeReference::Named(self.name.clone()?)),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct IntrospectionDirective {
+    name: String,
+    #[serde(default)]
+    args: Vec<IntrospectionInputValue>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDL: &str = r#"
+schema { query: RootQuery }
+
+type RootQuery {
+  user(id: ID!, format: String = "short"): User
+  users(filter: UserFilter): [User!]!
+}
+
+interface Node { id: ID! }
+
+type User implements Node {
+  id: ID!
+  name: String
+  login: String @deprecated(reason: "Use `name`.")
+  friends(first: Int): [User]
+}
+
+enum Role {
+  ADMIN @deprecated
+  MEMBER
+}
+
+input UserFilter { name: String, active: Boolean! }
+
+directive @cached(ttl: Int!) on FIELD
+"#;
+
+    #[test]
+    fn loads_sdl_schema() {
+        let schema = GraphqlSchema::from_sdl(SDL).unwrap();
+        let query = schema.root_type(OperationKind::Query).unwrap();
+        assert_eq!(query.name, "RootQuery");
+        let user = query.field("user").unwrap();
+        assert!(user.argument("id").unwrap().is_required());
+        assert!(!user.argument("format").unwrap().is_required());
+        assert_eq!(query.field("users").unwrap().ty.to_string(), "[User!]!");
+
+        let user = schema.get_type("User").unwrap();
+        assert_eq!(user.kind, TypeKind::Object);
+        assert!(user.field("name").is_some());
+        assert!(user.field("friends").is_some());
+        assert!(user.field("name").unwrap().deprecation_reason.is_none());
+        assert_eq!(
+            user.field("login").unwrap().deprecation_reason.as_deref(),
+            Some("Use `name`.")
+        );
+
+        let role = schema.get_type("Role").unwrap();
+        assert_eq!(
+            role.enum_value("ADMIN")
+                .unwrap()
+                .deprecation_reason
+                .as_deref(),
+            Some(DEFAULT_DEPRECATION_REASON)
+        );
+        assert!(role
+            .enum_value("MEMBER")
+            .unwrap()
+            .deprecation_reason
+            .is_none());
+
+        let filter = schema.get_type("UserFilter").unwrap();
+        assert!(filter.input_field("active").unwrap().is_required());
+
+        assert!(schema.get_directive("cached").is_some());
+        assert!(schema.get_directive("include").is_some());
+        assert!(schema.get_type("Boolean").is_some());
+        assert!(schema.root_type(OperationKind::Mutation).is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_sdl() {
+        assert!(matches!(
+            GraphqlSchema::from_sdl("type Query {"),
+            Err(SchemaError::InvalidSdl(_))
+        ));
+    }
+
+    #[test]
+    fn loads_introspection_response() {
+        let schema = GraphqlSchema::from_introspection(
+            r#"{
+  "data": {
+    "__schema": {
+      "queryType": { "name": "Query" },
+      "mutationType": null,
+      "subscriptionType": null,
+      "types": [
+        {
+          "kind": "OBJECT",
+          "name": "Query",
+          "fields": [
+            {
+              "name": "users",
+              "args": [
+                {
+                  "name": "ids",
+                  "type": {
+                    "kind": "NON_NULL",
+                    "name": null,
+                    "ofType": { "kind": "LIST", "name": null, "ofType": { "kind": "SCALAR", "name": "ID", "ofType": null } }
+                  },
+                  "defaultValue": null
+                }
+              ],
+              "type": { "kind": "LIST", "name": null, "ofType": { "kind": "OBJECT", "name": "User", "ofType": null } },
+              "isDeprecated": true,
+              "deprecationReason": "Use `search`."
+            }
+          ],
+          "inputFields": null
+        },
+        {
+          "kind": "ENUM",
+          "name": "Role",
+          "fields": null,
+          "inputFields": null,
+          "enumValues": [
+            { "name": "ADMIN", "isDeprecated": false, "deprecationReason": null }
+          ]
+        }
+      ],
+      "directives": []
+    }
+  }
+}"#,
+        )
+        .unwrap();
+        let users = schema
+            .root_type(OperationKind::Query)
+            .and_then(|query| query.field("users"))
+            .unwrap();
+        assert_eq!(users.ty.to_string(), "[User]");
+        assert_eq!(users.argument("ids").unwrap().ty.to_string(), "[ID]!");
+        assert!(users.argument("ids").unwrap().is_required());
+        assert_eq!(users.deprecation_reason.as_deref(), Some("Use `search`."));
+
+        let role = schema.get_type("Role").unwrap();
+        assert_eq!(role.kind, TypeKind::Enum);
+        assert!(role
+            .enum_value("ADMIN")
+            .unwrap()
+            .deprecation_reason
+            .is_none());
+    }
+
+    #[test]
+    fn checks_type_compatibility() {
+        let named = |name: &str| TypeReference::Named(name.to_string());
+        let non_null = |ty: TypeReference| TypeReference::NonNull(Box::new(ty));
+        let list = |ty: TypeReference| TypeReference::List(Box::new(ty));
+
+        assert!(named("ID").is_compatible_with(&named("ID")));
+        assert!(non_null(named("ID")).is_compatible_with(&named("ID")));
+        assert!(!named("ID").is_compatible_with(&non_null(named("ID"))));
+        assert!(!named("String").is_compatible_with(&named("ID")));
+        assert!(list(non_null(named("ID"))).is_compatible_with(&list(named("ID"))));
+        assert!(!list(named("ID")).is_compatible_with(&named("ID")));
+        assert!(!named("ID").is_compatible_with(&list(named("ID"))));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::schema::{
+    name_text, DirectiveDefinition, FieldDefinition, GraphqlSchema, InputValueDefinition,
+    OperationKind, TypeDefinition, TypeReference,
+};
+use biome_graphql_syntax::{
+    GraphqlArgument, GraphqlDirective, GraphqlField, GraphqlFragmentDefinition,
+    GraphqlInlineFragment, GraphqlObjectField, GraphqlOperationDefinition, GraphqlSelectionSet,
+    GraphqlSyntaxKind, GraphqlSyntaxNode,
+};
+use biome_rowan::{declare_node_union, AstNode};
+
+declare_node_union! {
+    /// A node that accepts arguments.
+    pub AnyGraphqlArgumentsOwner = GraphqlField | GraphqlDirective
+}
+
+/// The definition of the field or the directive that owns some arguments.
+pub(crate) enum ArgumentsOwnerDefinition<'a> {
+    Field {
+        parent_type: &'a TypeDefinition,
+        field: &'a FieldDefinition,
+    },
+    Directive(&'a DirectiveDefinition),
+}
+
+impl<'a> ArgumentsOwnerDefinition<'a> {
+    pub(crate) fn arguments(&self) -> &'a [InputValueDefinition] {
+        match self {
+            Self::Field { field, .. } => &field.arguments,
+            Self::Directive(directive) => &directive.arguments,
+        }
+    }
+
+    pub(crate) fn argument(&self, name: &str) -> Option<&'a InputValueDefinition> {
+        self.arguments()
+            .iter()
+            .find(|argument| argument.name == name)
+    }
+
+    /// Returns a description of the owner, such as `Query.user` or `@include`.
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            Self::Field { parent_type, field } => format!("{}.{}", parent_type.name, field.name),
+            Self::Directive(directive) => format!("@{}", directive.name),
+        }
+    }
+}
+
+/// Returns the type of the values selected by a selection set.
+///
+/// Returns `None` if the type can't be determined, for example when the selection set
+/// belongs to a field that doesn't exist.
+pub(crate) fn selection_set_type<'a>(
+    schema: &'a GraphqlSchema,
+    selection_set: &GraphqlSelectionSet,
+) -> Option<&'a TypeDefinition> {
+    let parent = selection_set.syntax().parent()?;
+    match parent.kind() {
+        // The shorthand syntax of the queries
+        GraphqlSyntaxKind::GRAPHQL_DEFINITION_LIST => schema.root_type(OperationKind::Query),
+        GraphqlSyntaxKind::GRAPHQL_OPERATION_DEFINITION => {
+            let operation = GraphqlOperationDefinition::cast(parent)?;
+            let keyword = operation.ty().ok()?.value_token().ok()?;
+            schema.root_type(OperationKind::from_keyword(keyword.text_trimmed())?)
+        }
+        GraphqlSyntaxKind::GRAPHQL_FIELD => {
+            let field = GraphqlField::cast(parent)?;
+            let (_, definition) = field_definition(schema, &field)?;
+            schema.get_type(definition.ty.named_type())
+        }
+        GraphqlSyntaxKind::GRAPHQL_INLINE_FRAGMENT => {
+            let fragment = GraphqlInlineFragment::cast(parent)?;
+            match fragment.type_condition() {
+                Some(condition) => schema.get_type(&name_text(&condition.ty().ok()?.name().ok()?)?),
+                None => selection_set_type(schema, &enclosing_selection_set(fragment.syntax())?),
+            }
+        }
+        GraphqlSyntaxKind::GRAPHQL_FRAGMENT_DEFINITION => {
+            let fragment = GraphqlFragmentDefinition::cast(parent)?;
+            let condition = fragment.type_condition().ok()?;
+            schema.get_type(&name_text(&condition.ty().ok()?.name().ok()?)?)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the selection set that contains a selection.
+pub(crate) fn enclosing_selection_set(
+    selection: &GraphqlSyntaxNode,
+) -> Option<GraphqlSelectionSet> {
+    // The parent of a selection is the list of selections
+    GraphqlSelectionSet::cast(selection.parent()?.parent()?)
+}
+
+/// Returns the type on which a field is selected, and the definition of the field.
+pub(crate) fn field_definition<'a>(
+    schema: &'a GraphqlSchema,
+    field: &GraphqlField,
+) -> Option<(&'a TypeDefinition, &'a FieldDefinition)> {
+    let parent_type = selection_set_type(schema, &enclosing_selection_set(field.syntax())?)?;
+    let name = name_text(&field.name().ok()?)?;
+    Some((parent_type, parent_type.field(&name)?))
+}
+
+/// Returns the definition of the field or the directive that owns the arguments.
+pub(crate) fn arguments_owner_definition<'a>(
+    schema: &'a GraphqlSchema,
+    owner: &AnyGraphqlArgumentsOwner,
+) -> Option<ArgumentsOwnerDefinition<'a>> {
+    match owner {
+        AnyGraphqlArgumentsOwner::GraphqlField(field) => {
+            let (parent_type, field) = field_definition(schema, field)?;
+            Some(ArgumentsOwnerDefinition::Field { parent_type, field })
+        }
+        AnyGraphqlArgumentsOwner::GraphqlDirective(directive) => {
+            let name = name_text(&directive.name().ok()?)?;
+            Some(ArgumentsOwnerDefinition::Directive(
+                schema.get_directive(&name)?,
+            ))
+        }
+    }
+}
+
+/// Returns the field or the directive that owns an argument.
+pub(crate) fn argument_owner(argument: &GraphqlArgument) -> Option<AnyGraphqlArgumentsOwner> {
+    // The parent of an argument is the list of arguments, inside the parentheses
+    AnyGraphqlArgumentsOwner::cast(argument.syntax().parent()?.parent()?.parent()?)
+}
+
+/// Returns the type expected at the position of a value, and whether this position has a default value.
+///
+/// The value is either the value of an argument, an element of a list, or the value of a field of an input object.
+pub(crate) fn expected_value_type(
+    schema: &GraphqlSchema,
+    value: &GraphqlSyntaxNode,
+) -> Option<(TypeReference, bool)> {
+    let parent = value.parent()?;
+    match parent.kind() {
+        GraphqlSyntaxKind::GRAPHQL_ARGUMENT => {
+            let argument = GraphqlArgument::cast(parent)?;
+            let owner = arguments_owner_definition(schema, &argument_owner(&argument)?)?;
+            let definition = owner.argument(&name_text(&argument.name().ok()?)?)?;
+            Some((definition.ty.clone(), definition.has_default_value))
+        }
+        GraphqlSyntaxKind::GRAPHQL_LIST_VALUE_ELEMENT_LIST => {
+            let (list_type, _) = expected_value_type(schema, &parent.parent()?)?;
+            Some((list_type.list_element()?.clone(), false))
+        }
+        GraphqlSyntaxKind::GRAPHQL_OBJECT_FIELD => {
+            let field = GraphqlObjectField::cast(parent)?;
+            // The parent of the field is the list of fields, inside the braces
+            let object = field.syntax().parent()?.parent()?;
+            let (object_type, _) = expected_value_type(schema, &object)?;
+            let object_type = schema.get_type(object_type.nullable().named_type())?;
+            let definition = object_type.input_field(&name_text(&field.name().ok()?)?)?;
+            Some((definition.ty.clone(), definition.has_default_value))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,141 @@
+use biome_analyze::{AnalysisFilter, ControlFlow, Never, RuleFilter};
+use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_graphql_analyze::project::GraphqlProjectIndex;
+use biome_graphql_analyze::schema::GraphqlSchema;
+use biome_graphql_parser::parse_graphql;
+use biome_test_utils::{
+    create_analyzer_options, diagnostic_to_string, parse_test_path, register_leak_checker,
+    write_analyzer_snapshot,
+};
+use std::sync::Arc;
+use std::{
+    ffi::OsStr,
+    fs::{read_dir, read_to_string},
+    path::{Path, PathBuf},
+    slice,
+};
+
+tests_macros::gen_tests! {"tests/specs/**/*.graphql", crate::run_test, "module"}
+
+/// The files next to the test files that contain the schema of the tests,
+/// either in the Schema Definition Language or as an introspection result
+const SCHEMA_FILE_NAMES: [&str; 2] = ["schema.graphqls", "schema.json"];
+
+fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
+    register_leak_checker();
+
+    let input_file = Path::new(input);
+    let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
+
+    let (group, rule) = parse_test_path(input_file);
+    if rule == "specs" {
+        panic!("the test file must be placed in the {rule}/<group-name>/<rule-name>/ directory");
+    }
+    if group == "specs" {
+        panic!("the test file must be placed in the {group}/{rule}/<rule-name>/ directory");
+    }
+    if biome_graphql_analyze::metadata()
+        .find_rule(group, rule)
+        .is_none()
+    {
+        panic!("could not find rule {group}/{rule}");
+    }
+
+    let rule_filter = RuleFilter::Rule(group, rule);
+    let filter = AnalysisFilter {
+        enabled_rules: Some(slice::from_ref(&rule_filter)),
+        ..AnalysisFilter::default()
+    };
+
+    let mut snapshot = String::new();
+
+    let input_code = read_to_string(input_file)
+        .unwrap_or_else(|err| panic!("failed to read {:?}: {:?}", input_file, err));
+    let quantity_diagnostics = analyze_and_snap(
+        &mut snapshot,
+        &input_code,
+        filter,
+        file_name,
+        input_file,
+        load_schema(input_file),
+        index_project(input_file),
+    );
+
+    insta::with_settings!({
+        prepend_module_to_snapshot => false,
+        snapshot_path => input_file.parent().unwrap(),
+    }, {
+        insta::assert_snapshot!(file_name, snapshot, file_name);
+    });
+
+    if input_code.contains("# should not generate diagnostics") && quantity_diagnostics > 0 {
+        panic!("This test should not generate diagnostics");
+    }
+}
+
+/// Loads the schema from one of the [SCHEMA_FILE_NAMES] in the directory of the test file
+fn load_schema(input_file: &Path) -> Option<Arc<GraphqlSchema>> {
+    let directory = input_file.parent()?;
+    SCHEMA_FILE_NAMES.iter().find_map(|schema_file_name| {
+        let schema_file = directory.join(schema_file_name);
+        let content = read_to_string(&schema_file).ok()?;
+        let schema = GraphqlSchema::from_file(&schema_file, &content)
+            .unwrap_or_else(|err| panic!("failed to load {:?}: {}", schema_file, err));
+        Some(Arc::new(schema))
+    })
+}
+
+/// Indexes the GraphQL documents in the directory of the test file, which form the project of the test.
+///
+/// The documents are identified by their file name, so that the snapshots don't depend on the location of the repository.
+fn index_project(input_file: &Path) -> Option<Arc<GraphqlProjectIndex>> {
+    let mut project = GraphqlProjectIndex::default();
+    for entry in read_dir(input_file.parent()?).ok()? {
+        let path = entry.ok()?.path();
+        if path.extension().and_then(OsStr::to_str) != Some("graphql") {
+            continue;
+        }
+        let content = read_to_string(&path)
+            .unwrap_or_else(|err| panic!("failed to read {:?}: {:?}", path, err));
+        project.add_document(
+            Path::new(path.file_name()?),
+            &parse_graphql(&content).tree(),
+        );
+    }
+    Some(Arc::new(project))
+}
+
+pub(crate) fn analyze_and_snap(
+    snapshot: &mut String,
+    input_code: &str,
+    filter: AnalysisFilter,
+    file_name: &str,
+    input_file: &Path,
+    schema: Option<Arc<GraphqlSchema>>,
+    project: Option<Arc<GraphqlProjectIndex>>,
+) -> usize {
+    let parsed = parse_graphql(input_code);
+    let root = parsed.tree();
+
+    let mut diagnostics = Vec::new();
+    let mut options = create_analyzer_options(input_file, &mut diagnostics);
+    options.file_path = PathBuf::from(file_name);
+
+    let (_, errors) =
+        biome_graphql_analyze::analyze(&root, filter, &options, schema, project, |event| {
+            if let Some(diag) = event.diagnostic() {
+                let error = diag.with_severity(Severity::Warning);
+                diagnostics.push(diagnostic_to_string(file_name, input_code, error));
+            }
+
+            ControlFlow::<Never>::Continue(())
+        });
+
+    for error in errors {
+        diagnostics.push(diagnostic_to_string(file_name, input_code, error));
+    }
+
+    write_analyzer_snapshot(snapshot, input_code, diagnostics.as_slice(), &[], "graphql");
+
+    diagnostics.len()
+}
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+use biome_diagnostics::category;
+use biome_formatter::comments::{
+    CommentKind, CommentPlacement, CommentStyle, Comments, DecoratedComment, SourceComment,
+};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{write, FormatResult, FormatRule};
+use biome_graphql_syntax::GraphqlLanguage;
+use biome_rowan::SyntaxTriviaPieceComments;
+use biome_suppression::parse_suppression_comment;
+
+pub type GraphqlComments = Comments<GraphqlLanguage>;
+
+#[derive(Default)]
+pub struct FormatGraphqlLeadingComment;
+
+impl FormatRule<SourceComment<GraphqlLanguage>> for FormatGraphqlLeadingComment {
+    type Context = GraphqlFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<GraphqlLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct GraphqlCommentStyle;
+
+impl CommentStyle for GraphqlCommentStyle {
+    type Language = GraphqlLanguage;
+
+    fn is_suppression(text: &str) -> bool {
+        // `parse_suppression_comment` only understands `//` and `/*` comments,
+        // so `# biome-ignore format: reason` is parsed as a `//` comment
+        let Some(comment) = text.strip_prefix('#') else {
+            return false;
+        };
+        parse_suppression_comment(&std::format!("//{comment}"))
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .any(|(key, _)| key == category!("format"))
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Line
+    }
+
+    fn place_comment(
+        &self,
+        comment: DecoratedComment<Self::Language>,
+    ) -> CommentPlacement<Self::Language> {
+        CommentPlacement::Default(comment)
+    }
+}
@@ -0,0 +1,359 @@
+use crate::comments::{FormatGraphqlLeadingComment, GraphqlComments};
+use crate::GraphqlCommentStyle;
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::{prelude::*, AttributePosition, IndentWidth, MaxEmptyLines};
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
+    TransformSourceMap,
+};
+use biome_graphql_syntax::GraphqlLanguage;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct GraphqlFormatContext {
+    options: GraphqlFormatOptions,
+    /// The comments of the nodes and tokens in the document.
+    comments: Rc<GraphqlComments>,
+    source_map: Option<TransformSourceMap>,
+}
+
+impl GraphqlFormatContext {
+    pub fn new(options: GraphqlFormatOptions, comments: GraphqlComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+}
+
+impl FormatContext for GraphqlFormatContext {
+    type Options = GraphqlFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for GraphqlFormatContext {
+    type Language = GraphqlLanguage;
+    type Style = GraphqlCommentStyle;
+    type CommentRule = FormatGraphqlLeadingComment;
+
+    fn comments(&self) -> &GraphqlComments {
+        &self.comments
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GraphqlFormatOptions {
+    indent_style: IndentStyle,
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    attribute_position: AttributePosition,
+    arguments_indent: ArgumentsIndent,
+    description_quote_style: DescriptionQuoteStyle,
+    blank_lines_between_definitions: BlankLinesBetweenDefinitions,
+}
+
+impl GraphqlFormatOptions {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_arguments_indent(mut self, arguments_indent: ArgumentsIndent) -> Self {
+        self.arguments_indent = arguments_indent;
+        self
+    }
+
+    pub fn with_description_quote_style(
+        mut self,
+        description_quote_style: DescriptionQuoteStyle,
+    ) -> Self {
+        self.description_quote_style = description_quote_style;
+        self
+    }
+
+    pub fn with_blank_lines_between_definitions(
+        mut self,
+        blank_lines_between_definitions: BlankLinesBetweenDefinitions,
+    ) -> Self {
+        self.blank_lines_between_definitions = blank_lines_between_definitions;
+        self
+    }
+
+    pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
+        self.indent_style = indent_style;
+    }
+
+    pub fn set_indent_width(&mut self, indent_width: IndentWidth) {
+        self.indent_width = indent_width;
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+
+    pub fn set_arguments_indent(&mut self, arguments_indent: ArgumentsIndent) {
+        self.arguments_indent = arguments_indent;
+    }
+
+    pub fn set_description_quote_style(&mut self, description_quote_style: DescriptionQuoteStyle) {
+        self.description_quote_style = description_quote_style;
+    }
+
+    pub fn set_blank_lines_between_definitions(
+        &mut self,
+        blank_lines_between_definitions: BlankLinesBetweenDefinitions,
+    ) {
+        self.blank_lines_between_definitions = blank_lines_between_definitions;
+    }
+
+    pub fn arguments_indent(&self) -> ArgumentsIndent {
+        self.arguments_indent
+    }
+
+    pub fn description_quote_style(&self) -> DescriptionQuoteStyle {
+        self.description_quote_style
+    }
+
+    pub fn blank_lines_between_definitions(&self) -> BlankLinesBetweenDefinitions {
+        self.blank_lines_between_definitions
+    }
+}
+
+impl FormatOptions for GraphqlFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+
+    fn attribute_position(&self) -> AttributePosition {
+        self.attribute_position
+    }
+
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        MaxEmptyLines::default()
+    }
+}
+
+impl fmt::Display for GraphqlFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Indent style: {}", self.indent_style)?;
+        writeln!(f, "Indent width: {}", self.indent_width.value())?;
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.get())?;
+        writeln!(f, "Arguments indent: {}", self.arguments_indent)?;
+        writeln!(
+            f,
+            "Description quote style: {}",
+            self.description_quote_style
+        )?;
+        writeln!(
+            f,
+            "Blank lines between definitions: {}",
+            self.blank_lines_between_definitions
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ArgumentsIndent {
+    /// The arguments and the variable definitions that don't fit on a line are indented
+    /// on their own lines, like the selections of a selection set
+    #[default]
+    Indent,
+    /// The arguments and the variable definitions that don't fit on a line are aligned
+    /// after the opening parenthesis
+    Align,
+}
+
+impl ArgumentsIndent {
+    pub const fn is_indent(&self) -> bool {
+        matches!(self, Self::Indent)
+    }
+
+    pub const fn is_align(&self) -> bool {
+        matches!(self, Self::Align)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for ArgumentsIndent {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "indent" | "Indent" => Ok(Self::Indent),
+            "align" | "Align" => Ok(Self::Align),
+            _ => Err(
+                "Value not supported for Arguments indent. Supported values are 'indent' and 'align'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ArgumentsIndent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArgumentsIndent::Indent => std::write!(f, "Indent"),
+            ArgumentsIndent::Align => std::write!(f, "Align"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum DescriptionQuoteStyle {
+    /// The descriptions keep their quotes
+    #[default]
+    Preserve,
+    /// The descriptions are block strings (`"""`) printed on their own lines
+    Block,
+    /// The single line descriptions are strings (`"`), when they don't contain quotes or backslashes
+    Double,
+}
+
+impl DescriptionQuoteStyle {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_block(&self) -> bool {
+        matches!(self, Self::Block)
+    }
+
+    pub const fn is_double(&self) -> bool {
+        matches!(self, Self::Double)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for DescriptionQuoteStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "block" | "Block" => Ok(Self::Block),
+            "double" | "Double" => Ok(Self::Double),
+            _ => Err(
+                "Value not supported for Description quote style. Supported values are 'preserve', 'block' and 'double'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for DescriptionQuoteStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DescriptionQuoteStyle::Preserve => std::write!(f, "Preserve"),
+            DescriptionQuoteStyle::Block => std::write!(f, "Block"),
+            DescriptionQuoteStyle::Double => std::write!(f, "Double"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum BlankLinesBetweenDefinitions {
+    /// The definitions keep the blank line that separates them from the previous definition
+    #[default]
+    Preserve,
+    /// The definitions are separated by a blank line
+    Always,
+    /// The definitions aren't separated by blank lines
+    Never,
+}
+
+// Required by [Bpaf]
+impl FromStr for BlankLinesBetweenDefinitions {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "always" | "Always" => Ok(Self::Always),
+            "never" | "Never" => Ok(Self::Never),
+            _ => Err(
+                "Value not supported for Blank lines between definitions. Supported values are 'preserve', 'always' and 'never'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for BlankLinesBetweenDefinitions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlankLinesBetweenDefinitions::Preserve => std::write!(f, "Preserve"),
+            BlankLinesBetweenDefinitions::Always => std::write!(f, "Always"),
+            BlankLinesBetweenDefinitions::Never => std::write!(f, "Never"),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::context::DescriptionQuoteStyle;
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::GraphqlSyntaxToken;
+use biome_rowan::TextSize;
+
+/// Formats a string value or the string of a description.
+///
+/// The block strings (`"""`) are printed on their own lines, at the indentation of the node that contains them.
+/// The strings are converted to the `quote_style` of the descriptions when their value doesn't change.
+pub(crate) fn format_string(
+    token: &GraphqlSyntaxToken,
+    quote_style: DescriptionQuoteStyle,
+) -> FormatString {
+    FormatString { token, quote_style }
+}
+
+pub(crate) struct FormatString<'token> {
+    token: &'token GraphqlSyntaxToken,
+    quote_style: DescriptionQuoteStyle,
+}
+
+impl Format<GraphqlFormatContext> for FormatString<'_> {
+    fn fmt(&self, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let text = self.token.text_trimmed();
+        let position = self.token.text_trimmed_range().start();
+
+        let lines = if let Some(content) = text
+            .strip_prefix("\"\"\"")
+            .and_then(|text| text.strip_suffix("\"\"\""))
+        {
+            block_string_lines(content)
+        } else if self.quote_style.is_block() {
+            // A backslash starts an escape sequence in a string, but it's a regular character in a block string
+            match text
+                .strip_prefix('"')
+                .and_then(|text| text.strip_suffix('"'))
+            {
+                Some(content) if !content.contains('\\') => vec![content],
+                _ => return self.token.format().fmt(f),
+            }
+        } else {
+            return self.token.format().fmt(f);
+        };
+
+        if self.quote_style.is_double() {
+            if let [line] = lines.as_slice() {
+                if !line.contains(['"', '\\']) {
+                    let string = std::format!("\"{line}\"");
+                    return format_replaced(self.token, &dynamic_text(&string, position)).fmt(f);
+                }
+            }
+        }
+
+        format_replaced(self.token, &FormatBlockString { lines, position }).fmt(f)
+    }
+}
+
+struct FormatBlockString<'a> {
+    lines: Vec<&'a str>,
+    position: TextSize,
+}
+
+impl Format<GraphqlFormatContext> for FormatBlockString<'_> {
+    fn fmt(&self, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        write!(f, [text("\"\"\"")])?;
+
+        let mut blank_lines = 0;
+        for line in &self.lines {
+            if line.is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+
+            if blank_lines > 0 {
+                write!(f, [empty_lines(blank_lines)])?;
+                blank_lines = 0;
+            } else {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(f, [dynamic_text(line, self.position)])?;
+        }
+
+        write!(f, [hard_line_break(), text("\"\"\"")])
+    }
+}
+
+/// Returns the lines of the value of a block string, from its `content` between the quotes.
+///
+/// Like the [block string value](https://spec.graphql.org/October2021/#BlockStringValue()),
+/// the common indentation and the leading and trailing blank lines are removed.
+/// The white spaces at the start of the first line and at the end of the lines are removed too,
+/// since the block string is printed on its own lines.
+fn block_string_lines(content: &str) -> Vec<&str> {
+    let lines: Vec<_> = content
+        .split("\r\n")
+        .flat_map(|line| line.split(['\n', '\r']))
+        .collect();
+
+    let is_white_space = |c: char| matches!(c, ' ' | '\t');
+    let common_indentation = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !line.trim_start_matches(is_white_space).is_empty())
+        .map(|line| line.len() - line.trim_start_matches(is_white_space).len())
+        .min()
+        .unwrap_or(0);
+
+    let lines: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line = if index == 0 {
+                line.trim_start_matches(is_white_space)
+            } else {
+                line.get(common_indentation..).unwrap_or_default()
+            };
+            line.trim_end_matches(is_white_space)
+        })
+        .collect();
+
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].to_vec(),
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,22 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_graphql_syntax::AnyGraphqlEnumTypeExtension;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyGraphqlEnumTypeExtension;
+impl FormatRule<AnyGraphqlEnumTypeExtension> for FormatAnyGraphqlEnumTypeExtension {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &AnyGraphqlEnumTypeExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        match node {
+            AnyGraphqlEnumTypeExtension::GraphqlBogusExtension(node) => node.format().fmt(f),
+            AnyGraphqlEnumTypeExtension::GraphqlEnumTypeExtension(node) => node.format().fmt(f),
+            AnyGraphqlEnumTypeExtension::GraphqlEnumTypeExtensionWithValues(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
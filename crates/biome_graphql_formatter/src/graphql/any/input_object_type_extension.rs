@@ -0,0 +1,24 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_graphql_syntax::AnyGraphqlInputObjectTypeExtension;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyGraphqlInputObjectTypeExtension;
+impl FormatRule<AnyGraphqlInputObjectTypeExtension> for FormatAnyGraphqlInputObjectTypeExtension {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &AnyGraphqlInputObjectTypeExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        match node {
+            AnyGraphqlInputObjectTypeExtension::GraphqlBogusExtension(node) => node.format().fmt(f),
+            AnyGraphqlInputObjectTypeExtension::GraphqlInputObjectTypeExtension(node) => {
+                node.format().fmt(f)
+            }
+            AnyGraphqlInputObjectTypeExtension::GraphqlInputObjectTypeExtensionWithFields(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
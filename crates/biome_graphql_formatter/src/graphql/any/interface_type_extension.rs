@@ -0,0 +1,27 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_graphql_syntax::AnyGraphqlInterfaceTypeExtension;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyGraphqlInterfaceTypeExtension;
+impl FormatRule<AnyGraphqlInterfaceTypeExtension> for FormatAnyGraphqlInterfaceTypeExtension {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &AnyGraphqlInterfaceTypeExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        match node {
+            AnyGraphqlInterfaceTypeExtension::GraphqlBogusExtension(node) => node.format().fmt(f),
+            AnyGraphqlInterfaceTypeExtension::GraphqlInterfaceTypeExtension(node) => {
+                node.format().fmt(f)
+            }
+            AnyGraphqlInterfaceTypeExtension::GraphqlInterfaceTypeExtensionWithDirectives(node) => {
+                node.format().fmt(f)
+            }
+            AnyGraphqlInterfaceTypeExtension::GraphqlInterfaceTypeExtensionWithFields(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
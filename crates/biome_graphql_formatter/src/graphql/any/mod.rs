@@ -0,0 +1,14 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod definition;
+pub(crate) mod enum_type_extension;
+pub(crate) mod input_object_type_extension;
+pub(crate) mod interface_type_extension;
+pub(crate) mod object_type_extension;
+pub(crate) mod operation_definition;
+pub(crate) mod primitive_type;
+pub(crate) mod schema_extension;
+pub(crate) mod selection;
+pub(crate) mod ts_type;
+pub(crate) mod union_type_extension;
+pub(crate) mod value;
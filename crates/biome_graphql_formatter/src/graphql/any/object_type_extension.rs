@@ -0,0 +1,25 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_graphql_syntax::AnyGraphqlObjectTypeExtension;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyGraphqlObjectTypeExtension;
+impl FormatRule<AnyGraphqlObjectTypeExtension> for FormatAnyGraphqlObjectTypeExtension {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &AnyGraphqlObjectTypeExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        match node {
+            AnyGraphqlObjectTypeExtension::GraphqlBogusExtension(node) => node.format().fmt(f),
+            AnyGraphqlObjectTypeExtension::GraphqlObjectTypeExtension(node) => node.format().fmt(f),
+            AnyGraphqlObjectTypeExtension::GraphqlObjectTypeExtensionWithDirectives(node) => {
+                node.format().fmt(f)
+            }
+            AnyGraphqlObjectTypeExtension::GraphqlObjectTypeExtensionWithFields(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
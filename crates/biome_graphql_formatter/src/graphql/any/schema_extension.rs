@@ -0,0 +1,18 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_graphql_syntax::AnyGraphqlSchemaExtension;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyGraphqlSchemaExtension;
+impl FormatRule<AnyGraphqlSchemaExtension> for FormatAnyGraphqlSchemaExtension {
+    type Context = GraphqlFormatContext;
+    fn fmt(&self, node: &AnyGraphqlSchemaExtension, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        match node {
+            AnyGraphqlSchemaExtension::GraphqlBogusExtension(node) => node.format().fmt(f),
+            AnyGraphqlSchemaExtension::GraphqlSchemaExtension(node) => node.format().fmt(f),
+            AnyGraphqlSchemaExtension::GraphqlSchemaExtensionWithRootOperationType(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
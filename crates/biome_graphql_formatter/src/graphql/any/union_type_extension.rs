@@ -0,0 +1,22 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_graphql_syntax::AnyGraphqlUnionTypeExtension;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyGraphqlUnionTypeExtension;
+impl FormatRule<AnyGraphqlUnionTypeExtension> for FormatAnyGraphqlUnionTypeExtension {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &AnyGraphqlUnionTypeExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        match node {
+            AnyGraphqlUnionTypeExtension::GraphqlBogusExtension(node) => node.format().fmt(f),
+            AnyGraphqlUnionTypeExtension::GraphqlUnionTypeExtension(node) => node.format().fmt(f),
+            AnyGraphqlUnionTypeExtension::GraphqlUnionTypeExtensionWithMembers(node) => {
+                node.format().fmt(f)
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use crate::utils::FormatGraphqlParenthesizedList;
+use biome_graphql_syntax::{GraphqlArguments, GraphqlArgumentsFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlArguments;
+
+impl FormatNodeRule<GraphqlArguments> for FormatGraphqlArguments {
+    fn fmt_fields(&self, node: &GraphqlArguments, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlArgumentsFields {
+            l_paren_token,
+            arguments,
+            r_paren_token,
+        } = node.as_fields();
+
+        FormatGraphqlParenthesizedList {
+            node: node.syntax(),
+            l_paren_token,
+            list: &arguments,
+            r_paren_token,
+        }
+        .fmt(f)
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlArguments,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use biome_graphql_syntax::{GraphqlDescription, GraphqlDescriptionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlDescription;
+
+impl FormatNodeRule<GraphqlDescription> for FormatGraphqlDescription {
+    fn fmt_fields(&self, node: &GraphqlDescription, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlDescriptionFields {
+            graphql_string_value,
+        } = node.as_fields();
+
+        graphql_string_value.format().fmt(f)
+    }
+}
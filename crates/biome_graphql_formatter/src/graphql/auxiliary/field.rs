@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlField, GraphqlFieldFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlField;
+
+impl FormatNodeRule<GraphqlField> for FormatGraphqlField {
+    fn fmt_fields(&self, node: &GraphqlField, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlFieldFields {
+            alias,
+            name,
+            arguments,
+            directives,
+            selection_set,
+        } = node.as_fields();
+
+        if let Some(alias) = alias {
+            write!(f, [alias.format(), space()])?;
+        }
+
+        write!(f, [name.format(), arguments.format()])?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        if let Some(selection_set) = selection_set {
+            write!(f, [space(), selection_set.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod alias;
+pub(crate) mod argument;
+pub(crate) mod arguments;
+pub(crate) mod description;
+pub(crate) mod directive;
+pub(crate) mod directive_location;
+pub(crate) mod field;
+pub(crate) mod fragment_spread;
+pub(crate) mod implements_interfaces;
+pub(crate) mod inline_fragment;
+pub(crate) mod name;
+pub(crate) mod object_field;
+pub(crate) mod operation_type;
+pub(crate) mod root;
+pub(crate) mod selection_set;
+pub(crate) mod type_condition;
+pub(crate) mod union_member_types;
+pub(crate) mod variable;
+pub(crate) mod variable_definitions;
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlRoot, GraphqlRootFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlRoot;
+
+impl FormatNodeRule<GraphqlRoot> for FormatGraphqlRoot {
+    fn fmt_fields(&self, node: &GraphqlRoot, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlRootFields {
+            bom_token,
+            definitions,
+            eof_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                bom_token.format(),
+                definitions.format(),
+                format_dangling_comments(node.syntax()),
+                format_removed(&eof_token?),
+                hard_line_break()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(&self, _: &GraphqlRoot, _: &mut GraphqlFormatter) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::{GraphqlSelectionSet, GraphqlSelectionSetFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlSelectionSet;
+
+impl FormatNodeRule<GraphqlSelectionSet> for FormatGraphqlSelectionSet {
+    fn fmt_fields(&self, node: &GraphqlSelectionSet, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlSelectionSetFields {
+            l_curly_token,
+            selections,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_curly_token.format(),
+                block_indent(&format_args![
+                    selections.format(),
+                    format_dangling_comments(node.syntax())
+                ]),
+                r_curly_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlSelectionSet,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlUnionMemberTypes, GraphqlUnionMemberTypesFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlUnionMemberTypes;
+
+impl FormatNodeRule<GraphqlUnionMemberTypes> for FormatGraphqlUnionMemberTypes {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlUnionMemberTypes,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlUnionMemberTypesFields {
+            eq_token,
+            bitwise_or_token,
+            members,
+        } = node.as_fields();
+
+        write!(f, [eq_token.format()])?;
+
+        // The leading `|` is optional, it's printed when the members break
+        if let Some(bitwise_or_token) = bitwise_or_token {
+            write!(f, [format_removed(&bitwise_or_token)])?;
+        }
+
+        write!(f, [members.format()])
+    }
+}
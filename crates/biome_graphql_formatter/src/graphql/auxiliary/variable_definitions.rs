@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use crate::utils::FormatGraphqlParenthesizedList;
+use biome_graphql_syntax::{GraphqlVariableDefinitions, GraphqlVariableDefinitionsFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlVariableDefinitions;
+
+impl FormatNodeRule<GraphqlVariableDefinitions> for FormatGraphqlVariableDefinitions {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlVariableDefinitions,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlVariableDefinitionsFields {
+            l_paren_token,
+            elements,
+            r_paren_token,
+        } = node.as_fields();
+
+        FormatGraphqlParenthesizedList {
+            node: node.syntax(),
+            l_paren_token,
+            list: &elements,
+            r_paren_token,
+        }
+        .fmt(f)
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlVariableDefinitions,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+use crate::FormatBogusNodeRule;
+use biome_graphql_syntax::GraphqlBogusExtension;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlBogusExtension;
+impl FormatBogusNodeRule<GraphqlBogusExtension> for FormatGraphqlBogusExtension {}
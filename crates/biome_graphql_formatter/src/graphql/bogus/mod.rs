@@ -0,0 +1,9 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+#[allow(clippy::module_inception)]
+pub(crate) mod bogus;
+pub(crate) mod bogus_definition;
+pub(crate) mod bogus_extension;
+pub(crate) mod bogus_selection;
+pub(crate) mod bogus_type;
+pub(crate) mod bogus_value;
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use crate::utils::FormatGraphqlParenthesizedList;
+use biome_graphql_syntax::{GraphqlArgumentsDefinition, GraphqlArgumentsDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlArgumentsDefinition;
+
+impl FormatNodeRule<GraphqlArgumentsDefinition> for FormatGraphqlArgumentsDefinition {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlArgumentsDefinition,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlArgumentsDefinitionFields {
+            l_paren_token,
+            arguments,
+            r_paren_token,
+        } = node.as_fields();
+
+        FormatGraphqlParenthesizedList {
+            node: node.syntax(),
+            l_paren_token,
+            list: &arguments,
+            r_paren_token,
+        }
+        .fmt(f)
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlArgumentsDefinition,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::{GraphqlEnumValuesDefinition, GraphqlEnumValuesDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlEnumValuesDefinition;
+
+impl FormatNodeRule<GraphqlEnumValuesDefinition> for FormatGraphqlEnumValuesDefinition {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlEnumValuesDefinition,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlEnumValuesDefinitionFields {
+            l_curly_token,
+            values,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_curly_token.format(),
+                block_indent(&format_args![
+                    values.format(),
+                    format_dangling_comments(node.syntax())
+                ]),
+                r_curly_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlEnumValuesDefinition,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
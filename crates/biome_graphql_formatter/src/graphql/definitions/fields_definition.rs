@@ -0,0 +1,41 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::{GraphqlFieldsDefinition, GraphqlFieldsDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlFieldsDefinition;
+
+impl FormatNodeRule<GraphqlFieldsDefinition> for FormatGraphqlFieldsDefinition {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlFieldsDefinition,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlFieldsDefinitionFields {
+            l_curly_token,
+            fields,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_curly_token.format(),
+                block_indent(&format_args![
+                    fields.format(),
+                    format_dangling_comments(node.syntax())
+                ]),
+                r_curly_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlFieldsDefinition,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::{GraphqlInputFieldsDefinition, GraphqlInputFieldsDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlInputFieldsDefinition;
+
+impl FormatNodeRule<GraphqlInputFieldsDefinition> for FormatGraphqlInputFieldsDefinition {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlInputFieldsDefinition,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlInputFieldsDefinitionFields {
+            l_curly_token,
+            fields,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_curly_token.format(),
+                block_indent(&format_args![
+                    fields.format(),
+                    format_dangling_comments(node.syntax())
+                ]),
+                r_curly_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlInputFieldsDefinition,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
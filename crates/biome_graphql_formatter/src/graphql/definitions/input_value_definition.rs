@@ -0,0 +1,42 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlInputValueDefinition, GraphqlInputValueDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlInputValueDefinition;
+
+impl FormatNodeRule<GraphqlInputValueDefinition> for FormatGraphqlInputValueDefinition {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlInputValueDefinition,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlInputValueDefinitionFields {
+            description,
+            name,
+            colon_token,
+            ty,
+            default,
+            directives,
+        } = node.as_fields();
+
+        if let Some(description) = description {
+            write!(f, [description.format(), hard_line_break()])?;
+        }
+
+        write!(
+            f,
+            [name.format(), colon_token.format(), space(), ty.format()]
+        )?;
+
+        if let Some(default) = default {
+            write!(f, [space(), default.format()])?;
+        }
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod arguments_definition;
+pub(crate) mod directive_definition;
+pub(crate) mod enum_type_definition;
+pub(crate) mod enum_value_definition;
+pub(crate) mod enum_values_definition;
+pub(crate) mod field_definition;
+pub(crate) mod fields_definition;
+pub(crate) mod fragment_definition;
+pub(crate) mod input_fields_definition;
+pub(crate) mod input_object_type_definition;
+pub(crate) mod input_value_definition;
+pub(crate) mod interface_type_definition;
+pub(crate) mod object_type_definition;
+pub(crate) mod operation_definition;
+pub(crate) mod root_operation_type_definition;
+pub(crate) mod scalar_type_definition;
+pub(crate) mod schema_definition;
+pub(crate) mod union_type_definition;
+pub(crate) mod variable_definition;
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlOperationDefinition, GraphqlOperationDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlOperationDefinition;
+
+impl FormatNodeRule<GraphqlOperationDefinition> for FormatGraphqlOperationDefinition {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlOperationDefinition,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlOperationDefinitionFields {
+            ty,
+            name,
+            variables,
+            directives,
+            selection_set,
+        } = node.as_fields();
+
+        write!(f, [ty.format()])?;
+
+        if let Some(name) = name {
+            write!(f, [space(), name.format()])?;
+        } else if variables.is_some() {
+            write!(f, [space()])?;
+        }
+
+        write!(f, [variables.format()])?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        write!(f, [space(), selection_set.format()])
+    }
+}
@@ -0,0 +1,55 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::{GraphqlSchemaDefinition, GraphqlSchemaDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlSchemaDefinition;
+
+impl FormatNodeRule<GraphqlSchemaDefinition> for FormatGraphqlSchemaDefinition {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlSchemaDefinition,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlSchemaDefinitionFields {
+            description,
+            schema_token,
+            directives,
+            l_curly_token,
+            root_operation_type,
+            r_curly_token,
+        } = node.as_fields();
+
+        if let Some(description) = description {
+            write!(f, [description.format(), hard_line_break()])?;
+        }
+
+        write!(f, [schema_token.format()])?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        write!(
+            f,
+            [
+                space(),
+                l_curly_token.format(),
+                block_indent(&format_args![
+                    root_operation_type.format(),
+                    format_dangling_comments(node.syntax())
+                ]),
+                r_curly_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlSchemaDefinition,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlUnionTypeDefinition, GraphqlUnionTypeDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlUnionTypeDefinition;
+
+impl FormatNodeRule<GraphqlUnionTypeDefinition> for FormatGraphqlUnionTypeDefinition {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlUnionTypeDefinition,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlUnionTypeDefinitionFields {
+            description,
+            union_token,
+            name,
+            directives,
+            union_members,
+        } = node.as_fields();
+
+        if let Some(description) = description {
+            write!(f, [description.format(), hard_line_break()])?;
+        }
+
+        write!(f, [union_token.format(), space(), name.format()])?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        if let Some(union_members) = union_members {
+            write!(f, [space(), union_members.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlEnumTypeExtension, GraphqlEnumTypeExtensionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlEnumTypeExtension;
+
+impl FormatNodeRule<GraphqlEnumTypeExtension> for FormatGraphqlEnumTypeExtension {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlEnumTypeExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlEnumTypeExtensionFields {
+            extend_token,
+            enum_token,
+            name,
+            directives,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                enum_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{
+    GraphqlEnumTypeExtensionWithValues, GraphqlEnumTypeExtensionWithValuesFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlEnumTypeExtensionWithValues;
+
+impl FormatNodeRule<GraphqlEnumTypeExtensionWithValues>
+    for FormatGraphqlEnumTypeExtensionWithValues
+{
+    fn fmt_fields(
+        &self,
+        node: &GraphqlEnumTypeExtensionWithValues,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlEnumTypeExtensionWithValuesFields {
+            extend_token,
+            enum_token,
+            name,
+            directives,
+            enum_values,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                enum_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        write!(f, [space(), enum_values.format()])
+    }
+}
@@ -0,0 +1,40 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{
+    GraphqlInputObjectTypeExtension, GraphqlInputObjectTypeExtensionFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlInputObjectTypeExtension;
+
+impl FormatNodeRule<GraphqlInputObjectTypeExtension> for FormatGraphqlInputObjectTypeExtension {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlInputObjectTypeExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlInputObjectTypeExtensionFields {
+            extend_token,
+            input_token,
+            name,
+            directives,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                input_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{
+    GraphqlInputObjectTypeExtensionWithFields, GraphqlInputObjectTypeExtensionWithFieldsFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlInputObjectTypeExtensionWithFields;
+
+impl FormatNodeRule<GraphqlInputObjectTypeExtensionWithFields>
+    for FormatGraphqlInputObjectTypeExtensionWithFields
+{
+    fn fmt_fields(
+        &self,
+        node: &GraphqlInputObjectTypeExtensionWithFields,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlInputObjectTypeExtensionWithFieldsFields {
+            extend_token,
+            input_token,
+            name,
+            directives,
+            input_fields,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                input_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        write!(f, [space(), input_fields.format()])
+    }
+}
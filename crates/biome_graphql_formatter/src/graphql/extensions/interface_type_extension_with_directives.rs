@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{
+    GraphqlInterfaceTypeExtensionWithDirectives, GraphqlInterfaceTypeExtensionWithDirectivesFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlInterfaceTypeExtensionWithDirectives;
+
+impl FormatNodeRule<GraphqlInterfaceTypeExtensionWithDirectives>
+    for FormatGraphqlInterfaceTypeExtensionWithDirectives
+{
+    fn fmt_fields(
+        &self,
+        node: &GraphqlInterfaceTypeExtensionWithDirectives,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlInterfaceTypeExtensionWithDirectivesFields {
+            extend_token,
+            interface_token,
+            name,
+            implements,
+            directives,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                interface_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if let Some(implements) = implements {
+            write!(f, [space(), implements.format()])?;
+        }
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        Ok(())
+    }
+}
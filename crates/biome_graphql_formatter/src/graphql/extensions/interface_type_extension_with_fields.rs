@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{
+    GraphqlInterfaceTypeExtensionWithFields, GraphqlInterfaceTypeExtensionWithFieldsFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlInterfaceTypeExtensionWithFields;
+
+impl FormatNodeRule<GraphqlInterfaceTypeExtensionWithFields>
+    for FormatGraphqlInterfaceTypeExtensionWithFields
+{
+    fn fmt_fields(
+        &self,
+        node: &GraphqlInterfaceTypeExtensionWithFields,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlInterfaceTypeExtensionWithFieldsFields {
+            extend_token,
+            interface_token,
+            name,
+            implements,
+            directives,
+            fields,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                interface_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if let Some(implements) = implements {
+            write!(f, [space(), implements.format()])?;
+        }
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        write!(f, [space(), fields.format()])
+    }
+}
@@ -0,0 +1,17 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod enum_type_extension;
+pub(crate) mod enum_type_extension_with_values;
+pub(crate) mod input_object_type_extension;
+pub(crate) mod input_object_type_extension_with_fields;
+pub(crate) mod interface_type_extension;
+pub(crate) mod interface_type_extension_with_directives;
+pub(crate) mod interface_type_extension_with_fields;
+pub(crate) mod object_type_extension;
+pub(crate) mod object_type_extension_with_directives;
+pub(crate) mod object_type_extension_with_fields;
+pub(crate) mod scalar_type_extension;
+pub(crate) mod schema_extension;
+pub(crate) mod schema_extension_with_root_operation_type;
+pub(crate) mod union_type_extension;
+pub(crate) mod union_type_extension_with_members;
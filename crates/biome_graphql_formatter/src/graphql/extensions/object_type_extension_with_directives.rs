@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{
+    GraphqlObjectTypeExtensionWithDirectives, GraphqlObjectTypeExtensionWithDirectivesFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlObjectTypeExtensionWithDirectives;
+
+impl FormatNodeRule<GraphqlObjectTypeExtensionWithDirectives>
+    for FormatGraphqlObjectTypeExtensionWithDirectives
+{
+    fn fmt_fields(
+        &self,
+        node: &GraphqlObjectTypeExtensionWithDirectives,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlObjectTypeExtensionWithDirectivesFields {
+            extend_token,
+            type_token,
+            name,
+            implements,
+            directives,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                type_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if let Some(implements) = implements {
+            write!(f, [space(), implements.format()])?;
+        }
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        Ok(())
+    }
+}
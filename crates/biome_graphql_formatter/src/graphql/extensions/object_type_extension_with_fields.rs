@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{
+    GraphqlObjectTypeExtensionWithFields, GraphqlObjectTypeExtensionWithFieldsFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlObjectTypeExtensionWithFields;
+
+impl FormatNodeRule<GraphqlObjectTypeExtensionWithFields>
+    for FormatGraphqlObjectTypeExtensionWithFields
+{
+    fn fmt_fields(
+        &self,
+        node: &GraphqlObjectTypeExtensionWithFields,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlObjectTypeExtensionWithFieldsFields {
+            extend_token,
+            type_token,
+            name,
+            implements,
+            directives,
+            fields,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                type_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if let Some(implements) = implements {
+            write!(f, [space(), implements.format()])?;
+        }
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        write!(f, [space(), fields.format()])
+    }
+}
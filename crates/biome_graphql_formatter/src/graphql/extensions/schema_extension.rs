@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlSchemaExtension, GraphqlSchemaExtensionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlSchemaExtension;
+
+impl FormatNodeRule<GraphqlSchemaExtension> for FormatGraphqlSchemaExtension {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlSchemaExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlSchemaExtensionFields {
+            extend_token,
+            schema_token,
+            directives,
+        } = node.as_fields();
+
+        write!(f, [extend_token.format(), space(), schema_token.format()])?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        Ok(())
+    }
+}
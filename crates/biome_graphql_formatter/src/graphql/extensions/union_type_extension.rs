@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlUnionTypeExtension, GraphqlUnionTypeExtensionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlUnionTypeExtension;
+
+impl FormatNodeRule<GraphqlUnionTypeExtension> for FormatGraphqlUnionTypeExtension {
+    fn fmt_fields(
+        &self,
+        node: &GraphqlUnionTypeExtension,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlUnionTypeExtensionFields {
+            extend_token,
+            union_token,
+            name,
+            directives,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                union_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        Ok(())
+    }
+}
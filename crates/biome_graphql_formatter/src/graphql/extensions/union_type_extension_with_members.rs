@@ -0,0 +1,43 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{
+    GraphqlUnionTypeExtensionWithMembers, GraphqlUnionTypeExtensionWithMembersFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlUnionTypeExtensionWithMembers;
+
+impl FormatNodeRule<GraphqlUnionTypeExtensionWithMembers>
+    for FormatGraphqlUnionTypeExtensionWithMembers
+{
+    fn fmt_fields(
+        &self,
+        node: &GraphqlUnionTypeExtensionWithMembers,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let GraphqlUnionTypeExtensionWithMembersFields {
+            extend_token,
+            union_token,
+            name,
+            directives,
+            union_members,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                extend_token.format(),
+                space(),
+                union_token.format(),
+                space(),
+                name.format()
+            ]
+        )?;
+
+        if !directives.is_empty() {
+            write!(f, [space(), directives.format()])?;
+        }
+
+        write!(f, [space(), union_members.format()])
+    }
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use crate::utils::FormatInlineSeparator;
+use biome_graphql_syntax::GraphqlArgumentDefinitionList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlArgumentDefinitionList;
+
+impl FormatRule<GraphqlArgumentDefinitionList> for FormatGraphqlArgumentDefinitionList {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &GraphqlArgumentDefinitionList,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        f.join_with(&FormatInlineSeparator)
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
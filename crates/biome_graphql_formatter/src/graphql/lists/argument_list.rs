@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use crate::utils::FormatInlineSeparator;
+use biome_graphql_syntax::GraphqlArgumentList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlArgumentList;
+
+impl FormatRule<GraphqlArgumentList> for FormatGraphqlArgumentList {
+    type Context = GraphqlFormatContext;
+    fn fmt(&self, node: &GraphqlArgumentList, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        f.join_with(&FormatInlineSeparator)
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
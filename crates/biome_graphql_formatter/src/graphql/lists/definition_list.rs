@@ -0,0 +1,40 @@
+use crate::context::BlankLinesBetweenDefinitions;
+use crate::prelude::*;
+use biome_graphql_syntax::GraphqlDefinitionList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlDefinitionList;
+
+impl FormatRule<GraphqlDefinitionList> for FormatGraphqlDefinitionList {
+    type Context = GraphqlFormatContext;
+    fn fmt(&self, node: &GraphqlDefinitionList, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        match f.options().blank_lines_between_definitions() {
+            BlankLinesBetweenDefinitions::Preserve => {
+                let mut join = f.join_nodes_with_hardline();
+
+                for definition in node {
+                    join.entry(
+                        definition.syntax(),
+                        &format_or_verbatim(definition.format()),
+                    );
+                }
+
+                join.finish()
+            }
+            BlankLinesBetweenDefinitions::Always => f
+                .join_with(&empty_line())
+                .entries(
+                    node.iter()
+                        .map(|definition| format_or_verbatim(definition.into_format())),
+                )
+                .finish(),
+            BlankLinesBetweenDefinitions::Never => f
+                .join_with(&hard_line_break())
+                .entries(
+                    node.iter()
+                        .map(|definition| format_or_verbatim(definition.into_format())),
+                )
+                .finish(),
+        }
+    }
+}
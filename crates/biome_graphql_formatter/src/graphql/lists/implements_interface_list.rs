@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use crate::separated::FormatGraphqlSeparatedList;
+use biome_graphql_syntax::GraphqlImplementsInterfaceList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlImplementsInterfaceList;
+
+impl FormatRule<GraphqlImplementsInterfaceList> for FormatGraphqlImplementsInterfaceList {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &GraphqlImplementsInterfaceList,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        FormatGraphqlSeparatedList::new(node, "&").fmt(f)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use crate::utils::FormatInlineSeparator;
+use biome_graphql_syntax::GraphqlListValueElementList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlListValueElementList;
+
+impl FormatRule<GraphqlListValueElementList> for FormatGraphqlListValueElementList {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &GraphqlListValueElementList,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        f.join_with(&FormatInlineSeparator)
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
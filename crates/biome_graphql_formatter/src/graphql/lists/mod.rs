@@ -0,0 +1,17 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod argument_definition_list;
+pub(crate) mod argument_list;
+pub(crate) mod definition_list;
+pub(crate) mod directive_list;
+pub(crate) mod directive_location_list;
+pub(crate) mod enum_value_list;
+pub(crate) mod field_definition_list;
+pub(crate) mod implements_interface_list;
+pub(crate) mod input_field_list;
+pub(crate) mod list_value_element_list;
+pub(crate) mod object_value_member_list;
+pub(crate) mod root_operation_type_definition_list;
+pub(crate) mod selection_list;
+pub(crate) mod union_member_type_list;
+pub(crate) mod variable_definition_list;
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use crate::utils::FormatInlineSeparator;
+use biome_graphql_syntax::GraphqlObjectValueMemberList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlObjectValueMemberList;
+
+impl FormatRule<GraphqlObjectValueMemberList> for FormatGraphqlObjectValueMemberList {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &GraphqlObjectValueMemberList,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        f.join_with(&FormatInlineSeparator)
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
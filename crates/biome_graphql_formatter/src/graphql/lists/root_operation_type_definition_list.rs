@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_graphql_syntax::GraphqlRootOperationTypeDefinitionList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlRootOperationTypeDefinitionList;
+
+impl FormatRule<GraphqlRootOperationTypeDefinitionList>
+    for FormatGraphqlRootOperationTypeDefinitionList
+{
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &GraphqlRootOperationTypeDefinitionList,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        for entry in node {
+            join.entry(entry.syntax(), &format_or_verbatim(entry.format()));
+        }
+
+        join.finish()
+    }
+}
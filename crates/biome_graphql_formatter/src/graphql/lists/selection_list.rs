@@ -0,0 +1,18 @@
+use crate::prelude::*;
+use biome_graphql_syntax::GraphqlSelectionList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlSelectionList;
+
+impl FormatRule<GraphqlSelectionList> for FormatGraphqlSelectionList {
+    type Context = GraphqlFormatContext;
+    fn fmt(&self, node: &GraphqlSelectionList, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let mut join = f.join_nodes_with_hardline();
+
+        for entry in node {
+            join.entry(entry.syntax(), &format_or_verbatim(entry.format()));
+        }
+
+        join.finish()
+    }
+}
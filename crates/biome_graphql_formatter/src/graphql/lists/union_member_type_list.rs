@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use crate::separated::FormatGraphqlSeparatedList;
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::GraphqlUnionMemberTypeList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlUnionMemberTypeList;
+
+impl FormatRule<GraphqlUnionMemberTypeList> for FormatGraphqlUnionMemberTypeList {
+    type Context = GraphqlFormatContext;
+    fn fmt(&self, node: &GraphqlUnionMemberTypeList, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        // The entries are printed on their own lines, after a `|`, when they don't fit on a single line
+        write!(
+            f,
+            [group(&indent(&format_args![
+                soft_line_break_or_space(),
+                if_group_breaks(&format_args![text("|"), space()]),
+                FormatGraphqlSeparatedList::new(node, "|").with_break_before_separators()
+            ]))]
+        )
+    }
+}
@@ -0,0 +1,19 @@
+use crate::prelude::*;
+use crate::utils::FormatInlineSeparator;
+use biome_graphql_syntax::GraphqlVariableDefinitionList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlVariableDefinitionList;
+
+impl FormatRule<GraphqlVariableDefinitionList> for FormatGraphqlVariableDefinitionList {
+    type Context = GraphqlFormatContext;
+    fn fmt(
+        &self,
+        node: &GraphqlVariableDefinitionList,
+        f: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        f.join_with(&FormatInlineSeparator)
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
@@ -0,0 +1,10 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod any;
+pub(crate) mod auxiliary;
+pub(crate) mod bogus;
+pub(crate) mod definitions;
+pub(crate) mod extensions;
+pub(crate) mod lists;
+pub(crate) mod types;
+pub(crate) mod value;
@@ -0,0 +1,5 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod list_type;
+pub(crate) mod named_type;
+pub(crate) mod non_null_type;
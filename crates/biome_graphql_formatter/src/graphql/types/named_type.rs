@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_graphql_syntax::{GraphqlNamedType, GraphqlNamedTypeFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlNamedType;
+
+impl FormatNodeRule<GraphqlNamedType> for FormatGraphqlNamedType {
+    fn fmt_fields(&self, node: &GraphqlNamedType, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlNamedTypeFields { name } = node.as_fields();
+
+        name.format().fmt(f)
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_graphql_syntax::{GraphqlBooleanValue, GraphqlBooleanValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlBooleanValue;
+
+impl FormatNodeRule<GraphqlBooleanValue> for FormatGraphqlBooleanValue {
+    fn fmt_fields(&self, node: &GraphqlBooleanValue, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlBooleanValueFields { value_token } = node.as_fields();
+
+        value_token.format().fmt(f)
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_graphql_syntax::{GraphqlEnumValue, GraphqlEnumValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlEnumValue;
+
+impl FormatNodeRule<GraphqlEnumValue> for FormatGraphqlEnumValue {
+    fn fmt_fields(&self, node: &GraphqlEnumValue, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlEnumValueFields { graphql_name } = node.as_fields();
+
+        graphql_name.format().fmt(f)
+    }
+}
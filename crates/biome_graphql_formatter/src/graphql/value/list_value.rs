@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::{GraphqlListValue, GraphqlListValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlListValue;
+
+impl FormatNodeRule<GraphqlListValue> for FormatGraphqlListValue {
+    fn fmt_fields(&self, node: &GraphqlListValue, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlListValueFields {
+            l_brack_token,
+            elements,
+            r_brack_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_brack_token.format(),
+                group(&soft_block_indent(&format_args![
+                    elements.format(),
+                    format_dangling_comments(node.syntax())
+                ])),
+                r_brack_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlListValue,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod boolean_value;
+pub(crate) mod default_value;
+pub(crate) mod enum_value;
+pub(crate) mod float_value;
+pub(crate) mod int_value;
+pub(crate) mod list_value;
+pub(crate) mod null_value;
+pub(crate) mod object_value;
+pub(crate) mod string_value;
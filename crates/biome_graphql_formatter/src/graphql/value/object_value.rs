@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_graphql_syntax::{GraphqlObjectValue, GraphqlObjectValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlObjectValue;
+
+impl FormatNodeRule<GraphqlObjectValue> for FormatGraphqlObjectValue {
+    fn fmt_fields(&self, node: &GraphqlObjectValue, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlObjectValueFields {
+            l_curly_token,
+            members,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_curly_token.format(),
+                group(&soft_space_or_block_indent(&format_args![
+                    members.format(),
+                    format_dangling_comments(node.syntax())
+                ])),
+                r_curly_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &GraphqlObjectValue,
+        _: &mut GraphqlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
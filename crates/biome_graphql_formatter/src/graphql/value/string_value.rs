@@ -0,0 +1,25 @@
+use crate::context::DescriptionQuoteStyle;
+use crate::format_string::format_string;
+use crate::prelude::*;
+use biome_graphql_syntax::GraphqlDescription;
+use biome_graphql_syntax::{GraphqlStringValue, GraphqlStringValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatGraphqlStringValue;
+
+impl FormatNodeRule<GraphqlStringValue> for FormatGraphqlStringValue {
+    fn fmt_fields(&self, node: &GraphqlStringValue, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let GraphqlStringValueFields {
+            graphql_string_literal_token,
+        } = node.as_fields();
+
+        // The quote style only applies to the descriptions
+        let quote_style = if node.parent::<GraphqlDescription>().is_some() {
+            f.options().description_quote_style()
+        } else {
+            DescriptionQuoteStyle::Preserve
+        };
+
+        format_string(&graphql_string_literal_token?, quote_style).fmt(f)
+    }
+}
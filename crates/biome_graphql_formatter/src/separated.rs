@@ -0,0 +1,74 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::GraphqlLanguage;
+use biome_rowan::AstSeparatedList;
+
+/// Formats a list separated by `&` or `|`, with a space around each separator.
+///
+/// When `break_before_separators` is set, the entries are printed on their own lines, each after its separator,
+/// if the enclosing group doesn't fit on a single line.
+pub(crate) struct FormatGraphqlSeparatedList<'a, List> {
+    list: &'a List,
+    separator: &'static str,
+    break_before_separators: bool,
+}
+
+impl<'a, List> FormatGraphqlSeparatedList<'a, List> {
+    pub(crate) fn new(list: &'a List, separator: &'static str) -> Self {
+        Self {
+            list,
+            separator,
+            break_before_separators: false,
+        }
+    }
+
+    pub(crate) fn with_break_before_separators(mut self) -> Self {
+        self.break_before_separators = true;
+        self
+    }
+}
+
+impl<List> Format<GraphqlFormatContext> for FormatGraphqlSeparatedList<'_, List>
+where
+    List: AstSeparatedList<Language = GraphqlLanguage>,
+    List::Node: AsFormat<GraphqlFormatContext>,
+{
+    fn fmt(&self, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let mut elements = self.list.elements().peekable();
+
+        // The parser reads an optional leading separator as the separator of a missing first entry,
+        // it's removed since the parent node prints the leading separator when needed
+        if let Some(element) = elements.next_if(|element| element.node().is_err()) {
+            if let Some(separator) = element.trailing_separator()? {
+                write!(f, [format_removed(separator)])?;
+            }
+        }
+
+        while let Some(element) = elements.next() {
+            write!(f, [element.node().format()])?;
+
+            if elements.peek().is_none() {
+                // The trailing separators aren't allowed
+                if let Some(separator) = element.trailing_separator()? {
+                    write!(f, [format_removed(separator)])?;
+                }
+                break;
+            }
+
+            if self.break_before_separators {
+                write!(f, [soft_line_break_or_space()])?;
+            } else {
+                write!(f, [space()])?;
+            }
+
+            match element.trailing_separator()? {
+                Some(separator) => write!(f, [separator.format()])?,
+                None => write!(f, [text(self.separator)])?,
+            }
+
+            write!(f, [space()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use crate::context::ArgumentsIndent;
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_graphql_syntax::{GraphqlSyntaxNode, GraphqlSyntaxToken};
+use biome_rowan::SyntaxResult;
+
+/// Formats the parenthesized arguments, argument definitions or variable definitions of a node.
+///
+/// They're printed on a single line, separated by commas, when they fit. Otherwise they're printed
+/// one per line, indented or aligned after the opening parenthesis per the `argumentsIndent` option.
+pub(crate) struct FormatGraphqlParenthesizedList<'a, List> {
+    pub(crate) node: &'a GraphqlSyntaxNode,
+    pub(crate) l_paren_token: SyntaxResult<GraphqlSyntaxToken>,
+    pub(crate) list: &'a List,
+    pub(crate) r_paren_token: SyntaxResult<GraphqlSyntaxToken>,
+}
+
+impl<List> Format<GraphqlFormatContext> for FormatGraphqlParenthesizedList<'_, List>
+where
+    List: AsFormat<GraphqlFormatContext>,
+{
+    fn fmt(&self, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        let content =
+            format_with(|f| write!(f, [self.list.format(), format_dangling_comments(self.node)]));
+
+        match f.options().arguments_indent() {
+            ArgumentsIndent::Indent => write!(
+                f,
+                [
+                    self.l_paren_token.format(),
+                    group(&soft_block_indent(&content)),
+                    self.r_paren_token.format()
+                ]
+            ),
+            ArgumentsIndent::Align => write!(
+                f,
+                [
+                    self.l_paren_token.format(),
+                    group(&align(1, &content)),
+                    self.r_paren_token.format()
+                ]
+            ),
+        }
+    }
+}
+
+/// Separates the entries of a list that's printed on a single line when it fits, like arguments
+/// or the members of an object value.
+///
+/// The commas are insignificant, they're only printed between the entries of a single line.
+pub(crate) struct FormatInlineSeparator;
+
+impl Format<GraphqlFormatContext> for FormatInlineSeparator {
+    fn fmt(&self, f: &mut GraphqlFormatter) -> FormatResult<()> {
+        write!(
+            f,
+            [
+                if_group_fits_on_line(&text(",")),
+                soft_line_break_or_space()
+            ]
+        )
+    }
+}
@@ -0,0 +1,46 @@
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_graphql_formatter::context::GraphqlFormatOptions;
+use biome_graphql_formatter::format_node;
+use biome_graphql_parser::parse_graphql;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+query   Hero($episode: Episode,   $first: Int = 3) { # comment
+hero(episode: $episode) { name, friends(first: $first) { name } }
+}
+"#;
+    let parse = parse_graphql(src);
+    let options = GraphqlFormatOptions::default();
+    let result = format_node(options.clone(), &parse.syntax())
+        .unwrap()
+        .print()
+        .unwrap();
+
+    let root = &parse.syntax();
+    let language = language::GraphqlTestFormatLanguage;
+
+    let check_reformat =
+        CheckReformat::new(root, result.as_code(), "quick_test", &language, options);
+    check_reformat.check_reformat();
+
+    assert_eq!(
+        result.as_code(),
+        r#"query Hero($episode: Episode, $first: Int = 3) {
+	# comment
+	hero(episode: $episode) {
+		name
+		friends(first: $first) {
+			name
+		}
+	}
+}
+"#
+    );
+}
@@ -0,0 +1,61 @@
+use biome_rowan::FileSourceError;
+use std::{ffi::OsStr, path::Path};
+
+#[cfg_attr(feature = "serde", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct GraphqlFileSource {
+    // ?? Options
+}
+
+impl GraphqlFileSource {
+    pub fn graphql() -> Self {
+        Self {
+            // ?? Options
+        }
+    }
+
+    /// Try to return the GraphQL file source corresponding to this file extension
+    pub fn try_from_extension(extension: &str) -> Result<Self, FileSourceError> {
+        match extension {
+            // https://github.com/github-linguist/linguist/blob/4ac734c15a96f9e16fd12330d0cb8de82274f700/lib/linguist/languages.yml#L2307-L2314
+            "graphql" | "gql" | "graphqls" => Ok(Self::graphql()),
+            _ => Err(FileSourceError::UnknownExtension(
+                Default::default(),
+                extension.into(),
+            )),
+        }
+    }
+
+    /// Try to return the GraphQL file source corresponding to this language ID
+    ///
+    /// See the [LSP spec] and [VS Code spec] for a list of language identifiers
+    ///
+    /// The language ID for code snippets is registered by [VS Code built-in extensions]
+    ///
+    /// [LSP spec]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentItem
+    /// [VS Code spec]: https://code.visualstudio.com/docs/languages/identifiers
+    pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
+        match language_id {
+            "graphql" => Ok(Self::graphql()),
+            _ => Err(FileSourceError::UnknownLanguageId(language_id.into())),
+        }
+    }
+}
+
+impl TryFrom<&Path> for GraphqlFileSource {
+    type Error = FileSourceError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        // We assume the file extensions are case-insensitive
+        // and we use the lowercase form of them for pattern matching
+        let extension = &path
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(str::to_lowercase)
+            .ok_or_else(|| FileSourceError::MissingFileExtension(path.into()))?;
+
+        Self::try_from_extension(extension)
+    }
+}
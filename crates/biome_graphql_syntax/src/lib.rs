@@ -2,12 +2,14 @@
 //! Both rome_grit_lexer and biome_grit_parser rely on these definitions, therefore
 //! they are wrapped in this crate to prevent cyclic dependencies
 
+mod file_source;
 #[macro_use]
 mod generated;
 mod syntax_node;
 
 use biome_rowan::{AstNode, RawSyntaxKind, SyntaxKind};
 pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use file_source::GraphqlFileSource;
 pub use generated::*;
 pub use syntax_node::*;
 
@@ -108,8 +110,9 @@ impl TryFrom<GraphqlSyntaxKind> for TriviaPieceKind {
             match value {
                 GraphqlSyntaxKind::NEWLINE => Ok(TriviaPieceKind::Newline),
                 GraphqlSyntaxKind::WHITESPACE => Ok(TriviaPieceKind::Whitespace),
+                // The commas are insignificant, like the white spaces and the line terminators
                 // https://spec.graphql.org/October2021/#sec-Insignificant-Commas
-                GraphqlSyntaxKind::COMMA => Ok(TriviaPieceKind::Skipped),
+                GraphqlSyntaxKind::COMMA => Ok(TriviaPieceKind::Whitespace),
                 GraphqlSyntaxKind::COMMENT => Ok(TriviaPieceKind::SingleLineComment),
                 _ => unreachable!("Not Trivia"),
             }
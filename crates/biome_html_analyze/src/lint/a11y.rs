@@ -0,0 +1,18 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub mod use_alt_text;
+pub mod use_valid_aria_props;
+pub mod use_valid_aria_values;
+
+declare_group! {
+    pub A11y {
+        name : "a11y" ,
+        rules : [
+            self :: use_alt_text :: UseAltText ,
+            self :: use_valid_aria_props :: UseValidAriaProps ,
+            self :: use_valid_aria_values :: UseValidAriaValues ,
+        ]
+     }
+}
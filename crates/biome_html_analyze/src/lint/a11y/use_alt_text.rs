@@ -0,0 +1,147 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::{fmt::Display, fmt::Formatter, markup};
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::{HtmlElement, TextRange};
+use biome_rowan::{AstNode, AstNodeList};
+
+declare_rule! {
+    /// Enforce that all elements that require alternative text have meaningful information to relay back to the end user.
+    ///
+    /// This is a critical component of accessibility for screen reader users in order for them to understand the content's purpose on the page.
+    /// By default, this rule checks for alternative text on the following elements: `<img>`, `<area>`, `<input type="image">`, and `<object>`.
+    ///
+    /// An `<object>` element can also provide its alternative text through its content.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <img src="image.png">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <input type="image" src="image.png">
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <img src="image.png" alt="image alt">
+    /// ```
+    ///
+    /// ```html
+    /// <input type="image" src="image.png" aria-label="alt text">
+    /// ```
+    ///
+    /// ```html
+    /// <object data="movie.mp4">A movie about the ocean</object>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.1.1](https://www.w3.org/WAI/WCAG21/Understanding/non-text-content.html)
+    ///
+    pub UseAltText {
+        version: "next",
+        name: "useAltText",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("alt-text")],
+        recommended: true,
+    }
+}
+
+pub enum ValidatedElement {
+    Object,
+    Img,
+    Area,
+    Input,
+}
+
+impl Display for ValidatedElement {
+    fn fmt(&self, fmt: &mut Formatter) -> std::io::Result<()> {
+        match self {
+            ValidatedElement::Object => fmt.write_markup(markup!(<Emphasis>"title"</Emphasis>)),
+            _ => fmt.write_markup(markup!(<Emphasis>"alt"</Emphasis>)),
+        }
+    }
+}
+
+impl Rule for UseAltText {
+    type Query = Ast<AnyHtmlTag>;
+    type State = (ValidatedElement, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let element = ctx.query();
+
+        let has_alt = has_valid_alt_text(element);
+        let has_aria_label = has_valid_label(element, "aria-label");
+        let has_aria_labelledby = has_valid_label(element, "aria-labelledby");
+        let name = element.name_value_token()?;
+        let validated_element = match name.text_trimmed().to_ascii_lowercase().as_str() {
+            "object" => {
+                let has_title = has_valid_label(element, "title");
+                if has_title || has_aria_label || has_aria_labelledby || has_content(element) {
+                    return None;
+                }
+                ValidatedElement::Object
+            }
+            "img" if !has_alt && !has_aria_label && !has_aria_labelledby => ValidatedElement::Img,
+            "area" if !has_alt && !has_aria_label && !has_aria_labelledby => ValidatedElement::Area,
+            "input"
+                if has_type_image_attribute(element)
+                    && !has_alt
+                    && !has_aria_label
+                    && !has_aria_labelledby =>
+            {
+                ValidatedElement::Input
+            }
+            _ => return None,
+        };
+
+        Some((validated_element, element.syntax().text_trimmed_range()))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (validate_element, range) = state;
+        let message = markup!(
+            "Provide a text alternative through the "{{validate_element}}", "<Emphasis>"aria-label"</Emphasis>" or "<Emphasis>"aria-labelledby"</Emphasis>" attribute"
+        ).to_owned();
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, message).note(markup! {
+                "Meaningful alternative text on elements helps users relying on screen readers to understand content's purpose within a page."
+            }),
+        )
+    }
+}
+
+fn has_type_image_attribute(element: &AnyHtmlTag) -> bool {
+    element
+        .find_attribute_by_name("type")
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("image"))
+}
+
+/// An empty `alt` attribute, such as `alt=""` or `alt`, is valid: it marks the image as decorative
+fn has_valid_alt_text(element: &AnyHtmlTag) -> bool {
+    element.find_attribute_by_name("alt").is_some()
+}
+
+fn has_valid_label(element: &AnyHtmlTag, name_to_lookup: &str) -> bool {
+    element
+        .find_attribute_by_name(name_to_lookup)
+        .and_then(|attribute| attribute.value())
+        .is_some_and(|value| !value.trim().is_empty())
+}
+
+/// Returns `true` when the `<object>` element has children, they're rendered when the object can't be
+fn has_content(element: &AnyHtmlTag) -> bool {
+    let AnyHtmlTag::HtmlOpeningElement(opening_element) = element else {
+        return false;
+    };
+    opening_element
+        .parent::<HtmlElement>()
+        .is_some_and(|element| !element.children().is_empty())
+}
@@ -0,0 +1,106 @@
+use crate::HtmlRuleAction;
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic, RuleSource};
+use biome_aria::AriaProperties;
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::HtmlAttribute;
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+
+declare_rule! {
+    /// Ensures that ARIA properties `aria-*` are all valid.
+    ///
+    /// The names of the attributes aren't case-sensitive in HTML, so `aria-Label` is reported as well.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <input class="" aria-labell="">
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <div aria-lorem="foobar"></div>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <input class="" aria-label="Name">
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    pub UseValidAriaProps {
+        version: "next",
+        name: "useValidAriaProps",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("aria-props")],
+        recommended: true,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseValidAriaProps {
+    type Query = Ast<AnyHtmlTag>;
+    type State = HtmlAttribute;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        node.attributes()
+            .iter()
+            .filter_map(|attribute| {
+                let attribute = attribute.as_html_attribute()?;
+                let attribute_name = attribute
+                    .name_value_token()?
+                    .text_trimmed()
+                    .to_ascii_lowercase();
+                if attribute_name.starts_with("aria-")
+                    && AriaProperties.get_property(&attribute_name).is_none()
+                {
+                    Some(attribute.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let attribute_name = attribute.name_value_token()?;
+        Some(RuleDiagnostic::new(
+            rule_category!(),
+            node.range(),
+            markup! {
+                "The element contains invalid ARIA attribute(s)"
+            },
+        ).detail(
+            attribute.range(),
+            markup! {
+                    <Emphasis>{attribute_name.text_trimmed()}</Emphasis>" is not a valid ARIA attribute."
+                },
+        ))
+    }
+
+    fn action(ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<HtmlRuleAction> {
+        let mut mutation = ctx.root().begin();
+
+        mutation.remove_node(attribute.clone());
+
+        Some(HtmlRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+
+                markup! { "Remove the invalid "<Emphasis>"aria-*"</Emphasis>" attribute.
+                Check the list of all "<Hyperlink href="https://developer.mozilla.org/en-US/docs/web/Accessibility/ARIA/Attributes#aria_attribute_types">"valid"</Hyperlink>" aria-* attributes." }
+                    .to_owned(),
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,160 @@
+use biome_analyze::context::RuleContext;
+use biome_analyze::{declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_aria::{AriaProperties, AriaPropertyTypeEnum};
+use biome_console::markup;
+use biome_html_syntax::{HtmlAttribute, HtmlSyntaxToken, TextRange};
+use biome_rowan::AstNode;
+use std::slice::Iter;
+
+declare_rule! {
+    /// Enforce that ARIA state and property values are valid.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <span role="checkbox" aria-checked="test">some text</span>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <span aria-labelledby="">some text</span>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <span aria-valuemax="hey">some text</span>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <span aria-orientation="hey">some text</span>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <span role="checkbox" aria-checked="true">some text</span>
+    /// <span aria-labelledby="fooId barId">some text</span>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    /// ### Resources
+    ///
+    /// - [ARIA Spec, States and Properties](https://www.w3.org/TR/wai-aria/#states_and_properties)
+    /// - [Chrome Audit Rules, AX_ARIA_04](https://github.com/GoogleChrome/accessibility-developer-tools/wiki/Audit-Rules#ax_aria_04)
+    pub UseValidAriaValues {
+        version: "next",
+        name: "useValidAriaValues",
+        language: "html",
+        sources: &[RuleSource::EslintJsxA11y("aria-proptypes")],
+        recommended: true,
+    }
+}
+
+pub struct UseValidAriaValuesState {
+    attribute_value_range: TextRange,
+    allowed_values: Iter<'static, &'static str>,
+    attribute_name: HtmlSyntaxToken,
+    property_type: AriaPropertyTypeEnum,
+}
+
+impl Rule for UseValidAriaValues {
+    type Query = Ast<HtmlAttribute>;
+    type State = UseValidAriaValuesState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+
+        let attribute_name = node.name_value_token()?;
+        let aria_property =
+            AriaProperties.get_property(&attribute_name.text_trimmed().to_ascii_lowercase())?;
+
+        let attribute_value = node.value()?;
+        if aria_property.contains_correct_value(&attribute_value) {
+            return None;
+        }
+
+        Some(UseValidAriaValuesState {
+            attribute_value_range: node.range(),
+            allowed_values: aria_property.values(),
+            attribute_name,
+            property_type: aria_property.property_type(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let attribute_name = state.attribute_name.text_trimmed();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.attribute_value_range,
+            markup! {
+                "The value of the ARIA attribute "<Emphasis>{attribute_name}</Emphasis>" is not correct."
+            },
+        );
+
+        let diagnostic = match state.property_type {
+            AriaPropertyTypeEnum::Boolean => {
+                diagnostic.footer_list(
+                    markup!{
+                        "The only supported values for the "<Emphasis>{attribute_name}</Emphasis>" property is one of the following:"
+                    },
+                    &["true", "false"]
+                )
+            }
+            AriaPropertyTypeEnum::Integer => {
+                diagnostic.note(
+                    markup!{
+                        "The only value supported is a number without fractional components."
+                    }
+                )
+            }
+            AriaPropertyTypeEnum::Id |
+            AriaPropertyTypeEnum::Idlist |
+            AriaPropertyTypeEnum::String => {
+                diagnostic.note(
+                    markup!{
+                        "The only supported value is text."
+                    }
+                )
+            }
+
+            AriaPropertyTypeEnum::Number => {
+                diagnostic.note(
+                    markup!{
+                        "The only supported value is number."
+                    }
+                )
+            }
+            AriaPropertyTypeEnum::Token => {
+                diagnostic.footer_list(
+                    markup!{
+                    "The only supported value for the "<Emphasis>{attribute_name}</Emphasis>" property is one of the following:"
+                },
+                    state.allowed_values.as_slice()
+                )
+            }
+            AriaPropertyTypeEnum::Tokenlist => {
+                diagnostic.footer_list(
+                    markup!{
+                    "The values supported for "<Emphasis>{attribute_name}</Emphasis>" property are one or more of the following:"
+                },
+                    state.allowed_values.as_slice()
+                )
+            }
+            AriaPropertyTypeEnum::Tristate => {
+                diagnostic.footer_list(
+                    markup!{
+                        "The only supported value for the "<Emphasis>{attribute_name}</Emphasis>" property one of the following:"
+                    },
+                    &["true", "false", "mixed"]
+                )
+            }
+        };
+
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,16 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub mod no_unused_refs;
+pub mod use_valid_vue_directives;
+
+declare_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_unused_refs :: NoUnusedRefs ,
+            self :: use_valid_vue_directives :: UseValidVueDirectives ,
+        ]
+     }
+}
@@ -0,0 +1,171 @@
+use crate::vue::{block_content, blocks};
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_html_syntax::{HtmlAttribute, HtmlRoot};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow the `ref` attributes of a Vue template that are never used.
+    ///
+    /// A `ref` attribute gives the script access to an element or a component of the template.
+    /// It's used when the element is accessed with `$refs`, such as `this.$refs.input`,
+    /// or when a script has a variable or a `useTemplateRef` call with the name of the ref,
+    /// such as `const input = ref(null)` in `<script setup>`.
+    /// A `ref` attribute that is never used only adds a reference that Vue keeps updated for nothing.
+    ///
+    /// The rule checks the static `ref` attributes of the `<template>` block of a component.
+    /// It doesn't report anything when `$refs` is accessed with a computed name, such as `$refs[name]`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <template>
+    ///     <input ref="input">
+    /// </template>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <template>
+    ///     <input ref="input">
+    ///     <button @click="$refs.input.focus()">Focus</button>
+    /// </template>
+    /// ```
+    ///
+    /// ```html
+    /// <script setup>
+    /// import { ref } from "vue";
+    ///
+    /// const input = ref(null);
+    /// </script>
+    ///
+    /// <template>
+    ///     <input ref="input">
+    /// </template>
+    /// ```
+    ///
+    pub NoUnusedRefs {
+        version: "next",
+        name: "noUnusedRefs",
+        language: "html",
+        sources: &[RuleSource::EslintVue("no-unused-refs")],
+        source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Vue],
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedRefs {
+    type Query = Ast<HtmlRoot>;
+    type State = HtmlAttribute;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let root = ctx.query();
+        let refs: Vec<_> = blocks(root, "template")
+            .flat_map(|template| template.syntax().descendants())
+            .filter_map(HtmlAttribute::cast)
+            .filter(|attribute| {
+                attribute
+                    .name_value_token()
+                    .is_some_and(|name| name.text_trimmed() == "ref")
+            })
+            .collect();
+        if refs.is_empty() {
+            return Vec::new();
+        }
+
+        let text = root.syntax().text_trimmed().to_string();
+        let Some(accessed_refs) = accessed_refs(&text) else {
+            return Vec::new();
+        };
+        let scripts: Vec<_> = blocks(root, "script")
+            .filter_map(|script| block_content(&script))
+            .collect();
+
+        refs.into_iter()
+            .filter(|attribute| {
+                attribute.value().is_some_and(|name| {
+                    let name = name.text();
+                    !name.is_empty()
+                        && !accessed_refs.contains(&name)
+                        && !scripts
+                            .iter()
+                            .any(|script| contains_identifier(script, name))
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, attribute: &Self::State) -> Option<RuleDiagnostic> {
+        let name = attribute.value()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                attribute.syntax().text_trimmed_range(),
+                markup! {
+                    "The ref "<Emphasis>{name.text()}</Emphasis>" is never used."
+                },
+            )
+            .note(markup! {
+                "Access the element with "<Emphasis>"$refs"</Emphasis>" or with a variable of the same name in the script, or remove the "<Emphasis>"ref"</Emphasis>" attribute."
+            }),
+        )
+    }
+}
+
+/// Returns the names of the refs accessed with `$refs`, such as `input` in `this.$refs.input` or `$refs["input"]`.
+///
+/// It returns `None` when `$refs` is used in another way, such as `$refs[name]`, because any ref could be used.
+fn accessed_refs(text: &str) -> Option<Vec<&str>> {
+    let mut names = Vec::new();
+    for (index, _) in text.match_indices("$refs") {
+        let is_preceded_by_identifier = text[..index].ends_with(is_identifier_char);
+        let rest = &text[index + "$refs".len()..];
+        if is_preceded_by_identifier || rest.starts_with(is_identifier_char) {
+            continue;
+        }
+
+        let rest = rest.strip_prefix("?.").unwrap_or(rest);
+        if let Some(property) = rest.strip_prefix('.') {
+            let end = property
+                .find(|char| !is_identifier_char(char))
+                .unwrap_or(property.len());
+            names.push(&property[..end]);
+        } else {
+            let name = rest
+                .strip_prefix('[')
+                .map(str::trim_start)
+                .and_then(|rest| {
+                    let quote = rest
+                        .chars()
+                        .next()
+                        .filter(|char| matches!(char, '"' | '\''))?;
+                    let rest = &rest[1..];
+                    let (name, rest) = rest.split_at(rest.find(quote)?);
+                    rest[1..].trim_start().starts_with(']').then_some(name)
+                })?;
+            names.push(name);
+        }
+    }
+    Some(names)
+}
+
+/// Returns `true` if `text` contains `name`, and the characters around it can't be a part of an identifier
+fn contains_identifier(text: &str, name: &str) -> bool {
+    text.match_indices(name).any(|(index, _)| {
+        !text[..index].ends_with(is_identifier_char)
+            && !text[index + name.len()..].starts_with(is_identifier_char)
+    })
+}
+
+fn is_identifier_char(char: char) -> bool {
+    char.is_alphanumeric() || matches!(char, '_' | '$')
+}
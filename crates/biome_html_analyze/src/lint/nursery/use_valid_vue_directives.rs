@@ -0,0 +1,308 @@
+use crate::vue::{is_component_name, is_in_template_block, VueDirective};
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_html_syntax::element_ext::AnyHtmlTag;
+use biome_html_syntax::{AnyHtmlElement, HtmlAttribute, HtmlElement};
+use biome_rowan::{AstNode, AstNodeList};
+
+declare_rule! {
+    /// Enforce valid usage of the built-in directives of a Vue template.
+    ///
+    /// The rule reports the directives that Vue can't compile, or that don't have any effect:
+    ///
+    /// - `v-if`, `v-else-if`, `v-show`, `v-for`, `v-model`, `v-text`, `v-html` and `v-memo` require a value,
+    ///   and `v-on` requires a value when it doesn't have modifiers;
+    /// - `v-else`, `v-once`, `v-pre` and `v-cloak` don't accept a value;
+    /// - the directives that don't have arguments, such as `v-if`, don't accept an argument or modifiers;
+    /// - `v-else` and `v-else-if` must follow an element with `v-if` or `v-else-if`,
+    ///   and can't be used on the same element as `v-if`;
+    /// - the value of `v-for` must have the form `item in items` or `item of items`;
+    /// - `v-model` can only be used on `<input>`, `<select>`, `<textarea>` and the components;
+    /// - `v-slot` can only be used on `<template>` and the components.
+    ///
+    /// The rule checks the `<template>` block of a component.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```html,expect_diagnostic
+    /// <template>
+    ///     <div v-if>Hidden</div>
+    /// </template>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <template>
+    ///     <div v-else>Orphan</div>
+    /// </template>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <template>
+    ///     <li v-for="items">{{ item }}</li>
+    /// </template>
+    /// ```
+    ///
+    /// ```html,expect_diagnostic
+    /// <template>
+    ///     <div v-model="text"></div>
+    /// </template>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```html
+    /// <template>
+    ///     <div v-if="visible">Shown</div>
+    ///     <div v-else>Hidden</div>
+    ///     <li v-for="item in items" :key="item.id">{{ item.name }}</li>
+    ///     <input v-model.trim="text">
+    ///     <MyList #item="{ item }">{{ item }}</MyList>
+    /// </template>
+    /// ```
+    ///
+    pub UseValidVueDirectives {
+        version: "next",
+        name: "useValidVueDirectives",
+        language: "html",
+        sources: &[
+            RuleSource::EslintVue("valid-v-cloak"),
+            RuleSource::EslintVue("valid-v-else"),
+            RuleSource::EslintVue("valid-v-else-if"),
+            RuleSource::EslintVue("valid-v-for"),
+            RuleSource::EslintVue("valid-v-html"),
+            RuleSource::EslintVue("valid-v-if"),
+            RuleSource::EslintVue("valid-v-memo"),
+            RuleSource::EslintVue("valid-v-model"),
+            RuleSource::EslintVue("valid-v-on"),
+            RuleSource::EslintVue("valid-v-once"),
+            RuleSource::EslintVue("valid-v-pre"),
+            RuleSource::EslintVue("valid-v-show"),
+            RuleSource::EslintVue("valid-v-slot"),
+            RuleSource::EslintVue("valid-v-text"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Vue],
+        recommended: false,
+    }
+}
+
+/// The directives that require a value
+const VALUE_REQUIRED: &[&str] = &[
+    "else-if", "for", "html", "if", "memo", "model", "show", "text",
+];
+
+/// The directives that don't accept a value
+const VALUE_FORBIDDEN: &[&str] = &["cloak", "else", "once", "pre"];
+
+/// The directives that don't accept an argument or modifiers
+const ARGUMENT_FORBIDDEN: &[&str] = &[
+    "cloak", "else", "else-if", "for", "html", "if", "memo", "once", "pre", "show", "text",
+];
+
+pub enum DirectiveProblem {
+    MissingValue,
+    UnexpectedValue,
+    UnexpectedArgument,
+    ConflictingConditions,
+    ElseWithoutIf,
+    InvalidFor,
+    InvalidModelElement,
+    InvalidSlotElement,
+}
+
+pub struct InvalidDirective {
+    /// The name of the directive without the `v-` prefix, such as `if`
+    name: String,
+    problem: DirectiveProblem,
+}
+
+impl Rule for UseValidVueDirectives {
+    type Query = Ast<HtmlAttribute>;
+    type State = InvalidDirective;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let attribute = ctx.query();
+        let attribute_name = attribute.name_value_token()?;
+        let directive = VueDirective::parse(attribute_name.text_trimmed())?;
+        if !is_in_template_block(attribute.syntax()) {
+            return None;
+        }
+        let tag = attribute.syntax().ancestors().find_map(AnyHtmlTag::cast)?;
+        let value = attribute.value();
+        let has_value = value.as_ref().is_some_and(|value| !value.trim().is_empty());
+        let name = directive.name;
+
+        let problem = if ARGUMENT_FORBIDDEN.contains(&name)
+            && (directive.argument.is_some() || !directive.modifiers.is_empty())
+        {
+            DirectiveProblem::UnexpectedArgument
+        } else if VALUE_FORBIDDEN.contains(&name) && attribute.initializer().is_some() {
+            DirectiveProblem::UnexpectedValue
+        } else if !has_value
+            && (VALUE_REQUIRED.contains(&name) || (name == "on" && directive.modifiers.is_empty()))
+        {
+            DirectiveProblem::MissingValue
+        } else if matches!(name, "else" | "else-if") && has_directive(&tag, "if") {
+            DirectiveProblem::ConflictingConditions
+        } else if matches!(name, "else" | "else-if") && !follows_condition(&tag) {
+            DirectiveProblem::ElseWithoutIf
+        } else if name == "for" && !value.is_some_and(|value| is_valid_for_expression(&value)) {
+            DirectiveProblem::InvalidFor
+        } else if name == "model"
+            && !tag_name(&tag).is_some_and(|tag_name| {
+                matches!(tag_name.as_str(), "input" | "select" | "textarea")
+                    || is_component_name(&tag_name)
+            })
+        {
+            DirectiveProblem::InvalidModelElement
+        } else if name == "slot"
+            && !tag_name(&tag)
+                .is_some_and(|tag_name| tag_name == "template" || is_component_name(&tag_name))
+        {
+            DirectiveProblem::InvalidSlotElement
+        } else {
+            return None;
+        };
+
+        Some(InvalidDirective {
+            name: name.to_string(),
+            problem,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = format!("v-{}", state.name);
+        let message = match state.problem {
+            DirectiveProblem::MissingValue => markup! {
+                "The directive "<Emphasis>{name}</Emphasis>" requires a value."
+            }
+            .to_owned(),
+            DirectiveProblem::UnexpectedValue => markup! {
+                "The directive "<Emphasis>{name}</Emphasis>" doesn't accept a value."
+            }
+            .to_owned(),
+            DirectiveProblem::UnexpectedArgument => markup! {
+                "The directive "<Emphasis>{name}</Emphasis>" doesn't accept an argument or modifiers."
+            }
+            .to_owned(),
+            DirectiveProblem::ConflictingConditions => markup! {
+                "The directive "<Emphasis>{name}</Emphasis>" can't be used on the same element as "<Emphasis>"v-if"</Emphasis>"."
+            }
+            .to_owned(),
+            DirectiveProblem::ElseWithoutIf => markup! {
+                "The directive "<Emphasis>{name}</Emphasis>" must follow an element with "<Emphasis>"v-if"</Emphasis>" or "<Emphasis>"v-else-if"</Emphasis>"."
+            }
+            .to_owned(),
+            DirectiveProblem::InvalidFor => markup! {
+                "The value of "<Emphasis>"v-for"</Emphasis>" must have the form "<Emphasis>"item in items"</Emphasis>"."
+            }
+            .to_owned(),
+            DirectiveProblem::InvalidModelElement => markup! {
+                "The directive "<Emphasis>"v-model"</Emphasis>" can only be used on "<Emphasis>"<input>"</Emphasis>", "<Emphasis>"<select>"</Emphasis>", "<Emphasis>"<textarea>"</Emphasis>" and the components."
+            }
+            .to_owned(),
+            DirectiveProblem::InvalidSlotElement => markup! {
+                "The directive "<Emphasis>"v-slot"</Emphasis>" can only be used on "<Emphasis>"<template>"</Emphasis>" and the components."
+            }
+            .to_owned(),
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().syntax().text_trimmed_range(),
+                message,
+            )
+            .note(markup! {
+                "Vue can't compile this directive, or ignores it."
+            }),
+        )
+    }
+}
+
+fn tag_name(tag: &AnyHtmlTag) -> Option<String> {
+    Some(tag.name_value_token()?.text_trimmed().to_string())
+}
+
+/// Returns `true` if `tag` has a directive with the given name, such as `if` for `v-if`
+fn has_directive(tag: &AnyHtmlTag, name: &str) -> bool {
+    tag.attributes().iter().any(|attribute| {
+        attribute
+            .as_html_attribute()
+            .and_then(|attribute| attribute.name_value_token())
+            .is_some_and(|attribute_name| {
+                VueDirective::parse(attribute_name.text_trimmed())
+                    .is_some_and(|directive| directive.name == name)
+            })
+    })
+}
+
+/// Returns `true` if the element of `tag` follows an element with `v-if` or `v-else-if`,
+/// only separated by whitespaces and comments
+fn follows_condition(tag: &AnyHtmlTag) -> bool {
+    let element = match tag {
+        AnyHtmlTag::HtmlOpeningElement(opening_element) => opening_element
+            .parent::<HtmlElement>()
+            .map(AnyHtmlElement::from),
+        AnyHtmlTag::HtmlSelfClosingElement(element) => Some(element.clone().into()),
+    };
+    let Some(previous) = element
+        .and_then(|element| element.syntax().prev_sibling())
+        .and_then(AnyHtmlElement::cast)
+    else {
+        return false;
+    };
+    let previous_tag = match previous {
+        AnyHtmlElement::HtmlElement(element) => {
+            element.opening_element().ok().map(AnyHtmlTag::from)
+        }
+        AnyHtmlElement::HtmlSelfClosingElement(element) => Some(element.into()),
+        _ => None,
+    };
+    previous_tag.is_some_and(|previous_tag| {
+        has_directive(&previous_tag, "if") || has_directive(&previous_tag, "else-if")
+    })
+}
+
+/// Returns `true` if `value` has the form `alias in expression` or `alias of expression`,
+/// where the alias is an identifier, a destructuring pattern, or a list of aliases between parentheses.
+fn is_valid_for_expression(value: &str) -> bool {
+    let value = value.trim_start();
+    let alias_end = match value.chars().next() {
+        Some(open @ ('(' | '[' | '{')) => {
+            let close = match open {
+                '(' => ')',
+                '[' => ']',
+                _ => '}',
+            };
+            let mut depth = 0usize;
+            value.char_indices().find_map(|(index, char)| {
+                if char == open {
+                    depth += 1;
+                } else if char == close {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(index + 1);
+                    }
+                }
+                None
+            })
+        }
+        Some(_) => value.find(|char: char| !(char.is_alphanumeric() || matches!(char, '_' | '$'))),
+        None => None,
+    };
+    let Some(alias_end) = alias_end.filter(|alias_end| *alias_end > 0) else {
+        return false;
+    };
+    let rest = value[alias_end..].trim_start();
+    let Some(expression) = rest.strip_prefix("in").or_else(|| rest.strip_prefix("of")) else {
+        return false;
+    };
+    expression.starts_with(char::is_whitespace) && !expression.trim().is_empty()
+}
@@ -0,0 +1,12 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoUnusedRefs =
+    <lint::nursery::no_unused_refs::NoUnusedRefs as biome_analyze::Rule>::Options;
+pub type UseAltText = <lint::a11y::use_alt_text::UseAltText as biome_analyze::Rule>::Options;
+pub type UseValidAriaProps =
+    <lint::a11y::use_valid_aria_props::UseValidAriaProps as biome_analyze::Rule>::Options;
+pub type UseValidAriaValues =
+    <lint::a11y::use_valid_aria_values::UseValidAriaValues as biome_analyze::Rule>::Options;
+pub type UseValidVueDirectives = < lint :: nursery :: use_valid_vue_directives :: UseValidVueDirectives as biome_analyze :: Rule > :: Options ;
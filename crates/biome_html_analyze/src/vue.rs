@@ -0,0 +1,178 @@
+//! A series of utilities to work with the blocks of the Vue single-file components
+
+use biome_html_syntax::{AnyHtmlElement, HtmlElement, HtmlElementList, HtmlRoot, HtmlSyntaxNode};
+use biome_rowan::{AstNode, AstNodeList};
+
+/// A directive of a Vue template, such as `v-on:click.prevent` in `<form v-on:click.prevent="submit">`
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct VueDirective<'a> {
+    /// The name of the directive without the `v-` prefix, such as `on`
+    pub(crate) name: &'a str,
+    /// The argument of the directive, such as `click`
+    pub(crate) argument: Option<&'a str>,
+    /// The modifiers of the directive, such as `prevent`
+    pub(crate) modifiers: Vec<&'a str>,
+}
+
+impl<'a> VueDirective<'a> {
+    /// Parses the name of an attribute, it returns `None` when the attribute isn't a directive.
+    ///
+    /// The shorthands `:`, `.`, `@` and `#` are the directives `v-bind`, `v-bind` with the `prop` modifier,
+    /// `v-on` and `v-slot`.
+    pub(crate) fn parse(attribute_name: &'a str) -> Option<Self> {
+        let (name, argument, modifiers) = if let Some(rest) = attribute_name.strip_prefix("v-") {
+            let end = rest.find([':', '.']).unwrap_or(rest.len());
+            let (name, rest) = rest.split_at(end);
+            match rest.strip_prefix(':') {
+                Some(rest) => {
+                    let (argument, modifiers) = split_argument(rest);
+                    (name, Some(argument), modifiers)
+                }
+                None => (name, None, rest),
+            }
+        } else {
+            let name = match attribute_name.as_bytes().first()? {
+                b':' | b'.' => "bind",
+                b'@' => "on",
+                b'#' => "slot",
+                _ => return None,
+            };
+            let (argument, modifiers) = split_argument(&attribute_name[1..]);
+            (name, Some(argument), modifiers)
+        };
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut modifiers: Vec<_> = modifiers
+            .split('.')
+            .filter(|modifier| !modifier.is_empty())
+            .collect();
+        if attribute_name.starts_with('.') {
+            modifiers.push("prop");
+        }
+
+        Some(Self {
+            name,
+            argument: argument.filter(|argument| !argument.is_empty()),
+            modifiers,
+        })
+    }
+}
+
+/// Splits the argument of a directive from its modifiers,
+/// the dynamic arguments such as `[name]` can contain dots.
+fn split_argument(text: &str) -> (&str, &str) {
+    let end = if text.starts_with('[') {
+        text.find(']').map_or(text.len(), |index| index + 1)
+    } else {
+        text.find('.').unwrap_or(text.len())
+    };
+    text.split_at(end)
+}
+
+/// Returns the name of `element`, such as `template` in `<template>`
+pub(crate) fn element_name(element: &HtmlElement) -> Option<String> {
+    let name = element
+        .opening_element()
+        .ok()?
+        .name()
+        .ok()?
+        .value_token()
+        .ok()?;
+    Some(name.text_trimmed().to_string())
+}
+
+/// Returns the top-level blocks of the component with the given name, such as `script`
+pub(crate) fn blocks<'a>(
+    root: &'a HtmlRoot,
+    name: &'a str,
+) -> impl Iterator<Item = HtmlElement> + 'a {
+    root.html().iter().filter_map(move |element| match element {
+        AnyHtmlElement::HtmlElement(element)
+            if element_name(&element).is_some_and(|element_name| element_name == name) =>
+        {
+            Some(element)
+        }
+        _ => None,
+    })
+}
+
+/// Returns the text of the content of `block`, such as the code of a `<script>` block
+pub(crate) fn block_content(block: &HtmlElement) -> Option<String> {
+    let content = block
+        .children()
+        .iter()
+        .find_map(|element| element.as_html_content().cloned())?;
+    Some(content.value_token().ok()?.text_trimmed().to_string())
+}
+
+/// Returns `true` if `node` is in the top-level `<template>` block of a component
+pub(crate) fn is_in_template_block(node: &HtmlSyntaxNode) -> bool {
+    node.ancestors()
+        .filter_map(HtmlElement::cast)
+        .last()
+        .is_some_and(|block| {
+            block
+                .parent::<HtmlElementList>()
+                .is_some_and(|list| list.parent::<HtmlRoot>().is_some())
+                && element_name(&block).is_some_and(|name| name == "template")
+        })
+}
+
+/// Returns `true` if `name` is the name of a component rather than a native element,
+/// such as `MyButton` or `my-button`
+pub(crate) fn is_component_name(name: &str) -> bool {
+    name.contains('-') || name.starts_with(|char: char| char.is_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VueDirective;
+
+    #[test]
+    fn parse_directives() {
+        assert_eq!(
+            VueDirective::parse("v-on:click.prevent.stop"),
+            Some(VueDirective {
+                name: "on",
+                argument: Some("click"),
+                modifiers: vec!["prevent", "stop"],
+            })
+        );
+        assert_eq!(
+            VueDirective::parse("v-model.trim"),
+            Some(VueDirective {
+                name: "model",
+                argument: None,
+                modifiers: vec!["trim"],
+            })
+        );
+        assert_eq!(
+            VueDirective::parse(":[key.name].camel"),
+            Some(VueDirective {
+                name: "bind",
+                argument: Some("[key.name]"),
+                modifiers: vec!["camel"],
+            })
+        );
+        assert_eq!(
+            VueDirective::parse(".textContent"),
+            Some(VueDirective {
+                name: "bind",
+                argument: Some("textContent"),
+                modifiers: vec!["prop"],
+            })
+        );
+        assert_eq!(
+            VueDirective::parse("#default"),
+            Some(VueDirective {
+                name: "slot",
+                argument: Some("default"),
+                modifiers: vec![],
+            })
+        );
+        assert_eq!(VueDirective::parse("class"), None);
+        assert_eq!(VueDirective::parse("v-"), None);
+    }
+}
@@ -7,6 +7,37 @@ use biome_html_syntax::{
     HtmlSyntaxToken as SyntaxToken, *,
 };
 use biome_rowan::AstNode;
+pub fn astro_frontmatter_element(
+    l_fence_token: SyntaxToken,
+    r_fence_token: SyntaxToken,
+) -> AstroFrontmatterElementBuilder {
+    AstroFrontmatterElementBuilder {
+        l_fence_token,
+        r_fence_token,
+        content_token: None,
+    }
+}
+pub struct AstroFrontmatterElementBuilder {
+    l_fence_token: SyntaxToken,
+    r_fence_token: SyntaxToken,
+    content_token: Option<SyntaxToken>,
+}
+impl AstroFrontmatterElementBuilder {
+    pub fn with_content_token(mut self, content_token: SyntaxToken) -> Self {
+        self.content_token = Some(content_token);
+        self
+    }
+    pub fn build(self) -> AstroFrontmatterElement {
+        AstroFrontmatterElement::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::ASTRO_FRONTMATTER_ELEMENT,
+            [
+                Some(SyntaxElement::Token(self.l_fence_token)),
+                self.content_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Token(self.r_fence_token)),
+            ],
+        ))
+    }
+}
 pub fn html_attribute(name: HtmlName) -> HtmlAttributeBuilder {
     HtmlAttributeBuilder {
         name,
@@ -35,7 +66,7 @@ impl HtmlAttributeBuilder {
 }
 pub fn html_attribute_initializer_clause(
     eq_token: SyntaxToken,
-    value: HtmlString,
+    value: AnyHtmlAttributeValue,
 ) -> HtmlAttributeInitializerClause {
     HtmlAttributeInitializerClause::unwrap_cast(SyntaxNode::new_detached(
         HtmlSyntaxKind::HTML_ATTRIBUTE_INITIALIZER_CLAUSE,
@@ -165,31 +196,33 @@ pub fn html_opening_element(
         ],
     ))
 }
-pub fn html_root(eof_token: SyntaxToken) -> HtmlRootBuilder {
+pub fn html_root(html: HtmlElementList, eof_token: SyntaxToken) -> HtmlRootBuilder {
     HtmlRootBuilder {
+        html,
         eof_token,
         bom_token: None,
+        frontmatter: None,
         directive: None,
-        html: None,
     }
 }
 pub struct HtmlRootBuilder {
+    html: HtmlElementList,
     eof_token: SyntaxToken,
     bom_token: Option<SyntaxToken>,
+    frontmatter: Option<AstroFrontmatterElement>,
     directive: Option<HtmlDirective>,
-    html: Option<AnyHtmlElement>,
 }
 impl HtmlRootBuilder {
     pub fn with_bom_token(mut self, bom_token: SyntaxToken) -> Self {
         self.bom_token = Some(bom_token);
         self
     }
-    pub fn with_directive(mut self, directive: HtmlDirective) -> Self {
-        self.directive = Some(directive);
+    pub fn with_frontmatter(mut self, frontmatter: AstroFrontmatterElement) -> Self {
+        self.frontmatter = Some(frontmatter);
         self
     }
-    pub fn with_html(mut self, html: AnyHtmlElement) -> Self {
-        self.html = Some(html);
+    pub fn with_directive(mut self, directive: HtmlDirective) -> Self {
+        self.directive = Some(directive);
         self
     }
     pub fn build(self) -> HtmlRoot {
@@ -197,10 +230,11 @@ impl HtmlRootBuilder {
             HtmlSyntaxKind::HTML_ROOT,
             [
                 self.bom_token.map(|token| SyntaxElement::Token(token)),
-                self.directive
+                self.frontmatter
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
-                self.html
+                self.directive
                     .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.html.into_syntax())),
                 Some(SyntaxElement::Token(self.eof_token)),
             ],
         ))
@@ -210,17 +244,52 @@ pub fn html_self_closing_element(
     l_angle_token: SyntaxToken,
     name: HtmlName,
     attributes: HtmlAttributeList,
-    slash_token: SyntaxToken,
     r_angle_token: SyntaxToken,
-) -> HtmlSelfClosingElement {
-    HtmlSelfClosingElement::unwrap_cast(SyntaxNode::new_detached(
-        HtmlSyntaxKind::HTML_SELF_CLOSING_ELEMENT,
+) -> HtmlSelfClosingElementBuilder {
+    HtmlSelfClosingElementBuilder {
+        l_angle_token,
+        name,
+        attributes,
+        r_angle_token,
+        slash_token: None,
+    }
+}
+pub struct HtmlSelfClosingElementBuilder {
+    l_angle_token: SyntaxToken,
+    name: HtmlName,
+    attributes: HtmlAttributeList,
+    r_angle_token: SyntaxToken,
+    slash_token: Option<SyntaxToken>,
+}
+impl HtmlSelfClosingElementBuilder {
+    pub fn with_slash_token(mut self, slash_token: SyntaxToken) -> Self {
+        self.slash_token = Some(slash_token);
+        self
+    }
+    pub fn build(self) -> HtmlSelfClosingElement {
+        HtmlSelfClosingElement::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::HTML_SELF_CLOSING_ELEMENT,
+            [
+                Some(SyntaxElement::Token(self.l_angle_token)),
+                Some(SyntaxElement::Node(self.name.into_syntax())),
+                Some(SyntaxElement::Node(self.attributes.into_syntax())),
+                self.slash_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Token(self.r_angle_token)),
+            ],
+        ))
+    }
+}
+pub fn html_single_text_expression(
+    l_curly_token: SyntaxToken,
+    expression: HtmlTextExpression,
+    r_curly_token: SyntaxToken,
+) -> HtmlSingleTextExpression {
+    HtmlSingleTextExpression::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::HTML_SINGLE_TEXT_EXPRESSION,
         [
-            Some(SyntaxElement::Token(l_angle_token)),
-            Some(SyntaxElement::Node(name.into_syntax())),
-            Some(SyntaxElement::Node(attributes.into_syntax())),
-            Some(SyntaxElement::Token(slash_token)),
-            Some(SyntaxElement::Token(r_angle_token)),
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(expression.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
         ],
     ))
 }
@@ -230,6 +299,247 @@ pub fn html_string(value_token: SyntaxToken) -> HtmlString {
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
+pub fn html_text_expression(value_token: SyntaxToken) -> HtmlTextExpression {
+    HtmlTextExpression::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::HTML_TEXT_EXPRESSION,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn svelte_each_block(
+    opening_block: SvelteEachOpeningBlock,
+    children: HtmlElementList,
+    closing_block: SvelteEachClosingBlock,
+) -> SvelteEachBlockBuilder {
+    SvelteEachBlockBuilder {
+        opening_block,
+        children,
+        closing_block,
+        else_clause: None,
+    }
+}
+pub struct SvelteEachBlockBuilder {
+    opening_block: SvelteEachOpeningBlock,
+    children: HtmlElementList,
+    closing_block: SvelteEachClosingBlock,
+    else_clause: Option<SvelteElseClause>,
+}
+impl SvelteEachBlockBuilder {
+    pub fn with_else_clause(mut self, else_clause: SvelteElseClause) -> Self {
+        self.else_clause = Some(else_clause);
+        self
+    }
+    pub fn build(self) -> SvelteEachBlock {
+        SvelteEachBlock::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::SVELTE_EACH_BLOCK,
+            [
+                Some(SyntaxElement::Node(self.opening_block.into_syntax())),
+                Some(SyntaxElement::Node(self.children.into_syntax())),
+                self.else_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.closing_block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn svelte_each_closing_block(
+    sv_curly_slash_token: SyntaxToken,
+    each_token: SyntaxToken,
+    r_curly_token: SyntaxToken,
+) -> SvelteEachClosingBlock {
+    SvelteEachClosingBlock::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_EACH_CLOSING_BLOCK,
+        [
+            Some(SyntaxElement::Token(sv_curly_slash_token)),
+            Some(SyntaxElement::Token(each_token)),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
+pub fn svelte_each_index(comma_token: SyntaxToken, value: HtmlName) -> SvelteEachIndex {
+    SvelteEachIndex::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_EACH_INDEX,
+        [
+            Some(SyntaxElement::Token(comma_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn svelte_each_key(
+    l_paren_token: SyntaxToken,
+    expression: HtmlTextExpression,
+    r_paren_token: SyntaxToken,
+) -> SvelteEachKey {
+    SvelteEachKey::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_EACH_KEY,
+        [
+            Some(SyntaxElement::Token(l_paren_token)),
+            Some(SyntaxElement::Node(expression.into_syntax())),
+            Some(SyntaxElement::Token(r_paren_token)),
+        ],
+    ))
+}
+pub fn svelte_each_opening_block(
+    sv_curly_hash_token: SyntaxToken,
+    each_token: SyntaxToken,
+    list: HtmlTextExpression,
+    as_token: SyntaxToken,
+    item: HtmlTextExpression,
+    r_curly_token: SyntaxToken,
+) -> SvelteEachOpeningBlockBuilder {
+    SvelteEachOpeningBlockBuilder {
+        sv_curly_hash_token,
+        each_token,
+        list,
+        as_token,
+        item,
+        r_curly_token,
+        index: None,
+        key: None,
+    }
+}
+pub struct SvelteEachOpeningBlockBuilder {
+    sv_curly_hash_token: SyntaxToken,
+    each_token: SyntaxToken,
+    list: HtmlTextExpression,
+    as_token: SyntaxToken,
+    item: HtmlTextExpression,
+    r_curly_token: SyntaxToken,
+    index: Option<SvelteEachIndex>,
+    key: Option<SvelteEachKey>,
+}
+impl SvelteEachOpeningBlockBuilder {
+    pub fn with_index(mut self, index: SvelteEachIndex) -> Self {
+        self.index = Some(index);
+        self
+    }
+    pub fn with_key(mut self, key: SvelteEachKey) -> Self {
+        self.key = Some(key);
+        self
+    }
+    pub fn build(self) -> SvelteEachOpeningBlock {
+        SvelteEachOpeningBlock::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::SVELTE_EACH_OPENING_BLOCK,
+            [
+                Some(SyntaxElement::Token(self.sv_curly_hash_token)),
+                Some(SyntaxElement::Token(self.each_token)),
+                Some(SyntaxElement::Node(self.list.into_syntax())),
+                Some(SyntaxElement::Token(self.as_token)),
+                Some(SyntaxElement::Node(self.item.into_syntax())),
+                self.index
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.key
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Token(self.r_curly_token)),
+            ],
+        ))
+    }
+}
+pub fn svelte_else_clause(
+    sv_curly_colon_token: SyntaxToken,
+    else_token: SyntaxToken,
+    r_curly_token: SyntaxToken,
+    children: HtmlElementList,
+) -> SvelteElseClause {
+    SvelteElseClause::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_ELSE_CLAUSE,
+        [
+            Some(SyntaxElement::Token(sv_curly_colon_token)),
+            Some(SyntaxElement::Token(else_token)),
+            Some(SyntaxElement::Token(r_curly_token)),
+            Some(SyntaxElement::Node(children.into_syntax())),
+        ],
+    ))
+}
+pub fn svelte_else_if_clause(
+    sv_curly_colon_token: SyntaxToken,
+    else_token: SyntaxToken,
+    if_token: SyntaxToken,
+    condition: HtmlTextExpression,
+    r_curly_token: SyntaxToken,
+    children: HtmlElementList,
+) -> SvelteElseIfClause {
+    SvelteElseIfClause::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_ELSE_IF_CLAUSE,
+        [
+            Some(SyntaxElement::Token(sv_curly_colon_token)),
+            Some(SyntaxElement::Token(else_token)),
+            Some(SyntaxElement::Token(if_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+            Some(SyntaxElement::Node(children.into_syntax())),
+        ],
+    ))
+}
+pub fn svelte_if_block(
+    opening_block: SvelteIfOpeningBlock,
+    children: HtmlElementList,
+    else_if_clauses: SvelteElseIfClauseList,
+    closing_block: SvelteIfClosingBlock,
+) -> SvelteIfBlockBuilder {
+    SvelteIfBlockBuilder {
+        opening_block,
+        children,
+        else_if_clauses,
+        closing_block,
+        else_clause: None,
+    }
+}
+pub struct SvelteIfBlockBuilder {
+    opening_block: SvelteIfOpeningBlock,
+    children: HtmlElementList,
+    else_if_clauses: SvelteElseIfClauseList,
+    closing_block: SvelteIfClosingBlock,
+    else_clause: Option<SvelteElseClause>,
+}
+impl SvelteIfBlockBuilder {
+    pub fn with_else_clause(mut self, else_clause: SvelteElseClause) -> Self {
+        self.else_clause = Some(else_clause);
+        self
+    }
+    pub fn build(self) -> SvelteIfBlock {
+        SvelteIfBlock::unwrap_cast(SyntaxNode::new_detached(
+            HtmlSyntaxKind::SVELTE_IF_BLOCK,
+            [
+                Some(SyntaxElement::Node(self.opening_block.into_syntax())),
+                Some(SyntaxElement::Node(self.children.into_syntax())),
+                Some(SyntaxElement::Node(self.else_if_clauses.into_syntax())),
+                self.else_clause
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.closing_block.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn svelte_if_closing_block(
+    sv_curly_slash_token: SyntaxToken,
+    if_token: SyntaxToken,
+    r_curly_token: SyntaxToken,
+) -> SvelteIfClosingBlock {
+    SvelteIfClosingBlock::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_IF_CLOSING_BLOCK,
+        [
+            Some(SyntaxElement::Token(sv_curly_slash_token)),
+            Some(SyntaxElement::Token(if_token)),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
+pub fn svelte_if_opening_block(
+    sv_curly_hash_token: SyntaxToken,
+    if_token: SyntaxToken,
+    condition: HtmlTextExpression,
+    r_curly_token: SyntaxToken,
+) -> SvelteIfOpeningBlock {
+    SvelteIfOpeningBlock::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_IF_OPENING_BLOCK,
+        [
+            Some(SyntaxElement::Token(sv_curly_hash_token)),
+            Some(SyntaxElement::Token(if_token)),
+            Some(SyntaxElement::Node(condition.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
 pub fn html_attribute_list<I>(items: I) -> HtmlAttributeList
 where
     I: IntoIterator<Item = AnyHtmlAttribute>,
@@ -254,6 +564,18 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn svelte_else_if_clause_list<I>(items: I) -> SvelteElseIfClauseList
+where
+    I: IntoIterator<Item = SvelteElseIfClause>,
+    I::IntoIter: ExactSizeIterator,
+{
+    SvelteElseIfClauseList::unwrap_cast(SyntaxNode::new_detached(
+        HtmlSyntaxKind::SVELTE_ELSE_IF_CLAUSE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn html_bogus<I>(slots: I) -> HtmlBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
@@ -0,0 +1,42 @@
+use crate::prelude::*;
+use biome_formatter::{normalize_newlines, write};
+use biome_html_syntax::{AstroFrontmatterElement, AstroFrontmatterElementFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAstroFrontmatterElement;
+
+impl FormatNodeRule<AstroFrontmatterElement> for FormatAstroFrontmatterElement {
+    fn fmt_fields(
+        &self,
+        node: &AstroFrontmatterElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        let AstroFrontmatterElementFields {
+            l_fence_token,
+            content_token,
+            r_fence_token,
+        } = node.as_fields();
+
+        write!(f, [l_fence_token.format(), hard_line_break()])?;
+
+        // The code of the frontmatter is printed as it is, the fences are on their own lines
+        if let Some(content_token) = content_token {
+            write!(
+                f,
+                [
+                    format_replaced(
+                        &content_token,
+                        &syntax_token_cow_slice(
+                            normalize_newlines(content_token.text_trimmed(), ['\r']),
+                            &content_token,
+                            content_token.text_trimmed_range().start(),
+                        )
+                    ),
+                    hard_line_break()
+                ]
+            )?;
+        }
+
+        write!(f, [r_fence_token.format()])
+    }
+}
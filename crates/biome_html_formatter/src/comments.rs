@@ -0,0 +1,51 @@
+use crate::prelude::*;
+use biome_diagnostics::category;
+use biome_formatter::comments::{CommentKind, CommentStyle, Comments, SourceComment};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{write, FormatResult, FormatRule};
+use biome_html_syntax::HtmlLanguage;
+use biome_rowan::SyntaxTriviaPieceComments;
+use biome_suppression::parse_suppression_comment;
+
+pub type HtmlComments = Comments<HtmlLanguage>;
+
+#[derive(Default)]
+pub struct FormatHtmlLeadingComment;
+
+impl FormatRule<SourceComment<HtmlLanguage>> for FormatHtmlLeadingComment {
+    type Context = HtmlFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<HtmlLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct HtmlCommentStyle;
+
+impl CommentStyle for HtmlCommentStyle {
+    type Language = HtmlLanguage;
+
+    fn is_suppression(text: &str) -> bool {
+        // `parse_suppression_comment` only understands `//` and `/*` comments,
+        // so `<!-- biome-ignore format: reason -->` is parsed as a `/*` comment
+        let Some(comment) = text
+            .strip_prefix("<!--")
+            .and_then(|comment| comment.strip_suffix("-->"))
+        else {
+            return false;
+        };
+        parse_suppression_comment(&std::format!("/*{comment}*/"))
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .any(|(key, _)| key == category!("format"))
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Block
+    }
+}
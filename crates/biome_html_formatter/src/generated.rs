@@ -0,0 +1,1216 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::{
+    AsFormat, FormatBogusNodeRule, FormatNodeRule, HtmlFormatContext, HtmlFormatter, IntoFormat,
+};
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_html_syntax::AstroFrontmatterElement>
+    for crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::AstroFrontmatterElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::AstroFrontmatterElement>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::AstroFrontmatterElement {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::AstroFrontmatterElement,
+        crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::AstroFrontmatterElement {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::AstroFrontmatterElement,
+        crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::astro::auxiliary::frontmatter_element::FormatAstroFrontmatterElement::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlAttribute>
+    for crate::html::auxiliary::attribute::FormatHtmlAttribute
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlAttribute,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlAttribute>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlAttribute {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlAttribute,
+        crate::html::auxiliary::attribute::FormatHtmlAttribute,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::attribute::FormatHtmlAttribute::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlAttribute {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlAttribute,
+        crate::html::auxiliary::attribute::FormatHtmlAttribute,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::attribute::FormatHtmlAttribute::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlAttributeInitializerClause>
+    for crate::html::auxiliary::attribute_initializer_clause::FormatHtmlAttributeInitializerClause
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlAttributeInitializerClause,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlAttributeInitializerClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlAttributeInitializerClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlAttributeInitializerClause,
+        crate::html::auxiliary::attribute_initializer_clause::FormatHtmlAttributeInitializerClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: html :: auxiliary :: attribute_initializer_clause :: FormatHtmlAttributeInitializerClause :: default ())
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlAttributeInitializerClause {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlAttributeInitializerClause,
+        crate::html::auxiliary::attribute_initializer_clause::FormatHtmlAttributeInitializerClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: html :: auxiliary :: attribute_initializer_clause :: FormatHtmlAttributeInitializerClause :: default ())
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlClosingElement>
+    for crate::html::auxiliary::closing_element::FormatHtmlClosingElement
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlClosingElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlClosingElement>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlClosingElement {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlClosingElement,
+        crate::html::auxiliary::closing_element::FormatHtmlClosingElement,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::closing_element::FormatHtmlClosingElement::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlClosingElement {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlClosingElement,
+        crate::html::auxiliary::closing_element::FormatHtmlClosingElement,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::closing_element::FormatHtmlClosingElement::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlContent>
+    for crate::html::auxiliary::content::FormatHtmlContent
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlContent,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlContent>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlContent {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlContent,
+        crate::html::auxiliary::content::FormatHtmlContent,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::content::FormatHtmlContent::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlContent {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlContent,
+        crate::html::auxiliary::content::FormatHtmlContent,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::content::FormatHtmlContent::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlDirective>
+    for crate::html::auxiliary::directive::FormatHtmlDirective
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlDirective,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlDirective>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlDirective {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlDirective,
+        crate::html::auxiliary::directive::FormatHtmlDirective,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::directive::FormatHtmlDirective::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlDirective {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlDirective,
+        crate::html::auxiliary::directive::FormatHtmlDirective,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::directive::FormatHtmlDirective::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlElement>
+    for crate::html::auxiliary::element::FormatHtmlElement
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlElement>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlElement {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlElement,
+        crate::html::auxiliary::element::FormatHtmlElement,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::element::FormatHtmlElement::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlElement {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlElement,
+        crate::html::auxiliary::element::FormatHtmlElement,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::element::FormatHtmlElement::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlName> for crate::html::auxiliary::name::FormatHtmlName {
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_html_syntax::HtmlName, f: &mut HtmlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlName>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlName {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlName,
+        crate::html::auxiliary::name::FormatHtmlName,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::name::FormatHtmlName::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlName {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlName,
+        crate::html::auxiliary::name::FormatHtmlName,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::name::FormatHtmlName::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlOpeningElement>
+    for crate::html::auxiliary::opening_element::FormatHtmlOpeningElement
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlOpeningElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlOpeningElement>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlOpeningElement {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlOpeningElement,
+        crate::html::auxiliary::opening_element::FormatHtmlOpeningElement,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::opening_element::FormatHtmlOpeningElement::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlOpeningElement {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlOpeningElement,
+        crate::html::auxiliary::opening_element::FormatHtmlOpeningElement,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::opening_element::FormatHtmlOpeningElement::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlRoot> for crate::html::auxiliary::root::FormatHtmlRoot {
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_html_syntax::HtmlRoot, f: &mut HtmlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlRoot>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlRoot {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlRoot,
+        crate::html::auxiliary::root::FormatHtmlRoot,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::root::FormatHtmlRoot::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlRoot {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlRoot,
+        crate::html::auxiliary::root::FormatHtmlRoot,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::root::FormatHtmlRoot::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlSelfClosingElement>
+    for crate::html::auxiliary::self_closing_element::FormatHtmlSelfClosingElement
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlSelfClosingElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlSelfClosingElement>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlSelfClosingElement {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlSelfClosingElement,
+        crate::html::auxiliary::self_closing_element::FormatHtmlSelfClosingElement,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::self_closing_element::FormatHtmlSelfClosingElement::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlSelfClosingElement {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlSelfClosingElement,
+        crate::html::auxiliary::self_closing_element::FormatHtmlSelfClosingElement,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::self_closing_element::FormatHtmlSelfClosingElement::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlSingleTextExpression>
+    for crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlSingleTextExpression,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlSingleTextExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlSingleTextExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlSingleTextExpression,
+        crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlSingleTextExpression {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlSingleTextExpression,
+        crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::single_text_expression::FormatHtmlSingleTextExpression::default(
+            ),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlString>
+    for crate::html::auxiliary::string::FormatHtmlString
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_html_syntax::HtmlString, f: &mut HtmlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlString>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlString {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlString,
+        crate::html::auxiliary::string::FormatHtmlString,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::string::FormatHtmlString::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlString {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlString,
+        crate::html::auxiliary::string::FormatHtmlString,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::string::FormatHtmlString::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlTextExpression>
+    for crate::html::auxiliary::text_expression::FormatHtmlTextExpression
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlTextExpression,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::HtmlTextExpression>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlTextExpression {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlTextExpression,
+        crate::html::auxiliary::text_expression::FormatHtmlTextExpression,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::auxiliary::text_expression::FormatHtmlTextExpression::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlTextExpression {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlTextExpression,
+        crate::html::auxiliary::text_expression::FormatHtmlTextExpression,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::auxiliary::text_expression::FormatHtmlTextExpression::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteEachBlock>
+    for crate::svelte::auxiliary::each_block::FormatSvelteEachBlock
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteEachBlock,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteEachBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteEachBlock,
+        crate::svelte::auxiliary::each_block::FormatSvelteEachBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_block::FormatSvelteEachBlock::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachBlock {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteEachBlock,
+        crate::svelte::auxiliary::each_block::FormatSvelteEachBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_block::FormatSvelteEachBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteEachClosingBlock>
+    for crate::svelte::auxiliary::each_closing_block::FormatSvelteEachClosingBlock
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteEachClosingBlock,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteEachClosingBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachClosingBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteEachClosingBlock,
+        crate::svelte::auxiliary::each_closing_block::FormatSvelteEachClosingBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_closing_block::FormatSvelteEachClosingBlock::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachClosingBlock {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteEachClosingBlock,
+        crate::svelte::auxiliary::each_closing_block::FormatSvelteEachClosingBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_closing_block::FormatSvelteEachClosingBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteEachIndex>
+    for crate::svelte::auxiliary::each_index::FormatSvelteEachIndex
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteEachIndex,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteEachIndex>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachIndex {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteEachIndex,
+        crate::svelte::auxiliary::each_index::FormatSvelteEachIndex,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_index::FormatSvelteEachIndex::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachIndex {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteEachIndex,
+        crate::svelte::auxiliary::each_index::FormatSvelteEachIndex,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_index::FormatSvelteEachIndex::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteEachKey>
+    for crate::svelte::auxiliary::each_key::FormatSvelteEachKey
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteEachKey,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteEachKey>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachKey {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteEachKey,
+        crate::svelte::auxiliary::each_key::FormatSvelteEachKey,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_key::FormatSvelteEachKey::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachKey {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteEachKey,
+        crate::svelte::auxiliary::each_key::FormatSvelteEachKey,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_key::FormatSvelteEachKey::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteEachOpeningBlock>
+    for crate::svelte::auxiliary::each_opening_block::FormatSvelteEachOpeningBlock
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteEachOpeningBlock,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteEachOpeningBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachOpeningBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteEachOpeningBlock,
+        crate::svelte::auxiliary::each_opening_block::FormatSvelteEachOpeningBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_opening_block::FormatSvelteEachOpeningBlock::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteEachOpeningBlock {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteEachOpeningBlock,
+        crate::svelte::auxiliary::each_opening_block::FormatSvelteEachOpeningBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::each_opening_block::FormatSvelteEachOpeningBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteElseClause>
+    for crate::svelte::auxiliary::else_clause::FormatSvelteElseClause
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteElseClause,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteElseClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteElseClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteElseClause,
+        crate::svelte::auxiliary::else_clause::FormatSvelteElseClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::else_clause::FormatSvelteElseClause::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteElseClause {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteElseClause,
+        crate::svelte::auxiliary::else_clause::FormatSvelteElseClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::else_clause::FormatSvelteElseClause::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteElseIfClause>
+    for crate::svelte::auxiliary::else_if_clause::FormatSvelteElseIfClause
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteElseIfClause,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteElseIfClause>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteElseIfClause {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteElseIfClause,
+        crate::svelte::auxiliary::else_if_clause::FormatSvelteElseIfClause,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::else_if_clause::FormatSvelteElseIfClause::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteElseIfClause {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteElseIfClause,
+        crate::svelte::auxiliary::else_if_clause::FormatSvelteElseIfClause,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::else_if_clause::FormatSvelteElseIfClause::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteIfBlock>
+    for crate::svelte::auxiliary::if_block::FormatSvelteIfBlock
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteIfBlock,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteIfBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteIfBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteIfBlock,
+        crate::svelte::auxiliary::if_block::FormatSvelteIfBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::if_block::FormatSvelteIfBlock::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteIfBlock {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteIfBlock,
+        crate::svelte::auxiliary::if_block::FormatSvelteIfBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::if_block::FormatSvelteIfBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteIfClosingBlock>
+    for crate::svelte::auxiliary::if_closing_block::FormatSvelteIfClosingBlock
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteIfClosingBlock,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteIfClosingBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteIfClosingBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteIfClosingBlock,
+        crate::svelte::auxiliary::if_closing_block::FormatSvelteIfClosingBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::if_closing_block::FormatSvelteIfClosingBlock::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteIfClosingBlock {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteIfClosingBlock,
+        crate::svelte::auxiliary::if_closing_block::FormatSvelteIfClosingBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::if_closing_block::FormatSvelteIfClosingBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::SvelteIfOpeningBlock>
+    for crate::svelte::auxiliary::if_opening_block::FormatSvelteIfOpeningBlock
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::SvelteIfOpeningBlock,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_html_syntax::SvelteIfOpeningBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteIfOpeningBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteIfOpeningBlock,
+        crate::svelte::auxiliary::if_opening_block::FormatSvelteIfOpeningBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::auxiliary::if_opening_block::FormatSvelteIfOpeningBlock::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteIfOpeningBlock {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteIfOpeningBlock,
+        crate::svelte::auxiliary::if_opening_block::FormatSvelteIfOpeningBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::auxiliary::if_opening_block::FormatSvelteIfOpeningBlock::default(),
+        )
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlAttributeList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlAttributeList,
+        crate::html::lists::attribute_list::FormatHtmlAttributeList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::lists::attribute_list::FormatHtmlAttributeList::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlAttributeList {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlAttributeList,
+        crate::html::lists::attribute_list::FormatHtmlAttributeList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::lists::attribute_list::FormatHtmlAttributeList::default(),
+        )
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlElementList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlElementList,
+        crate::html::lists::element_list::FormatHtmlElementList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::lists::element_list::FormatHtmlElementList::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlElementList {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlElementList,
+        crate::html::lists::element_list::FormatHtmlElementList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::lists::element_list::FormatHtmlElementList::default(),
+        )
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::SvelteElseIfClauseList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::SvelteElseIfClauseList,
+        crate::svelte::lists::else_if_clause_list::FormatSvelteElseIfClauseList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::svelte::lists::else_if_clause_list::FormatSvelteElseIfClauseList::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::SvelteElseIfClauseList {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::SvelteElseIfClauseList,
+        crate::svelte::lists::else_if_clause_list::FormatSvelteElseIfClauseList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::svelte::lists::else_if_clause_list::FormatSvelteElseIfClauseList::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlBogus> for crate::html::bogus::bogus::FormatHtmlBogus {
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_html_syntax::HtmlBogus, f: &mut HtmlFormatter) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_html_syntax::HtmlBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlBogus {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlBogus,
+        crate::html::bogus::bogus::FormatHtmlBogus,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::html::bogus::bogus::FormatHtmlBogus::default())
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlBogus {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlBogus,
+        crate::html::bogus::bogus::FormatHtmlBogus,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::html::bogus::bogus::FormatHtmlBogus::default())
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlBogusAttribute>
+    for crate::html::bogus::bogus_attribute::FormatHtmlBogusAttribute
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlBogusAttribute,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_html_syntax::HtmlBogusAttribute>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlBogusAttribute {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlBogusAttribute,
+        crate::html::bogus::bogus_attribute::FormatHtmlBogusAttribute,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::bogus::bogus_attribute::FormatHtmlBogusAttribute::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlBogusAttribute {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlBogusAttribute,
+        crate::html::bogus::bogus_attribute::FormatHtmlBogusAttribute,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::bogus::bogus_attribute::FormatHtmlBogusAttribute::default(),
+        )
+    }
+}
+impl FormatRule<biome_html_syntax::HtmlBogusElement>
+    for crate::html::bogus::bogus_element::FormatHtmlBogusElement
+{
+    type Context = HtmlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_html_syntax::HtmlBogusElement,
+        f: &mut HtmlFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_html_syntax::HtmlBogusElement>::fmt(self, node, f)
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::HtmlBogusElement {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::HtmlBogusElement,
+        crate::html::bogus::bogus_element::FormatHtmlBogusElement,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::bogus::bogus_element::FormatHtmlBogusElement::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::HtmlBogusElement {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::HtmlBogusElement,
+        crate::html::bogus::bogus_element::FormatHtmlBogusElement,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::bogus::bogus_element::FormatHtmlBogusElement::default(),
+        )
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlAttribute {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::AnyHtmlAttribute,
+        crate::html::any::attribute::FormatAnyHtmlAttribute,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::any::attribute::FormatAnyHtmlAttribute::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlAttribute {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::AnyHtmlAttribute,
+        crate::html::any::attribute::FormatAnyHtmlAttribute,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::any::attribute::FormatAnyHtmlAttribute::default(),
+        )
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlAttributeValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::AnyHtmlAttributeValue,
+        crate::html::any::attribute_value::FormatAnyHtmlAttributeValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::any::attribute_value::FormatAnyHtmlAttributeValue::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlAttributeValue {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::AnyHtmlAttributeValue,
+        crate::html::any::attribute_value::FormatAnyHtmlAttributeValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::any::attribute_value::FormatAnyHtmlAttributeValue::default(),
+        )
+    }
+}
+impl AsFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlElement {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_html_syntax::AnyHtmlElement,
+        crate::html::any::element::FormatAnyHtmlElement,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::html::any::element::FormatAnyHtmlElement::default(),
+        )
+    }
+}
+impl IntoFormat<HtmlFormatContext> for biome_html_syntax::AnyHtmlElement {
+    type Format = FormatOwnedWithRule<
+        biome_html_syntax::AnyHtmlElement,
+        crate::html::any::element::FormatAnyHtmlElement,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::html::any::element::FormatAnyHtmlElement::default(),
+        )
+    }
+}
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_html_syntax::AnyHtmlAttributeValue;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyHtmlAttributeValue;
+impl FormatRule<AnyHtmlAttributeValue> for FormatAnyHtmlAttributeValue {
+    type Context = HtmlFormatContext;
+    fn fmt(&self, node: &AnyHtmlAttributeValue, f: &mut HtmlFormatter) -> FormatResult<()> {
+        match node {
+            AnyHtmlAttributeValue::HtmlSingleTextExpression(node) => node.format().fmt(f),
+            AnyHtmlAttributeValue::HtmlString(node) => node.format().fmt(f),
+        }
+    }
+}
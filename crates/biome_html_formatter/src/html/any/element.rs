@@ -0,0 +1,20 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_html_syntax::AnyHtmlElement;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyHtmlElement;
+impl FormatRule<AnyHtmlElement> for FormatAnyHtmlElement {
+    type Context = HtmlFormatContext;
+    fn fmt(&self, node: &AnyHtmlElement, f: &mut HtmlFormatter) -> FormatResult<()> {
+        match node {
+            AnyHtmlElement::HtmlBogusElement(node) => node.format().fmt(f),
+            AnyHtmlElement::HtmlContent(node) => node.format().fmt(f),
+            AnyHtmlElement::HtmlElement(node) => node.format().fmt(f),
+            AnyHtmlElement::HtmlSelfClosingElement(node) => node.format().fmt(f),
+            AnyHtmlElement::HtmlSingleTextExpression(node) => node.format().fmt(f),
+            AnyHtmlElement::SvelteEachBlock(node) => node.format().fmt(f),
+            AnyHtmlElement::SvelteIfBlock(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{HtmlContent, HtmlContentFields};
+use biome_rowan::TextSize;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlContent;
+
+impl FormatNodeRule<HtmlContent> for FormatHtmlContent {
+    fn fmt_fields(&self, node: &HtmlContent, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlContentFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+        let text = value_token.text_trimmed();
+        let start = value_token.text_trimmed_range().start();
+
+        // The whitespace between the words is rendered as a single space,
+        // so the words are filled up to the line width
+        let words = format_with(|f| {
+            let mut fill = f.fill();
+            let mut offset = 0;
+
+            for word in text.split_ascii_whitespace() {
+                let word_start = offset + text[offset..].find(word).unwrap_or_default();
+                offset = word_start + word.len();
+
+                fill.entry(
+                    &soft_line_break_or_space(),
+                    &dynamic_text(word, start + TextSize::from(word_start as u32)),
+                );
+            }
+
+            fill.finish()
+        });
+
+        write!(f, [format_replaced(&value_token, &words)])
+    }
+}
@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{HtmlDirective, HtmlDirectiveFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlDirective;
+
+impl FormatNodeRule<HtmlDirective> for FormatHtmlDirective {
+    fn fmt_fields(&self, node: &HtmlDirective, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlDirectiveFields {
+            l_angle_token,
+            excl_token,
+            doctype_token,
+            html_token,
+            quirk_token,
+            public_id_token,
+            system_id_token,
+            r_angle_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_angle_token.format(),
+                excl_token.format(),
+                doctype_token.format()
+            ]
+        )?;
+
+        for token in [html_token, quirk_token, public_id_token, system_id_token]
+            .into_iter()
+            .flatten()
+        {
+            write!(f, [space(), token.format()])?;
+        }
+
+        write!(f, [r_angle_token.format()])
+    }
+}
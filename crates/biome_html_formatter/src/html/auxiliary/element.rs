@@ -0,0 +1,48 @@
+use crate::prelude::*;
+use crate::utils::{format_children, HtmlWhitespace};
+use biome_formatter::write;
+use biome_html_syntax::{HtmlElement, HtmlElementFields};
+
+/// The elements whose content is text, even if it contains tags, such as the code of a `<script>`.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlElement;
+
+impl FormatNodeRule<HtmlElement> for FormatHtmlElement {
+    fn fmt_fields(&self, node: &HtmlElement, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlElementFields {
+            opening_element,
+            children,
+            closing_element,
+        } = node.as_fields();
+        let opening_element = opening_element?;
+        let closing_element = closing_element?;
+        let name_token = opening_element.name()?.value_token()?;
+        let name = name_token.text_trimmed();
+
+        // The whitespace of a `<pre>` element is rendered as it is in the source
+        if name.eq_ignore_ascii_case("pre") {
+            return write!(f, [format_verbatim_node(node.syntax()).skip_comments()]);
+        }
+
+        write!(f, [opening_element.format()])?;
+
+        if RAW_TEXT_ELEMENTS
+            .iter()
+            .any(|raw_text_element| name.eq_ignore_ascii_case(raw_text_element))
+        {
+            // The text includes its whitespace, it's printed as it is
+            if !children.is_empty() {
+                write!(f, [format_verbatim_node(children.syntax())])?;
+            }
+            return write!(f, [closing_element.format()]);
+        }
+
+        write!(f, [format_children(&children)])?;
+
+        let before_closing =
+            HtmlWhitespace::before(&closing_element.l_angle_token()?).without_empty_line();
+        write!(f, [before_closing, closing_element.format()])
+    }
+}
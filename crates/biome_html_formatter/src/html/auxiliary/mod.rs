@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod attribute;
+pub(crate) mod attribute_initializer_clause;
+pub(crate) mod closing_element;
+pub(crate) mod content;
+pub(crate) mod directive;
+pub(crate) mod element;
+pub(crate) mod name;
+pub(crate) mod opening_element;
+pub(crate) mod root;
+pub(crate) mod self_closing_element;
+pub(crate) mod single_text_expression;
+pub(crate) mod string;
+pub(crate) mod text_expression;
@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write, AttributePosition, FormatOptions};
+use biome_html_syntax::{HtmlOpeningElement, HtmlOpeningElementFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlOpeningElement;
+
+impl FormatNodeRule<HtmlOpeningElement> for FormatHtmlOpeningElement {
+    fn fmt_fields(&self, node: &HtmlOpeningElement, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlOpeningElementFields {
+            l_angle_token,
+            name,
+            attributes,
+            r_angle_token,
+        } = node.as_fields();
+
+        let should_expand = f.options().attribute_position() == AttributePosition::Multiline
+            && attributes.len() > 1;
+
+        let format_attributes = format_with(|f| {
+            if attributes.is_empty() {
+                return Ok(());
+            }
+            write!(
+                f,
+                [
+                    indent(&format_args![
+                        soft_line_break_or_space(),
+                        attributes.format()
+                    ]),
+                    soft_line_break()
+                ]
+            )
+        });
+
+        write!(
+            f,
+            [group(&format_args![
+                l_angle_token.format(),
+                name.format(),
+                format_attributes,
+                r_angle_token.format()
+            ])
+            .should_expand(should_expand)]
+        )
+    }
+}
@@ -0,0 +1,54 @@
+use crate::prelude::*;
+use crate::utils::HtmlWhitespace;
+use biome_formatter::write;
+use biome_html_syntax::{HtmlRoot, HtmlRootFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlRoot;
+
+impl FormatNodeRule<HtmlRoot> for FormatHtmlRoot {
+    fn fmt_fields(&self, node: &HtmlRoot, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlRootFields {
+            bom_token,
+            frontmatter,
+            directive,
+            html,
+            eof_token,
+        } = node.as_fields();
+
+        write!(f, [bom_token.format()])?;
+
+        if let Some(frontmatter) = &frontmatter {
+            write!(f, [frontmatter.format()])?;
+
+            // The template of an Astro component is separated from its frontmatter by an empty line
+            if directive.is_some() || !html.is_empty() {
+                write!(f, [empty_line()])?;
+            }
+        }
+
+        write!(f, [directive.format()])?;
+
+        if let (Some(_), Some(first_token)) = (&directive, html.syntax().first_token()) {
+            match HtmlWhitespace::before(&first_token) {
+                HtmlWhitespace::EmptyLine => write!(f, [empty_line()])?,
+                _ => write!(f, [hard_line_break()])?,
+            }
+        }
+
+        write!(
+            f,
+            [
+                html.format(),
+                format_dangling_comments(node.syntax()),
+                format_removed(&eof_token?),
+                hard_line_break()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(&self, _: &HtmlRoot, _: &mut HtmlFormatter) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
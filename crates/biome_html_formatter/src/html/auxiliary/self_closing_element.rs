@@ -0,0 +1,51 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write, AttributePosition, FormatOptions};
+use biome_html_syntax::{HtmlSelfClosingElement, HtmlSelfClosingElementFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlSelfClosingElement;
+
+impl FormatNodeRule<HtmlSelfClosingElement> for FormatHtmlSelfClosingElement {
+    fn fmt_fields(&self, node: &HtmlSelfClosingElement, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlSelfClosingElementFields {
+            l_angle_token,
+            name,
+            attributes,
+            slash_token,
+            r_angle_token,
+        } = node.as_fields();
+
+        let should_expand = f.options().attribute_position() == AttributePosition::Multiline
+            && attributes.len() > 1;
+
+        let format_attributes = format_with(|f| {
+            if !attributes.is_empty() {
+                write!(
+                    f,
+                    [indent(&format_args![
+                        soft_line_break_or_space(),
+                        attributes.format()
+                    ])]
+                )?;
+            }
+
+            // The void elements, such as `<br>`, don't need the slash, it's kept when it's in the source
+            match &slash_token {
+                Some(slash_token) => write!(f, [soft_line_break_or_space(), slash_token.format()]),
+                None if !attributes.is_empty() => write!(f, [soft_line_break()]),
+                None => Ok(()),
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args![
+                l_angle_token.format(),
+                name.format(),
+                format_attributes,
+                r_angle_token.format()
+            ])
+            .should_expand(should_expand)]
+        )
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{HtmlString, HtmlStringFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlString;
+
+impl FormatNodeRule<HtmlString> for FormatHtmlString {
+    fn fmt_fields(&self, node: &HtmlString, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlStringFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+        let text = value_token.text_trimmed();
+
+        if text.starts_with(['"', '\'']) {
+            return write!(f, [value_token.format()]);
+        }
+
+        // The unquoted values, such as `<img src=logo.png>`, are quoted
+        let quote = if text.contains('"') { '\'' } else { '"' };
+        write!(
+            f,
+            [format_replaced(
+                &value_token,
+                &dynamic_text(
+                    &std::format!("{quote}{text}{quote}"),
+                    value_token.text_trimmed_range().start()
+                )
+            )]
+        )
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_html_syntax::{HtmlTextExpression, HtmlTextExpressionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlTextExpression;
+
+impl FormatNodeRule<HtmlTextExpression> for FormatHtmlTextExpression {
+    fn fmt_fields(&self, node: &HtmlTextExpression, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let HtmlTextExpressionFields { value_token } = node.as_fields();
+
+        // The code of the expression is printed as it is
+        value_token.format().fmt(f)
+    }
+}
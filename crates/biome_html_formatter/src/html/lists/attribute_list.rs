@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_html_syntax::HtmlAttributeList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlAttributeList;
+
+impl FormatRule<HtmlAttributeList> for FormatHtmlAttributeList {
+    type Context = HtmlFormatContext;
+    fn fmt(&self, node: &HtmlAttributeList, f: &mut HtmlFormatter) -> FormatResult<()> {
+        f.join_with(&soft_line_break_or_space())
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
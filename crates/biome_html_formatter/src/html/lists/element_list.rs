@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::HtmlWhitespace;
+use biome_formatter::write;
+use biome_html_syntax::HtmlElementList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatHtmlElementList;
+
+impl FormatRule<HtmlElementList> for FormatHtmlElementList {
+    type Context = HtmlFormatContext;
+    fn fmt(&self, node: &HtmlElementList, f: &mut HtmlFormatter) -> FormatResult<()> {
+        for (index, element) in node.iter().enumerate() {
+            // The whitespace before the first element is formatted by the parent
+            if index > 0 {
+                if let Some(first_token) = element.syntax().first_token() {
+                    write!(f, [HtmlWhitespace::before(&first_token)])?;
+                }
+            }
+
+            write!(f, [format_or_verbatim(element.format())])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::{format_children, HtmlWhitespace};
+use biome_formatter::write;
+use biome_html_syntax::{SvelteEachBlock, SvelteEachBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteEachBlock;
+
+impl FormatNodeRule<SvelteEachBlock> for FormatSvelteEachBlock {
+    fn fmt_fields(&self, node: &SvelteEachBlock, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteEachBlockFields {
+            opening_block,
+            children,
+            else_clause,
+            closing_block,
+        } = node.as_fields();
+        let closing_block = closing_block?;
+
+        write!(f, [opening_block.format(), format_children(&children)])?;
+
+        if let Some(else_clause) = else_clause {
+            let before_clause =
+                HtmlWhitespace::before(&else_clause.sv_curly_colon_token()?).without_empty_line();
+            write!(f, [before_clause, else_clause.format()])?;
+        }
+
+        let before_closing =
+            HtmlWhitespace::before(&closing_block.sv_curly_slash_token()?).without_empty_line();
+        write!(f, [before_closing, closing_block.format()])
+    }
+}
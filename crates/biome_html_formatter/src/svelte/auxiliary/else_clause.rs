@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use crate::utils::format_children;
+use biome_formatter::write;
+use biome_html_syntax::{SvelteElseClause, SvelteElseClauseFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteElseClause;
+
+impl FormatNodeRule<SvelteElseClause> for FormatSvelteElseClause {
+    fn fmt_fields(&self, node: &SvelteElseClause, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteElseClauseFields {
+            sv_curly_colon_token,
+            else_token,
+            r_curly_token,
+            children,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                sv_curly_colon_token.format(),
+                else_token.format(),
+                r_curly_token.format(),
+                format_children(&children)
+            ]
+        )
+    }
+}
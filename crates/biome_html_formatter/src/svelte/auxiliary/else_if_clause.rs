@@ -0,0 +1,34 @@
+use crate::prelude::*;
+use crate::utils::format_children;
+use biome_formatter::write;
+use biome_html_syntax::{SvelteElseIfClause, SvelteElseIfClauseFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteElseIfClause;
+
+impl FormatNodeRule<SvelteElseIfClause> for FormatSvelteElseIfClause {
+    fn fmt_fields(&self, node: &SvelteElseIfClause, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteElseIfClauseFields {
+            sv_curly_colon_token,
+            else_token,
+            if_token,
+            condition,
+            r_curly_token,
+            children,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                sv_curly_colon_token.format(),
+                else_token.format(),
+                space(),
+                if_token.format(),
+                space(),
+                condition.format(),
+                r_curly_token.format(),
+                format_children(&children)
+            ]
+        )
+    }
+}
@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use crate::utils::{format_children, HtmlWhitespace};
+use biome_formatter::write;
+use biome_html_syntax::{SvelteIfBlock, SvelteIfBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteIfBlock;
+
+impl FormatNodeRule<SvelteIfBlock> for FormatSvelteIfBlock {
+    fn fmt_fields(&self, node: &SvelteIfBlock, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteIfBlockFields {
+            opening_block,
+            children,
+            else_if_clauses,
+            else_clause,
+            closing_block,
+        } = node.as_fields();
+        let closing_block = closing_block?;
+
+        write!(
+            f,
+            [
+                opening_block.format(),
+                format_children(&children),
+                else_if_clauses.format()
+            ]
+        )?;
+
+        if let Some(else_clause) = else_clause {
+            let before_clause =
+                HtmlWhitespace::before(&else_clause.sv_curly_colon_token()?).without_empty_line();
+            write!(f, [before_clause, else_clause.format()])?;
+        }
+
+        let before_closing =
+            HtmlWhitespace::before(&closing_block.sv_curly_slash_token()?).without_empty_line();
+        write!(f, [before_closing, closing_block.format()])
+    }
+}
@@ -0,0 +1,28 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_html_syntax::{SvelteIfOpeningBlock, SvelteIfOpeningBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteIfOpeningBlock;
+
+impl FormatNodeRule<SvelteIfOpeningBlock> for FormatSvelteIfOpeningBlock {
+    fn fmt_fields(&self, node: &SvelteIfOpeningBlock, f: &mut HtmlFormatter) -> FormatResult<()> {
+        let SvelteIfOpeningBlockFields {
+            sv_curly_hash_token,
+            if_token,
+            condition,
+            r_curly_token,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                sv_curly_hash_token.format(),
+                if_token.format(),
+                space(),
+                condition.format(),
+                r_curly_token.format()
+            ]
+        )
+    }
+}
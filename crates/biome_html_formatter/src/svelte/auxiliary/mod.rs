@@ -0,0 +1,12 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod each_block;
+pub(crate) mod each_closing_block;
+pub(crate) mod each_index;
+pub(crate) mod each_key;
+pub(crate) mod each_opening_block;
+pub(crate) mod else_clause;
+pub(crate) mod else_if_clause;
+pub(crate) mod if_block;
+pub(crate) mod if_closing_block;
+pub(crate) mod if_opening_block;
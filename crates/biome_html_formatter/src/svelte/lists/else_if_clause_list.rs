@@ -0,0 +1,20 @@
+use crate::prelude::*;
+use crate::utils::HtmlWhitespace;
+use biome_formatter::write;
+use biome_html_syntax::SvelteElseIfClauseList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatSvelteElseIfClauseList;
+
+impl FormatRule<SvelteElseIfClauseList> for FormatSvelteElseIfClauseList {
+    type Context = HtmlFormatContext;
+    fn fmt(&self, node: &SvelteElseIfClauseList, f: &mut HtmlFormatter) -> FormatResult<()> {
+        for clause in node {
+            let before_clause =
+                HtmlWhitespace::before(&clause.sv_curly_colon_token()?).without_empty_line();
+            write!(f, [before_clause, clause.format()])?;
+        }
+
+        Ok(())
+    }
+}
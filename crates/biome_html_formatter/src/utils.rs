@@ -0,0 +1,91 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_html_syntax::{HtmlElementList, HtmlSyntaxToken};
+
+/// The whitespace that separates a token from the previous one in the source.
+///
+/// The whitespace between the children of an element can be rendered as a space,
+/// so the formatter keeps it, and only changes its indentation.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum HtmlWhitespace {
+    /// The token directly follows the previous one, such as `<b>` in `text<b>bold</b>`
+    None,
+    /// The whitespace doesn't contain a line break
+    Space,
+    /// The whitespace contains a line break
+    Newline,
+    /// The whitespace contains two line breaks or more
+    EmptyLine,
+}
+
+impl HtmlWhitespace {
+    /// Returns the whitespace that precedes `token`.
+    ///
+    /// Only the whitespace before the leading comments of the token is considered,
+    /// the comments are formatted with their own line breaks.
+    pub(crate) fn before(token: &HtmlSyntaxToken) -> Self {
+        let mut newlines = 0;
+        // The trailing whitespace of the previous token, when it isn't followed by a comment
+        let mut has_whitespace = token.prev_token().is_some_and(|prev_token| {
+            prev_token
+                .trailing_trivia()
+                .pieces()
+                .last()
+                .is_some_and(|piece| piece.is_whitespace())
+        });
+
+        for piece in token.leading_trivia().pieces() {
+            if piece.is_comments() {
+                break;
+            } else if piece.is_newline() {
+                newlines += 1;
+            } else if piece.is_whitespace() {
+                has_whitespace = true;
+            }
+        }
+
+        match newlines {
+            0 if has_whitespace => Self::Space,
+            0 => Self::None,
+            1 => Self::Newline,
+            _ => Self::EmptyLine,
+        }
+    }
+
+    /// Returns a line break instead of an empty line,
+    /// for the whitespace at the start and at the end of the children of an element.
+    pub(crate) const fn without_empty_line(self) -> Self {
+        match self {
+            Self::EmptyLine => Self::Newline,
+            whitespace => whitespace,
+        }
+    }
+}
+
+impl Format<HtmlFormatContext> for HtmlWhitespace {
+    fn fmt(&self, f: &mut Formatter<HtmlFormatContext>) -> FormatResult<()> {
+        match self {
+            Self::None => Ok(()),
+            Self::Space => write!(f, [space()]),
+            Self::Newline => write!(f, [hard_line_break()]),
+            Self::EmptyLine => write!(f, [empty_line()]),
+        }
+    }
+}
+
+/// Formats the children of an element or of a Svelte block, such as `{#if condition}`, indented.
+///
+/// The whitespace before the first child is kept, without its empty lines.
+pub(crate) fn format_children(children: &HtmlElementList) -> impl Format<HtmlFormatContext> + '_ {
+    format_with(move |f| {
+        if let Some(first_token) = children.syntax().first_token() {
+            let before_children = HtmlWhitespace::before(&first_token).without_empty_line();
+            write!(
+                f,
+                [indent(&format_args![before_children, children.format()])]
+            )?;
+        }
+
+        Ok(())
+    })
+}
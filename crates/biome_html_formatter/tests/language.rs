@@ -0,0 +1,60 @@
+use biome_formatter::{FormatResult, Formatted, Printed};
+use biome_formatter_test::TestFormatLanguage;
+use biome_html_formatter::context::{HtmlFormatContext, HtmlFormatOptions};
+use biome_html_formatter::{format_node, format_range, HtmlFormatLanguage};
+use biome_html_parser::{parse_html, HtmlParserOptions};
+use biome_html_syntax::HtmlLanguage;
+use biome_parser::AnyParse;
+use biome_rowan::{SyntaxNode, TextRange};
+use biome_service::settings::{ServiceLanguage, Settings};
+
+#[derive(Default)]
+pub struct HtmlTestFormatLanguage {
+    options: HtmlParserOptions,
+}
+
+impl HtmlTestFormatLanguage {
+    pub fn new(options: HtmlParserOptions) -> Self {
+        HtmlTestFormatLanguage { options }
+    }
+}
+
+impl TestFormatLanguage for HtmlTestFormatLanguage {
+    type ServiceLanguage = HtmlLanguage;
+    type Context = HtmlFormatContext;
+    type FormatLanguage = HtmlFormatLanguage;
+
+    fn parse(&self, text: &str) -> AnyParse {
+        let parse = parse_html(text, self.options);
+
+        AnyParse::new(parse.syntax().as_send().unwrap(), parse.into_diagnostics())
+    }
+
+    fn to_language_settings<'a>(
+        &self,
+        settings: &'a Settings,
+    ) -> &'a <Self::ServiceLanguage as ServiceLanguage>::FormatterSettings {
+        &settings.languages.html.formatter
+    }
+
+    fn format_node(
+        &self,
+        options: <Self::ServiceLanguage as ServiceLanguage>::FormatOptions,
+        node: &SyntaxNode<Self::ServiceLanguage>,
+    ) -> FormatResult<Formatted<Self::Context>> {
+        format_node(options, node)
+    }
+
+    fn format_range(
+        &self,
+        options: <Self::ServiceLanguage as ServiceLanguage>::FormatOptions,
+        node: &SyntaxNode<Self::ServiceLanguage>,
+        range: TextRange,
+    ) -> FormatResult<Printed> {
+        format_range(options, node, range)
+    }
+
+    fn default_options(&self) -> <Self::ServiceLanguage as ServiceLanguage>::FormatOptions {
+        HtmlFormatOptions::default()
+    }
+}
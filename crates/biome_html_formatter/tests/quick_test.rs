@@ -0,0 +1,39 @@
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_html_formatter::context::HtmlFormatOptions;
+use biome_html_formatter::format_node;
+use biome_html_parser::{parse_html, HtmlParserOptions};
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+<div><p   class=intro>Hello <b>world</b>!</p>
+<br/></div>
+"#;
+    let parser_options = HtmlParserOptions::default();
+    let parse = parse_html(src, parser_options);
+    let options = HtmlFormatOptions::default();
+    let result = format_node(options.clone(), &parse.syntax())
+        .unwrap()
+        .print()
+        .unwrap();
+
+    let root = &parse.syntax();
+    let language = language::HtmlTestFormatLanguage::new(parser_options);
+
+    let check_reformat =
+        CheckReformat::new(root, result.as_code(), "quick_test", &language, options);
+    check_reformat.check_reformat();
+
+    assert_eq!(
+        result.as_code(),
+        r#"<div><p class="intro">Hello <b>world</b>!</p>
+	<br /></div>
+"#
+    );
+}
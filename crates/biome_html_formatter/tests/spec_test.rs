@@ -0,0 +1,47 @@
+use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
+use biome_html_formatter::context::HtmlFormatOptions;
+use biome_html_parser::HtmlParserOptions;
+use std::path::Path;
+
+mod language {
+    include!("language.rs");
+}
+
+/// [insta.rs](https://insta.rs/docs) snapshot testing
+///
+/// For better development workflow, run
+/// `cargo watch -i '*.new' -x 'test -p biome_html_formatter formatter'`
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
+/// the input file name is `{spec_name}.html` and the output file name is `{spec_name}.html.snap`.
+///
+/// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
+///
+/// # Examples
+///
+/// * `html/elements` -> input: `tests/specs/html/elements.html`, expected output: `tests/specs/html/elements.html.snap`
+/// * `elements` -> input: `tests/specs/elements.html`, expected output: `tests/specs/elements.html.snap`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
+
+    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path) else {
+        return;
+    };
+
+    let extension = test_file.input_file().as_path().extension();
+    let parser_options = match extension.and_then(|extension| extension.to_str()) {
+        Some("svelte") => HtmlParserOptions::default().svelte(),
+        Some("astro") => HtmlParserOptions::default().astro(),
+        _ => HtmlParserOptions::default(),
+    };
+
+    let options = HtmlFormatOptions::default();
+    let language = language::HtmlTestFormatLanguage::new(parser_options);
+
+    let snapshot = SpecSnapshot::new(test_file, test_directory, language, options);
+
+    snapshot.test()
+}
@@ -0,0 +1,17 @@
+mod quick_test;
+mod spec_test;
+
+mod formatter {
+
+    mod html_module {
+        tests_macros::gen_tests! {"tests/specs/html/**/*.html", crate::spec_test::run, ""}
+    }
+
+    mod astro_module {
+        tests_macros::gen_tests! {"tests/specs/astro/**/*.astro", crate::spec_test::run, ""}
+    }
+
+    mod svelte_module {
+        tests_macros::gen_tests! {"tests/specs/svelte/**/*.svelte", crate::spec_test::run, ""}
+    }
+}
@@ -1,6 +1,7 @@
 mod tests;
 
-use crate::token_source::HtmlLexContext;
+use crate::token_source::{HtmlLexContext, TextExpressionEnd};
+use crate::HtmlParserOptions;
 use biome_html_syntax::HtmlSyntaxKind::{
     COMMENT, DOCTYPE_KW, EOF, ERROR_TOKEN, HTML_LITERAL, HTML_STRING_LITERAL, NEWLINE, TOMBSTONE,
     UNICODE_BOM, WHITESPACE,
@@ -9,9 +10,6 @@ use biome_html_syntax::{HtmlSyntaxKind, TextLen, TextSize, T};
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_parser::lexer::{Lexer, LexerCheckpoint, LexerWithCheckpoint, TokenFlags};
 use biome_rowan::SyntaxKind;
-use biome_unicode_table::lookup_byte;
-use biome_unicode_table::Dispatch::{BSL, QOT, UNI, WHS};
-use std::ops::Add;
 
 pub(crate) struct HtmlLexer<'src> {
     /// Source text
@@ -33,6 +31,8 @@ pub(crate) struct HtmlLexer<'src> {
     after_newline: bool,
 
     unicode_bom_length: usize,
+
+    options: HtmlParserOptions,
 }
 
 impl<'src> HtmlLexer<'src> {
@@ -47,8 +47,14 @@ impl<'src> HtmlLexer<'src> {
             after_newline: false,
             current_flags: TokenFlags::empty(),
             unicode_bom_length: 0,
+            options: HtmlParserOptions::default(),
         }
     }
+
+    pub(crate) fn with_options(self, options: HtmlParserOptions) -> Self {
+        Self { options, ..self }
+    }
+
     fn consume_token(&mut self, current: u8) -> HtmlSyntaxKind {
         match current {
             b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
@@ -58,7 +64,11 @@ impl<'src> HtmlLexer<'src> {
             b'!' => self.consume_byte(T![!]),
             b'=' => self.consume_byte(T![=]),
             b'\'' | b'"' => self.consume_string_literal(current),
-            _ if is_identifier_byte(current) => self.consume_identifier(current),
+            b'{' if self.options.has_text_expressions() => self.consume_byte(T!['{']),
+            b'}' if self.options.has_text_expressions() => self.consume_byte(T!['}']),
+            b'(' if self.options.svelte => self.consume_byte(T!['(']),
+            b')' if self.options.svelte => self.consume_byte(T![')']),
+            b',' if self.options.svelte => self.consume_byte(T![,]),
             _ => {
                 if self.position == 0 {
                     if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
@@ -66,90 +76,307 @@ impl<'src> HtmlLexer<'src> {
                         return bom;
                     }
                 }
-                self.consume_unexpected_character()
+                self.consume_name(current)
             }
         }
     }
 
+    /// Lexes the content of an element: its text, and the `<` of its children and of its closing element.
+    ///
+    /// The whitespaces around the text are trivia.
     fn consume_element_list_token(&mut self, current: u8) -> HtmlSyntaxKind {
         debug_assert!(!self.is_eof());
         match current {
-            b'<' => self.consume_byte(T![<]),
+            b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
+            b'<' if self.at_start_comment() => self.consume_comment(),
+            b'<' if self.at_start_tag() => self.consume_byte(T![<]),
+            b'{' if self.options.svelte => self.consume_svelte_l_curly(),
+            b'{' if self.options.astro => self.consume_byte(T!['{']),
+            b'-' if self.options.astro
+                && self.at_start_of_file()
+                && self.at_frontmatter_fence() =>
+            {
+                self.consume_fence()
+            }
+            _ => {
+                if self.position == 0 {
+                    if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
+                        self.unicode_bom_length = bom_size;
+                        return bom;
+                    }
+                }
+                self.consume_text()
+            }
+        }
+    }
+
+    /// Lexes the value of an attribute, which can be quoted or unquoted.
+    fn consume_attribute_value_token(&mut self, current: u8) -> HtmlSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
+            b'>' => self.consume_byte(T![>]),
+            b'\'' | b'"' => self.consume_string_literal(current),
+            b'{' if self.options.has_text_expressions() => self.consume_byte(T!['{']),
             _ => {
-                while let Some(chr) = self.current_byte() {
-                    match chr {
-                        b'<' => break,
-                        chr => {
-                            if chr.is_ascii() {
-                                self.advance(1);
-                            } else {
-                                self.advance_char_unchecked();
-                            }
-                        }
+                while let Some(byte) = self.current_byte() {
+                    match byte {
+                        b'\n' | b'\r' | b'\t' | b' ' | b'>' => break,
+                        _ => self.advance_byte_or_char(byte),
                     }
                 }
+                HTML_STRING_LITERAL
+            }
+        }
+    }
+
+    /// Lexes the content of an element whose content isn't HTML, such as `<script>` or `<style>`,
+    /// up to the closing element of `name`.
+    fn consume_raw_text_token(&mut self, name: &str) -> HtmlSyntaxKind {
+        if self.at_closing_element(name) {
+            return self.consume_byte(T![<]);
+        }
 
-                HTML_LITERAL
+        while let Some(byte) = self.current_byte() {
+            if byte == b'<' && self.at_closing_element(name) {
+                break;
             }
+            self.advance_byte_or_char(byte);
         }
+
+        HTML_LITERAL
     }
 
-    #[allow(unused)]
-    fn consume_element_token(&mut self, current: u8) {}
+    /// Lexes the code of an expression of a Svelte or an Astro component, such as `user.name` in `{user.name}`,
+    /// up to the token that ends it. The whitespaces around the code are trivia.
+    ///
+    /// The brackets, the strings and the template literals of the code are skipped,
+    /// so that the `}` of `{{ a: 1 }}` is the one that ends the expression.
+    fn consume_text_expression_token(
+        &mut self,
+        current: u8,
+        end: TextExpressionEnd,
+    ) -> HtmlSyntaxKind {
+        if matches!(current, b'\n' | b'\r' | b'\t' | b' ') {
+            return self.consume_newline_or_whitespaces();
+        }
+        if self.at_text_expression_end(end) {
+            return self.consume_token(current);
+        }
 
-    /// Bumps the current byte and creates a lexed token of the passed in kind.
-    #[inline]
-    fn consume_byte(&mut self, tok: HtmlSyntaxKind) -> HtmlSyntaxKind {
+        let mut depth = 0usize;
+        let mut code_end = self.position;
+
+        while let Some(byte) = self.current_byte() {
+            if depth == 0 && self.at_text_expression_end(end) {
+                break;
+            }
+            match byte {
+                b'(' | b'[' | b'{' => depth += 1,
+                b')' | b']' | b'}' => depth = depth.saturating_sub(1),
+                b'\'' | b'"' | b'`' => {
+                    self.skip_js_string(byte);
+                    code_end = self.position;
+                    continue;
+                }
+                _ => {}
+            }
+            self.advance_byte_or_char(byte);
+            if !matches!(byte, b'\n' | b'\r' | b'\t' | b' ') {
+                code_end = self.position;
+            }
+        }
+
+        self.position = code_end;
+        HTML_LITERAL
+    }
+
+    /// Returns `true` if the lexer is at the token that ends a text expression, outside of its brackets.
+    fn at_text_expression_end(&self, end: TextExpressionEnd) -> bool {
+        let Some(byte) = self.current_byte() else {
+            return false;
+        };
+        match end {
+            TextExpressionEnd::RCurly => byte == b'}',
+            TextExpressionEnd::As => byte == b'}' || self.at_as_keyword(),
+            TextExpressionEnd::EachItem => matches!(byte, b',' | b'(' | b'}'),
+            TextExpressionEnd::RParen => matches!(byte, b')' | b'}'),
+        }
+    }
+
+    /// Returns `true` if the lexer is at the `as` keyword of `{#each items as item}`,
+    /// which is surrounded by whitespaces.
+    fn at_as_keyword(&self) -> bool {
+        let bytes = self.source.as_bytes();
+        bytes[self.position..].starts_with(b"as")
+            && self.position > 0
+            && bytes[self.position - 1].is_ascii_whitespace()
+            && bytes
+                .get(self.position + 2)
+                .is_some_and(|byte| byte.is_ascii_whitespace())
+    }
+
+    /// Skips a string or a template literal of the code of an expression, with its escape sequences.
+    fn skip_js_string(&mut self, quote: u8) {
         self.advance(1);
-        tok
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'\\' => {
+                    self.advance(1);
+                    if let Some(escaped) = self.current_byte() {
+                        self.advance_byte_or_char(escaped);
+                    }
+                }
+                _ if byte == quote => {
+                    self.advance(1);
+                    return;
+                }
+                _ => self.advance_byte_or_char(byte),
+            }
+        }
     }
 
-    fn consume_unexpected_character(&mut self) -> HtmlSyntaxKind {
-        self.assert_at_char_boundary();
+    /// Lexes the `{` of a Svelte component in the content of an element:
+    /// the start of the `{#if}` and `{#each}` blocks, of their clauses and of their end,
+    /// or the `{` of a text expression.
+    fn consume_svelte_l_curly(&mut self) -> HtmlSyntaxKind {
+        self.assert_byte(b'{');
+        let rest = &self.source.as_bytes()[self.position + 1..];
+        let is_keyword = |keyword: &[u8]| {
+            rest.get(1..)
+                .and_then(|rest| rest.strip_prefix(keyword))
+                .is_some_and(|after| {
+                    after
+                        .first()
+                        .map_or(true, |byte| !is_svelte_name_byte(*byte))
+                })
+        };
+
+        match rest.first() {
+            Some(b'#') if is_keyword(b"if") || is_keyword(b"each") => {
+                self.advance(2);
+                T!["{#"]
+            }
+            Some(b':') if is_keyword(b"else") => {
+                self.advance(2);
+                T!["{:"]
+            }
+            Some(b'/') if is_keyword(b"if") || is_keyword(b"each") => {
+                self.advance(2);
+                T!["{/"]
+            }
+            _ => self.consume_byte(T!['{']),
+        }
+    }
 
-        let char = self.current_char_unchecked();
-        let err = ParseDiagnostic::new(
-            format!("Unexpected character `{}`", char),
-            self.text_position()..self.text_position() + char.text_len(),
-        );
-        self.diagnostics.push(err);
-        self.advance(char.len_utf8());
+    /// Lexes the frontmatter of an Astro component: the `---` fence that ends it,
+    /// or its code up to that fence. The whitespaces around the code are trivia.
+    fn consume_frontmatter_token(&mut self, current: u8) -> HtmlSyntaxKind {
+        if matches!(current, b'\n' | b'\r' | b'\t' | b' ') {
+            return self.consume_newline_or_whitespaces();
+        }
+        if self.at_frontmatter_fence() {
+            return self.consume_fence();
+        }
 
-        ERROR_TOKEN
+        let mut code_end = self.position;
+
+        while let Some(byte) = self.current_byte() {
+            if byte == b'-' && self.at_frontmatter_fence() {
+                break;
+            }
+            self.advance_byte_or_char(byte);
+            if !matches!(byte, b'\n' | b'\r' | b'\t' | b' ') {
+                code_end = self.position;
+            }
+        }
+
+        self.position = code_end;
+        HTML_LITERAL
     }
 
-    /// Asserts that the lexer is at a UTF8 char boundary
-    #[inline]
-    fn assert_at_char_boundary(&self) {
-        debug_assert!(self.source.is_char_boundary(self.position));
+    fn consume_fence(&mut self) -> HtmlSyntaxKind {
+        self.assert_byte(b'-');
+        self.advance(3);
+        T![---]
     }
 
-    fn consume_identifier(&mut self, first: u8) -> HtmlSyntaxKind {
-        self.assert_current_char_boundary();
+    /// Returns `true` if the lexer is at a `---` fence of the frontmatter of an Astro component,
+    /// which is alone on its line.
+    fn at_frontmatter_fence(&self) -> bool {
+        let bytes = self.source.as_bytes();
+        let at_line_start = self.position == 0 || matches!(bytes[self.position - 1], b'\n' | b'\r');
+        let Some(after_fence) = bytes[self.position..].strip_prefix(b"---") else {
+            return false;
+        };
 
-        const BUFFER_SIZE: usize = 14;
-        let mut buffer = [0u8; BUFFER_SIZE];
-        buffer[0] = first;
-        let mut len = 1;
+        at_line_start
+            && after_fence
+                .iter()
+                .find(|byte| !matches!(byte, b'\t' | b' '))
+                .map_or(true, |byte| matches!(byte, b'\n' | b'\r'))
+    }
 
-        self.advance_byte_or_char(first);
+    /// Returns `true` if only whitespaces, and the byte order mark, precede the current position.
+    fn at_start_of_file(&self) -> bool {
+        self.source[..self.position]
+            .trim_start_matches('\u{feff}')
+            .bytes()
+            .all(|byte| byte.is_ascii_whitespace())
+    }
+
+    /// Consumes the text up to the next tag or comment, without its trailing whitespaces.
+    fn consume_text(&mut self) -> HtmlSyntaxKind {
+        let mut end = self.position;
 
         while let Some(byte) = self.current_byte() {
-            if is_identifier_byte(byte) {
-                if len < BUFFER_SIZE {
-                    buffer[len] = byte;
-                    len += 1;
+            match byte {
+                b'<' if self.at_start_tag() || self.at_start_comment() => break,
+                // The text expressions, and the blocks of a Svelte component
+                b'{' if self.options.has_text_expressions() => break,
+                b'\n' | b'\r' | b'\t' | b' ' => self.advance(1),
+                _ => {
+                    self.advance_byte_or_char(byte);
+                    end = self.position;
                 }
+            }
+        }
+
+        self.position = end;
+        HTML_LITERAL
+    }
 
-                self.advance(1)
+    /// Bumps the current byte and creates a lexed token of the passed in kind.
+    #[inline]
+    fn consume_byte(&mut self, tok: HtmlSyntaxKind) -> HtmlSyntaxKind {
+        self.advance(1);
+        tok
+    }
+
+    fn consume_name(&mut self, first: u8) -> HtmlSyntaxKind {
+        self.assert_current_char_boundary();
+
+        let start = self.position;
+        self.advance_byte_or_char(first);
+
+        while let Some(byte) = self.current_byte() {
+            let is_name_byte = if self.options.svelte {
+                is_svelte_name_byte(byte)
+            } else if self.options.astro {
+                is_astro_name_byte(byte)
+            } else {
+                is_name_byte(byte)
+            };
+            if is_name_byte {
+                self.advance_byte_or_char(byte);
             } else {
                 break;
             }
         }
 
-        match &buffer[..len] {
-            b"doctype" | b"DOCTYPE" => DOCTYPE_KW,
-            _ => HTML_LITERAL,
+        if self.source[start..self.position].eq_ignore_ascii_case("doctype") {
+            DOCTYPE_KW
+        } else {
+            HTML_LITERAL
         }
     }
 
@@ -158,84 +385,24 @@ impl<'src> HtmlLexer<'src> {
         let start = self.text_position();
 
         self.advance(1); // Skip over the quote
-        let mut state = LexStringState::InString;
 
+        // The values of the attributes don't have escape sequences, and they can span multiple lines
         while let Some(chr) = self.current_byte() {
-            let dispatch = lookup_byte(chr);
-
-            match dispatch {
-                QOT if quote == chr => {
-                    self.advance(1);
-                    state = match state {
-                        LexStringState::InString => LexStringState::Terminated,
-                        state => state,
-                    };
-                    break;
-                }
-                // '\t' etc
-                BSL => {
-                    self.advance(1);
-
-                    match self.current_byte() {
-                        Some(b'\n' | b'\r') => self.advance(1),
-
-                        // Handle escaped `'` but only if this is a end quote string.
-                        Some(b'\'') if quote == b'\'' => {
-                            self.advance(1);
-                        }
-
-                        // Handle escaped `'` but only if this is a end quote string.
-                        Some(b'"') if quote == b'"' => {
-                            self.advance(1);
-                        }
-
-                        Some(b'u') => match (self.consume_unicode_escape(), state) {
-                            (Ok(_), _) => {}
-                            (Err(err), LexStringState::InString) => {
-                                self.diagnostics.push(err);
-                                state = LexStringState::InvalidEscapeSequence;
-                            }
-                            (Err(_), _) => {}
-                        },
-
-                        Some(chr) => {
-                            self.advance_byte_or_char(chr);
-                        }
-
-                        None => {}
-                    }
-                }
-                WHS if matches!(chr, b'\n' | b'\r') => {
-                    let unterminated =
-                        ParseDiagnostic::new("Missing closing quote", start..self.text_position())
-                            .with_hint("The closing quote must be on the same line.");
-
-                    self.diagnostics.push(unterminated);
-
-                    return ERROR_TOKEN;
-                }
-                // we don't need to handle IDT because it's always len 1.
-                UNI => self.advance_char_unchecked(),
-
-                _ => self.advance(1),
+            if chr == quote {
+                self.advance(1);
+                return HTML_STRING_LITERAL;
             }
+            self.advance_byte_or_char(chr);
         }
 
-        match state {
-            LexStringState::Terminated => HTML_STRING_LITERAL,
-            LexStringState::InString => {
-                let unterminated =
-                    ParseDiagnostic::new("Missing closing quote", start..self.text_position())
-                        .with_detail(
-                            self.source.text_len()..self.source.text_len(),
-                            "file ends here",
-                        );
-                self.diagnostics.push(unterminated);
-
-                ERROR_TOKEN
-            }
-            LexStringState::InvalidEscapeSequence => ERROR_TOKEN,
-        }
+        let unterminated =
+            ParseDiagnostic::new("Missing closing quote", start..self.text_position()).with_detail(
+                self.source.text_len()..self.source.text_len(),
+                "file ends here",
+            );
+        self.diagnostics.push(unterminated);
+
+        ERROR_TOKEN
     }
 
     fn consume_l_angle(&mut self) -> HtmlSyntaxKind {
@@ -249,6 +416,7 @@ impl<'src> HtmlLexer<'src> {
     }
 
     fn consume_comment(&mut self) -> HtmlSyntaxKind {
+        let start = self.text_position();
         // eat <!--
         self.advance(4);
 
@@ -261,6 +429,15 @@ impl<'src> HtmlLexer<'src> {
             self.advance_byte_or_char(char);
         }
 
+        let unterminated =
+            ParseDiagnostic::new("Unterminated comment", start..self.text_position())
+                .with_detail(
+                    self.source.text_len()..self.source.text_len(),
+                    "file ends here",
+                )
+                .with_hint("A comment ends with `-->`.");
+        self.diagnostics.push(unterminated);
+
         COMMENT
     }
 
@@ -271,59 +448,32 @@ impl<'src> HtmlLexer<'src> {
             && self.byte_at(3) == Some(b'-')
     }
 
+    /// Returns `true` if the lexer is at the `<` of a tag, which is followed by the name of an element,
+    /// by `/` or by `!`. Any other `<` is part of the text.
+    fn at_start_tag(&self) -> bool {
+        self.current_byte() == Some(b'<')
+            && self
+                .byte_at(1)
+                .is_some_and(|byte| byte.is_ascii_alphabetic() || matches!(byte, b'/' | b'!'))
+    }
+
+    /// Returns `true` if the lexer is at the closing element of `name`, such as `</script>`.
+    fn at_closing_element(&self, name: &str) -> bool {
+        let rest = &self.source.as_bytes()[self.position..];
+        rest.starts_with(b"</")
+            && rest
+                .get(2..2 + name.len())
+                .is_some_and(|tag| tag.eq_ignore_ascii_case(name.as_bytes()))
+            && rest.get(2 + name.len()).map_or(true, |byte| {
+                matches!(byte, b'\n' | b'\r' | b'\t' | b' ' | b'/' | b'>')
+            })
+    }
+
     fn at_end_comment(&mut self) -> bool {
         self.current_byte() == Some(b'-')
             && self.byte_at(1) == Some(b'-')
             && self.byte_at(2) == Some(b'>')
     }
-
-    /// Lexes a `\u0000` escape sequence. Assumes that the lexer is positioned at the `u` token.
-    ///
-    /// A unicode escape sequence must consist of 4 hex characters.
-    fn consume_unicode_escape(&mut self) -> Result<(), ParseDiagnostic> {
-        self.assert_byte(b'u');
-        self.assert_current_char_boundary();
-
-        let start = self.text_position();
-
-        let start = start
-            // Subtract 1 to get position of `\`
-            .checked_sub(TextSize::from(1))
-            .unwrap_or(start);
-
-        self.advance(1); // Advance over `u`
-
-        for _ in 0..4 {
-            match self.current_byte() {
-                Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
-                Some(_) => {
-                    let char = self.current_char_unchecked();
-                    // Reached a non-hex digit which is invalid
-                    return Err(ParseDiagnostic::new(
-
-                        "Invalid unicode sequence",
-                        start..self.text_position(),
-                    )
-                        .with_detail(self.text_position()..self.text_position().add(char.text_len()), "Non hexadecimal number")
-                        .with_hint("A unicode escape sequence must consist of 4 hexadecimal numbers: `\\uXXXX`, e.g. `\\u002F' for '/'."));
-                }
-                None => {
-                    // Reached the end of the file before processing 4 hex digits
-                    return Err(ParseDiagnostic::new(
-                        "Unicode escape sequence with two few hexadecimal numbers.",
-                        start..self.text_position(),
-                    )
-                        .with_detail(
-                            self.text_position()..self.text_position(),
-                            "reached the end of the file",
-                        )
-                        .with_hint("A unicode escape sequence must consist of 4 hexadecimal numbers: `\\uXXXX`, e.g. `\\u002F' for '/'."));
-                }
-            }
-        }
-
-        Ok(())
-    }
 }
 
 impl<'src> Lexer<'src> for HtmlLexer<'src> {
@@ -356,6 +506,12 @@ impl<'src> Lexer<'src> for HtmlLexer<'src> {
                 Some(current) => match context {
                     HtmlLexContext::Regular => self.consume_token(current),
                     HtmlLexContext::ElementList => self.consume_element_list_token(current),
+                    HtmlLexContext::AttributeValue => self.consume_attribute_value_token(current),
+                    HtmlLexContext::RawText(name) => self.consume_raw_text_token(name),
+                    HtmlLexContext::TextExpression(end) => {
+                        self.consume_text_expression_token(current, end)
+                    }
+                    HtmlLexContext::AstroFrontmatter => self.consume_frontmatter_token(current),
                 },
                 None => EOF,
             }
@@ -423,20 +579,24 @@ impl<'src> Lexer<'src> for HtmlLexer<'src> {
     }
 }
 
-fn is_identifier_byte(byte: u8) -> bool {
-    byte.is_ascii_alphanumeric()
+/// Returns `true` if `byte` can be part of the name of an element or of an attribute.
+fn is_name_byte(byte: u8) -> bool {
+    !matches!(
+        byte,
+        b'\n' | b'\r' | b'\t' | b' ' | b'"' | b'\'' | b'<' | b'>' | b'/' | b'=' | b'!'
+    )
 }
 
-#[derive(Copy, Clone, Debug)]
-enum LexStringState {
-    /// String that contains an invalid escape sequence
-    InvalidEscapeSequence,
-
-    /// Between the opening `"` and closing `"` quotes.
-    InString,
+/// Returns `true` if `byte` can be part of a name in a Svelte component,
+/// where the brackets and the commas of the expressions and of the blocks are tokens.
+fn is_svelte_name_byte(byte: u8) -> bool {
+    is_name_byte(byte) && !matches!(byte, b'{' | b'}' | b'(' | b')' | b',')
+}
 
-    /// Properly terminated string
-    Terminated,
+/// Returns `true` if `byte` can be part of a name in an Astro component,
+/// where the curly braces of the expressions are tokens.
+fn is_astro_name_byte(byte: u8) -> bool {
+    is_name_byte(byte) && !matches!(byte, b'{' | b'}')
 }
 
 impl<'src> LexerWithCheckpoint<'src> for HtmlLexer<'src> {
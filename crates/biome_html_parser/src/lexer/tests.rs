@@ -195,3 +195,19 @@ fn element_with_attributes() {
         R_ANGLE: 1,
     }
 }
+
+#[test]
+fn attribute_name_with_dashes() {
+    assert_lex! {
+        "aria-label",
+        HTML_LITERAL: 10,
+    }
+}
+
+#[test]
+fn string_literal_on_multiple_lines() {
+    assert_lex! {
+        "\"first\nsecond\"",
+        HTML_STRING_LITERAL: 14,
+    }
+}
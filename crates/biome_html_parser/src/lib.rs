@@ -1,8 +1,10 @@
 mod lexer;
 mod parser;
+mod state;
 mod syntax;
 mod token_source;
 
+pub use crate::parser::HtmlParserOptions;
 use crate::parser::{HtmlLosslessTreeSink, HtmlParser};
 use crate::syntax::parse_root;
 use biome_html_syntax::{HtmlRoot, HtmlSyntaxNode};
@@ -10,9 +12,13 @@ use biome_parser::diagnostic::ParseDiagnostic;
 use biome_rowan::{AstNode, NodeCache};
 
 /// Parses the provided string as HTML program using the provided node cache.
-pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
+pub fn parse_html_with_cache(
+    source: &str,
+    cache: &mut NodeCache,
+    options: HtmlParserOptions,
+) -> HtmlParse {
     tracing::debug_span!("Parsing phase").in_scope(move || {
-        let mut parser = HtmlParser::new(source);
+        let mut parser = HtmlParser::new(source, options);
 
         parse_root(&mut parser);
 
@@ -25,9 +31,9 @@ pub fn parse_html_with_cache(source: &str, cache: &mut NodeCache) -> HtmlParse {
         HtmlParse::new(green, diagnostics)
     })
 }
-pub fn parse_html(source: &str) -> HtmlParse {
+pub fn parse_html(source: &str, options: HtmlParserOptions) -> HtmlParse {
     let mut cache = NodeCache::default();
-    parse_html_with_cache(source, &mut cache)
+    parse_html_with_cache(source, &mut cache, options)
 }
 
 /// A utility struct for managing the result of a parser job
@@ -45,18 +51,19 @@ impl HtmlParse {
     /// The syntax node represented by this Parse result
     ///
     /// ```
-    /// # use biome_html_parser::parse_html;
+    /// # use biome_html_parser::{parse_html, HtmlParserOptions};
     /// # use biome_html_syntax::HtmlSyntaxKind;
     /// # use biome_rowan::{AstNode, AstNodeList, SyntaxError};
     ///
     /// # fn main() -> Result<(), SyntaxError> {
     /// use biome_html_syntax::HtmlSyntaxKind;
-    /// // let parse = parse_html(r#"<html></html>"#);
+    /// let parse = parse_html(r#"<html></html>"#, HtmlParserOptions::default());
     ///
     /// // Get the root value
-    /// // let root_value = parse.tree().html()?;
+    /// let root_value = parse.tree().html();
     ///
-    /// // assert_eq!(root_value.syntax().kind(), HtmlSyntaxKind::HTML_ELEMENT);
+    /// assert_eq!(root_value.syntax().kind(), HtmlSyntaxKind::HTML_ELEMENT_LIST);
+    /// assert_eq!(root_value.len(), 1);
     ///
     /// # Ok(())
     /// # }
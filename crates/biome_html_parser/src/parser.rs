@@ -1,3 +1,4 @@
+use crate::state::HtmlParserState;
 use crate::token_source::HtmlTokenSource;
 use biome_html_factory::HtmlSyntaxFactory;
 use biome_html_syntax::{HtmlLanguage, HtmlSyntaxKind};
@@ -13,16 +14,54 @@ pub(crate) type HtmlLosslessTreeSink<'source> =
 pub(crate) struct HtmlParser<'source> {
     context: ParserContext<HtmlSyntaxKind>,
     source: HtmlTokenSource<'source>,
+    state: HtmlParserState,
+}
+
+/// Options to pass to the HTML parser
+#[derive(Default, Debug, Clone, Copy)]
+pub struct HtmlParserOptions {
+    /// Enables the syntax of the Svelte components: the text expressions such as `{user.name}`,
+    /// the values of the attributes such as `value={name}`, and the `{#if}` and `{#each}` blocks.
+    pub svelte: bool,
+    /// Enables the syntax of the Astro components: the frontmatter between the `---` fences
+    /// at the start of the file, and the text expressions such as `{title}`.
+    pub astro: bool,
+}
+
+impl HtmlParserOptions {
+    pub fn svelte(mut self) -> Self {
+        self.svelte = true;
+        self
+    }
+
+    pub fn astro(mut self) -> Self {
+        self.astro = true;
+        self
+    }
+
+    /// Returns `true` if the text expressions between curly braces are enabled
+    pub(crate) fn has_text_expressions(&self) -> bool {
+        self.svelte || self.astro
+    }
 }
 
 impl<'source> HtmlParser<'source> {
-    pub fn new(source: &'source str) -> Self {
+    pub fn new(source: &'source str, options: HtmlParserOptions) -> Self {
         Self {
             context: ParserContext::default(),
-            source: HtmlTokenSource::from_str(source),
+            source: HtmlTokenSource::from_str(source, options),
+            state: HtmlParserState::new(),
         }
     }
 
+    pub(crate) fn state(&self) -> &HtmlParserState {
+        &self.state
+    }
+
+    pub(crate) fn state_mut(&mut self) -> &mut HtmlParserState {
+        &mut self.state
+    }
+
     pub fn finish(
         self,
     ) -> (
@@ -0,0 +1,29 @@
+pub(crate) struct HtmlParserState {
+    /// The names of the elements that contain the current position, from the outermost to the innermost one,
+    /// in lowercase.
+    ///
+    /// A closing element whose name matches one of them closes the elements up to it,
+    /// the other closing elements don't have an opening element.
+    pub(crate) open_elements: Vec<String>,
+
+    /// The number of the Svelte blocks that contain the current position, such as `{#if condition}`.
+    ///
+    /// The clauses and the end of a block, such as `{:else}` and `{/if}`, end the content of the elements of the block.
+    pub(crate) open_svelte_blocks: usize,
+}
+
+impl HtmlParserState {
+    pub fn new() -> Self {
+        Self {
+            open_elements: Vec::new(),
+            open_svelte_blocks: 0,
+        }
+    }
+
+    /// Returns `true` if `name` is the name of one of the open elements.
+    pub(crate) fn is_open(&self, name: &str) -> bool {
+        self.open_elements
+            .iter()
+            .any(|open_element| open_element.eq_ignore_ascii_case(name))
+    }
+}
@@ -0,0 +1,35 @@
+use crate::parser::HtmlParser;
+use crate::syntax::parse_error::unclosed_astro_frontmatter;
+use crate::token_source::HtmlLexContext;
+use biome_html_syntax::HtmlSyntaxKind::{ASTRO_FRONTMATTER_ELEMENT, HTML_LITERAL};
+use biome_html_syntax::T;
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+use biome_parser::Parser;
+
+/// Parses the frontmatter of an Astro component, the code between the `---` fences at the start of the file.
+///
+/// ```astro
+/// ---
+/// const title = "Astro";
+/// ---
+/// ```
+pub(crate) fn parse_astro_frontmatter(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T![---]) {
+        return Absent;
+    }
+    let m = p.start();
+
+    let opening_fence = p.cur_range();
+    p.bump_with_context(T![---], HtmlLexContext::AstroFrontmatter);
+    p.eat_with_context(HTML_LITERAL, HtmlLexContext::AstroFrontmatter);
+
+    if p.at(T![---]) {
+        p.bump_with_context(T![---], HtmlLexContext::ElementList);
+    } else {
+        p.error(unclosed_astro_frontmatter(p, opening_fence));
+    }
+
+    Present(m.complete(p, ASTRO_FRONTMATTER_ELEMENT))
+}
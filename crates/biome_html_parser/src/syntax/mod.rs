@@ -1,13 +1,22 @@
+mod astro;
 mod parse_error;
+mod svelte;
 
 use crate::parser::HtmlParser;
-use crate::syntax::parse_error::{expected_attribute, expected_child};
+use crate::syntax::astro::parse_astro_frontmatter;
+use crate::syntax::parse_error::{
+    expected_attribute, expected_attribute_value, expected_child, expected_element_name,
+    stray_closing_element, unclosed_element,
+};
+use crate::syntax::svelte::{
+    parse_single_text_expression, parse_stray_svelte_clause, parse_svelte_block,
+};
 use crate::token_source::HtmlLexContext;
 use biome_html_syntax::HtmlSyntaxKind::{
-    HTML_ATTRIBUTE, HTML_ATTRIBUTE_INITIALIZER_CLAUSE, HTML_ATTRIBUTE_LIST, HTML_BOGUS_ELEMENT,
-    HTML_CLOSING_ELEMENT, HTML_CONTENT, HTML_DIRECTIVE, HTML_ELEMENT, HTML_ELEMENT_LIST,
-    HTML_LITERAL, HTML_NAME, HTML_OPENING_ELEMENT, HTML_ROOT, HTML_SELF_CLOSING_ELEMENT,
-    HTML_STRING, HTML_STRING_LITERAL, UNICODE_BOM,
+    EOF, HTML_ATTRIBUTE, HTML_ATTRIBUTE_INITIALIZER_CLAUSE, HTML_ATTRIBUTE_LIST,
+    HTML_BOGUS_ATTRIBUTE, HTML_BOGUS_ELEMENT, HTML_CLOSING_ELEMENT, HTML_CONTENT, HTML_DIRECTIVE,
+    HTML_ELEMENT, HTML_ELEMENT_LIST, HTML_LITERAL, HTML_NAME, HTML_OPENING_ELEMENT, HTML_ROOT,
+    HTML_SELF_CLOSING_ELEMENT, HTML_STRING, HTML_STRING_LITERAL, UNICODE_BOM,
 };
 use biome_html_syntax::{HtmlSyntaxKind, T};
 use biome_parser::parse_lists::ParseNodeList;
@@ -17,15 +26,34 @@ use biome_parser::prelude::ParsedSyntax::Absent;
 use biome_parser::prelude::*;
 use biome_parser::Parser;
 
-const RECOVER_ATTRIBUTE_LIST: TokenSet<HtmlSyntaxKind> = token_set!(T![>], T![<], T![/]);
+const RECOVER_ATTRIBUTE_LIST: TokenSet<HtmlSyntaxKind> = token_set!(T![>], T![<], T![/], T!['{']);
+
+const RECOVER_ELEMENT_LIST: TokenSet<HtmlSyntaxKind> =
+    token_set!(T![<], T!['{'], T!["{#"], T!["{:"], T!["{/"]);
+
+/// The elements that can't have any content, they don't have a closing element.
+///
+/// See <https://html.spec.whatwg.org/multipage/syntax.html#void-elements>
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "param", "source",
+    "track", "wbr",
+];
+
+/// The elements whose content is text up to their closing element, even if it contains tags.
+///
+/// See <https://html.spec.whatwg.org/multipage/syntax.html#raw-text-elements>
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style", "textarea", "title"];
 
 pub(crate) fn parse_root(p: &mut HtmlParser) {
     let m = p.start();
 
-    p.eat(UNICODE_BOM);
+    p.eat_with_context(UNICODE_BOM, HtmlLexContext::ElementList);
 
+    parse_astro_frontmatter(p).ok();
     parse_doc_type(p).ok();
-    parse_element(p).ok();
+    ElementList.parse_list(p);
+
+    p.expect(EOF);
 
     m.complete(p, HTML_ROOT);
 }
@@ -38,12 +66,17 @@ fn parse_doc_type(p: &mut HtmlParser) -> ParsedSyntax {
     let m = p.start();
     p.bump(T![<]);
     p.bump(T![!]);
+    p.expect(T![doctype]);
 
-    if p.at(T![doctype]) {
-        p.eat(T![doctype]);
+    if p.at(HTML_LITERAL) && p.cur_text().eq_ignore_ascii_case("html") {
+        p.bump_remap(T![html]);
     }
+    // The legacy document types, such as `<!DOCTYPE html PUBLIC "-//W3C//DTD HTML 4.01//EN" "http://www.w3.org/TR/html4/strict.dtd">`
+    p.eat(HTML_LITERAL);
+    p.eat(HTML_STRING_LITERAL);
+    p.eat(HTML_STRING_LITERAL);
 
-    p.eat(T![>]);
+    p.expect_with_context(T![>], HtmlLexContext::ElementList);
 
     Present(m.complete(p, HTML_DIRECTIVE))
 }
@@ -55,38 +88,112 @@ fn parse_element(p: &mut HtmlParser) -> ParsedSyntax {
     let m = p.start();
 
     p.bump(T![<]);
-    // TODO: handle error
-    parse_literal(p).ok();
+    let name = if p.at(HTML_LITERAL) {
+        p.cur_text().to_ascii_lowercase()
+    } else {
+        String::new()
+    };
+    parse_literal(p).or_add_diagnostic(p, expected_element_name);
 
     AttributeList.parse_list(p);
 
     if p.at(T![/]) {
         p.bump(T![/]);
-        p.bump(T![>]);
-        Present(m.complete(p, HTML_SELF_CLOSING_ELEMENT))
+        p.expect_with_context(T![>], HtmlLexContext::ElementList);
+        return Present(m.complete(p, HTML_SELF_CLOSING_ELEMENT));
+    }
+
+    if VOID_ELEMENTS.contains(&name.as_str()) {
+        p.expect_with_context(T![>], HtmlLexContext::ElementList);
+        return Present(m.complete(p, HTML_SELF_CLOSING_ELEMENT));
+    }
+
+    let raw_text_element = RAW_TEXT_ELEMENTS
+        .iter()
+        .copied()
+        .find(|raw_text_element| *raw_text_element == name);
+
+    match raw_text_element {
+        Some(raw_text_element) => {
+            p.expect_with_context(T![>], HtmlLexContext::RawText(raw_text_element));
+        }
+        None => {
+            p.expect_with_context(T![>], HtmlLexContext::ElementList);
+        }
+    }
+    let opening = m.complete(p, HTML_OPENING_ELEMENT);
+
+    if raw_text_element.is_some() {
+        let children = p.start();
+        if p.at(HTML_LITERAL) {
+            let content = p.start();
+            p.bump(HTML_LITERAL);
+            content.complete(p, HTML_CONTENT);
+        }
+        children.complete(p, HTML_ELEMENT_LIST);
     } else {
-        p.bump_with_context(T![>], HtmlLexContext::ElementList);
-        let opening = m.complete(p, HTML_OPENING_ELEMENT);
+        p.state_mut().open_elements.push(name.clone());
         ElementList.parse_list(p);
-        // TODO: handle error
-        parse_closing_element(p).ok();
-        let previous = opening.precede(p);
+        p.state_mut().open_elements.pop();
+    }
 
-        Present(previous.complete(p, HTML_ELEMENT))
+    if closing_element_name(p).is_some_and(|closing_name| closing_name.eq_ignore_ascii_case(&name))
+    {
+        parse_closing_element(p).ok();
+    } else {
+        // The closing element is missing, or it's the closing element of a parent element
+        let range = opening.range(p);
+        p.error(unclosed_element(p, &name, range));
     }
+
+    let element = opening.precede(p);
+    Present(element.complete(p, HTML_ELEMENT))
 }
 
 fn parse_closing_element(p: &mut HtmlParser) -> ParsedSyntax {
+    if !(p.at(T![<]) && p.nth_at(1, T![/])) {
+        return Absent;
+    }
     let m = p.start();
     p.bump(T![<]);
     p.bump(T![/]);
-    let _name = parse_literal(p);
-    p.bump(T![>]);
+    parse_literal(p).or_add_diagnostic(p, expected_element_name);
+    p.expect_with_context(T![>], HtmlLexContext::ElementList);
     Present(m.complete(p, HTML_CLOSING_ELEMENT))
 }
 
+/// Parses a closing element that doesn't close any open element, such as `</div>` in `<span></div>`.
+fn parse_stray_closing_element(p: &mut HtmlParser) -> ParsedSyntax {
+    let name = closing_element_name(p).unwrap_or_default().to_string();
+
+    let mut closing = match parse_closing_element(p) {
+        Present(closing) => closing,
+        Absent => return Absent,
+    };
+    let range = closing.range(p);
+    p.error(stray_closing_element(p, &name, range));
+    closing.change_kind(p, HTML_BOGUS_ELEMENT);
+
+    Present(closing)
+}
+
+/// Returns the name of the closing element that starts at the current `<`, such as `div` in `</div>`.
+fn closing_element_name<'a>(p: &'a HtmlParser) -> Option<&'a str> {
+    if !p.at(T![<]) {
+        return None;
+    }
+
+    let after_l_angle = &p.source().text()[usize::from(p.cur_range().end())..];
+    let name = after_l_angle.strip_prefix('/')?;
+    let end = name
+        .find(|char: char| char.is_ascii_whitespace() || matches!(char, '/' | '>' | '<'))
+        .unwrap_or(name.len());
+
+    Some(&name[..end])
+}
+
 #[derive(Default)]
-struct ElementList;
+pub(crate) struct ElementList;
 
 impl ParseNodeList for ElementList {
     type Kind = HtmlSyntaxKind;
@@ -95,10 +202,14 @@ impl ParseNodeList for ElementList {
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
         match p.cur() {
+            T![<] if p.nth_at(1, T![/]) => parse_stray_closing_element(p),
             T![<] => parse_element(p),
+            T!['{'] => parse_single_text_expression(p, HtmlLexContext::ElementList),
+            T!["{#"] => parse_svelte_block(p),
+            T!["{:"] | T!["{/"] => parse_stray_svelte_clause(p),
             HTML_LITERAL => {
                 let m = p.start();
-                p.bump(HTML_LITERAL);
+                p.bump_with_context(HTML_LITERAL, HtmlLexContext::ElementList);
                 Present(m.complete(p, HTML_CONTENT))
             }
             _ => Absent,
@@ -106,9 +217,10 @@ impl ParseNodeList for ElementList {
     }
 
     fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
-        let at_l_angle0 = p.at(T![<]);
-        let at_slash1 = p.nth_at(1, T![/]);
-        at_l_angle0 && at_slash1
+        // The closing element of the current element, or of one of its parents
+        closing_element_name(p).is_some_and(|name| p.state().is_open(name))
+            // A clause or the end of the Svelte block that contains the list
+            || (p.state().open_svelte_blocks > 0 && (p.at(T!["{:"]) || p.at(T!["{/"])))
     }
 
     fn recover(
@@ -118,7 +230,7 @@ impl ParseNodeList for ElementList {
     ) -> RecoveryResult {
         parsed_element.or_recover_with_token_set(
             p,
-            &ParseRecoveryTokenSet::new(HTML_BOGUS_ELEMENT, token_set![T![<], T![>]]),
+            &ParseRecoveryTokenSet::new(HTML_BOGUS_ELEMENT, RECOVER_ELEMENT_LIST),
             expected_child,
         )
     }
@@ -133,12 +245,16 @@ impl ParseNodeList for AttributeList {
     const LIST_KIND: Self::Kind = HTML_ATTRIBUTE_LIST;
 
     fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
-        // Absent
-        parse_attribute(p)
+        if p.at(T!['{']) {
+            // The shorthand attributes and the spread attributes of Svelte, such as `{value}` and `{...props}`
+            parse_single_text_expression(p, HtmlLexContext::Regular)
+        } else {
+            parse_attribute(p)
+        }
     }
 
     fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
-        p.at(T![>]) || p.at(T![/])
+        p.at(T![>]) || p.at(T![/]) || p.at(T![<])
     }
 
     fn recover(
@@ -148,34 +264,34 @@ impl ParseNodeList for AttributeList {
     ) -> RecoveryResult {
         parsed_element.or_recover_with_token_set(
             p,
-            &ParseRecoveryTokenSet::new(HTML_BOGUS_ELEMENT, RECOVER_ATTRIBUTE_LIST),
+            &ParseRecoveryTokenSet::new(HTML_BOGUS_ATTRIBUTE, RECOVER_ATTRIBUTE_LIST),
             expected_attribute,
         )
     }
 }
 
 fn parse_attribute(p: &mut HtmlParser) -> ParsedSyntax {
-    if !p.at(HTML_LITERAL) {
+    if !p.at(HTML_LITERAL) && !p.at(T![doctype]) {
         return Absent;
     }
     let m = p.start();
-    // TODO: handle error
     parse_literal(p).ok();
-    if p.at(T![=]) {
-        parse_attribute_initializer(p).ok();
-        Present(m.complete(p, HTML_ATTRIBUTE))
-    } else {
-        Present(m.complete(p, HTML_ATTRIBUTE))
-    }
+    parse_attribute_initializer(p).ok();
+    Present(m.complete(p, HTML_ATTRIBUTE))
 }
 
-fn parse_literal(p: &mut HtmlParser) -> ParsedSyntax {
-    if !p.at(HTML_LITERAL) {
-        return Absent;
-    }
+pub(crate) fn parse_literal(p: &mut HtmlParser) -> ParsedSyntax {
     let m = p.start();
 
-    p.bump(HTML_LITERAL);
+    match p.cur() {
+        HTML_LITERAL => p.bump(HTML_LITERAL),
+        // An attribute named `doctype`
+        T![doctype] => p.bump_remap(HTML_LITERAL),
+        _ => {
+            m.abandon(p);
+            return Absent;
+        }
+    }
 
     Present(m.complete(p, HTML_NAME))
 }
@@ -196,8 +312,12 @@ fn parse_attribute_initializer(p: &mut HtmlParser) -> ParsedSyntax {
         return Absent;
     }
     let m = p.start();
-    p.bump(T![=]);
-    // TODO: handle error
-    parse_string_literal(p).ok();
+    p.bump_with_context(T![=], HtmlLexContext::AttributeValue);
+    if p.at(T!['{']) {
+        parse_single_text_expression(p, HtmlLexContext::Regular)
+    } else {
+        parse_string_literal(p)
+    }
+    .or_add_diagnostic(p, expected_attribute_value);
     Present(m.complete(p, HTML_ATTRIBUTE_INITIALIZER_CLAUSE))
 }
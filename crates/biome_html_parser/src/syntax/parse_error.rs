@@ -2,11 +2,69 @@ use crate::parser::HtmlParser;
 use biome_html_syntax::TextRange;
 use biome_parser::diagnostic::{expect_one_of, expected_node, ParseDiagnostic};
 use biome_parser::prelude::ToDiagnostic;
+use biome_parser::Parser;
 
 pub(crate) fn expected_attribute(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
     expected_node("attribute", range, p).into_diagnostic(p)
 }
 
+pub(crate) fn expected_attribute_value(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("attribute value", range, p).into_diagnostic(p)
+}
+
 pub(crate) fn expected_child(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
     expect_one_of(&["element", "text"], range).into_diagnostic(p)
 }
+
+pub(crate) fn expected_expression(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("expression", range, p).into_diagnostic(p)
+}
+
+pub(crate) fn expected_name(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("name", range, p).into_diagnostic(p)
+}
+
+pub(crate) fn expected_element_name(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("element name", range, p).into_diagnostic(p)
+}
+
+pub(crate) fn unclosed_element(p: &HtmlParser, name: &str, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(format!("The element `{name}` isn't closed."), range)
+        .with_hint(format!("Add the closing element `</{name}>`."))
+}
+
+pub(crate) fn stray_closing_element(
+    p: &HtmlParser,
+    name: &str,
+    range: TextRange,
+) -> ParseDiagnostic {
+    p.err_builder(
+        format!("The closing element `</{name}>` doesn't have an opening element."),
+        range,
+    )
+    .with_hint("Remove the closing element, or add its opening element before it.")
+}
+
+pub(crate) fn unclosed_svelte_block(
+    p: &HtmlParser,
+    name: &str,
+    range: TextRange,
+) -> ParseDiagnostic {
+    p.err_builder(format!("The block `{{#{name}}}` isn't closed."), range)
+        .with_hint(format!("Add the end of the block `{{/{name}}}`."))
+}
+
+pub(crate) fn stray_svelte_clause(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "This clause doesn't belong to an `{#if}` or an `{#each}` block.",
+        range,
+    )
+    .with_hint("Remove the clause, or move it inside its block.")
+}
+
+pub(crate) fn unclosed_astro_frontmatter(p: &HtmlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("The frontmatter isn't closed.", range)
+        .with_hint(
+            "Add the closing fence `---` on its own line, after the code of the frontmatter.",
+        )
+}
@@ -0,0 +1,253 @@
+use crate::parser::HtmlParser;
+use crate::syntax::parse_error::{
+    expected_expression, expected_name, stray_svelte_clause, unclosed_svelte_block,
+};
+use crate::syntax::{parse_literal, ElementList};
+use crate::token_source::{HtmlLexContext, TextExpressionEnd};
+use biome_html_syntax::HtmlSyntaxKind::{
+    EOF, HTML_BOGUS_ELEMENT, HTML_LITERAL, HTML_SINGLE_TEXT_EXPRESSION, HTML_TEXT_EXPRESSION,
+    SVELTE_EACH_BLOCK, SVELTE_EACH_CLOSING_BLOCK, SVELTE_EACH_INDEX, SVELTE_EACH_KEY,
+    SVELTE_EACH_OPENING_BLOCK, SVELTE_ELSE_CLAUSE, SVELTE_ELSE_IF_CLAUSE,
+    SVELTE_ELSE_IF_CLAUSE_LIST, SVELTE_IF_BLOCK, SVELTE_IF_CLOSING_BLOCK, SVELTE_IF_OPENING_BLOCK,
+};
+use biome_html_syntax::{HtmlSyntaxKind, T};
+use biome_parser::diagnostic::expected_token;
+use biome_parser::parse_lists::ParseNodeList;
+use biome_parser::parsed_syntax::ParsedSyntax::Present;
+use biome_parser::prelude::ParsedSyntax::Absent;
+use biome_parser::prelude::*;
+use biome_parser::Parser;
+
+/// Parses an expression between curly braces, such as `{user.name}` in the content of an element,
+/// or `{value}` in an attribute.
+///
+/// `context_after` is the context of the token that follows the `}`.
+pub(crate) fn parse_single_text_expression(
+    p: &mut HtmlParser,
+    context_after: HtmlLexContext,
+) -> ParsedSyntax {
+    if !p.at(T!['{']) {
+        return Absent;
+    }
+    let m = p.start();
+
+    p.bump_with_context(
+        T!['{'],
+        HtmlLexContext::TextExpression(TextExpressionEnd::RCurly),
+    );
+    parse_text_expression(p).or_add_diagnostic(p, expected_expression);
+    p.expect_with_context(T!['}'], context_after);
+
+    Present(m.complete(p, HTML_SINGLE_TEXT_EXPRESSION))
+}
+
+/// Parses the code of an expression, which the lexer returns as a single token.
+fn parse_text_expression(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(HTML_LITERAL) {
+        return Absent;
+    }
+    let m = p.start();
+
+    p.bump(HTML_LITERAL);
+
+    Present(m.complete(p, HTML_TEXT_EXPRESSION))
+}
+
+/// Parses a block that starts with `{#`, such as `{#if condition}...{/if}`.
+pub(crate) fn parse_svelte_block(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T!["{#"]) {
+        return Absent;
+    }
+
+    let after_hash = text_after_current(p);
+    if strip_keyword(after_hash, "if").is_some() {
+        parse_if_block(p)
+    } else if strip_keyword(after_hash, "each").is_some() {
+        parse_each_block(p)
+    } else {
+        Absent
+    }
+}
+
+fn parse_if_block(p: &mut HtmlParser) -> ParsedSyntax {
+    let m = p.start();
+
+    let opening = p.start();
+    p.bump(T!["{#"]);
+    p.bump_remap_with_context(
+        T![if],
+        HtmlLexContext::TextExpression(TextExpressionEnd::RCurly),
+    );
+    parse_text_expression(p).or_add_diagnostic(p, expected_expression);
+    p.expect_with_context(T!['}'], HtmlLexContext::ElementList);
+    let opening = opening.complete(p, SVELTE_IF_OPENING_BLOCK);
+
+    parse_block_children(p);
+
+    let clauses = p.start();
+    while at_else_if_clause(p) {
+        parse_else_if_clause(p);
+    }
+    clauses.complete(p, SVELTE_ELSE_IF_CLAUSE_LIST);
+
+    parse_else_clause(p).ok();
+
+    if at_closing_block(p, "if") {
+        parse_closing_block(p, T![if], SVELTE_IF_CLOSING_BLOCK);
+    } else {
+        let range = opening.range(p);
+        p.error(unclosed_svelte_block(p, "if", range));
+    }
+
+    Present(m.complete(p, SVELTE_IF_BLOCK))
+}
+
+fn parse_each_block(p: &mut HtmlParser) -> ParsedSyntax {
+    let m = p.start();
+
+    let opening = p.start();
+    p.bump(T!["{#"]);
+    p.bump_remap_with_context(
+        T![each],
+        HtmlLexContext::TextExpression(TextExpressionEnd::As),
+    );
+    parse_text_expression(p).or_add_diagnostic(p, expected_expression);
+
+    if p.at(HTML_LITERAL) && p.cur_text() == "as" {
+        p.bump_remap_with_context(
+            T![as],
+            HtmlLexContext::TextExpression(TextExpressionEnd::EachItem),
+        );
+    } else {
+        p.error(expected_token(T![as]));
+    }
+    parse_text_expression(p).or_add_diagnostic(p, expected_expression);
+
+    if p.at(T![,]) {
+        let index = p.start();
+        p.bump(T![,]);
+        parse_literal(p).or_add_diagnostic(p, expected_name);
+        index.complete(p, SVELTE_EACH_INDEX);
+    }
+
+    if p.at(T!['(']) {
+        let key = p.start();
+        p.bump_with_context(
+            T!['('],
+            HtmlLexContext::TextExpression(TextExpressionEnd::RParen),
+        );
+        parse_text_expression(p).or_add_diagnostic(p, expected_expression);
+        p.expect(T![')']);
+        key.complete(p, SVELTE_EACH_KEY);
+    }
+
+    p.expect_with_context(T!['}'], HtmlLexContext::ElementList);
+    let opening = opening.complete(p, SVELTE_EACH_OPENING_BLOCK);
+
+    parse_block_children(p);
+
+    parse_else_clause(p).ok();
+
+    if at_closing_block(p, "each") {
+        parse_closing_block(p, T![each], SVELTE_EACH_CLOSING_BLOCK);
+    } else {
+        let range = opening.range(p);
+        p.error(unclosed_svelte_block(p, "each", range));
+    }
+
+    Present(m.complete(p, SVELTE_EACH_BLOCK))
+}
+
+/// Parses the elements of a block or of a clause, up to the next clause or the end of the block.
+fn parse_block_children(p: &mut HtmlParser) {
+    p.state_mut().open_svelte_blocks += 1;
+    ElementList.parse_list(p);
+    p.state_mut().open_svelte_blocks -= 1;
+}
+
+fn parse_else_if_clause(p: &mut HtmlParser) {
+    let m = p.start();
+
+    p.bump(T!["{:"]);
+    p.bump_remap(T![else]);
+    p.bump_remap_with_context(
+        T![if],
+        HtmlLexContext::TextExpression(TextExpressionEnd::RCurly),
+    );
+    parse_text_expression(p).or_add_diagnostic(p, expected_expression);
+    p.expect_with_context(T!['}'], HtmlLexContext::ElementList);
+
+    parse_block_children(p);
+
+    m.complete(p, SVELTE_ELSE_IF_CLAUSE);
+}
+
+fn parse_else_clause(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T!["{:"]) || at_else_if_clause(p) {
+        return Absent;
+    }
+    let m = p.start();
+
+    p.bump(T!["{:"]);
+    p.bump_remap(T![else]);
+    p.expect_with_context(T!['}'], HtmlLexContext::ElementList);
+
+    parse_block_children(p);
+
+    Present(m.complete(p, SVELTE_ELSE_CLAUSE))
+}
+
+fn parse_closing_block(p: &mut HtmlParser, keyword: HtmlSyntaxKind, kind: HtmlSyntaxKind) {
+    let m = p.start();
+
+    p.bump(T!["{/"]);
+    p.bump_remap(keyword);
+    p.expect_with_context(T!['}'], HtmlLexContext::ElementList);
+
+    m.complete(p, kind);
+}
+
+/// Parses a clause or the end of a block that doesn't belong to any block, such as `{/if}` without `{#if}`.
+pub(crate) fn parse_stray_svelte_clause(p: &mut HtmlParser) -> ParsedSyntax {
+    if !p.at(T!["{:"]) && !p.at(T!["{/"]) {
+        return Absent;
+    }
+    let m = p.start();
+
+    p.bump_any();
+    while !p.at(EOF) && !p.at(T!['}']) && !p.at(T![<]) {
+        p.bump_any();
+    }
+    p.eat_with_context(T!['}'], HtmlLexContext::ElementList);
+
+    let clause = m.complete(p, HTML_BOGUS_ELEMENT);
+    let range = clause.range(p);
+    p.error(stray_svelte_clause(p, range));
+
+    Present(clause)
+}
+
+/// Returns `true` if the parser is at a `{:else if condition}` clause.
+fn at_else_if_clause(p: &HtmlParser) -> bool {
+    p.at(T!["{:"])
+        && strip_keyword(text_after_current(p), "else")
+            .and_then(|after_else| strip_keyword(after_else.trim_start(), "if"))
+            .is_some()
+}
+
+/// Returns `true` if the parser is at the end of the block named `keyword`, such as `{/if}`.
+fn at_closing_block(p: &HtmlParser, keyword: &str) -> bool {
+    p.at(T!["{/"]) && strip_keyword(text_after_current(p), keyword).is_some()
+}
+
+/// Returns the source text after the current token, such as `if condition}` after the `{#` of `{#if condition}`.
+fn text_after_current<'a>(p: &'a HtmlParser) -> &'a str {
+    &p.source().text()[usize::from(p.cur_range().end())..]
+}
+
+/// Removes `keyword` from the start of `text`, if it isn't the start of a longer word.
+fn strip_keyword<'a>(text: &'a str, keyword: &str) -> Option<&'a str> {
+    text.strip_prefix(keyword).filter(|after_keyword| {
+        !after_keyword.starts_with(|char: char| char.is_ascii_alphanumeric() || char == '_')
+    })
+}
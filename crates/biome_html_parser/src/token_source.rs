@@ -1,4 +1,5 @@
 use crate::lexer::HtmlLexer;
+use crate::HtmlParserOptions;
 use biome_html_syntax::HtmlSyntaxKind::EOF;
 use biome_html_syntax::{HtmlSyntaxKind, TextRange};
 use biome_parser::diagnostic::ParseDiagnostic;
@@ -16,12 +17,35 @@ pub(crate) struct HtmlTokenSource<'source> {
 
 #[derive(Copy, Clone, Debug, Default)]
 pub(crate) enum HtmlLexContext {
-    /// The default state
+    /// The default state, inside a tag
     #[default]
     Regular,
-    #[allow(unused)]
-    /// When the lexer is inside a element list, newlines, spaces and quotes are part of the text
+    /// When the lexer is inside a element list, quotes and the characters of the tags are part of the text
     ElementList,
+    /// When the lexer is after the `=` of an attribute, the value can be unquoted
+    AttributeValue,
+    /// When the lexer is inside an element whose content isn't HTML, such as `<script>`,
+    /// everything up to the closing element of the given name is text
+    RawText(&'static str),
+    /// When the lexer is inside a text expression of a Svelte or an Astro component, such as `{user.name}`,
+    /// the code of the expression is a single token
+    TextExpression(TextExpressionEnd),
+    /// When the lexer is inside the frontmatter of an Astro component,
+    /// the code up to the closing `---` fence is a single token
+    AstroFrontmatter,
+}
+
+/// The tokens that end the code of a text expression
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum TextExpressionEnd {
+    /// The `}` of `{user.name}` and of the blocks, such as `{#if condition}`
+    RCurly,
+    /// The `as` of `{#each items as item}`
+    As,
+    /// The `,`, the `(` or the `}` that follow the item of `{#each items as item, index (key)}`
+    EachItem,
+    /// The `)` of the key of `{#each items as item (key)}`
+    RParen,
 }
 
 impl LexContext for HtmlLexContext {
@@ -32,13 +56,13 @@ impl LexContext for HtmlLexContext {
 
 impl<'source> HtmlTokenSource<'source> {
     /// Creates a new token source for the given string
-    pub fn from_str(source: &'source str) -> Self {
-        let lexer = HtmlLexer::from_str(source);
+    pub fn from_str(source: &'source str, options: HtmlParserOptions) -> Self {
+        let lexer = HtmlLexer::from_str(source).with_options(options);
 
         let buffered = BufferedLexer::new(lexer);
         let mut source = Self::new(buffered);
 
-        source.next_non_trivia_token(HtmlLexContext::Regular, true);
+        source.next_non_trivia_token(HtmlLexContext::ElementList, true);
         source
     }
 
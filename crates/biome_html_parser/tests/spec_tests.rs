@@ -5,4 +5,8 @@ mod spec_test;
 mod ok {
     tests_macros::gen_tests! {"tests/html_specs/ok/**/*.html", crate::spec_test::run, "ok"}
     tests_macros::gen_tests! {"tests/html_specs/error/**/*.html", crate::spec_test::run, "error"}
+    tests_macros::gen_tests! {"tests/html_specs/ok/**/*.svelte", crate::spec_test::run, "ok"}
+    tests_macros::gen_tests! {"tests/html_specs/error/**/*.svelte", crate::spec_test::run, "error"}
+    tests_macros::gen_tests! {"tests/html_specs/ok/**/*.astro", crate::spec_test::run, "ok"}
+    tests_macros::gen_tests! {"tests/html_specs/error/**/*.astro", crate::spec_test::run, "error"}
 }
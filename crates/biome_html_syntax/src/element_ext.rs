@@ -0,0 +1,71 @@
+use crate::{
+    inner_string_text, HtmlAttribute, HtmlAttributeList, HtmlName, HtmlOpeningElement,
+    HtmlSelfClosingElement, HtmlSyntaxToken,
+};
+use biome_rowan::{declare_node_union, AstNodeList, SyntaxResult, TokenText};
+
+declare_node_union! {
+    /// The tag that contains the name and the attributes of an element, such as `<img src="logo.png">`
+    pub AnyHtmlTag = HtmlOpeningElement | HtmlSelfClosingElement
+}
+
+impl AnyHtmlTag {
+    pub fn name(&self) -> SyntaxResult<HtmlName> {
+        match self {
+            AnyHtmlTag::HtmlOpeningElement(element) => element.name(),
+            AnyHtmlTag::HtmlSelfClosingElement(element) => element.name(),
+        }
+    }
+
+    pub fn attributes(&self) -> HtmlAttributeList {
+        match self {
+            AnyHtmlTag::HtmlOpeningElement(element) => element.attributes(),
+            AnyHtmlTag::HtmlSelfClosingElement(element) => element.attributes(),
+        }
+    }
+
+    /// Returns the token of the name of the element, such as `img` in `<img src="logo.png">`.
+    pub fn name_value_token(&self) -> Option<HtmlSyntaxToken> {
+        self.name().ok()?.value_token().ok()
+    }
+
+    /// Returns the attribute with the given name, the names of the attributes aren't case-sensitive.
+    pub fn find_attribute_by_name(&self, name_to_lookup: &str) -> Option<HtmlAttribute> {
+        self.attributes().find_by_name(name_to_lookup)
+    }
+}
+
+impl HtmlAttributeList {
+    /// Returns the attribute with the given name, the names of the attributes aren't case-sensitive.
+    pub fn find_by_name(&self, name_to_lookup: &str) -> Option<HtmlAttribute> {
+        self.iter()
+            .filter_map(|attribute| attribute.as_html_attribute().cloned())
+            .find(|attribute| {
+                attribute
+                    .name_value_token()
+                    .is_some_and(|name| name.text_trimmed().eq_ignore_ascii_case(name_to_lookup))
+            })
+    }
+}
+
+impl HtmlAttribute {
+    /// Returns the token of the name of the attribute, such as `src` in `src="logo.png"`.
+    pub fn name_value_token(&self) -> Option<HtmlSyntaxToken> {
+        self.name().ok()?.value_token().ok()
+    }
+
+    /// Returns the value of the attribute, without its quotes.
+    ///
+    /// It returns `None` when the attribute doesn't have a value, such as `disabled` in `<input disabled>`,
+    /// or when its value is an expression, such as `value={name}` in a Svelte component.
+    pub fn value(&self) -> Option<TokenText> {
+        let value_token = self
+            .initializer()?
+            .value()
+            .ok()?
+            .as_html_string()?
+            .value_token()
+            .ok()?;
+        Some(inner_string_text(&value_token))
+    }
+}
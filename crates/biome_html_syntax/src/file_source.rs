@@ -1,13 +1,18 @@
 use biome_rowan::FileSourceError;
 use std::{ffi::OsStr, path::Path};
 
-#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
 pub struct HtmlFileSource {
-    #[allow(unused)]
     variant: HtmlVariant,
 }
 
-#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
 enum HtmlVariant {
     #[default]
     Standard,
@@ -35,7 +40,7 @@ impl HtmlFileSource {
     /// Try to return the HTML file source corresponding to this file extension
     pub fn try_from_extension(extension: &str) -> Result<Self, FileSourceError> {
         match extension {
-            "html" => Ok(Self::html()),
+            "html" | "htm" => Ok(Self::html()),
             "astro" => Ok(Self::astro()),
             _ => Err(FileSourceError::UnknownExtension(
                 Default::default(),
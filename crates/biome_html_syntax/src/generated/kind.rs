@@ -19,11 +19,24 @@ pub enum HtmlSyntaxKind {
     EQ,
     BANG,
     MINUS,
+    L_CURLY,
+    R_CURLY,
+    L_PAREN,
+    R_PAREN,
+    COMMA,
+    SV_CURLY_HASH,
+    SV_CURLY_COLON,
+    SV_CURLY_SLASH,
+    FENCE,
     NULL_KW,
     TRUE_KW,
     FALSE_KW,
     DOCTYPE_KW,
     HTML_KW,
+    IF_KW,
+    ELSE_KW,
+    EACH_KW,
+    AS_KW,
     HTML_STRING_LITERAL,
     HTML_LITERAL,
     ERROR_TOKEN,
@@ -46,6 +59,20 @@ pub enum HtmlSyntaxKind {
     HTML_ELEMENT_LIST,
     HTML_ATTRIBUTE_LIST,
     HTML_CONTENT,
+    HTML_SINGLE_TEXT_EXPRESSION,
+    HTML_TEXT_EXPRESSION,
+    SVELTE_IF_BLOCK,
+    SVELTE_IF_OPENING_BLOCK,
+    SVELTE_ELSE_IF_CLAUSE_LIST,
+    SVELTE_ELSE_IF_CLAUSE,
+    SVELTE_ELSE_CLAUSE,
+    SVELTE_IF_CLOSING_BLOCK,
+    SVELTE_EACH_BLOCK,
+    SVELTE_EACH_OPENING_BLOCK,
+    SVELTE_EACH_INDEX,
+    SVELTE_EACH_KEY,
+    SVELTE_EACH_CLOSING_BLOCK,
+    ASTRO_FRONTMATTER_ELEMENT,
     HTML_BOGUS,
     HTML_BOGUS_ELEMENT,
     HTML_BOGUS_ATTRIBUTE,
@@ -56,7 +83,8 @@ use self::HtmlSyntaxKind::*;
 impl HtmlSyntaxKind {
     pub const fn is_punct(self) -> bool {
         match self {
-            L_ANGLE | R_ANGLE | SLASH | EQ | BANG | MINUS => true,
+            L_ANGLE | R_ANGLE | SLASH | EQ | BANG | MINUS | L_CURLY | R_CURLY | L_PAREN
+            | R_PAREN | COMMA | SV_CURLY_HASH | SV_CURLY_COLON | SV_CURLY_SLASH | FENCE => true,
             _ => false,
         }
     }
@@ -68,7 +96,7 @@ impl HtmlSyntaxKind {
     }
     pub const fn is_list(self) -> bool {
         match self {
-            HTML_ELEMENT_LIST | HTML_ATTRIBUTE_LIST => true,
+            HTML_ELEMENT_LIST | HTML_ATTRIBUTE_LIST | SVELTE_ELSE_IF_CLAUSE_LIST => true,
             _ => false,
         }
     }
@@ -79,6 +107,10 @@ impl HtmlSyntaxKind {
             "false" => FALSE_KW,
             "doctype" => DOCTYPE_KW,
             "html" => HTML_KW,
+            "if" => IF_KW,
+            "else" => ELSE_KW,
+            "each" => EACH_KW,
+            "as" => AS_KW,
             _ => return None,
         };
         Some(kw)
@@ -91,11 +123,24 @@ impl HtmlSyntaxKind {
             EQ => "=",
             BANG => "!",
             MINUS => "-",
+            L_CURLY => "{",
+            R_CURLY => "}",
+            L_PAREN => "(",
+            R_PAREN => ")",
+            COMMA => ",",
+            SV_CURLY_HASH => "{#",
+            SV_CURLY_COLON => "{:",
+            SV_CURLY_SLASH => "{/",
+            FENCE => "---",
             NULL_KW => "null",
             TRUE_KW => "true",
             FALSE_KW => "false",
             DOCTYPE_KW => "doctype",
             HTML_KW => "html",
+            IF_KW => "if",
+            ELSE_KW => "else",
+            EACH_KW => "each",
+            AS_KW => "as",
             HTML_STRING_LITERAL => "string literal",
             _ => return None,
         };
@@ -104,4 +149,4 @@ impl HtmlSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [<] => { $ crate :: HtmlSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: HtmlSyntaxKind :: R_ANGLE } ; [/] => { $ crate :: HtmlSyntaxKind :: SLASH } ; [=] => { $ crate :: HtmlSyntaxKind :: EQ } ; [!] => { $ crate :: HtmlSyntaxKind :: BANG } ; [-] => { $ crate :: HtmlSyntaxKind :: MINUS } ; [null] => { $ crate :: HtmlSyntaxKind :: NULL_KW } ; [true] => { $ crate :: HtmlSyntaxKind :: TRUE_KW } ; [false] => { $ crate :: HtmlSyntaxKind :: FALSE_KW } ; [doctype] => { $ crate :: HtmlSyntaxKind :: DOCTYPE_KW } ; [html] => { $ crate :: HtmlSyntaxKind :: HTML_KW } ; [ident] => { $ crate :: HtmlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: HtmlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: HtmlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: HtmlSyntaxKind :: HASH } ; }
+macro_rules ! T { [<] => { $ crate :: HtmlSyntaxKind :: L_ANGLE } ; [>] => { $ crate :: HtmlSyntaxKind :: R_ANGLE } ; [/] => { $ crate :: HtmlSyntaxKind :: SLASH } ; [=] => { $ crate :: HtmlSyntaxKind :: EQ } ; [!] => { $ crate :: HtmlSyntaxKind :: BANG } ; [-] => { $ crate :: HtmlSyntaxKind :: MINUS } ; ['{'] => { $ crate :: HtmlSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: HtmlSyntaxKind :: R_CURLY } ; ['('] => { $ crate :: HtmlSyntaxKind :: L_PAREN } ; [')'] => { $ crate :: HtmlSyntaxKind :: R_PAREN } ; [,] => { $ crate :: HtmlSyntaxKind :: COMMA } ; ["{#"] => { $ crate :: HtmlSyntaxKind :: SV_CURLY_HASH } ; ["{:"] => { $ crate :: HtmlSyntaxKind :: SV_CURLY_COLON } ; ["{/"] => { $ crate :: HtmlSyntaxKind :: SV_CURLY_SLASH } ; [---] => { $ crate :: HtmlSyntaxKind :: FENCE } ; [null] => { $ crate :: HtmlSyntaxKind :: NULL_KW } ; [true] => { $ crate :: HtmlSyntaxKind :: TRUE_KW } ; [false] => { $ crate :: HtmlSyntaxKind :: FALSE_KW } ; [doctype] => { $ crate :: HtmlSyntaxKind :: DOCTYPE_KW } ; [html] => { $ crate :: HtmlSyntaxKind :: HTML_KW } ; [if] => { $ crate :: HtmlSyntaxKind :: IF_KW } ; [else] => { $ crate :: HtmlSyntaxKind :: ELSE_KW } ; [each] => { $ crate :: HtmlSyntaxKind :: EACH_KW } ; [as] => { $ crate :: HtmlSyntaxKind :: AS_KW } ; [ident] => { $ crate :: HtmlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: HtmlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: HtmlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: HtmlSyntaxKind :: HASH } ; }
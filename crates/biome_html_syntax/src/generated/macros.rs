@@ -16,6 +16,10 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::HtmlSyntaxNode::kind(&node) {
+                $crate::HtmlSyntaxKind::ASTRO_FRONTMATTER_ELEMENT => {
+                    let $pattern = unsafe { $crate::AstroFrontmatterElement::new_unchecked(node) };
+                    $body
+                }
                 $crate::HtmlSyntaxKind::HTML_ATTRIBUTE => {
                     let $pattern = unsafe { $crate::HtmlAttribute::new_unchecked(node) };
                     $body
@@ -57,10 +61,58 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::HtmlSelfClosingElement::new_unchecked(node) };
                     $body
                 }
+                $crate::HtmlSyntaxKind::HTML_SINGLE_TEXT_EXPRESSION => {
+                    let $pattern = unsafe { $crate::HtmlSingleTextExpression::new_unchecked(node) };
+                    $body
+                }
                 $crate::HtmlSyntaxKind::HTML_STRING => {
                     let $pattern = unsafe { $crate::HtmlString::new_unchecked(node) };
                     $body
                 }
+                $crate::HtmlSyntaxKind::HTML_TEXT_EXPRESSION => {
+                    let $pattern = unsafe { $crate::HtmlTextExpression::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_EACH_BLOCK => {
+                    let $pattern = unsafe { $crate::SvelteEachBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_EACH_CLOSING_BLOCK => {
+                    let $pattern = unsafe { $crate::SvelteEachClosingBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_EACH_INDEX => {
+                    let $pattern = unsafe { $crate::SvelteEachIndex::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_EACH_KEY => {
+                    let $pattern = unsafe { $crate::SvelteEachKey::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_EACH_OPENING_BLOCK => {
+                    let $pattern = unsafe { $crate::SvelteEachOpeningBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_ELSE_CLAUSE => {
+                    let $pattern = unsafe { $crate::SvelteElseClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_ELSE_IF_CLAUSE => {
+                    let $pattern = unsafe { $crate::SvelteElseIfClause::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_IF_BLOCK => {
+                    let $pattern = unsafe { $crate::SvelteIfBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_IF_CLOSING_BLOCK => {
+                    let $pattern = unsafe { $crate::SvelteIfClosingBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::HtmlSyntaxKind::SVELTE_IF_OPENING_BLOCK => {
+                    let $pattern = unsafe { $crate::SvelteIfOpeningBlock::new_unchecked(node) };
+                    $body
+                }
                 $crate::HtmlSyntaxKind::HTML_BOGUS => {
                     let $pattern = unsafe { $crate::HtmlBogus::new_unchecked(node) };
                     $body
@@ -81,6 +133,10 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::HtmlElementList::new_unchecked(node) };
                     $body
                 }
+                $crate::HtmlSyntaxKind::SVELTE_ELSE_IF_CLAUSE_LIST => {
+                    let $pattern = unsafe { $crate::SvelteElseIfClauseList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
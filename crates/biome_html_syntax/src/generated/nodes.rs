@@ -25,6 +25,52 @@ use std::fmt::{Debug, Formatter};
 #[allow(dead_code)]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AstroFrontmatterElement {
+    pub(crate) syntax: SyntaxNode,
+}
+impl AstroFrontmatterElement {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> AstroFrontmatterElementFields {
+        AstroFrontmatterElementFields {
+            l_fence_token: self.l_fence_token(),
+            content_token: self.content_token(),
+            r_fence_token: self.r_fence_token(),
+        }
+    }
+    pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+    pub fn r_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for AstroFrontmatterElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct AstroFrontmatterElementFields {
+    pub l_fence_token: SyntaxResult<SyntaxToken>,
+    pub content_token: Option<SyntaxToken>,
+    pub r_fence_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlAttribute {
     pub(crate) syntax: SyntaxNode,
 }
@@ -88,7 +134,7 @@ impl HtmlAttributeInitializerClause {
     pub fn eq_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 0usize)
     }
-    pub fn value(&self) -> SyntaxResult<HtmlString> {
+    pub fn value(&self) -> SyntaxResult<AnyHtmlAttributeValue> {
         support::required_node(&self.syntax, 1usize)
     }
 }
@@ -104,7 +150,7 @@ impl Serialize for HtmlAttributeInitializerClause {
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HtmlAttributeInitializerClauseFields {
     pub eq_token: SyntaxResult<SyntaxToken>,
-    pub value: SyntaxResult<HtmlString>,
+    pub value: SyntaxResult<AnyHtmlAttributeValue>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlClosingElement {
@@ -414,6 +460,7 @@ impl HtmlRoot {
     pub fn as_fields(&self) -> HtmlRootFields {
         HtmlRootFields {
             bom_token: self.bom_token(),
+            frontmatter: self.frontmatter(),
             directive: self.directive(),
             html: self.html(),
             eof_token: self.eof_token(),
@@ -422,14 +469,17 @@ impl HtmlRoot {
     pub fn bom_token(&self) -> Option<SyntaxToken> {
         support::token(&self.syntax, 0usize)
     }
-    pub fn directive(&self) -> Option<HtmlDirective> {
+    pub fn frontmatter(&self) -> Option<AstroFrontmatterElement> {
         support::node(&self.syntax, 1usize)
     }
-    pub fn html(&self) -> Option<AnyHtmlElement> {
+    pub fn directive(&self) -> Option<HtmlDirective> {
         support::node(&self.syntax, 2usize)
     }
+    pub fn html(&self) -> HtmlElementList {
+        support::list(&self.syntax, 3usize)
+    }
     pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
+        support::required_token(&self.syntax, 4usize)
     }
 }
 #[cfg(feature = "serde")]
@@ -444,8 +494,9 @@ impl Serialize for HtmlRoot {
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HtmlRootFields {
     pub bom_token: Option<SyntaxToken>,
+    pub frontmatter: Option<AstroFrontmatterElement>,
     pub directive: Option<HtmlDirective>,
-    pub html: Option<AnyHtmlElement>,
+    pub html: HtmlElementList,
     pub eof_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
@@ -480,8 +531,8 @@ impl HtmlSelfClosingElement {
     pub fn attributes(&self) -> HtmlAttributeList {
         support::list(&self.syntax, 2usize)
     }
-    pub fn slash_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 3usize)
+    pub fn slash_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
     }
     pub fn r_angle_token(&self) -> SyntaxResult<SyntaxToken> {
         support::required_token(&self.syntax, 4usize)
@@ -501,10 +552,56 @@ pub struct HtmlSelfClosingElementFields {
     pub l_angle_token: SyntaxResult<SyntaxToken>,
     pub name: SyntaxResult<HtmlName>,
     pub attributes: HtmlAttributeList,
-    pub slash_token: SyntaxResult<SyntaxToken>,
+    pub slash_token: Option<SyntaxToken>,
     pub r_angle_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
+pub struct HtmlSingleTextExpression {
+    pub(crate) syntax: SyntaxNode,
+}
+impl HtmlSingleTextExpression {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> HtmlSingleTextExpressionFields {
+        HtmlSingleTextExpressionFields {
+            l_curly_token: self.l_curly_token(),
+            expression: self.expression(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn expression(&self) -> SyntaxResult<HtmlTextExpression> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for HtmlSingleTextExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct HtmlSingleTextExpressionFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub expression: SyntaxResult<HtmlTextExpression>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub struct HtmlString {
     pub(crate) syntax: SyntaxNode,
 }
@@ -541,146 +638,1141 @@ pub struct HtmlStringFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyHtmlAttribute {
-    HtmlAttribute(HtmlAttribute),
-    HtmlBogusAttribute(HtmlBogusAttribute),
+pub struct HtmlTextExpression {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AnyHtmlAttribute {
-    pub fn as_html_attribute(&self) -> Option<&HtmlAttribute> {
-        match &self {
-            AnyHtmlAttribute::HtmlAttribute(item) => Some(item),
-            _ => None,
-        }
+impl HtmlTextExpression {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_html_bogus_attribute(&self) -> Option<&HtmlBogusAttribute> {
-        match &self {
-            AnyHtmlAttribute::HtmlBogusAttribute(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> HtmlTextExpressionFields {
+        HtmlTextExpressionFields {
+            value_token: self.value_token(),
         }
     }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for HtmlTextExpression {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyHtmlElement {
-    HtmlBogusElement(HtmlBogusElement),
-    HtmlContent(HtmlContent),
-    HtmlElement(HtmlElement),
-    HtmlSelfClosingElement(HtmlSelfClosingElement),
+pub struct HtmlTextExpressionFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
-impl AnyHtmlElement {
-    pub fn as_html_bogus_element(&self) -> Option<&HtmlBogusElement> {
-        match &self {
-            AnyHtmlElement::HtmlBogusElement(item) => Some(item),
-            _ => None,
-        }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteEachBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteEachBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_html_content(&self) -> Option<&HtmlContent> {
-        match &self {
-            AnyHtmlElement::HtmlContent(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> SvelteEachBlockFields {
+        SvelteEachBlockFields {
+            opening_block: self.opening_block(),
+            children: self.children(),
+            else_clause: self.else_clause(),
+            closing_block: self.closing_block(),
         }
     }
-    pub fn as_html_element(&self) -> Option<&HtmlElement> {
-        match &self {
-            AnyHtmlElement::HtmlElement(item) => Some(item),
-            _ => None,
-        }
+    pub fn opening_block(&self) -> SyntaxResult<SvelteEachOpeningBlock> {
+        support::required_node(&self.syntax, 0usize)
     }
-    pub fn as_html_self_closing_element(&self) -> Option<&HtmlSelfClosingElement> {
-        match &self {
-            AnyHtmlElement::HtmlSelfClosingElement(item) => Some(item),
-            _ => None,
-        }
+    pub fn children(&self) -> HtmlElementList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn else_clause(&self) -> Option<SvelteElseClause> {
+        support::node(&self.syntax, 2usize)
+    }
+    pub fn closing_block(&self) -> SyntaxResult<SvelteEachClosingBlock> {
+        support::required_node(&self.syntax, 3usize)
     }
 }
-impl AstNode for HtmlAttribute {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ATTRIBUTE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_ATTRIBUTE
+#[cfg(feature = "serde")]
+impl Serialize for SvelteEachBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteEachBlockFields {
+    pub opening_block: SyntaxResult<SvelteEachOpeningBlock>,
+    pub children: HtmlElementList,
+    pub else_clause: Option<SvelteElseClause>,
+    pub closing_block: SyntaxResult<SvelteEachClosingBlock>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteEachClosingBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteEachClosingBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteEachClosingBlockFields {
+        SvelteEachClosingBlockFields {
+            sv_curly_slash_token: self.sv_curly_slash_token(),
+            each_token: self.each_token(),
+            r_curly_token: self.r_curly_token(),
         }
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    pub fn sv_curly_slash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    pub fn each_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
     }
-}
-impl std::fmt::Debug for HtmlAttribute {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlAttribute")
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field(
-                "initializer",
-                &support::DebugOptionalElement(self.initializer()),
-            )
-            .finish()
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
     }
 }
-impl From<HtmlAttribute> for SyntaxNode {
-    fn from(n: HtmlAttribute) -> SyntaxNode {
-        n.syntax
+#[cfg(feature = "serde")]
+impl Serialize for SvelteEachClosingBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-impl From<HtmlAttribute> for SyntaxElement {
-    fn from(n: HtmlAttribute) -> SyntaxElement {
-        n.syntax.into()
-    }
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteEachClosingBlockFields {
+    pub sv_curly_slash_token: SyntaxResult<SyntaxToken>,
+    pub each_token: SyntaxResult<SyntaxToken>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
 }
-impl AstNode for HtmlAttributeInitializerClause {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ATTRIBUTE_INITIALIZER_CLAUSE as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_ATTRIBUTE_INITIALIZER_CLAUSE
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteEachIndex {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteEachIndex {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
+    pub fn as_fields(&self) -> SvelteEachIndexFields {
+        SvelteEachIndexFields {
+            comma_token: self.comma_token(),
+            value: self.value(),
         }
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    pub fn comma_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    pub fn value(&self) -> SyntaxResult<HtmlName> {
+        support::required_node(&self.syntax, 1usize)
     }
 }
-impl std::fmt::Debug for HtmlAttributeInitializerClause {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlAttributeInitializerClause")
-            .field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
-            .field("value", &support::DebugSyntaxResult(self.value()))
-            .finish()
+#[cfg(feature = "serde")]
+impl Serialize for SvelteEachIndex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-impl From<HtmlAttributeInitializerClause> for SyntaxNode {
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteEachIndexFields {
+    pub comma_token: SyntaxResult<SyntaxToken>,
+    pub value: SyntaxResult<HtmlName>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteEachKey {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteEachKey {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteEachKeyFields {
+        SvelteEachKeyFields {
+            l_paren_token: self.l_paren_token(),
+            expression: self.expression(),
+            r_paren_token: self.r_paren_token(),
+        }
+    }
+    pub fn l_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn expression(&self) -> SyntaxResult<HtmlTextExpression> {
+        support::required_node(&self.syntax, 1usize)
+    }
+    pub fn r_paren_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for SvelteEachKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteEachKeyFields {
+    pub l_paren_token: SyntaxResult<SyntaxToken>,
+    pub expression: SyntaxResult<HtmlTextExpression>,
+    pub r_paren_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteEachOpeningBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteEachOpeningBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteEachOpeningBlockFields {
+        SvelteEachOpeningBlockFields {
+            sv_curly_hash_token: self.sv_curly_hash_token(),
+            each_token: self.each_token(),
+            list: self.list(),
+            as_token: self.as_token(),
+            item: self.item(),
+            index: self.index(),
+            key: self.key(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn sv_curly_hash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn each_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn list(&self) -> SyntaxResult<HtmlTextExpression> {
+        support::required_node(&self.syntax, 2usize)
+    }
+    pub fn as_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+    pub fn item(&self) -> SyntaxResult<HtmlTextExpression> {
+        support::required_node(&self.syntax, 4usize)
+    }
+    pub fn index(&self) -> Option<SvelteEachIndex> {
+        support::node(&self.syntax, 5usize)
+    }
+    pub fn key(&self) -> Option<SvelteEachKey> {
+        support::node(&self.syntax, 6usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 7usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for SvelteEachOpeningBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteEachOpeningBlockFields {
+    pub sv_curly_hash_token: SyntaxResult<SyntaxToken>,
+    pub each_token: SyntaxResult<SyntaxToken>,
+    pub list: SyntaxResult<HtmlTextExpression>,
+    pub as_token: SyntaxResult<SyntaxToken>,
+    pub item: SyntaxResult<HtmlTextExpression>,
+    pub index: Option<SvelteEachIndex>,
+    pub key: Option<SvelteEachKey>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteElseClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteElseClause {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteElseClauseFields {
+        SvelteElseClauseFields {
+            sv_curly_colon_token: self.sv_curly_colon_token(),
+            else_token: self.else_token(),
+            r_curly_token: self.r_curly_token(),
+            children: self.children(),
+        }
+    }
+    pub fn sv_curly_colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn else_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn children(&self) -> HtmlElementList {
+        support::list(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for SvelteElseClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteElseClauseFields {
+    pub sv_curly_colon_token: SyntaxResult<SyntaxToken>,
+    pub else_token: SyntaxResult<SyntaxToken>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+    pub children: HtmlElementList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteElseIfClause {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteElseIfClause {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteElseIfClauseFields {
+        SvelteElseIfClauseFields {
+            sv_curly_colon_token: self.sv_curly_colon_token(),
+            else_token: self.else_token(),
+            if_token: self.if_token(),
+            condition: self.condition(),
+            r_curly_token: self.r_curly_token(),
+            children: self.children(),
+        }
+    }
+    pub fn sv_curly_colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn else_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn if_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+    pub fn condition(&self) -> SyntaxResult<HtmlTextExpression> {
+        support::required_node(&self.syntax, 3usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 4usize)
+    }
+    pub fn children(&self) -> HtmlElementList {
+        support::list(&self.syntax, 5usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for SvelteElseIfClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteElseIfClauseFields {
+    pub sv_curly_colon_token: SyntaxResult<SyntaxToken>,
+    pub else_token: SyntaxResult<SyntaxToken>,
+    pub if_token: SyntaxResult<SyntaxToken>,
+    pub condition: SyntaxResult<HtmlTextExpression>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+    pub children: HtmlElementList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteIfBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteIfBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteIfBlockFields {
+        SvelteIfBlockFields {
+            opening_block: self.opening_block(),
+            children: self.children(),
+            else_if_clauses: self.else_if_clauses(),
+            else_clause: self.else_clause(),
+            closing_block: self.closing_block(),
+        }
+    }
+    pub fn opening_block(&self) -> SyntaxResult<SvelteIfOpeningBlock> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn children(&self) -> HtmlElementList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn else_if_clauses(&self) -> SvelteElseIfClauseList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn else_clause(&self) -> Option<SvelteElseClause> {
+        support::node(&self.syntax, 3usize)
+    }
+    pub fn closing_block(&self) -> SyntaxResult<SvelteIfClosingBlock> {
+        support::required_node(&self.syntax, 4usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for SvelteIfBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteIfBlockFields {
+    pub opening_block: SyntaxResult<SvelteIfOpeningBlock>,
+    pub children: HtmlElementList,
+    pub else_if_clauses: SvelteElseIfClauseList,
+    pub else_clause: Option<SvelteElseClause>,
+    pub closing_block: SyntaxResult<SvelteIfClosingBlock>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteIfClosingBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteIfClosingBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteIfClosingBlockFields {
+        SvelteIfClosingBlockFields {
+            sv_curly_slash_token: self.sv_curly_slash_token(),
+            if_token: self.if_token(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn sv_curly_slash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn if_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for SvelteIfClosingBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteIfClosingBlockFields {
+    pub sv_curly_slash_token: SyntaxResult<SyntaxToken>,
+    pub if_token: SyntaxResult<SyntaxToken>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SvelteIfOpeningBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl SvelteIfOpeningBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> SvelteIfOpeningBlockFields {
+        SvelteIfOpeningBlockFields {
+            sv_curly_hash_token: self.sv_curly_hash_token(),
+            if_token: self.if_token(),
+            condition: self.condition(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn sv_curly_hash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn if_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn condition(&self) -> SyntaxResult<HtmlTextExpression> {
+        support::required_node(&self.syntax, 2usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for SvelteIfOpeningBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct SvelteIfOpeningBlockFields {
+    pub sv_curly_hash_token: SyntaxResult<SyntaxToken>,
+    pub if_token: SyntaxResult<SyntaxToken>,
+    pub condition: SyntaxResult<HtmlTextExpression>,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyHtmlAttribute {
+    HtmlAttribute(HtmlAttribute),
+    HtmlBogusAttribute(HtmlBogusAttribute),
+    HtmlSingleTextExpression(HtmlSingleTextExpression),
+}
+impl AnyHtmlAttribute {
+    pub fn as_html_attribute(&self) -> Option<&HtmlAttribute> {
+        match &self {
+            AnyHtmlAttribute::HtmlAttribute(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_bogus_attribute(&self) -> Option<&HtmlBogusAttribute> {
+        match &self {
+            AnyHtmlAttribute::HtmlBogusAttribute(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_single_text_expression(&self) -> Option<&HtmlSingleTextExpression> {
+        match &self {
+            AnyHtmlAttribute::HtmlSingleTextExpression(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyHtmlAttributeValue {
+    HtmlSingleTextExpression(HtmlSingleTextExpression),
+    HtmlString(HtmlString),
+}
+impl AnyHtmlAttributeValue {
+    pub fn as_html_single_text_expression(&self) -> Option<&HtmlSingleTextExpression> {
+        match &self {
+            AnyHtmlAttributeValue::HtmlSingleTextExpression(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_string(&self) -> Option<&HtmlString> {
+        match &self {
+            AnyHtmlAttributeValue::HtmlString(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyHtmlElement {
+    HtmlBogusElement(HtmlBogusElement),
+    HtmlContent(HtmlContent),
+    HtmlElement(HtmlElement),
+    HtmlSelfClosingElement(HtmlSelfClosingElement),
+    HtmlSingleTextExpression(HtmlSingleTextExpression),
+    SvelteEachBlock(SvelteEachBlock),
+    SvelteIfBlock(SvelteIfBlock),
+}
+impl AnyHtmlElement {
+    pub fn as_html_bogus_element(&self) -> Option<&HtmlBogusElement> {
+        match &self {
+            AnyHtmlElement::HtmlBogusElement(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_content(&self) -> Option<&HtmlContent> {
+        match &self {
+            AnyHtmlElement::HtmlContent(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_element(&self) -> Option<&HtmlElement> {
+        match &self {
+            AnyHtmlElement::HtmlElement(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_self_closing_element(&self) -> Option<&HtmlSelfClosingElement> {
+        match &self {
+            AnyHtmlElement::HtmlSelfClosingElement(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_html_single_text_expression(&self) -> Option<&HtmlSingleTextExpression> {
+        match &self {
+            AnyHtmlElement::HtmlSingleTextExpression(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_svelte_each_block(&self) -> Option<&SvelteEachBlock> {
+        match &self {
+            AnyHtmlElement::SvelteEachBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_svelte_if_block(&self) -> Option<&SvelteIfBlock> {
+        match &self {
+            AnyHtmlElement::SvelteIfBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for AstroFrontmatterElement {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(ASTRO_FRONTMATTER_ELEMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == ASTRO_FRONTMATTER_ELEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for AstroFrontmatterElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AstroFrontmatterElement")
+            .field(
+                "l_fence_token",
+                &support::DebugSyntaxResult(self.l_fence_token()),
+            )
+            .field(
+                "content_token",
+                &support::DebugOptionalElement(self.content_token()),
+            )
+            .field(
+                "r_fence_token",
+                &support::DebugSyntaxResult(self.r_fence_token()),
+            )
+            .finish()
+    }
+}
+impl From<AstroFrontmatterElement> for SyntaxNode {
+    fn from(n: AstroFrontmatterElement) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<AstroFrontmatterElement> for SyntaxElement {
+    fn from(n: AstroFrontmatterElement) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlAttribute {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ATTRIBUTE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_ATTRIBUTE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlAttribute")
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "initializer",
+                &support::DebugOptionalElement(self.initializer()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlAttribute> for SyntaxNode {
+    fn from(n: HtmlAttribute) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlAttribute> for SyntaxElement {
+    fn from(n: HtmlAttribute) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlAttributeInitializerClause {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ATTRIBUTE_INITIALIZER_CLAUSE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_ATTRIBUTE_INITIALIZER_CLAUSE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlAttributeInitializerClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlAttributeInitializerClause")
+            .field("eq_token", &support::DebugSyntaxResult(self.eq_token()))
+            .field("value", &support::DebugSyntaxResult(self.value()))
+            .finish()
+    }
+}
+impl From<HtmlAttributeInitializerClause> for SyntaxNode {
     fn from(n: HtmlAttributeInitializerClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlAttributeInitializerClause> for SyntaxElement {
-    fn from(n: HtmlAttributeInitializerClause) -> SyntaxElement {
+impl From<HtmlAttributeInitializerClause> for SyntaxElement {
+    fn from(n: HtmlAttributeInitializerClause) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlClosingElement {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_CLOSING_ELEMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_CLOSING_ELEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlClosingElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlClosingElement")
+            .field(
+                "l_angle_token",
+                &support::DebugSyntaxResult(self.l_angle_token()),
+            )
+            .field(
+                "slash_token",
+                &support::DebugSyntaxResult(self.slash_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field(
+                "r_angle_token",
+                &support::DebugSyntaxResult(self.r_angle_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlClosingElement> for SyntaxNode {
+    fn from(n: HtmlClosingElement) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlClosingElement> for SyntaxElement {
+    fn from(n: HtmlClosingElement) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlContent {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_CONTENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_CONTENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlContent")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlContent> for SyntaxNode {
+    fn from(n: HtmlContent) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlContent> for SyntaxElement {
+    fn from(n: HtmlContent) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlDirective {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_DIRECTIVE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_DIRECTIVE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlDirective")
+            .field(
+                "l_angle_token",
+                &support::DebugSyntaxResult(self.l_angle_token()),
+            )
+            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+            .field(
+                "doctype_token",
+                &support::DebugSyntaxResult(self.doctype_token()),
+            )
+            .field(
+                "html_token",
+                &support::DebugOptionalElement(self.html_token()),
+            )
+            .field(
+                "quirk_token",
+                &support::DebugOptionalElement(self.quirk_token()),
+            )
+            .field(
+                "public_id_token",
+                &support::DebugOptionalElement(self.public_id_token()),
+            )
+            .field(
+                "system_id_token",
+                &support::DebugOptionalElement(self.system_id_token()),
+            )
+            .field(
+                "r_angle_token",
+                &support::DebugSyntaxResult(self.r_angle_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlDirective> for SyntaxNode {
+    fn from(n: HtmlDirective) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlDirective> for SyntaxElement {
+    fn from(n: HtmlDirective) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlElement {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ELEMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_ELEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlElement")
+            .field(
+                "opening_element",
+                &support::DebugSyntaxResult(self.opening_element()),
+            )
+            .field("children", &self.children())
+            .field(
+                "closing_element",
+                &support::DebugSyntaxResult(self.closing_element()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlElement> for SyntaxNode {
+    fn from(n: HtmlElement) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlElement> for SyntaxElement {
+    fn from(n: HtmlElement) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlName {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_NAME as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_NAME
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlName")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlName> for SyntaxNode {
+    fn from(n: HtmlName) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlName> for SyntaxElement {
+    fn from(n: HtmlName) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlOpeningElement {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_OPENING_ELEMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_OPENING_ELEMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlOpeningElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlOpeningElement")
+            .field(
+                "l_angle_token",
+                &support::DebugSyntaxResult(self.l_angle_token()),
+            )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("attributes", &self.attributes())
+            .field(
+                "r_angle_token",
+                &support::DebugSyntaxResult(self.r_angle_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlOpeningElement> for SyntaxNode {
+    fn from(n: HtmlOpeningElement) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlOpeningElement> for SyntaxElement {
+    fn from(n: HtmlOpeningElement) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlRoot {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ROOT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_ROOT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlRoot")
+            .field(
+                "bom_token",
+                &support::DebugOptionalElement(self.bom_token()),
+            )
+            .field(
+                "frontmatter",
+                &support::DebugOptionalElement(self.frontmatter()),
+            )
+            .field(
+                "directive",
+                &support::DebugOptionalElement(self.directive()),
+            )
+            .field("html", &self.html())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+            .finish()
+    }
+}
+impl From<HtmlRoot> for SyntaxNode {
+    fn from(n: HtmlRoot) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlRoot> for SyntaxElement {
+    fn from(n: HtmlRoot) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlClosingElement {
+impl AstNode for HtmlSelfClosingElement {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_CLOSING_ELEMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_SELF_CLOSING_ELEMENT as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_CLOSING_ELEMENT
+        kind == HTML_SELF_CLOSING_ELEMENT
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -696,18 +1788,19 @@ impl AstNode for HtmlClosingElement {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlClosingElement {
+impl std::fmt::Debug for HtmlSelfClosingElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlClosingElement")
+        f.debug_struct("HtmlSelfClosingElement")
             .field(
                 "l_angle_token",
                 &support::DebugSyntaxResult(self.l_angle_token()),
             )
+            .field("name", &support::DebugSyntaxResult(self.name()))
+            .field("attributes", &self.attributes())
             .field(
                 "slash_token",
-                &support::DebugSyntaxResult(self.slash_token()),
+                &support::DebugOptionalElement(self.slash_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
             .field(
                 "r_angle_token",
                 &support::DebugSyntaxResult(self.r_angle_token()),
@@ -715,22 +1808,22 @@ impl std::fmt::Debug for HtmlClosingElement {
             .finish()
     }
 }
-impl From<HtmlClosingElement> for SyntaxNode {
-    fn from(n: HtmlClosingElement) -> SyntaxNode {
+impl From<HtmlSelfClosingElement> for SyntaxNode {
+    fn from(n: HtmlSelfClosingElement) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlClosingElement> for SyntaxElement {
-    fn from(n: HtmlClosingElement) -> SyntaxElement {
+impl From<HtmlSelfClosingElement> for SyntaxElement {
+    fn from(n: HtmlSelfClosingElement) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlContent {
+impl AstNode for HtmlSingleTextExpression {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_CONTENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_SINGLE_TEXT_EXPRESSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_CONTENT
+        kind == HTML_SINGLE_TEXT_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -746,9 +1839,55 @@ impl AstNode for HtmlContent {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlContent {
+impl std::fmt::Debug for HtmlSingleTextExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlContent")
+        f.debug_struct("HtmlSingleTextExpression")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("expression", &support::DebugSyntaxResult(self.expression()))
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
+            .finish()
+    }
+}
+impl From<HtmlSingleTextExpression> for SyntaxNode {
+    fn from(n: HtmlSingleTextExpression) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlSingleTextExpression> for SyntaxElement {
+    fn from(n: HtmlSingleTextExpression) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for HtmlString {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_STRING as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == HTML_STRING
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for HtmlString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HtmlString")
             .field(
                 "value_token",
                 &support::DebugSyntaxResult(self.value_token()),
@@ -756,22 +1895,22 @@ impl std::fmt::Debug for HtmlContent {
             .finish()
     }
 }
-impl From<HtmlContent> for SyntaxNode {
-    fn from(n: HtmlContent) -> SyntaxNode {
+impl From<HtmlString> for SyntaxNode {
+    fn from(n: HtmlString) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlContent> for SyntaxElement {
-    fn from(n: HtmlContent) -> SyntaxElement {
+impl From<HtmlString> for SyntaxElement {
+    fn from(n: HtmlString) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlDirective {
+impl AstNode for HtmlTextExpression {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_DIRECTIVE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_TEXT_EXPRESSION as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_DIRECTIVE
+        kind == HTML_TEXT_EXPRESSION
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -787,57 +1926,170 @@ impl AstNode for HtmlDirective {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlDirective {
+impl std::fmt::Debug for HtmlTextExpression {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlDirective")
+        f.debug_struct("HtmlTextExpression")
             .field(
-                "l_angle_token",
-                &support::DebugSyntaxResult(self.l_angle_token()),
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
             )
-            .field("excl_token", &support::DebugSyntaxResult(self.excl_token()))
+            .finish()
+    }
+}
+impl From<HtmlTextExpression> for SyntaxNode {
+    fn from(n: HtmlTextExpression) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<HtmlTextExpression> for SyntaxElement {
+    fn from(n: HtmlTextExpression) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for SvelteEachBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_EACH_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SVELTE_EACH_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for SvelteEachBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SvelteEachBlock")
             .field(
-                "doctype_token",
-                &support::DebugSyntaxResult(self.doctype_token()),
+                "opening_block",
+                &support::DebugSyntaxResult(self.opening_block()),
             )
+            .field("children", &self.children())
             .field(
-                "html_token",
-                &support::DebugOptionalElement(self.html_token()),
+                "else_clause",
+                &support::DebugOptionalElement(self.else_clause()),
             )
             .field(
-                "quirk_token",
-                &support::DebugOptionalElement(self.quirk_token()),
+                "closing_block",
+                &support::DebugSyntaxResult(self.closing_block()),
             )
+            .finish()
+    }
+}
+impl From<SvelteEachBlock> for SyntaxNode {
+    fn from(n: SvelteEachBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<SvelteEachBlock> for SyntaxElement {
+    fn from(n: SvelteEachBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for SvelteEachClosingBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_EACH_CLOSING_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SVELTE_EACH_CLOSING_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for SvelteEachClosingBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SvelteEachClosingBlock")
             .field(
-                "public_id_token",
-                &support::DebugOptionalElement(self.public_id_token()),
+                "sv_curly_slash_token",
+                &support::DebugSyntaxResult(self.sv_curly_slash_token()),
             )
+            .field("each_token", &support::DebugSyntaxResult(self.each_token()))
             .field(
-                "system_id_token",
-                &support::DebugOptionalElement(self.system_id_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
+            .finish()
+    }
+}
+impl From<SvelteEachClosingBlock> for SyntaxNode {
+    fn from(n: SvelteEachClosingBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<SvelteEachClosingBlock> for SyntaxElement {
+    fn from(n: SvelteEachClosingBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for SvelteEachIndex {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_EACH_INDEX as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SVELTE_EACH_INDEX
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for SvelteEachIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SvelteEachIndex")
             .field(
-                "r_angle_token",
-                &support::DebugSyntaxResult(self.r_angle_token()),
+                "comma_token",
+                &support::DebugSyntaxResult(self.comma_token()),
             )
+            .field("value", &support::DebugSyntaxResult(self.value()))
             .finish()
     }
 }
-impl From<HtmlDirective> for SyntaxNode {
-    fn from(n: HtmlDirective) -> SyntaxNode {
+impl From<SvelteEachIndex> for SyntaxNode {
+    fn from(n: SvelteEachIndex) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlDirective> for SyntaxElement {
-    fn from(n: HtmlDirective) -> SyntaxElement {
+impl From<SvelteEachIndex> for SyntaxElement {
+    fn from(n: SvelteEachIndex) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlElement {
+impl AstNode for SvelteEachKey {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ELEMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_EACH_KEY as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_ELEMENT
+        kind == SVELTE_EACH_KEY
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -853,37 +2105,88 @@ impl AstNode for HtmlElement {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlElement {
+impl std::fmt::Debug for SvelteEachKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlElement")
+        f.debug_struct("SvelteEachKey")
             .field(
-                "opening_element",
-                &support::DebugSyntaxResult(self.opening_element()),
+                "l_paren_token",
+                &support::DebugSyntaxResult(self.l_paren_token()),
             )
-            .field("children", &self.children())
+            .field("expression", &support::DebugSyntaxResult(self.expression()))
             .field(
-                "closing_element",
-                &support::DebugSyntaxResult(self.closing_element()),
+                "r_paren_token",
+                &support::DebugSyntaxResult(self.r_paren_token()),
+            )
+            .finish()
+    }
+}
+impl From<SvelteEachKey> for SyntaxNode {
+    fn from(n: SvelteEachKey) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<SvelteEachKey> for SyntaxElement {
+    fn from(n: SvelteEachKey) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for SvelteEachOpeningBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_EACH_OPENING_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SVELTE_EACH_OPENING_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for SvelteEachOpeningBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SvelteEachOpeningBlock")
+            .field(
+                "sv_curly_hash_token",
+                &support::DebugSyntaxResult(self.sv_curly_hash_token()),
+            )
+            .field("each_token", &support::DebugSyntaxResult(self.each_token()))
+            .field("list", &support::DebugSyntaxResult(self.list()))
+            .field("as_token", &support::DebugSyntaxResult(self.as_token()))
+            .field("item", &support::DebugSyntaxResult(self.item()))
+            .field("index", &support::DebugOptionalElement(self.index()))
+            .field("key", &support::DebugOptionalElement(self.key()))
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<HtmlElement> for SyntaxNode {
-    fn from(n: HtmlElement) -> SyntaxNode {
+impl From<SvelteEachOpeningBlock> for SyntaxNode {
+    fn from(n: SvelteEachOpeningBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlElement> for SyntaxElement {
-    fn from(n: HtmlElement) -> SyntaxElement {
+impl From<SvelteEachOpeningBlock> for SyntaxElement {
+    fn from(n: SvelteEachOpeningBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlName {
+impl AstNode for SvelteElseClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_NAME as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_ELSE_CLAUSE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_NAME
+        kind == SVELTE_ELSE_CLAUSE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -899,32 +2202,38 @@ impl AstNode for HtmlName {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlName {
+impl std::fmt::Debug for SvelteElseClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlName")
+        f.debug_struct("SvelteElseClause")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "sv_curly_colon_token",
+                &support::DebugSyntaxResult(self.sv_curly_colon_token()),
             )
+            .field("else_token", &support::DebugSyntaxResult(self.else_token()))
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
+            .field("children", &self.children())
             .finish()
     }
 }
-impl From<HtmlName> for SyntaxNode {
-    fn from(n: HtmlName) -> SyntaxNode {
+impl From<SvelteElseClause> for SyntaxNode {
+    fn from(n: SvelteElseClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlName> for SyntaxElement {
-    fn from(n: HtmlName) -> SyntaxElement {
+impl From<SvelteElseClause> for SyntaxElement {
+    fn from(n: SvelteElseClause) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlOpeningElement {
+impl AstNode for SvelteElseIfClause {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_OPENING_ELEMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_ELSE_IF_CLAUSE as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_OPENING_ELEMENT
+        kind == SVELTE_ELSE_IF_CLAUSE
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -940,38 +2249,40 @@ impl AstNode for HtmlOpeningElement {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlOpeningElement {
+impl std::fmt::Debug for SvelteElseIfClause {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlOpeningElement")
+        f.debug_struct("SvelteElseIfClause")
             .field(
-                "l_angle_token",
-                &support::DebugSyntaxResult(self.l_angle_token()),
+                "sv_curly_colon_token",
+                &support::DebugSyntaxResult(self.sv_curly_colon_token()),
             )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("attributes", &self.attributes())
+            .field("else_token", &support::DebugSyntaxResult(self.else_token()))
+            .field("if_token", &support::DebugSyntaxResult(self.if_token()))
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
             .field(
-                "r_angle_token",
-                &support::DebugSyntaxResult(self.r_angle_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
+            .field("children", &self.children())
             .finish()
     }
 }
-impl From<HtmlOpeningElement> for SyntaxNode {
-    fn from(n: HtmlOpeningElement) -> SyntaxNode {
+impl From<SvelteElseIfClause> for SyntaxNode {
+    fn from(n: SvelteElseIfClause) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlOpeningElement> for SyntaxElement {
-    fn from(n: HtmlOpeningElement) -> SyntaxElement {
+impl From<SvelteElseIfClause> for SyntaxElement {
+    fn from(n: SvelteElseIfClause) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlRoot {
+impl AstNode for SvelteIfBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_ROOT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_IF_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_ROOT
+        kind == SVELTE_IF_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -987,38 +2298,42 @@ impl AstNode for HtmlRoot {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlRoot {
+impl std::fmt::Debug for SvelteIfBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlRoot")
+        f.debug_struct("SvelteIfBlock")
             .field(
-                "bom_token",
-                &support::DebugOptionalElement(self.bom_token()),
+                "opening_block",
+                &support::DebugSyntaxResult(self.opening_block()),
             )
+            .field("children", &self.children())
+            .field("else_if_clauses", &self.else_if_clauses())
             .field(
-                "directive",
-                &support::DebugOptionalElement(self.directive()),
+                "else_clause",
+                &support::DebugOptionalElement(self.else_clause()),
+            )
+            .field(
+                "closing_block",
+                &support::DebugSyntaxResult(self.closing_block()),
             )
-            .field("html", &support::DebugOptionalElement(self.html()))
-            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
             .finish()
     }
 }
-impl From<HtmlRoot> for SyntaxNode {
-    fn from(n: HtmlRoot) -> SyntaxNode {
+impl From<SvelteIfBlock> for SyntaxNode {
+    fn from(n: SvelteIfBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlRoot> for SyntaxElement {
-    fn from(n: HtmlRoot) -> SyntaxElement {
+impl From<SvelteIfBlock> for SyntaxElement {
+    fn from(n: SvelteIfBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlSelfClosingElement {
+impl AstNode for SvelteIfClosingBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_SELF_CLOSING_ELEMENT as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_IF_CLOSING_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_SELF_CLOSING_ELEMENT
+        kind == SVELTE_IF_CLOSING_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1034,42 +2349,37 @@ impl AstNode for HtmlSelfClosingElement {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlSelfClosingElement {
+impl std::fmt::Debug for SvelteIfClosingBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlSelfClosingElement")
-            .field(
-                "l_angle_token",
-                &support::DebugSyntaxResult(self.l_angle_token()),
-            )
-            .field("name", &support::DebugSyntaxResult(self.name()))
-            .field("attributes", &self.attributes())
+        f.debug_struct("SvelteIfClosingBlock")
             .field(
-                "slash_token",
-                &support::DebugSyntaxResult(self.slash_token()),
+                "sv_curly_slash_token",
+                &support::DebugSyntaxResult(self.sv_curly_slash_token()),
             )
+            .field("if_token", &support::DebugSyntaxResult(self.if_token()))
             .field(
-                "r_angle_token",
-                &support::DebugSyntaxResult(self.r_angle_token()),
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<HtmlSelfClosingElement> for SyntaxNode {
-    fn from(n: HtmlSelfClosingElement) -> SyntaxNode {
+impl From<SvelteIfClosingBlock> for SyntaxNode {
+    fn from(n: SvelteIfClosingBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlSelfClosingElement> for SyntaxElement {
-    fn from(n: HtmlSelfClosingElement) -> SyntaxElement {
+impl From<SvelteIfClosingBlock> for SyntaxElement {
+    fn from(n: SvelteIfClosingBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
-impl AstNode for HtmlString {
+impl AstNode for SvelteIfOpeningBlock {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(HTML_STRING as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_IF_OPENING_BLOCK as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == HTML_STRING
+        kind == SVELTE_IF_OPENING_BLOCK
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
@@ -1085,23 +2395,29 @@ impl AstNode for HtmlString {
         self.syntax
     }
 }
-impl std::fmt::Debug for HtmlString {
+impl std::fmt::Debug for SvelteIfOpeningBlock {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("HtmlString")
+        f.debug_struct("SvelteIfOpeningBlock")
             .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
+                "sv_curly_hash_token",
+                &support::DebugSyntaxResult(self.sv_curly_hash_token()),
+            )
+            .field("if_token", &support::DebugSyntaxResult(self.if_token()))
+            .field("condition", &support::DebugSyntaxResult(self.condition()))
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
             )
             .finish()
     }
 }
-impl From<HtmlString> for SyntaxNode {
-    fn from(n: HtmlString) -> SyntaxNode {
+impl From<SvelteIfOpeningBlock> for SyntaxNode {
+    fn from(n: SvelteIfOpeningBlock) -> SyntaxNode {
         n.syntax
     }
 }
-impl From<HtmlString> for SyntaxElement {
-    fn from(n: HtmlString) -> SyntaxElement {
+impl From<SvelteIfOpeningBlock> for SyntaxElement {
+    fn from(n: SvelteIfOpeningBlock) -> SyntaxElement {
         n.syntax.into()
     }
 }
@@ -1115,12 +2431,21 @@ impl From<HtmlBogusAttribute> for AnyHtmlAttribute {
         AnyHtmlAttribute::HtmlBogusAttribute(node)
     }
 }
+impl From<HtmlSingleTextExpression> for AnyHtmlAttribute {
+    fn from(node: HtmlSingleTextExpression) -> AnyHtmlAttribute {
+        AnyHtmlAttribute::HtmlSingleTextExpression(node)
+    }
+}
 impl AstNode for AnyHtmlAttribute {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        HtmlAttribute::KIND_SET.union(HtmlBogusAttribute::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> = HtmlAttribute::KIND_SET
+        .union(HtmlBogusAttribute::KIND_SET)
+        .union(HtmlSingleTextExpression::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, HTML_ATTRIBUTE | HTML_BOGUS_ATTRIBUTE)
+        matches!(
+            kind,
+            HTML_ATTRIBUTE | HTML_BOGUS_ATTRIBUTE | HTML_SINGLE_TEXT_EXPRESSION
+        )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
         let res = match syntax.kind() {
@@ -1128,6 +2453,9 @@ impl AstNode for AnyHtmlAttribute {
             HTML_BOGUS_ATTRIBUTE => {
                 AnyHtmlAttribute::HtmlBogusAttribute(HtmlBogusAttribute { syntax })
             }
+            HTML_SINGLE_TEXT_EXPRESSION => {
+                AnyHtmlAttribute::HtmlSingleTextExpression(HtmlSingleTextExpression { syntax })
+            }
             _ => return None,
         };
         Some(res)
@@ -1136,12 +2464,14 @@ impl AstNode for AnyHtmlAttribute {
         match self {
             AnyHtmlAttribute::HtmlAttribute(it) => &it.syntax,
             AnyHtmlAttribute::HtmlBogusAttribute(it) => &it.syntax,
+            AnyHtmlAttribute::HtmlSingleTextExpression(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
         match self {
             AnyHtmlAttribute::HtmlAttribute(it) => it.syntax,
             AnyHtmlAttribute::HtmlBogusAttribute(it) => it.syntax,
+            AnyHtmlAttribute::HtmlSingleTextExpression(it) => it.syntax,
         }
     }
 }
@@ -1150,6 +2480,7 @@ impl std::fmt::Debug for AnyHtmlAttribute {
         match self {
             AnyHtmlAttribute::HtmlAttribute(it) => std::fmt::Debug::fmt(it, f),
             AnyHtmlAttribute::HtmlBogusAttribute(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlAttribute::HtmlSingleTextExpression(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -1158,6 +2489,7 @@ impl From<AnyHtmlAttribute> for SyntaxNode {
         match n {
             AnyHtmlAttribute::HtmlAttribute(it) => it.into(),
             AnyHtmlAttribute::HtmlBogusAttribute(it) => it.into(),
+            AnyHtmlAttribute::HtmlSingleTextExpression(it) => it.into(),
         }
     }
 }
@@ -1167,6 +2499,68 @@ impl From<AnyHtmlAttribute> for SyntaxElement {
         node.into()
     }
 }
+impl From<HtmlSingleTextExpression> for AnyHtmlAttributeValue {
+    fn from(node: HtmlSingleTextExpression) -> AnyHtmlAttributeValue {
+        AnyHtmlAttributeValue::HtmlSingleTextExpression(node)
+    }
+}
+impl From<HtmlString> for AnyHtmlAttributeValue {
+    fn from(node: HtmlString) -> AnyHtmlAttributeValue {
+        AnyHtmlAttributeValue::HtmlString(node)
+    }
+}
+impl AstNode for AnyHtmlAttributeValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        HtmlSingleTextExpression::KIND_SET.union(HtmlString::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, HTML_SINGLE_TEXT_EXPRESSION | HTML_STRING)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            HTML_SINGLE_TEXT_EXPRESSION => {
+                AnyHtmlAttributeValue::HtmlSingleTextExpression(HtmlSingleTextExpression { syntax })
+            }
+            HTML_STRING => AnyHtmlAttributeValue::HtmlString(HtmlString { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyHtmlAttributeValue::HtmlSingleTextExpression(it) => &it.syntax,
+            AnyHtmlAttributeValue::HtmlString(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyHtmlAttributeValue::HtmlSingleTextExpression(it) => it.syntax,
+            AnyHtmlAttributeValue::HtmlString(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyHtmlAttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyHtmlAttributeValue::HtmlSingleTextExpression(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlAttributeValue::HtmlString(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyHtmlAttributeValue> for SyntaxNode {
+    fn from(n: AnyHtmlAttributeValue) -> SyntaxNode {
+        match n {
+            AnyHtmlAttributeValue::HtmlSingleTextExpression(it) => it.into(),
+            AnyHtmlAttributeValue::HtmlString(it) => it.into(),
+        }
+    }
+}
+impl From<AnyHtmlAttributeValue> for SyntaxElement {
+    fn from(n: AnyHtmlAttributeValue) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
 impl From<HtmlBogusElement> for AnyHtmlElement {
     fn from(node: HtmlBogusElement) -> AnyHtmlElement {
         AnyHtmlElement::HtmlBogusElement(node)
@@ -1187,16 +2581,40 @@ impl From<HtmlSelfClosingElement> for AnyHtmlElement {
         AnyHtmlElement::HtmlSelfClosingElement(node)
     }
 }
+impl From<HtmlSingleTextExpression> for AnyHtmlElement {
+    fn from(node: HtmlSingleTextExpression) -> AnyHtmlElement {
+        AnyHtmlElement::HtmlSingleTextExpression(node)
+    }
+}
+impl From<SvelteEachBlock> for AnyHtmlElement {
+    fn from(node: SvelteEachBlock) -> AnyHtmlElement {
+        AnyHtmlElement::SvelteEachBlock(node)
+    }
+}
+impl From<SvelteIfBlock> for AnyHtmlElement {
+    fn from(node: SvelteIfBlock) -> AnyHtmlElement {
+        AnyHtmlElement::SvelteIfBlock(node)
+    }
+}
 impl AstNode for AnyHtmlElement {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> = HtmlBogusElement::KIND_SET
         .union(HtmlContent::KIND_SET)
         .union(HtmlElement::KIND_SET)
-        .union(HtmlSelfClosingElement::KIND_SET);
+        .union(HtmlSelfClosingElement::KIND_SET)
+        .union(HtmlSingleTextExpression::KIND_SET)
+        .union(SvelteEachBlock::KIND_SET)
+        .union(SvelteIfBlock::KIND_SET);
     fn can_cast(kind: SyntaxKind) -> bool {
         matches!(
             kind,
-            HTML_BOGUS_ELEMENT | HTML_CONTENT | HTML_ELEMENT | HTML_SELF_CLOSING_ELEMENT
+            HTML_BOGUS_ELEMENT
+                | HTML_CONTENT
+                | HTML_ELEMENT
+                | HTML_SELF_CLOSING_ELEMENT
+                | HTML_SINGLE_TEXT_EXPRESSION
+                | SVELTE_EACH_BLOCK
+                | SVELTE_IF_BLOCK
         )
     }
     fn cast(syntax: SyntaxNode) -> Option<Self> {
@@ -1207,6 +2625,11 @@ impl AstNode for AnyHtmlElement {
             HTML_SELF_CLOSING_ELEMENT => {
                 AnyHtmlElement::HtmlSelfClosingElement(HtmlSelfClosingElement { syntax })
             }
+            HTML_SINGLE_TEXT_EXPRESSION => {
+                AnyHtmlElement::HtmlSingleTextExpression(HtmlSingleTextExpression { syntax })
+            }
+            SVELTE_EACH_BLOCK => AnyHtmlElement::SvelteEachBlock(SvelteEachBlock { syntax }),
+            SVELTE_IF_BLOCK => AnyHtmlElement::SvelteIfBlock(SvelteIfBlock { syntax }),
             _ => return None,
         };
         Some(res)
@@ -1217,6 +2640,9 @@ impl AstNode for AnyHtmlElement {
             AnyHtmlElement::HtmlContent(it) => &it.syntax,
             AnyHtmlElement::HtmlElement(it) => &it.syntax,
             AnyHtmlElement::HtmlSelfClosingElement(it) => &it.syntax,
+            AnyHtmlElement::HtmlSingleTextExpression(it) => &it.syntax,
+            AnyHtmlElement::SvelteEachBlock(it) => &it.syntax,
+            AnyHtmlElement::SvelteIfBlock(it) => &it.syntax,
         }
     }
     fn into_syntax(self) -> SyntaxNode {
@@ -1225,6 +2651,9 @@ impl AstNode for AnyHtmlElement {
             AnyHtmlElement::HtmlContent(it) => it.syntax,
             AnyHtmlElement::HtmlElement(it) => it.syntax,
             AnyHtmlElement::HtmlSelfClosingElement(it) => it.syntax,
+            AnyHtmlElement::HtmlSingleTextExpression(it) => it.syntax,
+            AnyHtmlElement::SvelteEachBlock(it) => it.syntax,
+            AnyHtmlElement::SvelteIfBlock(it) => it.syntax,
         }
     }
 }
@@ -1235,6 +2664,9 @@ impl std::fmt::Debug for AnyHtmlElement {
             AnyHtmlElement::HtmlContent(it) => std::fmt::Debug::fmt(it, f),
             AnyHtmlElement::HtmlElement(it) => std::fmt::Debug::fmt(it, f),
             AnyHtmlElement::HtmlSelfClosingElement(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlElement::HtmlSingleTextExpression(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlElement::SvelteEachBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyHtmlElement::SvelteIfBlock(it) => std::fmt::Debug::fmt(it, f),
         }
     }
 }
@@ -1245,6 +2677,9 @@ impl From<AnyHtmlElement> for SyntaxNode {
             AnyHtmlElement::HtmlContent(it) => it.into(),
             AnyHtmlElement::HtmlElement(it) => it.into(),
             AnyHtmlElement::HtmlSelfClosingElement(it) => it.into(),
+            AnyHtmlElement::HtmlSingleTextExpression(it) => it.into(),
+            AnyHtmlElement::SvelteEachBlock(it) => it.into(),
+            AnyHtmlElement::SvelteIfBlock(it) => it.into(),
         }
     }
 }
@@ -1259,11 +2694,21 @@ impl std::fmt::Display for AnyHtmlAttribute {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AnyHtmlAttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for AnyHtmlElement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for AstroFrontmatterElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for HtmlAttribute {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
@@ -1314,11 +2759,71 @@ impl std::fmt::Display for HtmlSelfClosingElement {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for HtmlSingleTextExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 impl std::fmt::Display for HtmlString {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         std::fmt::Display::fmt(self.syntax(), f)
     }
 }
+impl std::fmt::Display for HtmlTextExpression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteEachBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteEachClosingBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteEachIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteEachKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteEachOpeningBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteElseClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteElseIfClause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteIfBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteIfClosingBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for SvelteIfOpeningBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct HtmlBogus {
@@ -1656,6 +3161,89 @@ impl IntoIterator for HtmlElementList {
         self.iter()
     }
 }
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct SvelteElseIfClauseList {
+    syntax_list: SyntaxList,
+}
+impl SvelteElseIfClauseList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for SvelteElseIfClauseList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(SVELTE_ELSE_IF_CLAUSE_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SVELTE_ELSE_IF_CLAUSE_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<SvelteElseIfClauseList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(SvelteElseIfClauseList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for SvelteElseIfClauseList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for SvelteElseIfClauseList {
+    type Language = Language;
+    type Node = SvelteElseIfClause;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for SvelteElseIfClauseList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SvelteElseIfClauseList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &SvelteElseIfClauseList {
+    type Item = SvelteElseIfClause;
+    type IntoIter = AstNodeListIterator<Language, SvelteElseIfClause>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for SvelteElseIfClauseList {
+    type Item = SvelteElseIfClause;
+    type IntoIter = AstNodeListIterator<Language, SvelteElseIfClause>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
 #[derive(Clone)]
 pub struct DebugSyntaxElementChildren(pub SyntaxElementChildren);
 impl Debug for DebugSyntaxElementChildren {
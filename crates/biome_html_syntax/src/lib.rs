@@ -1,5 +1,6 @@
 #[macro_use]
 mod generated;
+pub mod element_ext;
 mod file_source;
 mod syntax_node;
 
@@ -8,7 +9,7 @@ pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKi
 pub use file_source::HtmlFileSource;
 pub use syntax_node::*;
 
-use crate::HtmlSyntaxKind::{HTML_BOGUS, HTML_BOGUS_ATTRIBUTE};
+use crate::HtmlSyntaxKind::{HTML_BOGUS, HTML_BOGUS_ATTRIBUTE, HTML_BOGUS_ELEMENT};
 use biome_rowan::{AstNode, RawSyntaxKind, SyntaxKind, TokenText};
 
 impl From<u16> for HtmlSyntaxKind {
@@ -51,7 +52,7 @@ impl biome_rowan::SyntaxKind for HtmlSyntaxKind {
     fn to_bogus(&self) -> Self {
         match self {
             kind if AnyHtmlAttribute::can_cast(*kind) => HTML_BOGUS_ATTRIBUTE,
-            kind if AnyHtmlElement::can_cast(*kind) => HTML_BOGUS_ATTRIBUTE,
+            kind if AnyHtmlElement::can_cast(*kind) => HTML_BOGUS_ELEMENT,
 
             _ => HTML_BOGUS,
         }
@@ -105,12 +106,13 @@ impl TryFrom<HtmlSyntaxKind> for TriviaPieceKind {
     }
 }
 
-/// Text of `token`, excluding all trivia and removing quotes if `token` is a string literal.
+/// Text of `token`, excluding all trivia and removing quotes if `token` is a quoted string literal.
 pub fn inner_string_text(token: &HtmlSyntaxToken) -> TokenText {
     let mut text = token.token_text_trimmed();
-    if token.kind() == HtmlSyntaxKind::HTML_STRING_LITERAL {
+    // The value of an attribute can be unquoted
+    if token.kind() == HtmlSyntaxKind::HTML_STRING_LITERAL && text.starts_with(['"', '\'']) {
         // remove string delimiters
-        // SAFETY: string literal token have a delimiters at the start and the end of the string
+        // SAFETY: a quoted string literal token has a delimiter at the start and the end of the string
         let range = TextRange::new(1.into(), text.len() - TextSize::from(1));
         text = text.slice(range);
     }
@@ -4,15 +4,20 @@ use biome_analyze::{
     context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, SourceActionKind,
 };
 use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
 use biome_js_factory::make;
 use biome_js_syntax::{
     AnyJsImportClause, AnyJsModuleItem, AnyJsNamedImportSpecifier, JsImport, JsLanguage, JsModule,
-    JsSyntaxToken, TextRange, TriviaPieceKind, T,
+    JsNamedImportSpecifiers, JsSyntaxToken, TextRange, TriviaPieceKind, T,
 };
 use biome_rowan::{
     chain_trivia_pieces, syntax::SyntaxTrivia, AstNode, AstNodeExt, AstNodeList, AstSeparatedList,
-    BatchMutationExt, SyntaxTriviaPiece, TokenText, TriviaPiece,
+    BatchMutationExt, Direction, SyntaxTriviaPiece, TokenText, TriviaPiece,
 };
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
 
 use crate::JsRuleAction;
 
@@ -40,6 +45,68 @@ declare_rule! {
     /// import { Popup } from '@ui/Popup';
     /// import { createConnection } from '@server/database';
     /// ```
+    ///
+    /// The imports of the same source with named specifiers are merged in a single import.
+    /// The type-only imports are only merged with other type-only imports,
+    /// unless `mergeTypeImports` is enabled,
+    /// and the imports with import attributes are never merged.
+    /// The comments before and after a merged import are moved to the import that receives its specifiers.
+    ///
+    /// ```ts
+    /// import { lorem } from 'foo';
+    /// import type { Bar } from 'foo';
+    /// // Still needed
+    /// import { ipsum } from 'foo';
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The imports can be sorted in custom groups with the options `javascript.organizeImports`:
+    ///
+    /// ```json
+    /// {
+    ///     "javascript": {
+    ///         "organizeImports": {
+    ///             "groups": [
+    ///                 [":NODE:", ":BUN:"],
+    ///                 [":PACKAGE:"],
+    ///                 [":ALIAS:"],
+    ///                 [":PATH:"],
+    ///                 ["*.css"]
+    ///             ],
+    ///             "blankLinesBetweenGroups": true,
+    ///             "mergeImports": true,
+    ///             "mergeTypeImports": false
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// Each group is a list of matchers. The predefined matchers are:
+    ///
+    /// - `:NODE:`: the Node.js built-in modules, such as `node:fs` or `path`;
+    /// - `:BUN:`: the Bun built-in modules, such as `bun:test`;
+    /// - `:URL:`: the absolute URLs, such as `https://example.com/module.js`;
+    /// - `:PACKAGE:`: the packages, such as `react` or `npm:lodash`;
+    /// - `:ALIAS:`: the aliases, that is the sources starting with `#`, `@/`, `~`, `$` or `%`;
+    /// - `:PATH:`: the relative and absolute paths, such as `./utils` or `/src/utils`.
+    ///
+    /// The other matchers are patterns of import sources, where `*` matches any sequence of characters.
+    /// An import belongs to the first group that matches its source,
+    /// and the imports that don't match any group are placed after all the groups.
+    /// Inside a group, the imports are sorted like without groups.
+    ///
+    /// When `blankLinesBetweenGroups` is enabled, the imports separated by blank lines are sorted together,
+    /// a blank line is inserted between two groups and the blank lines inside a group are removed.
+    ///
+    /// When `mergeImports` is disabled, the imports of the same source are kept separate.
+    /// It's enabled by default.
+    ///
+    /// When `mergeTypeImports` is enabled, the type-only imports are merged with the value imports of the same source,
+    /// and their specifiers become inline `type` specifiers:
+    /// `import type { A } from 'foo'` and `import { b } from 'foo'` are merged in `import { type A, b } from 'foo'`.
+    /// It's disabled by default.
+    ///
     pub OrganizeImports {
         version: "1.0.0",
         name: "organizeImports",
@@ -53,10 +120,28 @@ impl Rule for OrganizeImports {
     type Query = Ast<JsModule>;
     type State = ImportGroups;
     type Signals = Option<Self::State>;
-    type Options = ();
+    type Options = OrganizeImportsOptions;
 
     fn run(ctx: &RuleContext<Self>) -> Option<Self::State> {
         let root = ctx.query();
+        let options = ctx.options();
+        let matchers: Vec<Vec<ImportMatcher>> = options
+            .groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|matcher| ImportMatcher::from(matcher.as_str()))
+                    .collect()
+            })
+            .collect();
+        let import_key = |source: TokenText| {
+            let group = matchers
+                .iter()
+                .position(|group| group.iter().any(|matcher| matcher.matches(source.text())))
+                .unwrap_or(matchers.len());
+            ImportKey { group, source }
+        };
         let mut groups = Vec::new();
         let mut first_node = None;
         let mut nodes = BTreeMap::new();
@@ -87,7 +172,7 @@ impl Rule for OrganizeImports {
                 // A side effect import creates its own import group
                 let mut nodes = BTreeMap::new();
                 nodes.insert(
-                    ImportKey(import.source_text().ok()?),
+                    import_key(import.source_text().ok()?),
                     vec![ImportNode::from(import.clone())],
                 );
                 groups.push(ImportGroup {
@@ -97,8 +182,11 @@ impl Rule for OrganizeImports {
                 continue;
             }
 
-            // If this is not the first import in the group, check for a group break
-            if has_empty_line(&import.import_token().ok()?.leading_trivia()) {
+            // If this is not the first import in the group, check for a group break.
+            // The blank lines are managed by the rule when they are required between groups.
+            if !options.blank_lines_between_groups
+                && has_empty_line(&import.import_token().ok()?.leading_trivia())
+            {
                 if let Some(first_node) = first_node.take() {
                     groups.push(ImportGroup {
                         first_node,
@@ -113,10 +201,20 @@ impl Rule for OrganizeImports {
                 first_node = Some(import.clone());
             }
 
-            nodes
-                .entry(ImportKey(import.source_text().ok()?))
-                .or_default()
-                .push(ImportNode::from(import));
+            let import_node = ImportNode::from(import);
+            let source_nodes = nodes
+                .entry(import_key(import_node.node.source_text().ok()?))
+                .or_default();
+            // Merge the imports of the same source instead of keeping duplicates
+            let target = source_nodes
+                .iter_mut()
+                .filter(|_| options.merge_imports)
+                .find(|target| target.can_merge(&import_node, options.merge_type_imports));
+            if let Some(target) = target {
+                target.merge(import_node);
+            } else {
+                source_nodes.push(import_node);
+            }
         }
 
         // Flush the remaining nodes
@@ -124,10 +222,17 @@ impl Rule for OrganizeImports {
             groups.push(ImportGroup { first_node, nodes });
         }
 
+        let blank_lines_between_groups = options.blank_lines_between_groups;
         groups
             .iter()
-            .any(|group| !group.is_sorted())
-            .then_some(ImportGroups { groups })
+            .any(|group| {
+                !group.is_sorted()
+                    || (blank_lines_between_groups && !group.has_sorted_blank_lines())
+            })
+            .then_some(ImportGroups {
+                groups,
+                blank_lines_between_groups,
+            })
     }
 
     fn action(ctx: &RuleContext<Self>, groups: &Self::State) -> Option<JsRuleAction> {
@@ -192,18 +297,23 @@ impl Rule for OrganizeImports {
 
             let nodes_iter = next_group
                 .nodes
-                .values()
-                // TODO: Try to merge nodes from the same source
-                .flat_map(|nodes| nodes.iter())
+                .iter()
+                .flat_map(|(key, nodes)| nodes.iter().map(|node| (key.group, node)))
                 .enumerate();
 
-            for (node_index, import_node) in nodes_iter {
+            let mut previous_group = None;
+            for (node_index, (group, import_node)) in nodes_iter {
                 // For each node in the group, pop an item from the old list
                 // iterator (ignoring `item` itself) and discard it
                 if node_index > 0 {
                     iter.next()
                         .unwrap_or_else(|| panic!("mising node {item_slot} {node_index}"));
                 }
+                // The imports merged in this node are also discarded
+                for _ in &import_node.merged_nodes {
+                    iter.next()
+                        .unwrap_or_else(|| panic!("mising merged node {item_slot} {node_index}"));
+                }
 
                 let first_token = import_node.node.import_token().ok()?;
                 let mut node = import_node.build_sorted_node();
@@ -239,6 +349,19 @@ impl Rule for OrganizeImports {
                     ));
                 }
 
+                if !import_node.merged_nodes.is_empty() {
+                    node = import_node.with_merged_comments(node)?;
+                }
+
+                if groups.blank_lines_between_groups && node_index > 0 {
+                    let import_token = node.import_token().ok()?;
+                    let needs_blank_line = previous_group != Some(group);
+                    if let Some(new_token) = with_blank_line(&import_token, needs_blank_line) {
+                        node = node.with_import_token(new_token);
+                    }
+                }
+                previous_group = Some(group);
+
                 new_list.push(AnyJsModuleItem::JsImport(node));
             }
 
@@ -272,6 +395,34 @@ impl Rule for OrganizeImports {
 pub struct ImportGroups {
     /// The list of all the import groups in the file
     groups: Vec<ImportGroup>,
+    /// Whether a blank line is required between the groups of the options
+    blank_lines_between_groups: bool,
+}
+
+/// Options for the assist `organizeImports`, set by `javascript.organizeImports`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct OrganizeImportsOptions {
+    /// The groups of imports, in the order in which they are sorted.
+    pub groups: Vec<Vec<String>>,
+    /// Requires a blank line between two groups of imports.
+    pub blank_lines_between_groups: bool,
+    /// Merges the imports of the same source in a single import.
+    pub merge_imports: bool,
+    /// Merges the type-only imports in the value imports of the same source.
+    pub merge_type_imports: bool,
+}
+
+impl Default for OrganizeImportsOptions {
+    fn default() -> Self {
+        Self {
+            groups: Vec::new(),
+            blank_lines_between_groups: false,
+            merge_imports: true,
+            merge_type_imports: false,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -295,13 +446,36 @@ impl ImportGroup {
         };
         let mut previous_start = import_node.node.syntax().text_range().end();
         import_node.is_sorted()
+            && import_node.merged_nodes.is_empty()
             && iter.all(|import_node| {
                 let start = import_node.node.syntax().text_range().end();
-                let is_sorted = previous_start < start && import_node.is_sorted();
+                let is_sorted = previous_start < start
+                    && import_node.is_sorted()
+                    && import_node.merged_nodes.is_empty();
                 previous_start = start;
                 is_sorted
             })
     }
+
+    /// Returns true if the already sorted nodes of the group are separated by a blank line
+    /// if and only if they belong to different groups of the options
+    fn has_sorted_blank_lines(&self) -> bool {
+        let mut previous_group = None;
+        self.nodes
+            .iter()
+            .flat_map(|(key, nodes)| nodes.iter().map(|node| (key.group, node)))
+            .all(|(group, import_node)| {
+                let is_sorted = previous_group.map_or(true, |previous_group| {
+                    let has_blank_line = import_node
+                        .node
+                        .import_token()
+                        .is_ok_and(|token| has_empty_line(&token.leading_trivia()));
+                    has_blank_line == (previous_group != group)
+                });
+                previous_group = Some(group);
+                is_sorted
+            })
+    }
 }
 
 #[derive(Debug)]
@@ -312,7 +486,12 @@ struct ImportNode {
     separator_count: usize,
     /// Map storing all the named import specifiers and their associated trailing separator,
     /// sorted in natural order
-    specifiers: BTreeMap<ImportKey, (AnyJsNamedImportSpecifier, Option<JsSyntaxToken>)>,
+    specifiers: BTreeMap<SpecifierKey, (AnyJsNamedImportSpecifier, Option<JsSyntaxToken>)>,
+    /// The imports of the same source whose named specifiers were merged in this node
+    merged_nodes: Vec<JsImport>,
+    /// Whether the `type` keyword of this type-only import is removed,
+    /// because a value import was merged in it
+    removes_type_token: bool,
 }
 
 impl From<JsImport> for ImportNode {
@@ -334,7 +513,7 @@ impl From<JsImport> for ImportNode {
                 let trailing_separator = element.trailing_separator.ok()?;
                 separator_count += usize::from(trailing_separator.is_some());
 
-                result.insert(ImportKey(key), (node, trailing_separator));
+                result.insert(SpecifierKey(key), (node, trailing_separator));
             }
 
             Some(result)
@@ -344,6 +523,8 @@ impl From<JsImport> for ImportNode {
             node,
             separator_count,
             specifiers: specifiers.unwrap_or_default(),
+            merged_nodes: Vec::new(),
+            removes_type_token: false,
         }
     }
 }
@@ -363,6 +544,92 @@ impl ImportNode {
         })
     }
 
+    /// Returns `true` if this import node is a type-only import
+    fn is_type_only(&self) -> bool {
+        !self.removes_type_token
+            && matches!(
+                self.node.import_clause(),
+                Ok(AnyJsImportClause::JsImportNamedClause(clause)) if clause.type_token().is_some()
+            )
+    }
+
+    /// Returns `true` if the named import specifiers of `other` can be merged in this import node.
+    ///
+    /// Both imports must be value imports or type-only imports with named specifiers and without attributes,
+    /// unless `merge_type_imports` is `true`.
+    /// The import specifiers of `other` must not collide with the specifiers of this node,
+    /// and `other` must not have comments that would be lost by the merge.
+    fn can_merge(&self, other: &Self, merge_type_imports: bool) -> bool {
+        let (
+            Ok(AnyJsImportClause::JsImportNamedClause(clause)),
+            Ok(AnyJsImportClause::JsImportNamedClause(other_clause)),
+        ) = (self.node.import_clause(), other.node.import_clause())
+        else {
+            return false;
+        };
+        let Ok(other_specifiers) = other_clause.named_specifiers() else {
+            return false;
+        };
+        (merge_type_imports || self.is_type_only() == other_clause.type_token().is_some())
+            && clause.assertion().is_none()
+            && other_clause.assertion().is_none()
+            // The specifiers of `other` couldn't be collected if some of them are bogus
+            && other.specifiers.len() == other_specifiers.specifiers().len()
+            && other
+                .specifiers
+                .keys()
+                .all(|key| !self.specifiers.contains_key(key))
+            && !has_unmovable_comments(&other.node, &other_specifiers)
+    }
+
+    /// Moves the named import specifiers of `other` in this import node
+    ///
+    /// When a type-only import is merged with a value import,
+    /// the specifiers of the type-only import become inline `type` specifiers.
+    fn merge(&mut self, other: Self) {
+        let is_type_only = self.is_type_only();
+        let other_is_type_only = other.is_type_only();
+        if is_type_only && !other_is_type_only {
+            for (specifier, _) in self.specifiers.values_mut() {
+                *specifier = with_type_modifier(specifier);
+            }
+            self.removes_type_token = true;
+        }
+        let mut specifiers = other.specifiers;
+        if other_is_type_only && !is_type_only {
+            for (specifier, _) in specifiers.values_mut() {
+                *specifier = with_type_modifier(specifier);
+            }
+        }
+        self.separator_count += other.separator_count;
+        self.specifiers.extend(specifiers);
+        self.merged_nodes.push(other.node);
+        self.merged_nodes.extend(other.merged_nodes);
+    }
+
+    /// Returns `node` with the comments before and after the merged imports
+    fn with_merged_comments(&self, node: JsImport) -> Option<JsImport> {
+        let import_token = node.import_token().ok()?;
+        let mut leading_trivia: Vec<_> = import_token.leading_trivia().pieces().collect();
+        let mut trailing_trivia = Vec::new();
+        for merged_node in &self.merged_nodes {
+            let merged_import_token = merged_node.import_token().ok()?;
+            leading_trivia.extend(
+                merged_import_token
+                    .leading_trivia()
+                    .pieces()
+                    .skip_while(|piece| piece.is_newline() || piece.is_whitespace()),
+            );
+            if let Some(last_token) = merged_node.syntax().last_token() {
+                if last_token.has_trailing_comments() {
+                    trailing_trivia.extend(last_token.trailing_trivia().pieces());
+                }
+            }
+        }
+        node.with_import_token(import_token.with_leading_trivia_pieces(leading_trivia))
+            .append_trivia_pieces(trailing_trivia)
+    }
+
     /// Build a clone of the original node this import node was created from with its import specifiers sorted
     fn build_sorted_node(&self) -> JsImport {
         let import = self.node.clone().detach();
@@ -505,12 +772,84 @@ impl ImportNode {
             }
         }
 
+        if self.removes_type_token {
+            let new_clause = import_named_clause
+                .clone()
+                .with_type_token(None)
+                .with_named_specifiers(new_specifiers);
+            return import
+                .replace_node_discard_trivia(import_named_clause, new_clause)
+                .expect("import_named_clause should be a child of import");
+        }
+
         import
             .replace_node_discard_trivia(old_specifiers, new_specifiers)
             .expect("old_specifiers should be a child of import")
     }
 }
 
+/// Returns a clone of `specifier` with an inline `type` modifier, such as `type A` for `A`.
+///
+/// The leading trivia of the specifier is moved to the `type` keyword.
+fn with_type_modifier(specifier: &AnyJsNamedImportSpecifier) -> AnyJsNamedImportSpecifier {
+    let has_type_token = match specifier {
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+            specifier.type_token().is_some()
+        }
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            specifier.type_token().is_some()
+        }
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => true,
+    };
+    let specifier = specifier.clone().detach();
+    let Some(first_token) = specifier.syntax().first_token().filter(|_| !has_type_token) else {
+        return specifier;
+    };
+    let type_token = make::token(T![type])
+        .with_leading_trivia_pieces(first_token.leading_trivia().pieces())
+        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+    let Some(specifier) = specifier
+        .clone()
+        .replace_token_discard_trivia(first_token.clone(), first_token.with_leading_trivia([]))
+    else {
+        return specifier;
+    };
+    match specifier {
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier) => {
+            specifier.with_type_token(Some(type_token)).into()
+        }
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            specifier.with_type_token(Some(type_token)).into()
+        }
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => specifier,
+    }
+}
+
+/// Returns `true` if `import` has comments that aren't kept when its named specifiers are moved to another import.
+///
+/// The comments attached to the specifiers, before the import and after its last token are kept.
+fn has_unmovable_comments(import: &JsImport, specifiers: &JsNamedImportSpecifiers) -> bool {
+    let (Ok(l_curly_token), Ok(r_curly_token)) =
+        (specifiers.l_curly_token(), specifiers.r_curly_token())
+    else {
+        return true;
+    };
+    let specifiers_range = TextRange::new(
+        l_curly_token.text_trimmed_range().end(),
+        r_curly_token.text_trimmed_range().start(),
+    );
+    let import_token = import.import_token().ok();
+    let last_token = import.syntax().last_token();
+    import
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .filter(|token| !specifiers_range.contains_range(token.text_trimmed_range()))
+        .any(|token| {
+            (token.has_leading_comments() && Some(&token) != import_token.as_ref())
+                || (token.has_trailing_comments() && Some(&token) != last_token.as_ref())
+        })
+}
+
 /// Return a clone of `prev_token` with a newline trivia piece prepended to its
 /// leading trivia if it didn't have one already. This function will try to copy
 /// the newline trivia piece from the leading trivia of `newline_source` if its set
@@ -574,18 +913,17 @@ fn leading_trivia_iter(
 }
 
 #[derive(Debug)]
-struct ImportKey(TokenText);
+struct ImportKey {
+    /// The index of the group of the options that matches the source
+    group: usize,
+    source: TokenText,
+}
 
 impl Ord for ImportKey {
     fn cmp(&self, other: &Self) -> Ordering {
-        let own_category = ImportCategory::from(self.0.text());
-        let other_category = ImportCategory::from(other.0.text());
-        if own_category != other_category {
-            return own_category.cmp(&other_category);
-        }
-
-        // Sort imports using natural ordering
-        natord::compare(&self.0, &other.0)
+        self.group
+            .cmp(&other.group)
+            .then_with(|| compare_sources(self.source.text(), other.source.text()))
     }
 }
 
@@ -598,16 +936,130 @@ impl PartialOrd for ImportKey {
 impl Eq for ImportKey {}
 
 impl PartialEq for ImportKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.group == other.group && self.source == other.source
+    }
+}
+
+#[derive(Debug)]
+struct SpecifierKey(TokenText);
+
+impl Ord for SpecifierKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_sources(self.0.text(), other.0.text())
+    }
+}
+
+impl PartialOrd for SpecifierKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for SpecifierKey {}
+
+impl PartialEq for SpecifierKey {
     fn eq(&self, other: &Self) -> bool {
         self.0 == other.0
     }
 }
 
+/// Compares two import sources by category, then using natural ordering
+fn compare_sources(own: &str, other: &str) -> Ordering {
+    let own_category = ImportCategory::from(own);
+    let other_category = ImportCategory::from(other);
+    if own_category != other_category {
+        return own_category.cmp(&other_category);
+    }
+
+    // Sort imports using natural ordering
+    natord::compare(own, other)
+}
+
+/// A matcher of a group of imports of the options.
+enum ImportMatcher {
+    /// `:NODE:`
+    Node,
+    /// `:BUN:`
+    Bun,
+    /// `:URL:`
+    Url,
+    /// `:PACKAGE:`
+    Package,
+    /// `:ALIAS:`
+    Alias,
+    /// `:PATH:`
+    Path,
+    /// A pattern where `*` matches any sequence of characters
+    Pattern(String),
+}
+
+impl From<&str> for ImportMatcher {
+    fn from(value: &str) -> Self {
+        match value {
+            ":NODE:" => Self::Node,
+            ":BUN:" => Self::Bun,
+            ":URL:" => Self::Url,
+            ":PACKAGE:" => Self::Package,
+            ":ALIAS:" => Self::Alias,
+            ":PATH:" => Self::Path,
+            _ => Self::Pattern(value.to_string()),
+        }
+    }
+}
+
+impl ImportMatcher {
+    fn matches(&self, source: &str) -> bool {
+        let is_alias = || source.starts_with(['#', '~', '$', '%']) || source.starts_with("@/");
+        match self {
+            Self::Node => ImportCategory::from(source) == ImportCategory::NodeBuiltin,
+            Self::Bun => ImportCategory::from(source) == ImportCategory::Bun,
+            Self::Url => ImportCategory::from(source) == ImportCategory::Url,
+            Self::Package => {
+                !is_alias()
+                    && matches!(
+                        ImportCategory::from(source),
+                        ImportCategory::Library | ImportCategory::Npm
+                    )
+            }
+            Self::Alias => is_alias(),
+            Self::Path => matches!(
+                ImportCategory::from(source),
+                ImportCategory::Relative | ImportCategory::Absolute
+            ),
+            Self::Pattern(pattern) => matches_pattern(pattern, source),
+        }
+    }
+}
+
+/// Returns `true` if `source` matches `pattern`, where `*` matches any sequence of characters.
+fn matches_pattern(pattern: &str, source: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return source.is_empty();
+    };
+    let Some(mut rest) = source.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<_> = parts.collect();
+    let Some(last) = parts.pop() else {
+        // The pattern doesn't contain `*`
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last)
+}
+
 /// Imports get sorted by categories before being sorted on natural order.
 ///
 /// The rationale for this is that imports "further away" from the source file
 /// are listed before imports closer to the source file.
-#[derive(Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
 enum ImportCategory {
     /// Anything with an explicit `bun:` prefix.
     Bun,
@@ -666,6 +1118,49 @@ fn is_ascii_whitespace(piece: &SyntaxTriviaPiece<JsLanguage>) -> bool {
     piece.is_newline() || piece.is_whitespace()
 }
 
+/// Returns a clone of `token` with a blank line in its leading trivia if `blank_line` is `true`,
+/// or without blank lines otherwise.
+///
+/// Returns `None` if `token` already has the expected blank lines.
+fn with_blank_line(token: &JsSyntaxToken, blank_line: bool) -> Option<JsSyntaxToken> {
+    if has_empty_line(&token.leading_trivia()) == blank_line {
+        return None;
+    }
+    let mut pieces: Vec<(TriviaPieceKind, &str)> = Vec::new();
+    if blank_line {
+        let newline = leading_trivia_iter(token)
+            .find(|(kind, _)| kind.is_newline())
+            .map_or("\n", |(_, text)| text);
+        let starts_with_newline = token
+            .leading_trivia()
+            .first()
+            .map_or(false, |piece| piece.is_newline());
+        if !starts_with_newline {
+            pieces.push((TriviaPieceKind::Newline, newline));
+        }
+        pieces.push((TriviaPieceKind::Newline, newline));
+        pieces.extend(leading_trivia_iter(token));
+    } else {
+        let mut was_newline = false;
+        for (kind, text) in leading_trivia_iter(token) {
+            if kind.is_newline() {
+                if was_newline {
+                    // Remove the empty line with its indentation
+                    while matches!(pieces.last(), Some((TriviaPieceKind::Whitespace, _))) {
+                        pieces.pop();
+                    }
+                    continue;
+                }
+                was_newline = true;
+            } else if !kind.is_whitespace() {
+                was_newline = false;
+            }
+            pieces.push((kind, text));
+        }
+    }
+    Some(token.with_leading_trivia(pieces))
+}
+
 /// Returns true if the provided trivia contains an empty line (two consecutive newline pieces, ignoring whitespace)
 fn has_empty_line(trivia: &SyntaxTrivia<JsLanguage>) -> bool {
     let mut was_newline = false;
@@ -9,7 +9,7 @@ use biome_analyze::{
 use biome_aria::{AriaProperties, AriaRoles};
 use biome_diagnostics::{category, Diagnostic, Error as DiagnosticError};
 use biome_js_syntax::{JsFileSource, JsLanguage};
-use biome_project::PackageJson;
+use biome_project::{PackageJson, StyleIndex, TailwindConfig, TsConfigJson};
 use biome_suppression::{parse_suppression_comment, SuppressionDiagnostic};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -20,15 +20,20 @@ mod ast_utils;
 pub mod globals;
 pub mod lint;
 pub mod options;
+pub mod project;
 mod react;
 mod registry;
 mod services;
+mod solid;
+mod storybook;
 mod suppression_action;
 mod syntax;
 pub mod utils;
 
+pub use crate::assists::correctness::organize_imports::OrganizeImportsOptions;
 pub use crate::registry::visit_registry;
 pub use crate::services::control_flow::ControlFlowGraph;
+pub use crate::services::template::ComponentTemplate;
 
 pub(crate) type JsRuleAction = RuleAction<JsLanguage>;
 
@@ -51,6 +56,7 @@ pub fn metadata() -> &'static MetadataRegistry {
 /// Additionally, this function takes a `inspect_matcher` function that can be
 /// used to inspect the "query matches" emitted by the analyzer before they are
 /// processed by the lint rules registry
+#[allow(clippy::too_many_arguments)]
 pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
@@ -58,6 +64,11 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    tsconfig: Option<TsConfigJson>,
+    tailwind_config: Option<TailwindConfig>,
+    workspace_packages: Vec<PackageJson>,
+    style_index: Option<Arc<StyleIndex>>,
+    template: Option<ComponentTemplate>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -130,6 +141,19 @@ where
     if let Some(manifest) = manifest {
         services.insert_service(Arc::new(manifest));
     }
+    if let Some(tsconfig) = tsconfig {
+        services.insert_service(Arc::new(tsconfig));
+    }
+    if let Some(tailwind_config) = tailwind_config {
+        services.insert_service(Arc::new(tailwind_config));
+    }
+    services.insert_service(Arc::new(workspace_packages));
+    if let Some(style_index) = style_index {
+        services.insert_service(style_index);
+    }
+    if let Some(template) = template {
+        services.insert_service(template);
+    }
     services.insert_service(source_type);
     (
         analyzer.run(AnalyzerContext {
@@ -145,12 +169,18 @@ where
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
 /// to selectively restrict analysis to specific rules / a specific source range,
 /// then call `emit_signal` when an analysis rule emits a diagnostic or action
+#[allow(clippy::too_many_arguments)]
 pub fn analyze<'a, F, B>(
     root: &LanguageRoot<JsLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
     source_type: JsFileSource,
     manifest: Option<PackageJson>,
+    tsconfig: Option<TsConfigJson>,
+    tailwind_config: Option<TailwindConfig>,
+    workspace_packages: Vec<PackageJson>,
+    style_index: Option<Arc<StyleIndex>>,
+    template: Option<ComponentTemplate>,
     emit_signal: F,
 ) -> (Option<B>, Vec<DiagnosticError>)
 where
@@ -164,6 +194,11 @@ where
         options,
         source_type,
         manifest,
+        tsconfig,
+        tailwind_config,
+        workspace_packages,
+        style_index,
+        template,
         emit_signal,
     )
 }
@@ -280,6 +315,11 @@ mod tests {
             &options,
             JsFileSource::tsx(),
             None,
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -372,6 +412,11 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let span = diag.get_span();
@@ -458,6 +503,11 @@ mod tests {
             &options,
             JsFileSource::js_module(),
             None,
+            None,
+            None,
+            Vec::new(),
+            None,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     let code = diag.category().unwrap();
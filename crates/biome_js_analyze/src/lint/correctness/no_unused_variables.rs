@@ -27,6 +27,10 @@ declare_rule! {
     /// The pattern of having an underscore as prefix of a name of variable is a very diffuse
     /// pattern among programmers, and Biome decided to follow it.
     ///
+    /// A variable that is assigned but never read is also reported,
+    /// including self-assignments such as `count += 1` and `count++`
+    /// whose result is not used.
+    ///
     /// This rule won't report unused imports.
     /// If you want to report unused imports,
     /// enable [noUnusedImports](https://biomejs.dev/linter/rules/no-unused-imports/).
@@ -41,6 +45,13 @@ declare_rule! {
     /// ```
     ///
     /// ```js,expect_diagnostic
+    /// let count = 0;
+    /// for (const item of items) {
+    ///     count += item;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
     /// function foo() {}
     /// ```
     ///
@@ -394,6 +405,14 @@ impl Rule for NoUnusedVariables {
             },
         );
 
+        let diag = if binding.all_writes(ctx.model()).next().is_some() {
+            diag.note(markup! {
+                "This " {symbol_type} " is assigned, but its value is never read."
+            })
+        } else {
+            diag
+        };
+
         let diag = diag.note(
             markup! {"Unused variables usually are result of incomplete refactoring, typos and other source of bugs."},
         );
@@ -2,55 +2,155 @@
 
 use biome_analyze::declare_group;
 
+pub mod no_async_in_computed;
+pub mod no_await_in_loop;
+pub mod no_conditional_tests;
 pub mod no_console;
 pub mod no_constant_math_min_max_clamp;
+pub mod no_deprecated_apis;
+pub mod no_direct_state_mutation;
+pub mod no_document_cookie;
 pub mod no_done_callback;
 pub mod no_duplicate_else_if;
+pub mod no_duplicate_test_titles;
 pub mod no_evolving_any;
+pub mod no_excessive_parameters;
+pub mod no_excessively_long_functions;
 pub mod no_flat_map_identity;
+pub mod no_global_dirname_filename;
+pub mod no_head_element;
+pub mod no_img_element;
+pub mod no_implicit_coercion;
+pub mod no_leaked_conditional_rendering;
+pub mod no_magic_numbers;
 pub mod no_misplaced_assertion;
 pub mod no_nodejs_modules;
+pub mod no_non_reactive_updates;
+pub mod no_package_internal_imports;
 pub mod no_react_specific_props;
+pub mod no_redundant_story_name;
 pub mod no_restricted_imports;
+pub mod no_solid_destructured_props;
+pub mod no_solid_uncalled_signals;
+pub mod no_solid_untracked_reads;
+pub mod no_standalone_expect;
+pub mod no_static_element_interactions;
+pub mod no_sync_scripts;
+pub mod no_undeclared_class_names;
 pub mod no_undeclared_dependencies;
+pub mod no_unnecessary_condition;
+pub mod no_unsafe_type_assertion;
+pub mod no_unstable_nested_components;
+pub mod no_unused_component_props;
+pub mod no_unused_store_subscriptions;
 pub mod no_useless_string_concat;
+pub mod no_useless_undefined;
 pub mod no_useless_undefined_initialization;
+pub mod use_aria_props_supported_by_role;
 pub mod use_array_literals;
+pub mod use_at_index;
+pub mod use_await_story_interactions;
+pub mod use_await_thenable;
+pub mod use_color_contrast;
 pub mod use_consistent_builtin_instantiation;
+pub mod use_consistent_curly_braces;
+pub mod use_consistent_test_function;
 pub mod use_default_switch_clause;
+pub mod use_define_props_order;
+pub mod use_explicit_function_return_type;
 pub mod use_explicit_length_check;
+pub mod use_explicit_member_accessibility;
 pub mod use_focusable_interactive;
+pub mod use_google_font_preconnect;
 pub mod use_import_restrictions;
+pub mod use_numeric_separators;
+pub mod use_object_spread;
+pub mod use_readonly_class_members;
 pub mod use_sorted_classes;
+pub mod use_story_default_export;
+pub mod use_story_name_pascal_case;
 pub mod use_throw_new_error;
 pub mod use_top_level_regex;
+pub mod use_tsconfig_path_aliases;
+pub mod use_valid_autocomplete;
+pub mod use_valid_runes;
 
 declare_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_async_in_computed :: NoAsyncInComputed ,
+            self :: no_await_in_loop :: NoAwaitInLoop ,
+            self :: no_conditional_tests :: NoConditionalTests ,
             self :: no_console :: NoConsole ,
             self :: no_constant_math_min_max_clamp :: NoConstantMathMinMaxClamp ,
+            self :: no_deprecated_apis :: NoDeprecatedApis ,
+            self :: no_direct_state_mutation :: NoDirectStateMutation ,
+            self :: no_document_cookie :: NoDocumentCookie ,
             self :: no_done_callback :: NoDoneCallback ,
             self :: no_duplicate_else_if :: NoDuplicateElseIf ,
+            self :: no_duplicate_test_titles :: NoDuplicateTestTitles ,
             self :: no_evolving_any :: NoEvolvingAny ,
+            self :: no_excessive_parameters :: NoExcessiveParameters ,
+            self :: no_excessively_long_functions :: NoExcessivelyLongFunctions ,
             self :: no_flat_map_identity :: NoFlatMapIdentity ,
+            self :: no_global_dirname_filename :: NoGlobalDirnameFilename ,
+            self :: no_head_element :: NoHeadElement ,
+            self :: no_img_element :: NoImgElement ,
+            self :: no_implicit_coercion :: NoImplicitCoercion ,
+            self :: no_leaked_conditional_rendering :: NoLeakedConditionalRendering ,
+            self :: no_magic_numbers :: NoMagicNumbers ,
             self :: no_misplaced_assertion :: NoMisplacedAssertion ,
             self :: no_nodejs_modules :: NoNodejsModules ,
+            self :: no_non_reactive_updates :: NoNonReactiveUpdates ,
+            self :: no_package_internal_imports :: NoPackageInternalImports ,
             self :: no_react_specific_props :: NoReactSpecificProps ,
+            self :: no_redundant_story_name :: NoRedundantStoryName ,
             self :: no_restricted_imports :: NoRestrictedImports ,
+            self :: no_solid_destructured_props :: NoSolidDestructuredProps ,
+            self :: no_solid_uncalled_signals :: NoSolidUncalledSignals ,
+            self :: no_solid_untracked_reads :: NoSolidUntrackedReads ,
+            self :: no_standalone_expect :: NoStandaloneExpect ,
+            self :: no_static_element_interactions :: NoStaticElementInteractions ,
+            self :: no_sync_scripts :: NoSyncScripts ,
+            self :: no_undeclared_class_names :: NoUndeclaredClassNames ,
             self :: no_undeclared_dependencies :: NoUndeclaredDependencies ,
+            self :: no_unnecessary_condition :: NoUnnecessaryCondition ,
+            self :: no_unsafe_type_assertion :: NoUnsafeTypeAssertion ,
+            self :: no_unstable_nested_components :: NoUnstableNestedComponents ,
+            self :: no_unused_component_props :: NoUnusedComponentProps ,
+            self :: no_unused_store_subscriptions :: NoUnusedStoreSubscriptions ,
             self :: no_useless_string_concat :: NoUselessStringConcat ,
+            self :: no_useless_undefined :: NoUselessUndefined ,
             self :: no_useless_undefined_initialization :: NoUselessUndefinedInitialization ,
+            self :: use_aria_props_supported_by_role :: UseAriaPropsSupportedByRole ,
             self :: use_array_literals :: UseArrayLiterals ,
+            self :: use_at_index :: UseAtIndex ,
+            self :: use_await_story_interactions :: UseAwaitStoryInteractions ,
+            self :: use_await_thenable :: UseAwaitThenable ,
+            self :: use_color_contrast :: UseColorContrast ,
             self :: use_consistent_builtin_instantiation :: UseConsistentBuiltinInstantiation ,
+            self :: use_consistent_curly_braces :: UseConsistentCurlyBraces ,
+            self :: use_consistent_test_function :: UseConsistentTestFunction ,
             self :: use_default_switch_clause :: UseDefaultSwitchClause ,
+            self :: use_define_props_order :: UseDefinePropsOrder ,
+            self :: use_explicit_function_return_type :: UseExplicitFunctionReturnType ,
             self :: use_explicit_length_check :: UseExplicitLengthCheck ,
+            self :: use_explicit_member_accessibility :: UseExplicitMemberAccessibility ,
             self :: use_focusable_interactive :: UseFocusableInteractive ,
+            self :: use_google_font_preconnect :: UseGoogleFontPreconnect ,
             self :: use_import_restrictions :: UseImportRestrictions ,
+            self :: use_numeric_separators :: UseNumericSeparators ,
+            self :: use_object_spread :: UseObjectSpread ,
+            self :: use_readonly_class_members :: UseReadonlyClassMembers ,
             self :: use_sorted_classes :: UseSortedClasses ,
+            self :: use_story_default_export :: UseStoryDefaultExport ,
+            self :: use_story_name_pascal_case :: UseStoryNamePascalCase ,
             self :: use_throw_new_error :: UseThrowNewError ,
             self :: use_top_level_regex :: UseTopLevelRegex ,
+            self :: use_tsconfig_path_aliases :: UseTsconfigPathAliases ,
+            self :: use_valid_autocomplete :: UseValidAutocomplete ,
+            self :: use_valid_runes :: UseValidRunes ,
         ]
      }
 }
@@ -0,0 +1,314 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyFunctionLike, AnyJsExpression, AnyJsFunction, AnyJsObjectMember, JsCallExpression,
+    JsExportDefaultExpressionClause, JsMethodObjectMember, JsObjectExpression,
+    JsPropertyObjectMember, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, TextRange,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, WalkEvent};
+
+declare_rule! {
+    /// Disallow asynchronous actions in Vue computed properties.
+    ///
+    /// A computed property must return its value synchronously.
+    /// An asynchronous computed property returns a promise instead of its value,
+    /// and the result of a callback scheduled in a computed property isn't tracked.
+    /// Use a watcher to perform asynchronous work.
+    ///
+    /// The rule reports async getters, `await` expressions, promise chains,
+    /// and calls to timer functions such as `setTimeout`,
+    /// in getters passed to `computed()` and in the `computed` option of the component.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const user = computed(async () => await fetchUser(id.value));
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// export default {
+    ///     computed: {
+    ///         user() {
+    ///             return fetchUser(this.id).then((response) => response.json());
+    ///         },
+    ///     },
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const fullName = computed(() => `${firstName.value} ${lastName.value}`);
+    /// ```
+    ///
+    /// ```js
+    /// export default {
+    ///     computed: {
+    ///         fullName() {
+    ///             return `${this.firstName} ${this.lastName}`;
+    ///         },
+    ///     },
+    /// };
+    /// ```
+    ///
+    pub NoAsyncInComputed {
+        version: "next",
+        name: "noAsyncInComputed",
+        language: "js",
+        sources: &[RuleSource::EslintVue("no-async-in-computed-properties")],
+        domains: &[RuleDomain::Vue],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyComputedDeclaration = JsCallExpression | JsPropertyObjectMember
+}
+
+pub enum AsyncAction {
+    AsyncFunction,
+    Await,
+    Promise,
+    Timer,
+}
+
+pub struct AsyncComputed {
+    action: AsyncAction,
+    range: TextRange,
+}
+
+impl Rule for NoAsyncInComputed {
+    type Query = Ast<AnyComputedDeclaration>;
+    type State = AsyncComputed;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let getters = match ctx.query() {
+            AnyComputedDeclaration::JsCallExpression(call) => {
+                composition_getter(call).into_iter().collect::<Vec<_>>()
+            }
+            AnyComputedDeclaration::JsPropertyObjectMember(member) => {
+                options_getters(member).unwrap_or_default()
+            }
+        };
+        getters.iter().flat_map(async_actions).collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = match state.action {
+            AsyncAction::AsyncFunction => markup! {
+                "A computed property should not be an async function."
+            },
+            AsyncAction::Await => markup! {
+                "A computed property should not await a value."
+            },
+            AsyncAction::Promise => markup! {
+                "A computed property should not chain a promise."
+            },
+            AsyncAction::Timer => markup! {
+                "A computed property should not schedule a callback."
+            },
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.range, message)
+                .note(markup! {
+                    "Computed properties must return their value synchronously. Use a watcher to perform asynchronous work."
+                }),
+        )
+    }
+}
+
+/// Returns the getter passed to `computed()`.
+///
+/// ```js
+/// computed(() => value);
+/// computed({ get() { return value }, set(newValue) {} });
+/// ```
+fn composition_getter(call: &JsCallExpression) -> Option<JsSyntaxNode> {
+    let AnyJsExpression::JsIdentifierExpression(callee) = call.callee().ok()?.omit_parentheses()
+    else {
+        return None;
+    };
+    if !callee.name().ok()?.has_name("computed") {
+        return None;
+    }
+    let argument = call.arguments().ok()?.args().first()?.ok()?;
+    let argument = argument.as_any_js_expression()?.clone().omit_parentheses();
+    match argument {
+        AnyJsExpression::JsObjectExpression(object) => object_getter(&object),
+        argument if AnyJsFunction::can_cast(argument.syntax().kind()) => {
+            Some(argument.into_syntax())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the getters of the `computed` option of a component.
+///
+/// ```js
+/// export default { computed: { value() {} } };
+/// defineComponent({ computed: { value: { get() {}, set(newValue) {} } } });
+/// ```
+fn options_getters(member: &JsPropertyObjectMember) -> Option<Vec<JsSyntaxNode>> {
+    let name = member.name().ok()?.name()?;
+    if name.text() != "computed" {
+        return None;
+    }
+    let options = member
+        .syntax()
+        .grand_parent()
+        .and_then(JsObjectExpression::cast)?;
+    if !is_component_options(&options) {
+        return None;
+    }
+    let AnyJsExpression::JsObjectExpression(computed) = member.value().ok()? else {
+        return None;
+    };
+    let getters = computed
+        .members()
+        .iter()
+        .filter_map(|member| match member.ok()? {
+            AnyJsObjectMember::JsMethodObjectMember(method) => Some(method.into_syntax()),
+            AnyJsObjectMember::JsPropertyObjectMember(property) => {
+                match property.value().ok()?.omit_parentheses() {
+                    AnyJsExpression::JsObjectExpression(object) => object_getter(&object),
+                    value if AnyJsFunction::can_cast(value.syntax().kind()) => {
+                        Some(value.into_syntax())
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
+    Some(getters)
+}
+
+/// Returns the `get` member of a writable computed property.
+fn object_getter(object: &JsObjectExpression) -> Option<JsSyntaxNode> {
+    object
+        .members()
+        .iter()
+        .find_map(|member| match member.ok()? {
+            AnyJsObjectMember::JsMethodObjectMember(method)
+                if method.name().ok()?.name()?.text() == "get" =>
+            {
+                Some(method.into_syntax())
+            }
+            AnyJsObjectMember::JsPropertyObjectMember(property)
+                if property.name().ok()?.name()?.text() == "get" =>
+            {
+                let value = property.value().ok()?.omit_parentheses();
+                AnyJsFunction::can_cast(value.syntax().kind()).then(|| value.into_syntax())
+            }
+            _ => None,
+        })
+}
+
+/// Returns `true` if `object` is the options object of a component.
+fn is_component_options(object: &JsObjectExpression) -> bool {
+    let Some(parent) = object.syntax().parent() else {
+        return false;
+    };
+    if JsExportDefaultExpressionClause::can_cast(parent.kind()) {
+        return true;
+    }
+    parent.kind() == JsSyntaxKind::JS_CALL_ARGUMENT_LIST
+        && parent
+            .grand_parent()
+            .and_then(JsCallExpression::cast)
+            .and_then(|call| call.callee().ok())
+            .is_some_and(|callee| match callee.omit_parentheses() {
+                AnyJsExpression::JsIdentifierExpression(callee) => callee
+                    .name()
+                    .is_ok_and(|name| name.has_name("defineComponent")),
+                _ => false,
+            })
+}
+
+/// Returns the asynchronous actions of `getter`, ignoring the nested functions.
+fn async_actions(getter: &JsSyntaxNode) -> Vec<AsyncComputed> {
+    let mut actions = Vec::new();
+    if let Some(token) = async_token(getter) {
+        actions.push(AsyncComputed {
+            action: AsyncAction::AsyncFunction,
+            range: token.text_trimmed_range(),
+        });
+    }
+    let mut iter = getter.preorder();
+    while let Some(event) = iter.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if &node != getter
+            && (AnyFunctionLike::can_cast(node.kind())
+                || matches!(
+                    node.kind(),
+                    JsSyntaxKind::JS_GETTER_OBJECT_MEMBER | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+                ))
+        {
+            iter.skip_subtree();
+            continue;
+        }
+        let action = match node.kind() {
+            JsSyntaxKind::JS_AWAIT_EXPRESSION => AsyncAction::Await,
+            JsSyntaxKind::JS_CALL_EXPRESSION => {
+                let Some(callee) = JsCallExpression::unwrap_cast(node.clone())
+                    .callee()
+                    .ok()
+                    .map(AnyJsExpression::omit_parentheses)
+                else {
+                    continue;
+                };
+                match callee {
+                    AnyJsExpression::JsStaticMemberExpression(member)
+                        if member
+                            .member()
+                            .and_then(|name| name.value_token())
+                            .is_ok_and(|token| {
+                                matches!(token.text_trimmed(), "then" | "catch" | "finally")
+                            }) =>
+                    {
+                        AsyncAction::Promise
+                    }
+                    AnyJsExpression::JsIdentifierExpression(identifier)
+                        if identifier.name().is_ok_and(|name| {
+                            name.value_token().is_ok_and(|token| {
+                                matches!(
+                                    token.text_trimmed(),
+                                    "setTimeout"
+                                        | "setInterval"
+                                        | "setImmediate"
+                                        | "requestAnimationFrame"
+                                        | "queueMicrotask"
+                                )
+                            })
+                        }) =>
+                    {
+                        AsyncAction::Timer
+                    }
+                    _ => continue,
+                }
+            }
+            _ => continue,
+        };
+        actions.push(AsyncComputed {
+            action,
+            range: node.text_trimmed_range(),
+        });
+    }
+    actions
+}
+
+fn async_token(getter: &JsSyntaxNode) -> Option<JsSyntaxToken> {
+    if let Some(function) = AnyJsFunction::cast_ref(getter) {
+        function.async_token()
+    } else {
+        JsMethodObjectMember::cast_ref(getter)?.async_token()
+    }
+}
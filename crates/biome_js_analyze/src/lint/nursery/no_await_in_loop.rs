@@ -0,0 +1,227 @@
+use crate::services::control_flow::AnyJsControlFlowRoot;
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsAssignmentPattern, AnyJsExpression, JsAssignmentExpression, JsAwaitExpression,
+    JsDoWhileStatement, JsForInStatement, JsForOfStatement, JsForStatement, JsInitializerClause,
+    JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator, JsWhileStatement,
+};
+use biome_rowan::{AstNode, TokenText};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Disallow `await` inside loops.
+    ///
+    /// Awaiting in every iteration of a loop runs the asynchronous operations one after the other.
+    /// When the iterations don't depend on each other,
+    /// starting all the operations and awaiting them together with `Promise.all()` is faster.
+    ///
+    /// The rule ignores the `await` expressions of the nested functions,
+    /// the iterables of `for...in` and `for...of` loops, the initializers of `for` loops,
+    /// and the bodies of `for await...of` loops, which iterate asynchronously on purpose.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// async function fetchAll(urls) {
+    ///     const responses = [];
+    ///     for (const url of urls) {
+    ///         responses.push(await fetch(url));
+    ///     }
+    ///     return responses;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// async function fetchAll(urls) {
+    ///     return await Promise.all(urls.map((url) => fetch(url)));
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignoreConditionDependencies`
+    ///
+    /// Ignore the `await` expressions whose result is used in the condition of the loop,
+    /// because the next iteration depends on them:
+    ///
+    /// ```js
+    /// let page = await fetchPage();
+    /// while (page.next) {
+    ///     page = await fetchPage(page.next);
+    /// }
+    /// ```
+    ///
+    /// Defaults to `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "ignoreConditionDependencies": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoAwaitInLoop {
+        version: "next",
+        name: "noAwaitInLoop",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-await-in-loop")],
+        recommended: false,
+    }
+}
+
+/// Options for the rule `noAwaitInLoop`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoAwaitInLoopOptions {
+    /// Ignore the `await` expressions whose result is used in the condition of the loop.
+    pub ignore_condition_dependencies: bool,
+}
+
+impl Rule for NoAwaitInLoop {
+    type Query = Ast<JsAwaitExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = NoAwaitInLoopOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let mut child = node.syntax().clone();
+        for ancestor in node.syntax().ancestors().skip(1) {
+            if AnyJsControlFlowRoot::can_cast(ancestor.kind()) {
+                return None;
+            }
+            if let Some(condition) = looped_condition(&ancestor, &child) {
+                if ctx.options().ignore_condition_dependencies
+                    && condition.is_some_and(|condition| is_condition_dependency(node, &condition))
+                {
+                    return None;
+                }
+                return Some(());
+            }
+            if JsForOfStatement::cast_ref(&ancestor)
+                .is_some_and(|for_of| for_of.await_token().is_some())
+            {
+                return None;
+            }
+            child = ancestor;
+        }
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Avoid using "<Emphasis>"await"</Emphasis>" inside loops."
+                },
+            )
+            .note(markup! {
+                "The iterations of the loop wait for each other. If they are independent, start the operations in the loop and await them together with "<Emphasis>"Promise.all()"</Emphasis>"."
+            }),
+        )
+    }
+}
+
+/// Returns the condition of `node` if `child` is evaluated in every iteration of the loop `node`.
+///
+/// Returns `None` if `node` isn't a loop or `child` isn't repeated,
+/// and `Some(None)` if the loop doesn't have a condition.
+fn looped_condition(node: &JsSyntaxNode, child: &JsSyntaxNode) -> Option<Option<AnyJsExpression>> {
+    let is_child = |other: Option<JsSyntaxNode>| other.is_some_and(|other| &other == child);
+    match node.kind() {
+        JsSyntaxKind::JS_FOR_STATEMENT => {
+            let node = JsForStatement::cast_ref(node)?;
+            let is_looped = is_child(node.test().map(|test| test.into_syntax()))
+                || is_child(node.update().map(|update| update.into_syntax()))
+                || is_child(node.body().ok().map(|body| body.into_syntax()));
+            is_looped.then(|| node.test())
+        }
+        JsSyntaxKind::JS_FOR_IN_STATEMENT => {
+            let node = JsForInStatement::cast_ref(node)?;
+            is_child(node.body().ok().map(|body| body.into_syntax())).then_some(None)
+        }
+        JsSyntaxKind::JS_FOR_OF_STATEMENT => {
+            let node = JsForOfStatement::cast_ref(node)?;
+            (node.await_token().is_none()
+                && is_child(node.body().ok().map(|body| body.into_syntax())))
+            .then_some(None)
+        }
+        JsSyntaxKind::JS_WHILE_STATEMENT => {
+            let node = JsWhileStatement::cast_ref(node)?;
+            let test = node.test().ok();
+            let is_looped = is_child(test.clone().map(|test| test.into_syntax()))
+                || is_child(node.body().ok().map(|body| body.into_syntax()));
+            is_looped.then_some(test)
+        }
+        JsSyntaxKind::JS_DO_WHILE_STATEMENT => {
+            let node = JsDoWhileStatement::cast_ref(node)?;
+            let test = node.test().ok();
+            let is_looped = is_child(test.clone().map(|test| test.into_syntax()))
+                || is_child(node.body().ok().map(|body| body.into_syntax()));
+            is_looped.then_some(test)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if `condition` depends on the result of `node`:
+/// either `node` is in the condition, or its result is assigned to a variable that the condition reads.
+fn is_condition_dependency(node: &JsAwaitExpression, condition: &AnyJsExpression) -> bool {
+    if condition.syntax().text_range().contains_range(node.range()) {
+        return true;
+    }
+    let Some(name) = assigned_name(node) else {
+        return false;
+    };
+    condition
+        .syntax()
+        .descendants()
+        .filter_map(JsReferenceIdentifier::cast)
+        .any(|reference| {
+            reference
+                .value_token()
+                .is_ok_and(|token| token.text_trimmed() == name.text())
+        })
+}
+
+/// Returns the name of the variable that is assigned the result of `node`.
+fn assigned_name(node: &JsAwaitExpression) -> Option<TokenText> {
+    let parent = node
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)?;
+    if let Some(assignment) = JsAssignmentExpression::cast_ref(&parent) {
+        let AnyJsAssignmentPattern::AnyJsAssignment(left) = assignment.left().ok()? else {
+            return None;
+        };
+        return Some(
+            left.as_js_identifier_assignment()?
+                .name_token()
+                .ok()?
+                .token_text_trimmed(),
+        );
+    }
+    let declarator = JsInitializerClause::cast(parent)?.parent::<JsVariableDeclarator>()?;
+    let id = declarator.id().ok()?;
+    Some(
+        id.as_any_js_binding()?
+            .as_js_identifier_binding()?
+            .name_token()
+            .ok()?
+            .token_text_trimmed(),
+    )
+}
@@ -0,0 +1,104 @@
+use crate::utils::test_framework::{enclosing_test_call, TestCallKind};
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    JsConditionalExpression, JsIfStatement, JsLogicalExpression, JsSwitchStatement,
+};
+use biome_rowan::{declare_node_union, AstNode};
+
+declare_rule! {
+    /// Disallow conditional logic in tests.
+    ///
+    /// A test with conditional logic may not run all its assertions,
+    /// and may pass without testing anything.
+    /// Split the test into several tests, one for each branch.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// it("renders the title", () => {
+    ///     if (isMobile) {
+    ///         expect(title).toBeHidden();
+    ///     } else {
+    ///         expect(title).toBeVisible();
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// test("computes the total", () => {
+    ///     const expected = discount ? 90 : 100;
+    ///     expect(total()).toBe(expected);
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// describe("title", () => {
+    ///     it("is hidden on mobile", () => {
+    ///         expect(title).toBeHidden();
+    ///     });
+    ///
+    ///     it("is visible on desktop", () => {
+    ///         expect(title).toBeVisible();
+    ///     });
+    /// });
+    /// ```
+    ///
+    pub NoConditionalTests {
+        version: "next",
+        name: "noConditionalTests",
+        language: "js",
+        sources: &[RuleSource::EslintJest("no-conditional-in-test")],
+        domains: &[RuleDomain::Test],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsConditionalLogic =
+        JsIfStatement
+        | JsSwitchStatement
+        | JsConditionalExpression
+        | JsLogicalExpression
+}
+
+impl Rule for NoConditionalTests {
+    type Query = Ast<AnyJsConditionalLogic>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let mut current = node.syntax().clone();
+        // A conditional in a helper function declared in a test is also reported.
+        while let Some((call, kind)) = enclosing_test_call(&current) {
+            if kind == TestCallKind::Case {
+                return Some(());
+            }
+            current = call.into_syntax();
+        }
+        None
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Avoid conditional logic in tests."
+                },
+            )
+            .note(markup! {
+                "A test with conditional logic may not run all its assertions. Write a test for each branch instead."
+            }),
+        )
+    }
+}
@@ -0,0 +1,229 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, JsReferenceIdentifier, JsSyntaxKind, JsSyntaxNode,
+    JsxReferenceIdentifier,
+};
+use biome_rowan::{declare_node_union, AstNode, TextRange};
+
+declare_rule! {
+    /// Disallow the use of deprecated APIs.
+    ///
+    /// A declaration can be marked as deprecated with the `@deprecated` tag of a JSDoc or TSDoc comment.
+    /// Editors usually strike the name of deprecated APIs through,
+    /// but nothing prevents new usages from slipping into the code base.
+    /// This rule reports every usage of a deprecated declaration,
+    /// and includes the deprecation message in the diagnostic.
+    ///
+    /// The rule resolves usages to their declaration through the semantic model.
+    /// Thus, only the declarations of the analyzed file are taken into account:
+    /// variables, functions, classes, interfaces, type aliases, enums, and namespaces.
+    /// Re-exports of a deprecated declaration are not reported.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// /** @deprecated Use `fetchUser` instead. */
+    /// function getUser() {}
+    ///
+    /// getUser();
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// /**
+    ///  * @deprecated
+    ///  */
+    /// interface Options {}
+    ///
+    /// let options: Options;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// /** Fetches a user. */
+    /// function fetchUser() {}
+    ///
+    /// fetchUser();
+    /// ```
+    ///
+    pub NoDeprecatedApis {
+        version: "next",
+        name: "noDeprecatedApis",
+        language: "js",
+        sources: &[RuleSource::EslintTypeScript("no-deprecated")],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsReferenceIdentifier = JsReferenceIdentifier | JsxReferenceIdentifier
+}
+
+pub struct DeprecatedUsage {
+    name: String,
+    message: Option<String>,
+    declaration_range: TextRange,
+}
+
+impl Rule for NoDeprecatedApis {
+    type Query = Semantic<AnyJsReferenceIdentifier>;
+    type State = DeprecatedUsage;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let reference = ctx.query();
+        let model = ctx.model();
+        let binding = match reference {
+            AnyJsReferenceIdentifier::JsReferenceIdentifier(reference) => model.binding(reference),
+            AnyJsReferenceIdentifier::JsxReferenceIdentifier(reference) => model.binding(reference),
+        }?;
+        if is_reexport(reference.syntax()) {
+            return None;
+        }
+        let declaration = binding.tree().declaration()?;
+        let documented = documented_node(&declaration)?;
+        // Usages inside the deprecated declaration itself are fine.
+        if documented
+            .text_trimmed_range()
+            .contains_range(reference.range())
+        {
+            return None;
+        }
+        let message = deprecation_message(&documented)?;
+        Some(DeprecatedUsage {
+            name: binding.tree().syntax().text_trimmed().to_string(),
+            message: (!message.is_empty()).then_some(message),
+            declaration_range: binding.tree().range(),
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            ctx.query().range(),
+            markup! {
+                <Emphasis>{name}</Emphasis>" is deprecated."
+            },
+        )
+        .detail(
+            state.declaration_range,
+            markup! {
+                "It is marked as deprecated here."
+            },
+        );
+        if let Some(message) = &state.message {
+            diagnostic = diagnostic.note(markup! {
+                {message}
+            });
+        }
+        Some(diagnostic)
+    }
+}
+
+fn is_reexport(reference: &JsSyntaxNode) -> bool {
+    reference.ancestors().any(|ancestor| {
+        matches!(
+            ancestor.kind(),
+            JsSyntaxKind::JS_EXPORT_NAMED_CLAUSE
+                | JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE
+        )
+    })
+}
+
+/// Returns the node that carries the documentation of `declaration`.
+///
+/// This is the enclosing statement or module item,
+/// such that the documentation of `export const x = 0` is attached to `export`.
+fn documented_node(declaration: &AnyJsBindingDeclaration) -> Option<JsSyntaxNode> {
+    let syntax = match declaration {
+        AnyJsBindingDeclaration::JsVariableDeclarator(_)
+        | AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::JsFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionDeclaration(_)
+        | AnyJsBindingDeclaration::TsDeclareFunctionExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassDeclaration(_)
+        | AnyJsBindingDeclaration::JsClassExportDefaultDeclaration(_)
+        | AnyJsBindingDeclaration::TsInterfaceDeclaration(_)
+        | AnyJsBindingDeclaration::TsTypeAliasDeclaration(_)
+        | AnyJsBindingDeclaration::TsEnumDeclaration(_)
+        | AnyJsBindingDeclaration::TsModuleDeclaration(_) => declaration.syntax(),
+        _ => return None,
+    };
+    syntax.ancestors().find(|node| {
+        node.parent().map_or(true, |parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_MODULE_ITEM_LIST | JsSyntaxKind::JS_STATEMENT_LIST
+            )
+        })
+    })
+}
+
+/// Returns the message of the `@deprecated` tag found in the doc comments of `node`.
+///
+/// Returns `None` if `node` is not deprecated.
+fn deprecation_message(node: &JsSyntaxNode) -> Option<String> {
+    let token = node.first_token()?;
+    let comment = token
+        .leading_trivia()
+        .pieces()
+        .filter_map(|piece| piece.as_comments())
+        .filter(|comment| comment.text().starts_with("/**"))
+        .last()?;
+    parse_deprecated_tag(comment.text())
+}
+
+/// Extracts the description of the `@deprecated` tag of the doc comment `comment`.
+fn parse_deprecated_tag(comment: &str) -> Option<String> {
+    let content = comment.strip_prefix("/**")?.strip_suffix("*/")?;
+    let mut lines = content
+        .lines()
+        .map(|line| line.trim_start().trim_start_matches('*').trim());
+    let first = lines.find_map(|line| line.strip_prefix("@deprecated"))?;
+    // Tags such as `@deprecatedSince` are not the `@deprecated` tag.
+    if first.starts_with(|c: char| !c.is_whitespace()) {
+        return None;
+    }
+    let mut message = first.trim().to_string();
+    for line in lines.take_while(|line| !line.starts_with('@')) {
+        if line.is_empty() {
+            continue;
+        }
+        if !message.is_empty() {
+            message.push(' ');
+        }
+        message.push_str(line);
+    }
+    Some(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_deprecated_tag;
+
+    #[test]
+    fn parse_deprecated_tags() {
+        assert_eq!(
+            parse_deprecated_tag("/** @deprecated */"),
+            Some(String::new())
+        );
+        assert_eq!(
+            parse_deprecated_tag("/** @deprecated Use `b`. */"),
+            Some("Use `b`.".to_string())
+        );
+        assert_eq!(
+            parse_deprecated_tag(
+                "/**\n * Description.\n * @deprecated since v2,\n * use `b`.\n * @see b\n */"
+            ),
+            Some("since v2, use `b`.".to_string())
+        );
+        assert_eq!(parse_deprecated_tag("/** Description. */"), None);
+        assert_eq!(parse_deprecated_tag("/** @deprecatedSince 2 */"), None);
+    }
+}
@@ -0,0 +1,364 @@
+use crate::react::hooks::{function_or_class_name, is_react_component, is_react_hook};
+use crate::react::{is_react_call_api, ReactLibrary};
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    AnyJsArrayBindingPatternElement, AnyJsArrowFunctionParameters, AnyJsBindingPattern,
+    AnyJsExpression, AnyJsFunction, AnyJsMemberExpression, JsCallExpression,
+    JsComputedMemberAssignment, JsIdentifierBinding, JsIdentifierExpression,
+    JsStaticMemberAssignment, JsSyntaxKind, JsSyntaxNode, JsUnaryExpression, JsUnaryOperator,
+    JsVariableDeclarator, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+use rustc_hash::FxHashSet;
+
+declare_rule! {
+    /// Disallow direct mutations of props, state, and store values in React components and hooks.
+    ///
+    /// React assumes that props and state are immutable.
+    /// A mutated object keeps its identity:
+    /// React doesn't detect the change, and doesn't re-render the component.
+    /// Memoized values and effects that depend on the object are not updated either.
+    /// The same applies to values returned by the `useSelector` hook of Redux.
+    ///
+    /// The rule reports assignments to properties, `delete` expressions,
+    /// and calls to mutating methods such as `push` or `sort`,
+    /// when they are applied to props, to `useState` and `useReducer` values,
+    /// or to values selected from a Redux store.
+    /// Local variables initialized with one of these values, or with one of their properties,
+    /// are tracked as well.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// import { useState } from "react";
+    ///
+    /// function List() {
+    ///     const [items, setItems] = useState([]);
+    ///     const add = (item) => {
+    ///         items.push(item);
+    ///         setItems(items);
+    ///     };
+    ///     return <button onClick={() => add(1)} />;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// function Profile(props) {
+    ///     const user = props.user;
+    ///     user.name = "anonymous";
+    ///     return <div>{user.name}</div>;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// import { useState } from "react";
+    ///
+    /// function List() {
+    ///     const [items, setItems] = useState([]);
+    ///     const add = (item) => setItems([...items, item]);
+    ///     return <button onClick={() => add(1)} />;
+    /// }
+    /// ```
+    ///
+    pub NoDirectStateMutation {
+        version: "next",
+        name: "noDirectStateMutation",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("no-direct-mutation-state")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+/// The kind of immutable value that is mutated.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ImmutableValueKind {
+    Props,
+    State,
+    Store,
+}
+
+pub struct Mutation {
+    kind: ImmutableValueKind,
+    range: TextRange,
+    source_range: TextRange,
+}
+
+/// Methods that mutate the object they are called on.
+const MUTATING_METHODS: &[&str] = &[
+    "add",
+    "clear",
+    "copyWithin",
+    "delete",
+    "fill",
+    "pop",
+    "push",
+    "reverse",
+    "set",
+    "shift",
+    "sort",
+    "splice",
+    "unshift",
+];
+
+impl Rule for NoDirectStateMutation {
+    type Query = Semantic<AnyJsFunction>;
+    type State = Mutation;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let model = ctx.model();
+        let Some(name) = function_or_class_name(function.syntax()) else {
+            return Vec::new();
+        };
+        let name = name.text();
+        let is_component = is_react_component(&name);
+        if !is_component && !is_react_hook(&name) {
+            return Vec::new();
+        }
+        let mut worklist = Vec::new();
+        if is_component {
+            if let Some(props) = props_pattern(function) {
+                let source_range = props.range();
+                worklist.extend(
+                    pattern_bindings(props.syntax())
+                        .map(|binding| (binding, ImmutableValueKind::Props, source_range)),
+                );
+            }
+        }
+        for declarator in function
+            .syntax()
+            .descendants()
+            .filter_map(JsVariableDeclarator::cast)
+            .filter(|declarator| is_in_function(declarator.syntax(), function.syntax()))
+        {
+            if let Some((kind, pattern)) = hook_value_pattern(model, &declarator) {
+                let source_range = declarator.range();
+                worklist.extend(
+                    pattern_bindings(&pattern).map(|binding| (binding, kind, source_range)),
+                );
+            }
+        }
+
+        let mut visited = FxHashSet::default();
+        let mut mutations = Vec::new();
+        while let Some((binding, kind, source_range)) = worklist.pop() {
+            if !visited.insert(binding.range()) {
+                continue;
+            }
+            for reference in binding.all_references(model) {
+                let Some(expression) = reference
+                    .syntax()
+                    .parent()
+                    .and_then(JsIdentifierExpression::cast)
+                else {
+                    continue;
+                };
+                match classify_usage(expression.syntax()) {
+                    Some(Usage::Mutation(range)) => mutations.push(Mutation {
+                        kind,
+                        range,
+                        source_range,
+                    }),
+                    Some(Usage::Alias(pattern)) => worklist.extend(
+                        pattern_bindings(&pattern).map(|binding| (binding, kind, source_range)),
+                    ),
+                    None => {}
+                }
+            }
+        }
+        mutations.sort_unstable_by_key(|mutation| mutation.range.start());
+        mutations
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (message, source, advice) = match state.kind {
+            ImmutableValueKind::Props => (
+                markup! { "Props should not be mutated." },
+                markup! { "This is the props of the component." },
+                markup! { "Props are read-only. Ask the parent component to pass a new value instead." },
+            ),
+            ImmutableValueKind::State => (
+                markup! { "State values should not be mutated." },
+                markup! { "This is the state value." },
+                markup! { "Mutating the state doesn't trigger a re-render. Call the state setter with a new value instead." },
+            ),
+            ImmutableValueKind::Store => (
+                markup! { "Values of the store should not be mutated." },
+                markup! { "This value is selected from the store." },
+                markup! { "Dispatch an action to update the store instead." },
+            ),
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.range, message)
+                .detail(state.source_range, source)
+                .note(advice),
+        )
+    }
+}
+
+enum Usage {
+    /// The value is mutated by the node at the given range.
+    Mutation(TextRange),
+    /// The value, or one of its properties, is assigned to a new variable.
+    Alias(JsSyntaxNode),
+}
+
+/// Determines how the value of the identifier `expression` is used.
+fn classify_usage(expression: &JsSyntaxNode) -> Option<Usage> {
+    let mut current = expression.clone();
+    let mut is_member = false;
+    loop {
+        let parent = current.parent()?;
+        match parent.kind() {
+            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+            | JsSyntaxKind::TS_NON_NULL_ASSERTION_EXPRESSION
+            | JsSyntaxKind::TS_AS_EXPRESSION
+            | JsSyntaxKind::TS_SATISFIES_EXPRESSION => current = parent,
+            _ => match AnyJsMemberExpression::cast_ref(&parent) {
+                Some(member)
+                    if member
+                        .object()
+                        .is_ok_and(|object| object.syntax() == &current) =>
+                {
+                    current = parent;
+                    is_member = true;
+                }
+                _ => break,
+            },
+        }
+    }
+    let parent = current.parent()?;
+    let is_object_of_assignment = JsStaticMemberAssignment::cast_ref(&parent)
+        .and_then(|assignment| assignment.object().ok())
+        .or_else(|| {
+            JsComputedMemberAssignment::cast_ref(&parent)
+                .and_then(|assignment| assignment.object().ok())
+        })
+        .is_some_and(|object| object.syntax() == &current);
+    if is_object_of_assignment {
+        return Some(Usage::Mutation(parent.text_trimmed_range()));
+    }
+    if is_member {
+        if let Some(call) = JsCallExpression::cast_ref(&parent) {
+            let is_mutating_call = call
+                .callee()
+                .is_ok_and(|callee| callee.syntax() == &current)
+                && AnyJsMemberExpression::cast_ref(&current)
+                    .and_then(|member| member.member_name())
+                    .is_some_and(|name| MUTATING_METHODS.contains(&name.text()));
+            return is_mutating_call.then(|| Usage::Mutation(call.range()));
+        }
+        if let Some(unary) = JsUnaryExpression::cast_ref(&parent) {
+            return (unary.operator().ok()? == JsUnaryOperator::Delete)
+                .then(|| Usage::Mutation(unary.range()));
+        }
+    }
+    if parent.kind() == JsSyntaxKind::JS_CALL_ARGUMENT_LIST {
+        // `Object.assign(value, ...)`
+        let call = JsCallExpression::cast(parent.parent()?.parent()?)?;
+        let is_first_argument = parent
+            .first_child()
+            .is_some_and(|argument| argument == current);
+        let is_object_assign = call
+            .callee()
+            .ok()
+            .and_then(|callee| AnyJsMemberExpression::cast(callee.into_syntax()))
+            .is_some_and(|callee| {
+                callee
+                    .member_name()
+                    .is_some_and(|name| name.text() == "assign")
+                    && callee.object().is_ok_and(|object| {
+                        object
+                            .as_js_identifier_expression()
+                            .and_then(|object| object.name().ok())
+                            .is_some_and(|name| name.has_name("Object"))
+                    })
+            });
+        return (is_first_argument && is_object_assign).then(|| Usage::Mutation(call.range()));
+    }
+    if parent.kind() == JsSyntaxKind::JS_INITIALIZER_CLAUSE {
+        let declarator = JsVariableDeclarator::cast(parent.parent()?)?;
+        return Some(Usage::Alias(declarator.id().ok()?.into_syntax()));
+    }
+    None
+}
+
+/// Returns the pattern of the props parameter of the component `function`.
+fn props_pattern(function: &AnyJsFunction) -> Option<AnyJsBindingPattern> {
+    match function.parameters().ok()? {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+            Some(AnyJsBindingPattern::AnyJsBinding(binding))
+        }
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => parameters
+            .items()
+            .first()?
+            .ok()?
+            .as_any_js_formal_parameter()?
+            .as_js_formal_parameter()?
+            .binding()
+            .ok(),
+    }
+}
+
+/// Returns the pattern that binds the value returned by `useState`, `useReducer`, or `useSelector`.
+fn hook_value_pattern(
+    model: &SemanticModel,
+    declarator: &JsVariableDeclarator,
+) -> Option<(ImmutableValueKind, JsSyntaxNode)> {
+    let initializer = declarator
+        .initializer()?
+        .expression()
+        .ok()?
+        .omit_parentheses();
+    let call = initializer.as_js_call_expression()?;
+    let callee = call.callee().ok()?.omit_parentheses();
+    let id = declarator.id().ok()?;
+    if is_react_call_api(&callee, model, ReactLibrary::React, "useState")
+        || is_react_call_api(&callee, model, ReactLibrary::React, "useReducer")
+    {
+        // `const [state, setState] = useState()`
+        let AnyJsBindingPattern::JsArrayBindingPattern(pattern) = id else {
+            return None;
+        };
+        let AnyJsArrayBindingPatternElement::JsArrayBindingPatternElement(element) =
+            pattern.elements().first()?.ok()?
+        else {
+            return None;
+        };
+        return Some((
+            ImmutableValueKind::State,
+            element.pattern().ok()?.into_syntax(),
+        ));
+    }
+    let is_selector = match callee {
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            identifier.name().ok()?.has_name("useSelector")
+        }
+        _ => false,
+    };
+    is_selector.then(|| (ImmutableValueKind::Store, id.into_syntax()))
+}
+
+/// Returns the bindings declared by the binding pattern `pattern`.
+fn pattern_bindings(pattern: &JsSyntaxNode) -> impl Iterator<Item = JsIdentifierBinding> {
+    pattern.descendants().filter_map(JsIdentifierBinding::cast)
+}
+
+/// Returns `true` if the closest function that encloses `node` is `function`.
+fn is_in_function(node: &JsSyntaxNode, function: &JsSyntaxNode) -> bool {
+    node.ancestors()
+        .find(|ancestor| AnyJsFunction::can_cast(ancestor.kind()))
+        .is_some_and(|ancestor| &ancestor == function)
+}
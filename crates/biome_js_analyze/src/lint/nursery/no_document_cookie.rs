@@ -0,0 +1,151 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    global_identifier, static_value::StaticValue, AnyJsExpression, AnyJsMemberExpression,
+    JsComputedMemberAssignment, JsStaticMemberAssignment,
+};
+use biome_rowan::{declare_node_union, AstNode, SyntaxResult};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+declare_rule! {
+    /// Disallow direct use of `document.cookie`.
+    ///
+    /// The `document.cookie` API is error-prone:
+    /// reading it returns all the cookies in a single string that must be parsed,
+    /// and writing it requires serializing the attributes of the cookie by hand.
+    /// Prefer the asynchronous [Cookie Store API](https://developer.mozilla.org/en-US/docs/Web/API/Cookie_Store_API)
+    /// or a dedicated abstraction.
+    ///
+    /// The files implementing such an abstraction can be allowed with the option `allowedFiles`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// document.cookie = "foo=bar; Secure";
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const cookies = window.document.cookie;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// await cookieStore.set({ name: "foo", value: "bar" });
+    /// ```
+    ///
+    /// ```js
+    /// function f(document) {
+    ///     document.cookie = "foo=bar";
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `allowedFiles`
+    ///
+    /// A list of paths of files where `document.cookie` can be used.
+    /// A file is allowed if its path ends with one of the listed paths.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "allowedFiles": ["src/utils/cookies.js"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoDocumentCookie {
+        version: "next",
+        name: "noDocumentCookie",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("no-document-cookie")],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsMemberAccess = AnyJsMemberExpression | JsStaticMemberAssignment | JsComputedMemberAssignment
+}
+
+impl AnyJsMemberAccess {
+    fn object(&self) -> SyntaxResult<AnyJsExpression> {
+        match self {
+            Self::AnyJsMemberExpression(expression) => expression.object(),
+            Self::JsStaticMemberAssignment(assignment) => assignment.object(),
+            Self::JsComputedMemberAssignment(assignment) => assignment.object(),
+        }
+    }
+
+    fn member_name(&self) -> Option<StaticValue> {
+        match self {
+            Self::AnyJsMemberExpression(expression) => expression.member_name(),
+            Self::JsStaticMemberAssignment(assignment) => Some(StaticValue::String(
+                assignment.member().ok()?.as_js_name()?.value_token().ok()?,
+            )),
+            Self::JsComputedMemberAssignment(assignment) => assignment
+                .member()
+                .ok()?
+                .omit_parentheses()
+                .as_static_value(),
+        }
+    }
+}
+
+/// Options for the rule `noDocumentCookie`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoDocumentCookieOptions {
+    /// A list of paths of files where `document.cookie` can be used.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_files: Vec<String>,
+}
+
+impl Rule for NoDocumentCookie {
+    type Query = Semantic<AnyJsMemberAccess>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = Box<NoDocumentCookieOptions>;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.member_name()?.text() != "cookie" {
+            return None;
+        }
+        let (reference, name) = global_identifier(&node.object().ok()?.omit_parentheses())?;
+        if name.text() != "document" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        let file_path = ctx.file_path();
+        let is_allowed_file = ctx
+            .options()
+            .allowed_files
+            .iter()
+            .any(|allowed_file| file_path.ends_with(Path::new(allowed_file)));
+        (!is_allowed_file).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't use "<Emphasis>"document.cookie"</Emphasis>" directly."
+                },
+            )
+            .note(markup! {
+                "Reading and writing "<Emphasis>"document.cookie"</Emphasis>" requires parsing and serializing cookies by hand, which is error-prone."
+            })
+            .note(markup! {
+                "Use the Cookie Store API or a dedicated abstraction instead."
+            }),
+        )
+    }
+}
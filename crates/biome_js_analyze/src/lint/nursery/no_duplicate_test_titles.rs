@@ -0,0 +1,142 @@
+use crate::utils::test_framework::{enclosing_test_suite, test_call_kind, TestCallKind};
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsCallArgument, AnyJsExpression, JsCallExpression, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Disallow tests and test suites with the same title in the same test suite.
+    ///
+    /// Two tests with the same title are hard to tell apart in the test report,
+    /// and one of them is often a copy that should have been renamed.
+    ///
+    /// Only titles that are string literals, or template literals without interpolation, are compared.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// describe("sum", () => {
+    ///     it("adds numbers", () => {});
+    ///     it("adds numbers", () => {});
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// describe("sum", () => {});
+    /// describe("sum", () => {});
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// describe("sum", () => {
+    ///     it("adds numbers", () => {});
+    ///     it("adds negative numbers", () => {});
+    /// });
+    ///
+    /// describe("product", () => {
+    ///     it("adds numbers", () => {});
+    /// });
+    /// ```
+    ///
+    pub NoDuplicateTestTitles {
+        version: "next",
+        name: "noDuplicateTestTitles",
+        language: "js",
+        sources: &[RuleSource::EslintJest("no-identical-title")],
+        domains: &[RuleDomain::Test],
+        recommended: false,
+    }
+}
+
+pub struct DuplicateTitle {
+    kind: TestCallKind,
+    /// The range of the first title.
+    first: TextRange,
+}
+
+impl Rule for NoDuplicateTestTitles {
+    type Query = Ast<JsCallExpression>;
+    type State = DuplicateTitle;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let kind = test_call_kind(call)?;
+        if kind == TestCallKind::Hook {
+            return None;
+        }
+        let (title, _) = test_title(call)?;
+        let suite = enclosing_test_suite(call.syntax());
+        // The siblings are either the calls of the callback of the suite, or the calls of the module.
+        let scope = match &suite {
+            Some(suite) => suite.syntax().clone(),
+            None => call.syntax().ancestors().last()?,
+        };
+        scope
+            .descendants()
+            .filter_map(JsCallExpression::cast)
+            .take_while(|sibling| sibling != call)
+            .find_map(|sibling| {
+                if test_call_kind(&sibling) != Some(kind)
+                    || enclosing_test_suite(sibling.syntax()) != suite
+                {
+                    return None;
+                }
+                let (sibling_title, range) = test_title(&sibling)?;
+                (sibling_title == title).then_some(DuplicateTitle { kind, first: range })
+            })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (_, range) = test_title(ctx.query())?;
+        let message = match state.kind {
+            TestCallKind::Suite => {
+                markup! { "This test suite has the same title as another test suite." }
+            }
+            _ => markup! { "This test has the same title as another test." },
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), range, message)
+                .detail(state.first, markup! { "The title is first used here." })
+                .note(markup! {
+                    "Give a different title to each test of a test suite."
+                }),
+        )
+    }
+}
+
+/// Returns the static title of the test `call` and its range.
+fn test_title(call: &JsCallExpression) -> Option<(String, TextRange)> {
+    let argument = call.arguments().ok()?.args().first()?.ok()?;
+    let AnyJsCallArgument::AnyJsExpression(argument) = argument else {
+        return None;
+    };
+    let title = match &argument {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => literal
+            .as_js_string_literal_expression()?
+            .inner_string_text()
+            .ok()?
+            .to_string(),
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+            let mut text = String::new();
+            for element in template.elements() {
+                text.push_str(
+                    element
+                        .as_js_template_chunk_element()?
+                        .template_chunk_token()
+                        .ok()?
+                        .text_trimmed(),
+                );
+            }
+            text
+        }
+        _ => return None,
+    };
+    Some((title, argument.range()))
+}
@@ -0,0 +1,157 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyJsArrowFunctionParameters, AnyJsFunction, AnyJsParameter, JsConstructorClassMember,
+    JsMethodClassMember, JsMethodObjectMember, JsParameters,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, TextRange};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU8;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Enforce a maximum number of parameters in a function.
+    ///
+    /// Functions that take many parameters are hard to call correctly,
+    /// because the order of the arguments must be remembered.
+    /// They are also often a sign that the function does too many things.
+    /// Consider grouping the related parameters in an object.
+    ///
+    /// The TypeScript `this` parameter isn't counted, because it isn't passed as an argument.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function foo(a, b, c, d) {}
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const foo = (a, b, c, d) => {};
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function foo(a, b, c) {}
+    /// ```
+    ///
+    /// ```js
+    /// function foo({ a, b, c, d }) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxParameters`
+    ///
+    /// The maximum number of parameters allowed in a function.
+    /// Defaults to `3`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "maxParameters": 4
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoExcessiveParameters {
+        version: "next",
+        name: "noExcessiveParameters",
+        language: "js",
+        sources: &[
+            RuleSource::Eslint("max-params"),
+            RuleSource::EslintTypeScript("max-params"),
+        ],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsFunctionWithParameters = AnyJsFunction
+        | JsMethodClassMember
+        | JsMethodObjectMember
+        | JsConstructorClassMember
+}
+
+impl AnyJsFunctionWithParameters {
+    /// Returns the number of parameters and their range.
+    fn parameters(&self) -> Option<(usize, TextRange)> {
+        let parameters = match self {
+            Self::AnyJsFunction(function) => match function.parameters().ok()? {
+                AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+                    return Some((1, binding.range()));
+                }
+                AnyJsArrowFunctionParameters::JsParameters(parameters) => parameters,
+            },
+            Self::JsMethodClassMember(method) => method.parameters().ok()?,
+            Self::JsMethodObjectMember(method) => method.parameters().ok()?,
+            Self::JsConstructorClassMember(constructor) => {
+                let parameters = constructor.parameters().ok()?;
+                return Some((parameters.parameters().len(), parameters.range()));
+            }
+        };
+        Some((count_parameters(&parameters), parameters.range()))
+    }
+}
+
+fn count_parameters(parameters: &JsParameters) -> usize {
+    parameters
+        .items()
+        .iter()
+        .filter(|parameter| !matches!(parameter, Ok(AnyJsParameter::TsThisParameter(_))))
+        .count()
+}
+
+/// Options for the rule `noExcessiveParameters`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoExcessiveParametersOptions {
+    /// The maximum number of parameters allowed in a function.
+    pub max_parameters: NonZeroU8,
+}
+
+impl Default for NoExcessiveParametersOptions {
+    fn default() -> Self {
+        Self {
+            max_parameters: NonZeroU8::new(3).unwrap(),
+        }
+    }
+}
+
+impl Rule for NoExcessiveParameters {
+    type Query = Ast<AnyJsFunctionWithParameters>;
+    /// The number of parameters and their range.
+    type State = (usize, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = NoExcessiveParametersOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (count, range) = ctx.query().parameters()?;
+        (count > ctx.options().max_parameters.get() as usize).then_some((count, range))
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, (count, range): &Self::State) -> Option<RuleDiagnostic> {
+        let max_parameters = ctx.options().max_parameters.get();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This function has too many parameters ("{count}")."
+                },
+            )
+            .note(markup! {
+                "The maximum number of parameters allowed is "{max_parameters}"."
+            })
+            .note(markup! {
+                "Consider grouping the related parameters in an object."
+            }),
+        )
+    }
+}
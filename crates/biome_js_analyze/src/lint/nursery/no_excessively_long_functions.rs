@@ -0,0 +1,186 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_syntax::{
+    AnyFunctionLike, JsConstructorClassMember, JsGetterClassMember, JsGetterObjectMember,
+    JsLanguage, JsSetterClassMember, JsSetterObjectMember, JsSyntaxNode,
+};
+use biome_rowan::{declare_node_union, AstNode, Direction, SyntaxTriviaPiece, TextRange};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU16;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Enforce a maximum number of lines in a function.
+    ///
+    /// Long functions are hard to read and to maintain,
+    /// and often do more than one thing.
+    /// Splitting them into smaller functions makes the code easier to understand and to test.
+    ///
+    /// The rule counts the logical lines of a function:
+    /// the lines containing only comments or whitespaces aren't counted.
+    /// The lines of the nested functions are counted in the enclosing function.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// // With the option `maxLines` set to `2`
+    /// function foo() {
+    ///     const x = 0;
+    ///     return x;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function foo() {
+    ///     // Comments aren't counted.
+    ///
+    ///     // Neither are blank lines.
+    ///     return 0;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxLines`
+    ///
+    /// The maximum number of logical lines allowed in a function.
+    /// Defaults to `50`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "maxLines": 30
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoExcessivelyLongFunctions {
+        version: "next",
+        name: "noExcessivelyLongFunctions",
+        language: "js",
+        sources: &[RuleSource::Eslint("max-lines-per-function")],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsFunctionWithBody = AnyFunctionLike
+        | JsConstructorClassMember
+        | JsGetterClassMember
+        | JsSetterClassMember
+        | JsGetterObjectMember
+        | JsSetterObjectMember
+}
+
+impl AnyJsFunctionWithBody {
+    /// Returns the range to highlight in the diagnostics:
+    /// the name of the function if any, otherwise its keyword or its arrow.
+    fn diagnostic_range(&self) -> Option<TextRange> {
+        match self {
+            Self::AnyFunctionLike(function) => function
+                .name_range()
+                .or_else(|| {
+                    function
+                        .function_token()
+                        .map(|token| token.text_trimmed_range())
+                })
+                .or_else(|| {
+                    function
+                        .fat_arrow_token()
+                        .map(|token| token.text_trimmed_range())
+                }),
+            Self::JsConstructorClassMember(member) => Some(member.name().ok()?.range()),
+            Self::JsGetterClassMember(member) => Some(member.name().ok()?.range()),
+            Self::JsSetterClassMember(member) => Some(member.name().ok()?.range()),
+            Self::JsGetterObjectMember(member) => Some(member.name().ok()?.range()),
+            Self::JsSetterObjectMember(member) => Some(member.name().ok()?.range()),
+        }
+    }
+}
+
+/// Options for the rule `noExcessivelyLongFunctions`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoExcessivelyLongFunctionsOptions {
+    /// The maximum number of logical lines allowed in a function.
+    pub max_lines: NonZeroU16,
+}
+
+impl Default for NoExcessivelyLongFunctionsOptions {
+    fn default() -> Self {
+        Self {
+            max_lines: NonZeroU16::new(50).unwrap(),
+        }
+    }
+}
+
+impl Rule for NoExcessivelyLongFunctions {
+    type Query = Ast<AnyJsFunctionWithBody>;
+    /// The number of logical lines of the function.
+    type State = usize;
+    type Signals = Option<Self::State>;
+    type Options = NoExcessivelyLongFunctionsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let line_count = count_logical_lines(ctx.query().syntax());
+        (line_count > ctx.options().max_lines.get() as usize).then_some(line_count)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, line_count: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let max_lines = ctx.options().max_lines;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.diagnostic_range().unwrap_or_else(|| node.range()),
+                markup! {
+                    "This function has too many lines ("{line_count}")."
+                },
+            )
+            .note(markup! {
+                "The maximum number of lines allowed is "{max_lines.get()}"."
+            })
+            .note(markup! {
+                "Consider splitting this function into smaller functions."
+            }),
+        )
+    }
+}
+
+/// Returns the number of lines of `node` that contain at least one token.
+fn count_logical_lines(node: &JsSyntaxNode) -> usize {
+    let mut count = 0;
+    // Whether the current line has already been counted.
+    let mut is_line_counted = false;
+    for token in node.descendants_tokens(Direction::Next) {
+        if has_line_break(token.leading_trivia().pieces()) {
+            is_line_counted = false;
+        }
+        for (index, line) in token.text_trimmed().split('\n').enumerate() {
+            if index > 0 {
+                is_line_counted = false;
+            }
+            if !is_line_counted && !line.trim().is_empty() {
+                count += 1;
+                is_line_counted = true;
+            }
+        }
+        if has_line_break(token.trailing_trivia().pieces()) {
+            is_line_counted = false;
+        }
+    }
+    count
+}
+
+/// Returns `true` if the trivia contains a newline, including in a multiline comment.
+fn has_line_break(mut pieces: impl Iterator<Item = SyntaxTriviaPiece<JsLanguage>>) -> bool {
+    pieces.any(|piece| piece.is_newline() || (piece.is_comments() && piece.text().contains('\n')))
+}
@@ -0,0 +1,311 @@
+use crate::{services::manifest::is_es_module, services::semantic::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsCallArgument, AnyJsExpression, AnyJsImportClause, AnyJsModuleItem,
+    AnyJsNamedImportSpecifier, JsCallArguments, JsIdentifierExpression, JsImport, JsModule,
+    JsReferenceIdentifier, JsSyntaxKind, JsSyntaxToken, T,
+};
+use biome_project::PackageJson;
+use biome_rowan::{
+    AstNode, AstNodeExt, AstNodeList, AstSeparatedList, BatchMutationExt, TriviaPieceKind,
+};
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow the use of `__dirname` and `__filename` in ECMAScript modules.
+    ///
+    /// The globals `__dirname` and `__filename` are only defined in CommonJS modules.
+    /// In an ECMAScript module, they are `undefined` and their use throws a `ReferenceError`.
+    /// Use `import.meta.url` with `fileURLToPath()` from `node:url` instead.
+    ///
+    /// A file is an ECMAScript module if its extension is `.mjs` or `.mts`,
+    /// or if the field `type` of the `package.json` of its package is set to `module`
+    /// and its extension isn't `.cjs` nor `.cts`.
+    /// The rule ignores the other files.
+    ///
+    /// The code fix imports `fileURLToPath()` if needed.
+    /// Note that the fixed `__dirname` ends with a path separator.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// // In a file `index.mjs`
+    /// const file = __filename;
+    /// ```
+    ///
+    /// ```js,ignore
+    /// // In a file `index.mjs`
+    /// const config = path.join(__dirname, "config.json");
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { fileURLToPath } from "node:url";
+    ///
+    /// const file = fileURLToPath(import.meta.url);
+    /// const dir = fileURLToPath(new URL(".", import.meta.url));
+    /// ```
+    ///
+    pub NoGlobalDirnameFilename {
+        version: "next",
+        name: "noGlobalDirnameFilename",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("prefer-module")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoGlobalDirnameFilename {
+    type Query = Semantic<JsReferenceIdentifier>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let name = node.value_token().ok()?;
+        if !matches!(name.text_trimmed(), "__dirname" | "__filename") {
+            return None;
+        }
+        let manifest = ctx.get_service::<Arc<PackageJson>>().map(Arc::as_ref);
+        if !is_es_module(ctx.file_path(), manifest) || ctx.model().binding(node).is_some() {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let name = node.value_token().ok()?;
+        let name = name.text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    <Emphasis>{name}</Emphasis>" isn't defined in ECMAScript modules."
+                },
+            )
+            .note(markup! {
+                "Use "<Emphasis>"import.meta.url"</Emphasis>" with "<Emphasis>"fileURLToPath()"</Emphasis>" from "<Emphasis>"node:url"</Emphasis>" instead."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let expression =
+            AnyJsExpression::from(JsIdentifierExpression::cast(node.syntax().parent()?)?);
+        let module = JsModule::cast(ctx.root().into_syntax())?;
+        let imported_name = find_file_url_to_path_import(&module);
+        let function_name = match &imported_name {
+            Some(name) => name.text_trimmed(),
+            None => {
+                // Don't shadow an existing binding when importing the function.
+                let model = ctx.model();
+                let is_name_taken = model
+                    .global_scope()
+                    .descendents()
+                    .any(|scope| scope.get_binding("fileURLToPath").is_some());
+                if is_name_taken {
+                    return None;
+                }
+                "fileURLToPath"
+            }
+        };
+        let import_meta_url = AnyJsExpression::from(make::js_static_member_expression(
+            make::js_import_meta_expression(
+                make::token(T![import]),
+                make::token(T![.]),
+                JsSyntaxToken::new_detached(JsSyntaxKind::META, "meta", [], []),
+            )
+            .into(),
+            make::token(T![.]),
+            make::js_name(make::ident("url")).into(),
+        ));
+        let argument = if node.value_token().ok()?.text_trimmed() == "__dirname" {
+            let quoted_dot = if ctx.as_preferred_quote().is_double() {
+                make::js_string_literal(".")
+            } else {
+                make::js_string_literal_single_quotes(".")
+            };
+            make::js_new_expression(
+                make::token(T![new]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_identifier_expression(make::js_reference_identifier(make::ident("URL")))
+                    .into(),
+            )
+            .with_arguments(call_arguments([
+                AnyJsExpression::AnyJsLiteralExpression(
+                    make::js_string_literal_expression(quoted_dot).into(),
+                ),
+                import_meta_url,
+            ]))
+            .build()
+            .into()
+        } else {
+            import_meta_url
+        };
+        let new_expression = AnyJsExpression::from(
+            make::js_call_expression(
+                make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                    function_name,
+                )))
+                .into(),
+                call_arguments([argument]),
+            )
+            .build(),
+        );
+
+        let mut mutation = ctx.root().begin();
+        if imported_name.is_some() {
+            mutation.replace_node(expression, new_expression);
+        } else {
+            // The list of items is replaced to insert the import,
+            // so the expression is replaced in the detached item that contains it.
+            let items = module.items();
+            let mut new_items = Vec::with_capacity(items.len() + 1);
+            for item in items.iter() {
+                if item.range().contains_range(expression.range()) {
+                    new_items.push(item.replace_node(expression.clone(), new_expression.clone())?);
+                } else {
+                    new_items.push(item);
+                }
+            }
+            let import_count = items
+                .iter()
+                .take_while(|item| JsImport::can_cast(item.syntax().kind()))
+                .count();
+            let is_double_quote = ctx.as_preferred_quote().is_double();
+            let import = if import_count == 0 {
+                // The import is inserted after the comments at the start of the file.
+                let first_item = new_items.first_mut()?;
+                let leading_trivia: Vec<_> = first_item
+                    .syntax()
+                    .first_leading_trivia()?
+                    .pieces()
+                    .collect();
+                *first_item = first_item.clone().with_leading_trivia_pieces([])?;
+                file_url_to_path_import(
+                    &make::token(T![import]).with_leading_trivia_pieces(leading_trivia),
+                    make::token(T![;]).with_trailing_trivia([(TriviaPieceKind::Newline, "\n")]),
+                    is_double_quote,
+                )
+            } else {
+                file_url_to_path_import(
+                    &make::token(T![import])
+                        .with_leading_trivia([(TriviaPieceKind::Newline, "\n")]),
+                    make::token(T![;]),
+                    is_double_quote,
+                )
+            };
+            new_items.insert(import_count, import);
+            mutation.replace_node_discard_trivia(items, make::js_module_item_list(new_items));
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use "<Emphasis>"fileURLToPath()"</Emphasis>" with "<Emphasis>"import.meta.url"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the local name of `fileURLToPath` if it's imported from `node:url` or `url`.
+fn find_file_url_to_path_import(module: &JsModule) -> Option<JsSyntaxToken> {
+    module.items().iter().find_map(|item| {
+        let AnyJsModuleItem::JsImport(import) = item else {
+            return None;
+        };
+        let AnyJsImportClause::JsImportNamedClause(clause) = import.import_clause().ok()? else {
+            return None;
+        };
+        let source = clause.source().ok()?.inner_string_text().ok()?;
+        if clause.type_token().is_some() || !matches!(source.text(), "node:url" | "url") {
+            return None;
+        }
+        clause
+            .named_specifiers()
+            .ok()?
+            .specifiers()
+            .iter()
+            .find_map(|specifier| match specifier.ok()? {
+                AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(specifier)
+                    if specifier.type_token().is_none() =>
+                {
+                    let binding = specifier.local_name().ok()?;
+                    let name = binding.as_js_identifier_binding()?.name_token().ok()?;
+                    (name.text_trimmed() == "fileURLToPath").then_some(name)
+                }
+                AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier)
+                    if specifier.type_token().is_none() =>
+                {
+                    let imported_name = specifier.name().ok()?.value().ok()?;
+                    if imported_name.text_trimmed() != "fileURLToPath" {
+                        return None;
+                    }
+                    let binding = specifier.local_name().ok()?;
+                    binding.as_js_identifier_binding()?.name_token().ok()
+                }
+                _ => None,
+            })
+    })
+}
+
+/// Returns `import { fileURLToPath } from "node:url";`.
+fn file_url_to_path_import(
+    import_token: &JsSyntaxToken,
+    semicolon_token: JsSyntaxToken,
+    is_double_quote: bool,
+) -> AnyJsModuleItem {
+    let specifier = make::js_shorthand_named_import_specifier(AnyJsBinding::from(
+        make::js_identifier_binding(make::ident("fileURLToPath")),
+    ))
+    .build();
+    let source = if is_double_quote {
+        make::js_string_literal("node:url")
+    } else {
+        make::js_string_literal_single_quotes("node:url")
+    };
+    let clause = make::js_import_named_clause(
+        make::js_named_import_specifiers(
+            make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            make::js_named_import_specifier_list([specifier.into()], []),
+            make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        ),
+        make::token_decorated_with_space(T![from]),
+        make::js_module_source(source),
+    )
+    .build();
+    make::js_import(
+        import_token.with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        clause.into(),
+    )
+    .with_semicolon_token(semicolon_token)
+    .build()
+    .into()
+}
+
+fn call_arguments<const N: usize>(arguments: [AnyJsExpression; N]) -> JsCallArguments {
+    let separators = (1..N)
+        .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+    make::js_call_arguments(
+        make::token(T!['(']),
+        make::js_call_argument_list(
+            arguments.map(AnyJsCallArgument::AnyJsExpression),
+            separators,
+        ),
+        make::token(T![')']),
+    )
+}
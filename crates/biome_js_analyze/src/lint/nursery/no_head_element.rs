@@ -0,0 +1,100 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_rowan::AstNode;
+use std::path::{Component, Path};
+
+declare_rule! {
+    /// Prevent usage of `<head>` element in a Next.js project.
+    ///
+    /// Next.js manages the `<head>` of the page.
+    /// A `<head>` element rendered by a page is not merged with the head of the document,
+    /// and may result in unexpected behavior.
+    /// Use the `<Head />` component from `next/head` instead.
+    ///
+    /// The rule ignores the custom document `pages/_document`, which renders the whole document,
+    /// and the files of the `app` directory, which don't use `next/head`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// function Index() {
+    ///     return (
+    ///         <head>
+    ///             <title>Invalid</title>
+    ///         </head>
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// import Head from "next/head";
+    ///
+    /// function Index() {
+    ///     return (
+    ///         <Head>
+    ///             <title>All good!</title>
+    ///         </Head>
+    ///     );
+    /// }
+    /// ```
+    ///
+    pub NoHeadElement {
+        version: "next",
+        name: "noHeadElement",
+        language: "jsx",
+        sources: &[RuleSource::EslintNext("no-head-element")],
+        domains: &[RuleDomain::Next],
+        recommended: false,
+    }
+}
+
+impl Rule for NoHeadElement {
+    type Query = Ast<AnyJsxElement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let name = node.name().ok()?;
+        if name.as_jsx_name()?.value_token().ok()?.text_trimmed() != "head" {
+            return None;
+        }
+        if is_document_or_app_file(ctx.file_path()) {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't use "<Emphasis>"<head>"</Emphasis>" element."
+                },
+            )
+            .note(markup! {
+                "Using the "<Emphasis>"<head>"</Emphasis>" element can cause unexpected behavior in a Next.js application. Use "<Emphasis>"<Head />"</Emphasis>" from "<Emphasis>"next/head"</Emphasis>" instead."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `path` is the custom document, or belongs to the `app` directory.
+fn is_document_or_app_file(path: &Path) -> bool {
+    path.file_stem().is_some_and(|stem| stem == "_document")
+        || path.parent().is_some_and(|parent| {
+            parent
+                .components()
+                .any(|c| c == Component::Normal("app".as_ref()))
+        })
+}
@@ -0,0 +1,96 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{jsx_ext::AnyJsxElement, JsxElement};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Prevent usage of `<img>` element in a Next.js project.
+    ///
+    /// Using the `<img>` element can result in slower Largest Contentful Paint (LCP)
+    /// and higher bandwidth usage, as the image is not optimized.
+    /// The `<Image />` component from `next/image` automatically optimizes images:
+    /// it resizes them, serves modern formats, and lazy loads them.
+    ///
+    /// An `<img>` inside a `<picture>` element is allowed,
+    /// because `<picture>` is used to provide art direction that `next/image` doesn't support.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <img alt="Foo" src="/foo.png" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// import Image from "next/image";
+    ///
+    /// <Image alt="Foo" src="/foo.png" width={100} height={100} />
+    /// ```
+    ///
+    /// ```jsx
+    /// <picture>
+    ///     <source srcSet="/foo.avif" type="image/avif" />
+    ///     <img alt="Foo" src="/foo.png" />
+    /// </picture>
+    /// ```
+    ///
+    pub NoImgElement {
+        version: "next",
+        name: "noImgElement",
+        language: "jsx",
+        sources: &[RuleSource::EslintNext("no-img-element")],
+        domains: &[RuleDomain::Next],
+        recommended: false,
+    }
+}
+
+impl Rule for NoImgElement {
+    type Query = Ast<AnyJsxElement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let name = node.name().ok()?;
+        if name.as_jsx_name()?.value_token().ok()?.text_trimmed() != "img" {
+            return None;
+        }
+        // `<img></img>` is the opening element of its own `JsxElement`.
+        let element = match node {
+            AnyJsxElement::JsxOpeningElement(element) => element.syntax().parent()?,
+            AnyJsxElement::JsxSelfClosingElement(element) => element.syntax().clone(),
+        };
+        let is_in_picture = element
+            .ancestors()
+            .skip(1)
+            .find_map(JsxElement::cast)
+            .and_then(|parent| parent.opening_element().ok()?.name().ok())
+            .and_then(|name| name.as_jsx_name()?.value_token().ok())
+            .is_some_and(|token| token.text_trimmed() == "picture");
+        (!is_in_picture).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Don't use "<Emphasis>"<img>"</Emphasis>" element."
+                },
+            )
+            .note(markup! {
+                "Using "<Emphasis>"<img>"</Emphasis>" could result in slower LCP and higher bandwidth."
+            })
+            .note(markup! {
+                "Consider using "<Emphasis>"<Image />"</Emphasis>" from "<Emphasis>"next/image"</Emphasis>" to automatically optimize images."
+            }),
+        )
+    }
+}
@@ -0,0 +1,336 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, AnyJsMemberExpression,
+    JsBinaryExpression, JsBinaryOperator, JsCallExpression, JsUnaryExpression, JsUnaryOperator, T,
+};
+use biome_rowan::{declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Disallow shorthand type conversions.
+    ///
+    /// Shorthand type conversions such as `!!x`, `+x`, and `"" + x` are short,
+    /// but they are harder to read than the explicit conversions `Boolean(x)`, `Number(x)`, and `String(x)`.
+    /// The rule also reports `~x.indexOf(y)`, which checks if `x` contains `y`,
+    /// and is better written as `x.includes(y)`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const isActive = !!user;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const count = +input;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const label = "" + value;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// if (~items.indexOf(item)) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const isActive = Boolean(user);
+    /// const count = Number(input);
+    /// const label = String(value);
+    /// if (items.includes(item)) {}
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The options `boolean`, `number`, and `string` enable the reports of the conversions
+    /// to booleans (`!!x` and `~x.indexOf(y)`), numbers (`+x`), and strings (`"" + x`).
+    /// They all default to `true`.
+    ///
+    /// The option `allow` lists the operators that are allowed: `"!!"`, `"~"`, and `"+"`.
+    /// The operator `"+"` allows both `+x` and `"" + x`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "string": false,
+    ///         "allow": ["!!"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoImplicitCoercion {
+        version: "next",
+        name: "noImplicitCoercion",
+        language: "js",
+        sources: &[RuleSource::Eslint("no-implicit-coercion")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyImplicitCoercion = JsUnaryExpression | JsBinaryExpression
+}
+
+/// Options for the rule `noImplicitCoercion`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoImplicitCoercionOptions {
+    /// Report the conversions to booleans: `!!x` and `~x.indexOf(y)`.
+    pub boolean: bool,
+    /// Report the conversions to numbers: `+x`.
+    pub number: bool,
+    /// Report the conversions to strings: `"" + x`.
+    pub string: bool,
+    /// The operators that are allowed.
+    pub allow: Vec<CoercionOperator>,
+}
+
+impl Default for NoImplicitCoercionOptions {
+    fn default() -> Self {
+        Self {
+            boolean: true,
+            number: true,
+            string: true,
+            allow: Vec::new(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub enum CoercionOperator {
+    /// `!!x`
+    #[serde(rename = "!!")]
+    DoubleNegation,
+    /// `~x.indexOf(y)`
+    #[serde(rename = "~")]
+    BitwiseNot,
+    /// `+x` and `"" + x`
+    #[serde(rename = "+")]
+    Plus,
+}
+
+pub enum ImplicitCoercion {
+    /// `!!x`
+    Boolean(AnyJsExpression),
+    /// `+x`
+    Number(AnyJsExpression),
+    /// `"" + x`
+    String(AnyJsExpression),
+    /// `~x.indexOf(y)`
+    IndexOf(JsCallExpression),
+}
+
+impl Rule for NoImplicitCoercion {
+    type Query = Semantic<AnyImplicitCoercion>;
+    type State = ImplicitCoercion;
+    type Signals = Option<Self::State>;
+    type Options = NoImplicitCoercionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let is_allowed = |operator| options.allow.contains(&operator);
+        match ctx.query() {
+            AnyImplicitCoercion::JsUnaryExpression(unary) => {
+                let argument = unary.argument().ok()?.omit_parentheses();
+                match unary.operator().ok()? {
+                    JsUnaryOperator::LogicalNot
+                        if options.boolean && !is_allowed(CoercionOperator::DoubleNegation) =>
+                    {
+                        let inner = argument.as_js_unary_expression()?;
+                        if inner.operator().ok()? != JsUnaryOperator::LogicalNot {
+                            return None;
+                        }
+                        Some(ImplicitCoercion::Boolean(inner.argument().ok()?))
+                    }
+                    JsUnaryOperator::Plus
+                        if options.number && !is_allowed(CoercionOperator::Plus) =>
+                    {
+                        if matches!(
+                            argument,
+                            AnyJsExpression::AnyJsLiteralExpression(
+                                AnyJsLiteralExpression::JsNumberLiteralExpression(_)
+                            )
+                        ) {
+                            return None;
+                        }
+                        Some(ImplicitCoercion::Number(unary.argument().ok()?))
+                    }
+                    JsUnaryOperator::BitwiseNot
+                        if options.boolean && !is_allowed(CoercionOperator::BitwiseNot) =>
+                    {
+                        let call = argument.as_js_call_expression()?;
+                        let callee =
+                            AnyJsMemberExpression::cast(call.callee().ok()?.into_syntax())?;
+                        matches!(callee.member_name()?.text(), "indexOf" | "lastIndexOf")
+                            .then(|| ImplicitCoercion::IndexOf(call.clone()))
+                    }
+                    _ => None,
+                }
+            }
+            AnyImplicitCoercion::JsBinaryExpression(binary) => {
+                if !options.string
+                    || is_allowed(CoercionOperator::Plus)
+                    || binary.operator().ok()? != JsBinaryOperator::Plus
+                {
+                    return None;
+                }
+                let left = binary.left().ok()?;
+                let right = binary.right().ok()?;
+                let other = if is_empty_string(&left) {
+                    right
+                } else if is_empty_string(&right) {
+                    left
+                } else {
+                    return None;
+                };
+                // `"" + "a"` is a concatenation.
+                if matches!(
+                    other.clone().omit_parentheses(),
+                    AnyJsExpression::AnyJsLiteralExpression(
+                        AnyJsLiteralExpression::JsStringLiteralExpression(_)
+                    ) | AnyJsExpression::JsTemplateExpression(_)
+                ) {
+                    return None;
+                }
+                Some(ImplicitCoercion::String(other))
+            }
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let (message, replacement) = match state {
+            ImplicitCoercion::Boolean(_) => ("a boolean", "Boolean(x)"),
+            ImplicitCoercion::Number(_) => ("a number", "Number(x)"),
+            ImplicitCoercion::String(_) => ("a string", "String(x)"),
+            ImplicitCoercion::IndexOf(_) => {
+                return Some(
+                    RuleDiagnostic::new(
+                        rule_category!(),
+                        ctx.query().range(),
+                        markup! {
+                            "Use "<Emphasis>".includes()"</Emphasis>" instead of "<Emphasis>"~"</Emphasis>" to check if an element is included."
+                        },
+                    )
+                    .note(markup! {
+                        "The bitwise operator hides the intent of the check."
+                    }),
+                );
+            }
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use "<Emphasis>{replacement}</Emphasis>" to convert to "{message}"."
+                },
+            )
+            .note(markup! {
+                "The shorthand conversion is harder to read than the explicit conversion."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let node = AnyJsExpression::cast(ctx.query().syntax().clone())?;
+        let (new_node, message) = match state {
+            ImplicitCoercion::Boolean(argument)
+            | ImplicitCoercion::Number(argument)
+            | ImplicitCoercion::String(argument) => {
+                let function = match state {
+                    ImplicitCoercion::Boolean(_) => "Boolean",
+                    ImplicitCoercion::Number(_) => "Number",
+                    _ => "String",
+                };
+                // Don't call a local binding that shadows the global function.
+                let model = ctx.model();
+                if model
+                    .scope(node.syntax())
+                    .ancestors()
+                    .any(|scope| scope.get_binding(function).is_some())
+                {
+                    return None;
+                }
+                let argument = argument.clone().omit_parentheses().trim_trivia()?;
+                let call = make::js_call_expression(
+                    make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                        function,
+                    )))
+                    .into(),
+                    make::js_call_arguments(
+                        make::token(T!['(']),
+                        make::js_call_argument_list(
+                            [AnyJsCallArgument::AnyJsExpression(argument)],
+                            [],
+                        ),
+                        make::token(T![')']),
+                    ),
+                )
+                .build();
+                (
+                    AnyJsExpression::from(call),
+                    markup! { "Use "<Emphasis>{function}"()"</Emphasis>" instead." }.to_owned(),
+                )
+            }
+            ImplicitCoercion::IndexOf(call) => {
+                let arguments = call.arguments().ok()?;
+                // The second argument of `lastIndexOf` searches backwards.
+                if arguments.args().len() != 1 {
+                    return None;
+                }
+                let member = AnyJsMemberExpression::cast(call.callee().ok()?.into_syntax())?;
+                let AnyJsMemberExpression::JsStaticMemberExpression(member) = member else {
+                    return None;
+                };
+                let new_member = member
+                    .clone()
+                    .with_member(make::js_name(make::ident("includes")).into());
+                let new_call = call.clone().with_callee(new_member.into()).trim_trivia()?;
+                (
+                    AnyJsExpression::from(new_call),
+                    markup! { "Use "<Emphasis>".includes()"</Emphasis>" instead." }.to_owned(),
+                )
+            }
+        };
+        mutation.replace_node(node, new_node);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            message,
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `expression` is `""`, `''`, or ``` `` ```.
+fn is_empty_string(expression: &AnyJsExpression) -> bool {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(literal),
+        ) => literal
+            .inner_string_text()
+            .is_ok_and(|text| text.text().is_empty()),
+        AnyJsExpression::JsTemplateExpression(template) => {
+            template.tag().is_none() && template.elements().is_empty()
+        }
+        _ => false,
+    }
+}
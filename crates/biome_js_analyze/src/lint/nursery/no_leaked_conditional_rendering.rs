@@ -0,0 +1,184 @@
+use crate::{services::semantic::Semantic, utils::type_info::TypeFacts, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, JsLogicalExpression, JsLogicalOperator, JsSyntaxKind,
+    T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow values that may render `0` or `NaN` in JSX conditional rendering.
+    ///
+    /// The `&&` operator is often used to conditionally render an element:
+    /// `{condition && <Element />}`.
+    /// When `condition` is falsy, the expression evaluates to `condition` itself.
+    /// React doesn't render `false`, `null`, `undefined`, or `""`,
+    /// but it renders the numbers `0` and `NaN`.
+    /// Thus, `{count && <Items />}` renders `0` when `count` is zero.
+    ///
+    /// Type annotations, literals, and expressions that always evaluate to a boolean are used
+    /// to determine whether a value may be a number.
+    /// Values of unknown type are reported.
+    ///
+    /// The rule provides a fix that converts the expression into a conditional expression.
+    /// Alternatively, compare the value explicitly, e.g. `count > 0 && <Items />`,
+    /// or convert it to a boolean with `Boolean(count)`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const Items = ({ items }) => <div>{items.length && <List items={items} />}</div>;
+    /// ```
+    ///
+    /// ```tsx,expect_diagnostic
+    /// const Counter = ({ count }: { count: number }) => <div>{count && <Badge count={count} />}</div>;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// const Items = ({ items }) => <div>{items.length > 0 && <List items={items} />}</div>;
+    /// ```
+    ///
+    /// ```tsx
+    /// const Profile = ({ user }: { user: User | undefined }) => <div>{!!user && <Avatar user={user} />}</div>;
+    /// ```
+    ///
+    /// ```tsx
+    /// function Greeting(name: string | undefined) {
+    ///     return <div>{name && <span>{name}</span>}</div>;
+    /// }
+    /// ```
+    ///
+    pub NoLeakedConditionalRendering {
+        version: "next",
+        name: "noLeakedConditionalRendering",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("jsx-no-leaked-render")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoLeakedConditionalRendering {
+    type Query = Semantic<JsLogicalExpression>;
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if node.operator().ok()? != JsLogicalOperator::LogicalAnd || !is_rendered(node) {
+            return None;
+        }
+        // In `a && b && <C />`, both `a` and `b` may be rendered.
+        let mut operands = Vec::new();
+        let mut current = node.left().ok()?.omit_parentheses();
+        loop {
+            match current {
+                AnyJsExpression::JsLogicalExpression(logical)
+                    if logical.operator().ok()? == JsLogicalOperator::LogicalAnd =>
+                {
+                    operands.push(logical.right().ok()?);
+                    current = logical.left().ok()?.omit_parentheses();
+                }
+                _ => {
+                    operands.push(current);
+                    break;
+                }
+            }
+        }
+        operands
+            .into_iter()
+            .rev()
+            .find(|operand| may_render_number(ctx.model(), operand))
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, operand: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                operand.range(),
+                markup! {
+                    "This value may be rendered as "<Emphasis>"0"</Emphasis>" or "<Emphasis>"NaN"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "React renders the numbers "<Emphasis>"0"</Emphasis>" and "<Emphasis>"NaN"</Emphasis>" when they are the result of "<Emphasis>"&&"</Emphasis>"."
+            })
+            .note(markup! {
+                "Convert the value to a boolean, for example with an explicit comparison, or use a conditional expression."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let left = node.left().ok()?;
+        // Chains of `&&` cannot be converted without duplicating operands.
+        if matches!(
+            left.clone().omit_parentheses(),
+            AnyJsExpression::JsLogicalExpression(_)
+        ) {
+            return None;
+        }
+        let operator = node.operator_token().ok()?;
+        let question_mark = make::token(T![?])
+            .with_leading_trivia_pieces(operator.leading_trivia().pieces())
+            .with_trailing_trivia_pieces(operator.trailing_trivia().pieces());
+        let conditional = make::js_conditional_expression(
+            left,
+            question_mark,
+            node.right().ok()?,
+            make::token_decorated_with_space(T![:]),
+            AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsNullLiteralExpression(make::js_null_literal_expression(
+                    make::token(T![null]),
+                )),
+            ),
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(node.clone()), conditional.into());
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use a conditional expression instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if the result of `node` is rendered as a JSX child.
+fn is_rendered(node: &JsLogicalExpression) -> bool {
+    node.syntax()
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+        .is_some_and(|ancestor| ancestor.kind() == JsSyntaxKind::JSX_EXPRESSION_CHILD)
+}
+
+fn may_render_number(model: &SemanticModel, operand: &AnyJsExpression) -> bool {
+    let operand = operand.clone().omit_parentheses();
+    if is_boolean_call(&operand) {
+        return false;
+    }
+    TypeFacts::from_expression(model, &operand)
+        .intersects(TypeFacts::ZERO_NUMBER | TypeFacts::ZERO_BIGINT)
+}
+
+/// Returns `true` if `expression` is a call to `Boolean`.
+fn is_boolean_call(expression: &AnyJsExpression) -> bool {
+    expression
+        .as_js_call_expression()
+        .and_then(|call| call.callee().ok())
+        .and_then(|callee| callee.as_js_identifier_expression()?.name().ok())
+        .is_some_and(|name| name.has_name("Boolean"))
+}
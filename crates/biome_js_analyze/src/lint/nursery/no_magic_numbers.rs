@@ -0,0 +1,274 @@
+use crate::services::semantic::Semantic;
+use crate::utils::batch::JsBatchMutation;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    AnyJsExpression, JsCallArgumentList, JsCallArguments, JsCallExpression,
+    JsNumberLiteralExpression, JsSyntaxKind, JsSyntaxNode, JsUnaryExpression, JsUnaryOperator,
+    JsVariableDeclaration, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Disallow magic numbers.
+    ///
+    /// A magic number is a numeric literal with an unexplained meaning.
+    /// Declaring the number as a named constant documents its meaning,
+    /// and avoids updating several occurrences of the same number when it changes.
+    ///
+    /// The numbers that initialize a `const` declaration, the values of object properties,
+    /// and the initializers of class properties are allowed,
+    /// because their names already explain them.
+    /// The radix of `parseInt()` is also allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const total = price * 1.2;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// setTimeout(retry, 5000);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// let attempts = 3;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const TAX_RATE = 1.2;
+    /// const total = price * TAX_RATE;
+    /// ```
+    ///
+    /// ```js
+    /// const config = { timeout: 5000 };
+    /// ```
+    ///
+    /// ```js
+    /// const last = items[items.length - 1];
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `ignore`
+    ///
+    /// The integers that are allowed everywhere.
+    /// Defaults to `[-1, 0, 1]`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "ignore": [0, 1, 2]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### `ignoreEnums`
+    ///
+    /// Allow numbers as initializers of the members of TypeScript enums.
+    /// Defaults to `false`.
+    ///
+    /// ### `ignoreDefaultValues`
+    ///
+    /// Allow numbers as default values of parameters and destructured bindings.
+    /// Defaults to `false`.
+    ///
+    pub NoMagicNumbers {
+        version: "next",
+        name: "noMagicNumbers",
+        language: "js",
+        sources: &[
+            RuleSource::Eslint("no-magic-numbers"),
+            RuleSource::EslintTypeScript("no-magic-numbers"),
+        ],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `noMagicNumbers`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoMagicNumbersOptions {
+    /// The integers that are allowed everywhere.
+    pub ignore: Vec<i64>,
+    /// Allow numbers as initializers of the members of TypeScript enums.
+    pub ignore_enums: bool,
+    /// Allow numbers as default values of parameters and destructured bindings.
+    pub ignore_default_values: bool,
+}
+
+impl Default for NoMagicNumbersOptions {
+    fn default() -> Self {
+        Self {
+            ignore: vec![-1, 0, 1],
+            ignore_enums: false,
+            ignore_default_values: false,
+        }
+    }
+}
+
+impl Rule for NoMagicNumbers {
+    type Query = Semantic<JsNumberLiteralExpression>;
+    /// The literal, including its minus sign.
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = NoMagicNumbersOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let literal = ctx.query();
+        let options = ctx.options();
+        let mut value = literal.as_number()?;
+        let mut number = AnyJsExpression::AnyJsLiteralExpression(literal.clone().into());
+        if let Some(unary) = literal.parent::<JsUnaryExpression>() {
+            if unary.operator().ok()? == JsUnaryOperator::Minus {
+                value = -value;
+                number = unary.into();
+            }
+        }
+        if value.fract() == 0.0
+            && options
+                .ignore
+                .iter()
+                .any(|ignored| *ignored as f64 == value)
+        {
+            return None;
+        }
+        let parent = number
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find(|node| node.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)?;
+        match parent.kind() {
+            JsSyntaxKind::JS_PROPERTY_OBJECT_MEMBER => return None,
+            JsSyntaxKind::JS_INITIALIZER_CLAUSE => {
+                if is_allowed_initializer(&parent, options) {
+                    return None;
+                }
+            }
+            JsSyntaxKind::JS_CALL_ARGUMENT_LIST if is_parse_int_radix(&parent, &number) => {
+                return None;
+            }
+            _ => {}
+        }
+        Some(number)
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, number: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                number.range(),
+                markup! {
+                    "Magic number detected."
+                },
+            )
+            .note(markup! {
+                "The meaning of this number isn't explained. Declare it as a named constant."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, number: &Self::State) -> Option<JsRuleAction> {
+        let name = constant_name(&number.syntax().text_trimmed().to_string());
+        // Don't introduce a constant that conflicts with an existing binding.
+        let model = ctx.model();
+        if model
+            .global_scope()
+            .descendents()
+            .any(|scope| scope.get_binding(&name).is_some())
+        {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        if !mutation.extract_to_top_level_constant(number, &name) {
+            return None;
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Extract the number into the constant "<Emphasis>{name}</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if the number initializes a `const` declaration or a class property,
+/// or an enum member or a default value that the options allow.
+fn is_allowed_initializer(initializer: &JsSyntaxNode, options: &NoMagicNumbersOptions) -> bool {
+    let Some(parent) = initializer.parent() else {
+        return false;
+    };
+    match parent.kind() {
+        JsSyntaxKind::JS_VARIABLE_DECLARATOR => JsVariableDeclarator::cast(parent)
+            .and_then(|declarator| {
+                declarator
+                    .syntax()
+                    .ancestors()
+                    .find_map(JsVariableDeclaration::cast)
+            })
+            .is_some_and(|declaration| declaration.is_const()),
+        JsSyntaxKind::TS_ENUM_MEMBER => options.ignore_enums,
+        JsSyntaxKind::JS_FORMAL_PARAMETER
+        | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY
+        | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY
+        | JsSyntaxKind::JS_ARRAY_BINDING_PATTERN_ELEMENT => options.ignore_default_values,
+        // Class properties are named as well.
+        JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER => true,
+        _ => false,
+    }
+}
+
+/// Returns `true` if `number` is the second argument of `parseInt()` or `Number.parseInt()`.
+fn is_parse_int_radix(arguments: &JsSyntaxNode, number: &AnyJsExpression) -> bool {
+    let Some(arguments) = JsCallArgumentList::cast_ref(arguments) else {
+        return false;
+    };
+    let is_radix = arguments
+        .iter()
+        .nth(1)
+        .and_then(|argument| argument.ok())
+        .is_some_and(|argument| argument.syntax() == number.syntax());
+    is_radix
+        && arguments
+            .parent::<JsCallArguments>()
+            .and_then(|arguments| arguments.parent::<JsCallExpression>())
+            .and_then(|call| call.callee().ok())
+            .is_some_and(|callee| {
+                let callee = callee.omit_parentheses();
+                callee.get_callee_member_name().map_or_else(
+                    || callee.syntax().text_trimmed() == "parseInt",
+                    |member| member.text_trimmed() == "parseInt",
+                )
+            })
+}
+
+/// Returns the name of the constant extracted from `number`, such as `MAGIC_NUMBER_1_5` for `1.5`.
+fn constant_name(number: &str) -> String {
+    let mut name = String::from("MAGIC_NUMBER_");
+    for c in number.chars() {
+        match c {
+            '-' => name.push_str("MINUS_"),
+            c if c.is_ascii_alphanumeric() => name.push(c.to_ascii_uppercase()),
+            _ => name.push('_'),
+        }
+    }
+    name
+}
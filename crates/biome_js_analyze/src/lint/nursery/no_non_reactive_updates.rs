@@ -0,0 +1,213 @@
+use crate::services::control_flow::AnyJsControlFlowRoot;
+use crate::services::semantic::Semantic;
+use crate::ComponentTemplate;
+use biome_analyze::{
+    context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsExpression, JsComputedMemberAssignment, JsComputedMemberExpression, JsFileSource,
+    JsStaticMemberAssignment, JsStaticMemberExpression, JsSyntaxNode, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Disallow updating a variable of a Svelte component that the markup uses, when the variable isn't reactive.
+    ///
+    /// In runes mode, only the variables declared with `$state`, `$derived` and `$props` are reactive.
+    /// When a function, such as an event handler, reassigns or mutates another variable,
+    /// the markup keeps showing the value that the variable had when the component was rendered.
+    ///
+    /// The rule reports the variables of the script of a component that the markup reads,
+    /// and that a function reassigns, such as `count++`, or mutates, such as `user.name = name`.
+    /// It only applies to the components that use runes.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```svelte
+    /// <script>
+    ///     const { label } = $props();
+    ///     let count = 0;
+    /// </script>
+    ///
+    /// <button onclick={() => count++}>{label}: {count}</button>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```svelte
+    /// <script>
+    ///     const { label } = $props();
+    ///     let count = $state(0);
+    /// </script>
+    ///
+    /// <button onclick={() => count++}>{label}: {count}</button>
+    /// ```
+    ///
+    pub NoNonReactiveUpdates {
+        version: "next",
+        name: "noNonReactiveUpdates",
+        language: "js",
+        sources: &[RuleSource::EslintSvelte("valid-compile")],
+        source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Svelte],
+        recommended: false,
+    }
+}
+
+/// The runes that the components of the runes mode use
+const RUNES: &[&str] = &[
+    "$bindable",
+    "$derived",
+    "$effect",
+    "$host",
+    "$inspect",
+    "$props",
+    "$state",
+];
+
+/// The runes that declare reactive variables
+const REACTIVE_RUNES: &[&str] = &["$derived", "$props", "$state"];
+
+impl Rule for NoNonReactiveUpdates {
+    type Query = Semantic<JsVariableDeclarator>;
+    /// The range of the name of the variable, and the range of its update
+    type State = (TextRange, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let declarator = ctx.query();
+        let model = ctx.model();
+        if !ctx
+            .source_type::<JsFileSource>()
+            .as_embedding_kind()
+            .is_svelte()
+        {
+            return None;
+        }
+        // The markup is only known when the template of the component is analyzed along with its script
+        let template = ctx.get_service::<ComponentTemplate>()?;
+        if template.contains(declarator.range()) || is_reactive_initializer(declarator, model) {
+            return None;
+        }
+        let id = declarator.id().ok()?;
+        let binding = model.as_binding(id.as_any_js_binding()?.as_js_identifier_binding()?);
+        if binding.scope() != model.global_scope() {
+            return None;
+        }
+        let is_read_by_markup = binding
+            .all_reads()
+            .any(|reference| template.contains(*reference.range()));
+        if !is_read_by_markup || !uses_runes(model) {
+            return None;
+        }
+        let update = binding.all_references().find(|reference| {
+            is_in_function(reference.syntax())
+                && (reference.is_write() || is_mutated(reference.syntax()))
+        })?;
+        Some((id.range(), update.syntax().text_trimmed_range()))
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, (name, update): &Self::State) -> Option<RuleDiagnostic> {
+        let declarator = ctx.query();
+        let name_text = declarator.id().ok()?.syntax().text_trimmed().to_string();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                name,
+                markup! {
+                    <Emphasis>{name_text}</Emphasis>" is updated, but it isn't reactive."
+                },
+            )
+            .detail(
+                update,
+                markup! {
+                    "The markup doesn't show the changes of this update."
+                },
+            )
+            .note(markup! {
+                "Declare the variable with "<Emphasis>"$state(...)"</Emphasis>" to make it reactive."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if the variable of `declarator` is initialized with a rune that declares a reactive variable,
+/// such as `$state(0)` or `$derived.by(compute)`
+fn is_reactive_initializer(declarator: &JsVariableDeclarator, model: &SemanticModel) -> bool {
+    let Some(AnyJsExpression::JsCallExpression(call)) = declarator
+        .initializer()
+        .and_then(|initializer| initializer.expression().ok())
+    else {
+        return false;
+    };
+    let callee = match call.callee() {
+        Ok(AnyJsExpression::JsStaticMemberExpression(member)) => member.object(),
+        callee => callee,
+    };
+    let Ok(AnyJsExpression::JsIdentifierExpression(identifier)) = callee else {
+        return false;
+    };
+    identifier.name().is_ok_and(|name| {
+        model.binding(&name).is_none()
+            && name
+                .value_token()
+                .is_ok_and(|token| REACTIVE_RUNES.contains(&token.text_trimmed()))
+    })
+}
+
+/// Returns `true` if the component uses runes, such as `$state`
+fn uses_runes(model: &SemanticModel) -> bool {
+    model.all_unresolved_references().any(|reference| {
+        let name = reference.syntax().text_trimmed();
+        RUNES.iter().any(|rune| name == *rune)
+    })
+}
+
+/// Returns `true` if `reference` is inside a function, which can run after the component is rendered
+fn is_in_function(reference: &JsSyntaxNode) -> bool {
+    reference
+        .ancestors()
+        .find_map(AnyJsControlFlowRoot::cast)
+        .is_some_and(|root| {
+            !matches!(
+                root,
+                AnyJsControlFlowRoot::JsModule(_) | AnyJsControlFlowRoot::JsScript(_)
+            )
+        })
+}
+
+/// Returns `true` if the read reference `reference` is the object of an assignment to a member,
+/// such as `user` in `user.name = name` or `user.address.city = city`
+fn is_mutated(reference: &JsSyntaxNode) -> bool {
+    let Some(mut object) = reference.parent() else {
+        return false;
+    };
+    while let Some(parent) = object.parent() {
+        let parent_object = if let Some(member) = JsStaticMemberExpression::cast_ref(&parent) {
+            member.object()
+        } else if let Some(member) = JsComputedMemberExpression::cast_ref(&parent) {
+            member.object()
+        } else if let Some(member) = JsStaticMemberAssignment::cast_ref(&parent) {
+            return member
+                .object()
+                .is_ok_and(|member_object| member_object.syntax() == &object);
+        } else if let Some(member) = JsComputedMemberAssignment::cast_ref(&parent) {
+            return member
+                .object()
+                .is_ok_and(|member_object| member_object.syntax() == &object);
+        } else {
+            return false;
+        };
+        if !parent_object.is_ok_and(|parent_object| parent_object.syntax() == &object) {
+            return false;
+        }
+        object = parent;
+    }
+    false
+}
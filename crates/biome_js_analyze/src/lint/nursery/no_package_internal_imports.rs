@@ -0,0 +1,139 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::AnyJsImportSpecifierLike;
+use biome_project::PackageJson;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow imports of the internal files of the other packages of the workspace.
+    ///
+    /// In a monorepo, the packages of the workspace declare their public entry points
+    /// in the field `exports` of their `package.json`.
+    /// Importing another file of a package, such as `@acme/ui/src/button`,
+    /// depends on its internal structure and breaks when the package is reorganized.
+    /// Moreover, Node.js and the bundlers refuse to resolve such imports once the package is published.
+    ///
+    /// The rule only checks the dependencies that are packages of the workspace,
+    /// that is the dependencies linked in `node_modules` by the package manager.
+    /// The packages without the field `exports` are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// In the following examples, the `package.json` of the package `@acme/ui` declares:
+    ///
+    /// ```json
+    /// {
+    ///     "name": "@acme/ui",
+    ///     "exports": {
+    ///         ".": "./src/index.js",
+    ///         "./button": "./src/button/index.js"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// import { Button } from "@acme/ui/src/button/index.js";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// import { Button } from "@acme/ui/button";
+    /// import { Theme } from "@acme/ui";
+    /// ```
+    ///
+    pub NoPackageInternalImports {
+        version: "next",
+        name: "noPackageInternalImports",
+        language: "js",
+        sources: &[RuleSource::EslintImport("no-internal-modules")],
+        source_kind: RuleSourceKind::Inspired,
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    /// The name of the imported package
+    package_name: Box<str>,
+    /// The public entry points of the imported package
+    entry_points: Box<[String]>,
+}
+
+impl Rule for NoPackageInternalImports {
+    type Query = Ast<AnyJsImportSpecifierLike>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let specifier = node.inner_string_text()?;
+        let (package_name, subpath) = split_package_specifier(specifier.text())?;
+        let workspace_packages = ctx.get_service::<Arc<Vec<PackageJson>>>()?;
+        let package = workspace_packages
+            .iter()
+            .find(|package| package.name.as_deref() == Some(package_name))?;
+        let exports = package.exports.as_ref()?;
+        if exports.is_exported(&format!("./{subpath}")) {
+            return None;
+        }
+        let entry_points = exports
+            .subpaths
+            .iter()
+            .filter(|(_, has_target)| *has_target)
+            .map(|(subpath, _)| {
+                format!(
+                    "{package_name}{}",
+                    subpath.strip_prefix('.').unwrap_or(subpath)
+                )
+            })
+            .collect();
+        Some(RuleState {
+            package_name: package_name.into(),
+            entry_points,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let token = ctx.query().module_name_token()?;
+        let package_name = state.package_name.as_ref();
+        let mut diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            token.text_trimmed_range(),
+            markup! {
+                "This import reaches into the internal files of the workspace package "<Emphasis>{package_name}</Emphasis>"."
+            },
+        )
+        .note(markup! {
+            "Only the entry points declared in the field "<Emphasis>"exports"</Emphasis>" of its "<Emphasis>"package.json"</Emphasis>" are public."
+        });
+        if !state.entry_points.is_empty() {
+            let entry_points = state.entry_points.join(", ");
+            diagnostic = diagnostic.note(markup! {
+                "Import one of its public entry points instead: "<Emphasis>{entry_points}</Emphasis>
+            });
+        }
+        Some(diagnostic)
+    }
+}
+
+/// Splits a bare specifier, such as `@acme/ui/src/button`, into the package name and the subpath.
+///
+/// Returns `None` if the specifier isn't a bare specifier or doesn't have a subpath.
+fn split_package_specifier(specifier: &str) -> Option<(&str, &str)> {
+    if specifier.starts_with(['.', '/']) || specifier.contains(':') {
+        return None;
+    }
+    let name_end = if specifier.starts_with('@') {
+        let scope_end = specifier.find('/')?;
+        scope_end + 1 + specifier[scope_end + 1..].find('/')?
+    } else {
+        specifier.find('/')?
+    };
+    let (package_name, subpath) = (&specifier[..name_end], &specifier[name_end + 1..]);
+    (!subpath.is_empty()).then_some((package_name, subpath))
+}
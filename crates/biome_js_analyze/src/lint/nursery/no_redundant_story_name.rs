@@ -0,0 +1,188 @@
+use crate::storybook::{is_stories_file, story_name_from_export};
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsAssignment, AnyJsAssignmentPattern, AnyJsExpression,
+    JsAssignmentExpression, JsExpressionStatement, JsInitializerClause, JsObjectExpression,
+    JsObjectMemberList, JsPropertyObjectMember, JsSyntaxKind, JsVariableDeclarator,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow story names that are equal to the names that Storybook generates.
+    ///
+    /// Storybook generates the name of a story from the name of its export:
+    /// the story exported as `PrimaryButton` is named `Primary Button`.
+    /// Setting the same name explicitly is redundant.
+    ///
+    /// The rule applies to the files whose name contains `.stories.` or `.story.`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export const PrimaryButton = {
+    ///     name: "Primary Button",
+    /// };
+    /// ```
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export const PrimaryButton = () => <Button primary />;
+    /// PrimaryButton.storyName = "Primary Button";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export const PrimaryButton = {
+    ///     name: "I am the primary",
+    /// };
+    /// ```
+    ///
+    pub NoRedundantStoryName {
+        version: "next",
+        name: "noRedundantStoryName",
+        language: "js",
+        sources: &[RuleSource::EslintStorybook("no-redundant-story-name")],
+        domains: &[RuleDomain::Storybook],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    /// A story name set by a `name` property in a CSF3 story,
+    /// or by an assignment to the `storyName` property of a story.
+    pub AnyStoryName = JsPropertyObjectMember | JsAssignmentExpression
+}
+
+impl Rule for NoRedundantStoryName {
+    type Query = Ast<AnyStoryName>;
+    /// The name of the story.
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let (export_name, name) = match ctx.query() {
+            AnyStoryName::JsPropertyObjectMember(member) => {
+                if member.name().ok()?.name()?.text() != "name" {
+                    return None;
+                }
+                let object = member
+                    .parent::<JsObjectMemberList>()?
+                    .parent::<JsObjectExpression>()?;
+                // Skip `satisfies`, `as`, and parentheses around the story.
+                let initializer = object
+                    .syntax()
+                    .ancestors()
+                    .skip(1)
+                    .find(|node| {
+                        !matches!(
+                            node.kind(),
+                            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION
+                                | JsSyntaxKind::TS_SATISFIES_EXPRESSION
+                                | JsSyntaxKind::TS_AS_EXPRESSION
+                        )
+                    })
+                    .and_then(JsInitializerClause::cast)?;
+                let declarator = initializer.parent::<JsVariableDeclarator>()?;
+                AnyJsBindingDeclaration::from(declarator.clone()).export()?;
+                let binding = declarator.id().ok()?;
+                let binding = binding.as_any_js_binding()?.as_js_identifier_binding()?;
+                (
+                    binding.name_token().ok()?.token_text_trimmed(),
+                    member.value().ok()?,
+                )
+            }
+            AnyStoryName::JsAssignmentExpression(assignment) => {
+                if !JsExpressionStatement::can_cast(assignment.syntax().parent()?.kind()) {
+                    return None;
+                }
+                let AnyJsAssignmentPattern::AnyJsAssignment(
+                    AnyJsAssignment::JsStaticMemberAssignment(left),
+                ) = assignment.left().ok()?
+                else {
+                    return None;
+                };
+                if left
+                    .member()
+                    .ok()?
+                    .as_js_name()?
+                    .value_token()
+                    .ok()?
+                    .text_trimmed()
+                    != "storyName"
+                {
+                    return None;
+                }
+                let AnyJsExpression::JsIdentifierExpression(object) = left.object().ok()? else {
+                    return None;
+                };
+                (
+                    object.name().ok()?.value_token().ok()?.token_text_trimmed(),
+                    assignment.right().ok()?,
+                )
+            }
+        };
+        let name = name.omit_parentheses().as_static_value()?;
+        let name = name.as_string_constant()?;
+        (is_stories_file(ctx.file_path()) && story_name_from_export(export_name.text()) == name)
+            .then(|| name.to_string())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This story name is redundant."
+                },
+            )
+            .note(markup! {
+                "Storybook already names the story "<Emphasis>{name}</Emphasis>" after the name of its export."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        match ctx.query() {
+            AnyStoryName::JsPropertyObjectMember(member) => {
+                let members = member.parent::<JsObjectMemberList>()?;
+                let following_separator = members
+                    .iter()
+                    .zip(members.separators().map(|separator| separator.ok()))
+                    .find(|(other, _)| {
+                        other
+                            .as_ref()
+                            .is_ok_and(|other| other.syntax() == member.syntax())
+                    })
+                    .and_then(|(_, separator)| separator);
+                if let Some(separator) = following_separator {
+                    mutation.remove_token(separator);
+                }
+                mutation.remove_node(member.clone());
+            }
+            AnyStoryName::JsAssignmentExpression(assignment) => {
+                mutation.remove_node(assignment.parent::<JsExpressionStatement>()?);
+            }
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Remove the story name." }.to_owned(),
+            mutation,
+        ))
+    }
+}
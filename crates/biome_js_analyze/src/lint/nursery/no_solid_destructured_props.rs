@@ -0,0 +1,90 @@
+use crate::solid::{is_solid_component, props_parameter};
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsBindingPattern, AnyJsFunction, JsObjectBindingPattern};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow destructuring props in Solid components.
+    ///
+    /// Solid tracks the reads of the props object:
+    /// destructuring the props reads them once, when the component is created,
+    /// and the component doesn't update when the props change.
+    /// Access the props through the props object, or use `splitProps` to split them.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// function Greeting({ name }) {
+    ///     return <p>Hello {name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const Greeting = ({ name = "World" }) => <p>Hello {name}</p>;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// function Greeting(props) {
+    ///     return <p>Hello {props.name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// function Greeting(props) {
+    ///     const [local, others] = splitProps(props, ["name"]);
+    ///     return <p {...others}>Hello {local.name}</p>;
+    /// }
+    /// ```
+    ///
+    pub NoSolidDestructuredProps {
+        version: "next",
+        name: "noSolidDestructuredProps",
+        language: "jsx",
+        sources: &[RuleSource::EslintSolid("no-destructure")],
+        domains: &[RuleDomain::Solid],
+        recommended: false,
+    }
+}
+
+impl Rule for NoSolidDestructuredProps {
+    type Query = Ast<AnyJsFunction>;
+    type State = JsObjectBindingPattern;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        if !is_solid_component(function) {
+            return None;
+        }
+        match props_parameter(function)? {
+            AnyJsBindingPattern::JsObjectBindingPattern(pattern) => Some(pattern),
+            _ => None,
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, pattern: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                pattern.range(),
+                markup! {
+                    "Destructuring the props breaks the reactivity of the component."
+                },
+            )
+            .note(markup! {
+                "The destructured values are read once and don't update when the props change."
+            })
+            .note(markup! {
+                "Access the props through the props object, or use "<Emphasis>"splitProps"</Emphasis>" to split them."
+            }),
+        )
+    }
+}
@@ -0,0 +1,177 @@
+use crate::services::semantic::Semantic;
+use crate::solid::accessor_binding;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleDomain,
+    RuleSource, RuleSourceKind,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::ReferencesExtensions;
+use biome_js_syntax::{
+    jsx_ext::AnyJsxElement, AnyJsExpression, JsConditionalExpression, JsIdentifierExpression,
+    JsIfStatement, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow reading Solid signals without calling them.
+    ///
+    /// A signal created with `createSignal` or `createMemo` is an accessor function.
+    /// Its value is obtained by calling it.
+    /// Using the accessor where a value is expected,
+    /// for example in a JSX child, a template literal, or an operation,
+    /// uses the function itself instead of its value.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const [count, setCount] = createSignal(0);
+    /// <p>{count}</p>;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const [count, setCount] = createSignal(0);
+    /// const message = `Count: ${count}`;
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const [count, setCount] = createSignal(0);
+    /// const double = createMemo(() => count() * 2);
+    /// if (double > 10) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// const [count, setCount] = createSignal(0);
+    /// <p>{count()}</p>;
+    /// ```
+    ///
+    /// Passing the accessor to a function or a component is allowed:
+    ///
+    /// ```jsx
+    /// const [count, setCount] = createSignal(0);
+    /// createEffect(on(count, (value) => console.log(value)));
+    /// <Counter count={count} />;
+    /// ```
+    ///
+    pub NoSolidUncalledSignals {
+        version: "next",
+        name: "noSolidUncalledSignals",
+        language: "jsx",
+        sources: &[RuleSource::EslintSolid("reactivity")],
+        source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Solid],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for NoSolidUncalledSignals {
+    type Query = Semantic<JsVariableDeclarator>;
+    type State = JsIdentifierExpression;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let Some(binding) = accessor_binding(ctx.query()) else {
+            return Vec::new();
+        };
+        binding
+            .all_reads(model)
+            .filter_map(|reference| {
+                let expression = JsIdentifierExpression::cast(reference.syntax().parent()?)?;
+                is_value_usage(expression.syntax()).then_some(expression)
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, expression: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                expression.range(),
+                markup! {
+                    "This signal is used without being called."
+                },
+            )
+            .note(markup! {
+                "A signal is a function that returns its value. Call the signal to read the value."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, expression: &Self::State) -> Option<JsRuleAction> {
+        let call = make::js_call_expression(
+            AnyJsExpression::JsIdentifierExpression(expression.clone().trim_trivia()?),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list([], []),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            AnyJsExpression::JsIdentifierExpression(expression.clone()),
+            AnyJsExpression::JsCallExpression(call),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Call the signal." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if the value of `expression` is used, rather than the accessor itself.
+fn is_value_usage(expression: &JsSyntaxNode) -> bool {
+    let mut current = expression.clone();
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION => current = parent,
+            JsSyntaxKind::JSX_EXPRESSION_CHILD
+            | JsSyntaxKind::JS_TEMPLATE_ELEMENT
+            | JsSyntaxKind::JS_BINARY_EXPRESSION
+            | JsSyntaxKind::JS_LOGICAL_EXPRESSION
+            | JsSyntaxKind::JS_UNARY_EXPRESSION
+            | JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION
+            | JsSyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION => {
+                // `count.toString()` reads a member of the value, but not `values[count]`.
+                return parent.kind() != JsSyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION
+                    || parent.first_child().as_ref() == Some(&current);
+            }
+            JsSyntaxKind::JS_CONDITIONAL_EXPRESSION => {
+                return JsConditionalExpression::unwrap_cast(parent)
+                    .test()
+                    .is_ok_and(|test| test.syntax() == &current);
+            }
+            JsSyntaxKind::JS_IF_STATEMENT => {
+                return JsIfStatement::unwrap_cast(parent)
+                    .test()
+                    .is_ok_and(|test| test.syntax() == &current);
+            }
+            JsSyntaxKind::JSX_EXPRESSION_ATTRIBUTE_VALUE => {
+                // Components may expect accessors, but DOM elements expect values.
+                return parent
+                    .ancestors()
+                    .find_map(AnyJsxElement::cast)
+                    .and_then(|element| element.name_value_token())
+                    .is_some_and(|name| {
+                        name.text_trimmed()
+                            .chars()
+                            .next()
+                            .is_some_and(char::is_lowercase)
+                    });
+            }
+            _ => return false,
+        }
+    }
+    false
+}
@@ -0,0 +1,183 @@
+use crate::services::semantic::Semantic;
+use crate::solid::{accessor_binding, is_solid_component, is_untracked_in, props_parameter};
+use biome_analyze::{
+    context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_semantic::ReferencesExtensions;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsBindingPattern, AnyJsExpression, AnyJsFunction, AnyJsMemberExpression,
+    JsCallExpression, JsIdentifierExpression, JsSyntaxNode, JsVariableDeclarator, TextRange,
+};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow reading reactive values outside a tracking scope in Solid components.
+    ///
+    /// The body of a Solid component runs only once.
+    /// Reading a prop or a signal directly in the body of the component reads its value once,
+    /// and the component doesn't update when the value changes.
+    /// Read reactive values in JSX, or in functions such as the ones passed to `createEffect` or `createMemo`.
+    ///
+    /// Props whose name starts with `initial` or `default` are intended to be read once,
+    /// and are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// function Greeting(props) {
+    ///     const name = props.name;
+    ///     return <p>Hello {name}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// function Counter() {
+    ///     const [count, setCount] = createSignal(0);
+    ///     const double = count() * 2;
+    ///     return <p>{double}</p>;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// function Greeting(props) {
+    ///     const name = () => props.name;
+    ///     return <p>Hello {name()}</p>;
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// function Counter(props) {
+    ///     const [count, setCount] = createSignal(props.initialCount);
+    ///     createEffect(() => console.log(count()));
+    ///     return <p>{count() * 2}</p>;
+    /// }
+    /// ```
+    ///
+    pub NoSolidUntrackedReads {
+        version: "next",
+        name: "noSolidUntrackedReads",
+        language: "jsx",
+        sources: &[RuleSource::EslintSolid("reactivity")],
+        source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Solid],
+        recommended: false,
+    }
+}
+
+pub enum ReactiveValueKind {
+    Prop,
+    Signal,
+}
+
+pub struct UntrackedRead {
+    kind: ReactiveValueKind,
+    range: TextRange,
+}
+
+impl Rule for NoSolidUntrackedReads {
+    type Query = Semantic<AnyJsFunction>;
+    type State = UntrackedRead;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let function = ctx.query();
+        let model = ctx.model();
+        if !is_solid_component(function) {
+            return Vec::new();
+        }
+        let mut reads = Vec::new();
+        if let Some(AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(props))) =
+            props_parameter(function)
+        {
+            for reference in props.all_reads(model) {
+                let Some(member) = member_read(reference.syntax()) else {
+                    continue;
+                };
+                let is_initial_value = member.member_name().is_some_and(|name| {
+                    let name = name.text();
+                    name.starts_with("initial") || name.starts_with("default")
+                });
+                if !is_initial_value && is_untracked_in(member.syntax(), function.syntax()) {
+                    reads.push(UntrackedRead {
+                        kind: ReactiveValueKind::Prop,
+                        range: member.range(),
+                    });
+                }
+            }
+        }
+        let declarators = function
+            .syntax()
+            .descendants()
+            .filter_map(JsVariableDeclarator::cast)
+            .filter(|declarator| is_in_function(declarator.syntax(), function.syntax()));
+        for declarator in declarators {
+            let Some(accessor) = accessor_binding(&declarator) else {
+                continue;
+            };
+            for reference in accessor.all_reads(model) {
+                let Some(call) = accessor_call(reference.syntax()) else {
+                    continue;
+                };
+                if is_untracked_in(call.syntax(), function.syntax()) {
+                    reads.push(UntrackedRead {
+                        kind: ReactiveValueKind::Signal,
+                        range: call.range(),
+                    });
+                }
+            }
+        }
+        reads
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let message = match state.kind {
+            ReactiveValueKind::Prop => markup! {
+                "This prop is read outside a tracking scope."
+            },
+            ReactiveValueKind::Signal => markup! {
+                "This signal is read outside a tracking scope."
+            },
+        };
+        Some(
+            RuleDiagnostic::new(rule_category!(), state.range, message)
+                .note(markup! {
+                    "The body of a component runs once: the value is read once and doesn't update when it changes."
+                })
+                .note(markup! {
+                    "Read the value in JSX, or wrap the read in a function."
+                }),
+        )
+    }
+}
+
+/// Returns the member expression that reads a member of the props referenced by `reference`.
+fn member_read(reference: &JsSyntaxNode) -> Option<AnyJsMemberExpression> {
+    let expression = JsIdentifierExpression::cast(reference.parent()?)?;
+    let member = AnyJsMemberExpression::cast(expression.syntax().parent()?)?;
+    (member.object().ok()?.syntax() == expression.syntax()).then_some(member)
+}
+
+/// Returns the call of the accessor referenced by `reference`.
+fn accessor_call(reference: &JsSyntaxNode) -> Option<JsCallExpression> {
+    let expression = JsIdentifierExpression::cast(reference.parent()?)?;
+    let call = JsCallExpression::cast(expression.syntax().parent()?)?;
+    let is_callee = matches!(
+        call.callee().ok()?,
+        AnyJsExpression::JsIdentifierExpression(callee) if callee == expression
+    );
+    is_callee.then_some(call)
+}
+
+/// Returns `true` if the closest function that encloses `node` is `function`.
+fn is_in_function(node: &JsSyntaxNode, function: &JsSyntaxNode) -> bool {
+    node.ancestors()
+        .find(|ancestor| AnyJsFunction::can_cast(ancestor.kind()))
+        .is_some_and(|ancestor| &ancestor == function)
+}
@@ -0,0 +1,104 @@
+use crate::utils::test_framework::{callback_test_call, TestCallKind};
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsExpression, AnyJsFunction, JsCallExpression};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow using `expect` outside of tests.
+    ///
+    /// An assertion outside of a test doesn't belong to any test:
+    /// its failure isn't reported as the failure of a test, and may not be reported at all.
+    ///
+    /// An `expect` in a function that isn't a test callback is allowed,
+    /// because the function may be a helper called by tests.
+    /// Calls to members of `expect`, such as `expect.hasAssertions()`, are also allowed.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// describe("a test suite", () => {
+    ///     expect(1).toBe(1);
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// beforeEach(() => {
+    ///     expect(setup()).toBeTruthy();
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// describe("a test suite", () => {
+    ///     it("works", () => {
+    ///         expect(1).toBe(1);
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// ```js
+    /// function expectValid(value) {
+    ///     expect(validate(value)).toBe(true);
+    /// }
+    /// ```
+    ///
+    pub NoStandaloneExpect {
+        version: "next",
+        name: "noStandaloneExpect",
+        language: "js",
+        sources: &[RuleSource::EslintJest("no-standalone-expect")],
+        domains: &[RuleDomain::Test],
+        recommended: false,
+    }
+}
+
+impl Rule for NoStandaloneExpect {
+    type Query = Ast<JsCallExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let AnyJsExpression::JsIdentifierExpression(callee) = call.callee().ok()? else {
+            return None;
+        };
+        if !callee.name().ok()?.has_name("expect") {
+            return None;
+        }
+        let Some(function) = call
+            .syntax()
+            .ancestors()
+            .find(|ancestor| AnyJsFunction::can_cast(ancestor.kind()))
+        else {
+            // `expect` at the top level of the module
+            return Some(());
+        };
+        match callback_test_call(&function) {
+            Some((_, TestCallKind::Suite | TestCallKind::Hook)) => Some(()),
+            // In a test, or in a helper function
+            Some((_, TestCallKind::Case)) | None => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This "<Emphasis>"expect"</Emphasis>" is used outside of a test."
+                },
+            )
+            .note(markup! {
+                "An assertion outside of a test doesn't belong to any test, and its failure may not be reported. Move the assertion into a test."
+            }),
+        )
+    }
+}
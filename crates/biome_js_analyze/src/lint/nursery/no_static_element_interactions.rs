@@ -0,0 +1,253 @@
+use crate::{services::aria::Aria, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    jsx_ext::AnyJsxElement, AnyJsArrowFunctionParameters, AnyJsExpression, AnyJsFunctionBody,
+    AnyJsLiteralExpression, AnyJsxAttribute, AnyJsxAttributeName, AnyJsxAttributeValue,
+    JsxAttribute, JsxAttributeList, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind};
+use rustc_hash::FxHashMap;
+
+declare_rule! {
+    /// Disallow the mouse and keyboard event handlers on the static elements without a role.
+    ///
+    /// The static elements, such as `<div>` and `<span>`, don't have any semantics.
+    /// When one of them handles the clicks or the key presses,
+    /// the assistive technologies don't know that the element is interactive.
+    ///
+    /// Use an interactive element such as `<button>` instead,
+    /// or give the element a `role` that describes its behavior.
+    /// The elements hidden with `aria-hidden` are ignored.
+    ///
+    /// The code action adds `role="button"` and `tabIndex={0}` to the element,
+    /// and an empty `onKeyDown` handler when the element doesn't have a keyboard handler,
+    /// so that the element can be implemented as a button.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div onClick={() => {}} />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <span onKeyDown={handleKeyDown}>Open</span>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <a onClick={handleClick}>Open</a>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <>
+    ///     <button onClick={() => {}}>Open</button>
+    ///     <div role="button" tabIndex={0} onClick={() => {}} onKeyDown={() => {}} />
+    ///     <a href="/" onClick={handleClick}>Open</a>
+    ///     <div onClick={() => {}} aria-hidden="true" />
+    ///     <MyComponent onClick={() => {}} />
+    /// </>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 4.1.2](https://www.w3.org/WAI/WCAG21/Understanding/name-role-value)
+    ///
+    pub NoStaticElementInteractions {
+        version: "next",
+        name: "noStaticElementInteractions",
+        language: "jsx",
+        sources: &[RuleSource::EslintJsxA11y("no-static-element-interactions")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// The event handlers that make an element interactive.
+const INTERACTIVE_HANDLERS: [&str; 6] = [
+    "onClick",
+    "onMouseDown",
+    "onMouseUp",
+    "onKeyPress",
+    "onKeyDown",
+    "onKeyUp",
+];
+
+const KEYBOARD_HANDLERS: [&str; 3] = ["onKeyPress", "onKeyDown", "onKeyUp"];
+
+impl Rule for NoStaticElementInteractions {
+    type Query = Aria<AnyJsxElement>;
+    type State = JsxAttribute;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let element_name = node.name().ok()?.as_jsx_name()?.value_token().ok()?;
+        if node.has_spread_prop()
+            || node.find_attribute_by_name("role").is_some()
+            || node.has_truthy_attribute("aria-hidden")
+        {
+            return None;
+        }
+        let aria_roles = ctx.aria_roles();
+        let attributes = extract_defined_attributes(&node.attributes());
+        let role = aria_roles.get_implicit_role(element_name.text_trimmed(), &attributes)?;
+        let generic_role = aria_roles.get_role("generic")?;
+        if role.type_name() != generic_role.type_name() {
+            return None;
+        }
+        INTERACTIVE_HANDLERS.iter().find_map(|handler| {
+            node.find_attribute_by_name(handler)
+                .filter(|attribute| !attribute.is_value_null_or_undefined())
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let handler = state.name_value_token()?;
+        let handler = handler.text_trimmed();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This static element handles the event "<Emphasis>{handler}</Emphasis>" but doesn't have a role."
+                },
+            )
+            .detail(state.range(), markup! {
+                "The event handler is declared here."
+            })
+            .note(markup! {
+                "The assistive technologies don't know that the element is interactive."
+            })
+            .note(markup! {
+                "Use an interactive element such as "<Emphasis>"<button>"</Emphasis>" instead, or add a "<Emphasis>"role"</Emphasis>" to the element."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let old_attribute_list = node.attributes();
+        let mut new_attribute_list: Vec<_> = old_attribute_list.iter().collect();
+        // The trailing trivia of the last attribute, such as the space before `/>`,
+        // is moved after the new attributes
+        let last_attribute = new_attribute_list.pop()?;
+        let trailing_trivia = last_attribute.syntax().last_trailing_trivia()?;
+        new_attribute_list.push(last_attribute.trim_trailing_trivia()?);
+        new_attribute_list.push(AnyJsxAttribute::JsxAttribute(make_attribute(
+            "role",
+            AnyJsxAttributeValue::JsxString(make::jsx_string(make::jsx_string_literal("button"))),
+        )));
+        if node.find_attribute_by_name("tabIndex").is_none() {
+            let zero = AnyJsExpression::AnyJsLiteralExpression(
+                AnyJsLiteralExpression::JsNumberLiteralExpression(
+                    make::js_number_literal_expression(make::js_number_literal(0)),
+                ),
+            );
+            new_attribute_list.push(AnyJsxAttribute::JsxAttribute(make_attribute(
+                "tabIndex",
+                make_expression_value(zero),
+            )));
+        }
+        let has_keyboard_handler = KEYBOARD_HANDLERS
+            .iter()
+            .any(|handler| node.find_attribute_by_name(handler).is_some());
+        if !has_keyboard_handler {
+            let handler = make::js_arrow_function_expression(
+                AnyJsArrowFunctionParameters::JsParameters(make::js_parameters(
+                    make::token(T!['(']),
+                    make::js_parameter_list([], []),
+                    make::token(T![')']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                )),
+                make::token(T![=>]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                AnyJsFunctionBody::JsFunctionBody(make::js_function_body(
+                    make::token(T!['{']),
+                    make::js_directive_list([]),
+                    make::js_statement_list([]),
+                    make::token(T!['}']),
+                )),
+            )
+            .build();
+            new_attribute_list.push(AnyJsxAttribute::JsxAttribute(make_attribute(
+                "onKeyDown",
+                make_expression_value(AnyJsExpression::JsArrowFunctionExpression(handler)),
+            )));
+        }
+        let last_attribute = new_attribute_list
+            .pop()?
+            .with_trailing_trivia_pieces(trailing_trivia.pieces())?;
+        new_attribute_list.push(last_attribute);
+        mutation.replace_node_discard_trivia(
+            old_attribute_list,
+            make::jsx_attribute_list(new_attribute_list),
+        );
+        let message = if has_keyboard_handler {
+            markup! { "Add the attribute "<Emphasis>"role=\"button\""</Emphasis>"." }.to_owned()
+        } else {
+            markup! {
+                "Add the attribute "<Emphasis>"role=\"button\""</Emphasis>" and a keyboard handler."
+            }
+            .to_owned()
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().to_applicability(),
+            message,
+            mutation,
+        ))
+    }
+}
+
+/// Returns the names and the values of the attributes.
+///
+/// Unlike [crate::services::aria::AriaServices::extract_attributes],
+/// an attribute with a dynamic value is kept without any value,
+/// because the event handlers are always dynamic.
+fn extract_defined_attributes(attribute_list: &JsxAttributeList) -> FxHashMap<String, Vec<String>> {
+    let mut defined_attributes: FxHashMap<String, Vec<String>> = FxHashMap::default();
+    for attribute in attribute_list {
+        let AnyJsxAttribute::JsxAttribute(attribute) = attribute else {
+            continue;
+        };
+        let Some(name) = attribute.name_value_token() else {
+            continue;
+        };
+        let values = match attribute.initializer() {
+            Some(_) => attribute.as_static_value().map_or_else(Vec::new, |value| {
+                value.text().split(' ').map(ToString::to_string).collect()
+            }),
+            None => vec!["true".to_string()],
+        };
+        defined_attributes
+            .entry(name.text_trimmed().to_string())
+            .or_insert(values);
+    }
+    defined_attributes
+}
+
+fn make_attribute(name: &str, value: AnyJsxAttributeValue) -> JsxAttribute {
+    make::jsx_attribute(AnyJsxAttributeName::JsxName(make::jsx_name(
+        make::jsx_ident(name).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+    )))
+    .with_initializer(make::jsx_attribute_initializer_clause(
+        make::token(T![=]),
+        value,
+    ))
+    .build()
+}
+
+fn make_expression_value(expression: AnyJsExpression) -> AnyJsxAttributeValue {
+    AnyJsxAttributeValue::JsxExpressionAttributeValue(make::jsx_expression_attribute_value(
+        make::token(T!['{']),
+        expression,
+        make::token(T!['}']),
+    ))
+}
@@ -0,0 +1,88 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Prevent the usage of synchronous scripts.
+    ///
+    /// A synchronous script blocks the parsing of the page until it's downloaded and executed.
+    /// This can impact the performance of the page.
+    /// Add the `async` or the `defer` attribute to the script,
+    /// or use the `<Script />` component from `next/script`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <script src="https://third-party-script.js" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <script src="https://third-party-script.js" async />
+    /// ```
+    ///
+    /// ```jsx
+    /// <script src="https://third-party-script.js" defer />
+    /// ```
+    ///
+    /// ```jsx
+    /// import Script from "next/script";
+    ///
+    /// <Script src="https://third-party-script.js" />
+    /// ```
+    ///
+    pub NoSyncScripts {
+        version: "next",
+        name: "noSyncScripts",
+        language: "jsx",
+        sources: &[RuleSource::EslintNext("no-sync-scripts")],
+        domains: &[RuleDomain::Next],
+        recommended: false,
+    }
+}
+
+impl Rule for NoSyncScripts {
+    type Query = Ast<AnyJsxElement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let name = node.name().ok()?;
+        if name.as_jsx_name()?.value_token().ok()?.text_trimmed() != "script" {
+            return None;
+        }
+        // Spread attributes may include `async` or `defer`.
+        if node.has_spread_prop() || node.find_attribute_by_name("src").is_none() {
+            return None;
+        }
+        if node.find_attribute_by_name("async").is_some()
+            || node.find_attribute_by_name("defer").is_some()
+        {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Synchronous scripts should not be used."
+                },
+            )
+            .note(markup! {
+                "Synchronous scripts block the rendering of the page. Add the "<Emphasis>"async"</Emphasis>" or the "<Emphasis>"defer"</Emphasis>" attribute, or use "<Emphasis>"<Script />"</Emphasis>" from "<Emphasis>"next/script"</Emphasis>"."
+            }),
+        )
+    }
+}
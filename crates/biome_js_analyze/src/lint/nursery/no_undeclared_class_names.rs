@@ -0,0 +1,103 @@
+use crate::project::{attribute_words, CLASS_ATTRIBUTES};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_js_syntax::JsxAttribute;
+use biome_project::StyleIndex;
+use biome_rowan::TextRange;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow class names that aren't declared by the stylesheets of the project.
+    ///
+    /// A class name without a matching selector has no effect: it's often a typo,
+    /// or the leftover of a selector that was renamed or removed.
+    ///
+    /// The rule compares the static values of the JSX attributes `className` and `class`
+    /// with the class selectors of the CSS files of the project.
+    /// The rule only reports the class names when the project contains at least one stylesheet.
+    /// The class names provided by a CSS framework that generates its stylesheet at build time,
+    /// such as Tailwind CSS, aren't declared by the stylesheets of the project, so this rule
+    /// isn't suited to the projects that use such a framework.
+    ///
+    /// ## Examples
+    ///
+    /// In the following examples, the project contains the CSS file:
+    ///
+    /// ```css,ignore
+    /// .button {}
+    /// .button.primary {}
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,ignore
+    /// <button className="button secondary" />
+    /// ```
+    ///
+    /// ```jsx,ignore
+    /// <button className={clsx("buton", { primary: isPrimary })} />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx,ignore
+    /// <button className={isPrimary ? "button primary" : "button"} />
+    /// ```
+    ///
+    /// ```jsx,ignore
+    /// <button className={`button ${variant}`} />
+    /// ```
+    ///
+    pub NoUndeclaredClassNames {
+        version: "next",
+        name: "noUndeclaredClassNames",
+        language: "jsx",
+        recommended: false,
+    }
+}
+
+impl Rule for NoUndeclaredClassNames {
+    type Query = Ast<JsxAttribute>;
+    /// A class name without a selector, and its range.
+    type State = (String, TextRange);
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(style_index) = ctx.get_service::<Arc<StyleIndex>>() else {
+            return Vec::new();
+        };
+        if !style_index.has_stylesheets() {
+            return Vec::new();
+        }
+        let attribute = ctx.query();
+        let is_class_attribute = attribute
+            .name_value_token()
+            .is_some_and(|name| CLASS_ATTRIBUTES.contains(&name.text_trimmed()));
+        if !is_class_attribute {
+            return Vec::new();
+        }
+        attribute_words(attribute)
+            .into_iter()
+            .filter(|(class_name, _)| !style_index.is_class_declared(class_name))
+            .collect()
+    }
+
+    fn diagnostic(
+        _: &RuleContext<Self>,
+        (class_name, range): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The class "<Emphasis>{class_name}</Emphasis>" isn't declared by the stylesheets of the project."
+                },
+            )
+            .note(markup! {
+                "This class name has no effect. Fix the name, or declare a selector for it."
+            }),
+        )
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{services::semantic::Semantic, utils::type_info::TypeFacts, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsStatement, JsConditionalExpression, JsDoWhileStatement, JsFileSource,
+    JsForStatement, JsIfStatement, JsLogicalExpression, JsLogicalOperator, JsSyntaxKind,
+    JsWhileStatement,
+};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow conditions that are always truthy, always falsy, or never nullish according to their types.
+    ///
+    /// A condition that always evaluates to the same value is often the sign of a bug,
+    /// or of a check that became useless after a refactoring.
+    /// The rule uses the type annotations available in the file
+    /// to determine the possible values of a condition:
+    /// annotations of variables and parameters, type assertions, and literals assigned to constants.
+    ///
+    /// The rule checks the conditions of `if`, `while`, `do-while`, and `for` statements,
+    /// the test of conditional expressions, the left operand of `&&` and `||`,
+    /// and the left operand of `??` which should be possibly `null` or `undefined`.
+    ///
+    /// Conditions that are literals, such as `while (true)`, are ignored.
+    /// They are reported by [noConstantCondition](https://biomejs.dev/linter/rules/no-constant-condition).
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// function head(items: string[]) {
+    ///     if (items) {
+    ///         return items[0];
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function greet(name: string) {
+    ///     return name ?? "world";
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// function check(value: null) {
+    ///     return value && value.length;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function head(items: string[] | undefined) {
+    ///     if (items) {
+    ///         return items[0];
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// function greet(name?: string) {
+    ///     return name ?? "world";
+    /// }
+    /// ```
+    ///
+    pub NoUnnecessaryCondition {
+        version: "next",
+        name: "noUnnecessaryCondition",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("no-unnecessary-condition")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsConditional =
+        JsIfStatement
+        | JsWhileStatement
+        | JsDoWhileStatement
+        | JsForStatement
+        | JsConditionalExpression
+        | JsLogicalExpression
+}
+
+impl AnyJsConditional {
+    /// Returns the expression that is checked by this node.
+    fn condition(&self) -> Option<AnyJsExpression> {
+        match self {
+            Self::JsIfStatement(node) => node.test().ok(),
+            Self::JsWhileStatement(node) => node.test().ok(),
+            Self::JsDoWhileStatement(node) => node.test().ok(),
+            Self::JsForStatement(node) => node.test(),
+            Self::JsConditionalExpression(node) => node.test().ok(),
+            Self::JsLogicalExpression(node) => node.left().ok(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum UnnecessaryCondition {
+    AlwaysTruthy,
+    AlwaysFalsy,
+    NeverNullish,
+}
+
+impl Rule for NoUnnecessaryCondition {
+    type Query = Semantic<AnyJsConditional>;
+    type State = UnnecessaryCondition;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        let node = ctx.query();
+        let condition = node.condition()?.omit_parentheses();
+        if is_literal_like(&condition) {
+            return None;
+        }
+        let facts = TypeFacts::from_expression(ctx.model(), &condition);
+        if let AnyJsConditional::JsLogicalExpression(logical) = node {
+            if logical.operator().ok()? == JsLogicalOperator::NullishCoalescing {
+                return facts
+                    .is_never_nullish()
+                    .then_some(UnnecessaryCondition::NeverNullish);
+            }
+        }
+        if facts.is_always_truthy() {
+            Some(UnnecessaryCondition::AlwaysTruthy)
+        } else if facts.is_always_falsy() {
+            Some(UnnecessaryCondition::AlwaysFalsy)
+        } else {
+            None
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let condition = ctx.query().condition()?;
+        let diagnostic = match state {
+            UnnecessaryCondition::AlwaysTruthy => RuleDiagnostic::new(
+                rule_category!(),
+                condition.range(),
+                markup! {
+                    "This condition is always truthy."
+                },
+            ),
+            UnnecessaryCondition::AlwaysFalsy => RuleDiagnostic::new(
+                rule_category!(),
+                condition.range(),
+                markup! {
+                    "This condition is always falsy."
+                },
+            ),
+            UnnecessaryCondition::NeverNullish => RuleDiagnostic::new(
+                rule_category!(),
+                condition.range(),
+                markup! {
+                    "This expression is never "<Emphasis>"null"</Emphasis>" or "<Emphasis>"undefined"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The right operand of "<Emphasis>"??"</Emphasis>" is never evaluated."
+            }),
+        };
+        Some(diagnostic.note(markup! {
+            "The type of the expression doesn't allow any other value. Remove the condition or fix the type."
+        }))
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let is_truthy = *state != UnnecessaryCondition::AlwaysFalsy;
+        match node {
+            AnyJsConditional::JsIfStatement(statement) => {
+                let replacement = if is_truthy {
+                    Some(statement.consequent().ok()?)
+                } else {
+                    statement
+                        .else_clause()
+                        .and_then(|else_clause| else_clause.alternate().ok())
+                };
+                match replacement {
+                    Some(replacement) => {
+                        mutation.replace_node(AnyJsStatement::from(statement.clone()), replacement);
+                    }
+                    None => {
+                        let parent_kind = statement.syntax().parent()?.kind();
+                        if !matches!(
+                            parent_kind,
+                            JsSyntaxKind::JS_STATEMENT_LIST | JsSyntaxKind::JS_MODULE_ITEM_LIST
+                        ) {
+                            return None;
+                        }
+                        mutation.remove_node(statement.clone());
+                    }
+                }
+            }
+            AnyJsConditional::JsConditionalExpression(expression) => {
+                let replacement = if is_truthy {
+                    expression.consequent().ok()?
+                } else {
+                    expression.alternate().ok()?
+                };
+                mutation.replace_node(AnyJsExpression::from(expression.clone()), replacement);
+            }
+            AnyJsConditional::JsLogicalExpression(expression) => {
+                let keep_left = match expression.operator().ok()? {
+                    JsLogicalOperator::NullishCoalescing => true,
+                    JsLogicalOperator::LogicalOr => is_truthy,
+                    JsLogicalOperator::LogicalAnd => !is_truthy,
+                };
+                let replacement = if keep_left {
+                    expression.left().ok()?
+                } else {
+                    expression.right().ok()?
+                };
+                mutation.replace_node(AnyJsExpression::from(expression.clone()), replacement);
+            }
+            // Removing a loop condition changes the loop into an infinite loop or dead code.
+            AnyJsConditional::JsWhileStatement(_)
+            | AnyJsConditional::JsDoWhileStatement(_)
+            | AnyJsConditional::JsForStatement(_) => return None,
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Remove the unnecessary condition." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `expression` is a constant that doesn't depend on types.
+fn is_literal_like(expression: &AnyJsExpression) -> bool {
+    matches!(
+        expression,
+        AnyJsExpression::AnyJsLiteralExpression(_)
+            | AnyJsExpression::JsTemplateExpression(_)
+            | AnyJsExpression::JsObjectExpression(_)
+            | AnyJsExpression::JsArrayExpression(_)
+            | AnyJsExpression::JsFunctionExpression(_)
+            | AnyJsExpression::JsArrowFunctionExpression(_)
+            | AnyJsExpression::JsClassExpression(_)
+    )
+}
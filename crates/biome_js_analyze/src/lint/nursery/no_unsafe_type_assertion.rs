@@ -0,0 +1,378 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsExpression, AnyJsObjectMember, AnyTsName, AnyTsType,
+    AnyTsTypeMember, JsCallExpression, JsObjectExpression, JsReferenceIdentifier, JsSyntaxKind,
+    TsAsExpression, TsTypeAssertionExpression,
+};
+use biome_rowan::{declare_node_union, AstNode, SyntaxResult, TextRange, TokenText};
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Disallow type assertions that widen a value or cross unrelated types.
+    ///
+    /// A type assertion tells TypeScript to trust the author instead of checking the code.
+    /// Some assertions are harmless, others silently disable type checking:
+    ///
+    /// - asserting to `any` widens the value and turns off checking for every usage;
+    /// - a double assertion such as `x as unknown as T` lets any value pretend to be any type;
+    /// - asserting an object literal to an interface or an object type alias hides missing members.
+    ///
+    /// The rule relies on the type annotations found in the file.
+    /// Object literals are only checked against interfaces and type aliases declared in the same file,
+    /// and that are not extended from another type.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// const value = input as any;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const value = input as unknown as string;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// interface User {
+    ///     name: string;
+    ///     age: number;
+    /// }
+    /// const user = { name: "Jane" } as User;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// interface User {
+    ///     name: string;
+    ///     age?: number;
+    /// }
+    /// const user = { name: "Jane" } as User;
+    /// const point = { x: 0, y: 0 } as const;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "allowAsConst": true,
+    ///         "allowInTests": false
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### allowAsConst
+    ///
+    /// When set to `false`, `as const` assertions are also reported.
+    /// This is useful for code bases that forbid every kind of type assertion.
+    ///
+    /// Default: `true`
+    ///
+    /// ### allowInTests
+    ///
+    /// When set to `true`, assertions located inside test calls such as `it()`, `test()`, or `describe()` are ignored.
+    /// Tests often build partial fixtures that don't need to satisfy the whole type.
+    ///
+    /// Default: `false`
+    ///
+    pub NoUnsafeTypeAssertion {
+        version: "next",
+        name: "noUnsafeTypeAssertion",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("no-unsafe-type-assertion")],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyTsTypeAssertion = TsAsExpression | TsTypeAssertionExpression
+}
+
+impl AnyTsTypeAssertion {
+    fn expression(&self) -> SyntaxResult<AnyJsExpression> {
+        match self {
+            AnyTsTypeAssertion::TsAsExpression(expr) => expr.expression(),
+            AnyTsTypeAssertion::TsTypeAssertionExpression(expr) => expr.expression(),
+        }
+    }
+
+    fn ty(&self) -> SyntaxResult<AnyTsType> {
+        match self {
+            AnyTsTypeAssertion::TsAsExpression(expr) => expr.ty(),
+            AnyTsTypeAssertion::TsTypeAssertionExpression(expr) => expr.ty(),
+        }
+    }
+}
+
+/// Options for the rule `noUnsafeTypeAssertion`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NoUnsafeTypeAssertionOptions {
+    /// If `false`, `as const` assertions are reported.
+    #[serde(default = "enabled")]
+    pub allow_as_const: bool,
+
+    /// If `true`, assertions inside test calls are ignored.
+    #[serde(default)]
+    pub allow_in_tests: bool,
+}
+
+impl Default for NoUnsafeTypeAssertionOptions {
+    fn default() -> Self {
+        Self {
+            allow_as_const: true,
+            allow_in_tests: false,
+        }
+    }
+}
+
+const fn enabled() -> bool {
+    true
+}
+
+pub enum UnsafeTypeAssertion {
+    /// `x as any`
+    WidenToAny(TextRange),
+    /// `x as unknown as T` or `x as any as T`
+    DoubleAssertion {
+        range: TextRange,
+        intermediate_type: &'static str,
+    },
+    /// `{ a: 0 } as { a: number, b: number }`
+    MissingMembers {
+        range: TextRange,
+        type_name: TokenText,
+        missing: Box<[TokenText]>,
+    },
+    /// `x as const` when `allowAsConst` is disabled
+    ConstAssertion(TextRange),
+}
+
+impl Rule for NoUnsafeTypeAssertion {
+    type Query = Semantic<AnyTsTypeAssertion>;
+    type State = UnsafeTypeAssertion;
+    type Signals = Option<Self::State>;
+    type Options = NoUnsafeTypeAssertionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let assertion = ctx.query();
+        let options = ctx.options();
+        if options.allow_in_tests && is_in_test_call(assertion) {
+            return None;
+        }
+        let ty = assertion.ty().ok()?;
+        let expression = assertion.expression().ok()?.omit_parentheses();
+        let range = assertion.range();
+        if is_const_reference(&ty) {
+            return (!options.allow_as_const).then_some(UnsafeTypeAssertion::ConstAssertion(range));
+        }
+        if let Some(inner) = AnyTsTypeAssertion::cast_ref(expression.syntax()) {
+            match inner.ty().ok()? {
+                AnyTsType::TsAnyType(_) => {
+                    return Some(UnsafeTypeAssertion::DoubleAssertion {
+                        range,
+                        intermediate_type: "any",
+                    })
+                }
+                AnyTsType::TsUnknownType(_) => {
+                    return Some(UnsafeTypeAssertion::DoubleAssertion {
+                        range,
+                        intermediate_type: "unknown",
+                    })
+                }
+                _ => {}
+            }
+        }
+        match ty {
+            AnyTsType::TsAnyType(_) => {
+                // `x as any as T` is reported once, as a double assertion.
+                let is_intermediate = assertion
+                    .syntax()
+                    .ancestors()
+                    .skip(1)
+                    .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+                    .is_some_and(|ancestor| AnyTsTypeAssertion::can_cast(ancestor.kind()));
+                (!is_intermediate).then_some(UnsafeTypeAssertion::WidenToAny(range))
+            }
+            AnyTsType::TsReferenceType(reference_type) => {
+                let object = JsObjectExpression::cast(expression.into_syntax())?;
+                if reference_type.type_arguments().is_some() {
+                    return None;
+                }
+                let AnyTsName::JsReferenceIdentifier(name) = reference_type.name().ok()? else {
+                    return None;
+                };
+                let type_name = name.value_token().ok()?;
+                let missing = missing_members(ctx.model(), &name, &object)?;
+                (!missing.is_empty()).then(|| UnsafeTypeAssertion::MissingMembers {
+                    range,
+                    type_name: type_name.token_text_trimmed(),
+                    missing: missing.into_boxed_slice(),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            UnsafeTypeAssertion::WidenToAny(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This assertion widens the value to "<Emphasis>"any"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Every usage of the resulting value escapes type checking."
+            }),
+            UnsafeTypeAssertion::DoubleAssertion {
+                range,
+                intermediate_type,
+            } => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This double assertion goes through "<Emphasis>{intermediate_type}</Emphasis>" and can convert a value to an unrelated type."
+                },
+            )
+            .note(markup! {
+                "Ensure that the value has the asserted type, or narrow it with a type guard."
+            }),
+            UnsafeTypeAssertion::MissingMembers {
+                range,
+                type_name,
+                missing,
+            } => {
+                let type_name = type_name.text();
+                let missing = missing
+                    .iter()
+                    .map(|name| name.text())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    range,
+                    markup! {
+                        "This object is asserted to "<Emphasis>{type_name}</Emphasis>", but it lacks some of its required members."
+                    },
+                )
+                .note(markup! {
+                    "The following members are missing: "<Emphasis>{missing}</Emphasis>"."
+                })
+                .note(markup! {
+                    "Use a type annotation or the "<Emphasis>"satisfies"</Emphasis>" operator to let TypeScript check the object."
+                })
+            }
+            UnsafeTypeAssertion::ConstAssertion(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "Type assertions are not allowed, including "<Emphasis>"as const"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Use an explicit literal type annotation instead."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Returns `true` if `ty` is the `const` of an `as const` assertion.
+fn is_const_reference(ty: &AnyTsType) -> bool {
+    ty.as_ts_reference_type()
+        .and_then(|reference| reference.name().ok())
+        .and_then(|name| name.as_js_reference_identifier()?.value_token().ok())
+        .is_some_and(|token| token.text_trimmed() == "const")
+}
+
+fn is_in_test_call(assertion: &AnyTsTypeAssertion) -> bool {
+    assertion
+        .syntax()
+        .ancestors()
+        .filter_map(JsCallExpression::cast)
+        .any(|call| call.is_test_call_expression().unwrap_or(false))
+}
+
+/// Returns the names of the required members of the type `name` that are not set in `object`.
+///
+/// Returns `None` if the members of the type or of the object cannot be statically determined.
+fn missing_members(
+    model: &SemanticModel,
+    name: &JsReferenceIdentifier,
+    object: &JsObjectExpression,
+) -> Option<Vec<TokenText>> {
+    let binding = model.binding(name)?;
+    let members = match binding.tree().declaration()? {
+        AnyJsBindingDeclaration::TsInterfaceDeclaration(decl) => {
+            if decl.extends_clause().is_some() || decl.type_parameters().is_some() {
+                return None;
+            }
+            decl.members()
+        }
+        AnyJsBindingDeclaration::TsTypeAliasDeclaration(decl) => {
+            if decl.type_parameters().is_some() {
+                return None;
+            }
+            let AnyTsType::TsObjectType(object_type) = decl.ty().ok()? else {
+                return None;
+            };
+            object_type.members()
+        }
+        _ => return None,
+    };
+    let mut required = Vec::new();
+    for member in members {
+        match member {
+            AnyTsTypeMember::TsPropertySignatureTypeMember(member) => {
+                if member.optional_token().is_none() {
+                    required.push(member.name().ok()?.name()?);
+                }
+            }
+            AnyTsTypeMember::TsMethodSignatureTypeMember(member) => {
+                if member.optional_token().is_none() {
+                    required.push(member.name().ok()?.name()?);
+                }
+            }
+            AnyTsTypeMember::TsGetterSignatureTypeMember(member) => {
+                required.push(member.name().ok()?.name()?);
+            }
+            AnyTsTypeMember::TsSetterSignatureTypeMember(_) => {}
+            // Index and call signatures don't require any member.
+            AnyTsTypeMember::TsIndexSignatureTypeMember(_)
+            | AnyTsTypeMember::TsCallSignatureTypeMember(_)
+            | AnyTsTypeMember::TsConstructSignatureTypeMember(_) => {}
+            AnyTsTypeMember::JsBogusMember(_) => return None,
+        }
+    }
+    let mut provided = Vec::new();
+    for member in object.members() {
+        let name = match member.ok()? {
+            AnyJsObjectMember::JsPropertyObjectMember(member) => member.name().ok()?.name()?,
+            AnyJsObjectMember::JsMethodObjectMember(member) => member.name().ok()?.name()?,
+            AnyJsObjectMember::JsGetterObjectMember(member) => member.name().ok()?.name()?,
+            AnyJsObjectMember::JsSetterObjectMember(member) => member.name().ok()?.name()?,
+            AnyJsObjectMember::JsShorthandPropertyObjectMember(member) => {
+                member.name().ok()?.value_token().ok()?.token_text_trimmed()
+            }
+            // Spread members can bring any member.
+            AnyJsObjectMember::JsSpread(_) | AnyJsObjectMember::JsBogusMember(_) => return None,
+        };
+        provided.push(name);
+    }
+    required.retain(|name| {
+        !provided
+            .iter()
+            .any(|provided| provided.text() == name.text())
+    });
+    Some(required)
+}
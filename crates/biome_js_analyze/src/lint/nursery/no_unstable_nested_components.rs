@@ -0,0 +1,141 @@
+use crate::react::hooks::{function_or_class_name, is_react_component};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsBinding, AnyJsClass, AnyJsFunction, JsSyntaxKind, JsSyntaxNode};
+use biome_rowan::{declare_node_union, AstNode};
+
+declare_rule! {
+    /// Disallow defining components inside other components.
+    ///
+    /// A component defined in the body of another component is a new component on every render.
+    /// React compares components by identity:
+    /// it unmounts the previous instance of the nested component and mounts a new one,
+    /// losing its state and the state of its children, and recreating its DOM nodes.
+    /// This is slow and causes subtle bugs, such as inputs that lose focus while typing.
+    ///
+    /// Components are detected by their name, which must start with an uppercase letter,
+    /// and by the presence of JSX in their body.
+    /// Functions wrapped in `memo` or `forwardRef` are also considered.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// function Parent() {
+    ///     function Child() {
+    ///         return <div />;
+    ///     }
+    ///     return <Child />;
+    /// }
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// const Parent = () => {
+    ///     const Child = memo(() => <div />);
+    ///     return <Child />;
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// function Child() {
+    ///     return <div />;
+    /// }
+    ///
+    /// function Parent() {
+    ///     return <Child />;
+    /// }
+    /// ```
+    ///
+    /// ```jsx
+    /// function List({ items }) {
+    ///     const renderItem = (item) => <li>{item}</li>;
+    ///     return <ul>{items.map(renderItem)}</ul>;
+    /// }
+    /// ```
+    ///
+    pub NoUnstableNestedComponents {
+        version: "next",
+        name: "noUnstableNestedComponents",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("no-unstable-nested-components")],
+        recommended: false,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsComponentDefinition = AnyJsFunction | AnyJsClass
+}
+
+pub struct NestedComponent {
+    name: AnyJsBinding,
+    parent_name: AnyJsBinding,
+}
+
+impl Rule for NoUnstableNestedComponents {
+    type Query = Ast<AnyJsComponentDefinition>;
+    type State = NestedComponent;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query().syntax();
+        let name = component_name(node)?;
+        if let Some(class) = AnyJsClass::cast_ref(node) {
+            class.extends_clause()?;
+        }
+        if !contains_jsx(node) {
+            return None;
+        }
+        let parent_name = node
+            .ancestors()
+            .skip(1)
+            .filter(|ancestor| AnyJsComponentDefinition::can_cast(ancestor.kind()))
+            .find_map(|ancestor| component_name(&ancestor))?;
+        Some(NestedComponent { name, parent_name })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.text();
+        let parent_name = state.parent_name.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.name.range(),
+                markup! {
+                    "The component "<Emphasis>{name}</Emphasis>" is defined inside the component "<Emphasis>{parent_name}</Emphasis>"."
+                },
+            )
+            .detail(
+                state.parent_name.range(),
+                markup! {
+                    "A new "<Emphasis>{name}</Emphasis>" is created on every render of this component."
+                },
+            )
+            .note(markup! {
+                "React remounts the nested component on every render, which resets its state and is slow."
+            })
+            .note(markup! {
+                "Move the component outside of "<Emphasis>{parent_name}</Emphasis>", and pass the values it needs as props."
+            }),
+        )
+    }
+}
+
+/// Returns the name of `node` if it's a function or a class named like a component.
+fn component_name(node: &JsSyntaxNode) -> Option<AnyJsBinding> {
+    function_or_class_name(node).filter(|name| is_react_component(&name.text()))
+}
+
+fn contains_jsx(node: &JsSyntaxNode) -> bool {
+    node.descendants().any(|descendant| {
+        matches!(
+            descendant.kind(),
+            JsSyntaxKind::JSX_ELEMENT
+                | JsSyntaxKind::JSX_SELF_CLOSING_ELEMENT
+                | JsSyntaxKind::JSX_FRAGMENT
+        )
+    })
+}
@@ -0,0 +1,318 @@
+use crate::react::hooks::{function_or_class_name, is_react_component};
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_semantic::{ReferencesExtensions, SemanticModel};
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsArrowFunctionParameters, AnyJsBinding,
+    AnyJsBindingPattern, AnyJsExpression, AnyJsFormalParameter, AnyJsFunction,
+    AnyJsObjectBindingPatternMember, AnyJsParameter, AnyTsName, AnyTsType, AnyTsTypeMember,
+    JsComputedMemberExpression, JsObjectBindingPattern, JsStaticMemberExpression, JsSyntaxKind,
+    JsSyntaxNode, JsVariableDeclarator, TextRange, TsTypeMemberList,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList, TokenText};
+use rustc_hash::FxHashSet;
+
+declare_rule! {
+    /// Disallow props that are declared in the props type of a component but never used.
+    ///
+    /// Props that are declared but never read by the component are misleading:
+    /// callers pass values that have no effect.
+    /// They are often leftovers of a refactoring.
+    ///
+    /// The rule inspects function components whose props are annotated with
+    /// an interface, a type alias, or an object type declared in the same file.
+    /// Components typed with `FC<Props>` are also supported.
+    /// A prop is used when it is destructured from the props parameter,
+    /// or when it is read from the props object, e.g. `props.name`.
+    ///
+    /// When the props object is used in another way, for example spread into another component,
+    /// every prop is considered used.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```tsx,expect_diagnostic
+    /// interface Props {
+    ///     name: string;
+    ///     age: number;
+    /// }
+    ///
+    /// function User({ name }: Props) {
+    ///     return <div>{name}</div>;
+    /// }
+    /// ```
+    ///
+    /// ```tsx,expect_diagnostic
+    /// const User = (props: { name: string; age: number }) => {
+    ///     return <div>{props.name}</div>;
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```tsx
+    /// interface Props {
+    ///     name: string;
+    ///     age: number;
+    /// }
+    ///
+    /// function User({ name, age }: Props) {
+    ///     return <div>{name} ({age})</div>;
+    /// }
+    /// ```
+    ///
+    /// ```tsx
+    /// function Button(props: { label: string; onClick: () => void }) {
+    ///     return <button {...props} />;
+    /// }
+    /// ```
+    ///
+    pub NoUnusedComponentProps {
+        version: "next",
+        name: "noUnusedComponentProps",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("no-unused-prop-types")],
+        recommended: false,
+    }
+}
+
+pub struct UnusedProp {
+    name: TokenText,
+    range: TextRange,
+    component_range: TextRange,
+}
+
+impl Rule for NoUnusedComponentProps {
+    type Query = Semantic<AnyJsFunction>;
+    type State = UnusedProp;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let model = ctx.model();
+        let function = ctx.query();
+        let Some(component_name) = function_or_class_name(function.syntax())
+            .filter(|name| is_react_component(&name.text()))
+        else {
+            return Vec::new();
+        };
+        let Some((props, props_type)) = props_parameter(function) else {
+            return Vec::new();
+        };
+        let Some(props_type) =
+            props_type.or_else(|| component_props_type(&function.syntax().parent()?))
+        else {
+            return Vec::new();
+        };
+        let Some(members) = declared_members(model, &props_type) else {
+            return Vec::new();
+        };
+        let Some(used) = used_props(model, &props) else {
+            return Vec::new();
+        };
+        members
+            .into_iter()
+            .filter(|(name, _)| !used.contains(name.text()))
+            .map(|(name, range)| UnusedProp {
+                name,
+                range,
+                component_range: component_name.range(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The prop "<Emphasis>{name}</Emphasis>" is declared but never used."
+                },
+            )
+            .detail(
+                state.component_range,
+                markup! {
+                    "This component doesn't read "<Emphasis>{name}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Remove the prop from the props type, or use it in the component."
+            }),
+        )
+    }
+}
+
+/// Returns the binding of the props parameter of `function`, and its type annotation if any.
+fn props_parameter(function: &AnyJsFunction) -> Option<(AnyJsBindingPattern, Option<AnyTsType>)> {
+    match function.parameters().ok()? {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+            Some((AnyJsBindingPattern::AnyJsBinding(binding), None))
+        }
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => {
+            let mut items = parameters.items().iter();
+            let first = items.next()?.ok()?;
+            // Components receive a single argument.
+            if items.next().is_some() {
+                return None;
+            }
+            let AnyJsParameter::AnyJsFormalParameter(AnyJsFormalParameter::JsFormalParameter(
+                parameter,
+            )) = first
+            else {
+                return None;
+            };
+            let ty = parameter
+                .type_annotation()
+                .and_then(|annotation| annotation.ty().ok());
+            Some((parameter.binding().ok()?, ty))
+        }
+    }
+}
+
+/// Returns `Props` if `initializer` is the initializer of `const C: FC<Props> = ...`.
+fn component_props_type(initializer: &JsSyntaxNode) -> Option<AnyTsType> {
+    let declarator = JsVariableDeclarator::cast(initializer.parent()?)?;
+    let ty = declarator
+        .variable_annotation()?
+        .type_annotation()
+        .ok()??
+        .ty()
+        .ok()?;
+    let reference = ty.as_ts_reference_type()?;
+    let name = match reference.name().ok()? {
+        AnyTsName::JsReferenceIdentifier(name) => name.value_token().ok()?,
+        AnyTsName::TsQualifiedName(name) => name.right().ok()?.value_token().ok()?,
+    };
+    if !matches!(name.text_trimmed(), "FC" | "FunctionComponent") {
+        return None;
+    }
+    reference
+        .type_arguments()?
+        .ts_type_argument_list()
+        .iter()
+        .next()?
+        .ok()
+}
+
+/// Returns the names and ranges of the members of the props type `ty`.
+///
+/// Returns `None` if the members cannot be determined.
+fn declared_members(model: &SemanticModel, ty: &AnyTsType) -> Option<Vec<(TokenText, TextRange)>> {
+    let members = match ty {
+        AnyTsType::TsObjectType(ty) => ty.members(),
+        AnyTsType::TsReferenceType(reference) => {
+            if reference.type_arguments().is_some() {
+                return None;
+            }
+            let AnyTsName::JsReferenceIdentifier(name) = reference.name().ok()? else {
+                return None;
+            };
+            match model.binding(&name)?.tree().declaration()? {
+                AnyJsBindingDeclaration::TsInterfaceDeclaration(declaration) => {
+                    if declaration.type_parameters().is_some() {
+                        return None;
+                    }
+                    declaration.members()
+                }
+                AnyJsBindingDeclaration::TsTypeAliasDeclaration(declaration) => {
+                    if declaration.type_parameters().is_some() {
+                        return None;
+                    }
+                    declaration.ty().ok()?.as_ts_object_type()?.members()
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(member_names(&members))
+}
+
+fn member_names(members: &TsTypeMemberList) -> Vec<(TokenText, TextRange)> {
+    members
+        .iter()
+        .filter_map(|member| {
+            let name = match member {
+                AnyTsTypeMember::TsPropertySignatureTypeMember(member) => member.name().ok()?,
+                AnyTsTypeMember::TsMethodSignatureTypeMember(member) => member.name().ok()?,
+                _ => return None,
+            };
+            Some((name.name()?, name.range()))
+        })
+        .collect()
+}
+
+/// Returns the names of the props read from `props`.
+///
+/// Returns `None` if the props object escapes, such that any prop may be used.
+fn used_props(model: &SemanticModel, props: &AnyJsBindingPattern) -> Option<FxHashSet<String>> {
+    let mut used = FxHashSet::default();
+    match props {
+        AnyJsBindingPattern::JsObjectBindingPattern(pattern) => {
+            collect_destructured_props(pattern, &mut used)?;
+        }
+        AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) => {
+            for reference in binding.all_references(model) {
+                let expression = reference.syntax().parent()?;
+                let parent = expression.parent()?;
+                if let Some(member) = JsStaticMemberExpression::cast_ref(&parent) {
+                    used.insert(
+                        member
+                            .member()
+                            .ok()?
+                            .as_js_name()?
+                            .value_token()
+                            .ok()?
+                            .text_trimmed()
+                            .to_string(),
+                    );
+                } else if let Some(member) = JsComputedMemberExpression::cast_ref(&parent) {
+                    let AnyJsExpression::AnyJsLiteralExpression(literal) = member.member().ok()?
+                    else {
+                        return None;
+                    };
+                    let literal = literal.as_js_string_literal_expression()?;
+                    used.insert(literal.inner_string_text().ok()?.to_string());
+                } else if let Some(declarator) = parent
+                    .parent()
+                    .and_then(JsVariableDeclarator::cast)
+                    .filter(|_| parent.kind() == JsSyntaxKind::JS_INITIALIZER_CLAUSE)
+                {
+                    let AnyJsBindingPattern::JsObjectBindingPattern(pattern) =
+                        declarator.id().ok()?
+                    else {
+                        return None;
+                    };
+                    collect_destructured_props(&pattern, &mut used)?;
+                } else {
+                    return None;
+                }
+            }
+        }
+        _ => return None,
+    }
+    Some(used)
+}
+
+fn collect_destructured_props(
+    pattern: &JsObjectBindingPattern,
+    used: &mut FxHashSet<String>,
+) -> Option<()> {
+    for property in pattern.properties() {
+        match property.ok()? {
+            AnyJsObjectBindingPatternMember::JsObjectBindingPatternProperty(property) => {
+                used.insert(property.member().ok()?.name()?.to_string());
+            }
+            AnyJsObjectBindingPatternMember::JsObjectBindingPatternShorthandProperty(property) => {
+                used.insert(property.identifier().ok()?.text());
+            }
+            // A rest element captures the remaining props.
+            _ => return None,
+        }
+    }
+    Some(())
+}
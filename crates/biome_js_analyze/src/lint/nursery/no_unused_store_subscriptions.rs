@@ -0,0 +1,138 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleDomain};
+use biome_console::markup;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsNamedImportSpecifier, JsCallExpression, JsIdentifierBinding, JsImport,
+    JsSyntaxKind, JsVariableDeclarator,
+};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow discarding the unsubscriber of a Svelte store subscription.
+    ///
+    /// `subscribe` returns a function that cancels the subscription.
+    /// A subscription whose unsubscriber is discarded is never cancelled:
+    /// the callback keeps running after the component is destroyed, and the component leaks.
+    /// Keep the unsubscriber and call it when the component is destroyed,
+    /// or use the `$store` syntax that manages the subscription automatically.
+    ///
+    /// The rule reports stores created with `writable`, `readable`, or `derived` from `svelte/store`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// import { writable } from "svelte/store";
+    ///
+    /// const count = writable(0);
+    /// count.subscribe((value) => console.log(value));
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { onDestroy } from "svelte";
+    /// import { writable } from "svelte/store";
+    ///
+    /// const count = writable(0);
+    /// const unsubscribe = count.subscribe((value) => console.log(value));
+    /// onDestroy(unsubscribe);
+    /// ```
+    ///
+    pub NoUnusedStoreSubscriptions {
+        version: "next",
+        name: "noUnusedStoreSubscriptions",
+        language: "js",
+        domains: &[RuleDomain::Svelte],
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnusedStoreSubscriptions {
+    type Query = Semantic<JsCallExpression>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        if call.syntax().parent()?.kind() != JsSyntaxKind::JS_EXPRESSION_STATEMENT {
+            return None;
+        }
+        let AnyJsExpression::JsStaticMemberExpression(callee) = call.callee().ok()? else {
+            return None;
+        };
+        if callee.member().ok()?.value_token().ok()?.text_trimmed() != "subscribe" {
+            return None;
+        }
+        let object = callee.object().ok()?;
+        let store = model.binding(&object.as_js_identifier_expression()?.name().ok()?)?;
+        let declarator = store
+            .syntax()
+            .ancestors()
+            .skip(1)
+            .find_map(JsVariableDeclarator::cast)?;
+        let initializer = declarator
+            .initializer()?
+            .expression()
+            .ok()?
+            .omit_parentheses();
+        let constructor = initializer.as_js_call_expression()?.callee().ok()?;
+        is_store_constructor(model, &constructor).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The unsubscriber of this subscription is discarded."
+                },
+            )
+            .note(markup! {
+                "The subscription is never cancelled, and keeps running after the component is destroyed."
+            })
+            .note(markup! {
+                "Call the function returned by "<Emphasis>"subscribe"</Emphasis>" when the component is destroyed, or use the "<Emphasis>"$store"</Emphasis>" syntax."
+            }),
+        )
+    }
+}
+
+/// Returns `true` if `callee` is `writable`, `readable`, or `derived` imported from `svelte/store`.
+fn is_store_constructor(model: &SemanticModel, callee: &AnyJsExpression) -> bool {
+    let Some(reference) = callee
+        .as_js_identifier_expression()
+        .and_then(|identifier| identifier.name().ok())
+    else {
+        return false;
+    };
+    let Some(binding) = model.binding(&reference) else {
+        return false;
+    };
+    let Some(binding) = JsIdentifierBinding::cast_ref(binding.syntax()) else {
+        return false;
+    };
+    let Some(specifier) = binding.parent::<AnyJsNamedImportSpecifier>() else {
+        return false;
+    };
+    let name = match &specifier {
+        AnyJsNamedImportSpecifier::JsNamedImportSpecifier(specifier) => {
+            specifier.name().and_then(|name| name.value())
+        }
+        AnyJsNamedImportSpecifier::JsShorthandNamedImportSpecifier(_) => binding.name_token(),
+        AnyJsNamedImportSpecifier::JsBogusNamedImportSpecifier(_) => return false,
+    };
+    if !name.is_ok_and(|name| matches!(name.text_trimmed(), "writable" | "readable" | "derived")) {
+        return false;
+    }
+    specifier
+        .import_clause()
+        .and_then(|clause| clause.parent::<JsImport>())
+        .and_then(|import| import.source_text().ok())
+        .is_some_and(|source| source.text() == "svelte/store")
+}
@@ -0,0 +1,199 @@
+use crate::services::semantic::Semantic;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsFunctionBody, JsArrowFunctionExpression, JsFileSource, JsIdentifierExpression,
+    JsInitializerClause, JsReturnStatement, JsSyntaxKind, JsVariableDeclaration, JsYieldArgument,
+    T,
+};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow useless `undefined`.
+    ///
+    /// A function without a returned value returns `undefined`,
+    /// and a variable, a parameter, or a destructured binding without a value is `undefined`.
+    /// Writing `undefined` explicitly in these places is useless.
+    ///
+    /// The default values of the parameters are ignored in TypeScript files,
+    /// because `(x: T = undefined)` makes the parameter optional.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// function f() {
+    ///     return undefined;
+    /// }
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const f = () => undefined;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// let value = undefined;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// function f(value = undefined) {}
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// function f() {
+    ///     return;
+    /// }
+    /// ```
+    ///
+    /// ```js
+    /// let value;
+    /// ```
+    ///
+    /// ```js
+    /// function f(value) {}
+    /// ```
+    ///
+    pub NoUselessUndefined {
+        version: "next",
+        name: "noUselessUndefined",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("no-useless-undefined")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+pub enum UselessUndefined {
+    /// `return undefined`
+    Return(JsReturnStatement),
+    /// `yield undefined`
+    Yield(JsYieldArgument),
+    /// `() => undefined`
+    ArrowBody(JsArrowFunctionExpression),
+    /// `let x = undefined`, `(x = undefined) => {}`, `const { x = undefined } = y`
+    Initializer(JsInitializerClause),
+}
+
+impl Rule for NoUselessUndefined {
+    type Query = Semantic<JsIdentifierExpression>;
+    type State = UselessUndefined;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let reference = node.name().ok()?;
+        if !reference.is_undefined() || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        let parent = node.syntax().parent()?;
+        match parent.kind() {
+            JsSyntaxKind::JS_RETURN_STATEMENT => {
+                JsReturnStatement::cast(parent).map(UselessUndefined::Return)
+            }
+            JsSyntaxKind::JS_YIELD_ARGUMENT => {
+                let argument = JsYieldArgument::cast(parent)?;
+                // `yield* undefined` delegates to `undefined`.
+                argument
+                    .star_token()
+                    .is_none()
+                    .then_some(UselessUndefined::Yield(argument))
+            }
+            JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION => {
+                JsArrowFunctionExpression::cast(parent).map(UselessUndefined::ArrowBody)
+            }
+            JsSyntaxKind::JS_INITIALIZER_CLAUSE => {
+                let initializer = JsInitializerClause::cast(parent)?;
+                let owner = initializer.syntax().parent()?;
+                let is_useless = match owner.kind() {
+                    // `const` declarations require an initializer.
+                    JsSyntaxKind::JS_VARIABLE_DECLARATOR => owner
+                        .ancestors()
+                        .find_map(JsVariableDeclaration::cast)
+                        .is_some_and(|declaration| !declaration.is_const()),
+                    JsSyntaxKind::JS_FORMAL_PARAMETER => {
+                        !ctx.source_type::<JsFileSource>().language().is_typescript()
+                    }
+                    JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_PROPERTY
+                    | JsSyntaxKind::JS_OBJECT_BINDING_PATTERN_SHORTHAND_PROPERTY
+                    | JsSyntaxKind::JS_ARRAY_BINDING_PATTERN_ELEMENT => true,
+                    _ => false,
+                };
+                is_useless.then_some(UselessUndefined::Initializer(initializer))
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let note = match state {
+            UselessUndefined::Return(_)
+            | UselessUndefined::Yield(_)
+            | UselessUndefined::ArrowBody(_) => {
+                markup! { "A function without a returned value already returns "<Emphasis>"undefined"</Emphasis>"." }
+            }
+            UselessUndefined::Initializer(_) => {
+                markup! { "A binding without a value is already "<Emphasis>"undefined"</Emphasis>"." }
+            }
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This "<Emphasis>"undefined"</Emphasis>" is useless."
+                },
+            )
+            .note(note),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        match state {
+            UselessUndefined::Return(statement) => {
+                let return_token = statement.return_token().ok()?;
+                mutation.replace_token_discard_trivia(
+                    return_token.clone(),
+                    return_token.with_trailing_trivia([]),
+                );
+                mutation.remove_node(ctx.query().clone());
+            }
+            UselessUndefined::Yield(argument) => {
+                let yield_token = argument.syntax().prev_sibling_or_token()?.into_token()?;
+                mutation.replace_token_discard_trivia(
+                    yield_token.clone(),
+                    yield_token.with_trailing_trivia([]),
+                );
+                mutation.remove_node(argument.clone());
+            }
+            UselessUndefined::ArrowBody(arrow) => {
+                let body = arrow.body().ok()?;
+                let empty_body = make::js_function_body(
+                    make::token(T!['{']),
+                    make::js_directive_list([]),
+                    make::js_statement_list([]),
+                    make::token(T!['}']),
+                );
+                mutation.replace_node(body, AnyJsFunctionBody::JsFunctionBody(empty_body));
+            }
+            UselessUndefined::Initializer(initializer) => {
+                mutation.remove_node(initializer.clone());
+            }
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Remove the "<Emphasis>"undefined"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
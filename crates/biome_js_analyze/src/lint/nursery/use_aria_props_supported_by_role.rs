@@ -0,0 +1,161 @@
+use crate::services::aria::Aria;
+use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_aria::{is_aria_property_valid, roles::AriaRoleDefinition, AriaRoles};
+use biome_console::markup;
+use biome_js_syntax::{jsx_ext::AnyJsxElement, AnyJsxAttribute, JsxAttribute};
+use biome_rowan::{AstNode, AstNodeList};
+
+declare_rule! {
+    /// Enforce that ARIA properties are supported by the role of the element.
+    ///
+    /// Each ARIA role supports a specific set of `aria-*` attributes, in addition to the global ones such as `aria-label`.
+    /// An attribute that isn't supported by the role is ignored by the assistive technologies.
+    ///
+    /// The role of an element is its explicit `role` attribute or, when it's missing, the implicit role of the HTML element.
+    /// The custom components and the elements with a dynamic `role` are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <div role="button" aria-checked="true">Save</div>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <a href="/" aria-checked="true">Home</a>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input type="checkbox" aria-valuenow="1" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <>
+    ///     <div role="checkbox" aria-checked="true" aria-label="Subscribe" />
+    ///     <button aria-pressed="true">Bold</button>
+    ///     <input type="range" aria-valuetext="Medium" />
+    ///     <Button aria-checked="true" />
+    /// </>
+    /// ```
+    ///
+    /// ## Resources
+    ///
+    /// - [ARIA Roles Model](https://www.w3.org/TR/wai-aria-1.2/#roles)
+    /// - [ARIA Global States and Properties](https://www.w3.org/TR/wai-aria-1.2/#global_states)
+    ///
+    pub UseAriaPropsSupportedByRole {
+        version: "next",
+        name: "useAriaPropsSupportedByRole",
+        language: "jsx",
+        sources: &[RuleSource::EslintJsxA11y("role-supports-aria-props")],
+        recommended: false,
+    }
+}
+
+pub struct RuleState {
+    /// The unsupported `aria-*` attribute
+    attribute: JsxAttribute,
+    /// The explicit role of the element, if any
+    explicit_role: Option<String>,
+}
+
+impl Rule for UseAriaPropsSupportedByRole {
+    type Query = Aria<AnyJsxElement>;
+    type State = RuleState;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let Some(element_name) = node
+            .name()
+            .ok()
+            .and_then(|name| name.as_jsx_name().cloned())
+        else {
+            return Vec::new();
+        };
+        let aria_roles = ctx.aria_roles();
+        let (role, explicit_role) = match node.find_attribute_by_name("role") {
+            Some(role_attribute) => {
+                let Some((role, name)) = get_explicit_role(aria_roles, &role_attribute) else {
+                    return Vec::new();
+                };
+                (role, Some(name))
+            }
+            None => {
+                let Ok(element_name) = element_name.value_token() else {
+                    return Vec::new();
+                };
+                let role = ctx
+                    .extract_attributes(&node.attributes())
+                    .and_then(|attributes| {
+                        aria_roles.get_implicit_role(element_name.text_trimmed(), &attributes)
+                    });
+                let Some(role) = role else {
+                    return Vec::new();
+                };
+                (role, None)
+            }
+        };
+        node.attributes()
+            .iter()
+            .filter_map(|attribute| match attribute {
+                AnyJsxAttribute::JsxAttribute(attribute) => Some(attribute),
+                AnyJsxAttribute::JsxSpreadAttribute(_) => None,
+            })
+            .filter(|attribute| {
+                attribute.name_value_token().map_or(false, |name| {
+                    let name = name.text_trimmed();
+                    // The invalid attributes are reported by `useValidAriaProps`
+                    is_aria_property_valid(name) && !role.is_property_supported(name)
+                })
+            })
+            .map(|attribute| RuleState {
+                attribute,
+                explicit_role: explicit_role.clone(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.attribute.name_value_token()?;
+        let name = name.text_trimmed();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.attribute.range(),
+            markup! {
+                "The ARIA attribute "<Emphasis>{name}</Emphasis>" is not supported by the role of this element."
+            },
+        );
+        let diagnostic = match &state.explicit_role {
+            Some(role) => diagnostic.note(markup! {
+                "The role "<Emphasis>{role}</Emphasis>" doesn't support this attribute."
+            }),
+            None => diagnostic.note(markup! {
+                "The implicit role of this element doesn't support this attribute."
+            }),
+        };
+        Some(diagnostic.note(markup! {
+            "Remove the attribute or use a role that supports it."
+        }))
+    }
+}
+
+/// Returns the explicit role of an element and its name.
+///
+/// Returns `None` if the role is dynamic or doesn't contain any valid role.
+fn get_explicit_role(
+    aria_roles: &AriaRoles,
+    role_attribute: &JsxAttribute,
+) -> Option<(&'static dyn AriaRoleDefinition, String)> {
+    let static_value = role_attribute.as_static_value()?;
+    // If a role attribute has multiple values, the first valid value (specified role) will be used.
+    // Check: https://www.w3.org/TR/2014/REC-wai-aria-implementation-20140320/#mapping_role
+    static_value.text().split(' ').find_map(|name| {
+        let role = aria_roles.get_role(name)?;
+        Some((role, name.to_string()))
+    })
+}
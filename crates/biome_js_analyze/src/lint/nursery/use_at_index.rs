@@ -0,0 +1,381 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsCallArgument, AnyJsExpression, AnyJsLiteralExpression, JsBinaryOperator, JsCallExpression,
+    JsComputedMemberExpression, JsUnaryExpression, JsUnaryOperator, T,
+};
+use biome_rowan::{declare_node_union, AstNode, AstSeparatedList, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Use `at()` instead of integer index access.
+    ///
+    /// Accessing an element from the end of an array or a string with `array[array.length - 1]`
+    /// repeats the array, and is harder to read than `array.at(-1)`.
+    /// The rule also reports `array.slice(-1)[0]`, `array.slice(-1).pop()`,
+    /// and `string.charAt(string.length - 1)`.
+    ///
+    /// `Array.prototype.at()` and `String.prototype.at()` are available since ES2022.
+    /// If your code runs on runtimes that don't support them, set the `target` option.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const last = array[array.length - 1];
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const last = array.slice(-1)[0];
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const last = string.charAt(string.length - 1);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const last = array.at(-1);
+    /// ```
+    ///
+    /// ```js
+    /// const first = array[0];
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `checkAllIndexAccess`
+    ///
+    /// Report all the accesses with an integer index, such as `array[0]`.
+    /// Defaults to `false`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "checkAllIndexAccess": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### `target`
+    ///
+    /// The version of ECMAScript of the runtimes that the code runs on, from `"es5"` to `"es2024"`, or `"esnext"`.
+    /// The rule doesn't report anything when the target is older than `"es2022"`.
+    /// Defaults to `"esnext"`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "target": "es2021"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseAtIndex {
+        version: "next",
+        name: "useAtIndex",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("prefer-at")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyIndexAccess = JsComputedMemberExpression | JsCallExpression
+}
+
+/// Options for the rule `useAtIndex`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseAtIndexOptions {
+    /// Report all the accesses with an integer index.
+    pub check_all_index_access: bool,
+    /// The version of ECMAScript of the runtimes that the code runs on.
+    pub target: EcmaVersion,
+}
+
+/// A version of ECMAScript.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deserialize,
+    Deserializable,
+    Eq,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    Serialize,
+)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum EcmaVersion {
+    Es5,
+    Es2015,
+    Es2016,
+    Es2017,
+    Es2018,
+    Es2019,
+    Es2020,
+    Es2021,
+    Es2022,
+    Es2023,
+    Es2024,
+    /// The latest version
+    #[default]
+    #[serde(rename = "esnext")]
+    EsNext,
+}
+
+pub struct AtIndex {
+    /// The array or the string that is accessed.
+    object: AnyJsExpression,
+    /// The index of the element: negative indexes start from the end.
+    index: i64,
+}
+
+impl Rule for UseAtIndex {
+    type Query = Ast<AnyIndexAccess>;
+    type State = AtIndex;
+    type Signals = Option<Self::State>;
+    type Options = UseAtIndexOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        // `at()` doesn't exist before ES2022
+        if ctx.options().target < EcmaVersion::Es2022 {
+            return None;
+        }
+        let node = ctx.query();
+        // `delete array[array.length - 1]` doesn't read the element,
+        // and `array[array.length - 1]()` calls the element with `array` as `this`.
+        if let Some(parent) = node.syntax().parent() {
+            if JsUnaryExpression::cast_ref(&parent)
+                .is_some_and(|unary| unary.operator() == Ok(JsUnaryOperator::Delete))
+                || JsCallExpression::cast_ref(&parent).is_some_and(|call| {
+                    call.callee()
+                        .is_ok_and(|callee| callee.syntax() == node.syntax())
+                })
+            {
+                return None;
+            }
+        }
+        match node {
+            AnyIndexAccess::JsComputedMemberExpression(member) => {
+                if member.is_optional_chain() {
+                    return None;
+                }
+                let object = member.object().ok()?;
+                let index = member.member().ok()?.omit_parentheses();
+                // `array.slice(-1)[0]`
+                if let Some(slice) = object.clone().omit_parentheses().as_js_call_expression() {
+                    if integer_value(&index)? == 0 {
+                        let (object, index) = slice_start(slice)?;
+                        return Some(AtIndex { object, index });
+                    }
+                    return None;
+                }
+                if let Some(index) = index_from_end(&object, &index) {
+                    return Some(AtIndex {
+                        object,
+                        index: -index,
+                    });
+                }
+                if ctx.options().check_all_index_access {
+                    let index = integer_value(&index)?;
+                    return (index >= 0).then_some(AtIndex { object, index });
+                }
+                None
+            }
+            AnyIndexAccess::JsCallExpression(call) => {
+                let callee = call.callee().ok()?.omit_parentheses();
+                let callee = callee.as_js_static_member_expression()?;
+                let method = callee.member().ok()?;
+                let method = method.as_js_name()?.value_token().ok()?;
+                let arguments = call.arguments().ok()?.args();
+                match method.text_trimmed() {
+                    // `array.slice(-1).pop()`
+                    "pop" if arguments.is_empty() && !callee.is_optional_chain() => {
+                        let object = callee.object().ok()?.omit_parentheses();
+                        let (object, index) = slice_start(object.as_js_call_expression()?)?;
+                        (index == -1).then_some(AtIndex { object, index })
+                    }
+                    // `string.charAt(string.length - 1)`
+                    "charAt" if arguments.len() == 1 && !callee.is_optional_chain() => {
+                        let object = callee.object().ok()?;
+                        let argument = arguments.first()?.ok()?;
+                        let argument = argument.as_any_js_expression()?.clone().omit_parentheses();
+                        let index = index_from_end(&object, &argument)?;
+                        Some(AtIndex {
+                            object,
+                            index: -index,
+                        })
+                    }
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let replacement = format!(
+            "{}.at({})",
+            state.object.syntax().text_trimmed(),
+            state.index
+        );
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Prefer "<Emphasis>{replacement}</Emphasis>" over the index access."
+                },
+            )
+            .note(markup! {
+                "Using "<Emphasis>".at()"</Emphasis>" doesn't repeat the array to access an element from the end."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let node = AnyJsExpression::cast(ctx.query().syntax().clone())?;
+        let number = AnyJsExpression::AnyJsLiteralExpression(
+            make::js_number_literal_expression(make::js_number_literal(state.index.unsigned_abs()))
+                .into(),
+        );
+        let index = if state.index < 0 {
+            make::js_unary_expression(make::token(T![-]), number).into()
+        } else {
+            number
+        };
+        let at_call = make::js_call_expression(
+            make::js_static_member_expression(
+                state.object.clone().trim_trivia()?,
+                make::token(T![.]),
+                make::js_name(make::ident("at")).into(),
+            )
+            .into(),
+            make::js_call_arguments(
+                make::token(T!['(']),
+                make::js_call_argument_list([AnyJsCallArgument::AnyJsExpression(index)], []),
+                make::token(T![')']),
+            ),
+        )
+        .build();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(node, at_call.into());
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use "<Emphasis>".at()"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `N` if `index` is `object.length - N`.
+fn index_from_end(object: &AnyJsExpression, index: &AnyJsExpression) -> Option<i64> {
+    let binary = index.as_js_binary_expression()?;
+    if binary.operator().ok()? != JsBinaryOperator::Minus {
+        return None;
+    }
+    let length = binary.left().ok()?.omit_parentheses();
+    let length = length.as_js_static_member_expression()?;
+    if length
+        .member()
+        .ok()?
+        .as_js_name()?
+        .value_token()
+        .ok()?
+        .text_trimmed()
+        != "length"
+    {
+        return None;
+    }
+    let length_object = length.object().ok()?.omit_parentheses();
+    let object = object.clone().omit_parentheses();
+    if !is_simple_reference(&object)
+        || length_object.syntax().text_trimmed() != object.syntax().text_trimmed()
+    {
+        return None;
+    }
+    let value = integer_value(&binary.right().ok()?.omit_parentheses())?;
+    (value > 0).then_some(value)
+}
+
+/// Returns the object and the start index of `object.slice(-N)` or `object.slice(-N, -N + 1)`.
+fn slice_start(call: &JsCallExpression) -> Option<(AnyJsExpression, i64)> {
+    let callee = call.callee().ok()?.omit_parentheses();
+    let callee = callee.as_js_static_member_expression()?;
+    if callee.is_optional_chain()
+        || callee
+            .member()
+            .ok()?
+            .as_js_name()?
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            != "slice"
+    {
+        return None;
+    }
+    let arguments = call.arguments().ok()?.args();
+    let mut arguments = arguments.iter();
+    let start = arguments.next()?.ok()?;
+    let start = integer_value(&start.as_any_js_expression()?.clone().omit_parentheses())?;
+    if start >= 0 {
+        return None;
+    }
+    if let Some(end) = arguments.next() {
+        let end = end.ok()?;
+        let end = integer_value(&end.as_any_js_expression()?.clone().omit_parentheses())?;
+        if end != start + 1 || end == 0 || arguments.next().is_some() {
+            return None;
+        }
+    }
+    Some((callee.object().ok()?, start))
+}
+
+/// Returns the value of an integer literal, or of a negated integer literal.
+fn integer_value(expression: &AnyJsExpression) -> Option<i64> {
+    match expression {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(literal),
+        ) => {
+            let value = literal.as_number()?;
+            (value.fract() == 0.0 && value.abs() < (1u64 << 53) as f64).then_some(value as i64)
+        }
+        AnyJsExpression::JsUnaryExpression(unary)
+            if unary.operator().ok()? == JsUnaryOperator::Minus =>
+        {
+            integer_value(&unary.argument().ok()?.omit_parentheses()).map(|value| -value)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if evaluating `expression` twice has no side effects:
+/// `array`, `this.array`, or `object.array`.
+fn is_simple_reference(expression: &AnyJsExpression) -> bool {
+    match expression {
+        AnyJsExpression::JsIdentifierExpression(_) | AnyJsExpression::JsThisExpression(_) => true,
+        AnyJsExpression::JsStaticMemberExpression(member) => member
+            .object()
+            .is_ok_and(|object| is_simple_reference(&object.omit_parentheses())),
+        _ => false,
+    }
+}
@@ -0,0 +1,287 @@
+use crate::storybook::is_stories_file;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    function_ext::AnyFunctionLike, AnyJsAssignment, AnyJsAssignmentPattern, AnyJsExpression,
+    AnyJsFunction, JsAssignmentExpression, JsCallArgumentList, JsCallArguments, JsCallExpression,
+    JsPropertyObjectMember, JsSyntaxKind, JsSyntaxNode, T,
+};
+use biome_rowan::{AstNode, BatchMutationExt, TriviaPieceKind};
+
+declare_rule! {
+    /// Enforce awaiting the interactions of the `play` functions of stories.
+    ///
+    /// The interactions of a `play` function, such as `userEvent.click()`, `waitFor()`,
+    /// `step()`, the `findBy*` queries, and the `expect` assertions, are asynchronous.
+    /// An interaction that isn't awaited can run after the end of the `play` function,
+    /// and is not displayed correctly in the Interactions panel of Storybook.
+    ///
+    /// The rule applies to the files whose name contains `.stories.` or `.story.`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export const Submitted = {
+    ///     play: async ({ canvasElement }) => {
+    ///         const canvas = within(canvasElement);
+    ///         userEvent.click(canvas.getByRole("button"));
+    ///     },
+    /// };
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export const Submitted = {
+    ///     play: async ({ canvasElement }) => {
+    ///         const canvas = within(canvasElement);
+    ///         await userEvent.click(canvas.getByRole("button"));
+    ///     },
+    /// };
+    /// ```
+    ///
+    pub UseAwaitStoryInteractions {
+        version: "next",
+        name: "useAwaitStoryInteractions",
+        language: "js",
+        sources: &[RuleSource::EslintStorybook("await-interactions")],
+        domains: &[RuleDomain::Storybook],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseAwaitStoryInteractions {
+    type Query = Ast<JsCallExpression>;
+    /// The function that directly contains the interaction.
+    type State = AnyFunctionLike;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        if !is_interaction(call) || is_awaited(call.syntax()) {
+            return None;
+        }
+        let function = call.syntax().ancestors().find_map(AnyFunctionLike::cast)?;
+        let mut current = function.clone();
+        // Interactions grouped by `step()` belong to the enclosing `play` function.
+        while !is_play_function(&current) {
+            if !is_step_callback(&current) {
+                return None;
+            }
+            current = current
+                .syntax()
+                .ancestors()
+                .skip(1)
+                .find_map(AnyFunctionLike::cast)?;
+        }
+        is_stories_file(ctx.file_path()).then_some(function)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This interaction isn't awaited."
+                },
+            )
+            .note(markup! {
+                "The interactions of a "<Emphasis>"play"</Emphasis>" function are asynchronous. An interaction that isn't awaited can run after the end of the "<Emphasis>"play"</Emphasis>" function."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, function: &Self::State) -> Option<JsRuleAction> {
+        // Adding `await` to a function that isn't async is a syntax error.
+        if !function.is_async() {
+            return None;
+        }
+        let call = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let await_token = make::token(T![await])
+            .with_leading_trivia_pieces(call.syntax().first_leading_trivia()?.pieces())
+            .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+        let argument = AnyJsExpression::from(call.clone().trim_leading_trivia()?);
+        mutation.replace_node(
+            AnyJsExpression::from(call.clone()),
+            make::js_await_expression(await_token, argument).into(),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Add "<Emphasis>"await"</Emphasis>" to the interaction." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `call` is an asynchronous interaction of a `play` function.
+fn is_interaction(call: &JsCallExpression) -> bool {
+    match call.callee().map(AnyJsExpression::omit_parentheses) {
+        Ok(AnyJsExpression::JsIdentifierExpression(callee)) => callee
+            .name()
+            .and_then(|name| name.value_token())
+            .is_ok_and(|token| {
+                matches!(
+                    token.text_trimmed(),
+                    "step" | "waitFor" | "waitForElementToBeRemoved"
+                )
+            }),
+        Ok(AnyJsExpression::JsStaticMemberExpression(callee)) => {
+            let Some(member) = callee.member().ok().and_then(|member| {
+                Some(
+                    member
+                        .as_js_name()?
+                        .value_token()
+                        .ok()?
+                        .token_text_trimmed(),
+                )
+            }) else {
+                return false;
+            };
+            let member = member.text();
+            if member.starts_with("findBy") || member.starts_with("findAllBy") {
+                return true;
+            }
+            match callee.object().map(AnyJsExpression::omit_parentheses) {
+                Ok(AnyJsExpression::JsIdentifierExpression(object)) => {
+                    member != "setup"
+                        && object
+                            .name()
+                            .and_then(|name| name.value_token())
+                            .is_ok_and(|token| token.text_trimmed() == "userEvent")
+                }
+                Ok(object) => is_top_of_expect_chain(call, object),
+                Err(_) => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `call` ends an assertion chain that starts with `expect(...)`,
+/// such as `expect(value).not.toBe(1)`.
+fn is_top_of_expect_chain(call: &JsCallExpression, mut object: AnyJsExpression) -> bool {
+    if call.syntax().parent().is_some_and(|parent| {
+        matches!(
+            parent.kind(),
+            JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION | JsSyntaxKind::JS_COMPUTED_MEMBER_EXPRESSION
+        )
+    }) {
+        return false;
+    }
+    loop {
+        object = match object {
+            AnyJsExpression::JsStaticMemberExpression(member) => match member.object() {
+                Ok(object) => object,
+                Err(_) => return false,
+            },
+            AnyJsExpression::JsCallExpression(call) => {
+                return call
+                    .callee()
+                    .ok()
+                    .and_then(|callee| {
+                        Some(
+                            callee
+                                .as_js_identifier_expression()?
+                                .name()
+                                .ok()?
+                                .value_token()
+                                .ok()?
+                                .text_trimmed()
+                                == "expect",
+                        )
+                    })
+                    .unwrap_or(false);
+            }
+            _ => return false,
+        }
+    }
+}
+
+/// Returns `true` if the value of `node` is awaited or returned.
+fn is_awaited(node: &JsSyntaxNode) -> bool {
+    node.ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+        .is_some_and(|parent| {
+            matches!(
+                parent.kind(),
+                JsSyntaxKind::JS_AWAIT_EXPRESSION
+                    | JsSyntaxKind::JS_RETURN_STATEMENT
+                    | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            )
+        })
+}
+
+/// Returns `true` if `function` is the `play` function of a story.
+fn is_play_function(function: &AnyFunctionLike) -> bool {
+    match function {
+        AnyFunctionLike::JsMethodObjectMember(method) => method
+            .name()
+            .ok()
+            .and_then(|name| name.name())
+            .is_some_and(|name| name.text() == "play"),
+        AnyFunctionLike::AnyJsFunction(
+            function @ (AnyJsFunction::JsArrowFunctionExpression(_)
+            | AnyJsFunction::JsFunctionExpression(_)),
+        ) => {
+            let Some(parent) = function.syntax().parent() else {
+                return false;
+            };
+            if let Some(member) = JsPropertyObjectMember::cast_ref(&parent) {
+                return member
+                    .name()
+                    .ok()
+                    .and_then(|name| name.name())
+                    .is_some_and(|name| name.text() == "play");
+            }
+            // `Story.play = async () => {}`
+            JsAssignmentExpression::cast(parent)
+                .and_then(|assignment| assignment.left().ok())
+                .and_then(|left| match left {
+                    AnyJsAssignmentPattern::AnyJsAssignment(
+                        AnyJsAssignment::JsStaticMemberAssignment(left),
+                    ) => Some(left.member().ok()?.as_js_name()?.value_token().ok()?),
+                    _ => None,
+                })
+                .is_some_and(|member| member.text_trimmed() == "play")
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if `function` is the callback of a `step()` call.
+fn is_step_callback(function: &AnyFunctionLike) -> bool {
+    function
+        .parent::<JsCallArgumentList>()
+        .and_then(|arguments| arguments.parent::<JsCallArguments>())
+        .and_then(|arguments| arguments.parent::<JsCallExpression>())
+        .and_then(|call| call.callee().ok())
+        .and_then(|callee| {
+            Some(
+                callee
+                    .as_js_identifier_expression()?
+                    .name()
+                    .ok()?
+                    .value_token()
+                    .ok()?
+                    .text_trimmed()
+                    == "step",
+            )
+        })
+        .unwrap_or(false)
+}
@@ -0,0 +1,106 @@
+use crate::{services::semantic::Semantic, utils::type_info::TypeFacts, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsExpression, JsAwaitExpression};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Enforce that `await` is only applied to values that may be thenables.
+    ///
+    /// Awaiting a value that isn't a `Promise` or another thenable is allowed in JavaScript,
+    /// but it is generally a mistake:
+    /// the value is returned unchanged after a microtask.
+    /// This often means that the awaited function was expected to be asynchronous,
+    /// or that the `await` keyword is a leftover of a refactoring.
+    ///
+    /// The rule reports `await` applied to literals, objects, functions,
+    /// and to variables and parameters whose type annotation doesn't allow a thenable.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// await 42;
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// async function f(name: string) {
+    ///     return await name;
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// async function f(name: Promise<string>) {
+    ///     return await name;
+    /// }
+    /// ```
+    ///
+    /// ```ts
+    /// async function f(value: unknown) {
+    ///     return await value;
+    /// }
+    /// ```
+    ///
+    pub UseAwaitThenable {
+        version: "next",
+        name: "useAwaitThenable",
+        language: "js",
+        sources: &[RuleSource::EslintTypeScript("await-thenable")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for UseAwaitThenable {
+    type Query = Semantic<JsAwaitExpression>;
+    type State = AnyJsExpression;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let argument = ctx.query().argument().ok()?;
+        TypeFacts::from_expression(ctx.model(), &argument)
+            .is_never_thenable()
+            .then_some(argument)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, argument: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                node.range(),
+                markup! {
+                    "Unexpected "<Emphasis>"await"</Emphasis>" of a value that is not a thenable."
+                },
+            )
+            .detail(
+                argument.range(),
+                markup! {
+                    "This value is never a "<Emphasis>"Promise"</Emphasis>" or another thenable."
+                },
+            )
+            .note(markup! {
+                "Awaiting a non-thenable value only delays the execution. Remove the "<Emphasis>"await"</Emphasis>" or await the intended promise."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, argument: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(node.clone()), argument.clone());
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Remove the "<Emphasis>"await"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
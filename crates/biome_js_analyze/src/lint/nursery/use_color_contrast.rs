@@ -0,0 +1,221 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_color::Color;
+use biome_console::markup;
+use biome_js_syntax::{
+    jsx_ext::AnyJsxElement, static_value::StaticValue, AnyJsExpression, AnyJsObjectMember,
+    AnyJsxAttributeValue, JsObjectExpression,
+};
+use biome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Enforce a sufficient contrast between the text color and the background color of the inline styles.
+    ///
+    /// The text with a low contrast is hard or impossible to read for many users,
+    /// especially for the users with a low vision.
+    /// The level AA of the WCAG requires a contrast ratio of at least 4.5:1 for the normal text,
+    /// and of at least 3:1 for the large text:
+    /// a font size of at least `24px`, or of at least `18.66px` in bold.
+    ///
+    /// The rule only checks the inline styles of the HTML elements
+    /// that set both the properties `color` and `backgroundColor` (or `background`) to static colors.
+    /// The background colors with transparency are ignored, because the color behind them is unknown.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <p style={{ color: "#999", backgroundColor: "#fff" }}>Hello</p>
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <span style={{ color: "yellow", background: "white", fontSize: 24 }}>Hello</span>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <>
+    ///     <p style={{ color: "#767676", backgroundColor: "#fff" }}>Hello</p>
+    ///     <h1 style={{ color: "#949494", backgroundColor: "#fff", fontSize: "24px" }}>Hello</h1>
+    ///     <p style={{ color: textColor, backgroundColor: "#fff" }}>Hello</p>
+    /// </>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.4.3](https://www.w3.org/WAI/WCAG21/Understanding/contrast-minimum)
+    ///
+    pub UseColorContrast {
+        version: "next",
+        name: "useColorContrast",
+        language: "jsx",
+        recommended: false,
+    }
+}
+
+/// The minimum contrast ratio of the normal text at the level AA.
+const NORMAL_TEXT_MIN_RATIO: f64 = 4.5;
+
+/// The minimum contrast ratio of the large text at the level AA.
+const LARGE_TEXT_MIN_RATIO: f64 = 3.0;
+
+pub struct RuleState {
+    /// The range of the property `color`
+    range: TextRange,
+    ratio: f64,
+    is_large_text: bool,
+}
+
+impl Rule for UseColorContrast {
+    type Query = Ast<AnyJsxElement>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        if !node.is_element() {
+            return None;
+        }
+        let style = node.find_attribute_by_name("style")?;
+        let AnyJsxAttributeValue::JsxExpressionAttributeValue(value) =
+            style.initializer()?.value().ok()?
+        else {
+            return None;
+        };
+        let AnyJsExpression::JsObjectExpression(object) = value.expression().ok()? else {
+            return None;
+        };
+        let style = InlineStyle::from_object(&object)?;
+        let (color_range, color) = style.color?;
+        let background = style.background?;
+        if !background.is_opaque() {
+            return None;
+        }
+        let color = color.blend_over(&background);
+        let ratio = color.contrast_ratio(&background);
+        (ratio < min_ratio(style.is_large_text)).then_some(RuleState {
+            range: color_range,
+            ratio,
+            is_large_text: style.is_large_text,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        // The ratio is truncated to not round a ratio below the minimum up to the minimum
+        let ratio = format!("{:.2}", (state.ratio * 100.0).floor() / 100.0);
+        let min_ratio = format!("{:.1}", min_ratio(state.is_large_text));
+        let text_size = if state.is_large_text {
+            "large"
+        } else {
+            "normal"
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The contrast between the text color and the background color is too low."
+                },
+            )
+            .note(markup! {
+                "The contrast ratio is "<Emphasis>{ratio}":1"</Emphasis>", but the level AA of the WCAG requires at least "<Emphasis>{min_ratio}":1"</Emphasis>" for the "{text_size}" text."
+            })
+            .note(markup! {
+                "Use a darker or a lighter color to make the text readable."
+            }),
+        )
+    }
+}
+
+fn min_ratio(is_large_text: bool) -> f64 {
+    if is_large_text {
+        LARGE_TEXT_MIN_RATIO
+    } else {
+        NORMAL_TEXT_MIN_RATIO
+    }
+}
+
+/// The properties of an inline style that are relevant for the contrast.
+#[derive(Default)]
+struct InlineStyle {
+    color: Option<(TextRange, Color)>,
+    background: Option<Color>,
+    is_large_text: bool,
+}
+
+impl InlineStyle {
+    /// Returns `None` if the object contains spread or computed members,
+    /// which can override the static properties.
+    fn from_object(object: &JsObjectExpression) -> Option<Self> {
+        let mut style = Self::default();
+        let mut font_size = None;
+        let mut is_bold = false;
+        for member in object.members() {
+            let AnyJsObjectMember::JsPropertyObjectMember(member) = member.ok()? else {
+                return None;
+            };
+            let name = member.name().ok()?.name()?;
+            let value = member.value().ok()?.as_static_value();
+            match name.text() {
+                "color" => {
+                    style.color = value
+                        .as_ref()
+                        .and_then(static_string)
+                        .and_then(Color::parse)
+                        .map(|color| (member.range(), color));
+                }
+                "backgroundColor" | "background" => {
+                    style.background = value
+                        .as_ref()
+                        .and_then(static_string)
+                        .and_then(Color::parse);
+                }
+                "fontSize" => font_size = value.as_ref().and_then(font_size_in_pixels),
+                "fontWeight" => {
+                    is_bold = value.as_ref().map_or(false, |value| match value {
+                        StaticValue::Number(_) | StaticValue::String(_) => {
+                            let weight = value.text();
+                            matches!(weight, "bold" | "bolder")
+                                || weight
+                                    .parse::<f64>()
+                                    .map_or(false, |weight| weight >= 700.0)
+                        }
+                        _ => false,
+                    });
+                }
+                _ => {}
+            }
+        }
+        // See https://www.w3.org/TR/WCAG21/#dfn-large-scale
+        style.is_large_text =
+            font_size.map_or(false, |size| size >= 24.0 || (is_bold && size >= 18.66));
+        Some(style)
+    }
+}
+
+fn static_string(value: &StaticValue) -> Option<&str> {
+    match value {
+        StaticValue::String(_) => Some(value.text()),
+        _ => None,
+    }
+}
+
+/// Returns the font size in pixels, or `None` if it depends on the context, such as `1.5em`.
+fn font_size_in_pixels(value: &StaticValue) -> Option<f64> {
+    match value {
+        // React appends `px` to the numbers
+        StaticValue::Number(_) => value.text().parse().ok(),
+        StaticValue::String(_) => {
+            let text = value.text().trim();
+            if let Some(pixels) = text.strip_suffix("px") {
+                pixels.trim().parse().ok()
+            } else {
+                let points: f64 = text.strip_suffix("pt")?.trim().parse().ok()?;
+                Some(points * 4.0 / 3.0)
+            }
+        }
+        _ => None,
+    }
+}
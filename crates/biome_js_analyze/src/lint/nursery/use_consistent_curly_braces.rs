@@ -0,0 +1,337 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsLiteralExpression, AnyJsTemplateElement, AnyJsxAttributeValue,
+    AnyJsxChild, JsSyntaxKind, JsSyntaxToken, JsxAttributeInitializerClause, JsxChildList,
+    JsxExpressionAttributeValue, JsxExpressionChild, JsxString, JsxText, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, BatchMutationExt, TextRange, TextSize,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Enforce a consistent use of curly braces around the string props and the string children of JSX elements.
+    ///
+    /// A string can be written either as a plain JSX string (`<Foo bar="x">text</Foo>`)
+    /// or as a string literal wrapped in curly braces (`<Foo bar={"x"}>{"text"}</Foo>`).
+    /// By default, the rule reports the curly braces that aren't needed.
+    ///
+    /// The rule ignores the strings that would change their meaning when moved
+    /// between a JSX string and a JavaScript string, such as the strings containing escape sequences,
+    /// HTML entities, or characters that aren't allowed in JSX text.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <Foo bar={"baz"} />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <Foo>{"Hello world"}</Foo>
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <Foo bar="baz">Hello world</Foo>
+    /// ```
+    ///
+    /// ```jsx
+    /// <Foo bar={"a\nb"}>{"{braces}"}</Foo>
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// The options `props` and `children` set the policy for the string props
+    /// and for the string children respectively.
+    /// Both accept the following values:
+    ///
+    /// - `"never"`: the curly braces around strings are reported. This is the default.
+    /// - `"always"`: the strings without curly braces are reported.
+    /// - `"ignore"`: the strings aren't checked.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "props": "always",
+    ///         "children": "ignore"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseConsistentCurlyBraces {
+        version: "next",
+        name: "useConsistentCurlyBraces",
+        language: "jsx",
+        sources: &[RuleSource::EslintReact("jsx-curly-brace-presence")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsxStringCandidate = JsxExpressionAttributeValue | JsxString | JsxExpressionChild | JsxText
+}
+
+/// Options for the rule `useConsistentCurlyBraces`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseConsistentCurlyBracesOptions {
+    /// The policy for the string props.
+    pub props: CurlyBracesPolicy,
+    /// The policy for the string children.
+    pub children: CurlyBracesPolicy,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum CurlyBracesPolicy {
+    /// Strings must not be wrapped in curly braces.
+    #[default]
+    Never,
+    /// Strings must be wrapped in curly braces.
+    Always,
+    /// Strings aren't checked.
+    Ignore,
+}
+
+impl Rule for UseConsistentCurlyBraces {
+    type Query = Ast<AnyJsxStringCandidate>;
+    /// The content of the string, without quotes.
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = UseConsistentCurlyBracesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        match ctx.query() {
+            AnyJsxStringCandidate::JsxExpressionAttributeValue(value) => {
+                if options.props != CurlyBracesPolicy::Never
+                    || value.syntax().has_comments_descendants()
+                {
+                    return None;
+                }
+                let content = string_content(&value.expression().ok()?)?;
+                is_valid_jsx_string(&content).then_some(content)
+            }
+            AnyJsxStringCandidate::JsxString(string) => {
+                if options.props != CurlyBracesPolicy::Always
+                    || !JsxAttributeInitializerClause::can_cast(string.syntax().parent()?.kind())
+                {
+                    return None;
+                }
+                let content = string.inner_string_text().ok()?.to_string();
+                is_valid_js_string(&content).then_some(content)
+            }
+            AnyJsxStringCandidate::JsxExpressionChild(child) => {
+                if options.children != CurlyBracesPolicy::Never
+                    || child.syntax().has_comments_descendants()
+                {
+                    return None;
+                }
+                let content = string_content(&child.expression()?)?;
+                is_valid_jsx_text(&content).then_some(content)
+            }
+            AnyJsxStringCandidate::JsxText(text) => {
+                if options.children != CurlyBracesPolicy::Always {
+                    return None;
+                }
+                let value = text.value_token().ok()?;
+                let content = value.text().trim();
+                (!content.is_empty() && is_valid_js_string(content)).then(|| content.to_string())
+            }
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        let node = ctx.query();
+        let (message, note) = match node {
+            AnyJsxStringCandidate::JsxExpressionAttributeValue(_) => (
+                markup! { "This string prop doesn't need curly braces." },
+                markup! { "Remove the curly braces around the string." },
+            ),
+            AnyJsxStringCandidate::JsxString(_) => (
+                markup! { "This string prop isn't wrapped in curly braces." },
+                markup! { "Wrap the string in curly braces." },
+            ),
+            AnyJsxStringCandidate::JsxExpressionChild(_) => (
+                markup! { "This string child doesn't need curly braces." },
+                markup! { "Remove the curly braces around the string." },
+            ),
+            AnyJsxStringCandidate::JsxText(_) => (
+                markup! { "This string child isn't wrapped in curly braces." },
+                markup! { "Wrap the string in curly braces." },
+            ),
+        };
+        let range = match node {
+            AnyJsxStringCandidate::JsxText(text) => {
+                // Only highlight the text without the surrounding whitespaces.
+                let value = text.value_token().ok()?;
+                let start = value.text().len() - value.text().trim_start().len();
+                TextRange::at(
+                    value.text_range().start() + TextSize::from(start as u32),
+                    TextSize::from(value.text().trim().len() as u32),
+                )
+            }
+            _ => node.range(),
+        };
+        Some(RuleDiagnostic::new(rule_category!(), range, message).note(note))
+    }
+
+    fn action(ctx: &RuleContext<Self>, content: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let message = match ctx.query() {
+            AnyJsxStringCandidate::JsxExpressionAttributeValue(value) => {
+                let new_value =
+                    make::jsx_string(quoted(JsSyntaxKind::JSX_STRING_LITERAL, content)?);
+                mutation.replace_node(
+                    AnyJsxAttributeValue::from(value.clone()),
+                    AnyJsxAttributeValue::from(new_value),
+                );
+                markup! { "Remove the curly braces." }
+            }
+            AnyJsxStringCandidate::JsxString(string) => {
+                let new_value = make::jsx_expression_attribute_value(
+                    make::token(T!['{']),
+                    string_literal_expression(content)?,
+                    make::token(T!['}']),
+                );
+                mutation.replace_node(
+                    AnyJsxAttributeValue::from(string.clone()),
+                    AnyJsxAttributeValue::from(new_value),
+                );
+                markup! { "Add curly braces." }
+            }
+            AnyJsxStringCandidate::JsxExpressionChild(child) => {
+                let text = make::jsx_text(JsSyntaxToken::new_detached(
+                    JsSyntaxKind::JSX_TEXT_LITERAL,
+                    content,
+                    [],
+                    [],
+                ));
+                mutation.replace_node(AnyJsxChild::from(child.clone()), AnyJsxChild::from(text));
+                markup! { "Remove the curly braces." }
+            }
+            AnyJsxStringCandidate::JsxText(text) => {
+                let list = JsxChildList::cast(text.syntax().parent()?)?;
+                let value = text.value_token().ok()?;
+                let value = value.text();
+                let leading = &value[..value.len() - value.trim_start().len()];
+                let trailing = &value[value.trim_end().len()..];
+                let expression_child =
+                    make::jsx_expression_child(make::token(T!['{']), make::token(T!['}']))
+                        .with_expression(string_literal_expression(content)?)
+                        .build();
+                let mut children = Vec::with_capacity(list.len() + 2);
+                for child in list.iter() {
+                    if child.syntax() != text.syntax() {
+                        children.push(child);
+                        continue;
+                    }
+                    if !leading.is_empty() {
+                        children.push(AnyJsxChild::from(jsx_text(leading)));
+                    }
+                    children.push(AnyJsxChild::from(expression_child.clone()));
+                    if !trailing.is_empty() {
+                        children.push(AnyJsxChild::from(jsx_text(trailing)));
+                    }
+                }
+                mutation.replace_node_discard_trivia(list, make::jsx_child_list(children));
+                markup! { "Add curly braces." }
+            }
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            message.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the content of `expression` if it's a string literal
+/// or a template literal without tag and substitutions.
+///
+/// The strings containing backslashes are ignored,
+/// because their escape sequences aren't interpreted in JSX.
+fn string_content(expression: &AnyJsExpression) -> Option<String> {
+    let content = match expression {
+        AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsStringLiteralExpression(string),
+        ) => string.inner_string_text().ok()?.to_string(),
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+            let mut content = String::new();
+            for element in template.elements() {
+                let AnyJsTemplateElement::JsTemplateChunkElement(chunk) = element else {
+                    return None;
+                };
+                content.push_str(chunk.template_chunk_token().ok()?.text());
+            }
+            content
+        }
+        _ => return None,
+    };
+    (!content.contains('\\')).then_some(content)
+}
+
+/// Returns `true` if `content` keeps its meaning inside a JSX string.
+fn is_valid_jsx_string(content: &str) -> bool {
+    !content.contains(['&', '\n', '\r']) && (!content.contains('"') || !content.contains('\''))
+}
+
+/// Returns `true` if `content` keeps its meaning as JSX text.
+///
+/// The leading and the trailing whitespaces are ignored, because JSX trims them.
+fn is_valid_jsx_text(content: &str) -> bool {
+    !content.is_empty()
+        && content.trim() == content
+        && !content.contains(['&', '{', '}', '<', '>', '\n', '\r'])
+}
+
+/// Returns `true` if `content` keeps its meaning inside a JavaScript string.
+fn is_valid_js_string(content: &str) -> bool {
+    is_valid_jsx_string(content) && !content.contains('\\')
+}
+
+/// Returns a token of `kind` containing `content` between double quotes,
+/// or between single quotes if `content` contains double quotes.
+fn quoted(kind: JsSyntaxKind, content: &str) -> Option<JsSyntaxToken> {
+    let text = if !content.contains('"') {
+        format!("\"{content}\"")
+    } else if !content.contains('\'') {
+        format!("'{content}'")
+    } else {
+        return None;
+    };
+    Some(JsSyntaxToken::new_detached(kind, &text, [], []))
+}
+
+fn string_literal_expression(content: &str) -> Option<AnyJsExpression> {
+    let literal =
+        make::js_string_literal_expression(quoted(JsSyntaxKind::JS_STRING_LITERAL, content)?);
+    Some(AnyJsExpression::AnyJsLiteralExpression(literal.into()))
+}
+
+fn jsx_text(text: &str) -> JsxText {
+    make::jsx_text(JsSyntaxToken::new_detached(
+        JsSyntaxKind::JSX_TEXT_LITERAL,
+        text,
+        [],
+        [],
+    ))
+}
@@ -0,0 +1,199 @@
+use crate::utils::test_framework::{enclosing_test_suite, test_call_kind, TestCallKind};
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{JsCallExpression, JsSyntaxToken};
+use biome_rowan::{AstNode, BatchMutationExt};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Enforce using the same function, `it` or `test`, to declare tests.
+    ///
+    /// `it` and `test` are aliases.
+    /// Using both in the same codebase is inconsistent.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// it("adds numbers", () => {
+    ///     expect(add(1, 2)).toBe(3);
+    /// });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// describe("add", () => {
+    ///     test("adds numbers", () => {
+    ///         expect(add(1, 2)).toBe(3);
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// test("adds numbers", () => {
+    ///     expect(add(1, 2)).toBe(3);
+    /// });
+    ///
+    /// describe("add", () => {
+    ///     it("adds numbers", () => {
+    ///         expect(add(1, 2)).toBe(3);
+    ///     });
+    /// });
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "function": "test",
+    ///         "withinDescribe": "it"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### function
+    ///
+    /// The function to use for the tests that aren't in a test suite.
+    /// Accepts `"test"` or `"it"`. Defaults to `"test"`.
+    ///
+    /// ### withinDescribe
+    ///
+    /// The function to use for the tests in a test suite.
+    /// Accepts `"test"` or `"it"`. Defaults to `"it"`.
+    ///
+    pub UseConsistentTestFunction {
+        version: "next",
+        name: "useConsistentTestFunction",
+        language: "js",
+        sources: &[RuleSource::EslintJest("consistent-test-it")],
+        domains: &[RuleDomain::Test],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// Options for the rule `useConsistentTestFunction`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ConsistentTestFunctionOptions {
+    /// The function to use for the tests that aren't in a test suite.
+    pub function: TestFunction,
+    /// The function to use for the tests in a test suite.
+    pub within_describe: TestFunction,
+}
+
+impl Default for ConsistentTestFunctionOptions {
+    fn default() -> Self {
+        Self {
+            function: TestFunction::Test,
+            within_describe: TestFunction::It,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum TestFunction {
+    /// `test()`
+    #[default]
+    Test,
+    /// `it()`
+    It,
+}
+
+impl TestFunction {
+    const fn name(self, is_skipped: bool) -> &'static str {
+        match (self, is_skipped) {
+            (Self::Test, false) => "test",
+            (Self::Test, true) => "xtest",
+            (Self::It, false) => "it",
+            (Self::It, true) => "xit",
+        }
+    }
+}
+
+pub struct InconsistentTestFunction {
+    token: JsSyntaxToken,
+    expected: &'static str,
+    within_describe: bool,
+}
+
+impl Rule for UseConsistentTestFunction {
+    type Query = Ast<JsCallExpression>;
+    type State = InconsistentTestFunction;
+    type Signals = Option<Self::State>;
+    type Options = ConsistentTestFunctionOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let options = ctx.options();
+        if test_call_kind(call)? != TestCallKind::Case {
+            return None;
+        }
+        let token = call.callee().ok()?.get_callee_object_name()?;
+        let is_skipped = match token.text_trimmed() {
+            "it" | "test" => false,
+            "xit" | "xtest" => true,
+            _ => return None,
+        };
+        let within_describe = enclosing_test_suite(call.syntax()).is_some();
+        let function = if within_describe {
+            options.within_describe
+        } else {
+            options.function
+        };
+        let expected = function.name(is_skipped);
+        (token.text_trimmed() != expected).then_some(InconsistentTestFunction {
+            token,
+            expected,
+            within_describe,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let expected = state.expected;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.token.text_trimmed_range(),
+            markup! {
+                "Use "<Emphasis>{expected}</Emphasis>" instead of "<Emphasis>{state.token.text_trimmed()}</Emphasis>"."
+            },
+        );
+        Some(if state.within_describe {
+            diagnostic.note(markup! {
+                "The tests of a test suite should be declared with "<Emphasis>{expected}</Emphasis>"."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "The tests outside of a test suite should be declared with "<Emphasis>{expected}</Emphasis>"."
+            })
+        })
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(state.token.clone(), make::ident(state.expected));
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Use "<Emphasis>{state.expected}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
@@ -0,0 +1,211 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsModuleItem, AnyJsStatement, JsCallExpression, JsModule, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Enforce declaring `defineProps` and `defineEmits` at the top of Vue `<script setup>`.
+    ///
+    /// Declaring the props and the events of a component first,
+    /// right after the imports and the type declarations,
+    /// makes the interface of the component easy to find.
+    /// `defineProps` must come before `defineEmits`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const count = ref(0);
+    /// const props = defineProps({ title: String });
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const emit = defineEmits(["change"]);
+    /// const props = defineProps({ title: String });
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// import { ref } from "vue";
+    ///
+    /// const props = defineProps({ title: String });
+    /// const emit = defineEmits(["change"]);
+    /// const count = ref(0);
+    /// ```
+    ///
+    pub UseDefinePropsOrder {
+        version: "next",
+        name: "useDefinePropsOrder",
+        language: "js",
+        sources: &[RuleSource::EslintVue("define-macros-order")],
+        source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Vue],
+        recommended: false,
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Macro {
+    DefineProps,
+    DefineEmits,
+}
+
+pub struct MisplacedMacro {
+    kind: Macro,
+    range: TextRange,
+    /// The statement that should come after the macro.
+    preceding: TextRange,
+}
+
+impl Rule for UseDefinePropsOrder {
+    type Query = Ast<JsModule>;
+    type State = MisplacedMacro;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut misplaced = Vec::new();
+        let mut first_statement = None;
+        let mut first_emits = None;
+        for item in ctx.query().items() {
+            match macro_kind(&item) {
+                Some(Macro::DefineProps) => {
+                    if let Some(preceding) = first_statement.or(first_emits) {
+                        misplaced.push(MisplacedMacro {
+                            kind: Macro::DefineProps,
+                            range: item.range(),
+                            preceding,
+                        });
+                    }
+                }
+                Some(Macro::DefineEmits) => {
+                    if let Some(preceding) = first_statement {
+                        misplaced.push(MisplacedMacro {
+                            kind: Macro::DefineEmits,
+                            range: item.range(),
+                            preceding,
+                        });
+                    }
+                    first_emits.get_or_insert(item.range());
+                }
+                None if !is_declaration_header(&item) => {
+                    first_statement.get_or_insert(item.range());
+                }
+                None => {}
+            }
+        }
+        misplaced
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = match state.kind {
+            Macro::DefineProps => "defineProps",
+            Macro::DefineEmits => "defineEmits",
+        };
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    <Emphasis>{name}</Emphasis>" should be declared at the top of the script."
+                },
+            )
+            .detail(
+                state.preceding,
+                markup! { "Move it before this statement." },
+            ),
+        )
+    }
+}
+
+/// Returns the macro called by `item`.
+///
+/// ```js
+/// defineProps();
+/// const props = defineProps();
+/// const props = withDefaults(defineProps(), {});
+/// ```
+fn macro_kind(item: &AnyJsModuleItem) -> Option<Macro> {
+    let AnyJsModuleItem::AnyJsStatement(statement) = item else {
+        return None;
+    };
+    let expression = match statement {
+        AnyJsStatement::JsExpressionStatement(statement) => statement.expression().ok()?,
+        AnyJsStatement::JsVariableStatement(statement) => {
+            let declarators = statement.declaration().ok()?.declarators();
+            if declarators.len() != 1 {
+                return None;
+            }
+            declarators
+                .first()?
+                .ok()?
+                .initializer()?
+                .expression()
+                .ok()?
+        }
+        _ => return None,
+    };
+    let mut call = expression
+        .omit_parentheses()
+        .as_js_call_expression()?
+        .clone();
+    if callee_name(&call)? == "withDefaults" {
+        let argument = call.arguments().ok()?.args().first()?.ok()?;
+        call = argument
+            .as_any_js_expression()?
+            .clone()
+            .omit_parentheses()
+            .as_js_call_expression()?
+            .clone();
+    }
+    match callee_name(&call)?.as_str() {
+        "defineProps" => Some(Macro::DefineProps),
+        "defineEmits" => Some(Macro::DefineEmits),
+        _ => None,
+    }
+}
+
+fn callee_name(call: &JsCallExpression) -> Option<String> {
+    let AnyJsExpression::JsIdentifierExpression(callee) = call.callee().ok()?.omit_parentheses()
+    else {
+        return None;
+    };
+    Some(
+        callee
+            .name()
+            .ok()?
+            .value_token()
+            .ok()?
+            .text_trimmed()
+            .to_string(),
+    )
+}
+
+/// Returns `true` if `item` may precede the macros: imports and type declarations.
+fn is_declaration_header(item: &AnyJsModuleItem) -> bool {
+    match item {
+        AnyJsModuleItem::JsImport(_) => true,
+        AnyJsModuleItem::AnyJsStatement(statement) => matches!(
+            statement,
+            AnyJsStatement::TsInterfaceDeclaration(_)
+                | AnyJsStatement::TsTypeAliasDeclaration(_)
+                | AnyJsStatement::TsDeclareStatement(_)
+                | AnyJsStatement::JsEmptyStatement(_)
+        ),
+        AnyJsModuleItem::JsExport(export) => export.export_clause().is_ok_and(|clause| {
+            clause
+                .as_any_js_declaration_clause()
+                .is_some_and(|declaration| {
+                    declaration.as_ts_interface_declaration().is_some()
+                        || declaration.as_ts_type_alias_declaration().is_some()
+                })
+        }),
+    }
+}
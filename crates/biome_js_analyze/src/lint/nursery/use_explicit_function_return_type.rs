@@ -0,0 +1,589 @@
+use crate::{services::semantic::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, AnyJsArrowFunctionParameters, AnyJsBinding,
+    AnyJsClassMemberName, AnyJsExpression, AnyJsFunctionBody, AnyJsLiteralExpression,
+    AnyJsStatement, AnyTsName, AnyTsReturnType, AnyTsType, JsArrowFunctionExpression,
+    JsClassDeclaration, JsClassExportDefaultDeclaration, JsFileSource, JsFunctionBody,
+    JsFunctionDeclaration, JsFunctionExportDefaultDeclaration, JsFunctionExpression,
+    JsMethodClassMember, JsMethodObjectMember, JsParameters, JsReturnStatement, JsSyntaxKind,
+    JsSyntaxNode, JsSyntaxToken, JsVariableDeclarator, TriviaPieceKind, TsReturnTypeAnnotation, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, BatchMutationExt, TextRange, WalkEvent,
+};
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Require explicit return types on functions and class methods.
+    ///
+    /// Explicit return types make the intent of a function clear,
+    /// and prevent an accidental change of the inferred type from leaking to the callers.
+    /// They also speed up type checking of large code bases.
+    ///
+    /// Function expressions and arrow functions that are typed by their context are ignored:
+    /// callbacks passed as arguments, functions assigned to an annotated variable,
+    /// and functions used in a `satisfies` or an `as` expression.
+    ///
+    /// The code action infers the return type from simple bodies:
+    /// literals, template literals, annotated variables and parameters, type assertions,
+    /// and functions that return nothing.
+    /// An async function gets a `Promise` of the inferred type.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// function greet() {
+    ///     return "Hello";
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// const log = (message: string) => {
+    ///     console.log(message);
+    /// };
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Counter {
+    ///     count: number = 0;
+    ///     get() {
+    ///         return this.count;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// function greet(): string {
+    ///     return "Hello";
+    /// }
+    /// const log = (message: string): void => {
+    ///     console.log(message);
+    /// };
+    /// [1, 2].map((n) => n * 2);
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "onlyExported": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### onlyExported
+    ///
+    /// When set to `true`, only the functions that are part of the public API of the module are checked:
+    /// exported functions, functions assigned to exported variables,
+    /// and non-private methods of exported classes.
+    ///
+    /// Default: `false`
+    ///
+    pub UseExplicitFunctionReturnType {
+        version: "next",
+        name: "useExplicitFunctionReturnType",
+        language: "ts",
+        sources: &[
+            RuleSource::EslintTypeScript("explicit-function-return-type"),
+            RuleSource::EslintTypeScript("explicit-module-boundary-types"),
+        ],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+declare_node_union! {
+    pub AnyJsFunctionWithReturnType =
+        JsArrowFunctionExpression
+        | JsFunctionDeclaration
+        | JsFunctionExportDefaultDeclaration
+        | JsFunctionExpression
+        | JsMethodClassMember
+        | JsMethodObjectMember
+}
+
+impl AnyJsFunctionWithReturnType {
+    fn return_type_annotation(&self) -> Option<TsReturnTypeAnnotation> {
+        match self {
+            Self::JsArrowFunctionExpression(func) => func.return_type_annotation(),
+            Self::JsFunctionDeclaration(func) => func.return_type_annotation(),
+            Self::JsFunctionExportDefaultDeclaration(func) => func.return_type_annotation(),
+            Self::JsFunctionExpression(func) => func.return_type_annotation(),
+            Self::JsMethodClassMember(func) => func.return_type_annotation(),
+            Self::JsMethodObjectMember(func) => func.return_type_annotation(),
+        }
+    }
+
+    fn async_token(&self) -> Option<JsSyntaxToken> {
+        match self {
+            Self::JsArrowFunctionExpression(func) => func.async_token(),
+            Self::JsFunctionDeclaration(func) => func.async_token(),
+            Self::JsFunctionExportDefaultDeclaration(func) => func.async_token(),
+            Self::JsFunctionExpression(func) => func.async_token(),
+            Self::JsMethodClassMember(func) => func.async_token(),
+            Self::JsMethodObjectMember(func) => func.async_token(),
+        }
+    }
+
+    fn star_token(&self) -> Option<JsSyntaxToken> {
+        match self {
+            Self::JsArrowFunctionExpression(_) => None,
+            Self::JsFunctionDeclaration(func) => func.star_token(),
+            Self::JsFunctionExportDefaultDeclaration(func) => func.star_token(),
+            Self::JsFunctionExpression(func) => func.star_token(),
+            Self::JsMethodClassMember(func) => func.star_token(),
+            Self::JsMethodObjectMember(func) => func.star_token(),
+        }
+    }
+
+    fn body(&self) -> Option<AnyJsFunctionBody> {
+        let body = match self {
+            Self::JsArrowFunctionExpression(func) => return func.body().ok(),
+            Self::JsFunctionDeclaration(func) => func.body(),
+            Self::JsFunctionExportDefaultDeclaration(func) => func.body(),
+            Self::JsFunctionExpression(func) => func.body(),
+            Self::JsMethodClassMember(func) => func.body(),
+            Self::JsMethodObjectMember(func) => func.body(),
+        };
+        body.ok().map(AnyJsFunctionBody::JsFunctionBody)
+    }
+
+    /// Returns the parameters of the function if they are enclosed in parentheses.
+    fn parameters(&self) -> Option<JsParameters> {
+        match self {
+            Self::JsArrowFunctionExpression(func) => match func.parameters().ok()? {
+                AnyJsArrowFunctionParameters::JsParameters(parameters) => Some(parameters),
+                AnyJsArrowFunctionParameters::AnyJsBinding(_) => None,
+            },
+            Self::JsFunctionDeclaration(func) => func.parameters().ok(),
+            Self::JsFunctionExportDefaultDeclaration(func) => func.parameters().ok(),
+            Self::JsFunctionExpression(func) => func.parameters().ok(),
+            Self::JsMethodClassMember(func) => func.parameters().ok(),
+            Self::JsMethodObjectMember(func) => func.parameters().ok(),
+        }
+    }
+
+    /// Returns the range from the start of the function to the end of its parameters.
+    fn signature_range(&self) -> TextRange {
+        let start = self.syntax().text_trimmed_range().start();
+        let end = match self {
+            Self::JsArrowFunctionExpression(func) => func
+                .parameters()
+                .map(|parameters| parameters.syntax().text_trimmed_range().end()),
+            Self::JsFunctionDeclaration(func) => func
+                .parameters()
+                .map(|parameters| parameters.syntax().text_trimmed_range().end()),
+            Self::JsFunctionExportDefaultDeclaration(func) => func
+                .parameters()
+                .map(|parameters| parameters.syntax().text_trimmed_range().end()),
+            Self::JsFunctionExpression(func) => func
+                .parameters()
+                .map(|parameters| parameters.syntax().text_trimmed_range().end()),
+            Self::JsMethodClassMember(func) => func
+                .parameters()
+                .map(|parameters| parameters.syntax().text_trimmed_range().end()),
+            Self::JsMethodObjectMember(func) => func
+                .parameters()
+                .map(|parameters| parameters.syntax().text_trimmed_range().end()),
+        };
+        end.map_or_else(
+            |_| self.syntax().text_trimmed_range(),
+            |end| TextRange::new(start, end),
+        )
+    }
+
+    fn with_return_type_annotation(
+        self,
+        parameters: JsParameters,
+        annotation: TsReturnTypeAnnotation,
+    ) -> Self {
+        let annotation = Some(annotation);
+        match self {
+            Self::JsArrowFunctionExpression(func) => func
+                .with_parameters(parameters.into())
+                .with_return_type_annotation(annotation)
+                .into(),
+            Self::JsFunctionDeclaration(func) => func
+                .with_parameters(parameters)
+                .with_return_type_annotation(annotation)
+                .into(),
+            Self::JsFunctionExportDefaultDeclaration(func) => func
+                .with_parameters(parameters)
+                .with_return_type_annotation(annotation)
+                .into(),
+            Self::JsFunctionExpression(func) => func
+                .with_parameters(parameters)
+                .with_return_type_annotation(annotation)
+                .into(),
+            Self::JsMethodClassMember(func) => func
+                .with_parameters(parameters)
+                .with_return_type_annotation(annotation)
+                .into(),
+            Self::JsMethodObjectMember(func) => func
+                .with_parameters(parameters)
+                .with_return_type_annotation(annotation)
+                .into(),
+        }
+    }
+}
+
+/// Options for the rule `useExplicitFunctionReturnType`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExplicitFunctionReturnTypeOptions {
+    /// If `true`, only the functions that are part of the public API of the module are checked.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub only_exported: bool,
+}
+
+fn is_default<T: Default + Eq>(value: &T) -> bool {
+    value == &T::default()
+}
+
+impl Rule for UseExplicitFunctionReturnType {
+    type Query = Semantic<AnyJsFunctionWithReturnType>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ExplicitFunctionReturnTypeOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let func = ctx.query();
+        let source_type = ctx.source_type::<JsFileSource>().language();
+        if !source_type.is_typescript() || source_type.is_definition_file() {
+            return None;
+        }
+        if func.return_type_annotation().is_some() || is_contextually_typed(func) {
+            return None;
+        }
+        if ctx.options().only_exported && !is_public_api(ctx.model(), func) {
+            return None;
+        }
+        Some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<RuleDiagnostic> {
+        let func = ctx.query();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                func.signature_range(),
+                markup! {
+                    "Missing return type on function."
+                },
+            )
+            .note(markup! {
+                "Declaring the return type makes the code self-documented and prevents unintended changes of the inferred type."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<JsRuleAction> {
+        let func = ctx.query();
+        if func.star_token().is_some() {
+            return None;
+        }
+        let parameters = func.parameters()?;
+        let mut return_type = infer_return_type(ctx.model(), &func.body()?)?;
+        if func.async_token().is_some() {
+            return_type = promise_of(return_type);
+        }
+        let return_type_text = return_type.syntax().text_trimmed().to_string();
+        // The trivia after `)` is moved after the return type.
+        let r_paren = parameters.r_paren_token().ok()?;
+        let return_type = return_type.append_trivia_pieces(r_paren.trailing_trivia().pieces())?;
+        let parameters = parameters.with_r_paren_token(r_paren.with_trailing_trivia([]));
+        let annotation = make::ts_return_type_annotation(
+            make::token(T![:]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            AnyTsReturnType::AnyTsType(return_type),
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(
+            func.clone(),
+            func.clone()
+                .with_return_type_annotation(parameters, annotation),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Add the return type "<Emphasis>{return_type_text}</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if the type of the function expression is provided by its context.
+fn is_contextually_typed(func: &AnyJsFunctionWithReturnType) -> bool {
+    if !matches!(
+        func,
+        AnyJsFunctionWithReturnType::JsArrowFunctionExpression(_)
+            | AnyJsFunctionWithReturnType::JsFunctionExpression(_)
+    ) {
+        return false;
+    }
+    let Some(parent) = func
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+    else {
+        return false;
+    };
+    match parent.kind() {
+        JsSyntaxKind::JS_CALL_ARGUMENT_LIST
+        | JsSyntaxKind::TS_AS_EXPRESSION
+        | JsSyntaxKind::TS_SATISFIES_EXPRESSION
+        | JsSyntaxKind::TS_TYPE_ASSERTION_EXPRESSION => true,
+        JsSyntaxKind::JS_INITIALIZER_CLAUSE => parent
+            .parent()
+            .and_then(JsVariableDeclarator::cast)
+            .is_some_and(|declarator| declarator.variable_annotation().is_some()),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the function is part of the public API of the module.
+fn is_public_api(model: &SemanticModel, func: &AnyJsFunctionWithReturnType) -> bool {
+    match func {
+        AnyJsFunctionWithReturnType::JsFunctionExportDefaultDeclaration(_) => true,
+        AnyJsFunctionWithReturnType::JsFunctionDeclaration(func) => {
+            is_exported_binding(model, func.id().ok().as_ref())
+        }
+        AnyJsFunctionWithReturnType::JsArrowFunctionExpression(_)
+        | AnyJsFunctionWithReturnType::JsFunctionExpression(_) => {
+            is_exported_expression(model, func.syntax())
+        }
+        AnyJsFunctionWithReturnType::JsMethodClassMember(method) => {
+            if method.modifiers().iter().any(|modifier| {
+                modifier
+                    .as_ts_accessibility_modifier()
+                    .is_some_and(|modifier| modifier.is_private())
+            }) || matches!(
+                method.name(),
+                Ok(AnyJsClassMemberName::JsPrivateClassMemberName(_))
+            ) {
+                return false;
+            }
+            method.syntax().ancestors().any(|ancestor| {
+                if JsClassExportDefaultDeclaration::can_cast(ancestor.kind()) {
+                    true
+                } else if let Some(class) = JsClassDeclaration::cast_ref(&ancestor) {
+                    is_exported_binding(model, class.id().ok().as_ref())
+                } else {
+                    false
+                }
+            })
+        }
+        AnyJsFunctionWithReturnType::JsMethodObjectMember(method) => method
+            .syntax()
+            .grand_parent()
+            .is_some_and(|object| is_exported_expression(model, &object)),
+    }
+}
+
+fn is_exported_binding(model: &SemanticModel, binding: Option<&AnyJsBinding>) -> bool {
+    binding
+        .and_then(|binding| binding.as_js_identifier_binding())
+        .is_some_and(|binding| model.is_exported(binding))
+}
+
+/// Returns `true` if the expression is default-exported or assigned to an exported variable.
+fn is_exported_expression(model: &SemanticModel, expression: &JsSyntaxNode) -> bool {
+    let Some(parent) = expression
+        .ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+    else {
+        return false;
+    };
+    match parent.kind() {
+        JsSyntaxKind::JS_EXPORT_DEFAULT_EXPRESSION_CLAUSE => true,
+        JsSyntaxKind::JS_INITIALIZER_CLAUSE => parent
+            .parent()
+            .and_then(JsVariableDeclarator::cast)
+            .and_then(|declarator| declarator.id().ok())
+            .is_some_and(|id| is_exported_binding(model, id.as_any_js_binding())),
+        _ => false,
+    }
+}
+
+/// Infers the return type of a function body.
+///
+/// Returns `None` if the return type cannot be determined with confidence.
+fn infer_return_type(model: &SemanticModel, body: &AnyJsFunctionBody) -> Option<AnyTsType> {
+    let body = match body {
+        AnyJsFunctionBody::AnyJsExpression(expression) => {
+            return infer_expression_type(model, expression)
+        }
+        AnyJsFunctionBody::JsFunctionBody(body) => body,
+    };
+    let returns = own_return_statements(body);
+    if returns.iter().all(|ret| ret.argument().is_none()) {
+        return Some(make::ts_void_type(make::token(T![void])).into());
+    }
+    // A function that can reach the end of its body also returns `undefined`.
+    let ends_with_exit = body.statements().last().is_some_and(|statement| {
+        matches!(
+            statement,
+            AnyJsStatement::JsReturnStatement(_) | AnyJsStatement::JsThrowStatement(_)
+        )
+    });
+    if !ends_with_exit {
+        return None;
+    }
+    let mut types: Vec<AnyTsType> = Vec::new();
+    for ret in returns {
+        let ty = match ret.argument() {
+            Some(argument) => infer_expression_type(model, &argument)?,
+            None => make::ts_undefined_type(make::token(T![undefined])).into(),
+        };
+        let text = ty.syntax().text_trimmed().to_string();
+        if !types
+            .iter()
+            .any(|existing| existing.syntax().text_trimmed() == text.as_str())
+        {
+            types.push(ty);
+        }
+    }
+    union_of(types)
+}
+
+/// Returns the `return` statements of `body`, excluding those of nested functions.
+fn own_return_statements(body: &JsFunctionBody) -> Vec<JsReturnStatement> {
+    let mut returns = Vec::new();
+    let mut iter = body.syntax().preorder();
+    while let Some(event) = iter.next() {
+        let WalkEvent::Enter(node) = event else {
+            continue;
+        };
+        if node != *body.syntax() && is_function_boundary(node.kind()) {
+            iter.skip_subtree();
+        } else if let Some(ret) = JsReturnStatement::cast(node) {
+            returns.push(ret);
+        }
+    }
+    returns
+}
+
+fn is_function_boundary(kind: JsSyntaxKind) -> bool {
+    matches!(
+        kind,
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_METHOD_CLASS_MEMBER
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_GETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_CLASS_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+            | JsSyntaxKind::JS_CLASS_DECLARATION
+            | JsSyntaxKind::JS_CLASS_EXPRESSION
+    )
+}
+
+fn infer_expression_type(model: &SemanticModel, expression: &AnyJsExpression) -> Option<AnyTsType> {
+    match expression.clone().omit_parentheses() {
+        AnyJsExpression::AnyJsLiteralExpression(literal) => Some(match literal {
+            AnyJsLiteralExpression::JsStringLiteralExpression(_) => {
+                make::ts_string_type(make::token(T![string])).into()
+            }
+            AnyJsLiteralExpression::JsNumberLiteralExpression(_) => {
+                make::ts_number_type(make::token(T![number])).into()
+            }
+            AnyJsLiteralExpression::JsBigintLiteralExpression(_) => {
+                make::ts_bigint_type(make::token(T![bigint])).into()
+            }
+            AnyJsLiteralExpression::JsBooleanLiteralExpression(_) => {
+                make::ts_boolean_type(make::token(T![boolean])).into()
+            }
+            AnyJsLiteralExpression::JsNullLiteralExpression(_) => {
+                make::ts_null_literal_type(make::token(T![null])).into()
+            }
+            AnyJsLiteralExpression::JsRegexLiteralExpression(_) => {
+                make::ts_reference_type(AnyTsName::JsReferenceIdentifier(
+                    make::js_reference_identifier(make::ident("RegExp")),
+                ))
+                .build()
+                .into()
+            }
+        }),
+        AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+            Some(make::ts_string_type(make::token(T![string])).into())
+        }
+        AnyJsExpression::TsAsExpression(expression) => {
+            let ty = expression.ty().ok()?;
+            // `as const` doesn't give a type that can be written back.
+            if ty
+                .as_ts_reference_type()
+                .and_then(|reference| reference.name().ok())
+                .is_some_and(|name| name.syntax().text_trimmed() == "const")
+            {
+                return None;
+            }
+            ty.trim_trivia()
+        }
+        AnyJsExpression::JsIdentifierExpression(identifier) => {
+            let reference = identifier.name().ok()?;
+            if reference.is_undefined() {
+                return Some(make::ts_undefined_type(make::token(T![undefined])).into());
+            }
+            let binding = model.binding(&reference)?;
+            let annotation = match binding.tree().declaration()? {
+                AnyJsBindingDeclaration::JsVariableDeclarator(declarator) => {
+                    declarator.variable_annotation()?.type_annotation().ok()??
+                }
+                AnyJsBindingDeclaration::JsFormalParameter(parameter) => {
+                    // An optional parameter may be `undefined`.
+                    if parameter.question_mark_token().is_some() {
+                        return None;
+                    }
+                    parameter.type_annotation()?
+                }
+                _ => return None,
+            };
+            annotation.ty().ok()?.trim_trivia()
+        }
+        _ => None,
+    }
+}
+
+fn union_of(mut types: Vec<AnyTsType>) -> Option<AnyTsType> {
+    if types.len() <= 1 {
+        return types.pop();
+    }
+    let separators = (0..types.len() - 1).map(|_| make::token_decorated_with_space(T![|]));
+    Some(
+        make::ts_union_type(make::ts_union_type_variant_list(types, separators))
+            .build()
+            .into(),
+    )
+}
+
+fn promise_of(ty: AnyTsType) -> AnyTsType {
+    make::ts_reference_type(AnyTsName::JsReferenceIdentifier(
+        make::js_reference_identifier(make::ident("Promise")),
+    ))
+    .with_type_arguments(make::ts_type_arguments(
+        make::token(T![<]),
+        make::ts_type_argument_list([ty], []),
+        make::token(T![>]),
+    ))
+    .build()
+    .into()
+}
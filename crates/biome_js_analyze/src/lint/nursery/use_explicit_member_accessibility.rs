@@ -0,0 +1,384 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsClassMember, AnyJsClassMemberName, AnyJsFormalParameter, JsFileSource, JsLanguage,
+    JsSyntaxNode, JsSyntaxToken, Modifier, TextRange, TsAccessibilityModifier, TsPropertyParameter,
+    T,
+};
+use biome_rowan::{
+    chain_trivia_pieces, declare_node_union, AstNode, AstNodeList, BatchMutation, BatchMutationExt,
+    TriviaPieceKind,
+};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Require or disallow the accessibility modifiers on the class members.
+    ///
+    /// TypeScript class members are public by default.
+    /// Some teams prefer to always write the accessibility modifier,
+    /// so that the visibility of every member is explicit,
+    /// while other teams prefer to omit the redundant `public` modifier.
+    ///
+    /// The members with a private name, such as `#name`, and the index signatures are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Animal {
+    ///     name: string;
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Animal {
+    ///     constructor(readonly name: string) {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// class Animal {
+    ///     public constructor(private readonly name: string) {}
+    ///
+    ///     protected move(distance: number) {}
+    ///
+    ///     #secret = 0;
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `accessibility`
+    ///
+    /// - `"explicit"`: every class member must have an accessibility modifier. This is the default.
+    /// - `"noPublic"`: the class members must not use the `public` modifier.
+    ///   The parameter properties that only have the `public` modifier are allowed
+    ///   because removing the modifier would turn them into plain parameters.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "accessibility": "noPublic"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseExplicitMemberAccessibility {
+        version: "next",
+        name: "useExplicitMemberAccessibility",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("explicit-member-accessibility")],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useExplicitMemberAccessibility`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ExplicitMemberAccessibilityOptions {
+    pub accessibility: MemberAccessibility,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum MemberAccessibility {
+    /// Every class member must have an accessibility modifier.
+    #[default]
+    Explicit,
+    /// The class members must not use the `public` modifier.
+    NoPublic,
+}
+
+declare_node_union! {
+    pub AnyAccessibleMember = AnyJsClassMember | TsPropertyParameter
+}
+
+pub enum AccessibilityIssue {
+    /// The member doesn't have an accessibility modifier
+    Missing,
+    /// The member has an unnecessary `public` modifier
+    Public(TsAccessibilityModifier),
+}
+
+impl Rule for UseExplicitMemberAccessibility {
+    type Query = Ast<AnyAccessibleMember>;
+    type State = AccessibilityIssue;
+    type Signals = Option<Self::State>;
+    type Options = ExplicitMemberAccessibilityOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return None;
+        }
+        let member = ctx.query();
+        let modifiers = member.modifier_list()?;
+        let accessibility = modifiers.children().find_map(TsAccessibilityModifier::cast);
+        match (ctx.options().accessibility, accessibility) {
+            (MemberAccessibility::Explicit, None) => Some(AccessibilityIssue::Missing),
+            (MemberAccessibility::NoPublic, Some(accessibility)) if accessibility.is_public() => {
+                // Without its only modifier, a parameter property becomes a plain parameter
+                let is_only_parameter_modifier =
+                    matches!(member, AnyAccessibleMember::TsPropertyParameter(_))
+                        && modifiers.children().count() == 1;
+                (!is_only_parameter_modifier).then_some(AccessibilityIssue::Public(accessibility))
+            }
+            _ => None,
+        }
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            AccessibilityIssue::Missing => RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().name_range()?,
+                markup! {
+                    "This class member doesn't have an accessibility modifier."
+                },
+            )
+            .note(markup! {
+                "The accessibility modifiers document the visibility of the class members."
+            }),
+            AccessibilityIssue::Public(accessibility) => RuleDiagnostic::new(
+                rule_category!(),
+                accessibility.range(),
+                markup! {
+                    "The "<Emphasis>"public"</Emphasis>" modifier is unnecessary."
+                },
+            )
+            .note(markup! {
+                "The class members are public by default."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        let message = match state {
+            AccessibilityIssue::Missing => {
+                insert_public_modifier(&mut mutation, ctx.query())?;
+                markup! { "Add the modifier "<Emphasis>"public"</Emphasis>"." }
+            }
+            AccessibilityIssue::Public(accessibility) => {
+                let modifier_token = accessibility.modifier_token().ok()?;
+                // Keep the comments and the line breaks that precede the modifier
+                let next_token = modifier_token.next_token()?;
+                let new_next_token = next_token.with_leading_trivia_pieces(chain_trivia_pieces(
+                    modifier_token.leading_trivia().pieces(),
+                    next_token.leading_trivia().pieces(),
+                ));
+                mutation.replace_token_discard_trivia(next_token, new_next_token);
+                mutation.remove_node(accessibility.clone());
+                markup! { "Remove the modifier "<Emphasis>"public"</Emphasis>"." }
+            }
+        };
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().to_applicability(),
+            message.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+impl AnyAccessibleMember {
+    /// Returns the list of modifiers of the member,
+    /// or `None` if the member can't have an accessibility modifier.
+    fn modifier_list(&self) -> Option<JsSyntaxNode> {
+        let modifiers = match self {
+            Self::AnyJsClassMember(member) => {
+                if matches!(
+                    member.name(),
+                    Ok(Some(AnyJsClassMemberName::JsPrivateClassMemberName(_)))
+                ) {
+                    return None;
+                }
+                match member {
+                    AnyJsClassMember::JsConstructorClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::JsGetterClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::JsMethodClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::JsPropertyClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::JsSetterClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::TsConstructorSignatureClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::TsGetterSignatureClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::TsMethodSignatureClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::TsPropertySignatureClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::TsInitializedPropertySignatureClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::TsSetterSignatureClassMember(member) => {
+                        member.modifiers().into_syntax()
+                    }
+                    AnyJsClassMember::JsBogusMember(_)
+                    | AnyJsClassMember::JsEmptyClassMember(_)
+                    | AnyJsClassMember::JsStaticInitializationBlockClassMember(_)
+                    | AnyJsClassMember::TsIndexSignatureClassMember(_) => return None,
+                }
+            }
+            Self::TsPropertyParameter(parameter) => parameter.modifiers().into_syntax(),
+        };
+        Some(modifiers)
+    }
+
+    fn name_range(&self) -> Option<TextRange> {
+        match self {
+            Self::AnyJsClassMember(member) => Some(member.name().ok()??.range()),
+            Self::TsPropertyParameter(parameter) => match parameter.formal_parameter().ok()? {
+                AnyJsFormalParameter::JsFormalParameter(parameter) => {
+                    Some(parameter.binding().ok()?.range())
+                }
+                AnyJsFormalParameter::JsBogusParameter(_) => None,
+            },
+        }
+    }
+}
+
+/// Inserts the modifier `public` in the modifiers of `member`, after its decorators.
+fn insert_public_modifier(
+    mutation: &mut BatchMutation<JsLanguage>,
+    member: &AnyAccessibleMember,
+) -> Option<()> {
+    match member {
+        AnyAccessibleMember::AnyJsClassMember(member) => match member {
+            AnyJsClassMember::JsConstructorClassMember(member) => insert_modifier(
+                mutation,
+                member.modifiers(),
+                make::js_constructor_modifier_list,
+            ),
+            AnyJsClassMember::JsGetterClassMember(member) => {
+                insert_modifier(mutation, member.modifiers(), make::js_method_modifier_list)
+            }
+            AnyJsClassMember::JsMethodClassMember(member) => {
+                insert_modifier(mutation, member.modifiers(), make::js_method_modifier_list)
+            }
+            AnyJsClassMember::JsPropertyClassMember(member) => insert_modifier(
+                mutation,
+                member.modifiers(),
+                make::js_property_modifier_list,
+            ),
+            AnyJsClassMember::JsSetterClassMember(member) => {
+                insert_modifier(mutation, member.modifiers(), make::js_method_modifier_list)
+            }
+            AnyJsClassMember::TsConstructorSignatureClassMember(member) => insert_modifier(
+                mutation,
+                member.modifiers(),
+                make::js_constructor_modifier_list,
+            ),
+            AnyJsClassMember::TsGetterSignatureClassMember(member) => insert_modifier(
+                mutation,
+                member.modifiers(),
+                make::ts_method_signature_modifier_list,
+            ),
+            AnyJsClassMember::TsMethodSignatureClassMember(member) => insert_modifier(
+                mutation,
+                member.modifiers(),
+                make::ts_method_signature_modifier_list,
+            ),
+            AnyJsClassMember::TsPropertySignatureClassMember(member) => insert_modifier(
+                mutation,
+                member.modifiers(),
+                make::ts_property_signature_modifier_list,
+            ),
+            AnyJsClassMember::TsInitializedPropertySignatureClassMember(member) => insert_modifier(
+                mutation,
+                member.modifiers(),
+                make::ts_property_signature_modifier_list,
+            ),
+            AnyJsClassMember::TsSetterSignatureClassMember(member) => insert_modifier(
+                mutation,
+                member.modifiers(),
+                make::ts_method_signature_modifier_list,
+            ),
+            AnyJsClassMember::JsBogusMember(_)
+            | AnyJsClassMember::JsEmptyClassMember(_)
+            | AnyJsClassMember::JsStaticInitializationBlockClassMember(_)
+            | AnyJsClassMember::TsIndexSignatureClassMember(_) => None,
+        },
+        AnyAccessibleMember::TsPropertyParameter(parameter) => insert_modifier(
+            mutation,
+            parameter.modifiers(),
+            make::ts_property_parameter_modifier_list,
+        ),
+    }
+}
+
+/// Replaces `modifiers` with a list built by `make_list` where the modifier `public` is inserted after the decorators.
+///
+/// The modifier takes the leading trivia of the token that follows it, so that it starts on the same line.
+fn insert_modifier<List, Item>(
+    mutation: &mut BatchMutation<JsLanguage>,
+    modifiers: List,
+    make_list: impl FnOnce(Vec<Item>) -> List,
+) -> Option<()>
+where
+    List: AstNode<Language = JsLanguage> + AstNodeList<Language = JsLanguage, Node = Item>,
+    Item: AstNode<Language = JsLanguage> + From<TsAccessibilityModifier>,
+    for<'a> Modifier: From<&'a Item>,
+{
+    let mut items: Vec<_> = modifiers.iter().collect();
+    let position = items
+        .iter()
+        .take_while(|item| Modifier::from(*item) == Modifier::Decorator)
+        .count();
+    let next_token = match items.get(position) {
+        Some(item) => item.syntax().first_token()?,
+        None => next_token_after(modifiers.syntax())?,
+    };
+    let public_token = make::token(T![public])
+        .with_leading_trivia_pieces(next_token.leading_trivia().pieces())
+        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+    match items.get_mut(position) {
+        Some(item) => *item = item.clone().with_leading_trivia_pieces([])?,
+        None => mutation
+            .replace_token_discard_trivia(next_token.clone(), next_token.with_leading_trivia([])),
+    }
+    items.insert(
+        position,
+        Item::from(make::ts_accessibility_modifier(public_token)),
+    );
+    mutation.replace_node_discard_trivia(modifiers, make_list(items));
+    Some(())
+}
+
+/// Returns the first token that follows the list of modifiers `node`.
+///
+/// An empty list of modifiers is the first child of the member, so its first token follows the list.
+fn next_token_after(node: &JsSyntaxNode) -> Option<JsSyntaxToken> {
+    match node.last_token() {
+        Some(last_token) => last_token.next_token(),
+        None => node.parent()?.first_token(),
+    }
+}
@@ -1,10 +1,16 @@
-use biome_analyze::{context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleSource};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
 use biome_aria::AriaRoles;
 use biome_console::markup;
-use biome_js_syntax::{jsx_ext::AnyJsxElement, AnyJsxAttributeValue};
-use biome_rowan::AstNode;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    jsx_ext::AnyJsxElement, AnyJsExpression, AnyJsLiteralExpression, AnyJsxAttribute,
+    AnyJsxAttributeName, AnyJsxAttributeValue, T,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt, TriviaPieceKind};
 
-use crate::services::aria::Aria;
+use crate::{services::aria::Aria, JsRuleAction};
 
 declare_rule! {
     /// Elements with an interactive role and interaction handlers must be focusable.
@@ -15,6 +21,9 @@ declare_rule! {
     /// You could also consider switching from an interactive role to its semantic HTML element
     /// instead.
     ///
+    /// The code action adds `tabIndex={0}` to the element, which makes it focusable
+    /// in the order of the document.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -43,6 +52,7 @@ declare_rule! {
         language: "jsx",
         sources: &[RuleSource::EslintJsxA11y("interactive-support-focus")],
         recommended: true,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
@@ -94,6 +104,49 @@ impl Rule for UseFocusableInteractive {
             }),
         )
     }
+
+    fn action(ctx: &RuleContext<Self>, _: &Self::State) -> Option<JsRuleAction> {
+        let node = ctx.query();
+        let mut mutation = ctx.root().begin();
+        let old_attribute_list = node.attributes();
+        let mut new_attribute_list: Vec<_> = old_attribute_list.iter().collect();
+        // The trailing trivia of the last attribute, such as the space before `/>`,
+        // is moved after the new attribute
+        let last_attribute = new_attribute_list.pop()?;
+        let trailing_trivia = last_attribute.syntax().last_trailing_trivia()?;
+        new_attribute_list.push(last_attribute.trim_trailing_trivia()?);
+        let zero = AnyJsExpression::AnyJsLiteralExpression(
+            AnyJsLiteralExpression::JsNumberLiteralExpression(make::js_number_literal_expression(
+                make::js_number_literal(0),
+            )),
+        );
+        let tabindex_attribute = make::jsx_attribute(AnyJsxAttributeName::JsxName(make::jsx_name(
+            make::jsx_ident("tabIndex").with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+        )))
+        .with_initializer(make::jsx_attribute_initializer_clause(
+            make::token(T![=]),
+            AnyJsxAttributeValue::JsxExpressionAttributeValue(
+                make::jsx_expression_attribute_value(
+                    make::token(T!['{']),
+                    zero,
+                    make::token(T!['}']),
+                ),
+            ),
+        ))
+        .build()
+        .with_trailing_trivia_pieces(trailing_trivia.pieces())?;
+        new_attribute_list.push(AnyJsxAttribute::JsxAttribute(tabindex_attribute));
+        mutation.replace_node_discard_trivia(
+            old_attribute_list,
+            make::jsx_attribute_list(new_attribute_list),
+        );
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().to_applicability(),
+            markup! { "Add the attribute "<Emphasis>"tabIndex={0}"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
 }
 
 /// Checks if the given role attribute value is interactive or not based on ARIA roles.
@@ -0,0 +1,87 @@
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Ensure the `preconnect` attribute is used when using Google Fonts.
+    ///
+    /// When using Google Fonts, the `rel="preconnect"` attribute should be added to the `<link>`
+    /// that targets `https://fonts.gstatic.com`, so that the browser establishes the connection
+    /// to the font server as early as possible.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <link href="https://fonts.gstatic.com" />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <link rel="preload" href="https://fonts.gstatic.com" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <link rel="preconnect" href="https://fonts.gstatic.com" />
+    /// ```
+    ///
+    /// ```jsx
+    /// <link href="/logo.svg" rel="icon" />
+    /// ```
+    ///
+    pub UseGoogleFontPreconnect {
+        version: "next",
+        name: "useGoogleFontPreconnect",
+        language: "jsx",
+        sources: &[RuleSource::EslintNext("google-font-preconnect")],
+        domains: &[RuleDomain::Next],
+        recommended: false,
+    }
+}
+
+impl Rule for UseGoogleFontPreconnect {
+    type Query = Ast<AnyJsxElement>;
+    type State = ();
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let name = node.name().ok()?;
+        if name.as_jsx_name()?.value_token().ok()?.text_trimmed() != "link" {
+            return None;
+        }
+        let href = node.find_attribute_by_name("href")?.as_static_value()?;
+        if !href
+            .as_string_constant()?
+            .starts_with("https://fonts.gstatic.com")
+        {
+            return None;
+        }
+        let is_preconnect = node
+            .find_attribute_by_name("rel")
+            .and_then(|rel| rel.as_static_value())
+            .is_some_and(|rel| rel.as_string_constant() == Some("preconnect"));
+        (!is_preconnect).then_some(())
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The attribute "<Emphasis>"rel=\"preconnect\""</Emphasis>" is missing from the Google Font."
+                },
+            )
+            .note(markup! {
+                "Preconnecting to the font server allows the browser to download the fonts earlier."
+            }),
+        )
+    }
+}
@@ -0,0 +1,224 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{JsBigintLiteralExpression, JsNumberLiteralExpression, JsSyntaxToken};
+use biome_rowan::{declare_node_union, AstNode, BatchMutationExt, SyntaxResult};
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Enforce the use of numeric separators in long numeric literals.
+    ///
+    /// Numeric separators `_` make long numbers easier to read.
+    /// The digits are grouped by three in decimal numbers, by two in hexadecimal numbers,
+    /// and by four in binary and octal numbers.
+    ///
+    /// The rule ignores the literals that already contain separators,
+    /// and the legacy octal literals such as `0777`, which don't allow separators.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// const budget = 1000000;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const color = 0xFFAA33;
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// const mask = 0b10101010;
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// const budget = 1_000_000;
+    /// const color = 0xFF_AA_33;
+    /// const mask = 0b1010_1010;
+    /// const port = 8080;
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `minimumDigits`
+    ///
+    /// The minimum number of digits of the integer part or the fractional part of a number
+    /// from which separators are required.
+    /// Defaults to `5`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "minimumDigits": 7
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseNumericSeparators {
+        version: "next",
+        name: "useNumericSeparators",
+        language: "js",
+        sources: &[RuleSource::EslintUnicorn("numeric-separators-style")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyNumericLiteral = JsNumberLiteralExpression | JsBigintLiteralExpression
+}
+
+impl AnyNumericLiteral {
+    fn value_token(&self) -> SyntaxResult<JsSyntaxToken> {
+        match self {
+            Self::JsNumberLiteralExpression(literal) => literal.value_token(),
+            Self::JsBigintLiteralExpression(literal) => literal.value_token(),
+        }
+    }
+}
+
+/// Options for the rule `useNumericSeparators`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NumericSeparatorsOptions {
+    /// The minimum number of digits from which separators are required.
+    pub minimum_digits: u8,
+}
+
+impl Default for NumericSeparatorsOptions {
+    fn default() -> Self {
+        Self { minimum_digits: 5 }
+    }
+}
+
+impl Rule for UseNumericSeparators {
+    type Query = Ast<AnyNumericLiteral>;
+    /// The literal with separators.
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = NumericSeparatorsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let token = ctx.query().value_token().ok()?;
+        let minimum_digits = ctx.options().minimum_digits as usize;
+        with_separators(token.text_trimmed(), minimum_digits.max(1))
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "This long number doesn't use numeric separators."
+                },
+            )
+            .note(markup! {
+                "Numeric separators make long numbers easier to read."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, new_text: &Self::State) -> Option<JsRuleAction> {
+        let token = ctx.query().value_token().ok()?;
+        let new_token = JsSyntaxToken::new_detached(token.kind(), new_text, [], []);
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(token, new_token);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Add numeric separators: "<Emphasis>{new_text}</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `text` with the digits grouped by separators,
+/// or `None` if `text` doesn't need separators.
+fn with_separators(text: &str, minimum_digits: usize) -> Option<String> {
+    if text.contains('_') {
+        return None;
+    }
+    let (number, suffix) = match text.strip_suffix('n') {
+        Some(number) => (number, "n"),
+        None => (text, ""),
+    };
+    let prefix = number.get(..2).map(str::to_ascii_lowercase);
+    let result = match prefix.as_deref() {
+        Some(radix @ ("0x" | "0b" | "0o")) => {
+            let group_length = if radix == "0x" { 2 } else { 4 };
+            let digits = &number[2..];
+            if digits.len() < minimum_digits {
+                return None;
+            }
+            format!("{}{}", &number[..2], group_from_end(digits, group_length))
+        }
+        _ => {
+            // Legacy octal literals and numbers with a leading zero don't allow separators.
+            if number.len() > 1 && number.starts_with('0') && number.as_bytes()[1].is_ascii_digit()
+            {
+                return None;
+            }
+            let (mantissa, exponent) = match number.find(['e', 'E']) {
+                Some(index) => number.split_at(index),
+                None => (number, ""),
+            };
+            let (integer, fraction) = match mantissa.find('.') {
+                Some(index) => (&mantissa[..index], Some(&mantissa[index + 1..])),
+                None => (mantissa, None),
+            };
+            let mut result = if integer.len() >= minimum_digits {
+                group_from_end(integer, 3)
+            } else {
+                integer.to_string()
+            };
+            if let Some(fraction) = fraction {
+                result.push('.');
+                if fraction.len() >= minimum_digits {
+                    result.push_str(&group_from_start(fraction, 3));
+                } else {
+                    result.push_str(fraction);
+                }
+            }
+            result.push_str(exponent);
+            result
+        }
+    };
+    let result = format!("{result}{suffix}");
+    (result != text).then_some(result)
+}
+
+/// Groups `digits` by `group_length`, starting from the last digit: `1_000_000`.
+fn group_from_end(digits: &str, group_length: usize) -> String {
+    let mut result = String::with_capacity(digits.len() + digits.len() / group_length);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % group_length == 0 {
+            result.push('_');
+        }
+        result.push(digit);
+    }
+    result
+}
+
+/// Groups `digits` by `group_length`, starting from the first digit: `.000_001`.
+fn group_from_start(digits: &str, group_length: usize) -> String {
+    let mut result = String::with_capacity(digits.len() + digits.len() / group_length);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && index % group_length == 0 {
+            result.push('_');
+        }
+        result.push(digit);
+    }
+    result
+}
@@ -0,0 +1,194 @@
+use crate::{services::semantic::Semantic, JsRuleAction};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    global_identifier, AnyJsCallArgument, AnyJsExpression, AnyJsMemberExpression,
+    AnyJsObjectMember, JsArrowFunctionExpression, JsCallExpression, JsExpressionStatement,
+    JsSyntaxNode, T,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, TriviaPieceKind};
+
+declare_rule! {
+    /// Prefer object spread over `Object.assign()` when constructing a new object.
+    ///
+    /// Calling `Object.assign()` with an object literal as first argument creates a new object.
+    /// The object spread syntax is more concise and doesn't require a function call.
+    ///
+    /// The rule reports the calls of the global `Object.assign()` whose first argument is an object literal.
+    /// When the object literal is empty, a code fix replaces the call with an object spread.
+    /// When it isn't empty, the call is reported without a code fix.
+    /// The calls with a spread argument, such as `Object.assign({}, ...sources)`, are ignored,
+    /// because there is no equivalent object spread.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,expect_diagnostic
+    /// Object.assign({}, foo);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// Object.assign({}, { foo: "bar" }, baz);
+    /// ```
+    ///
+    /// ```js,expect_diagnostic
+    /// Object.assign({ foo: "bar" }, baz);
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// ({ ...foo });
+    /// ```
+    ///
+    /// ```js
+    /// Object.assign(foo, bar);
+    /// ```
+    ///
+    /// ```js
+    /// Object.assign({}, ...sources);
+    /// ```
+    ///
+    pub UseObjectSpread {
+        version: "next",
+        name: "useObjectSpread",
+        language: "js",
+        sources: &[RuleSource::Eslint("prefer-object-spread")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+impl Rule for UseObjectSpread {
+    type Query = Semantic<JsCallExpression>;
+    /// Whether the target of `Object.assign()` is an empty object literal.
+    type State = bool;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        if call.is_optional_chain() {
+            return None;
+        }
+        let callee =
+            AnyJsMemberExpression::cast(call.callee().ok()?.omit_parentheses().into_syntax())?;
+        if callee.member_name()?.text() != "assign" {
+            return None;
+        }
+        let (reference, name) = global_identifier(&callee.object().ok()?.omit_parentheses())?;
+        if name.text() != "Object" || ctx.model().binding(&reference).is_some() {
+            return None;
+        }
+        let mut arguments = call.arguments().ok()?.args().iter();
+        let target = arguments.next()?.ok()?;
+        let is_empty_target = target
+            .as_any_js_expression()?
+            .clone()
+            .omit_parentheses()
+            .as_js_object_expression()?
+            .members()
+            .is_empty();
+        for argument in arguments {
+            if matches!(argument.ok()?, AnyJsCallArgument::JsSpread(_)) {
+                return None;
+            }
+        }
+        Some(is_empty_target)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, _: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "Use an object spread instead of "<Emphasis>"Object.assign()"</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The object spread syntax is more concise than "<Emphasis>"Object.assign()"</Emphasis>" when creating a new object."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, is_empty_target: &Self::State) -> Option<JsRuleAction> {
+        if !is_empty_target {
+            return None;
+        }
+        let call = ctx.query();
+        let mut members = Vec::new();
+        for argument in call.arguments().ok()?.args().iter().skip(1) {
+            let argument = argument
+                .ok()?
+                .as_any_js_expression()?
+                .clone()
+                .trim_trivia()?;
+            members.push(AnyJsObjectMember::from(make::js_spread(
+                make::token(T![...]),
+                argument,
+            )));
+        }
+        let separators = (1..members.len())
+            .map(|_| make::token(T![,]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]));
+        let object = if members.is_empty() {
+            make::js_object_expression(
+                make::token(T!['{']),
+                make::js_object_member_list([], []),
+                make::token(T!['}']),
+            )
+        } else {
+            make::js_object_expression(
+                make::token(T!['{']).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                make::js_object_member_list(members, separators),
+                make::token(T!['}']).with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+            )
+        };
+        let mut new_expression = AnyJsExpression::from(object);
+        if is_first_in_statement_or_arrow_body(call.syntax()) {
+            new_expression = make::js_parenthesized_expression(
+                make::token(T!['(']),
+                new_expression,
+                make::token(T![')']),
+            )
+            .into();
+        }
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node(AnyJsExpression::from(call.clone()), new_expression);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Replace "<Emphasis>"Object.assign()"</Emphasis>" with an object spread." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns `true` if `node` starts an expression statement or the body of an arrow function.
+///
+/// An object expression needs parentheses in these positions,
+/// otherwise it's parsed as a block.
+fn is_first_in_statement_or_arrow_body(node: &JsSyntaxNode) -> bool {
+    let mut node = node.clone();
+    while let Some(parent) = node.parent() {
+        if JsExpressionStatement::can_cast(parent.kind()) {
+            return true;
+        }
+        if let Some(arrow) = JsArrowFunctionExpression::cast_ref(&parent) {
+            return arrow.body().is_ok_and(|body| body.syntax() == &node);
+        }
+        if !AnyJsExpression::can_cast(parent.kind())
+            || parent.text_trimmed_range().start() != node.text_trimmed_range().start()
+        {
+            return false;
+        }
+        node = parent;
+    }
+    false
+}
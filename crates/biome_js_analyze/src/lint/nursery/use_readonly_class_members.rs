@@ -0,0 +1,364 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_js_factory::make;
+use biome_js_syntax::{
+    AnyJsClass, AnyJsClassMember, AnyJsClassMemberName, AnyJsConstructorParameter, AnyJsExpression,
+    AnyJsFormalParameter, AnyJsName, AnyJsPropertyModifier, AnyTsPropertyParameterModifier,
+    JsComputedMemberAssignment, JsFileSource, JsPropertyClassMember, JsStaticMemberAssignment,
+    JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, Modifier, TextRange, TsPropertyParameter, T,
+};
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, TriviaPieceKind,
+};
+
+declare_rule! {
+    /// Require private class members that are never reassigned to be marked as `readonly`.
+    ///
+    /// A private member can only be assigned from the inside of its class.
+    /// When it's only assigned in its declaration or in the constructor,
+    /// marking it as `readonly` documents that it never changes
+    /// and lets TypeScript report the accidental assignments.
+    ///
+    /// The rule checks the private properties, declared with the modifier `private` or with a private name such as `#name`,
+    /// and the private parameter properties of the constructor.
+    /// The static properties are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Container {
+    ///     private value: number;
+    ///
+    ///     constructor(value: number) {
+    ///         this.value = value;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Container {
+    ///     #value = 0;
+    ///
+    ///     get value() {
+    ///         return this.#value;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```ts,expect_diagnostic
+    /// class Container {
+    ///     constructor(private value: number) {}
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```ts
+    /// class Container {
+    ///     private readonly value: number;
+    ///     private count = 0;
+    ///
+    ///     constructor(value: number) {
+    ///         this.value = value;
+    ///     }
+    ///
+    ///     increment() {
+    ///         this.count++;
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseReadonlyClassMembers {
+        version: "next",
+        name: "useReadonlyClassMembers",
+        language: "ts",
+        sources: &[RuleSource::EslintTypeScript("prefer-readonly")],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyReadonlyCandidate = JsPropertyClassMember | TsPropertyParameter
+}
+
+impl Rule for UseReadonlyClassMembers {
+    type Query = Ast<AnyJsClass>;
+    type State = AnyReadonlyCandidate;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !ctx.source_type::<JsFileSource>().language().is_typescript() {
+            return Vec::new();
+        }
+        let class = ctx.query();
+        let mut candidates: Vec<_> = get_readonly_candidates(class)
+            .filter_map(|candidate| Some((candidate.member_name()?, candidate)))
+            .collect();
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+        for node in class.members().syntax().descendants() {
+            if let Some(assignment) = JsStaticMemberAssignment::cast_ref(&node) {
+                let Some(name) = assignment
+                    .member()
+                    .ok()
+                    .as_ref()
+                    .and_then(MemberName::from_js_name)
+                else {
+                    continue;
+                };
+                if !is_constructor_initialization(class, &assignment) {
+                    candidates.retain(|(candidate_name, _)| *candidate_name != name);
+                }
+            } else if let Some(assignment) = JsComputedMemberAssignment::cast_ref(&node) {
+                let member = assignment.member().ok();
+                match member.as_ref().and_then(AnyJsExpression::as_static_value) {
+                    Some(value) => {
+                        let Some(text) = value.as_string_constant() else {
+                            continue;
+                        };
+                        candidates.retain(|(candidate_name, _)| {
+                            candidate_name.is_private_name || candidate_name.text != text
+                        });
+                    }
+                    // The assigned member is unknown: it can be any member without a private name
+                    None => candidates.retain(|(candidate_name, _)| candidate_name.is_private_name),
+                }
+            }
+            if candidates.is_empty() {
+                break;
+            }
+        }
+        candidates
+            .into_iter()
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.name_range()?,
+                markup! {
+                    "This private class member is never reassigned."
+                },
+            )
+            .note(markup! {
+                "Mark it as "<Emphasis>"readonly"</Emphasis>" to prevent the accidental assignments."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
+        let mut mutation = ctx.root().begin();
+        match state {
+            AnyReadonlyCandidate::JsPropertyClassMember(member) => {
+                let modifiers = member.modifiers();
+                let position = modifiers
+                    .iter()
+                    .take_while(|modifier| Modifier::from(modifier) < Modifier::Readonly)
+                    .count();
+                let readonly_token = if position == 0 {
+                    // The modifier takes the place of the first token of the member
+                    let first_token = member.syntax().first_token()?;
+                    let readonly_token = make::token(T![readonly])
+                        .with_leading_trivia_pieces(first_token.leading_trivia().pieces())
+                        .with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")]);
+                    mutation.replace_token_discard_trivia(
+                        first_token.clone(),
+                        first_token.with_leading_trivia([]),
+                    );
+                    readonly_token
+                } else {
+                    readonly_token()
+                };
+                let readonly_modifier = AnyJsPropertyModifier::TsReadonlyModifier(
+                    make::ts_readonly_modifier(readonly_token),
+                );
+                let mut items: Vec<_> = modifiers.iter().collect();
+                items.insert(position, readonly_modifier);
+                mutation
+                    .replace_node_discard_trivia(modifiers, make::js_property_modifier_list(items));
+            }
+            AnyReadonlyCandidate::TsPropertyParameter(parameter) => {
+                let modifiers = parameter.modifiers();
+                let position = modifiers
+                    .iter()
+                    .take_while(|modifier| Modifier::from(modifier) < Modifier::Readonly)
+                    .count();
+                let readonly_modifier = AnyTsPropertyParameterModifier::TsReadonlyModifier(
+                    make::ts_readonly_modifier(readonly_token()),
+                );
+                let mut items: Vec<_> = modifiers.iter().collect();
+                items.insert(position, readonly_modifier);
+                mutation.replace_node_discard_trivia(
+                    modifiers,
+                    make::ts_property_parameter_modifier_list(items),
+                );
+            }
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            ctx.metadata().to_applicability(),
+            markup! { "Add the modifier "<Emphasis>"readonly"</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// The name of a class member, distinguishing the private names such as `#name`.
+#[derive(Debug, Eq, PartialEq)]
+struct MemberName {
+    is_private_name: bool,
+    text: String,
+}
+
+impl MemberName {
+    fn from_js_name(name: &AnyJsName) -> Option<Self> {
+        Some(Self {
+            is_private_name: matches!(name, AnyJsName::JsPrivateName(_)),
+            text: name.value_token().ok()?.text_trimmed().to_string(),
+        })
+    }
+}
+
+impl AnyReadonlyCandidate {
+    fn member_name(&self) -> Option<MemberName> {
+        match self {
+            Self::JsPropertyClassMember(member) => {
+                let name = member.name().ok()?;
+                Some(MemberName {
+                    is_private_name: matches!(
+                        name,
+                        AnyJsClassMemberName::JsPrivateClassMemberName(_)
+                    ),
+                    text: name.name()?.text().to_string(),
+                })
+            }
+            Self::TsPropertyParameter(parameter) => Some(MemberName {
+                is_private_name: false,
+                text: parameter_name_token(parameter)?.text_trimmed().to_string(),
+            }),
+        }
+    }
+
+    fn name_range(&self) -> Option<TextRange> {
+        match self {
+            Self::JsPropertyClassMember(member) => Some(member.name().ok()?.range()),
+            Self::TsPropertyParameter(parameter) => {
+                Some(parameter_name_token(parameter)?.text_trimmed_range())
+            }
+        }
+    }
+}
+
+/// Returns the private properties and the private parameter properties of `class` that aren't `readonly`.
+fn get_readonly_candidates(class: &AnyJsClass) -> impl Iterator<Item = AnyReadonlyCandidate> {
+    let members = class.members();
+    let properties = members
+        .iter()
+        .filter_map(|member| match member {
+            AnyJsClassMember::JsPropertyClassMember(member) => Some(member),
+            _ => None,
+        })
+        .filter(|member| {
+            let is_private_name = matches!(
+                member.name(),
+                Ok(AnyJsClassMemberName::JsPrivateClassMemberName(_))
+            );
+            let mut is_private = is_private_name;
+            for modifier in member.modifiers() {
+                match Modifier::from(&modifier) {
+                    Modifier::Private => is_private = true,
+                    Modifier::Static | Modifier::Readonly | Modifier::Accessor => return false,
+                    _ => {}
+                }
+            }
+            is_private
+        })
+        .map(AnyReadonlyCandidate::from);
+    let parameters = members
+        .iter()
+        .find_map(|member| match member {
+            AnyJsClassMember::JsConstructorClassMember(member) => member.parameters().ok(),
+            _ => None,
+        })
+        .into_iter()
+        .flat_map(|parameters| parameters.parameters().iter())
+        .filter_map(|parameter| match parameter.ok()? {
+            AnyJsConstructorParameter::TsPropertyParameter(parameter) => Some(parameter),
+            _ => None,
+        })
+        .filter(|parameter| {
+            let mut is_private = false;
+            for modifier in parameter.modifiers() {
+                match Modifier::from(&modifier) {
+                    Modifier::Private => is_private = true,
+                    Modifier::Readonly => return false,
+                    _ => {}
+                }
+            }
+            is_private
+        })
+        .map(AnyReadonlyCandidate::from);
+    properties.chain(parameters)
+}
+
+/// Returns `true` if `assignment` assigns a member of `this` directly in the constructor of `class`.
+///
+/// The assignments in the nested functions, including the arrow functions, aren't allowed on `readonly` members.
+fn is_constructor_initialization(
+    class: &AnyJsClass,
+    assignment: &JsStaticMemberAssignment,
+) -> bool {
+    if !matches!(
+        assignment.object(),
+        Ok(AnyJsExpression::JsThisExpression(_))
+    ) {
+        return false;
+    }
+    let Some(function) = assignment.syntax().ancestors().find(is_function_boundary) else {
+        return false;
+    };
+    function.kind() == JsSyntaxKind::JS_CONSTRUCTOR_CLASS_MEMBER
+        && function.grand_parent().as_ref() == Some(class.syntax())
+}
+
+/// Returns `true` if `node` starts a new function body or a new class member.
+fn is_function_boundary(node: &JsSyntaxNode) -> bool {
+    matches!(
+        node.kind(),
+        JsSyntaxKind::JS_FUNCTION_DECLARATION
+            | JsSyntaxKind::JS_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_ARROW_FUNCTION_EXPRESSION
+            | JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+            | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+            | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+    ) || AnyJsClassMember::can_cast(node.kind())
+}
+
+fn parameter_name_token(parameter: &TsPropertyParameter) -> Option<JsSyntaxToken> {
+    match parameter.formal_parameter().ok()? {
+        AnyJsFormalParameter::JsFormalParameter(parameter) => parameter
+            .binding()
+            .ok()?
+            .as_any_js_binding()?
+            .as_js_identifier_binding()?
+            .name_token()
+            .ok(),
+        AnyJsFormalParameter::JsBogusParameter(_) => None,
+    }
+}
+
+fn readonly_token() -> JsSyntaxToken {
+    make::token(T![readonly]).with_trailing_trivia([(TriviaPieceKind::Whitespace, " ")])
+}
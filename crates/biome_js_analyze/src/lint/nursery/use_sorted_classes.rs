@@ -16,8 +16,10 @@ use biome_js_factory::make::{
     js_literal_member_name, js_string_literal, js_string_literal_expression,
     js_string_literal_single_quotes, js_template_chunk, js_template_chunk_element, jsx_string,
 };
+use biome_project::TailwindConfig;
 use biome_rowan::{AstNode, BatchMutationExt};
 use lazy_static::lazy_static;
+use std::sync::Arc;
 
 use crate::JsRuleAction;
 
@@ -51,10 +53,7 @@ declare_rule! {
     /// Notably, keep in mind that the following features are not supported yet:
     ///
     /// - Variant sorting.
-    /// - Custom utilitites and variants (such as ones introduced by Tailwind CSS plugins). Only the default Tailwind CSS configuration is supported.
-    /// - Options such as `prefix` and `separator`.
-    /// - Tagged template literals.
-    /// - Object properties (e.g. in `clsx` calls).
+    /// - Custom variants (such as ones introduced by Tailwind CSS plugins).
     ///
     /// Please don't report issues about these features.
     /// :::
@@ -101,15 +100,41 @@ declare_rule! {
     /// tw.div`px-2`;
     /// ```
     ///
-    /// :::caution
-    /// Tagged template literal support has not been implemented yet.
-    /// :::
+    /// A function also matches the calls and the tagged templates of its members, such as `tw.div` for `tw`, or `styled.button` for `styled`.
     ///
     /// ### Sort-related
     ///
-    /// :::caution
-    /// At the moment, this rule does not support customizing the sort options. Instead, the default Tailwind CSS configuration is hard-coded.
-    /// :::
+    /// The sort options are read from the Tailwind CSS configuration file next to the `package.json` file of the project. Biome looks for the following files, in order:
+    ///
+    /// - `tailwind.config.json`: a precomputed configuration.
+    /// - `tailwind.config.js`, `tailwind.config.cjs`, `tailwind.config.mjs`, and `tailwind.config.ts`.
+    ///
+    /// From a JavaScript or TypeScript file, Biome reads the literal values of the `prefix` and `separator` fields, the classes of the selectors passed to `addUtilities` and `addComponents`, and the utilities passed to `matchUtilities` and `matchComponents` by the plugins:
+    ///
+    /// ```js,ignore
+    /// module.exports = {
+    ///     prefix: "tw-",
+    ///     plugins: [
+    ///         plugin(({ addUtilities, matchUtilities }) => {
+    ///             addUtilities({ ".content-auto": { contentVisibility: "auto" } });
+    ///             matchUtilities({ tab: (value) => ({ tabSize: value }) });
+    ///         }),
+    ///     ],
+    /// };
+    /// ```
+    ///
+    /// When the configuration is computed by the plugins at runtime, generate a `tailwind.config.json` file instead. The utilities and the components are written without the prefix, and a utility that accepts a value ends with `-*`:
+    ///
+    /// ```json
+    /// {
+    ///     "prefix": "tw-",
+    ///     "separator": "_",
+    ///     "components": ["btn"],
+    ///     "utilities": ["content-auto", "tab-*"]
+    /// }
+    /// ```
+    ///
+    /// The custom utilities and components are sorted after the ones of the default Tailwind CSS configuration, in declaration order. When a prefix is set, the classes without the prefix are considered custom classes.
     ///
     /// ## Differences with [Prettier](https://github.com/tailwindlabs/prettier-plugin-tailwindcss)
     ///
@@ -124,9 +149,9 @@ declare_rule! {
     /// - False positives: classes can be wrongly recognized as utilities even though their values are incorrect. For example, if there's a `px-` utility defined in the configuration, it will match all of the following classes: `px-2`, `px-1337`, `px-[not-actually-valid]`, `px-literally-anything`.
     /// - No distinction between different utilities that share the same prefix: for example, `text-red-500` and `text-lg` are both interpreted as the same type of utility by this rule, even though the former refers to a color and the latter to a font size. This results in all utilities that share the same prefix being sorted together, regardless of their actual values.
     ///
-    /// ### Custom additions must be specified
+    /// ### Custom additions must be literal
     ///
-    /// The built-in Tailwind CSS preset (enabled by default) contains the set of utilities and variants that are available with the default configuration. More utilities and variants can be added through Tailwind CSS plugins. In Biome, these need to be manually specified in the Biome configuration file in order to "extend" the preset.
+    /// The built-in Tailwind CSS preset (enabled by default) contains the set of utilities and variants that are available with the default configuration. More utilities and variants can be added through Tailwind CSS plugins. Biome only knows the utilities that are written literally in the configuration file, or listed in a precomputed `tailwind.config.json` file.
     ///
     /// ### Presets can't be modified
     ///
@@ -166,7 +191,17 @@ impl Rule for UseSortedClasses {
 
         if node.should_visit(options)? {
             if let Some(value) = node.value() {
-                let sorted_value = sort_class_name(&value, &SORT_CONFIG);
+                let sorted_value = match ctx.get_service::<Arc<TailwindConfig>>() {
+                    Some(tailwind_config) => {
+                        let sort_config = SortConfig::new(
+                            get_utilities_preset(&UseSortedClassesPreset::default()),
+                            Vec::new(),
+                        )
+                        .with_tailwind_config(tailwind_config);
+                        sort_class_name(&value, &sort_config)
+                    }
+                    None => sort_class_name(&value, &SORT_CONFIG),
+                };
                 if value.text() != sorted_value {
                     return Some(sorted_value);
                 }
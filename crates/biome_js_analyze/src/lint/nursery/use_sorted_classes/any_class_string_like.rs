@@ -6,22 +6,24 @@ use biome_js_syntax::{
 };
 use biome_rowan::{declare_node_union, AstNode, TokenText};
 
-fn get_callee_name(call_expression: &JsCallExpression) -> Option<TokenText> {
-    call_expression
-        .callee()
-        .ok()?
-        .as_js_identifier_expression()?
-        .name()
-        .ok()?
-        .name()
-        .ok()
+/// Checks if a callee or a tag is one of the target functions, or a member of one of them,
+/// such as `tw.div` for the function `tw`.
+fn is_target_function(callee: &AnyJsExpression, options: &UtilityClassSortingOptions) -> bool {
+    let name = match callee {
+        AnyJsExpression::JsIdentifierExpression(_)
+        | AnyJsExpression::JsStaticMemberExpression(_) => callee.get_callee_object_name(),
+        _ => None,
+    };
+    name.is_some_and(|name| options.has_function(name.text_trimmed()))
 }
 
 fn is_call_expression_of_target_function(
     call_expression: &JsCallExpression,
     options: &UtilityClassSortingOptions,
 ) -> bool {
-    get_callee_name(call_expression).is_some_and(|name| options.has_function(name.text()))
+    call_expression
+        .callee()
+        .is_ok_and(|callee| is_target_function(&callee, options))
 }
 
 fn get_attribute_name(attribute: &JsxAttribute) -> Option<TokenText> {
@@ -96,11 +98,8 @@ impl AnyClassStringLike {
             AnyClassStringLike::JsTemplateChunkElement(template) => {
                 for ancestor in template.syntax().ancestors().skip(1) {
                     if let Some(template_expression) = JsTemplateExpression::cast_ref(&ancestor) {
-                        if let Some(AnyJsExpression::JsIdentifierExpression(tag)) =
-                            template_expression.tag()
-                        {
-                            let name = tag.name().ok()?.name().ok()?;
-                            if options.has_function(name.text()) {
+                        if let Some(tag) = template_expression.tag() {
+                            if is_target_function(&tag, options) {
                                 return Some(true);
                             }
                         }
@@ -14,7 +14,7 @@ use super::{
     class_lexer::{tokenize_class, ClassSegmentStructure},
     sort_config::SortConfig,
 };
-use crate::lint::nursery::use_sorted_classes::sort_config::UtilityLayer;
+use crate::lint::nursery::use_sorted_classes::sort_config::{CustomUtilityLayer, UtilityLayer};
 
 // utilities
 // ---------
@@ -101,8 +101,12 @@ struct UtilityInfo {
 
 /// Computes sort-related information about a CSS utility. If the utility is not recognized,
 /// `None` is returned.
+///
+/// The custom utilities of a layer are matched after the utilities of the preset layer with the
+/// same name.
 fn get_utility_info(
     utility_config: &[UtilityLayer],
+    custom_utilities: &[CustomUtilityLayer],
     utility_data: &ClassSegmentStructure,
 ) -> Option<UtilityInfo> {
     // Arbitrary CSS utilities always go in the "arbitrary" layer, at index 0.
@@ -122,8 +126,18 @@ fn get_utility_info(
 
     // Iterate over each layer, looking for a match.
     for layer_data in utility_config.iter() {
+        let custom_classes = custom_utilities
+            .iter()
+            .filter(|custom_layer| custom_layer.name == layer_data.name)
+            .flat_map(|custom_layer| custom_layer.classes.iter().map(String::as_str));
         // Iterate over each target in the layer, looking for a match.
-        for (index, target) in layer_data.classes.iter().enumerate() {
+        for (index, target) in layer_data
+            .classes
+            .iter()
+            .copied()
+            .chain(custom_classes)
+            .enumerate()
+        {
             match UtilityMatch::from(target, utility_text) {
                 UtilityMatch::Exact => {
                     // Exact matches can be returned immediately.
@@ -161,7 +175,7 @@ fn get_utility_info(
 #[cfg(test)]
 mod get_utility_info_tests {
     use super::*;
-    use crate::lint::nursery::use_sorted_classes::sort_config::UtilityLayer;
+    use crate::lint::nursery::use_sorted_classes::sort_config::{CustomUtilityLayer, UtilityLayer};
 
     #[test]
     fn test_exact_match() {
@@ -174,7 +188,7 @@ mod get_utility_info_tests {
             arbitrary: false,
         };
         assert_eq!(
-            get_utility_info(utility_config.as_slice(), &utility_data),
+            get_utility_info(utility_config.as_slice(), &[], &utility_data),
             Some(UtilityInfo {
                 layer: "layer",
                 index: 0,
@@ -185,7 +199,7 @@ mod get_utility_info_tests {
             arbitrary: false,
         };
         assert_eq!(
-            get_utility_info(utility_config.as_slice(), &utility_data),
+            get_utility_info(utility_config.as_slice(), &[], &utility_data),
             None
         );
     }
@@ -201,7 +215,7 @@ mod get_utility_info_tests {
             arbitrary: false,
         };
         assert_eq!(
-            get_utility_info(utility_config.as_slice(), &utility_data),
+            get_utility_info(utility_config.as_slice(), &[], &utility_data),
             Some(UtilityInfo {
                 layer: "layer",
                 index: 0,
@@ -212,7 +226,7 @@ mod get_utility_info_tests {
             arbitrary: false,
         };
         assert_eq!(
-            get_utility_info(utility_config.as_slice(), &utility_data),
+            get_utility_info(utility_config.as_slice(), &[], &utility_data),
             None
         );
     }
@@ -228,7 +242,7 @@ mod get_utility_info_tests {
             arbitrary: false,
         };
         assert_eq!(
-            get_utility_info(utility_config.as_slice(), &utility_data),
+            get_utility_info(utility_config.as_slice(), &[], &utility_data),
             Some(UtilityInfo {
                 layer: "layer",
                 index: 1,
@@ -247,7 +261,7 @@ mod get_utility_info_tests {
             arbitrary: false,
         };
         assert_eq!(
-            get_utility_info(utility_config.as_slice(), &utility_data),
+            get_utility_info(utility_config.as_slice(), &[], &utility_data),
             Some(UtilityInfo {
                 layer: "layer",
                 index: 0,
@@ -255,6 +269,48 @@ mod get_utility_info_tests {
         );
     }
 
+    #[test]
+    fn test_custom_utilities() {
+        let utility_config = vec![UtilityLayer {
+            name: "layer",
+            classes: &["px-", "block$"],
+        }];
+        let custom_utilities = vec![CustomUtilityLayer {
+            name: "layer",
+            classes: vec!["content-auto$".to_string(), "tab-".to_string()],
+        }];
+        let utility_data = ClassSegmentStructure {
+            text: "tab-4".to_string(),
+            arbitrary: false,
+        };
+        assert_eq!(
+            get_utility_info(
+                utility_config.as_slice(),
+                custom_utilities.as_slice(),
+                &utility_data
+            ),
+            Some(UtilityInfo {
+                layer: "layer",
+                index: 3,
+            })
+        );
+        let utility_data = ClassSegmentStructure {
+            text: "content-auto".to_string(),
+            arbitrary: false,
+        };
+        assert_eq!(
+            get_utility_info(
+                utility_config.as_slice(),
+                custom_utilities.as_slice(),
+                &utility_data
+            ),
+            Some(UtilityInfo {
+                layer: "layer",
+                index: 2,
+            })
+        );
+    }
+
     #[test]
     fn test_arbitrary_layer() {
         let utility_config = vec![UtilityLayer {
@@ -266,7 +322,7 @@ mod get_utility_info_tests {
             arbitrary: true,
         };
         assert_eq!(
-            get_utility_info(utility_config.as_slice(), &utility_data),
+            get_utility_info(utility_config.as_slice(), &[], &utility_data),
             Some(UtilityInfo {
                 layer: "arbitrary",
                 index: 0,
@@ -294,8 +350,22 @@ pub struct ClassInfo {
 /// Computes sort-related information about a CSS class. If the class is not recognized as a utility,
 /// it is considered a custom class instead and `None` is returned.
 pub fn get_class_info(class_name: &str, sort_config: &SortConfig) -> Option<ClassInfo> {
-    let utility_data = tokenize_class(class_name)?;
-    let utility_info = get_utility_info(sort_config.utilities, &utility_data.utility);
+    let mut utility_data = tokenize_class(class_name, &sort_config.separator)?;
+    if let Some(prefix) = &sort_config.prefix {
+        // Arbitrary properties, such as `[mask-type:luminance]`, don't have the prefix.
+        if !utility_data.utility.arbitrary {
+            utility_data.utility.text = utility_data
+                .utility
+                .text
+                .strip_prefix(prefix.as_str())?
+                .to_string();
+        }
+    }
+    let utility_info = get_utility_info(
+        sort_config.utilities,
+        &sort_config.custom_utilities,
+        &utility_data.utility,
+    );
     if let Some(utility_info) = utility_info {
         return Some(ClassInfo {
             text: class_name.to_string(),
@@ -317,6 +387,7 @@ pub fn get_class_info(class_name: &str, sort_config: &SortConfig) -> Option<Clas
 mod get_class_info_tests {
     use super::*;
     use crate::lint::nursery::use_sorted_classes::sort_config::UtilityLayer;
+    use biome_project::TailwindConfig;
 
     #[test]
     fn test_get_class_info() {
@@ -396,4 +467,39 @@ mod get_class_info_tests {
         );
         assert_eq!(get_class_info("unknown", &sort_config), None);
     }
+
+    #[test]
+    fn test_get_class_info_with_tailwind_config() {
+        const UTILITIES_CONFIG: [UtilityLayer; 1] = [UtilityLayer {
+            name: "utilities",
+            classes: &["px-", "block$"],
+        }];
+        let tailwind_config = TailwindConfig {
+            prefix: Some("tw-".to_string()),
+            separator: Some("_".to_string()),
+            components: Vec::new(),
+            utilities: vec!["tab-*".to_string()],
+        };
+        let sort_config = SortConfig::new(UTILITIES_CONFIG.as_slice(), vec![])
+            .with_tailwind_config(&tailwind_config);
+        assert_eq!(
+            get_class_info("tw-block", &sort_config),
+            Some(ClassInfo {
+                text: "tw-block".to_string(),
+                variant_weight: None,
+                layer_index: 0,
+                utility_index: 1,
+            })
+        );
+        assert_eq!(
+            get_class_info("hover_tw-tab-4", &sort_config),
+            Some(ClassInfo {
+                text: "hover_tw-tab-4".to_string(),
+                variant_weight: Some(0),
+                layer_index: 0,
+                utility_index: 2,
+            })
+        );
+        assert_eq!(get_class_info("block", &sort_config), None);
+    }
 }
@@ -8,9 +8,9 @@
 //! The results of the lexer are then used to process classes into `ClassInfo` structs, which are, in
 //! turn, used to sort the classes.
 
-/// Splits a string into segments based on a list of indexes. The characters at the indexes are not
-/// included in the segments, as they are considered delimiters.
-fn split_at_indexes<'a>(s: &'a str, indexes: &[usize]) -> Vec<&'a str> {
+/// Splits a string into segments based on a list of indexes. The delimiters of length
+/// `delimiter_len` at the indexes are not included in the segments.
+fn split_at_indexes<'a>(s: &'a str, indexes: &[usize], delimiter_len: usize) -> Vec<&'a str> {
     let mut segments = Vec::new();
     let mut start_offset = 0;
     let mut start = 0;
@@ -22,7 +22,7 @@ fn split_at_indexes<'a>(s: &'a str, indexes: &[usize]) -> Vec<&'a str> {
         if index > start {
             segments.push(&s[start + start_offset..index]);
         }
-        start_offset = 1;
+        start_offset = delimiter_len;
         start = index;
     }
 
@@ -40,18 +40,21 @@ mod tests {
     #[test]
     fn test_split_at_indexes() {
         assert_eq!(
-            split_at_indexes("foo:bar:baz", &[3, 7]),
+            split_at_indexes("foo:bar:baz", &[3, 7], 1),
             vec!["foo", "bar", "baz"]
         );
-        assert_eq!(split_at_indexes("foobar:baz", &[6]), vec!["foobar", "baz"]);
-        assert_eq!(split_at_indexes("foobarbaz", &[]), vec!["foobarbaz"]);
         assert_eq!(
-            split_at_indexes("foo_bar_baz", &[3, 7]),
+            split_at_indexes("foobar:baz", &[6], 1),
+            vec!["foobar", "baz"]
+        );
+        assert_eq!(split_at_indexes("foobarbaz", &[], 1), vec!["foobarbaz"]);
+        assert_eq!(
+            split_at_indexes("foo_bar_baz", &[3, 7], 1),
             vec!["foo", "bar", "baz"]
         );
-        assert_eq!(split_at_indexes(":", &[0]), Vec::<&str>::new());
-        assert_eq!(split_at_indexes(":::", &[0]), vec!["::"]);
-        assert_eq!(split_at_indexes(":::", &[1]), vec![":", ":"]);
+        assert_eq!(split_at_indexes(":", &[0], 1), Vec::<&str>::new());
+        assert_eq!(split_at_indexes(":::", &[0], 1), vec!["::"]);
+        assert_eq!(split_at_indexes(":::", &[1], 1), vec![":", ":"]);
     }
 }
 
@@ -95,9 +98,8 @@ pub struct ClassStructure {
 }
 
 /// Processes a CSS class into a class structure, containing a list of variants and the
-/// utility itself.
-pub fn tokenize_class(class_name: &str) -> Option<ClassStructure> {
-    // TODO: add custom separator argument (currently hardcoded to `:`).
+/// utility itself. The variants are separated by `separator`, usually `:`.
+pub fn tokenize_class(class_name: &str, separator: &str) -> Option<ClassStructure> {
     let mut arbitrary_block_depth = 0;
     let mut at_arbitrary_block_start = false;
     let mut quoted_arbitrary_block_type: Option<Quote> = None;
@@ -159,25 +161,30 @@ pub fn tokenize_class(class_name: &str) -> Option<ClassStructure> {
                     return None;
                 }
             }
-            ':' => {
-                if arbitrary_block_depth == 0 {
-                    delimiter_indexes.push(index);
-                }
-            }
             _ => {}
         };
+        if arbitrary_block_depth == 0
+            && !separator.is_empty()
+            && class_name[index..].starts_with(separator)
+            && delimiter_indexes
+                .last()
+                .map_or(true, |last| index >= last + separator.len())
+        {
+            delimiter_indexes.push(index);
+        }
         if at_arbitrary_block_start && !is_start_of_arbitrary_block {
             at_arbitrary_block_start = false;
         };
         last_char = next_last_char;
     }
-    let mut variants: Vec<ClassSegmentStructure> = split_at_indexes(class_name, &delimiter_indexes)
-        .iter()
-        .map(|&s| ClassSegmentStructure {
-            arbitrary: s.starts_with('['),
-            text: s.to_string(),
-        })
-        .collect();
+    let mut variants: Vec<ClassSegmentStructure> =
+        split_at_indexes(class_name, &delimiter_indexes, separator.len())
+            .iter()
+            .map(|&s| ClassSegmentStructure {
+                arbitrary: s.starts_with('['),
+                text: s.to_string(),
+            })
+            .collect();
     let utility = variants.pop()?;
 
     Some(ClassStructure { variants, utility })
@@ -190,7 +197,7 @@ mod tests_tokenize_class {
     #[test]
     fn test_tokenize_class() {
         assert_eq!(
-            tokenize_class("px-2"),
+            tokenize_class("px-2", ":"),
             Some(ClassStructure {
                 variants: Vec::new(),
                 utility: ClassSegmentStructure {
@@ -200,7 +207,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("hover:px-2"),
+            tokenize_class("hover:px-2", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: false,
@@ -213,7 +220,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("sm:hover:px-2"),
+            tokenize_class("sm:hover:px-2", ":"),
             Some(ClassStructure {
                 variants: vec![
                     ClassSegmentStructure {
@@ -232,7 +239,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("hover:[mask:circle]"),
+            tokenize_class("hover:[mask:circle]", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: false,
@@ -245,7 +252,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("[&:nth-child(3)]:px-2"),
+            tokenize_class("[&:nth-child(3)]:px-2", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: true,
@@ -258,7 +265,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("hover:[mask:circle]"),
+            tokenize_class("hover:[mask:circle]", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: false,
@@ -271,7 +278,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("[&:nth-child(3)]:[mask:circle]"),
+            tokenize_class("[&:nth-child(3)]:[mask:circle]", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: true,
@@ -284,7 +291,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("font-[Roboto]:[mask:circle]"),
+            tokenize_class("font-[Roboto]:[mask:circle]", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: false,
@@ -297,7 +304,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("font-['Roboto']:[mask:circle]"),
+            tokenize_class("font-['Roboto']:[mask:circle]", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: false,
@@ -310,7 +317,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("quotes-['Ro'b\"`oto']:block"),
+            tokenize_class("quotes-['Ro'b\"`oto']:block", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: false,
@@ -323,7 +330,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("quotes-[']']:block"),
+            tokenize_class("quotes-[']']:block", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: false,
@@ -336,7 +343,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("quotes-[\"]\"]"),
+            tokenize_class("quotes-[\"]\"]", ":"),
             Some(ClassStructure {
                 variants: Vec::new(),
                 utility: ClassSegmentStructure {
@@ -346,7 +353,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("quotes-[`]`]"),
+            tokenize_class("quotes-[`]`]", ":"),
             Some(ClassStructure {
                 variants: Vec::new(),
                 utility: ClassSegmentStructure {
@@ -355,9 +362,9 @@ mod tests_tokenize_class {
                 },
             })
         );
-        assert_eq!(tokenize_class("no-quotes-[]]:block"), None);
+        assert_eq!(tokenize_class("no-quotes-[]]:block", ":"), None);
         assert_eq!(
-            tokenize_class("escaped-quotes-[']\\']:block"),
+            tokenize_class("escaped-quotes-[']\\']:block", ":"),
             Some(ClassStructure {
                 variants: Vec::new(),
                 utility: ClassSegmentStructure {
@@ -367,7 +374,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("double-escaped-quotes-[']\\\\']:block"),
+            tokenize_class("double-escaped-quotes-[']\\\\']:block", ":"),
             Some(ClassStructure {
                 variants: vec![ClassSegmentStructure {
                     arbitrary: false,
@@ -380,7 +387,7 @@ mod tests_tokenize_class {
             })
         );
         assert_eq!(
-            tokenize_class("triple-escaped-quotes-[']\\\\\\']:block"),
+            tokenize_class("triple-escaped-quotes-[']\\\\\\']:block", ":"),
             Some(ClassStructure {
                 variants: Vec::new(),
                 utility: ClassSegmentStructure {
@@ -390,4 +397,50 @@ mod tests_tokenize_class {
             })
         );
     }
+
+    #[test]
+    fn test_tokenize_class_custom_separator() {
+        assert_eq!(
+            tokenize_class("hover_px-2", "_"),
+            Some(ClassStructure {
+                variants: vec![ClassSegmentStructure {
+                    arbitrary: false,
+                    text: "hover".to_string(),
+                }],
+                utility: ClassSegmentStructure {
+                    arbitrary: false,
+                    text: "px-2".to_string(),
+                },
+            })
+        );
+        assert_eq!(
+            tokenize_class("sm__[&_p]__px-2", "__"),
+            Some(ClassStructure {
+                variants: vec![
+                    ClassSegmentStructure {
+                        arbitrary: false,
+                        text: "sm".to_string(),
+                    },
+                    ClassSegmentStructure {
+                        arbitrary: true,
+                        text: "[&_p]".to_string(),
+                    },
+                ],
+                utility: ClassSegmentStructure {
+                    arbitrary: false,
+                    text: "px-2".to_string(),
+                },
+            })
+        );
+        assert_eq!(
+            tokenize_class("hover:px-2", "_"),
+            Some(ClassStructure {
+                variants: Vec::new(),
+                utility: ClassSegmentStructure {
+                    arbitrary: false,
+                    text: "hover:px-2".to_string(),
+                },
+            })
+        );
+    }
 }
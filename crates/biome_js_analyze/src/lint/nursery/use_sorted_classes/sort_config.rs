@@ -5,6 +5,7 @@
 //! - The list of variants, in order of importance (which is used to compute the variants weight).
 //! - Other options, such as prefix and separator.
 
+use biome_project::TailwindConfig;
 use std::collections::HashMap;
 
 /// A utility layer, containing its name and an ordered list of classes.
@@ -13,6 +14,13 @@ pub struct UtilityLayer {
     pub classes: &'static [&'static str],
 }
 
+/// A layer of custom utilities, such as the ones added by Tailwind CSS plugins. Its classes are
+/// sorted after the classes of the preset layer with the same name.
+pub struct CustomUtilityLayer {
+    pub name: &'static str,
+    pub classes: Vec<String>,
+}
+
 /// The utilities config, contains an ordered list of utility layers.
 pub type UtilitiesConfig = &'static [UtilityLayer];
 
@@ -22,8 +30,13 @@ pub type VariantsConfig = Vec<String>;
 /// The sort config, containing the utility config and the variant config.
 pub struct SortConfig {
     pub utilities: &'static [UtilityLayer],
+    pub custom_utilities: Vec<CustomUtilityLayer>,
     pub variants: VariantsConfig,
     pub layer_index_map: HashMap<&'static str, usize>,
+    /// The prefix of the utilities, such as `tw-` in `tw-px-2`.
+    pub prefix: Option<String>,
+    /// The separator between the variants and the utility.
+    pub separator: String,
 }
 
 impl SortConfig {
@@ -40,8 +53,48 @@ impl SortConfig {
 
         Self {
             utilities: utilities_config,
+            custom_utilities: Vec::new(),
             variants,
             layer_index_map,
+            prefix: None,
+            separator: ":".to_string(),
         }
     }
+
+    /// Extends the sort config with the prefix, the separator, and the custom utilities of a
+    /// Tailwind CSS configuration.
+    pub fn with_tailwind_config(mut self, config: &TailwindConfig) -> Self {
+        self.prefix = config.prefix.clone().filter(|prefix| !prefix.is_empty());
+        self.separator = config.separator().to_string();
+        self.custom_utilities = vec![
+            CustomUtilityLayer {
+                name: "components",
+                classes: config
+                    .components
+                    .iter()
+                    .map(String::as_str)
+                    .map(to_target)
+                    .collect(),
+            },
+            CustomUtilityLayer {
+                name: "utilities",
+                classes: config
+                    .utilities
+                    .iter()
+                    .map(String::as_str)
+                    .map(to_target)
+                    .collect(),
+            },
+        ];
+        self
+    }
+}
+
+/// Converts a utility of the Tailwind CSS configuration to a target: `tab-*` matches the
+/// utilities that start with `tab-`, and the other utilities are matched exactly.
+fn to_target(utility: &str) -> String {
+    match utility.strip_suffix('*') {
+        Some(partial) => partial.to_string(),
+        None => format!("{utility}$"),
+    }
 }
@@ -0,0 +1,105 @@
+use crate::storybook::is_stories_file;
+use biome_analyze::{
+    context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+};
+use biome_console::markup;
+use biome_js_syntax::{AnyJsExportClause, AnyJsModuleItem, JsModule, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Require a default export in stories files.
+    ///
+    /// Storybook reads the metadata of the stories of a file, such as their title and
+    /// their component, from the default export of the file.
+    /// A stories file without a default export isn't loaded by Storybook.
+    ///
+    /// The rule applies to the files whose name contains `.stories.` or `.story.`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export const Primary = {};
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export default {
+    ///     title: "Button",
+    ///     component: Button,
+    /// };
+    ///
+    /// export const Primary = {};
+    /// ```
+    ///
+    pub UseStoryDefaultExport {
+        version: "next",
+        name: "useStoryDefaultExport",
+        language: "js",
+        sources: &[RuleSource::EslintStorybook("default-exports")],
+        domains: &[RuleDomain::Storybook],
+        recommended: false,
+    }
+}
+
+impl Rule for UseStoryDefaultExport {
+    type Query = Ast<JsModule>;
+    /// The range of the first export of the file.
+    type State = TextRange;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_stories_file(ctx.file_path()) {
+            return None;
+        }
+        let mut first_export = None;
+        for item in ctx.query().items() {
+            let AnyJsModuleItem::JsExport(export) = item else {
+                continue;
+            };
+            let clause = export.export_clause().ok()?;
+            match clause {
+                AnyJsExportClause::JsExportDefaultDeclarationClause(_)
+                | AnyJsExportClause::JsExportDefaultExpressionClause(_) => return None,
+                AnyJsExportClause::JsExportNamedClause(named)
+                    if named.specifiers().iter().flatten().any(|specifier| {
+                        specifier
+                            .as_js_export_named_specifier()
+                            .and_then(|specifier| specifier.exported_name().ok())
+                            .and_then(|name| name.value().ok())
+                            .is_some_and(|name| name.text_trimmed() == "default")
+                    }) =>
+                {
+                    return None;
+                }
+                _ => {}
+            }
+            first_export.get_or_insert(export.range());
+        }
+        // A file without exports isn't a stories file.
+        first_export
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, range: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "This stories file doesn't have a default export."
+                },
+            )
+            .note(markup! {
+                "Storybook reads the metadata of the stories from the default export, and ignores a stories file without it."
+            })
+            .note(markup! {
+                "Add a default export with the metadata of the stories, such as their "<Emphasis>"title"</Emphasis>" and their "<Emphasis>"component"</Emphasis>"."
+            }),
+        )
+    }
+}
@@ -0,0 +1,117 @@
+use crate::services::semantic::Semantic;
+use crate::storybook::is_stories_file;
+use crate::utils::rename::RenameSymbolExtensions;
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic, RuleDomain,
+    RuleSource,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{binding_ext::AnyJsBindingDeclaration, JsIdentifierBinding};
+use biome_rowan::{AstNode, BatchMutationExt, TokenText};
+use biome_string_case::Case;
+
+declare_rule! {
+    /// Enforce PascalCase for the names of the stories.
+    ///
+    /// Storybook derives the name of a story from the name of its export.
+    /// Naming stories in PascalCase makes them stand out from the other exports,
+    /// and keeps their names consistent with the names of components.
+    ///
+    /// The rule applies to the files whose name contains `.stories.` or `.story.`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export default { component: Button };
+    ///
+    /// export const primaryButton = {};
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// // Button.stories.js
+    /// export default { component: Button };
+    ///
+    /// export const PrimaryButton = {};
+    /// ```
+    ///
+    pub UseStoryNamePascalCase {
+        version: "next",
+        name: "useStoryNamePascalCase",
+        language: "js",
+        sources: &[RuleSource::EslintStorybook("prefer-pascal-case")],
+        domains: &[RuleDomain::Storybook],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+impl Rule for UseStoryNamePascalCase {
+    type Query = Semantic<JsIdentifierBinding>;
+    type State = TokenText;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let binding = ctx.query();
+        let declaration = binding.declaration()?;
+        if !matches!(
+            declaration,
+            AnyJsBindingDeclaration::JsVariableDeclarator(_)
+                | AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+        ) || declaration.export().is_none()
+            || !is_stories_file(ctx.file_path())
+        {
+            return None;
+        }
+        let name = binding.name_token().ok()?.token_text_trimmed();
+        // `__namedExportsOrder` sets the order of the stories, and isn't a story.
+        if name.text() == "__namedExportsOrder"
+            || matches!(
+                Case::identify(name.text(), false),
+                Case::Pascal | Case::NumberableCapital
+            )
+        {
+            return None;
+        }
+        Some(name)
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, name: &Self::State) -> Option<RuleDiagnostic> {
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                ctx.query().range(),
+                markup! {
+                    "The story "<Emphasis>{name.text()}</Emphasis>" isn't in PascalCase."
+                },
+            )
+            .note(markup! {
+                "Storybook derives the name of the story from the name of the export. Name the stories in PascalCase to keep them consistent."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, name: &Self::State) -> Option<JsRuleAction> {
+        let new_name = Case::Pascal.convert(name.text());
+        if new_name.is_empty() {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        if !mutation.rename_node_declaration(ctx.model(), ctx.query(), &new_name) {
+            return None;
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Rename the story to "<Emphasis>{new_name}</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
@@ -1,9 +1,19 @@
-use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, FixKind, Rule, RuleDiagnostic,
+};
 use biome_console::markup;
-use biome_js_syntax::{AnyJsPropertyModifier, JsPropertyClassMember, JsRegexLiteralExpression};
-use biome_rowan::{AstNode, AstNodeList};
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{
+    function_ext::AnyFunctionLike, AnyJsExpression, AnyJsPropertyModifier, JsInitializerClause,
+    JsPropertyClassMember, JsRegexLiteralExpression, JsSyntaxKind, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, AstNodeList, BatchMutationExt};
+use biome_string_case::Case;
 
 use crate::services::control_flow::AnyJsControlFlowRoot;
+use crate::services::semantic::Semantic;
+use crate::utils::batch::JsBatchMutation;
+use crate::JsRuleAction;
 
 declare_rule! {
     /// Require all regex literals to be declared at the top level.
@@ -12,6 +22,11 @@ declare_rule! {
     ///
     /// It's important to note that this rule is not recommended for all cases. Placing regex literals at the top level can hurt startup times. In browser contexts, this can result in longer page loads.
     ///
+    /// The code action moves the regex literal to a constant declared before the top-level statement that contains it.
+    /// The constant is named after the variable that the regex initializes, or after the function that contains it.
+    /// The action isn't available for the regex literals with the `g` or `y` flag,
+    /// because these regexes keep the position of the last match between the calls.
+    ///
     /// ## Examples
     ///
     /// ### Invalid
@@ -37,11 +52,12 @@ declare_rule! {
         name: "useTopLevelRegex",
         language: "js",
         recommended: false,
+        fix_kind: FixKind::Unsafe,
     }
 }
 
 impl Rule for UseTopLevelRegex {
-    type Query = Ast<JsRegexLiteralExpression>;
+    type Query = Semantic<JsRegexLiteralExpression>;
     type State = ();
     type Signals = Option<Self::State>;
     type Options = ();
@@ -87,4 +103,86 @@ impl Rule for UseTopLevelRegex {
             }),
         )
     }
+
+    fn action(ctx: &RuleContext<Self>, _state: &Self::State) -> Option<JsRuleAction> {
+        let regex = ctx.query();
+        let (_, flags) = regex.decompose().ok()?;
+        // Hoisting a stateful regex shares its `lastIndex` between the calls.
+        if flags.text().contains(['g', 'y']) {
+            return None;
+        }
+        let base_name = constant_name(regex);
+        let model = ctx.model();
+        let name = std::iter::once(base_name.clone())
+            .chain((2..).map(|index| format!("{base_name}_{index}")))
+            .take(100)
+            .find(|name| {
+                !model
+                    .global_scope()
+                    .descendents()
+                    .any(|scope| scope.get_binding(name).is_some())
+            })?;
+        let mut mutation = ctx.root().begin();
+        let expression = AnyJsExpression::AnyJsLiteralExpression(regex.clone().into());
+        if !mutation.extract_to_top_level_constant(&expression, &name) {
+            return None;
+        }
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Move the regex literal to the top-level constant "<Emphasis>{name}</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the name of the constant for `regex`:
+/// the name of the variable that it initializes, the name of the function that contains it
+/// followed by `_REGEX` if it doesn't already end with it, or `REGEX`.
+fn constant_name(regex: &JsRegexLiteralExpression) -> String {
+    let variable_name = regex
+        .syntax()
+        .ancestors()
+        .skip(1)
+        .find(|node| node.kind() != JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION)
+        .and_then(JsInitializerClause::cast)
+        .and_then(|initializer| initializer.parent::<JsVariableDeclarator>())
+        .and_then(|declarator| {
+            let id = declarator.id().ok()?;
+            let binding = id.as_any_js_binding()?.as_js_identifier_binding()?;
+            Some(binding.name_token().ok()?.token_text_trimmed())
+        });
+    if let Some(name) = variable_name {
+        return Case::Constant.convert(name.text());
+    }
+    let function_name = regex
+        .syntax()
+        .ancestors()
+        .find_map(AnyFunctionLike::cast)
+        .and_then(|function| match function {
+            AnyFunctionLike::AnyJsFunction(function) => {
+                let binding = function.binding()?;
+                Some(
+                    binding
+                        .as_js_identifier_binding()?
+                        .name_token()
+                        .ok()?
+                        .token_text_trimmed(),
+                )
+            }
+            AnyFunctionLike::JsMethodObjectMember(method) => method.name().ok()?.name(),
+            AnyFunctionLike::JsMethodClassMember(method) => method.name().ok()?.name(),
+        });
+    match function_name {
+        Some(name) => {
+            let name = Case::Constant.convert(name.text());
+            if name.ends_with("REGEX") || name.ends_with("PATTERN") {
+                name
+            } else {
+                format!("{name}_REGEX")
+            }
+        }
+        None => "REGEX".to_string(),
+    }
 }
@@ -0,0 +1,164 @@
+use crate::JsRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_js_syntax::{AnyJsImportSpecifierLike, JsSyntaxKind, JsSyntaxToken};
+use biome_project::{normalize_path, TsConfigJson};
+use biome_rowan::BatchMutationExt;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+
+declare_rule! {
+    /// Prefer the path aliases of the `tsconfig.json` over deep relative imports.
+    ///
+    /// Relative imports that go up many directories, such as `../../../utils/format`,
+    /// are hard to read and break when the importing file is moved.
+    /// When the imported file is covered by a path alias of `compilerOptions.paths`,
+    /// the alias is more readable and doesn't depend on the location of the importing file.
+    ///
+    /// The rule uses the `tsconfig.json` located next to the `package.json` of the project.
+    /// The relative imports that go up more directories than `maxParentDirectories` are reported
+    /// if they can be expressed with a path alias.
+    ///
+    /// The code fix is unsafe because the other tools, such as bundlers and test runners,
+    /// must also be configured to resolve the path aliases.
+    ///
+    /// ## Examples
+    ///
+    /// In the following examples, the `tsconfig.json` maps `@/*` to `./src/*`:
+    ///
+    /// ```json
+    /// {
+    ///     "compilerOptions": {
+    ///         "paths": {
+    ///             "@/*": ["./src/*"]
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```js,ignore
+    /// // In a file `src/features/cart/total.js`
+    /// import { format } from "../../utils/format";
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js,ignore
+    /// // In a file `src/features/cart/total.js`
+    /// import { format } from "@/utils/format";
+    /// import { items } from "../items";
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `maxParentDirectories`
+    ///
+    /// The number of parent directories that a relative import can go up without being reported.
+    /// Defaults to `1`.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "maxParentDirectories": 2
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseTsconfigPathAliases {
+        version: "next",
+        name: "useTsconfigPathAliases",
+        language: "js",
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+/// Options for the rule `useTsconfigPathAliases`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseTsconfigPathAliasesOptions {
+    /// The number of parent directories that a relative import can go up without being reported.
+    pub max_parent_directories: u8,
+}
+
+impl Default for UseTsconfigPathAliasesOptions {
+    fn default() -> Self {
+        Self {
+            max_parent_directories: 1,
+        }
+    }
+}
+
+impl Rule for UseTsconfigPathAliases {
+    type Query = Ast<AnyJsImportSpecifierLike>;
+    /// The specifier that uses a path alias.
+    type State = String;
+    type Signals = Option<Self::State>;
+    type Options = UseTsconfigPathAliasesOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let specifier = node.inner_string_text()?;
+        let specifier = specifier.text();
+        let parent_directories = specifier
+            .split('/')
+            .take_while(|component| *component == "..")
+            .count();
+        if parent_directories <= ctx.options().max_parent_directories as usize {
+            return None;
+        }
+        let tsconfig = ctx.get_service::<Arc<TsConfigJson>>()?;
+        let directory = ctx.file_path().parent()?;
+        // The paths must be relative to the same directory to be compared.
+        if directory.is_absolute() != tsconfig.directory.is_absolute() {
+            return None;
+        }
+        let imported_path = normalize_path(&directory.join(specifier));
+        tsconfig.path_aliases_of(&imported_path).into_iter().next()
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, alias: &Self::State) -> Option<RuleDiagnostic> {
+        let token = ctx.query().module_name_token()?;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                token.text_trimmed_range(),
+                markup! {
+                    "This relative import can use the path alias "<Emphasis>{alias}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "Deep relative imports are hard to read and break when the importing file is moved."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, alias: &Self::State) -> Option<JsRuleAction> {
+        let token = ctx.query().module_name_token()?;
+        let quote = token.text_trimmed().chars().next()?;
+        let new_token = JsSyntaxToken::new_detached(
+            JsSyntaxKind::JS_STRING_LITERAL,
+            &format!("{quote}{alias}{quote}"),
+            [],
+            [],
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token_transfer_trivia(token, new_token);
+        Some(JsRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use the path alias "<Emphasis>{alias}</Emphasis>"." }.to_owned(),
+            mutation,
+        ))
+    }
+}
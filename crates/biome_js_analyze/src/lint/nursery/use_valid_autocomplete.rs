@@ -0,0 +1,264 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleSource};
+use biome_console::markup;
+use biome_js_syntax::jsx_ext::AnyJsxElement;
+use biome_rowan::{AstNode, TextRange};
+
+declare_rule! {
+    /// Enforce that the `autocomplete` attribute has a valid value.
+    ///
+    /// The attribute `autocomplete` of the form controls tells the browser
+    /// and the assistive technologies which personal information the control expects.
+    /// Its value is either `on`, `off`, or a list of tokens with the following grammar:
+    ///
+    /// 1. an optional section, such as `section-billing`;
+    /// 2. an optional `shipping` or `billing`;
+    /// 3. an optional `home`, `work`, `mobile`, `fax` or `pager`, only before a contact field such as `tel` or `email`;
+    /// 4. a field name, such as `name` or `postal-code`;
+    /// 5. an optional `webauthn`.
+    ///
+    /// The rule checks the attributes `autocomplete` and `autoComplete` of the elements `<input>`, `<select>` and `<textarea>`,
+    /// and checks that the element `<form>` uses `on` or `off`.
+    /// The dynamic values and the custom components are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input autoComplete="incorrect" />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input autoComplete="home name" />
+    /// ```
+    ///
+    /// ```jsx,expect_diagnostic
+    /// <input autoComplete="email billing" />
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```jsx
+    /// <>
+    ///     <input autoComplete="name" />
+    ///     <input autoComplete="section-checkout shipping postal-code" />
+    ///     <input autoComplete="work email" />
+    ///     <input autoComplete="off" />
+    ///     <MyInput autoComplete="incorrect" />
+    /// </>
+    /// ```
+    ///
+    /// ## Accessibility guidelines
+    ///
+    /// - [WCAG 1.3.5](https://www.w3.org/WAI/WCAG21/Understanding/identify-input-purpose)
+    ///
+    /// ## Resources
+    ///
+    /// - [HTML Standard, Autofill](https://html.spec.whatwg.org/multipage/form-control-infrastructure.html#autofill)
+    ///
+    pub UseValidAutocomplete {
+        version: "next",
+        name: "useValidAutocomplete",
+        language: "jsx",
+        sources: &[RuleSource::EslintJsxA11y("autocomplete-valid")],
+        recommended: false,
+    }
+}
+
+/// The autofill field names that don't accept a contact token such as `home`.
+const FIELD_NAMES: [&str; 44] = [
+    "name",
+    "honorific-prefix",
+    "given-name",
+    "additional-name",
+    "family-name",
+    "honorific-suffix",
+    "nickname",
+    "username",
+    "new-password",
+    "current-password",
+    "one-time-code",
+    "organization-title",
+    "organization",
+    "street-address",
+    "address-line1",
+    "address-line2",
+    "address-line3",
+    "address-level4",
+    "address-level3",
+    "address-level2",
+    "address-level1",
+    "country",
+    "country-name",
+    "postal-code",
+    "cc-name",
+    "cc-given-name",
+    "cc-additional-name",
+    "cc-family-name",
+    "cc-number",
+    "cc-exp",
+    "cc-exp-month",
+    "cc-exp-year",
+    "cc-csc",
+    "cc-type",
+    "transaction-currency",
+    "transaction-amount",
+    "language",
+    "bday",
+    "bday-day",
+    "bday-month",
+    "bday-year",
+    "sex",
+    "url",
+    "photo",
+];
+
+/// The autofill field names that accept a contact token such as `home`.
+const CONTACT_FIELD_NAMES: [&str; 10] = [
+    "tel",
+    "tel-country-code",
+    "tel-national",
+    "tel-area-code",
+    "tel-local",
+    "tel-local-prefix",
+    "tel-local-suffix",
+    "tel-extension",
+    "email",
+    "impp",
+];
+
+const CONTACT_TOKENS: [&str; 5] = ["home", "work", "mobile", "fax", "pager"];
+
+pub struct RuleState {
+    /// The range of the value of the attribute
+    range: TextRange,
+    kind: InvalidAutocomplete,
+}
+
+enum InvalidAutocomplete {
+    /// The token isn't part of the grammar
+    UnknownToken(String),
+    /// The token is valid, but not at this position
+    MisplacedToken(String),
+    /// `on` or `off` is combined with other tokens
+    CombinedOnOff,
+    /// A contact token such as `home` precedes a field that isn't a contact field
+    ContactTokenOnNonContactField { contact: String, field: String },
+    /// The value doesn't contain a field name
+    MissingFieldName,
+    /// A `<form>` uses a value other than `on` and `off`
+    InvalidFormValue,
+}
+
+impl Rule for UseValidAutocomplete {
+    type Query = Ast<AnyJsxElement>;
+    type State = RuleState;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let node = ctx.query();
+        let element_name = node.name().ok()?.as_jsx_name()?.value_token().ok()?;
+        let element_name = element_name.text_trimmed();
+        if !matches!(element_name, "input" | "select" | "textarea" | "form") {
+            return None;
+        }
+        let attribute = node
+            .find_attribute_by_name("autoComplete")
+            .or_else(|| node.find_attribute_by_name("autocomplete"))?;
+        let value = attribute.initializer()?.value().ok()?;
+        let static_value = value.as_static_value()?;
+        let text = static_value.text().to_ascii_lowercase();
+        let kind = if element_name == "form" {
+            (!matches!(text.trim(), "on" | "off")).then_some(InvalidAutocomplete::InvalidFormValue)
+        } else {
+            validate_autocomplete(&text).err()
+        }?;
+        Some(RuleState {
+            range: value.range(),
+            kind,
+        })
+    }
+
+    fn diagnostic(_: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                "The value of the "<Emphasis>"autocomplete"</Emphasis>" attribute is invalid."
+            },
+        );
+        let diagnostic = match &state.kind {
+            InvalidAutocomplete::UnknownToken(token) => diagnostic.note(markup! {
+                <Emphasis>{token}</Emphasis>" isn't a valid autofill token."
+            }),
+            InvalidAutocomplete::MisplacedToken(token) => diagnostic.note(markup! {
+                <Emphasis>{token}</Emphasis>" isn't allowed at this position."
+            }),
+            InvalidAutocomplete::CombinedOnOff => diagnostic.note(markup! {
+                <Emphasis>"on"</Emphasis>" and "<Emphasis>"off"</Emphasis>" can't be combined with other tokens."
+            }),
+            InvalidAutocomplete::ContactTokenOnNonContactField { contact, field } => diagnostic
+                .note(markup! {
+                    <Emphasis>{contact}</Emphasis>" can only precede a contact field such as "<Emphasis>"tel"</Emphasis>" or "<Emphasis>"email"</Emphasis>", but "<Emphasis>{field}</Emphasis>" isn't one."
+                }),
+            InvalidAutocomplete::MissingFieldName => diagnostic.note(markup! {
+                "The value doesn't contain a field name such as "<Emphasis>"name"</Emphasis>" or "<Emphasis>"email"</Emphasis>"."
+            }),
+            InvalidAutocomplete::InvalidFormValue => diagnostic.note(markup! {
+                "The "<Emphasis>"autocomplete"</Emphasis>" attribute of a form only accepts "<Emphasis>"on"</Emphasis>" or "<Emphasis>"off"</Emphasis>"."
+            }),
+        };
+        Some(diagnostic.note(markup! {
+            "The assistive technologies and the browsers rely on a valid value to identify the purpose of the control."
+        }))
+    }
+}
+
+/// Validates a lowercase value of the `autocomplete` attribute of a form control.
+fn validate_autocomplete(value: &str) -> Result<(), InvalidAutocomplete> {
+    let tokens: Vec<_> = value.split_ascii_whitespace().collect();
+    match tokens.as_slice() {
+        // An empty value uses the default autofill of the browser
+        [] | ["on" | "off"] => return Ok(()),
+        _ if tokens.iter().any(|token| matches!(*token, "on" | "off")) => {
+            return Err(InvalidAutocomplete::CombinedOnOff);
+        }
+        _ => {}
+    }
+    let mut tokens = tokens.into_iter().peekable();
+    tokens.next_if(|token| token.len() > "section-".len() && token.starts_with("section-"));
+    tokens.next_if(|token| matches!(*token, "shipping" | "billing"));
+    let contact = tokens.next_if(|token| CONTACT_TOKENS.contains(token));
+    let field = tokens.next().ok_or(InvalidAutocomplete::MissingFieldName)?;
+    if CONTACT_FIELD_NAMES.contains(&field) {
+        // Any contact token is allowed
+    } else if FIELD_NAMES.contains(&field) {
+        if let Some(contact) = contact {
+            return Err(InvalidAutocomplete::ContactTokenOnNonContactField {
+                contact: contact.to_string(),
+                field: field.to_string(),
+            });
+        }
+    } else {
+        return Err(invalid_token(field));
+    }
+    tokens.next_if_eq(&"webauthn");
+    match tokens.next() {
+        Some(token) => Err(invalid_token(token)),
+        None => Ok(()),
+    }
+}
+
+fn invalid_token(token: &str) -> InvalidAutocomplete {
+    let is_known_token = token.starts_with("section-")
+        || matches!(token, "shipping" | "billing" | "webauthn")
+        || CONTACT_TOKENS.contains(&token)
+        || FIELD_NAMES.contains(&token)
+        || CONTACT_FIELD_NAMES.contains(&token);
+    if is_known_token {
+        InvalidAutocomplete::MisplacedToken(token.to_string())
+    } else {
+        InvalidAutocomplete::UnknownToken(token.to_string())
+    }
+}
@@ -0,0 +1,179 @@
+use crate::services::semantic::Semantic;
+use biome_analyze::{
+    context::RuleContext, declare_rule, Rule, RuleDiagnostic, RuleDomain, RuleSource,
+    RuleSourceKind,
+};
+use biome_console::markup;
+use biome_js_syntax::{
+    AnyJsExpression, JsCallExpression, JsFileSource, JsSyntaxKind, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+
+declare_rule! {
+    /// Enforce the valid usage of Svelte runes.
+    ///
+    /// Runes are compiler instructions: the Svelte compiler rejects a rune that isn't used where it expects it.
+    ///
+    /// - `$state`, `$state.raw`, `$derived`, `$derived.by`, and `$props` must initialize a variable or a class field.
+    /// - `$effect`, `$effect.pre`, and `$inspect` must be used as statements.
+    /// - `$state` accepts at most one argument, `$derived` and `$effect` exactly one, and `$props` none.
+    ///
+    /// The rule only applies to Svelte components and to `.svelte.js` and `.svelte.ts` modules.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```js
+    /// // Invalid, in a Svelte component
+    /// let count;
+    /// count = $state(0);
+    ///
+    /// const double = $derived();
+    ///
+    /// const cleanup = $effect(() => {});
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```js
+    /// let count = $state(0);
+    /// const double = $derived(count * 2);
+    ///
+    /// $effect(() => {
+    ///     console.log(double);
+    /// });
+    /// ```
+    ///
+    pub UseValidRunes {
+        version: "next",
+        name: "useValidRunes",
+        language: "js",
+        sources: &[RuleSource::EslintSvelte("valid-compile")],
+        source_kind: RuleSourceKind::Inspired,
+        domains: &[RuleDomain::Svelte],
+        recommended: false,
+    }
+}
+
+pub enum InvalidRune {
+    /// The rune must initialize a variable or a class field.
+    Initializer(&'static str),
+    /// The rune must be used as a statement.
+    Statement(&'static str),
+    /// The rune receives an unexpected number of arguments.
+    Arguments(&'static str, &'static str),
+}
+
+impl Rule for UseValidRunes {
+    type Query = Semantic<JsCallExpression>;
+    type State = (InvalidRune, TextRange);
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let call = ctx.query();
+        let model = ctx.model();
+        let is_svelte = ctx
+            .source_type::<JsFileSource>()
+            .as_embedding_kind()
+            .is_svelte()
+            || ctx
+                .file_path()
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.ends_with(".svelte"));
+        if !is_svelte {
+            return None;
+        }
+        let callee = call.callee().ok()?;
+        let (object, name) = match &callee {
+            AnyJsExpression::JsIdentifierExpression(identifier) => (identifier.clone(), None),
+            AnyJsExpression::JsStaticMemberExpression(member) => (
+                member.object().ok()?.as_js_identifier_expression()?.clone(),
+                Some(member.member().ok()?.value_token().ok()?),
+            ),
+            _ => return None,
+        };
+        let reference = object.name().ok()?;
+        // A rune is a global: a binding named after a rune isn't a rune.
+        if model.binding(&reference).is_some() {
+            return None;
+        }
+        let rune = match (
+            reference.value_token().ok()?.text_trimmed(),
+            name.as_ref().map(|name| name.text_trimmed()),
+        ) {
+            ("$state", None) => "$state",
+            ("$state", Some("raw")) => "$state.raw",
+            ("$derived", None) => "$derived",
+            ("$derived", Some("by")) => "$derived.by",
+            ("$props", None) => "$props",
+            ("$effect", None) => "$effect",
+            ("$effect", Some("pre")) => "$effect.pre",
+            ("$inspect", None) => "$inspect",
+            _ => return None,
+        };
+        let range = callee.range();
+        let parent = call.syntax().parent()?;
+        let is_initializer = parent.kind() == JsSyntaxKind::JS_INITIALIZER_CLAUSE
+            && parent.parent().is_some_and(|grand_parent| {
+                JsVariableDeclarator::can_cast(grand_parent.kind())
+                    || grand_parent.kind() == JsSyntaxKind::JS_PROPERTY_CLASS_MEMBER
+            });
+        let is_statement = parent.kind() == JsSyntaxKind::JS_EXPRESSION_STATEMENT;
+        match rune {
+            // `$inspect(value).with(callback)`
+            "$inspect" if parent.kind() == JsSyntaxKind::JS_STATIC_MEMBER_EXPRESSION => {}
+            "$effect" | "$effect.pre" | "$inspect" if !is_statement => {
+                return Some((InvalidRune::Statement(rune), range));
+            }
+            "$state" | "$state.raw" | "$derived" | "$derived.by" | "$props" if !is_initializer => {
+                return Some((InvalidRune::Initializer(rune), range));
+            }
+            _ => {}
+        }
+        let arguments = call.arguments().ok()?.args().len();
+        let expected = match rune {
+            "$state" | "$state.raw" if arguments > 1 => "at most one argument",
+            "$derived" | "$derived.by" | "$effect" | "$effect.pre" if arguments != 1 => {
+                "exactly one argument"
+            }
+            "$props" if arguments != 0 => "no argument",
+            _ => return None,
+        };
+        Some((InvalidRune::Arguments(rune, expected), call.range()))
+    }
+
+    fn diagnostic(
+        _ctx: &RuleContext<Self>,
+        (invalid, range): &Self::State,
+    ) -> Option<RuleDiagnostic> {
+        let diagnostic = match invalid {
+            InvalidRune::Initializer(rune) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    <Emphasis>{rune}</Emphasis>" can only be used as the initializer of a variable or a class field."
+                },
+            ),
+            InvalidRune::Statement(rune) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    <Emphasis>{rune}</Emphasis>" can only be used as a statement."
+                },
+            ),
+            InvalidRune::Arguments(rune, expected) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    <Emphasis>{rune}</Emphasis>" expects "{expected}"."
+                },
+            ),
+        };
+        Some(diagnostic.note(markup! {
+            "Runes are compiler instructions, and the Svelte compiler rejects this usage."
+        }))
+    }
+}
@@ -4,7 +4,11 @@ use biome_analyze::{
 };
 use biome_console::markup;
 use biome_deserialize_macros::Deserializable;
-use biome_rowan::TextRange;
+use biome_js_syntax::{
+    AnyJsBinding, AnyJsExportClause, AnyJsExportDefaultDeclaration, AnyJsExportNamedSpecifier,
+    AnyJsModuleItem, AnyJsRoot, JsSyntaxToken,
+};
+use biome_rowan::{AstSeparatedList, TextRange};
 use biome_string_case::{Case, Cases};
 use rustc_hash::FxHashSet;
 use serde::{Deserialize, Serialize};
@@ -64,7 +68,8 @@ declare_rule! {
     ///     "options": {
     ///         "strictCase": false,
     ///         "requireAscii": true,
-    ///         "filenameCases": ["camelCase", "export"]
+    ///         "filenameCases": ["camelCase", "export"],
+    ///         "matchDefaultExport": false
     ///     }
     /// }
     /// ```
@@ -99,6 +104,18 @@ declare_rule! {
     /// You can enforce a stricter convention by setting `filenameCases` option.
     /// `filenameCases` accepts an array of cases among the following cases: [`camelCase`], [`kebab-case`], [`PascalCase`], [`snake_case`], and `export`.
     ///
+    /// ### matchDefaultExport
+    ///
+    /// When this option is set to `true`, the filename of a module with a named default export
+    /// must be equal to the name of the default export converted in one of the cases of `filenameCases`.
+    /// If `filenameCases` includes `export`, then the filename can also be equal to the unconverted name.
+    /// Modules without a named default export are checked as usual.
+    ///
+    /// For instance, with `filenameCases` set to `["kebab-case"]`,
+    /// a module that includes `export default function MyComponent() {}` must be named `my-component.js`.
+    ///
+    /// Default: `false`
+    ///
     /// [case]: https://en.wikipedia.org/wiki/Naming_convention_(programming)#Examples_of_multiple-word_identifier_formats
     /// [`camelCase`]: https://en.wikipedia.org/wiki/Camel_case
     /// [`kebab-case`]: https://en.wikipedia.org/wiki/Letter_case#Kebab_case
@@ -147,6 +164,15 @@ impl Rule for UseFilenamingConvention {
                 return Some(FileNamingConventionState::Extension);
             }
         }
+        if options.match_default_export {
+            if let Some(default_export_name) = default_export_name(&ctx.root()) {
+                let default_export_name = default_export_name.text_trimmed();
+                let is_matching = expected_filenames(default_export_name, options)
+                    .any(|expected_name| expected_name == name);
+                return (!is_matching)
+                    .then(|| FileNamingConventionState::DefaultExport(default_export_name.into()));
+            }
+        }
         // Check filename case
         if !allowed_cases.is_empty() {
             let trimmed_name = name.trim_matches('_');
@@ -247,6 +273,34 @@ impl Rule for UseFilenamingConvention {
                     "The filename could be renamed to one of the following names:\n"{suggested_filenames}
                 }))
             },
+            FileNamingConventionState::DefaultExport(default_export_name) => {
+                let mut splitted = file_name.split('.');
+                let name = splitted.next()?;
+                let name = if name.is_empty() {
+                    // The filename starts with a dot
+                    splitted.next()?
+                } else if let Some(stripped_name) = name.strip_prefix('+') {
+                    stripped_name
+                } else {
+                    name
+                };
+                let suggested_filenames = expected_filenames(default_export_name, options)
+                    .map(|expected_name| file_name.replacen(name, &expected_name, 1))
+                    // Deduplicate suggestions
+                    .collect::<FxHashSet<_>>()
+                    .into_iter()
+                    .collect::<SmallVec<[_; 4]>>()
+                    .join("\n");
+                Some(RuleDiagnostic::new(
+                    rule_category!(),
+                    None as Option<TextRange>,
+                    markup! {
+                        "The filename should match the name of the default export "<Emphasis>{default_export_name.as_ref()}</Emphasis>" because "<Emphasis>"matchDefaultExport"</Emphasis>" is set to `true`."
+                    },
+                ).note(markup! {
+                    "The filename could be renamed to one of the following names:\n"{suggested_filenames}
+                }))
+            },
             FileNamingConventionState::Extension => {
                 Some(RuleDiagnostic::new(
                     rule_category!(),
@@ -268,6 +322,82 @@ pub enum FileNamingConventionState {
     Filename,
     /// An extension is not in lowercase
     Extension,
+    /// The filename doesn't match the name of the default export
+    DefaultExport(Box<str>),
+}
+
+/// Returns the names that a file with a default export named `default_export_name` can have.
+fn expected_filenames<'a>(
+    default_export_name: &'a str,
+    options: &'a FilenamingConventionOptions,
+) -> impl Iterator<Item = String> + 'a {
+    let unconverted_name = options
+        .filename_cases
+        .0
+        .contains(&FilenameCase::Export)
+        .then(|| default_export_name.to_string());
+    options
+        .filename_cases
+        .cases()
+        .into_iter()
+        .map(|case| case.convert(default_export_name))
+        .chain(unconverted_name)
+}
+
+/// Returns the name of the default export of the module if it has one.
+fn default_export_name(root: &AnyJsRoot) -> Option<JsSyntaxToken> {
+    let module = root.as_js_module()?;
+    module.items().into_iter().find_map(|item| {
+        let AnyJsModuleItem::JsExport(export) = item else {
+            return None;
+        };
+        match export.export_clause().ok()? {
+            AnyJsExportClause::JsExportDefaultDeclarationClause(clause) => {
+                let binding = match clause.declaration().ok()? {
+                    AnyJsExportDefaultDeclaration::JsClassExportDefaultDeclaration(class) => {
+                        class.id()?
+                    }
+                    AnyJsExportDefaultDeclaration::JsFunctionExportDefaultDeclaration(function) => {
+                        function.id()?
+                    }
+                    AnyJsExportDefaultDeclaration::TsDeclareFunctionExportDefaultDeclaration(
+                        function,
+                    ) => function.id()?,
+                    AnyJsExportDefaultDeclaration::TsInterfaceDeclaration(interface) => {
+                        return interface.id().ok()?.name_token().ok();
+                    }
+                };
+                match binding {
+                    AnyJsBinding::JsIdentifierBinding(binding) => binding.name_token().ok(),
+                    _ => None,
+                }
+            }
+            AnyJsExportClause::JsExportDefaultExpressionClause(clause) => clause
+                .expression()
+                .ok()?
+                .omit_parentheses()
+                .as_js_identifier_expression()?
+                .name()
+                .ok()?
+                .value_token()
+                .ok(),
+            AnyJsExportClause::JsExportNamedClause(clause) => {
+                clause.specifiers().iter().find_map(|specifier| {
+                    let AnyJsExportNamedSpecifier::JsExportNamedSpecifier(specifier) =
+                        specifier.ok()?
+                    else {
+                        return None;
+                    };
+                    let exported_name = specifier.exported_name().ok()?.value().ok()?;
+                    if exported_name.text_trimmed() != "default" {
+                        return None;
+                    }
+                    specifier.local_name().ok()?.value_token().ok()
+                })
+            }
+            _ => None,
+        }
+    })
 }
 
 /// Rule's options.
@@ -287,6 +417,11 @@ pub struct FilenamingConventionOptions {
     /// Allowed cases for file names.
     #[serde(default, skip_serializing_if = "is_default_filename_cases")]
     pub filename_cases: FilenameCases,
+
+    /// If `true`, then the filename of a module with a named default export
+    /// must match the name of the default export in one of the allowed cases.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub match_default_export: bool,
 }
 
 const fn enabled() -> bool {
@@ -311,6 +446,7 @@ impl Default for FilenamingConventionOptions {
             strict_case: true,
             require_ascii: false,
             filename_cases: FilenameCases::default(),
+            match_default_export: false,
         }
     }
 }
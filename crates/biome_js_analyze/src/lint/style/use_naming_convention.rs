@@ -2,10 +2,7 @@ use std::ops::{Deref, Range};
 
 use crate::{
     services::{control_flow::AnyJsControlFlowRoot, semantic::Semantic},
-    utils::{
-        regex::RestrictedRegex,
-        rename::{AnyJsRenamableDeclaration, RenameSymbolExtensions},
-    },
+    utils::rename::{AnyJsRenamableDeclaration, RenameSymbolExtensions},
     JsRuleAction,
 };
 use biome_analyze::{
@@ -13,7 +10,7 @@ use biome_analyze::{
     RuleSourceKind,
 };
 use biome_console::markup;
-use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic};
+use biome_deserialize::{DeserializableValidator, DeserializationDiagnostic, RestrictedRegex};
 use biome_deserialize_macros::Deserializable;
 use biome_diagnostics::Applicability;
 use biome_js_semantic::{CanBeImportedExported, SemanticModel};
@@ -392,6 +389,26 @@ declare_rule! {
     /// - `formats`: the string [case] that the name must follow.
     ///   The supported cases are: [`PascalCase`], [`CONSTANT_CASE`], [`camelCase`], and [`snake_case`].
     ///
+    /// A convention can also set a `message` that is displayed in the diagnostics of the declarations that don't fulfill its requirements.
+    /// For example, you can explain why interfaces must not start with `I`:
+    ///
+    /// ```json
+    /// {
+    ///     "//": "...",
+    ///     "options": {
+    ///         "conventions": [
+    ///             {
+    ///                 "selector": {
+    ///                     "kind": "interface"
+    ///                 },
+    ///                 "match": "(?:[^I].*|I[a-z].*)",
+    ///                 "message": "Interfaces must not be prefixed with `I`."
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
     /// If `match` is set and `formats` is unset,
     /// then the part of the name captured by the regular expression is forwarded to the next convention of the array.
     ///
@@ -506,6 +523,7 @@ impl Rule for UseNamingConvention {
                     end: name.len() as u16,
                 },
                 suggestion: Suggestion::Ascii,
+                message: None,
             });
         }
         let node_selector = Selector::from_name(node)?;
@@ -524,6 +542,7 @@ impl Rule for UseNamingConvention {
                             end: (name_range_start + name.len()) as u16,
                         },
                         suggestion: Suggestion::Match(matching.as_source().to_string()),
+                        message: convention.message.clone(),
                     });
                 };
                 if let Some(first_capture) = capture.iter().skip(1).find_map(|x| x) {
@@ -553,6 +572,7 @@ impl Rule for UseNamingConvention {
                         end: (name_range_start + name.len()) as u16,
                     },
                     suggestion: Suggestion::Formats(convention.formats.clone()),
+                    message: convention.message.clone(),
                 });
             }
         }
@@ -575,6 +595,7 @@ impl Rule for UseNamingConvention {
                 end: (name_range_start + name.len()) as u16,
             },
             suggestion: Suggestion::Formats(default_convention.formats.clone()),
+            message: None,
         })
     }
 
@@ -583,6 +604,7 @@ impl Rule for UseNamingConvention {
             convention_selector,
             name_range,
             suggestion,
+            message,
         } = state;
         let options = ctx.options();
         let node = ctx.query();
@@ -594,7 +616,11 @@ impl Rule for UseNamingConvention {
         } else {
             ""
         };
-        match suggestion {
+        let with_message = |diagnostic: RuleDiagnostic| match message {
+            Some(message) => diagnostic.note(markup! { {message} }),
+            None => diagnostic,
+        };
+        let diagnostic = match suggestion {
             Suggestion::Ascii => {
                 Some(RuleDiagnostic::new(
                     rule_category!(),
@@ -624,7 +650,7 @@ impl Rule for UseNamingConvention {
                     if matches!(actual_case, Case::Camel | Case::Pascal)
                         && Case::identify(trimmed_name, true) == Case::Unknown
                     {
-                        return Some(RuleDiagnostic::new(
+                        return Some(with_message(RuleDiagnostic::new(
                             rule_category!(),
                             name_token_range,
                             markup! {
@@ -632,7 +658,7 @@ impl Rule for UseNamingConvention {
                             },
                         ).note(markup! {
                             "If you want to use consecutive uppercase characters in "{format_args!("{actual_case}")}", then set the "<Emphasis>"strictCase"</Emphasis>" option to `false`.\nSee the rule "<Hyperlink href="https://biomejs.dev/linter/rules/use-naming-convention#options">"options"</Hyperlink>" for more details."
-                        }));
+                        })));
                     }
                 }
                 let expected_case_names = expected_cases
@@ -648,7 +674,8 @@ impl Rule for UseNamingConvention {
                     },
                 ))
             },
-        }
+        };
+        diagnostic.map(with_message)
     }
 
     fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsRuleAction> {
@@ -721,11 +748,13 @@ impl AnyIdentifierBindingLike {
 
 #[derive(Debug)]
 pub struct State {
-    // Selector of the convention which is not fullfilled.
+    // Selector of the convention which is not fulfilled.
     convention_selector: Selector,
     // Range of the name where the suggestion applies
     name_range: Range<u16>,
     suggestion: Suggestion,
+    // Message of the custom convention which is not fulfilled.
+    message: Option<String>,
 }
 
 #[derive(Debug)]
@@ -836,6 +865,10 @@ pub struct Convention {
     /// String cases to enforce
     #[serde(default, skip_serializing_if = "is_default")]
     formats: Formats,
+
+    /// Message displayed when the convention is not fulfilled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
 }
 
 impl DeserializableValidator for Convention {
@@ -1193,28 +1226,33 @@ impl Selector {
                 selector: Selector::with_modifiers(self.kind, Modifier::Readonly),
                 matching: None,
                 formats: Formats(Case::Camel | Case::Constant),
+                message: None,
             },
             Kind::TypeGetter => Convention {
                 selector: kind.into(),
                 matching: None,
                 formats: Formats(Case::Camel | Case::Constant),
+                message: None,
             },
             Kind::Function if Scope::Global.contains(self.scope) => Convention {
                 selector: Selector::with_scope(kind, Scope::Global),
                 matching: None,
                 formats: Formats(Case::Camel | Case::Pascal | Case::Upper),
+                message: None,
             },
             Kind::Variable | Kind::Const | Kind::Var if Scope::Global.contains(self.scope) => {
                 Convention {
                     selector: Selector::with_scope(kind, Scope::Global),
                     matching: None,
                     formats: Formats(Case::Camel | Case::Pascal | Case::Constant),
+                    message: None,
                 }
             }
             Kind::Any | Kind::ExportAlias | Kind::ImportAlias => Convention {
                 selector: kind.into(),
                 matching: None,
                 formats: Formats(Case::Camel | Case::Pascal | Case::Constant),
+                message: None,
             },
             Kind::ClassProperty | Kind::ClassGetter
                 if self.modifiers.contains(Modifier::Static) =>
@@ -1223,6 +1261,7 @@ impl Selector {
                     selector: Selector::with_modifiers(kind, Modifier::Static),
                     matching: None,
                     formats: Formats(Case::Camel | Case::Constant),
+                    message: None,
                 }
             }
             Kind::CatchParameter
@@ -1245,6 +1284,7 @@ impl Selector {
                 selector: kind.into(),
                 matching: None,
                 formats: Formats(Case::Camel.into()),
+                message: None,
             },
             Kind::TypeLike
             | Kind::Class
@@ -1255,16 +1295,19 @@ impl Selector {
                 selector: kind.into(),
                 matching: None,
                 formats: Formats(Case::Pascal.into()),
+                message: None,
             },
             Kind::EnumMember => Convention {
                 selector: kind.into(),
                 matching: None,
                 formats: Formats(Case::from(options.enum_member_case).into()),
+                message: None,
             },
             Kind::Variable | Kind::Const | Kind::Var | Kind::Let => Convention {
                 selector: kind.into(),
                 matching: None,
                 formats: Formats(Case::Camel | Case::Pascal),
+                message: None,
             },
             Kind::Function
             | Kind::ExportNamespace
@@ -1275,6 +1318,7 @@ impl Selector {
                 selector: kind.into(),
                 matching: None,
                 formats: Formats(Case::Camel | Case::Pascal),
+                message: None,
             },
         }
     }
@@ -11,8 +11,12 @@ pub type NoAriaUnsupportedElements = < lint :: a11y :: no_aria_unsupported_eleme
 pub type NoArrayIndexKey =
     <lint::suspicious::no_array_index_key::NoArrayIndexKey as biome_analyze::Rule>::Options;
 pub type NoAssignInExpressions = < lint :: suspicious :: no_assign_in_expressions :: NoAssignInExpressions as biome_analyze :: Rule > :: Options ;
+pub type NoAsyncInComputed =
+    <lint::nursery::no_async_in_computed::NoAsyncInComputed as biome_analyze::Rule>::Options;
 pub type NoAsyncPromiseExecutor = < lint :: suspicious :: no_async_promise_executor :: NoAsyncPromiseExecutor as biome_analyze :: Rule > :: Options ;
 pub type NoAutofocus = <lint::a11y::no_autofocus::NoAutofocus as biome_analyze::Rule>::Options;
+pub type NoAwaitInLoop =
+    <lint::nursery::no_await_in_loop::NoAwaitInLoop as biome_analyze::Rule>::Options;
 pub type NoBannedTypes =
     <lint::complexity::no_banned_types::NoBannedTypes as biome_analyze::Rule>::Options;
 pub type NoBarrelFile =
@@ -31,6 +35,8 @@ pub type NoCommentText =
     <lint::suspicious::no_comment_text::NoCommentText as biome_analyze::Rule>::Options;
 pub type NoCompareNegZero =
     <lint::suspicious::no_compare_neg_zero::NoCompareNegZero as biome_analyze::Rule>::Options;
+pub type NoConditionalTests =
+    <lint::nursery::no_conditional_tests::NoConditionalTests as biome_analyze::Rule>::Options;
 pub type NoConfusingLabels =
     <lint::suspicious::no_confusing_labels::NoConfusingLabels as biome_analyze::Rule>::Options;
 pub type NoConfusingVoidType =
@@ -54,8 +60,13 @@ pub type NoDebugger = <lint::suspicious::no_debugger::NoDebugger as biome_analyz
 pub type NoDefaultExport =
     <lint::style::no_default_export::NoDefaultExport as biome_analyze::Rule>::Options;
 pub type NoDelete = <lint::performance::no_delete::NoDelete as biome_analyze::Rule>::Options;
+pub type NoDeprecatedApis =
+    <lint::nursery::no_deprecated_apis::NoDeprecatedApis as biome_analyze::Rule>::Options;
+pub type NoDirectStateMutation = < lint :: nursery :: no_direct_state_mutation :: NoDirectStateMutation as biome_analyze :: Rule > :: Options ;
 pub type NoDistractingElements =
     <lint::a11y::no_distracting_elements::NoDistractingElements as biome_analyze::Rule>::Options;
+pub type NoDocumentCookie =
+    <lint::nursery::no_document_cookie::NoDocumentCookie as biome_analyze::Rule>::Options;
 pub type NoDoneCallback =
     <lint::nursery::no_done_callback::NoDoneCallback as biome_analyze::Rule>::Options;
 pub type NoDoubleEquals =
@@ -70,6 +81,7 @@ pub type NoDuplicateJsxProps =
 pub type NoDuplicateObjectKeys = < lint :: suspicious :: no_duplicate_object_keys :: NoDuplicateObjectKeys as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateParameters = < lint :: suspicious :: no_duplicate_parameters :: NoDuplicateParameters as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateTestHooks = < lint :: suspicious :: no_duplicate_test_hooks :: NoDuplicateTestHooks as biome_analyze :: Rule > :: Options ;
+pub type NoDuplicateTestTitles = < lint :: nursery :: no_duplicate_test_titles :: NoDuplicateTestTitles as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyBlockStatements = < lint :: suspicious :: no_empty_block_statements :: NoEmptyBlockStatements as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyCharacterClassInRegex = < lint :: correctness :: no_empty_character_class_in_regex :: NoEmptyCharacterClassInRegex as biome_analyze :: Rule > :: Options ;
 pub type NoEmptyInterface =
@@ -81,6 +93,9 @@ pub type NoEvolvingAny =
     <lint::nursery::no_evolving_any::NoEvolvingAny as biome_analyze::Rule>::Options;
 pub type NoExcessiveCognitiveComplexity = < lint :: complexity :: no_excessive_cognitive_complexity :: NoExcessiveCognitiveComplexity as biome_analyze :: Rule > :: Options ;
 pub type NoExcessiveNestedTestSuites = < lint :: complexity :: no_excessive_nested_test_suites :: NoExcessiveNestedTestSuites as biome_analyze :: Rule > :: Options ;
+pub type NoExcessiveParameters =
+    <lint::nursery::no_excessive_parameters::NoExcessiveParameters as biome_analyze::Rule>::Options;
+pub type NoExcessivelyLongFunctions = < lint :: nursery :: no_excessively_long_functions :: NoExcessivelyLongFunctions as biome_analyze :: Rule > :: Options ;
 pub type NoExplicitAny =
     <lint::suspicious::no_explicit_any::NoExplicitAny as biome_analyze::Rule>::Options;
 pub type NoExportsInTest =
@@ -98,6 +113,7 @@ pub type NoFunctionAssign =
     <lint::suspicious::no_function_assign::NoFunctionAssign as biome_analyze::Rule>::Options;
 pub type NoGlobalAssign =
     <lint::suspicious::no_global_assign::NoGlobalAssign as biome_analyze::Rule>::Options;
+pub type NoGlobalDirnameFilename = < lint :: nursery :: no_global_dirname_filename :: NoGlobalDirnameFilename as biome_analyze :: Rule > :: Options ;
 pub type NoGlobalEval =
     <lint::security::no_global_eval::NoGlobalEval as biome_analyze::Rule>::Options;
 pub type NoGlobalIsFinite =
@@ -105,12 +121,18 @@ pub type NoGlobalIsFinite =
 pub type NoGlobalIsNan =
     <lint::suspicious::no_global_is_nan::NoGlobalIsNan as biome_analyze::Rule>::Options;
 pub type NoGlobalObjectCalls = < lint :: correctness :: no_global_object_calls :: NoGlobalObjectCalls as biome_analyze :: Rule > :: Options ;
+pub type NoHeadElement =
+    <lint::nursery::no_head_element::NoHeadElement as biome_analyze::Rule>::Options;
 pub type NoHeaderScope =
     <lint::a11y::no_header_scope::NoHeaderScope as biome_analyze::Rule>::Options;
+pub type NoImgElement =
+    <lint::nursery::no_img_element::NoImgElement as biome_analyze::Rule>::Options;
 pub type NoImplicitAnyLet =
     <lint::suspicious::no_implicit_any_let::NoImplicitAnyLet as biome_analyze::Rule>::Options;
 pub type NoImplicitBoolean =
     <lint::style::no_implicit_boolean::NoImplicitBoolean as biome_analyze::Rule>::Options;
+pub type NoImplicitCoercion =
+    <lint::nursery::no_implicit_coercion::NoImplicitCoercion as biome_analyze::Rule>::Options;
 pub type NoImportAssign =
     <lint::suspicious::no_import_assign::NoImportAssign as biome_analyze::Rule>::Options;
 pub type NoInferrableTypes =
@@ -122,6 +144,9 @@ pub type NoInvalidConstructorSuper = < lint :: correctness :: no_invalid_constru
 pub type NoInvalidNewBuiltin = < lint :: correctness :: no_invalid_new_builtin :: NoInvalidNewBuiltin as biome_analyze :: Rule > :: Options ;
 pub type NoInvalidUseBeforeDeclaration = < lint :: correctness :: no_invalid_use_before_declaration :: NoInvalidUseBeforeDeclaration as biome_analyze :: Rule > :: Options ;
 pub type NoLabelVar = <lint::suspicious::no_label_var::NoLabelVar as biome_analyze::Rule>::Options;
+pub type NoLeakedConditionalRendering = < lint :: nursery :: no_leaked_conditional_rendering :: NoLeakedConditionalRendering as biome_analyze :: Rule > :: Options ;
+pub type NoMagicNumbers =
+    <lint::nursery::no_magic_numbers::NoMagicNumbers as biome_analyze::Rule>::Options;
 pub type NoMisleadingCharacterClass = < lint :: suspicious :: no_misleading_character_class :: NoMisleadingCharacterClass as biome_analyze :: Rule > :: Options ;
 pub type NoMisleadingInstantiator = < lint :: suspicious :: no_misleading_instantiator :: NoMisleadingInstantiator as biome_analyze :: Rule > :: Options ;
 pub type NoMisplacedAssertion =
@@ -139,9 +164,12 @@ pub type NoNodejsModules =
     <lint::nursery::no_nodejs_modules::NoNodejsModules as biome_analyze::Rule>::Options;
 pub type NoNonNullAssertion =
     <lint::style::no_non_null_assertion::NoNonNullAssertion as biome_analyze::Rule>::Options;
+pub type NoNonReactiveUpdates =
+    <lint::nursery::no_non_reactive_updates::NoNonReactiveUpdates as biome_analyze::Rule>::Options;
 pub type NoNoninteractiveElementToInteractiveRole = < lint :: a11y :: no_noninteractive_element_to_interactive_role :: NoNoninteractiveElementToInteractiveRole as biome_analyze :: Rule > :: Options ;
 pub type NoNoninteractiveTabindex = < lint :: a11y :: no_noninteractive_tabindex :: NoNoninteractiveTabindex as biome_analyze :: Rule > :: Options ;
 pub type NoNonoctalDecimalEscape = < lint :: correctness :: no_nonoctal_decimal_escape :: NoNonoctalDecimalEscape as biome_analyze :: Rule > :: Options ;
+pub type NoPackageInternalImports = < lint :: nursery :: no_package_internal_imports :: NoPackageInternalImports as biome_analyze :: Rule > :: Options ;
 pub type NoParameterAssign =
     <lint::style::no_parameter_assign::NoParameterAssign as biome_analyze::Rule>::Options;
 pub type NoParameterProperties =
@@ -162,6 +190,8 @@ pub type NoRedundantAlt =
     <lint::a11y::no_redundant_alt::NoRedundantAlt as biome_analyze::Rule>::Options;
 pub type NoRedundantRoles =
     <lint::a11y::no_redundant_roles::NoRedundantRoles as biome_analyze::Rule>::Options;
+pub type NoRedundantStoryName =
+    <lint::nursery::no_redundant_story_name::NoRedundantStoryName as biome_analyze::Rule>::Options;
 pub type NoRedundantUseStrict = < lint :: suspicious :: no_redundant_use_strict :: NoRedundantUseStrict as biome_analyze :: Rule > :: Options ;
 pub type NoRenderReturnValue = < lint :: correctness :: no_render_return_value :: NoRenderReturnValue as biome_analyze :: Rule > :: Options ;
 pub type NoRestrictedGlobals =
@@ -179,8 +209,14 @@ pub type NoShoutyConstants =
     <lint::style::no_shouty_constants::NoShoutyConstants as biome_analyze::Rule>::Options;
 pub type NoSkippedTests =
     <lint::suspicious::no_skipped_tests::NoSkippedTests as biome_analyze::Rule>::Options;
+pub type NoSolidDestructuredProps = < lint :: nursery :: no_solid_destructured_props :: NoSolidDestructuredProps as biome_analyze :: Rule > :: Options ;
+pub type NoSolidUncalledSignals = < lint :: nursery :: no_solid_uncalled_signals :: NoSolidUncalledSignals as biome_analyze :: Rule > :: Options ;
+pub type NoSolidUntrackedReads = < lint :: nursery :: no_solid_untracked_reads :: NoSolidUntrackedReads as biome_analyze :: Rule > :: Options ;
 pub type NoSparseArray =
     <lint::suspicious::no_sparse_array::NoSparseArray as biome_analyze::Rule>::Options;
+pub type NoStandaloneExpect =
+    <lint::nursery::no_standalone_expect::NoStandaloneExpect as biome_analyze::Rule>::Options;
+pub type NoStaticElementInteractions = < lint :: nursery :: no_static_element_interactions :: NoStaticElementInteractions as biome_analyze :: Rule > :: Options ;
 pub type NoStaticOnlyClass =
     <lint::complexity::no_static_only_class::NoStaticOnlyClass as biome_analyze::Rule>::Options;
 pub type NoStringCaseMismatch = < lint :: correctness :: no_string_case_mismatch :: NoStringCaseMismatch as biome_analyze :: Rule > :: Options ;
@@ -188,12 +224,16 @@ pub type NoSuspiciousSemicolonInJsx = < lint :: suspicious :: no_suspicious_semi
 pub type NoSvgWithoutTitle =
     <lint::a11y::no_svg_without_title::NoSvgWithoutTitle as biome_analyze::Rule>::Options;
 pub type NoSwitchDeclarations = < lint :: correctness :: no_switch_declarations :: NoSwitchDeclarations as biome_analyze :: Rule > :: Options ;
+pub type NoSyncScripts =
+    <lint::nursery::no_sync_scripts::NoSyncScripts as biome_analyze::Rule>::Options;
 pub type NoThenProperty =
     <lint::suspicious::no_then_property::NoThenProperty as biome_analyze::Rule>::Options;
 pub type NoThisInStatic =
     <lint::complexity::no_this_in_static::NoThisInStatic as biome_analyze::Rule>::Options;
+pub type NoUndeclaredClassNames = < lint :: nursery :: no_undeclared_class_names :: NoUndeclaredClassNames as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredDependencies = < lint :: nursery :: no_undeclared_dependencies :: NoUndeclaredDependencies as biome_analyze :: Rule > :: Options ;
 pub type NoUndeclaredVariables = < lint :: correctness :: no_undeclared_variables :: NoUndeclaredVariables as biome_analyze :: Rule > :: Options ;
+pub type NoUnnecessaryCondition = < lint :: nursery :: no_unnecessary_condition :: NoUnnecessaryCondition as biome_analyze :: Rule > :: Options ;
 pub type NoUnnecessaryContinue = < lint :: correctness :: no_unnecessary_continue :: NoUnnecessaryContinue as biome_analyze :: Rule > :: Options ;
 pub type NoUnreachable =
     <lint::correctness::no_unreachable::NoUnreachable as biome_analyze::Rule>::Options;
@@ -205,11 +245,15 @@ pub type NoUnsafeFinally =
 pub type NoUnsafeNegation =
     <lint::suspicious::no_unsafe_negation::NoUnsafeNegation as biome_analyze::Rule>::Options;
 pub type NoUnsafeOptionalChaining = < lint :: correctness :: no_unsafe_optional_chaining :: NoUnsafeOptionalChaining as biome_analyze :: Rule > :: Options ;
+pub type NoUnsafeTypeAssertion = < lint :: nursery :: no_unsafe_type_assertion :: NoUnsafeTypeAssertion as biome_analyze :: Rule > :: Options ;
+pub type NoUnstableNestedComponents = < lint :: nursery :: no_unstable_nested_components :: NoUnstableNestedComponents as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedComponentProps = < lint :: nursery :: no_unused_component_props :: NoUnusedComponentProps as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedImports =
     <lint::correctness::no_unused_imports::NoUnusedImports as biome_analyze::Rule>::Options;
 pub type NoUnusedLabels =
     <lint::correctness::no_unused_labels::NoUnusedLabels as biome_analyze::Rule>::Options;
 pub type NoUnusedPrivateClassMembers = < lint :: correctness :: no_unused_private_class_members :: NoUnusedPrivateClassMembers as biome_analyze :: Rule > :: Options ;
+pub type NoUnusedStoreSubscriptions = < lint :: nursery :: no_unused_store_subscriptions :: NoUnusedStoreSubscriptions as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedTemplateLiteral = < lint :: style :: no_unused_template_literal :: NoUnusedTemplateLiteral as biome_analyze :: Rule > :: Options ;
 pub type NoUnusedVariables =
     <lint::correctness::no_unused_variables::NoUnusedVariables as biome_analyze::Rule>::Options;
@@ -234,6 +278,8 @@ pub type NoUselessTernary =
 pub type NoUselessThisAlias =
     <lint::complexity::no_useless_this_alias::NoUselessThisAlias as biome_analyze::Rule>::Options;
 pub type NoUselessTypeConstraint = < lint :: complexity :: no_useless_type_constraint :: NoUselessTypeConstraint as biome_analyze :: Rule > :: Options ;
+pub type NoUselessUndefined =
+    <lint::nursery::no_useless_undefined::NoUselessUndefined as biome_analyze::Rule>::Options;
 pub type NoUselessUndefinedInitialization = < lint :: nursery :: no_useless_undefined_initialization :: NoUselessUndefinedInitialization as biome_analyze :: Rule > :: Options ;
 pub type NoVar = <lint::style::no_var::NoVar as biome_analyze::Rule>::Options;
 pub type NoVoid = <lint::complexity::no_void::NoVoid as biome_analyze::Rule>::Options;
@@ -247,29 +293,42 @@ pub type UseAnchorContent =
 pub type UseAriaActivedescendantWithTabindex = < lint :: a11y :: use_aria_activedescendant_with_tabindex :: UseAriaActivedescendantWithTabindex as biome_analyze :: Rule > :: Options ;
 pub type UseAriaPropsForRole =
     <lint::a11y::use_aria_props_for_role::UseAriaPropsForRole as biome_analyze::Rule>::Options;
+pub type UseAriaPropsSupportedByRole = < lint :: nursery :: use_aria_props_supported_by_role :: UseAriaPropsSupportedByRole as biome_analyze :: Rule > :: Options ;
 pub type UseArrayLiterals =
     <lint::nursery::use_array_literals::UseArrayLiterals as biome_analyze::Rule>::Options;
 pub type UseArrowFunction =
     <lint::complexity::use_arrow_function::UseArrowFunction as biome_analyze::Rule>::Options;
 pub type UseAsConstAssertion =
     <lint::style::use_as_const_assertion::UseAsConstAssertion as biome_analyze::Rule>::Options;
+pub type UseAtIndex = <lint::nursery::use_at_index::UseAtIndex as biome_analyze::Rule>::Options;
 pub type UseAwait = <lint::suspicious::use_await::UseAwait as biome_analyze::Rule>::Options;
+pub type UseAwaitStoryInteractions = < lint :: nursery :: use_await_story_interactions :: UseAwaitStoryInteractions as biome_analyze :: Rule > :: Options ;
+pub type UseAwaitThenable =
+    <lint::nursery::use_await_thenable::UseAwaitThenable as biome_analyze::Rule>::Options;
 pub type UseBlockStatements =
     <lint::style::use_block_statements::UseBlockStatements as biome_analyze::Rule>::Options;
 pub type UseButtonType =
     <lint::a11y::use_button_type::UseButtonType as biome_analyze::Rule>::Options;
 pub type UseCollapsedElseIf =
     <lint::style::use_collapsed_else_if::UseCollapsedElseIf as biome_analyze::Rule>::Options;
+pub type UseColorContrast =
+    <lint::nursery::use_color_contrast::UseColorContrast as biome_analyze::Rule>::Options;
 pub type UseConsistentArrayType = < lint :: style :: use_consistent_array_type :: UseConsistentArrayType as biome_analyze :: Rule > :: Options ;
 pub type UseConsistentBuiltinInstantiation = < lint :: nursery :: use_consistent_builtin_instantiation :: UseConsistentBuiltinInstantiation as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentCurlyBraces = < lint :: nursery :: use_consistent_curly_braces :: UseConsistentCurlyBraces as biome_analyze :: Rule > :: Options ;
+pub type UseConsistentTestFunction = < lint :: nursery :: use_consistent_test_function :: UseConsistentTestFunction as biome_analyze :: Rule > :: Options ;
 pub type UseConst = <lint::style::use_const::UseConst as biome_analyze::Rule>::Options;
 pub type UseDefaultParameterLast = < lint :: style :: use_default_parameter_last :: UseDefaultParameterLast as biome_analyze :: Rule > :: Options ;
 pub type UseDefaultSwitchClause = < lint :: nursery :: use_default_switch_clause :: UseDefaultSwitchClause as biome_analyze :: Rule > :: Options ;
 pub type UseDefaultSwitchClauseLast = < lint :: suspicious :: use_default_switch_clause_last :: UseDefaultSwitchClauseLast as biome_analyze :: Rule > :: Options ;
+pub type UseDefinePropsOrder =
+    <lint::nursery::use_define_props_order::UseDefinePropsOrder as biome_analyze::Rule>::Options;
 pub type UseEnumInitializers =
     <lint::style::use_enum_initializers::UseEnumInitializers as biome_analyze::Rule>::Options;
 pub type UseExhaustiveDependencies = < lint :: correctness :: use_exhaustive_dependencies :: UseExhaustiveDependencies as biome_analyze :: Rule > :: Options ;
+pub type UseExplicitFunctionReturnType = < lint :: nursery :: use_explicit_function_return_type :: UseExplicitFunctionReturnType as biome_analyze :: Rule > :: Options ;
 pub type UseExplicitLengthCheck = < lint :: nursery :: use_explicit_length_check :: UseExplicitLengthCheck as biome_analyze :: Rule > :: Options ;
+pub type UseExplicitMemberAccessibility = < lint :: nursery :: use_explicit_member_accessibility :: UseExplicitMemberAccessibility as biome_analyze :: Rule > :: Options ;
 pub type UseExponentiationOperator = < lint :: style :: use_exponentiation_operator :: UseExponentiationOperator as biome_analyze :: Rule > :: Options ;
 pub type UseExportType =
     <lint::style::use_export_type::UseExportType as biome_analyze::Rule>::Options;
@@ -281,6 +340,7 @@ pub type UseFragmentSyntax =
     <lint::style::use_fragment_syntax::UseFragmentSyntax as biome_analyze::Rule>::Options;
 pub type UseGetterReturn =
     <lint::suspicious::use_getter_return::UseGetterReturn as biome_analyze::Rule>::Options;
+pub type UseGoogleFontPreconnect = < lint :: nursery :: use_google_font_preconnect :: UseGoogleFontPreconnect as biome_analyze :: Rule > :: Options ;
 pub type UseHeadingContent =
     <lint::a11y::use_heading_content::UseHeadingContent as biome_analyze::Rule>::Options;
 pub type UseHookAtTopLevel =
@@ -316,8 +376,13 @@ pub type UseNumberNamespace =
     <lint::style::use_number_namespace::UseNumberNamespace as biome_analyze::Rule>::Options;
 pub type UseNumericLiterals =
     <lint::style::use_numeric_literals::UseNumericLiterals as biome_analyze::Rule>::Options;
+pub type UseNumericSeparators =
+    <lint::nursery::use_numeric_separators::UseNumericSeparators as biome_analyze::Rule>::Options;
+pub type UseObjectSpread =
+    <lint::nursery::use_object_spread::UseObjectSpread as biome_analyze::Rule>::Options;
 pub type UseOptionalChain =
     <lint::complexity::use_optional_chain::UseOptionalChain as biome_analyze::Rule>::Options;
+pub type UseReadonlyClassMembers = < lint :: nursery :: use_readonly_class_members :: UseReadonlyClassMembers as biome_analyze :: Rule > :: Options ;
 pub type UseRegexLiterals =
     <lint::complexity::use_regex_literals::UseRegexLiterals as biome_analyze::Rule>::Options;
 pub type UseSelfClosingElements = < lint :: style :: use_self_closing_elements :: UseSelfClosingElements as biome_analyze :: Rule > :: Options ;
@@ -333,11 +398,14 @@ pub type UseSingleCaseStatement = < lint :: style :: use_single_case_statement :
 pub type UseSingleVarDeclarator = < lint :: style :: use_single_var_declarator :: UseSingleVarDeclarator as biome_analyze :: Rule > :: Options ;
 pub type UseSortedClasses =
     <lint::nursery::use_sorted_classes::UseSortedClasses as biome_analyze::Rule>::Options;
+pub type UseStoryDefaultExport = < lint :: nursery :: use_story_default_export :: UseStoryDefaultExport as biome_analyze :: Rule > :: Options ;
+pub type UseStoryNamePascalCase = < lint :: nursery :: use_story_name_pascal_case :: UseStoryNamePascalCase as biome_analyze :: Rule > :: Options ;
 pub type UseTemplate = <lint::style::use_template::UseTemplate as biome_analyze::Rule>::Options;
 pub type UseThrowNewError =
     <lint::nursery::use_throw_new_error::UseThrowNewError as biome_analyze::Rule>::Options;
 pub type UseTopLevelRegex =
     <lint::nursery::use_top_level_regex::UseTopLevelRegex as biome_analyze::Rule>::Options;
+pub type UseTsconfigPathAliases = < lint :: nursery :: use_tsconfig_path_aliases :: UseTsconfigPathAliases as biome_analyze :: Rule > :: Options ;
 pub type UseValidAnchor =
     <lint::a11y::use_valid_anchor::UseValidAnchor as biome_analyze::Rule>::Options;
 pub type UseValidAriaProps =
@@ -346,8 +414,12 @@ pub type UseValidAriaRole =
     <lint::a11y::use_valid_aria_role::UseValidAriaRole as biome_analyze::Rule>::Options;
 pub type UseValidAriaValues =
     <lint::a11y::use_valid_aria_values::UseValidAriaValues as biome_analyze::Rule>::Options;
+pub type UseValidAutocomplete =
+    <lint::nursery::use_valid_autocomplete::UseValidAutocomplete as biome_analyze::Rule>::Options;
 pub type UseValidForDirection = < lint :: correctness :: use_valid_for_direction :: UseValidForDirection as biome_analyze :: Rule > :: Options ;
 pub type UseValidLang = <lint::a11y::use_valid_lang::UseValidLang as biome_analyze::Rule>::Options;
+pub type UseValidRunes =
+    <lint::nursery::use_valid_runes::UseValidRunes as biome_analyze::Rule>::Options;
 pub type UseValidTypeof =
     <lint::suspicious::use_valid_typeof::UseValidTypeof as biome_analyze::Rule>::Options;
 pub type UseWhile = <lint::style::use_while::UseWhile as biome_analyze::Rule>::Options;
@@ -0,0 +1,160 @@
+//! The class names and the identifiers that a module contributes to the [biome_project::StyleIndex] of a project.
+
+use biome_js_syntax::{AnyJsRoot, JsSyntaxKind, JsSyntaxNode, JsSyntaxToken, JsxAttribute};
+use biome_project::StyleSymbols;
+use biome_rowan::{AstNode, TextRange, TextSize};
+
+/// The attributes of the JSX elements that reference the class names of the stylesheets.
+pub(crate) const CLASS_ATTRIBUTES: [&str; 2] = ["class", "className"];
+
+/// Returns the class names and the identifiers referenced by the JSX attributes of a module.
+///
+/// The names are the static words of the attributes `class`, `className` and `id`,
+/// see [attribute_words].
+///
+/// ## Examples
+///
+/// ```
+/// use biome_js_analyze::project::referenced_style_symbols;
+/// use biome_js_parser::{parse, JsParserOptions};
+/// use biome_js_syntax::JsFileSource;
+///
+/// let source = r#"<div id="app" className={active ? "tab active" : "tab"} />"#;
+/// let root = parse(source, JsFileSource::jsx(), JsParserOptions::default()).tree();
+/// let symbols = referenced_style_symbols(&root);
+/// assert!(symbols.classes.contains("tab"));
+/// assert!(symbols.classes.contains("active"));
+/// assert!(symbols.ids.contains("app"));
+/// ```
+pub fn referenced_style_symbols(root: &AnyJsRoot) -> StyleSymbols {
+    let mut symbols = StyleSymbols::default();
+    for attribute in root.syntax().descendants().filter_map(JsxAttribute::cast) {
+        let Some(name) = attribute.name_value_token() else {
+            continue;
+        };
+        let names = match name.text_trimmed() {
+            "id" => &mut symbols.ids,
+            name if CLASS_ATTRIBUTES.contains(&name) => &mut symbols.classes,
+            _ => continue,
+        };
+        names.extend(
+            attribute_words(&attribute)
+                .into_iter()
+                .map(|(word, _)| word),
+        );
+    }
+    symbols
+}
+
+/// Returns the words of the value of a JSX attribute that are known statically, with their range.
+///
+/// The words are taken from the string literals and the template literals of the value,
+/// and from the names of the object members, such as the keys of the objects passed to `clsx`.
+/// The string literals compared with other values, such as `variant === "primary"`, aren't class names.
+/// The words of a template literal that are adjacent to a substitution are ignored,
+/// because they are only a part of a word built at runtime.
+pub(crate) fn attribute_words(attribute: &JsxAttribute) -> Vec<(String, TextRange)> {
+    let mut words = Vec::new();
+    let Some(value) = attribute
+        .initializer()
+        .and_then(|initializer| initializer.value().ok())
+    else {
+        return words;
+    };
+    for node in value.syntax().descendants() {
+        match node.kind() {
+            JsSyntaxKind::JSX_STRING | JsSyntaxKind::JS_STRING_LITERAL_EXPRESSION => {
+                let is_compared = node
+                    .parent()
+                    .is_some_and(|parent| parent.kind() == JsSyntaxKind::JS_BINARY_EXPRESSION);
+                if is_compared {
+                    continue;
+                }
+                if let Some(token) = node.first_token() {
+                    push_words(&token, true, true, true, &mut words);
+                }
+            }
+            JsSyntaxKind::JS_LITERAL_MEMBER_NAME => {
+                if let Some(token) = node.first_token() {
+                    match token.kind() {
+                        JsSyntaxKind::JS_STRING_LITERAL => {
+                            push_words(&token, true, true, true, &mut words)
+                        }
+                        JsSyntaxKind::IDENT => push_words(&token, false, true, true, &mut words),
+                        _ => {}
+                    }
+                }
+            }
+            JsSyntaxKind::JS_TEMPLATE_CHUNK_ELEMENT => {
+                if let Some(token) = node.first_token() {
+                    push_words(
+                        &token,
+                        false,
+                        !has_sibling_substitution(node.prev_sibling()),
+                        !has_sibling_substitution(node.next_sibling()),
+                        &mut words,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+fn has_sibling_substitution(sibling: Option<JsSyntaxNode>) -> bool {
+    sibling.is_some_and(|sibling| sibling.kind() == JsSyntaxKind::JS_TEMPLATE_ELEMENT)
+}
+
+/// Pushes the whitespace-separated words of `token`.
+///
+/// `is_quoted` tells whether the text of the token is surrounded by quotes.
+/// When `is_first_complete` or `is_last_complete` is `false`, the first or the last word is ignored
+/// unless it's separated from the boundary of the token by a whitespace.
+fn push_words(
+    token: &JsSyntaxToken,
+    is_quoted: bool,
+    is_first_complete: bool,
+    is_last_complete: bool,
+    words: &mut Vec<(String, TextRange)>,
+) {
+    let text = token.text_trimmed();
+    let (offset, text) = if is_quoted {
+        if text.len() < 2 {
+            return;
+        }
+        (1, &text[1..text.len() - 1])
+    } else {
+        (0, text)
+    };
+    let start = token.text_trimmed_range().start() + TextSize::from(offset);
+    let mut token_words = Vec::new();
+    let mut word_start = None;
+    for (index, char) in text.char_indices().chain([(text.len(), ' ')]) {
+        match (word_start, char.is_ascii_whitespace()) {
+            (None, false) => word_start = Some(index),
+            (Some(start_index), true) => {
+                token_words.push((start_index, index));
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+    if !is_first_complete && token_words.first().is_some_and(|(first, _)| *first == 0) {
+        token_words.remove(0);
+    }
+    if !is_last_complete
+        && token_words
+            .last()
+            .is_some_and(|(_, last)| *last == text.len())
+    {
+        token_words.pop();
+    }
+    for (start_index, end_index) in token_words {
+        let range = TextRange::new(
+            start + TextSize::from(start_index as u32),
+            start + TextSize::from(end_index as u32),
+        );
+        words.push((text[start_index..end_index].to_string(), range));
+    }
+}
@@ -8,8 +8,9 @@ use biome_diagnostics::Severity;
 use biome_js_semantic::{Capture, Closure, ClosureExtensions, SemanticModel};
 use biome_js_syntax::binding_ext::AnyJsBindingDeclaration;
 use biome_js_syntax::{
-    binding_ext::AnyJsIdentifierBinding, static_value::StaticValue, AnyJsExpression,
-    AnyJsMemberExpression, JsArrowFunctionExpression, JsCallExpression, JsFunctionExpression,
+    binding_ext::AnyJsIdentifierBinding, static_value::StaticValue, AnyJsBinding, AnyJsClass,
+    AnyJsExpression, AnyJsFunction, AnyJsMemberExpression, JsArrowFunctionExpression,
+    JsCallExpression, JsFunctionExpression, JsSyntaxKind, JsSyntaxNode, JsVariableDeclarator,
     TextRange,
 };
 use biome_js_syntax::{JsArrayBindingPatternElement, JsLanguage};
@@ -131,6 +132,65 @@ pub(crate) fn is_react_component(name: &str) -> bool {
     name.chars().next().is_some_and(char::is_uppercase)
 }
 
+/// Returns the binding that names the function or the class `node`.
+///
+/// This is either the name of the declaration, or the variable to which the function
+/// is assigned, possibly through a call to `memo` or `forwardRef`:
+///
+/// ```js
+/// function Component() {}
+/// const Component = () => {};
+/// const Component = memo(function () {});
+/// ```
+pub(crate) fn function_or_class_name(node: &JsSyntaxNode) -> Option<AnyJsBinding> {
+    let own_name = if let Some(function) = AnyJsFunction::cast_ref(node) {
+        function.binding()
+    } else if let Some(class) = AnyJsClass::cast_ref(node) {
+        class.id()
+    } else {
+        return None;
+    };
+    if own_name.is_some() {
+        return own_name;
+    }
+    let mut current = node.clone();
+    loop {
+        let parent = current.parent()?;
+        match parent.kind() {
+            JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION => current = parent,
+            JsSyntaxKind::JS_INITIALIZER_CLAUSE => {
+                let declarator = JsVariableDeclarator::cast(parent.parent()?)?;
+                return declarator.id().ok()?.as_any_js_binding().cloned();
+            }
+            JsSyntaxKind::JS_CALL_ARGUMENT_LIST => {
+                let call = JsCallExpression::cast(parent.parent()?.parent()?)?;
+                let callee = call.callee().ok()?.omit_parentheses();
+                let callee_name = match callee {
+                    AnyJsExpression::JsIdentifierExpression(identifier) => identifier
+                        .name()
+                        .ok()?
+                        .value_token()
+                        .ok()?
+                        .token_text_trimmed(),
+                    AnyJsExpression::JsStaticMemberExpression(member) => member
+                        .member()
+                        .ok()?
+                        .as_js_name()?
+                        .value_token()
+                        .ok()?
+                        .token_text_trimmed(),
+                    _ => return None,
+                };
+                if !matches!(callee_name.text(), "memo" | "forwardRef") {
+                    return None;
+                }
+                current = call.into_syntax();
+            }
+            _ => return None,
+        }
+    }
+}
+
 /// Checks whether the given function name belongs to a React hook, based on the
 /// official convention for React hook naming: Hook names must start with `use`
 /// followed by a capital letter.
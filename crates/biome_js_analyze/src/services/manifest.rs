@@ -5,6 +5,7 @@ use biome_analyze::{
 use biome_js_syntax::{AnyJsRoot, JsLanguage, JsSyntaxNode};
 use biome_project::PackageJson;
 use biome_rowan::AstNode;
+use std::path::Path;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -77,3 +78,19 @@ where
         N::unwrap_cast(node.clone())
     }
 }
+
+/// Returns `true` if the file at `path` is an ECMAScript module for Node.js.
+///
+/// The files with the extensions `.mjs` and `.mts` are always modules,
+/// and the files with the extensions `.cjs` and `.cts` are always CommonJS modules.
+/// The other JavaScript and TypeScript files are modules
+/// if the field `type` of the manifest of their package is set to `module`.
+pub(crate) fn is_es_module(path: &Path, manifest: Option<&PackageJson>) -> bool {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("mjs" | "mts") => true,
+        Some("js" | "jsx" | "ts" | "tsx") => manifest
+            .and_then(|manifest| manifest.r#type)
+            .is_some_and(|package_type| package_type.is_module()),
+        _ => false,
+    }
+}
@@ -1,5 +1,6 @@
 pub mod aria;
 pub mod control_flow;
 pub mod semantic;
+pub mod template;
 
 pub mod manifest;
@@ -0,0 +1,25 @@
+use biome_rowan::TextRange;
+
+/// The expressions of the template of a component, such as `{count + 1}` in a Svelte component.
+///
+/// The file handlers of the components write the expressions of the template in the analyzed module,
+/// at their position in the component, so that the rules see the variables that the markup uses.
+/// This service tells the rules which code of the module comes from the template.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentTemplate {
+    /// The ranges of the expressions of the template, in the order of the source
+    expressions: Vec<TextRange>,
+}
+
+impl ComponentTemplate {
+    pub fn new(expressions: Vec<TextRange>) -> Self {
+        Self { expressions }
+    }
+
+    /// Returns `true` if `range` is inside an expression of the template
+    pub fn contains(&self, range: TextRange) -> bool {
+        self.expressions
+            .iter()
+            .any(|expression| expression.contains_range(range))
+    }
+}
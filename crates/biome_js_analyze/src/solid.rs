@@ -0,0 +1,102 @@
+//! A series of AST utilities to work with the Solid library
+
+use crate::react::hooks::function_or_class_name;
+use biome_js_syntax::{
+    AnyJsArrayBindingPatternElement, AnyJsArrowFunctionParameters, AnyJsBinding,
+    AnyJsBindingPattern, AnyJsExpression, AnyJsFunction, JsIdentifierBinding, JsSyntaxKind,
+    JsSyntaxNode, JsVariableDeclarator,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// Returns `true` if `function` is a Solid component, i.e. its name starts with an uppercase letter.
+pub(crate) fn is_solid_component(function: &AnyJsFunction) -> bool {
+    function_or_class_name(function.syntax())
+        .is_some_and(|name| name.text().chars().next().is_some_and(char::is_uppercase))
+}
+
+/// Returns the pattern of the props parameter of the component `function`.
+pub(crate) fn props_parameter(function: &AnyJsFunction) -> Option<AnyJsBindingPattern> {
+    match function.parameters().ok()? {
+        AnyJsArrowFunctionParameters::AnyJsBinding(binding) => {
+            Some(AnyJsBindingPattern::AnyJsBinding(binding))
+        }
+        AnyJsArrowFunctionParameters::JsParameters(parameters) => parameters
+            .items()
+            .first()?
+            .ok()?
+            .as_any_js_formal_parameter()?
+            .as_js_formal_parameter()?
+            .binding()
+            .ok(),
+    }
+}
+
+/// Returns the binding of the accessor declared by `declarator`, if any.
+///
+/// ```js
+/// const [count, setCount] = createSignal(0);
+/// const double = createMemo(() => count() * 2);
+/// ```
+pub(crate) fn accessor_binding(declarator: &JsVariableDeclarator) -> Option<JsIdentifierBinding> {
+    let initializer = declarator
+        .initializer()?
+        .expression()
+        .ok()?
+        .omit_parentheses();
+    let callee = initializer.as_js_call_expression()?.callee().ok()?;
+    let AnyJsExpression::JsIdentifierExpression(callee) = callee.omit_parentheses() else {
+        return None;
+    };
+    let callee = callee.name().ok()?;
+    let id = declarator.id().ok()?;
+    if callee.has_name("createSignal") {
+        let AnyJsBindingPattern::JsArrayBindingPattern(pattern) = id else {
+            return None;
+        };
+        let AnyJsArrayBindingPatternElement::JsArrayBindingPatternElement(element) =
+            pattern.elements().first()?.ok()?
+        else {
+            return None;
+        };
+        let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) =
+            element.pattern().ok()?
+        else {
+            return None;
+        };
+        Some(binding)
+    } else if callee.has_name("createMemo") {
+        let AnyJsBindingPattern::AnyJsBinding(AnyJsBinding::JsIdentifierBinding(binding)) = id
+        else {
+            return None;
+        };
+        Some(binding)
+    } else {
+        None
+    }
+}
+
+/// Returns `true` if `node` is evaluated when `function` runs, outside a tracking scope.
+///
+/// Solid tracks the reads of JSX expressions, and the reads of nested functions are deferred.
+pub(crate) fn is_untracked_in(node: &JsSyntaxNode, function: &JsSyntaxNode) -> bool {
+    for ancestor in node.ancestors().skip(1) {
+        if &ancestor == function {
+            return true;
+        }
+        if AnyJsFunction::can_cast(ancestor.kind())
+            || matches!(
+                ancestor.kind(),
+                JsSyntaxKind::JS_METHOD_OBJECT_MEMBER
+                    | JsSyntaxKind::JS_GETTER_OBJECT_MEMBER
+                    | JsSyntaxKind::JS_SETTER_OBJECT_MEMBER
+                    | JsSyntaxKind::JSX_EXPRESSION_CHILD
+                    | JsSyntaxKind::JSX_EXPRESSION_ATTRIBUTE_VALUE
+                    | JsSyntaxKind::JSX_SPREAD_ATTRIBUTE
+                    | JsSyntaxKind::JSX_SPREAD_CHILD
+            )
+        {
+            return false;
+        }
+    }
+    false
+}
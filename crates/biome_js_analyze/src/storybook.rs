@@ -0,0 +1,84 @@
+//! A series of utilities to work with Storybook stories
+
+use std::path::Path;
+
+/// Returns `true` if `path` is a stories file, such as `Button.stories.tsx`.
+pub(crate) fn is_stories_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            let parts: Vec<_> = name.split('.').collect();
+            // Skip the name of the story and the extension.
+            parts.len() > 2
+                && parts[1..parts.len() - 1]
+                    .iter()
+                    .any(|part| matches!(*part, "stories" | "story"))
+        })
+}
+
+/// Returns the name that Storybook generates for the story exported as `export_name`.
+///
+/// The export name is split into words, and every word is capitalized:
+/// `primaryButton` and `primary_button` become `Primary Button`.
+pub(crate) fn story_name_from_export(export_name: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+    let chars: Vec<char> = export_name.chars().collect();
+    for (index, &c) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            continue;
+        }
+        if let Some(&previous) = index.checked_sub(1).and_then(|index| chars.get(index)) {
+            let next = chars.get(index + 1);
+            let is_boundary = (previous.is_lowercase() && c.is_uppercase())
+                || (previous.is_alphabetic() && c.is_ascii_digit())
+                || (previous.is_ascii_digit() && c.is_alphabetic())
+                // `HTMLButton` is `HTML Button`
+                || (previous.is_uppercase()
+                    && c.is_uppercase()
+                    && next.is_some_and(|next| next.is_lowercase()));
+            if is_boundary && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+        }
+        word.push(c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().chain(chars).collect()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_story_names() {
+        assert_eq!(story_name_from_export("Primary"), "Primary");
+        assert_eq!(story_name_from_export("PrimaryButton"), "Primary Button");
+        assert_eq!(story_name_from_export("primary_button"), "Primary Button");
+        assert_eq!(story_name_from_export("HTMLButton"), "HTML Button");
+        assert_eq!(story_name_from_export("Button2"), "Button 2");
+    }
+
+    #[test]
+    fn detects_stories_files() {
+        assert!(is_stories_file(Path::new("src/Button.stories.tsx")));
+        assert!(is_stories_file(Path::new("Button.story.js")));
+        assert!(!is_stories_file(Path::new("stories.js")));
+        assert!(!is_stories_file(Path::new("Button.tsx")));
+    }
+}
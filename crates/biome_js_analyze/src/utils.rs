@@ -3,10 +3,11 @@ use biome_rowan::{AstNode, Direction, WalkEvent};
 use std::iter;
 
 pub mod batch;
-pub mod regex;
 pub mod rename;
+pub mod test_framework;
 #[cfg(test)]
 pub mod tests;
+pub mod type_info;
 
 #[derive(Debug, PartialEq)]
 pub enum EscapeError {
@@ -1,12 +1,16 @@
 use biome_js_factory::make::{self, jsx_child_list};
 use biome_js_syntax::{
-    AnyJsConstructorParameter, AnyJsFormalParameter, AnyJsObjectMember, AnyJsParameter,
-    AnyJsStatement, AnyJsxChild, JsConstructorParameterList, JsFormalParameter, JsLanguage,
-    JsModuleItemList, JsObjectMemberList, JsParameterList, JsStatementList, JsSyntaxKind,
-    JsSyntaxNode, JsVariableDeclaration, JsVariableDeclarator, JsVariableDeclaratorList,
-    JsVariableStatement, JsxChildList, T,
+    AnyJsBinding, AnyJsBindingPattern, AnyJsConstructorParameter, AnyJsExpression,
+    AnyJsFormalParameter, AnyJsModuleItem, AnyJsObjectMember, AnyJsParameter, AnyJsStatement,
+    AnyJsxChild, JsConstructorParameterList, JsFormalParameter, JsLanguage, JsModuleItemList,
+    JsObjectMemberList, JsParameterList, JsScript, JsStatementList, JsSyntaxKind, JsSyntaxNode,
+    JsVariableDeclaration, JsVariableDeclarator, JsVariableDeclaratorList, JsVariableStatement,
+    JsxChildList, T,
+};
+use biome_rowan::{
+    chain_trivia_pieces, AstNode, AstNodeExt, AstNodeList, AstSeparatedList, BatchMutation,
+    TriviaPieceKind,
 };
-use biome_rowan::{chain_trivia_pieces, AstNode, AstSeparatedList, BatchMutation};
 
 pub trait JsBatchMutation {
     /// Removes the declarator, and:
@@ -52,6 +56,23 @@ pub trait JsBatchMutation {
     ) -> bool
     where
         I: IntoIterator<Item = AnyJsxChild>;
+
+    /// Replaces the expression with a reference to `name`, and declares `name`
+    /// with the expression as a constant before the top-level statement that contains it:
+    ///
+    /// ```js
+    /// function f() { return 42; }
+    /// ```
+    ///
+    /// becomes
+    ///
+    /// ```js
+    /// const ANSWER = 42;
+    /// function f() { return ANSWER; }
+    /// ```
+    ///
+    /// Returns `false` if the expression isn't in a module or a script.
+    fn extract_to_top_level_constant(&mut self, expression: &AnyJsExpression, name: &str) -> bool;
 }
 
 fn remove_js_formal_parameter_from_js_parameter_list(
@@ -308,6 +329,103 @@ impl JsBatchMutation for BatchMutation<JsLanguage> {
         }
     }
 
+    fn extract_to_top_level_constant(&mut self, expression: &AnyJsExpression, name: &str) -> bool {
+        let Some(item) = expression.syntax().ancestors().find(|node| {
+            node.parent().is_some_and(|parent| {
+                JsModuleItemList::can_cast(parent.kind())
+                    || (JsStatementList::can_cast(parent.kind())
+                        && parent
+                            .parent()
+                            .is_some_and(|root| JsScript::can_cast(root.kind())))
+            })
+        }) else {
+            return false;
+        };
+        let Some(initializer) = expression.clone().trim_trivia() else {
+            return false;
+        };
+        let reference = AnyJsExpression::from(make::js_identifier_expression(
+            make::js_reference_identifier(make::ident(name)),
+        ));
+        // The blank lines before the statement are kept before the constant,
+        // and its comments stay attached to it.
+        let leading_trivia: Vec<_> = item
+            .first_leading_trivia()
+            .map(|trivia| trivia.pieces().collect())
+            .unwrap_or_default();
+        let blank_len = leading_trivia
+            .iter()
+            .take_while(|piece| piece.is_newline() || piece.is_whitespace())
+            .count();
+        let declaration = make::js_variable_statement(
+            make::js_variable_declaration(
+                make::token(T![const])
+                    .with_leading_trivia_pieces(leading_trivia[..blank_len].iter().cloned()),
+                make::js_variable_declarator_list(
+                    [
+                        make::js_variable_declarator(AnyJsBindingPattern::AnyJsBinding(
+                            AnyJsBinding::JsIdentifierBinding(make::js_identifier_binding(
+                                make::ident(name)
+                                    .with_leading_trivia([(TriviaPieceKind::Whitespace, " ")]),
+                            )),
+                        ))
+                        .with_initializer(make::js_initializer_clause(
+                            make::token_decorated_with_space(T![=]),
+                            initializer,
+                        ))
+                        .build(),
+                    ],
+                    [],
+                ),
+            )
+            .build(),
+        )
+        .with_semicolon_token(
+            make::token(T![;]).with_trailing_trivia([(TriviaPieceKind::Newline, "\n")]),
+        )
+        .build();
+        let remaining_trivia = leading_trivia[blank_len..].iter().cloned();
+        if let Some(list) = item.parent().and_then(JsModuleItemList::cast) {
+            let Some(new_item) = AnyJsModuleItem::cast(item.clone())
+                .and_then(|item| item.replace_node(expression.clone(), reference))
+                .and_then(|item| item.with_leading_trivia_pieces(remaining_trivia))
+            else {
+                return false;
+            };
+            let mut items = Vec::with_capacity(list.len() + 1);
+            for other in list.iter() {
+                if other.syntax() == &item {
+                    items.push(AnyJsModuleItem::AnyJsStatement(declaration.clone().into()));
+                    items.push(new_item.clone());
+                } else {
+                    items.push(other);
+                }
+            }
+            self.replace_node_discard_trivia(list, make::js_module_item_list(items));
+            true
+        } else if let Some(list) = item.parent().and_then(JsStatementList::cast) {
+            let Some(new_item) = AnyJsStatement::cast(item.clone())
+                .and_then(|item| item.replace_node(expression.clone(), reference))
+                .and_then(|item| item.with_leading_trivia_pieces(remaining_trivia))
+            else {
+                return false;
+            };
+            let mut statements = Vec::with_capacity(list.len() + 1);
+            for other in list.iter() {
+                if other.syntax() == &item {
+                    statements.push(declaration.clone().into());
+                    statements.push(new_item.clone());
+                } else {
+                    statements.push(other);
+                }
+            }
+            self.replace_node_discard_trivia(list, make::js_statement_list(statements));
+            true
+        } else {
+            false
+        }
+    }
+
     fn transfer_leading_trivia_to_sibling(&mut self, node: &JsSyntaxNode) {
         let Some(pieces) = node.first_leading_trivia().map(|trivia| trivia.pieces()) else {
             return;
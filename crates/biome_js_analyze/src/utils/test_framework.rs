@@ -0,0 +1,83 @@
+//! Utilities to work with the calls of test frameworks, such as Jest, Mocha, and Vitest.
+
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsFunction, JsCallExpression, JsSyntaxKind, JsSyntaxNode,
+};
+use biome_rowan::{AstNode, Direction};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum TestCallKind {
+    /// `describe()`, `describe.only()`, `test.describe()`, ...
+    Suite,
+    /// `it()`, `test()`, `test.only()`, ...
+    Case,
+    /// `beforeEach()`, `afterAll()`, ...
+    Hook,
+}
+
+/// Returns the kind of test call of `call`, if any.
+pub(crate) fn test_call_kind(call: &JsCallExpression) -> Option<TestCallKind> {
+    let callee = call.callee().ok()?;
+    if let AnyJsExpression::JsIdentifierExpression(identifier) = &callee {
+        let name = identifier.name().ok()?;
+        if matches!(
+            name.value_token().ok()?.text_trimmed(),
+            "beforeEach" | "beforeAll" | "afterEach" | "afterAll" | "before" | "after"
+        ) {
+            return Some(TestCallKind::Hook);
+        }
+    }
+    if callee.contains_a_test_pattern() != Ok(true) {
+        return None;
+    }
+    let is_suite = callee
+        .syntax()
+        .descendants_tokens(Direction::Next)
+        .any(|token| matches!(token.text_trimmed(), "describe" | "xdescribe" | "fdescribe"));
+    Some(if is_suite {
+        TestCallKind::Suite
+    } else {
+        TestCallKind::Case
+    })
+}
+
+/// Returns the test call that receives the function `function` as argument.
+///
+/// ```js
+/// test("title", () => {});
+/// ```
+pub(crate) fn callback_test_call(
+    function: &JsSyntaxNode,
+) -> Option<(JsCallExpression, TestCallKind)> {
+    let mut current = function.clone();
+    while current.parent()?.kind() == JsSyntaxKind::JS_PARENTHESIZED_EXPRESSION {
+        current = current.parent()?;
+    }
+    let arguments = current.parent()?;
+    if arguments.kind() != JsSyntaxKind::JS_CALL_ARGUMENT_LIST {
+        return None;
+    }
+    let call = JsCallExpression::cast(arguments.grand_parent()?)?;
+    let kind = test_call_kind(&call)?;
+    Some((call, kind))
+}
+
+/// Returns the closest test call whose callback contains `node`.
+pub(crate) fn enclosing_test_call(node: &JsSyntaxNode) -> Option<(JsCallExpression, TestCallKind)> {
+    node.ancestors()
+        .skip(1)
+        .filter(|ancestor| AnyJsFunction::can_cast(ancestor.kind()))
+        .find_map(|function| callback_test_call(&function))
+}
+
+/// Returns the closest test suite whose callback contains `node`.
+pub(crate) fn enclosing_test_suite(node: &JsSyntaxNode) -> Option<JsCallExpression> {
+    let mut current = node.clone();
+    while let Some((call, kind)) = enclosing_test_call(&current) {
+        if kind == TestCallKind::Suite {
+            return Some(call);
+        }
+        current = call.into_syntax();
+    }
+    None
+}
@@ -0,0 +1,551 @@
+//! Lightweight type information derived from TypeScript annotations and literals.
+//!
+//! Biome doesn't have a type checker.
+//! However, a lot of type information is directly available in the source:
+//! annotations of variables and parameters, type assertions, and literals.
+//! [TypeFacts] summarizes the runtime values that a type or an expression may take.
+//! Rules can use it to detect conditions that are always truthy or values that are never thenables.
+//!
+//! The facts are always an over-approximation:
+//! when a type cannot be statically understood, every fact is assumed.
+
+use biome_js_semantic::SemanticModel;
+use biome_js_syntax::{
+    binding_ext::AnyJsBindingDeclaration, numbers::parse_js_number, AnyJsExpression,
+    AnyJsLiteralExpression, AnyTsName, AnyTsType, AnyTsTypeMember, JsFormalParameter, JsSyntaxKind,
+    JsSyntaxNode, JsUnaryOperator, JsVariableDeclarator, TsTypeMemberList,
+};
+use biome_rowan::{AstNode, AstNodeList, AstSeparatedList};
+
+bitflags::bitflags! {
+    /// The set of runtime categories a value may belong to.
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    pub(crate) struct TypeFacts: u16 {
+        const UNDEFINED = 1 << 0;
+        const NULL = 1 << 1;
+        const TRUE = 1 << 2;
+        const FALSE = 1 << 3;
+        /// `0`, `-0`, and `NaN`
+        const ZERO_NUMBER = 1 << 4;
+        const NON_ZERO_NUMBER = 1 << 5;
+        const ZERO_BIGINT = 1 << 6;
+        const NON_ZERO_BIGINT = 1 << 7;
+        const EMPTY_STRING = 1 << 8;
+        const NON_EMPTY_STRING = 1 << 9;
+        const SYMBOL = 1 << 10;
+        /// Objects that are not thenables, including functions and arrays.
+        const OBJECT = 1 << 11;
+        /// Objects with a `then` method.
+        const THENABLE = 1 << 12;
+
+        const NULLISH = Self::UNDEFINED.bits() | Self::NULL.bits();
+        const BOOLEAN = Self::TRUE.bits() | Self::FALSE.bits();
+        const NUMBER = Self::ZERO_NUMBER.bits() | Self::NON_ZERO_NUMBER.bits();
+        const BIGINT = Self::ZERO_BIGINT.bits() | Self::NON_ZERO_BIGINT.bits();
+        const STRING = Self::EMPTY_STRING.bits() | Self::NON_EMPTY_STRING.bits();
+        const FALSY = Self::NULLISH.bits()
+            | Self::FALSE.bits()
+            | Self::ZERO_NUMBER.bits()
+            | Self::ZERO_BIGINT.bits()
+            | Self::EMPTY_STRING.bits();
+        const UNKNOWN = Self::NULLISH.bits()
+            | Self::BOOLEAN.bits()
+            | Self::NUMBER.bits()
+            | Self::BIGINT.bits()
+            | Self::STRING.bits()
+            | Self::SYMBOL.bits()
+            | Self::OBJECT.bits()
+            | Self::THENABLE.bits();
+    }
+}
+
+/// Maximum number of declarations followed to compute the facts of an expression.
+const MAX_DEPTH: u8 = 8;
+
+impl TypeFacts {
+    /// Returns `true` if the value is known to be always truthy.
+    pub(crate) fn is_always_truthy(self) -> bool {
+        !self.is_empty() && !self.intersects(Self::FALSY)
+    }
+
+    /// Returns `true` if the value is known to be always falsy.
+    pub(crate) fn is_always_falsy(self) -> bool {
+        !self.is_empty() && Self::FALSY.contains(self)
+    }
+
+    /// Returns `true` if the value is known to be neither `null` nor `undefined`.
+    pub(crate) fn is_never_nullish(self) -> bool {
+        !self.is_empty() && !self.intersects(Self::NULLISH)
+    }
+
+    /// Returns `true` if the value is known to be a primitive or an object without a `then` method.
+    pub(crate) fn is_never_thenable(self) -> bool {
+        !self.is_empty() && !self.contains(Self::THENABLE)
+    }
+
+    fn from_type_with_depth(model: &SemanticModel, ty: &AnyTsType, depth: u8) -> Self {
+        if depth == 0 {
+            return Self::UNKNOWN;
+        }
+        match ty {
+            AnyTsType::TsUndefinedType(_) | AnyTsType::TsVoidType(_) => Self::UNDEFINED,
+            AnyTsType::TsNullLiteralType(_) => Self::NULL,
+            AnyTsType::TsNeverType(_) => Self::empty(),
+            AnyTsType::TsBooleanType(_) => Self::BOOLEAN,
+            AnyTsType::TsBooleanLiteralType(ty) => match ty.literal() {
+                Ok(token) if token.kind() == JsSyntaxKind::TRUE_KW => Self::TRUE,
+                Ok(_) => Self::FALSE,
+                Err(_) => Self::BOOLEAN,
+            },
+            AnyTsType::TsNumberType(_) => Self::NUMBER,
+            AnyTsType::TsNumberLiteralType(ty) => ty
+                .literal_token()
+                .ok()
+                .and_then(|token| parse_js_number(token.text_trimmed()))
+                .map_or(Self::NUMBER, |value| {
+                    if value == 0.0 {
+                        Self::ZERO_NUMBER
+                    } else {
+                        Self::NON_ZERO_NUMBER
+                    }
+                }),
+            AnyTsType::TsBigintType(_) => Self::BIGINT,
+            AnyTsType::TsBigintLiteralType(ty) => {
+                ty.literal_token().map_or(Self::BIGINT, |token| {
+                    if is_zero_bigint(token.text_trimmed()) {
+                        Self::ZERO_BIGINT
+                    } else {
+                        Self::NON_ZERO_BIGINT
+                    }
+                })
+            }
+            AnyTsType::TsStringType(_) | AnyTsType::TsTemplateLiteralType(_) => Self::STRING,
+            AnyTsType::TsStringLiteralType(ty) => {
+                ty.literal_token().map_or(Self::STRING, |token| {
+                    // The token includes the quotes.
+                    if token.text_trimmed().len() <= 2 {
+                        Self::EMPTY_STRING
+                    } else {
+                        Self::NON_EMPTY_STRING
+                    }
+                })
+            }
+            AnyTsType::TsSymbolType(_) => Self::SYMBOL,
+            AnyTsType::TsTypeOperatorType(ty) => match ty.operator_token() {
+                // `unique symbol`
+                Ok(token) if token.kind() == JsSyntaxKind::UNIQUE_KW => Self::SYMBOL,
+                // `readonly T[]`
+                Ok(token) if token.kind() == JsSyntaxKind::READONLY_KW => {
+                    ty.ty().map_or(Self::UNKNOWN, |ty| {
+                        Self::from_type_with_depth(model, &ty, depth - 1)
+                    })
+                }
+                _ => Self::UNKNOWN,
+            },
+            AnyTsType::TsArrayType(_)
+            | AnyTsType::TsTupleType(_)
+            | AnyTsType::TsFunctionType(_)
+            | AnyTsType::TsConstructorType(_) => Self::OBJECT,
+            AnyTsType::TsNonPrimitiveType(_) => Self::OBJECT | Self::THENABLE,
+            AnyTsType::TsObjectType(ty) => object_type_facts(&ty.members()),
+            AnyTsType::TsParenthesizedType(ty) => ty.ty().map_or(Self::UNKNOWN, |ty| {
+                Self::from_type_with_depth(model, &ty, depth - 1)
+            }),
+            AnyTsType::TsUnionType(ty) => ty.types().iter().fold(Self::empty(), |facts, ty| {
+                facts
+                    | ty.map_or(Self::UNKNOWN, |ty| {
+                        Self::from_type_with_depth(model, &ty, depth - 1)
+                    })
+            }),
+            AnyTsType::TsReferenceType(ty) => {
+                // Generic types such as `Array<T>` are handled by name.
+                let Ok(AnyTsName::JsReferenceIdentifier(name)) = ty.name() else {
+                    return Self::UNKNOWN;
+                };
+                if let Some(binding) = model.binding(&name) {
+                    let Some(declaration) = binding.tree().declaration() else {
+                        return Self::UNKNOWN;
+                    };
+                    return match declaration {
+                        AnyJsBindingDeclaration::TsInterfaceDeclaration(decl)
+                            if decl.extends_clause().is_none() =>
+                        {
+                            object_type_facts(&decl.members())
+                        }
+                        AnyJsBindingDeclaration::TsTypeAliasDeclaration(decl)
+                            if decl.type_parameters().is_none() =>
+                        {
+                            decl.ty().map_or(Self::UNKNOWN, |ty| {
+                                Self::from_type_with_depth(model, &ty, depth - 1)
+                            })
+                        }
+                        AnyJsBindingDeclaration::TsEnumDeclaration(_) => {
+                            Self::NUMBER | Self::STRING
+                        }
+                        _ => Self::UNKNOWN,
+                    };
+                }
+                let Ok(token) = name.value_token() else {
+                    return Self::UNKNOWN;
+                };
+                match token.text_trimmed() {
+                    "Promise" | "PromiseLike" => Self::THENABLE,
+                    "Array" | "ReadonlyArray" | "Map" | "ReadonlyMap" | "Set" | "ReadonlySet"
+                    | "WeakMap" | "WeakSet" | "Date" | "RegExp" | "Error" | "Function"
+                    | "Record" => Self::OBJECT,
+                    _ => Self::UNKNOWN,
+                }
+            }
+            _ => Self::UNKNOWN,
+        }
+    }
+
+    /// Returns the facts of the value of an expression.
+    pub(crate) fn from_expression(model: &SemanticModel, expression: &AnyJsExpression) -> Self {
+        Self::from_expression_with_depth(model, expression, MAX_DEPTH)
+    }
+
+    fn from_expression_with_depth(
+        model: &SemanticModel,
+        expression: &AnyJsExpression,
+        depth: u8,
+    ) -> Self {
+        if depth == 0 {
+            return Self::UNKNOWN;
+        }
+        match expression.clone().omit_parentheses() {
+            AnyJsExpression::AnyJsLiteralExpression(literal) => literal_facts(&literal),
+            AnyJsExpression::JsTemplateExpression(template) if template.tag().is_none() => {
+                if template.elements().is_empty() {
+                    Self::EMPTY_STRING
+                } else if template
+                    .elements()
+                    .iter()
+                    .any(|element| element.as_js_template_chunk_element().is_some())
+                {
+                    Self::NON_EMPTY_STRING
+                } else {
+                    Self::STRING
+                }
+            }
+            AnyJsExpression::JsObjectExpression(_)
+            | AnyJsExpression::JsArrayExpression(_)
+            | AnyJsExpression::JsFunctionExpression(_)
+            | AnyJsExpression::JsArrowFunctionExpression(_)
+            | AnyJsExpression::JsClassExpression(_) => Self::OBJECT,
+            AnyJsExpression::JsNewExpression(expression) => {
+                let is_promise = expression
+                    .callee()
+                    .ok()
+                    .and_then(|callee| callee.as_js_identifier_expression()?.name().ok())
+                    .and_then(|name| name.value_token().ok())
+                    .is_some_and(|token| token.text_trimmed() == "Promise");
+                if is_promise {
+                    Self::THENABLE
+                } else {
+                    Self::OBJECT | Self::THENABLE
+                }
+            }
+            AnyJsExpression::JsUnaryExpression(expression) => match expression.operator() {
+                Ok(JsUnaryOperator::LogicalNot) => {
+                    let facts = expression.argument().map_or(Self::UNKNOWN, |argument| {
+                        Self::from_expression_with_depth(model, &argument, depth - 1)
+                    });
+                    if facts.is_always_truthy() {
+                        Self::FALSE
+                    } else if facts.is_always_falsy() {
+                        Self::TRUE
+                    } else {
+                        Self::BOOLEAN
+                    }
+                }
+                Ok(JsUnaryOperator::Delete) => Self::BOOLEAN,
+                Ok(JsUnaryOperator::Void) => Self::UNDEFINED,
+                Ok(JsUnaryOperator::Typeof) => Self::NON_EMPTY_STRING,
+                _ => Self::NUMBER | Self::BIGINT,
+            },
+            AnyJsExpression::JsInstanceofExpression(_) | AnyJsExpression::JsInExpression(_) => {
+                Self::BOOLEAN
+            }
+            AnyJsExpression::JsBinaryExpression(expression) => {
+                if expression.is_comparison_operator() {
+                    Self::BOOLEAN
+                } else {
+                    Self::UNKNOWN
+                }
+            }
+            AnyJsExpression::TsAsExpression(expression) => match expression.ty() {
+                Ok(ty) if !is_const_type(&ty) => Self::from_type_with_depth(model, &ty, depth - 1),
+                Ok(_) => expression.expression().map_or(Self::UNKNOWN, |expression| {
+                    Self::from_expression_with_depth(model, &expression, depth - 1)
+                }),
+                Err(_) => Self::UNKNOWN,
+            },
+            AnyJsExpression::TsSatisfiesExpression(expression) => {
+                expression.expression().map_or(Self::UNKNOWN, |expression| {
+                    Self::from_expression_with_depth(model, &expression, depth - 1)
+                })
+            }
+            AnyJsExpression::TsNonNullAssertionExpression(expression) => {
+                expression.expression().map_or(Self::UNKNOWN, |expression| {
+                    Self::from_expression_with_depth(model, &expression, depth - 1) - Self::NULLISH
+                })
+            }
+            AnyJsExpression::JsIdentifierExpression(identifier) => {
+                let Ok(reference) = identifier.name() else {
+                    return Self::UNKNOWN;
+                };
+                let Some(binding) = model.binding(&reference) else {
+                    return if reference.is_undefined() {
+                        Self::UNDEFINED
+                    } else {
+                        Self::UNKNOWN
+                    };
+                };
+                match binding.tree().declaration() {
+                    Some(AnyJsBindingDeclaration::JsVariableDeclarator(declarator)) => {
+                        if let Some(ty) = declarator
+                            .variable_annotation()
+                            .and_then(|annotation| annotation.type_annotation().ok()?)
+                            .and_then(|annotation| annotation.ty().ok())
+                        {
+                            return Self::from_type_with_depth(model, &ty, depth - 1);
+                        }
+                        let is_const = declarator
+                            .declaration()
+                            .is_some_and(|declaration| declaration.is_const());
+                        match declarator.initializer() {
+                            Some(initializer) if is_const => {
+                                initializer
+                                    .expression()
+                                    .map_or(Self::UNKNOWN, |expression| {
+                                        Self::from_expression_with_depth(
+                                            model,
+                                            &expression,
+                                            depth - 1,
+                                        )
+                                    })
+                            }
+                            _ => Self::UNKNOWN,
+                        }
+                    }
+                    Some(AnyJsBindingDeclaration::JsFormalParameter(parameter)) => {
+                        let Some(ty) = parameter
+                            .type_annotation()
+                            .and_then(|annotation| annotation.ty().ok())
+                        else {
+                            return Self::UNKNOWN;
+                        };
+                        let facts = Self::from_type_with_depth(model, &ty, depth - 1);
+                        if parameter.initializer().is_some() {
+                            facts - Self::UNDEFINED
+                        } else if parameter.question_mark_token().is_some() {
+                            facts | Self::UNDEFINED
+                        } else {
+                            facts
+                        }
+                    }
+                    Some(AnyJsBindingDeclaration::JsObjectBindingPatternShorthandProperty(
+                        property,
+                    )) => property
+                        .identifier()
+                        .ok()
+                        .and_then(|identifier| {
+                            let name = identifier.as_js_identifier_binding()?.name_token().ok()?;
+                            destructured_property_facts(
+                                model,
+                                property.syntax(),
+                                name.text_trimmed(),
+                                property.init().is_some(),
+                                depth - 1,
+                            )
+                        })
+                        .unwrap_or(Self::UNKNOWN),
+                    Some(AnyJsBindingDeclaration::JsObjectBindingPatternProperty(property)) => {
+                        property
+                            .member()
+                            .ok()
+                            .and_then(|member| member.name())
+                            .and_then(|name| {
+                                destructured_property_facts(
+                                    model,
+                                    property.syntax(),
+                                    name.text(),
+                                    property.init().is_some(),
+                                    depth - 1,
+                                )
+                            })
+                            .unwrap_or(Self::UNKNOWN)
+                    }
+                    Some(
+                        AnyJsBindingDeclaration::JsFunctionDeclaration(_)
+                        | AnyJsBindingDeclaration::JsClassDeclaration(_),
+                    ) => Self::OBJECT,
+                    _ => Self::UNKNOWN,
+                }
+            }
+            _ => Self::UNKNOWN,
+        }
+    }
+}
+
+/// Returns the facts of the property `name` destructured by `property`,
+/// according to the annotation of the destructured parameter or variable.
+fn destructured_property_facts(
+    model: &SemanticModel,
+    property: &JsSyntaxNode,
+    name: &str,
+    has_default: bool,
+    depth: u8,
+) -> Option<TypeFacts> {
+    // property -> property list -> object pattern -> parameter or declarator
+    let owner = property.parent()?.parent()?.parent()?;
+    let annotation = if let Some(parameter) = JsFormalParameter::cast_ref(&owner) {
+        parameter.type_annotation()?
+    } else if let Some(declarator) = JsVariableDeclarator::cast_ref(&owner) {
+        declarator.variable_annotation()?.type_annotation().ok()??
+    } else {
+        return None;
+    };
+    let facts = property_type_facts(model, &annotation.ty().ok()?, name, depth)?;
+    Some(if has_default {
+        facts - TypeFacts::UNDEFINED
+    } else {
+        facts
+    })
+}
+
+/// Returns the facts of the property `name` of the object type `ty`.
+fn property_type_facts(
+    model: &SemanticModel,
+    ty: &AnyTsType,
+    name: &str,
+    depth: u8,
+) -> Option<TypeFacts> {
+    if depth == 0 {
+        return None;
+    }
+    let members = match ty {
+        AnyTsType::TsObjectType(ty) => ty.members(),
+        AnyTsType::TsParenthesizedType(ty) => {
+            return property_type_facts(model, &ty.ty().ok()?, name, depth - 1)
+        }
+        AnyTsType::TsReferenceType(ty) => {
+            let AnyTsName::JsReferenceIdentifier(reference) = ty.name().ok()? else {
+                return None;
+            };
+            match model.binding(&reference)?.tree().declaration()? {
+                AnyJsBindingDeclaration::TsInterfaceDeclaration(declaration)
+                    if declaration.extends_clause().is_none() =>
+                {
+                    declaration.members()
+                }
+                AnyJsBindingDeclaration::TsTypeAliasDeclaration(declaration)
+                    if declaration.type_parameters().is_none() =>
+                {
+                    return property_type_facts(model, &declaration.ty().ok()?, name, depth - 1)
+                }
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    members.iter().find_map(|member| match member {
+        AnyTsTypeMember::TsPropertySignatureTypeMember(member)
+            if member.name().ok()?.name()?.text() == name =>
+        {
+            let ty = member.type_annotation()?.ty().ok()?;
+            let facts = TypeFacts::from_type_with_depth(model, &ty, depth - 1);
+            Some(if member.optional_token().is_some() {
+                facts | TypeFacts::UNDEFINED
+            } else {
+                facts
+            })
+        }
+        AnyTsTypeMember::TsMethodSignatureTypeMember(member)
+            if member.name().ok()?.name()?.text() == name =>
+        {
+            Some(TypeFacts::OBJECT)
+        }
+        _ => None,
+    })
+}
+
+fn literal_facts(literal: &AnyJsLiteralExpression) -> TypeFacts {
+    match literal {
+        AnyJsLiteralExpression::JsBooleanLiteralExpression(literal) => {
+            match literal.value_token() {
+                Ok(token) if token.kind() == JsSyntaxKind::TRUE_KW => TypeFacts::TRUE,
+                Ok(_) => TypeFacts::FALSE,
+                Err(_) => TypeFacts::BOOLEAN,
+            }
+        }
+        AnyJsLiteralExpression::JsNullLiteralExpression(_) => TypeFacts::NULL,
+        AnyJsLiteralExpression::JsNumberLiteralExpression(literal) => {
+            literal.as_number().map_or(TypeFacts::NUMBER, |value| {
+                if value == 0.0 {
+                    TypeFacts::ZERO_NUMBER
+                } else {
+                    TypeFacts::NON_ZERO_NUMBER
+                }
+            })
+        }
+        AnyJsLiteralExpression::JsBigintLiteralExpression(literal) => {
+            literal.value_token().map_or(TypeFacts::BIGINT, |token| {
+                if is_zero_bigint(token.text_trimmed()) {
+                    TypeFacts::ZERO_BIGINT
+                } else {
+                    TypeFacts::NON_ZERO_BIGINT
+                }
+            })
+        }
+        AnyJsLiteralExpression::JsStringLiteralExpression(literal) => literal
+            .inner_string_text()
+            .map_or(TypeFacts::STRING, |text| {
+                if text.is_empty() {
+                    TypeFacts::EMPTY_STRING
+                } else {
+                    TypeFacts::NON_EMPTY_STRING
+                }
+            }),
+        AnyJsLiteralExpression::JsRegexLiteralExpression(_) => TypeFacts::OBJECT,
+    }
+}
+
+/// Returns the facts of an object type given its members.
+fn object_type_facts(members: &TsTypeMemberList) -> TypeFacts {
+    let has_then = members.iter().any(|member| {
+        let name = match member {
+            AnyTsTypeMember::TsPropertySignatureTypeMember(member) => member.name(),
+            AnyTsTypeMember::TsMethodSignatureTypeMember(member) => member.name(),
+            // An index signature may include `then`.
+            AnyTsTypeMember::TsIndexSignatureTypeMember(_) => return true,
+            _ => return false,
+        };
+        name.ok()
+            .and_then(|name| name.name())
+            .map_or(true, |name| name.text() == "then")
+    });
+    if has_then {
+        TypeFacts::OBJECT | TypeFacts::THENABLE
+    } else {
+        TypeFacts::OBJECT
+    }
+}
+
+fn is_zero_bigint(text: &str) -> bool {
+    text.trim_end_matches('n')
+        .trim_start_matches("0x")
+        .trim_start_matches("0b")
+        .trim_start_matches("0o")
+        .chars()
+        .all(|c| c == '0' || c == '_')
+}
+
+/// Returns `true` if `ty` is the `const` of an `as const` assertion.
+fn is_const_type(ty: &AnyTsType) -> bool {
+    ty.as_ts_reference_type()
+        .and_then(|reference| reference.name().ok())
+        .and_then(|name| name.as_js_reference_identifier()?.value_token().ok())
+        .is_some_and(|token| token.text_trimmed() == "const")
+}
@@ -1,17 +1,27 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
+use biome_css_analyze::project::declared_style_symbols;
+use biome_css_parser::{parse_css, CssParserOptions};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
 use biome_js_parser::{parse, JsParserOptions};
 use biome_js_syntax::{JsFileSource, JsLanguage};
+use biome_project::StyleIndex;
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
-    has_bogus_nodes_or_empty_slots, load_manifest, parse_test_path, register_leak_checker,
-    scripts_from_json, write_analyzer_snapshot, CheckActionType,
+    has_bogus_nodes_or_empty_slots, load_manifest, load_tailwind_config, load_tsconfig,
+    load_workspace_packages, parse_test_path, register_leak_checker, scripts_from_json,
+    write_analyzer_snapshot, CheckActionType,
+};
+use std::sync::Arc;
+use std::{
+    ffi::OsStr,
+    fs::{read_dir, read_to_string},
+    path::Path,
+    slice,
 };
-use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
 
-tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_test, "module"}
+tests_macros::gen_tests! {"tests/specs/**/*.{cjs,js,jsx,mjs,mts,tsx,ts,json,jsonc,svelte}", crate::run_test, "module"}
 tests_macros::gen_tests! {"tests/suppression/**/*.{cjs,js,jsx,tsx,ts,json,jsonc,svelte}", crate::run_suppression_test, "module"}
 
 fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
@@ -88,6 +98,24 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
     }
 }
 
+/// Indexes the stylesheets in the directory of the test file, which form the project of the test.
+///
+/// Returns `None` if the directory doesn't contain any stylesheet.
+fn index_stylesheets(input_file: &Path) -> Option<Arc<StyleIndex>> {
+    let mut style_index = StyleIndex::default();
+    for entry in read_dir(input_file.parent()?).ok()? {
+        let path = entry.ok()?.path();
+        if path.extension().and_then(OsStr::to_str) != Some("css") {
+            continue;
+        }
+        let content = read_to_string(&path)
+            .unwrap_or_else(|err| panic!("failed to read {:?}: {:?}", path, err));
+        let root = parse_css(&content, CssParserOptions::default()).tree();
+        style_index.add_stylesheet(Path::new(path.file_name()?), declared_style_symbols(&root));
+    }
+    style_index.has_stylesheets().then(|| Arc::new(style_index))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn analyze_and_snap(
     snapshot: &mut String,
@@ -106,9 +134,23 @@ pub(crate) fn analyze_and_snap(
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
     let manifest = load_manifest(input_file, &mut diagnostics);
+    let tsconfig = load_tsconfig(input_file, &mut diagnostics);
+    let tailwind_config = load_tailwind_config(input_file, &mut diagnostics);
+    let workspace_packages = load_workspace_packages(input_file, &mut diagnostics);
+    let style_index = index_stylesheets(input_file);
 
-    let (_, errors) =
-        biome_js_analyze::analyze(&root, filter, &options, source_type, manifest, |event| {
+    let (_, errors) = biome_js_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        source_type,
+        manifest,
+        tsconfig,
+        tailwind_config,
+        workspace_packages,
+        style_index,
+        None,
+        |event| {
             if let Some(mut diag) = event.diagnostic() {
                 for action in event.actions() {
                     if check_action_type.is_suppression() {
@@ -164,7 +206,8 @@ pub(crate) fn analyze_and_snap(
             }
 
             ControlFlow::<Never>::Continue(())
-        });
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
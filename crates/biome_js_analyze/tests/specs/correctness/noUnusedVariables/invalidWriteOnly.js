@@ -0,0 +1,34 @@
+let count = 0;
+count += 1;
+count++;
+
+let total;
+total = 1;
+total = 2;
+
+let accumulated = 0;
+for (const item of [1, 2, 3]) {
+	accumulated += item;
+}
+
+let lastValue;
+function update(value) {
+	lastValue = value;
+}
+update(1);
+
+let flag = false;
+(() => {
+	flag = true;
+})();
+
+let a, b;
+a = b = 0;
+console.log(b);
+
+let x = 0;
+for (let i = 0; i < 10; x++, i++) {}
+
+let obj = {};
+[obj] = [{}];
+({ obj } = { obj: {} });
@@ -0,0 +1,9 @@
+import styles from "./button.module.css";
+import { Button } from "@/components/button";
+import React from "react";
+import { readFile } from "node:fs";
+import { format } from "../utils/format";
+import { theme } from "~/theme";
+import path from "path";
+import "./global.css";
+import { z } from "zod";
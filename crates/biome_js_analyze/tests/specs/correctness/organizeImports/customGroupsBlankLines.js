@@ -0,0 +1,10 @@
+import styles from "./button.module.css";
+import { Button } from "@/components/button";
+import { z } from "zod";
+
+import React from "react";
+import { readFile } from "node:fs";
+// Helpers
+import { format } from "../utils/format";
+
+import { theme } from "~/theme";
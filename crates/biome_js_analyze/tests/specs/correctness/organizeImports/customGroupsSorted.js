@@ -0,0 +1,6 @@
+import { readFile } from "node:fs";
+
+import React from "react";
+import { z } from "zod";
+
+import { Button } from "@/components/button";
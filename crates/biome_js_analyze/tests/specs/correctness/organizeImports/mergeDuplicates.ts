@@ -0,0 +1,9 @@
+import { lorem, foo } from "foo";
+import type { Bar } from "foo";
+import { ipsum } from "../ipsum";
+import { bar } from "foo";
+import type { Baz } from "foo";
+import { type Qux, dolor } from "foo";
+import { sit } from "foo" with { type: "json" };
+import { lorem as amet } from "foo";
+import * as all from "foo";
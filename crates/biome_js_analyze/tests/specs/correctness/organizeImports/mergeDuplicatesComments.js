@@ -0,0 +1,7 @@
+// Header
+import { lorem } from "foo";
+// Needed for the tests
+import { ipsum } from "foo"; // trailing
+import { amet /* inner */, dolor } from "foo";
+import { sit } /* kept apart */ from "foo";
+import { consectetur } from "../consectetur";
@@ -0,0 +1,3 @@
+import { lorem } from "foo";
+import { bar } from "bar";
+import { ipsum } from "foo";
@@ -0,0 +1,12 @@
+import { lorem } from "foo";
+import type { Bar } from "foo";
+import type { B, A } from "bar";
+import { c } from "bar";
+import type { D } from "baz";
+import type {
+	F,
+	E,
+} from "qux";
+import {
+	g,
+} from "qux";
@@ -0,0 +1,41 @@
+const user = computed(async () => await fetchUser(id.value));
+
+const posts = computed(() => {
+	return fetchPosts().then((response) => response.json());
+});
+
+const delayed = computed(() => {
+	setTimeout(() => {
+		count.value++;
+	}, 100);
+	return count.value;
+});
+
+const writable = computed({
+	async get() {
+		return await load();
+	},
+	set(value) {},
+});
+
+export default {
+	computed: {
+		user() {
+			return fetchUser(this.id).catch(() => null);
+		},
+		async posts() {
+			return [];
+		},
+		profile: {
+			get: async function () {
+				return await fetchProfile();
+			},
+		},
+	},
+};
+
+defineComponent({
+	computed: {
+		timer: () => setInterval(tick, 1000),
+	},
+});
@@ -0,0 +1,38 @@
+/* should not generate diagnostics */
+const fullName = computed(() => `${firstName.value} ${lastName.value}`);
+
+const handlers = computed(() => ({
+	async onClick() {
+		await save();
+	},
+}));
+
+const writable = computed({
+	get() {
+		return count.value;
+	},
+	async set(value) {
+		await save(value);
+	},
+});
+
+export default {
+	computed: {
+		fullName() {
+			return `${this.firstName} ${this.lastName}`;
+		},
+	},
+	methods: {
+		async load() {
+			await fetchUser(this.id);
+		},
+	},
+};
+
+const options = {
+	computed: {
+		async notAComponent() {},
+	},
+};
+
+const value = notComputed(async () => await load());
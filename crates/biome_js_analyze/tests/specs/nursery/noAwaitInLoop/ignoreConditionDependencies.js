@@ -0,0 +1,18 @@
+async function condition() {
+	while (await hasMore()) {
+	}
+}
+
+async function assigned() {
+	let page = await fetchPage();
+	while (page.next) {
+		page = await fetchPage(page.next);
+		await save(page);
+	}
+}
+
+async function declared() {
+	for (let done = false; !done; ) {
+		const done = await step();
+	}
+}
@@ -0,0 +1,38 @@
+async function forOf(urls) {
+	for (const url of urls) {
+		await fetch(url);
+	}
+}
+
+async function forIn(object) {
+	for (const key in object) {
+		object[key] = await load(key);
+	}
+}
+
+async function forLoop(items) {
+	for (let i = 0; i < (await count()); i++) {
+		await items[i];
+	}
+}
+
+async function whileLoop() {
+	while (await hasMore()) {
+		await next();
+	}
+}
+
+async function doWhileLoop() {
+	let page;
+	do {
+		page = await fetchPage(page);
+	} while (page.next);
+}
+
+async function nestedInForAwait(groups) {
+	for await (const group of groups) {
+		for (const item of group) {
+			await item;
+		}
+	}
+}
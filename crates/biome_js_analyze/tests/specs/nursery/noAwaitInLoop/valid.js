@@ -0,0 +1,27 @@
+/* should not generate diagnostics */
+async function all(urls) {
+	return await Promise.all(urls.map((url) => fetch(url)));
+}
+
+async function iterable() {
+	for (const url of await getUrls()) {
+	}
+	for (const key in await getObject()) {
+	}
+	for (let i = await start(); i < 10; i++) {
+	}
+}
+
+async function nestedFunction(urls) {
+	for (const url of urls) {
+		urls.forEach(async (item) => {
+			await fetch(item);
+		});
+	}
+}
+
+async function forAwait(streams) {
+	for await (const chunk of streams) {
+		await process(chunk);
+	}
+}
@@ -0,0 +1,26 @@
+it("renders the title", () => {
+	if (isMobile) {
+		expect(title).toBeHidden();
+	} else {
+		expect(title).toBeVisible();
+	}
+});
+
+test("computes the total", () => {
+	const expected = discount ? 90 : 100;
+	expect(total()).toBe(expected);
+});
+
+describe("suite", () => {
+	it.only("switches", function () {
+		switch (kind) {
+			case "a":
+				expect(a).toBe(1);
+		}
+	});
+});
+
+test("uses a helper", () => {
+	const check = (value) => value && expect(value).toBeTruthy();
+	check(1);
+});
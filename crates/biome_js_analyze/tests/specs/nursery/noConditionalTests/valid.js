@@ -0,0 +1,22 @@
+/* should not generate diagnostics */
+describe("title", () => {
+	if (process.env.CI) {
+		beforeAll(() => {});
+	}
+
+	beforeEach(() => {
+		if (reset) {
+			cleanup();
+		}
+	});
+
+	it("is hidden on mobile", () => {
+		expect(title).toBeHidden();
+	});
+});
+
+function helper(value) {
+	return value ? 1 : 2;
+}
+
+const value = enabled ? 1 : 2;
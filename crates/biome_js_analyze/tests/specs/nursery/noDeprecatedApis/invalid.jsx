@@ -0,0 +1,4 @@
+/** @deprecated Use `<Button>` instead. */
+function OldButton() {}
+
+<OldButton />;
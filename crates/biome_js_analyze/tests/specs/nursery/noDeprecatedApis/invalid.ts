@@ -0,0 +1,37 @@
+/** @deprecated Use `fetchUser` instead. */
+function getUser() {}
+getUser();
+
+/**
+ * Computes the sum.
+ *
+ * @deprecated since v2,
+ * use `add` instead.
+ * @param a first operand
+ */
+export function sum(a: number, b: number) {
+	return a + b;
+}
+sum(1, 2);
+
+/** @deprecated */
+export const LIMIT = 10;
+const limit = LIMIT;
+
+/** @deprecated Use `NewOptions`. */
+interface Options {}
+let options: Options;
+
+/** @deprecated */
+type Alias = string;
+let alias: Alias;
+
+/** @deprecated */
+class Legacy {}
+new Legacy();
+
+/** @deprecated */
+enum Color {
+	Red,
+}
+Color.Red;
@@ -0,0 +1,21 @@
+/* should not generate diagnostics */
+/** Fetches a user. */
+function fetchUser() {}
+fetchUser();
+
+// @deprecated is only meaningful in doc comments
+function notDoc() {}
+notDoc();
+
+/** @deprecatedSince 2 */
+function other() {}
+other();
+
+/** @deprecated */
+function recursive(n: number): number {
+	return n === 0 ? 0 : recursive(n - 1);
+}
+
+/** @deprecated */
+function reexported() {}
+export { reexported };
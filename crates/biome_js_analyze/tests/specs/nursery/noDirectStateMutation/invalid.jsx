@@ -0,0 +1,38 @@
+import { useReducer, useState } from "react";
+import { useSelector } from "react-redux";
+
+function List() {
+	const [items, setItems] = useState([]);
+	const add = (item) => {
+		items.push(item);
+		setItems(items);
+	};
+	return <button onClick={() => add(1)} />;
+}
+
+function Profile(props) {
+	const user = props.user;
+	user.name = "anonymous";
+	props.count++;
+	delete props.extra;
+	return <div>{user.name}</div>;
+}
+
+const Destructured = ({ settings }) => {
+	settings.options.sort();
+	Object.assign(settings, { theme: "dark" });
+	return null;
+};
+
+function useTodos() {
+	const [state, dispatch] = useReducer(reducer, { todos: [] });
+	const { todos } = state;
+	todos[0] = "first";
+	return [state, dispatch];
+}
+
+function Cart() {
+	const cart = useSelector((state) => state.cart);
+	cart.items.splice(0, 1);
+	return null;
+}
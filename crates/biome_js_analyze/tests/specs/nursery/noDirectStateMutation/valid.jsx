@@ -0,0 +1,30 @@
+/* should not generate diagnostics */
+import { useState } from "react";
+
+function List() {
+	const [items, setItems] = useState([]);
+	const add = (item) => setItems([...items, item]);
+	const sorted = [...items].sort();
+	const copy = items.slice();
+	copy.push(1);
+	return <button onClick={() => add(sorted)} />;
+}
+
+function Profile(props) {
+	let user = props.user;
+	user = { ...user, name: "anonymous" };
+	const name = props.name;
+	return <div>{user.name + name}</div>;
+}
+
+function helper(props) {
+	props.value = 1;
+}
+
+function Local() {
+	const local = { a: 1 };
+	local.a = 2;
+	const [count] = useCounter();
+	count.value = 1;
+	return null;
+}
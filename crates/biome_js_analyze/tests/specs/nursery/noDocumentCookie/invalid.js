@@ -0,0 +1,7 @@
+document.cookie = "foo=bar";
+document.cookie += "; Secure";
+const cookies = document.cookie;
+window.document.cookie = "foo=bar";
+globalThis.document.cookie = "foo=bar";
+document["cookie"] = "foo=bar";
+(document).cookie = "foo=bar";
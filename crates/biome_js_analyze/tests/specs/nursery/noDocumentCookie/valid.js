@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+await cookieStore.set({ name: "foo", value: "bar" });
+document.title = "foo";
+foo.document.cookie = "foo=bar";
+cookie = "foo=bar";
+function f(document) {
+	document.cookie = "foo=bar";
+}
@@ -0,0 +1,15 @@
+describe("sum", () => {
+	it("adds numbers", () => {});
+	it("adds numbers", () => {});
+	it.only(`adds numbers`, () => {});
+});
+
+describe("sum", () => {});
+
+test("top level", () => {});
+test.skip("top level", () => {});
+
+describe("product", () => {
+	describe("nested", () => {});
+	describe("nested", () => {});
+});
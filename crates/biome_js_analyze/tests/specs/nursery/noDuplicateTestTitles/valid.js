@@ -0,0 +1,22 @@
+/* should not generate diagnostics */
+describe("sum", () => {
+	it("adds numbers", () => {});
+	it("adds negative numbers", () => {});
+
+	describe("adds numbers", () => {
+		it("adds numbers", () => {});
+	});
+});
+
+describe("product", () => {
+	it("adds numbers", () => {});
+});
+
+test(`with ${value}`, () => {});
+test(`with ${value}`, () => {});
+
+test(title, () => {});
+test(title, () => {});
+
+beforeEach(() => {});
+beforeEach(() => {});
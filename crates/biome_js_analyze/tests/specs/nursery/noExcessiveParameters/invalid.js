@@ -0,0 +1,10 @@
+function foo(a, b, c, d) {}
+const bar = function (a, b, c, d) {};
+const baz = (a, b, c, d) => {};
+const obj = {
+	method(a, b, c, d) {},
+};
+class Foo {
+	constructor(a, b, c, d) {}
+	method(a, b, c, ...d) {}
+}
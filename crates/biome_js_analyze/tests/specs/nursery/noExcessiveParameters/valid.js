@@ -0,0 +1,9 @@
+/* should not generate diagnostics */
+function foo(a, b, c) {}
+function bar({ a, b, c, d }) {}
+const baz = (a) => {};
+const qux = a => {};
+class Foo {
+	constructor(a, b, c) {}
+	set value(a) {}
+}
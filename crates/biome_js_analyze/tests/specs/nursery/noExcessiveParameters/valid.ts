@@ -0,0 +1,2 @@
+/* should not generate diagnostics */
+function foo(this: Window, a: number, b: number, c: number) {}
@@ -0,0 +1,26 @@
+function foo() {
+	const a = 0;
+	const b = 1;
+	return a + b;
+}
+
+const bar = () => {
+	const a = `
+multiline
+template`;
+	return a;
+};
+
+class Foo {
+	constructor() {
+		this.a = 0;
+		this.b = 1;
+		this.c = 2;
+	}
+
+	method() {
+		const a = 0;
+		/* comment */ const b = 1;
+		return a + b;
+	}
+}
@@ -0,0 +1,17 @@
+/* should not generate diagnostics */
+function foo() {
+	// comment
+
+	/*
+	 * multiline comment
+	 */
+	return 0;
+}
+
+const bar = () => 0;
+
+const baz = {
+	get a() {
+		return 0;
+	},
+};
@@ -0,0 +1,4 @@
+// Header comment
+const file = __filename;
+const dir = __dirname;
+console.log(path.join(__dirname, "config.json"));
@@ -0,0 +1 @@
+const file: string = __filename;
@@ -0,0 +1,3 @@
+import { fileURLToPath as toPath } from "node:url";
+
+const file = __filename;
@@ -0,0 +1,2 @@
+const fileURLToPath = () => {};
+const file = __filename;
@@ -0,0 +1 @@
+const dir = __dirname;
@@ -0,0 +1,4 @@
+import path from "node:path";
+import fs from "node:fs";
+
+const config = fs.readFileSync(path.join(__dirname, "config.json"));
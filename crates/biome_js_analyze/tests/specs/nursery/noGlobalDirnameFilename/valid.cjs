@@ -0,0 +1,3 @@
+/* should not generate diagnostics */
+const dir = __dirname;
+const file = __filename;
@@ -0,0 +1,2 @@
+/* should not generate diagnostics */
+const dir = __dirname;
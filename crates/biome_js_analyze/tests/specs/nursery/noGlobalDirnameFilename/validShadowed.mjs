@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+import { fileURLToPath } from "node:url";
+
+const __dirname = fileURLToPath(new URL(".", import.meta.url));
+console.log(__dirname);
+function f(__filename) {
+	return __filename;
+}
@@ -0,0 +1,14 @@
+/* should not generate diagnostics */
+import { Html, Main, NextScript } from "next/document";
+
+export default function Document() {
+	return (
+		<Html>
+			<head />
+			<body>
+				<Main />
+				<NextScript />
+			</body>
+		</Html>
+	);
+}
@@ -0,0 +1,11 @@
+function Index() {
+	return (
+		<head>
+			<title>Invalid</title>
+		</head>
+	);
+}
+
+function Page() {
+	return <head />;
+}
@@ -0,0 +1,10 @@
+/* should not generate diagnostics */
+import Head from "next/head";
+
+function Index() {
+	return (
+		<Head>
+			<title>All good!</title>
+		</Head>
+	);
+}
@@ -0,0 +1,17 @@
+<img alt="Foo" src="/foo.png" />;
+
+<img alt="Foo" src="/foo.png"></img>;
+
+function Avatar() {
+	return (
+		<div>
+			<img alt="Avatar" src="/avatar.png" />
+		</div>
+	);
+}
+
+<picture>
+	<div>
+		<img alt="Nested" src="/nested.png" />
+	</div>
+</picture>;
@@ -0,0 +1,13 @@
+/* should not generate diagnostics */
+import Image from "next/image";
+
+<Image alt="Foo" src="/foo.png" width={100} height={100} />;
+
+<picture>
+	<source srcSet="/foo.avif" type="image/avif" />
+	<img alt="Foo" src="/foo.png" />
+</picture>;
+
+<picture>
+	<img alt="Foo" src="/foo.png"></img>
+</picture>;
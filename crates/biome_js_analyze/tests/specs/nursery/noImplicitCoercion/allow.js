@@ -0,0 +1,5 @@
+/* should not generate diagnostics */
+const isActive = !!user;
+const count = +input;
+const label = "" + value;
+const found = ~items.indexOf(item);
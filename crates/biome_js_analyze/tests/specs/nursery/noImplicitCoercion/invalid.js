@@ -0,0 +1,11 @@
+const isActive = !!user;
+const isSet = !!(a && b);
+const count = +input;
+const total = +(a.value);
+const label = "" + value;
+const suffix = value + '';
+const template = `` + value;
+if (~items.indexOf(item)) {
+}
+const found = ~items.lastIndexOf(item, 2);
+const chained = !!foo.bar();
@@ -0,0 +1,3 @@
+function convert(Boolean, value) {
+	return !!value;
+}
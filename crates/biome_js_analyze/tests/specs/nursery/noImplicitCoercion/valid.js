@@ -0,0 +1,11 @@
+/* should not generate diagnostics */
+const isActive = Boolean(user);
+const isNot = !user;
+const count = Number(input);
+const positive = +1;
+const label = String(value);
+const text = "" + "literal";
+const greeting = "Hello " + name;
+const inverted = ~value;
+if (items.includes(item)) {
+}
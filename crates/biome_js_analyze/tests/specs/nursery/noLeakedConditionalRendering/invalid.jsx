@@ -0,0 +1,13 @@
+const Items = ({ items }) => <div>{items.length && <List items={items} />}</div>;
+
+const Nested = ({ a, b }) => <div>{a && b && <span />}</div>;
+
+const Parenthesized = ({ count }) => (
+	<div>
+		{(count && (
+			<span>{count}</span>
+		))}
+	</div>
+);
+
+const Zero = () => <div>{0 && <span />}</div>;
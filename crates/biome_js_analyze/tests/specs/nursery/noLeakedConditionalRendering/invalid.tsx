@@ -0,0 +1,14 @@
+const Counter = ({ count }: { count: number }) => <div>{count && <Badge count={count} />}</div>;
+
+interface Props {
+	total?: number;
+	label: string;
+}
+
+function Summary({ total, label }: Props) {
+	return <div>{label && total && <span>{total}</span>}</div>;
+}
+
+function Annotated(value: number | undefined) {
+	return <div>{value && <span>{value}</span>}</div>;
+}
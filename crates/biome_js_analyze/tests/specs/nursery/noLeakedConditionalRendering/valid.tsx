@@ -0,0 +1,35 @@
+/* should not generate diagnostics */
+const Items = ({ items }) => <div>{items.length > 0 && <List items={items} />}</div>;
+
+const Coerced = ({ user }) => <div>{!!user && <Avatar user={user} />}</div>;
+
+const WithBoolean = ({ user }) => <div>{Boolean(user) && <Avatar user={user} />}</div>;
+
+const Ternary = ({ count }) => <div>{count ? <span /> : null}</div>;
+
+function Greeting(name: string | undefined) {
+	return <div>{name && <span>{name}</span>}</div>;
+}
+
+interface Props {
+	user?: { name: string };
+	visible: boolean;
+	title: string | null;
+}
+
+function Card({ user, visible, title }: Props) {
+	return (
+		<div>
+			{user && <span>{user.name}</span>}
+			{visible && <span />}
+			{title && <h1>{title}</h1>}
+		</div>
+	);
+}
+
+const Attribute = ({ count }) => <div hidden={count && true} />;
+
+const NotRendered = (count) => {
+	const value = count && 1;
+	return <div>{value > 0}</div>;
+};
@@ -0,0 +1,5 @@
+/* should not generate diagnostics */
+enum Status {
+	Active = 2,
+	Inactive = 3,
+}
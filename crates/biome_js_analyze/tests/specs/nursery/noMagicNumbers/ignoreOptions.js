@@ -0,0 +1,6 @@
+/* should not generate diagnostics */
+function withDefault(size = 10) {}
+
+const { width = 100 } = options;
+
+const half = value / 2;
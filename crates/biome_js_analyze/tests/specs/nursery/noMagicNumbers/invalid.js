@@ -0,0 +1,21 @@
+const total = price * 1.2;
+
+setTimeout(retry, 5000);
+
+let attempts = 3;
+
+function area(radius) {
+	// Compute the area.
+	return radius * radius * 3.14159;
+}
+
+if (count > -5) {
+}
+
+const bits = 0xff & value;
+
+function withDefault(size = 10) {}
+
+const { width = 100 } = options;
+
+parseInt(value, 16, 2);
@@ -0,0 +1,5 @@
+enum Status {
+	Active = 2,
+}
+
+const limit: number = 10 as number;
@@ -0,0 +1,20 @@
+/* should not generate diagnostics */
+const TAX_RATE = 1.2;
+const total = price * TAX_RATE;
+
+const NEGATIVE = -5;
+
+const config = { timeout: 5000 };
+
+const last = items[items.length - 1];
+
+const first = items[0];
+
+for (let i = 0; i < items.length; i += 1) {}
+
+parseInt(value, 16);
+Number.parseInt(value, 8);
+
+class Retry {
+	attempts = 3;
+}
@@ -0,0 +1,6 @@
+import { Button } from "@acme/ui/src/button/index.js";
+import { Theme } from "@acme/ui/src/theme";
+import { Lock } from "@acme/ui/icons/internal/lock";
+export { Icon } from "@acme/ui/src/icons/arrow.js";
+const lazy = import("@acme/ui/button/index.js");
+const { format } = require("utils/dist/format");
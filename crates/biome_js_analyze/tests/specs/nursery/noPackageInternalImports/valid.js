@@ -0,0 +1,10 @@
+/* should not generate diagnostics */
+import { Theme } from "@acme/ui";
+import { Button } from "@acme/ui/button";
+import { Arrow } from "@acme/ui/icons/arrow";
+import manifest from "@acme/ui/package.json";
+import { format } from "utils";
+import { helper } from "legacy/src/helper";
+import { debounce } from "lodash/debounce";
+import { local } from "./src/local";
+import { readFile } from "node:fs";
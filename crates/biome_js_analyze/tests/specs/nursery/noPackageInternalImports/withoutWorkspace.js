@@ -0,0 +1,2 @@
+/* should not generate diagnostics */
+import { Button } from "@acme/ui/src/button/index.js";
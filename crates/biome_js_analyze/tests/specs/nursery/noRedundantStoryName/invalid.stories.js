@@ -0,0 +1,16 @@
+export default { title: "Button" };
+
+export const PrimaryButton = {
+	name: "Primary Button",
+	args: { primary: true },
+};
+
+export const Secondary = {
+	args: {},
+	name: "Secondary",
+};
+
+export const HTMLButton = { name: "HTML Button" };
+
+export const Button2 = () => {};
+Button2.storyName = "Button 2";
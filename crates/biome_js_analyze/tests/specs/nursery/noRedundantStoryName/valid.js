@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+export const PrimaryButton = {
+	name: "Primary Button",
+};
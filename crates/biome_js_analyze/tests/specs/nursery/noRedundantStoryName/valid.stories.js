@@ -0,0 +1,15 @@
+/* should not generate diagnostics */
+export default { title: "Button" };
+
+export const PrimaryButton = {
+	name: "I am the primary",
+};
+
+export const Secondary = () => {};
+Secondary.storyName = "Other";
+
+const Local = { name: "Local" };
+
+export const WithArgs = {
+	args: { name: "With Args" },
+};
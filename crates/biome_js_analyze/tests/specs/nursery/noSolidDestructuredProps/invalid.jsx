@@ -0,0 +1,9 @@
+function Greeting({ name }) {
+	return <p>Hello {name}</p>;
+}
+
+const Card = ({ title, ...others }) => <div {...others}>{title}</div>;
+
+const Named = function ({ a = 1 }) {
+	return <span>{a}</span>;
+};
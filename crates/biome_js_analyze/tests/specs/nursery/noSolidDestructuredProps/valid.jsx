@@ -0,0 +1,16 @@
+/* should not generate diagnostics */
+function Greeting(props) {
+	return <p>Hello {props.name}</p>;
+}
+
+function Split(props) {
+	const [local, others] = splitProps(props, ["name"]);
+	return <p {...others}>Hello {local.name}</p>;
+}
+
+// Not a component
+function format({ name }) {
+	return name.trim();
+}
+
+[1, 2].map(({ value }) => value);
@@ -0,0 +1,19 @@
+const [count, setCount] = createSignal(0);
+const double = createMemo(() => count() * 2);
+
+<p>{count}</p>;
+
+<p>{(count)}</p>;
+
+const message = `Count: ${count}`;
+
+const sum = count + 1;
+
+if (double > 10) {
+}
+
+const label = double ? "big" : "small";
+
+count.toString();
+
+<input value={count} />;
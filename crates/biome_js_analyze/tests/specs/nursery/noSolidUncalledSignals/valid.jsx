@@ -0,0 +1,18 @@
+/* should not generate diagnostics */
+const [count, setCount] = createSignal(0);
+const double = createMemo(() => count() * 2);
+
+<p>{count()}</p>;
+
+const message = `Count: ${count()}`;
+
+createEffect(on(count, (value) => console.log(value)));
+
+<Counter count={count} />;
+
+const accessors = [count, double];
+
+const current = values[count()];
+
+const [value] = useState(0);
+<p>{value}</p>;
@@ -0,0 +1,17 @@
+function Greeting(props) {
+	const name = props.name;
+	return <p>Hello {name}</p>;
+}
+
+const Counter = () => {
+	const [count, setCount] = createSignal(0);
+	const double = count() * 2;
+	return <p>{double}</p>;
+};
+
+function Title(props) {
+	if (props["visible"]) {
+		return <h1>{props.title}</h1>;
+	}
+	return null;
+}
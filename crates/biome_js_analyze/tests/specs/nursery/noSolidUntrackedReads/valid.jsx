@@ -0,0 +1,30 @@
+/* should not generate diagnostics */
+function Greeting(props) {
+	const name = () => props.name;
+	return <p>Hello {name()}</p>;
+}
+
+function Counter(props) {
+	const [count, setCount] = createSignal(props.initialCount);
+	const [step] = createSignal(props.defaultStep);
+	createEffect(() => console.log(count()));
+	return <p onClick={() => setCount(count() + step())}>{count() * 2}</p>;
+}
+
+function Show(props) {
+	return <div class={props.class} {...props.rest}>{props.children}</div>;
+}
+
+const Store = (props) => {
+	const state = {
+		get value() {
+			return props.value;
+		},
+	};
+	return <p>{state.value}</p>;
+};
+
+// Not a component
+function read(props) {
+	return props.name;
+}
@@ -0,0 +1,15 @@
+expect(1).toBe(1);
+
+describe("a test suite", () => {
+	expect(1).toBe(1);
+});
+
+beforeEach(() => {
+	expect(setup()).toBeTruthy();
+});
+
+describe.only("nested", () => {
+	describe("suite", function () {
+		expect(value).toBeDefined();
+	});
+});
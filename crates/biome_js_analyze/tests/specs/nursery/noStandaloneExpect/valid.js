@@ -0,0 +1,23 @@
+/* should not generate diagnostics */
+describe("a test suite", () => {
+	it("works", () => {
+		expect(1).toBe(1);
+	});
+
+	test.skip("skipped", async () => {
+		await expect(promise).resolves.toBe(1);
+	});
+});
+
+function expectValid(value) {
+	expect(validate(value)).toBe(true);
+}
+
+const expectEmpty = (value) => expect(value).toHaveLength(0);
+
+it("asserts", () => {
+	expect.hasAssertions();
+	[1, 2].forEach((value) => expect(value).toBeGreaterThan(0));
+});
+
+expect.extend({});
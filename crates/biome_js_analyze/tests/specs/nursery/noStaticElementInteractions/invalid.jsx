@@ -0,0 +1,14 @@
+<>
+	<div onClick={() => {}} />
+	<div onClick={handleClick} onKeyDown={handleKeyDown}>Open</div>
+	<span onKeyDown={handleKeyDown}>Open</span>
+	<span onMouseDown={handleMouseDown} tabIndex={-1}>Open</span>
+	<a onClick={handleClick}>Open</a>
+	<div onKeyUp={handleKeyUp} aria-hidden="false" />
+	<footer
+		onClick={handleClick}
+		className="footer"
+	>
+		Footer
+	</footer>
+</>
@@ -0,0 +1,20 @@
+/* should not generate diagnostics */
+<>
+	<div />
+	<div className="container" onFocus={handleFocus} />
+	<button onClick={() => {}}>Open</button>
+	<input type="text" onKeyDown={handleKeyDown} />
+	<a href="/" onClick={handleClick}>Open</a>
+	<a href={url} onClick={handleClick}>Open</a>
+	<div role="button" tabIndex={0} onClick={() => {}} onKeyDown={() => {}} />
+	<div role={role} onClick={() => {}} />
+	<div onClick={() => {}} aria-hidden="true" />
+	<div onClick={() => {}} aria-hidden />
+	<div onClick={null} />
+	<div onClick={undefined} />
+	<div {...props} onClick={() => {}} />
+	<li onClick={handleClick}>Item</li>
+	<h1 onClick={handleClick}>Title</h1>
+	<MyComponent onClick={() => {}} />
+	<foo.bar onClick={() => {}} />
+</>
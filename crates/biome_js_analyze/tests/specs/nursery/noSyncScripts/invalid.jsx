@@ -0,0 +1,5 @@
+<script src="https://third-party-script.js" />;
+
+<script src="https://third-party-script.js"></script>;
+
+<script src={url} type="module" />;
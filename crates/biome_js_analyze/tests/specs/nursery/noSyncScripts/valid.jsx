@@ -0,0 +1,12 @@
+/* should not generate diagnostics */
+import Script from "next/script";
+
+<script src="https://third-party-script.js" async />;
+
+<script src="https://third-party-script.js" defer />;
+
+<script src="https://third-party-script.js" {...props} />;
+
+<script>{`console.log("inline")`}</script>;
+
+<Script src="https://third-party-script.js" />;
@@ -0,0 +1,4 @@
+<button className="button secondary" />;
+<button className={clsx("buton", { primary: isPrimary })} />;
+<h2 class="title header" />;
+<div className={`card ${size} shadow`} />;
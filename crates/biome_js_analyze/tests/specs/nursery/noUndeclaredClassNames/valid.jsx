@@ -0,0 +1,6 @@
+<button className={isPrimary ? "button primary" : "button"} />;
+<button className={`button ${variant}`} />;
+<button className={`button-${size}`} />;
+<div className={clsx("card", { title: hasTitle })} id="undeclared" />;
+<div className={variant === "undeclared" ? "button" : "card"} />;
+<span data-class="undeclared" />;
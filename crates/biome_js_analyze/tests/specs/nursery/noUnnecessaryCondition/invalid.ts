@@ -0,0 +1,56 @@
+function alwaysTruthyIf(items: string[]) {
+	if (items) {
+		return items[0];
+	}
+}
+
+function alwaysFalsyIf(value: null) {
+	if (value) {
+		console.log(value);
+	} else {
+		console.log("nothing");
+	}
+}
+
+function alwaysFalsyIfWithoutElse(value: undefined | 0) {
+	if (value) {
+		console.log(value);
+	}
+	return 1;
+}
+
+function nullish(name: string) {
+	return name ?? "world";
+}
+
+function logicalAnd(callback: () => void) {
+	return callback && callback();
+}
+
+function logicalOr(value: "" | 0 | false) {
+	return value || "default";
+}
+
+function ternary(value: { a: number }) {
+	return value ? value.a : 0;
+}
+
+function loop(items: number[]) {
+	while (items) {
+		items.pop();
+	}
+}
+
+function negation(items: string[]) {
+	if (!items) {
+		return;
+	}
+}
+
+const config = { debug: true };
+if (config) {
+}
+
+function asserted(value: string | undefined) {
+	return value! ?? "";
+}
@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+const items = [];
+if (items) {
+}
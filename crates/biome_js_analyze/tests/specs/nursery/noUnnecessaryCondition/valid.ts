@@ -0,0 +1,43 @@
+/* should not generate diagnostics */
+function optional(items?: string[]) {
+	if (items) {
+		return items[0];
+	}
+}
+
+function nullable(name: string | null) {
+	return name ?? "world";
+}
+
+function maybeEmpty(value: string) {
+	return value || "default";
+}
+
+function maybeZero(value: number) {
+	return value ? 1 : 0;
+}
+
+function unknownValue(value: unknown) {
+	if (value) {
+	}
+}
+
+function generic<T>(value: T) {
+	return value ?? null;
+}
+
+function defaulted(value: string[] = []) {
+	return value.length;
+}
+
+while (true) {
+	break;
+}
+
+let mutable = { a: 1 };
+if (mutable) {
+}
+
+declare const external: Foo;
+if (external) {
+}
@@ -0,0 +1,5 @@
+/* should not generate diagnostics */
+test("partial fixture", () => {
+	const user = input as any;
+	const other = input as unknown as string;
+});
@@ -0,0 +1,2 @@
+const a = { x: 0 } as const;
+const b = <const>["a", "b"];
@@ -0,0 +1,16 @@
+const a = input as any;
+const b = <any>input;
+const c = input as unknown as string;
+const d = input as any as number;
+const e = (input as unknown) as string;
+
+interface User {
+	name: string;
+	age: number;
+	greet(): void;
+}
+const f = { name: "Jane" } as User;
+const g = <User>{ name: "Jane", age: 30 };
+
+type Point = { x: number; y: number };
+const h = { x: 0 } as Point;
@@ -0,0 +1,22 @@
+/* should not generate diagnostics */
+const a = input as string;
+const b = { x: 0, y: 0 } as const;
+const c = [1, 2] as const;
+
+interface User {
+	name: string;
+	age?: number;
+	greet?(): void;
+}
+const d = { name: "Jane" } as User;
+
+interface Admin extends User {
+	role: string;
+}
+const e = { name: "Jane" } as Admin;
+
+type Point = { x: number; y: number };
+const f = { ...origin } as Point;
+const g = { x: 0, y: 0, z: 0 } as Point;
+
+const h = { x: 0 } as Imported;
@@ -0,0 +1,45 @@
+function Parent() {
+	function Child() {
+		return <div />;
+	}
+	return <Child />;
+}
+
+const ArrowParent = () => {
+	const Child = () => <span />;
+	return <Child />;
+};
+
+function MemoParent() {
+	const Child = memo(() => <div />);
+	const Input = React.forwardRef((props, ref) => <input ref={ref} {...props} />);
+	return (
+		<>
+			<Child />
+			<Input />
+		</>
+	);
+}
+
+const WrappedParent = memo(function () {
+	const Child = function () {
+		return <div />;
+	};
+	return <Child />;
+});
+
+function ClassParent() {
+	class Child extends React.Component {
+		render() {
+			return <div />;
+		}
+	}
+	return <Child />;
+}
+
+class ClassComponent extends React.Component {
+	render() {
+		const Child = () => <div />;
+		return <Child />;
+	}
+}
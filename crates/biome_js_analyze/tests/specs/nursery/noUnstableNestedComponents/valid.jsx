@@ -0,0 +1,35 @@
+/* should not generate diagnostics */
+function Child() {
+	return <div />;
+}
+
+function Parent() {
+	return <Child />;
+}
+
+function List({ items }) {
+	const renderItem = (item) => <li>{item}</li>;
+	return <ul>{items.map(renderItem)}</ul>;
+}
+
+function Table({ rows }) {
+	return rows.map((row) => <Row key={row.id} {...row} />);
+}
+
+function helper() {
+	function Inner() {
+		return <div />;
+	}
+	return Inner;
+}
+
+function Component() {
+	const Value = computeValue();
+	class Error extends Base {}
+	return <div>{Value}</div>;
+}
+
+function Callbacks() {
+	const onClick = useCallback(() => <div />, []);
+	return <button onClick={onClick} />;
+}
@@ -0,0 +1,33 @@
+interface Props {
+	name: string;
+	age: number;
+	onSelect(): void;
+}
+
+function User({ name }: Props) {
+	return <div>{name}</div>;
+}
+
+const Inline = (props: { title: string; subtitle?: string }) => {
+	return <h1>{props.title}</h1>;
+};
+
+type CardProps = {
+	header: string;
+	footer: string;
+	body: string;
+};
+
+const Card: React.FC<CardProps> = (props) => {
+	const { header } = props;
+	return (
+		<div>
+			{header}
+			{props["body"]}
+		</div>
+	);
+};
+
+export default function Page({ title: pageTitle }: { title: string; description: string }) {
+	return <title>{pageTitle}</title>;
+}
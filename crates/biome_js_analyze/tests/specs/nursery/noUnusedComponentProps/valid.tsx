@@ -0,0 +1,44 @@
+/* should not generate diagnostics */
+interface Props {
+	name: string;
+	age: number;
+}
+
+function User({ name, age }: Props) {
+	return (
+		<div>
+			{name} ({age})
+		</div>
+	);
+}
+
+function Spread(props: { label: string; onClick: () => void }) {
+	return <button {...props} />;
+}
+
+function Rest({ label, ...rest }: { label: string; disabled: boolean }) {
+	return <button {...rest}>{label}</button>;
+}
+
+function Forwarded(props: Props) {
+	return <Child data={props} />;
+}
+
+function helper({ name }: Props) {
+	return name;
+}
+
+function Generic<T>({ items }: { items: T[] }) {
+	return <ul>{items.length}</ul>;
+}
+
+function External({ a }: ExternalProps) {
+	return <div>{a}</div>;
+}
+
+const Members = (props: Props) => (
+	<div>
+		{props.name}
+		{props?.age}
+	</div>
+);
@@ -0,0 +1,10 @@
+import { derived, readable, writable as createWritable } from "svelte/store";
+
+const count = createWritable(0);
+count.subscribe((value) => console.log(value));
+
+const time = readable(new Date());
+time.subscribe(console.log);
+
+const double = derived(count, ($count) => $count * 2);
+double.subscribe(() => {});
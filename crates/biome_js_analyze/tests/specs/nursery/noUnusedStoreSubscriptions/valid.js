@@ -0,0 +1,16 @@
+/* should not generate diagnostics */
+import { onDestroy } from "svelte";
+import { writable } from "svelte/store";
+import { writable as otherWritable } from "other-store";
+
+const count = writable(0);
+const unsubscribe = count.subscribe((value) => console.log(value));
+onDestroy(unsubscribe);
+
+onDestroy(count.subscribe(() => {}));
+
+const other = otherWritable(0);
+other.subscribe(() => {});
+
+const observable = createObservable();
+observable.subscribe(() => {});
@@ -0,0 +1,17 @@
+function returnUndefined() {
+	return undefined;
+}
+
+function* yieldUndefined() {
+	yield undefined;
+}
+
+const arrow = () => undefined;
+
+let value = undefined;
+var other = undefined, last = 1;
+
+function parameter(value = undefined) {}
+
+const { property = undefined } = object;
+const [element = undefined] = array;
@@ -0,0 +1,3 @@
+function parameter(value: number = undefined) {}
+
+let value: number | undefined = undefined;
@@ -0,0 +1,24 @@
+/* should not generate diagnostics */
+function returnNothing() {
+	return;
+}
+
+function* delegate() {
+	yield* undefined;
+}
+
+const constant = undefined;
+
+call(undefined);
+
+const object = { value: undefined };
+
+function shadowed(undefined) {
+	return undefined;
+}
+
+class Property {
+	value = undefined;
+}
+
+const comparison = value === undefined;
@@ -0,0 +1,12 @@
+<>
+	<div role="button" aria-checked="true">Save</div>
+	<div role="presentation" aria-expanded="false" />
+	<div role="unknown checkbox" aria-valuenow="3" />
+	<a href="/" aria-checked="true">Home</a>
+	<input type="checkbox" aria-valuenow="1" />
+	<input type="range" aria-checked="true" aria-multiline="true" />
+	<button aria-selected="true">Save</button>
+	<div aria-checked="true" />
+	<h1 aria-valuemax="3">Title</h1>
+	<ul aria-pressed="true" />
+</>
@@ -0,0 +1,18 @@
+/* should not generate diagnostics */
+<>
+	<div role="checkbox" aria-checked="true" aria-label="Subscribe" />
+	<div role="slider" aria-valuenow="3" aria-valuemin="0" aria-valuemax="5" />
+	<div role={role} aria-checked="true" />
+	<div role="unknown" aria-checked="true" />
+	<button aria-pressed="true" aria-expanded="false">Bold</button>
+	<input type="range" aria-valuetext="Medium" />
+	<input aria-checked="true" />
+	<input type={type} aria-checked="true" />
+	<a href="/" aria-expanded="true" aria-describedby="hint">Home</a>
+	<h1 aria-level="2" aria-hidden="true">Title</h1>
+	<div aria-bogus="true" aria-hidden="true" aria-label="Label" />
+	<Button aria-checked="true" />
+	<foo.bar aria-checked="true" />
+	<input type="checkbox" aria-checked={checked} onChange={onChange} aria-valuenow="1" />
+	<div {...props} aria-live="polite" />
+</>
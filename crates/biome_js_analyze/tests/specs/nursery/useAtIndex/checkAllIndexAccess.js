@@ -0,0 +1,4 @@
+const first = array[0];
+const second = array[1];
+const negative = array[-1];
+const key = array["0"];
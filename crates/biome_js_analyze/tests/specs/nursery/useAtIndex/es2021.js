@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+const last = array[array.length - 1];
+const sliced = array.slice(-1)[0];
+const char = string.charAt(string.length - 1);
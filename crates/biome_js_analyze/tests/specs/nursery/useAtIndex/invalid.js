@@ -0,0 +1,9 @@
+const last = array[array.length - 1];
+const secondToLast = array[array.length - 2];
+const nested = this.items[this.items.length - 1];
+const parenthesized = (array)[(array.length - 1)];
+const sliced = array.slice(-1)[0];
+const slicedRange = array.slice(-2, -1)[0];
+const popped = array.slice(-1).pop();
+const char = string.charAt(string.length - 1);
+foo(array[array.length - 1]);
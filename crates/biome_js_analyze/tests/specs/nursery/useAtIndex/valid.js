@@ -0,0 +1,13 @@
+/* should not generate diagnostics */
+const last = array.at(-1);
+const first = array[0];
+const other = array[other.length - 1];
+const sideEffect = getArray()[getArray().length - 1];
+const optional = array?.[array.length - 1];
+delete array[array.length - 1];
+array[array.length - 1]();
+array[array.length - 1] = value;
+const all = array.slice(-1);
+const notLast = array.slice(-2)[1];
+const zero = array[array.length - 0];
+const char = string.charAt(0);
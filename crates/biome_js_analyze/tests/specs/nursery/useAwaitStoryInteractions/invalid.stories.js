@@ -0,0 +1,25 @@
+export default { title: "Form" };
+
+export const Submitted = {
+	play: async ({ canvasElement, step }) => {
+		const canvas = within(canvasElement);
+		userEvent.type(canvas.getByRole("textbox"), "Hello");
+		canvas.findByText("Hello");
+		expect(canvas.getByRole("button")).not.toBeDisabled();
+		step("Submit", async () => {
+			userEvent.click(canvas.getByRole("button"));
+		});
+		waitFor(() => expect(1).toBe(1));
+	},
+};
+
+export const Method = {
+	async play({ canvasElement }) {
+		userEvent.click(canvasElement);
+	},
+};
+
+export const Assigned = () => {};
+Assigned.play = ({ canvasElement }) => {
+	userEvent.click(canvasElement);
+};
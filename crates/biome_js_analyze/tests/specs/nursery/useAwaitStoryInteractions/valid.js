@@ -0,0 +1,6 @@
+/* should not generate diagnostics */
+export const Submitted = {
+	play: async ({ canvasElement }) => {
+		userEvent.click(canvasElement);
+	},
+};
@@ -0,0 +1,21 @@
+/* should not generate diagnostics */
+export default { title: "Form" };
+
+export const Submitted = {
+	play: async ({ canvasElement, step }) => {
+		const canvas = within(canvasElement);
+		await userEvent.type(canvas.getByRole("textbox"), "Hello");
+		await canvas.findByText("Hello");
+		await expect(canvas.getByRole("button")).not.toBeDisabled();
+		await step("Submit", async () => {
+			await userEvent.click(canvas.getByRole("button"));
+		});
+		const user = userEvent.setup();
+		canvas.getByRole("button");
+		return waitFor(() => expect(1).toBe(1));
+	},
+};
+
+function helper() {
+	userEvent.click(document.body);
+}
@@ -0,0 +1,15 @@
+async function literals() {
+	await 42;
+	await "text";
+	await `template`;
+	await null;
+	await undefined;
+	await [];
+	await {};
+	await (() => {});
+}
+
+async function constants() {
+	const value = 1;
+	return await value;
+}
@@ -0,0 +1,18 @@
+async function annotated(name: string, count: number, flags: boolean[]) {
+	await name;
+	await count;
+	await flags;
+}
+
+interface Point {
+	x: number;
+	y: number;
+}
+
+async function withInterface(point: Point) {
+	return /* comment */ await point;
+}
+
+async function asserted(value: unknown) {
+	return await (value as string);
+}
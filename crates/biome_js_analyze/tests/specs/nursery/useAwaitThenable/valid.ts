@@ -0,0 +1,29 @@
+/* should not generate diagnostics */
+async function promises(value: Promise<string>, like: PromiseLike<number>) {
+	await value;
+	await like;
+}
+
+async function unknowns(value: unknown, anything: any, external: External) {
+	await value;
+	await anything;
+	await external;
+}
+
+async function calls() {
+	await fetch("https://example.com");
+	await new Promise((resolve) => setTimeout(resolve, 10));
+}
+
+interface Thenable {
+	then(onfulfilled: () => void): void;
+}
+
+async function thenable(value: Thenable, union: string | Promise<string>) {
+	await value;
+	await union;
+}
+
+async function generic<T>(value: T) {
+	await value;
+}
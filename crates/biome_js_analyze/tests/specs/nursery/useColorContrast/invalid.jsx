@@ -0,0 +1,12 @@
+<>
+	<p style={{ color: "#999", backgroundColor: "#fff" }}>Hello</p>
+	<p style={{ color: "#777777", backgroundColor: "white" }}>Hello</p>
+	<span style={{ color: "yellow", background: "white", fontSize: 24 }}>Hello</span>
+	<p style={{ backgroundColor: "rgb(0 0 0)", color: "hsl(0, 0%, 30%)" }}>Hello</p>
+	<p style={{ color: "rgba(0, 0, 0, 0.3)", backgroundColor: "#fff" }}>Hello</p>
+	<p style={{ color: "#949494", backgroundColor: "#fff", fontSize: "18px", fontWeight: "bold" }}>Hello</p>
+	<p style={{ color: "#999", backgroundColor: "#fff", fontSize: "2em" }}>Hello</p>
+	<p style={{ "color": "#999", "backgroundColor": "#fff" }}>Hello</p>
+	<p style={{ color: "#000", backgroundColor: "#fff", color: "#eee" }}>Hello</p>
+	<p style={{ color: `#999`, backgroundColor: "#fff" }}>Hello</p>
+</>
@@ -0,0 +1,22 @@
+/* should not generate diagnostics */
+<>
+	<p style={{ color: "#767676", backgroundColor: "#fff" }}>Hello</p>
+	<p style={{ color: "black", backgroundColor: "white" }}>Hello</p>
+	<h1 style={{ color: "#949494", backgroundColor: "#fff", fontSize: "24px" }}>Hello</h1>
+	<h1 style={{ color: "#949494", backgroundColor: "#fff", fontSize: 19, fontWeight: 700 }}>Hello</h1>
+	<h1 style={{ color: "#949494", backgroundColor: "#fff", fontSize: "14pt", fontWeight: "bold" }}>Hello</h1>
+	<p style={{ color: textColor, backgroundColor: "#fff" }}>Hello</p>
+	<p style={{ color: "#999", backgroundColor: background }}>Hello</p>
+	<p style={{ color: "#999" }}>Hello</p>
+	<p style={{ backgroundColor: "#fff" }}>Hello</p>
+	<p style={{ color: "#999", backgroundColor: "rgba(255, 255, 255, 0.5)" }}>Hello</p>
+	<p style={{ color: "#999", backgroundColor: "transparent" }}>Hello</p>
+	<p style={{ color: "#999", background: "url(image.png) #fff" }}>Hello</p>
+	<p style={{ color: "currentcolor", backgroundColor: "#fff" }}>Hello</p>
+	<p style={{ color: "var(--text)", backgroundColor: "#fff" }}>Hello</p>
+	<p style={{ color: "#999", backgroundColor: "#fff", ...overrides }}>Hello</p>
+	<p style={{ [key]: "#999", backgroundColor: "#fff" }}>Hello</p>
+	<p style={{ color: "#eee", backgroundColor: "#fff", color: "#000" }}>Hello</p>
+	<p style={styles}>Hello</p>
+	<Text style={{ color: "#999", backgroundColor: "#fff" }}>Hello</Text>
+</>
@@ -0,0 +1,10 @@
+<Foo bar={"baz"} />;
+<Foo bar={'baz'} />;
+<Foo bar={`baz`} />;
+<Foo bar={"it's"} />;
+<Foo bar={'say "hi"'} />;
+<Foo>{"Hello world"}</Foo>;
+<Foo>{`Hello`} world</Foo>;
+<Foo>
+	{"Hello"}
+</Foo>;
@@ -0,0 +1,6 @@
+<Foo bar="baz" />;
+<Foo bar='say "hi"' />;
+<Foo>Hello world</Foo>;
+<Foo>
+	Hello world
+</Foo>;
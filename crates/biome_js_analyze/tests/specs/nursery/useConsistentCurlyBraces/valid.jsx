@@ -0,0 +1,16 @@
+/* should not generate diagnostics */
+<Foo bar="baz" />;
+<Foo bar={"a\nb"} />;
+<Foo bar={"&amp;"} />;
+<Foo bar={`it's "quoted"`} />;
+<Foo bar={`a${b}`} />;
+<Foo bar={tag`baz`} />;
+<Foo bar={/* comment */ "baz"} />;
+<Foo bar={baz} />;
+<Foo>Hello world</Foo>;
+<Foo>{"{braces}"}</Foo>;
+<Foo>{"<tag>"}</Foo>;
+<Foo>{" "}</Foo>;
+<Foo>{"trailing "}</Foo>;
+<Foo>{""}</Foo>;
+<Foo>{value}</Foo>;
@@ -0,0 +1,13 @@
+/* should not generate diagnostics */
+<Foo bar={"baz"} />;
+<Foo bar="a\b" />;
+<Foo bar="&amp;" />;
+<Foo>{"Hello world"}</Foo>;
+<Foo>
+	<Bar />
+</Foo>;
+<Foo>
+	Multi
+	line
+</Foo>;
+<Foo>Tom &amp; Jerry</Foo>;
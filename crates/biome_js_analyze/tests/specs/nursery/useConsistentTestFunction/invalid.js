@@ -0,0 +1,13 @@
+it("adds numbers", () => {});
+
+it.only("adds numbers", () => {});
+
+xit("is skipped", () => {});
+
+describe("add", () => {
+	test("adds numbers", () => {});
+
+	xtest("is skipped", () => {});
+
+	test.skip("is skipped", () => {});
+});
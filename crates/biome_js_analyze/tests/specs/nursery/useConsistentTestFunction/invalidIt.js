@@ -0,0 +1,6 @@
+test("adds numbers", () => {});
+
+describe("add", () => {
+	test("adds numbers", () => {});
+	it("adds numbers", () => {});
+});
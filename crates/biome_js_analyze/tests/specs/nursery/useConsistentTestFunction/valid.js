@@ -0,0 +1,16 @@
+/* should not generate diagnostics */
+test("adds numbers", () => {});
+
+test.only("adds numbers", () => {});
+
+xtest("is skipped", () => {});
+
+describe("add", () => {
+	it("adds numbers", () => {});
+
+	xit("is skipped", () => {});
+
+	describe("nested", () => {
+		it.skip("adds numbers", () => {});
+	});
+});
@@ -0,0 +1,5 @@
+import { ref } from "vue";
+
+const count = ref(0);
+const props = defineProps({ title: String });
+const emit = defineEmits(["change"]);
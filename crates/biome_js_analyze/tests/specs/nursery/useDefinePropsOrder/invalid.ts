@@ -0,0 +1,6 @@
+interface Props {
+	title: string;
+}
+
+const emit = defineEmits<{ change: [value: string] }>();
+const props = withDefaults(defineProps<Props>(), { title: "Title" });
@@ -0,0 +1,14 @@
+/* should not generate diagnostics */
+import { ref } from "vue";
+
+export interface Props {
+	title: string;
+}
+
+type Emits = {
+	change: [value: string];
+};
+
+const props = defineProps<Props>();
+const emit = defineEmits<Emits>();
+const count = ref(0);
@@ -0,0 +1,65 @@
+function greet() {
+	return "Hello";
+}
+
+function noReturn(message: string) {
+	console.log(message);
+}
+
+function fromParameter(value: number) {
+	return value;
+}
+
+function union(flag: boolean) {
+	if (flag) {
+		return "yes";
+	}
+	return null;
+}
+
+async function fetchCount() {
+	return 0;
+}
+
+function* generate() {
+	yield 1;
+}
+
+function fallsThrough(flag: boolean) {
+	if (flag) {
+		return 1;
+	}
+}
+
+const arrow = (name: string) => `Hello ${name}`;
+
+const arrowWithBody = () => {
+	const value: Map<string, number> = new Map();
+	return value;
+};
+
+const withoutParentheses = async x => x;
+
+const fn = function () {
+	return true;
+};
+
+class Counter {
+	count = 0;
+	increment(step: number) {
+		this.count += step;
+	}
+	value() {
+		return this.count;
+	}
+}
+
+const object = {
+	method() {
+		return 1n;
+	},
+};
+
+export default function () {
+	return /regex/;
+}
@@ -0,0 +1,42 @@
+function internal() {
+	return 0;
+}
+
+export function exported() {
+	return 0;
+}
+
+function exportedLater() {
+	return 0;
+}
+export { exportedLater };
+
+export const exportedArrow = () => 0;
+
+const internalArrow = () => 0;
+
+export default () => 0;
+
+export class Service {
+	run() {
+		return 0;
+	}
+	private helper() {
+		return 0;
+	}
+	#secret() {
+		return 0;
+	}
+}
+
+class InternalService {
+	run() {
+		return 0;
+	}
+}
+
+export const api = {
+	call() {
+		return 0;
+	},
+};
@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+function greet() {
+	return "Hello";
+}
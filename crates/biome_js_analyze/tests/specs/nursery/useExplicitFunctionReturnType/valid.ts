@@ -0,0 +1,17 @@
+/* should not generate diagnostics */
+function greet(): string {
+	return "Hello";
+}
+
+const arrow = (name: string): string => `Hello ${name}`;
+
+[1, 2].map((n) => n * 2);
+
+const typed: (n: number) => number = (n) => n * 2;
+
+const satisfied = (() => 0) satisfies () => number;
+
+class Counter {
+	constructor() {}
+	set value(value: number) {}
+}
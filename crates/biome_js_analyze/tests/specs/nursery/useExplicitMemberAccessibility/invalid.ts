@@ -0,0 +1,33 @@
+class Animal {
+	name: string;
+	static count = 0;
+	readonly legs = 4;
+
+	constructor(readonly species: string) {}
+
+	move(distance: number) {}
+
+	get age() {
+		return 0;
+	}
+
+	set age(value: number) {}
+
+	async *walk() {}
+
+	@decorated
+	decoratedProperty = 0;
+
+	@decorated decoratedMethod() {}
+
+	// Comment
+	commented = 0;
+}
+
+abstract class Shape {
+	abstract area(): number;
+	abstract name: string;
+	declare kind: string;
+	constructor();
+	constructor(value?: number) {}
+}
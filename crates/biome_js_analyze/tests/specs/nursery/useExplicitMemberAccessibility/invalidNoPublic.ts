@@ -0,0 +1,13 @@
+class Animal {
+	public name: string;
+	public static count = 0;
+
+	public constructor(public readonly species: string, public owner: string) {}
+
+	public move(distance: number) {}
+
+	@decorated public decorated = 0;
+
+	// Comment
+	public commented = 0;
+}
@@ -0,0 +1,21 @@
+/* should not generate diagnostics */
+class Animal {
+	public name: string;
+	private static count = 0;
+	protected readonly legs = 4;
+	#secret = 0;
+	#method() {}
+	[key: string]: unknown;
+
+	public constructor(private readonly species: string) {}
+
+	public move(distance: number) {}
+
+	protected get age() {
+		return 0;
+	}
+
+	static {
+		Animal.count = 1;
+	}
+}
@@ -0,0 +1,6 @@
+/* should not generate diagnostics */
+class Animal {
+	name = "";
+
+	move() {}
+}
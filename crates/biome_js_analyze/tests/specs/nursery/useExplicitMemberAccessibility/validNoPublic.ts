@@ -0,0 +1,10 @@
+/* should not generate diagnostics */
+class Animal {
+	name: string;
+	private static count = 0;
+	protected legs = 4;
+
+	constructor(public species: string, private readonly owner: string) {}
+
+	move(distance: number) {}
+}
@@ -1,4 +1,9 @@
 <div>
 	<div role="button" />
 	<div role="tab" />
+	<span role="link" onClick={handleClick}>Open</span>
+	<div
+		role="checkbox"
+		aria-checked="false"
+	/>
 </div>;
@@ -0,0 +1,5 @@
+<link href="https://fonts.gstatic.com" />;
+
+<link rel="preload" href="https://fonts.gstatic.com" />;
+
+<link href="https://fonts.gstatic.com/s/inter.woff2" rel="stylesheet"></link>;
@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+<link rel="preconnect" href="https://fonts.gstatic.com" />;
+
+<link rel="preconnect" href="https://fonts.gstatic.com" crossOrigin="" />;
+
+<link href="/logo.svg" rel="icon" />;
+
+<link href="https://fonts.googleapis.com/css2?family=Inter" rel="stylesheet" />;
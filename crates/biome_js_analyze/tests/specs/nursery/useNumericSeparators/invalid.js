@@ -0,0 +1,11 @@
+const budget = 1000000;
+const large = 123456789012;
+const color = 0xFFAA33;
+const mask = 0b10101010;
+const permissions = 0o7777777;
+const precise = 3.14159265;
+const tiny = 0.000001;
+const scientific = 12345e10;
+const big = 9007199254740993n;
+const bigHex = 0xFFFFFFn;
+const negative = -100000;
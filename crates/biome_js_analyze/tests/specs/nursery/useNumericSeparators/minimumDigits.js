@@ -0,0 +1,2 @@
+const tooShort = 100000;
+const long = 1000000;
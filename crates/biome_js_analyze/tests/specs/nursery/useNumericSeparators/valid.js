@@ -0,0 +1,11 @@
+/* should not generate diagnostics */
+const budget = 1_000_000;
+const color = 0xFF_AA_33;
+const mask = 0b1010_1010;
+const port = 8080;
+const short = 0xFF;
+const legacyOctal = 0777777;
+const leadingZero = 0123456;
+const grouped = 10_0000;
+const fraction = 1.25;
+const big = 1234n;
@@ -0,0 +1,12 @@
+Object.assign({}, foo);
+Object.assign({}, foo, bar);
+Object.assign({}, { foo: "bar" }, baz);
+Object.assign({});
+const a = Object.assign({}, foo);
+const b = () => Object.assign({}, foo);
+Object.assign({}, foo).bar;
+(Object).assign({}, foo);
+Object["assign"]({}, foo);
+Object.assign(({}), foo);
+Object.assign({ foo: "bar" }, baz);
+Object.assign({ ...foo }, bar);
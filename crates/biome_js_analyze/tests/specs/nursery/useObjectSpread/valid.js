@@ -0,0 +1,13 @@
+/* should not generate diagnostics */
+({ ...foo });
+Object.assign(foo, bar);
+Object.assign(foo.bar, baz);
+Object.assign({}, ...sources);
+Object.assign({ foo }, ...sources);
+Object.assign();
+Object?.assign({}, foo);
+Object.assign?.({}, foo);
+Object.keys({}, foo);
+function f(Object) {
+	Object.assign({}, foo);
+}
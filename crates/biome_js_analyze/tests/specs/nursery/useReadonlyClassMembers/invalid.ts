@@ -0,0 +1,37 @@
+class Constructor {
+	private value: number;
+
+	constructor(value: number) {
+		this.value = value;
+	}
+}
+
+class Initializer {
+	private count = 0;
+	#name = "name";
+
+	get label() {
+		return `${this.#name}: ${this.count}`;
+	}
+}
+
+class ParameterProperty {
+	constructor(private service: Service, protected other: Service) {}
+
+	run() {
+		return this.service.run();
+	}
+}
+
+class Modifiers {
+	@observable private decorated = 0;
+	private override overridden = 0;
+}
+
+class OtherInstances {
+	private value = 0;
+
+	equals(other: OtherInstances) {
+		return other.value === this.value;
+	}
+}
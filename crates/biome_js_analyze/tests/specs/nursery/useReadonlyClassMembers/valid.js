@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+class JavaScript {
+	#value = 0;
+
+	get value() {
+		return this.#value;
+	}
+}
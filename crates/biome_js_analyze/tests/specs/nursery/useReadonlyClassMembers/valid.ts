@@ -0,0 +1,78 @@
+/* should not generate diagnostics */
+class Readonly {
+	private readonly value: number;
+	readonly #name = "name";
+
+	constructor(value: number, private readonly service: Service) {
+		this.value = value;
+	}
+}
+
+class NotPrivate {
+	value = 0;
+	protected other = 0;
+	constructor(public service: Service) {}
+}
+
+class Reassigned {
+	private count = 0;
+	private total = 0;
+	#name = "name";
+	private items: string[] = [];
+	private computed = 0;
+	private destructured = 0;
+
+	increment() {
+		this.count++;
+		this.total += 1;
+		this.#name = "other";
+		[this.destructured] = [1];
+	}
+
+	reset() {
+		this.items = [];
+		this["computed"] = 0;
+	}
+}
+
+class ArrowInConstructor {
+	private value = 0;
+
+	constructor() {
+		setTimeout(() => {
+			this.value = 1;
+		});
+	}
+}
+
+class ParameterReassigned {
+	constructor(private service: Service) {}
+
+	replace(service: Service) {
+		this.service = service;
+	}
+}
+
+class OtherInstanceAssignment {
+	private value = 0;
+
+	copy(other: OtherInstanceAssignment) {
+		other.value = this.value;
+	}
+}
+
+class UnknownMember {
+	private value = 0;
+
+	set(key: string) {
+		this[key] = 1;
+	}
+}
+
+class Static {
+	private static instance = 0;
+}
+
+class Accessor {
+	private accessor value = 0;
+}
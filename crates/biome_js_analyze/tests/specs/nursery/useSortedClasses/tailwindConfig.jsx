@@ -0,0 +1,8 @@
+<>
+	{/* SHOULD emit diagnostics (prefix, separator and custom utilities of the Tailwind config) */}
+	<div class="tw-tab-4 tw-content-auto foo tw-p-4 tw-btn" />
+	<div class="px-2 tw-px-2 bar" />
+	<div class="hover_tw-p-4 tw-tab-2 tw-m-2" />
+	{/* SHOULD NOT emit diagnostics */}
+	<div class="foo tw-btn tw-p-4 tw-content-auto tw-tab-4" />
+</>;
@@ -0,0 +1,7 @@
+import { Button } from "./Button";
+
+export const Primary = {
+	args: { primary: true },
+};
+
+export const Secondary = {};
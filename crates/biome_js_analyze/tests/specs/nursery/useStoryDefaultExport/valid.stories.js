@@ -0,0 +1,9 @@
+/* should not generate diagnostics */
+import { Button } from "./Button";
+
+export default {
+	title: "Button",
+	component: Button,
+};
+
+export const Primary = {};
@@ -0,0 +1,8 @@
+/* should not generate diagnostics */
+import { Button } from "./Button";
+
+const meta = { component: Button };
+
+export { meta as default };
+
+export const Primary = {};
@@ -0,0 +1,9 @@
+export default { title: "Button" };
+
+export const primary = {};
+
+export const secondary_button = {};
+
+export function large() {}
+
+export let SMALL_BUTTON = {};
@@ -0,0 +1,12 @@
+/* should not generate diagnostics */
+export default { title: "Button" };
+
+export const Primary = {};
+
+export const SecondaryButton = {};
+
+export function Large() {}
+
+export const __namedExportsOrder = ["Primary", "SecondaryButton", "Large"];
+
+const helper = {};
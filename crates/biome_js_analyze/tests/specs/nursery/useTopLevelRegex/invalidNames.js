@@ -0,0 +1,21 @@
+import { EMAIL_PATTERN } from "./patterns";
+
+function isEmail(value) {
+	const emailPattern = /^[^@]+@[^@]+$/;
+	return emailPattern.test(value);
+}
+
+// Checks the identifier.
+function isIdentifier(value) {
+	return /^[a-z]+$/i.test(value);
+}
+
+function replaceAll(value) {
+	return value.replace(/a/g, "b");
+}
+
+const matcher = {
+	matches(value) {
+		return /^\d+$/.test(value);
+	},
+};
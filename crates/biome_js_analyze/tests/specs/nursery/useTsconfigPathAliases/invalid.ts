@@ -0,0 +1,6 @@
+import { format } from "../../utils/format";
+import { total } from '../../../specs/utils/total';
+export { price } from "../../utils/price";
+const lazy = import("../../utils/lazy");
+const legacy = require("../../../lib/legacy");
+const config = require("../../../config/index");
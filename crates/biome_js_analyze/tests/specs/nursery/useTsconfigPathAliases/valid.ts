@@ -0,0 +1,7 @@
+/* should not generate diagnostics */
+import { items } from "../items";
+import { item } from "./item";
+import { format } from "@/utils/format";
+import { outside } from "../../../../outside";
+import { other } from "../../../config/other";
+import "react";
@@ -0,0 +1,2 @@
+/* should not generate diagnostics */
+import { format } from "../../utils/format";
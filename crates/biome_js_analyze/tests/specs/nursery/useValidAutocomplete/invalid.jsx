@@ -0,0 +1,15 @@
+<>
+	<input autoComplete="incorrect" />
+	<input autoComplete="nmae" />
+	<input autocomplete="home name" />
+	<input autoComplete="email billing" />
+	<input autoComplete="shipping" />
+	<input autoComplete="section-checkout" />
+	<input autoComplete="on name" />
+	<input autoComplete="shipping section-checkout name" />
+	<input autoComplete="work mobile tel" />
+	<input autoComplete="email webauthn name" />
+	<select autoComplete="country-code" />
+	<textarea autoComplete={"street-addres"} />
+	<form autoComplete="name" />
+</>
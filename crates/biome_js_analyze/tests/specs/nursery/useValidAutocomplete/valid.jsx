@@ -0,0 +1,22 @@
+/* should not generate diagnostics */
+<>
+	<input autoComplete="name" />
+	<input autoComplete="" />
+	<input autoComplete="on" />
+	<input autoComplete="OFF" />
+	<input autoComplete="Given-Name" />
+	<input autocomplete="email" />
+	<input autoComplete="section-checkout shipping postal-code" />
+	<input autoComplete="billing cc-number" />
+	<input autoComplete="work email" />
+	<input autoComplete="section-contact home tel-national" />
+	<input autoComplete="username webauthn" />
+	<input autoComplete="  current-password  " />
+	<input autoComplete={autoComplete} />
+	<input autoComplete={`name ${suffix}`} />
+	<select autoComplete="country" />
+	<textarea autoComplete="street-address" />
+	<form autoComplete="off" />
+	<div autoComplete="incorrect" />
+	<MyInput autoComplete="incorrect" />
+</>
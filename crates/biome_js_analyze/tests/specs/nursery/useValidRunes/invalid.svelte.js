@@ -0,0 +1,20 @@
+let count;
+count = $state(0);
+
+const double = $derived();
+
+const cleanup = $effect(() => {});
+
+const many = $state(0, 1);
+
+export function useCounter() {
+	return $state.raw({ count: 0 });
+}
+
+const props = $props({});
+
+const by = $derived.by(() => count * 2, count);
+
+$effect.pre();
+
+console.log($inspect(count));
@@ -0,0 +1,4 @@
+/* should not generate diagnostics */
+// Runes only exist in Svelte files.
+let count;
+count = $state(0);
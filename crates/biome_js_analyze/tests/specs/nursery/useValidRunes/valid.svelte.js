@@ -0,0 +1,24 @@
+/* should not generate diagnostics */
+let count = $state(0);
+let items = $state.raw([]);
+const double = $derived(count * 2);
+const triple = $derived.by(() => count * 3);
+let { title } = $props();
+
+class Counter {
+	count = $state(0);
+	double = $derived(this.count * 2);
+}
+
+$effect(() => {
+	console.log(double);
+});
+
+$effect.pre(() => {});
+
+$inspect(count);
+$inspect(count).with(console.trace);
+
+function scoped($state) {
+	return $state(1, 2, 3);
+}
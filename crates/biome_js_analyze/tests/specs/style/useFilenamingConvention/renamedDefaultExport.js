@@ -0,0 +1,2 @@
+const fooBar = {};
+export { fooBar as default };
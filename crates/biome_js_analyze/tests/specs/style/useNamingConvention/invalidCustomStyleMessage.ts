@@ -0,0 +1,11 @@
+export interface IProps {}
+
+export interface Props {}
+
+export interface Ideal {}
+
+export function useState() {}
+
+export function use_state() {}
+
+export function usestate() {}
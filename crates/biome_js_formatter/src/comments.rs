@@ -1,4 +1,5 @@
 use crate::prelude::*;
+use crate::utils::jsdoc::{is_jsdoc_comment, FormatJsdocComment};
 use crate::utils::AnyJsConditional;
 use biome_diagnostics_categories::category;
 use biome_formatter::comments::is_alignable_comment;
@@ -33,7 +34,9 @@ impl FormatRule<SourceComment<JsLanguage>> for FormatJsLeadingComment {
         comment: &SourceComment<JsLanguage>,
         f: &mut Formatter<Self::Context>,
     ) -> FormatResult<()> {
-        if is_alignable_comment(comment.piece()) {
+        if f.options().format_jsdoc().value() && is_jsdoc_comment(comment) {
+            write!(f, [FormatJsdocComment::new(comment)])
+        } else if is_alignable_comment(comment.piece()) {
             let mut source_offset = comment.piece().text_range().start();
 
             let mut lines = comment.piece().text().lines();
@@ -5,13 +5,14 @@ use biome_deserialize_macros::{Deserializable, Merge};
 use biome_formatter::printer::PrinterOptions;
 use biome_formatter::{
     AttributePosition, CstFormatContext, FormatContext, FormatElement, FormatOptions, IndentStyle,
-    IndentWidth, LineEnding, LineWidth, QuoteStyle, TransformSourceMap,
+    IndentWidth, LineEnding, LineWidth, MaxEmptyLines, QuoteStyle, TransformSourceMap,
 };
 use biome_js_syntax::{AnyJsFunctionBody, JsFileSource, JsLanguage};
 use std::fmt;
 use std::fmt::Debug;
 use std::rc::Rc;
 use std::str::FromStr;
+use std::sync::Arc;
 pub use trailing_commas::TrailingCommas;
 
 #[derive(Debug, Clone)]
@@ -129,6 +130,20 @@ impl CstFormatContext for JsFormatContext {
     }
 }
 
+/// Formats the content of the tagged template literals whose content is another language,
+/// such as the CSS of `` styled.div`color: red;` ``.
+///
+/// The JavaScript formatter doesn't know the other languages:
+/// the content of a template literal is printed as it is, unless a formatter of its tag is provided.
+pub trait FormatEmbeddedCode: fmt::Debug + Send + Sync {
+    /// Returns the formatted `code`, or `None` if it can't be formatted.
+    ///
+    /// `tag` is the code of the tag of the template literal, such as `css` or `styled.div`.
+    /// The substitutions of the template, such as `${color}`, are replaced by placeholders in `code`,
+    /// which must be kept in the formatted code.
+    fn format_embedded_code(&self, tag: &str, code: &str) -> Option<String>;
+}
+
 #[derive(Debug, Clone)]
 pub struct JsFormatOptions {
     /// The indent style.
@@ -143,6 +158,9 @@ pub struct JsFormatOptions {
     /// What's the max width of a line. Defaults to 80.
     line_width: LineWidth,
 
+    /// How many consecutive empty lines are kept. Defaults to 1.
+    max_empty_lines: MaxEmptyLines,
+
     /// The style for quotes. Defaults to double.
     quote_style: QuoteStyle,
 
@@ -167,11 +185,40 @@ pub struct JsFormatOptions {
     /// Whether to hug the closing bracket of multiline HTML/JSX tags to the end of the last line, rather than being alone on the following line. Defaults to false.
     bracket_same_line: BracketSameLine,
 
+    /// Whether to print each attribute of the JSX elements that have more than one attribute on its own line. Defaults to false.
+    single_attribute_per_line: SingleAttributePerLine,
+
     /// Information related to the current file
     source_type: JsFileSource,
 
     /// Attribute position style. By default auto.
     attribute_position: AttributePosition,
+
+    /// Whether the objects that have a line break before their first member are kept expanded. Defaults to "preserve".
+    object_wrap: ObjectWrap,
+
+    /// Whether the operators of the binary expressions that break are printed at the end of the line or at the start of the next line. Defaults to "end".
+    operator_position: OperatorPosition,
+
+    /// Whether to normalize the JSDoc comments: align their asterisks, reflow their descriptions and lay out their tags. Defaults to false.
+    format_jsdoc: FormatJsdoc,
+
+    /// Whether the decorators of the class members are printed on their own line or on the line of the member. Defaults to "preserve".
+    decorator_placement: DecoratorPlacement,
+
+    /// Whether the conditionals in the alternate of a conditional are indented or printed at the same indent, as a chain. Defaults to "nested".
+    ternary_style: TernaryStyle,
+
+    /// The minimum number of calls of a member chain for it to be broken with a call per line,
+    /// the shorter chains are printed on one line and only their arguments break. Defaults to 0.
+    member_chain_min_calls: u8,
+
+    /// Whether the member chains that fit on one line are always printed on one line,
+    /// even when they have more than two calls with complex arguments. Defaults to false.
+    inline_short_member_chains: InlineShortMemberChains,
+
+    /// Formats the content of the tagged template literals, none by default
+    embedded_code_formatter: Option<Arc<dyn FormatEmbeddedCode>>,
 }
 
 impl JsFormatOptions {
@@ -182,6 +229,7 @@ impl JsFormatOptions {
             indent_width: IndentWidth::default(),
             line_ending: LineEnding::default(),
             line_width: LineWidth::default(),
+            max_empty_lines: MaxEmptyLines::default(),
             quote_style: QuoteStyle::default(),
             jsx_quote_style: QuoteStyle::default(),
             quote_properties: QuoteProperties::default(),
@@ -190,7 +238,16 @@ impl JsFormatOptions {
             arrow_parentheses: ArrowParentheses::default(),
             bracket_spacing: BracketSpacing::default(),
             bracket_same_line: BracketSameLine::default(),
+            single_attribute_per_line: SingleAttributePerLine::default(),
             attribute_position: AttributePosition::default(),
+            object_wrap: ObjectWrap::default(),
+            operator_position: OperatorPosition::default(),
+            format_jsdoc: FormatJsdoc::default(),
+            decorator_placement: DecoratorPlacement::default(),
+            ternary_style: TernaryStyle::default(),
+            member_chain_min_calls: 0,
+            inline_short_member_chains: InlineShortMemberChains::default(),
+            embedded_code_formatter: None,
         }
     }
 
@@ -209,6 +266,14 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_single_attribute_per_line(
+        mut self,
+        single_attribute_per_line: SingleAttributePerLine,
+    ) -> Self {
+        self.single_attribute_per_line = single_attribute_per_line;
+        self
+    }
+
     pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
         self.indent_style = indent_style;
         self
@@ -229,6 +294,11 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_max_empty_lines(mut self, max_empty_lines: MaxEmptyLines) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
     pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
         self.quote_style = quote_style;
         self
@@ -259,6 +329,52 @@ impl JsFormatOptions {
         self
     }
 
+    pub fn with_object_wrap(mut self, object_wrap: ObjectWrap) -> Self {
+        self.object_wrap = object_wrap;
+        self
+    }
+
+    pub fn with_operator_position(mut self, operator_position: OperatorPosition) -> Self {
+        self.operator_position = operator_position;
+        self
+    }
+
+    pub fn with_format_jsdoc(mut self, format_jsdoc: FormatJsdoc) -> Self {
+        self.format_jsdoc = format_jsdoc;
+        self
+    }
+
+    pub fn with_decorator_placement(mut self, decorator_placement: DecoratorPlacement) -> Self {
+        self.decorator_placement = decorator_placement;
+        self
+    }
+
+    pub fn with_ternary_style(mut self, ternary_style: TernaryStyle) -> Self {
+        self.ternary_style = ternary_style;
+        self
+    }
+
+    pub fn with_member_chain_min_calls(mut self, member_chain_min_calls: u8) -> Self {
+        self.member_chain_min_calls = member_chain_min_calls;
+        self
+    }
+
+    pub fn with_inline_short_member_chains(
+        mut self,
+        inline_short_member_chains: InlineShortMemberChains,
+    ) -> Self {
+        self.inline_short_member_chains = inline_short_member_chains;
+        self
+    }
+
+    pub fn with_embedded_code_formatter(
+        mut self,
+        embedded_code_formatter: impl FormatEmbeddedCode + 'static,
+    ) -> Self {
+        self.embedded_code_formatter = Some(Arc::new(embedded_code_formatter));
+        self
+    }
+
     pub fn set_arrow_parentheses(&mut self, arrow_parentheses: ArrowParentheses) {
         self.arrow_parentheses = arrow_parentheses;
     }
@@ -271,6 +387,13 @@ impl JsFormatOptions {
         self.bracket_same_line = bracket_same_line;
     }
 
+    pub fn set_single_attribute_per_line(
+        &mut self,
+        single_attribute_per_line: SingleAttributePerLine,
+    ) {
+        self.single_attribute_per_line = single_attribute_per_line;
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -287,6 +410,10 @@ impl JsFormatOptions {
         self.line_width = line_width;
     }
 
+    pub fn set_max_empty_lines(&mut self, max_empty_lines: MaxEmptyLines) {
+        self.max_empty_lines = max_empty_lines;
+    }
+
     pub fn set_quote_style(&mut self, quote_style: QuoteStyle) {
         self.quote_style = quote_style;
     }
@@ -310,6 +437,37 @@ impl JsFormatOptions {
         self.semicolons = semicolons;
     }
 
+    pub fn set_object_wrap(&mut self, object_wrap: ObjectWrap) {
+        self.object_wrap = object_wrap;
+    }
+
+    pub fn set_operator_position(&mut self, operator_position: OperatorPosition) {
+        self.operator_position = operator_position;
+    }
+
+    pub fn set_format_jsdoc(&mut self, format_jsdoc: FormatJsdoc) {
+        self.format_jsdoc = format_jsdoc;
+    }
+
+    pub fn set_decorator_placement(&mut self, decorator_placement: DecoratorPlacement) {
+        self.decorator_placement = decorator_placement;
+    }
+
+    pub fn set_ternary_style(&mut self, ternary_style: TernaryStyle) {
+        self.ternary_style = ternary_style;
+    }
+
+    pub fn set_member_chain_min_calls(&mut self, member_chain_min_calls: u8) {
+        self.member_chain_min_calls = member_chain_min_calls;
+    }
+
+    pub fn set_inline_short_member_chains(
+        &mut self,
+        inline_short_member_chains: InlineShortMemberChains,
+    ) {
+        self.inline_short_member_chains = inline_short_member_chains;
+    }
+
     pub fn arrow_parentheses(&self) -> ArrowParentheses {
         self.arrow_parentheses
     }
@@ -322,6 +480,10 @@ impl JsFormatOptions {
         self.bracket_same_line
     }
 
+    pub fn single_attribute_per_line(&self) -> SingleAttributePerLine {
+        self.single_attribute_per_line
+    }
+
     pub fn quote_style(&self) -> QuoteStyle {
         self.quote_style
     }
@@ -353,6 +515,38 @@ impl JsFormatOptions {
     pub fn attribute_position(&self) -> AttributePosition {
         self.attribute_position
     }
+
+    pub fn object_wrap(&self) -> ObjectWrap {
+        self.object_wrap
+    }
+
+    pub fn operator_position(&self) -> OperatorPosition {
+        self.operator_position
+    }
+
+    pub fn format_jsdoc(&self) -> FormatJsdoc {
+        self.format_jsdoc
+    }
+
+    pub fn decorator_placement(&self) -> DecoratorPlacement {
+        self.decorator_placement
+    }
+
+    pub fn ternary_style(&self) -> TernaryStyle {
+        self.ternary_style
+    }
+
+    pub fn member_chain_min_calls(&self) -> u8 {
+        self.member_chain_min_calls
+    }
+
+    pub fn inline_short_member_chains(&self) -> InlineShortMemberChains {
+        self.inline_short_member_chains
+    }
+
+    pub fn embedded_code_formatter(&self) -> Option<&dyn FormatEmbeddedCode> {
+        self.embedded_code_formatter.as_deref()
+    }
 }
 
 impl FormatOptions for JsFormatOptions {
@@ -368,6 +562,10 @@ impl FormatOptions for JsFormatOptions {
         self.line_width
     }
 
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        self.max_empty_lines
+    }
+
     fn line_ending(&self) -> LineEnding {
         self.line_ending
     }
@@ -387,6 +585,7 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.get())?;
+        writeln!(f, "Max empty lines: {}", self.max_empty_lines)?;
         writeln!(f, "Quote style: {}", self.quote_style)?;
         writeln!(f, "JSX quote style: {}", self.jsx_quote_style)?;
         writeln!(f, "Quote properties: {}", self.quote_properties)?;
@@ -395,7 +594,23 @@ impl fmt::Display for JsFormatOptions {
         writeln!(f, "Arrow parentheses: {}", self.arrow_parentheses)?;
         writeln!(f, "Bracket spacing: {}", self.bracket_spacing.value())?;
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
-        writeln!(f, "Attribute Position: {}", self.attribute_position)
+        writeln!(
+            f,
+            "Single attribute per line: {}",
+            self.single_attribute_per_line.value()
+        )?;
+        writeln!(f, "Attribute Position: {}", self.attribute_position)?;
+        writeln!(f, "Object wrap: {}", self.object_wrap)?;
+        writeln!(f, "Operator position: {}", self.operator_position)?;
+        writeln!(f, "Format JSDoc: {}", self.format_jsdoc.value())?;
+        writeln!(f, "Decorator placement: {}", self.decorator_placement)?;
+        writeln!(f, "Ternary style: {}", self.ternary_style)?;
+        writeln!(f, "Member chain min calls: {}", self.member_chain_min_calls)?;
+        writeln!(
+            f,
+            "Inline short member chains: {}",
+            self.inline_short_member_chains.value()
+        )
     }
 }
 
@@ -520,6 +735,196 @@ impl fmt::Display for ArrowParentheses {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ObjectWrap {
+    #[default]
+    Preserve,
+    Collapse,
+}
+
+impl ObjectWrap {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_collapse(&self) -> bool {
+        matches!(self, Self::Collapse)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for ObjectWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "collapse" | "Collapse" => Ok(Self::Collapse),
+            _ => Err("Value not supported for Object wrap. Supported values are 'preserve' and 'collapse'."),
+        }
+    }
+}
+
+impl fmt::Display for ObjectWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ObjectWrap::Preserve => write!(f, "Preserve"),
+            ObjectWrap::Collapse => write!(f, "Collapse"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum OperatorPosition {
+    Start,
+    #[default]
+    End,
+}
+
+impl OperatorPosition {
+    pub const fn is_start(&self) -> bool {
+        matches!(self, Self::Start)
+    }
+
+    pub const fn is_end(&self) -> bool {
+        matches!(self, Self::End)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for OperatorPosition {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "start" | "Start" => Ok(Self::Start),
+            "end" | "End" => Ok(Self::End),
+            _ => Err("Value not supported for Operator position. Supported values are 'start' and 'end'."),
+        }
+    }
+}
+
+impl fmt::Display for OperatorPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OperatorPosition::Start => write!(f, "Start"),
+            OperatorPosition::End => write!(f, "End"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum DecoratorPlacement {
+    /// The decorators of the class members stay on the line of the member when they are in the input
+    #[default]
+    Preserve,
+    /// The decorators of the class members are always printed on their own line
+    OwnLine,
+    /// A single decorator of a class property is printed on the line of the property when it fits
+    Inline,
+}
+
+impl DecoratorPlacement {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_own_line(&self) -> bool {
+        matches!(self, Self::OwnLine)
+    }
+
+    pub const fn is_inline(&self) -> bool {
+        matches!(self, Self::Inline)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for DecoratorPlacement {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "own-line" | "ownLine" | "OwnLine" => Ok(Self::OwnLine),
+            "inline" | "Inline" => Ok(Self::Inline),
+            _ => Err("Value not supported for Decorator placement. Supported values are 'preserve', 'own-line' and 'inline'."),
+        }
+    }
+}
+
+impl fmt::Display for DecoratorPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecoratorPlacement::Preserve => write!(f, "Preserve"),
+            DecoratorPlacement::OwnLine => write!(f, "Own line"),
+            DecoratorPlacement::Inline => write!(f, "Inline"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum TernaryStyle {
+    /// The conditionals in the alternate of a conditional are indented
+    #[default]
+    Nested,
+    /// The conditionals in the alternate of a conditional are printed at the same indent as their parent
+    Chain,
+}
+
+impl TernaryStyle {
+    pub const fn is_nested(&self) -> bool {
+        matches!(self, Self::Nested)
+    }
+
+    pub const fn is_chain(&self) -> bool {
+        matches!(self, Self::Chain)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for TernaryStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "nested" | "Nested" => Ok(Self::Nested),
+            "chain" | "Chain" => Ok(Self::Chain),
+            _ => Err(
+                "Value not supported for Ternary style. Supported values are 'nested' and 'chain'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for TernaryStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TernaryStyle::Nested => write!(f, "Nested"),
+            TernaryStyle::Chain => write!(f, "Chain"),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, Merge, PartialEq)]
 #[cfg_attr(
     feature = "serde",
@@ -567,3 +972,66 @@ impl From<bool> for BracketSameLine {
         Self(value)
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct SingleAttributePerLine(bool);
+
+impl SingleAttributePerLine {
+    /// Return the boolean value for this [SingleAttributePerLine]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for SingleAttributePerLine {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct FormatJsdoc(bool);
+
+impl FormatJsdoc {
+    /// Return the boolean value for this [FormatJsdoc]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for FormatJsdoc {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub struct InlineShortMemberChains(bool);
+
+impl InlineShortMemberChains {
+    /// Return the boolean value for this [InlineShortMemberChains]
+    pub fn value(&self) -> bool {
+        self.0
+    }
+}
+
+impl From<bool> for InlineShortMemberChains {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
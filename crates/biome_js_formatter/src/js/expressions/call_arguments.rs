@@ -9,7 +9,7 @@ use crate::prelude::*;
 use crate::utils::function_body::FunctionBodyCacheMode;
 use crate::utils::member_chain::SimpleArgument;
 use crate::utils::{is_long_curried_call, write_arguments_multi_line};
-use biome_formatter::{format_args, format_element, write, VecBuffer};
+use biome_formatter::{format_args, format_element, write, FormatOptions, VecBuffer};
 use biome_js_syntax::{
     AnyJsCallArgument, AnyJsExpression, AnyJsFunctionBody, AnyJsLiteralExpression, AnyJsStatement,
     AnyTsReturnType, AnyTsType, JsBinaryExpressionFields, JsCallArgumentList, JsCallArguments,
@@ -714,6 +714,7 @@ struct FormatAllArgsBrokenOut<'a> {
 impl<'a> Format<JsFormatContext> for FormatAllArgsBrokenOut<'a> {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
         let is_inside_import = self.node.parent::<JsImportCallExpression>().is_some();
+        let max_empty_lines = f.options().max_empty_lines();
 
         write!(
             f,
@@ -722,9 +723,9 @@ impl<'a> Format<JsFormatContext> for FormatAllArgsBrokenOut<'a> {
                 soft_block_indent(&format_with(|f| {
                     for (index, entry) in self.args.iter().enumerate() {
                         if index > 0 {
-                            match entry.leading_lines() {
-                                0 | 1 => write!(f, [soft_line_break_or_space()])?,
-                                _ => write!(f, [empty_line()])?,
+                            match max_empty_lines.empty_lines_before(entry.leading_lines()) {
+                                0 => write!(f, [soft_line_break_or_space()])?,
+                                count => write!(f, [empty_lines(count)])?,
                             }
                         }
 
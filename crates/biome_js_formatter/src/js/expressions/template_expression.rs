@@ -4,9 +4,13 @@ use biome_formatter::write;
 use crate::js::expressions::static_member_expression::member_chain_callee_needs_parens;
 use crate::js::lists::template_element_list::FormatJsTemplateElementListOptions;
 use crate::parentheses::NeedsParentheses;
-use biome_js_syntax::{AnyJsExpression, JsSyntaxNode, JsTemplateExpression, TsTemplateLiteralType};
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsTemplateElement, JsSyntaxNode, JsTemplateChunkElement, JsTemplateElement,
+    JsTemplateExpression, TsTemplateLiteralType,
+};
 use biome_js_syntax::{JsSyntaxToken, TsTypeArguments};
-use biome_rowan::{declare_node_union, SyntaxResult};
+use biome_rowan::{declare_node_union, SyntaxResult, TextSize};
+use std::ops::Range;
 
 #[derive(Debug, Clone, Default)]
 pub(crate) struct FormatJsTemplateExpression;
@@ -37,7 +41,10 @@ impl Format<JsFormatContext> for AnyJsTemplate {
             ]
         )?;
 
-        self.write_elements(f)?;
+        match self.embedded_code(f) {
+            Some(embedded_code) => write!(f, [embedded_code])?,
+            None => self.write_elements(f)?,
+        }
 
         write!(f, [self.r_tick_token().format()])
     }
@@ -81,6 +88,58 @@ impl AnyJsTemplate {
         }
     }
 
+    /// Returns the content of a tagged template whose content is another language, such as the CSS
+    /// of `` styled.div`color: red;` ``, formatted with the embedded code formatter of the options.
+    ///
+    /// The substitutions are replaced by placeholders, the content isn't formatted when
+    /// the formatted code doesn't keep each placeholder once.
+    fn embedded_code(&self, f: &JsFormatter) -> Option<FormatEmbeddedTemplate> {
+        let AnyJsTemplate::JsTemplateExpression(template) = self else {
+            return None;
+        };
+        let tag = template.tag()?;
+        let formatter = f.options().embedded_code_formatter()?;
+
+        let mut code = String::new();
+        let mut chunks = Vec::new();
+        let mut substitutions = Vec::new();
+        for element in template.elements() {
+            match element {
+                AnyJsTemplateElement::JsTemplateChunkElement(element) => {
+                    let chunk = element.template_chunk_token().ok()?;
+                    if chunk.text_trimmed().contains(PLACEHOLDER_PREFIX) {
+                        return None;
+                    }
+                    code.push_str(chunk.text_trimmed());
+                    chunks.push(element);
+                }
+                AnyJsTemplateElement::JsTemplateElement(element) => {
+                    code.push_str(PLACEHOLDER_PREFIX);
+                    code.push_str(&substitutions.len().to_string());
+                    substitutions.push(element);
+                }
+            }
+        }
+
+        let formatted =
+            formatter.format_embedded_code(&tag.syntax().text_trimmed().to_string(), &code)?;
+        let mut indices: Vec<usize> = placeholders(&formatted)
+            .into_iter()
+            .map(|(_, index)| index)
+            .collect();
+        indices.sort_unstable();
+        if !indices.iter().copied().eq(0..substitutions.len()) {
+            return None;
+        }
+
+        Some(FormatEmbeddedTemplate {
+            formatted,
+            chunks,
+            substitutions,
+            position: template.l_tick_token().ok()?.text_trimmed_range().end(),
+        })
+    }
+
     fn r_tick_token(&self) -> SyntaxResult<JsSyntaxToken> {
         match self {
             AnyJsTemplate::JsTemplateExpression(template) => template.r_tick_token(),
@@ -89,6 +148,90 @@ impl AnyJsTemplate {
     }
 }
 
+/// The text that takes the place of a substitution, such as `${color}`, in the code of an embedded language,
+/// followed by the index of the substitution. It's a valid identifier in CSS and a valid text in HTML.
+const PLACEHOLDER_PREFIX: &str = "biome-placeholder-";
+
+/// Returns the range and the index of the substitution of each placeholder of `text`
+fn placeholders(text: &str) -> Vec<(Range<usize>, usize)> {
+    text.match_indices(PLACEHOLDER_PREFIX)
+        .filter_map(|(start, _)| {
+            let digits_start = start + PLACEHOLDER_PREFIX.len();
+            let digits_length = text[digits_start..]
+                .find(|char: char| !char.is_ascii_digit())
+                .unwrap_or(text.len() - digits_start);
+            let end = digits_start + digits_length;
+            let index = text[digits_start..end].parse().ok()?;
+            Some((start..end, index))
+        })
+        .collect()
+}
+
+/// The formatted content of a tagged template whose content is another language.
+///
+/// The content starts on the line that follows the opening backtick, indented one level deeper than the template,
+/// and the closing backtick is on its own line. The substitutions are formatted at the place of their placeholder.
+struct FormatEmbeddedTemplate {
+    formatted: String,
+    chunks: Vec<JsTemplateChunkElement>,
+    substitutions: Vec<JsTemplateElement>,
+    /// The position of the start of the content in the source
+    position: TextSize,
+}
+
+impl Format<JsFormatContext> for FormatEmbeddedTemplate {
+    fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
+        for chunk in &self.chunks {
+            // The chunks are replaced by the formatted code, they can't be suppressed on their own
+            f.comments().mark_suppression_checked(chunk.syntax());
+            if let Ok(token) = chunk.template_chunk_token() {
+                write!(f, [format_removed(&token)])?;
+            }
+        }
+        if self.formatted.trim().is_empty() {
+            return Ok(());
+        }
+
+        let content = format_with(|f| {
+            let mut is_first_line = true;
+            let mut after_blank_line = false;
+            for line in self.formatted.lines() {
+                if line.trim().is_empty() {
+                    after_blank_line = !is_first_line;
+                    continue;
+                }
+                if after_blank_line {
+                    write!(f, [empty_line()])?;
+                } else if !is_first_line {
+                    write!(f, [hard_line_break()])?;
+                }
+                is_first_line = false;
+                after_blank_line = false;
+
+                let mut text_start = 0;
+                for (range, index) in placeholders(line) {
+                    if range.start > text_start {
+                        write!(
+                            f,
+                            [dynamic_text(&line[text_start..range.start], self.position)]
+                        )?;
+                    }
+                    if let Some(substitution) = self.substitutions.get(index) {
+                        write!(f, [substitution.format()])?;
+                    }
+                    text_start = range.end;
+                }
+                if text_start < line.len() {
+                    write!(f, [dynamic_text(&line[text_start..], self.position)])?;
+                }
+            }
+            Ok(())
+        });
+
+        write!(f, [block_indent(&content)])
+    }
+}
+
 /// `TemplateLiteral`'s are `PrimaryExpression's that never need parentheses.
 impl NeedsParentheses for JsTemplateExpression {
     fn needs_parentheses_with_parent(&self, parent: &JsSyntaxNode) -> bool {
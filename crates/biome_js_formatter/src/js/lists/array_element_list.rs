@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use biome_formatter::{write, CstFormatContext, FormatRuleWithOptions, GroupId};
+use biome_formatter::{write, CstFormatContext, FormatOptions, FormatRuleWithOptions, GroupId};
 
 use crate::utils::array::write_array_node;
 
@@ -35,6 +35,7 @@ impl FormatRule<JsArrayElementList> for FormatJsArrayElementList {
             ArrayLayout::Fill => {
                 let trailing_separator = FormatTrailingCommas::ES5.trailing_separator(f.options());
 
+                let max_empty_lines = f.options().max_empty_lines();
                 let mut filler = f.fill();
 
                 // Using format_separated is valid in this case as can_print_fill does not allow holes
@@ -46,8 +47,10 @@ impl FormatRule<JsArrayElementList> for FormatJsArrayElementList {
                     filler.entry(
                         &format_once(|f| {
                             let element = element?;
-                            if get_lines_before(element.syntax()) > 1 {
-                                write!(f, [empty_line()])
+                            let lines_before = get_lines_before(element.syntax());
+                            let count = max_empty_lines.empty_lines_before(lines_before);
+                            if count > 0 {
+                                write!(f, [empty_lines(count)])
                             } else if f.comments().has_leading_own_line_comment(element.syntax()) {
                                 write!(f, [hard_line_break()])
                             } else {
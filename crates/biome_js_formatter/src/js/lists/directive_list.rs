@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use biome_formatter::write;
+use biome_formatter::{write, FormatOptions};
 use biome_js_syntax::JsDirectiveList;
 use biome_rowan::{AstNode, AstNodeList};
 
@@ -25,12 +25,10 @@ impl FormatRule<JsDirectiveList> for FormatJsDirectiveList {
 
         // }
         //```
-        // so we should keep an extra empty line after JsDirectiveList
-        let need_extra_empty_line = if let Some(next_sibling) = next_sibling {
-            get_lines_before(&next_sibling) > 1
-        } else {
-            false
-        };
+        // so we should keep the empty lines after JsDirectiveList, as many as `maxEmptyLines` allows
+        let lines_before = next_sibling.map_or(0, |next_sibling| get_lines_before(&next_sibling));
+        let max_empty_lines = f.options().max_empty_lines();
+        let empty_lines_after = max_empty_lines.empty_lines_before(lines_before);
 
         let mut join = f.join_nodes_with_hardline();
 
@@ -40,10 +38,6 @@ impl FormatRule<JsDirectiveList> for FormatJsDirectiveList {
 
         join.finish()?;
 
-        if need_extra_empty_line {
-            write!(f, [empty_line()])
-        } else {
-            write!(f, [hard_line_break()])
-        }
+        write!(f, [empty_lines(empty_lines_after)])
     }
 }
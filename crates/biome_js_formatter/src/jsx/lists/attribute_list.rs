@@ -10,7 +10,9 @@ impl FormatRule<JsxAttributeList> for FormatJsxAttributeList {
     type Context = JsFormatContext;
 
     fn fmt(&self, node: &JsxAttributeList, f: &mut JsFormatter) -> FormatResult<()> {
-        let line_break = if f.options().attribute_position() == AttributePosition::Multiline {
+        let line_break = if f.options().attribute_position() == AttributePosition::Multiline
+            || f.options().single_attribute_per_line().value()
+        {
             hard_line_break()
         } else {
             soft_line_break_or_space()
@@ -558,9 +558,9 @@ impl Label for JsLabels {
 #[cfg(test)]
 mod tests {
 
-    use super::format_range;
+    use super::{format_node, format_range};
 
-    use crate::context::JsFormatOptions;
+    use crate::context::{FormatEmbeddedCode, JsFormatOptions};
     use biome_formatter::IndentStyle;
     use biome_js_parser::{parse, parse_script, JsParserOptions};
     use biome_js_syntax::JsFileSource;
@@ -754,4 +754,41 @@ function() {
 
         assert!(result.is_err());
     }
+
+    /// Prints each declaration of the `styled.*` templates on its own line
+    #[derive(Debug)]
+    struct SplitDeclarations;
+
+    impl FormatEmbeddedCode for SplitDeclarations {
+        fn format_embedded_code(&self, tag: &str, code: &str) -> Option<String> {
+            if !tag.starts_with("styled.") {
+                return None;
+            }
+            let declarations: Vec<_> = code
+                .split(';')
+                .map(|declaration| declaration.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|declaration| !declaration.is_empty())
+                .map(|declaration| format!("{declaration};\n"))
+                .collect();
+            Some(declarations.concat())
+        }
+    }
+
+    #[test]
+    fn format_embedded_templates() {
+        let src = "const Button = styled.div`color:   red; background: ${ color };`;\nconst text = foo`  keep   this `;\n";
+
+        let syntax = JsFileSource::js_module();
+        let tree = parse(src, syntax, JsParserOptions::default());
+        let options = JsFormatOptions::new(syntax).with_embedded_code_formatter(SplitDeclarations);
+        let result = format_node(options, &tree.syntax())
+            .unwrap()
+            .print()
+            .unwrap();
+
+        assert_eq!(
+            result.as_code(),
+            "const Button = styled.div`\n\tcolor: red;\n\tbackground: ${color};\n`;\nconst text = foo`  keep   this `;\n"
+        );
+    }
 }
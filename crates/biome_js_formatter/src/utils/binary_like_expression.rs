@@ -314,12 +314,18 @@ impl Format<JsFormatContext> for BinaryLeftOrRightSide {
                 let operator_and_right_expression = format_with(|f| {
                     let should_inline = binary_like_expression.should_inline_logical_expression();
 
-                    write!(f, [space(), operator_token.format()])?;
-
                     if should_inline {
-                        write!(f, [space()])?;
+                        write!(f, [space(), operator_token.format(), space()])?;
+                    } else if f.options().operator_position().is_start() {
+                        write!(
+                            f,
+                            [soft_line_break_or_space(), operator_token.format(), space()]
+                        )?;
                     } else {
-                        write!(f, [soft_line_break_or_space()])?;
+                        write!(
+                            f,
+                            [space(), operator_token.format(), soft_line_break_or_space()]
+                        )?;
                     }
 
                     write!(f, [right.format()])?;
@@ -109,8 +109,21 @@ impl FormatRule<AnyJsConditional> for FormatJsAnyConditionalRule {
                     space()
                 ]
             )?;
+
+            // The conditionals of a chain are printed at the same indent:
+            // ```js
+            // const animal = isBird
+            //     ? "bird"
+            //     : isCat
+            //     ? "cat"
+            //     : "dog";
+            // ```
+            let is_alternate_chained = f.options().ternary_style().is_chain()
+                && alternate.syntax().kind() == syntax.kind();
             let alternate = format_with(|f| {
-                if indent_style.is_space() {
+                if is_alternate_chained {
+                    write!(f, [alternate])
+                } else if indent_style.is_space() {
                     write!(f, [align(2, &alternate)])
                 } else {
                     write!(f, [indent(&alternate)])
@@ -1,8 +1,11 @@
+use crate::context::DecoratorPlacement;
 use crate::prelude::*;
 use crate::utils::sort_modifiers_by_precedence;
 use crate::{AsFormat, IntoFormat};
 use biome_formatter::{format_args, write};
-use biome_js_syntax::JsSyntaxKind::JS_DECORATOR;
+use biome_js_syntax::JsSyntaxKind::{
+    JS_DECORATOR, JS_PROPERTY_MODIFIER_LIST, TS_PROPERTY_SIGNATURE_MODIFIER_LIST,
+};
 use biome_js_syntax::{JsLanguage, Modifier};
 use biome_rowan::{AstNode, AstNodeList, NodeOrToken};
 
@@ -24,7 +27,16 @@ where
 {
     fn fmt(&self, f: &mut Formatter<JsFormatContext>) -> FormatResult<()> {
         let modifiers = sort_modifiers_by_precedence(&self.list);
-        let should_expand = should_expand_decorators(&self.list);
+        let should_expand = match f.options().decorator_placement() {
+            DecoratorPlacement::Preserve => should_expand_decorators(&self.list),
+            DecoratorPlacement::OwnLine => self
+                .list
+                .iter()
+                .any(|node| node.syntax().kind() == JS_DECORATOR),
+            DecoratorPlacement::Inline => {
+                !is_single_property_decorator(&self.list) && should_expand_decorators(&self.list)
+            }
+        };
 
         // Returning early here is important, because otherwise this node
         // returns a group that always has a soft line break, which causes
@@ -90,6 +102,25 @@ where
     }
 }
 
+/// Returns `true` if the list is the modifiers of a class property, and has a single decorator, such as `@Input() name: string;`
+fn is_single_property_decorator<List, Node>(list: &List) -> bool
+where
+    Node: AstNode<Language = JsLanguage>,
+    List: AstNodeList<Language = JsLanguage, Node = Node>,
+{
+    let is_property = matches!(
+        list.syntax_list().node().kind(),
+        JS_PROPERTY_MODIFIER_LIST | TS_PROPERTY_SIGNATURE_MODIFIER_LIST
+    );
+
+    is_property
+        && list
+            .iter()
+            .filter(|node| node.syntax().kind() == JS_DECORATOR)
+            .count()
+            == 1
+}
+
 /// This function expands decorators enclosing a group if there is a newline between decorators or after the last decorator.
 pub(crate) fn should_expand_decorators<List, Node>(list: &List) -> bool
 where
@@ -0,0 +1,386 @@
+use crate::prelude::*;
+use biome_formatter::comments::{is_doc_comment, SourceComment};
+use biome_formatter::write;
+use biome_js_syntax::JsLanguage;
+use biome_text_size::TextSize;
+
+/// The tags whose type is followed by the name of a parameter or a property,
+/// such as `@param {string} name`
+const TAGS_WITH_NAME: [&str; 6] = [
+    "@param",
+    "@arg",
+    "@argument",
+    "@property",
+    "@prop",
+    "@template",
+];
+
+/// Returns `true` if the comment is a JSDoc comment that can be normalized by [FormatJsdocComment].
+///
+/// The comment starts with `/**`, spans multiple lines, and every one of its other lines starts with `*`.
+/// The comments on a single line, such as the type casts `/** @type {string} */ (value)`, and the
+/// comments that start with `/***`, which are often banners, are left untouched.
+pub(crate) fn is_jsdoc_comment(comment: &SourceComment<JsLanguage>) -> bool {
+    let piece = comment.piece();
+    let text = piece.text();
+    let mut other_lines = text.lines().skip(1).peekable();
+
+    is_doc_comment(piece)
+        && !text.starts_with("/***")
+        && other_lines.peek().is_some()
+        && other_lines.all(|line| line.trim_start().starts_with('*'))
+}
+
+/// Formats a JSDoc comment:
+/// - the asterisks of all the lines are aligned;
+/// - the paragraphs of the description are reflowed to the line width;
+/// - the tags are printed as `@tag {type} name description`, without the empty lines between them,
+///   and with an empty line between the description and the first tag.
+///
+/// The code blocks, the `@example` tags, the lists, and the indented lines that don't continue
+/// a paragraph are kept as they are.
+///
+/// ```js
+/// /**
+///  *   Adds two   numbers.
+///  * @param { number } a The first number
+///  *
+///  * @return {number}
+///  */
+/// ```
+///
+/// becomes
+///
+/// ```js
+/// /**
+///  * Adds two numbers.
+///  *
+///  * @param {number} a The first number
+///  * @returns {number}
+///  */
+/// ```
+pub(crate) struct FormatJsdocComment<'a> {
+    comment: &'a SourceComment<JsLanguage>,
+}
+
+impl<'a> FormatJsdocComment<'a> {
+    pub(crate) fn new(comment: &'a SourceComment<JsLanguage>) -> Self {
+        Self { comment }
+    }
+}
+
+impl Format<JsFormatContext> for FormatJsdocComment<'_> {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        let piece = self.comment.piece();
+        let source_position = piece.text_range().start();
+        let blocks = parse_jsdoc(piece.text());
+
+        write!(f, [text("/**")])?;
+
+        for block in &blocks {
+            write!(f, [hard_line_break()])?;
+
+            match block {
+                JsdocBlock::Empty | JsdocBlock::Verbatim("") => write!(f, [text(" *")])?,
+                JsdocBlock::Verbatim(line) => {
+                    write!(f, [text(" * "), dynamic_text(line, source_position)])?
+                }
+                JsdocBlock::Paragraph(words) => write!(
+                    f,
+                    [
+                        text(" * "),
+                        FormatJsdocWords {
+                            words,
+                            line_start: " * ",
+                            source_position
+                        }
+                    ]
+                )?,
+                JsdocBlock::Tag { head, words } => {
+                    write!(f, [text(" * "), dynamic_text(head, source_position)])?;
+
+                    if !words.is_empty() {
+                        write!(
+                            f,
+                            [
+                                space(),
+                                FormatJsdocWords {
+                                    words,
+                                    line_start: " *   ",
+                                    source_position
+                                }
+                            ]
+                        )?;
+                    }
+                }
+            }
+        }
+
+        write!(f, [hard_line_break(), text(" */")])
+    }
+}
+
+/// Fills the words of a paragraph or of the description of a tag,
+/// every new line starts with `line_start` to keep the asterisks aligned.
+struct FormatJsdocWords<'a> {
+    words: &'a [&'a str],
+    line_start: &'static str,
+    source_position: TextSize,
+}
+
+impl Format<JsFormatContext> for FormatJsdocWords<'_> {
+    fn fmt(&self, f: &mut JsFormatter) -> FormatResult<()> {
+        let separator = format_with(|f| {
+            write!(
+                f,
+                [
+                    soft_line_break_or_space(),
+                    if_group_breaks(&text(self.line_start))
+                ]
+            )
+        });
+
+        let mut fill = f.fill();
+        for word in self.words {
+            fill.entry(&separator, &dynamic_text(word, self.source_position));
+        }
+        fill.finish()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum JsdocBlock<'a> {
+    /// An empty line between two paragraphs
+    Empty,
+    /// A line that is printed as it is, such as a line of a code block or an item of a list
+    Verbatim(&'a str),
+    /// The words of a paragraph of the description
+    Paragraph(Vec<&'a str>),
+    /// A tag, `head` is its name, its type and the name of its parameter, followed by the words of its description
+    Tag { head: String, words: Vec<&'a str> },
+}
+
+/// Splits the text of a JSDoc comment into the blocks that are printed on their own lines
+fn parse_jsdoc(comment: &str) -> Vec<JsdocBlock> {
+    let content = comment
+        .strip_prefix("/**")
+        .and_then(|content| content.strip_suffix("*/"))
+        .unwrap_or(comment);
+
+    let mut blocks = Vec::new();
+    let mut in_code_block = false;
+    let mut in_example = false;
+    let mut has_tag = false;
+
+    for line in content.lines() {
+        let line = line.trim_start();
+        let line = line.strip_prefix('*').unwrap_or(line);
+        let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            blocks.push(JsdocBlock::Verbatim(line));
+            continue;
+        }
+
+        if in_code_block {
+            blocks.push(JsdocBlock::Verbatim(line));
+            continue;
+        }
+
+        if line.starts_with('@') {
+            // Remove the empty lines between the previous block and the tag
+            while matches!(
+                blocks.last(),
+                Some(JsdocBlock::Empty | JsdocBlock::Verbatim(""))
+            ) {
+                blocks.pop();
+            }
+
+            if !has_tag && !blocks.is_empty() {
+                blocks.push(JsdocBlock::Empty);
+            }
+
+            let (head, description) = parse_tag(line);
+            in_example = head.starts_with("@example");
+            has_tag = true;
+
+            blocks.push(JsdocBlock::Tag {
+                head,
+                words: description.split_whitespace().collect(),
+            });
+            continue;
+        }
+
+        if in_example {
+            blocks.push(JsdocBlock::Verbatim(line));
+            continue;
+        }
+
+        if line.is_empty() {
+            if !matches!(blocks.last(), None | Some(JsdocBlock::Empty)) {
+                blocks.push(JsdocBlock::Empty);
+            }
+            continue;
+        }
+
+        // The lines indented by four spaces are code, the other indented lines
+        // continue the paragraph or the list item that precedes them
+        let trimmed = line.trim_start();
+        let is_code = line.starts_with('\t') || line.len() - trimmed.len() >= 4;
+        let after_verbatim = matches!(blocks.last(), Some(JsdocBlock::Verbatim(_)));
+
+        match blocks.last_mut() {
+            // The lines that follow a tag continue its description
+            Some(JsdocBlock::Tag { words, .. }) => words.extend(trimmed.split_whitespace()),
+            Some(JsdocBlock::Paragraph(words)) if !is_markdown_block_start(trimmed) => {
+                words.extend(trimmed.split_whitespace())
+            }
+            _ if is_code
+                || is_markdown_block_start(trimmed)
+                || (after_verbatim && line != trimmed) =>
+            {
+                blocks.push(JsdocBlock::Verbatim(line))
+            }
+            _ => blocks.push(JsdocBlock::Paragraph(trimmed.split_whitespace().collect())),
+        }
+    }
+
+    while matches!(
+        blocks.last(),
+        Some(JsdocBlock::Empty | JsdocBlock::Verbatim(""))
+    ) {
+        blocks.pop();
+    }
+
+    blocks
+}
+
+/// Returns the head of a tag, its name, its type and the name of its parameter, and the rest of its line.
+///
+/// `@return` is renamed to `@returns`, and the spaces inside the braces of the type are removed.
+fn parse_tag(line: &str) -> (String, &str) {
+    let name_end = line
+        .find(|char: char| char.is_whitespace() || char == '{')
+        .unwrap_or(line.len());
+    let (name, rest) = line.split_at(name_end);
+    let name = if name == "@return" { "@returns" } else { name };
+    let mut head = String::from(name);
+    let mut rest = rest.trim_start();
+
+    if rest.starts_with('{') {
+        match find_closing(rest, '{', '}') {
+            Some(end) => {
+                head.push_str(" {");
+                head.push_str(rest[1..end].trim());
+                head.push('}');
+                rest = rest[end + 1..].trim_start();
+            }
+            // The type continues on the next lines
+            None => return (head, rest),
+        }
+    }
+
+    if TAGS_WITH_NAME.contains(&name) && !rest.is_empty() {
+        let name_end = if rest.starts_with('[') {
+            find_closing(rest, '[', ']').map(|end| end + 1)
+        } else {
+            None
+        }
+        .or_else(|| rest.find(char::is_whitespace))
+        .unwrap_or(rest.len());
+
+        head.push(' ');
+        head.push_str(&rest[..name_end]);
+        rest = rest[name_end..].trim_start();
+    }
+
+    (head, rest)
+}
+
+/// Returns the offset of the bracket that closes the one at the start of `text`
+fn find_closing(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0usize;
+
+    for (index, char) in text.char_indices() {
+        if char == open {
+            depth += 1;
+        } else if char == close {
+            depth -= 1;
+
+            if depth == 0 {
+                return Some(index);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns `true` for the lines that start a Markdown block that can't be reflowed:
+/// the items of the lists, the rows of the tables, the headings and the quotes.
+fn is_markdown_block_start(line: &str) -> bool {
+    if line.starts_with(['|', '#', '>'])
+        || line.starts_with("- ")
+        || line.starts_with("* ")
+        || line.starts_with("+ ")
+    {
+        return true;
+    }
+
+    let digits = line.len()
+        - line
+            .trim_start_matches(|char: char| char.is_ascii_digit())
+            .len();
+    digits > 0 && (line[digits..].starts_with(". ") || line[digits..].starts_with(") "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_jsdoc, parse_tag, JsdocBlock};
+
+    #[test]
+    fn parses_tags() {
+        assert_eq!(
+            parse_tag("@param { string } name The name"),
+            (String::from("@param {string} name"), "The name")
+        );
+        assert_eq!(
+            parse_tag("@param {number} [count=1] The count"),
+            (String::from("@param {number} [count=1]"), "The count")
+        );
+        assert_eq!(
+            parse_tag("@return {Promise<{ a: string }>}"),
+            (String::from("@returns {Promise<{ a: string }>}"), "")
+        );
+        assert_eq!(
+            parse_tag("@deprecated Use `other` instead"),
+            (String::from("@deprecated"), "Use `other` instead")
+        );
+    }
+
+    #[test]
+    fn parses_blocks() {
+        let blocks = parse_jsdoc(
+            "/**\n   * Adds two\n   *   numbers.\n   *\n   *\n   * - first\n   * @param a The first\n   *   number\n   *\n   * @return {number}\n   */",
+        );
+
+        assert_eq!(
+            blocks,
+            vec![
+                JsdocBlock::Paragraph(vec!["Adds", "two", "numbers."]),
+                JsdocBlock::Empty,
+                JsdocBlock::Verbatim("- first"),
+                JsdocBlock::Empty,
+                JsdocBlock::Tag {
+                    head: String::from("@param a"),
+                    words: vec!["The", "first", "number"]
+                },
+                JsdocBlock::Tag {
+                    head: String::from("@returns {number}"),
+                    words: vec![]
+                },
+            ]
+        );
+    }
+}
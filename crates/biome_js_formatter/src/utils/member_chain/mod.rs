@@ -282,7 +282,8 @@ impl MemberChain {
             any_complex_args = any_complex_args || !has_simple_arguments(call);
         }
 
-        if calls_count > 2 && any_complex_args {
+        if calls_count > 2 && any_complex_args && !f.options().inline_short_member_chains().value()
+        {
             return Ok(true);
         }
 
@@ -332,6 +333,16 @@ impl MemberChain {
         self.head.members().iter().chain(self.tail.members())
     }
 
+    /// Returns `true` if the chain has fewer calls than the minimum number of calls of the broken chains
+    fn has_fewer_calls_than_minimum(&self, f: &JsFormatter) -> bool {
+        let calls_count = self
+            .members()
+            .filter(|member| matches!(member, ChainMember::CallExpression { .. }))
+            .count();
+
+        calls_count < usize::from(f.options().member_chain_min_calls())
+    }
+
     fn has_comments(&self, comments: &JsComments) -> bool {
         let mut members = self.members();
 
@@ -369,7 +380,7 @@ impl Format<JsFormatContext> for MemberChain {
             joiner.finish()
         });
 
-        if self.tail.len() <= 1 && !has_comments {
+        if (self.tail.len() <= 1 || self.has_fewer_calls_than_minimum(f)) && !has_comments {
             return if is_long_curried_call(Some(&self.root)) {
                 write!(f, [format_one_line])
             } else if self.root.is_test_call_expression()? && self.head.members().len() >= 2 {
@@ -8,6 +8,7 @@ pub(crate) mod format_class;
 pub(crate) mod format_modifiers;
 pub(crate) mod format_node_without_comments;
 pub(crate) mod function_body;
+pub(crate) mod jsdoc;
 pub mod jsx;
 pub(crate) mod member_chain;
 mod object;
@@ -29,7 +30,7 @@ pub(crate) use assignment_like::{
 pub(crate) use binary_like_expression::{
     needs_binary_like_parentheses, AnyJsBinaryLikeExpression, AnyJsBinaryLikeLeftExpression,
 };
-use biome_formatter::{format_args, write, Buffer};
+use biome_formatter::{format_args, write, Buffer, FormatOptions};
 use biome_js_syntax::JsSyntaxToken;
 use biome_js_syntax::{
     AnyJsExpression, AnyJsStatement, JsCallExpression, JsInitializerClause, JsLanguage, Modifier,
@@ -145,13 +146,13 @@ impl Format<JsFormatContext> for FormatInterpreterToken<'_> {
                 )]
             )?;
 
-            match interpreter
+            let lines_before = interpreter
                 .next_token()
-                .map_or(0, |next_token| get_lines_before_token(&next_token))
-            {
-                0 | 1 => write!(f, [hard_line_break()]),
-                _ => write!(f, [empty_line()]),
-            }
+                .map_or(0, |next_token| get_lines_before_token(&next_token));
+            let max_empty_lines = f.options().max_empty_lines();
+            let count = max_empty_lines.empty_lines_before(lines_before);
+
+            write!(f, [empty_lines(count)])
         } else {
             Ok(())
         }
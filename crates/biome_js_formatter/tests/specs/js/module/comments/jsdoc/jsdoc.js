@@ -0,0 +1,49 @@
+/**
+ *   Adds two   numbers
+ * and returns
+ *    the result.
+ * @param { number } a The first number
+ *
+ * @param {number} [b=0] The second number, which is added to the first one if the function is called with it
+ * @return {number}
+ */
+function add(a, b = 0) {
+	return a + b;
+}
+
+class Store {
+	/**
+	* Reads a value.
+	*
+	* ```js
+	* store.get("key");
+	* ```
+	*
+	*
+	* - returns `undefined` when the key is missing
+	* @example
+	*   store.get("key")
+	*/
+	get(key) {}
+}
+
+/***
+ * Not a JSDoc comment
+ */
+const answer = 42;
+
+/** @type {string} */
+let name = "biome";
+
+/**  @type {number}  */
+let count = 0;
+
+const store = foo(/** @type {Store} */ (value), /** @type {number} */ (count));
+
+/**
+ Not every line starts with an asterisk
+   @param a
+ */
+function identity(a) {
+	return a;
+}
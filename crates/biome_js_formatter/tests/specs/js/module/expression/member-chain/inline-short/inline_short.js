@@ -0,0 +1,3 @@
+items.filter((item) => item.active).map((item) => item.id).join(", ");
+
+promise.then((result) => console.log(result)).catch((error) => handleErrorProperly(error)).finally(cleanup);
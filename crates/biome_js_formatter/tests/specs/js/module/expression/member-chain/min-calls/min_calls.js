@@ -0,0 +1,3 @@
+items.filter((item) => item.active).map((item) => item.id).join(", ");
+
+items.filter((item) => item.active).map((item) => item.id).sort().join(", ");
@@ -0,0 +1,7 @@
+const isValid = firstCondition && secondCondition && thirdCondition && fourthCondition && fifthCondition;
+
+const total = firstAmount + secondAmount + thirdAmount + fourthAmount + fifthAmount + sixthAmount;
+
+if (firstCondition && secondCondition && thirdCondition && fourthCondition && fifth) {
+	run();
+}
@@ -0,0 +1,5 @@
+const message = i % 3 === 0 && i % 5 === 0 ? "fizzbuzz" : i % 3 === 0 ? "fizz" : i % 5 === 0 ? "buzz" : String(i);
+
+function animal() {
+	return isBirdOfTheSpecies ? "bird of the species" : isCatOfTheBreed ? "cat of the breed" : "dog";
+}
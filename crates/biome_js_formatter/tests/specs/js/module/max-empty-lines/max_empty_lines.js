@@ -0,0 +1,18 @@
+import a from "a";
+
+
+
+import b from "b";
+
+
+const first = 1;
+const second = 2;
+
+
+
+function foo() {
+	const a = 1;
+
+
+	return a;
+}
@@ -0,0 +1,11 @@
+const a = {
+	b: 1, c: 2 };
+
+const type = { d: 1,
+	e: 2 };
+
+function f() {
+	return {
+		g: 1,
+	};
+}
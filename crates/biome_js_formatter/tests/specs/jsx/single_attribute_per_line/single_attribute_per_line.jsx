@@ -0,0 +1,7 @@
+const Component = () => (
+	<div>
+		<div data-a="1">Hello</div>
+		<div data-a="1" data-b="2">Hello</div>
+		<img src="/images/foo.png" alt="bar" />
+	</div>
+);
@@ -0,0 +1,10 @@
+class Component {
+	@Input() name: string;
+	@Output() change = new EventEmitter<string>();
+	@HostListener("click") onClick() {}
+	@Input()
+	label: string;
+	@Input() @Required() id: string;
+	@Input() aVeryLongPropertyNameThatIsReallyHardToFitOnOneLine: SomeLongTypeName;
+	static plain = 1;
+}
@@ -0,0 +1,802 @@
+//! Validation of JSON documents against a [JSON Schema](https://json-schema.org/).
+//!
+//! The validator supports the structural keywords of the schemas: `type`, `enum`, `const`,
+//! `properties`, `patternProperties`, `additionalProperties`, `required`, `items`, `prefixItems`,
+//! `additionalItems`, the bounds of the numbers, strings, arrays and objects, `uniqueItems`,
+//! `pattern`, the combinators `allOf`, `anyOf`, `oneOf`, `not`, `if`/`then`/`else`,
+//! and the references to the same schema with `$ref`.
+//! The other keywords, such as `format`, and the references to other schemas are ignored.
+
+use biome_json_syntax::{
+    AnyJsonValue, JsonArrayValue, JsonMemberName, JsonNumberValue, JsonObjectValue, JsonRoot,
+    JsonStringValue, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+use regex::Regex;
+use std::fmt::{self, Display, Formatter, Write};
+
+/// The maximum number of nested schemas applied to a value, which stops the cycles of `$ref`.
+const MAX_DEPTH: u32 = 128;
+
+/// A JSON value detached from the syntax tree.
+#[derive(Clone, Debug)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Converts a value of the syntax tree, or returns `None` if the value contains bogus nodes.
+    pub fn from_json(value: &AnyJsonValue) -> Option<Self> {
+        Some(match value {
+            AnyJsonValue::JsonNullValue(_) => Self::Null,
+            AnyJsonValue::JsonBooleanValue(value) => {
+                Self::Bool(value.value_token().ok()?.text_trimmed() == "true")
+            }
+            AnyJsonValue::JsonNumberValue(value) => Self::Number(number_value(value)?),
+            AnyJsonValue::JsonStringValue(value) => Self::String(string_value(value)?),
+            AnyJsonValue::JsonArrayValue(array) => Self::Array(
+                array
+                    .elements()
+                    .iter()
+                    .map(|element| Self::from_json(&element.ok()?))
+                    .collect::<Option<_>>()?,
+            ),
+            AnyJsonValue::JsonObjectValue(object) => Self::Object(
+                object
+                    .json_member_list()
+                    .iter()
+                    .map(|member| {
+                        let member = member.ok()?;
+                        let name = member_name(&member.name().ok()?)?;
+                        Some((name, Self::from_json(&member.value().ok()?)?))
+                    })
+                    .collect::<Option<_>>()?,
+            ),
+            AnyJsonValue::JsonBogusValue(_) => return None,
+        })
+    }
+
+    /// Returns the value of the property `name`, if the value is an object.
+    pub fn get(&self, name: &str) -> Option<&Self> {
+        match self {
+            // The last property wins when a name is duplicated
+            Self::Object(members) => members
+                .iter()
+                .rev()
+                .find(|(member, _)| member == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if it's a non-negative integer.
+    fn as_count(&self) -> Option<u64> {
+        let value = self.as_number()?;
+        (value >= 0.0 && value.fract() == 0.0).then_some(value as u64)
+    }
+}
+
+impl PartialEq for JsonValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Null, Self::Null) => true,
+            (Self::Bool(left), Self::Bool(right)) => left == right,
+            (Self::Number(left), Self::Number(right)) => left == right,
+            (Self::String(left), Self::String(right)) => left == right,
+            (Self::Array(left), Self::Array(right)) => left == right,
+            // The order of the properties doesn't matter
+            (Self::Object(left), Self::Object(right)) => {
+                left.len() == right.len()
+                    && left
+                        .iter()
+                        .all(|(name, value)| other.get(name).is_some_and(|other| other == value))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Display for JsonValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::String(value) => write_string(f, value),
+            Self::Array(items) => {
+                f.write_char('[')?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_char(']')
+            }
+            Self::Object(members) => {
+                f.write_char('{')?;
+                for (index, (name, value)) in members.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_string(f, name)?;
+                    write!(f, ": {value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in value.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// A violation of a schema by a value of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaViolation {
+    /// The range of the value, of the opening bracket of an object or an array,
+    /// or of the name of a property.
+    pub range: TextRange,
+    pub kind: SchemaViolationKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SchemaViolationKind {
+    /// The value doesn't have one of the types of the schema.
+    InvalidType {
+        expected: Vec<String>,
+        found: &'static str,
+    },
+    /// A required property is missing from the object.
+    MissingProperty(String),
+    /// The schema doesn't allow the property.
+    UnknownProperty(String),
+    /// The value isn't one of the values of `enum`.
+    NotInEnum(Vec<JsonValue>),
+    /// The value isn't the value of `const`.
+    NotConst(JsonValue),
+    /// The number is lower than `minimum` or `exclusiveMinimum`.
+    NumberTooSmall { limit: f64, exclusive: bool },
+    /// The number is greater than `maximum` or `exclusiveMaximum`.
+    NumberTooLarge { limit: f64, exclusive: bool },
+    /// The number isn't a multiple of `multipleOf`.
+    NotMultipleOf(f64),
+    /// The string has fewer characters than `minLength`.
+    StringTooShort(u64),
+    /// The string has more characters than `maxLength`.
+    StringTooLong(u64),
+    /// The string doesn't match `pattern`.
+    PatternMismatch(String),
+    /// The array has fewer items than `minItems`.
+    TooFewItems(u64),
+    /// The array has more items than `maxItems`.
+    TooManyItems(u64),
+    /// The item is already in the array, and `uniqueItems` is enabled.
+    DuplicateItem,
+    /// The object has fewer properties than `minProperties`.
+    TooFewProperties(u64),
+    /// The object has more properties than `maxProperties`.
+    TooManyProperties(u64),
+    /// The value doesn't match any schema of `anyOf` or `oneOf`.
+    NoMatchingSchema,
+    /// The value matches several schemas of `oneOf`.
+    MultipleMatchingSchemas,
+    /// The schema is `false`, or the value matches the schema of `not`.
+    Forbidden,
+}
+
+/// A schema that validates the JSON documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonSchema {
+    root: JsonValue,
+}
+
+impl JsonSchema {
+    /// Loads the schema from the root of a parsed document,
+    /// or returns `None` if the document isn't a schema.
+    pub fn from_root(root: &JsonRoot) -> Option<Self> {
+        let root = JsonValue::from_json(&root.value().ok()?)?;
+        matches!(root, JsonValue::Object(_) | JsonValue::Bool(_)).then_some(Self { root })
+    }
+
+    /// Returns the violations of the schema by the document.
+    pub fn validate(&self, root: &JsonRoot) -> Vec<SchemaViolation> {
+        let mut violations = Vec::new();
+        if let Ok(value) = root.value() {
+            self.validate_value(&self.root, &value, 0, &mut violations);
+        }
+        violations
+    }
+
+    /// Resolves a reference to a schema of the same document, such as `#/definitions/rule`.
+    fn resolve(&self, reference: &str) -> Option<&JsonValue> {
+        let pointer = reference.strip_prefix('#')?;
+        if pointer.is_empty() {
+            return Some(&self.root);
+        }
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .try_fold(&self.root, |value, segment| {
+                let segment = segment.replace("~1", "/").replace("~0", "~");
+                match value {
+                    JsonValue::Array(items) => items.get(segment.parse::<usize>().ok()?),
+                    _ => value.get(&segment),
+                }
+            })
+    }
+
+    fn is_valid(&self, schema: &JsonValue, value: &AnyJsonValue, depth: u32) -> bool {
+        let mut violations = Vec::new();
+        self.validate_value(schema, value, depth, &mut violations);
+        violations.is_empty()
+    }
+
+    fn validate_value(
+        &self,
+        schema: &JsonValue,
+        value: &AnyJsonValue,
+        depth: u32,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        match schema {
+            JsonValue::Object(_) => {}
+            JsonValue::Bool(false) => {
+                violations.push(SchemaViolation {
+                    range: value_range(value),
+                    kind: SchemaViolationKind::Forbidden,
+                });
+                return;
+            }
+            _ => return,
+        }
+        if depth > MAX_DEPTH || matches!(value, AnyJsonValue::JsonBogusValue(_)) {
+            return;
+        }
+
+        if let Some(target) = schema
+            .get("$ref")
+            .and_then(JsonValue::as_str)
+            .and_then(|reference| self.resolve(reference))
+        {
+            self.validate_value(target, value, depth + 1, violations);
+        }
+
+        let expected: Vec<&str> = match schema.get("type") {
+            Some(JsonValue::String(name)) => vec![name],
+            Some(JsonValue::Array(names)) => names.iter().filter_map(JsonValue::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !expected.is_empty() && !expected.iter().any(|name| has_type(value, name)) {
+            violations.push(SchemaViolation {
+                range: value_range(value),
+                kind: SchemaViolationKind::InvalidType {
+                    expected: expected.into_iter().map(str::to_string).collect(),
+                    found: type_name(value),
+                },
+            });
+            // The other keywords would only repeat the violation
+            return;
+        }
+
+        let allowed_values = schema.get("enum");
+        let constant = schema.get("const");
+        if allowed_values.is_some() || constant.is_some() {
+            if let Some(instance) = JsonValue::from_json(value) {
+                if let Some(JsonValue::Array(allowed_values)) = allowed_values {
+                    if !allowed_values.contains(&instance) {
+                        violations.push(SchemaViolation {
+                            range: value.range(),
+                            kind: SchemaViolationKind::NotInEnum(allowed_values.clone()),
+                        });
+                    }
+                }
+                if let Some(constant) = constant {
+                    if *constant != instance {
+                        violations.push(SchemaViolation {
+                            range: value.range(),
+                            kind: SchemaViolationKind::NotConst(constant.clone()),
+                        });
+                    }
+                }
+            }
+        }
+
+        match value {
+            AnyJsonValue::JsonObjectValue(object) => {
+                self.validate_object(schema, object, depth, violations)
+            }
+            AnyJsonValue::JsonArrayValue(array) => {
+                self.validate_array(schema, array, depth, violations)
+            }
+            AnyJsonValue::JsonStringValue(string) => validate_string(schema, string, violations),
+            AnyJsonValue::JsonNumberValue(number) => validate_number(schema, number, violations),
+            _ => {}
+        }
+
+        if let Some(JsonValue::Array(schemas)) = schema.get("allOf") {
+            for schema in schemas {
+                self.validate_value(schema, value, depth + 1, violations);
+            }
+        }
+        if let Some(JsonValue::Array(schemas)) = schema.get("anyOf") {
+            self.validate_alternatives(schemas, value, depth, false, violations);
+        }
+        if let Some(JsonValue::Array(schemas)) = schema.get("oneOf") {
+            self.validate_alternatives(schemas, value, depth, true, violations);
+        }
+        if let Some(not) = schema.get("not") {
+            if self.is_valid(not, value, depth + 1) {
+                violations.push(SchemaViolation {
+                    range: value_range(value),
+                    kind: SchemaViolationKind::Forbidden,
+                });
+            }
+        }
+        if let Some(condition) = schema.get("if") {
+            let branch = if self.is_valid(condition, value, depth + 1) {
+                schema.get("then")
+            } else {
+                schema.get("else")
+            };
+            if let Some(branch) = branch {
+                self.validate_value(branch, value, depth + 1, violations);
+            }
+        }
+    }
+
+    /// Validates the value against the schemas of `anyOf`, or of `oneOf` when `is_exclusive` is `true`.
+    fn validate_alternatives(
+        &self,
+        schemas: &[JsonValue],
+        value: &AnyJsonValue,
+        depth: u32,
+        is_exclusive: bool,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let results: Vec<_> = schemas
+            .iter()
+            .map(|schema| {
+                let mut violations = Vec::new();
+                self.validate_value(schema, value, depth + 1, &mut violations);
+                violations
+            })
+            .collect();
+        let matching = results.iter().filter(|result| result.is_empty()).count();
+        if matching == 0 {
+            // When a single schema accepts the type of the value,
+            // its violations are more precise than a generic violation.
+            let range = value_range(value);
+            let mut candidates = results.into_iter().filter(|result| {
+                !matches!(
+                    result.as_slice(),
+                    [SchemaViolation {
+                        range: violation_range,
+                        kind: SchemaViolationKind::InvalidType { .. },
+                    }] if *violation_range == range
+                )
+            });
+            match (candidates.next(), candidates.next()) {
+                (Some(candidate), None) => violations.extend(candidate),
+                _ => violations.push(SchemaViolation {
+                    range,
+                    kind: SchemaViolationKind::NoMatchingSchema,
+                }),
+            }
+        } else if is_exclusive && matching > 1 {
+            violations.push(SchemaViolation {
+                range: value_range(value),
+                kind: SchemaViolationKind::MultipleMatchingSchemas,
+            });
+        }
+    }
+
+    fn validate_object(
+        &self,
+        schema: &JsonValue,
+        object: &JsonObjectValue,
+        depth: u32,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        // The `$schema` property of the document is always allowed
+        let is_document = object
+            .syntax()
+            .parent()
+            .is_some_and(|parent| JsonRoot::can_cast(parent.kind()));
+        let properties = schema.get("properties");
+        let pattern_properties: Vec<_> = match schema.get("patternProperties") {
+            Some(JsonValue::Object(patterns)) => patterns
+                .iter()
+                .filter_map(|(pattern, schema)| Some((Regex::new(pattern).ok()?, schema)))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let additional_properties = schema.get("additionalProperties");
+
+        let mut names = Vec::new();
+        for member in object.json_member_list().iter().flatten() {
+            let (Ok(name_node), Ok(value)) = (member.name(), member.value()) else {
+                continue;
+            };
+            let Some(name) = member_name(&name_node) else {
+                continue;
+            };
+            let mut is_declared = false;
+            if let Some(property) = properties.and_then(|properties| properties.get(&name)) {
+                is_declared = true;
+                self.validate_value(property, &value, depth + 1, violations);
+            }
+            for (pattern, property) in &pattern_properties {
+                if pattern.is_match(&name) {
+                    is_declared = true;
+                    self.validate_value(property, &value, depth + 1, violations);
+                }
+            }
+            if !(is_declared || is_document && name == "$schema") {
+                match additional_properties {
+                    Some(JsonValue::Bool(false)) => violations.push(SchemaViolation {
+                        range: name_node.range(),
+                        kind: SchemaViolationKind::UnknownProperty(name.clone()),
+                    }),
+                    Some(additional_properties) => {
+                        self.validate_value(additional_properties, &value, depth + 1, violations)
+                    }
+                    None => {}
+                }
+            }
+            names.push(name);
+        }
+
+        let range = value_range(&object.clone().into());
+        if let Some(JsonValue::Array(required)) = schema.get("required") {
+            for name in required.iter().filter_map(JsonValue::as_str) {
+                if !names.iter().any(|member| member == name) {
+                    violations.push(SchemaViolation {
+                        range,
+                        kind: SchemaViolationKind::MissingProperty(name.to_string()),
+                    });
+                }
+            }
+        }
+        let count = names.len() as u64;
+        if let Some(limit) = schema.get("minProperties").and_then(JsonValue::as_count) {
+            if count < limit {
+                violations.push(SchemaViolation {
+                    range,
+                    kind: SchemaViolationKind::TooFewProperties(limit),
+                });
+            }
+        }
+        if let Some(limit) = schema.get("maxProperties").and_then(JsonValue::as_count) {
+            if count > limit {
+                violations.push(SchemaViolation {
+                    range,
+                    kind: SchemaViolationKind::TooManyProperties(limit),
+                });
+            }
+        }
+    }
+
+    fn validate_array(
+        &self,
+        schema: &JsonValue,
+        array: &JsonArrayValue,
+        depth: u32,
+        violations: &mut Vec<SchemaViolation>,
+    ) {
+        let elements: Vec<_> = array.elements().iter().flatten().collect();
+        // `prefixItems` replaces the array form of `items` since the draft 2020-12
+        let (prefix_items, items) = match (schema.get("prefixItems"), schema.get("items")) {
+            (Some(JsonValue::Array(prefix_items)), items) => (prefix_items.as_slice(), items),
+            (None, Some(JsonValue::Array(prefix_items))) => {
+                (prefix_items.as_slice(), schema.get("additionalItems"))
+            }
+            (_, items) => (&[][..], items),
+        };
+        for (index, element) in elements.iter().enumerate() {
+            if let Some(item) = prefix_items.get(index).or(items) {
+                self.validate_value(item, element, depth + 1, violations);
+            }
+        }
+
+        let range = value_range(&array.clone().into());
+        let count = elements.len() as u64;
+        if let Some(limit) = schema.get("minItems").and_then(JsonValue::as_count) {
+            if count < limit {
+                violations.push(SchemaViolation {
+                    range,
+                    kind: SchemaViolationKind::TooFewItems(limit),
+                });
+            }
+        }
+        if let Some(limit) = schema.get("maxItems").and_then(JsonValue::as_count) {
+            if count > limit {
+                violations.push(SchemaViolation {
+                    range,
+                    kind: SchemaViolationKind::TooManyItems(limit),
+                });
+            }
+        }
+        if matches!(schema.get("uniqueItems"), Some(JsonValue::Bool(true))) {
+            let values: Vec<_> = elements.iter().map(JsonValue::from_json).collect();
+            for (index, (element, value)) in elements.iter().zip(&values).enumerate() {
+                if value.is_some() && values[..index].contains(value) {
+                    violations.push(SchemaViolation {
+                        range: element.range(),
+                        kind: SchemaViolationKind::DuplicateItem,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn validate_string(
+    schema: &JsonValue,
+    string: &JsonStringValue,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let Some(value) = string_value(string) else {
+        return;
+    };
+    let length = value.chars().count() as u64;
+    if let Some(limit) = schema.get("minLength").and_then(JsonValue::as_count) {
+        if length < limit {
+            violations.push(SchemaViolation {
+                range: string.range(),
+                kind: SchemaViolationKind::StringTooShort(limit),
+            });
+        }
+    }
+    if let Some(limit) = schema.get("maxLength").and_then(JsonValue::as_count) {
+        if length > limit {
+            violations.push(SchemaViolation {
+                range: string.range(),
+                kind: SchemaViolationKind::StringTooLong(limit),
+            });
+        }
+    }
+    if let Some(pattern) = schema.get("pattern").and_then(JsonValue::as_str) {
+        // The patterns that aren't supported by the regex engine are ignored
+        if Regex::new(pattern).is_ok_and(|regex| !regex.is_match(&value)) {
+            violations.push(SchemaViolation {
+                range: string.range(),
+                kind: SchemaViolationKind::PatternMismatch(pattern.to_string()),
+            });
+        }
+    }
+}
+
+fn validate_number(
+    schema: &JsonValue,
+    number: &JsonNumberValue,
+    violations: &mut Vec<SchemaViolation>,
+) {
+    let Some(value) = number_value(number) else {
+        return;
+    };
+    let mut push = |kind| {
+        violations.push(SchemaViolation {
+            range: number.range(),
+            kind,
+        })
+    };
+    // Before the draft 6, `exclusiveMinimum` and `exclusiveMaximum` are booleans
+    // that modify `minimum` and `maximum`
+    if let Some(limit) = schema.get("minimum").and_then(JsonValue::as_number) {
+        let exclusive = matches!(schema.get("exclusiveMinimum"), Some(JsonValue::Bool(true)));
+        if value < limit || (exclusive && value == limit) {
+            push(SchemaViolationKind::NumberTooSmall { limit, exclusive });
+        }
+    }
+    if let Some(limit) = schema
+        .get("exclusiveMinimum")
+        .and_then(JsonValue::as_number)
+    {
+        if value <= limit {
+            push(SchemaViolationKind::NumberTooSmall {
+                limit,
+                exclusive: true,
+            });
+        }
+    }
+    if let Some(limit) = schema.get("maximum").and_then(JsonValue::as_number) {
+        let exclusive = matches!(schema.get("exclusiveMaximum"), Some(JsonValue::Bool(true)));
+        if value > limit || (exclusive && value == limit) {
+            push(SchemaViolationKind::NumberTooLarge { limit, exclusive });
+        }
+    }
+    if let Some(limit) = schema
+        .get("exclusiveMaximum")
+        .and_then(JsonValue::as_number)
+    {
+        if value >= limit {
+            push(SchemaViolationKind::NumberTooLarge {
+                limit,
+                exclusive: true,
+            });
+        }
+    }
+    if let Some(divisor) = schema.get("multipleOf").and_then(JsonValue::as_number) {
+        let quotient = value / divisor;
+        if divisor > 0.0 && (quotient - quotient.round()).abs() > 1e-9 {
+            push(SchemaViolationKind::NotMultipleOf(divisor));
+        }
+    }
+}
+
+/// Returns the path of the schema declared by the `$schema` property of the document.
+pub fn declared_schema(root: &JsonRoot) -> Option<String> {
+    let value = root.value().ok()?;
+    let object = value.as_json_object_value()?;
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .filter(|member| {
+            member
+                .name()
+                .ok()
+                .and_then(|name| member_name(&name))
+                .is_some_and(|name| name == "$schema")
+        })
+        .last()
+        .and_then(|member| string_value(member.value().ok()?.as_json_string_value()?))
+}
+
+/// Returns whether the value has the type `name` of a schema.
+fn has_type(value: &AnyJsonValue, name: &str) -> bool {
+    match name {
+        "null" => matches!(value, AnyJsonValue::JsonNullValue(_)),
+        "boolean" => matches!(value, AnyJsonValue::JsonBooleanValue(_)),
+        "object" => matches!(value, AnyJsonValue::JsonObjectValue(_)),
+        "array" => matches!(value, AnyJsonValue::JsonArrayValue(_)),
+        "string" => matches!(value, AnyJsonValue::JsonStringValue(_)),
+        "number" => matches!(value, AnyJsonValue::JsonNumberValue(_)),
+        "integer" => value
+            .as_json_number_value()
+            .and_then(number_value)
+            .is_some_and(|value| value.fract() == 0.0),
+        // The unknown types are ignored
+        _ => true,
+    }
+}
+
+fn type_name(value: &AnyJsonValue) -> &'static str {
+    match value {
+        AnyJsonValue::JsonNullValue(_) => "null",
+        AnyJsonValue::JsonBooleanValue(_) => "boolean",
+        AnyJsonValue::JsonObjectValue(_) => "object",
+        AnyJsonValue::JsonArrayValue(_) => "array",
+        AnyJsonValue::JsonStringValue(_) => "string",
+        AnyJsonValue::JsonNumberValue(_) => "number",
+        AnyJsonValue::JsonBogusValue(_) => "unknown",
+    }
+}
+
+/// Returns the range of the opening bracket of an object or an array,
+/// so that the diagnostics of a large value don't cover all its lines.
+fn value_range(value: &AnyJsonValue) -> TextRange {
+    let opening_token = match value {
+        AnyJsonValue::JsonObjectValue(object) => object.l_curly_token().ok(),
+        AnyJsonValue::JsonArrayValue(array) => array.l_brack_token().ok(),
+        _ => None,
+    };
+    opening_token.map_or_else(|| value.range(), |token| token.text_trimmed_range())
+}
+
+fn member_name(name: &JsonMemberName) -> Option<String> {
+    Some(unescape(name.inner_string_text().ok()?.text()))
+}
+
+fn string_value(value: &JsonStringValue) -> Option<String> {
+    Some(unescape(value.inner_string_text().ok()?.text()))
+}
+
+fn number_value(value: &JsonNumberValue) -> Option<f64> {
+    value.value_token().ok()?.text_trimmed().parse().ok()
+}
+
+/// Replaces the escape sequences of a JSON string by the characters they represent.
+fn unescape(text: &str) -> String {
+    if !text.contains('\\') {
+        return text.to_string();
+    }
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('b') => result.push('\u{8}'),
+            Some('f') => result.push('\u{c}'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                let c = u32::from_str_radix(&code, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .unwrap_or(char::REPLACEMENT_CHARACTER);
+                result.push(c);
+            }
+            Some(c) => result.push(c),
+            None => {}
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(unescape("plain"), "plain");
+        assert_eq!(unescape(r#"\"quoted\""#), "\"quoted\"");
+        assert_eq!(unescape(r"^\\d+$"), r"^\d+$");
+        assert_eq!(unescape(r"é\/\n"), "é/\n");
+    }
+
+    #[test]
+    fn test_display() {
+        let value = JsonValue::Object(vec![
+            ("name".to_string(), JsonValue::String("a\"b".to_string())),
+            (
+                "items".to_string(),
+                JsonValue::Array(vec![JsonValue::Number(1.0), JsonValue::Null]),
+            ),
+        ]);
+        assert_eq!(value.to_string(), r#"{"name": "a\"b", "items": [1, null]}"#);
+    }
+
+    #[test]
+    fn test_object_equality_ignores_order() {
+        let left = JsonValue::Object(vec![
+            ("a".to_string(), JsonValue::Bool(true)),
+            ("b".to_string(), JsonValue::Number(1.0)),
+        ]);
+        let right = JsonValue::Object(vec![
+            ("b".to_string(), JsonValue::Number(1.0)),
+            ("a".to_string(), JsonValue::Bool(true)),
+        ]);
+        assert_eq!(left, right);
+        assert_ne!(left, JsonValue::Object(vec![]));
+    }
+}
@@ -1,15 +1,23 @@
+pub mod json_schema;
 mod lint;
 pub mod options;
+pub mod package_json;
 mod registry;
+pub mod tsconfig;
 pub mod utils;
 
+use crate::json_schema::JsonSchema;
 pub use crate::registry::visit_registry;
+use crate::tsconfig::TsconfigExtends;
 use biome_analyze::{
     AnalysisFilter, AnalyzerOptions, AnalyzerSignal, ControlFlow, LanguageRoot, MatchQueryParams,
-    MetadataRegistry, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
+    MetadataRegistry, RuleAction, RuleRegistry, SuppressionDiagnostic, SuppressionKind,
 };
 use biome_diagnostics::Error;
 use biome_json_syntax::JsonLanguage;
+use std::sync::Arc;
+
+pub(crate) type JsonRuleAction = RuleAction<JsonLanguage>;
 
 /// Return the static [MetadataRegistry] for the JSON analyzer rules
 pub fn metadata() -> &'static MetadataRegistry {
@@ -31,13 +39,23 @@ pub fn analyze<'a, F, B>(
     root: &LanguageRoot<JsonLanguage>,
     filter: AnalysisFilter,
     options: &'a AnalyzerOptions,
+    json_schema: Option<Arc<JsonSchema>>,
+    tsconfig_extends: Option<Arc<TsconfigExtends>>,
     emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
     F: FnMut(&dyn AnalyzerSignal<JsonLanguage>) -> ControlFlow<B> + 'a,
     B: 'a,
 {
-    analyze_with_inspect_matcher(root, filter, |_| {}, options, emit_signal)
+    analyze_with_inspect_matcher(
+        root,
+        filter,
+        |_| {},
+        options,
+        json_schema,
+        tsconfig_extends,
+        emit_signal,
+    )
 }
 
 /// Run the analyzer on the provided `root`: this process will use the given `filter`
@@ -51,6 +69,8 @@ pub fn analyze_with_inspect_matcher<'a, V, F, B>(
     filter: AnalysisFilter,
     inspect_matcher: V,
     options: &'a AnalyzerOptions,
+    json_schema: Option<Arc<JsonSchema>>,
+    tsconfig_extends: Option<Arc<TsconfigExtends>>,
     mut emit_signal: F,
 ) -> (Option<B>, Vec<Error>)
 where
@@ -66,7 +86,7 @@ where
     let mut registry = RuleRegistry::builder(&filter, root);
     visit_registry(&mut registry);
 
-    let (registry, services, diagnostics, visitors) = registry.build();
+    let (registry, mut services, diagnostics, visitors) = registry.build();
 
     // Bail if we can't parse a rule option
     if !diagnostics.is_empty() {
@@ -85,6 +105,12 @@ where
         analyzer.add_visitor(phase, visitor);
     }
 
+    if let Some(json_schema) = json_schema {
+        services.insert_service(json_schema);
+    }
+    if let Some(tsconfig_extends) = tsconfig_extends {
+        services.insert_service(tsconfig_extends);
+    }
     (
         analyzer.run(biome_analyze::AnalyzerContext {
             root: root.clone(),
@@ -140,6 +166,8 @@ mod tests {
                 ..AnalysisFilter::default()
             },
             &options,
+            None,
+            None,
             |signal| {
                 if let Some(diag) = signal.diagnostic() {
                     error_ranges.push(diag.location().span.unwrap());
@@ -2,13 +2,33 @@
 
 use biome_analyze::declare_group;
 
+pub mod no_conflicting_tsconfig_options;
+pub mod no_deprecated_tsconfig_options;
 pub mod no_duplicate_json_keys;
+pub mod no_duplicate_package_json_dependencies;
+pub mod no_json_schema_violations;
+pub mod no_unknown_tsconfig_options;
+pub mod no_unresolved_tsconfig_extends;
+pub mod use_required_package_json_fields;
+pub mod use_sorted_keys;
+pub mod use_sorted_package_json_keys;
+pub mod use_valid_package_json_versions;
 
 declare_group! {
     pub Nursery {
         name : "nursery" ,
         rules : [
+            self :: no_conflicting_tsconfig_options :: NoConflictingTsconfigOptions ,
+            self :: no_deprecated_tsconfig_options :: NoDeprecatedTsconfigOptions ,
             self :: no_duplicate_json_keys :: NoDuplicateJsonKeys ,
+            self :: no_duplicate_package_json_dependencies :: NoDuplicatePackageJsonDependencies ,
+            self :: no_json_schema_violations :: NoJsonSchemaViolations ,
+            self :: no_unknown_tsconfig_options :: NoUnknownTsconfigOptions ,
+            self :: no_unresolved_tsconfig_extends :: NoUnresolvedTsconfigExtends ,
+            self :: use_required_package_json_fields :: UseRequiredPackageJsonFields ,
+            self :: use_sorted_keys :: UseSortedKeys ,
+            self :: use_sorted_package_json_keys :: UseSortedPackageJsonKeys ,
+            self :: use_valid_package_json_versions :: UseValidPackageJsonVersions ,
         ]
      }
 }
@@ -0,0 +1,202 @@
+use crate::tsconfig::{compiler_option_members, is_tsconfig};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonMember, JsonRoot, TextRange};
+use biome_rowan::AstNode;
+
+declare_rule! {
+    /// Disallow the compiler options that contradict each other.
+    ///
+    /// TypeScript rejects some combinations of compiler options, but only reports them when it compiles the project.
+    /// The rule reports the following combinations, when both options are set in the same file:
+    ///
+    /// - `moduleResolution` set to `bundler`, with `module` set to `commonjs`, `amd`, `umd`, `system`, `none`, `node16`, `node18` or `nodenext`;
+    /// - `moduleResolution` set to `node16` or `nodenext`, with `module` set to another value than `node16`, `node18` or `nodenext`;
+    /// - `emitDeclarationOnly` set to `true`, with `noEmit` set to `true`;
+    /// - `emitDeclarationOnly` set to `true`, with `declaration` set to `false`;
+    /// - `composite` set to `true`, with `declaration` set to `false`.
+    ///
+    /// The options inherited with `extends` aren't taken into account.
+    ///
+    /// The rule only applies to the configuration files of TypeScript: `tsconfig.json`, `tsconfig.*.json` and `jsconfig.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "module": "commonjs",
+    ///         "moduleResolution": "bundler"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "noEmit": true,
+    ///         "emitDeclarationOnly": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "module": "esnext",
+    ///         "moduleResolution": "bundler"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoConflictingTsconfigOptions {
+        version: "next",
+        name: "noConflictingTsconfigOptions",
+        language: "json",
+        domains: &[RuleDomain::Tsconfig],
+        recommended: false,
+    }
+}
+
+/// A combination of compiler options that TypeScript rejects.
+struct Conflict {
+    option: &'static str,
+    is_conflicting_value: fn(&str) -> bool,
+    other_option: &'static str,
+    is_other_conflicting_value: fn(&str) -> bool,
+    note: &'static str,
+}
+
+const CONFLICTS: &[Conflict] = &[
+    Conflict {
+        option: "moduleResolution",
+        is_conflicting_value: |value| value == "bundler",
+        other_option: "module",
+        is_other_conflicting_value: |value| {
+            matches!(
+                value,
+                "commonjs" | "amd" | "umd" | "system" | "none" | "node16" | "node18" | "nodenext"
+            )
+        },
+        note: "The module resolution bundler requires the module preserve, or an ECMAScript module such as esnext.",
+    },
+    Conflict {
+        option: "moduleResolution",
+        is_conflicting_value: |value| matches!(value, "node16" | "nodenext"),
+        other_option: "module",
+        is_other_conflicting_value: |value| !matches!(value, "node16" | "node18" | "nodenext"),
+        note: "The module resolutions node16 and nodenext require the module node16, node18 or nodenext.",
+    },
+    Conflict {
+        option: "emitDeclarationOnly",
+        is_conflicting_value: |value| value == "true",
+        other_option: "noEmit",
+        is_other_conflicting_value: |value| value == "true",
+        note: "No declaration file can be emitted when the emit is disabled.",
+    },
+    Conflict {
+        option: "emitDeclarationOnly",
+        is_conflicting_value: |value| value == "true",
+        other_option: "declaration",
+        is_other_conflicting_value: |value| value == "false",
+        note: "Only the declaration files are emitted, so the declaration files must be enabled.",
+    },
+    Conflict {
+        option: "composite",
+        is_conflicting_value: |value| value == "true",
+        other_option: "declaration",
+        is_other_conflicting_value: |value| value == "false",
+        note: "The composite projects must emit declaration files, so that the projects that reference them can use them.",
+    },
+];
+
+pub struct ConflictingOptions {
+    /// The range of the value of the option
+    range: TextRange,
+    /// The range of the value of the other option
+    other_range: TextRange,
+    conflict: &'static Conflict,
+}
+
+impl Rule for NoConflictingTsconfigOptions {
+    type Query = Ast<JsonRoot>;
+    type State = ConflictingOptions;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig(ctx.file_path()) {
+            return Vec::new();
+        }
+        let members = compiler_option_members(ctx.query());
+        let find_value = |option: &str| {
+            members
+                .iter()
+                .find(|(name, _)| name == option)
+                .and_then(|(_, member)| option_value(member))
+        };
+        CONFLICTS
+            .iter()
+            .filter_map(|conflict| {
+                let (value, range) = find_value(conflict.option)?;
+                let (other_value, other_range) = find_value(conflict.other_option)?;
+                ((conflict.is_conflicting_value)(&value)
+                    && (conflict.is_other_conflicting_value)(&other_value))
+                .then_some(ConflictingOptions {
+                    range,
+                    other_range,
+                    conflict,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let Conflict {
+            option,
+            other_option,
+            note,
+            ..
+        } = state.conflict;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This value of "<Emphasis>{option}</Emphasis>" conflicts with the value of "<Emphasis>{other_option}</Emphasis>"."
+                },
+            )
+            .detail(
+                state.other_range,
+                markup! {
+                    "The option "<Emphasis>{other_option}</Emphasis>" is set here."
+                },
+            )
+            .note(markup! {
+                {note}
+            })
+            .note(markup! {
+                "TypeScript reports an error for this combination of options. Change one of the values."
+            }),
+        )
+    }
+}
+
+/// Returns the value of a compiler option in lowercase, with its range,
+/// when it's a string or a boolean.
+fn option_value(member: &JsonMember) -> Option<(String, TextRange)> {
+    match member.value().ok()? {
+        AnyJsonValue::JsonStringValue(value) => Some((
+            value.inner_string_text().ok()?.to_ascii_lowercase(),
+            value.range(),
+        )),
+        AnyJsonValue::JsonBooleanValue(value) => {
+            Some((value.syntax().text_trimmed().to_string(), value.range()))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,169 @@
+use crate::tsconfig::{compiler_option_members, deprecated_compiler_option, is_tsconfig};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleDomain,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_json_factory::make;
+use biome_json_syntax::{AnyJsonValue, JsonMemberName, JsonRoot, TextRange};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow the compiler options deprecated by TypeScript.
+    ///
+    /// TypeScript 5.0 deprecated the following options, and TypeScript 5.5 removed them:
+    /// `charset`, `importsNotUsedAsValues`, `keyofStringsOnly`, `noImplicitUseStrict`, `noStrictGenericChecks`,
+    /// `out`, `preserveValueImports`, `suppressExcessPropertyErrors` and `suppressImplicitAnyIndexErrors`.
+    /// It also deprecated the target `ES3`.
+    ///
+    /// The code action renames `out` to `outFile`. It's unsafe, because `outFile` doesn't
+    /// order the output files in the same way.
+    ///
+    /// The rule only applies to the configuration files of TypeScript: `tsconfig.json`, `tsconfig.*.json` and `jsconfig.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "importsNotUsedAsValues": "error"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "target": "ES3"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "verbatimModuleSyntax": true,
+    ///         "target": "ES2022"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoDeprecatedTsconfigOptions {
+        version: "next",
+        name: "noDeprecatedTsconfigOptions",
+        language: "json",
+        domains: &[RuleDomain::Tsconfig],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub enum DeprecatedOption {
+    /// A deprecated option, with the option that replaces it
+    Option {
+        name: JsonMemberName,
+        replacement: Option<&'static str>,
+    },
+    /// The target `ES3`
+    TargetEs3(TextRange),
+}
+
+impl Rule for NoDeprecatedTsconfigOptions {
+    type Query = Ast<JsonRoot>;
+    type State = DeprecatedOption;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig(ctx.file_path()) {
+            return Vec::new();
+        }
+        compiler_option_members(ctx.query())
+            .into_iter()
+            .filter_map(|(name, member)| {
+                if let Some(replacement) = deprecated_compiler_option(&name) {
+                    return Some(DeprecatedOption::Option {
+                        name: member.name().ok()?,
+                        replacement,
+                    });
+                }
+                if name != "target" {
+                    return None;
+                }
+                let AnyJsonValue::JsonStringValue(target) = member.value().ok()? else {
+                    return None;
+                };
+                target
+                    .inner_string_text()
+                    .ok()?
+                    .eq_ignore_ascii_case("es3")
+                    .then(|| DeprecatedOption::TargetEs3(target.range()))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            DeprecatedOption::Option { name, replacement } => {
+                let text = name.inner_string_text().ok()?;
+                let text = text.text();
+                let diagnostic = RuleDiagnostic::new(
+                    rule_category!(),
+                    name.range(),
+                    markup! {
+                        "The compiler option "<Emphasis>{text}</Emphasis>" is deprecated."
+                    },
+                )
+                .note(markup! {
+                    "TypeScript 5.0 deprecated this option, and TypeScript 5.5 removed it."
+                });
+                match replacement {
+                    Some(replacement) => diagnostic.note(markup! {
+                        "Use "<Emphasis>{replacement}</Emphasis>" instead."
+                    }),
+                    None => diagnostic.note(markup! {
+                        "Remove this option."
+                    }),
+                }
+            }
+            DeprecatedOption::TargetEs3(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The target "<Emphasis>"ES3"</Emphasis>" is deprecated."
+                },
+            )
+            .note(markup! {
+                "TypeScript 5.0 deprecated this target, and TypeScript 5.5 removed it. Use "<Emphasis>"ES5"</Emphasis>" or a later target instead."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let DeprecatedOption::Option {
+            name,
+            replacement: Some("outFile"),
+        } = state
+        else {
+            return None;
+        };
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(
+            name.value_token().ok()?,
+            make::json_string_literal("outFile"),
+        );
+        Some(JsonRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use "<Emphasis>"outFile"</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
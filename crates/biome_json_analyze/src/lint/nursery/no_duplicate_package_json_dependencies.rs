@@ -0,0 +1,199 @@
+use crate::utils::{find_member, is_package_json, member_name, root_object};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleDomain,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_json_factory::make;
+use biome_json_syntax::{
+    AnyJsonValue, JsonMember, JsonMemberList, JsonObjectValue, JsonRoot, TextRange,
+};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow the packages listed both in `dependencies` and `devDependencies`.
+    ///
+    /// The package managers install the dependencies of `dependencies` in development too,
+    /// so a package listed in both fields is installed with the version range of one of them,
+    /// which depends on the package manager.
+    ///
+    /// When the two version ranges are the same, the code action removes the package from `devDependencies`.
+    /// Otherwise, the version range to keep must be chosen manually.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "dependencies": {
+    ///         "react": "^18.0.0"
+    ///     },
+    ///     "devDependencies": {
+    ///         "react": "^18.0.0"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "dependencies": {
+    ///         "react": "^18.0.0"
+    ///     },
+    ///     "devDependencies": {
+    ///         "vitest": "^2.0.0"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoDuplicatePackageJsonDependencies {
+        version: "next",
+        name: "noDuplicatePackageJsonDependencies",
+        language: "json",
+        domains: &[RuleDomain::PackageJson],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+pub struct DuplicateDependency {
+    /// The member of the package in `devDependencies`
+    dev_dependency: JsonMember,
+    /// The range of the name of the package in `dependencies`
+    dependency_range: TextRange,
+    /// Whether the two version ranges are the same
+    has_same_version: bool,
+}
+
+impl Rule for NoDuplicatePackageJsonDependencies {
+    type Query = Ast<JsonRoot>;
+    type State = DuplicateDependency;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Vec::new();
+        }
+        let Some(object) = root_object(ctx.query()) else {
+            return Vec::new();
+        };
+        let (Some(dependencies), Some(dev_dependencies)) = (
+            dependency_list(&object, "dependencies"),
+            dependency_list(&object, "devDependencies"),
+        ) else {
+            return Vec::new();
+        };
+        let dependencies = dependencies.iter().flatten().collect::<Vec<_>>();
+        dev_dependencies
+            .iter()
+            .flatten()
+            .filter_map(|dev_dependency| {
+                let name = member_name(&dev_dependency)?;
+                let dependency = dependencies
+                    .iter()
+                    .find(|dependency| member_name(dependency).as_deref() == Some(name.as_str()))?;
+                let has_same_version = version_text(dependency).is_some()
+                    && version_text(dependency) == version_text(&dev_dependency);
+                Some(DuplicateDependency {
+                    dependency_range: dependency.name().ok()?.range(),
+                    dev_dependency,
+                    has_same_version,
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = member_name(&state.dev_dependency)?;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.dev_dependency.name().ok()?.range(),
+            markup! {
+                "The package "<Emphasis>{name}</Emphasis>" is listed both in "<Emphasis>"dependencies"</Emphasis>" and "<Emphasis>"devDependencies"</Emphasis>"."
+            },
+        )
+        .detail(
+            state.dependency_range,
+            markup! {
+                "The package is also listed here."
+            },
+        );
+        let diagnostic = if state.has_same_version {
+            diagnostic.note(markup! {
+                "The package is installed in development as a dependency, so it can be removed from "<Emphasis>"devDependencies"</Emphasis>"."
+            })
+        } else {
+            diagnostic.note(markup! {
+                "The version ranges are different, keep the package in only one of the fields."
+            })
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        if !state.has_same_version {
+            return None;
+        }
+        let list = state
+            .dev_dependency
+            .syntax()
+            .parent()
+            .and_then(JsonMemberList::cast)?;
+        let mut elements = list
+            .elements()
+            .map(|element| {
+                Some((
+                    element.node().ok()?.clone(),
+                    element.trailing_separator().ok()?.cloned(),
+                ))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let index = elements
+            .iter()
+            .position(|(member, _)| member == &state.dev_dependency)?;
+        let (_, separator) = elements.remove(index);
+        // The new last member doesn't have a separator, unless the list had a trailing separator
+        if separator.is_none() {
+            if let Some((_, last_separator)) = elements.last_mut() {
+                *last_separator = None;
+            }
+        }
+        let separators: Vec<_> = elements
+            .iter()
+            .filter_map(|(_, separator)| separator.clone())
+            .collect();
+        let new_list = make::json_member_list(
+            elements.iter().map(|(member, _)| member.clone()),
+            separators,
+        );
+        let mut mutation = ctx.root().begin();
+        mutation.replace_node_discard_trivia(list, new_list);
+        Some(JsonRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Remove the package from "<Emphasis>"devDependencies"</Emphasis>"." }
+                .to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the members of the object of the field `field`.
+fn dependency_list(object: &JsonObjectValue, field: &str) -> Option<JsonMemberList> {
+    match find_member(object, field)?.value().ok()? {
+        AnyJsonValue::JsonObjectValue(dependencies) => Some(dependencies.json_member_list()),
+        _ => None,
+    }
+}
+
+/// Returns the text of the version range of a dependency, without the trivia.
+fn version_text(dependency: &JsonMember) -> Option<String> {
+    Some(dependency.value().ok()?.syntax().text_trimmed().to_string())
+}
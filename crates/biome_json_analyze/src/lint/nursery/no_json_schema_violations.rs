@@ -0,0 +1,270 @@
+use crate::json_schema::{JsonSchema, SchemaViolation, SchemaViolationKind};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_json_syntax::JsonRoot;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow the values that don't match the JSON Schema of the document.
+    ///
+    /// The schema of a document is the local file declared by its `$schema` property,
+    /// such as `"$schema": "./schemas/settings.json"`, or the schema mapped to its path
+    /// by the option `json.schemas` of the configuration:
+    ///
+    /// ```json
+    /// {
+    ///     "json": {
+    ///         "schemas": [
+    ///             {
+    ///                 "include": ["config/*.json"],
+    ///                 "path": "./schemas/config.schema.json"
+    ///             }
+    ///         ]
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// The schemas can't be fetched from a URL: a document whose `$schema` is a URL
+    /// is validated only if a schema is mapped to its path.
+    ///
+    /// The rule supports the structural keywords of JSON Schema, such as `type`, `enum`,
+    /// `properties`, `required`, `additionalProperties`, `items`, the bounds of the values,
+    /// `pattern`, `allOf`, `anyOf`, `oneOf`, and the references to the definitions
+    /// of the same schema with `$ref`. The other keywords, such as `format`, are ignored.
+    ///
+    /// ## Examples
+    ///
+    /// In the following examples, the schema of the document is:
+    ///
+    /// ```json
+    /// {
+    ///     "type": "object",
+    ///     "properties": {
+    ///         "name": { "type": "string" },
+    ///         "port": { "type": "integer", "minimum": 1 }
+    ///     },
+    ///     "required": ["name"],
+    ///     "additionalProperties": false
+    /// }
+    /// ```
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "name": "server",
+    ///     "port": "8080"
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "name": "server",
+    ///     "host": "localhost"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "name": "server",
+    ///     "port": 8080
+    /// }
+    /// ```
+    ///
+    pub NoJsonSchemaViolations {
+        version: "next",
+        name: "noJsonSchemaViolations",
+        language: "json",
+        recommended: false,
+    }
+}
+
+impl Rule for NoJsonSchemaViolations {
+    type Query = Ast<JsonRoot>;
+    type State = SchemaViolation;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let Some(json_schema) = ctx.get_service::<Arc<JsonSchema>>() else {
+            return Vec::new();
+        };
+        json_schema.validate(ctx.query())
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match &state.kind {
+            SchemaViolationKind::InvalidType { expected, found } => {
+                let expected = expected.join(" or ");
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "Expected a value of type "<Emphasis>{expected}</Emphasis>", but found "<Emphasis>{found}</Emphasis>"."
+                    },
+                )
+            }
+            SchemaViolationKind::MissingProperty(name) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This object is missing the required property "<Emphasis>{name}</Emphasis>"."
+                },
+            ),
+            SchemaViolationKind::UnknownProperty(name) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The property "<Emphasis>{name}</Emphasis>" isn't allowed by the schema."
+                },
+            ),
+            SchemaViolationKind::NotInEnum(allowed_values) => {
+                let allowed_values = allowed_values
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "This value isn't one of the values allowed by the schema."
+                    },
+                )
+                .note(markup! {
+                    "The allowed values are "<Emphasis>{allowed_values}</Emphasis>"."
+                })
+            }
+            SchemaViolationKind::NotConst(expected) => {
+                let expected = expected.to_string();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "Expected the value "<Emphasis>{expected}</Emphasis>"."
+                    },
+                )
+            }
+            SchemaViolationKind::NumberTooSmall { limit, exclusive } => {
+                let limit = limit.to_string();
+                let comparison = if *exclusive {
+                    "greater than"
+                } else {
+                    "greater than or equal to"
+                };
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "The number must be "{comparison}" "<Emphasis>{limit}</Emphasis>"."
+                    },
+                )
+            }
+            SchemaViolationKind::NumberTooLarge { limit, exclusive } => {
+                let limit = limit.to_string();
+                let comparison = if *exclusive {
+                    "less than"
+                } else {
+                    "less than or equal to"
+                };
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "The number must be "{comparison}" "<Emphasis>{limit}</Emphasis>"."
+                    },
+                )
+            }
+            SchemaViolationKind::NotMultipleOf(divisor) => {
+                let divisor = divisor.to_string();
+                RuleDiagnostic::new(
+                    rule_category!(),
+                    state.range,
+                    markup! {
+                        "The number must be a multiple of "<Emphasis>{divisor}</Emphasis>"."
+                    },
+                )
+            }
+            SchemaViolationKind::StringTooShort(limit) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The string must have at least "<Emphasis>{limit}</Emphasis>" characters."
+                },
+            ),
+            SchemaViolationKind::StringTooLong(limit) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The string must have at most "<Emphasis>{limit}</Emphasis>" characters."
+                },
+            ),
+            SchemaViolationKind::PatternMismatch(pattern) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The string doesn't match the pattern "<Emphasis>{pattern}</Emphasis>"."
+                },
+            ),
+            SchemaViolationKind::TooFewItems(limit) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The array must have at least "<Emphasis>{limit}</Emphasis>" items."
+                },
+            ),
+            SchemaViolationKind::TooManyItems(limit) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The array must have at most "<Emphasis>{limit}</Emphasis>" items."
+                },
+            ),
+            SchemaViolationKind::DuplicateItem => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This item is already in the array, but the schema requires unique items."
+                },
+            ),
+            SchemaViolationKind::TooFewProperties(limit) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The object must have at least "<Emphasis>{limit}</Emphasis>" properties."
+                },
+            ),
+            SchemaViolationKind::TooManyProperties(limit) => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The object must have at most "<Emphasis>{limit}</Emphasis>" properties."
+                },
+            ),
+            SchemaViolationKind::NoMatchingSchema => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This value doesn't match any of the schemas allowed here."
+                },
+            ),
+            SchemaViolationKind::MultipleMatchingSchemas => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This value matches several schemas, but the schema requires exactly one."
+                },
+            ),
+            SchemaViolationKind::Forbidden => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This value isn't allowed by the schema."
+                },
+            ),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::tsconfig::{
+    compiler_option_ignoring_case, compiler_option_members, is_compiler_option, is_tsconfig,
+};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleDomain,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_json_factory::make;
+use biome_json_syntax::{JsonMemberName, JsonRoot};
+use biome_rowan::{AstNode, BatchMutationExt};
+
+declare_rule! {
+    /// Disallow the compiler options that TypeScript doesn't know.
+    ///
+    /// TypeScript reports an error for an unknown compiler option, but the editors often don't show it,
+    /// and a misspelled option, such as `strictNullCheck`, silently keeps its default value.
+    /// The names of the options are case-sensitive: when the name of an option has another case,
+    /// such as `strictnullchecks`, the code action fixes its case.
+    /// The code action is unsafe, because the option starts to apply.
+    ///
+    /// The rule only applies to the configuration files of TypeScript: `tsconfig.json`, `tsconfig.*.json` and `jsconfig.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "strictNullCheck": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "esmoduleinterop": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "compilerOptions": {
+    ///         "strictNullChecks": true,
+    ///         "esModuleInterop": true
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub NoUnknownTsconfigOptions {
+        version: "next",
+        name: "noUnknownTsconfigOptions",
+        language: "json",
+        domains: &[RuleDomain::Tsconfig],
+        recommended: false,
+        fix_kind: FixKind::Unsafe,
+    }
+}
+
+pub struct UnknownOption {
+    name: JsonMemberName,
+    /// The option with the same name in another case
+    suggestion: Option<&'static str>,
+}
+
+impl Rule for NoUnknownTsconfigOptions {
+    type Query = Ast<JsonRoot>;
+    type State = UnknownOption;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig(ctx.file_path()) {
+            return Vec::new();
+        }
+        compiler_option_members(ctx.query())
+            .into_iter()
+            .filter(|(name, _)| !is_compiler_option(name))
+            .filter_map(|(name, member)| {
+                Some(UnknownOption {
+                    name: member.name().ok()?,
+                    suggestion: compiler_option_ignoring_case(&name),
+                })
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.name.inner_string_text().ok()?;
+        let name = name.text();
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.name.range(),
+            markup! {
+                "The compiler option "<Emphasis>{name}</Emphasis>" doesn't exist."
+            },
+        );
+        let diagnostic = if let Some(suggestion) = state.suggestion {
+            diagnostic.note(markup! {
+                "The names of the compiler options are case-sensitive. Did you mean "<Emphasis>{suggestion}</Emphasis>"?"
+            })
+        } else {
+            diagnostic.note(markup! {
+                "TypeScript ignores this option, check its name in the "<Hyperlink href="https://www.typescriptlang.org/tsconfig/">"reference of the compiler options"</Hyperlink>"."
+            })
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let suggestion = state.suggestion?;
+        let mut mutation = ctx.root().begin();
+        mutation.replace_token(
+            state.name.value_token().ok()?,
+            make::json_string_literal(suggestion),
+        );
+        Some(JsonRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::MaybeIncorrect,
+            markup! { "Use "<Emphasis>{suggestion}</Emphasis>" instead." }.to_owned(),
+            mutation,
+        ))
+    }
+}
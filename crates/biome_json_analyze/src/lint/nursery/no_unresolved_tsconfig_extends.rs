@@ -0,0 +1,89 @@
+use crate::tsconfig::{extends_values, is_tsconfig, TsconfigExtends};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain};
+use biome_console::markup;
+use biome_json_syntax::{JsonRoot, JsonStringValue};
+use biome_rowan::AstNode;
+use std::sync::Arc;
+
+declare_rule! {
+    /// Disallow the configuration files extended by `extends` that don't exist.
+    ///
+    /// TypeScript reports an error when it doesn't find a configuration file of `extends`,
+    /// but the editors and the other tools that read `tsconfig.json` often ignore it.
+    ///
+    /// A relative path is resolved from the folder of the configuration file, with the extension `.json`
+    /// if the path doesn't exist. A package, such as `@tsconfig/node20/tsconfig.json`, is looked for
+    /// in the `node_modules` folders of the folder of the configuration file and its ancestors.
+    ///
+    /// The rule only applies to the configuration files of TypeScript: `tsconfig.json`, `tsconfig.*.json` and `jsconfig.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "extends": "./tsconfig.missing.json"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "extends": "./tsconfig.base.json"
+    /// }
+    /// ```
+    ///
+    pub NoUnresolvedTsconfigExtends {
+        version: "next",
+        name: "noUnresolvedTsconfigExtends",
+        language: "json",
+        domains: &[RuleDomain::Tsconfig],
+        recommended: false,
+    }
+}
+
+impl Rule for NoUnresolvedTsconfigExtends {
+    type Query = Ast<JsonRoot>;
+    type State = JsonStringValue;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_tsconfig(ctx.file_path()) {
+            return Vec::new();
+        }
+        let Some(tsconfig_extends) = ctx.get_service::<Arc<TsconfigExtends>>() else {
+            return Vec::new();
+        };
+        extends_values(ctx.query())
+            .into_iter()
+            .filter(|value| {
+                value
+                    .inner_string_text()
+                    .is_ok_and(|specifier| tsconfig_extends.is_unresolved(&specifier))
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let specifier = state.inner_string_text().ok()?;
+        let specifier = specifier.text();
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range(),
+                markup! {
+                    "The configuration file "<Emphasis>{specifier}</Emphasis>" doesn't exist."
+                },
+            )
+            .note(markup! {
+                "TypeScript can't extend this configuration file, so it reports an error and ignores the options it contains."
+            })
+            .note(markup! {
+                "Fix the path, or install the package that contains the configuration file."
+            }),
+        )
+    }
+}
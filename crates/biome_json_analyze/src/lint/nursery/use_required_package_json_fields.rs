@@ -0,0 +1,135 @@
+use crate::utils::{find_member, is_package_json, root_object};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_json_syntax::{JsonRoot, TextRange};
+use serde::{Deserialize, Serialize};
+
+declare_rule! {
+    /// Require the fields of `package.json` that describe how a package can be used.
+    ///
+    /// By default, the rule requires the fields `license` and `repository`,
+    /// which tell the users of the package under which terms they can use it, and where its code is.
+    /// The option `fields` sets the required fields, for example to require the field `engines`,
+    /// or to require no fields in the private packages with an override.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "name": "my-package",
+    ///     "version": "1.0.0"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "name": "my-package",
+    ///     "version": "1.0.0",
+    ///     "repository": "github:user/my-package",
+    ///     "license": "MIT"
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `fields`
+    ///
+    /// The fields that each `package.json` must have. `["license", "repository"]` by default.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "fields": ["license", "repository", "engines"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseRequiredPackageJsonFields {
+        version: "next",
+        name: "useRequiredPackageJsonFields",
+        language: "json",
+        domains: &[RuleDomain::PackageJson],
+        recommended: false,
+    }
+}
+
+/// Options for the rule `useRequiredPackageJsonFields`.
+#[derive(Clone, Debug, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseRequiredPackageJsonFieldsOptions {
+    /// The fields that each `package.json` must have.
+    pub fields: Vec<String>,
+}
+
+impl Default for UseRequiredPackageJsonFieldsOptions {
+    fn default() -> Self {
+        Self {
+            fields: vec!["license".to_string(), "repository".to_string()],
+        }
+    }
+}
+
+pub struct MissingField {
+    /// The range of the opening brace of the object of `package.json`
+    range: TextRange,
+    field: String,
+}
+
+impl Rule for UseRequiredPackageJsonFields {
+    type Query = Ast<JsonRoot>;
+    type State = MissingField;
+    type Signals = Vec<Self::State>;
+    type Options = UseRequiredPackageJsonFieldsOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return Vec::new();
+        }
+        let Some(object) = root_object(ctx.query()) else {
+            return Vec::new();
+        };
+        let Ok(l_curly_token) = object.l_curly_token() else {
+            return Vec::new();
+        };
+        ctx.options()
+            .fields
+            .iter()
+            .filter(|field| find_member(&object, field).is_none())
+            .map(|field| MissingField {
+                range: l_curly_token.text_trimmed_range(),
+                field: field.clone(),
+            })
+            .collect()
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let field = &state.field;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.range,
+            markup! {
+                "The field "<Emphasis>{field}</Emphasis>" is missing from "<Emphasis>"package.json"</Emphasis>"."
+            },
+        );
+        let diagnostic = match field.as_str() {
+            "license" => diagnostic.note(markup! {
+                "The license tells the users of the package under which terms they can use it."
+            }),
+            "repository" => diagnostic.note(markup! {
+                "The repository tells the users of the package where its code is."
+            }),
+            _ => diagnostic.note(markup! {
+                "The configuration of the rule requires this field."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
@@ -0,0 +1,277 @@
+use crate::utils::member_name;
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_deserialize_macros::Deserializable;
+use biome_diagnostics::Applicability;
+use biome_json_syntax::{JsonMember, JsonMemberName, JsonObjectValue};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+declare_rule! {
+    /// Enforce the order of the keys of the objects.
+    ///
+    /// The keys of an object are easier to find, and the changes of the object are easier to merge,
+    /// when the keys are sorted.
+    ///
+    /// By default, the keys are sorted in lexicographic order.
+    /// The option `sortOrder` sorts the keys in natural order instead, which compares the numbers of the keys by their values,
+    /// and the option `priorityKeys` places some keys before the other keys.
+    ///
+    /// The options can be set for some files only with `overrides`,
+    /// for example to place `name` and `version` first in the files named `package.json`.
+    ///
+    /// The keys aren't moved by the code action when they have comments.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,expect_diagnostic
+    /// {
+    ///     "vite": "^5.0.0",
+    ///     "react": "^18.0.0"
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json
+    /// {
+    ///     "react": "^18.0.0",
+    ///     "vite": "^5.0.0"
+    /// }
+    /// ```
+    ///
+    /// ## Options
+    ///
+    /// ### `sortOrder`
+    ///
+    /// The order of the keys:
+    ///
+    /// - `lexicographic`: the keys are compared character by character, so `item10` is placed before `item2` (default);
+    /// - `natural`: the numbers of the keys are compared by their values, so `item2` is placed before `item10`.
+    ///
+    /// ### `priorityKeys`
+    ///
+    /// The keys that are placed before the other keys, in the order of the list.
+    ///
+    /// ```json
+    /// {
+    ///     "options": {
+    ///         "sortOrder": "natural",
+    ///         "priorityKeys": ["name", "version"]
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseSortedKeys {
+        version: "next",
+        name: "useSortedKeys",
+        language: "json",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+/// Options for the rule `useSortedKeys`.
+#[derive(Clone, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UseSortedKeysOptions {
+    /// The order of the keys.
+    pub sort_order: KeySortOrder,
+    /// The keys that are placed before the other keys, in the order of the list.
+    pub priority_keys: Vec<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Deserializable, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum KeySortOrder {
+    /// The keys are compared character by character.
+    #[default]
+    Lexicographic,
+    /// The numbers of the keys are compared by their values.
+    Natural,
+}
+
+impl KeySortOrder {
+    fn compare(self, a: &str, b: &str) -> Ordering {
+        match self {
+            Self::Lexicographic => a.cmp(b),
+            Self::Natural => natural_cmp(a, b),
+        }
+    }
+}
+
+pub struct UnsortedKeys {
+    /// The indexes of the members in their sorted order
+    order: Vec<usize>,
+    /// The name of the first member that isn't at its place
+    misplaced: JsonMemberName,
+    /// Whether the misplaced member is one of the priority keys
+    is_priority_key: bool,
+    /// The name of the member that should come after the misplaced member
+    before: String,
+}
+
+impl Rule for UseSortedKeys {
+    type Query = Ast<JsonObjectValue>;
+    type State = UnsortedKeys;
+    type Signals = Option<Self::State>;
+    type Options = UseSortedKeysOptions;
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let options = ctx.options();
+        let members = object_members(ctx.query()).ok()?;
+        let names = members
+            .iter()
+            .map(member_name)
+            .collect::<Option<Vec<_>>>()?;
+        let priority = |name: &str| {
+            options
+                .priority_keys
+                .iter()
+                .position(|key| key == name)
+                .unwrap_or(options.priority_keys.len())
+        };
+        let mut order = (0..members.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            priority(&names[a])
+                .cmp(&priority(&names[b]))
+                .then_with(|| options.sort_order.compare(&names[a], &names[b]))
+        });
+        let index = order
+            .iter()
+            .enumerate()
+            .position(|(index, sorted_index)| index != *sorted_index)?;
+        let misplaced = members[order[index]].name().ok()?;
+        Some(UnsortedKeys {
+            is_priority_key: priority(&names[order[index]]) < options.priority_keys.len(),
+            before: names[index].clone(),
+            misplaced,
+            order,
+        })
+    }
+
+    fn diagnostic(ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.misplaced.inner_string_text().ok()?;
+        let name = name.text();
+        let before = &state.before;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.misplaced.range(),
+            markup! {
+                "The key "<Emphasis>{name}</Emphasis>" should be placed before "<Emphasis>{before}</Emphasis>"."
+            },
+        );
+        let diagnostic = if state.is_priority_key {
+            diagnostic.note(markup! {
+                "The priority keys are placed before the other keys, in the order of the option "<Emphasis>"priorityKeys"</Emphasis>"."
+            })
+        } else {
+            match ctx.options().sort_order {
+                KeySortOrder::Lexicographic => diagnostic.note(markup! {
+                    "The keys are sorted in lexicographic order."
+                }),
+                KeySortOrder::Natural => diagnostic.note(markup! {
+                    "The keys are sorted in natural order, which compares the numbers by their values."
+                }),
+            }
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let members = object_members(ctx.query()).ok()?;
+        // Moving a member with comments could attach the comments to another member
+        if members
+            .iter()
+            .any(|member| member.syntax().has_comments_direct())
+        {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        for (index, sorted_index) in state.order.iter().enumerate() {
+            if index != *sorted_index {
+                mutation.replace_node(members[index].clone(), members[*sorted_index].clone());
+            }
+        }
+        Some(JsonRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Sort the keys." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn object_members(object: &JsonObjectValue) -> SyntaxResult<Vec<JsonMember>> {
+    object.json_member_list().iter().collect()
+}
+
+/// Compares `a` and `b` in natural order: the sequences of digits are compared by their values,
+/// and the other characters are compared one by one.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let a_chunks = chunks(a);
+    let b_chunks = chunks(b);
+    for (a_chunk, b_chunk) in a_chunks.iter().zip(&b_chunks) {
+        let is_number = |chunk: &str| chunk.starts_with(|c: char| c.is_ascii_digit());
+        let ordering = if is_number(a_chunk) && is_number(b_chunk) {
+            let a_number = a_chunk.trim_start_matches('0');
+            let b_number = b_chunk.trim_start_matches('0');
+            a_number
+                .len()
+                .cmp(&b_number.len())
+                .then_with(|| a_number.cmp(b_number))
+        } else {
+            a_chunk.cmp(b_chunk)
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+    a_chunks
+        .len()
+        .cmp(&b_chunks.len())
+        // The numbers with leading zeros, such as `01` and `1`, are equal
+        .then_with(|| a.cmp(b))
+}
+
+/// Splits `text` in sequences of digits and sequences of other characters.
+fn chunks(text: &str) -> Vec<&str> {
+    let bytes = text.as_bytes();
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    for index in 1..bytes.len() {
+        if bytes[index].is_ascii_digit() != bytes[index - 1].is_ascii_digit() {
+            chunks.push(&text[start..index]);
+            start = index;
+        }
+    }
+    if start < text.len() {
+        chunks.push(&text[start..]);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp("item2", "item10"), Ordering::Less);
+        assert_eq!(natural_cmp("item10", "item9"), Ordering::Greater);
+        assert_eq!(natural_cmp("item", "item1"), Ordering::Less);
+        assert_eq!(natural_cmp("a1b2", "a1b10"), Ordering::Less);
+        assert_eq!(natural_cmp("1", "01"), Ordering::Greater);
+        assert_eq!(natural_cmp("b", "a10"), Ordering::Greater);
+        assert_eq!(natural_cmp("é2", "é10"), Ordering::Less);
+        assert_eq!(natural_cmp("same", "same"), Ordering::Equal);
+    }
+}
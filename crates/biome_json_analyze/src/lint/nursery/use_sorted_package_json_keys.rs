@@ -0,0 +1,191 @@
+use crate::package_json::{sorted_dependency_order, sorted_field_order};
+use crate::utils::{is_package_json, member_name, PACKAGE_JSON_DEPENDENCY_FIELDS};
+use crate::JsonRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+    RuleDomain,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_json_syntax::{JsonMember, JsonMemberName, JsonObjectValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutationExt, SyntaxResult};
+
+declare_rule! {
+    /// Enforce a conventional order of the fields of `package.json`, and sort the dependencies.
+    ///
+    /// The top-level fields follow the order used by most of the packages:
+    /// the fields that describe the package, such as `name` and `version`, come first,
+    /// followed by the entry points, the scripts, the dependencies and the configuration of the tools.
+    /// The fields that aren't known by the rule are placed after the known fields, in their original order.
+    ///
+    /// The dependencies of `dependencies`, `devDependencies`, `optionalDependencies` and `peerDependencies`
+    /// are sorted by name, as `npm` does when it installs a dependency.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    /// The fields aren't moved by the code action when they have comments.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "version": "1.0.0",
+    ///     "name": "my-package"
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "dependencies": {
+    ///         "react": "^18.0.0",
+    ///         "@biomejs/biome": "^1.9.0"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "name": "my-package",
+    ///     "version": "1.0.0",
+    ///     "dependencies": {
+    ///         "@biomejs/biome": "^1.9.0",
+    ///         "react": "^18.0.0"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseSortedPackageJsonKeys {
+        version: "next",
+        name: "useSortedPackageJsonKeys",
+        language: "json",
+        domains: &[RuleDomain::PackageJson],
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortedObjectKind {
+    /// The object at the root of `package.json`
+    Fields,
+    /// The object of one of the fields that list the dependencies
+    Dependencies,
+}
+
+pub struct UnsortedKeys {
+    kind: SortedObjectKind,
+    /// The indexes of the members in their sorted order
+    order: Vec<usize>,
+    /// The name of the first member that isn't at its place
+    misplaced: JsonMemberName,
+    /// The name of the member that should come after the misplaced member
+    before: String,
+}
+
+impl Rule for UseSortedPackageJsonKeys {
+    type Query = Ast<JsonObjectValue>;
+    type State = UnsortedKeys;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        if !is_package_json(ctx.file_path()) {
+            return None;
+        }
+        let object = ctx.query();
+        let kind = sorted_object_kind(object)?;
+        let members = object_members(object).ok()?;
+        let names = members
+            .iter()
+            .map(member_name)
+            .collect::<Option<Vec<_>>>()?;
+        let order = match kind {
+            SortedObjectKind::Fields => sorted_field_order(&names),
+            SortedObjectKind::Dependencies => sorted_dependency_order(&names),
+        };
+        let index = order
+            .iter()
+            .enumerate()
+            .position(|(index, sorted_index)| index != *sorted_index)?;
+        let misplaced = members[order[index]].name().ok()?;
+        Some(UnsortedKeys {
+            kind,
+            before: names[index].clone(),
+            misplaced,
+            order,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = state.misplaced.inner_string_text().ok()?;
+        let name = name.text();
+        let before = &state.before;
+        let diagnostic = RuleDiagnostic::new(
+            rule_category!(),
+            state.misplaced.range(),
+            markup! {
+                "The key "<Emphasis>{name}</Emphasis>" should be placed before "<Emphasis>{before}</Emphasis>"."
+            },
+        );
+        let diagnostic = match state.kind {
+            SortedObjectKind::Fields => diagnostic.note(markup! {
+                "The fields of "<Emphasis>"package.json"</Emphasis>" follow a conventional order, which makes the manifests of the packages easier to read."
+            }),
+            SortedObjectKind::Dependencies => diagnostic.note(markup! {
+                "The dependencies are sorted by name, as "<Emphasis>"npm"</Emphasis>" does when it installs a dependency."
+            }),
+        };
+        Some(diagnostic)
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<JsonRuleAction> {
+        let members = object_members(ctx.query()).ok()?;
+        // Moving a member with comments could attach the comments to another member
+        if members
+            .iter()
+            .any(|member| member.syntax().has_comments_direct())
+        {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        for (index, sorted_index) in state.order.iter().enumerate() {
+            if index != *sorted_index {
+                mutation.replace_node(members[index].clone(), members[*sorted_index].clone());
+            }
+        }
+        let message = match state.kind {
+            SortedObjectKind::Fields => markup! { "Sort the fields." },
+            SortedObjectKind::Dependencies => markup! { "Sort the dependencies." },
+        };
+        Some(JsonRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            message.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the kind of `object` if its keys must be sorted.
+fn sorted_object_kind(object: &JsonObjectValue) -> Option<SortedObjectKind> {
+    let parent = object.syntax().parent()?;
+    if JsonRoot::can_cast(parent.kind()) {
+        return Some(SortedObjectKind::Fields);
+    }
+    let member = JsonMember::cast(parent)?;
+    let is_top_level_member = member
+        .syntax()
+        .grand_parent()
+        .and_then(|object| object.parent())
+        .is_some_and(|root| JsonRoot::can_cast(root.kind()));
+    let field = member_name(&member)?;
+    (is_top_level_member && PACKAGE_JSON_DEPENDENCY_FIELDS.contains(&field.as_str()))
+        .then_some(SortedObjectKind::Dependencies)
+}
+
+fn object_members(object: &JsonObjectValue) -> SyntaxResult<Vec<JsonMember>> {
+    object.json_member_list().iter().collect()
+}
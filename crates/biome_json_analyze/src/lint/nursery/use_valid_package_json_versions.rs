@@ -0,0 +1,375 @@
+use crate::utils::{
+    find_member, is_package_json, member_name, root_object, PACKAGE_JSON_DEPENDENCY_FIELDS,
+};
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic, RuleDomain};
+use biome_console::markup;
+use biome_json_syntax::{AnyJsonValue, JsonRoot, TextRange};
+use biome_rowan::{AstNode, AstSeparatedList};
+
+declare_rule! {
+    /// Enforce valid semantic versions and version ranges in `package.json`.
+    ///
+    /// The field `version` must be a [semantic version](https://semver.org/), such as `1.0.0` or `2.0.0-beta.1`.
+    ///
+    /// The dependencies of `dependencies`, `devDependencies`, `optionalDependencies` and `peerDependencies`
+    /// must be a version range supported by the package managers, such as `^1.2.0`, `~1.2.3`, `>=1.0.0 <2.0.0`,
+    /// `1.x` or `1.0.0 - 2.0.0`.
+    /// The rule also accepts the dist-tags, such as `latest`, the paths and the URLs,
+    /// the GitHub repositories, such as `user/repo`,
+    /// and the protocols of the package managers, such as `workspace:*` and `npm:other-package@^1.0.0`.
+    ///
+    /// The rule only applies to the files named `package.json`.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "version": "1.0"
+    /// }
+    /// ```
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "dependencies": {
+    ///         "react": "^18.0.0.1"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```json,ignore
+    /// {
+    ///     "version": "1.0.0",
+    ///     "dependencies": {
+    ///         "react": "^18.0.0",
+    ///         "lodash": "4.x",
+    ///         "my-utils": "workspace:*"
+    ///     }
+    /// }
+    /// ```
+    ///
+    pub UseValidPackageJsonVersions {
+        version: "next",
+        name: "useValidPackageJsonVersions",
+        language: "json",
+        domains: &[RuleDomain::PackageJson],
+        recommended: false,
+    }
+}
+
+pub enum InvalidVersion {
+    /// The field `version` of the package
+    Version(TextRange),
+    /// The version range of a dependency
+    Dependency(TextRange),
+}
+
+impl Rule for UseValidPackageJsonVersions {
+    type Query = Ast<JsonRoot>;
+    type State = InvalidVersion;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut signals = Vec::new();
+        if !is_package_json(ctx.file_path()) {
+            return signals;
+        }
+        let Some(object) = root_object(ctx.query()) else {
+            return signals;
+        };
+        if let Some(value) = find_member(&object, "version").and_then(|member| member.value().ok())
+        {
+            if !string_value(&value).is_some_and(|version| is_valid_version(&version)) {
+                signals.push(InvalidVersion::Version(value.range()));
+            }
+        }
+        for field in PACKAGE_JSON_DEPENDENCY_FIELDS {
+            let Some(AnyJsonValue::JsonObjectValue(dependencies)) =
+                find_member(&object, field).and_then(|member| member.value().ok())
+            else {
+                continue;
+            };
+            for dependency in dependencies.json_member_list().iter().flatten() {
+                if member_name(&dependency).is_none() {
+                    continue;
+                }
+                let Ok(value) = dependency.value() else {
+                    continue;
+                };
+                if !string_value(&value).is_some_and(|specifier| is_valid_specifier(&specifier)) {
+                    signals.push(InvalidVersion::Dependency(value.range()));
+                }
+            }
+        }
+        signals
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let diagnostic = match state {
+            InvalidVersion::Version(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The version of the package isn't a valid semantic version."
+                },
+            )
+            .note(markup! {
+                "A semantic version has the form "<Emphasis>"MAJOR.MINOR.PATCH"</Emphasis>", such as "<Emphasis>"1.0.0"</Emphasis>"."
+            }),
+            InvalidVersion::Dependency(range) => RuleDiagnostic::new(
+                rule_category!(),
+                range,
+                markup! {
+                    "The version range of this dependency isn't valid."
+                },
+            )
+            .note(markup! {
+                "Use a version range, such as "<Emphasis>"^1.2.0"</Emphasis>", a dist-tag, such as "<Emphasis>"latest"</Emphasis>", a path or a URL."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+fn string_value(value: &AnyJsonValue) -> Option<String> {
+    let value = value.as_json_string_value()?;
+    Some(value.inner_string_text().ok()?.to_string())
+}
+
+/// Returns `true` if `version` is a semantic version, such as `1.2.3-beta.1+build`.
+fn is_valid_version(version: &str) -> bool {
+    let (version, build) = match version.split_once('+') {
+        Some((version, build)) => (version, Some(build)),
+        None => (version, None),
+    };
+    let (version, prerelease) = match version.split_once('-') {
+        Some((version, prerelease)) => (version, Some(prerelease)),
+        None => (version, None),
+    };
+    let mut parts = version.split('.');
+    let is_core_valid =
+        (0..3).all(|_| parts.next().is_some_and(is_numeric_identifier)) && parts.next().is_none();
+    is_core_valid
+        && prerelease.map_or(true, is_valid_prerelease)
+        && build.map_or(true, is_valid_build)
+}
+
+/// Returns `true` if `specifier` is a valid version specifier of a dependency.
+fn is_valid_specifier(specifier: &str) -> bool {
+    const PROTOCOLS: &[&str] = &[
+        "bitbucket:",
+        "catalog:",
+        "file:",
+        "gist:",
+        "git:",
+        "git+",
+        "github:",
+        "gitlab:",
+        "http:",
+        "https:",
+        "jsr:",
+        "link:",
+        "patch:",
+        "portal:",
+        "workspace:",
+    ];
+    let specifier = specifier.trim();
+    if let Some(alias) = specifier.strip_prefix("npm:") {
+        // The alias of a package, such as `npm:other-package@^1.0.0`
+        return match alias.get(1..).and_then(|alias| alias.rsplit_once('@')) {
+            Some((_, range)) => is_valid_range(range) || is_dist_tag(range),
+            None => !alias.is_empty(),
+        };
+    }
+    if PROTOCOLS
+        .iter()
+        .any(|protocol| specifier.starts_with(protocol))
+    {
+        return true;
+    }
+    // The paths and the GitHub repositories, such as `user/repo`. A version range never contains a slash.
+    if specifier.contains('/') {
+        return true;
+    }
+    is_valid_range(specifier) || is_dist_tag(specifier)
+}
+
+/// Returns `true` if `specifier` is a dist-tag, such as `latest` or `next`.
+///
+/// The specifiers that start like a version range, such as `1.2.3.4` or `^2`, aren't dist-tags.
+fn is_dist_tag(specifier: &str) -> bool {
+    let mut chars = specifier.chars();
+    let Some(first) = chars.next() else {
+        return false;
+    };
+    let starts_like_range = first.is_ascii_digit()
+        || matches!(first, '^' | '~' | '<' | '>' | '=' | '*')
+        || (matches!(first, 'v' | 'V' | 'x' | 'X')
+            && chars.next().map_or(true, |c| !c.is_ascii_alphabetic()));
+    !starts_like_range
+        && specifier
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_'))
+}
+
+/// Returns `true` if `range` is a version range, such as `^1.2.0 || >=2.0.0 <3.0.0`.
+fn is_valid_range(range: &str) -> bool {
+    range.split("||").all(|range| {
+        let comparators = comparators(range);
+        match comparators.as_slice() {
+            [from, hyphen, to] if hyphen == "-" => is_valid_partial(from) && is_valid_partial(to),
+            comparators => comparators
+                .iter()
+                .all(|comparator| is_valid_comparator(comparator)),
+        }
+    })
+}
+
+/// Splits a range into its comparators, joining the operators separated
+/// from their version by spaces, such as `>= 1.2.0`.
+fn comparators(range: &str) -> Vec<String> {
+    let mut comparators = Vec::new();
+    let mut operator = String::new();
+    for word in range.split_whitespace() {
+        operator.push_str(word);
+        if !is_operator(word) {
+            comparators.push(std::mem::take(&mut operator));
+        }
+    }
+    if !operator.is_empty() {
+        comparators.push(operator);
+    }
+    comparators
+}
+
+fn is_operator(word: &str) -> bool {
+    matches!(word, "<" | "<=" | ">" | ">=" | "=" | "~" | "~>" | "^")
+}
+
+fn is_valid_comparator(comparator: &str) -> bool {
+    let partial = ["<=", ">=", "~>", "<", ">", "=", "~", "^"]
+        .iter()
+        .find_map(|operator| comparator.strip_prefix(operator))
+        .unwrap_or(comparator);
+    is_valid_partial(partial.trim_start())
+}
+
+/// Returns `true` if `partial` is a version where the missing parts are wildcards, such as `1.x` or `2`.
+fn is_valid_partial(partial: &str) -> bool {
+    let partial = partial.trim_start_matches(['v', '=']);
+    let (partial, build) = match partial.split_once('+') {
+        Some((partial, build)) => (partial, Some(build)),
+        None => (partial, None),
+    };
+    let (partial, prerelease) = match partial.split_once('-') {
+        Some((partial, prerelease)) => (partial, Some(prerelease)),
+        None => (partial, None),
+    };
+    let parts = partial.split('.').collect::<Vec<_>>();
+    let are_parts_valid = parts.len() <= 3
+        && parts
+            .iter()
+            .all(|part| matches!(*part, "x" | "X" | "*") || is_numeric_identifier(part));
+    // The prerelease and the build only follow a complete version
+    let is_complete = parts.len() == 3;
+    are_parts_valid
+        && prerelease.map_or(true, |prerelease| {
+            is_complete && is_valid_prerelease(prerelease)
+        })
+        && build.map_or(true, |build| is_complete && is_valid_build(build))
+}
+
+/// Returns `true` if `identifier` is a number without leading zeros.
+fn is_numeric_identifier(identifier: &str) -> bool {
+    !identifier.is_empty()
+        && identifier.bytes().all(|byte| byte.is_ascii_digit())
+        && (identifier == "0" || !identifier.starts_with('0'))
+}
+
+fn is_valid_prerelease(prerelease: &str) -> bool {
+    prerelease.split('.').all(|identifier| {
+        if identifier.bytes().all(|byte| byte.is_ascii_digit()) {
+            is_numeric_identifier(identifier)
+        } else {
+            identifier
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+        }
+    })
+}
+
+fn is_valid_build(build: &str) -> bool {
+    build.split('.').all(|identifier| {
+        !identifier.is_empty()
+            && identifier
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_version() {
+        assert!(is_valid_version("1.0.0"));
+        assert!(is_valid_version("0.10.2-beta.1+build.5"));
+        assert!(!is_valid_version("1.0"));
+        assert!(!is_valid_version("v1.0.0"));
+        assert!(!is_valid_version("01.0.0"));
+        assert!(!is_valid_version("1.0.0-"));
+    }
+
+    #[test]
+    fn test_is_valid_range() {
+        for range in [
+            "",
+            "*",
+            "1",
+            "1.x",
+            "^1.2.3",
+            "~1.2",
+            ">=1.0.0 <2.0.0",
+            ">= 1.0.0",
+            "1.0.0 - 2.0.0",
+            "^1.0.0 || ^2.0.0",
+            "^2.0.0-rc.1",
+            "v1.2.3",
+        ] {
+            assert!(is_valid_range(range), "{range}");
+        }
+        for range in ["^1.2.3.4", "1.2-beta", ">=", "1 - ", "^01.0.0", "latest"] {
+            assert!(!is_valid_range(range), "{range}");
+        }
+    }
+
+    #[test]
+    fn test_is_valid_specifier() {
+        for specifier in [
+            "latest",
+            "next",
+            "workspace:^",
+            "npm:other-package@^1.0.0",
+            "npm:@scope/package@latest",
+            "file:../package",
+            "./package",
+            "user/repo#main",
+            "https://example.com/package.tgz",
+        ] {
+            assert!(is_valid_specifier(specifier), "{specifier}");
+        }
+        for specifier in [
+            "^1.2.3.4",
+            "1.2.3.4",
+            "npm:other-package@^1.2.3.4",
+            "lat est",
+        ] {
+            assert!(!is_valid_specifier(specifier), "{specifier}");
+        }
+    }
+}
@@ -2,5 +2,16 @@
 
 use crate::lint;
 
+pub type NoConflictingTsconfigOptions = < lint :: nursery :: no_conflicting_tsconfig_options :: NoConflictingTsconfigOptions as biome_analyze :: Rule > :: Options ;
+pub type NoDeprecatedTsconfigOptions = < lint :: nursery :: no_deprecated_tsconfig_options :: NoDeprecatedTsconfigOptions as biome_analyze :: Rule > :: Options ;
 pub type NoDuplicateJsonKeys =
     <lint::nursery::no_duplicate_json_keys::NoDuplicateJsonKeys as biome_analyze::Rule>::Options;
+pub type NoDuplicatePackageJsonDependencies = < lint :: nursery :: no_duplicate_package_json_dependencies :: NoDuplicatePackageJsonDependencies as biome_analyze :: Rule > :: Options ;
+pub type NoJsonSchemaViolations = < lint :: nursery :: no_json_schema_violations :: NoJsonSchemaViolations as biome_analyze :: Rule > :: Options ;
+pub type NoUnknownTsconfigOptions = < lint :: nursery :: no_unknown_tsconfig_options :: NoUnknownTsconfigOptions as biome_analyze :: Rule > :: Options ;
+pub type NoUnresolvedTsconfigExtends = < lint :: nursery :: no_unresolved_tsconfig_extends :: NoUnresolvedTsconfigExtends as biome_analyze :: Rule > :: Options ;
+pub type UseRequiredPackageJsonFields = < lint :: nursery :: use_required_package_json_fields :: UseRequiredPackageJsonFields as biome_analyze :: Rule > :: Options ;
+pub type UseSortedKeys =
+    <lint::nursery::use_sorted_keys::UseSortedKeys as biome_analyze::Rule>::Options;
+pub type UseSortedPackageJsonKeys = < lint :: nursery :: use_sorted_package_json_keys :: UseSortedPackageJsonKeys as biome_analyze :: Rule > :: Options ;
+pub type UseValidPackageJsonVersions = < lint :: nursery :: use_valid_package_json_versions :: UseValidPackageJsonVersions as biome_analyze :: Rule > :: Options ;
@@ -0,0 +1,209 @@
+//! Knowledge of the conventional layout of `package.json`.
+//!
+//! The order of the fields is the one used by most of the packages, and the dependencies are sorted
+//! by name, as `npm` does when it installs a dependency.
+
+use crate::utils::{member_name, root_object, PACKAGE_JSON_DEPENDENCY_FIELDS};
+use biome_json_syntax::{AnyJsonValue, JsonLanguage, JsonMember, JsonObjectValue, JsonRoot};
+use biome_rowan::{AstNode, AstSeparatedList, BatchMutation, BatchMutationExt, SyntaxResult};
+
+/// The conventional order of the top-level fields of `package.json`
+pub const FIELD_ORDER: &[&str] = &[
+    "$schema",
+    "name",
+    "displayName",
+    "version",
+    "private",
+    "description",
+    "categories",
+    "keywords",
+    "homepage",
+    "bugs",
+    "repository",
+    "funding",
+    "license",
+    "author",
+    "maintainers",
+    "contributors",
+    "publisher",
+    "sideEffects",
+    "type",
+    "imports",
+    "exports",
+    "main",
+    "module",
+    "browser",
+    "types",
+    "typesVersions",
+    "typings",
+    "bin",
+    "man",
+    "directories",
+    "files",
+    "workspaces",
+    "scripts",
+    "config",
+    "resolutions",
+    "dependencies",
+    "devDependencies",
+    "dependenciesMeta",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "bundleDependencies",
+    "bundledDependencies",
+    "overrides",
+    "packageManager",
+    "engines",
+    "os",
+    "cpu",
+    "publishConfig",
+];
+
+/// Returns the indexes of the top-level fields named `names` in their conventional order.
+///
+/// The fields that aren't in [FIELD_ORDER] are placed after the known fields, in their original order.
+pub fn sorted_field_order(names: &[String]) -> Vec<usize> {
+    let mut order = (0..names.len()).collect::<Vec<_>>();
+    order.sort_by_key(|&index| {
+        FIELD_ORDER
+            .iter()
+            .position(|field| *field == names[index])
+            .unwrap_or(FIELD_ORDER.len())
+    });
+    order
+}
+
+/// Returns the indexes of the dependencies named `names` sorted by name
+pub fn sorted_dependency_order(names: &[String]) -> Vec<usize> {
+    let mut order = (0..names.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| names[a].cmp(&names[b]));
+    order
+}
+
+/// Sorts the top-level fields of a `package.json` document, and the dependencies
+/// of the fields listed by [PACKAGE_JSON_DEPENDENCY_FIELDS].
+///
+/// The members of an object aren't moved when one of them has comments,
+/// because the comments could be attached to another member.
+pub fn sort_package_json(root: JsonRoot) -> JsonRoot {
+    let Some(object) = root_object(&root) else {
+        return root;
+    };
+
+    // The dependencies are sorted first: the fields that contain them are moved afterwards
+    let mut mutation = root.clone().begin();
+    let mut has_changes = false;
+    for member in object.json_member_list().iter().flatten() {
+        let is_dependency_field = member_name(&member)
+            .is_some_and(|name| PACKAGE_JSON_DEPENDENCY_FIELDS.contains(&name.as_str()));
+        if !is_dependency_field {
+            continue;
+        }
+        if let Ok(AnyJsonValue::JsonObjectValue(dependencies)) = member.value() {
+            has_changes |= reorder_members(&mut mutation, &dependencies, sorted_dependency_order);
+        }
+    }
+    let root = if has_changes {
+        JsonRoot::unwrap_cast(mutation.commit())
+    } else {
+        root
+    };
+
+    let Some(object) = root_object(&root) else {
+        return root;
+    };
+    let mut mutation = root.clone().begin();
+    if reorder_members(&mut mutation, &object, sorted_field_order) {
+        JsonRoot::unwrap_cast(mutation.commit())
+    } else {
+        root
+    }
+}
+
+/// Moves the members of `object` to the places given by `order`, returns `false` if they're already sorted
+fn reorder_members(
+    mutation: &mut BatchMutation<JsonLanguage>,
+    object: &JsonObjectValue,
+    order: fn(&[String]) -> Vec<usize>,
+) -> bool {
+    let Ok(members) = object
+        .json_member_list()
+        .iter()
+        .collect::<SyntaxResult<Vec<JsonMember>>>()
+    else {
+        return false;
+    };
+    if members
+        .iter()
+        .any(|member| member.syntax().has_comments_direct())
+    {
+        return false;
+    }
+    let Some(names) = members.iter().map(member_name).collect::<Option<Vec<_>>>() else {
+        return false;
+    };
+
+    let mut has_changes = false;
+    for (index, sorted_index) in order(&names).into_iter().enumerate() {
+        if index != sorted_index {
+            mutation.replace_node(members[index].clone(), members[sorted_index].clone());
+            has_changes = true;
+        }
+    }
+    has_changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::find_member;
+    use biome_json_parser::{parse_json, JsonParserOptions};
+
+    fn member_names(object: &JsonObjectValue) -> Vec<String> {
+        object
+            .json_member_list()
+            .iter()
+            .flatten()
+            .filter_map(|member| member_name(&member))
+            .collect()
+    }
+
+    #[test]
+    fn sorts_fields_and_dependencies() {
+        let parse = parse_json(
+            r#"{
+    "version": "1.0.0",
+    "dependencies": { "react": "^18.0.0", "@biomejs/biome": "^1.9.0" },
+    "custom": true,
+    "name": "app"
+}"#,
+            JsonParserOptions::default(),
+        );
+
+        let root = sort_package_json(parse.tree());
+        let object = root_object(&root).unwrap();
+        assert_eq!(
+            member_names(&object),
+            ["name", "version", "dependencies", "custom"]
+        );
+
+        let dependencies = find_member(&object, "dependencies")
+            .and_then(|member| member.value().ok())
+            .and_then(|value| value.as_json_object_value().cloned())
+            .unwrap();
+        assert_eq!(member_names(&dependencies), ["@biomejs/biome", "react"]);
+    }
+
+    #[test]
+    fn keeps_members_with_comments() {
+        let source = r#"{
+    "version": "1.0.0",
+    // the name of the package
+    "name": "app"
+}"#;
+        let parse = parse_json(source, JsonParserOptions::default().with_allow_comments());
+
+        assert_eq!(sort_package_json(parse.tree()).syntax().to_string(), source);
+    }
+}
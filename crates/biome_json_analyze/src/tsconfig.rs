@@ -0,0 +1,335 @@
+//! Knowledge of the configuration files of TypeScript, such as `tsconfig.json`.
+//!
+//! The compiler options are the ones of TypeScript 5.x. The resolution of `extends` follows
+//! the algorithm of TypeScript for the paths, and looks for the packages in the `node_modules` folders.
+
+use crate::utils::{find_member, member_name, root_object};
+use biome_json_syntax::{AnyJsonValue, JsonMember, JsonObjectValue, JsonRoot, JsonStringValue};
+use biome_rowan::AstSeparatedList;
+use std::path::{Component, Path, PathBuf};
+
+/// The compiler options of TypeScript, sorted by name.
+const COMPILER_OPTIONS: &[&str] = &[
+    "allowArbitraryExtensions",
+    "allowImportingTsExtensions",
+    "allowJs",
+    "allowSyntheticDefaultImports",
+    "allowUmdGlobalAccess",
+    "allowUnreachableCode",
+    "allowUnusedLabels",
+    "alwaysStrict",
+    "assumeChangesOnlyAffectDirectDependencies",
+    "baseUrl",
+    "charset",
+    "checkJs",
+    "composite",
+    "customConditions",
+    "declaration",
+    "declarationDir",
+    "declarationMap",
+    "diagnostics",
+    "disableReferencedProjectLoad",
+    "disableSizeLimit",
+    "disableSolutionSearching",
+    "disableSourceOfProjectReferenceRedirect",
+    "downlevelIteration",
+    "emitBOM",
+    "emitDeclarationOnly",
+    "emitDecoratorMetadata",
+    "erasableSyntaxOnly",
+    "esModuleInterop",
+    "exactOptionalPropertyTypes",
+    "experimentalDecorators",
+    "explainFiles",
+    "extendedDiagnostics",
+    "forceConsistentCasingInFileNames",
+    "generateCpuProfile",
+    "generateTrace",
+    "importHelpers",
+    "importsNotUsedAsValues",
+    "incremental",
+    "inlineSourceMap",
+    "inlineSources",
+    "isolatedDeclarations",
+    "isolatedModules",
+    "jsx",
+    "jsxFactory",
+    "jsxFragmentFactory",
+    "jsxImportSource",
+    "keyofStringsOnly",
+    "lib",
+    "libReplacement",
+    "listEmittedFiles",
+    "listFiles",
+    "locale",
+    "mapRoot",
+    "maxNodeModuleJsDepth",
+    "module",
+    "moduleDetection",
+    "moduleResolution",
+    "moduleSuffixes",
+    "newLine",
+    "noCheck",
+    "noEmit",
+    "noEmitHelpers",
+    "noEmitOnError",
+    "noErrorTruncation",
+    "noFallthroughCasesInSwitch",
+    "noImplicitAny",
+    "noImplicitOverride",
+    "noImplicitReturns",
+    "noImplicitThis",
+    "noImplicitUseStrict",
+    "noLib",
+    "noPropertyAccessFromIndexSignature",
+    "noResolve",
+    "noStrictGenericChecks",
+    "noUncheckedIndexedAccess",
+    "noUncheckedSideEffectImports",
+    "noUnusedLocals",
+    "noUnusedParameters",
+    "out",
+    "outDir",
+    "outFile",
+    "paths",
+    "plugins",
+    "preserveConstEnums",
+    "preserveSymlinks",
+    "preserveValueImports",
+    "preserveWatchOutput",
+    "pretty",
+    "reactNamespace",
+    "removeComments",
+    "resolveJsonModule",
+    "resolvePackageJsonExports",
+    "resolvePackageJsonImports",
+    "rewriteRelativeImportExtensions",
+    "rootDir",
+    "rootDirs",
+    "skipDefaultLibCheck",
+    "skipLibCheck",
+    "sourceMap",
+    "sourceRoot",
+    "stopBuildOnErrors",
+    "strict",
+    "strictBindCallApply",
+    "strictBuiltinIteratorReturn",
+    "strictFunctionTypes",
+    "strictNullChecks",
+    "strictPropertyInitialization",
+    "stripInternal",
+    "suppressExcessPropertyErrors",
+    "suppressImplicitAnyIndexErrors",
+    "target",
+    "traceResolution",
+    "tsBuildInfoFile",
+    "typeRoots",
+    "types",
+    "useDefineForClassFields",
+    "useUnknownInCatchVariables",
+    "verbatimModuleSyntax",
+];
+
+/// The compiler options deprecated by TypeScript 5.0, and removed by TypeScript 5.5,
+/// with the option that replaces them.
+const DEPRECATED_COMPILER_OPTIONS: &[(&str, Option<&str>)] = &[
+    ("charset", None),
+    ("importsNotUsedAsValues", Some("verbatimModuleSyntax")),
+    ("keyofStringsOnly", None),
+    ("noImplicitUseStrict", None),
+    ("noStrictGenericChecks", None),
+    ("out", Some("outFile")),
+    ("preserveValueImports", Some("verbatimModuleSyntax")),
+    ("suppressExcessPropertyErrors", None),
+    ("suppressImplicitAnyIndexErrors", None),
+];
+
+/// Returns `true` if `path` is a configuration file of TypeScript,
+/// such as `tsconfig.json`, `tsconfig.build.json` or `jsconfig.json`.
+pub fn is_tsconfig(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| {
+            name == "jsconfig.json" || (name.starts_with("tsconfig.") && name.ends_with(".json"))
+        })
+}
+
+/// Returns `true` if `name` is a compiler option of TypeScript.
+pub fn is_compiler_option(name: &str) -> bool {
+    COMPILER_OPTIONS.binary_search(&name).is_ok()
+}
+
+/// Returns the compiler option named `name` with another case, such as `strictNullChecks` for `strictnullchecks`.
+pub fn compiler_option_ignoring_case(name: &str) -> Option<&'static str> {
+    COMPILER_OPTIONS
+        .iter()
+        .find(|option| option.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+/// Returns the option that replaces the deprecated compiler option `name`,
+/// or `None` if the option isn't deprecated.
+pub fn deprecated_compiler_option(name: &str) -> Option<Option<&'static str>> {
+    DEPRECATED_COMPILER_OPTIONS
+        .iter()
+        .find(|(option, _)| *option == name)
+        .map(|(_, replacement)| *replacement)
+}
+
+/// Returns the object of the field `compilerOptions`.
+pub fn compiler_options(root: &JsonRoot) -> Option<JsonObjectValue> {
+    let object = root_object(root)?;
+    match find_member(&object, "compilerOptions")?.value().ok()? {
+        AnyJsonValue::JsonObjectValue(options) => Some(options),
+        _ => None,
+    }
+}
+
+/// Returns the members of the compiler options, with their names.
+pub fn compiler_option_members(root: &JsonRoot) -> Vec<(String, JsonMember)> {
+    let Some(options) = compiler_options(root) else {
+        return Vec::new();
+    };
+    options
+        .json_member_list()
+        .iter()
+        .flatten()
+        .filter_map(|member| Some((member_name(&member)?, member)))
+        .collect()
+}
+
+/// Returns the configuration files extended by the field `extends`, which is a string or an array of strings.
+pub fn extends_values(root: &JsonRoot) -> Vec<JsonStringValue> {
+    let Some(value) = root_object(root)
+        .and_then(|object| find_member(&object, "extends"))
+        .and_then(|member| member.value().ok())
+    else {
+        return Vec::new();
+    };
+    match value {
+        AnyJsonValue::JsonStringValue(value) => vec![value],
+        AnyJsonValue::JsonArrayValue(array) => array
+            .elements()
+            .iter()
+            .flatten()
+            .filter_map(|element| element.as_json_string_value().cloned())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Returns `true` if the configuration file `specifier`, extended by a configuration file
+/// of the folder `directory`, exists.
+///
+/// A relative or absolute path is resolved from `directory`, with the extension `.json` if the path
+/// doesn't exist. A package is looked for in the `node_modules` folders of `directory` and its ancestors.
+pub fn resolve_extends(
+    directory: &Path,
+    specifier: &str,
+    path_exists: impl Fn(&Path) -> bool,
+) -> bool {
+    let is_path = specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || specifier == "."
+        || specifier == ".."
+        || Path::new(specifier).is_absolute();
+    if is_path {
+        return path_exists(&normalize(&directory.join(specifier)))
+            || (!specifier.ends_with(".json")
+                && path_exists(&normalize(&directory.join(format!("{specifier}.json")))));
+    }
+    directory.ancestors().any(|ancestor| {
+        let node_modules = ancestor.join("node_modules");
+        path_exists(&node_modules.join(specifier))
+            || path_exists(&node_modules.join(format!("{specifier}.json")))
+    })
+}
+
+/// Removes the components `.` and `..` of `path` without reading the file system, as TypeScript does.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+/// The configuration files of `extends` that don't exist.
+#[derive(Debug, Default)]
+pub struct TsconfigExtends {
+    unresolved: Vec<String>,
+}
+
+impl TsconfigExtends {
+    /// Resolves the configuration files extended by the document `root` of the folder `directory`.
+    pub fn resolve(root: &JsonRoot, directory: &Path, path_exists: impl Fn(&Path) -> bool) -> Self {
+        let unresolved = extends_values(root)
+            .iter()
+            .filter_map(|value| Some(value.inner_string_text().ok()?.to_string()))
+            .filter(|specifier| !resolve_extends(directory, specifier, &path_exists))
+            .collect();
+        Self { unresolved }
+    }
+
+    /// Returns `true` if the configuration file `specifier` doesn't exist.
+    pub fn is_unresolved(&self, specifier: &str) -> bool {
+        self.unresolved
+            .iter()
+            .any(|unresolved| unresolved == specifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compiler_options_are_sorted() {
+        assert!(COMPILER_OPTIONS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_is_tsconfig() {
+        assert!(is_tsconfig(Path::new("project/tsconfig.json")));
+        assert!(is_tsconfig(Path::new("tsconfig.build.json")));
+        assert!(is_tsconfig(Path::new("jsconfig.json")));
+        assert!(!is_tsconfig(Path::new("package.json")));
+        assert!(!is_tsconfig(Path::new("tsconfig.yaml")));
+    }
+
+    #[test]
+    fn test_resolve_extends() {
+        let files = [
+            PathBuf::from("/project/tsconfig.base.json"),
+            PathBuf::from("/project/configs/strict.json"),
+            PathBuf::from("/node_modules/@tsconfig/node20/tsconfig.json"),
+            PathBuf::from("/node_modules/@tsconfig/node20"),
+        ];
+        let path_exists = |path: &Path| files.iter().any(|file| file == path);
+        let directory = Path::new("/project/app");
+        assert!(resolve_extends(
+            directory,
+            "../tsconfig.base.json",
+            path_exists
+        ));
+        assert!(resolve_extends(directory, "../configs/strict", path_exists));
+        assert!(resolve_extends(
+            directory,
+            "@tsconfig/node20/tsconfig.json",
+            path_exists
+        ));
+        assert!(resolve_extends(directory, "@tsconfig/node20", path_exists));
+        assert!(!resolve_extends(
+            directory,
+            "./tsconfig.base.json",
+            path_exists
+        ));
+        assert!(!resolve_extends(directory, "@tsconfig/node18", path_exists));
+    }
+}
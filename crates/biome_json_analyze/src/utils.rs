@@ -1,5 +1,43 @@
-use biome_json_syntax::{JsonMember, JsonMemberList, JsonMemberName, JsonObjectValue};
-use biome_rowan::AstNode;
+use biome_json_syntax::{
+    AnyJsonValue, JsonMember, JsonMemberList, JsonMemberName, JsonObjectValue, JsonRoot,
+};
+use biome_rowan::{AstNode, AstSeparatedList};
+use std::path::Path;
+
+/// The fields of a `package.json` that map the names of the dependencies to their version ranges
+pub const PACKAGE_JSON_DEPENDENCY_FIELDS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "optionalDependencies",
+    "peerDependencies",
+];
+
+/// Returns `true` if `path` is the manifest `package.json` of a package
+pub fn is_package_json(path: &Path) -> bool {
+    path.file_name().is_some_and(|name| name == "package.json")
+}
+
+/// Returns the object at the root of the document, if the document is an object
+pub fn root_object(root: &JsonRoot) -> Option<JsonObjectValue> {
+    match root.value().ok()? {
+        AnyJsonValue::JsonObjectValue(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// Returns the unquoted name of a member
+pub fn member_name(member: &JsonMember) -> Option<String> {
+    Some(member.name().ok()?.inner_string_text().ok()?.to_string())
+}
+
+/// Returns the first member of `object` named `name`
+pub fn find_member(object: &JsonObjectValue, name: &str) -> Option<JsonMember> {
+    object
+        .json_member_list()
+        .iter()
+        .flatten()
+        .find(|member| member_name(member).as_deref() == Some(name))
+}
 
 /// Mathes a JSON member name node against a path
 pub fn matches_path(optional_node: Option<&JsonMemberName>, path: &[&str]) -> bool {
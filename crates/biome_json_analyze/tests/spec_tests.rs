@@ -1,15 +1,17 @@
 use biome_analyze::{AnalysisFilter, AnalyzerAction, ControlFlow, Never, RuleFilter};
 use biome_diagnostics::advice::CodeSuggestionAdvice;
 use biome_diagnostics::{DiagnosticExt, Severity};
+use biome_json_analyze::json_schema::JsonSchema;
+use biome_json_analyze::tsconfig::{is_tsconfig, TsconfigExtends};
 use biome_json_parser::{parse_json, JsonParserOptions};
-use biome_json_syntax::JsonLanguage;
+use biome_json_syntax::{JsonLanguage, JsonRoot};
 use biome_rowan::AstNode;
 use biome_test_utils::{
     assert_errors_are_absent, code_fix_to_string, create_analyzer_options, diagnostic_to_string,
     has_bogus_nodes_or_empty_slots, parse_test_path, register_leak_checker,
     write_analyzer_snapshot,
 };
-use std::{ffi::OsStr, fs::read_to_string, path::Path, slice};
+use std::{ffi::OsStr, fs::read_to_string, path::Path, slice, sync::Arc};
 
 tests_macros::gen_tests! {"tests/specs/**/*.{json}", crate::run_test, "module"}
 
@@ -18,8 +20,20 @@ fn run_test(input: &'static str, _: &str, _: &str, _: &str) {
 
     let input_file = Path::new(input);
     let file_name = input_file.file_name().and_then(OsStr::to_str).unwrap();
+    // The JSON schemas and the options used by the tests aren't tests
+    if file_name.ends_with(".schema.json") || file_name.ends_with(".options.json") {
+        return;
+    }
 
-    let (group, rule) = parse_test_path(input_file);
+    let (mut group, mut rule) = parse_test_path(input_file);
+    if biome_json_analyze::metadata()
+        .find_rule(group, rule)
+        .is_none()
+    {
+        // The files that must have a specific name, such as `package.json`,
+        // are placed in a folder named after their test case
+        (group, rule) = parse_test_path(input_file.parent().unwrap());
+    }
     if rule == "specs" || rule == "suppression" {
         panic!("the test file must be placed in the {rule}/<group-name>/<rule-name>/ directory");
     }
@@ -73,30 +87,39 @@ pub(crate) fn analyze_and_snap(
     let mut diagnostics = Vec::new();
     let mut code_fixes = Vec::new();
     let options = create_analyzer_options(input_file, &mut diagnostics);
+    let json_schema = load_json_schema(input_file);
+    let tsconfig_extends = resolve_tsconfig_extends(input_file, &root);
+
+    let (_, errors) = biome_json_analyze::analyze(
+        &root,
+        filter,
+        &options,
+        json_schema,
+        tsconfig_extends,
+        |event| {
+            if let Some(mut diag) = event.diagnostic() {
+                for action in event.actions() {
+                    if !action.is_suppression() {
+                        check_code_action(input_file, input_code, &action);
+                        diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                    }
+                }
+
+                let error = diag.with_severity(Severity::Warning);
+                diagnostics.push(diagnostic_to_string(file_name, input_code, error));
+                return ControlFlow::Continue(());
+            }
 
-    let (_, errors) = biome_json_analyze::analyze(&root, filter, &options, |event| {
-        if let Some(mut diag) = event.diagnostic() {
             for action in event.actions() {
                 if !action.is_suppression() {
                     check_code_action(input_file, input_code, &action);
-                    diag = diag.add_code_suggestion(CodeSuggestionAdvice::from(action));
+                    code_fixes.push(code_fix_to_string(input_code, action));
                 }
             }
 
-            let error = diag.with_severity(Severity::Warning);
-            diagnostics.push(diagnostic_to_string(file_name, input_code, error));
-            return ControlFlow::Continue(());
-        }
-
-        for action in event.actions() {
-            if !action.is_suppression() {
-                check_code_action(input_file, input_code, &action);
-                code_fixes.push(code_fix_to_string(input_code, action));
-            }
-        }
-
-        ControlFlow::<Never>::Continue(())
-    });
+            ControlFlow::<Never>::Continue(())
+        },
+    );
 
     for error in errors {
         diagnostics.push(diagnostic_to_string(file_name, input_code, error));
@@ -112,6 +135,26 @@ pub(crate) fn analyze_and_snap(
     diagnostics.len()
 }
 
+/// Loads the JSON schema of the test `<name>.json` from the file `<name>.schema.json`
+fn load_json_schema(input_file: &Path) -> Option<Arc<JsonSchema>> {
+    let schema_file = input_file.with_extension("schema.json");
+    let content = read_to_string(schema_file).ok()?;
+    let parsed = parse_json(&content, JsonParserOptions::default());
+    JsonSchema::from_root(&parsed.tree()).map(Arc::new)
+}
+
+/// Resolves the configuration files extended by the test, when it's a configuration file of TypeScript
+fn resolve_tsconfig_extends(input_file: &Path, root: &JsonRoot) -> Option<Arc<TsconfigExtends>> {
+    if !is_tsconfig(input_file) {
+        return None;
+    }
+    Some(Arc::new(TsconfigExtends::resolve(
+        root,
+        input_file.parent()?,
+        Path::exists,
+    )))
+}
+
 fn check_code_action(path: &Path, source: &str, action: &AnalyzerAction<JsonLanguage>) {
     let (new_tree, text_edit) = match action
         .mutation
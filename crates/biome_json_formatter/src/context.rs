@@ -5,7 +5,7 @@ use biome_formatter::separated::TrailingSeparator;
 use biome_formatter::{prelude::*, AttributePosition, IndentWidth};
 use biome_formatter::{
     CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
-    TransformSourceMap,
+    MaxEmptyLines, TransformSourceMap,
 };
 use biome_json_syntax::JsonLanguage;
 use std::default::Default;
@@ -64,9 +64,12 @@ pub struct JsonFormatOptions {
     indent_width: IndentWidth,
     line_ending: LineEnding,
     line_width: LineWidth,
+    max_empty_lines: MaxEmptyLines,
     attribute_position: AttributePosition,
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
     trailing_commas: TrailingCommas,
+    /// How the elements of the arrays are laid out when the arrays don't fit on a line. Defaults to "auto".
+    array_wrap: ArrayWrap,
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
@@ -104,6 +107,45 @@ impl fmt::Display for TrailingCommas {
     }
 }
 
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Deserializable, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ArrayWrap {
+    #[default]
+    /// The arrays of numbers are filled, the other arrays have one element per line
+    Auto,
+    /// The arrays of numbers, strings, booleans and `null` are filled: as many elements as fit go on each line
+    Fill,
+    /// The arrays have one element per line
+    OnePerLine,
+}
+
+impl FromStr for ArrayWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" | "Auto" => Ok(Self::Auto),
+            "fill" | "Fill" => Ok(Self::Fill),
+            "one-per-line" | "OnePerLine" => Ok(Self::OnePerLine),
+            _ => Err("Value not supported for ArrayWrap"),
+        }
+    }
+}
+
+impl fmt::Display for ArrayWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArrayWrap::Auto => std::write!(f, "Auto"),
+            ArrayWrap::Fill => std::write!(f, "Fill"),
+            ArrayWrap::OnePerLine => std::write!(f, "One per line"),
+        }
+    }
+}
+
 impl JsonFormatOptions {
     pub fn new() -> Self {
         Self {
@@ -131,11 +173,21 @@ impl JsonFormatOptions {
         self
     }
 
+    pub fn with_max_empty_lines(mut self, max_empty_lines: MaxEmptyLines) -> Self {
+        self.max_empty_lines = max_empty_lines;
+        self
+    }
+
     pub fn with_trailing_commas(mut self, trailing_commas: TrailingCommas) -> Self {
         self.trailing_commas = trailing_commas;
         self
     }
 
+    pub fn with_array_wrap(mut self, array_wrap: ArrayWrap) -> Self {
+        self.array_wrap = array_wrap;
+        self
+    }
+
     pub fn set_indent_style(&mut self, indent_style: IndentStyle) {
         self.indent_style = indent_style;
     }
@@ -152,10 +204,22 @@ impl JsonFormatOptions {
         self.line_width = line_width;
     }
 
+    pub fn set_max_empty_lines(&mut self, max_empty_lines: MaxEmptyLines) {
+        self.max_empty_lines = max_empty_lines;
+    }
+
     pub fn set_trailing_commas(&mut self, trailing_commas: TrailingCommas) {
         self.trailing_commas = trailing_commas;
     }
 
+    pub fn set_array_wrap(&mut self, array_wrap: ArrayWrap) {
+        self.array_wrap = array_wrap;
+    }
+
+    pub fn array_wrap(&self) -> ArrayWrap {
+        self.array_wrap
+    }
+
     pub(crate) fn to_trailing_separator(&self) -> TrailingSeparator {
         match self.trailing_commas {
             TrailingCommas::None => TrailingSeparator::Omit,
@@ -181,6 +245,10 @@ impl FormatOptions for JsonFormatOptions {
         self.line_width
     }
 
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        self.max_empty_lines
+    }
+
     fn as_print_options(&self) -> PrinterOptions {
         PrinterOptions::from(self)
     }
@@ -196,6 +264,8 @@ impl fmt::Display for JsonFormatOptions {
         writeln!(f, "Indent width: {}", self.indent_width.value())?;
         writeln!(f, "Line ending: {}", self.line_ending)?;
         writeln!(f, "Line width: {}", self.line_width.get())?;
-        writeln!(f, "Trailing commas: {}", self.trailing_commas)
+        writeln!(f, "Max empty lines: {}", self.max_empty_lines)?;
+        writeln!(f, "Trailing commas: {}", self.trailing_commas)?;
+        writeln!(f, "Array wrap: {}", self.array_wrap)
     }
 }
@@ -1,6 +1,6 @@
 use crate::prelude::*;
 use biome_formatter::token::string::{normalize_string, Quote};
-use biome_json_syntax::JsonSyntaxToken;
+use biome_json_syntax::{JsonSyntaxKind, JsonSyntaxToken};
 use std::borrow::Cow;
 
 pub(crate) fn format_string_token(token: &JsonSyntaxToken) -> CleanedStringLiteralText {
@@ -14,6 +14,12 @@ pub(crate) struct CleanedStringLiteralText<'token> {
 impl Format<JsonFormatContext> for CleanedStringLiteralText<'_> {
     fn fmt(&self, f: &mut Formatter<JsonFormatContext>) -> FormatResult<()> {
         let content = self.token.text_trimmed();
+
+        // The JSON5 identifiers and single quoted strings are kept as is
+        if self.token.kind() == JsonSyntaxKind::IDENT || content.starts_with('\'') {
+            return self.token.format().fmt(f);
+        }
+
         let raw_content = &content[1..content.len() - 1];
 
         let text = match normalize_string(raw_content, Quote::Double, false) {
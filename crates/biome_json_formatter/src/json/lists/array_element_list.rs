@@ -1,6 +1,7 @@
+use crate::context::ArrayWrap;
 use crate::prelude::*;
 use crate::separated::FormatAstSeparatedListExtension;
-use biome_formatter::write;
+use biome_formatter::{write, FormatOptions};
 use biome_json_syntax::{AnyJsonValue, JsonArrayElementList};
 use biome_rowan::{AstNode, AstSeparatedList};
 
@@ -10,7 +11,13 @@ pub(crate) struct FormatJsonArrayElementList;
 impl FormatRule<JsonArrayElementList> for FormatJsonArrayElementList {
     type Context = JsonFormatContext;
     fn fmt(&self, node: &JsonArrayElementList, f: &mut JsonFormatter) -> FormatResult<()> {
-        let layout = if can_concisely_print_array_list(node) {
+        let can_fill = match f.options().array_wrap() {
+            ArrayWrap::Auto => can_concisely_print_array_list(node),
+            ArrayWrap::Fill => is_literal_array_list(node),
+            ArrayWrap::OnePerLine => false,
+        };
+
+        let layout = if can_fill {
             ArrayLayout::Fill
         } else {
             ArrayLayout::OnePerLine
@@ -19,6 +26,7 @@ impl FormatRule<JsonArrayElementList> for FormatJsonArrayElementList {
         match layout {
             ArrayLayout::Fill => {
                 let trailing_separator = f.options().to_trailing_separator();
+                let max_empty_lines = f.options().max_empty_lines();
                 let mut filler = f.fill();
 
                 for (element, formatted) in node
@@ -27,10 +35,10 @@ impl FormatRule<JsonArrayElementList> for FormatJsonArrayElementList {
                 {
                     filler.entry(
                         &format_once(|f| {
-                            if get_lines_before(element?.syntax()) > 1 {
-                                write!(f, [empty_line()])
-                            } else {
-                                write!(f, [soft_line_break_or_space()])
+                            let lines_before = get_lines_before(element?.syntax());
+                            match max_empty_lines.empty_lines_before(lines_before) {
+                                0 => write!(f, [soft_line_break_or_space()]),
+                                count => write!(f, [empty_lines(count)]),
                             }
                         }),
                         &formatted,
@@ -93,3 +101,21 @@ pub(crate) fn can_concisely_print_array_list(list: &JsonArrayElementList) -> boo
     list.iter()
         .all(|node| matches!(node, Ok(AnyJsonValue::JsonNumberValue(_))))
 }
+
+/// Returns `true` if the array only contains numbers, strings, booleans and `null`,
+/// the arrays that can be "fill-printed" when the option `arrayWrap` is `fill`.
+fn is_literal_array_list(list: &JsonArrayElementList) -> bool {
+    if list.is_empty() {
+        return false;
+    }
+
+    list.iter().all(|node| {
+        matches!(
+            node,
+            Ok(AnyJsonValue::JsonNumberValue(_)
+                | AnyJsonValue::JsonStringValue(_)
+                | AnyJsonValue::JsonBooleanValue(_)
+                | AnyJsonValue::JsonNullValue(_))
+        )
+    })
+}
@@ -7,6 +7,15 @@ pub(crate) struct FormatJsonNumberValue;
 
 impl FormatNodeRule<JsonNumberValue> for FormatJsonNumberValue {
     fn fmt_fields(&self, node: &JsonNumberValue, f: &mut JsonFormatter) -> FormatResult<()> {
-        format_number_token(&node.value_token()?).fmt(f)
+        let value_token = node.value_token()?;
+        // The JSON5 numbers `Infinity` and `NaN` are kept as is
+        if matches!(
+            value_token.text_trimmed().trim_start_matches(['+', '-']),
+            "Infinity" | "NaN"
+        ) {
+            value_token.format().fmt(f)
+        } else {
+            format_number_token(&value_token).fmt(f)
+        }
     }
 }
@@ -1,5 +1,6 @@
 use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
 use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_syntax::JsonFileSource;
 use std::path::Path;
 
 mod language {
@@ -24,14 +25,20 @@ mod language {
 /// * `json/null` -> input: `tests/specs/json/null.json`, expected output: `tests/specs/json/null.json.snap`
 /// * `null` -> input: `tests/specs/null.json`, expected output: `tests/specs/null.json.snap`
 pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    // The options of the tests in a directory aren't a test
+    if spec_input_file.ends_with("/options.json") {
+        return;
+    }
+
     let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
 
     let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path) else {
         return;
     };
 
+    let source_type: JsonFileSource = test_file.input_file().as_path().try_into().unwrap();
     let options = JsonFormatOptions::default();
-    let language = language::JsonTestFormatLanguage::default();
+    let language = language::JsonTestFormatLanguage::new(source_type);
 
     let snapshot = SpecSnapshot::new(test_file, test_directory, language, options);
 
@@ -139,6 +139,8 @@ impl<'src> Lexer<'src> {
                     b'\r' | b'\n' => {
                         break;
                     }
+                    // JSON5 allows the vertical tab and the form feed
+                    _ if self.options.allow_json5 => self.advance(1),
                     _ => {
                         let start = self.text_position();
                         self.advance(1);
@@ -310,6 +312,10 @@ impl<'src> Lexer<'src> {
             IDT => self.lex_identifier(current),
             COM => self.eat_byte(T![,]),
             MIN | DIG | ZER => self.lex_number(current),
+            PLS if self.options.allow_json5 => self.lex_number(current),
+            PRD if self.options.allow_json5 && matches!(self.peek_byte(), Some(b'0'..=b'9')) => {
+                self.lex_number(current)
+            }
             COL => self.eat_byte(T![:]),
             BTO => self.eat_byte(T!['[']),
             BTC => self.eat_byte(T![']']),
@@ -327,6 +333,10 @@ impl<'src> Lexer<'src> {
                     // A BOM can only appear at the start of a file, so if we haven't advanced at all yet,
                     // perform the check. At any other position, the BOM is just considered plain whitespace.
                     UNICODE_BOM
+                } else if self.options.allow_json5 && (chr.is_whitespace() || chr == '\u{feff}') {
+                    // JSON5 allows all the Unicode space separators
+                    self.advance(chr.len_utf8());
+                    WHITESPACE
                 } else {
                     self.eat_unexpected_character()
                 }
@@ -358,10 +368,18 @@ impl<'src> Lexer<'src> {
 
         let start = self.text_position();
 
-        if current == b'-' {
+        if matches!(current, b'-' | b'+') {
             self.advance(1);
         }
 
+        if self.options.allow_json5 {
+            match (self.current_byte(), self.peek_byte()) {
+                (Some(b'I' | b'N'), _) => return self.lex_named_number(start),
+                (Some(b'0'), Some(b'x' | b'X')) => return self.lex_hexadecimal_number(start),
+                _ => {}
+            }
+        }
+
         let mut state = LexNumberState::FirstDigit;
 
         loop {
@@ -403,6 +421,16 @@ impl<'src> Lexer<'src> {
                         {
                             LexNumberState::FractionalPart
                         }
+                        // JSON5 allows a leading or a trailing decimal point: `.5` and `5.`
+                        LexNumberState::FirstDigit
+                            if self.options.allow_json5
+                                && matches!(self.current_byte(), Some(b'0'..=b'9')) =>
+                        {
+                            LexNumberState::FractionalPart
+                        }
+                        LexNumberState::IntegerPart if self.options.allow_json5 => {
+                            LexNumberState::FractionalPart
+                        }
                         LexNumberState::IntegerPart => LexNumberState::Invalid {
                             position: self.text_position(),
                             reason: InvalidNumberReason::MissingFraction,
@@ -451,6 +479,14 @@ impl<'src> Lexer<'src> {
             LexNumberState::IntegerPart
             | LexNumberState::FractionalPart
             | LexNumberState::Exponent => JSON_NUMBER_LITERAL,
+            LexNumberState::FirstDigit if self.options.allow_json5 => {
+                let err = ParseDiagnostic::new(
+                    "A sign must be followed by a digit, `Infinity` or `NaN`",
+                    start..self.text_position(),
+                );
+                self.diagnostics.push(err);
+                ERROR_TOKEN
+            }
             LexNumberState::FirstDigit => {
                 let err = ParseDiagnostic::new(
                     "Minus must be followed by a digit",
@@ -492,6 +528,47 @@ impl<'src> Lexer<'src> {
         }
     }
 
+    /// Lexes the JSON5 number `Infinity` or `NaN` that follows a sign.
+    fn lex_named_number(&mut self, start: TextSize) -> JsonSyntaxKind {
+        let name_start = self.position;
+
+        while let Some(IDT | DIG | ZER) = self.current_byte().map(lookup_byte) {
+            self.advance(1);
+        }
+
+        if matches!(&self.source[name_start..self.position], "Infinity" | "NaN") {
+            JSON_NUMBER_LITERAL
+        } else {
+            self.diagnostics.push(ParseDiagnostic::new(
+                "A sign must be followed by a digit, `Infinity` or `NaN`",
+                start..self.text_position(),
+            ));
+            ERROR_TOKEN
+        }
+    }
+
+    /// Lexes a JSON5 hexadecimal number. Assumes that the lexer is positioned at the `0` of `0x`.
+    fn lex_hexadecimal_number(&mut self, start: TextSize) -> JsonSyntaxKind {
+        self.assert_byte(b'0');
+        self.advance(2); // Skip over `0x`
+
+        let digits_start = self.position;
+
+        while matches!(self.current_byte(), Some(byte) if byte.is_ascii_hexdigit()) {
+            self.advance(1);
+        }
+
+        if self.position == digits_start {
+            self.diagnostics.push(
+                ParseDiagnostic::new("Missing hexadecimal digits", start..self.text_position())
+                    .with_hint("A hexadecimal number must have at least one digit: `0x1F`."),
+            );
+            ERROR_TOKEN
+        } else {
+            JSON_NUMBER_LITERAL
+        }
+    }
+
     fn lex_string_literal(&mut self, quote: u8) -> JsonSyntaxKind {
         // Handle invalid quotes
         self.assert_at_char_boundary();
@@ -499,7 +576,7 @@ impl<'src> Lexer<'src> {
 
         self.advance(1); // Skip over the quote
         let mut state = match quote {
-            b'\'' => LexStringState::InvalidQuote,
+            b'\'' if !self.options.allow_json5 => LexStringState::InvalidQuote,
             _ => LexStringState::InString,
         };
 
@@ -535,11 +612,37 @@ impl<'src> Lexer<'src> {
                         },
 
                         // Handle escaped `'` but only if this is a single quote string. The whole string will
-                        // be marked as erroneous
-                        Some(b'\'') if quote == b'\'' => {
+                        // be marked as erroneous, unless JSON5 is allowed.
+                        Some(b'\'') if quote == b'\'' || self.options.allow_json5 => {
                             self.advance(1);
                         }
 
+                        Some(b'x') if self.options.allow_json5 => {
+                            match (self.lex_hexadecimal_escape(), state) {
+                                (Ok(_), _) => {}
+                                (Err(err), LexStringState::InString) => {
+                                    self.diagnostics.push(err);
+                                    state = LexStringState::InvalidEscapeSequence;
+                                }
+                                (Err(_), _) => {}
+                            }
+                        }
+
+                        // JSON5 allows to continue a string on the next line with a backslash
+                        Some(b'\n' | b'\r') if self.options.allow_json5 => {
+                            self.consume_newline();
+                        }
+
+                        // JSON5 allows to escape any character, except the digits (but `\0`)
+                        Some(byte)
+                            if self.options.allow_json5
+                                && (!byte.is_ascii_digit()
+                                    || (byte == b'0'
+                                        && !matches!(self.peek_byte(), Some(b'0'..=b'9')))) =>
+                        {
+                            self.advance_byte_or_char(byte);
+                        }
+
                         Some(_) => {
                             if matches!(state, LexStringState::InString) {
                                 let c = self.current_char_unchecked();
@@ -584,7 +687,11 @@ impl<'src> Lexer<'src> {
                 // * quotation mark: (U+0022),
                 // * reverse solidus (U+005C),
                 // * and the **control characters U+0000 to U+001F** <- This
-                ERR | WHS if matches!(state, LexStringState::InString) && chr <= 0x1f => {
+                ERR | WHS
+                    if matches!(state, LexStringState::InString)
+                        && chr <= 0x1f
+                        && !self.options.allow_json5 =>
+                {
                     self.diagnostics.push(
                         ParseDiagnostic::new(
 
@@ -677,11 +784,40 @@ impl<'src> Lexer<'src> {
         Ok(())
     }
 
+    /// Lexes a JSON5 `\x00` escape sequence. Assumes that the lexer is positioned at the `x` token.
+    ///
+    /// A hexadecimal escape sequence must consist of 2 hex characters.
+    fn lex_hexadecimal_escape(&mut self) -> Result<(), ParseDiagnostic> {
+        self.assert_byte(b'x');
+
+        // Subtract 1 to get position of `\`
+        let start = self.text_position() - TextSize::from(1);
+
+        self.advance(1); // Advance over `x`
+
+        for _ in 0..2 {
+            match self.current_byte() {
+                Some(byte) if byte.is_ascii_hexdigit() => self.advance(1),
+                _ => {
+                    return Err(ParseDiagnostic::new(
+                        "Invalid hexadecimal escape sequence",
+                        start..self.text_position(),
+                    )
+                    .with_hint("A hexadecimal escape sequence must consist of 2 hexadecimal numbers: `\\xXX`, e.g. `\\x2F' for '/'."));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Implements basic lexing of identifiers without support for escape sequences.
     /// This is merely for improved error recovery as identifiers are not valid in JSON.
     fn lex_identifier(&mut self, first: u8) -> JsonSyntaxKind {
         self.assert_at_char_boundary();
 
+        let start = self.position;
+
         let mut keyword = KeywordMatcher::from_byte(first);
 
         self.advance_byte_or_char(first);
@@ -712,6 +848,11 @@ impl<'src> Lexer<'src> {
             KeywordMatcher::Null => NULL_KW,
             KeywordMatcher::True => TRUE_KW,
             KeywordMatcher::False => FALSE_KW,
+            _ if self.options.allow_json5
+                && matches!(&self.source[start..self.position], "Infinity" | "NaN") =>
+            {
+                JSON_NUMBER_LITERAL
+            }
             _ => IDENT,
         }
     }
@@ -2,6 +2,7 @@
 #![allow(unused_mut, unused_variables, unused_assignments)]
 
 use super::{Lexer, TextSize};
+use crate::JsonParserOptions;
 use biome_json_syntax::JsonSyntaxKind::{self, EOF};
 use quickcheck_macros::quickcheck;
 use std::sync::mpsc::channel;
@@ -11,8 +12,11 @@ use std::time::Duration;
 // Assert the result of lexing a piece of source code,
 // and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
 macro_rules! assert_lex {
-    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
-        let mut lexer = Lexer::from_str($src);
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex! { options: JsonParserOptions::default(), $src, $($kind:$len,)* }
+    };
+    (options: $options:expr, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = Lexer::from_str($src).with_options($options);
         let mut idx = 0;
         let mut tok_idx = TextSize::default();
 
@@ -449,3 +453,100 @@ fn keywords() {
         assert_eq!(lexer.next_token().expect("Expected EOF token").kind, EOF);
     }
 }
+
+#[test]
+fn json5_strings() {
+    let options = JsonParserOptions::default().with_allow_json5();
+    assert_lex! {
+        options: options,
+        "'a \\' b'",
+        JSON_STRING_LITERAL:8,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        r#""\x41\0\a""#,
+        JSON_STRING_LITERAL:10,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        "'a\\\nb'",
+        JSON_STRING_LITERAL:6,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        r#""\x4""#,
+        ERROR_TOKEN:5,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        r#""\1""#,
+        ERROR_TOKEN:4,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_numbers() {
+    let options = JsonParserOptions::default().with_allow_json5();
+    assert_lex! {
+        options: options,
+        "0x1F",
+        JSON_NUMBER_LITERAL:4,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        "+.5",
+        JSON_NUMBER_LITERAL:3,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        "5.e3",
+        JSON_NUMBER_LITERAL:4,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        "-Infinity",
+        JSON_NUMBER_LITERAL:9,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        "NaN",
+        JSON_NUMBER_LITERAL:3,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        "0x",
+        ERROR_TOKEN:2,
+        EOF:0
+    }
+    assert_lex! {
+        options: options,
+        "+Inf",
+        ERROR_TOKEN:4,
+        EOF:0
+    }
+}
+
+#[test]
+fn json5_only_numbers() {
+    assert_lex! {
+        "+1",
+        ERROR_TOKEN:1,
+        JSON_NUMBER_LITERAL:1,
+        EOF:0
+    }
+    assert_lex! {
+        "NaN",
+        IDENT:3,
+        EOF:0
+    }
+}
@@ -16,6 +16,8 @@ pub(crate) struct JsonParser<'source> {
 pub struct JsonParserOptions {
     pub allow_comments: bool,
     pub allow_trailing_commas: bool,
+    /// Parses the JSON5 extensions: unquoted keys, single quoted strings, hexadecimal numbers, etc.
+    pub allow_json5: bool,
 }
 
 impl JsonParserOptions {
@@ -28,6 +30,14 @@ impl JsonParserOptions {
         self.allow_trailing_commas = true;
         self
     }
+
+    /// Enables the JSON5 syntax, which also allows comments and trailing commas.
+    pub fn with_allow_json5(mut self) -> Self {
+        self.allow_comments = true;
+        self.allow_trailing_commas = true;
+        self.allow_json5 = true;
+        self
+    }
 }
 
 impl<'source> JsonParser<'source> {
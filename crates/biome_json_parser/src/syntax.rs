@@ -291,7 +291,20 @@ fn parse_member_name(p: &mut JsonParser) -> ParsedSyntax {
         }
         IDENT | T![null] | T![true] | T![false] => {
             let m = p.start();
-            p.error(p.err_builder("Property key must be double quoted", p.cur_range()));
+            if p.options().allow_json5 {
+                p.bump_remap(IDENT);
+                Present(m.complete(p, JSON_MEMBER_NAME))
+            } else {
+                p.error(p.err_builder("Property key must be double quoted", p.cur_range()));
+                p.bump_remap(IDENT);
+                Present(m.complete(p, JSON_BOGUS))
+            }
+        }
+        // `Infinity` and `NaN` are lexed as numbers, but they're also valid JSON5 identifiers
+        JSON_NUMBER_LITERAL
+            if p.options().allow_json5 && matches!(p.cur_text(), "Infinity" | "NaN") =>
+        {
+            let m = p.start();
             p.bump_remap(IDENT);
             Present(m.complete(p, JSON_MEMBER_NAME))
         }
@@ -27,3 +27,9 @@ mod allow_trainling_commas {
     tests_macros::gen_tests! {"tests/json_test_suite/allow_trailing_commas/ok/*.json", crate::spec_test::run, "ok"}
     tests_macros::gen_tests! {"tests/json_test_suite/allow_trailing_commas/err/*.json", crate::spec_test::run, "error"}
 }
+
+mod json5 {
+    //! Tests with the JSON5 extensions
+    tests_macros::gen_tests! {"tests/json_test_suite/json5/ok/*.json5", crate::spec_test::run, "ok"}
+    tests_macros::gen_tests! {"tests/json_test_suite/json5/err/*.json5", crate::spec_test::run, "error"}
+}
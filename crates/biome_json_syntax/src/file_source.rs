@@ -8,6 +8,7 @@ use std::{ffi::OsStr, path::Path};
 pub struct JsonFileSource {
     allow_trailing_commas: bool,
     allow_comments: bool,
+    allow_json5: bool,
 }
 
 impl JsonFileSource {
@@ -126,6 +127,7 @@ impl JsonFileSource {
         Self {
             allow_comments: false,
             allow_trailing_commas: false,
+            allow_json5: false,
         }
     }
 
@@ -133,6 +135,7 @@ impl JsonFileSource {
         Self {
             allow_comments: true,
             allow_trailing_commas: false,
+            allow_json5: false,
         }
     }
 
@@ -140,6 +143,17 @@ impl JsonFileSource {
         Self {
             allow_comments: true,
             allow_trailing_commas: true,
+            allow_json5: false,
+        }
+    }
+
+    /// [JSON5](https://json5.org/) allows comments and trailing commas,
+    /// as well as unquoted keys, single quotes, hexadecimal numbers and multi-line strings.
+    pub fn json5() -> Self {
+        Self {
+            allow_comments: true,
+            allow_trailing_commas: true,
+            allow_json5: true,
         }
     }
 
@@ -161,6 +175,10 @@ impl JsonFileSource {
         self.allow_comments
     }
 
+    pub fn allow_json5(&self) -> bool {
+        self.allow_json5
+    }
+
     pub fn is_well_known_json_file(file_name: &str) -> bool {
         Self::WELL_KNOWN_JSON_FILES
             .binary_search(&file_name)
@@ -216,6 +234,8 @@ impl JsonFileSource {
             | "sublime-workspace"
             | "sublime_metrics"
             | "sublime_session" => Ok(Self::json_allow_comments_and_trailing_commas()),
+            // https://spec.json5.org/#file-extension
+            "json5" => Ok(Self::json5()),
             _ => Err(FileSourceError::UnknownExtension(
                 Default::default(),
                 extension.into(),
@@ -236,6 +256,7 @@ impl JsonFileSource {
         match language_id {
             "json" => Ok(Self::json()),
             "jsonc" | "snippets" => Ok(Self::json_allow_comments_and_trailing_commas()),
+            "json5" => Ok(Self::json5()),
             _ => Err(FileSourceError::UnknownLanguageId(language_id.into())),
         }
     }
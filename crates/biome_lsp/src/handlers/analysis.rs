@@ -6,16 +6,13 @@ use anyhow::{Context, Result};
 use biome_analyze::{ActionCategory, SourceActionKind};
 use biome_diagnostics::Applicability;
 use biome_fs::BiomePath;
-use biome_rowan::{TextRange, TextSize};
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
 use biome_service::workspace::{
-    FeatureName, FeaturesBuilder, FixFileMode, FixFileParams, GetFileContentParams,
-    PullActionsParams, SupportsFeatureParams,
+    FeatureName, FeaturesBuilder, FixFileMode, FixFileParams, PullActionsParams,
+    SupportsFeatureParams,
 };
 use biome_service::WorkspaceError;
 use std::borrow::Cow;
 use std::collections::HashMap;
-use std::ops::Sub;
 use tower_lsp::lsp_types::{
     self as lsp, CodeActionKind, CodeActionOrCommand, CodeActionParams, CodeActionResponse,
 };
@@ -76,15 +73,6 @@ pub(crate) fn code_actions(
     let position_encoding = session.position_encoding();
 
     let diagnostics = params.context.diagnostics;
-    let content = session.workspace.get_file_content(GetFileContentParams {
-        path: biome_path.clone(),
-    })?;
-    let offset = match biome_path.extension().and_then(|s| s.to_str()) {
-        Some("vue") => VueFileHandler::start(content.as_str()),
-        Some("astro") => AstroFileHandler::start(content.as_str()),
-        Some("svelte") => SvelteFileHandler::start(content.as_str()),
-        _ => None,
-    };
     let cursor_range = from_proto::text_range(&doc.line_index, params.range, position_encoding)
         .with_context(|| {
             format!(
@@ -92,18 +80,6 @@ pub(crate) fn code_actions(
                 params.range, &doc.line_index,
             )
         })?;
-    let cursor_range = if let Some(offset) = offset {
-        if cursor_range.start().gt(&TextSize::from(offset)) {
-            TextRange::new(
-                cursor_range.start().sub(TextSize::from(offset)),
-                cursor_range.end().sub(TextSize::from(offset)),
-            )
-        } else {
-            cursor_range
-        }
-    } else {
-        cursor_range
-    };
 
     debug!("Cursor range {:?}", &cursor_range);
 
@@ -133,7 +109,6 @@ pub(crate) fn code_actions(
             biome_path.clone(),
             &doc.line_index,
             &diagnostics,
-            offset,
         )?
     } else {
         None
@@ -172,7 +147,6 @@ pub(crate) fn code_actions(
                 position_encoding,
                 &diagnostics,
                 action,
-                offset,
             )
             .ok()?;
 
@@ -208,7 +182,6 @@ fn fix_all(
     biome_path: BiomePath,
     line_index: &LineIndex,
     diagnostics: &[lsp::Diagnostic],
-    offset: Option<u32>,
 ) -> Result<Option<CodeActionOrCommand>, WorkspaceError> {
     let should_format = session
         .workspace
@@ -221,6 +194,8 @@ fn fix_all(
         path: biome_path,
         fix_file_mode: FixFileMode::SafeFixes,
         should_format,
+        rule: None,
+        suppression_reason: None,
     })?;
 
     if fixed.actions.is_empty() {
@@ -240,18 +215,6 @@ fn fix_all(
             let position_encoding = session.position_encoding();
 
             let diag_range = from_proto::text_range(line_index, d.range, position_encoding).ok()?;
-            let diag_range = if let Some(offset) = offset {
-                if diag_range.start().gt(&TextSize::from(offset)) {
-                    TextRange::new(
-                        diag_range.start().sub(TextSize::from(offset)),
-                        diag_range.end().sub(TextSize::from(offset)),
-                    )
-                } else {
-                    diag_range
-                }
-            } else {
-                diag_range
-            };
             let has_matching_rule = fixed.actions.iter().any(|action| {
                 let Some((group_name, rule_name)) = &action.rule_name else {
                     return false;
@@ -12,10 +12,10 @@ use biome_fs::{BiomePath, FileSystem};
 use biome_service::configuration::{
     load_configuration, LoadedConfiguration, PartialConfigurationExt,
 };
-use biome_service::file_handlers::{AstroFileHandler, SvelteFileHandler, VueFileHandler};
+use biome_service::project::{find_tailwind_config, find_workspace_packages};
 use biome_service::workspace::{
-    FeaturesBuilder, GetFileContentParams, OpenProjectParams, PullDiagnosticsParams,
-    RegisterProjectFolderParams, SupportsFeatureParams, UpdateProjectParams,
+    FeaturesBuilder, OpenProjectParams, PullDiagnosticsParams, RegisterProjectFolderParams,
+    SupportsFeatureParams, UpdateProjectParams,
 };
 use biome_service::workspace::{RageEntry, RageParams, RageResult, UpdateSettingsParams};
 use biome_service::Workspace;
@@ -315,16 +315,6 @@ impl Session {
             })?;
 
             tracing::trace!("biome diagnostics: {:#?}", result.diagnostics);
-            let content = self.workspace.get_file_content(GetFileContentParams {
-                path: biome_path.clone(),
-            })?;
-            let offset = match biome_path.extension().and_then(|s| s.to_str()) {
-                Some("vue") => VueFileHandler::start(content.as_str()),
-                Some("astro") => AstroFileHandler::start(content.as_str()),
-                Some("svelte") => SvelteFileHandler::start(content.as_str()),
-                _ => None,
-            };
-
             result
                 .diagnostics
                 .into_iter()
@@ -334,7 +324,6 @@ impl Session {
                         &url,
                         &doc.line_index,
                         self.position_encoding(),
-                        offset,
                     ) {
                         Ok(diag) => Some(diag),
                         Err(err) => {
@@ -539,6 +528,10 @@ impl Session {
             match result {
                 Ok(result) => {
                     if let Some(result) = result {
+                        let tsconfig_path = result.file_path.with_file_name("tsconfig.json");
+                        let tailwind_config = find_tailwind_config(&*self.fs, &result.file_path);
+                        let workspace_packages =
+                            find_workspace_packages(&*self.fs, &result.file_path, &result.content);
                         let biome_path = BiomePath::new(result.file_path);
                         let result = self.workspace.open_project(OpenProjectParams {
                             path: biome_path.clone(),
@@ -548,9 +541,58 @@ impl Session {
                         if let Err(err) = result {
                             error!("{}", err);
                         }
-                        let result = self
-                            .workspace
-                            .update_current_project(UpdateProjectParams { path: biome_path });
+                        let tsconfig_path = match self.fs.read_file_from_path(&tsconfig_path) {
+                            Ok(content) => {
+                                let tsconfig_path = BiomePath::new(tsconfig_path);
+                                let result = self.workspace.open_project(OpenProjectParams {
+                                    path: tsconfig_path.clone(),
+                                    content,
+                                    version: 0,
+                                });
+                                match result {
+                                    Ok(()) => Some(tsconfig_path),
+                                    Err(err) => {
+                                        error!("{}", err);
+                                        None
+                                    }
+                                }
+                            }
+                            Err(_) => None,
+                        };
+                        let tailwind_config_path = tailwind_config.and_then(|tailwind_config| {
+                            let tailwind_config_path = BiomePath::new(tailwind_config.file_path);
+                            let result = self.workspace.open_project(OpenProjectParams {
+                                path: tailwind_config_path.clone(),
+                                content: tailwind_config.content,
+                                version: 0,
+                            });
+                            match result {
+                                Ok(()) => Some(tailwind_config_path),
+                                Err(err) => {
+                                    error!("{}", err);
+                                    None
+                                }
+                            }
+                        });
+                        let mut workspace_package_paths = Vec::new();
+                        for workspace_package in workspace_packages {
+                            let path = BiomePath::new(workspace_package.file_path);
+                            let result = self.workspace.open_project(OpenProjectParams {
+                                path: path.clone(),
+                                content: workspace_package.content,
+                                version: 0,
+                            });
+                            match result {
+                                Ok(()) => workspace_package_paths.push(path),
+                                Err(err) => error!("{}", err),
+                            }
+                        }
+                        let result = self.workspace.update_current_project(UpdateProjectParams {
+                            path: biome_path,
+                            tsconfig_path,
+                            tailwind_config_path,
+                            workspace_package_paths,
+                        });
                         if let Err(err) = result {
                             error!("{}", err);
                         }
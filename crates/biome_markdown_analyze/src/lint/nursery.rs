@@ -0,0 +1,14 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub mod no_undefined_markdown_references;
+
+declare_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_undefined_markdown_references :: NoUndefinedMarkdownReferences ,
+        ]
+     }
+}
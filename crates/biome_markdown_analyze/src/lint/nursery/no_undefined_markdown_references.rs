@@ -0,0 +1,268 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_markdown_syntax::{MarkdownDocument, MarkdownLinkDefinition, MarkdownTextual};
+use biome_rowan::{AstNode, TextRange, TextSize};
+use rustc_hash::FxHashSet;
+use std::ops::Range;
+
+declare_rule! {
+    /// Disallow the reference links whose label doesn't match a link definition.
+    ///
+    /// A reference link, such as `[Biome][biome]`, `[biome][]` or `[biome]`,
+    /// uses the destination of the link definition that has the same label, such as `[biome]: https://biomejs.dev`.
+    /// The labels are compared without their case, and with their whitespaces collapsed.
+    /// When the document doesn't define the label, the link is rendered as text, with its brackets.
+    ///
+    /// The rule ignores the brackets of the task list items, `[ ]` and `[x]`,
+    /// of the footnotes, such as `[^1]`, and of the alerts, such as `[!NOTE]`.
+    /// The brackets that follow a word, such as `array[0]`, aren't reported either.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```md,expect_diagnostic
+    /// Read [the documentation][docs].
+    /// ```
+    ///
+    /// ```md,expect_diagnostic
+    /// Read [the documentation].
+    ///
+    /// [documentation]: https://biomejs.dev
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```md
+    /// Read [the documentation][docs] and [the Docs][].
+    ///
+    /// [docs]: https://biomejs.dev
+    /// [the docs]: https://biomejs.dev
+    /// ```
+    ///
+    pub NoUndefinedMarkdownReferences {
+        version: "next",
+        name: "noUndefinedMarkdownReferences",
+        language: "md",
+        recommended: false,
+    }
+}
+
+pub struct UndefinedReference {
+    /// The label as it's written in the document
+    label: String,
+    /// The range of the whole reference link
+    range: TextRange,
+}
+
+impl Rule for NoUndefinedMarkdownReferences {
+    type Query = Ast<MarkdownDocument>;
+    type State = UndefinedReference;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let document = ctx.query().syntax();
+
+        // The definitions apply to the whole document, including the ones that follow the references
+        let definitions: FxHashSet<String> = document
+            .descendants()
+            .filter_map(MarkdownLinkDefinition::cast)
+            .filter_map(|definition| {
+                let value_token = definition.value_token().ok()?;
+                definition_label(value_token.text_trimmed()).map(normalize_label)
+            })
+            .collect();
+
+        let mut undefined = Vec::new();
+        for textual in document.descendants().filter_map(MarkdownTextual::cast) {
+            let Ok(value_token) = textual.value_token() else {
+                continue;
+            };
+            let text = value_token.text_trimmed();
+            let start = value_token.text_trimmed_range().start();
+
+            for reference in references(text) {
+                let label = &text[reference.label];
+                if !definitions.contains(&normalize_label(label)) {
+                    undefined.push(UndefinedReference {
+                        label: label.to_string(),
+                        range: TextRange::new(
+                            start + TextSize::from(reference.range.start as u32),
+                            start + TextSize::from(reference.range.end as u32),
+                        ),
+                    });
+                }
+            }
+        }
+        undefined
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let label = &state.label;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The label "<Emphasis>{label}</Emphasis>" isn't defined."
+                },
+            )
+            .note(markup! {
+                "This link is rendered as text, because the document doesn't have a link definition with this label."
+            })
+            .note(markup! {
+                "Add a link definition, such as "<Emphasis>"["{label}"]: https://example.com"</Emphasis>", or use an inline link."
+            }),
+        )
+    }
+}
+
+/// A reference link in a line of text.
+struct Reference {
+    /// The range of the whole link, such as `[text][label]`
+    range: Range<usize>,
+    /// The range of the label, `label` in `[text][label]` and `text` in `[text][]` or `[text]`
+    label: Range<usize>,
+}
+
+/// Returns the reference links of a line of text.
+fn references(text: &str) -> Vec<Reference> {
+    let bytes = text.as_bytes();
+    let mut references = Vec::new();
+    let mut position = 0;
+
+    while position < bytes.len() {
+        match bytes[position] {
+            b'\\' => position += 2,
+            b'`' => position = code_span_end(text, position),
+            b'[' => {
+                let Some(text_end) = closing_bracket(text, position) else {
+                    position += 1;
+                    continue;
+                };
+                let link_text = position + 1..text_end;
+                let after = text_end + 1;
+
+                match bytes.get(after) {
+                    // An inline link, `[text](destination)`
+                    Some(b'(') => position = after,
+                    // A full reference link, `[text][label]`, or a collapsed one, `[text][]`
+                    Some(b'[') => {
+                        let Some(label_end) = closing_bracket(text, after) else {
+                            position = after;
+                            continue;
+                        };
+                        let label = if label_end == after + 1 {
+                            link_text
+                        } else {
+                            after + 1..label_end
+                        };
+                        if !text[label.clone()].trim().is_empty() {
+                            references.push(Reference {
+                                range: position..label_end + 1,
+                                label,
+                            });
+                        }
+                        position = label_end + 1;
+                    }
+                    // A shortcut reference link, `[label]`
+                    _ => {
+                        let follows_word = text[..position]
+                            .chars()
+                            .next_back()
+                            .is_some_and(|char| char.is_alphanumeric() || char == '_');
+                        if !follows_word && is_shortcut_label(&text[link_text.clone()]) {
+                            references.push(Reference {
+                                range: position..after,
+                                label: link_text,
+                            });
+                        }
+                        position = after;
+                    }
+                }
+            }
+            _ => position += 1,
+        }
+    }
+
+    references
+}
+
+/// Returns `true` if the text between brackets can be the label of a shortcut reference link.
+fn is_shortcut_label(label: &str) -> bool {
+    let trimmed = label.trim();
+    !trimmed.is_empty()
+        // A task list item
+        && !matches!(label, " " | "x" | "X")
+        // A footnote or an alert
+        && !trimmed.starts_with(['^', '!'])
+        // A link with an image, `[![alt](image)](destination)`, or a nested link
+        && !trimmed.contains(['[', ']'])
+}
+
+/// Returns the index of the bracket that closes the bracket at `start`,
+/// the nested brackets, the escaped brackets and the brackets of the code spans are skipped.
+fn closing_bracket(text: &str, start: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0;
+    let mut position = start;
+
+    while position < bytes.len() {
+        match bytes[position] {
+            b'\\' => {
+                position += 2;
+                continue;
+            }
+            b'`' => {
+                position = code_span_end(text, position);
+                continue;
+            }
+            b'[' => depth += 1,
+            b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(position);
+                }
+            }
+            _ => {}
+        }
+        position += 1;
+    }
+
+    None
+}
+
+/// Returns the end of the code span that starts at `start`,
+/// or the end of its opening backticks if it isn't closed on this line.
+fn code_span_end(text: &str, start: usize) -> usize {
+    let backticks = text[start..].len() - text[start..].trim_start_matches('`').len();
+    let content_start = start + backticks;
+
+    let mut position = content_start;
+    while let Some(offset) = text[position..].find('`') {
+        let run_start = position + offset;
+        let run_length = text[run_start..].len() - text[run_start..].trim_start_matches('`').len();
+        if run_length == backticks {
+            return run_start + run_length;
+        }
+        position = run_start + run_length;
+    }
+
+    content_start
+}
+
+/// Returns the label of a link definition, `label` in `[label]: destination`.
+fn definition_label(text: &str) -> Option<&str> {
+    let end = closing_bracket(text, 0)?;
+    text.get(1..end)
+}
+
+/// Returns the label in lowercase, with its whitespaces collapsed into single spaces.
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
@@ -0,0 +1,5 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoUndefinedMarkdownReferences = < lint :: nursery :: no_undefined_markdown_references :: NoUndefinedMarkdownReferences as biome_analyze :: Rule > :: Options ;
@@ -0,0 +1,6 @@
+#[rustfmt::skip]
+pub(super) mod syntax_factory;
+#[rustfmt::skip]
+pub mod node_factory;
+
+pub use syntax_factory::MarkdownSyntaxFactory;
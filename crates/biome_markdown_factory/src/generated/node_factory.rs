@@ -0,0 +1,266 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+use biome_markdown_syntax::{
+    MarkdownSyntaxElement as SyntaxElement, MarkdownSyntaxNode as SyntaxNode,
+    MarkdownSyntaxToken as SyntaxToken, *,
+};
+use biome_rowan::AstNode;
+pub fn markdown_document(
+    blocks: MarkdownBlockList,
+    eof_token: SyntaxToken,
+) -> MarkdownDocumentBuilder {
+    MarkdownDocumentBuilder {
+        blocks,
+        eof_token,
+        bom_token: None,
+        front_matter: None,
+    }
+}
+pub struct MarkdownDocumentBuilder {
+    blocks: MarkdownBlockList,
+    eof_token: SyntaxToken,
+    bom_token: Option<SyntaxToken>,
+    front_matter: Option<MarkdownFrontMatter>,
+}
+impl MarkdownDocumentBuilder {
+    pub fn with_bom_token(mut self, bom_token: SyntaxToken) -> Self {
+        self.bom_token = Some(bom_token);
+        self
+    }
+    pub fn with_front_matter(mut self, front_matter: MarkdownFrontMatter) -> Self {
+        self.front_matter = Some(front_matter);
+        self
+    }
+    pub fn build(self) -> MarkdownDocument {
+        MarkdownDocument::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MARKDOWN_DOCUMENT,
+            [
+                self.bom_token.map(|token| SyntaxElement::Token(token)),
+                self.front_matter
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                Some(SyntaxElement::Node(self.blocks.into_syntax())),
+                Some(SyntaxElement::Token(self.eof_token)),
+            ],
+        ))
+    }
+}
+pub fn markdown_fenced_code_block(l_fence_token: SyntaxToken) -> MarkdownFencedCodeBlockBuilder {
+    MarkdownFencedCodeBlockBuilder {
+        l_fence_token,
+        info_token: None,
+        content_token: None,
+        r_fence_token: None,
+    }
+}
+pub struct MarkdownFencedCodeBlockBuilder {
+    l_fence_token: SyntaxToken,
+    info_token: Option<SyntaxToken>,
+    content_token: Option<SyntaxToken>,
+    r_fence_token: Option<SyntaxToken>,
+}
+impl MarkdownFencedCodeBlockBuilder {
+    pub fn with_info_token(mut self, info_token: SyntaxToken) -> Self {
+        self.info_token = Some(info_token);
+        self
+    }
+    pub fn with_content_token(mut self, content_token: SyntaxToken) -> Self {
+        self.content_token = Some(content_token);
+        self
+    }
+    pub fn with_r_fence_token(mut self, r_fence_token: SyntaxToken) -> Self {
+        self.r_fence_token = Some(r_fence_token);
+        self
+    }
+    pub fn build(self) -> MarkdownFencedCodeBlock {
+        MarkdownFencedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MARKDOWN_FENCED_CODE_BLOCK,
+            [
+                Some(SyntaxElement::Token(self.l_fence_token)),
+                self.info_token.map(|token| SyntaxElement::Token(token)),
+                self.content_token.map(|token| SyntaxElement::Token(token)),
+                self.r_fence_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn markdown_front_matter(value_token: SyntaxToken) -> MarkdownFrontMatter {
+    MarkdownFrontMatter::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_FRONT_MATTER,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn markdown_heading(opening_token: SyntaxToken) -> MarkdownHeadingBuilder {
+    MarkdownHeadingBuilder {
+        opening_token,
+        content: None,
+        closing_token: None,
+    }
+}
+pub struct MarkdownHeadingBuilder {
+    opening_token: SyntaxToken,
+    content: Option<MarkdownTextual>,
+    closing_token: Option<SyntaxToken>,
+}
+impl MarkdownHeadingBuilder {
+    pub fn with_content(mut self, content: MarkdownTextual) -> Self {
+        self.content = Some(content);
+        self
+    }
+    pub fn with_closing_token(mut self, closing_token: SyntaxToken) -> Self {
+        self.closing_token = Some(closing_token);
+        self
+    }
+    pub fn build(self) -> MarkdownHeading {
+        MarkdownHeading::unwrap_cast(SyntaxNode::new_detached(
+            MarkdownSyntaxKind::MARKDOWN_HEADING,
+            [
+                Some(SyntaxElement::Token(self.opening_token)),
+                self.content
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.closing_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn markdown_html_block(value_token: SyntaxToken) -> MarkdownHtmlBlock {
+    MarkdownHtmlBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_HTML_BLOCK,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn markdown_indented_code_block(value_token: SyntaxToken) -> MarkdownIndentedCodeBlock {
+    MarkdownIndentedCodeBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_INDENTED_CODE_BLOCK,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn markdown_link_definition(value_token: SyntaxToken) -> MarkdownLinkDefinition {
+    MarkdownLinkDefinition::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_LINK_DEFINITION,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn markdown_list(items: MarkdownListItemList) -> MarkdownList {
+    MarkdownList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_LIST,
+        [Some(SyntaxElement::Node(items.into_syntax()))],
+    ))
+}
+pub fn markdown_list_item(
+    marker_token: SyntaxToken,
+    content: MarkdownBlockList,
+) -> MarkdownListItem {
+    MarkdownListItem::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_LIST_ITEM,
+        [
+            Some(SyntaxElement::Token(marker_token)),
+            Some(SyntaxElement::Node(content.into_syntax())),
+        ],
+    ))
+}
+pub fn markdown_paragraph(lines: MarkdownTextualList) -> MarkdownParagraph {
+    MarkdownParagraph::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_PARAGRAPH,
+        [Some(SyntaxElement::Node(lines.into_syntax()))],
+    ))
+}
+pub fn markdown_quote(value_token: SyntaxToken) -> MarkdownQuote {
+    MarkdownQuote::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_QUOTE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn markdown_setext_heading(
+    content: MarkdownParagraph,
+    underline_token: SyntaxToken,
+) -> MarkdownSetextHeading {
+    MarkdownSetextHeading::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_SETEXT_HEADING,
+        [
+            Some(SyntaxElement::Node(content.into_syntax())),
+            Some(SyntaxElement::Token(underline_token)),
+        ],
+    ))
+}
+pub fn markdown_textual(value_token: SyntaxToken) -> MarkdownTextual {
+    MarkdownTextual::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_TEXTUAL,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn markdown_thematic_break(value_token: SyntaxToken) -> MarkdownThematicBreak {
+    MarkdownThematicBreak::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_THEMATIC_BREAK,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn mdx_esm_block(value_token: SyntaxToken) -> MdxEsmBlock {
+    MdxEsmBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MDX_ESM_BLOCK,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn mdx_jsx_block(value_token: SyntaxToken) -> MdxJsxBlock {
+    MdxJsxBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MDX_JSX_BLOCK,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn markdown_block_list<I>(items: I) -> MarkdownBlockList
+where
+    I: IntoIterator<Item = AnyMarkdownBlock>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MarkdownBlockList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_BLOCK_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn markdown_list_item_list<I>(items: I) -> MarkdownListItemList
+where
+    I: IntoIterator<Item = MarkdownListItem>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MarkdownListItemList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_LIST_ITEM_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn markdown_textual_list<I>(items: I) -> MarkdownTextualList
+where
+    I: IntoIterator<Item = MarkdownTextual>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MarkdownTextualList::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_TEXTUAL_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn markdown_bogus<I>(slots: I) -> MarkdownBogus
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MarkdownBogus::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_BOGUS,
+        slots,
+    ))
+}
+pub fn markdown_bogus_block<I>(slots: I) -> MarkdownBogusBlock
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    MarkdownBogusBlock::unwrap_cast(SyntaxNode::new_detached(
+        MarkdownSyntaxKind::MARKDOWN_BOGUS_BLOCK,
+        slots,
+    ))
+}
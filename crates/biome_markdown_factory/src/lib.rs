@@ -0,0 +1,12 @@
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_rowan::TreeBuilder;
+
+mod generated;
+pub mod make;
+pub use crate::generated::MarkdownSyntaxFactory;
+
+// Re-exported for tests
+#[doc(hidden)]
+pub use biome_markdown_syntax as syntax;
+
+pub type MarkdownSyntaxTreeBuilder = TreeBuilder<'static, MarkdownLanguage, MarkdownSyntaxFactory>;
@@ -0,0 +1 @@
+pub use crate::generated::node_factory::*;
@@ -0,0 +1,225 @@
+use crate::comments::{FormatMarkdownLeadingComment, MarkdownComments};
+use crate::MarkdownCommentStyle;
+use biome_deserialize_macros::{Deserializable, Merge};
+use biome_formatter::{prelude::*, AttributePosition, IndentWidth, MaxEmptyLines};
+use biome_formatter::{
+    CstFormatContext, FormatContext, FormatOptions, IndentStyle, LineEnding, LineWidth,
+    TransformSourceMap,
+};
+use biome_markdown_syntax::MarkdownLanguage;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct MarkdownFormatContext {
+    options: MarkdownFormatOptions,
+    /// The comments of the nodes and tokens in the program.
+    comments: Rc<MarkdownComments>,
+    source_map: Option<TransformSourceMap>,
+}
+
+impl MarkdownFormatContext {
+    pub fn new(options: MarkdownFormatOptions, comments: MarkdownComments) -> Self {
+        Self {
+            options,
+            comments: Rc::new(comments),
+            source_map: None,
+        }
+    }
+
+    pub fn with_source_map(mut self, source_map: Option<TransformSourceMap>) -> Self {
+        self.source_map = source_map;
+        self
+    }
+}
+
+impl FormatContext for MarkdownFormatContext {
+    type Options = MarkdownFormatOptions;
+
+    fn options(&self) -> &Self::Options {
+        &self.options
+    }
+
+    fn source_map(&self) -> Option<&TransformSourceMap> {
+        None
+    }
+}
+
+impl CstFormatContext for MarkdownFormatContext {
+    type Language = MarkdownLanguage;
+    type Style = MarkdownCommentStyle;
+    type CommentRule = FormatMarkdownLeadingComment;
+
+    fn comments(&self) -> &MarkdownComments {
+        &self.comments
+    }
+}
+
+/// Formats the code of the fenced code blocks, and the JSX code of the MDX blocks.
+///
+/// The Markdown formatter doesn't know the other languages:
+/// the code of a fenced code block is printed as it is, unless a formatter of its language is provided.
+pub trait FormatEmbeddedCode: fmt::Debug {
+    /// Returns the formatted `code`, or `None` if it can't be formatted.
+    ///
+    /// `language` is the first word of the info string of the code block, such as `js` or `css`,
+    /// or `jsx` for the imports, the exports and the elements of an MDX document.
+    fn format_embedded_code(&self, language: &str, code: &str) -> Option<String>;
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MarkdownFormatOptions {
+    /// The list items are always indented with spaces, to align their content with their marker
+    indent_style: IndentStyle,
+    indent_width: IndentWidth,
+    line_ending: LineEnding,
+    line_width: LineWidth,
+    prose_wrap: ProseWrap,
+    attribute_position: AttributePosition,
+    embedded_code_formatter: Option<Rc<dyn FormatEmbeddedCode>>,
+}
+
+impl MarkdownFormatOptions {
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_prose_wrap(mut self, prose_wrap: ProseWrap) -> Self {
+        self.prose_wrap = prose_wrap;
+        self
+    }
+
+    pub fn with_embedded_code_formatter(
+        mut self,
+        embedded_code_formatter: impl FormatEmbeddedCode + 'static,
+    ) -> Self {
+        self.embedded_code_formatter = Some(Rc::new(embedded_code_formatter));
+        self
+    }
+
+    pub fn set_line_ending(&mut self, line_ending: LineEnding) {
+        self.line_ending = line_ending;
+    }
+
+    pub fn set_line_width(&mut self, line_width: LineWidth) {
+        self.line_width = line_width;
+    }
+
+    pub fn set_prose_wrap(&mut self, prose_wrap: ProseWrap) {
+        self.prose_wrap = prose_wrap;
+    }
+
+    pub fn prose_wrap(&self) -> ProseWrap {
+        self.prose_wrap
+    }
+
+    pub fn embedded_code_formatter(&self) -> Option<&dyn FormatEmbeddedCode> {
+        self.embedded_code_formatter.as_deref()
+    }
+}
+
+impl FormatOptions for MarkdownFormatOptions {
+    fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
+    fn as_print_options(&self) -> PrinterOptions {
+        PrinterOptions::from(self)
+    }
+
+    fn attribute_position(&self) -> AttributePosition {
+        self.attribute_position
+    }
+
+    fn max_empty_lines(&self) -> MaxEmptyLines {
+        MaxEmptyLines::default()
+    }
+}
+
+impl fmt::Display for MarkdownFormatOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Line ending: {}", self.line_ending)?;
+        writeln!(f, "Line width: {}", self.line_width.get())?;
+        writeln!(f, "Prose wrap: {}", self.prose_wrap)
+    }
+}
+
+/// How the lines of the paragraphs are wrapped.
+#[derive(Clone, Copy, Debug, Default, Deserializable, Eq, Hash, Merge, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize, schemars::JsonSchema),
+    serde(rename_all = "camelCase")
+)]
+pub enum ProseWrap {
+    /// The line breaks of the paragraphs are kept as they are.
+    #[default]
+    Preserve,
+    /// The paragraphs are wrapped at the line width.
+    Always,
+    /// Every paragraph is printed on a single line.
+    Never,
+}
+
+impl ProseWrap {
+    pub const fn is_preserve(&self) -> bool {
+        matches!(self, Self::Preserve)
+    }
+
+    pub const fn is_always(&self) -> bool {
+        matches!(self, Self::Always)
+    }
+
+    pub const fn is_never(&self) -> bool {
+        matches!(self, Self::Never)
+    }
+}
+
+// Required by [Bpaf]
+impl FromStr for ProseWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" | "Preserve" => Ok(Self::Preserve),
+            "always" | "Always" => Ok(Self::Always),
+            "never" | "Never" => Ok(Self::Never),
+            _ => Err("Value not supported for Prose wrap. Supported values are 'preserve', 'always' and 'never'."),
+        }
+    }
+}
+
+impl fmt::Display for ProseWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProseWrap::Preserve => std::write!(f, "Preserve"),
+            ProseWrap::Always => std::write!(f, "Always"),
+            ProseWrap::Never => std::write!(f, "Never"),
+        }
+    }
+}
@@ -0,0 +1,829 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::{
+    AsFormat, FormatBogusNodeRule, FormatNodeRule, IntoFormat, MarkdownFormatContext,
+    MarkdownFormatter,
+};
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_markdown_syntax::MarkdownDocument>
+    for crate::markdown::auxiliary::document::FormatMarkdownDocument
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownDocument,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownDocument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownDocument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownDocument,
+        crate::markdown::auxiliary::document::FormatMarkdownDocument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::document::FormatMarkdownDocument::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownDocument {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownDocument,
+        crate::markdown::auxiliary::document::FormatMarkdownDocument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::document::FormatMarkdownDocument::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownFencedCodeBlock>
+    for crate::markdown::auxiliary::fenced_code_block::FormatMarkdownFencedCodeBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownFencedCodeBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownFencedCodeBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownFencedCodeBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownFencedCodeBlock,
+        crate::markdown::auxiliary::fenced_code_block::FormatMarkdownFencedCodeBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::fenced_code_block::FormatMarkdownFencedCodeBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownFencedCodeBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownFencedCodeBlock,
+        crate::markdown::auxiliary::fenced_code_block::FormatMarkdownFencedCodeBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::fenced_code_block::FormatMarkdownFencedCodeBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownFrontMatter>
+    for crate::markdown::auxiliary::front_matter::FormatMarkdownFrontMatter
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownFrontMatter,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownFrontMatter>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownFrontMatter {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownFrontMatter,
+        crate::markdown::auxiliary::front_matter::FormatMarkdownFrontMatter,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::front_matter::FormatMarkdownFrontMatter::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownFrontMatter {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownFrontMatter,
+        crate::markdown::auxiliary::front_matter::FormatMarkdownFrontMatter,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::front_matter::FormatMarkdownFrontMatter::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownHeading>
+    for crate::markdown::auxiliary::heading::FormatMarkdownHeading
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownHeading,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownHeading>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownHeading {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownHeading,
+        crate::markdown::auxiliary::heading::FormatMarkdownHeading,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::heading::FormatMarkdownHeading::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownHeading {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownHeading,
+        crate::markdown::auxiliary::heading::FormatMarkdownHeading,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::heading::FormatMarkdownHeading::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownHtmlBlock>
+    for crate::markdown::auxiliary::html_block::FormatMarkdownHtmlBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownHtmlBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownHtmlBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownHtmlBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownHtmlBlock,
+        crate::markdown::auxiliary::html_block::FormatMarkdownHtmlBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::html_block::FormatMarkdownHtmlBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownHtmlBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownHtmlBlock,
+        crate::markdown::auxiliary::html_block::FormatMarkdownHtmlBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::html_block::FormatMarkdownHtmlBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownIndentedCodeBlock>
+    for crate::markdown::auxiliary::indented_code_block::FormatMarkdownIndentedCodeBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownIndentedCodeBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownIndentedCodeBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownIndentedCodeBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownIndentedCodeBlock,
+        crate::markdown::auxiliary::indented_code_block::FormatMarkdownIndentedCodeBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: markdown :: auxiliary :: indented_code_block :: FormatMarkdownIndentedCodeBlock :: default ())
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownIndentedCodeBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownIndentedCodeBlock,
+        crate::markdown::auxiliary::indented_code_block::FormatMarkdownIndentedCodeBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: markdown :: auxiliary :: indented_code_block :: FormatMarkdownIndentedCodeBlock :: default ())
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownLinkDefinition>
+    for crate::markdown::auxiliary::link_definition::FormatMarkdownLinkDefinition
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownLinkDefinition,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownLinkDefinition>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownLinkDefinition {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownLinkDefinition,
+        crate::markdown::auxiliary::link_definition::FormatMarkdownLinkDefinition,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::link_definition::FormatMarkdownLinkDefinition::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownLinkDefinition {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownLinkDefinition,
+        crate::markdown::auxiliary::link_definition::FormatMarkdownLinkDefinition,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::link_definition::FormatMarkdownLinkDefinition::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownList>
+    for crate::markdown::auxiliary::list::FormatMarkdownList
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownList,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownList>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownList,
+        crate::markdown::auxiliary::list::FormatMarkdownList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::list::FormatMarkdownList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownList,
+        crate::markdown::auxiliary::list::FormatMarkdownList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::list::FormatMarkdownList::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownListItem>
+    for crate::markdown::auxiliary::list_item::FormatMarkdownListItem
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownListItem,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownListItem>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownListItem {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownListItem,
+        crate::markdown::auxiliary::list_item::FormatMarkdownListItem,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::list_item::FormatMarkdownListItem::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownListItem {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownListItem,
+        crate::markdown::auxiliary::list_item::FormatMarkdownListItem,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::list_item::FormatMarkdownListItem::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownParagraph>
+    for crate::markdown::auxiliary::paragraph::FormatMarkdownParagraph
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownParagraph,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownParagraph>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownParagraph {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownParagraph,
+        crate::markdown::auxiliary::paragraph::FormatMarkdownParagraph,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::paragraph::FormatMarkdownParagraph::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownParagraph {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownParagraph,
+        crate::markdown::auxiliary::paragraph::FormatMarkdownParagraph,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::paragraph::FormatMarkdownParagraph::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownQuote>
+    for crate::markdown::auxiliary::quote::FormatMarkdownQuote
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownQuote,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownQuote>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownQuote {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownQuote,
+        crate::markdown::auxiliary::quote::FormatMarkdownQuote,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::quote::FormatMarkdownQuote::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownQuote {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownQuote,
+        crate::markdown::auxiliary::quote::FormatMarkdownQuote,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::quote::FormatMarkdownQuote::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownSetextHeading>
+    for crate::markdown::auxiliary::setext_heading::FormatMarkdownSetextHeading
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownSetextHeading,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownSetextHeading>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownSetextHeading {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownSetextHeading,
+        crate::markdown::auxiliary::setext_heading::FormatMarkdownSetextHeading,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::setext_heading::FormatMarkdownSetextHeading::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownSetextHeading {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownSetextHeading,
+        crate::markdown::auxiliary::setext_heading::FormatMarkdownSetextHeading,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::setext_heading::FormatMarkdownSetextHeading::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownTextual>
+    for crate::markdown::auxiliary::textual::FormatMarkdownTextual
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownTextual,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownTextual>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownTextual {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownTextual,
+        crate::markdown::auxiliary::textual::FormatMarkdownTextual,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::textual::FormatMarkdownTextual::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownTextual {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownTextual,
+        crate::markdown::auxiliary::textual::FormatMarkdownTextual,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::textual::FormatMarkdownTextual::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownThematicBreak>
+    for crate::markdown::auxiliary::thematic_break::FormatMarkdownThematicBreak
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownThematicBreak,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MarkdownThematicBreak>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownThematicBreak {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownThematicBreak,
+        crate::markdown::auxiliary::thematic_break::FormatMarkdownThematicBreak,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::auxiliary::thematic_break::FormatMarkdownThematicBreak::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownThematicBreak {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownThematicBreak,
+        crate::markdown::auxiliary::thematic_break::FormatMarkdownThematicBreak,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::auxiliary::thematic_break::FormatMarkdownThematicBreak::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdxEsmBlock>
+    for crate::mdx::auxiliary::esm_block::FormatMdxEsmBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdxEsmBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdxEsmBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdxEsmBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdxEsmBlock,
+        crate::mdx::auxiliary::esm_block::FormatMdxEsmBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::mdx::auxiliary::esm_block::FormatMdxEsmBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdxEsmBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdxEsmBlock,
+        crate::mdx::auxiliary::esm_block::FormatMdxEsmBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::mdx::auxiliary::esm_block::FormatMdxEsmBlock::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MdxJsxBlock>
+    for crate::mdx::auxiliary::jsx_block::FormatMdxJsxBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MdxJsxBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_markdown_syntax::MdxJsxBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MdxJsxBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MdxJsxBlock,
+        crate::mdx::auxiliary::jsx_block::FormatMdxJsxBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::mdx::auxiliary::jsx_block::FormatMdxJsxBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MdxJsxBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MdxJsxBlock,
+        crate::mdx::auxiliary::jsx_block::FormatMdxJsxBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::mdx::auxiliary::jsx_block::FormatMdxJsxBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownBlockList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownBlockList,
+        crate::markdown::lists::block_list::FormatMarkdownBlockList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::block_list::FormatMarkdownBlockList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownBlockList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownBlockList,
+        crate::markdown::lists::block_list::FormatMarkdownBlockList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::block_list::FormatMarkdownBlockList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownListItemList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownListItemList,
+        crate::markdown::lists::list_item_list::FormatMarkdownListItemList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::list_item_list::FormatMarkdownListItemList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownListItemList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownListItemList,
+        crate::markdown::lists::list_item_list::FormatMarkdownListItemList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::list_item_list::FormatMarkdownListItemList::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownTextualList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownTextualList,
+        crate::markdown::lists::textual_list::FormatMarkdownTextualList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::lists::textual_list::FormatMarkdownTextualList::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownTextualList {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownTextualList,
+        crate::markdown::lists::textual_list::FormatMarkdownTextualList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::lists::textual_list::FormatMarkdownTextualList::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownBogus>
+    for crate::markdown::bogus::bogus::FormatMarkdownBogus
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownBogus,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_markdown_syntax::MarkdownBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownBogus {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownBogus,
+        crate::markdown::bogus::bogus::FormatMarkdownBogus,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::bogus::bogus::FormatMarkdownBogus::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownBogus {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownBogus,
+        crate::markdown::bogus::bogus::FormatMarkdownBogus,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::bogus::bogus::FormatMarkdownBogus::default(),
+        )
+    }
+}
+impl FormatRule<biome_markdown_syntax::MarkdownBogusBlock>
+    for crate::markdown::bogus::bogus_block::FormatMarkdownBogusBlock
+{
+    type Context = MarkdownFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_markdown_syntax::MarkdownBogusBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_markdown_syntax::MarkdownBogusBlock>::fmt(self, node, f)
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownBogusBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::MarkdownBogusBlock,
+        crate::markdown::bogus::bogus_block::FormatMarkdownBogusBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::bogus::bogus_block::FormatMarkdownBogusBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::MarkdownBogusBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::MarkdownBogusBlock,
+        crate::markdown::bogus::bogus_block::FormatMarkdownBogusBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::bogus::bogus_block::FormatMarkdownBogusBlock::default(),
+        )
+    }
+}
+impl AsFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMarkdownBlock {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_markdown_syntax::AnyMarkdownBlock,
+        crate::markdown::any::block::FormatAnyMarkdownBlock,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::markdown::any::block::FormatAnyMarkdownBlock::default(),
+        )
+    }
+}
+impl IntoFormat<MarkdownFormatContext> for biome_markdown_syntax::AnyMarkdownBlock {
+    type Format = FormatOwnedWithRule<
+        biome_markdown_syntax::AnyMarkdownBlock,
+        crate::markdown::any::block::FormatAnyMarkdownBlock,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::markdown::any::block::FormatAnyMarkdownBlock::default(),
+        )
+    }
+}
@@ -0,0 +1,26 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_markdown_syntax::AnyMarkdownBlock;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyMarkdownBlock;
+impl FormatRule<AnyMarkdownBlock> for FormatAnyMarkdownBlock {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &AnyMarkdownBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        match node {
+            AnyMarkdownBlock::MarkdownBogusBlock(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownFencedCodeBlock(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownHeading(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownHtmlBlock(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownIndentedCodeBlock(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownLinkDefinition(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownList(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownParagraph(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownQuote(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownSetextHeading(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MarkdownThematicBreak(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MdxEsmBlock(node) => node.format().fmt(f),
+            AnyMarkdownBlock::MdxJsxBlock(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownDocument, MarkdownDocumentFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownDocument;
+
+impl FormatNodeRule<MarkdownDocument> for FormatMarkdownDocument {
+    fn fmt_fields(&self, node: &MarkdownDocument, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MarkdownDocumentFields {
+            bom_token,
+            front_matter,
+            blocks,
+            eof_token,
+        } = node.as_fields();
+
+        write!(f, [bom_token.format(), front_matter.format()])?;
+
+        if front_matter.is_some() && !blocks.is_empty() {
+            write!(f, [empty_line()])?;
+        }
+
+        write!(
+            f,
+            [
+                blocks.format(),
+                format_removed(&eof_token?),
+                hard_line_break()
+            ]
+        )
+    }
+}
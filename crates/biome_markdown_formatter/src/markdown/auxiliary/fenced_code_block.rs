@@ -0,0 +1,100 @@
+use crate::prelude::*;
+use crate::utils::{token_column, FormatLines};
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownFencedCodeBlock, MarkdownFencedCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownFencedCodeBlock;
+
+impl FormatNodeRule<MarkdownFencedCodeBlock> for FormatMarkdownFencedCodeBlock {
+    fn fmt_fields(
+        &self,
+        node: &MarkdownFencedCodeBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MarkdownFencedCodeBlockFields {
+            l_fence_token,
+            info_token,
+            content_token,
+            r_fence_token,
+        } = node.as_fields();
+        let l_fence_token = l_fence_token?;
+        let info = info_token
+            .as_ref()
+            .map_or("", |info_token| info_token.text_trimmed());
+
+        // The lines of the code are indented like the opening fence
+        let indentation = token_column(&l_fence_token);
+        let code = content_token.as_ref().map(|content_token| {
+            FormatLines::new(
+                content_token.text_trimmed(),
+                content_token.text_trimmed_range().start(),
+                indentation,
+            )
+        });
+
+        let language = info.split_whitespace().next().unwrap_or_default();
+        let formatted_code = match (&code, f.options().embedded_code_formatter()) {
+            (Some(code), Some(formatter)) if !language.is_empty() => {
+                formatter.format_embedded_code(language, &code.text())
+            }
+            _ => None,
+        };
+        let code = match (&formatted_code, code) {
+            (Some(formatted_code), Some(_)) => Some(FormatLines::formatted(
+                formatted_code.trim_end_matches(['\n', '\r']),
+                node.syntax().text_trimmed_range().start(),
+            )),
+            (_, code) => code,
+        }
+        .map(FormatLines::starting_on_new_line);
+
+        // A backtick in the info string would end a fence of backticks
+        let fence_char = if info.contains('`') { '~' } else { '`' };
+        // The fence is longer than any line of the code that could close it
+        let fence_length = code
+            .iter()
+            .flat_map(|code| code.non_blank_lines())
+            .map(|line| {
+                let line = line.trim_start_matches([' ', '\t']);
+                line.len() - line.trim_start_matches(fence_char).len()
+            })
+            .max()
+            .map_or(3, |length| (length + 1).max(3));
+        let fence = fence_char.to_string().repeat(fence_length);
+
+        write!(
+            f,
+            [
+                format_replaced(
+                    &l_fence_token,
+                    &dynamic_text(&fence, l_fence_token.text_trimmed_range().start())
+                ),
+                info_token.format()
+            ]
+        )?;
+
+        if let (Some(content_token), Some(code)) = (&content_token, &code) {
+            write!(f, [format_replaced(content_token, code)])?;
+        }
+
+        write!(f, [hard_line_break()])?;
+        match r_fence_token {
+            Some(r_fence_token) => write!(
+                f,
+                [format_replaced(
+                    &r_fence_token,
+                    &dynamic_text(&fence, r_fence_token.text_trimmed_range().start())
+                )]
+            ),
+            // The code block isn't closed: it's closed at the end of its container
+            None => write!(
+                f,
+                [dynamic_text(
+                    &fence,
+                    node.syntax().text_trimmed_range().end()
+                )]
+            ),
+        }
+    }
+}
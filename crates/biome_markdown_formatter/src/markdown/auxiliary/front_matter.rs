@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::FormatLines;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownFrontMatter, MarkdownFrontMatterFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownFrontMatter;
+
+impl FormatNodeRule<MarkdownFrontMatter> for FormatMarkdownFrontMatter {
+    fn fmt_fields(
+        &self,
+        node: &MarkdownFrontMatter,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MarkdownFrontMatterFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        let lines = FormatLines::new(
+            value_token.text_trimmed(),
+            value_token.text_trimmed_range().start(),
+            0,
+        );
+        write!(f, [format_replaced(&value_token, &lines)])
+    }
+}
@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownHeading, MarkdownHeadingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownHeading;
+
+impl FormatNodeRule<MarkdownHeading> for FormatMarkdownHeading {
+    fn fmt_fields(&self, node: &MarkdownHeading, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MarkdownHeadingFields {
+            opening_token,
+            content,
+            closing_token,
+        } = node.as_fields();
+
+        write!(f, [opening_token.format()])?;
+
+        if let Some(content) = content {
+            write!(f, [space(), content.format()])?;
+        }
+
+        // The closing sequence is optional: `## Heading ##` is printed `## Heading`
+        if let Some(closing_token) = closing_token {
+            write!(f, [format_removed(&closing_token)])?;
+        }
+
+        Ok(())
+    }
+}
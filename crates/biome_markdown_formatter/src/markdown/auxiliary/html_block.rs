@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use crate::utils::{token_column, FormatLines};
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownHtmlBlock, MarkdownHtmlBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownHtmlBlock;
+
+impl FormatNodeRule<MarkdownHtmlBlock> for FormatMarkdownHtmlBlock {
+    fn fmt_fields(&self, node: &MarkdownHtmlBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MarkdownHtmlBlockFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        // The lines are printed as they are, indented like the first one
+        let lines = FormatLines::new(
+            value_token.text_trimmed(),
+            value_token.text_trimmed_range().start(),
+            token_column(&value_token),
+        );
+        write!(f, [format_replaced(&value_token, &lines)])
+    }
+}
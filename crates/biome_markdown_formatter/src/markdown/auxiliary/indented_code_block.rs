@@ -0,0 +1,27 @@
+use crate::prelude::*;
+use crate::utils::{source_content_column, FormatLines};
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownIndentedCodeBlock, MarkdownIndentedCodeBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownIndentedCodeBlock;
+
+impl FormatNodeRule<MarkdownIndentedCodeBlock> for FormatMarkdownIndentedCodeBlock {
+    fn fmt_fields(
+        &self,
+        node: &MarkdownIndentedCodeBlock,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MarkdownIndentedCodeBlockFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        // The code is indented by four columns more than the content of its list item
+        let lines = FormatLines::new(
+            value_token.text_trimmed(),
+            value_token.text_trimmed_range().start(),
+            source_content_column(node.syntax()) + 4,
+        )
+        .with_prefix("    ");
+        write!(f, [format_replaced(&value_token, &lines)])
+    }
+}
@@ -0,0 +1,17 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownLinkDefinition, MarkdownLinkDefinitionFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownLinkDefinition;
+
+impl FormatNodeRule<MarkdownLinkDefinition> for FormatMarkdownLinkDefinition {
+    fn fmt_fields(
+        &self,
+        node: &MarkdownLinkDefinition,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MarkdownLinkDefinitionFields { value_token } = node.as_fields();
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,14 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownList, MarkdownListFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownList;
+
+impl FormatNodeRule<MarkdownList> for FormatMarkdownList {
+    fn fmt_fields(&self, node: &MarkdownList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MarkdownListFields { items } = node.as_fields();
+
+        write!(f, [items.format()])
+    }
+}
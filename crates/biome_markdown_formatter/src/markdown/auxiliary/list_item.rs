@@ -0,0 +1,50 @@
+use crate::prelude::*;
+use biome_formatter::{write, FormatRuleWithOptions};
+use biome_markdown_syntax::{MarkdownListItem, MarkdownListItemFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownListItem {
+    /// The normalized marker of the item, the marker of the source document is kept if `None`
+    marker: Option<String>,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct FormatMarkdownListItemOptions {
+    pub(crate) marker: Option<String>,
+}
+
+impl FormatRuleWithOptions<MarkdownListItem> for FormatMarkdownListItem {
+    type Options = FormatMarkdownListItemOptions;
+
+    fn with_options(mut self, options: Self::Options) -> Self {
+        self.marker = options.marker;
+        self
+    }
+}
+
+impl FormatNodeRule<MarkdownListItem> for FormatMarkdownListItem {
+    fn fmt_fields(&self, node: &MarkdownListItem, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MarkdownListItemFields { marker, content } = node.as_fields();
+        let marker = marker?;
+
+        let marker_text = self
+            .marker
+            .as_deref()
+            .unwrap_or_else(|| marker.text_trimmed());
+        write!(
+            f,
+            [format_replaced(
+                &marker,
+                &dynamic_text(marker_text, marker.text_trimmed_range().start())
+            )]
+        )?;
+
+        if content.is_empty() {
+            return Ok(());
+        }
+
+        // The lines of the content are aligned with its first line, after the marker
+        let content_width = u8::try_from(marker_text.len() + 1).unwrap_or(u8::MAX);
+        write!(f, [space(), align(content_width, &content.format())])
+    }
+}
@@ -0,0 +1,16 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod document;
+pub(crate) mod fenced_code_block;
+pub(crate) mod front_matter;
+pub(crate) mod heading;
+pub(crate) mod html_block;
+pub(crate) mod indented_code_block;
+pub(crate) mod link_definition;
+pub(crate) mod list;
+pub(crate) mod list_item;
+pub(crate) mod paragraph;
+pub(crate) mod quote;
+pub(crate) mod setext_heading;
+pub(crate) mod textual;
+pub(crate) mod thematic_break;
@@ -0,0 +1,220 @@
+use crate::context::ProseWrap;
+use crate::prelude::*;
+use crate::utils::{hard_line_break_after, HardLineBreak};
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownParagraph, MarkdownParagraphFields, MarkdownTextualList};
+use biome_rowan::{TextRange, TextSize};
+use std::ops::Range;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownParagraph;
+
+impl FormatNodeRule<MarkdownParagraph> for FormatMarkdownParagraph {
+    fn fmt_fields(&self, node: &MarkdownParagraph, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MarkdownParagraphFields { lines } = node.as_fields();
+
+        match f.options().prose_wrap() {
+            // The lines of a table can't be wrapped
+            ProseWrap::Preserve => write!(f, [lines.format()]),
+            _ if is_table(&lines) => write!(f, [lines.format()]),
+            ProseWrap::Always => {
+                FormatWrappedLines::new(&lines, WordSeparator::SoftLineBreakOrSpace).fmt(f)
+            }
+            ProseWrap::Never => FormatWrappedLines::new(&lines, WordSeparator::Space).fmt(f),
+        }
+    }
+}
+
+/// Prints the words of the lines of a paragraph separated by `separator`,
+/// except the words that follow a hard line break.
+struct FormatWrappedLines<'a> {
+    lines: &'a MarkdownTextualList,
+    separator: WordSeparator,
+}
+
+impl<'a> FormatWrappedLines<'a> {
+    fn new(lines: &'a MarkdownTextualList, separator: WordSeparator) -> Self {
+        Self { lines, separator }
+    }
+}
+
+impl Format<MarkdownFormatContext> for FormatWrappedLines<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The words of the lines are printed in place of the lines
+        for line in self.lines.iter() {
+            f.comments().mark_suppression_checked(line.syntax());
+        }
+
+        let mut fill = f.fill();
+        let mut previous_hard_line_break = None;
+        let last_index = self.lines.len().saturating_sub(1);
+
+        for (index, line) in self.lines.iter().enumerate() {
+            let token = line.value_token()?;
+            let token_text = token.text_trimmed();
+            let start = token.text_trimmed_range().start();
+            let hard_line_break = if index < last_index {
+                hard_line_break_after(&token)
+            } else {
+                None
+            };
+
+            let words = words(token_text);
+            let last_word = words.len().saturating_sub(1);
+            for (word_index, range) in words.into_iter().enumerate() {
+                let word = &token_text[range.clone()];
+                let separator = if word_index == 0 && previous_hard_line_break.is_some() {
+                    WordSeparator::HardLineBreak
+                } else if self.separator == WordSeparator::SoftLineBreakOrSpace
+                    && can_start_block(word)
+                {
+                    // The word stays on the line of the previous word, it would start a block otherwise
+                    WordSeparator::Space
+                } else {
+                    self.separator
+                };
+
+                let is_first_word = word_index == 0;
+                let is_last_word = word_index == last_word;
+                let word = format_with(|f| {
+                    // The words are printed from the text of the token
+                    if is_first_word {
+                        write!(f, [format_removed(&token)])?;
+                    }
+                    let range = TextRange::new(
+                        start + TextSize::from(range.start as u32),
+                        start + TextSize::from(range.end as u32),
+                    );
+                    write!(f, [located_token_text(&token, range)])?;
+                    if is_last_word && hard_line_break == Some(HardLineBreak::Spaces) {
+                        write!(f, [text("  ")])?;
+                    }
+                    Ok(())
+                });
+                fill.entry(&separator, &word);
+            }
+
+            previous_hard_line_break = hard_line_break;
+        }
+
+        fill.finish()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum WordSeparator {
+    Space,
+    SoftLineBreakOrSpace,
+    HardLineBreak,
+}
+
+impl Format<MarkdownFormatContext> for WordSeparator {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        match self {
+            WordSeparator::Space => write!(f, [space()]),
+            WordSeparator::SoftLineBreakOrSpace => write!(f, [soft_line_break_or_space()]),
+            WordSeparator::HardLineBreak => write!(f, [hard_line_break()]),
+        }
+    }
+}
+
+/// Returns the ranges of the words of `text`, which are separated by whitespaces.
+///
+/// The code spans are part of their word: their whitespaces are significant.
+fn words(text: &str) -> Vec<Range<usize>> {
+    let bytes = text.as_bytes();
+    let mut words = Vec::new();
+    let mut word_start = None;
+    let mut position = 0;
+
+    while position < bytes.len() {
+        match bytes[position] {
+            b' ' | b'\t' => {
+                if let Some(start) = word_start.take() {
+                    words.push(start..position);
+                }
+                position += 1;
+            }
+            b'`' => {
+                word_start.get_or_insert(position);
+                position = code_span_end(text, position);
+            }
+            b'\\' => {
+                word_start.get_or_insert(position);
+                // An escaped character, such as `\``
+                position += 1 + text[position + 1..]
+                    .chars()
+                    .next()
+                    .map_or(0, char::len_utf8);
+            }
+            _ => {
+                word_start.get_or_insert(position);
+                position += text[position..].chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+
+    if let Some(start) = word_start {
+        words.push(start..bytes.len());
+    }
+    words
+}
+
+/// Returns the end of the code span that starts at `start`,
+/// or the end of its opening backticks if it isn't closed on this line.
+fn code_span_end(text: &str, start: usize) -> usize {
+    let backticks = text[start..].len() - text[start..].trim_start_matches('`').len();
+    let content_start = start + backticks;
+
+    let mut position = content_start;
+    while let Some(offset) = text[position..].find('`') {
+        let run_start = position + offset;
+        let run_length = text[run_start..].len() - text[run_start..].trim_start_matches('`').len();
+        if run_length == backticks {
+            return run_start + run_length;
+        }
+        position = run_start + run_length;
+    }
+
+    content_start
+}
+
+/// Returns `true` if a line that starts with `word` could start a block,
+/// instead of continuing the paragraph.
+fn can_start_block(word: &str) -> bool {
+    let Some(first) = word.chars().next() else {
+        return false;
+    };
+
+    match first {
+        // Headings, list items, thematic breaks and setext underlines
+        '#' | '-' | '+' | '*' | '=' | '_' => word.chars().all(|char| char == first),
+        // Quotes and HTML blocks
+        '>' | '<' => true,
+        // Fenced code blocks
+        '`' | '~' => word.starts_with("```") || word.starts_with("~~~"),
+        // Ordered list items
+        '0'..='9' => {
+            let marker = word.trim_start_matches(|char: char| char.is_ascii_digit());
+            marker == "." || marker == ")"
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if the paragraph is a table: its second line separates the header row from the other rows,
+/// such as `| --- | :-: |`.
+fn is_table(lines: &MarkdownTextualList) -> bool {
+    lines
+        .iter()
+        .nth(1)
+        .and_then(|line| line.value_token().ok())
+        .is_some_and(|token| {
+            let text = token.text_trimmed();
+            text.contains('-')
+                && text.contains(['|', ':'])
+                && text
+                    .chars()
+                    .all(|char| matches!(char, '|' | '-' | ':' | ' ' | '\t'))
+        })
+}
@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownSetextHeading, MarkdownSetextHeadingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownSetextHeading;
+
+impl FormatNodeRule<MarkdownSetextHeading> for FormatMarkdownSetextHeading {
+    fn fmt_fields(
+        &self,
+        node: &MarkdownSetextHeading,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MarkdownSetextHeadingFields {
+            content,
+            underline_token,
+        } = node.as_fields();
+        let underline_token = underline_token?;
+
+        // The setext headings are printed as ATX headings, on a single line:
+        // an underline of `=` is a heading of level 1, an underline of `-` a heading of level 2
+        let opening = if underline_token.text_trimmed().starts_with('=') {
+            "#"
+        } else {
+            "##"
+        };
+        write!(f, [format_replaced(&underline_token, &text(opening))])?;
+
+        // The lines of the content are printed on the line of the heading, without the paragraph
+        let content = content?;
+        f.comments().mark_suppression_checked(content.syntax());
+        for line in content.lines() {
+            write!(f, [space(), line.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{MarkdownTextual, MarkdownTextualFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownTextual;
+
+impl FormatNodeRule<MarkdownTextual> for FormatMarkdownTextual {
+    fn fmt_fields(&self, node: &MarkdownTextual, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MarkdownTextualFields { value_token } = node.as_fields();
+        write!(f, [value_token.format()])
+    }
+}
@@ -0,0 +1,31 @@
+use crate::prelude::*;
+use crate::utils::is_tight_list_content;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    MarkdownBlockList, MarkdownSyntaxKind, MarkdownThematicBreak, MarkdownThematicBreakFields,
+};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownThematicBreak;
+
+impl FormatNodeRule<MarkdownThematicBreak> for FormatMarkdownThematicBreak {
+    fn fmt_fields(
+        &self,
+        node: &MarkdownThematicBreak,
+        f: &mut MarkdownFormatter,
+    ) -> FormatResult<()> {
+        let MarkdownThematicBreakFields { value_token } = node.as_fields();
+
+        // `---` on the line after a paragraph would be the underline of a setext heading
+        let follows_paragraph_line = node
+            .syntax()
+            .prev_sibling()
+            .is_some_and(|previous| previous.kind() == MarkdownSyntaxKind::MARKDOWN_PARAGRAPH)
+            && node
+                .parent::<MarkdownBlockList>()
+                .is_some_and(|blocks| is_tight_list_content(&blocks));
+        let thematic_break = if follows_paragraph_line { "***" } else { "---" };
+
+        write!(f, [format_replaced(&value_token?, &text(thematic_break))])
+    }
+}
@@ -0,0 +1,7 @@
+use crate::FormatBogusNodeRule;
+use biome_markdown_syntax::MarkdownBogus;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownBogus;
+
+impl FormatBogusNodeRule<MarkdownBogus> for FormatMarkdownBogus {}
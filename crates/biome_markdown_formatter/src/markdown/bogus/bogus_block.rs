@@ -0,0 +1,7 @@
+use crate::FormatBogusNodeRule;
+use biome_markdown_syntax::MarkdownBogusBlock;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownBogusBlock;
+
+impl FormatBogusNodeRule<MarkdownBogusBlock> for FormatMarkdownBogusBlock {}
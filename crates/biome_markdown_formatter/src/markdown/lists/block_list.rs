@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use crate::utils::is_tight_list_content;
+use biome_markdown_syntax::MarkdownBlockList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownBlockList;
+
+impl FormatRule<MarkdownBlockList> for FormatMarkdownBlockList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MarkdownBlockList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        // The blocks of an item of a tight list aren't separated by blank lines
+        if is_tight_list_content(node) {
+            f.join_with(&hard_line_break())
+                .entries(node.iter().formatted())
+                .finish()
+        } else {
+            f.join_with(&empty_line())
+                .entries(node.iter().formatted())
+                .finish()
+        }
+    }
+}
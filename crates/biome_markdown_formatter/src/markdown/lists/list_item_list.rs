@@ -0,0 +1,119 @@
+use crate::markdown::auxiliary::list_item::FormatMarkdownListItemOptions;
+use crate::prelude::*;
+use crate::utils::is_loose_list;
+use biome_markdown_syntax::{MarkdownList, MarkdownListItemList, MarkdownSyntaxKind, T};
+use biome_rowan::Direction;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownListItemList;
+
+impl FormatRule<MarkdownListItemList> for FormatMarkdownListItemList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MarkdownListItemList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let list = node.parent::<MarkdownList>();
+        let is_loose = list.as_ref().is_some_and(is_loose_list);
+        let markers = ListMarkers::new(node, list.as_ref());
+
+        let items = node.iter().enumerate().map(|(index, item)| {
+            item.into_format()
+                .with_options(FormatMarkdownListItemOptions {
+                    marker: markers.marker(index),
+                })
+        });
+
+        // The items of a loose list are separated by blank lines
+        if is_loose {
+            f.join_with(&empty_line()).entries(items).finish()
+        } else {
+            f.join_with(&hard_line_break()).entries(items).finish()
+        }
+    }
+}
+
+/// The normalized markers of the items of a list.
+enum ListMarkers {
+    /// `-` or `*` for all the items
+    Bullet(&'static str),
+    /// The numbers of the items start from `start` and increase by `increment`,
+    /// followed by the delimiter of the first item, `.` or `)`
+    Ordered {
+        start: u64,
+        increment: u64,
+        delimiter: char,
+    },
+    /// The markers are kept as they are
+    Preserve,
+}
+
+impl ListMarkers {
+    fn new(items: &MarkdownListItemList, list: Option<&MarkdownList>) -> Self {
+        let mut markers = items.iter().filter_map(|item| item.marker().ok());
+        let Some(first) = markers.next() else {
+            return Self::Preserve;
+        };
+
+        match first.kind() {
+            T![-] | T![*] | T![+] => {
+                // Two lists next to each other would be a single list if they used the same marker
+                let previous_bullet_lists = list.map_or(0, |list| {
+                    list.syntax()
+                        .siblings(Direction::Prev)
+                        .skip(1)
+                        .map_while(MarkdownList::cast)
+                        .take_while(is_bullet_list)
+                        .count()
+                });
+                if previous_bullet_lists % 2 == 0 {
+                    Self::Bullet("-")
+                } else {
+                    Self::Bullet("*")
+                }
+            }
+            MarkdownSyntaxKind::MARKDOWN_ORDERED_MARKER_LITERAL => {
+                let Some((start, delimiter)) = parse_ordered_marker(first.text_trimmed()) else {
+                    return Self::Preserve;
+                };
+                // `1. 1. 1.` is kept, `1. 2. 3.` or `1. 3. 7.` is incremented
+                let second = markers.next().and_then(|second| {
+                    parse_ordered_marker(second.text_trimmed()).map(|(number, _)| number)
+                });
+                let increment = u64::from(second != Some(start));
+                Self::Ordered {
+                    start,
+                    increment,
+                    delimiter,
+                }
+            }
+            _ => Self::Preserve,
+        }
+    }
+
+    fn marker(&self, index: usize) -> Option<String> {
+        match self {
+            Self::Bullet(marker) => Some((*marker).to_string()),
+            Self::Ordered {
+                start,
+                increment,
+                delimiter,
+            } => {
+                let number = start.saturating_add(increment.saturating_mul(index as u64));
+                Some(std::format!("{number}{delimiter}"))
+            }
+            Self::Preserve => None,
+        }
+    }
+}
+
+fn is_bullet_list(list: &MarkdownList) -> bool {
+    list.items()
+        .first()
+        .and_then(|item| item.marker().ok())
+        .is_some_and(|marker| matches!(marker.kind(), T![-] | T![*] | T![+]))
+}
+
+/// Returns the number and the delimiter of an ordered list marker, such as `1.` or `2)`.
+fn parse_ordered_marker(marker: &str) -> Option<(u64, char)> {
+    let delimiter = marker.chars().last()?;
+    let number = marker[..marker.len() - delimiter.len_utf8()].parse().ok()?;
+    Some((number, delimiter))
+}
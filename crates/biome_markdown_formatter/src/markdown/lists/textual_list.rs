@@ -0,0 +1,30 @@
+use crate::prelude::*;
+use crate::utils::{hard_line_break_after, HardLineBreak};
+use biome_formatter::write;
+use biome_markdown_syntax::MarkdownTextualList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMarkdownTextualList;
+
+impl FormatRule<MarkdownTextualList> for FormatMarkdownTextualList {
+    type Context = MarkdownFormatContext;
+    fn fmt(&self, node: &MarkdownTextualList, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let last_index = node.len().saturating_sub(1);
+
+        for (index, line) in node.iter().enumerate() {
+            if index > 0 {
+                write!(f, [hard_line_break()])?;
+            }
+            write!(f, [line.format()])?;
+
+            // The trailing spaces of a hard line break are trivia
+            if index < last_index
+                && hard_line_break_after(&line.value_token()?) == Some(HardLineBreak::Spaces)
+            {
+                write!(f, [text("  ")])?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+use crate::utils::{token_column, write_mdx_code, FormatLines};
+use biome_markdown_syntax::{MdxEsmBlock, MdxEsmBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdxEsmBlock;
+
+impl FormatNodeRule<MdxEsmBlock> for FormatMdxEsmBlock {
+    fn fmt_fields(&self, node: &MdxEsmBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdxEsmBlockFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        let lines = FormatLines::new(
+            value_token.text_trimmed(),
+            value_token.text_trimmed_range().start(),
+            token_column(&value_token),
+        );
+        let formatted = f
+            .options()
+            .embedded_code_formatter()
+            .and_then(|formatter| formatter.format_embedded_code("jsx", &lines.text()));
+
+        write_mdx_code(&value_token, lines, formatted.as_deref(), f)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::prelude::*;
+use crate::utils::{token_column, write_mdx_code, FormatLines};
+use biome_markdown_syntax::{MdxJsxBlock, MdxJsxBlockFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatMdxJsxBlock;
+
+impl FormatNodeRule<MdxJsxBlock> for FormatMdxJsxBlock {
+    fn fmt_fields(&self, node: &MdxJsxBlock, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let MdxJsxBlockFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        let lines = FormatLines::new(
+            value_token.text_trimmed(),
+            value_token.text_trimmed_range().start(),
+            token_column(&value_token),
+        );
+        let code = lines.text();
+        // The text between the tags is Markdown, that the JSX formatter would reflow
+        let formatted = match f.options().embedded_code_formatter() {
+            Some(formatter) if !has_jsx_text(&code) => formatter.format_embedded_code("jsx", &code),
+            _ => None,
+        };
+        // The element is formatted as an expression statement, without its semicolon
+        let formatted = formatted.as_deref().map(|formatted| {
+            let formatted = formatted.trim_end();
+            formatted.strip_suffix(';').unwrap_or(formatted)
+        });
+
+        write_mdx_code(&value_token, lines, formatted, f)
+    }
+}
+
+/// Returns `true` if `code` has text outside of the tags of its elements and of its expressions,
+/// such as `Sales` in `<Chart>Sales</Chart>`.
+fn has_jsx_text(code: &str) -> bool {
+    let mut in_tag = false;
+    let mut quote = None;
+    let mut braces = 0usize;
+
+    for char in code.chars() {
+        match char {
+            _ if quote.is_some() => {
+                if quote == Some(char) {
+                    quote = None;
+                }
+            }
+            '{' => braces += 1,
+            '}' => braces = braces.saturating_sub(1),
+            _ if braces > 0 => {}
+            '"' | '\'' if in_tag => quote = Some(char),
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag && !char.is_whitespace() => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
@@ -0,0 +1,324 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_markdown_syntax::{
+    MarkdownBlockList, MarkdownList, MarkdownListItem, MarkdownSyntaxKind, MarkdownSyntaxNode,
+    MarkdownSyntaxToken,
+};
+use biome_rowan::TextSize;
+
+/// Prints lines of the source document, such as the code of a code block.
+///
+/// The blank lines are kept as they are, and the lines are indented like the block that contains them.
+pub(crate) struct FormatLines<'a> {
+    lines: Vec<(&'a str, TextSize)>,
+    /// Printed before every line that isn't blank, the indentation of an indented code block
+    prefix: &'static str,
+    /// Whether the first line is printed on a new line, or after the current content
+    starts_on_new_line: bool,
+}
+
+impl<'a> FormatLines<'a> {
+    /// Creates the lines of `text`, which starts at `start` in the source document,
+    /// without the first `indentation` columns of each line.
+    pub(crate) fn new(text: &'a str, start: TextSize, indentation: usize) -> Self {
+        let lines = lines(text)
+            .map(|(offset, line)| {
+                let stripped = strip_indentation(line, indentation);
+                let offset = offset + line.len() - stripped.len();
+                (stripped, start + TextSize::from(offset as u32))
+            })
+            .collect();
+        Self {
+            lines,
+            prefix: "",
+            starts_on_new_line: false,
+        }
+    }
+
+    /// Creates the lines of the code of a token that was formatted by another formatter.
+    pub(crate) fn formatted(text: &'a str, position: TextSize) -> Self {
+        Self {
+            lines: lines(text).map(|(_, line)| (line, position)).collect(),
+            prefix: "",
+            starts_on_new_line: false,
+        }
+    }
+
+    pub(crate) fn with_prefix(mut self, prefix: &'static str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    pub(crate) fn starting_on_new_line(mut self) -> Self {
+        self.starts_on_new_line = true;
+        self
+    }
+
+    /// Returns the lines joined with line feeds.
+    pub(crate) fn text(&self) -> String {
+        let lines: Vec<_> = self.lines.iter().map(|(line, _)| *line).collect();
+        lines.join("\n")
+    }
+
+    /// Returns the lines, without the blank lines.
+    pub(crate) fn non_blank_lines(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.lines
+            .iter()
+            .map(|(line, _)| *line)
+            .filter(|line| !is_blank(line))
+    }
+}
+
+impl Format<MarkdownFormatContext> for FormatLines<'_> {
+    fn fmt(&self, f: &mut MarkdownFormatter) -> FormatResult<()> {
+        let mut blank_lines = 0;
+        let mut is_first_line = true;
+
+        for (line, position) in &self.lines {
+            if is_blank(line) {
+                blank_lines += 1;
+                continue;
+            }
+
+            write_blank_lines(blank_lines, *position, f)?;
+            blank_lines = 0;
+
+            if !is_first_line || self.starts_on_new_line {
+                write!(f, [hard_line_break()])?;
+            }
+            is_first_line = false;
+
+            if !self.prefix.is_empty() {
+                write!(f, [text(self.prefix)])?;
+            }
+            write!(f, [dynamic_text(line, *position)])?;
+        }
+
+        // The blank lines at the end of the code of a fenced code block
+        let end = self
+            .lines
+            .last()
+            .map_or(TextSize::default(), |(_, position)| *position);
+        write_blank_lines(blank_lines, end, f)
+    }
+}
+
+/// Writes `count` blank lines after the current line.
+///
+/// The printer doesn't print a line break on a line that's still empty, so the line breaks are written as text.
+/// The line break after the text is then ignored, but it still indents the next line.
+fn write_blank_lines(
+    count: usize,
+    position: TextSize,
+    f: &mut MarkdownFormatter,
+) -> FormatResult<()> {
+    if count == 0 {
+        return Ok(());
+    }
+    write!(f, [dynamic_text(&"\n".repeat(count + 1), position)])
+}
+
+/// Returns the lines of `text` with their offset, without their line ending.
+pub(crate) fn lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut start = Some(0);
+    std::iter::from_fn(move || {
+        let line_start = start?;
+        let rest = &text[line_start..];
+        match rest.find(['\n', '\r']) {
+            Some(line_end) => {
+                let line_ending = if rest[line_end..].starts_with("\r\n") {
+                    2
+                } else {
+                    1
+                };
+                start = Some(line_start + line_end + line_ending);
+                Some((line_start, &rest[..line_end]))
+            }
+            None => {
+                start = None;
+                Some((line_start, rest))
+            }
+        }
+    })
+}
+
+/// Writes the code of an MDX block: the code formatted by the JSX formatter when it could format it,
+/// or the lines of the block as they are.
+pub(crate) fn write_mdx_code(
+    token: &MarkdownSyntaxToken,
+    lines: FormatLines<'_>,
+    formatted: Option<&str>,
+    f: &mut MarkdownFormatter,
+) -> FormatResult<()> {
+    match formatted {
+        Some(formatted) => {
+            let formatted = FormatLines::formatted(
+                formatted.trim_end_matches(['\n', '\r']),
+                token.text_trimmed_range().start(),
+            );
+            write!(f, [format_replaced(token, &formatted)])
+        }
+        None => write!(f, [format_replaced(token, &lines)]),
+    }
+}
+
+pub(crate) fn is_blank(line: &str) -> bool {
+    line.trim_start_matches([' ', '\t']).is_empty()
+}
+
+/// Removes up to `columns` columns of indentation from the start of `line`.
+pub(crate) fn strip_indentation(line: &str, columns: usize) -> &str {
+    let mut column = 0;
+    for (index, char) in line.char_indices() {
+        if column >= columns {
+            return &line[index..];
+        }
+        match char {
+            ' ' | '\t' => column = column_after(column, char),
+            _ => return &line[index..],
+        }
+    }
+    ""
+}
+
+/// Returns the column after `char` that starts at `column`,
+/// where the tabs advance to the next multiple of four.
+fn column_after(column: usize, char: char) -> usize {
+    match char {
+        '\t' => column + 4 - column % 4,
+        _ => column + 1,
+    }
+}
+
+/// Returns the column of the first character of `token` in the source document.
+pub(crate) fn token_column(token: &MarkdownSyntaxToken) -> usize {
+    let leading_trivia_length =
+        usize::from(token.text_trimmed_range().start() - token.text_range().start());
+    let leading_trivia = &token.text()[..leading_trivia_length];
+    if let Some(line_break) = leading_trivia.rfind(['\n', '\r']) {
+        return leading_trivia[line_break + 1..]
+            .chars()
+            .fold(0, column_after);
+    }
+
+    // The tokens before `token` on its line, the first one can start on a previous line
+    let mut previous_tokens = Vec::new();
+    let mut current = token.prev_token();
+    while let Some(previous) = current {
+        let has_line_break = previous.text().contains(['\n', '\r']);
+        current = previous.prev_token();
+        previous_tokens.push(previous);
+        if has_line_break {
+            break;
+        }
+    }
+
+    let mut column = 0;
+    for previous in previous_tokens.iter().rev() {
+        // The BOM is part of the first line, but it doesn't have a column
+        if previous.kind() == MarkdownSyntaxKind::UNICODE_BOM {
+            continue;
+        }
+        let text = previous.text();
+        let line = match text.rfind(['\n', '\r']) {
+            Some(line_break) => &text[line_break + 1..],
+            None => text,
+        };
+        column = line.chars().fold(column, column_after);
+    }
+    leading_trivia.chars().fold(column, column_after)
+}
+
+/// Returns the column of the content of the innermost list item that contains `node` in the source document,
+/// or `0` if `node` isn't in a list item.
+pub(crate) fn source_content_column(node: &MarkdownSyntaxNode) -> usize {
+    let Some(item) = node.ancestors().skip(1).find_map(MarkdownListItem::cast) else {
+        return 0;
+    };
+    let Ok(marker) = item.marker() else {
+        return 0;
+    };
+
+    let marker_end = token_column(&marker) + marker.text_trimmed().len();
+    let spaces = marker
+        .trailing_trivia()
+        .text()
+        .chars()
+        .fold(marker_end, column_after)
+        - marker_end;
+    let has_content_on_marker_line = marker.next_token().is_some_and(|next| {
+        !next
+            .leading_trivia()
+            .pieces()
+            .any(|piece| piece.is_newline())
+    });
+
+    // The content of an item that starts with an indented code block, or on the next line,
+    // is one space after the marker
+    if has_content_on_marker_line && spaces <= 4 {
+        marker_end + spaces
+    } else {
+        marker_end + 1
+    }
+}
+
+/// Returns `true` if there's a blank line before `node` in the source document.
+pub(crate) fn has_preceding_blank_line(node: &MarkdownSyntaxNode) -> bool {
+    node.first_token().is_some_and(|token| {
+        token
+            .leading_trivia()
+            .pieces()
+            .filter(|piece| piece.is_newline())
+            .count()
+            >= 2
+    })
+}
+
+/// Returns `true` if the items of `list` are separated by blank lines,
+/// or if the blocks of one of its items are separated by blank lines.
+///
+/// The paragraphs of the items of a loose list are rendered in `<p>` elements.
+pub(crate) fn is_loose_list(list: &MarkdownList) -> bool {
+    list.items().iter().enumerate().any(|(index, item)| {
+        (index > 0 && has_preceding_blank_line(item.syntax()))
+            || item
+                .content()
+                .iter()
+                .skip(1)
+                .any(|block| has_preceding_blank_line(block.syntax()))
+    })
+}
+
+/// Returns `true` if `blocks` are the content of an item of a tight list,
+/// which aren't separated by blank lines.
+pub(crate) fn is_tight_list_content(blocks: &MarkdownBlockList) -> bool {
+    blocks
+        .parent::<MarkdownListItem>()
+        .and_then(|item| item.syntax().grand_parent())
+        .and_then(MarkdownList::cast)
+        .is_some_and(|list| !is_loose_list(&list))
+}
+
+/// The hard line break at the end of a line of a paragraph.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum HardLineBreak {
+    /// A backslash, which is part of the line
+    Backslash,
+    /// Two spaces or more after the line, printed as two spaces
+    Spaces,
+}
+
+/// Returns the hard line break at the end of the line of `token`.
+///
+/// The last line of a paragraph doesn't have a hard line break.
+pub(crate) fn hard_line_break_after(token: &MarkdownSyntaxToken) -> Option<HardLineBreak> {
+    let text = token.text_trimmed();
+    let backslashes = text.len() - text.trim_end_matches('\\').len();
+    if backslashes % 2 == 1 {
+        Some(HardLineBreak::Backslash)
+    } else if token.trailing_trivia().text().starts_with("  ") {
+        Some(HardLineBreak::Spaces)
+    } else {
+        None
+    }
+}
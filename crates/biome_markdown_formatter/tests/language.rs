@@ -0,0 +1,60 @@
+use biome_formatter::{FormatResult, Formatted, Printed};
+use biome_formatter_test::TestFormatLanguage;
+use biome_markdown_formatter::context::{MarkdownFormatContext, MarkdownFormatOptions};
+use biome_markdown_formatter::{format_node, format_range, MarkdownFormatLanguage};
+use biome_markdown_parser::{parse_markdown, MarkdownParserOptions};
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_parser::AnyParse;
+use biome_rowan::{SyntaxNode, TextRange};
+use biome_service::settings::{ServiceLanguage, Settings};
+
+#[derive(Default)]
+pub struct MarkdownTestFormatLanguage {
+    options: MarkdownParserOptions,
+}
+
+impl MarkdownTestFormatLanguage {
+    pub fn new(options: MarkdownParserOptions) -> Self {
+        MarkdownTestFormatLanguage { options }
+    }
+}
+
+impl TestFormatLanguage for MarkdownTestFormatLanguage {
+    type ServiceLanguage = MarkdownLanguage;
+    type Context = MarkdownFormatContext;
+    type FormatLanguage = MarkdownFormatLanguage;
+
+    fn parse(&self, text: &str) -> AnyParse {
+        let parse = parse_markdown(text, self.options);
+
+        AnyParse::new(parse.syntax().as_send().unwrap(), parse.into_diagnostics())
+    }
+
+    fn to_language_settings<'a>(
+        &self,
+        settings: &'a Settings,
+    ) -> &'a <Self::ServiceLanguage as ServiceLanguage>::FormatterSettings {
+        &settings.languages.markdown.formatter
+    }
+
+    fn format_node(
+        &self,
+        options: <Self::ServiceLanguage as ServiceLanguage>::FormatOptions,
+        node: &SyntaxNode<Self::ServiceLanguage>,
+    ) -> FormatResult<Formatted<Self::Context>> {
+        format_node(options, node)
+    }
+
+    fn format_range(
+        &self,
+        options: <Self::ServiceLanguage as ServiceLanguage>::FormatOptions,
+        node: &SyntaxNode<Self::ServiceLanguage>,
+        range: TextRange,
+    ) -> FormatResult<Printed> {
+        format_range(options, node, range)
+    }
+
+    fn default_options(&self) -> <Self::ServiceLanguage as ServiceLanguage>::FormatOptions {
+        MarkdownFormatOptions::default()
+    }
+}
@@ -0,0 +1,46 @@
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_formatter::format_node;
+use biome_markdown_parser::{parse_markdown, MarkdownParserOptions};
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+Heading
+=======
+
+* first
+  second line
+*   third
+"#;
+    let parser_options = MarkdownParserOptions::default();
+    let parse = parse_markdown(src, parser_options);
+    let options = MarkdownFormatOptions::default();
+    let result = format_node(options.clone(), &parse.syntax())
+        .unwrap()
+        .print()
+        .unwrap();
+
+    let root = &parse.syntax();
+    let language = language::MarkdownTestFormatLanguage::new(parser_options);
+
+    let check_reformat =
+        CheckReformat::new(root, result.as_code(), "quick_test", &language, options);
+    check_reformat.check_reformat();
+
+    assert_eq!(
+        result.as_code(),
+        r#"# Heading
+
+- first
+  second line
+- third
+"#
+    );
+}
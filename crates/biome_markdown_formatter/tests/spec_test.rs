@@ -0,0 +1,46 @@
+use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
+use biome_markdown_formatter::context::MarkdownFormatOptions;
+use biome_markdown_parser::MarkdownParserOptions;
+use std::path::Path;
+
+mod language {
+    include!("language.rs");
+}
+
+/// [insta.rs](https://insta.rs/docs) snapshot testing
+///
+/// For better development workflow, run
+/// `cargo watch -i '*.new' -x 'test -p biome_markdown_formatter formatter'`
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
+/// the input file name is `{spec_name}.md` and the output file name is `{spec_name}.md.snap`.
+///
+/// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
+///
+/// # Examples
+///
+/// * `markdown/lists` -> input: `tests/specs/markdown/lists.md`, expected output: `tests/specs/markdown/lists.md.snap`
+/// * `lists` -> input: `tests/specs/lists.md`, expected output: `tests/specs/lists.md.snap`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
+
+    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path) else {
+        return;
+    };
+
+    let extension = test_file.input_file().as_path().extension();
+    let parser_options = match extension.and_then(|extension| extension.to_str()) {
+        Some("mdx") => MarkdownParserOptions::default().mdx(),
+        _ => MarkdownParserOptions::default(),
+    };
+
+    let options = MarkdownFormatOptions::default();
+    let language = language::MarkdownTestFormatLanguage::new(parser_options);
+
+    let snapshot = SpecSnapshot::new(test_file, test_directory, language, options);
+
+    snapshot.test()
+}
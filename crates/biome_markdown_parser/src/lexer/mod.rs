@@ -0,0 +1,802 @@
+//! A Markdown lexer which yields SyntaxKind tokens used by the biome Markdown parser.
+//!
+//! The Markdown syntax is line oriented: the first token of every line decides the kind of block it starts,
+//! so the lexer keeps track of the lines and of the blocks that are open,
+//! and yields the tokens of the blocks that don't contain other blocks
+//! (the fenced code, the HTML blocks, the quotes...) as a single token.
+#[rustfmt::skip]
+mod tests;
+
+use crate::MarkdownParserOptions;
+use biome_markdown_syntax::{MarkdownSyntaxKind, MarkdownSyntaxKind::*, T};
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::lexer::{Lexer, LexerCheckpoint, LexerWithCheckpoint, TokenFlags};
+use biome_rowan::TextSize;
+
+#[derive(Debug)]
+pub(crate) struct MarkdownLexer<'src> {
+    /// Source text
+    source: &'src str,
+
+    /// The start byte position in the source text of the next token.
+    position: usize,
+
+    /// `true` if there has been a line break between the last non-trivia token and the next non-trivia token.
+    after_newline: bool,
+
+    /// If the source starts with a Unicode BOM, this is the number of bytes for that token.
+    unicode_bom_length: usize,
+
+    /// Byte offset of the current token from the start of the source
+    /// The range of the current token can be computed by
+    /// `self.position - self.current_start`.
+    current_start: TextSize,
+
+    /// The kind of the current token
+    current_kind: MarkdownSyntaxKind,
+
+    /// Flags for the current token
+    current_flags: TokenFlags,
+
+    /// diagnostics emitted during the parsing phase
+    diagnostics: Vec<ParseDiagnostic>,
+
+    /// The byte position of the start of the current line
+    line_start: usize,
+
+    /// `true` if the next non-trivia token starts a block:
+    /// it's the first token of its line, or it follows a list marker.
+    at_block_start: bool,
+
+    /// `true` if the current line has a non-trivia token, `false` for the blank lines.
+    line_has_content: bool,
+
+    /// `true` if the last block is a paragraph that the next line can continue.
+    in_paragraph: bool,
+
+    /// `true` after the opening of a heading, the rest of the line is the heading content.
+    in_heading: bool,
+
+    /// The content columns of the list items that contain the current line,
+    /// from the outermost to the innermost.
+    list_columns: Vec<usize>,
+
+    /// The fenced code block that is open.
+    fence: Option<Fence>,
+
+    options: MarkdownParserOptions,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Fence {
+    /// The fence character, `` ` `` or `~`
+    marker: u8,
+    /// The number of fence characters of the opening fence
+    length: usize,
+    state: FenceState,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum FenceState {
+    /// The rest of the line of the opening fence
+    Info,
+    /// The lines after the opening fence
+    Content,
+    /// The line of the closing fence
+    Closing,
+}
+
+impl<'src> Lexer<'src> for MarkdownLexer<'src> {
+    const NEWLINE: Self::Kind = NEWLINE;
+    const WHITESPACE: Self::Kind = WHITESPACE;
+
+    type Kind = MarkdownSyntaxKind;
+    type LexContext = ();
+    type ReLexContext = ();
+
+    fn source(&self) -> &'src str {
+        self.source
+    }
+
+    fn current(&self) -> Self::Kind {
+        self.current_kind
+    }
+
+    fn current_start(&self) -> TextSize {
+        self.current_start
+    }
+
+    fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
+        self.current_start = self.text_position();
+        self.current_flags = TokenFlags::empty();
+
+        let kind = match self.current_byte() {
+            Some(current) => self.consume_token(current),
+            None => EOF,
+        };
+
+        self.current_kind = kind;
+
+        // The BOM is part of the first line
+        if !kind.is_trivia() && kind != UNICODE_BOM {
+            if self.after_newline {
+                self.current_flags
+                    .set(TokenFlags::PRECEDING_LINE_BREAK, true);
+            }
+            self.after_newline = false;
+            self.line_has_content = true;
+        }
+
+        kind
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.current_flags.has_preceding_line_break()
+    }
+
+    fn has_unicode_escape(&self) -> bool {
+        self.current_flags.has_unicode_escape()
+    }
+
+    fn rewind(&mut self, checkpoint: LexerCheckpoint<Self::Kind>) {
+        // The checkpoint doesn't hold the state of the blocks, such as the open lists and
+        // fences, it's restored by lexing the source again until the position of the checkpoint.
+        let mut diagnostics = std::mem::take(&mut self.diagnostics);
+        diagnostics.truncate(checkpoint.diagnostics_pos as usize);
+        let position = u32::from(checkpoint.position) as usize;
+
+        *self = Self::from_str(self.source).with_options(self.options);
+        while self.position < position {
+            self.next_token(());
+        }
+        self.diagnostics = diagnostics;
+    }
+
+    fn finish(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    fn current_flags(&self) -> TokenFlags {
+        self.current_flags
+    }
+
+    fn push_diagnostic(&mut self, diagnostic: ParseDiagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    #[inline]
+    fn advance_char_unchecked(&mut self) {
+        let c = self.current_char_unchecked();
+        self.position += c.len_utf8();
+    }
+
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        self.position += n;
+    }
+}
+
+impl<'src> LexerWithCheckpoint<'src> for MarkdownLexer<'src> {
+    fn checkpoint(&self) -> LexerCheckpoint<Self::Kind> {
+        LexerCheckpoint {
+            position: TextSize::from(self.position as u32),
+            current_start: self.current_start,
+            current_flags: self.current_flags,
+            current_kind: self.current_kind,
+            after_line_break: self.after_newline,
+            unicode_bom_length: self.unicode_bom_length,
+            diagnostics_pos: self.diagnostics.len() as u32,
+        }
+    }
+}
+
+impl<'src> MarkdownLexer<'src> {
+    /// Make a new lexer from a str, this is safe because strs are valid utf8
+    pub fn from_str(source: &'src str) -> Self {
+        Self {
+            source,
+            position: 0,
+            after_newline: true,
+            unicode_bom_length: 0,
+            current_start: TextSize::from(0),
+            current_kind: TOMBSTONE,
+            current_flags: TokenFlags::empty(),
+            diagnostics: Vec::new(),
+            line_start: 0,
+            at_block_start: true,
+            line_has_content: false,
+            in_paragraph: false,
+            in_heading: false,
+            list_columns: Vec::new(),
+            fence: None,
+            options: MarkdownParserOptions::default(),
+        }
+    }
+
+    pub(crate) fn with_options(mut self, options: MarkdownParserOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Lexes the next token
+    ///
+    /// Guaranteed to not be at the end of the file
+    fn consume_token(&mut self, current: u8) -> MarkdownSyntaxKind {
+        if self.position == 0 {
+            if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
+                self.unicode_bom_length = bom_size;
+                self.line_start = bom_size;
+                return bom;
+            }
+        }
+
+        if self.position == self.line_start && self.fence_state() == Some(FenceState::Content) {
+            if let Some(kind) = self.consume_code_content() {
+                return kind;
+            }
+            if let Some(fence) = &mut self.fence {
+                fence.state = FenceState::Closing;
+            }
+        }
+
+        match current {
+            b'\n' | b'\r' => self.consume_line_end(),
+            b' ' | b'\t' if self.at_block_start => self.consume_indentation(),
+            b' ' | b'\t' => self.consume_whitespaces(),
+            _ if self.at_block_start => self.consume_block_start(current),
+            _ if self.in_heading => self.consume_heading_content(),
+            _ if self.fence_state() == Some(FenceState::Info) => {
+                self.advance_to(self.trimmed_line_end(self.position));
+                MARKDOWN_INFO_STRING_LITERAL
+            }
+            _ => self.consume_textual(),
+        }
+    }
+
+    fn consume_line_end(&mut self) -> MarkdownSyntaxKind {
+        self.consume_newline();
+        self.after_newline = true;
+
+        if !self.line_has_content {
+            // A blank line ends the paragraphs
+            self.in_paragraph = false;
+        }
+        if let Some(fence) = &mut self.fence {
+            if fence.state == FenceState::Info {
+                fence.state = FenceState::Content;
+            }
+        }
+
+        self.line_start = self.position;
+        self.at_block_start = true;
+        self.line_has_content = false;
+        self.in_heading = false;
+
+        NEWLINE
+    }
+
+    fn consume_whitespaces(&mut self) -> MarkdownSyntaxKind {
+        while matches!(self.current_byte(), Some(b' ' | b'\t')) {
+            self.advance(1);
+        }
+        WHITESPACE
+    }
+
+    /// Consumes the indentation of a line, or the indented code block that starts on this line.
+    fn consume_indentation(&mut self) -> MarkdownSyntaxKind {
+        if self.position == self.line_start && self.fence.is_none() && !self.in_paragraph {
+            let content = self.skip_whitespaces(self.position);
+            if !self.is_line_end(content) {
+                let column = self.column(content);
+                self.close_list_items(column);
+                if column >= self.content_column() + 4 {
+                    return self.consume_indented_code();
+                }
+            }
+        }
+
+        self.consume_whitespaces()
+    }
+
+    /// Consumes the first token of a block, at the start of a line or after a list marker.
+    fn consume_block_start(&mut self, current: u8) -> MarkdownSyntaxKind {
+        self.at_block_start = false;
+
+        if self.fence_state() == Some(FenceState::Closing) {
+            self.fence = None;
+            self.advance_to(self.trimmed_line_end(self.position));
+            return MARKDOWN_FENCE_LITERAL;
+        }
+
+        let is_first_on_line = self.skip_whitespaces(self.line_start) == self.position;
+        // A line that isn't indented like the content of the innermost list item
+        // can continue its paragraph, or start a new item in any case
+        let leaves_list_item =
+            is_first_on_line && self.column(self.position) < self.content_column();
+        let is_lazy = self.in_paragraph
+            && !self.interrupts_paragraph(self.position)
+            && !(leaves_list_item && self.is_list_marker(self.position));
+        // A lazy continuation line stays in the paragraph of its list item
+        if is_first_on_line && !is_lazy {
+            self.close_list_items(self.column(self.position));
+        }
+
+        let kind = match current {
+            b'-' if self.position == self.unicode_bom_length
+                && self.front_matter_end().is_some() =>
+            {
+                self.consume_front_matter()
+            }
+            b'=' | b'-'
+                if self.in_paragraph
+                    && !leaves_list_item
+                    && self.is_setext_underline(self.position) =>
+            {
+                self.advance_to(self.trimmed_line_end(self.position));
+                MARKDOWN_SETEXT_UNDERLINE_LITERAL
+            }
+            b'*' | b'-' | b'_' if self.is_thematic_break(self.position) => {
+                self.advance_to(self.trimmed_line_end(self.position));
+                MARKDOWN_THEMATIC_BREAK_LITERAL
+            }
+            b'#' if self.is_heading_opening(self.position) => {
+                self.in_heading = true;
+                self.advance_to(self.skip_byte(self.position, b'#'));
+                MARKDOWN_HASH_LITERAL
+            }
+            b'`' | b'~' if self.is_fence_opening(self.position) => {
+                self.consume_fence_opening(current)
+            }
+            b'>' => self.consume_quote(),
+            // The HTML of MDX is JSX, only the comments and the processing instructions are HTML blocks
+            b'<' if self.options.mdx && !self.in_paragraph && self.is_jsx_start(self.position) => {
+                self.consume_until_blank_line();
+                MDX_JSX_LITERAL
+            }
+            b'<' if !self.in_paragraph && self.is_html_start(self.position) => self.consume_html(),
+            b'[' if !self.in_paragraph && self.is_link_definition(self.position) => {
+                self.advance_to(self.trimmed_line_end(self.position));
+                MARKDOWN_LINK_DEFINITION_LITERAL
+            }
+            b'i' | b'e'
+                if self.options.mdx && !self.in_paragraph && self.is_esm_start(self.position) =>
+            {
+                self.consume_until_blank_line();
+                MDX_ESM_LITERAL
+            }
+            b'-' | b'*' | b'+' if !is_lazy && self.is_bullet(self.position) => {
+                let kind = match current {
+                    b'-' => T![-],
+                    b'*' => T![*],
+                    _ => T![+],
+                };
+                self.consume_list_marker(1, kind)
+            }
+            b'0'..=b'9' if !is_lazy => match self.ordered_marker_length(self.position) {
+                Some(length) => self.consume_list_marker(length, MARKDOWN_ORDERED_MARKER_LITERAL),
+                None => self.consume_textual(),
+            },
+            _ => self.consume_textual(),
+        };
+
+        self.in_paragraph = kind == MARKDOWN_TEXTUAL_LITERAL;
+        kind
+    }
+
+    /// Consumes the rest of the line as a textual token, without the trailing whitespaces.
+    fn consume_textual(&mut self) -> MarkdownSyntaxKind {
+        let end = self.trimmed_line_end(self.position);
+        if end == self.position {
+            // Unreachable in practice: the whitespaces are consumed before
+            self.advance_char_unchecked();
+        } else {
+            self.advance_to(end);
+        }
+        MARKDOWN_TEXTUAL_LITERAL
+    }
+
+    /// Consumes the content of a heading, `Heading` in `# Heading #`, or its closing sequence.
+    fn consume_heading_content(&mut self) -> MarkdownSyntaxKind {
+        let end = self.trimmed_line_end(self.position);
+        let text = &self.source[self.position..end];
+        let without_closing = text.trim_end_matches('#');
+
+        if without_closing.is_empty() {
+            self.in_heading = false;
+            self.advance_to(end);
+            return MARKDOWN_HASH_LITERAL;
+        }
+
+        // The closing sequence must be preceded by a space: `# C#` doesn't have one
+        let content_length =
+            if without_closing.len() < text.len() && without_closing.ends_with([' ', '\t']) {
+                without_closing.trim_end_matches([' ', '\t']).len()
+            } else {
+                text.len()
+            };
+        self.advance(content_length);
+        MARKDOWN_TEXTUAL_LITERAL
+    }
+
+    fn consume_fence_opening(&mut self, marker: u8) -> MarkdownSyntaxKind {
+        let end = self.skip_byte(self.position, marker);
+        self.fence = Some(Fence {
+            marker,
+            length: end - self.position,
+            state: FenceState::Info,
+        });
+        self.advance_to(end);
+        MARKDOWN_FENCE_LITERAL
+    }
+
+    /// Consumes the lines of a fenced code block until its closing fence,
+    /// or returns `None` if the current line is the closing fence or a blank line.
+    fn consume_code_content(&mut self) -> Option<MarkdownSyntaxKind> {
+        let fence = self.fence?;
+        let mut end = self.position;
+        let mut line = self.position;
+
+        loop {
+            let line_end = self.line_end(line);
+            if self.is_closing_fence(line, fence) {
+                break;
+            }
+            end = line_end;
+            match self.next_line(line) {
+                // The line break at the end of the document isn't part of the code
+                Some(next) if next < self.source.len() => line = next,
+                _ => break,
+            }
+        }
+
+        if end == self.position {
+            return None;
+        }
+
+        if let Some(fence) = &mut self.fence {
+            fence.state = FenceState::Closing;
+        }
+        self.advance_to(end);
+        Some(MARKDOWN_CODE_CONTENT_LITERAL)
+    }
+
+    /// Consumes the lines indented with four spaces, including the blank lines between them.
+    fn consume_indented_code(&mut self) -> MarkdownSyntaxKind {
+        let min_column = self.content_column() + 4;
+        let mut end = self.position;
+        let mut line = self.position;
+
+        loop {
+            let content = self.skip_whitespaces(line);
+            if !self.is_line_end(content) {
+                if self.column_in_line(line, content) < min_column {
+                    break;
+                }
+                end = self.line_end(line);
+            }
+            match self.next_line(line) {
+                Some(next) => line = next,
+                None => break,
+            }
+        }
+
+        self.advance_to(end);
+        MARKDOWN_INDENTED_CODE_LITERAL
+    }
+
+    /// Consumes the lines of a quote, until a blank line or a line that starts another block.
+    fn consume_quote(&mut self) -> MarkdownSyntaxKind {
+        let mut end = self.line_end(self.position);
+
+        while let Some(line) = self.next_line(end) {
+            let content = self.skip_whitespaces(line);
+            if self.is_line_end(content)
+                || (self.byte(content) != Some(b'>') && self.interrupts_paragraph(content))
+            {
+                break;
+            }
+            end = self.line_end(line);
+        }
+
+        self.advance_to(self.trim_end(self.position, end));
+        MARKDOWN_QUOTE_LITERAL
+    }
+
+    /// Consumes the lines of an HTML block, until a blank line.
+    fn consume_html(&mut self) -> MarkdownSyntaxKind {
+        self.consume_until_blank_line();
+        MARKDOWN_HTML_LITERAL
+    }
+
+    /// Consumes the current line and the lines that follow it, until a blank line.
+    fn consume_until_blank_line(&mut self) {
+        let mut end = self.line_end(self.position);
+
+        while let Some(line) = self.next_line(end) {
+            if self.is_line_end(self.skip_whitespaces(line)) {
+                break;
+            }
+            end = self.line_end(line);
+        }
+
+        self.advance_to(self.trim_end(self.position, end));
+    }
+
+    /// Consumes the front matter, from its opening `---` to its closing `---` or `...`.
+    fn consume_front_matter(&mut self) -> MarkdownSyntaxKind {
+        let end = self.front_matter_end().unwrap_or(self.position + 3);
+        self.advance_to(end);
+        MARKDOWN_FRONT_MATTER_LITERAL
+    }
+
+    fn consume_list_marker(
+        &mut self,
+        length: usize,
+        kind: MarkdownSyntaxKind,
+    ) -> MarkdownSyntaxKind {
+        let marker_column = self.column(self.position);
+        self.advance(length);
+
+        let content = self.skip_whitespaces(self.position);
+        let spaces = self.column_in_line(self.line_start, content) - marker_column - length;
+        // The content of an item that starts with an indented code block, or on the next line,
+        // is one space after the marker
+        let content_column = if self.is_line_end(content) || spaces > 4 {
+            marker_column + length + 1
+        } else {
+            marker_column + length + spaces
+        };
+        self.list_columns.push(content_column);
+        self.at_block_start = true;
+        kind
+    }
+
+    /// Closes the list items whose content is indented more than a line starting at `column`.
+    fn close_list_items(&mut self, column: usize) {
+        while self
+            .list_columns
+            .last()
+            .is_some_and(|content_column| *content_column > column)
+        {
+            self.list_columns.pop();
+        }
+    }
+
+    /// The content column of the innermost list item
+    fn content_column(&self) -> usize {
+        self.list_columns.last().copied().unwrap_or_default()
+    }
+
+    fn fence_state(&self) -> Option<FenceState> {
+        self.fence.map(|fence| fence.state)
+    }
+
+    /// Returns `true` if a line starting at `position` starts a block that ends a paragraph
+    fn interrupts_paragraph(&self, position: usize) -> bool {
+        match self.byte(position) {
+            Some(b'#') => self.is_heading_opening(position),
+            Some(b'`' | b'~') => self.is_fence_opening(position),
+            Some(b'>') => true,
+            Some(b'=') => self.is_setext_underline(position),
+            Some(b'-') => {
+                self.is_setext_underline(position)
+                    || self.is_thematic_break(position)
+                    || (self.is_bullet(position) && !self.is_blank_after(position + 1))
+            }
+            Some(b'*' | b'_' | b'+') => {
+                self.is_thematic_break(position)
+                    || (self.is_bullet(position) && !self.is_blank_after(position + 1))
+            }
+            // Only the ordered lists that start at 1 can interrupt a paragraph
+            Some(b'1') => self
+                .ordered_marker_length(position)
+                .is_some_and(|length| length == 2 && !self.is_blank_after(position + length)),
+            _ => false,
+        }
+    }
+
+    /// `# Heading`: one to six `#` followed by a space or the end of the line
+    fn is_heading_opening(&self, position: usize) -> bool {
+        let end = self.skip_byte(position, b'#');
+        end - position <= 6 && self.is_space_or_line_end(end)
+    }
+
+    /// ```` ```js ````: three backticks or more, not followed by a backtick,
+    /// or three tildes or more
+    fn is_fence_opening(&self, position: usize) -> bool {
+        let Some(marker) = self.byte(position) else {
+            return false;
+        };
+        let end = self.skip_byte(position, marker);
+        end - position >= 3
+            && (marker == b'~' || !self.source[end..self.line_end(end)].contains('`'))
+    }
+
+    fn is_closing_fence(&self, line: usize, fence: Fence) -> bool {
+        let start = self.skip_whitespaces(line);
+        let end = self.skip_byte(start, fence.marker);
+        end - start >= fence.length && self.is_blank_after(end)
+    }
+
+    /// `***`, `---` or `___`, with optional spaces between the characters
+    fn is_thematic_break(&self, position: usize) -> bool {
+        let Some(marker) = self.byte(position) else {
+            return false;
+        };
+        let text = &self.source[position..self.line_end(position)];
+        text.bytes()
+            .all(|byte| matches!(byte, b' ' | b'\t') || byte == marker)
+            && text.bytes().filter(|byte| *byte == marker).count() >= 3
+    }
+
+    /// `===` or `---` after a paragraph
+    fn is_setext_underline(&self, position: usize) -> bool {
+        let Some(marker) = self.byte(position) else {
+            return false;
+        };
+        self.is_blank_after(self.skip_byte(position, marker))
+    }
+
+    /// `<div>`, `</div>`, `<!-- comment -->` or `<?php`
+    fn is_html_start(&self, position: usize) -> bool {
+        self.byte(position + 1)
+            .is_some_and(|byte| byte.is_ascii_alphabetic() || matches!(byte, b'/' | b'!' | b'?'))
+    }
+
+    /// `<Chart>`, `</Chart>` or `<>`
+    fn is_jsx_start(&self, position: usize) -> bool {
+        self.byte(position + 1)
+            .is_some_and(|byte| byte.is_ascii_alphabetic() || matches!(byte, b'/' | b'>'))
+    }
+
+    /// `import { Chart } from "./chart.jsx";` or `export const meta = {};`, outside of the list items
+    fn is_esm_start(&self, position: usize) -> bool {
+        let text = &self.source[position..self.line_end(position)];
+        self.list_columns.is_empty()
+            && ["import", "export"].into_iter().any(|keyword| {
+                text.strip_prefix(keyword)
+                    .is_some_and(|rest| rest.starts_with([' ', '\t', '{', '*']))
+            })
+    }
+
+    /// `[label]: destination`
+    fn is_link_definition(&self, position: usize) -> bool {
+        let text = &self.source[position + 1..self.line_end(position)];
+        let mut escaped = false;
+        for (index, char) in text.char_indices() {
+            match char {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '[' => return false,
+                ']' => return index > 0 && text[index + 1..].starts_with(':'),
+                _ => {}
+            }
+        }
+        false
+    }
+
+    fn is_list_marker(&self, position: usize) -> bool {
+        match self.byte(position) {
+            Some(b'-' | b'*' | b'+') => self.is_bullet(position),
+            _ => self.ordered_marker_length(position).is_some(),
+        }
+    }
+
+    /// `-`, `*` or `+` followed by a space or the end of the line
+    fn is_bullet(&self, position: usize) -> bool {
+        self.is_space_or_line_end(position + 1)
+    }
+
+    /// Returns the length of the ordered list marker at `position`, such as `1.` or `2)`.
+    fn ordered_marker_length(&self, position: usize) -> Option<usize> {
+        let end = self.skip_while(position, |byte| byte.is_ascii_digit());
+        let digits = end - position;
+        ((1..=9).contains(&digits)
+            && matches!(self.byte(end), Some(b'.' | b')'))
+            && self.is_space_or_line_end(end + 1))
+        .then_some(digits + 1)
+    }
+
+    /// Returns the end of the front matter, when the document starts with one.
+    fn front_matter_end(&self) -> Option<usize> {
+        let position = self.unicode_bom_length;
+        if self.trimmed_line_end(position) != position + 3
+            || !self.source[position..].starts_with("---")
+        {
+            return None;
+        }
+
+        let mut line = self.next_line(self.line_end(position))?;
+        loop {
+            let end = self.trimmed_line_end(line);
+            if matches!(&self.source[line..end], "---" | "...") {
+                return Some(end);
+            }
+            line = self.next_line(self.line_end(line))?;
+        }
+    }
+
+    fn is_space_or_line_end(&self, position: usize) -> bool {
+        matches!(
+            self.byte(position),
+            None | Some(b' ' | b'\t' | b'\n' | b'\r')
+        )
+    }
+
+    /// Returns `true` if the rest of the line after `position` only contains whitespaces
+    fn is_blank_after(&self, position: usize) -> bool {
+        self.is_line_end(self.skip_whitespaces(position))
+    }
+
+    fn is_line_end(&self, position: usize) -> bool {
+        matches!(self.byte(position), None | Some(b'\n' | b'\r'))
+    }
+
+    fn byte(&self, position: usize) -> Option<u8> {
+        self.source.as_bytes().get(position).copied()
+    }
+
+    fn skip_while(&self, position: usize, predicate: impl Fn(u8) -> bool) -> usize {
+        let bytes = self.source.as_bytes();
+        let mut end = position;
+        while end < bytes.len() && predicate(bytes[end]) {
+            end += 1;
+        }
+        end
+    }
+
+    fn skip_byte(&self, position: usize, byte: u8) -> usize {
+        self.skip_while(position, |current| current == byte)
+    }
+
+    fn skip_whitespaces(&self, position: usize) -> usize {
+        self.skip_while(position, |byte| matches!(byte, b' ' | b'\t'))
+    }
+
+    /// Returns the position of the line break that ends the line of `position`, or the end of the source.
+    fn line_end(&self, position: usize) -> usize {
+        self.skip_while(position, |byte| !matches!(byte, b'\n' | b'\r'))
+    }
+
+    /// Returns the end of the line of `position`, without its trailing whitespaces.
+    fn trimmed_line_end(&self, position: usize) -> usize {
+        self.trim_end(position, self.line_end(position))
+    }
+
+    fn trim_end(&self, start: usize, end: usize) -> usize {
+        start + self.source[start..end].trim_end_matches([' ', '\t']).len()
+    }
+
+    /// Returns the start of the line after the line break at `line_end`, if there's one.
+    fn next_line(&self, line_end: usize) -> Option<usize> {
+        let position = self.line_end(line_end);
+        match self.byte(position)? {
+            b'\r' if self.byte(position + 1) == Some(b'\n') => Some(position + 2),
+            _ => Some(position + 1),
+        }
+    }
+
+    /// Returns the column of `position` in the current line.
+    fn column(&self, position: usize) -> usize {
+        self.column_in_line(self.line_start, position)
+    }
+
+    /// Returns the column of `position` in the line that starts at `line`,
+    /// where the tabs advance to the next multiple of four.
+    fn column_in_line(&self, line: usize, position: usize) -> usize {
+        self.source.as_bytes()[line..position]
+            .iter()
+            .fold(0, |column, byte| match byte {
+                b'\t' => column + 4 - column % 4,
+                _ => column + 1,
+            })
+    }
+
+    fn advance_to(&mut self, position: usize) {
+        self.position = position;
+    }
+}
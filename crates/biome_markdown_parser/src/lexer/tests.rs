@@ -0,0 +1,376 @@
+#![cfg(test)]
+#![allow(unused_mut, unused_variables, unused_assignments)]
+
+use super::{MarkdownLexer, TextSize};
+use crate::MarkdownParserOptions;
+use biome_markdown_syntax::MarkdownSyntaxKind::{self, EOF};
+use biome_parser::lexer::Lexer;
+use quickcheck_macros::quickcheck;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+// Assert the result of lexing a piece of source code,
+// and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens.
+macro_rules! assert_lex {
+    (@options $options:expr, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = MarkdownLexer::from_str($src).with_options($options);
+        let mut idx = 0;
+        let mut tok_idx = TextSize::default();
+
+        let mut new_str = String::with_capacity($src.len());
+        let mut tokens = vec![];
+
+        while lexer.next_token(()) != EOF {
+            tokens.push((lexer.current(), lexer.current_range()));
+        }
+
+        $(
+            assert_eq!(
+                tokens[idx].0,
+                MarkdownSyntaxKind::$kind,
+                "expected token kind {}, but found {:?}",
+                stringify!($kind),
+                tokens[idx].0,
+            );
+
+            assert_eq!(
+                tokens[idx].1.len(),
+                TextSize::from($len),
+                "expected token length of {}, but found {:?} for token {:?}",
+                $len,
+                tokens[idx].1.len(),
+                tokens[idx].0,
+            );
+
+            new_str.push_str(&$src[tokens[idx].1]);
+            tok_idx += tokens[idx].1.len();
+
+            idx += 1;
+        )*
+
+        if idx < tokens.len() {
+            panic!(
+                "expected {} tokens but lexer returned {}, first unexpected token is '{:?}'",
+                idx,
+                tokens.len(),
+                tokens[idx].0
+            );
+        } else {
+            assert_eq!(idx, tokens.len());
+        }
+
+        assert_eq!($src, new_str, "Failed to reconstruct input");
+    }};
+    (mdx: $src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(@options MarkdownParserOptions::default().mdx(), $src, $($kind:$len,)*)
+    };
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(@options MarkdownParserOptions::default(), $src, $($kind:$len,)*)
+    };
+}
+
+// This is for testing if the lexer is truly lossless
+// It parses random strings and puts them back together with the produced tokens and compares
+#[quickcheck]
+fn losslessness(string: String) -> bool {
+    // using an mpsc channel allows us to spawn a thread and spawn the lexer there, then if
+    // it takes more than 2 seconds we panic because it is 100% infinite recursion
+    let cloned = string.clone();
+    let (sender, receiver) = channel();
+    thread::spawn(move || {
+        let mut lexer = MarkdownLexer::from_str(&cloned);
+        let mut tokens = vec![];
+
+        while lexer.next_token(()) != EOF {
+            tokens.push(lexer.current_range());
+        }
+
+        sender
+            .send(tokens)
+            .expect("Could not send tokens to receiver");
+    });
+    let token_ranges = receiver
+        .recv_timeout(Duration::from_secs(2))
+        .unwrap_or_else(|_| {
+            panic!(
+                "Lexer is infinitely recursing with this code: ->{}<-",
+                string
+            )
+        });
+
+    let mut new_str = String::with_capacity(string.len());
+    let mut idx = TextSize::from(0);
+
+    for range in token_ranges {
+        new_str.push_str(&string[range]);
+        idx += range.len();
+    }
+
+    string == new_str
+}
+
+#[test]
+fn empty() {
+    assert_lex! {
+        "",
+    }
+}
+
+#[test]
+fn bom() {
+    assert_lex! {
+        "\u{feff}# Biome",
+        UNICODE_BOM:3,
+        MARKDOWN_HASH_LITERAL:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:5
+    }
+}
+
+#[test]
+fn headings() {
+    assert_lex! {
+        "# Biome",
+        MARKDOWN_HASH_LITERAL:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:5
+    }
+
+    assert_lex! {
+        "## Biome ##",
+        MARKDOWN_HASH_LITERAL:2,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:5,
+        WHITESPACE:1,
+        MARKDOWN_HASH_LITERAL:2
+    }
+
+    assert_lex! {
+        "# C#",
+        MARKDOWN_HASH_LITERAL:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:2
+    }
+
+    assert_lex! {
+        "#hashtag",
+        MARKDOWN_TEXTUAL_LITERAL:8
+    }
+
+    assert_lex! {
+        "Title\n=====",
+        MARKDOWN_TEXTUAL_LITERAL:5,
+        NEWLINE:1,
+        MARKDOWN_SETEXT_UNDERLINE_LITERAL:5
+    }
+
+    assert_lex! {
+        "Title\n---",
+        MARKDOWN_TEXTUAL_LITERAL:5,
+        NEWLINE:1,
+        MARKDOWN_SETEXT_UNDERLINE_LITERAL:3
+    }
+}
+
+#[test]
+fn paragraphs() {
+    assert_lex! {
+        "first line\nsecond line  ",
+        MARKDOWN_TEXTUAL_LITERAL:10,
+        NEWLINE:1,
+        MARKDOWN_TEXTUAL_LITERAL:11,
+        WHITESPACE:2
+    }
+
+    assert_lex! {
+        "a\r\nb",
+        MARKDOWN_TEXTUAL_LITERAL:1,
+        NEWLINE:2,
+        MARKDOWN_TEXTUAL_LITERAL:1
+    }
+
+    // A paragraph can't be interrupted by an indented code block or a link definition
+    assert_lex! {
+        "text\n    more\n[a]: b",
+        MARKDOWN_TEXTUAL_LITERAL:4,
+        NEWLINE:1,
+        WHITESPACE:4,
+        MARKDOWN_TEXTUAL_LITERAL:4,
+        NEWLINE:1,
+        MARKDOWN_TEXTUAL_LITERAL:6
+    }
+}
+
+#[test]
+fn thematic_breaks() {
+    assert_lex! {
+        "***\n\n- - -",
+        MARKDOWN_THEMATIC_BREAK_LITERAL:3,
+        NEWLINE:1,
+        NEWLINE:1,
+        MARKDOWN_THEMATIC_BREAK_LITERAL:5
+    }
+}
+
+#[test]
+fn fenced_code_blocks() {
+    assert_lex! {
+        "```js\nlet a;\n```",
+        MARKDOWN_FENCE_LITERAL:3,
+        MARKDOWN_INFO_STRING_LITERAL:2,
+        NEWLINE:1,
+        MARKDOWN_CODE_CONTENT_LITERAL:6,
+        NEWLINE:1,
+        MARKDOWN_FENCE_LITERAL:3
+    }
+
+    assert_lex! {
+        "~~~\n\n  code\n~~~~",
+        MARKDOWN_FENCE_LITERAL:3,
+        NEWLINE:1,
+        MARKDOWN_CODE_CONTENT_LITERAL:7,
+        NEWLINE:1,
+        MARKDOWN_FENCE_LITERAL:4
+    }
+
+    assert_lex! {
+        "```\n# not a heading",
+        MARKDOWN_FENCE_LITERAL:3,
+        NEWLINE:1,
+        MARKDOWN_CODE_CONTENT_LITERAL:15
+    }
+}
+
+#[test]
+fn indented_code_blocks() {
+    assert_lex! {
+        "    let a;\n\n    let b;\ntext",
+        MARKDOWN_INDENTED_CODE_LITERAL:22,
+        NEWLINE:1,
+        MARKDOWN_TEXTUAL_LITERAL:4
+    }
+}
+
+#[test]
+fn lists() {
+    assert_lex! {
+        "- first\n  - nested\n1. one",
+        MINUS:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:5,
+        NEWLINE:1,
+        WHITESPACE:2,
+        MINUS:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:6,
+        NEWLINE:1,
+        MARKDOWN_ORDERED_MARKER_LITERAL:2,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:3
+    }
+
+    assert_lex! {
+        "* a\n+ b",
+        STAR:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:1,
+        NEWLINE:1,
+        PLUS:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:1
+    }
+
+    // Only the ordered lists that start at 1 can interrupt a paragraph
+    assert_lex! {
+        "text\n2. not a list",
+        MARKDOWN_TEXTUAL_LITERAL:4,
+        NEWLINE:1,
+        MARKDOWN_TEXTUAL_LITERAL:13
+    }
+}
+
+#[test]
+fn quotes() {
+    assert_lex! {
+        "> quote\nlazy\n> more\n\ntext",
+        MARKDOWN_QUOTE_LITERAL:19,
+        NEWLINE:1,
+        NEWLINE:1,
+        MARKDOWN_TEXTUAL_LITERAL:4
+    }
+}
+
+#[test]
+fn html_blocks() {
+    assert_lex! {
+        "<div>\n  *hi*\n</div>\n\ntext",
+        MARKDOWN_HTML_LITERAL:19,
+        NEWLINE:1,
+        NEWLINE:1,
+        MARKDOWN_TEXTUAL_LITERAL:4
+    }
+}
+
+#[test]
+fn mdx_esm_blocks() {
+    assert_lex! {
+        mdx: "import { Chart } from \"./chart.jsx\";\nexport const year = 2024;\n\nimportant",
+        MDX_ESM_LITERAL:62,
+        NEWLINE:1,
+        NEWLINE:1,
+        MARKDOWN_TEXTUAL_LITERAL:9
+    }
+}
+
+#[test]
+fn mdx_esm_in_list_item() {
+    assert_lex! {
+        mdx: "- import x",
+        MINUS:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:8
+    }
+}
+
+#[test]
+fn mdx_jsx_blocks() {
+    assert_lex! {
+        mdx: "<Chart year={2024} />\n\n<>\n  text\n</>\n\n<!-- note -->",
+        MDX_JSX_LITERAL:21,
+        NEWLINE:1,
+        NEWLINE:1,
+        MDX_JSX_LITERAL:13,
+        NEWLINE:1,
+        NEWLINE:1,
+        MARKDOWN_HTML_LITERAL:13
+    }
+}
+
+#[test]
+fn esm_without_mdx() {
+    assert_lex! {
+        "import { Chart } from \"./chart.jsx\";",
+        MARKDOWN_TEXTUAL_LITERAL:36
+    }
+}
+
+#[test]
+fn link_definitions() {
+    assert_lex! {
+        "[biome]: https://biomejs.dev",
+        MARKDOWN_LINK_DEFINITION_LITERAL:28
+    }
+}
+
+#[test]
+fn front_matter() {
+    assert_lex! {
+        "---\ntitle: Biome\n---\n# Biome",
+        MARKDOWN_FRONT_MATTER_LITERAL:20,
+        NEWLINE:1,
+        MARKDOWN_HASH_LITERAL:1,
+        WHITESPACE:1,
+        MARKDOWN_TEXTUAL_LITERAL:5
+    }
+}
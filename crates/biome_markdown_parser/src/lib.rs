@@ -0,0 +1,144 @@
+//! Extremely fast and lossless Markdown Parser.
+//!
+//! The parser recognizes the blocks of a document: the headings, the paragraphs, the lists,
+//! the code blocks... The inline content of the paragraphs and of the headings isn't parsed.
+
+pub use crate::parser::MarkdownParserOptions;
+use biome_markdown_factory::MarkdownSyntaxFactory;
+use biome_markdown_syntax::{MarkdownDocument, MarkdownLanguage, MarkdownSyntaxNode};
+pub use biome_parser::prelude::*;
+use biome_parser::tree_sink::LosslessTreeSink;
+use biome_rowan::{AstNode, NodeCache};
+use parser::{parse_document, MarkdownParser};
+
+mod lexer;
+mod parser;
+mod token_source;
+
+pub(crate) type MarkdownLosslessTreeSink<'source> =
+    LosslessTreeSink<'source, MarkdownLanguage, MarkdownSyntaxFactory>;
+
+pub fn parse_markdown(source: &str, options: MarkdownParserOptions) -> MarkdownParse {
+    let mut cache = NodeCache::default();
+    parse_markdown_with_cache(source, &mut cache, options)
+}
+
+/// Parses the provided string as a Markdown document using the provided node cache.
+pub fn parse_markdown_with_cache(
+    source: &str,
+    cache: &mut NodeCache,
+    options: MarkdownParserOptions,
+) -> MarkdownParse {
+    tracing::debug_span!("Parsing phase").in_scope(move || {
+        let mut parser = MarkdownParser::new(source, options);
+
+        parse_document(&mut parser);
+
+        let (events, diagnostics, trivia) = parser.finish();
+
+        let mut tree_sink = MarkdownLosslessTreeSink::with_cache(source, &trivia, cache);
+        biome_parser::event::process(&mut tree_sink, events, diagnostics);
+        let (green, diagnostics) = tree_sink.finish();
+
+        MarkdownParse::new(green, diagnostics)
+    })
+}
+
+/// A utility struct for managing the result of a parser job
+#[derive(Debug)]
+pub struct MarkdownParse {
+    root: MarkdownSyntaxNode,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl MarkdownParse {
+    pub fn new(root: MarkdownSyntaxNode, diagnostics: Vec<ParseDiagnostic>) -> MarkdownParse {
+        MarkdownParse { root, diagnostics }
+    }
+
+    /// The syntax node represented by this Parse result
+    ///
+    /// ```
+    /// # use biome_markdown_parser::{parse_markdown, MarkdownParserOptions};
+    /// # use biome_markdown_syntax::MarkdownSyntaxKind;
+    /// # use biome_rowan::{AstNode, AstNodeList, SyntaxError};
+    ///
+    /// # fn main() -> Result<(), SyntaxError> {
+    /// use biome_markdown_syntax::MarkdownSyntaxKind;
+    /// let parse = parse_markdown("# Biome\n\nToolchain of the web\n", MarkdownParserOptions::default());
+    ///
+    /// let blocks = parse.tree().blocks();
+    ///
+    /// assert_eq!(blocks.syntax().kind(), MarkdownSyntaxKind::MARKDOWN_BLOCK_LIST);
+    /// assert_eq!(blocks.len(), 2);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn syntax(&self) -> MarkdownSyntaxNode {
+        self.root.clone()
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn into_diagnostics(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Returns [true] if the parser encountered some errors during the parsing.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.is_error())
+    }
+
+    /// Convert this parse result into a typed AST node.
+    ///
+    /// # Panics
+    /// Panics if the node represented by this parse result mismatches.
+    pub fn tree(&self) -> MarkdownDocument {
+        MarkdownDocument::unwrap_cast(self.syntax())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse_markdown, MarkdownParserOptions};
+
+    #[test]
+    fn parser_smoke_test() {
+        let src = r#"
+# Biome
+
+Biome is a **fast** toolchain for the web.
+
+- format
+- lint
+
+```js
+let biome = "fast";
+```
+"#;
+
+        let markdown = parse_markdown(src, MarkdownParserOptions::default());
+        assert!(!markdown.has_errors());
+    }
+
+    #[test]
+    fn mdx_smoke_test() {
+        let src = r#"
+import { Chart } from "./chart.jsx";
+
+# Biome
+
+<Chart year={2024} />
+"#;
+
+        let mdx = parse_markdown(src, MarkdownParserOptions::default().mdx());
+        assert!(!mdx.has_errors());
+    }
+}
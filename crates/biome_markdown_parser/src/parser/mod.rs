@@ -0,0 +1,295 @@
+use crate::token_source::MarkdownTokenSource;
+use biome_markdown_syntax::{
+    MarkdownSyntaxKind::{self, *},
+    T,
+};
+use biome_parser::diagnostic::merge_diagnostics;
+use biome_parser::event::Event;
+use biome_parser::prelude::*;
+use biome_parser::token_source::Trivia;
+use biome_parser::ParserContext;
+
+pub(crate) struct MarkdownParser<'source> {
+    context: ParserContext<MarkdownSyntaxKind>,
+    source: MarkdownTokenSource<'source>,
+}
+
+/// Options to pass to the Markdown parser
+#[derive(Default, Debug, Clone, Copy)]
+pub struct MarkdownParserOptions {
+    /// Enables the syntax of MDX: the blocks of JavaScript imports and exports,
+    /// and the blocks of JSX elements, which replace the HTML blocks.
+    pub mdx: bool,
+}
+
+impl MarkdownParserOptions {
+    pub fn mdx(mut self) -> Self {
+        self.mdx = true;
+        self
+    }
+}
+
+impl<'source> MarkdownParser<'source> {
+    pub fn new(source: &'source str, options: MarkdownParserOptions) -> Self {
+        Self {
+            context: ParserContext::default(),
+            source: MarkdownTokenSource::from_str(source, options),
+        }
+    }
+
+    pub fn finish(
+        self,
+    ) -> (
+        Vec<Event<MarkdownSyntaxKind>>,
+        Vec<ParseDiagnostic>,
+        Vec<Trivia>,
+    ) {
+        let (trivia, lexer_diagnostics) = self.source.finish();
+        let (events, parse_diagnostics) = self.context.finish();
+
+        let diagnostics = merge_diagnostics(lexer_diagnostics, parse_diagnostics);
+
+        (events, diagnostics, trivia)
+    }
+}
+
+impl<'source> Parser for MarkdownParser<'source> {
+    type Kind = MarkdownSyntaxKind;
+    type Source = MarkdownTokenSource<'source>;
+
+    fn context(&self) -> &ParserContext<Self::Kind> {
+        &self.context
+    }
+
+    fn context_mut(&mut self) -> &mut ParserContext<Self::Kind> {
+        &mut self.context
+    }
+
+    fn source(&self) -> &Self::Source {
+        &self.source
+    }
+
+    fn source_mut(&mut self) -> &mut Self::Source {
+        &mut self.source
+    }
+}
+
+/// Parses a Markdown document.
+///
+/// Every text is valid Markdown: the parser never emits diagnostics.
+pub(crate) fn parse_document(p: &mut MarkdownParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.eat(UNICODE_BOM);
+
+    if p.at(MARKDOWN_FRONT_MATTER_LITERAL) {
+        let front_matter = p.start();
+        p.bump(MARKDOWN_FRONT_MATTER_LITERAL);
+        front_matter.complete(p, MARKDOWN_FRONT_MATTER);
+    }
+
+    parse_block_list(p, 0);
+
+    p.expect(EOF);
+
+    m.complete(p, MARKDOWN_DOCUMENT)
+}
+
+/// Parses the blocks of the document or of a list item whose content starts at `content_column`.
+fn parse_block_list(p: &mut MarkdownParser, content_column: usize) -> CompletedMarker {
+    let m = p.start();
+
+    // The first block of a list item can be on the line of its marker
+    while !p.at(EOF) && (!p.has_preceding_line_break() || current_column(p) >= content_column) {
+        parse_block(p, content_column);
+    }
+
+    m.complete(p, MARKDOWN_BLOCK_LIST)
+}
+
+fn parse_block(p: &mut MarkdownParser, content_column: usize) -> CompletedMarker {
+    match p.cur() {
+        MARKDOWN_HASH_LITERAL => parse_heading(p),
+        MARKDOWN_TEXTUAL_LITERAL => parse_paragraph(p, content_column),
+        // The underline of a paragraph that isn't in the same list item
+        MARKDOWN_SETEXT_UNDERLINE_LITERAL if p.cur_text().starts_with('-') => {
+            let m = p.start();
+            p.bump_remap(MARKDOWN_THEMATIC_BREAK_LITERAL);
+            m.complete(p, MARKDOWN_THEMATIC_BREAK)
+        }
+        MARKDOWN_SETEXT_UNDERLINE_LITERAL => {
+            let m = p.start();
+            let lines = p.start();
+            parse_textual(p);
+            lines.complete(p, MARKDOWN_TEXTUAL_LIST);
+            m.complete(p, MARKDOWN_PARAGRAPH)
+        }
+        MARKDOWN_THEMATIC_BREAK_LITERAL => parse_single_token_block(p, MARKDOWN_THEMATIC_BREAK),
+        MARKDOWN_FENCE_LITERAL => parse_fenced_code_block(p),
+        MARKDOWN_INDENTED_CODE_LITERAL => parse_single_token_block(p, MARKDOWN_INDENTED_CODE_BLOCK),
+        MARKDOWN_HTML_LITERAL => parse_single_token_block(p, MARKDOWN_HTML_BLOCK),
+        MARKDOWN_QUOTE_LITERAL => parse_single_token_block(p, MARKDOWN_QUOTE),
+        MARKDOWN_LINK_DEFINITION_LITERAL => parse_single_token_block(p, MARKDOWN_LINK_DEFINITION),
+        MDX_ESM_LITERAL => parse_single_token_block(p, MDX_ESM_BLOCK),
+        MDX_JSX_LITERAL => parse_single_token_block(p, MDX_JSX_BLOCK),
+        T![-] | T![*] | T![+] | MARKDOWN_ORDERED_MARKER_LITERAL => parse_list(p, content_column),
+        _ => parse_single_token_block(p, MARKDOWN_BOGUS_BLOCK),
+    }
+}
+
+fn parse_single_token_block(p: &mut MarkdownParser, kind: MarkdownSyntaxKind) -> CompletedMarker {
+    let m = p.start();
+    p.bump_any();
+    m.complete(p, kind)
+}
+
+/// Parses an ATX heading, `## Heading ##`.
+fn parse_heading(p: &mut MarkdownParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.bump(MARKDOWN_HASH_LITERAL);
+    if p.at(MARKDOWN_TEXTUAL_LITERAL) && !p.has_preceding_line_break() {
+        parse_textual(p);
+    }
+    if p.at(MARKDOWN_HASH_LITERAL) && !p.has_preceding_line_break() {
+        p.bump(MARKDOWN_HASH_LITERAL);
+    }
+
+    m.complete(p, MARKDOWN_HEADING)
+}
+
+/// Parses the lines of a paragraph, and its underline when it's a setext heading.
+fn parse_paragraph(p: &mut MarkdownParser, content_column: usize) -> CompletedMarker {
+    let m = p.start();
+
+    let lines = p.start();
+    parse_textual(p);
+    // The lexer only yields a textual token after a paragraph line when it continues the paragraph
+    while p.at(MARKDOWN_TEXTUAL_LITERAL) && !has_preceding_blank_line(p) {
+        parse_textual(p);
+    }
+    lines.complete(p, MARKDOWN_TEXTUAL_LIST);
+
+    let paragraph = m.complete(p, MARKDOWN_PARAGRAPH);
+
+    if p.at(MARKDOWN_SETEXT_UNDERLINE_LITERAL) && current_column(p) >= content_column {
+        let heading = paragraph.precede(p);
+        p.bump(MARKDOWN_SETEXT_UNDERLINE_LITERAL);
+        heading.complete(p, MARKDOWN_SETEXT_HEADING)
+    } else {
+        paragraph
+    }
+}
+
+fn parse_textual(p: &mut MarkdownParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump_remap(MARKDOWN_TEXTUAL_LITERAL);
+    m.complete(p, MARKDOWN_TEXTUAL)
+}
+
+/// Parses a fenced code block, from its opening fence to its closing fence.
+///
+/// The closing fence is missing when the code block ends with the document.
+fn parse_fenced_code_block(p: &mut MarkdownParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.bump(MARKDOWN_FENCE_LITERAL);
+    p.eat(MARKDOWN_INFO_STRING_LITERAL);
+    p.eat(MARKDOWN_CODE_CONTENT_LITERAL);
+    p.eat(MARKDOWN_FENCE_LITERAL);
+
+    m.complete(p, MARKDOWN_FENCED_CODE_BLOCK)
+}
+
+/// Parses the consecutive list items that have the same kind of marker:
+/// `-`, `*`, `+`, or the ordered markers with the same delimiter, `1.` or `1)`.
+fn parse_list(p: &mut MarkdownParser, content_column: usize) -> CompletedMarker {
+    let m = p.start();
+    let items = p.start();
+
+    let kind = p.cur();
+    let delimiter = list_marker_delimiter(p);
+    loop {
+        parse_list_item(p);
+
+        if !p.at(kind)
+            || list_marker_delimiter(p) != delimiter
+            || current_column(p) < content_column
+        {
+            break;
+        }
+    }
+
+    items.complete(p, MARKDOWN_LIST_ITEM_LIST);
+    m.complete(p, MARKDOWN_LIST)
+}
+
+fn parse_list_item(p: &mut MarkdownParser) -> CompletedMarker {
+    let m = p.start();
+
+    let content_column = list_item_content_column(p);
+    p.bump_any();
+    parse_block_list(p, content_column);
+
+    m.complete(p, MARKDOWN_LIST_ITEM)
+}
+
+/// Returns the last character of the current list marker, `.` or `)` for the ordered lists.
+fn list_marker_delimiter(p: &MarkdownParser) -> Option<char> {
+    p.cur_text().chars().last()
+}
+
+/// Returns the column of the content of the list item whose marker is the current token.
+///
+/// The content starts after the spaces that follow the marker,
+/// or one space after the marker when the item starts with an indented code block or a blank line.
+fn list_item_content_column(p: &MarkdownParser) -> usize {
+    let text = p.source().text();
+    let marker_end = usize::from(p.cur_range().end());
+    let marker_end_column = current_column(p) + p.cur_text().len();
+
+    let rest = &text[marker_end..];
+    let rest = &rest[..rest.find(['\n', '\r']).unwrap_or(rest.len())];
+    let content = rest.trim_start_matches([' ', '\t']);
+    let spaces =
+        column_after(marker_end_column, &rest[..rest.len() - content.len()]) - marker_end_column;
+
+    if content.is_empty() || spaces > 4 {
+        marker_end_column + 1
+    } else {
+        marker_end_column + spaces
+    }
+}
+
+/// Returns the column of the first non-whitespace character of the current token.
+///
+/// The indented code blocks and the content of the fenced code blocks start with their indentation.
+fn current_column(p: &MarkdownParser) -> usize {
+    let text = p.source().text();
+    let start = usize::from(p.cur_range().start());
+    let line_start = match text[..start].rfind(['\n', '\r']) {
+        Some(line_break) => line_break + 1,
+        // The BOM is part of the first line, but it doesn't have a column
+        None => text.len() - text.trim_start_matches('\u{feff}').len(),
+    };
+    let indentation = text[start..].len() - text[start..].trim_start_matches([' ', '\t']).len();
+
+    column_after(0, &text[line_start..start + indentation])
+}
+
+/// Returns the column after `text` that starts at `column`,
+/// where the tabs advance to the next multiple of four.
+fn column_after(column: usize, text: &str) -> usize {
+    text.chars().fold(column, |column, char| match char {
+        '\t' => column + 4 - column % 4,
+        _ => column + 1,
+    })
+}
+
+/// Returns `true` if there's a blank line between the previous token and the current token.
+fn has_preceding_blank_line(p: &MarkdownParser) -> bool {
+    let text = p.source().text();
+    let start = p.last_end().map_or(0, usize::from);
+    let trivia = &text[start..usize::from(p.cur_range().start())];
+    trivia.replace("\r\n", "\n").matches(['\n', '\r']).count() >= 2
+}
@@ -0,0 +1,107 @@
+use crate::lexer::MarkdownLexer;
+use crate::MarkdownParserOptions;
+use biome_markdown_syntax::MarkdownSyntaxKind::EOF;
+use biome_markdown_syntax::{MarkdownSyntaxKind, TextRange};
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::lexer::BufferedLexer;
+use biome_parser::prelude::TokenSource;
+use biome_parser::token_source::{TokenSourceWithBufferedLexer, Trivia};
+use biome_rowan::TriviaPieceKind;
+
+pub(crate) struct MarkdownTokenSource<'source> {
+    lexer: BufferedLexer<MarkdownSyntaxKind, MarkdownLexer<'source>>,
+    trivia_list: Vec<Trivia>,
+}
+
+impl<'source> MarkdownTokenSource<'source> {
+    pub(crate) fn new(lexer: BufferedLexer<MarkdownSyntaxKind, MarkdownLexer<'source>>) -> Self {
+        Self {
+            lexer,
+            trivia_list: Vec::new(),
+        }
+    }
+    pub fn from_str(source: &'source str, options: MarkdownParserOptions) -> Self {
+        let lexer = MarkdownLexer::from_str(source).with_options(options);
+        let lexer = BufferedLexer::new(lexer);
+
+        let mut source = MarkdownTokenSource::new(lexer);
+
+        source.next_non_trivia_token(true);
+        source
+    }
+
+    fn next_non_trivia_token(&mut self, first_token: bool) {
+        let mut trailing = !first_token;
+
+        loop {
+            let kind = self.lexer.next_token(());
+
+            let trivia_kind = TriviaPieceKind::try_from(kind);
+
+            match trivia_kind {
+                Err(_) => {
+                    // Not trivia
+                    break;
+                }
+                Ok(trivia_kind) => {
+                    if trivia_kind.is_newline() {
+                        trailing = false;
+                    }
+
+                    self.trivia_list
+                        .push(Trivia::new(trivia_kind, self.current_range(), trailing));
+                }
+            }
+        }
+    }
+}
+
+impl<'source> TokenSource for MarkdownTokenSource<'source> {
+    type Kind = MarkdownSyntaxKind;
+
+    fn current(&self) -> Self::Kind {
+        self.lexer.current()
+    }
+
+    fn current_range(&self) -> TextRange {
+        self.lexer.current_range()
+    }
+
+    fn text(&self) -> &str {
+        self.lexer.source()
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.lexer.has_preceding_line_break()
+    }
+
+    fn bump(&mut self) {
+        if self.current() != EOF {
+            self.next_non_trivia_token(false)
+        }
+    }
+
+    fn skip_as_trivia(&mut self) {
+        if self.current() != EOF {
+            self.trivia_list.push(Trivia::new(
+                TriviaPieceKind::Skipped,
+                self.current_range(),
+                false,
+            ));
+
+            self.next_non_trivia_token(false)
+        }
+    }
+
+    fn finish(self) -> (Vec<Trivia>, Vec<ParseDiagnostic>) {
+        (self.trivia_list, self.lexer.finish())
+    }
+}
+
+impl<'source> TokenSourceWithBufferedLexer<MarkdownLexer<'source>>
+    for MarkdownTokenSource<'source>
+{
+    fn lexer(&mut self) -> &mut BufferedLexer<MarkdownSyntaxKind, MarkdownLexer<'source>> {
+        &mut self.lexer
+    }
+}
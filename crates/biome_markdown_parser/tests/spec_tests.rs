@@ -0,0 +1,9 @@
+#![allow(non_snake_case)]
+
+mod spec_test;
+
+mod ok {
+    //! Tests of valid Markdown: every document is valid Markdown
+    tests_macros::gen_tests! {"tests/markdown_test_suite/ok/**/*.md", crate::spec_test::run, "ok"}
+    tests_macros::gen_tests! {"tests/markdown_test_suite/ok/**/*.mdx", crate::spec_test::run, "ok"}
+}
@@ -0,0 +1,104 @@
+use biome_rowan::FileSourceError;
+use std::{ffi::OsStr, path::Path};
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct MarkdownFileSource {
+    variant: MarkdownVariant,
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
+enum MarkdownVariant {
+    #[default]
+    Standard,
+    /// Markdown with the JavaScript imports and exports, and the JSX elements
+    Mdx,
+}
+
+impl MarkdownFileSource {
+    pub fn markdown() -> Self {
+        Self {
+            variant: MarkdownVariant::Standard,
+        }
+    }
+
+    pub fn mdx() -> Self {
+        Self {
+            variant: MarkdownVariant::Mdx,
+        }
+    }
+
+    pub const fn is_mdx(&self) -> bool {
+        matches!(self.variant, MarkdownVariant::Mdx)
+    }
+
+    /// Try to return the Markdown file source corresponding to this file name from well-known files
+    ///
+    /// The Markdown files always have an extension, even the well-known ones such as `README.md`.
+    pub fn try_from_well_known(file_name: &str) -> Result<Self, FileSourceError> {
+        Err(FileSourceError::UnknownFileName(file_name.into()))
+    }
+
+    /// Try to return the Markdown file source corresponding to this file extension
+    pub fn try_from_extension(extension: &str) -> Result<Self, FileSourceError> {
+        match extension {
+            // https://github.com/github-linguist/linguist/blob/4ac734c15a96f9e16fd12330d0cb8de82274f700/lib/linguist/languages.yml#L4220-L4231
+            "md" | "markdown" | "mdown" | "mdwn" | "mkd" | "mkdn" | "mkdown" | "ronn"
+            | "workbook" => Ok(Self::markdown()),
+            "mdx" => Ok(Self::mdx()),
+            _ => Err(FileSourceError::UnknownExtension(
+                Default::default(),
+                extension.into(),
+            )),
+        }
+    }
+
+    /// Try to return the Markdown file source corresponding to this language ID
+    ///
+    /// See the [LSP spec] and [VS Code spec] for a list of language identifiers
+    ///
+    /// The language ID for code snippets is registered by [VS Code built-in extensions]
+    ///
+    /// The language ID for MDX is registered by its [VS Code extension]
+    ///
+    /// [LSP spec]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentItem
+    /// [VS Code spec]: https://code.visualstudio.com/docs/languages/identifiers
+    /// [VS Code extension]: https://github.com/mdx-js/mdx-analyzer/blob/main/packages/vscode-mdx/package.json
+    pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
+        match language_id {
+            "markdown" => Ok(Self::markdown()),
+            "mdx" => Ok(Self::mdx()),
+            _ => Err(FileSourceError::UnknownLanguageId(language_id.into())),
+        }
+    }
+}
+
+impl TryFrom<&Path> for MarkdownFileSource {
+    type Error = FileSourceError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let file_name = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?;
+
+        if let Ok(file_source) = Self::try_from_well_known(file_name) {
+            return Ok(file_source);
+        }
+
+        // We assume the file extensions are case-insensitive
+        // and we use the lowercase form of them for pattern matching
+        let extension = &path
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(str::to_lowercase)
+            .ok_or_else(|| FileSourceError::MissingFileExtension(path.into()))?;
+
+        Self::try_from_extension(extension)
+    }
+}
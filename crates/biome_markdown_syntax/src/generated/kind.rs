@@ -0,0 +1,119 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#![allow(clippy::all)]
+#![allow(bad_style, missing_docs, unreachable_pub)]
+#[doc = r" The kind of syntax node, e.g. `IDENT`, `FUNCTION_KW`, or `FOR_STMT`."]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+#[repr(u16)]
+pub enum MarkdownSyntaxKind {
+    #[doc(hidden)]
+    TOMBSTONE,
+    #[doc = r" Marks the end of the file. May have trivia attached"]
+    EOF,
+    #[doc = r" Any Unicode BOM character that may be present at the start of"]
+    #[doc = r" a file."]
+    UNICODE_BOM,
+    MINUS,
+    STAR,
+    PLUS,
+    NULL_KW,
+    MARKDOWN_FRONT_MATTER_LITERAL,
+    MARKDOWN_HASH_LITERAL,
+    MARKDOWN_TEXTUAL_LITERAL,
+    MARKDOWN_SETEXT_UNDERLINE_LITERAL,
+    MARKDOWN_THEMATIC_BREAK_LITERAL,
+    MARKDOWN_FENCE_LITERAL,
+    MARKDOWN_INFO_STRING_LITERAL,
+    MARKDOWN_CODE_CONTENT_LITERAL,
+    MARKDOWN_INDENTED_CODE_LITERAL,
+    MARKDOWN_HTML_LITERAL,
+    MARKDOWN_QUOTE_LITERAL,
+    MARKDOWN_LINK_DEFINITION_LITERAL,
+    MARKDOWN_ORDERED_MARKER_LITERAL,
+    MDX_ESM_LITERAL,
+    MDX_JSX_LITERAL,
+    ERROR_TOKEN,
+    NEWLINE,
+    WHITESPACE,
+    MARKDOWN_DOCUMENT,
+    MARKDOWN_FRONT_MATTER,
+    MARKDOWN_BLOCK_LIST,
+    MARKDOWN_HEADING,
+    MARKDOWN_SETEXT_HEADING,
+    MARKDOWN_TEXTUAL,
+    MARKDOWN_THEMATIC_BREAK,
+    MARKDOWN_PARAGRAPH,
+    MARKDOWN_TEXTUAL_LIST,
+    MARKDOWN_FENCED_CODE_BLOCK,
+    MARKDOWN_INDENTED_CODE_BLOCK,
+    MARKDOWN_HTML_BLOCK,
+    MARKDOWN_QUOTE,
+    MARKDOWN_LINK_DEFINITION,
+    MARKDOWN_LIST,
+    MARKDOWN_LIST_ITEM_LIST,
+    MARKDOWN_LIST_ITEM,
+    MDX_ESM_BLOCK,
+    MDX_JSX_BLOCK,
+    MARKDOWN_BOGUS,
+    MARKDOWN_BOGUS_BLOCK,
+    #[doc(hidden)]
+    __LAST,
+}
+use self::MarkdownSyntaxKind::*;
+impl MarkdownSyntaxKind {
+    pub const fn is_punct(self) -> bool {
+        match self {
+            MINUS | STAR | PLUS => true,
+            _ => false,
+        }
+    }
+    pub const fn is_literal(self) -> bool {
+        match self {
+            MARKDOWN_FRONT_MATTER_LITERAL
+            | MARKDOWN_HASH_LITERAL
+            | MARKDOWN_TEXTUAL_LITERAL
+            | MARKDOWN_SETEXT_UNDERLINE_LITERAL
+            | MARKDOWN_THEMATIC_BREAK_LITERAL
+            | MARKDOWN_FENCE_LITERAL
+            | MARKDOWN_INFO_STRING_LITERAL
+            | MARKDOWN_CODE_CONTENT_LITERAL
+            | MARKDOWN_INDENTED_CODE_LITERAL
+            | MARKDOWN_HTML_LITERAL
+            | MARKDOWN_QUOTE_LITERAL
+            | MARKDOWN_LINK_DEFINITION_LITERAL
+            | MARKDOWN_ORDERED_MARKER_LITERAL
+            | MDX_ESM_LITERAL
+            | MDX_JSX_LITERAL => true,
+            _ => false,
+        }
+    }
+    pub const fn is_list(self) -> bool {
+        match self {
+            MARKDOWN_BLOCK_LIST
+            | MARKDOWN_TEXTUAL_LIST
+            | MARKDOWN_LIST
+            | MARKDOWN_LIST_ITEM_LIST => true,
+            _ => false,
+        }
+    }
+    pub fn from_keyword(ident: &str) -> Option<MarkdownSyntaxKind> {
+        let kw = match ident {
+            "null" => NULL_KW,
+            _ => return None,
+        };
+        Some(kw)
+    }
+    pub const fn to_string(&self) -> Option<&'static str> {
+        let tok = match self {
+            MINUS => "-",
+            STAR => "*",
+            PLUS => "+",
+            NULL_KW => "null",
+            _ => return None,
+        };
+        Some(tok)
+    }
+}
+#[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
+#[macro_export]
+macro_rules ! T { [-] => { $ crate :: MarkdownSyntaxKind :: MINUS } ; [*] => { $ crate :: MarkdownSyntaxKind :: STAR } ; [+] => { $ crate :: MarkdownSyntaxKind :: PLUS } ; [null] => { $ crate :: MarkdownSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: MarkdownSyntaxKind :: IDENT } ; [EOF] => { $ crate :: MarkdownSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: MarkdownSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: MarkdownSyntaxKind :: HASH } ; }
@@ -0,0 +1,109 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#[doc = r" Reconstruct an AstNode from a SyntaxNode"]
+#[doc = r""]
+#[doc = r" This macros performs a match over the [kind](biome_rowan::SyntaxNode::kind)"]
+#[doc = r" of the provided [biome_rowan::SyntaxNode] and constructs the appropriate"]
+#[doc = r" AstNode type for it, then execute the provided expression over it."]
+#[doc = r""]
+#[doc = r" # Examples"]
+#[doc = r""]
+#[doc = r" ```ignore"]
+#[doc = r" map_syntax_node!(syntax_node, node => node.format())"]
+#[doc = r" ```"]
+#[macro_export]
+macro_rules! map_syntax_node {
+    ($ node : expr , $ pattern : pat => $ body : expr) => {
+        match $node {
+            node => match $crate::MarkdownSyntaxNode::kind(&node) {
+                $crate::MarkdownSyntaxKind::MARKDOWN_DOCUMENT => {
+                    let $pattern = unsafe { $crate::MarkdownDocument::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_FENCED_CODE_BLOCK => {
+                    let $pattern = unsafe { $crate::MarkdownFencedCodeBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_FRONT_MATTER => {
+                    let $pattern = unsafe { $crate::MarkdownFrontMatter::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_HEADING => {
+                    let $pattern = unsafe { $crate::MarkdownHeading::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_HTML_BLOCK => {
+                    let $pattern = unsafe { $crate::MarkdownHtmlBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_INDENTED_CODE_BLOCK => {
+                    let $pattern =
+                        unsafe { $crate::MarkdownIndentedCodeBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_LINK_DEFINITION => {
+                    let $pattern = unsafe { $crate::MarkdownLinkDefinition::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_LIST => {
+                    let $pattern = unsafe { $crate::MarkdownList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_LIST_ITEM => {
+                    let $pattern = unsafe { $crate::MarkdownListItem::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_PARAGRAPH => {
+                    let $pattern = unsafe { $crate::MarkdownParagraph::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_QUOTE => {
+                    let $pattern = unsafe { $crate::MarkdownQuote::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_SETEXT_HEADING => {
+                    let $pattern = unsafe { $crate::MarkdownSetextHeading::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_TEXTUAL => {
+                    let $pattern = unsafe { $crate::MarkdownTextual::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_THEMATIC_BREAK => {
+                    let $pattern = unsafe { $crate::MarkdownThematicBreak::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MDX_ESM_BLOCK => {
+                    let $pattern = unsafe { $crate::MdxEsmBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MDX_JSX_BLOCK => {
+                    let $pattern = unsafe { $crate::MdxJsxBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_BOGUS => {
+                    let $pattern = unsafe { $crate::MarkdownBogus::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_BOGUS_BLOCK => {
+                    let $pattern = unsafe { $crate::MarkdownBogusBlock::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_BLOCK_LIST => {
+                    let $pattern = unsafe { $crate::MarkdownBlockList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_LIST_ITEM_LIST => {
+                    let $pattern = unsafe { $crate::MarkdownListItemList::new_unchecked(node) };
+                    $body
+                }
+                $crate::MarkdownSyntaxKind::MARKDOWN_TEXTUAL_LIST => {
+                    let $pattern = unsafe { $crate::MarkdownTextualList::new_unchecked(node) };
+                    $body
+                }
+                _ => unreachable!(),
+            },
+        }
+    };
+}
+pub(crate) use map_syntax_node;
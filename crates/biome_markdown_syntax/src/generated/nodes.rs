@@ -0,0 +1,2100 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#![allow(clippy::enum_variant_names)]
+#![allow(clippy::match_like_matches_macro)]
+use crate::{
+    macros::map_syntax_node,
+    MarkdownLanguage as Language, MarkdownSyntaxElement as SyntaxElement,
+    MarkdownSyntaxElementChildren as SyntaxElementChildren,
+    MarkdownSyntaxKind::{self as SyntaxKind, *},
+    MarkdownSyntaxList as SyntaxList, MarkdownSyntaxNode as SyntaxNode,
+    MarkdownSyntaxToken as SyntaxToken,
+};
+use biome_rowan::{support, AstNode, RawSyntaxKind, SyntaxKindSet, SyntaxResult};
+#[allow(unused)]
+use biome_rowan::{
+    AstNodeList, AstNodeListIterator, AstNodeSlotMap, AstSeparatedList,
+    AstSeparatedListNodesIterator,
+};
+#[cfg(feature = "serde")]
+use serde::ser::SerializeSeq;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+use std::fmt::{Debug, Formatter};
+#[doc = r" Sentinel value indicating a missing element in a dynamic node, where"]
+#[doc = r" the slots are not statically known."]
+#[allow(dead_code)]
+pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownDocument {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownDocument {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownDocumentFields {
+        MarkdownDocumentFields {
+            bom_token: self.bom_token(),
+            front_matter: self.front_matter(),
+            blocks: self.blocks(),
+            eof_token: self.eof_token(),
+        }
+    }
+    pub fn bom_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn front_matter(&self) -> Option<MarkdownFrontMatter> {
+        support::node(&self.syntax, 1usize)
+    }
+    pub fn blocks(&self) -> MarkdownBlockList {
+        support::list(&self.syntax, 2usize)
+    }
+    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownDocument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownDocumentFields {
+    pub bom_token: Option<SyntaxToken>,
+    pub front_matter: Option<MarkdownFrontMatter>,
+    pub blocks: MarkdownBlockList,
+    pub eof_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownFencedCodeBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownFencedCodeBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownFencedCodeBlockFields {
+        MarkdownFencedCodeBlockFields {
+            l_fence_token: self.l_fence_token(),
+            info_token: self.info_token(),
+            content_token: self.content_token(),
+            r_fence_token: self.r_fence_token(),
+        }
+    }
+    pub fn l_fence_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn info_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+    pub fn content_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 2usize)
+    }
+    pub fn r_fence_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownFencedCodeBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownFencedCodeBlockFields {
+    pub l_fence_token: SyntaxResult<SyntaxToken>,
+    pub info_token: Option<SyntaxToken>,
+    pub content_token: Option<SyntaxToken>,
+    pub r_fence_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownFrontMatter {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownFrontMatter {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownFrontMatterFields {
+        MarkdownFrontMatterFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownFrontMatter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownFrontMatterFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownHeading {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownHeading {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownHeadingFields {
+        MarkdownHeadingFields {
+            opening_token: self.opening_token(),
+            content: self.content(),
+            closing_token: self.closing_token(),
+        }
+    }
+    pub fn opening_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> Option<MarkdownTextual> {
+        support::node(&self.syntax, 1usize)
+    }
+    pub fn closing_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownHeading {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownHeadingFields {
+    pub opening_token: SyntaxResult<SyntaxToken>,
+    pub content: Option<MarkdownTextual>,
+    pub closing_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownHtmlBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownHtmlBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownHtmlBlockFields {
+        MarkdownHtmlBlockFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownHtmlBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownHtmlBlockFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownIndentedCodeBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownIndentedCodeBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownIndentedCodeBlockFields {
+        MarkdownIndentedCodeBlockFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownIndentedCodeBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownIndentedCodeBlockFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownLinkDefinition {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownLinkDefinition {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownLinkDefinitionFields {
+        MarkdownLinkDefinitionFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownLinkDefinition {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownLinkDefinitionFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownList {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownListFields {
+        MarkdownListFields {
+            items: self.items(),
+        }
+    }
+    pub fn items(&self) -> MarkdownListItemList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownListFields {
+    pub items: MarkdownListItemList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownListItem {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownListItem {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownListItemFields {
+        MarkdownListItemFields {
+            marker: self.marker(),
+            content: self.content(),
+        }
+    }
+    pub fn marker(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn content(&self) -> MarkdownBlockList {
+        support::list(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownListItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownListItemFields {
+    pub marker: SyntaxResult<SyntaxToken>,
+    pub content: MarkdownBlockList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownParagraph {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownParagraph {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownParagraphFields {
+        MarkdownParagraphFields {
+            lines: self.lines(),
+        }
+    }
+    pub fn lines(&self) -> MarkdownTextualList {
+        support::list(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownParagraph {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownParagraphFields {
+    pub lines: MarkdownTextualList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownQuote {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownQuote {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownQuoteFields {
+        MarkdownQuoteFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownQuote {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownQuoteFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownSetextHeading {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownSetextHeading {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownSetextHeadingFields {
+        MarkdownSetextHeadingFields {
+            content: self.content(),
+            underline_token: self.underline_token(),
+        }
+    }
+    pub fn content(&self) -> SyntaxResult<MarkdownParagraph> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn underline_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownSetextHeading {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownSetextHeadingFields {
+    pub content: SyntaxResult<MarkdownParagraph>,
+    pub underline_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownTextual {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownTextual {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownTextualFields {
+        MarkdownTextualFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownTextual {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownTextualFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MarkdownThematicBreak {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MarkdownThematicBreak {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MarkdownThematicBreakFields {
+        MarkdownThematicBreakFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownThematicBreak {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownThematicBreakFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdxEsmBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdxEsmBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdxEsmBlockFields {
+        MdxEsmBlockFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MdxEsmBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MdxEsmBlockFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct MdxJsxBlock {
+    pub(crate) syntax: SyntaxNode,
+}
+impl MdxJsxBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> MdxJsxBlockFields {
+        MdxJsxBlockFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MdxJsxBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MdxJsxBlockFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyMarkdownBlock {
+    MarkdownBogusBlock(MarkdownBogusBlock),
+    MarkdownFencedCodeBlock(MarkdownFencedCodeBlock),
+    MarkdownHeading(MarkdownHeading),
+    MarkdownHtmlBlock(MarkdownHtmlBlock),
+    MarkdownIndentedCodeBlock(MarkdownIndentedCodeBlock),
+    MarkdownLinkDefinition(MarkdownLinkDefinition),
+    MarkdownList(MarkdownList),
+    MarkdownParagraph(MarkdownParagraph),
+    MarkdownQuote(MarkdownQuote),
+    MarkdownSetextHeading(MarkdownSetextHeading),
+    MarkdownThematicBreak(MarkdownThematicBreak),
+    MdxEsmBlock(MdxEsmBlock),
+    MdxJsxBlock(MdxJsxBlock),
+}
+impl AnyMarkdownBlock {
+    pub fn as_markdown_bogus_block(&self) -> Option<&MarkdownBogusBlock> {
+        match &self {
+            AnyMarkdownBlock::MarkdownBogusBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_fenced_code_block(&self) -> Option<&MarkdownFencedCodeBlock> {
+        match &self {
+            AnyMarkdownBlock::MarkdownFencedCodeBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_heading(&self) -> Option<&MarkdownHeading> {
+        match &self {
+            AnyMarkdownBlock::MarkdownHeading(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_html_block(&self) -> Option<&MarkdownHtmlBlock> {
+        match &self {
+            AnyMarkdownBlock::MarkdownHtmlBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_indented_code_block(&self) -> Option<&MarkdownIndentedCodeBlock> {
+        match &self {
+            AnyMarkdownBlock::MarkdownIndentedCodeBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_link_definition(&self) -> Option<&MarkdownLinkDefinition> {
+        match &self {
+            AnyMarkdownBlock::MarkdownLinkDefinition(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_list(&self) -> Option<&MarkdownList> {
+        match &self {
+            AnyMarkdownBlock::MarkdownList(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_paragraph(&self) -> Option<&MarkdownParagraph> {
+        match &self {
+            AnyMarkdownBlock::MarkdownParagraph(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_quote(&self) -> Option<&MarkdownQuote> {
+        match &self {
+            AnyMarkdownBlock::MarkdownQuote(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_setext_heading(&self) -> Option<&MarkdownSetextHeading> {
+        match &self {
+            AnyMarkdownBlock::MarkdownSetextHeading(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_markdown_thematic_break(&self) -> Option<&MarkdownThematicBreak> {
+        match &self {
+            AnyMarkdownBlock::MarkdownThematicBreak(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_mdx_esm_block(&self) -> Option<&MdxEsmBlock> {
+        match &self {
+            AnyMarkdownBlock::MdxEsmBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_mdx_jsx_block(&self) -> Option<&MdxJsxBlock> {
+        match &self {
+            AnyMarkdownBlock::MdxJsxBlock(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for MarkdownDocument {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_DOCUMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_DOCUMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownDocument")
+            .field(
+                "bom_token",
+                &support::DebugOptionalElement(self.bom_token()),
+            )
+            .field(
+                "front_matter",
+                &support::DebugOptionalElement(self.front_matter()),
+            )
+            .field("blocks", &self.blocks())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+            .finish()
+    }
+}
+impl From<MarkdownDocument> for SyntaxNode {
+    fn from(n: MarkdownDocument) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownDocument> for SyntaxElement {
+    fn from(n: MarkdownDocument) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownFencedCodeBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_FENCED_CODE_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_FENCED_CODE_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownFencedCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownFencedCodeBlock")
+            .field(
+                "l_fence_token",
+                &support::DebugSyntaxResult(self.l_fence_token()),
+            )
+            .field(
+                "info_token",
+                &support::DebugOptionalElement(self.info_token()),
+            )
+            .field(
+                "content_token",
+                &support::DebugOptionalElement(self.content_token()),
+            )
+            .field(
+                "r_fence_token",
+                &support::DebugOptionalElement(self.r_fence_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownFencedCodeBlock> for SyntaxNode {
+    fn from(n: MarkdownFencedCodeBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownFencedCodeBlock> for SyntaxElement {
+    fn from(n: MarkdownFencedCodeBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownFrontMatter {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_FRONT_MATTER as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_FRONT_MATTER
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownFrontMatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownFrontMatter")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownFrontMatter> for SyntaxNode {
+    fn from(n: MarkdownFrontMatter) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownFrontMatter> for SyntaxElement {
+    fn from(n: MarkdownFrontMatter) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownHeading {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_HEADING as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_HEADING
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownHeading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownHeading")
+            .field(
+                "opening_token",
+                &support::DebugSyntaxResult(self.opening_token()),
+            )
+            .field("content", &support::DebugOptionalElement(self.content()))
+            .field(
+                "closing_token",
+                &support::DebugOptionalElement(self.closing_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownHeading> for SyntaxNode {
+    fn from(n: MarkdownHeading) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownHeading> for SyntaxElement {
+    fn from(n: MarkdownHeading) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownHtmlBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_HTML_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_HTML_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownHtmlBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownHtmlBlock")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownHtmlBlock> for SyntaxNode {
+    fn from(n: MarkdownHtmlBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownHtmlBlock> for SyntaxElement {
+    fn from(n: MarkdownHtmlBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownIndentedCodeBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_INDENTED_CODE_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_INDENTED_CODE_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownIndentedCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownIndentedCodeBlock")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownIndentedCodeBlock> for SyntaxNode {
+    fn from(n: MarkdownIndentedCodeBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownIndentedCodeBlock> for SyntaxElement {
+    fn from(n: MarkdownIndentedCodeBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownLinkDefinition {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_LINK_DEFINITION as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_LINK_DEFINITION
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownLinkDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownLinkDefinition")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownLinkDefinition> for SyntaxNode {
+    fn from(n: MarkdownLinkDefinition) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownLinkDefinition> for SyntaxElement {
+    fn from(n: MarkdownLinkDefinition) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownList")
+            .field("items", &self.items())
+            .finish()
+    }
+}
+impl From<MarkdownList> for SyntaxNode {
+    fn from(n: MarkdownList) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownList> for SyntaxElement {
+    fn from(n: MarkdownList) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownListItem {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_LIST_ITEM as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_LIST_ITEM
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownListItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownListItem")
+            .field("marker", &support::DebugSyntaxResult(self.marker()))
+            .field("content", &self.content())
+            .finish()
+    }
+}
+impl From<MarkdownListItem> for SyntaxNode {
+    fn from(n: MarkdownListItem) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownListItem> for SyntaxElement {
+    fn from(n: MarkdownListItem) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownParagraph {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_PARAGRAPH as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_PARAGRAPH
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownParagraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownParagraph")
+            .field("lines", &self.lines())
+            .finish()
+    }
+}
+impl From<MarkdownParagraph> for SyntaxNode {
+    fn from(n: MarkdownParagraph) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownParagraph> for SyntaxElement {
+    fn from(n: MarkdownParagraph) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownQuote {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_QUOTE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_QUOTE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownQuote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownQuote")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownQuote> for SyntaxNode {
+    fn from(n: MarkdownQuote) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownQuote> for SyntaxElement {
+    fn from(n: MarkdownQuote) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownSetextHeading {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_SETEXT_HEADING as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_SETEXT_HEADING
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownSetextHeading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownSetextHeading")
+            .field("content", &support::DebugSyntaxResult(self.content()))
+            .field(
+                "underline_token",
+                &support::DebugSyntaxResult(self.underline_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownSetextHeading> for SyntaxNode {
+    fn from(n: MarkdownSetextHeading) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownSetextHeading> for SyntaxElement {
+    fn from(n: MarkdownSetextHeading) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownTextual {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_TEXTUAL as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_TEXTUAL
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownTextual {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownTextual")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownTextual> for SyntaxNode {
+    fn from(n: MarkdownTextual) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownTextual> for SyntaxElement {
+    fn from(n: MarkdownTextual) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MarkdownThematicBreak {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_THEMATIC_BREAK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_THEMATIC_BREAK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownThematicBreak {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownThematicBreak")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MarkdownThematicBreak> for SyntaxNode {
+    fn from(n: MarkdownThematicBreak) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownThematicBreak> for SyntaxElement {
+    fn from(n: MarkdownThematicBreak) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdxEsmBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MDX_ESM_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MDX_ESM_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdxEsmBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MdxEsmBlock")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MdxEsmBlock> for SyntaxNode {
+    fn from(n: MdxEsmBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MdxEsmBlock> for SyntaxElement {
+    fn from(n: MdxEsmBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for MdxJsxBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MDX_JSX_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MDX_JSX_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MdxJsxBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MdxJsxBlock")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<MdxJsxBlock> for SyntaxNode {
+    fn from(n: MdxJsxBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MdxJsxBlock> for SyntaxElement {
+    fn from(n: MdxJsxBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl From<MarkdownBogusBlock> for AnyMarkdownBlock {
+    fn from(node: MarkdownBogusBlock) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownBogusBlock(node)
+    }
+}
+impl From<MarkdownFencedCodeBlock> for AnyMarkdownBlock {
+    fn from(node: MarkdownFencedCodeBlock) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownFencedCodeBlock(node)
+    }
+}
+impl From<MarkdownHeading> for AnyMarkdownBlock {
+    fn from(node: MarkdownHeading) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownHeading(node)
+    }
+}
+impl From<MarkdownHtmlBlock> for AnyMarkdownBlock {
+    fn from(node: MarkdownHtmlBlock) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownHtmlBlock(node)
+    }
+}
+impl From<MarkdownIndentedCodeBlock> for AnyMarkdownBlock {
+    fn from(node: MarkdownIndentedCodeBlock) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownIndentedCodeBlock(node)
+    }
+}
+impl From<MarkdownLinkDefinition> for AnyMarkdownBlock {
+    fn from(node: MarkdownLinkDefinition) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownLinkDefinition(node)
+    }
+}
+impl From<MarkdownList> for AnyMarkdownBlock {
+    fn from(node: MarkdownList) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownList(node)
+    }
+}
+impl From<MarkdownParagraph> for AnyMarkdownBlock {
+    fn from(node: MarkdownParagraph) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownParagraph(node)
+    }
+}
+impl From<MarkdownQuote> for AnyMarkdownBlock {
+    fn from(node: MarkdownQuote) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownQuote(node)
+    }
+}
+impl From<MarkdownSetextHeading> for AnyMarkdownBlock {
+    fn from(node: MarkdownSetextHeading) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownSetextHeading(node)
+    }
+}
+impl From<MarkdownThematicBreak> for AnyMarkdownBlock {
+    fn from(node: MarkdownThematicBreak) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MarkdownThematicBreak(node)
+    }
+}
+impl From<MdxEsmBlock> for AnyMarkdownBlock {
+    fn from(node: MdxEsmBlock) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MdxEsmBlock(node)
+    }
+}
+impl From<MdxJsxBlock> for AnyMarkdownBlock {
+    fn from(node: MdxJsxBlock) -> AnyMarkdownBlock {
+        AnyMarkdownBlock::MdxJsxBlock(node)
+    }
+}
+impl AstNode for AnyMarkdownBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = MarkdownBogusBlock::KIND_SET
+        .union(MarkdownFencedCodeBlock::KIND_SET)
+        .union(MarkdownHeading::KIND_SET)
+        .union(MarkdownHtmlBlock::KIND_SET)
+        .union(MarkdownIndentedCodeBlock::KIND_SET)
+        .union(MarkdownLinkDefinition::KIND_SET)
+        .union(MarkdownList::KIND_SET)
+        .union(MarkdownParagraph::KIND_SET)
+        .union(MarkdownQuote::KIND_SET)
+        .union(MarkdownSetextHeading::KIND_SET)
+        .union(MarkdownThematicBreak::KIND_SET)
+        .union(MdxEsmBlock::KIND_SET)
+        .union(MdxJsxBlock::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            MARKDOWN_BOGUS_BLOCK
+                | MARKDOWN_FENCED_CODE_BLOCK
+                | MARKDOWN_HEADING
+                | MARKDOWN_HTML_BLOCK
+                | MARKDOWN_INDENTED_CODE_BLOCK
+                | MARKDOWN_LINK_DEFINITION
+                | MARKDOWN_LIST
+                | MARKDOWN_PARAGRAPH
+                | MARKDOWN_QUOTE
+                | MARKDOWN_SETEXT_HEADING
+                | MARKDOWN_THEMATIC_BREAK
+                | MDX_ESM_BLOCK
+                | MDX_JSX_BLOCK
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            MARKDOWN_BOGUS_BLOCK => {
+                AnyMarkdownBlock::MarkdownBogusBlock(MarkdownBogusBlock { syntax })
+            }
+            MARKDOWN_FENCED_CODE_BLOCK => {
+                AnyMarkdownBlock::MarkdownFencedCodeBlock(MarkdownFencedCodeBlock { syntax })
+            }
+            MARKDOWN_HEADING => AnyMarkdownBlock::MarkdownHeading(MarkdownHeading { syntax }),
+            MARKDOWN_HTML_BLOCK => {
+                AnyMarkdownBlock::MarkdownHtmlBlock(MarkdownHtmlBlock { syntax })
+            }
+            MARKDOWN_INDENTED_CODE_BLOCK => {
+                AnyMarkdownBlock::MarkdownIndentedCodeBlock(MarkdownIndentedCodeBlock { syntax })
+            }
+            MARKDOWN_LINK_DEFINITION => {
+                AnyMarkdownBlock::MarkdownLinkDefinition(MarkdownLinkDefinition { syntax })
+            }
+            MARKDOWN_LIST => AnyMarkdownBlock::MarkdownList(MarkdownList { syntax }),
+            MARKDOWN_PARAGRAPH => AnyMarkdownBlock::MarkdownParagraph(MarkdownParagraph { syntax }),
+            MARKDOWN_QUOTE => AnyMarkdownBlock::MarkdownQuote(MarkdownQuote { syntax }),
+            MARKDOWN_SETEXT_HEADING => {
+                AnyMarkdownBlock::MarkdownSetextHeading(MarkdownSetextHeading { syntax })
+            }
+            MARKDOWN_THEMATIC_BREAK => {
+                AnyMarkdownBlock::MarkdownThematicBreak(MarkdownThematicBreak { syntax })
+            }
+            MDX_ESM_BLOCK => AnyMarkdownBlock::MdxEsmBlock(MdxEsmBlock { syntax }),
+            MDX_JSX_BLOCK => AnyMarkdownBlock::MdxJsxBlock(MdxJsxBlock { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyMarkdownBlock::MarkdownBogusBlock(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownFencedCodeBlock(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownHeading(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownHtmlBlock(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownIndentedCodeBlock(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownLinkDefinition(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownList(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownParagraph(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownQuote(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownSetextHeading(it) => &it.syntax,
+            AnyMarkdownBlock::MarkdownThematicBreak(it) => &it.syntax,
+            AnyMarkdownBlock::MdxEsmBlock(it) => &it.syntax,
+            AnyMarkdownBlock::MdxJsxBlock(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyMarkdownBlock::MarkdownBogusBlock(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownFencedCodeBlock(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownHeading(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownHtmlBlock(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownIndentedCodeBlock(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownLinkDefinition(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownList(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownParagraph(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownQuote(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownSetextHeading(it) => it.syntax,
+            AnyMarkdownBlock::MarkdownThematicBreak(it) => it.syntax,
+            AnyMarkdownBlock::MdxEsmBlock(it) => it.syntax,
+            AnyMarkdownBlock::MdxJsxBlock(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyMarkdownBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyMarkdownBlock::MarkdownBogusBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownFencedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownHeading(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownHtmlBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownIndentedCodeBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownLinkDefinition(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownList(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownParagraph(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownQuote(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownSetextHeading(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MarkdownThematicBreak(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MdxEsmBlock(it) => std::fmt::Debug::fmt(it, f),
+            AnyMarkdownBlock::MdxJsxBlock(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyMarkdownBlock> for SyntaxNode {
+    fn from(n: AnyMarkdownBlock) -> SyntaxNode {
+        match n {
+            AnyMarkdownBlock::MarkdownBogusBlock(it) => it.into(),
+            AnyMarkdownBlock::MarkdownFencedCodeBlock(it) => it.into(),
+            AnyMarkdownBlock::MarkdownHeading(it) => it.into(),
+            AnyMarkdownBlock::MarkdownHtmlBlock(it) => it.into(),
+            AnyMarkdownBlock::MarkdownIndentedCodeBlock(it) => it.into(),
+            AnyMarkdownBlock::MarkdownLinkDefinition(it) => it.into(),
+            AnyMarkdownBlock::MarkdownList(it) => it.into(),
+            AnyMarkdownBlock::MarkdownParagraph(it) => it.into(),
+            AnyMarkdownBlock::MarkdownQuote(it) => it.into(),
+            AnyMarkdownBlock::MarkdownSetextHeading(it) => it.into(),
+            AnyMarkdownBlock::MarkdownThematicBreak(it) => it.into(),
+            AnyMarkdownBlock::MdxEsmBlock(it) => it.into(),
+            AnyMarkdownBlock::MdxJsxBlock(it) => it.into(),
+        }
+    }
+}
+impl From<AnyMarkdownBlock> for SyntaxElement {
+    fn from(n: AnyMarkdownBlock) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl std::fmt::Display for AnyMarkdownBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownFencedCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownFrontMatter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownHeading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownHtmlBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownIndentedCodeBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownLinkDefinition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownListItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownParagraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownQuote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownSetextHeading {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownTextual {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MarkdownThematicBreak {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdxEsmBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for MdxJsxBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownBogus {
+    syntax: SyntaxNode,
+}
+impl MarkdownBogus {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for MarkdownBogus {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_BOGUS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_BOGUS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownBogus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownBogus")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<MarkdownBogus> for SyntaxNode {
+    fn from(n: MarkdownBogus) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownBogus> for SyntaxElement {
+    fn from(n: MarkdownBogus) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MarkdownBogusBlock {
+    syntax: SyntaxNode,
+}
+impl MarkdownBogusBlock {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for MarkdownBogusBlock {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_BOGUS_BLOCK as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_BOGUS_BLOCK
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for MarkdownBogusBlock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarkdownBogusBlock")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<MarkdownBogusBlock> for SyntaxNode {
+    fn from(n: MarkdownBogusBlock) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<MarkdownBogusBlock> for SyntaxElement {
+    fn from(n: MarkdownBogusBlock) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MarkdownBlockList {
+    syntax_list: SyntaxList,
+}
+impl MarkdownBlockList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MarkdownBlockList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_BLOCK_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_BLOCK_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<MarkdownBlockList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MarkdownBlockList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownBlockList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MarkdownBlockList {
+    type Language = Language;
+    type Node = AnyMarkdownBlock;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MarkdownBlockList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MarkdownBlockList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &MarkdownBlockList {
+    type Item = AnyMarkdownBlock;
+    type IntoIter = AstNodeListIterator<Language, AnyMarkdownBlock>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for MarkdownBlockList {
+    type Item = AnyMarkdownBlock;
+    type IntoIter = AstNodeListIterator<Language, AnyMarkdownBlock>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MarkdownListItemList {
+    syntax_list: SyntaxList,
+}
+impl MarkdownListItemList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MarkdownListItemList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_LIST_ITEM_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_LIST_ITEM_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<MarkdownListItemList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MarkdownListItemList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownListItemList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MarkdownListItemList {
+    type Language = Language;
+    type Node = MarkdownListItem;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MarkdownListItemList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MarkdownListItemList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &MarkdownListItemList {
+    type Item = MarkdownListItem;
+    type IntoIter = AstNodeListIterator<Language, MarkdownListItem>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for MarkdownListItemList {
+    type Item = MarkdownListItem;
+    type IntoIter = AstNodeListIterator<Language, MarkdownListItem>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct MarkdownTextualList {
+    syntax_list: SyntaxList,
+}
+impl MarkdownTextualList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for MarkdownTextualList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(MARKDOWN_TEXTUAL_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == MARKDOWN_TEXTUAL_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<MarkdownTextualList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(MarkdownTextualList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for MarkdownTextualList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for MarkdownTextualList {
+    type Language = Language;
+    type Node = MarkdownTextual;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for MarkdownTextualList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MarkdownTextualList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &MarkdownTextualList {
+    type Item = MarkdownTextual;
+    type IntoIter = AstNodeListIterator<Language, MarkdownTextual>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for MarkdownTextualList {
+    type Item = MarkdownTextual;
+    type IntoIter = AstNodeListIterator<Language, MarkdownTextual>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone)]
+pub struct DebugSyntaxElementChildren(pub SyntaxElementChildren);
+impl Debug for DebugSyntaxElementChildren {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.clone().0.map(DebugSyntaxElement))
+            .finish()
+    }
+}
+struct DebugSyntaxElement(SyntaxElement);
+impl Debug for DebugSyntaxElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            SyntaxElement::Node(node) => {
+                map_syntax_node ! (node . clone () , node => std :: fmt :: Debug :: fmt (& node , f))
+            }
+            SyntaxElement::Token(token) => Debug::fmt(token, f),
+        }
+    }
+}
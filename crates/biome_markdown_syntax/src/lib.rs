@@ -0,0 +1,98 @@
+#[macro_use]
+mod generated;
+mod file_source;
+mod syntax_node;
+
+pub use self::generated::*;
+use biome_rowan::RawSyntaxKind;
+pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use file_source::MarkdownFileSource;
+pub use syntax_node::*;
+
+impl From<u16> for MarkdownSyntaxKind {
+    fn from(d: u16) -> MarkdownSyntaxKind {
+        assert!(d <= (MarkdownSyntaxKind::__LAST as u16));
+        unsafe { std::mem::transmute::<u16, MarkdownSyntaxKind>(d) }
+    }
+}
+
+impl From<MarkdownSyntaxKind> for u16 {
+    fn from(k: MarkdownSyntaxKind) -> u16 {
+        k as u16
+    }
+}
+
+impl biome_rowan::SyntaxKind for MarkdownSyntaxKind {
+    const TOMBSTONE: Self = MarkdownSyntaxKind::TOMBSTONE;
+    const EOF: Self = MarkdownSyntaxKind::EOF;
+
+    fn is_bogus(&self) -> bool {
+        matches!(
+            self,
+            MarkdownSyntaxKind::MARKDOWN_BOGUS | MarkdownSyntaxKind::MARKDOWN_BOGUS_BLOCK
+        )
+    }
+
+    fn to_bogus(&self) -> Self {
+        match self {
+            MarkdownSyntaxKind::MARKDOWN_BOGUS_BLOCK => MarkdownSyntaxKind::MARKDOWN_BOGUS_BLOCK,
+            _ => MarkdownSyntaxKind::MARKDOWN_BOGUS,
+        }
+    }
+
+    #[inline]
+    fn to_raw(&self) -> RawSyntaxKind {
+        RawSyntaxKind(*self as u16)
+    }
+
+    #[inline]
+    fn from_raw(raw: RawSyntaxKind) -> Self {
+        Self::from(raw.0)
+    }
+
+    fn is_root(&self) -> bool {
+        matches!(self, MarkdownSyntaxKind::MARKDOWN_DOCUMENT)
+    }
+
+    fn is_list(&self) -> bool {
+        MarkdownSyntaxKind::is_list(*self)
+    }
+
+    fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            MarkdownSyntaxKind::NEWLINE | MarkdownSyntaxKind::WHITESPACE
+        )
+    }
+
+    fn to_string(&self) -> Option<&'static str> {
+        MarkdownSyntaxKind::to_string(self)
+    }
+}
+
+impl MarkdownSyntaxKind {
+    /// Markdown doesn't have comments: the HTML comments are HTML blocks.
+    pub fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            MarkdownSyntaxKind::NEWLINE | MarkdownSyntaxKind::WHITESPACE
+        )
+    }
+
+    #[inline]
+    pub const fn is_keyword(self) -> bool {
+        matches!(self, T![null])
+    }
+}
+
+impl TryFrom<MarkdownSyntaxKind> for TriviaPieceKind {
+    type Error = ();
+
+    fn try_from(value: MarkdownSyntaxKind) -> Result<Self, Self::Error> {
+        match value {
+            MarkdownSyntaxKind::NEWLINE => Ok(TriviaPieceKind::Newline),
+            MarkdownSyntaxKind::WHITESPACE => Ok(TriviaPieceKind::Whitespace),
+            _ => Err(()),
+        }
+    }
+}
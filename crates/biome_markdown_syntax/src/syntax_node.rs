@@ -0,0 +1,17 @@
+use crate::{MarkdownDocument, MarkdownSyntaxKind};
+use biome_rowan::Language;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct MarkdownLanguage;
+
+impl Language for MarkdownLanguage {
+    type Kind = MarkdownSyntaxKind;
+    type Root = MarkdownDocument;
+}
+
+pub type MarkdownSyntaxNode = biome_rowan::SyntaxNode<MarkdownLanguage>;
+pub type MarkdownSyntaxToken = biome_rowan::SyntaxToken<MarkdownLanguage>;
+pub type MarkdownSyntaxElement = biome_rowan::SyntaxElement<MarkdownLanguage>;
+pub type MarkdownSyntaxNodeChildren = biome_rowan::SyntaxNodeChildren<MarkdownLanguage>;
+pub type MarkdownSyntaxElementChildren = biome_rowan::SyntaxElementChildren<MarkdownLanguage>;
+pub type MarkdownSyntaxList = biome_rowan::SyntaxList<MarkdownLanguage>;
@@ -1,6 +1,7 @@
 mod diagnostics;
 mod license;
 mod node_js_project;
+mod style_index;
 
 pub use crate::diagnostics::{ProjectAnalyzeDiagnostic, ProjectDiagnostic};
 use biome_deserialize::{DeserializationDiagnostic, Deserialized};
@@ -8,10 +9,14 @@ use biome_diagnostics::serde::Diagnostic;
 use biome_parser::diagnostic::ParseDiagnostic;
 use biome_rowan::Language;
 pub use license::generated::*;
-pub use node_js_project::{NodeJsProject, PackageJson};
+pub use node_js_project::{
+    normalize_path, CompilerOptions, NodeJsProject, PackageExports, PackageJson, PackageType,
+    TailwindConfig, TsConfigJson,
+};
 use std::any::TypeId;
 use std::fmt::Debug;
 use std::path::Path;
+pub use style_index::{StyleIndex, StyleSymbols};
 
 pub(crate) type LanguageRoot<L> = <L as Language>::Root;
 
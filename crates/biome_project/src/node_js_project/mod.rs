@@ -1,7 +1,12 @@
 mod package_json;
+mod tailwind_config;
+mod tsconfig_json;
 
-pub use crate::node_js_project::package_json::PackageJson;
+pub use crate::node_js_project::package_json::{PackageExports, PackageJson, PackageType};
+pub use crate::node_js_project::tailwind_config::TailwindConfig;
+pub use crate::node_js_project::tsconfig_json::{normalize_path, CompilerOptions, TsConfigJson};
 use crate::{Manifest, Project, ProjectAnalyzeDiagnostic, ProjectAnalyzeResult, LICENSE_LIST};
+use biome_js_syntax::AnyJsRoot;
 use biome_json_syntax::JsonRoot;
 use biome_rowan::Language;
 use std::path::{Path, PathBuf};
@@ -13,6 +18,12 @@ pub struct NodeJsProject {
     pub manifest_path: PathBuf,
     /// The `package.json` manifest
     pub manifest: PackageJson,
+    /// The `tsconfig.json` file next to the manifest, if any
+    pub tsconfig: Option<TsConfigJson>,
+    /// The Tailwind CSS configuration file next to the manifest, if any
+    pub tailwind_config: Option<TailwindConfig>,
+    /// The manifests of the other packages of the workspace that the package depends on
+    pub workspace_packages: Vec<PackageJson>,
     /// Diagnostics emitted during the operations
     pub diagnostics: Vec<biome_diagnostics::serde::Diagnostic>,
 }
@@ -21,6 +32,40 @@ impl NodeJsProject {
     pub fn from_root(&mut self, root: &JsonRoot) {
         self.deserialize_manifest(root);
     }
+
+    /// Loads the `tsconfig.json` file located at `path`.
+    ///
+    /// The deserialization diagnostics are ignored, because the other tools report them.
+    pub fn tsconfig_from_root(&mut self, path: &Path, root: &JsonRoot) {
+        let (tsconfig, _) = TsConfigJson::deserialize_manifest(root).consume();
+        self.tsconfig = tsconfig.map(|mut tsconfig| {
+            tsconfig.directory = path.parent().map(Path::to_path_buf).unwrap_or_default();
+            tsconfig
+        });
+    }
+
+    /// Loads a precomputed `tailwind.config.json` file.
+    ///
+    /// The deserialization diagnostics are ignored, because the other tools report them.
+    pub fn tailwind_config_from_json_root(&mut self, root: &JsonRoot) {
+        let (tailwind_config, _) = TailwindConfig::deserialize_manifest(root).consume();
+        self.tailwind_config = tailwind_config;
+    }
+
+    /// Loads a `tailwind.config.js` file, reading its literal values.
+    pub fn tailwind_config_from_js_root(&mut self, root: &AnyJsRoot) {
+        self.tailwind_config = Some(TailwindConfig::from_js_root(root));
+    }
+
+    /// Loads the `package.json` file of another package of the workspace.
+    ///
+    /// The deserialization diagnostics are ignored, because they are reported for the package itself.
+    pub fn workspace_package_from_root(&mut self, root: &JsonRoot) {
+        let (manifest, _) = PackageJson::deserialize_manifest(root).consume();
+        if let Some(manifest) = manifest {
+            self.workspace_packages.push(manifest);
+        }
+    }
 }
 
 pub(crate) type ProjectLanguageRoot<M> = <<M as Manifest>::Language as Language>::Root;
@@ -18,6 +18,29 @@ pub struct PackageJson {
     pub peer_dependencies: Dependencies,
     pub optional_dependencies: Dependencies,
     pub license: Option<(String, TextRange)>,
+    pub r#type: Option<PackageType>,
+    pub exports: Option<PackageExports>,
+}
+
+impl PackageJson {
+    /// Returns `true` if `specifier` is listed in any kind of dependencies of the package.
+    pub fn has_dependency(&self, specifier: &str) -> bool {
+        self.dependencies.contains(specifier)
+            || self.dev_dependencies.contains(specifier)
+            || self.peer_dependencies.contains(specifier)
+            || self.optional_dependencies.contains(specifier)
+    }
+
+    /// Returns the names of all kinds of dependencies of the package.
+    pub fn all_dependencies(&self) -> impl Iterator<Item = &str> {
+        self.dependencies
+            .0
+            .keys()
+            .chain(self.dev_dependencies.0.keys())
+            .chain(self.peer_dependencies.0.keys())
+            .chain(self.optional_dependencies.0.keys())
+            .map(String::as_str)
+    }
 }
 
 impl Manifest for PackageJson {
@@ -41,6 +64,69 @@ impl Dependencies {
     }
 }
 
+/// The module format of the JavaScript files of a package, set by the field `type`.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum PackageType {
+    #[default]
+    CommonJs,
+    Module,
+}
+
+impl PackageType {
+    pub const fn is_module(&self) -> bool {
+        matches!(self, Self::Module)
+    }
+
+    pub const fn is_commonjs(&self) -> bool {
+        matches!(self, Self::CommonJs)
+    }
+}
+
+/// The subpaths of a package declared in the field `exports`.
+#[derive(Debug, Default, Clone)]
+pub struct PackageExports {
+    /// The subpaths in declaration order, such as `.`, `./button` or `./components/*`,
+    /// and whether they have a target. A subpath mapped to `null` isn't exported.
+    pub subpaths: Vec<(String, bool)>,
+}
+
+impl PackageExports {
+    /// Exports only the main entry point of the package.
+    fn main() -> Self {
+        Self {
+            subpaths: vec![(".".to_string(), true)],
+        }
+    }
+
+    /// Returns `true` if `subpath` can be imported, according to the resolution of Node.js.
+    ///
+    /// `subpath` must start with `.`, for example `./button`.
+    pub fn is_exported(&self, subpath: &str) -> bool {
+        if let Some((_, has_target)) = self.subpaths.iter().find(|(key, _)| key == subpath) {
+            return *has_target;
+        }
+        // The pattern with the longest prefix takes precedence.
+        self.subpaths
+            .iter()
+            .filter_map(|(key, has_target)| {
+                let prefix = match key.split_once('*') {
+                    Some((prefix, suffix)) => {
+                        let rest = subpath.strip_prefix(prefix)?;
+                        (rest.len() > suffix.len() && rest.ends_with(suffix)).then_some(prefix)
+                    }
+                    // Deprecated folder mappings, such as `./utils/`
+                    None if key.ends_with('/') => {
+                        subpath.starts_with(key.as_str()).then_some(key.as_str())
+                    }
+                    None => None,
+                }?;
+                Some((prefix.len(), *has_target))
+            })
+            .max_by_key(|(prefix_len, _)| *prefix_len)
+            .is_some_and(|(_, has_target)| has_target)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum Version {
     SemVer(node_semver::Version),
@@ -88,6 +174,9 @@ impl DeserializationVisitor for PackageJsonVisitor {
                     result.license = Deserializable::deserialize(&value, &key_text, diagnostics)
                         .map(|license| (license, license_range));
                 }
+                "type" => {
+                    result.r#type = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 "description" => {
                     result.description =
                         Deserializable::deserialize(&value, &key_text, diagnostics);
@@ -116,6 +205,9 @@ impl DeserializationVisitor for PackageJsonVisitor {
                         result.optional_dependencies = deps;
                     }
                 }
+                "exports" => {
+                    result.exports = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
                 _ => {
                     // each package can add their own field, so we should ignore any extraneous key
                     // and only deserialize the ones that Biome deems important
@@ -139,3 +231,136 @@ impl Deserializable for Version {
         }
     }
 }
+
+impl Deserializable for PackageType {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        let value = Text::deserialize(value, name, diagnostics)?;
+        match value.text() {
+            "commonjs" => Some(Self::CommonJs),
+            "module" => Some(Self::Module),
+            // Unknown values are ignored
+            _ => None,
+        }
+    }
+}
+
+impl Deserializable for PackageExports {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(PackageExportsVisitor, name, diagnostics)
+    }
+}
+
+struct PackageExportsVisitor;
+impl DeserializationVisitor for PackageExportsVisitor {
+    type Output = PackageExports;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::STR
+        .union(VisitableType::ARRAY)
+        .union(VisitableType::MAP);
+
+    fn visit_str(
+        self,
+        _value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::main())
+    }
+
+    fn visit_array(
+        self,
+        _items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(PackageExports::main())
+    }
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut subpaths = Vec::new();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            // The keys that don't start with `.` are conditions, such as `import` or `require`.
+            if key_text.text().starts_with('.') {
+                let Some(has_target) = value.deserialize(HasTargetVisitor, &key_text, diagnostics)
+                else {
+                    continue;
+                };
+                subpaths.push((key_text.text().to_string(), has_target));
+            }
+        }
+        if subpaths.is_empty() {
+            Some(PackageExports::main())
+        } else {
+            Some(PackageExports { subpaths })
+        }
+    }
+}
+
+/// Returns `false` if the target of a subpath is `null`.
+struct HasTargetVisitor;
+impl DeserializationVisitor for HasTargetVisitor {
+    type Output = bool;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::NULL
+        .union(VisitableType::STR)
+        .union(VisitableType::ARRAY)
+        .union(VisitableType::MAP);
+
+    fn visit_null(
+        self,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(false)
+    }
+
+    fn visit_str(
+        self,
+        _value: Text,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(true)
+    }
+
+    fn visit_array(
+        self,
+        _items: impl Iterator<Item = Option<impl DeserializableValue>>,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(true)
+    }
+
+    fn visit_map(
+        self,
+        _members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        _diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        Some(true)
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{LanguageRoot, Manifest};
+use biome_deserialize::json::deserialize_from_json_ast;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
+    Deserialized, Text, VisitableType,
+};
+use biome_js_syntax::{
+    AnyJsArrayElement, AnyJsExpression, AnyJsObjectMember, AnyJsRoot, JsCallExpression,
+    JsPropertyObjectMember,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_rowan::{AstNode, AstSeparatedList};
+use biome_text_size::TextRange;
+
+/// The subset of a Tailwind CSS configuration that Biome deems important.
+///
+/// Biome can't execute a `tailwind.config.js` file, so the configuration is either read
+/// statically from the file, or from a precomputed `tailwind.config.json` file:
+///
+/// ```json
+/// {
+///     "prefix": "tw-",
+///     "separator": "_",
+///     "components": ["btn"],
+///     "utilities": ["content-auto", "tab-*"]
+/// }
+/// ```
+///
+/// The utilities and the components are written without the prefix. A trailing `-*` means that
+/// the utility accepts a value, such as `tab-4`.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct TailwindConfig {
+    /// The field `prefix`, prepended to every utility
+    pub prefix: Option<String>,
+    /// The field `separator`, between the variants and the utility
+    pub separator: Option<String>,
+    /// The components added by the plugins, in declaration order
+    pub components: Vec<String>,
+    /// The utilities added by the plugins, in declaration order
+    pub utilities: Vec<String>,
+}
+
+impl TailwindConfig {
+    /// The names of the configuration files, in order of precedence.
+    pub const FILE_NAMES: [&'static str; 5] = [
+        "tailwind.config.json",
+        "tailwind.config.js",
+        "tailwind.config.cjs",
+        "tailwind.config.mjs",
+        "tailwind.config.ts",
+    ];
+
+    /// Returns the separator between the variants and the utility, `:` by default.
+    pub fn separator(&self) -> &str {
+        self.separator.as_deref().unwrap_or(":")
+    }
+
+    /// Reads the configuration from the source of a `tailwind.config.js` file.
+    ///
+    /// Only the literal values are read:
+    /// - the string values of the properties `prefix` and `separator`;
+    /// - the classes of the selectors passed to `addUtilities` and `addComponents`;
+    /// - the names of the utilities passed to `matchUtilities` and `matchComponents`.
+    ///
+    /// ## Examples
+    ///
+    /// ```js
+    /// module.exports = {
+    ///     prefix: "tw-",
+    ///     plugins: [
+    ///         plugin(({ addUtilities, matchUtilities }) => {
+    ///             addUtilities({ ".content-auto": { "content-visibility": "auto" } });
+    ///             matchUtilities({ tab: (value) => ({ tabSize: value }) });
+    ///         }),
+    ///     ],
+    /// };
+    /// ```
+    pub fn from_js_root(root: &AnyJsRoot) -> Self {
+        let mut result = Self::default();
+        for node in root.syntax().descendants() {
+            if let Some(member) = JsPropertyObjectMember::cast_ref(&node) {
+                result.visit_property(&member);
+            } else if let Some(call) = JsCallExpression::cast_ref(&node) {
+                result.visit_plugin_call(&call);
+            }
+        }
+        result
+    }
+
+    fn visit_property(&mut self, member: &JsPropertyObjectMember) -> Option<()> {
+        let name = member.name().ok()?.name()?;
+        let field = match name.text() {
+            "prefix" => &mut self.prefix,
+            "separator" => &mut self.separator,
+            _ => return None,
+        };
+        if field.is_none() {
+            let value = member.value().ok()?.as_static_value()?;
+            *field = Some(value.as_string_constant()?.to_string());
+        }
+        Some(())
+    }
+
+    fn visit_plugin_call(&mut self, call: &JsCallExpression) -> Option<()> {
+        let callee = call.callee().ok()?.get_callee_member_name()?;
+        let (utilities, is_functional) = match callee.text_trimmed() {
+            "addUtilities" => (&mut self.utilities, false),
+            "addComponents" => (&mut self.components, false),
+            "matchUtilities" => (&mut self.utilities, true),
+            "matchComponents" => (&mut self.components, true),
+            _ => return None,
+        };
+        let argument = call.arguments().ok()?.args().first()?.ok()?;
+        let objects = match argument.as_any_js_expression()? {
+            AnyJsExpression::JsObjectExpression(object) => vec![object.clone()],
+            // `addUtilities` also accepts an array of objects
+            AnyJsExpression::JsArrayExpression(array) => array
+                .elements()
+                .iter()
+                .filter_map(|element| match element.ok()? {
+                    AnyJsArrayElement::AnyJsExpression(AnyJsExpression::JsObjectExpression(
+                        object,
+                    )) => Some(object),
+                    _ => None,
+                })
+                .collect(),
+            _ => return None,
+        };
+        for object in objects {
+            for member in object.members().iter().flatten() {
+                let AnyJsObjectMember::JsPropertyObjectMember(member) = member else {
+                    continue;
+                };
+                let Some(name) = member.name().ok().and_then(|name| name.name()) else {
+                    continue;
+                };
+                if is_functional {
+                    push_unique(utilities, format!("{}-*", name.text()));
+                } else {
+                    for class in selector_classes(name.text()) {
+                        push_unique(utilities, class);
+                    }
+                }
+            }
+        }
+        Some(())
+    }
+}
+
+fn push_unique(utilities: &mut Vec<String>, utility: String) {
+    if !utilities.contains(&utility) {
+        utilities.push(utility);
+    }
+}
+
+/// Returns the unescaped class names of a selector, such as `content-auto` for `.content-auto > *`.
+fn selector_classes(selector: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut chars = selector.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '.' {
+            continue;
+        }
+        let mut class = String::new();
+        while let Some(&c) = chars.peek() {
+            if c == '\\' {
+                chars.next();
+                if let Some(escaped) = chars.next() {
+                    class.push(escaped);
+                }
+            } else if c.is_alphanumeric() || c == '-' || c == '_' {
+                class.push(c);
+                chars.next();
+            } else {
+                break;
+            }
+        }
+        if !class.is_empty() {
+            result.push(class);
+        }
+    }
+    result
+}
+
+impl Manifest for TailwindConfig {
+    type Language = JsonLanguage;
+
+    fn deserialize_manifest(root: &LanguageRoot<Self::Language>) -> Deserialized<Self> {
+        deserialize_from_json_ast::<TailwindConfig>(root, "")
+    }
+}
+
+impl Deserializable for TailwindConfig {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(TailwindConfigVisitor, name, diagnostics)
+    }
+}
+
+struct TailwindConfigVisitor;
+impl DeserializationVisitor for TailwindConfigVisitor {
+    type Output = TailwindConfig;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "prefix" => {
+                    result.prefix = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "separator" => {
+                    result.separator = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "components" => {
+                    if let Some(components) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.components = components;
+                    }
+                }
+                "utilities" => {
+                    if let Some(utilities) =
+                        Deserializable::deserialize(&value, &key_text, diagnostics)
+                    {
+                        result.utilities = utilities;
+                    }
+                }
+                _ => {
+                    // The other fields are ignored
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_js_parser::{parse, JsParserOptions};
+    use biome_js_syntax::JsFileSource;
+
+    #[test]
+    fn reads_the_literal_values_of_a_js_config() {
+        let source = r#"
+import plugin from "tailwindcss/plugin";
+
+export default {
+    prefix: "tw-",
+    plugins: [
+        plugin(function ({ addUtilities, addComponents, matchUtilities }) {
+            addUtilities({
+                ".content-auto": { contentVisibility: "auto" },
+                ".scrollbar-hidden::-webkit-scrollbar": { display: "none" },
+            });
+            addComponents([{ ".btn, .btn-primary": { padding: "0.5rem" } }]);
+            matchUtilities({ tab: (value) => ({ tabSize: value }) });
+        }),
+    ],
+};
+"#;
+        let parsed = parse(
+            source,
+            JsFileSource::js_module(),
+            JsParserOptions::default(),
+        );
+        let config = TailwindConfig::from_js_root(&parsed.tree());
+
+        assert_eq!(config.prefix.as_deref(), Some("tw-"));
+        assert_eq!(config.separator(), ":");
+        assert_eq!(config.components, vec!["btn", "btn-primary"]);
+        assert_eq!(
+            config.utilities,
+            vec!["content-auto", "scrollbar-hidden", "tab-*"]
+        );
+    }
+
+    #[test]
+    fn test_selector_classes() {
+        assert_eq!(selector_classes(".content-auto"), vec!["content-auto"]);
+        assert_eq!(
+            selector_classes(".scrollbar-hidden::-webkit-scrollbar"),
+            vec!["scrollbar-hidden"]
+        );
+        assert_eq!(
+            selector_classes(".btn, .btn-primary"),
+            vec!["btn", "btn-primary"]
+        );
+        assert_eq!(selector_classes(".w-1\\/2 > *"), vec!["w-1/2"]);
+        assert_eq!(selector_classes("@media print"), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{LanguageRoot, Manifest};
+use biome_deserialize::json::deserialize_from_json_ast;
+use biome_deserialize::{
+    Deserializable, DeserializableValue, DeserializationDiagnostic, DeserializationVisitor,
+    Deserialized, Text, VisitableType,
+};
+use biome_json_syntax::JsonLanguage;
+use biome_text_size::TextRange;
+use std::path::{Component, Path, PathBuf};
+
+/// The subset of a `tsconfig.json` that Biome deems important.
+#[derive(Debug, Default, Clone)]
+pub struct TsConfigJson {
+    /// The directory of the `tsconfig.json` file.
+    ///
+    /// It isn't part of the file: it's set when the file is loaded.
+    pub directory: PathBuf,
+    pub compiler_options: CompilerOptions,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct CompilerOptions {
+    /// The field `compilerOptions.baseUrl`
+    pub base_url: Option<String>,
+    /// The field `compilerOptions.paths`, in declaration order
+    pub paths: Vec<(String, Vec<String>)>,
+}
+
+impl TsConfigJson {
+    /// Returns the directory from which the targets of `compilerOptions.paths` are resolved.
+    pub fn paths_base_directory(&self) -> PathBuf {
+        match &self.compiler_options.base_url {
+            Some(base_url) => normalize_path(&self.directory.join(base_url)),
+            None => normalize_path(&self.directory),
+        }
+    }
+
+    /// Returns the specifiers that use a path alias of `compilerOptions.paths` to import `path`.
+    ///
+    /// `path` must be normalized and be relative to the same directory as [TsConfigJson::directory].
+    /// The specifiers are returned in the order of declaration of the aliases.
+    pub fn path_aliases_of(&self, path: &Path) -> Vec<String> {
+        let base_directory = self.paths_base_directory();
+        let Some(path) = path.to_str() else {
+            return Vec::new();
+        };
+        let mut result = Vec::new();
+        for (alias, targets) in &self.compiler_options.paths {
+            for target in targets {
+                let Some(target) = normalize_path(&base_directory.join(target))
+                    .to_str()
+                    .map(|target| target.replace('\\', "/"))
+                else {
+                    continue;
+                };
+                let path = path.replace('\\', "/");
+                let specifier = match (alias.split_once('*'), target.split_once('*')) {
+                    (Some((alias_prefix, alias_suffix)), Some((target_prefix, target_suffix))) => {
+                        match path
+                            .strip_prefix(target_prefix)
+                            .and_then(|rest| rest.strip_suffix(target_suffix))
+                        {
+                            Some(captured) if !captured.is_empty() => {
+                                format!("{alias_prefix}{captured}{alias_suffix}")
+                            }
+                            _ => continue,
+                        }
+                    }
+                    (None, None) if path == target => alias.clone(),
+                    _ => continue,
+                };
+                if !result.contains(&specifier) {
+                    result.push(specifier);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Returns `path` without the components `.` and with the components `..` resolved.
+///
+/// The file system isn't accessed: symbolic links aren't resolved.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    let mut result = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    result.components().next_back(),
+                    None | Some(Component::ParentDir)
+                ) {
+                    result.push(component);
+                } else {
+                    result.pop();
+                }
+            }
+            _ => result.push(component),
+        }
+    }
+    result
+}
+
+impl Manifest for TsConfigJson {
+    type Language = JsonLanguage;
+
+    fn deserialize_manifest(root: &LanguageRoot<Self::Language>) -> Deserialized<Self> {
+        deserialize_from_json_ast::<TsConfigJson>(root, "")
+    }
+}
+
+impl Deserializable for TsConfigJson {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(TsConfigJsonVisitor, name, diagnostics)
+    }
+}
+
+struct TsConfigJsonVisitor;
+impl DeserializationVisitor for TsConfigJsonVisitor {
+    type Output = TsConfigJson;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            if key_text.text() == "compilerOptions" {
+                if let Some(compiler_options) =
+                    Deserializable::deserialize(&value, &key_text, diagnostics)
+                {
+                    result.compiler_options = compiler_options;
+                }
+            }
+            // The other fields are ignored
+        }
+        Some(result)
+    }
+}
+
+impl Deserializable for CompilerOptions {
+    fn deserialize(
+        value: &impl DeserializableValue,
+        name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self> {
+        value.deserialize(CompilerOptionsVisitor, name, diagnostics)
+    }
+}
+
+struct CompilerOptionsVisitor;
+impl DeserializationVisitor for CompilerOptionsVisitor {
+    type Output = CompilerOptions;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let Some(key_text) = Text::deserialize(&key, "", diagnostics) else {
+                continue;
+            };
+            match key_text.text() {
+                "baseUrl" => {
+                    result.base_url = Deserializable::deserialize(&value, &key_text, diagnostics);
+                }
+                "paths" => {
+                    if let Some(paths) = value.deserialize(PathsVisitor, &key_text, diagnostics) {
+                        result.paths = paths;
+                    }
+                }
+                _ => {
+                    // The other compiler options are ignored
+                }
+            }
+        }
+        Some(result)
+    }
+}
+
+/// Deserializes `compilerOptions.paths` preserving the order of the aliases.
+struct PathsVisitor;
+impl DeserializationVisitor for PathsVisitor {
+    type Output = Vec<(String, Vec<String>)>;
+
+    const EXPECTED_TYPE: VisitableType = VisitableType::MAP;
+
+    fn visit_map(
+        self,
+        members: impl Iterator<Item = Option<(impl DeserializableValue, impl DeserializableValue)>>,
+        _range: TextRange,
+        _name: &str,
+        diagnostics: &mut Vec<DeserializationDiagnostic>,
+    ) -> Option<Self::Output> {
+        let mut result = Self::Output::default();
+        for (key, value) in members.flatten() {
+            let alias = Deserializable::deserialize(&key, "", diagnostics);
+            let targets = Deserializable::deserialize(&value, "", diagnostics);
+            if let (Some(alias), Some(targets)) = (alias, targets) {
+                result.push((alias, targets));
+            }
+        }
+        Some(result)
+    }
+}
@@ -0,0 +1,147 @@
+//! The index of the class names and the identifiers shared by the stylesheets and the markup of a project.
+//!
+//! A stylesheet declares class names and identifiers with its selectors, and the markup references them
+//! with its attributes. The index records the names of each document, so that the rules can compare
+//! the names of a single document with the names of the other documents of the project.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::path::{Path, PathBuf};
+
+/// The class names and the identifiers of a document.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StyleSymbols {
+    pub classes: FxHashSet<String>,
+    pub ids: FxHashSet<String>,
+}
+
+impl StyleSymbols {
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty() && self.ids.is_empty()
+    }
+}
+
+/// The class names and the identifiers declared by the stylesheets of a project,
+/// and the ones referenced by its markup.
+#[derive(Clone, Debug, Default)]
+pub struct StyleIndex {
+    stylesheets: FxHashMap<PathBuf, StyleSymbols>,
+    markup: FxHashMap<PathBuf, StyleSymbols>,
+}
+
+impl StyleIndex {
+    /// Records the class names and the identifiers declared by the selectors of a stylesheet.
+    ///
+    /// The names previously recorded for the same document are replaced.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use biome_project::{StyleIndex, StyleSymbols};
+    /// use std::path::Path;
+    ///
+    /// let mut index = StyleIndex::default();
+    /// let mut symbols = StyleSymbols::default();
+    /// symbols.classes.insert("button".to_string());
+    /// index.add_stylesheet(Path::new("button.css"), symbols);
+    /// assert!(index.is_class_declared("button"));
+    /// assert!(!index.is_class_referenced("button"));
+    /// ```
+    pub fn add_stylesheet(&mut self, path: &Path, symbols: StyleSymbols) {
+        self.stylesheets.insert(path.to_path_buf(), symbols);
+    }
+
+    /// Records the class names and the identifiers referenced by the attributes of a markup document.
+    ///
+    /// The names previously recorded for the same document are replaced.
+    pub fn add_markup(&mut self, path: &Path, symbols: StyleSymbols) {
+        self.markup.insert(path.to_path_buf(), symbols);
+    }
+
+    /// Removes the names of a document, for example when the file is deleted.
+    pub fn remove_document(&mut self, path: &Path) {
+        self.stylesheets.remove(path);
+        self.markup.remove(path);
+    }
+
+    /// Returns `true` if at least one stylesheet is recorded.
+    ///
+    /// The rules can't tell whether a class name is declared when the index doesn't know any stylesheet.
+    pub fn has_stylesheets(&self) -> bool {
+        !self.stylesheets.is_empty()
+    }
+
+    /// Returns `true` if at least one markup document is recorded.
+    ///
+    /// The rules can't tell whether a selector is used when the index doesn't know any markup document.
+    pub fn has_markup(&self) -> bool {
+        !self.markup.is_empty()
+    }
+
+    /// Returns `true` if a stylesheet declares a selector with the class name `name`.
+    pub fn is_class_declared(&self, name: &str) -> bool {
+        self.stylesheets
+            .values()
+            .any(|symbols| symbols.classes.contains(name))
+    }
+
+    /// Returns `true` if a stylesheet declares a selector with the identifier `name`.
+    pub fn is_id_declared(&self, name: &str) -> bool {
+        self.stylesheets
+            .values()
+            .any(|symbols| symbols.ids.contains(name))
+    }
+
+    /// Returns `true` if a markup document references the class name `name`.
+    pub fn is_class_referenced(&self, name: &str) -> bool {
+        self.markup
+            .values()
+            .any(|symbols| symbols.classes.contains(name))
+    }
+
+    /// Returns `true` if a markup document references the identifier `name`.
+    pub fn is_id_referenced(&self, name: &str) -> bool {
+        self.markup
+            .values()
+            .any(|symbols| symbols.ids.contains(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbols(classes: &[&str], ids: &[&str]) -> StyleSymbols {
+        StyleSymbols {
+            classes: classes.iter().map(|name| (*name).to_string()).collect(),
+            ids: ids.iter().map(|name| (*name).to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn replaces_the_names_of_a_document() {
+        let mut index = StyleIndex::default();
+        let path = Path::new("app.jsx");
+        index.add_markup(path, symbols(&["header"], &["main"]));
+        index.add_markup(path, symbols(&["footer"], &[]));
+
+        assert!(!index.is_class_referenced("header"));
+        assert!(!index.is_id_referenced("main"));
+        assert!(index.is_class_referenced("footer"));
+    }
+
+    #[test]
+    fn removes_the_names_of_a_document() {
+        let mut index = StyleIndex::default();
+        let first = Path::new("first.css");
+        let second = Path::new("second.css");
+        index.add_stylesheet(first, symbols(&["button"], &["app"]));
+        index.add_stylesheet(second, symbols(&["button"], &[]));
+
+        index.remove_document(first);
+
+        assert!(index.is_class_declared("button"));
+        assert!(!index.is_id_declared("app"));
+        assert!(index.has_stylesheets());
+        assert!(!index.has_markup());
+    }
+}
@@ -1,20 +1,27 @@
 use crate::settings::Settings;
 use crate::{DynRef, WorkspaceError, VERSION};
-use biome_analyze::AnalyzerRules;
+use biome_analyze::options::RuleOptions;
+use biome_analyze::{AnalyzerRules, RuleKey};
 use biome_configuration::diagnostics::CantLoadExtendFile;
 use biome_configuration::{
-    push_to_analyzer_rules, ConfigurationDiagnostic, ConfigurationPathHint, ConfigurationPayload,
-    PartialConfiguration,
+    editorconfig, push_to_analyzer_rules, ConfigurationDiagnostic, ConfigurationPathHint,
+    ConfigurationPayload, PartialConfiguration,
 };
 use biome_console::markup;
 use biome_css_analyze::metadata as css_lint_metadata;
 use biome_deserialize::json::deserialize_from_json_str;
-use biome_deserialize::{Deserialized, Merge};
+use biome_deserialize::{DeserializationDiagnostic, Deserialized, Merge};
 use biome_diagnostics::{DiagnosticExt, Error, Severity};
-use biome_fs::{AutoSearchResult, ConfigName, FileSystem, OpenOptions};
-use biome_js_analyze::metadata as js_lint_metadata;
+use biome_fs::{AutoSearchResult, BiomePath, ConfigName, FileSystem, OpenOptions};
+use biome_graphql_analyze::metadata as graphql_lint_metadata;
+use biome_html_analyze::metadata as html_lint_metadata;
+use biome_js_analyze::{metadata as js_lint_metadata, OrganizeImportsOptions};
+use biome_json_analyze::metadata as json_lint_metadata;
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
+use biome_markdown_analyze::metadata as markdown_lint_metadata;
+use biome_toml_analyze::metadata as toml_lint_metadata;
+use biome_yaml_analyze::metadata as yaml_lint_metadata;
 use std::ffi::OsStr;
 use std::fmt::Debug;
 use std::io::ErrorKind;
@@ -141,7 +148,74 @@ pub fn load_configuration(
     config_path: ConfigurationPathHint,
 ) -> Result<LoadedConfiguration, WorkspaceError> {
     let config = load_config(fs, config_path)?;
-    LoadedConfiguration::try_from_payload(config, fs)
+    let mut loaded_configuration = LoadedConfiguration::try_from_payload(config, fs)?;
+    loaded_configuration.apply_editorconfig(fs);
+    Ok(loaded_configuration)
+}
+
+impl LoadedConfiguration {
+    /// Merges the options of the `.editorconfig` file of the project under the ones of the
+    /// configuration, when `formatter.useEditorconfig` is enabled.
+    ///
+    /// The `.editorconfig` file is searched from the directory of the configuration file.
+    /// Its `[*]` section is merged under the options of the formatter, and its other sections
+    /// become overrides that are applied before the overrides of the configuration.
+    fn apply_editorconfig(&mut self, fs: &DynRef<'_, dyn FileSystem>) {
+        let use_editorconfig = self
+            .configuration
+            .formatter
+            .as_ref()
+            .and_then(|formatter| formatter.use_editorconfig)
+            .unwrap_or_default();
+        if !use_editorconfig {
+            return;
+        }
+
+        let Some(directory_path) = self.directory_path() else {
+            return;
+        };
+        let Ok(Some(AutoSearchResult { content, file_path })) =
+            fs.auto_search(directory_path, &[".editorconfig"], false)
+        else {
+            return;
+        };
+
+        let editorconfig = match editorconfig::parse_str(&content) {
+            Ok(editorconfig) => editorconfig,
+            Err(error) => {
+                let diagnostic = DeserializationDiagnostic::new(markup! {
+                    "Biome couldn't parse the .editorconfig file: "{error.to_string()}
+                });
+                self.diagnostics.push(
+                    ConfigurationDiagnostic::from(diagnostic)
+                        .with_file_path(file_path.display().to_string()),
+                );
+                return;
+            }
+        };
+
+        let (editorconfig_configuration, diagnostics) = editorconfig.to_biome();
+        self.diagnostics.extend(
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| diagnostic.with_file_path(file_path.display().to_string())),
+        );
+
+        if let Some(mut editorconfig_configuration) = editorconfig_configuration {
+            let mut configuration = std::mem::take(&mut self.configuration);
+            let mut overrides = editorconfig_configuration
+                .overrides
+                .take()
+                .unwrap_or_default();
+            if let Some(configuration_overrides) = configuration.overrides.take() {
+                overrides.0.extend(configuration_overrides.0);
+            }
+
+            editorconfig_configuration.merge_with(configuration);
+            editorconfig_configuration.overrides = Some(overrides);
+            self.configuration = editorconfig_configuration;
+        }
+    }
 }
 
 /// - [Result]: if an error occurred while loading the configuration file.
@@ -322,7 +396,29 @@ pub fn to_analyzer_rules(settings: &Settings, path: &Path) -> AnalyzerRules {
     if let Some(rules) = linter_settings.rules.as_ref() {
         push_to_analyzer_rules(rules, js_lint_metadata(), &mut analyzer_rules);
         push_to_analyzer_rules(rules, css_lint_metadata(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, json_lint_metadata(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, graphql_lint_metadata(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, yaml_lint_metadata(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, toml_lint_metadata(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, markdown_lint_metadata(), &mut analyzer_rules);
+        push_to_analyzer_rules(rules, html_lint_metadata(), &mut analyzer_rules);
     }
+    let organize_imports = overrides.override_js_organize_imports(
+        &BiomePath::new(path),
+        &settings.languages.javascript.organize_imports,
+    );
+    analyzer_rules.push_rule(
+        RuleKey::new("correctness", "organizeImports"),
+        RuleOptions::new(
+            OrganizeImportsOptions {
+                groups: organize_imports.groups,
+                blank_lines_between_groups: organize_imports.blank_lines_between_groups,
+                merge_imports: organize_imports.merge_imports,
+                merge_type_imports: organize_imports.merge_type_imports,
+            },
+            None,
+        ),
+    );
 
     overrides.override_analyzer_rules(path, analyzer_rules)
 }
@@ -3,7 +3,8 @@ use biome_configuration::{CantLoadExtendFile, ConfigurationDiagnostic};
 use biome_console::fmt::Bytes;
 use biome_console::markup;
 use biome_diagnostics::{
-    category, Advices, Category, Diagnostic, DiagnosticTags, Location, LogCategory, Severity, Visit,
+    category, Advices, Category, Diagnostic, DiagnosticTags, Location, LogCategory,
+    MessageAndDescription, Severity, Visit,
 };
 use biome_formatter::{FormatError, PrintError};
 use biome_fs::{BiomePath, FileSystemDiagnostic};
@@ -303,6 +304,28 @@ impl Diagnostic for SourceFileNotSupported {
         }
     }
 
+    fn description(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        if self.file_source != DocumentFileSource::Unknown {
+            // The name of the language is only written as markup
+            let language = MessageAndDescription::from(markup! {{&self.file_source}}.to_owned());
+            write!(
+                fmt,
+                "Biome doesn't support this feature for the language {language}"
+            )
+        } else if let Some(ext) = self.extension.as_ref() {
+            write!(
+                fmt,
+                "Biome could not determine the language for the file extension {ext}"
+            )
+        } else {
+            write!(
+                fmt,
+                "Biome could not determine the language for the file {} because it doesn't have a clear extension",
+                self.path
+            )
+        }
+    }
+
     fn verbose_advices(&self, visitor: &mut dyn Visit) -> std::io::Result<()> {
         visitor.record_log(
             LogCategory::Info,
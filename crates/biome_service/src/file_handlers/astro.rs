@@ -1,67 +1,345 @@
+use crate::file_handlers::svelte::write_wrapped_expression;
 use crate::file_handlers::{
-    javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities,
+    indentation_at, javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams,
+    DebugCapabilities, EmbeddedFormatter, ExtensionHandler, FixAllParams, FormatterCapabilities,
+    LintParams, LintResults, ParseResult, ParserCapabilities,
 };
-use crate::settings::WorkspaceSettingsHandle;
+use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
     DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
+use biome_analyze::AnalysisFilter;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
-use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{JsFileSource, TextRange, TextSize};
+use biome_html_parser::HtmlParserOptions;
+use biome_html_syntax::{
+    HtmlElement, HtmlName, HtmlOpeningElement, HtmlRoot, HtmlSelfClosingElement, HtmlSyntaxNode,
+    HtmlTextExpression,
+};
+use biome_js_parser::{JsParserOptions, Parse};
+use biome_js_syntax::{
+    AnyJsModuleItem, AnyJsRoot, AnyJsStatement, JsFileSource, TextRange, TextSize,
+};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
-use lazy_static::lazy_static;
-use regex::{Matches, Regex, RegexBuilder};
+use biome_rowan::{AstNode, AstNodeList, NodeCache};
+use std::ops::Range;
+use tracing::debug;
 
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct AstroFileHandler;
 
-lazy_static! {
-    pub static ref ASTRO_FENCE: Regex = RegexBuilder::new(r#"^---\s*$"#)
-        .multi_line(true)
-        .build()
-        .unwrap();
+/// The kind of a block of an Astro component whose content isn't HTML
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AstroBlockKind {
+    /// The frontmatter, the code between the `---` fences at the start of the file
+    Frontmatter,
+    /// A `<style>` element, only the styles without a `lang` attribute, or with `lang="css"`, are CSS
+    Style { is_css: bool },
+}
+
+/// The frontmatter or a `<style>` element of an Astro component
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AstroBlock {
+    pub kind: AstroBlockKind,
+    /// The range of the content of the block, between its fences or between its opening tag and its closing tag
+    pub content_range: TextRange,
 }
 
 impl AstroFileHandler {
-    /// It extracts the JavaScript code contained in the frontmatter of an Astro file
+    /// Returns the frontmatter and the `<style>` elements of an Astro file, in the order of the source.
     ///
-    /// If the frontmatter doesn't exist, an empty string is returned.
-    pub fn input(text: &str) -> &str {
-        let mut matches = Self::matches(text);
-        match (matches.next(), matches.next()) {
-            (Some(start), Some(end)) => &text[start.end()..end.start()],
-            _ => "",
+    /// The blocks that aren't closed are ignored, the parser reports them.
+    pub fn blocks(root: &HtmlRoot) -> Vec<AstroBlock> {
+        let frontmatter = Self::frontmatter(root).map(|content_range| AstroBlock {
+            kind: AstroBlockKind::Frontmatter,
+            content_range,
+        });
+        let styles = root
+            .html()
+            .syntax()
+            .descendants()
+            .filter_map(HtmlElement::cast)
+            .filter_map(|element| {
+                let opening_element = element.opening_element().ok()?;
+                let closing_element = element.closing_element().ok()?;
+                let name = opening_element.name().ok()?.value_token().ok()?;
+                if !name.text_trimmed().eq_ignore_ascii_case("style") {
+                    return None;
+                }
+                let lang = opening_element
+                    .attributes()
+                    .find_by_name("lang")
+                    .and_then(|attribute| attribute.value());
+
+                Some(AstroBlock {
+                    kind: AstroBlockKind::Style {
+                        is_css: lang.map_or(true, |lang| lang.text().eq_ignore_ascii_case("css")),
+                    },
+                    content_range: TextRange::new(
+                        opening_element.syntax().text_range().end(),
+                        closing_element.syntax().text_range().start(),
+                    ),
+                })
+            });
+
+        frontmatter.into_iter().chain(styles).collect()
+    }
+
+    /// Returns the range of the code of the frontmatter, between its fences.
+    pub fn frontmatter(root: &HtmlRoot) -> Option<TextRange> {
+        let frontmatter = root.frontmatter()?;
+        let l_fence = frontmatter.l_fence_token().ok()?;
+        let r_fence = frontmatter.r_fence_token().ok()?;
+
+        Some(TextRange::new(
+            l_fence.text_trimmed_range().end(),
+            r_fence.text_trimmed_range().start(),
+        ))
+    }
+}
+
+/// The frontmatter of an Astro file, parsed with the JavaScript parser
+struct AstroFrontmatter {
+    parse: AnyParse,
+    /// The range of the frontmatter in the Astro file, empty when the file doesn't have a frontmatter
+    content_range: TextRange,
+}
+
+impl AstroFrontmatter {
+    /// Parses the frontmatter of an Astro file, so that the ranges of its nodes are the offsets in the whole file.
+    ///
+    /// The text around the frontmatter is replaced by whitespace, only its line breaks are kept.
+    /// When `with_template` is `true`, the expressions of the template and the names of the components
+    /// are parsed along with the frontmatter, so that the rules see the variables that the template uses.
+    /// The diagnostics of the parser of the Astro file are reported along with the ones of the frontmatter.
+    fn parse_in_file(parse: &AnyParse, with_template: bool) -> Self {
+        let root: HtmlRoot = parse.tree();
+        let text = root.syntax().to_string();
+        let content_range = frontmatter_of(&root);
+
+        let mut code = padding(&text);
+        code.replace_range(Range::<usize>::from(content_range), &text[content_range]);
+        if with_template {
+            for node in root.html().syntax().descendants() {
+                if let Some(expression) = HtmlTextExpression::cast_ref(&node) {
+                    write_template_expression(&mut code, &expression);
+                } else if let Some(name) = component_name(&node) {
+                    write_component_name(&mut code, &name);
+                }
+            }
+        }
+
+        let mut diagnostics = parse.diagnostics().to_vec();
+        let frontmatter_parse =
+            biome_js_parser::parse(&code, JsFileSource::astro(), JsParserOptions::default());
+        let root = frontmatter_parse.syntax();
+        diagnostics.extend(frontmatter_parse.into_diagnostics());
+
+        Self {
+            // SAFETY: the parser should always return a root node
+            parse: AnyParse::new(root.as_send().unwrap(), diagnostics),
+            content_range,
         }
     }
 
-    /// Returns the start byte offset of the Astro fence
-    pub fn start(input: &str) -> Option<u32> {
-        ASTRO_FENCE.find_iter(input).next().map(|m| m.end() as u32)
+    /// Parses only the content of the frontmatter of an Astro file, the ranges of its nodes start at the frontmatter
+    fn parse_content(parse: &AnyParse) -> (String, Self) {
+        let root: HtmlRoot = parse.tree();
+        let text = root.syntax().to_string();
+        let content_range = frontmatter_of(&root);
+
+        let frontmatter_parse = biome_js_parser::parse(
+            &text[content_range],
+            JsFileSource::astro(),
+            JsParserOptions::default(),
+        );
+        let root = frontmatter_parse.syntax();
+        let diagnostics = frontmatter_parse.into_diagnostics();
+
+        let frontmatter = Self {
+            // SAFETY: the parser should always return a root node
+            parse: AnyParse::new(root.as_send().unwrap(), diagnostics),
+            content_range,
+        };
+        (text, frontmatter)
     }
+}
+
+/// Replaces each byte of `text` by a space, except the line breaks, so that the offsets and the lines stay the same
+pub(super) fn padding(text: &str) -> String {
+    text.bytes()
+        .map(|byte| match byte {
+            b'\n' | b'\r' => byte as char,
+            _ => ' ',
+        })
+        .collect()
+}
+
+/// Returns the range of the content of the frontmatter, an empty range at the end of the file when there's no frontmatter.
+fn frontmatter_of(root: &HtmlRoot) -> TextRange {
+    AstroFileHandler::frontmatter(root)
+        .unwrap_or_else(|| TextRange::empty(root.syntax().text_range().end()))
+}
+
+/// Parses the code of an expression of the template, such as `title` in `{title}`.
+///
+/// The expressions that contain markup, such as `{items.map((item) => <li>{item}</li>)}`,
+/// aren't TypeScript, the parse of their code is `None`.
+fn parse_expression(code: &str) -> Option<Parse<AnyJsRoot>> {
+    let parse = biome_js_parser::parse(code, JsFileSource::astro(), JsParserOptions::default());
+    if parse.has_errors() {
+        return None;
+    }
+    let AnyJsRoot::JsModule(module) = parse.tree() else {
+        return None;
+    };
+    let mut items = module.items().iter();
+    let is_expression = matches!(
+        items.next(),
+        Some(AnyJsModuleItem::AnyJsStatement(
+            AnyJsStatement::JsExpressionStatement(_)
+        ))
+    );
+
+    (is_expression && items.next().is_none() && module.directives().is_empty()).then_some(parse)
+}
 
-    fn matches(input: &str) -> Matches {
-        ASTRO_FENCE.find_iter(input)
+/// Writes the code of an expression of the template over the whitespace that replaces it in `code`.
+///
+/// The spread attributes, such as `{...props}`, are written without their dots.
+fn write_template_expression(code: &mut String, expression: &HtmlTextExpression) {
+    let Ok(token) = expression.value_token() else {
+        return;
+    };
+    let start = usize::from(token.text_trimmed_range().start());
+    let end = usize::from(token.text_trimmed_range().end());
+    if start == 0 || end >= code.len() {
+        return;
     }
 
-    /// It takes the original content of an Astro file, and new output of an Astro file. The output is only the content contained inside the
-    /// Astro fences. The function replaces `output` inside those fences.
-    pub fn output(input: &str, output: &str) -> String {
-        let mut matches = Self::matches(input);
-        if let (Some(start), Some(end)) = (matches.next(), matches.next()) {
-            format!(
-                "{}{}{}",
-                &input[..start.end() + 1],
-                output.trim_start(),
-                &input[end.start()..]
-            )
-        } else {
-            input.to_string()
+    let (expression, start) = match token.text_trimmed().strip_prefix("...") {
+        Some(spread) => (spread, start + 3),
+        None => (token.text_trimmed(), start),
+    };
+    if parse_expression(expression).is_some() {
+        write_wrapped_expression(code, expression, start);
+    }
+}
+
+/// Returns the name of an element that is a component, such as `Card` in `<Card title="Astro" />`.
+///
+/// The names of the components start with an uppercase letter, the other elements are HTML elements.
+fn component_name(node: &HtmlSyntaxNode) -> Option<HtmlName> {
+    let name = if let Some(element) = HtmlOpeningElement::cast_ref(node) {
+        element.name().ok()?
+    } else {
+        HtmlSelfClosingElement::cast_ref(node)?.name().ok()?
+    };
+    let value_token = name.value_token().ok()?;
+    let text = value_token.text_trimmed();
+    let is_component = text.starts_with(|char: char| char.is_ascii_uppercase())
+        && text
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || matches!(char, '_' | '$' | '.'));
+
+    is_component.then_some(name)
+}
+
+/// Writes the name of a component as a statement, after a semicolon that takes the place of its `<`.
+fn write_component_name(code: &mut String, name: &HtmlName) {
+    let Ok(token) = name.value_token() else {
+        return;
+    };
+    let start = usize::from(token.text_trimmed_range().start());
+    let end = usize::from(token.text_trimmed_range().end());
+    if start == 0 || end > code.len() {
+        return;
+    }
+
+    code.replace_range(start - 1..start, ";");
+    code.replace_range(start..end, token.text_trimmed());
+}
+
+/// Formats an Astro file: the HTML formatter formats the whole file, including the expressions of the template,
+/// then the JavaScript and the CSS formatters format the frontmatter, the expressions and the `<style>` elements.
+#[derive(Debug)]
+struct AstroFormatter(EmbeddedFormatter);
+
+impl AstroFormatter {
+    fn new(path: &BiomePath, settings: &Settings) -> Self {
+        Self(EmbeddedFormatter::new(
+            path,
+            settings,
+            [JsFileSource::astro()],
+        ))
+    }
+
+    fn format(&self, text: &str) -> Result<Printed, WorkspaceError> {
+        let parser_options = HtmlParserOptions::default().astro();
+        let mut code = self.0.format_document(text, parser_options)?;
+
+        // The HTML formatter prints the content of the blocks and the code of the expressions as is,
+        // their ranges are replaced from the last one, so that the ranges of the others stay valid
+        let root = biome_html_parser::parse_html(&code, parser_options).tree();
+        let mut replacements: Vec<(TextRange, String)> = AstroFileHandler::blocks(&root)
+            .into_iter()
+            .filter_map(|block| {
+                let content = &code[block.content_range];
+                let formatted = match block.kind {
+                    AstroBlockKind::Frontmatter => {
+                        self.0.format_script_block(JsFileSource::astro(), content)
+                    }
+                    AstroBlockKind::Style { is_css: true } => self
+                        .0
+                        .format_style_element(content, indentation_at(&code, block.content_range)),
+                    AstroBlockKind::Style { is_css: false } => None,
+                }?;
+                Some((block.content_range, formatted))
+            })
+            .collect();
+        replacements.extend(
+            root.html()
+                .syntax()
+                .descendants()
+                .filter_map(HtmlTextExpression::cast)
+                .filter_map(|expression| {
+                    let token = expression.value_token().ok()?;
+                    let formatted = self.format_expression(token.text_trimmed())?;
+                    Some((token.text_trimmed_range(), formatted))
+                }),
+        );
+        replacements.sort_by_key(|(range, _)| range.start());
+
+        for (range, formatted) in replacements.into_iter().rev() {
+            code.replace_range(Range::<usize>::from(range), &formatted);
         }
+
+        Ok(Printed::new(code, None, Vec::new(), Vec::new()))
+    }
+
+    /// Returns the formatted code of an expression of the template, when it fits on a single line.
+    ///
+    /// The spread attributes, such as `{...props}`, keep their dots.
+    fn format_expression(&self, code: &str) -> Option<String> {
+        let options = self.0.js_options(JsFileSource::astro())?;
+        let (spread, expression) = match code.strip_prefix("...") {
+            Some(expression) => ("...", expression),
+            None => ("", code),
+        };
+        let parse = parse_expression(expression)?;
+        let formatted = biome_js_formatter::format_node(options.clone(), &parse.syntax()).ok()?;
+        let formatted = formatted.print().ok()?.into_code();
+
+        // The formatter prints the expression as a statement, without the semicolons around it
+        let formatted = formatted.trim_end();
+        let formatted = formatted.strip_prefix(';').unwrap_or(formatted);
+        let formatted = formatted.strip_suffix(';').unwrap_or(formatted);
+        if formatted.contains(['\n', '\r']) {
+            return None;
+        }
+
+        Some(format!("{spread}{formatted}"))
     }
 }
 
@@ -90,25 +368,21 @@ impl ExtensionHandler for AstroFileHandler {
     }
 }
 
+/// Parses the whole Astro file with the HTML parser, the language of the file is the one of its frontmatter
 fn parse(
     _rome_path: &BiomePath,
-    file_source: DocumentFileSource,
+    _file_source: DocumentFileSource,
     text: &str,
     _settings: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let frontmatter = AstroFileHandler::input(text);
-    let parse = parse_js_with_cache(
-        frontmatter,
-        file_source
-            .to_js_file_source()
-            .unwrap_or(JsFileSource::ts()),
-        JsParserOptions::default(),
-        cache,
-    );
+    let parse =
+        biome_html_parser::parse_html_with_cache(text, cache, HtmlParserOptions::default().astro());
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
 
+    debug!("Parsing file with language {:?}", JsFileSource::astro());
+
     ParseResult {
         any_parse: AnyParse::new(
             // SAFETY: the parser should always return a root node
@@ -122,44 +396,128 @@ fn parse(
 #[tracing::instrument(level = "trace", skip(parse, settings))]
 fn format(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format(biome_path, document_file_source, parse, settings)
+    let formatter = AstroFormatter::new(biome_path, settings.settings());
+    let root: HtmlRoot = parse.tree();
+    formatter.format(&root.syntax().to_string())
 }
+
 pub(crate) fn format_range(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_range(biome_path, document_file_source, parse, settings, range)
+    let frontmatter = AstroFrontmatter::parse_in_file(&parse, false);
+    javascript::format_range(
+        biome_path,
+        &JsFileSource::astro().into(),
+        frontmatter.parse,
+        settings,
+        range,
+    )
 }
 
 pub(crate) fn format_on_type(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_on_type(biome_path, document_file_source, parse, settings, offset)
+    let frontmatter = AstroFrontmatter::parse_in_file(&parse, false);
+    javascript::format_on_type(
+        biome_path,
+        &JsFileSource::astro().into(),
+        frontmatter.parse,
+        settings,
+        offset,
+    )
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    let frontmatter = AstroFrontmatter::parse_in_file(&params.parse, true);
+    javascript::lint(LintParams {
+        parse: frontmatter.parse,
+        ..params
+    })
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    javascript::code_actions(params)
+    let frontmatter = AstroFrontmatter::parse_in_file(&params.parse, true);
+    javascript::code_actions(CodeActionsParams {
+        parse: frontmatter.parse,
+        ..params
+    })
 }
 
+/// Applies the fixes to the frontmatter of the Astro file, and formats the whole file when `should_format` is `true`.
+///
+/// The template is analyzed along with the frontmatter, so that a fix doesn't remove an import
+/// that only the template uses, but only the signals of the frontmatter are fixed.
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    javascript::fix_all(params)
+    let frontmatter = AstroFrontmatter::parse_in_file(&params.parse, true);
+    let root: HtmlRoot = params.parse.tree();
+    let mut code = root.syntax().to_string();
+    let formatter = params
+        .should_format
+        .then(|| AstroFormatter::new(params.biome_path, params.settings.settings()));
+
+    let result = javascript::fix_all(FixAllParams {
+        parse: frontmatter.parse,
+        should_format: false,
+        document_file_source: JsFileSource::astro().into(),
+        filter: AnalysisFilter {
+            range: Some(frontmatter.content_range),
+            ..params.filter
+        },
+        ..params
+    })?;
+
+    // The fixes only change the frontmatter, the text that follows it keeps its length.
+    // A fix that removes the first statement also removes its leading whitespace, which starts
+    // in place of the opening fence, the line break that follows the fence is then written back.
+    if !frontmatter.content_range.is_empty() {
+        let start = usize::from(frontmatter.content_range.start());
+        let following_length = code.len() - usize::from(frontmatter.content_range.end());
+        let fixed = result
+            .code
+            .get(..result.code.len().saturating_sub(following_length))
+            .unwrap_or_default();
+        let content = match fixed.strip_prefix(padding(&code[..start]).as_str()) {
+            Some(content) => content.to_string(),
+            None => {
+                let line_break = if code[start..].starts_with("\r\n") {
+                    "\r\n"
+                } else {
+                    "\n"
+                };
+                format!("{line_break}{}", fixed.trim_start())
+            }
+        };
+        code.replace_range(Range::<usize>::from(frontmatter.content_range), &content);
+    }
+    if let Some(formatter) = formatter {
+        code = formatter.format(&code)?.into_code();
+    }
+
+    Ok(FixFileResult { code, ..result })
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+fn organize_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let (mut code, frontmatter) = AstroFrontmatter::parse_content(&parse);
+    let result = javascript::organize_imports(path, frontmatter.parse, settings)?;
+    code.replace_range(
+        Range::<usize>::from(frontmatter.content_range),
+        &result.code,
+    );
+    Ok(OrganizeImportsResult { code })
 }
@@ -29,6 +29,7 @@ pub struct CssFormatterSettings {
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub quote_style: Option<QuoteStyle>,
+    pub collapse_single_declarations: Option<bool>,
     pub enabled: Option<bool>,
 }
 
@@ -36,6 +37,7 @@ pub struct CssFormatterSettings {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct CssParserSettings {
     pub allow_wrong_line_comments: bool,
+    pub tailwind: bool,
 }
 
 impl ServiceLanguage for CssLanguage {
@@ -83,7 +85,11 @@ impl ServiceLanguage for CssLanguage {
             .with_indent_style(indent_style)
             .with_indent_width(indent_width)
             .with_line_width(line_width)
-            .with_quote_style(language.quote_style.unwrap_or_default()),
+            .with_max_empty_lines(global.max_empty_lines.unwrap_or_default())
+            .with_quote_style(language.quote_style.unwrap_or_default())
+            .with_collapse_single_declarations(
+                language.collapse_single_declarations.unwrap_or_default(),
+            ),
         )
     }
 }
@@ -131,19 +137,28 @@ impl ExtensionHandler for CssFileHandler {
 
 fn parse(
     biome_path: &BiomePath,
-    _file_source: DocumentFileSource,
+    file_source: DocumentFileSource,
     text: &str,
     settings: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
     let parser = &settings.settings().languages.css.parser;
     let overrides = &settings.settings().override_settings;
-    let options: CssParserOptions =
+    let mut options: CssParserOptions =
         overrides
             .as_css_parser_options(biome_path)
             .unwrap_or(CssParserOptions {
                 allow_wrong_line_comments: parser.allow_wrong_line_comments,
+                tailwind: parser.tailwind,
+                ..Default::default()
             });
+    if let Some(file_source) = file_source.to_css_file_source() {
+        if file_source.is_scss() {
+            options = options.scss();
+        } else if file_source.is_less() {
+            options = options.less();
+        }
+    }
     let parse = biome_css_parser::parse_css_with_cache(text, cache, options);
     let root = parse.syntax();
     let diagnostics = parse.into_diagnostics();
@@ -252,7 +267,11 @@ fn format_on_type(
     Ok(printed)
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+fn organize_imports(
+    _path: &BiomePath,
+    parse: AnyParse,
+    _settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     Ok(OrganizeImportsResult {
         code: parse.syntax::<CssLanguage>().to_string(),
     })
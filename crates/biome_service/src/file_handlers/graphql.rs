@@ -0,0 +1,242 @@
+use super::{DocumentFileSource, ExtensionHandler, ParseResult};
+use crate::file_handlers::DebugCapabilities;
+use crate::file_handlers::{
+    AnalyzerCapabilities, Capabilities, FormatterCapabilities, ParserCapabilities,
+};
+use crate::settings::{
+    FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
+    WorkspaceSettingsHandle,
+};
+use crate::workspace::{GetSyntaxTreeResult, OrganizeImportsResult};
+use crate::WorkspaceError;
+use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
+use biome_fs::BiomePath;
+use biome_graphql_formatter::context::{
+    ArgumentsIndent, BlankLinesBetweenDefinitions, DescriptionQuoteStyle, GraphqlFormatOptions,
+};
+use biome_graphql_formatter::format_node;
+use biome_graphql_syntax::{GraphqlLanguage, GraphqlRoot, GraphqlSyntaxNode};
+use biome_parser::AnyParse;
+use biome_rowan::NodeCache;
+use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GraphqlFormatterSettings {
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub indent_width: Option<IndentWidth>,
+    pub indent_style: Option<IndentStyle>,
+    pub arguments_indent: Option<ArgumentsIndent>,
+    pub description_quote_style: Option<DescriptionQuoteStyle>,
+    pub blank_lines_between_definitions: Option<BlankLinesBetweenDefinitions>,
+    pub enabled: Option<bool>,
+}
+
+impl ServiceLanguage for GraphqlLanguage {
+    type FormatterSettings = GraphqlFormatterSettings;
+    type LinterSettings = ();
+    type OrganizeImportsSettings = ();
+    type FormatOptions = GraphqlFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+
+    fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
+        &language.graphql
+    }
+
+    fn resolve_format_options(
+        global: &FormatSettings,
+        _overrides: &OverrideSettings,
+        language: &GraphqlFormatterSettings,
+        _path: &BiomePath,
+        _document_file_source: &DocumentFileSource,
+    ) -> Self::FormatOptions {
+        let indent_style = if let Some(indent_style) = language.indent_style {
+            indent_style
+        } else {
+            global.indent_style.unwrap_or_default()
+        };
+        let line_width = if let Some(line_width) = language.line_width {
+            line_width
+        } else {
+            global.line_width.unwrap_or_default()
+        };
+        let indent_width = if let Some(indent_width) = language.indent_width {
+            indent_width
+        } else {
+            global.indent_width.unwrap_or_default()
+        };
+
+        let line_ending = if let Some(line_ending) = language.line_ending {
+            line_ending
+        } else {
+            global.line_ending.unwrap_or_default()
+        };
+
+        GraphqlFormatOptions::new()
+            .with_line_ending(line_ending)
+            .with_indent_style(indent_style)
+            .with_indent_width(indent_width)
+            .with_line_width(line_width)
+            .with_arguments_indent(language.arguments_indent.unwrap_or_default())
+            .with_description_quote_style(language.description_quote_style.unwrap_or_default())
+            .with_blank_lines_between_definitions(
+                language.blank_lines_between_definitions.unwrap_or_default(),
+            )
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct GraphqlFileHandler;
+
+impl ExtensionHandler for GraphqlFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: None,
+                code_actions: None,
+                rename: None,
+                fix_all: None,
+                organize_imports: Some(organize_imports),
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: Some(format_range),
+                format_on_type: Some(format_on_type),
+            },
+        }
+    }
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _settings: WorkspaceSettingsHandle,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = biome_graphql_parser::parse_graphql_with_cache(text, cache);
+    let root = parse.syntax();
+    let diagnostics = parse.into_diagnostics();
+
+    ParseResult {
+        any_parse: AnyParse::new(
+            // SAFETY: the parser should always return a root node
+            root.as_send().unwrap(),
+            diagnostics,
+        ),
+        language: Some(file_source),
+    }
+}
+
+fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: GraphqlSyntaxNode = parse.syntax();
+    let tree: GraphqlRoot = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let options = settings.format_options::<GraphqlLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<GraphqlLanguage>(path, document_file_source);
+
+    tracing::debug!("Format with the following options: \n{}", options);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+fn format_range(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    range: TextRange,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<GraphqlLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+    let printed = biome_graphql_formatter::format_range(options, &tree, range)?;
+    Ok(printed)
+}
+
+fn format_on_type(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    offset: TextSize,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<GraphqlLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+
+    let range = tree.text_range();
+    if offset < range.start() || offset > range.end() {
+        return Err(WorkspaceError::FormatError(FormatError::RangeError {
+            input: TextRange::at(offset, TextSize::from(0)),
+            tree: range,
+        }));
+    }
+
+    let token = match tree.token_at_offset(offset) {
+        // File is empty, do nothing
+        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::Single(token) => token,
+        // The cursor should be right after the closing character that was just typed,
+        // select the previous token as the correct one
+        TokenAtOffset::Between(token, _) => token,
+    };
+
+    let root_node = match token.parent() {
+        Some(node) => node,
+        None => panic!("found a token with no parent"),
+    };
+
+    let printed = biome_graphql_formatter::format_sub_tree(options, &root_node)?;
+    Ok(printed)
+}
+
+fn organize_imports(
+    _path: &BiomePath,
+    parse: AnyParse,
+    _settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    Ok(OrganizeImportsResult {
+        code: parse.syntax::<GraphqlLanguage>().to_string(),
+    })
+}
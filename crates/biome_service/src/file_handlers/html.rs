@@ -0,0 +1,704 @@
+use super::{CodeActionsParams, DocumentFileSource, ExtensionHandler, ParseResult};
+use crate::configuration::to_analyzer_rules;
+use crate::file_handlers::astro::padding;
+use crate::file_handlers::mdx::apply_block_changes;
+use crate::file_handlers::DebugCapabilities;
+use crate::file_handlers::{
+    domain_rules, indentation_at, javascript, AnalyzerCapabilities, Capabilities,
+    EmbeddedFormatter, FixAllParams, FormatterCapabilities, LintParams, LintResults,
+    ParserCapabilities,
+};
+use crate::settings::{
+    FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
+    Settings, WorkspaceSettingsHandle,
+};
+use crate::workspace::{
+    FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
+};
+use crate::WorkspaceError;
+use biome_analyze::options::PreferredQuote;
+use biome_analyze::{
+    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, GroupCategory, Never,
+    Queryable, RegistryVisitor, Rule, RuleCategories, RuleCategory, RuleDomain, RuleFilter,
+    RuleGroup,
+};
+use biome_configuration::linter::RuleSelector;
+use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
+use biome_formatter::{
+    AttributePosition, FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
+};
+use biome_fs::BiomePath;
+use biome_html_analyze::analyze;
+use biome_html_formatter::context::HtmlFormatOptions;
+use biome_html_formatter::format_node;
+use biome_html_parser::HtmlParserOptions;
+use biome_html_syntax::{HtmlElement, HtmlLanguage, HtmlRoot, HtmlSyntaxNode};
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::JsFileSource;
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::ops::Range;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct HtmlFormatterSettings {
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub indent_width: Option<IndentWidth>,
+    pub indent_style: Option<IndentStyle>,
+    pub attribute_position: Option<AttributePosition>,
+    pub enabled: Option<bool>,
+}
+
+impl ServiceLanguage for HtmlLanguage {
+    type FormatterSettings = HtmlFormatterSettings;
+    type LinterSettings = ();
+    type OrganizeImportsSettings = ();
+    type FormatOptions = HtmlFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+
+    fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
+        &language.html
+    }
+
+    fn resolve_format_options(
+        global: &FormatSettings,
+        _overrides: &OverrideSettings,
+        language: &HtmlFormatterSettings,
+        _path: &BiomePath,
+        _document_file_source: &DocumentFileSource,
+    ) -> Self::FormatOptions {
+        let indent_style = if let Some(indent_style) = language.indent_style {
+            indent_style
+        } else {
+            global.indent_style.unwrap_or_default()
+        };
+        let line_width = if let Some(line_width) = language.line_width {
+            line_width
+        } else {
+            global.line_width.unwrap_or_default()
+        };
+        let indent_width = if let Some(indent_width) = language.indent_width {
+            indent_width
+        } else {
+            global.indent_width.unwrap_or_default()
+        };
+
+        let line_ending = if let Some(line_ending) = language.line_ending {
+            line_ending
+        } else {
+            global.line_ending.unwrap_or_default()
+        };
+
+        HtmlFormatOptions::new()
+            .with_line_ending(line_ending)
+            .with_indent_style(indent_style)
+            .with_indent_width(indent_width)
+            .with_line_width(line_width)
+            .with_attribute_position(
+                language
+                    .attribute_position
+                    .or(global.attribute_position)
+                    .unwrap_or_default(),
+            )
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct HtmlFileHandler;
+
+/// The kind of an element of an HTML document whose content isn't HTML
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum EmbeddedBlockKind {
+    /// A `<script>` element whose `type` is JavaScript, `file_source` is a module for `type="module"`
+    Script { file_source: JsFileSource },
+    /// A `<style>` element, only the styles without a `type` attribute, or with `type="text/css"`, are CSS
+    Style { is_css: bool },
+}
+
+/// A `<script>` or a `<style>` element of an HTML document
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct EmbeddedBlock {
+    kind: EmbeddedBlockKind,
+    /// The range of the content of the element, between its opening tag and its closing tag
+    content_range: TextRange,
+}
+
+impl HtmlFileHandler {
+    /// Returns the `<script>` and the `<style>` elements of an HTML document, in the order of the source.
+    ///
+    /// The scripts whose `type` isn't JavaScript, such as `<script type="application/ld+json">`, and the
+    /// elements that aren't closed are ignored.
+    fn embedded_blocks(root: &HtmlRoot) -> Vec<EmbeddedBlock> {
+        root.syntax()
+            .descendants()
+            .filter_map(HtmlElement::cast)
+            .filter_map(|element| {
+                let opening_element = element.opening_element().ok()?;
+                let closing_element = element.closing_element().ok()?;
+                let name = opening_element.name().ok()?.value_token().ok()?;
+                let script_type = opening_element
+                    .attributes()
+                    .find_by_name("type")
+                    .and_then(|attribute| attribute.value())
+                    .map(|value| value.text().trim().to_ascii_lowercase());
+
+                let kind = match name.text_trimmed().to_ascii_lowercase().as_str() {
+                    "script" => EmbeddedBlockKind::Script {
+                        file_source: Self::script_file_source(script_type.as_deref())?,
+                    },
+                    "style" => EmbeddedBlockKind::Style {
+                        is_css: script_type
+                            .as_deref()
+                            .map_or(true, |value| value == "text/css"),
+                    },
+                    _ => return None,
+                };
+
+                Some(EmbeddedBlock {
+                    kind,
+                    content_range: TextRange::new(
+                        opening_element.syntax().text_range().end(),
+                        closing_element.syntax().text_range().start(),
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the language of a `<script>` element from its `type` attribute,
+    /// `None` when the script isn't JavaScript.
+    fn script_file_source(script_type: Option<&str>) -> Option<JsFileSource> {
+        match script_type {
+            None
+            | Some(
+                ""
+                | "text/javascript"
+                | "application/javascript"
+                | "text/ecmascript"
+                | "application/ecmascript",
+            ) => Some(JsFileSource::js_script()),
+            Some("module") => Some(JsFileSource::js_module()),
+            Some(_) => None,
+        }
+    }
+}
+
+/// The `<script>` elements of an HTML document, parsed together with the JavaScript parser
+struct HtmlScripts {
+    parse: AnyParse,
+    /// A module when one of the scripts is a module, a script otherwise
+    file_source: JsFileSource,
+    /// The code of the scripts, the text of the document where everything but the scripts is whitespace
+    code: String,
+    content_ranges: Vec<TextRange>,
+}
+
+impl HtmlScripts {
+    /// Parses the scripts of an HTML document, so that the ranges of their nodes are the offsets in the whole document.
+    ///
+    /// The text around the scripts is replaced by whitespace, only its line breaks are kept, and a semicolon
+    /// takes the place of the `<` of each closing tag, so that a script doesn't continue the statement of the previous one.
+    /// Returns `None` when the document doesn't have a script.
+    fn parse_in_file(parse: &AnyParse) -> Option<Self> {
+        let root: HtmlRoot = parse.tree();
+        let text = root.syntax().to_string();
+
+        let mut code = padding(&text);
+        let mut file_source = JsFileSource::js_script();
+        let mut content_ranges = Vec::new();
+        for block in HtmlFileHandler::embedded_blocks(&root) {
+            let EmbeddedBlockKind::Script {
+                file_source: script_file_source,
+            } = block.kind
+            else {
+                continue;
+            };
+            if script_file_source.is_module() {
+                file_source = script_file_source;
+            }
+
+            let end = usize::from(block.content_range.end());
+            code.replace_range(
+                Range::<usize>::from(block.content_range),
+                &text[block.content_range],
+            );
+            code.replace_range(end..end + 1, ";");
+            content_ranges.push(block.content_range);
+        }
+        if content_ranges.is_empty() {
+            return None;
+        }
+
+        let script_parse = biome_js_parser::parse(&code, file_source, JsParserOptions::default());
+        let root = script_parse.syntax();
+        let diagnostics = script_parse.into_diagnostics();
+
+        Some(Self {
+            // SAFETY: the parser should always return a root node
+            parse: AnyParse::new(root.as_send().unwrap(), diagnostics),
+            file_source,
+            code,
+            content_ranges,
+        })
+    }
+}
+
+/// Formats an HTML document: the HTML formatter formats the whole document, then the JavaScript
+/// and the CSS formatters format the content of the `<script>` and the `<style>` elements.
+#[derive(Debug)]
+struct HtmlDocumentFormatter(EmbeddedFormatter);
+
+impl HtmlDocumentFormatter {
+    fn new(path: &BiomePath, settings: &Settings) -> Self {
+        let js_file_sources = [JsFileSource::js_script(), JsFileSource::js_module()];
+        Self(EmbeddedFormatter::new(path, settings, js_file_sources))
+    }
+
+    fn format(&self, text: &str) -> Result<Printed, WorkspaceError> {
+        let mut code = self.0.format_document(text, HtmlParserOptions::default())?;
+
+        // The HTML formatter prints the content of the elements as is, their ranges
+        // are replaced from the last one, so that the ranges of the others stay valid
+        let root = biome_html_parser::parse_html(&code, HtmlParserOptions::default()).tree();
+        for block in HtmlFileHandler::embedded_blocks(&root).into_iter().rev() {
+            let content = &code[block.content_range];
+            if content.trim().is_empty() {
+                continue;
+            }
+            let indentation = indentation_at(&code, block.content_range);
+            let formatted = match block.kind {
+                EmbeddedBlockKind::Script { file_source } => {
+                    self.0
+                        .format_script_element(file_source, content, indentation)
+                }
+                EmbeddedBlockKind::Style { is_css: true } => {
+                    self.0.format_style_element(content, indentation)
+                }
+                EmbeddedBlockKind::Style { is_css: false } => None,
+            };
+            if let Some(formatted) = formatted {
+                code.replace_range(Range::<usize>::from(block.content_range), &formatted);
+            }
+        }
+
+        Ok(Printed::new(code, None, Vec::new(), Vec::new()))
+    }
+}
+
+impl ExtensionHandler for HtmlFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: Some(code_actions),
+                rename: None,
+                fix_all: Some(fix_all),
+                organize_imports: Some(organize_imports),
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: Some(format_range),
+                format_on_type: Some(format_on_type),
+            },
+        }
+    }
+}
+
+fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _settings: WorkspaceSettingsHandle,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let parse = biome_html_parser::parse_html_with_cache(text, cache, HtmlParserOptions::default());
+    let root = parse.syntax();
+    let diagnostics = parse.into_diagnostics();
+
+    ParseResult {
+        any_parse: AnyParse::new(
+            // SAFETY: the parser should always return a root node
+            root.as_send().unwrap(),
+            diagnostics,
+        ),
+        language: Some(file_source),
+    }
+}
+
+fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: HtmlSyntaxNode = parse.syntax();
+    let tree: HtmlRoot = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+fn debug_formatter_ir(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let options = settings.format_options::<HtmlLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+/// Formats the whole HTML document, including the content of its `<script>` and `<style>` elements
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+fn format(
+    path: &BiomePath,
+    _document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let formatter = HtmlDocumentFormatter::new(path, settings.settings());
+
+    tracing::debug!("Format with the following options: \n{:?}", formatter);
+
+    let root: HtmlRoot = parse.tree();
+    formatter.format(&root.syntax().to_string())
+}
+
+fn format_range(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    range: TextRange,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<HtmlLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+    let printed = biome_html_formatter::format_range(options, &tree, range)?;
+    Ok(printed)
+}
+
+fn format_on_type(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    offset: TextSize,
+) -> Result<Printed, WorkspaceError> {
+    let options = settings.format_options::<HtmlLanguage>(path, document_file_source);
+
+    let tree = parse.syntax();
+
+    let range = tree.text_range();
+    if offset < range.start() || offset > range.end() {
+        return Err(WorkspaceError::FormatError(FormatError::RangeError {
+            input: TextRange::at(offset, TextSize::from(0)),
+            tree: range,
+        }));
+    }
+
+    let token = match tree.token_at_offset(offset) {
+        // File is empty, do nothing
+        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::Single(token) => token,
+        // The cursor should be right after the closing character that was just typed,
+        // select the previous token as the correct one
+        TokenAtOffset::Between(token, _) => token,
+    };
+
+    let root_node = match token.parent() {
+        Some(node) => node,
+        None => panic!("found a token with no parent"),
+    };
+
+    let printed = biome_html_formatter::format_sub_tree(options, &root_node)?;
+    Ok(printed)
+}
+
+/// Lints the HTML document with the HTML rules, and its `<script>` elements with the JavaScript rules
+fn lint(params: LintParams) -> LintResults {
+    let mut results = lint_html(&params, None);
+    let Some(scripts) = HtmlScripts::parse_in_file(&params.parse) else {
+        return results;
+    };
+
+    let max_diagnostics = params
+        .max_diagnostics
+        .saturating_sub(results.diagnostics.len() as u32);
+    let script_results = javascript::lint(LintParams {
+        parse: scripts.parse,
+        language: scripts.file_source.into(),
+        max_diagnostics,
+        ..params
+    });
+    results.diagnostics.extend(script_results.diagnostics);
+    results.errors += script_results.errors;
+    results.skipped_diagnostics += script_results.skipped_diagnostics;
+    results
+}
+
+/// Lints the markup of an HTML document, or of a component whose markup is HTML, such as a Vue component,
+/// with the HTML rules. The diagnostics of the parser are reported along with the ones of the rules.
+///
+/// When `domain` is set, only the rules of the domain run: the other rules don't know the syntax
+/// of the templates of its components, such as the `:alt` binding of a Vue template.
+pub(crate) fn lint_html(params: &LintParams, domain: Option<RuleDomain>) -> LintResults {
+    tracing::debug_span!("Linting HTML file", path =? params.path, language =? params.language)
+        .in_scope(move || {
+            let root: HtmlRoot = params.parse.tree();
+            let mut diagnostics = params.parse.clone().into_diagnostics();
+            let settings = params.settings.settings();
+
+            let mut diagnostic_count = diagnostics.len() as u32;
+            let mut errors = diagnostics
+                .iter()
+                .filter(|diag| diag.severity() <= Severity::Error)
+                .count();
+
+            let skipped_diagnostics = diagnostic_count - diagnostics.len() as u32;
+
+            let mut rules = settings.as_rules(params.path.as_path());
+            let rule_filter_list = if let Some(rule) = params.rule {
+                // We execute a single rule or group because the `--rule` filter is specified.
+                match rule {
+                    RuleSelector::Group(group) => {
+                        if let Some(rules) = rules.as_mut() {
+                            // Ensure that the recommended field is not set to `false`.
+                            rules.to_mut().set_recommended();
+                        }
+                        rules
+                            .as_ref()
+                            .map(|rules| rules.as_enabled_rules())
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter(|rule_filter| rule_filter.group() == group.as_str())
+                            .collect()
+                    }
+                    RuleSelector::Rule(group, rule_name) => {
+                        if let Some(rules) = rules.as_mut() {
+                            // Set the severity level of the rule to its default.
+                            rules.to_mut().set_default_severity(group, rule_name);
+                        }
+                        vec![rule.into()]
+                    }
+                }
+            } else {
+                let mut rule_filter_list = rules
+                    .as_ref()
+                    .map(|rules| rules.as_enabled_rules())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                rule_filter_list.extend(domain_rules(
+                    params.path.as_path(),
+                    params.manifest.as_ref(),
+                    rules.as_deref(),
+                ));
+                rule_filter_list
+            };
+
+            let analyzer_options =
+                compute_analyzer_options(&params.settings, PathBuf::from(params.path.as_path()));
+            let disabled_rules = domain.map(rules_outside_domain).unwrap_or_default();
+            let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+            filter.disabled_rules = Some(disabled_rules.as_slice());
+            filter.categories = params.categories;
+            let has_lint = filter.categories.contains(RuleCategories::LINT);
+
+            let (_, analyze_diagnostics) = analyze(&root, filter, &analyzer_options, |signal| {
+                if let Some(mut diagnostic) = signal.diagnostic() {
+                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                    if !has_lint && diagnostic.category() == Some(category!("suppressions/unused"))
+                    {
+                        return ControlFlow::<Never>::Continue(());
+                    }
+
+                    diagnostic_count += 1;
+
+                    // We do now check if the severity of the diagnostics should be changed.
+                    // The configuration allows to change the severity of the diagnostics emitted by rules.
+                    let severity = diagnostic
+                        .category()
+                        .filter(|category| category.name().starts_with("lint/"))
+                        .map_or_else(
+                            || diagnostic.severity(),
+                            |category| {
+                                rules
+                                    .as_ref()
+                                    .and_then(|rules| rules.get_severity_from_code(category))
+                                    .unwrap_or(Severity::Warning)
+                            },
+                        );
+
+                    if severity <= Severity::Error {
+                        errors += 1;
+                    }
+
+                    if diagnostic_count <= params.max_diagnostics {
+                        for action in signal.actions() {
+                            if !action.is_suppression() {
+                                diagnostic = diagnostic.add_code_suggestion(action.into());
+                            }
+                        }
+
+                        let error = diagnostic.with_severity(severity);
+
+                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            });
+
+            diagnostics.extend(
+                analyze_diagnostics
+                    .into_iter()
+                    .map(biome_diagnostics::serde::Diagnostic::new)
+                    .collect::<Vec<_>>(),
+            );
+
+            LintResults {
+                diagnostics,
+                errors,
+                skipped_diagnostics,
+            }
+        })
+}
+
+/// Collects the lint rules that don't belong to a domain
+struct RulesOutsideDomainVisitor {
+    domain: RuleDomain,
+    rules: Vec<RuleFilter<'static>>,
+}
+
+impl RegistryVisitor<HtmlLanguage> for RulesOutsideDomainVisitor {
+    fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = HtmlLanguage>>(&mut self) {
+        G::record_rules(self)
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = HtmlLanguage>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        if !R::METADATA.domains.contains(&self.domain) {
+            self.rules.push(RuleFilter::Rule(
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name,
+            ));
+        }
+    }
+}
+
+/// Returns the HTML lint rules that don't belong to `domain`
+fn rules_outside_domain(domain: RuleDomain) -> Vec<RuleFilter<'static>> {
+    let mut visitor = RulesOutsideDomainVisitor {
+        domain,
+        rules: Vec::new(),
+    };
+    biome_html_analyze::visit_registry(&mut visitor);
+    visitor.rules
+}
+
+fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    match HtmlScripts::parse_in_file(&params.parse) {
+        Some(scripts) => javascript::code_actions(CodeActionsParams {
+            parse: scripts.parse,
+            language: scripts.file_source.into(),
+            ..params
+        }),
+        None => PullActionsResult {
+            actions: Vec::new(),
+        },
+    }
+}
+
+/// Applies the fixes to the `<script>` elements of the HTML document,
+/// and formats the whole document when `should_format` is `true`.
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let tree: HtmlRoot = params.parse.tree();
+    let text = tree.syntax().to_string();
+    let formatter = params
+        .should_format
+        .then(|| HtmlDocumentFormatter::new(params.biome_path, params.settings.settings()));
+
+    let result = match HtmlScripts::parse_in_file(&params.parse) {
+        Some(scripts) => {
+            let result = javascript::fix_all(FixAllParams {
+                parse: scripts.parse,
+                should_format: false,
+                document_file_source: scripts.file_source.into(),
+                ..params
+            })?;
+            let code =
+                apply_block_changes(&text, &scripts.code, &result.code, &scripts.content_ranges);
+            FixFileResult { code, ..result }
+        }
+        None => FixFileResult {
+            actions: vec![],
+            errors: 0,
+            skipped_suggested_fixes: 0,
+            code: text,
+        },
+    };
+
+    match formatter {
+        Some(formatter) => Ok(FixFileResult {
+            code: formatter.format(&result.code)?.into_code(),
+            ..result
+        }),
+        None => Ok(result),
+    }
+}
+
+/// Sorts the imports of the `<script type="module">` elements of the HTML document
+fn organize_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let text = parse.syntax::<HtmlLanguage>().to_string();
+    let Some(scripts) = HtmlScripts::parse_in_file(&parse) else {
+        return Ok(OrganizeImportsResult { code: text });
+    };
+
+    let result = javascript::organize_imports(path, scripts.parse, settings)?;
+    Ok(OrganizeImportsResult {
+        code: apply_block_changes(&text, &scripts.code, &result.code, &scripts.content_ranges),
+    })
+}
+
+fn compute_analyzer_options(
+    settings: &WorkspaceSettingsHandle,
+    file_path: PathBuf,
+) -> AnalyzerOptions {
+    let configuration = AnalyzerConfiguration {
+        rules: to_analyzer_rules(settings.settings(), file_path.as_path()),
+        globals: vec![],
+        preferred_quote: PreferredQuote::Double,
+        jsx_runtime: Default::default(),
+    };
+    AnalyzerOptions {
+        configuration,
+        file_path,
+        ..AnalyzerOptions::default()
+    }
+}
@@ -4,8 +4,11 @@ use super::{
 };
 use crate::configuration::to_analyzer_rules;
 use crate::diagnostics::extension_error;
-use crate::file_handlers::{is_diagnostic_error, FixAllParams};
-use crate::settings::OverrideSettings;
+use crate::file_handlers::{
+    is_diagnostic_error, resolve_format_options, EmbeddedFormatter, FixAllParams,
+};
+use crate::matcher::Pattern;
+use crate::settings::{OverrideSettings, Settings};
 use crate::workspace::{DocumentFileSource, OrganizeImportsResult};
 use crate::{
     settings::{
@@ -21,14 +24,15 @@ use crate::{
 use biome_analyze::options::PreferredQuote;
 use biome_analyze::{
     AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, GroupCategory, Never,
-    QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleFilter, RuleGroup,
+    QueryMatch, RegistryVisitor, RuleCategories, RuleCategory, RuleDomain, RuleFilter, RuleGroup,
 };
-use biome_configuration::javascript::JsxRuntime;
+use biome_configuration::javascript::{EmbeddedLanguageTags, JsxRuntime};
 use biome_configuration::linter::RuleSelector;
+use biome_configuration::Rules;
 use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{
-    AttributePosition, FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed,
-    QuoteStyle,
+    AttributePosition, FormatError, FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth,
+    Printed, QuoteStyle,
 };
 use biome_fs::BiomePath;
 use biome_js_analyze::utils::rename::{RenameError, RenameSymbolExtensions};
@@ -37,7 +41,9 @@ use biome_js_analyze::{
 };
 use biome_js_formatter::context::trailing_commas::TrailingCommas;
 use biome_js_formatter::context::{
-    ArrowParentheses, BracketSameLine, BracketSpacing, JsFormatOptions, QuoteProperties, Semicolons,
+    ArrowParentheses, BracketSameLine, BracketSpacing, DecoratorPlacement, FormatEmbeddedCode,
+    FormatJsdoc, InlineShortMemberChains, JsFormatOptions, ObjectWrap, OperatorPosition,
+    QuoteProperties, Semicolons, SingleAttributePerLine, TernaryStyle,
 };
 use biome_js_formatter::format_node;
 use biome_js_parser::JsParserOptions;
@@ -46,12 +52,13 @@ use biome_js_syntax::{
     AnyJsRoot, JsFileSource, JsLanguage, JsSyntaxNode, TextRange, TextSize, TokenAtOffset,
 };
 use biome_parser::AnyParse;
-use biome_rowan::{AstNode, BatchMutationExt, Direction, NodeCache};
+use biome_project::PackageJson;
+use biome_rowan::{AstNode, BatchMutationExt, Direction, Language, NodeCache};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::ffi::OsStr;
 use std::fmt::Debug;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tracing::{debug, debug_span, error, info, trace, trace_span};
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -65,12 +72,21 @@ pub struct JsFormatterSettings {
     pub arrow_parentheses: Option<ArrowParentheses>,
     pub bracket_spacing: Option<BracketSpacing>,
     pub bracket_same_line: Option<BracketSameLine>,
+    pub single_attribute_per_line: Option<SingleAttributePerLine>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub enabled: Option<bool>,
     pub attribute_position: Option<AttributePosition>,
+    pub object_wrap: Option<ObjectWrap>,
+    pub operator_position: Option<OperatorPosition>,
+    pub format_jsdoc: Option<FormatJsdoc>,
+    pub decorator_placement: Option<DecoratorPlacement>,
+    pub ternary_style: Option<TernaryStyle>,
+    pub member_chain_min_calls: Option<u8>,
+    pub inline_short_member_chains: Option<InlineShortMemberChains>,
+    pub embedded_language_tags: Option<EmbeddedLanguageTags>,
 }
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -85,9 +101,25 @@ pub struct JsLinterSettings {
     pub globals: Vec<String>,
 }
 
-#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
-pub struct JsOrganizeImportsSettings {}
+pub struct JsOrganizeImportsSettings {
+    pub groups: Vec<Vec<String>>,
+    pub blank_lines_between_groups: bool,
+    pub merge_imports: bool,
+    pub merge_type_imports: bool,
+}
+
+impl Default for JsOrganizeImportsSettings {
+    fn default() -> Self {
+        Self {
+            groups: Vec::new(),
+            blank_lines_between_groups: false,
+            merge_imports: true,
+            merge_type_imports: false,
+        }
+    }
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -150,6 +182,7 @@ impl ServiceLanguage for JsLanguage {
                 .or(global.line_ending)
                 .unwrap_or_default(),
         )
+        .with_max_empty_lines(global.max_empty_lines.unwrap_or_default())
         .with_quote_style(language.quote_style.unwrap_or_default())
         .with_jsx_quote_style(language.jsx_quote_style.unwrap_or_default())
         .with_quote_properties(language.quote_properties.unwrap_or_default())
@@ -158,6 +191,14 @@ impl ServiceLanguage for JsLanguage {
         .with_arrow_parentheses(language.arrow_parentheses.unwrap_or_default())
         .with_bracket_spacing(language.bracket_spacing.unwrap_or_default())
         .with_bracket_same_line(language.bracket_same_line.unwrap_or_default())
+        .with_single_attribute_per_line(language.single_attribute_per_line.unwrap_or_default())
+        .with_object_wrap(language.object_wrap.unwrap_or_default())
+        .with_operator_position(language.operator_position.unwrap_or_default())
+        .with_format_jsdoc(language.format_jsdoc.unwrap_or_default())
+        .with_decorator_placement(language.decorator_placement.unwrap_or_default())
+        .with_ternary_style(language.ternary_style.unwrap_or_default())
+        .with_member_chain_min_calls(language.member_chain_min_calls.unwrap_or_default())
+        .with_inline_short_member_chains(language.inline_short_member_chains.unwrap_or_default())
         .with_attribute_position(
             language
                 .attribute_position
@@ -169,6 +210,105 @@ impl ServiceLanguage for JsLanguage {
     }
 }
 
+/// Formats the CSS, HTML and GraphQL of the tagged template literals whose tag is in
+/// the embedded language tags of the configuration, with the options of the files of that language.
+#[derive(Debug)]
+struct EmbeddedTemplateFormatter {
+    tags: EmbeddedLanguageTags,
+    formatter: EmbeddedFormatter,
+}
+
+impl EmbeddedTemplateFormatter {
+    fn new(path: &BiomePath, settings: &Settings) -> Self {
+        let tags = settings
+            .languages
+            .javascript
+            .formatter
+            .embedded_language_tags
+            .clone()
+            .unwrap_or_default();
+
+        Self {
+            tags,
+            formatter: EmbeddedFormatter::new(path, settings, []),
+        }
+    }
+
+    /// Formats a style sheet, or the declarations of a rule such as the ones of `styled.div`
+    fn format_css(&self, code: &str) -> Option<String> {
+        if let Some(formatted) = self.formatter.format_css(code) {
+            return Some(formatted);
+        }
+
+        // The declarations are formatted as the block of a rule, whose first and last lines are removed
+        let options = self.formatter.css_options()?;
+        let formatted = self.formatter.format_css(&format!("a {{\n{code}\n}}"))?;
+        let indentation = match options.indent_style() {
+            IndentStyle::Tab => "\t".to_string(),
+            IndentStyle::Space => " ".repeat(options.indent_width().value() as usize),
+        };
+        let lines: Vec<_> = formatted.lines().collect();
+        let declarations = lines.get(1..lines.len().saturating_sub(1))?;
+
+        let mut code = String::new();
+        for line in declarations {
+            code.push_str(line.strip_prefix(&indentation).unwrap_or(line));
+            code.push('\n');
+        }
+        Some(code)
+    }
+}
+
+impl FormatEmbeddedCode for EmbeddedTemplateFormatter {
+    fn format_embedded_code(&self, tag: &str, code: &str) -> Option<String> {
+        let matches =
+            |patterns: &[String]| patterns.iter().any(|pattern| matches_tag(pattern, tag));
+        if matches(&self.tags.css) {
+            self.format_css(code)
+        } else if matches(&self.tags.html) {
+            self.formatter.format_html(code)
+        } else if matches(&self.tags.graphql) {
+            self.formatter.format_graphql(code)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns `true` when the tag matches the pattern, where `*` matches any text,
+/// such as `styled.*` for `styled.div` or `styled(*)` for `styled(Button)`.
+fn matches_tag(pattern: &str, tag: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = tag.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<_> = parts.collect();
+    let Some(last) = parts.pop() else {
+        return rest.is_empty();
+    };
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+/// Resolves the options of the JavaScript formatter, which formats the tagged template literals
+/// with the formatters of their language.
+pub(crate) fn format_options(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    settings: &Settings,
+) -> JsFormatOptions {
+    let embedded_code_formatter = EmbeddedTemplateFormatter::new(path, settings);
+    resolve_format_options::<JsLanguage>(settings, path, *document_file_source)
+        .with_embedded_code_formatter(embedded_code_formatter)
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) struct JsFileHandler;
 
@@ -273,6 +413,11 @@ fn debug_control_flow(parse: AnyParse, cursor: TextSize) -> String {
         &options,
         JsFileSource::default(),
         None,
+        None,
+        None,
+        Vec::new(),
+        None,
+        None,
         |_| ControlFlow::<Never>::Continue(()),
     );
 
@@ -285,7 +430,7 @@ fn debug_formatter_ir(
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<String, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(path, document_file_source);
+    let options = format_options(path, document_file_source, settings.settings());
 
     let tree = parse.syntax();
     let formatted = format_node(options, &tree)?;
@@ -348,6 +493,11 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                     .unwrap_or_default()
                     .into_iter()
                     .collect::<Vec<_>>();
+                rule_filter_list.extend(domain_rules(
+                    params.path.as_path(),
+                    params.manifest.as_ref(),
+                    rules.as_deref(),
+                ));
                 if settings.organize_imports.enabled && !params.categories.is_syntax() {
                     rule_filter_list.push(RuleFilter::Rule("correctness", "organizeImports"));
                 }
@@ -380,6 +530,11 @@ pub(crate) fn lint(params: LintParams) -> LintResults {
                 &analyzer_options,
                 file_source,
                 params.manifest,
+                params.tsconfig,
+                params.tailwind_config,
+                params.workspace_packages,
+                None,
+                params.template,
                 |signal| {
                     if let Some(mut diagnostic) = signal.diagnostic() {
                         // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
@@ -471,6 +626,92 @@ impl RegistryVisitor<JsLanguage> for ActionsVisitor<'_> {
     }
 }
 
+/// Collects the lint rules of the domains that the project depends on, or that the file belongs to.
+struct DomainsVisitor<'a> {
+    path: &'a Path,
+    manifest: Option<&'a PackageJson>,
+    enabled_rules: Vec<RuleFilter<'static>>,
+}
+
+impl DomainsVisitor<'_> {
+    fn is_domain_enabled(&self, domain: &RuleDomain) -> bool {
+        let has_dependency = |dependency: &str| {
+            let Some(manifest) = self.manifest else {
+                return false;
+            };
+            match dependency.strip_suffix('*') {
+                Some(scope) => manifest
+                    .all_dependencies()
+                    .any(|name| name.starts_with(scope)),
+                None => manifest.has_dependency(dependency),
+            }
+        };
+        let matches_glob =
+            |glob: &str| Pattern::new(glob).is_ok_and(|pattern| pattern.matches_path(self.path));
+
+        domain
+            .manifest_dependencies()
+            .iter()
+            .any(|dependency| has_dependency(dependency))
+            || domain.globs().iter().any(|glob| matches_glob(glob))
+    }
+}
+
+impl<L: Language> RegistryVisitor<L> for DomainsVisitor<'_> {
+    fn record_category<C: GroupCategory<Language = L>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_group<G: RuleGroup<Language = L>>(&mut self) {
+        G::record_rules(self)
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: biome_analyze::Rule + 'static,
+        R::Query: biome_analyze::Queryable<Language = L>,
+        <R::Query as biome_analyze::Queryable>::Output: Clone,
+    {
+        let is_domain_enabled = R::METADATA
+            .domains
+            .iter()
+            .any(|domain| self.is_domain_enabled(domain));
+        if is_domain_enabled {
+            self.enabled_rules.push(RuleFilter::Rule(
+                <R::Group as RuleGroup>::NAME,
+                R::METADATA.name,
+            ));
+        }
+    }
+}
+
+/// Returns the rules of the domains that the project depends on, or that the file of `path` belongs to,
+/// except the rules that are disabled by the configuration.
+///
+/// The rules of the JavaScript and the HTML analyzers are both returned,
+/// since the components of a domain, such as the Vue components, are checked by both.
+pub(crate) fn domain_rules(
+    path: &Path,
+    manifest: Option<&PackageJson>,
+    rules: Option<&Rules>,
+) -> Vec<RuleFilter<'static>> {
+    let mut visitor = DomainsVisitor {
+        path,
+        manifest,
+        enabled_rules: Vec::new(),
+    };
+    visit_registry(&mut visitor);
+    biome_html_analyze::visit_registry(&mut visitor);
+    let disabled_rules = rules.map(Rules::as_disabled_rules).unwrap_or_default();
+    visitor
+        .enabled_rules
+        .into_iter()
+        .filter(|rule| !disabled_rules.contains(rule))
+        .collect()
+}
+
 #[tracing::instrument(level = "debug", skip(params))]
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
     let CodeActionsParams {
@@ -479,6 +720,9 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
         workspace,
         path,
         manifest,
+        tsconfig,
+        tailwind_config,
+        workspace_packages,
         language,
     } = params;
     debug_span!("Code actions JavaScript", range =? range, path =? path).in_scope(move || {
@@ -503,6 +747,7 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
 
                 enabled_rules.extend(visitor.enabled_rules);
             }
+            enabled_rules.extend(domain_rules(path.as_path(), manifest.as_ref(), rules));
 
             let mut filter = if !enabled_rules.is_empty() {
                 AnalysisFilter::from_enabled_rules(Some(enabled_rules.as_slice()))
@@ -531,6 +776,11 @@ pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
                 &analyzer_options,
                 source_type,
                 manifest,
+                tsconfig,
+                tailwind_config,
+                workspace_packages,
+                None,
+                None,
                 |signal| {
                     actions.extend(signal.actions().into_code_action_iter().map(|item| {
                         CodeAction {
@@ -559,12 +809,17 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
         parse,
         rules,
         fix_file_mode,
+        suppression_reason,
         settings,
         should_format,
         biome_path,
         mut filter,
         manifest,
+        tsconfig,
+        tailwind_config,
+        workspace_packages,
         document_file_source,
+        ..
     } = params;
 
     let Some(file_source) = document_file_source
@@ -580,14 +835,30 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
 
     let mut skipped_suggested_fixes = 0;
     let mut errors: u16 = 0;
-    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(biome_path.as_path()));
+    let mut analyzer_options =
+        compute_analyzer_options(&settings, PathBuf::from(biome_path.as_path()));
+    analyzer_options.suppression_reason = suppression_reason;
+    // The number of diagnostics that could be suppressed in the previous pass
+    let mut previous_suppressions = usize::MAX;
     loop {
+        let mut suppression = None;
+        let mut suppressions = 0;
+        if matches!(fix_file_mode, FixFileMode::ApplySuppressions) {
+            // The diagnostics are counted again at each pass, so the last pass counts
+            // the errors whose diagnostics couldn't be suppressed
+            errors = 0;
+        }
         let (action, _) = analyze(
             &tree,
             filter,
             &analyzer_options,
             file_source,
             manifest.clone(),
+            tsconfig.clone(),
+            tailwind_config.clone(),
+            workspace_packages.clone(),
+            None,
+            None,
             |signal| {
                 let current_diagnostic = signal.diagnostic();
 
@@ -598,8 +869,11 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                 }
 
                 for action in signal.actions() {
-                    // suppression actions should not be part of the fixes (safe or suggested)
-                    if action.is_suppression() {
+                    // suppression actions should not be part of the fixes (safe or suggested),
+                    // and only the suppression actions are applied when suppressing the rules
+                    if action.is_suppression()
+                        != matches!(fix_file_mode, FixFileMode::ApplySuppressions)
+                    {
                         continue;
                     }
 
@@ -622,6 +896,14 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
                                 return ControlFlow::Break(action);
                             }
                         }
+                        FixFileMode::ApplySuppressions => {
+                            // The pass continues, so all the diagnostics are counted
+                            suppressions += 1;
+                            if suppression.is_none() {
+                                suppression = Some(action);
+                            }
+                            break;
+                        }
                     }
                 }
 
@@ -629,11 +911,32 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             },
         );
 
+        let action = if matches!(fix_file_mode, FixFileMode::ApplySuppressions) {
+            // A suppression comment that doesn't suppress its diagnostic would be inserted at
+            // each pass, the suppressions stop when they don't reduce the diagnostics
+            let action = suppression.filter(|_| suppressions < previous_suppressions);
+            previous_suppressions = suppressions;
+            action
+        } else {
+            action
+        };
+
         match action {
             Some(action) => {
                 if let (root, Some((range, _))) =
                     action.mutation.commit_with_text_range_and_edit(true)
                 {
+                    // The fixes are applied inside the analyzed range, which then ends where
+                    // the fixed text ends
+                    if let Some(analyzed_range) = filter.range {
+                        let end = (analyzed_range.end() + root.text_range().len())
+                            .checked_sub(tree.syntax().text_range().len())
+                            .unwrap_or(analyzed_range.start());
+                        filter.range = Some(TextRange::new(
+                            analyzed_range.start(),
+                            end.max(analyzed_range.start()),
+                        ));
+                    }
                     tree = match AnyJsRoot::cast(root) {
                         Some(tree) => tree,
                         None => {
@@ -657,7 +960,7 @@ pub(crate) fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceEr
             None => {
                 let code = if should_format {
                     format_node(
-                        settings.format_options::<JsLanguage>(biome_path, &document_file_source),
+                        format_options(biome_path, &document_file_source, settings.settings()),
                         tree.syntax(),
                     )?
                     .print()?
@@ -683,7 +986,7 @@ pub(crate) fn format(
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    let options = format_options(biome_path, document_file_source, settings.settings());
 
     debug!("Options used for format: \n{}", options);
 
@@ -707,7 +1010,7 @@ pub(crate) fn format_range(
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(biome_path, document_file_source);
+    let options = format_options(biome_path, document_file_source, settings.settings());
 
     let tree = parse.syntax();
     let printed = biome_js_formatter::format_range(options, &tree, range)?;
@@ -722,7 +1025,7 @@ pub(crate) fn format_on_type(
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    let options = settings.format_options::<JsLanguage>(path, document_file_source);
+    let options = format_options(path, document_file_source, settings.settings());
 
     let tree = parse.syntax();
 
@@ -793,7 +1096,11 @@ fn rename(
     }
 }
 
-pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+pub(crate) fn organize_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     let mut tree: AnyJsRoot = parse.tree();
 
     let filter = AnalysisFilter {
@@ -802,12 +1109,18 @@ pub(crate) fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult,
         ..AnalysisFilter::default()
     };
 
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(path.as_path()));
     let (action, _) = analyze(
         &tree,
         filter,
-        &AnalyzerOptions::default(),
+        &analyzer_options,
         JsFileSource::default(),
         None,
+        None,
+        None,
+        Vec::new(),
+        None,
+        None,
         |signal| {
             for action in signal.actions() {
                 if action.is_suppression() {
@@ -903,5 +1216,6 @@ fn compute_analyzer_options(
     AnalyzerOptions {
         configuration,
         file_path,
+        ..AnalyzerOptions::default()
     }
 }
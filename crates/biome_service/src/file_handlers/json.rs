@@ -2,17 +2,18 @@ use super::{CodeActionsParams, DocumentFileSource, ExtensionHandler, ParseResult
 use crate::configuration::to_analyzer_rules;
 use crate::file_handlers::DebugCapabilities;
 use crate::file_handlers::{
-    AnalyzerCapabilities, Capabilities, FixAllParams, FormatterCapabilities, LintParams,
-    LintResults, ParserCapabilities,
+    is_diagnostic_error, unsupported_suppressions, AnalyzerCapabilities, Capabilities,
+    FixAllParams, FormatterCapabilities, LintParams, LintResults, ParserCapabilities,
 };
 use crate::settings::{
     FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
     WorkspaceSettingsHandle,
 };
 use crate::workspace::{
-    FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
+    FixAction, FixFileMode, FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult,
+    PullActionsResult,
 };
-use crate::WorkspaceError;
+use crate::{Matcher, WorkspaceError};
 use biome_analyze::options::PreferredQuote;
 use biome_analyze::{
     AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategories,
@@ -20,17 +21,21 @@ use biome_analyze::{
 use biome_configuration::linter::RuleSelector;
 use biome_configuration::PartialConfiguration;
 use biome_deserialize::json::deserialize_from_json_ast;
-use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
+use biome_diagnostics::{category, Applicability, Diagnostic, DiagnosticExt, Severity};
 use biome_formatter::{FormatError, IndentStyle, IndentWidth, LineEnding, LineWidth, Printed};
 use biome_fs::{BiomePath, ConfigName, ROME_JSON};
+use biome_js_analyze::RuleError;
 use biome_json_analyze::analyze;
-use biome_json_formatter::context::{JsonFormatOptions, TrailingCommas};
+use biome_json_analyze::package_json::sort_package_json;
+use biome_json_analyze::utils::is_package_json;
+use biome_json_formatter::context::{ArrayWrap, JsonFormatOptions, TrailingCommas};
 use biome_json_formatter::format_node;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::{JsonLanguage, JsonRoot, JsonSyntaxNode};
 use biome_parser::AnyParse;
 use biome_rowan::{AstNode, NodeCache};
 use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::borrow::Cow;
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
@@ -41,6 +46,8 @@ pub struct JsonFormatterSettings {
     pub indent_width: Option<IndentWidth>,
     pub indent_style: Option<IndentStyle>,
     pub trailing_commas: Option<TrailingCommas>,
+    pub array_wrap: Option<ArrayWrap>,
+    pub sort_package_json: Option<bool>,
     pub enabled: Option<bool>,
 }
 
@@ -51,9 +58,23 @@ pub struct JsonParserSettings {
     pub allow_trailing_commas: bool,
 }
 
+#[derive(Debug, Default)]
+pub struct JsonLinterSettings {
+    /// The JSON schemas mapped to the files in the configuration
+    pub schemas: Vec<JsonSchemaSettings>,
+}
+
+#[derive(Debug)]
+pub struct JsonSchemaSettings {
+    /// The files validated by the schema
+    pub included_files: Matcher,
+    /// The path of the schema
+    pub path: PathBuf,
+}
+
 impl ServiceLanguage for JsonLanguage {
     type FormatterSettings = JsonFormatterSettings;
-    type LinterSettings = ();
+    type LinterSettings = JsonLinterSettings;
     type OrganizeImportsSettings = ();
     type FormatOptions = JsonFormatOptions;
     type ParserSettings = JsonParserSettings;
@@ -99,7 +120,9 @@ impl ServiceLanguage for JsonLanguage {
                 .with_indent_style(indent_style)
                 .with_indent_width(indent_width)
                 .with_line_width(line_width)
-                .with_trailing_commas(language.trailing_commas.unwrap_or_default()),
+                .with_max_empty_lines(global.max_empty_lines.unwrap_or_default())
+                .with_trailing_commas(language.trailing_commas.unwrap_or_default())
+                .with_array_wrap(language.array_wrap.unwrap_or_default()),
         )
     }
 }
@@ -149,6 +172,7 @@ fn parse(
                 || optional_json_file_source.map_or(false, |x| x.allow_comments()),
             allow_trailing_commas: parser.allow_trailing_commas
                 || optional_json_file_source.map_or(false, |x| x.allow_trailing_commas()),
+            allow_json5: optional_json_file_source.map_or(false, |x| x.allow_json5()),
         },
     );
     let parse = biome_json_parser::parse_json_with_cache(text, cache, options);
@@ -196,11 +220,16 @@ fn format(
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
+    let should_sort = should_sort_package_json(path, &settings);
     let options = settings.format_options::<JsonLanguage>(path, document_file_source);
 
     tracing::debug!("Format with the following options: \n{}", options);
 
-    let tree = parse.syntax();
+    let tree = if should_sort {
+        sort_package_json(parse.tree()).syntax().clone()
+    } else {
+        parse.syntax()
+    };
     let formatted = format_node(options, &tree)?;
 
     match formatted.print() {
@@ -209,6 +238,24 @@ fn format(
     }
 }
 
+/// Returns `true` if the keys of the file are sorted before it's formatted:
+/// the file is a `package.json` and the option `json.formatter.sortPackageJson` is enabled for it.
+fn should_sort_package_json(path: &BiomePath, settings: &WorkspaceSettingsHandle) -> bool {
+    if !is_package_json(path) {
+        return false;
+    }
+    let settings = settings.settings();
+    settings.override_settings.override_json_sort_package_json(
+        path,
+        settings
+            .languages
+            .json
+            .formatter
+            .sort_package_json
+            .unwrap_or_default(),
+    )
+}
+
 fn format_range(
     path: &BiomePath,
     document_file_source: &DocumentFileSource,
@@ -332,50 +379,58 @@ fn lint(params: LintParams) -> LintResults {
             filter.categories = params.categories;
             let has_lint = filter.categories.contains(RuleCategories::LINT);
 
-            let (_, analyze_diagnostics) = analyze(&root, filter, &analyzer_options, |signal| {
-                if let Some(mut diagnostic) = signal.diagnostic() {
-                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
-                    if !has_lint && diagnostic.category() == Some(category!("suppressions/unused"))
-                    {
-                        return ControlFlow::<Never>::Continue(());
-                    }
+            let (_, analyze_diagnostics) = analyze(
+                &root,
+                filter,
+                &analyzer_options,
+                params.json_schema,
+                params.tsconfig_extends,
+                |signal| {
+                    if let Some(mut diagnostic) = signal.diagnostic() {
+                        // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                        if !has_lint
+                            && diagnostic.category() == Some(category!("suppressions/unused"))
+                        {
+                            return ControlFlow::<Never>::Continue(());
+                        }
 
-                    diagnostic_count += 1;
-
-                    // We do now check if the severity of the diagnostics should be changed.
-                    // The configuration allows to change the severity of the diagnostics emitted by rules.
-                    let severity = diagnostic
-                        .category()
-                        .filter(|category| category.name().starts_with("lint/"))
-                        .map_or_else(
-                            || diagnostic.severity(),
-                            |category| {
-                                rules
-                                    .as_ref()
-                                    .and_then(|rules| rules.get_severity_from_code(category))
-                                    .unwrap_or(Severity::Warning)
-                            },
-                        );
-
-                    if severity <= Severity::Error {
-                        errors += 1;
-                    }
+                        diagnostic_count += 1;
+
+                        // We do now check if the severity of the diagnostics should be changed.
+                        // The configuration allows to change the severity of the diagnostics emitted by rules.
+                        let severity = diagnostic
+                            .category()
+                            .filter(|category| category.name().starts_with("lint/"))
+                            .map_or_else(
+                                || diagnostic.severity(),
+                                |category| {
+                                    rules
+                                        .as_ref()
+                                        .and_then(|rules| rules.get_severity_from_code(category))
+                                        .unwrap_or(Severity::Warning)
+                                },
+                            );
+
+                        if severity <= Severity::Error {
+                            errors += 1;
+                        }
 
-                    if diagnostic_count <= params.max_diagnostics {
-                        for action in signal.actions() {
-                            if !action.is_suppression() {
-                                diagnostic = diagnostic.add_code_suggestion(action.into());
+                        if diagnostic_count <= params.max_diagnostics {
+                            for action in signal.actions() {
+                                if !action.is_suppression() {
+                                    diagnostic = diagnostic.add_code_suggestion(action.into());
+                                }
                             }
-                        }
 
-                        let error = diagnostic.with_severity(severity);
+                            let error = diagnostic.with_severity(severity);
 
-                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                            diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                        }
                     }
-                }
 
-                ControlFlow::<Never>::Continue(())
-            });
+                    ControlFlow::<Never>::Continue(())
+                },
+            );
 
             diagnostics.extend(
                 analyze_diagnostics
@@ -399,16 +454,137 @@ fn code_actions(_: CodeActionsParams) -> PullActionsResult {
 }
 
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    let tree: JsonRoot = params.parse.tree();
-    Ok(FixFileResult {
-        actions: vec![],
-        errors: 0,
-        skipped_suggested_fixes: 0,
-        code: tree.syntax().to_string(),
-    })
+    let FixAllParams {
+        parse,
+        rules,
+        fix_file_mode,
+        settings,
+        should_format,
+        biome_path,
+        mut filter,
+        json_schema,
+        tsconfig_extends,
+        document_file_source,
+        ..
+    } = params;
+
+    // The JSON analyzer doesn't read the suppression comments
+    if matches!(fix_file_mode, FixFileMode::ApplySuppressions) {
+        return Err(unsupported_suppressions(biome_path, document_file_source));
+    }
+
+    let mut tree: JsonRoot = parse.tree();
+    let mut actions = Vec::new();
+
+    filter.categories = RuleCategories::SYNTAX | RuleCategories::LINT;
+
+    let mut skipped_suggested_fixes = 0;
+    let mut errors: u16 = 0;
+    let analyzer_options = compute_analyzer_options(&settings, PathBuf::from(biome_path.as_path()));
+    loop {
+        let (action, _) = analyze(
+            &tree,
+            filter,
+            &analyzer_options,
+            json_schema.clone(),
+            tsconfig_extends.clone(),
+            |signal| {
+                let current_diagnostic = signal.diagnostic();
+
+                if let Some(diagnostic) = current_diagnostic.as_ref() {
+                    if is_diagnostic_error(diagnostic, rules) {
+                        errors += 1;
+                    }
+                }
+
+                for action in signal.actions() {
+                    // suppression actions should not be part of the fixes (safe or suggested)
+                    if action.is_suppression() {
+                        continue;
+                    }
+
+                    match fix_file_mode {
+                        FixFileMode::SafeFixes => {
+                            if action.applicability == Applicability::MaybeIncorrect {
+                                skipped_suggested_fixes += 1;
+                            }
+                            if action.applicability == Applicability::Always {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                        FixFileMode::SafeAndUnsafeFixes => {
+                            if matches!(
+                                action.applicability,
+                                Applicability::Always | Applicability::MaybeIncorrect
+                            ) {
+                                errors = errors.saturating_sub(1);
+                                return ControlFlow::Break(action);
+                            }
+                        }
+                        FixFileMode::ApplySuppressions => {}
+                    }
+                }
+
+                ControlFlow::Continue(())
+            },
+        );
+
+        match action {
+            Some(action) => {
+                if let (root, Some((range, _))) =
+                    action.mutation.commit_with_text_range_and_edit(true)
+                {
+                    tree = match JsonRoot::cast(root) {
+                        Some(tree) => tree,
+                        None => {
+                            return Err(WorkspaceError::RuleError(
+                                RuleError::ReplacedRootWithNonRootError {
+                                    rule_name: action.rule_name.map(|(group, rule)| {
+                                        (Cow::Borrowed(group), Cow::Borrowed(rule))
+                                    }),
+                                },
+                            ));
+                        }
+                    };
+                    actions.push(FixAction {
+                        rule_name: action
+                            .rule_name
+                            .map(|(group, rule)| (Cow::Borrowed(group), Cow::Borrowed(rule))),
+                        range,
+                    });
+                }
+            }
+            None => {
+                let code = if should_format {
+                    let should_sort = should_sort_package_json(biome_path, &settings);
+                    let options =
+                        settings.format_options::<JsonLanguage>(biome_path, &document_file_source);
+                    let tree = if should_sort {
+                        sort_package_json(tree).syntax().clone()
+                    } else {
+                        tree.syntax().clone()
+                    };
+                    format_node(options, &tree)?.print()?.into_code()
+                } else {
+                    tree.syntax().to_string()
+                };
+                return Ok(FixFileResult {
+                    code,
+                    skipped_suggested_fixes,
+                    actions,
+                    errors: errors.into(),
+                });
+            }
+        }
+    }
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
+fn organize_imports(
+    _path: &BiomePath,
+    parse: AnyParse,
+    _settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
     Ok(OrganizeImportsResult {
         code: parse.syntax::<JsonLanguage>().to_string(),
     })
@@ -427,5 +603,6 @@ fn compute_analyzer_options(
     AnalyzerOptions {
         configuration,
         file_path,
+        ..AnalyzerOptions::default()
     }
 }
@@ -0,0 +1,447 @@
+use super::{CodeActionsParams, DocumentFileSource, ExtensionHandler, ParseResult};
+use crate::configuration::to_analyzer_rules;
+use crate::file_handlers::DebugCapabilities;
+use crate::file_handlers::{
+    resolve_format_options, unsupported_suppressions, AnalyzerCapabilities, Capabilities,
+    EmbeddedFormatter, FixAllParams, FormatterCapabilities, LintParams, LintResults,
+    ParserCapabilities,
+};
+use crate::settings::{
+    FormatSettings, LanguageListSettings, LanguageSettings, OverrideSettings, ServiceLanguage,
+    Settings, WorkspaceSettingsHandle,
+};
+use crate::workspace::{
+    FixFileMode, FixFileResult, GetSyntaxTreeResult, OrganizeImportsResult, PullActionsResult,
+};
+use crate::WorkspaceError;
+use biome_analyze::options::PreferredQuote;
+use biome_analyze::{
+    AnalysisFilter, AnalyzerConfiguration, AnalyzerOptions, ControlFlow, Never, RuleCategories,
+};
+use biome_configuration::linter::RuleSelector;
+use biome_diagnostics::{category, Diagnostic, DiagnosticExt, Severity};
+use biome_formatter::{FormatError, LineEnding, LineWidth, Printed};
+use biome_fs::BiomePath;
+use biome_js_syntax::JsFileSource;
+use biome_json_syntax::JsonFileSource;
+use biome_markdown_analyze::analyze;
+use biome_markdown_formatter::context::{FormatEmbeddedCode, MarkdownFormatOptions, ProseWrap};
+use biome_markdown_formatter::format_node;
+use biome_markdown_parser::MarkdownParserOptions;
+use biome_markdown_syntax::{MarkdownDocument, MarkdownLanguage, MarkdownSyntaxNode};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, NodeCache};
+use biome_rowan::{TextRange, TextSize, TokenAtOffset};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MarkdownFormatterSettings {
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub prose_wrap: Option<ProseWrap>,
+    pub enabled: Option<bool>,
+}
+
+impl ServiceLanguage for MarkdownLanguage {
+    type FormatterSettings = MarkdownFormatterSettings;
+    type LinterSettings = ();
+    type OrganizeImportsSettings = ();
+    type FormatOptions = MarkdownFormatOptions;
+    type ParserSettings = ();
+    type EnvironmentSettings = ();
+
+    fn lookup_settings(language: &LanguageListSettings) -> &LanguageSettings<Self> {
+        &language.markdown
+    }
+
+    fn resolve_format_options(
+        global: &FormatSettings,
+        _overrides: &OverrideSettings,
+        language: &MarkdownFormatterSettings,
+        _path: &BiomePath,
+        _document_file_source: &DocumentFileSource,
+    ) -> Self::FormatOptions {
+        let line_width = if let Some(line_width) = language.line_width {
+            line_width
+        } else {
+            global.line_width.unwrap_or_default()
+        };
+
+        let line_ending = if let Some(line_ending) = language.line_ending {
+            line_ending
+        } else {
+            global.line_ending.unwrap_or_default()
+        };
+
+        MarkdownFormatOptions::new()
+            .with_line_ending(line_ending)
+            .with_line_width(line_width)
+            .with_prose_wrap(language.prose_wrap.unwrap_or_default())
+    }
+}
+
+/// Formats the code of the fenced code blocks whose language is supported by Biome,
+/// with the options of the files of that language.
+#[derive(Debug)]
+struct FencedCodeFormatter(EmbeddedFormatter);
+
+impl FencedCodeFormatter {
+    fn new(path: &BiomePath, settings: &Settings) -> Self {
+        let js_file_sources = [
+            JsFileSource::js_module(),
+            JsFileSource::jsx(),
+            JsFileSource::ts(),
+            JsFileSource::tsx(),
+        ];
+        Self(EmbeddedFormatter::new(path, settings, js_file_sources))
+    }
+}
+
+impl FormatEmbeddedCode for FencedCodeFormatter {
+    fn format_embedded_code(&self, language: &str, code: &str) -> Option<String> {
+        match language.to_ascii_lowercase().as_str() {
+            "js" | "javascript" | "mjs" | "cjs" => {
+                self.0.format_js(JsFileSource::js_module(), code)
+            }
+            "jsx" => self.0.format_js(JsFileSource::jsx(), code),
+            "ts" | "typescript" | "mts" | "cts" => self.0.format_js(JsFileSource::ts(), code),
+            "tsx" => self.0.format_js(JsFileSource::tsx(), code),
+            "json" => self.0.format_json(JsonFileSource::json(), code),
+            "jsonc" => self
+                .0
+                .format_json(JsonFileSource::json_allow_comments(), code),
+            "json5" => self.0.format_json(JsonFileSource::json5(), code),
+            "css" => self.0.format_css(code),
+            _ => None,
+        }
+    }
+}
+
+/// Resolves the options of the Markdown formatter, which formats the fenced code blocks
+/// with the formatters of their language.
+pub(crate) fn format_options(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    settings: &Settings,
+) -> MarkdownFormatOptions {
+    let fenced_code_formatter = FencedCodeFormatter::new(path, settings);
+    resolve_format_options::<MarkdownLanguage>(settings, path, *document_file_source)
+        .with_embedded_code_formatter(fenced_code_formatter)
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MarkdownFileHandler;
+
+impl ExtensionHandler for MarkdownFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities { parse: Some(parse) },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(debug_formatter_ir),
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: Some(code_actions),
+                rename: None,
+                fix_all: Some(fix_all),
+                organize_imports: Some(organize_imports),
+            },
+            formatter: FormatterCapabilities {
+                format: Some(format),
+                format_range: Some(format_range),
+                format_on_type: Some(format_on_type),
+            },
+        }
+    }
+}
+
+/// Parses a Markdown document, with the syntax of MDX when the file is an MDX file
+pub(crate) fn parse(
+    _biome_path: &BiomePath,
+    file_source: DocumentFileSource,
+    text: &str,
+    _settings: WorkspaceSettingsHandle,
+    cache: &mut NodeCache,
+) -> ParseResult {
+    let options = match file_source.to_markdown_file_source() {
+        Some(markdown) if markdown.is_mdx() => MarkdownParserOptions::default().mdx(),
+        _ => MarkdownParserOptions::default(),
+    };
+    let parse = biome_markdown_parser::parse_markdown_with_cache(text, cache, options);
+    let root = parse.syntax();
+    let diagnostics = parse.into_diagnostics();
+
+    ParseResult {
+        any_parse: AnyParse::new(
+            // SAFETY: the parser should always return a root node
+            root.as_send().unwrap(),
+            diagnostics,
+        ),
+        language: Some(file_source),
+    }
+}
+
+pub(crate) fn debug_syntax_tree(_rome_path: &BiomePath, parse: AnyParse) -> GetSyntaxTreeResult {
+    let syntax: MarkdownSyntaxNode = parse.syntax();
+    let tree: MarkdownDocument = parse.tree();
+    GetSyntaxTreeResult {
+        cst: format!("{syntax:#?}"),
+        ast: format!("{tree:#?}"),
+    }
+}
+
+pub(crate) fn debug_formatter_ir(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<String, WorkspaceError> {
+    let options = format_options(path, document_file_source, settings.settings());
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    let root_element = formatted.into_document();
+    Ok(root_element.to_string())
+}
+
+#[tracing::instrument(level = "debug", skip(parse, settings))]
+pub(crate) fn format(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<Printed, WorkspaceError> {
+    let options = format_options(path, document_file_source, settings.settings());
+
+    tracing::debug!("Format with the following options: \n{}", options);
+
+    let tree = parse.syntax();
+    let formatted = format_node(options, &tree)?;
+
+    match formatted.print() {
+        Ok(printed) => Ok(printed),
+        Err(error) => Err(WorkspaceError::FormatError(error.into())),
+    }
+}
+
+pub(crate) fn format_range(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    range: TextRange,
+) -> Result<Printed, WorkspaceError> {
+    let options = format_options(path, document_file_source, settings.settings());
+
+    let tree = parse.syntax();
+    let printed = biome_markdown_formatter::format_range(options, &tree, range)?;
+    Ok(printed)
+}
+
+pub(crate) fn format_on_type(
+    path: &BiomePath,
+    document_file_source: &DocumentFileSource,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+    offset: TextSize,
+) -> Result<Printed, WorkspaceError> {
+    let options = format_options(path, document_file_source, settings.settings());
+
+    let tree = parse.syntax();
+
+    let range = tree.text_range();
+    if offset < range.start() || offset > range.end() {
+        return Err(WorkspaceError::FormatError(FormatError::RangeError {
+            input: TextRange::at(offset, TextSize::from(0)),
+            tree: range,
+        }));
+    }
+
+    let token = match tree.token_at_offset(offset) {
+        // File is empty, do nothing
+        TokenAtOffset::None => panic!("empty file"),
+        TokenAtOffset::Single(token) => token,
+        // The cursor should be right after the closing character that was just typed,
+        // select the previous token as the correct one
+        TokenAtOffset::Between(token, _) => token,
+    };
+
+    let root_node = match token.parent() {
+        Some(node) => node,
+        None => panic!("found a token with no parent"),
+    };
+
+    let printed = biome_markdown_formatter::format_sub_tree(options, &root_node)?;
+    Ok(printed)
+}
+
+fn lint(params: LintParams) -> LintResults {
+    tracing::debug_span!("Linting Markdown file", path =? params.path, language =? params.language)
+        .in_scope(move || {
+            let root: MarkdownDocument = params.parse.tree();
+            let mut diagnostics = params.parse.into_diagnostics();
+            let settings = params.settings.settings();
+
+            let mut diagnostic_count = diagnostics.len() as u32;
+            let mut errors = diagnostics
+                .iter()
+                .filter(|diag| diag.severity() <= Severity::Error)
+                .count();
+
+            let skipped_diagnostics = diagnostic_count - diagnostics.len() as u32;
+
+            let mut rules = settings.as_rules(params.path.as_path());
+            let rule_filter_list = if let Some(rule) = params.rule {
+                // We execute a single rule or group because the `--rule` filter is specified.
+                match rule {
+                    RuleSelector::Group(group) => {
+                        if let Some(rules) = rules.as_mut() {
+                            // Ensure that the recommended field is not set to `false`.
+                            rules.to_mut().set_recommended();
+                        }
+                        rules
+                            .as_ref()
+                            .map(|rules| rules.as_enabled_rules())
+                            .unwrap_or_default()
+                            .into_iter()
+                            .filter(|rule_filter| rule_filter.group() == group.as_str())
+                            .collect()
+                    }
+                    RuleSelector::Rule(group, rule_name) => {
+                        if let Some(rules) = rules.as_mut() {
+                            // Set the severity level of the rule to its default.
+                            rules.to_mut().set_default_severity(group, rule_name);
+                        }
+                        vec![rule.into()]
+                    }
+                }
+            } else {
+                let rule_filter_list = rules
+                    .as_ref()
+                    .map(|rules| rules.as_enabled_rules())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                rule_filter_list
+            };
+
+            let analyzer_options =
+                compute_analyzer_options(&params.settings, PathBuf::from(params.path.as_path()));
+            let mut filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
+            filter.categories = params.categories;
+            let has_lint = filter.categories.contains(RuleCategories::LINT);
+
+            let (_, analyze_diagnostics) = analyze(&root, filter, &analyzer_options, |signal| {
+                if let Some(mut diagnostic) = signal.diagnostic() {
+                    // Do not report unused suppression comment diagnostics if this is a syntax-only analyzer pass
+                    if !has_lint && diagnostic.category() == Some(category!("suppressions/unused"))
+                    {
+                        return ControlFlow::<Never>::Continue(());
+                    }
+
+                    diagnostic_count += 1;
+
+                    // We do now check if the severity of the diagnostics should be changed.
+                    // The configuration allows to change the severity of the diagnostics emitted by rules.
+                    let severity = diagnostic
+                        .category()
+                        .filter(|category| category.name().starts_with("lint/"))
+                        .map_or_else(
+                            || diagnostic.severity(),
+                            |category| {
+                                rules
+                                    .as_ref()
+                                    .and_then(|rules| rules.get_severity_from_code(category))
+                                    .unwrap_or(Severity::Warning)
+                            },
+                        );
+
+                    if severity <= Severity::Error {
+                        errors += 1;
+                    }
+
+                    if diagnostic_count <= params.max_diagnostics {
+                        for action in signal.actions() {
+                            if !action.is_suppression() {
+                                diagnostic = diagnostic.add_code_suggestion(action.into());
+                            }
+                        }
+
+                        let error = diagnostic.with_severity(severity);
+
+                        diagnostics.push(biome_diagnostics::serde::Diagnostic::new(error));
+                    }
+                }
+
+                ControlFlow::<Never>::Continue(())
+            });
+
+            diagnostics.extend(
+                analyze_diagnostics
+                    .into_iter()
+                    .map(biome_diagnostics::serde::Diagnostic::new)
+                    .collect::<Vec<_>>(),
+            );
+
+            LintResults {
+                diagnostics,
+                errors,
+                skipped_diagnostics,
+            }
+        })
+}
+
+fn code_actions(_: CodeActionsParams) -> PullActionsResult {
+    PullActionsResult {
+        actions: Vec::new(),
+    }
+}
+
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    // The Markdown analyzer doesn't read the suppression comments
+    if matches!(params.fix_file_mode, FixFileMode::ApplySuppressions) {
+        return Err(unsupported_suppressions(
+            params.biome_path,
+            params.document_file_source,
+        ));
+    }
+
+    let tree: MarkdownDocument = params.parse.tree();
+    Ok(FixFileResult {
+        actions: vec![],
+        errors: 0,
+        skipped_suggested_fixes: 0,
+        code: tree.syntax().to_string(),
+    })
+}
+
+fn organize_imports(
+    _path: &BiomePath,
+    parse: AnyParse,
+    _settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    Ok(OrganizeImportsResult {
+        code: parse.syntax::<MarkdownLanguage>().to_string(),
+    })
+}
+
+fn compute_analyzer_options(
+    settings: &WorkspaceSettingsHandle,
+    file_path: PathBuf,
+) -> AnalyzerOptions {
+    let configuration = AnalyzerConfiguration {
+        rules: to_analyzer_rules(settings.settings(), file_path.as_path()),
+        globals: vec![],
+        preferred_quote: PreferredQuote::Double,
+        jsx_runtime: Default::default(),
+    };
+    AnalyzerOptions {
+        configuration,
+        file_path,
+        ..AnalyzerOptions::default()
+    }
+}
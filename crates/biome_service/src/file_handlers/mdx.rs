@@ -0,0 +1,305 @@
+use crate::file_handlers::astro::padding;
+use crate::file_handlers::{
+    javascript, markdown, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults,
+    ParserCapabilities,
+};
+use crate::settings::WorkspaceSettingsHandle;
+use crate::workspace::{FixFileResult, OrganizeImportsResult, PullActionsResult};
+use crate::WorkspaceError;
+use biome_fs::BiomePath;
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{
+    AnyJsExpression, AnyJsModuleItem, AnyJsRoot, AnyJsStatement, JsFileSource, TextRange, TextSize,
+};
+use biome_markdown_parser::MarkdownParserOptions;
+use biome_markdown_syntax::{MarkdownSyntaxNode, MarkdownSyntaxToken, MdxEsmBlock, MdxJsxBlock};
+use biome_parser::AnyParse;
+use biome_rowan::{AstNode, AstNodeList};
+use biome_text_edit::{CompressedOp, DiffOp, TextEdit};
+use std::ops::Range;
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct MdxFileHandler;
+
+impl ExtensionHandler for MdxFileHandler {
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            parser: ParserCapabilities {
+                parse: Some(markdown::parse),
+            },
+            debug: DebugCapabilities {
+                debug_syntax_tree: Some(markdown::debug_syntax_tree),
+                debug_control_flow: None,
+                debug_formatter_ir: Some(markdown::debug_formatter_ir),
+            },
+            analyzer: AnalyzerCapabilities {
+                lint: Some(lint),
+                code_actions: Some(code_actions),
+                rename: None,
+                fix_all: Some(fix_all),
+                organize_imports: Some(organize_imports),
+            },
+            formatter: FormatterCapabilities {
+                format: Some(markdown::format),
+                format_range: Some(markdown::format_range),
+                format_on_type: Some(markdown::format_on_type),
+            },
+        }
+    }
+}
+
+/// The `import`/`export` statements and the JSX of an MDX document, parsed with the JavaScript parser
+struct MdxModule {
+    parse: AnyParse,
+    /// The code of the module, the text of the document where everything but the blocks is whitespace
+    code: String,
+    /// The ranges of the blocks whose code is in the module, in the order of the document
+    code_ranges: Vec<TextRange>,
+}
+
+impl MdxModule {
+    /// Parses the blocks of an MDX document, so that the ranges of their nodes are the offsets in the whole document.
+    ///
+    /// The text around the blocks is replaced by whitespace, only its line breaks are kept.
+    /// When `with_jsx` is `true`, the JSX blocks are parsed along with the `import`/`export` statements,
+    /// so that the rules see the components that the document uses.
+    /// The diagnostics of the parser of the document are reported along with the ones of the module.
+    fn parse_in_document(parse: &AnyParse, with_jsx: bool) -> Self {
+        let root: MarkdownSyntaxNode = parse.syntax();
+        let text = root.to_string();
+
+        let mut code = padding(&text);
+        let mut code_ranges = Vec::new();
+        for node in root.descendants() {
+            if let Some(block) = MdxEsmBlock::cast_ref(&node) {
+                if let Ok(token) = block.value_token() {
+                    let range = token.text_trimmed_range();
+                    code.replace_range(Range::<usize>::from(range), token.text_trimmed());
+                    code_ranges.push(range);
+                }
+            } else if let Some(block) = MdxJsxBlock::cast_ref(&node).filter(|_| with_jsx) {
+                let Ok(token) = block.value_token() else {
+                    continue;
+                };
+                if write_jsx_block(&mut code, &token) {
+                    code_ranges.push(token.text_trimmed_range());
+                } else {
+                    write_component_names(&mut code, &token);
+                }
+            }
+        }
+
+        let mut diagnostics = parse.diagnostics().to_vec();
+        let module_parse =
+            biome_js_parser::parse(&code, JsFileSource::jsx(), JsParserOptions::default());
+        let root = module_parse.syntax();
+        diagnostics.extend(module_parse.into_diagnostics());
+
+        Self {
+            // SAFETY: the parser should always return a root node
+            parse: AnyParse::new(root.as_send().unwrap(), diagnostics),
+            code,
+            code_ranges,
+        }
+    }
+}
+
+/// Writes a JSX block that is a single element, such as `<Chart year={2024} />`, as a statement of the module.
+///
+/// The statement that precedes the block is closed with a semicolon, which takes the place
+/// of the whitespace at the end of its line, otherwise the `<` of the element would continue it.
+/// Returns `false` when the block isn't written.
+fn write_jsx_block(code: &mut String, token: &MarkdownSyntaxToken) -> bool {
+    if !is_jsx_element(token.text_trimmed()) {
+        return false;
+    }
+    let range = Range::<usize>::from(token.text_trimmed_range());
+    let before = code[..range.start].trim_end_matches(['\n', '\r']);
+    match before.as_bytes().last() {
+        None | Some(b';') => {}
+        Some(b' ') => {
+            let end = before.len();
+            code.replace_range(end - 1..end, ";");
+        }
+        Some(_) => return false,
+    }
+
+    code.replace_range(range, token.text_trimmed());
+    true
+}
+
+/// Returns `true` when the code of a JSX block is a single element or fragment.
+///
+/// The blocks whose content is Markdown, such as `<Note>\n  Some **text**\n</Note>`, aren't always JSX.
+fn is_jsx_element(code: &str) -> bool {
+    let parse = biome_js_parser::parse(code, JsFileSource::jsx(), JsParserOptions::default());
+    if parse.has_errors() {
+        return false;
+    }
+    let AnyJsRoot::JsModule(module) = parse.tree() else {
+        return false;
+    };
+    let mut items = module.items().iter();
+    let is_element = match items.next() {
+        Some(AnyJsModuleItem::AnyJsStatement(AnyJsStatement::JsExpressionStatement(statement))) => {
+            matches!(
+                statement.expression(),
+                Ok(AnyJsExpression::JsxTagExpression(_))
+            )
+        }
+        _ => false,
+    };
+
+    is_element && items.next().is_none() && module.directives().is_empty()
+}
+
+/// Writes the names of the components of a JSX block as statements,
+/// after a semicolon that takes the place of their `<`, such as `;Note` for `<Note>`.
+///
+/// The names of the components start with an uppercase letter, the other elements are HTML elements.
+fn write_component_names(code: &mut String, token: &MarkdownSyntaxToken) {
+    let text = token.text_trimmed();
+    let start = usize::from(token.text_trimmed_range().start());
+
+    for (index, _) in text.match_indices('<') {
+        let name = &text[index + 1..];
+        let length = name
+            .find(|char: char| !(char.is_ascii_alphanumeric() || matches!(char, '_' | '$' | '.')))
+            .unwrap_or(name.len());
+        let name = name[..length].trim_end_matches('.');
+        if !name.starts_with(|char: char| char.is_ascii_uppercase()) {
+            continue;
+        }
+
+        let name_start = start + index + 1;
+        code.replace_range(name_start - 1..name_start, ";");
+        code.replace_range(name_start..name_start + name.len(), name);
+    }
+}
+
+/// Applies the changes of `fixed`, the fixed code of the module, to the blocks of `text`, the text of the document.
+///
+/// The changes outside the blocks only touch the whitespace that replaces the rest of the document,
+/// the text of the document is kept there.
+pub(super) fn apply_block_changes(
+    text: &str,
+    code: &str,
+    fixed: &str,
+    code_ranges: &[TextRange],
+) -> String {
+    let diff = TextEdit::from_unicode_words(code, fixed);
+    let is_in_block = |offset: TextSize| {
+        code_ranges
+            .iter()
+            .any(|range| range.contains_inclusive(offset))
+    };
+
+    let mut output = String::with_capacity(text.len());
+    let mut position = TextSize::from(0);
+    for op in diff.iter() {
+        match op {
+            CompressedOp::DiffOp(DiffOp::Equal { range }) => {
+                let end = position + range.len();
+                output.push_str(&text[TextRange::new(position, end)]);
+                position = end;
+            }
+            CompressedOp::DiffOp(DiffOp::Insert { range }) => {
+                if is_in_block(position) {
+                    output.push_str(diff.get_text(*range));
+                }
+            }
+            CompressedOp::DiffOp(DiffOp::Delete { range }) => {
+                let end = position + range.len();
+                let deleted = &text[TextRange::new(position, end)];
+                for (index, char) in deleted.char_indices() {
+                    let offset = position + TextSize::from(index as u32);
+                    if !code_ranges.iter().any(|block| block.contains(offset)) {
+                        output.push(char);
+                    }
+                }
+                position = end;
+            }
+            CompressedOp::EqualLines { line_count } => {
+                let line_break_count = line_count.get() as usize + 1;
+                let length: TextSize = code[usize::from(position)..]
+                    .split_inclusive('\n')
+                    .take(line_break_count)
+                    .map(TextSize::of)
+                    .sum();
+                let end = position + length;
+                output.push_str(&text[TextRange::new(position, end)]);
+                position = end;
+            }
+        }
+    }
+
+    output
+}
+
+/// Lints the `import`/`export` statements and the JSX of the document with the JavaScript rules
+pub(crate) fn lint(params: LintParams) -> LintResults {
+    let module = MdxModule::parse_in_document(&params.parse, true);
+    javascript::lint(LintParams {
+        parse: module.parse,
+        language: JsFileSource::jsx().into(),
+        ..params
+    })
+}
+
+pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
+    let module = MdxModule::parse_in_document(&params.parse, true);
+    javascript::code_actions(CodeActionsParams {
+        parse: module.parse,
+        language: JsFileSource::jsx().into(),
+        ..params
+    })
+}
+
+/// Applies the fixes to the blocks of the MDX document, and formats the whole document when `should_format` is `true`.
+fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
+    let module = MdxModule::parse_in_document(&params.parse, true);
+    let root: MarkdownSyntaxNode = params.parse.syntax();
+    let text = root.to_string();
+    let format_options = params.should_format.then(|| {
+        markdown::format_options(
+            params.biome_path,
+            &params.document_file_source,
+            params.settings.settings(),
+        )
+    });
+
+    let result = javascript::fix_all(FixAllParams {
+        parse: module.parse,
+        should_format: false,
+        document_file_source: JsFileSource::jsx().into(),
+        ..params
+    })?;
+
+    let mut code = apply_block_changes(&text, &module.code, &result.code, &module.code_ranges);
+    if let Some(options) = format_options {
+        let parse =
+            biome_markdown_parser::parse_markdown(&code, MarkdownParserOptions::default().mdx());
+        code = biome_markdown_formatter::format_node(options, &parse.syntax())?
+            .print()?
+            .into_code();
+    }
+
+    Ok(FixFileResult { code, ..result })
+}
+
+/// Sorts the imports of the `import`/`export` blocks of the document, the JSX blocks are kept as is
+fn organize_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let module = MdxModule::parse_in_document(&parse, false);
+    let root: MarkdownSyntaxNode = parse.syntax();
+    let text = root.to_string();
+    let result = javascript::organize_imports(path, module.parse, settings)?;
+
+    Ok(OrganizeImportsResult {
+        code: apply_block_changes(&text, &module.code, &result.code, &module.code_ranges),
+    })
+}
@@ -1,13 +1,14 @@
 use self::{
-    css::CssFileHandler, javascript::JsFileHandler, json::JsonFileHandler,
-    unknown::UnknownFileHandler,
+    css::CssFileHandler, graphql::GraphqlFileHandler, html::HtmlFileHandler,
+    javascript::JsFileHandler, json::JsonFileHandler, markdown::MarkdownFileHandler,
+    mdx::MdxFileHandler, toml::TomlFileHandler, unknown::UnknownFileHandler, yaml::YamlFileHandler,
 };
-pub use crate::file_handlers::astro::{AstroFileHandler, ASTRO_FENCE};
-pub use crate::file_handlers::svelte::{SvelteFileHandler, SVELTE_FENCE};
-pub use crate::file_handlers::vue::{VueFileHandler, VUE_FENCE};
+pub use crate::file_handlers::astro::{AstroBlock, AstroBlockKind, AstroFileHandler};
+pub use crate::file_handlers::svelte::{SvelteBlock, SvelteBlockKind, SvelteFileHandler};
+pub use crate::file_handlers::vue::{VueBlock, VueBlockKind, VueFileHandler};
 use crate::workspace::{FixFileMode, OrganizeImportsResult};
 use crate::{
-    settings::WorkspaceSettingsHandle,
+    settings::{ServiceLanguage, Settings, WorkspaceSettingsHandle},
     workspace::{FixFileResult, GetSyntaxTreeResult, PullActionsResult, RenameResult},
     WorkspaceError,
 };
@@ -17,27 +18,57 @@ use biome_configuration::Rules;
 use biome_console::fmt::Formatter;
 use biome_console::markup;
 use biome_css_formatter::can_format_css_yet;
-use biome_css_syntax::CssFileSource;
+use biome_css_formatter::context::CssFormatOptions;
+use biome_css_parser::CssParserOptions;
+use biome_css_syntax::{CssFileSource, CssLanguage};
 use biome_diagnostics::{Diagnostic, Severity};
-use biome_formatter::Printed;
+use biome_formatter::{FormatOptions, IndentStyle, Printed};
 use biome_fs::BiomePath;
-use biome_js_parser::{parse, JsParserOptions};
-use biome_js_syntax::{EmbeddingKind, JsFileSource, Language, TextRange, TextSize};
-use biome_json_syntax::JsonFileSource;
+use biome_graphql_formatter::context::GraphqlFormatOptions;
+use biome_graphql_syntax::{GraphqlFileSource, GraphqlLanguage};
+use biome_html_formatter::context::HtmlFormatOptions;
+use biome_html_parser::HtmlParserOptions;
+use biome_html_syntax::{HtmlFileSource, HtmlLanguage};
+use biome_js_analyze::ComponentTemplate;
+use biome_js_formatter::context::JsFormatOptions;
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, TextRange, TextSize};
+use biome_json_analyze::json_schema::JsonSchema;
+use biome_json_analyze::tsconfig::TsconfigExtends;
+use biome_json_formatter::context::JsonFormatOptions;
+use biome_json_parser::JsonParserOptions;
+use biome_json_syntax::{JsonFileSource, JsonLanguage};
+use biome_markdown_syntax::MarkdownFileSource;
 use biome_parser::AnyParse;
-use biome_project::PackageJson;
+use biome_project::{PackageJson, TailwindConfig, TsConfigJson};
 use biome_rowan::{FileSourceError, NodeCache};
-pub use javascript::JsFormatterSettings;
+use biome_toml_syntax::TomlFileSource;
+use biome_yaml_syntax::YamlFileSource;
+pub use graphql::GraphqlFormatterSettings;
+pub use html::HtmlFormatterSettings;
+pub(crate) use javascript::domain_rules;
+pub use javascript::{JsFormatterSettings, JsOrganizeImportsSettings};
+pub use json::{JsonLinterSettings, JsonSchemaSettings};
+pub use markdown::MarkdownFormatterSettings;
 use std::ffi::OsStr;
 use std::path::Path;
+use std::sync::Arc;
+pub use toml::TomlFormatterSettings;
+pub use yaml::YamlFormatterSettings;
 
 mod astro;
 mod css;
+mod graphql;
+mod html;
 mod javascript;
 mod json;
+mod markdown;
+mod mdx;
 mod svelte;
+mod toml;
 mod unknown;
 mod vue;
+mod yaml;
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
@@ -47,6 +78,11 @@ pub enum DocumentFileSource {
     Js(JsFileSource),
     Json(JsonFileSource),
     Css(CssFileSource),
+    Yaml(YamlFileSource),
+    Toml(TomlFileSource),
+    Markdown(MarkdownFileSource),
+    Graphql(GraphqlFileSource),
+    Html(HtmlFileSource),
     #[default]
     Unknown,
 }
@@ -69,6 +105,36 @@ impl From<CssFileSource> for DocumentFileSource {
     }
 }
 
+impl From<YamlFileSource> for DocumentFileSource {
+    fn from(value: YamlFileSource) -> Self {
+        Self::Yaml(value)
+    }
+}
+
+impl From<TomlFileSource> for DocumentFileSource {
+    fn from(value: TomlFileSource) -> Self {
+        Self::Toml(value)
+    }
+}
+
+impl From<MarkdownFileSource> for DocumentFileSource {
+    fn from(value: MarkdownFileSource) -> Self {
+        Self::Markdown(value)
+    }
+}
+
+impl From<GraphqlFileSource> for DocumentFileSource {
+    fn from(value: GraphqlFileSource) -> Self {
+        Self::Graphql(value)
+    }
+}
+
+impl From<HtmlFileSource> for DocumentFileSource {
+    fn from(value: HtmlFileSource) -> Self {
+        Self::Html(value)
+    }
+}
+
 impl From<&Path> for DocumentFileSource {
     fn from(path: &Path) -> Self {
         Self::from_path(path)
@@ -86,6 +152,18 @@ impl DocumentFileSource {
         if let Ok(file_source) = CssFileSource::try_from_well_known(file_name) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_well_known(file_name) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = TomlFileSource::try_from_well_known(file_name) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_well_known(file_name) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = HtmlFileSource::try_from_well_known(file_name) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownFileName(file_name.into()))
     }
 
@@ -105,6 +183,21 @@ impl DocumentFileSource {
         if let Ok(file_source) = CssFileSource::try_from_extension(extension) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = TomlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = GraphqlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = HtmlFileSource::try_from_extension(extension) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownExtension(
             Default::default(),
             extension.into(),
@@ -127,6 +220,21 @@ impl DocumentFileSource {
         if let Ok(file_source) = CssFileSource::try_from_language_id(language_id) {
             return Ok(file_source.into());
         }
+        if let Ok(file_source) = YamlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = TomlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = MarkdownFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = GraphqlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
+        if let Ok(file_source) = HtmlFileSource::try_from_language_id(language_id) {
+            return Ok(file_source.into());
+        }
         Err(FileSourceError::UnknownLanguageId(language_id.into()))
     }
 
@@ -190,6 +298,85 @@ impl DocumentFileSource {
         Self::try_from_path(path).map_or(DocumentFileSource::Unknown, |file_source| file_source)
     }
 
+    /// Guesses the document file source from the content of a file whose path doesn't have an extension,
+    /// such as the code piped from `stdin`:
+    /// - a shebang that runs `node`, `nodejs`, `bun` or `deno` is JavaScript, one that runs `ts-node` or `tsx` is TypeScript,
+    ///   the other shebangs are unknown;
+    /// - a content that starts with `{` or `[` is JSON;
+    /// - a content that starts with `<!DOCTYPE html` or `<html` is HTML;
+    /// - a content that starts with `<?xml` is unknown;
+    /// - any other content, JSX included, is JavaScript.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use biome_js_syntax::JsFileSource;
+    /// use biome_json_syntax::JsonFileSource;
+    /// use biome_service::workspace::DocumentFileSource;
+    /// assert_eq!(
+    ///     DocumentFileSource::from_content("#!/usr/bin/env node\nrun();"),
+    ///     JsFileSource::jsx().into()
+    /// );
+    /// assert_eq!(
+    ///     DocumentFileSource::from_content("#!/usr/bin/env -S npx tsx\nrun();"),
+    ///     JsFileSource::ts().into()
+    /// );
+    /// assert_eq!(
+    ///     DocumentFileSource::from_content("#!/bin/sh\necho"),
+    ///     DocumentFileSource::Unknown
+    /// );
+    /// assert_eq!(
+    ///     DocumentFileSource::from_content("  { \"a\": 1 }"),
+    ///     JsonFileSource::json().into()
+    /// );
+    /// assert_eq!(
+    ///     DocumentFileSource::from_content("<?xml version=\"1.0\"?>"),
+    ///     DocumentFileSource::Unknown
+    /// );
+    /// assert_eq!(
+    ///     DocumentFileSource::from_content("<div>{a}</div>"),
+    ///     JsFileSource::jsx().into()
+    /// );
+    /// ```
+    pub fn from_content(content: &str) -> Self {
+        let content = content.trim_start_matches('\u{feff}');
+
+        if let Some(shebang) = content.strip_prefix("#!") {
+            // `#!/usr/bin/node`, `#!/usr/bin/env node` or `#!/usr/bin/env -S npx tsx`
+            let interpreter = shebang
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(|word| word.rsplit('/').next().unwrap_or(word))
+                .find(|word| !matches!(*word, "env" | "npx") && !word.starts_with('-'));
+
+            return match interpreter {
+                Some("node" | "nodejs" | "bun" | "deno") => JsFileSource::jsx().into(),
+                Some("ts-node" | "tsx") => JsFileSource::ts().into(),
+                _ => DocumentFileSource::Unknown,
+            };
+        }
+
+        let content = content.trim_start();
+        let starts_with = |prefix: &str| {
+            content
+                .as_bytes()
+                .get(..prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+        };
+
+        if content.starts_with(['{', '[']) {
+            JsonFileSource::json().into()
+        } else if starts_with("<!doctype html") || starts_with("<html") {
+            HtmlFileSource::html().into()
+        } else if starts_with("<?xml") {
+            DocumentFileSource::Unknown
+        } else {
+            JsFileSource::jsx().into()
+        }
+    }
+
     /// Returns the document file source if it's not unknown, otherwise returns `other`.
     ///
     /// # Examples
@@ -234,6 +421,26 @@ impl DocumentFileSource {
         matches!(self, DocumentFileSource::Css(_))
     }
 
+    pub const fn is_yaml_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Yaml(_))
+    }
+
+    pub const fn is_toml_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Toml(_))
+    }
+
+    pub const fn is_markdown_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Markdown(_))
+    }
+
+    pub const fn is_graphql_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Graphql(_))
+    }
+
+    pub const fn is_html_like(&self) -> bool {
+        matches!(self, DocumentFileSource::Html(_))
+    }
+
     pub fn to_js_file_source(&self) -> Option<JsFileSource> {
         match self {
             DocumentFileSource::Js(file_source) => Some(*file_source),
@@ -255,16 +462,52 @@ impl DocumentFileSource {
         }
     }
 
-    pub fn can_parse(path: &Path, content: &str) -> bool {
+    pub fn to_yaml_file_source(&self) -> Option<YamlFileSource> {
+        match self {
+            DocumentFileSource::Yaml(yaml) => Some(*yaml),
+            _ => None,
+        }
+    }
+
+    pub fn to_toml_file_source(&self) -> Option<TomlFileSource> {
+        match self {
+            DocumentFileSource::Toml(toml) => Some(*toml),
+            _ => None,
+        }
+    }
+
+    pub fn to_markdown_file_source(&self) -> Option<MarkdownFileSource> {
+        match self {
+            DocumentFileSource::Markdown(markdown) => Some(*markdown),
+            _ => None,
+        }
+    }
+
+    pub fn to_graphql_file_source(&self) -> Option<GraphqlFileSource> {
+        match self {
+            DocumentFileSource::Graphql(graphql) => Some(*graphql),
+            _ => None,
+        }
+    }
+
+    pub fn to_html_file_source(&self) -> Option<HtmlFileSource> {
+        match self {
+            DocumentFileSource::Html(html) => Some(*html),
+            _ => None,
+        }
+    }
+
+    pub fn can_parse(path: &Path, _content: &str) -> bool {
         let file_source = DocumentFileSource::from(path);
         match file_source {
-            DocumentFileSource::Js(js) => match js.as_embedding_kind() {
-                EmbeddingKind::Astro => ASTRO_FENCE.is_match(content),
-                EmbeddingKind::Vue => VUE_FENCE.is_match(content),
-                EmbeddingKind::Svelte => SVELTE_FENCE.is_match(content),
-                EmbeddingKind::None => true,
-            },
-            DocumentFileSource::Json(_) | DocumentFileSource::Css(_) => true,
+            DocumentFileSource::Js(_)
+            | DocumentFileSource::Json(_)
+            | DocumentFileSource::Css(_)
+            | DocumentFileSource::Yaml(_)
+            | DocumentFileSource::Toml(_)
+            | DocumentFileSource::Markdown(_)
+            | DocumentFileSource::Graphql(_)
+            | DocumentFileSource::Html(_) => true,
             DocumentFileSource::Unknown => false,
         }
     }
@@ -288,13 +531,26 @@ impl biome_console::fmt::Display for DocumentFileSource {
                 }
             }
             DocumentFileSource::Json(json) => {
-                if json.allow_comments() {
+                if json.allow_json5() {
+                    fmt.write_markup(markup! { "JSON5" })
+                } else if json.allow_comments() {
                     fmt.write_markup(markup! { "JSONC" })
                 } else {
                     fmt.write_markup(markup! { "JSON" })
                 }
             }
             DocumentFileSource::Css(_) => fmt.write_markup(markup! { "CSS" }),
+            DocumentFileSource::Yaml(_) => fmt.write_markup(markup! { "YAML" }),
+            DocumentFileSource::Toml(_) => fmt.write_markup(markup! { "TOML" }),
+            DocumentFileSource::Markdown(markdown) => {
+                if markdown.is_mdx() {
+                    fmt.write_markup(markup! { "MDX" })
+                } else {
+                    fmt.write_markup(markup! { "Markdown" })
+                }
+            }
+            DocumentFileSource::Graphql(_) => fmt.write_markup(markup! { "GraphQL" }),
+            DocumentFileSource::Html(_) => fmt.write_markup(markup! { "HTML" }),
             DocumentFileSource::Unknown => fmt.write_markup(markup! { "Unknown" }),
         }
     }
@@ -305,11 +561,18 @@ pub struct FixAllParams<'a> {
     pub(crate) rules: Option<&'a Rules>,
     pub(crate) filter: AnalysisFilter<'a>,
     pub(crate) fix_file_mode: FixFileMode,
+    /// The explanation of the suppression comments, used by [FixFileMode::ApplySuppressions]
+    pub(crate) suppression_reason: Option<String>,
     pub(crate) settings: WorkspaceSettingsHandle<'a>,
     /// Whether it should format the code action
     pub(crate) should_format: bool,
     pub(crate) biome_path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) tsconfig: Option<TsConfigJson>,
+    pub(crate) tailwind_config: Option<TailwindConfig>,
+    pub(crate) workspace_packages: Vec<PackageJson>,
+    pub(crate) json_schema: Option<Arc<JsonSchema>>,
+    pub(crate) tsconfig_extends: Option<Arc<TsconfigExtends>>,
     pub(crate) document_file_source: DocumentFileSource,
 }
 
@@ -370,6 +633,12 @@ pub(crate) struct LintParams<'a> {
     pub(crate) rule: Option<RuleSelector>,
     pub(crate) categories: RuleCategories,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) tsconfig: Option<TsConfigJson>,
+    pub(crate) tailwind_config: Option<TailwindConfig>,
+    pub(crate) workspace_packages: Vec<PackageJson>,
+    pub(crate) json_schema: Option<Arc<JsonSchema>>,
+    pub(crate) tsconfig_extends: Option<Arc<TsconfigExtends>>,
+    pub(crate) template: Option<ComponentTemplate>,
 }
 
 pub(crate) struct LintResults {
@@ -384,6 +653,9 @@ pub(crate) struct CodeActionsParams<'a> {
     pub(crate) workspace: WorkspaceSettingsHandle<'a>,
     pub(crate) path: &'a BiomePath,
     pub(crate) manifest: Option<PackageJson>,
+    pub(crate) tsconfig: Option<TsConfigJson>,
+    pub(crate) tailwind_config: Option<TailwindConfig>,
+    pub(crate) workspace_packages: Vec<PackageJson>,
     pub(crate) language: DocumentFileSource,
 }
 
@@ -391,7 +663,11 @@ type Lint = fn(LintParams) -> LintResults;
 type CodeActions = fn(CodeActionsParams) -> PullActionsResult;
 type FixAll = fn(FixAllParams) -> Result<FixFileResult, WorkspaceError>;
 type Rename = fn(&BiomePath, AnyParse, TextSize, String) -> Result<RenameResult, WorkspaceError>;
-type OrganizeImports = fn(AnyParse) -> Result<OrganizeImportsResult, WorkspaceError>;
+type OrganizeImports = fn(
+    &BiomePath,
+    AnyParse,
+    WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError>;
 
 #[derive(Default)]
 pub struct AnalyzerCapabilities {
@@ -455,6 +731,12 @@ pub(crate) struct Features {
     astro: AstroFileHandler,
     vue: VueFileHandler,
     svelte: SvelteFileHandler,
+    yaml: YamlFileHandler,
+    toml: TomlFileHandler,
+    markdown: MarkdownFileHandler,
+    mdx: MdxFileHandler,
+    graphql: GraphqlFileHandler,
+    html: HtmlFileHandler,
     unknown: UnknownFileHandler,
 }
 
@@ -467,6 +749,12 @@ impl Features {
             astro: AstroFileHandler {},
             vue: VueFileHandler {},
             svelte: SvelteFileHandler {},
+            yaml: YamlFileHandler {},
+            toml: TomlFileHandler {},
+            markdown: MarkdownFileHandler {},
+            mdx: MdxFileHandler {},
+            graphql: GraphqlFileHandler {},
+            html: HtmlFileHandler {},
             unknown: UnknownFileHandler::default(),
         }
     }
@@ -493,6 +781,12 @@ impl Features {
                     self.unknown.capabilities()
                 }
             }
+            DocumentFileSource::Yaml(_) => self.yaml.capabilities(),
+            DocumentFileSource::Toml(_) => self.toml.capabilities(),
+            DocumentFileSource::Markdown(source) if source.is_mdx() => self.mdx.capabilities(),
+            DocumentFileSource::Markdown(_) => self.markdown.capabilities(),
+            DocumentFileSource::Graphql(_) => self.graphql.capabilities(),
+            DocumentFileSource::Html(_) => self.html.capabilities(),
             DocumentFileSource::Unknown => self.unknown.capabilities(),
         }
     }
@@ -520,74 +814,258 @@ pub(crate) fn is_diagnostic_error(
     severity >= Severity::Error
 }
 
-/// Parse the "lang" attribute from the opening tag of the "\<script\>" block in Svelte or Vue files.
-/// This function will return the language based on the existence or the value of the "lang" attribute.
-/// We use the JSX parser at the moment to parse the opening tag. So the opening tag should be first
-/// matched by regular expressions.
-///
-// TODO: We should change the parser when HTMLish languages are supported.
-pub(crate) fn parse_lang_from_script_opening_tag(script_opening_tag: &str) -> Language {
-    parse(
-        script_opening_tag,
-        JsFileSource::jsx(),
-        JsParserOptions::default(),
+/// The error of [FixFileMode::ApplySuppressions] for the languages whose analyzer doesn't read the
+/// suppression comments, so the comments can't be inserted
+pub(crate) fn unsupported_suppressions(
+    path: &BiomePath,
+    file_source: DocumentFileSource,
+) -> WorkspaceError {
+    WorkspaceError::source_file_not_supported(
+        file_source,
+        path.display().to_string(),
+        path.extension().and_then(OsStr::to_str).map(String::from),
+    )
+}
+
+/// Resolves the formatter options of a language, for the formatters of the files
+/// that contain code of other languages, such as Markdown and Vue files.
+pub(crate) fn resolve_format_options<L: ServiceLanguage>(
+    settings: &Settings,
+    path: &BiomePath,
+    file_source: DocumentFileSource,
+) -> L::FormatOptions {
+    L::resolve_format_options(
+        &settings.formatter,
+        &settings.override_settings,
+        &L::lookup_settings(&settings.languages).formatter,
+        path,
+        &file_source,
     )
-    .try_tree()
-    .and_then(|tree| {
-        tree.as_js_module()?.items().into_iter().find_map(|item| {
-            let expression = item
-                .as_any_js_statement()?
-                .as_js_expression_statement()?
-                .expression()
-                .ok()?;
-            let tag = expression.as_jsx_tag_expression()?.tag().ok()?;
-            let opening_element = tag.as_jsx_element()?.opening_element().ok()?;
-            let lang_attribute = opening_element.attributes().find_by_name("lang").ok()??;
-            let attribute_value = lang_attribute.initializer()?.value().ok()?;
-            let attribute_inner_string =
-                attribute_value.as_jsx_string()?.inner_string_text().ok()?;
-            match attribute_inner_string.text() {
-                "ts" | "tsx" => Some(Language::TypeScript {
-                    definition_file: false,
-                }),
-                _ => None,
+}
+
+/// Formats the code of the languages embedded in a file, such as the scripts and the styles of an
+/// HTML file or the fenced code blocks of a Markdown document, with the options of the files of
+/// their language.
+///
+/// The formatter of a language that is disabled keeps the code of that language as is.
+/// The options are resolved before formatting, because the formatter can't hold the settings of the workspace.
+#[derive(Debug)]
+pub(crate) struct EmbeddedFormatter {
+    html_options: Option<HtmlFormatOptions>,
+    /// The options of the kinds of scripts of the file, empty if their formatter is disabled
+    js_options: Vec<JsFormatOptions>,
+    json_options: Option<JsonFormatOptions>,
+    css_options: Option<CssFormatOptions>,
+    graphql_options: Option<GraphqlFormatOptions>,
+}
+
+impl EmbeddedFormatter {
+    /// Resolves the options of the formatters for the file of `path`, whose scripts
+    /// have one of the `js_file_sources`
+    pub(crate) fn new(
+        path: &BiomePath,
+        settings: &Settings,
+        js_file_sources: impl IntoIterator<Item = JsFileSource>,
+    ) -> Self {
+        let html_options = (!settings.html_formatter_disabled()).then(|| {
+            resolve_format_options::<HtmlLanguage>(settings, path, HtmlFileSource::html().into())
+        });
+        let js_options = if settings.javascript_formatter_disabled() {
+            Vec::new()
+        } else {
+            js_file_sources
+                .into_iter()
+                .map(|file_source| {
+                    resolve_format_options::<JsLanguage>(settings, path, file_source.into())
+                })
+                .collect()
+        };
+        let json_options = (!settings.json_formatter_disabled()).then(|| {
+            resolve_format_options::<JsonLanguage>(settings, path, JsonFileSource::json().into())
+        });
+        let css_options = (can_format_css_yet() && !settings.css_formatter_disabled()).then(|| {
+            resolve_format_options::<CssLanguage>(settings, path, CssFileSource::css().into())
+        });
+        let graphql_options = (!settings.graphql_formatter_disabled()).then(|| {
+            resolve_format_options::<GraphqlLanguage>(
+                settings,
+                path,
+                GraphqlFileSource::graphql().into(),
+            )
+        });
+
+        Self {
+            html_options,
+            js_options,
+            json_options,
+            css_options,
+            graphql_options,
+        }
+    }
+
+    pub(crate) fn js_options(&self, file_source: JsFileSource) -> Option<&JsFormatOptions> {
+        self.js_options
+            .iter()
+            .find(|options| options.source_type() == file_source)
+    }
+
+    pub(crate) fn css_options(&self) -> Option<&CssFormatOptions> {
+        self.css_options.as_ref()
+    }
+
+    /// Formats the whole file with the HTML formatter, which prints the embedded code as is
+    pub(crate) fn format_document(
+        &self,
+        text: &str,
+        parser_options: HtmlParserOptions,
+    ) -> Result<String, WorkspaceError> {
+        match &self.html_options {
+            Some(options) => {
+                let parse = biome_html_parser::parse_html(text, parser_options);
+                Ok(
+                    biome_html_formatter::format_node(options.clone(), &parse.syntax())?
+                        .print()?
+                        .into_code(),
+                )
             }
-        })
-    })
-    .map_or(Language::JavaScript, |lang| lang)
-}
-
-#[test]
-fn test_svelte_script_lang() {
-    const SVELTE_JS_SCRIPT_OPENING_TAG: &str = r#"<script>"#;
-    const SVELTE_TS_SCRIPT_OPENING_TAG: &str = r#"<script lang="ts">"#;
-    const SVELTE_CONTEXT_MODULE_JS_SCRIPT_OPENING_TAG: &str = r#"<script context="module">"#;
-    const SVELTE_CONTEXT_MODULE_TS_SCRIPT_OPENING_TAG: &str =
-        r#"<script context="module" lang="ts">"#;
-
-    assert!(parse_lang_from_script_opening_tag(SVELTE_JS_SCRIPT_OPENING_TAG).is_javascript());
-    assert!(parse_lang_from_script_opening_tag(SVELTE_TS_SCRIPT_OPENING_TAG).is_typescript());
-    assert!(
-        parse_lang_from_script_opening_tag(SVELTE_CONTEXT_MODULE_JS_SCRIPT_OPENING_TAG)
-            .is_javascript()
-    );
-    assert!(
-        parse_lang_from_script_opening_tag(SVELTE_CONTEXT_MODULE_TS_SCRIPT_OPENING_TAG)
-            .is_typescript()
-    );
-}
-
-#[test]
-fn test_vue_script_lang() {
-    const VUE_JS_SCRIPT_OPENING_TAG: &str = r#"<script>"#;
-    const VUE_TS_SCRIPT_OPENING_TAG: &str = r#"<script lang="ts">"#;
-    const VUE_TSX_SCRIPT_OPENING_TAG: &str = r#"<script lang="tsx">"#;
-    const VUE_SETUP_JS_SCRIPT_OPENING_TAG: &str = r#"<script setup>"#;
-    const VUE_SETUP_TS_SCRIPT_OPENING_TAG: &str = r#"<script setup lang="ts">"#;
-
-    assert!(parse_lang_from_script_opening_tag(VUE_JS_SCRIPT_OPENING_TAG).is_javascript());
-    assert!(parse_lang_from_script_opening_tag(VUE_TS_SCRIPT_OPENING_TAG).is_typescript());
-    assert!(parse_lang_from_script_opening_tag(VUE_TSX_SCRIPT_OPENING_TAG).is_typescript());
-    assert!(parse_lang_from_script_opening_tag(VUE_SETUP_JS_SCRIPT_OPENING_TAG).is_javascript());
-    assert!(parse_lang_from_script_opening_tag(VUE_SETUP_TS_SCRIPT_OPENING_TAG).is_typescript());
+            None => Ok(text.to_string()),
+        }
+    }
+
+    pub(crate) fn format_html(&self, code: &str) -> Option<String> {
+        let options = self.html_options.as_ref()?;
+        let parse = biome_html_parser::parse_html(code, HtmlParserOptions::default());
+        if parse.has_errors() {
+            return None;
+        }
+        let formatted = biome_html_formatter::format_node(options.clone(), &parse.syntax()).ok()?;
+        Some(formatted.print().ok()?.into_code())
+    }
+
+    pub(crate) fn format_js(&self, file_source: JsFileSource, code: &str) -> Option<String> {
+        let options = self.js_options(file_source)?;
+        let parse = biome_js_parser::parse(code, file_source, JsParserOptions::default());
+        if parse.has_errors() {
+            return None;
+        }
+        let formatted = biome_js_formatter::format_node(options.clone(), &parse.syntax()).ok()?;
+        Some(formatted.print().ok()?.into_code())
+    }
+
+    pub(crate) fn format_json(&self, file_source: JsonFileSource, code: &str) -> Option<String> {
+        let options = self.json_options.as_ref()?;
+        let parse = biome_json_parser::parse_json(
+            code,
+            JsonParserOptions {
+                allow_comments: file_source.allow_comments(),
+                allow_trailing_commas: file_source.allow_trailing_commas(),
+                allow_json5: file_source.allow_json5(),
+            },
+        );
+        if parse.has_errors() {
+            return None;
+        }
+        let formatted = biome_json_formatter::format_node(options.clone(), &parse.syntax()).ok()?;
+        Some(formatted.print().ok()?.into_code())
+    }
+
+    pub(crate) fn format_css(&self, code: &str) -> Option<String> {
+        let options = self.css_options()?;
+        let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
+        if parse.has_errors() {
+            return None;
+        }
+        let formatted = biome_css_formatter::format_node(options.clone(), &parse.syntax()).ok()?;
+        Some(formatted.print().ok()?.into_code())
+    }
+
+    pub(crate) fn format_graphql(&self, code: &str) -> Option<String> {
+        let options = self.graphql_options.as_ref()?;
+        let parse = biome_graphql_parser::parse_graphql(code);
+        if parse.has_errors() {
+            return None;
+        }
+        let formatted =
+            biome_graphql_formatter::format_node(options.clone(), &parse.syntax()).ok()?;
+        Some(formatted.print().ok()?.into_code())
+    }
+
+    /// Returns the formatted script of a block, which starts on the line that follows the opening tag
+    pub(crate) fn format_script_block(
+        &self,
+        file_source: JsFileSource,
+        code: &str,
+    ) -> Option<String> {
+        let formatted = self.format_js(file_source, code)?;
+        let line_ending = self.js_options(file_source)?.line_ending();
+        Some(format!("{}{formatted}", line_ending.as_str()))
+    }
+
+    /// Returns the formatted styles of a block, which start on the line that follows the opening tag
+    pub(crate) fn format_style_block(&self, code: &str) -> Option<String> {
+        let formatted = self.format_css(code)?;
+        let line_ending = self.css_options()?.line_ending();
+        Some(format!("{}{formatted}", line_ending.as_str()))
+    }
+
+    /// Returns the formatted script of an element, indented one level deeper than `indentation`,
+    /// the indentation of the line of the opening tag
+    pub(crate) fn format_script_element(
+        &self,
+        file_source: JsFileSource,
+        code: &str,
+        indentation: &str,
+    ) -> Option<String> {
+        let formatted = self.format_js(file_source, code)?;
+        let options = self.js_options(file_source)?;
+        Some(indent_embedded_code(&formatted, indentation, options))
+    }
+
+    /// Returns the formatted styles of an element, indented one level deeper than `indentation`,
+    /// the indentation of the line of the opening tag
+    pub(crate) fn format_style_element(&self, code: &str, indentation: &str) -> Option<String> {
+        let formatted = self.format_css(code)?;
+        let options = self.css_options()?;
+        Some(indent_embedded_code(&formatted, indentation, options))
+    }
+}
+
+/// Returns the formatted `code` of an element, which starts on the line that follows the opening tag,
+/// indented one level deeper than `indentation`, the indentation of the line of the opening tag.
+/// The closing tag follows on its own line, with the indentation of the opening tag.
+pub(crate) fn indent_embedded_code(
+    code: &str,
+    indentation: &str,
+    options: &impl FormatOptions,
+) -> String {
+    let line_ending = options.line_ending().as_str();
+    let indent = match options.indent_style() {
+        IndentStyle::Tab => "\t".to_string(),
+        IndentStyle::Space => " ".repeat(usize::from(options.indent_width().value())),
+    };
+    let mut indented = String::from(line_ending);
+    for line in code.lines() {
+        if !line.is_empty() {
+            indented.push_str(indentation);
+            indented.push_str(&indent);
+            indented.push_str(line);
+        }
+        indented.push_str(line_ending);
+    }
+    indented.push_str(indentation);
+
+    indented
+}
+
+/// Returns the indentation of the line where `range` starts
+pub(crate) fn indentation_at(code: &str, range: TextRange) -> &str {
+    let before = &code[..usize::from(range.start())];
+    let line = before
+        .rfind(['\n', '\r'])
+        .map_or(before, |line_break| &before[line_break + 1..]);
+    let indentation_length = line
+        .find(|char: char| !matches!(char, ' ' | '\t'))
+        .unwrap_or(line.len());
+
+    &line[..indentation_length]
 }
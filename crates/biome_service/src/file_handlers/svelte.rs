@@ -1,87 +1,313 @@
 use crate::file_handlers::{
     javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities,
+    EmbeddedFormatter, ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams,
+    LintResults, ParseResult, ParserCapabilities,
 };
-use crate::settings::WorkspaceSettingsHandle;
+use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
     DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
+use biome_analyze::AnalysisFilter;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
-use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{EmbeddingKind, JsFileSource, Language, TextRange, TextSize};
+use biome_html_parser::HtmlParserOptions;
+use biome_html_syntax::{AnyHtmlElement, HtmlRoot, HtmlTextExpression, SvelteEachOpeningBlock};
+use biome_js_analyze::ComponentTemplate;
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
-use lazy_static::lazy_static;
-use regex::{Match, Regex};
+use biome_rowan::{AstNode, NodeCache};
+use std::ops::Range;
 use tracing::debug;
 
-use super::parse_lang_from_script_opening_tag;
-
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct SvelteFileHandler;
 
-lazy_static! {
-    // https://regex101.com/r/E4n4hh/6
-    pub static ref SVELTE_FENCE: Regex = Regex::new(
-        r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#
-    )
-    .unwrap();
+/// The kind of a top-level block of a Svelte component
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SvelteBlockKind {
+    /// A `<script>` block, `module` is `true` for `<script context="module">` and `<script module>`
+    Script {
+        module: bool,
+        file_source: JsFileSource,
+    },
+    /// A `<style>` block, only the styles without a `lang` attribute, or with `lang="css"`, are CSS
+    Style { is_css: bool },
+}
+
+/// A top-level `<script>` or `<style>` block of a Svelte component
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SvelteBlock {
+    pub kind: SvelteBlockKind,
+    /// The range of the content of the block, between its opening tag and its closing tag
+    pub content_range: TextRange,
 }
 
 impl SvelteFileHandler {
-    /// It extracts the JavaScript/TypeScript code contained in the script block of a Svelte file
+    /// Returns the top-level `<script>` and `<style>` blocks of a Svelte file, in the order of the source.
     ///
-    /// If there's no script block, an empty string is returned.
-    pub fn input(text: &str) -> &str {
-        match Self::matches_script(text) {
-            Some(script) => &text[script.start()..script.end()],
-            _ => "",
+    /// The blocks that aren't closed are ignored, the parser reports them.
+    pub fn blocks(root: &HtmlRoot) -> Vec<SvelteBlock> {
+        root.html()
+            .into_iter()
+            .filter_map(|element| {
+                let AnyHtmlElement::HtmlElement(element) = element else {
+                    return None;
+                };
+                let opening_element = element.opening_element().ok()?;
+                let closing_element = element.closing_element().ok()?;
+                let name = opening_element.name().ok()?.value_token().ok()?;
+                let attributes = opening_element.attributes();
+                let lang = attributes
+                    .find_by_name("lang")
+                    .and_then(|attribute| attribute.value());
+                let lang = lang.as_ref().map(|lang| lang.text());
+
+                let kind = match name.text_trimmed().to_ascii_lowercase().as_str() {
+                    "script" => SvelteBlockKind::Script {
+                        module: attributes.find_by_name("module").is_some()
+                            || attributes
+                                .find_by_name("context")
+                                .and_then(|attribute| attribute.value())
+                                .is_some_and(|context| context.text() == "module"),
+                        file_source: Self::script_file_source(lang),
+                    },
+                    "style" => SvelteBlockKind::Style {
+                        is_css: lang.map_or(true, |lang| lang.eq_ignore_ascii_case("css")),
+                    },
+                    _ => return None,
+                };
+
+                Some(SvelteBlock {
+                    kind,
+                    content_range: TextRange::new(
+                        opening_element.syntax().text_range().end(),
+                        closing_element.syntax().text_range().start(),
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the script that Biome analyzes: the instance `<script>` block when there's one,
+    /// the `<script context="module">` block otherwise.
+    pub fn script(blocks: &[SvelteBlock]) -> Option<&SvelteBlock> {
+        let mut scripts = blocks
+            .iter()
+            .filter(|block| matches!(block.kind, SvelteBlockKind::Script { .. }));
+        scripts
+            .clone()
+            .find(|block| matches!(block.kind, SvelteBlockKind::Script { module: false, .. }))
+            .or_else(|| scripts.next())
+    }
+
+    fn script_file_source(lang: Option<&str>) -> JsFileSource {
+        let file_source = match lang {
+            Some("ts" | "typescript") => JsFileSource::ts(),
+            _ => JsFileSource::js_module(),
+        };
+        file_source.with_embedding_kind(EmbeddingKind::Svelte)
+    }
+}
+
+/// The script of a Svelte file, parsed with the JavaScript parser
+struct SvelteScript {
+    parse: AnyParse,
+    file_source: JsFileSource,
+    /// The range of the script in the Svelte file, empty when the file doesn't have a script
+    content_range: TextRange,
+}
+
+impl SvelteScript {
+    /// Parses the script of a Svelte file, so that the ranges of its nodes are the offsets in the whole file.
+    ///
+    /// The text that precedes and follows the script is replaced by whitespace, only its line breaks are kept.
+    /// When `with_template` is `true`, the expressions of the template are parsed along with the script,
+    /// so that the rules see the variables that the markup uses.
+    /// The diagnostics of the parser of the Svelte file are reported along with the ones of the script.
+    fn parse_in_file(parse: &AnyParse, with_template: bool) -> Self {
+        let root: HtmlRoot = parse.tree();
+        let text = root.syntax().to_string();
+        let (file_source, content_range) = script_of(&root);
+
+        let mut script: String = text
+            .bytes()
+            .map(|byte| match byte {
+                b'\n' | b'\r' => byte as char,
+                _ => ' ',
+            })
+            .collect();
+        script.replace_range(Range::<usize>::from(content_range), &text[content_range]);
+        if with_template {
+            for expression in root
+                .syntax()
+                .descendants()
+                .filter_map(HtmlTextExpression::cast)
+            {
+                write_template_expression(&mut script, &expression);
+            }
+        }
+
+        let mut diagnostics = parse.diagnostics().to_vec();
+        let script_parse = biome_js_parser::parse(&script, file_source, JsParserOptions::default());
+        let root = script_parse.syntax();
+        diagnostics.extend(script_parse.into_diagnostics());
+
+        Self {
+            // SAFETY: the parser should always return a root node
+            parse: AnyParse::new(root.as_send().unwrap(), diagnostics),
+            file_source,
+            content_range,
         }
     }
 
-    /// It takes the original content of a Svelte file, and new output of an Svelte file. The output is only the content contained inside the
-    /// Svelte `<script>` tag. The function replaces `output` inside that `<script>`.
-    pub fn output(input: &str, output: &str) -> String {
-        if let Some(script) = Self::matches_script(input) {
-            format!(
-                "{}{}{}",
-                &input[..script.start()],
-                output,
-                &input[script.end()..]
-            )
-        } else {
-            input.to_string()
+    /// Parses only the content of the script of a Svelte file, the ranges of its nodes start at the script
+    fn parse_content(parse: &AnyParse) -> (String, Self) {
+        let root: HtmlRoot = parse.tree();
+        let text = root.syntax().to_string();
+        let (file_source, content_range) = script_of(&root);
+
+        let script_parse = biome_js_parser::parse(
+            &text[content_range],
+            file_source,
+            JsParserOptions::default(),
+        );
+        let root = script_parse.syntax();
+        let diagnostics = script_parse.into_diagnostics();
+
+        let script = Self {
+            // SAFETY: the parser should always return a root node
+            parse: AnyParse::new(root.as_send().unwrap(), diagnostics),
+            file_source,
+            content_range,
+        };
+        (text, script)
+    }
+}
+
+/// Writes the code of an expression of the template over the whitespace that replaces it in `script`.
+///
+/// The expression is wrapped in parentheses, which take the place of the delimiters around it,
+/// such as the curly braces of `{count + 1}`, and follows a semicolon when there's room for it,
+/// so that it isn't parsed as a part of the code that precedes it.
+/// The `{@const}` tags are written as declarations, the other tags are written without their name,
+/// the item of an each block is a pattern, not an expression, so it isn't written.
+fn write_template_expression(script: &mut String, expression: &HtmlTextExpression) {
+    let is_each_item = expression
+        .parent::<SvelteEachOpeningBlock>()
+        .and_then(|block| block.item().ok())
+        .is_some_and(|item| &item == expression);
+    if is_each_item {
+        return;
+    }
+    let Ok(token) = expression.value_token() else {
+        return;
+    };
+    let code = token.text_trimmed();
+    let start = usize::from(token.text_trimmed_range().start());
+    let end = usize::from(token.text_trimmed_range().end());
+    if start == 0 || end >= script.len() {
+        return;
+    }
+
+    if let Some(tag) = code.strip_prefix('@') {
+        let name_length = tag
+            .find(|char: char| !char.is_ascii_alphabetic())
+            .unwrap_or(tag.len());
+        match &tag[..name_length] {
+            "const" => {
+                script.replace_range(start - 1..start, ";");
+                script.replace_range(start + 1..end, &code[1..]);
+                script.replace_range(end..end + 1, ";");
+            }
+            "html" | "render" | "debug" => {
+                let expression = tag[name_length..].trim_start();
+                if !expression.is_empty() {
+                    write_wrapped_expression(script, expression, end - expression.len());
+                }
+            }
+            _ => {}
         }
+    } else if let Some(spread) = code.strip_prefix("...") {
+        write_wrapped_expression(script, spread, start + 3);
+    } else {
+        write_wrapped_expression(script, code, start);
+    }
+}
+
+/// Writes `code` at `start`, between parentheses, after a semicolon when the byte before the parenthesis is free.
+pub(super) fn write_wrapped_expression(script: &mut String, code: &str, start: usize) {
+    let end = start + code.len();
+    if start >= 2 && script.as_bytes()[start - 2].is_ascii_whitespace() {
+        script.replace_range(start - 2..start - 1, ";");
     }
+    script.replace_range(start - 1..start, "(");
+    script.replace_range(start..end, code);
+    script.replace_range(end..end + 1, ")");
+}
+
+/// Returns the expressions of the template that [SvelteScript::parse_in_file] writes in the script
+fn template_of(root: &HtmlRoot) -> ComponentTemplate {
+    let expressions = root
+        .syntax()
+        .descendants()
+        .filter_map(HtmlTextExpression::cast)
+        .filter_map(|expression| Some(expression.value_token().ok()?.text_trimmed_range()))
+        .collect();
+    ComponentTemplate::new(expressions)
+}
 
-    /// Returns the start byte offset of the Svelte `<script>` tag
-    pub fn start(input: &str) -> Option<u32> {
-        Self::matches_script(input).map(|m| m.start() as u32)
+/// Returns the language and the range of the content of the analyzed script,
+/// an empty range at the end of the file when there's no script.
+fn script_of(root: &HtmlRoot) -> (JsFileSource, TextRange) {
+    let blocks = SvelteFileHandler::blocks(root);
+    match SvelteFileHandler::script(&blocks) {
+        Some(SvelteBlock {
+            kind: SvelteBlockKind::Script { file_source, .. },
+            content_range,
+        }) => (*file_source, *content_range),
+        _ => (
+            JsFileSource::svelte(),
+            TextRange::empty(root.syntax().text_range().end()),
+        ),
     }
+}
+
+/// Formats a Svelte file: the HTML formatter formats the whole file, including the blocks and the expressions
+/// of the template, then the JavaScript and the CSS formatters format the content of the `<script>` and the `<style>` blocks.
+#[derive(Debug)]
+struct SvelteFormatter(EmbeddedFormatter);
 
-    fn matches_script(input: &str) -> Option<Match> {
-        SVELTE_FENCE
-            .captures(input)
-            .and_then(|captures| captures.name("script"))
+impl SvelteFormatter {
+    fn new(path: &BiomePath, settings: &Settings) -> Self {
+        let js_file_sources = [JsFileSource::js_module(), JsFileSource::ts()]
+            .map(|file_source| file_source.with_embedding_kind(EmbeddingKind::Svelte));
+        Self(EmbeddedFormatter::new(path, settings, js_file_sources))
     }
 
-    pub fn file_source(text: &str) -> JsFileSource {
-        SVELTE_FENCE
-            .captures(text)
-            .and_then(|captures| {
-                match parse_lang_from_script_opening_tag(captures.name("opening")?.as_str()) {
-                    Language::JavaScript => {
-                        Some(JsFileSource::js_module().with_embedding_kind(EmbeddingKind::Svelte))
-                    }
-                    Language::TypeScript { .. } => {
-                        Some(JsFileSource::ts().with_embedding_kind(EmbeddingKind::Svelte))
-                    }
+    fn format(&self, text: &str) -> Result<Printed, WorkspaceError> {
+        let parser_options = HtmlParserOptions::default().svelte();
+        let mut code = self.0.format_document(text, parser_options)?;
+
+        // The HTML formatter prints the content of the blocks as is, their ranges
+        // are replaced from the last one, so that the ranges of the others stay valid
+        let root = biome_html_parser::parse_html(&code, parser_options).tree();
+        for block in SvelteFileHandler::blocks(&root).into_iter().rev() {
+            let content = &code[block.content_range];
+            let formatted = match block.kind {
+                SvelteBlockKind::Script { file_source, .. } => {
+                    self.0.format_script_block(file_source, content)
                 }
-            })
-            .map_or(JsFileSource::js_module(), |fs| fs)
+                SvelteBlockKind::Style { is_css: true } => self.0.format_style_block(content),
+                SvelteBlockKind::Style { is_css: false } => None,
+            };
+            if let Some(formatted) = formatted {
+                code.replace_range(Range::<usize>::from(block.content_range), &formatted);
+            }
+        }
+
+        Ok(Printed::new(code, None, Vec::new(), Vec::new()))
     }
 }
 
@@ -110,6 +336,7 @@ impl ExtensionHandler for SvelteFileHandler {
     }
 }
 
+/// Parses the whole Svelte file with the HTML parser, the language of the file is the one of its script
 fn parse(
     _rome_path: &BiomePath,
     _file_source: DocumentFileSource,
@@ -117,15 +344,17 @@ fn parse(
     _settings: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let script = SvelteFileHandler::input(text);
-    let file_source = SvelteFileHandler::file_source(text);
-
-    debug!("Parsing file with language {:?}", file_source);
-
-    let parse = parse_js_with_cache(script, file_source, JsParserOptions::default(), cache);
+    let parse = biome_html_parser::parse_html_with_cache(
+        text,
+        cache,
+        HtmlParserOptions::default().svelte(),
+    );
     let root = parse.syntax();
+    let (file_source, _) = script_of(&parse.tree());
     let diagnostics = parse.into_diagnostics();
 
+    debug!("Parsing file with language {:?}", file_source);
+
     ParseResult {
         any_parse: AnyParse::new(
             // SAFETY: the parser should always return a root node
@@ -139,44 +368,115 @@ fn parse(
 #[tracing::instrument(level = "trace", skip(parse, settings))]
 fn format(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format(biome_path, document_file_source, parse, settings)
+    let formatter = SvelteFormatter::new(biome_path, settings.settings());
+    let root: HtmlRoot = parse.tree();
+    formatter.format(&root.syntax().to_string())
 }
+
 pub(crate) fn format_range(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_range(biome_path, document_file_source, parse, settings, range)
+    let script = SvelteScript::parse_in_file(&parse, false);
+    javascript::format_range(
+        biome_path,
+        &script.file_source.into(),
+        script.parse,
+        settings,
+        range,
+    )
 }
 
 pub(crate) fn format_on_type(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_on_type(biome_path, document_file_source, parse, settings, offset)
+    let script = SvelteScript::parse_in_file(&parse, false);
+    javascript::format_on_type(
+        biome_path,
+        &script.file_source.into(),
+        script.parse,
+        settings,
+        offset,
+    )
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    let script = SvelteScript::parse_in_file(&params.parse, true);
+    let template = template_of(&params.parse.tree());
+    javascript::lint(LintParams {
+        parse: script.parse,
+        language: script.file_source.into(),
+        template: Some(template),
+        ..params
+    })
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    javascript::code_actions(params)
+    let script = SvelteScript::parse_in_file(&params.parse, true);
+    javascript::code_actions(CodeActionsParams {
+        parse: script.parse,
+        language: script.file_source.into(),
+        ..params
+    })
 }
 
+/// Applies the fixes to the script of the Svelte file, and formats the whole file when `should_format` is `true`.
+///
+/// The expressions of the template are analyzed along with the script, so that a fix doesn't remove
+/// an import that only the markup uses, but only the signals of the script are fixed.
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    javascript::fix_all(params)
+    let script = SvelteScript::parse_in_file(&params.parse, true);
+    let root: HtmlRoot = params.parse.tree();
+    let mut code = root.syntax().to_string();
+    let formatter = params
+        .should_format
+        .then(|| SvelteFormatter::new(params.biome_path, params.settings.settings()));
+
+    let result = javascript::fix_all(FixAllParams {
+        parse: script.parse,
+        should_format: false,
+        document_file_source: script.file_source.into(),
+        filter: AnalysisFilter {
+            range: Some(script.content_range),
+            ..params.filter
+        },
+        ..params
+    })?;
+
+    // The fixes only change the script, the text that follows it keeps its length
+    let following_length = code.len() - usize::from(script.content_range.end());
+    let content = result.code.get(
+        usize::from(script.content_range.start())
+            ..result.code.len().saturating_sub(following_length),
+    );
+    if let Some(content) = content {
+        code.replace_range(Range::<usize>::from(script.content_range), content);
+    }
+    if let Some(formatter) = formatter {
+        code = formatter.format(&code)?.into_code();
+    }
+
+    Ok(FixFileResult { code, ..result })
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+fn organize_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let (mut code, script) = SvelteScript::parse_content(&parse);
+    let result = javascript::organize_imports(path, script.parse, settings)?;
+    code.replace_range(Range::<usize>::from(script.content_range), &result.code);
+    Ok(OrganizeImportsResult { code })
 }
@@ -1,87 +1,247 @@
 use crate::file_handlers::{
-    javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
-    ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams, LintResults, ParseResult,
-    ParserCapabilities,
+    html, javascript, AnalyzerCapabilities, Capabilities, CodeActionsParams, DebugCapabilities,
+    EmbeddedFormatter, ExtensionHandler, FixAllParams, FormatterCapabilities, LintParams,
+    LintResults, ParseResult, ParserCapabilities,
 };
-use crate::settings::WorkspaceSettingsHandle;
+use crate::settings::{Settings, WorkspaceSettingsHandle};
 use crate::workspace::{
-    DocumentFileSource, FixFileResult, OrganizeImportsResult, PullActionsResult,
+    DocumentFileSource, FixAction, FixFileResult, OrganizeImportsResult, PullActionsResult,
 };
 use crate::WorkspaceError;
+use biome_analyze::RuleDomain;
 use biome_formatter::Printed;
 use biome_fs::BiomePath;
-use biome_js_parser::{parse_js_with_cache, JsParserOptions};
-use biome_js_syntax::{EmbeddingKind, JsFileSource, Language, TextRange, TextSize};
+use biome_html_parser::HtmlParserOptions;
+use biome_html_syntax::{AnyHtmlElement, HtmlRoot};
+use biome_js_parser::JsParserOptions;
+use biome_js_syntax::{EmbeddingKind, JsFileSource, TextRange, TextSize};
+use biome_parser::prelude::ParseDiagnostic;
 use biome_parser::AnyParse;
-use biome_rowan::NodeCache;
-use lazy_static::lazy_static;
-use regex::{Match, Regex};
+use biome_rowan::{AstNode, NodeCache};
+use std::ops::Range;
 use tracing::debug;
 
-use super::parse_lang_from_script_opening_tag;
-
 #[derive(Debug, Default, PartialEq, Eq)]
 pub struct VueFileHandler;
 
-lazy_static! {
-    // https://regex101.com/r/E4n4hh/6
-    pub static ref VUE_FENCE: Regex = Regex::new(
-        r#"(?ixs)(?<opening><script(?:\s.*?)?>)\r?\n(?<script>(?U:.*))</script>"#
-    )
-    .unwrap();
+/// The kind of a top-level block of a Vue single-file component
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum VueBlockKind {
+    /// The `<template>` block
+    Template,
+    /// A `<script>` or a `<script setup>` block, the `lang` attribute selects its language
+    Script {
+        setup: bool,
+        file_source: JsFileSource,
+    },
+    /// A `<style>` block, only the styles without a `lang` attribute, or with `lang="css"`, are CSS
+    Style { is_css: bool },
+    /// A custom block, such as `<i18n>`
+    Custom,
+}
+
+/// A top-level block of a Vue single-file component, such as `<script setup>`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VueBlock {
+    pub kind: VueBlockKind,
+    /// The range of the content of the block, between its opening tag and its closing tag
+    pub content_range: TextRange,
 }
 
 impl VueFileHandler {
-    /// It extracts the JavaScript/TypeScript code contained in the script block of a Vue file
+    /// Returns the top-level blocks of a Vue file, in the order of the source.
     ///
-    /// If there's no script block, an empty string is returned.
-    pub fn input(text: &str) -> &str {
-        match Self::matches_script(text) {
-            Some(script) => &text[script.start()..script.end()],
-            _ => "",
-        }
+    /// The blocks that aren't closed are ignored, the parser reports them.
+    pub fn blocks(root: &HtmlRoot) -> Vec<VueBlock> {
+        root.html()
+            .into_iter()
+            .filter_map(|element| {
+                let AnyHtmlElement::HtmlElement(element) = element else {
+                    return None;
+                };
+                let opening_element = element.opening_element().ok()?;
+                let closing_element = element.closing_element().ok()?;
+                let name = opening_element.name().ok()?.value_token().ok()?;
+                let attributes = opening_element.attributes();
+                let lang = attributes
+                    .find_by_name("lang")
+                    .and_then(|attribute| attribute.value());
+                let lang = lang.as_ref().map(|lang| lang.text());
+
+                let kind = match name.text_trimmed().to_ascii_lowercase().as_str() {
+                    "template" => VueBlockKind::Template,
+                    "script" => VueBlockKind::Script {
+                        setup: attributes.find_by_name("setup").is_some(),
+                        file_source: Self::script_file_source(lang),
+                    },
+                    "style" => VueBlockKind::Style {
+                        is_css: lang.map_or(true, |lang| lang.eq_ignore_ascii_case("css")),
+                    },
+                    _ => VueBlockKind::Custom,
+                };
+
+                Some(VueBlock {
+                    kind,
+                    content_range: TextRange::new(
+                        opening_element.syntax().text_range().end(),
+                        closing_element.syntax().text_range().start(),
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the script that Biome analyzes: the `<script setup>` block when there's one,
+    /// the first `<script>` block otherwise.
+    pub fn script(blocks: &[VueBlock]) -> Option<&VueBlock> {
+        let mut scripts = blocks
+            .iter()
+            .filter(|block| matches!(block.kind, VueBlockKind::Script { .. }));
+        scripts
+            .clone()
+            .find(|block| matches!(block.kind, VueBlockKind::Script { setup: true, .. }))
+            .or_else(|| scripts.next())
+    }
+
+    fn script_file_source(lang: Option<&str>) -> JsFileSource {
+        let file_source = match lang {
+            Some("ts") => JsFileSource::ts(),
+            Some("tsx") => JsFileSource::tsx(),
+            Some("jsx") => JsFileSource::jsx(),
+            _ => JsFileSource::js_module(),
+        };
+        file_source.with_embedding_kind(EmbeddingKind::Vue)
+    }
+}
+
+/// The script of a Vue file, parsed with the JavaScript parser
+struct VueScript {
+    parse: AnyParse,
+    file_source: JsFileSource,
+    /// The range of the script in the Vue file, empty when the file doesn't have a script
+    content_range: TextRange,
+}
+
+impl VueScript {
+    /// Parses the script of a Vue file, so that the ranges of its nodes are the offsets in the whole file.
+    ///
+    /// The text that precedes the script is replaced by whitespace, only its line breaks are kept.
+    /// The diagnostics of the parser of the Vue file are reported along with the ones of the script.
+    fn parse_in_file(parse: &AnyParse) -> Self {
+        Self::parse_in_file_with_diagnostics(parse, parse.diagnostics().to_vec())
     }
 
-    /// It takes the original content of a Vue file, and new output of an Vue file. The output is only the content contained inside the
-    /// Vue `<script>` tag. The function replaces `output` inside that `<script>`.
-    pub fn output(input: &str, output: &str) -> String {
-        if let Some(script) = Self::matches_script(input) {
-            format!(
-                "{}{}{}",
-                &input[..script.start()],
-                output,
-                &input[script.end()..]
-            )
-        } else {
-            input.to_string()
+    /// Same as [VueScript::parse_in_file], but the diagnostics of the script are reported along with `diagnostics`
+    /// rather than the ones of the parser of the Vue file.
+    fn parse_in_file_with_diagnostics(
+        parse: &AnyParse,
+        mut diagnostics: Vec<ParseDiagnostic>,
+    ) -> Self {
+        let root: HtmlRoot = parse.tree();
+        let text = root.syntax().to_string();
+        let (file_source, content_range) = script_of(&root);
+
+        let mut script: String = text[..usize::from(content_range.start())]
+            .bytes()
+            .map(|byte| match byte {
+                b'\n' | b'\r' => byte as char,
+                _ => ' ',
+            })
+            .collect();
+        script.push_str(&text[content_range]);
+
+        let script_parse = biome_js_parser::parse(&script, file_source, JsParserOptions::default());
+        let root = script_parse.syntax();
+        diagnostics.extend(script_parse.into_diagnostics());
+
+        Self {
+            // SAFETY: the parser should always return a root node
+            parse: AnyParse::new(root.as_send().unwrap(), diagnostics),
+            file_source,
+            content_range,
         }
     }
 
-    /// Returns the start byte offset of the Vue `<script>` tag
-    pub fn start(input: &str) -> Option<u32> {
-        Self::matches_script(input).map(|m| m.start() as u32)
+    /// Parses only the content of the script of a Vue file, the ranges of its nodes start at the script
+    fn parse_content(parse: &AnyParse) -> (String, Self) {
+        let root: HtmlRoot = parse.tree();
+        let text = root.syntax().to_string();
+        let (file_source, content_range) = script_of(&root);
+
+        let script_parse = biome_js_parser::parse(
+            &text[content_range],
+            file_source,
+            JsParserOptions::default(),
+        );
+        let root = script_parse.syntax();
+        let diagnostics = script_parse.into_diagnostics();
+
+        let script = Self {
+            // SAFETY: the parser should always return a root node
+            parse: AnyParse::new(root.as_send().unwrap(), diagnostics),
+            file_source,
+            content_range,
+        };
+        (text, script)
     }
+}
+
+/// Returns the language and the range of the content of the analyzed script,
+/// an empty range at the end of the file when there's no script.
+fn script_of(root: &HtmlRoot) -> (JsFileSource, TextRange) {
+    let blocks = VueFileHandler::blocks(root);
+    match VueFileHandler::script(&blocks) {
+        Some(VueBlock {
+            kind: VueBlockKind::Script { file_source, .. },
+            content_range,
+        }) => (*file_source, *content_range),
+        _ => (
+            JsFileSource::vue(),
+            TextRange::empty(root.syntax().text_range().end()),
+        ),
+    }
+}
+
+/// Formats a Vue file: the HTML formatter formats the whole file,
+/// then the JavaScript and the CSS formatters format the content of the `<script>` and the `<style>` blocks.
+#[derive(Debug)]
+struct VueFormatter(EmbeddedFormatter);
 
-    fn matches_script(input: &str) -> Option<Match> {
-        VUE_FENCE
-            .captures(input)
-            .and_then(|captures| captures.name("script"))
+impl VueFormatter {
+    fn new(path: &BiomePath, settings: &Settings) -> Self {
+        let js_file_sources = [
+            JsFileSource::js_module(),
+            JsFileSource::jsx(),
+            JsFileSource::ts(),
+            JsFileSource::tsx(),
+        ]
+        .map(|file_source| file_source.with_embedding_kind(EmbeddingKind::Vue));
+        Self(EmbeddedFormatter::new(path, settings, js_file_sources))
     }
 
-    pub fn file_source(text: &str) -> JsFileSource {
-        VUE_FENCE
-            .captures(text)
-            .and_then(|captures| {
-                match parse_lang_from_script_opening_tag(captures.name("opening")?.as_str()) {
-                    Language::JavaScript => {
-                        Some(JsFileSource::js_module().with_embedding_kind(EmbeddingKind::Vue))
-                    }
-                    Language::TypeScript { .. } => {
-                        Some(JsFileSource::ts().with_embedding_kind(EmbeddingKind::Vue))
-                    }
+    fn format(&self, text: &str) -> Result<Printed, WorkspaceError> {
+        let mut code = self.0.format_document(text, HtmlParserOptions::default())?;
+
+        // The HTML formatter prints the content of the blocks as is, their ranges
+        // are replaced from the last one, so that the ranges of the others stay valid
+        let root = biome_html_parser::parse_html(&code, HtmlParserOptions::default()).tree();
+        for block in VueFileHandler::blocks(&root).into_iter().rev() {
+            let content = &code[block.content_range];
+            let formatted = match block.kind {
+                VueBlockKind::Script { file_source, .. } => {
+                    self.0.format_script_block(file_source, content)
                 }
-            })
-            .map_or(JsFileSource::js_module(), |fs| fs)
+                VueBlockKind::Style { is_css: true } => self.0.format_style_block(content),
+                VueBlockKind::Style { is_css: false }
+                | VueBlockKind::Template
+                | VueBlockKind::Custom => None,
+            };
+            if let Some(formatted) = formatted {
+                code.replace_range(Range::<usize>::from(block.content_range), &formatted);
+            }
+        }
+
+        Ok(Printed::new(code, None, Vec::new(), Vec::new()))
     }
 }
 
@@ -110,6 +270,7 @@ impl ExtensionHandler for VueFileHandler {
     }
 }
 
+/// Parses the whole Vue file with the HTML parser, the language of the file is the one of its script
 fn parse(
     _rome_path: &BiomePath,
     _file_source: DocumentFileSource,
@@ -117,15 +278,13 @@ fn parse(
     _settings: WorkspaceSettingsHandle,
     cache: &mut NodeCache,
 ) -> ParseResult {
-    let script = VueFileHandler::input(text);
-    let file_source = VueFileHandler::file_source(text);
-
-    debug!("Parsing file with language {:?}", file_source);
-
-    let parse = parse_js_with_cache(script, file_source, JsParserOptions::default(), cache);
+    let parse = biome_html_parser::parse_html_with_cache(text, cache, HtmlParserOptions::default());
     let root = parse.syntax();
+    let (file_source, _) = script_of(&parse.tree());
     let diagnostics = parse.into_diagnostics();
 
+    debug!("Parsing file with language {:?}", file_source);
+
     ParseResult {
         any_parse: AnyParse::new(
             // SAFETY: the parser should always return a root node
@@ -139,45 +298,118 @@ fn parse(
 #[tracing::instrument(level = "trace", skip(parse, settings))]
 fn format(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format(biome_path, document_file_source, parse, settings)
+    let formatter = VueFormatter::new(biome_path, settings.settings());
+    let root: HtmlRoot = parse.tree();
+    formatter.format(&root.syntax().to_string())
 }
 
 pub(crate) fn format_range(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     range: TextRange,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_range(biome_path, document_file_source, parse, settings, range)
+    let script = VueScript::parse_in_file(&parse);
+    javascript::format_range(
+        biome_path,
+        &script.file_source.into(),
+        script.parse,
+        settings,
+        range,
+    )
 }
 
 pub(crate) fn format_on_type(
     biome_path: &BiomePath,
-    document_file_source: &DocumentFileSource,
+    _document_file_source: &DocumentFileSource,
     parse: AnyParse,
     settings: WorkspaceSettingsHandle,
     offset: TextSize,
 ) -> Result<Printed, WorkspaceError> {
-    javascript::format_on_type(biome_path, document_file_source, parse, settings, offset)
+    let script = VueScript::parse_in_file(&parse);
+    javascript::format_on_type(
+        biome_path,
+        &script.file_source.into(),
+        script.parse,
+        settings,
+        offset,
+    )
 }
 
 pub(crate) fn lint(params: LintParams) -> LintResults {
-    javascript::lint(params)
+    let mut results = html::lint_html(&params, Some(RuleDomain::Vue));
+    // The diagnostics of the parser of the Vue file are already reported by the lint of the template
+    let script = VueScript::parse_in_file_with_diagnostics(&params.parse, Vec::new());
+    let max_diagnostics = params
+        .max_diagnostics
+        .saturating_sub(results.diagnostics.len() as u32);
+    let script_results = javascript::lint(LintParams {
+        parse: script.parse,
+        language: script.file_source.into(),
+        max_diagnostics,
+        ..params
+    });
+    results.diagnostics.extend(script_results.diagnostics);
+    results.errors += script_results.errors;
+    results.skipped_diagnostics += script_results.skipped_diagnostics;
+    results
 }
 
 pub(crate) fn code_actions(params: CodeActionsParams) -> PullActionsResult {
-    javascript::code_actions(params)
+    let script = VueScript::parse_in_file(&params.parse);
+    javascript::code_actions(CodeActionsParams {
+        parse: script.parse,
+        language: script.file_source.into(),
+        ..params
+    })
 }
 
+/// Applies the fixes to the script of the Vue file, and formats the whole file when `should_format` is `true`
 fn fix_all(params: FixAllParams) -> Result<FixFileResult, WorkspaceError> {
-    javascript::fix_all(params)
+    let (text, script) = VueScript::parse_content(&params.parse);
+    let formatter = params
+        .should_format
+        .then(|| VueFormatter::new(params.biome_path, params.settings.settings()));
+
+    let result = javascript::fix_all(FixAllParams {
+        parse: script.parse,
+        should_format: false,
+        document_file_source: script.file_source.into(),
+        ..params
+    })?;
+
+    let mut code = text;
+    code.replace_range(Range::<usize>::from(script.content_range), &result.code);
+    if let Some(formatter) = formatter {
+        code = formatter.format(&code)?.into_code();
+    }
+
+    Ok(FixFileResult {
+        code,
+        actions: result
+            .actions
+            .into_iter()
+            .map(|action| FixAction {
+                range: action.range + script.content_range.start(),
+                ..action
+            })
+            .collect(),
+        ..result
+    })
 }
 
-fn organize_imports(parse: AnyParse) -> Result<OrganizeImportsResult, WorkspaceError> {
-    javascript::organize_imports(parse)
+fn organize_imports(
+    path: &BiomePath,
+    parse: AnyParse,
+    settings: WorkspaceSettingsHandle,
+) -> Result<OrganizeImportsResult, WorkspaceError> {
+    let (mut code, script) = VueScript::parse_content(&parse);
+    let result = javascript::organize_imports(path, script.parse, settings)?;
+    code.replace_range(Range::<usize>::from(script.content_range), &result.code);
+    Ok(OrganizeImportsResult { code })
 }
@@ -12,6 +12,7 @@ pub mod workspace;
 
 pub mod configuration;
 pub mod diagnostics;
+pub mod project;
 #[cfg(feature = "schema")]
 pub mod workspace_types;
 
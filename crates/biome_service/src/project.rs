@@ -0,0 +1,58 @@
+use biome_deserialize::json::deserialize_from_json_str;
+use biome_fs::{AutoSearchResult, FileSystem};
+use biome_json_parser::JsonParserOptions;
+use biome_project::{PackageJson, TailwindConfig};
+use std::path::Path;
+
+/// Returns the `package.json` files of the other packages of the workspace
+/// that the package of `manifest_path` depends on.
+///
+/// The package managers link the packages of a workspace in the directories `node_modules`,
+/// so a dependency is a package of the workspace if it's resolved to a symbolic link.
+pub fn find_workspace_packages(
+    fs: &dyn FileSystem,
+    manifest_path: &Path,
+    manifest_content: &str,
+) -> Vec<AutoSearchResult> {
+    let Some(manifest) = deserialize_from_json_str::<PackageJson>(
+        manifest_content,
+        JsonParserOptions::default(),
+        "",
+    )
+    .into_deserialized() else {
+        return Vec::new();
+    };
+    let Some(package_directory) = manifest_path.parent() else {
+        return Vec::new();
+    };
+    let mut result = Vec::new();
+    for dependency in manifest.all_dependencies() {
+        // The dependencies are resolved like Node.js does, from the closest `node_modules`.
+        let Some(dependency_path) = package_directory
+            .ancestors()
+            .map(|directory| directory.join("node_modules").join(dependency))
+            .find(|dependency_path| fs.path_exists(dependency_path))
+        else {
+            continue;
+        };
+        if !fs.path_is_symlink(&dependency_path) {
+            continue;
+        }
+        let file_path = dependency_path.join("package.json");
+        if let Ok(content) = fs.read_file_from_path(&file_path) {
+            result.push(AutoSearchResult { content, file_path });
+        }
+    }
+    result
+}
+
+/// Returns the Tailwind CSS configuration file next to the `package.json` file of `manifest_path`.
+///
+/// The names of [TailwindConfig::FILE_NAMES] are tried in order.
+pub fn find_tailwind_config(fs: &dyn FileSystem, manifest_path: &Path) -> Option<AutoSearchResult> {
+    TailwindConfig::FILE_NAMES.iter().find_map(|file_name| {
+        let file_path = manifest_path.with_file_name(file_name);
+        let content = fs.read_file_from_path(&file_path).ok()?;
+        Some(AutoSearchResult { content, file_path })
+    })
+}
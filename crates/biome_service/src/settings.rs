@@ -1,3 +1,4 @@
+use crate::file_handlers::{JsOrganizeImportsSettings, JsonLinterSettings, JsonSchemaSettings};
 use crate::workspace::{DocumentFileSource, ProjectKey, WorkspaceData};
 use crate::{Matcher, WorkspaceError};
 use biome_analyze::AnalyzerRules;
@@ -6,18 +7,24 @@ use biome_configuration::javascript::JsxRuntime;
 use biome_configuration::organize_imports::OrganizeImports;
 use biome_configuration::{
     push_to_analyzer_rules, ConfigurationDiagnostic, CssConfiguration, FilesConfiguration,
-    FormatterConfiguration, JavascriptConfiguration, JsonConfiguration, LinterConfiguration,
+    FormatterConfiguration, GraphqlConfiguration, HtmlConfiguration, JavascriptConfiguration,
+    JsonConfiguration, JsonSchemas, LinterConfiguration, MarkdownConfiguration,
     OverrideFormatterConfiguration, OverrideLinterConfiguration,
     OverrideOrganizeImportsConfiguration, Overrides, PartialConfiguration, PartialCssConfiguration,
     PartialJavascriptConfiguration, PartialJsonConfiguration, PlainIndentStyle, Rules,
+    TomlConfiguration, YamlConfiguration,
 };
 use biome_css_formatter::context::CssFormatOptions;
 use biome_css_parser::CssParserOptions;
 use biome_css_syntax::CssLanguage;
 use biome_deserialize::{Merge, StringSet};
 use biome_diagnostics::Category;
-use biome_formatter::{AttributePosition, IndentStyle, IndentWidth, LineEnding, LineWidth};
+use biome_formatter::{
+    AttributePosition, IndentStyle, IndentWidth, LineEnding, LineWidth, MaxEmptyLines,
+};
 use biome_fs::BiomePath;
+use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_analyze::metadata;
 use biome_js_formatter::context::JsFormatOptions;
 use biome_js_parser::JsParserOptions;
@@ -25,6 +32,9 @@ use biome_js_syntax::{JsFileSource, JsLanguage};
 use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::JsonParserOptions;
 use biome_json_syntax::JsonLanguage;
+use biome_markdown_syntax::MarkdownLanguage;
+use biome_toml_syntax::TomlLanguage;
+use biome_yaml_syntax::YamlLanguage;
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use indexmap::IndexSet;
 use rustc_hash::FxHashMap;
@@ -211,12 +221,35 @@ impl Settings {
         }
         // json settings
         if let Some(json) = configuration.json {
-            self.languages.json = JsonConfiguration::from(json).into();
+            let json = JsonConfiguration::from(json);
+            let linter = to_json_linter_settings(working_directory.clone(), &json.schemas)?;
+            self.languages.json = json.into();
+            self.languages.json.linter = linter;
         }
         // css settings
         if let Some(css) = configuration.css {
             self.languages.css = CssConfiguration::from(css).into();
         }
+        // yaml settings
+        if let Some(yaml) = configuration.yaml {
+            self.languages.yaml = YamlConfiguration::from(yaml).into();
+        }
+        // toml settings
+        if let Some(toml) = configuration.toml {
+            self.languages.toml = TomlConfiguration::from(toml).into();
+        }
+        // markdown settings
+        if let Some(markdown) = configuration.markdown {
+            self.languages.markdown = MarkdownConfiguration::from(markdown).into();
+        }
+        // graphql settings
+        if let Some(graphql) = configuration.graphql {
+            self.languages.graphql = GraphqlConfiguration::from(graphql).into();
+        }
+        // html settings
+        if let Some(html) = configuration.html {
+            self.languages.html = HtmlConfiguration::from(html).into();
+        }
 
         // NOTE: keep this last. Computing the overrides require reading the settings computed by the parent settings.
         if let Some(overrides) = configuration.overrides {
@@ -250,6 +283,36 @@ impl Settings {
         enabled == Some(&false)
     }
 
+    /// Whether the formatter is disabled for YAML files
+    pub fn yaml_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.yaml.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for TOML files
+    pub fn toml_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.toml.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for Markdown files
+    pub fn markdown_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.markdown.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for GraphQL files
+    pub fn graphql_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.graphql.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
+    /// Whether the formatter is disabled for HTML files
+    pub fn html_formatter_disabled(&self) -> bool {
+        let enabled = self.languages.html.formatter.enabled.as_ref();
+        enabled == Some(&false)
+    }
+
     /// Retrieves the settings of the linter
     pub fn linter(&self) -> &LinterSettings {
         &self.linter
@@ -313,6 +376,7 @@ pub struct FormatSettings {
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
     pub attribute_position: Option<AttributePosition>,
+    pub max_empty_lines: Option<MaxEmptyLines>,
     /// List of ignore paths/files
     pub ignored_files: Matcher,
     /// List of included paths/files
@@ -329,6 +393,7 @@ impl Default for FormatSettings {
             line_ending: Some(LineEnding::default()),
             line_width: Some(LineWidth::default()),
             attribute_position: Some(AttributePosition::default()),
+            max_empty_lines: Some(MaxEmptyLines::default()),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
         }
@@ -347,6 +412,7 @@ pub struct OverrideFormatSettings {
     pub indent_width: Option<IndentWidth>,
     pub line_ending: Option<LineEnding>,
     pub line_width: Option<LineWidth>,
+    pub max_empty_lines: Option<MaxEmptyLines>,
 }
 
 /// Linter settings for the entire workspace
@@ -422,6 +488,11 @@ pub struct LanguageListSettings {
     pub javascript: LanguageSettings<JsLanguage>,
     pub json: LanguageSettings<JsonLanguage>,
     pub css: LanguageSettings<CssLanguage>,
+    pub yaml: LanguageSettings<YamlLanguage>,
+    pub toml: LanguageSettings<TomlLanguage>,
+    pub markdown: LanguageSettings<MarkdownLanguage>,
+    pub graphql: LanguageSettings<GraphqlLanguage>,
+    pub html: LanguageSettings<HtmlLanguage>,
 }
 
 impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
@@ -437,15 +508,32 @@ impl From<JavascriptConfiguration> for LanguageSettings<JsLanguage> {
         language_setting.formatter.arrow_parentheses = Some(formatter.arrow_parentheses);
         language_setting.formatter.bracket_spacing = Some(formatter.bracket_spacing.into());
         language_setting.formatter.bracket_same_line = Some(formatter.bracket_same_line.into());
+        language_setting.formatter.single_attribute_per_line =
+            Some(formatter.single_attribute_per_line.into());
+        language_setting.formatter.object_wrap = Some(formatter.object_wrap);
+        language_setting.formatter.operator_position = Some(formatter.operator_position);
+        language_setting.formatter.format_jsdoc = Some(formatter.format_jsdoc.into());
+        language_setting.formatter.decorator_placement = Some(formatter.decorator_placement);
+        language_setting.formatter.ternary_style = Some(formatter.ternary_style);
+        language_setting.formatter.member_chain_min_calls = Some(formatter.member_chain_min_calls);
+        language_setting.formatter.inline_short_member_chains =
+            Some(formatter.inline_short_member_chains.into());
         language_setting.formatter.enabled = Some(formatter.enabled);
         language_setting.formatter.line_width = formatter.line_width;
         language_setting.formatter.indent_width = formatter.indent_width.map(Into::into);
         language_setting.formatter.indent_style = formatter.indent_style.map(Into::into);
+        language_setting.formatter.embedded_language_tags = Some(formatter.embedded_language_tags);
         language_setting.parser.parse_class_parameter_decorators =
             javascript.parser.unsafe_parameter_decorators_enabled;
 
         language_setting.globals = Some(javascript.globals.into_index_set());
         language_setting.environment = javascript.jsx_runtime.into();
+        language_setting.organize_imports.groups = javascript.organize_imports.groups.0;
+        language_setting.organize_imports.blank_lines_between_groups =
+            javascript.organize_imports.blank_lines_between_groups;
+        language_setting.organize_imports.merge_imports = javascript.organize_imports.merge_imports;
+        language_setting.organize_imports.merge_type_imports =
+            javascript.organize_imports.merge_type_imports;
 
         language_setting
     }
@@ -458,6 +546,8 @@ impl From<JsonConfiguration> for LanguageSettings<JsonLanguage> {
         language_setting.parser.allow_comments = json.parser.allow_comments;
         language_setting.parser.allow_trailing_commas = json.parser.allow_trailing_commas;
         language_setting.formatter.trailing_commas = json.formatter.trailing_commas;
+        language_setting.formatter.array_wrap = json.formatter.array_wrap;
+        language_setting.formatter.sort_package_json = json.formatter.sort_package_json;
         language_setting.formatter.enabled = Some(json.formatter.enabled);
         language_setting.formatter.line_width = json.formatter.line_width;
         language_setting.formatter.indent_width = json.formatter.indent_width.map(Into::into);
@@ -476,6 +566,86 @@ impl From<CssConfiguration> for LanguageSettings<CssLanguage> {
         language_setting.formatter.indent_width = css.formatter.indent_width.map(Into::into);
         language_setting.formatter.indent_style = css.formatter.indent_style.map(Into::into);
         language_setting.formatter.quote_style = Some(css.formatter.quote_style);
+        language_setting.formatter.collapse_single_declarations =
+            Some(css.formatter.collapse_single_declarations);
+        language_setting.parser.allow_wrong_line_comments = css.parser.allow_wrong_line_comments;
+        language_setting.parser.tailwind = css.parser.tailwind;
+
+        language_setting
+    }
+}
+
+impl From<YamlConfiguration> for LanguageSettings<YamlLanguage> {
+    fn from(yaml: YamlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<YamlLanguage> = LanguageSettings::default();
+
+        language_setting.formatter.enabled = Some(yaml.formatter.enabled);
+        language_setting.formatter.line_ending = yaml.formatter.line_ending;
+        language_setting.formatter.line_width = yaml.formatter.line_width;
+        language_setting.formatter.indent_width = yaml.formatter.indent_width.map(Into::into);
+        language_setting.formatter.quote_style = Some(yaml.formatter.quote_style);
+
+        language_setting
+    }
+}
+
+impl From<TomlConfiguration> for LanguageSettings<TomlLanguage> {
+    fn from(toml: TomlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<TomlLanguage> = LanguageSettings::default();
+
+        language_setting.formatter.enabled = Some(toml.formatter.enabled);
+        language_setting.formatter.line_ending = toml.formatter.line_ending;
+        language_setting.formatter.line_width = toml.formatter.line_width;
+        language_setting.formatter.indent_width = toml.formatter.indent_width.map(Into::into);
+        language_setting.formatter.indent_style = toml.formatter.indent_style.map(Into::into);
+        language_setting.formatter.quote_style = Some(toml.formatter.quote_style);
+
+        language_setting
+    }
+}
+
+impl From<MarkdownConfiguration> for LanguageSettings<MarkdownLanguage> {
+    fn from(markdown: MarkdownConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<MarkdownLanguage> = LanguageSettings::default();
+
+        language_setting.formatter.enabled = Some(markdown.formatter.enabled);
+        language_setting.formatter.line_ending = markdown.formatter.line_ending;
+        language_setting.formatter.line_width = markdown.formatter.line_width;
+        language_setting.formatter.prose_wrap = Some(markdown.formatter.prose_wrap);
+
+        language_setting
+    }
+}
+
+impl From<GraphqlConfiguration> for LanguageSettings<GraphqlLanguage> {
+    fn from(graphql: GraphqlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<GraphqlLanguage> = LanguageSettings::default();
+
+        language_setting.formatter.enabled = Some(graphql.formatter.enabled);
+        language_setting.formatter.line_ending = graphql.formatter.line_ending;
+        language_setting.formatter.line_width = graphql.formatter.line_width;
+        language_setting.formatter.indent_width = graphql.formatter.indent_width.map(Into::into);
+        language_setting.formatter.indent_style = graphql.formatter.indent_style.map(Into::into);
+        language_setting.formatter.arguments_indent = Some(graphql.formatter.arguments_indent);
+        language_setting.formatter.description_quote_style =
+            Some(graphql.formatter.description_quote_style);
+        language_setting.formatter.blank_lines_between_definitions =
+            Some(graphql.formatter.blank_lines_between_definitions);
+
+        language_setting
+    }
+}
+
+impl From<HtmlConfiguration> for LanguageSettings<HtmlLanguage> {
+    fn from(html: HtmlConfiguration) -> Self {
+        let mut language_setting: LanguageSettings<HtmlLanguage> = LanguageSettings::default();
+
+        language_setting.formatter.enabled = Some(html.formatter.enabled);
+        language_setting.formatter.line_ending = html.formatter.line_ending;
+        language_setting.formatter.line_width = html.formatter.line_width;
+        language_setting.formatter.indent_width = html.formatter.indent_width.map(Into::into);
+        language_setting.formatter.indent_style = html.formatter.indent_style.map(Into::into);
+        language_setting.formatter.attribute_position = Some(html.formatter.attribute_position);
 
         language_setting
     }
@@ -748,6 +918,26 @@ impl OverrideSettings {
             })
     }
 
+    /// It scans the current override rules and returns the organize imports settings of the last matched override
+    pub fn override_js_organize_imports(
+        &self,
+        path: &BiomePath,
+        base_setting: &JsOrganizeImportsSettings,
+    ) -> JsOrganizeImportsSettings {
+        self.patterns
+            .iter()
+            .fold(base_setting.clone(), |organize_imports, pattern| {
+                let included = pattern.include.matches_path(path);
+                let excluded = pattern.exclude.matches_path(path);
+
+                if included && !excluded {
+                    pattern.languages.javascript.organize_imports.clone()
+                } else {
+                    organize_imports
+                }
+            })
+    }
+
     /// It scans the current override rules and return the formatting options that of the first override is matched
     pub fn override_json_format_options(
         &self,
@@ -768,6 +958,28 @@ impl OverrideSettings {
         })
     }
 
+    /// It scans the current override rules and returns whether the keys of `package.json` are sorted,
+    /// according to the last matched override
+    pub fn override_json_sort_package_json(&self, path: &BiomePath, base_setting: bool) -> bool {
+        self.patterns
+            .iter()
+            .fold(base_setting, |sort_package_json, pattern| {
+                let included = pattern.include.matches_path(path);
+                let excluded = pattern.exclude.matches_path(path);
+
+                if included && !excluded {
+                    pattern
+                        .languages
+                        .json
+                        .formatter
+                        .sort_package_json
+                        .unwrap_or(sort_package_json)
+                } else {
+                    sort_package_json
+                }
+            })
+    }
+
     /// It scans the current override rules and return the formatting options that of the first override is matched
     pub fn override_css_format_options(
         &self,
@@ -834,6 +1046,8 @@ impl OverrideSettings {
 
                 return Some(CssParserOptions {
                     allow_wrong_line_comments: css_parser.allow_wrong_line_comments,
+                    tailwind: css_parser.tailwind,
+                    ..Default::default()
                 });
             }
         }
@@ -956,6 +1170,9 @@ impl OverrideSettingPattern {
         if let Some(line_width) = js_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(max_empty_lines) = formatter.max_empty_lines {
+            options.set_max_empty_lines(max_empty_lines);
+        }
         if let Some(quote_style) = js_formatter.quote_style {
             options.set_quote_style(quote_style);
         }
@@ -980,9 +1197,33 @@ impl OverrideSettingPattern {
         if let Some(bracket_same_line) = js_formatter.bracket_same_line {
             options.set_bracket_same_line(bracket_same_line);
         }
+        if let Some(single_attribute_per_line) = js_formatter.single_attribute_per_line {
+            options.set_single_attribute_per_line(single_attribute_per_line);
+        }
         if let Some(attribute_position) = js_formatter.attribute_position {
             options.set_attribute_position(attribute_position);
         }
+        if let Some(object_wrap) = js_formatter.object_wrap {
+            options.set_object_wrap(object_wrap);
+        }
+        if let Some(operator_position) = js_formatter.operator_position {
+            options.set_operator_position(operator_position);
+        }
+        if let Some(format_jsdoc) = js_formatter.format_jsdoc {
+            options.set_format_jsdoc(format_jsdoc);
+        }
+        if let Some(decorator_placement) = js_formatter.decorator_placement {
+            options.set_decorator_placement(decorator_placement);
+        }
+        if let Some(ternary_style) = js_formatter.ternary_style {
+            options.set_ternary_style(ternary_style);
+        }
+        if let Some(member_chain_min_calls) = js_formatter.member_chain_min_calls {
+            options.set_member_chain_min_calls(member_chain_min_calls);
+        }
+        if let Some(inline_short_member_chains) = js_formatter.inline_short_member_chains {
+            options.set_inline_short_member_chains(inline_short_member_chains);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_js_format_options.write() {
             let options = options.clone();
@@ -1013,9 +1254,15 @@ impl OverrideSettingPattern {
         if let Some(line_width) = json_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(max_empty_lines) = formatter.max_empty_lines {
+            options.set_max_empty_lines(max_empty_lines);
+        }
         if let Some(trailing_commas) = json_formatter.trailing_commas {
             options.set_trailing_commas(trailing_commas);
         }
+        if let Some(array_wrap) = json_formatter.array_wrap {
+            options.set_array_wrap(array_wrap);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_json_format_options.write() {
             let options = options.clone();
@@ -1046,9 +1293,15 @@ impl OverrideSettingPattern {
         if let Some(line_width) = css_formatter.line_width.or(formatter.line_width) {
             options.set_line_width(line_width);
         }
+        if let Some(max_empty_lines) = formatter.max_empty_lines {
+            options.set_max_empty_lines(max_empty_lines);
+        }
         if let Some(quote_style) = css_formatter.quote_style {
             options.set_quote_style(quote_style);
         }
+        if let Some(collapse_single_declarations) = css_formatter.collapse_single_declarations {
+            options.set_collapse_single_declarations(collapse_single_declarations);
+        }
 
         if let Ok(mut writeonly_cache) = self.cached_css_format_options.write() {
             let options = options.clone();
@@ -1125,6 +1378,30 @@ pub fn to_matcher(
     Ok(matcher)
 }
 
+/// Creates the [JsonLinterSettings] from the JSON schemas of the configuration
+///
+/// ## Errors
+///
+/// It can raise an error if the patterns aren't valid
+fn to_json_linter_settings(
+    working_directory: Option<PathBuf>,
+    schemas: &JsonSchemas,
+) -> Result<JsonLinterSettings, WorkspaceError> {
+    let schemas = schemas
+        .0
+        .iter()
+        .map(|schema| {
+            Ok(JsonSchemaSettings {
+                included_files: to_matcher(working_directory.clone(), Some(&schema.include))?,
+                path: working_directory
+                    .as_ref()
+                    .map_or_else(|| PathBuf::from(&schema.path), |dir| dir.join(&schema.path)),
+            })
+        })
+        .collect::<Result<_, WorkspaceError>>()?;
+    Ok(JsonLinterSettings { schemas })
+}
+
 fn to_git_ignore(path: PathBuf, matches: &[String]) -> Result<Gitignore, WorkspaceError> {
     let mut gitignore_builder = GitignoreBuilder::new(path.clone());
 
@@ -1235,6 +1512,7 @@ pub(crate) fn to_override_format_settings(
 
     let line_ending = conf.line_ending.or(format_settings.line_ending);
     let line_width = conf.line_width.or(format_settings.line_width);
+    let max_empty_lines = conf.max_empty_lines.or(format_settings.max_empty_lines);
     let format_with_errors = conf
         .format_with_errors
         .unwrap_or(format_settings.format_with_errors);
@@ -1245,6 +1523,7 @@ pub(crate) fn to_override_format_settings(
         indent_width,
         line_ending,
         line_width,
+        max_empty_lines,
         format_with_errors,
     }
 }
@@ -1289,6 +1568,30 @@ fn to_javascript_language_settings(
         .bracket_same_line
         .map(Into::into)
         .or(parent_formatter.bracket_same_line);
+    language_setting.formatter.single_attribute_per_line = formatter
+        .single_attribute_per_line
+        .map(Into::into)
+        .or(parent_formatter.single_attribute_per_line);
+    language_setting.formatter.object_wrap = formatter.object_wrap.or(parent_formatter.object_wrap);
+    language_setting.formatter.operator_position = formatter
+        .operator_position
+        .or(parent_formatter.operator_position);
+    language_setting.formatter.format_jsdoc = formatter
+        .format_jsdoc
+        .map(Into::into)
+        .or(parent_formatter.format_jsdoc);
+    language_setting.formatter.decorator_placement = formatter
+        .decorator_placement
+        .or(parent_formatter.decorator_placement);
+    language_setting.formatter.ternary_style =
+        formatter.ternary_style.or(parent_formatter.ternary_style);
+    language_setting.formatter.member_chain_min_calls = formatter
+        .member_chain_min_calls
+        .or(parent_formatter.member_chain_min_calls);
+    language_setting.formatter.inline_short_member_chains = formatter
+        .inline_short_member_chains
+        .map(Into::into)
+        .or(parent_formatter.inline_short_member_chains);
     language_setting.formatter.enabled = formatter.enabled.or(parent_formatter.enabled);
     language_setting.formatter.line_width = formatter.line_width.or(parent_formatter.line_width);
     language_setting.formatter.line_ending = formatter.line_ending.or(parent_formatter.line_ending);
@@ -1301,6 +1604,9 @@ fn to_javascript_language_settings(
         .indent_style
         .map(Into::into)
         .or(parent_formatter.indent_style);
+    language_setting.formatter.embedded_language_tags = formatter
+        .embedded_language_tags
+        .or_else(|| parent_formatter.embedded_language_tags.clone());
 
     let parser = conf.parser.take().unwrap_or_default();
     let parent_parser = &parent_settings.parser;
@@ -1308,8 +1614,20 @@ fn to_javascript_language_settings(
         .unsafe_parameter_decorators_enabled
         .unwrap_or(parent_parser.parse_class_parameter_decorators);
 
-    let organize_imports = conf.organize_imports;
-    if let Some(_organize_imports) = organize_imports {}
+    let organize_imports = conf.organize_imports.take().unwrap_or_default();
+    let parent_organize_imports = &parent_settings.organize_imports;
+    language_setting.organize_imports.groups = organize_imports
+        .groups
+        .map_or_else(|| parent_organize_imports.groups.clone(), |groups| groups.0);
+    language_setting.organize_imports.blank_lines_between_groups = organize_imports
+        .blank_lines_between_groups
+        .unwrap_or(parent_organize_imports.blank_lines_between_groups);
+    language_setting.organize_imports.merge_imports = organize_imports
+        .merge_imports
+        .unwrap_or(parent_organize_imports.merge_imports);
+    language_setting.organize_imports.merge_type_imports = organize_imports
+        .merge_type_imports
+        .unwrap_or(parent_organize_imports.merge_type_imports);
 
     language_setting.globals = conf
         .globals
@@ -1346,6 +1664,10 @@ fn to_json_language_settings(
     language_setting.formatter.trailing_commas = formatter
         .trailing_commas
         .or(parent_formatter.trailing_commas);
+    language_setting.formatter.array_wrap = formatter.array_wrap.or(parent_formatter.array_wrap);
+    language_setting.formatter.sort_package_json = formatter
+        .sort_package_json
+        .or(parent_formatter.sort_package_json);
 
     let parser = conf.parser.take().unwrap_or_default();
     let parent_parser = &parent_settings.parser;
@@ -1381,12 +1703,16 @@ fn to_css_language_settings(
         .map(Into::into)
         .or(parent_formatter.indent_style);
     language_setting.formatter.quote_style = formatter.quote_style.or(parent_formatter.quote_style);
+    language_setting.formatter.collapse_single_declarations = formatter
+        .collapse_single_declarations
+        .or(parent_formatter.collapse_single_declarations);
 
     let parser = conf.parser.take().unwrap_or_default();
     let parent_parser = &parent_settings.parser;
     language_setting.parser.allow_wrong_line_comments = parser
         .allow_wrong_line_comments
         .unwrap_or(parent_parser.allow_wrong_line_comments);
+    language_setting.parser.tailwind = parser.tailwind.unwrap_or(parent_parser.tailwind);
 
     language_setting
 }
@@ -1418,6 +1744,7 @@ pub fn to_format_settings(
         line_width: Some(conf.line_width),
         format_with_errors: conf.format_with_errors,
         attribute_position: Some(conf.attribute_position),
+        max_empty_lines: Some(conf.max_empty_lines),
         ignored_files: to_matcher(working_directory.clone(), Some(&conf.ignore))?,
         included_files: to_matcher(working_directory, Some(&conf.include))?,
     })
@@ -1445,6 +1772,7 @@ impl TryFrom<OverrideFormatterConfiguration> for FormatSettings {
             line_ending: conf.line_ending,
             line_width: conf.line_width,
             attribute_position: Some(AttributePosition::default()),
+            max_empty_lines: conf.max_empty_lines,
             format_with_errors: conf.format_with_errors.unwrap_or_default(),
             ignored_files: Matcher::empty(),
             included_files: Matcher::empty(),
@@ -168,6 +168,16 @@ impl FileFeaturesResult {
                 !can_format_css_yet()
                     || !settings.formatter().enabled
                     || settings.css_formatter_disabled()
+            } else if file_source.is_yaml_like() {
+                !settings.formatter().enabled || settings.yaml_formatter_disabled()
+            } else if file_source.is_toml_like() {
+                !settings.formatter().enabled || settings.toml_formatter_disabled()
+            } else if file_source.is_markdown_like() {
+                !settings.formatter().enabled || settings.markdown_formatter_disabled()
+            } else if file_source.is_graphql_like() {
+                !settings.formatter().enabled || settings.graphql_formatter_disabled()
+            } else if file_source.is_html_like() {
+                !settings.formatter().enabled || settings.html_formatter_disabled()
             } else {
                 !settings.formatter().enabled
             };
@@ -442,6 +452,19 @@ pub struct OpenProjectParams {
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UpdateProjectParams {
     pub path: BiomePath,
+    /// The path of the `tsconfig.json` file of the project, if any.
+    /// The file must be opened with [Workspace::open_project].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tsconfig_path: Option<BiomePath>,
+    /// The path of the Tailwind CSS configuration file of the project, if any.
+    /// The file must be opened with [Workspace::open_project].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tailwind_config_path: Option<BiomePath>,
+    /// The paths of the `package.json` files of the other packages of the workspace
+    /// that the project depends on.
+    /// The files must be opened with [Workspace::open_project].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub workspace_package_paths: Vec<BiomePath>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -556,6 +579,8 @@ pub enum FixFileMode {
     SafeFixes,
     /// Applies [safe](biome_diagnostics::Applicability::Always) and [unsafe](biome_diagnostics::Applicability::MaybeIncorrect) fixes
     SafeAndUnsafeFixes,
+    /// Applies the code actions that suppress the rules, instead of the fixes
+    ApplySuppressions,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -564,6 +589,10 @@ pub struct FixFileParams {
     pub path: BiomePath,
     pub fix_file_mode: FixFileMode,
     pub should_format: bool,
+    /// Fixes only the given rule or rule group
+    pub rule: Option<RuleSelector>,
+    /// The explanation of the suppression comments, when the suppressions are applied
+    pub suppression_reason: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -918,6 +947,12 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
             })
     }
 
+    pub fn get_formatter_ir(&self) -> Result<String, WorkspaceError> {
+        self.workspace.get_formatter_ir(GetFormatterIRParams {
+            path: self.path.clone(),
+        })
+    }
+
     pub fn change_file(&self, version: i32, content: String) -> Result<(), WorkspaceError> {
         self.workspace.change_file(ChangeFileParams {
             path: self.path.clone(),
@@ -977,11 +1012,15 @@ impl<'app, W: Workspace + ?Sized> FileGuard<'app, W> {
         &self,
         fix_file_mode: FixFileMode,
         should_format: bool,
+        rule: Option<RuleSelector>,
+        suppression_reason: Option<String>,
     ) -> Result<FixFileResult, WorkspaceError> {
         self.workspace.fix_file(FixFileParams {
             path: self.path.clone(),
             fix_file_mode,
             should_format,
+            rule,
+            suppression_reason,
         })
     }
 
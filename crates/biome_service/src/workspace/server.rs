@@ -8,7 +8,8 @@ use super::{
     UnregisterProjectFolderParams, UpdateProjectParams, UpdateSettingsParams,
 };
 use crate::file_handlers::{
-    Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams, ParseResult,
+    domain_rules, Capabilities, CodeActionsParams, DocumentFileSource, FixAllParams, LintParams,
+    ParseResult,
 };
 use crate::settings::{WorkspaceSettings, WorkspaceSettingsHandleMut};
 use crate::workspace::{
@@ -19,13 +20,18 @@ use crate::{
     file_handlers::Features, settings::WorkspaceSettingsHandle, Workspace, WorkspaceError,
 };
 use biome_analyze::AnalysisFilter;
+use biome_configuration::linter::RuleSelector;
 use biome_diagnostics::{
     serde::Diagnostic as SerdeDiagnostic, Diagnostic, DiagnosticExt, Severity,
 };
 use biome_formatter::Printed;
 use biome_fs::{BiomePath, ConfigName};
 use biome_grit_patterns::GritQuery;
-use biome_json_parser::{parse_json_with_cache, JsonParserOptions};
+use biome_js_parser::{parse_js_with_cache, JsParserOptions};
+use biome_js_syntax::JsFileSource;
+use biome_json_analyze::json_schema::{declared_schema, JsonSchema};
+use biome_json_analyze::tsconfig::{is_tsconfig, TsconfigExtends};
+use biome_json_parser::{parse_json, parse_json_with_cache, JsonParserOptions};
 use biome_json_syntax::JsonFileSource;
 use biome_parser::AnyParse;
 use biome_project::NodeJsProject;
@@ -36,6 +42,7 @@ use std::borrow::Borrow;
 use std::ffi::OsStr;
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
 use std::{panic::RefUnwindSafe, sync::RwLock};
 use tracing::{debug, info, info_span};
 
@@ -54,10 +61,18 @@ pub(super) struct WorkspaceServer {
     manifests: DashMap<BiomePath, NodeJsProject>,
     /// The current focused project
     current_project_path: RwLock<Option<BiomePath>>,
+    /// The path of the `tsconfig.json` file of the current project
+    current_tsconfig_path: RwLock<Option<BiomePath>>,
+    /// The path of the Tailwind CSS configuration file of the current project
+    current_tailwind_config_path: RwLock<Option<BiomePath>>,
+    /// The paths of the `package.json` files of the other packages of the workspace
+    current_workspace_package_paths: RwLock<Vec<BiomePath>>,
     /// Stores the document sources used across the workspace
     file_sources: RwLock<IndexSet<DocumentFileSource>>,
     /// Stores patterns to search for.
     patterns: DashMap<PatternId, GritQuery>,
+    /// Stores the JSON schemas loaded to validate the JSON documents
+    json_schemas: DashMap<BiomePath, Arc<JsonSchema>>,
 }
 
 /// The `Workspace` object is long-lived, so we want it to be able to cross
@@ -93,8 +108,12 @@ impl WorkspaceServer {
             file_features: DashMap::default(),
             manifests: DashMap::default(),
             current_project_path: RwLock::default(),
+            current_tsconfig_path: RwLock::default(),
+            current_tailwind_config_path: RwLock::default(),
+            current_workspace_package_paths: RwLock::default(),
             file_sources: RwLock::default(),
             patterns: Default::default(),
+            json_schemas: DashMap::default(),
         }
     }
 
@@ -157,19 +176,73 @@ impl WorkspaceServer {
                 Entry::Occupied(entry) => Ok(Some(entry.get().clone())),
                 Entry::Vacant(entry) => {
                     let path = entry.key();
-                    let mut document = self
-                        .documents
-                        .get_mut(path)
-                        .ok_or_else(WorkspaceError::not_found)?;
-                    let document = &mut *document;
-                    let parsed = parse_json_with_cache(
-                        document.content.as_str(),
-                        &mut document.node_cache,
-                        JsonParserOptions::default(),
-                    );
-
                     let mut node_js_project = NodeJsProject::default();
-                    node_js_project.from_root(&parsed.tree());
+                    {
+                        let mut document = self
+                            .documents
+                            .get_mut(path)
+                            .ok_or_else(WorkspaceError::not_found)?;
+                        let document = &mut *document;
+                        let parsed = parse_json_with_cache(
+                            document.content.as_str(),
+                            &mut document.node_cache,
+                            JsonParserOptions::default(),
+                        );
+                        node_js_project.from_root(&parsed.tree());
+                    }
+
+                    let tsconfig_path = self.current_tsconfig_path.read().unwrap();
+                    if let Some(tsconfig_path) = tsconfig_path.as_ref() {
+                        if let Some(mut document) = self.documents.get_mut(tsconfig_path) {
+                            let document = &mut *document;
+                            let parsed = parse_json_with_cache(
+                                document.content.as_str(),
+                                &mut document.node_cache,
+                                JsonParserOptions::default()
+                                    .with_allow_comments()
+                                    .with_allow_trailing_commas(),
+                            );
+                            node_js_project.tsconfig_from_root(tsconfig_path, &parsed.tree());
+                        }
+                    }
+
+                    let tailwind_config_path = self.current_tailwind_config_path.read().unwrap();
+                    if let Some(tailwind_config_path) = tailwind_config_path.as_ref() {
+                        if let Some(mut document) = self.documents.get_mut(tailwind_config_path) {
+                            let document = &mut *document;
+                            if tailwind_config_path.extension_as_str() == Some("json") {
+                                let parsed = parse_json_with_cache(
+                                    document.content.as_str(),
+                                    &mut document.node_cache,
+                                    JsonParserOptions::default(),
+                                );
+                                node_js_project.tailwind_config_from_json_root(&parsed.tree());
+                            } else {
+                                let parsed = parse_js_with_cache(
+                                    document.content.as_str(),
+                                    JsFileSource::try_from(tailwind_config_path.as_path())
+                                        .unwrap_or_default(),
+                                    JsParserOptions::default(),
+                                    &mut document.node_cache,
+                                );
+                                node_js_project.tailwind_config_from_js_root(&parsed.tree());
+                            }
+                        }
+                    }
+
+                    let workspace_package_paths =
+                        self.current_workspace_package_paths.read().unwrap();
+                    for workspace_package_path in workspace_package_paths.iter() {
+                        if let Some(mut document) = self.documents.get_mut(workspace_package_path) {
+                            let document = &mut *document;
+                            let parsed = parse_json_with_cache(
+                                document.content.as_str(),
+                                &mut document.node_cache,
+                                JsonParserOptions::default(),
+                            );
+                            node_js_project.workspace_package_from_root(&parsed.tree());
+                        }
+                    }
 
                     Ok(Some(entry.insert(node_js_project).clone()))
                 }
@@ -179,6 +252,82 @@ impl WorkspaceServer {
         }
     }
 
+    /// Returns the JSON schema of a JSON document: the local schema declared by its `$schema`
+    /// property, or else the first schema mapped to its path in the configuration.
+    ///
+    /// The schemas are read from the documents of the workspace, or else from the file system.
+    /// The remote schemas aren't fetched.
+    fn get_json_schema(&self, path: &BiomePath, parse: &AnyParse) -> Option<Arc<JsonSchema>> {
+        if !matches!(self.get_file_source(path), DocumentFileSource::Json(_)) {
+            return None;
+        }
+        let declared_schema_path = declared_schema(&parse.tree()).and_then(|schema| {
+            let schema = schema.strip_prefix("file://").unwrap_or(&schema);
+            if schema.contains("://") {
+                return None;
+            }
+            let directory = path.parent().unwrap_or(Path::new(""));
+            Some(BiomePath::new(directory.join(schema)))
+        });
+        if let Some(schema) =
+            declared_schema_path.and_then(|schema_path| self.load_json_schema(schema_path))
+        {
+            return Some(schema);
+        }
+        let schema_path = {
+            let workspace = self.workspace();
+            let settings = workspace.settings();
+            settings
+                .languages
+                .json
+                .linter
+                .schemas
+                .iter()
+                .find(|schema| schema.included_files.matches_path(path))
+                .map(|schema| BiomePath::new(&schema.path))?
+        };
+        self.load_json_schema(schema_path)
+    }
+
+    /// Resolves the configuration files extended by a configuration file of TypeScript.
+    ///
+    /// A configuration file exists if it's a document of the workspace, or a file of the file system.
+    fn get_tsconfig_extends(
+        &self,
+        path: &BiomePath,
+        parse: &AnyParse,
+    ) -> Option<Arc<TsconfigExtends>> {
+        if !matches!(self.get_file_source(path), DocumentFileSource::Json(_)) || !is_tsconfig(path)
+        {
+            return None;
+        }
+        let directory = path.parent().unwrap_or(Path::new(""));
+        Some(Arc::new(TsconfigExtends::resolve(
+            &parse.tree(),
+            directory,
+            |file| self.documents.contains_key(&BiomePath::new(file)) || file.exists(),
+        )))
+    }
+
+    fn load_json_schema(&self, path: BiomePath) -> Option<Arc<JsonSchema>> {
+        if let Some(schema) = self.json_schemas.get(&path) {
+            return Some(schema.clone());
+        }
+        let content = match self.documents.get(&path) {
+            Some(document) => document.content.clone(),
+            None => fs::read_to_string(path.as_path()).ok()?,
+        };
+        let parsed = parse_json(
+            &content,
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+        );
+        let schema = Arc::new(JsonSchema::from_root(&parsed.tree())?);
+        self.json_schemas.insert(path, schema.clone());
+        Some(schema)
+    }
+
     fn get_source(&self, index: usize) -> Option<DocumentFileSource> {
         let file_sources = self.file_sources.read().unwrap();
         file_sources.get_index(index).copied()
@@ -355,7 +504,7 @@ impl Workspace for WorkspaceServer {
             }
             Entry::Vacant(entry) => {
                 let capabilities = self.get_file_capabilities(&params.path);
-                let language = DocumentFileSource::from_path(&params.path);
+                let language = self.get_file_source(&params.path);
                 let path = params.path.as_path();
                 let settings = self.workspace();
                 let settings = settings.settings();
@@ -365,10 +514,7 @@ impl Workspace for WorkspaceServer {
                     .with_capabilities(&capabilities)
                     .with_settings_and_language(settings, &language, path);
 
-                if settings.files.ignore_unknown
-                    && language == DocumentFileSource::Unknown
-                    && self.get_file_source(&params.path) == DocumentFileSource::Unknown
-                {
+                if settings.files.ignore_unknown && language == DocumentFileSource::Unknown {
                     file_features.ignore_not_supported();
                 } else if file_name == Some(ConfigName::biome_json())
                     || file_name == Some(ConfigName::biome_jsonc())
@@ -429,6 +575,7 @@ impl Workspace for WorkspaceServer {
                 .unwrap_or(DocumentFileSource::from_path(&params.path)),
         );
         self.syntax.remove(&params.path);
+        self.json_schemas.remove(&params.path);
         self.documents.insert(
             params.path.clone(),
             Document {
@@ -447,6 +594,7 @@ impl Workspace for WorkspaceServer {
     fn open_project(&self, params: OpenProjectParams) -> Result<(), WorkspaceError> {
         let index = self.set_source(JsonFileSource::json().into());
         self.syntax.remove(&params.path);
+        self.json_schemas.remove(&params.path);
         self.documents.insert(
             params.path,
             Document {
@@ -483,6 +631,15 @@ impl Workspace for WorkspaceServer {
 
     fn update_current_project(&self, params: UpdateProjectParams) -> Result<(), WorkspaceError> {
         let mut current_project_path = self.current_project_path.write().unwrap();
+        let mut current_tsconfig_path = self.current_tsconfig_path.write().unwrap();
+        let mut current_tailwind_config_path = self.current_tailwind_config_path.write().unwrap();
+        let mut current_workspace_package_paths =
+            self.current_workspace_package_paths.write().unwrap();
+        // The project must be loaded again with the new configuration files and workspace packages
+        self.manifests.remove(&params.path);
+        *current_tsconfig_path = params.tsconfig_path;
+        *current_tailwind_config_path = params.tailwind_config_path;
+        *current_workspace_package_paths = params.workspace_package_paths;
         let _ = current_project_path.insert(params.path);
         Ok(())
     }
@@ -560,6 +717,7 @@ impl Workspace for WorkspaceServer {
         }
 
         self.syntax.remove(&params.path);
+        self.json_schemas.remove(&params.path);
         Ok(())
     }
 
@@ -570,6 +728,7 @@ impl Workspace for WorkspaceServer {
             .ok_or_else(WorkspaceError::not_found)?;
 
         self.syntax.remove(&params.path);
+        self.json_schemas.remove(&params.path);
         Ok(())
     }
 
@@ -580,7 +739,18 @@ impl Workspace for WorkspaceServer {
         params: PullDiagnosticsParams,
     ) -> Result<PullDiagnosticsResult, WorkspaceError> {
         let parse = self.get_parse(params.path.clone())?;
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
+        let (manifest, tsconfig, tailwind_config, workspace_packages) =
+            match self.get_current_project()? {
+                Some(project) => (
+                    Some(project.manifest),
+                    project.tsconfig,
+                    project.tailwind_config,
+                    project.workspace_packages,
+                ),
+                None => (None, None, None, Vec::new()),
+            };
+        let json_schema = self.get_json_schema(&params.path, &parse);
+        let tsconfig_extends = self.get_tsconfig_extends(&params.path, &parse);
         let (diagnostics, errors, skipped_diagnostics) =
             if let Some(lint) = self.get_file_capabilities(&params.path).analyzer.lint {
                 info_span!("Pulling diagnostics", categories =? params.categories).in_scope(|| {
@@ -593,6 +763,12 @@ impl Workspace for WorkspaceServer {
                         language: self.get_file_source(&params.path),
                         categories: params.categories,
                         manifest,
+                        tsconfig,
+                        tailwind_config,
+                        workspace_packages,
+                        json_schema,
+                        tsconfig_extends,
+                        template: None,
                     });
 
                     (
@@ -637,7 +813,16 @@ impl Workspace for WorkspaceServer {
 
         let parse = self.get_parse(params.path.clone())?;
         let workspace = self.workspace();
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
+        let (manifest, tsconfig, tailwind_config, workspace_packages) =
+            match self.get_current_project()? {
+                Some(project) => (
+                    Some(project.manifest),
+                    project.tsconfig,
+                    project.tailwind_config,
+                    project.workspace_packages,
+                ),
+                None => (None, None, None, Vec::new()),
+            };
         let language = self.get_file_source(&params.path);
         Ok(code_actions(CodeActionsParams {
             parse,
@@ -645,6 +830,9 @@ impl Workspace for WorkspaceServer {
             workspace,
             path: &params.path,
             manifest,
+            tsconfig,
+            tailwind_config,
+            workspace_packages,
             language,
         }))
     }
@@ -727,24 +915,60 @@ impl Workspace for WorkspaceServer {
         let parse = self.get_parse(params.path.clone())?;
         // Compute final rules (taking `overrides` into account)
         let rules = settings.as_rules(params.path.as_path());
-        let rule_filter_list = rules
-            .as_ref()
-            .map(|rules| rules.as_enabled_rules())
-            .unwrap_or_default()
-            .into_iter()
-            .collect::<Vec<_>>();
+        let (manifest, tsconfig, tailwind_config, workspace_packages) =
+            match self.get_current_project()? {
+                Some(project) => (
+                    Some(project.manifest),
+                    project.tsconfig,
+                    project.tailwind_config,
+                    project.workspace_packages,
+                ),
+                None => (None, None, None, Vec::new()),
+            };
+        let rule_filter_list = match params.rule {
+            // Only the fixes of the rule or group of `--rule` are applied
+            Some(RuleSelector::Group(group)) => rules
+                .as_ref()
+                .map(|rules| rules.as_enabled_rules())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|rule_filter| rule_filter.group() == group.as_str())
+                .collect::<Vec<_>>(),
+            Some(rule) => vec![rule.into()],
+            None => {
+                let mut rule_filter_list = rules
+                    .as_ref()
+                    .map(|rules| rules.as_enabled_rules())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                rule_filter_list.extend(domain_rules(
+                    params.path.as_path(),
+                    manifest.as_ref(),
+                    rules.as_deref(),
+                ));
+                rule_filter_list
+            }
+        };
         let filter = AnalysisFilter::from_enabled_rules(Some(rule_filter_list.as_slice()));
-        let manifest = self.get_current_project()?.map(|pr| pr.manifest);
         let language = self.get_file_source(&params.path);
+        let json_schema = self.get_json_schema(&params.path, &parse);
+        let tsconfig_extends = self.get_tsconfig_extends(&params.path, &parse);
         fix_all(FixAllParams {
             parse,
             rules: rules.as_ref().map(|x| x.borrow()),
             fix_file_mode: params.fix_file_mode,
+            suppression_reason: params.suppression_reason,
             filter,
             settings: self.workspace(),
             should_format: params.should_format,
             biome_path: &params.path,
             manifest,
+            tsconfig,
+            tailwind_config,
+            workspace_packages,
+            json_schema,
+            tsconfig_extends,
             document_file_source: language,
         })
     }
@@ -811,8 +1035,8 @@ impl Workspace for WorkspaceServer {
             .organize_imports
             .ok_or_else(self.build_capability_error(&params.path))?;
 
-        let parse = self.get_parse(params.path)?;
-        let result = organize_imports(parse)?;
+        let parse = self.get_parse(params.path.clone())?;
+        let result = organize_imports(&params.path, parse, self.workspace())?;
 
         Ok(result)
     }
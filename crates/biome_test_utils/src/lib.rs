@@ -3,10 +3,11 @@ use biome_analyze::{AnalyzerAction, AnalyzerConfiguration, AnalyzerOptions, Anal
 use biome_configuration::PartialConfiguration;
 use biome_console::fmt::{Formatter, Termcolor};
 use biome_console::markup;
+use biome_css_parser::CssParserOptions;
 use biome_diagnostics::termcolor::Buffer;
 use biome_diagnostics::{DiagnosticExt, Error, PrintDiagnostic};
 use biome_json_parser::{JsonParserOptions, ParseDiagnostic};
-use biome_project::PackageJson;
+use biome_project::{PackageJson, TailwindConfig, TsConfigJson};
 use biome_rowan::{SyntaxKind, SyntaxNode, SyntaxSlot};
 use biome_service::configuration::to_analyzer_rules;
 use biome_service::settings::{ServiceLanguage, Settings};
@@ -107,6 +108,31 @@ pub fn create_analyzer_options(
     }
 }
 
+/// Creates the options of the CSS parser from the ".options.json" file of a test,
+/// so a test can enable a syntax such as the directives of Tailwind CSS.
+pub fn create_css_parser_options(input_file: &Path) -> CssParserOptions {
+    let options_file = input_file.with_extension("options.json");
+    let Ok(json) = std::fs::read_to_string(options_file) else {
+        return CssParserOptions::default();
+    };
+    let deserialized = biome_deserialize::json::deserialize_from_json_str::<PartialConfiguration>(
+        json.as_str(),
+        JsonParserOptions::default(),
+        "",
+    );
+    let parser = deserialized
+        .into_deserialized()
+        .and_then(|configuration| configuration.css)
+        .and_then(|css| css.parser)
+        .unwrap_or_default();
+
+    CssParserOptions {
+        allow_wrong_line_comments: parser.allow_wrong_line_comments.unwrap_or_default(),
+        tailwind: parser.tailwind.unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
 pub fn load_manifest(input_file: &Path, diagnostics: &mut Vec<String>) -> Option<PackageJson> {
     let options_file = input_file.with_extension("package.json");
     if let Ok(json) = std::fs::read_to_string(options_file.clone()) {
@@ -136,6 +162,113 @@ pub fn load_manifest(input_file: &Path, diagnostics: &mut Vec<String>) -> Option
     None
 }
 
+/// Loads the file `<name>.tsconfig.json` next to `input_file`.
+///
+/// The targets of the path aliases are resolved from the directory of `input_file`.
+pub fn load_tsconfig(input_file: &Path, diagnostics: &mut Vec<String>) -> Option<TsConfigJson> {
+    let tsconfig_file = input_file.with_extension("tsconfig.json");
+    if let Ok(json) = std::fs::read_to_string(tsconfig_file.clone()) {
+        let deserialized = biome_deserialize::json::deserialize_from_json_str::<TsConfigJson>(
+            json.as_str(),
+            JsonParserOptions::default()
+                .with_allow_comments()
+                .with_allow_trailing_commas(),
+            "",
+        );
+        if deserialized.has_errors() {
+            diagnostics.extend(
+                deserialized
+                    .into_diagnostics()
+                    .into_iter()
+                    .map(|diagnostic| {
+                        diagnostic_to_string(
+                            tsconfig_file.file_stem().unwrap().to_str().unwrap(),
+                            &json,
+                            diagnostic,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        } else {
+            return deserialized.into_deserialized().map(|mut tsconfig| {
+                tsconfig.directory = input_file.parent().unwrap().to_path_buf();
+                tsconfig
+            });
+        }
+    }
+    None
+}
+
+/// Loads the file `<name>.tailwind.config.json` next to `input_file`.
+///
+/// The file is a precomputed Tailwind CSS configuration.
+pub fn load_tailwind_config(
+    input_file: &Path,
+    diagnostics: &mut Vec<String>,
+) -> Option<TailwindConfig> {
+    let tailwind_config_file = input_file.with_extension("tailwind.config.json");
+    if let Ok(json) = std::fs::read_to_string(tailwind_config_file.clone()) {
+        let deserialized = biome_deserialize::json::deserialize_from_json_str::<TailwindConfig>(
+            json.as_str(),
+            JsonParserOptions::default(),
+            "",
+        );
+        if deserialized.has_errors() {
+            diagnostics.extend(
+                deserialized
+                    .into_diagnostics()
+                    .into_iter()
+                    .map(|diagnostic| {
+                        diagnostic_to_string(
+                            tailwind_config_file.file_stem().unwrap().to_str().unwrap(),
+                            &json,
+                            diagnostic,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        } else {
+            return deserialized.into_deserialized();
+        }
+    }
+    None
+}
+
+/// Loads the file `<name>.workspace.json` next to `input_file`.
+///
+/// The file contains the array of the `package.json` files of the other packages of the workspace.
+pub fn load_workspace_packages(
+    input_file: &Path,
+    diagnostics: &mut Vec<String>,
+) -> Vec<PackageJson> {
+    let workspace_file = input_file.with_extension("workspace.json");
+    if let Ok(json) = std::fs::read_to_string(workspace_file.clone()) {
+        let deserialized = biome_deserialize::json::deserialize_from_json_str::<Vec<PackageJson>>(
+            json.as_str(),
+            JsonParserOptions::default(),
+            "",
+        );
+        if deserialized.has_errors() {
+            diagnostics.extend(
+                deserialized
+                    .into_diagnostics()
+                    .into_iter()
+                    .map(|diagnostic| {
+                        diagnostic_to_string(
+                            workspace_file.file_stem().unwrap().to_str().unwrap(),
+                            &json,
+                            diagnostic,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        } else {
+            return deserialized.into_deserialized().unwrap_or_default();
+        }
+    }
+    Vec::new()
+}
+
 pub fn diagnostic_to_string(name: &str, source: &str, diag: Error) -> String {
     let error = diag.with_file_path(name).with_file_source_code(source);
     let text = markup_to_string(biome_console::markup! {
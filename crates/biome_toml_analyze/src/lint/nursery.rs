@@ -0,0 +1,18 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub mod no_duplicate_toml_keys;
+pub mod no_invalid_toml_dates;
+pub mod use_sorted_toml_keys;
+
+declare_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: no_duplicate_toml_keys :: NoDuplicateTomlKeys ,
+            self :: no_invalid_toml_dates :: NoInvalidTomlDates ,
+            self :: use_sorted_toml_keys :: UseSortedTomlKeys ,
+        ]
+     }
+}
@@ -0,0 +1,215 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_rowan::{AstNode, AstSeparatedList, TextRange};
+use biome_toml_syntax::{
+    AnyTomlKeyValue, AnyTomlTable, AnyTomlValue, TomlKey, TomlKeyValue, TomlKeyValueList, TomlRoot,
+};
+use rustc_hash::FxHashMap;
+use std::collections::hash_map::Entry;
+
+declare_rule! {
+    /// Disallow the keys and the tables that are defined more than once in a TOML document.
+    ///
+    /// A TOML document that defines the same key twice is invalid, and the tools that read it reject it.
+    /// The keys are compared with the tables that contain them:
+    /// `name` in `[package]` and `name` in `[[bin]]` are different keys,
+    /// as well as the keys of two tables of the same array of tables.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```toml,expect_diagnostic
+    /// name = "biome"
+    /// name = "rome"
+    /// ```
+    ///
+    /// ```toml,expect_diagnostic
+    /// [dependencies]
+    /// serde = "1.0"
+    ///
+    /// [dependencies]
+    /// tokio = "1.0"
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```toml
+    /// [[bin]]
+    /// name = "first"
+    ///
+    /// [[bin]]
+    /// name = "second"
+    /// ```
+    ///
+    pub NoDuplicateTomlKeys {
+        version: "next",
+        name: "noDuplicateTomlKeys",
+        language: "toml",
+        recommended: false,
+    }
+}
+
+pub struct DuplicateKey {
+    /// The key as it's written in the document
+    name: String,
+    /// The range of the first definition of the key
+    first: TextRange,
+    /// The range of the key that defines it again
+    duplicate: TextRange,
+}
+
+impl Rule for NoDuplicateTomlKeys {
+    type Query = Ast<TomlRoot>;
+    type State = DuplicateKey;
+    type Signals = Vec<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let mut visitor = KeyVisitor::default();
+        visitor.visit_root(ctx.query());
+        visitor.duplicates
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.name;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.duplicate,
+                markup! {
+                    "The key "<Emphasis>{name}</Emphasis>" is already defined."
+                },
+            )
+            .detail(
+                state.first,
+                markup! {
+                    "It's first defined here."
+                },
+            )
+            .note(markup! {
+                "A TOML document can't define a key or a table more than once."
+            }),
+        )
+    }
+}
+
+#[derive(Default)]
+struct KeyVisitor {
+    /// The ranges of the keys that define the values and the tables, by path
+    definitions: FxHashMap<Vec<String>, TextRange>,
+    /// The number of tables of the arrays of tables, by path
+    arrays_of_tables: FxHashMap<Vec<String>, usize>,
+    duplicates: Vec<DuplicateKey>,
+}
+
+impl KeyVisitor {
+    fn visit_root(&mut self, root: &TomlRoot) {
+        self.visit_key_values(&[], &root.entries());
+
+        for table in root.tables() {
+            match table {
+                AnyTomlTable::TomlTable(table) => {
+                    let Ok(key) = table.key() else {
+                        continue;
+                    };
+                    let Some(path) = self.resolve(&key) else {
+                        continue;
+                    };
+                    self.define(path.clone(), &key);
+                    self.visit_key_values(&path, &table.entries());
+                }
+                AnyTomlTable::TomlArrayOfTables(table) => {
+                    let Ok(key) = table.key() else {
+                        continue;
+                    };
+                    let Some(mut path) = self.resolve(&key) else {
+                        continue;
+                    };
+                    if !self.arrays_of_tables.contains_key(&path) {
+                        // Only the first table defines the array
+                        self.define(path.clone(), &key);
+                    }
+                    let count = self.arrays_of_tables.entry(path.clone()).or_default();
+                    path.push(format!("[{count}]"));
+                    *count += 1;
+                    self.visit_key_values(&path, &table.entries());
+                }
+            }
+        }
+    }
+
+    fn visit_key_values(&mut self, path: &[String], entries: &TomlKeyValueList) {
+        for entry in entries {
+            if let AnyTomlKeyValue::TomlKeyValue(key_value) = entry {
+                self.visit_key_value(path, &key_value);
+            }
+        }
+    }
+
+    fn visit_key_value(&mut self, path: &[String], key_value: &TomlKeyValue) {
+        let Ok(key) = key_value.key() else {
+            return;
+        };
+        let Ok(names) = key.names() else {
+            return;
+        };
+        let mut path = path.to_vec();
+        path.extend(names.iter().map(ToString::to_string));
+        self.define(path.clone(), &key);
+
+        if let Ok(value) = key_value.value() {
+            self.visit_value(path, &value);
+        }
+    }
+
+    /// Visits the key-value pairs of the inline tables, including the ones in arrays.
+    fn visit_value(&mut self, mut path: Vec<String>, value: &AnyTomlValue) {
+        match value {
+            AnyTomlValue::TomlInlineTable(table) => {
+                for key_value in table.entries().iter().flatten() {
+                    self.visit_key_value(&path, &key_value);
+                }
+            }
+            AnyTomlValue::TomlArray(array) => {
+                for (index, element) in array.elements().iter().enumerate() {
+                    if let Ok(element) = element {
+                        path.push(format!("[{index}]"));
+                        self.visit_value(path.clone(), &element);
+                        path.pop();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the path of a table header, where the arrays of tables are followed by the index of their last table:
+    /// `[bin.metadata]` is a table of the last table of `[[bin]]`.
+    fn resolve(&self, key: &TomlKey) -> Option<Vec<String>> {
+        let names = key.names().ok()?;
+        let mut path = Vec::with_capacity(names.len());
+        for (index, name) in names.iter().enumerate() {
+            path.push(name.to_string());
+            if index + 1 < names.len() {
+                if let Some(count) = self.arrays_of_tables.get(&path) {
+                    path.push(format!("[{}]", count - 1));
+                }
+            }
+        }
+        Some(path)
+    }
+
+    fn define(&mut self, path: Vec<String>, key: &TomlKey) {
+        match self.definitions.entry(path) {
+            Entry::Occupied(entry) => self.duplicates.push(DuplicateKey {
+                name: key.syntax().text_trimmed().to_string(),
+                first: *entry.get(),
+                duplicate: key.range(),
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(key.range());
+            }
+        }
+    }
+}
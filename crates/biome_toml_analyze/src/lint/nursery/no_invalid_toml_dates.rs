@@ -0,0 +1,254 @@
+use biome_analyze::{context::RuleContext, declare_rule, Ast, Rule, RuleDiagnostic};
+use biome_console::markup;
+use biome_rowan::{TextRange, TextSize};
+use biome_toml_syntax::TomlDateTimeValue;
+use std::ops::Range;
+
+declare_rule! {
+    /// Disallow the date-times that don't exist or that aren't written in the TOML format.
+    ///
+    /// The date-times of TOML follow [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339):
+    /// the dates are written as `1979-05-27`, the times as `07:32:00`,
+    /// and the offsets as `Z` or `-07:00`.
+    /// The rule reports the dates that don't exist, such as `2023-02-29`,
+    /// and the times whose hours, minutes or seconds are out of range.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```toml,expect_diagnostic
+    /// released = 2023-02-29
+    /// ```
+    ///
+    /// ```toml,expect_diagnostic
+    /// start = 24:00:00
+    /// ```
+    ///
+    /// ```toml,expect_diagnostic
+    /// updated = 2024-01-01T10:00
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```toml
+    /// released = 2024-02-29
+    /// updated = 1979-05-27T07:32:00.999-07:00
+    /// ```
+    ///
+    pub NoInvalidTomlDates {
+        version: "next",
+        name: "noInvalidTomlDates",
+        language: "toml",
+        recommended: false,
+    }
+}
+
+pub enum DateTimeError {
+    /// The value isn't written in the date-time format
+    Format,
+    Month,
+    Day {
+        days_in_month: u32,
+    },
+    Hour,
+    Minute,
+    Second,
+}
+
+pub struct InvalidDateTime {
+    error: DateTimeError,
+    /// The range of the invalid component, or of the whole value when its format is invalid
+    range: TextRange,
+    /// The text of the invalid component
+    text: String,
+}
+
+impl Rule for NoInvalidTomlDates {
+    type Query = Ast<TomlDateTimeValue>;
+    type State = InvalidDateTime;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let value_token = ctx.query().value_token().ok()?;
+        let text = value_token.text_trimmed();
+        let (error, range) = check_date_time(text).err()?;
+        let start = value_token.text_trimmed_range().start();
+        Some(InvalidDateTime {
+            error,
+            range: TextRange::new(
+                start + TextSize::from(range.start as u32),
+                start + TextSize::from(range.end as u32),
+            ),
+            text: text[range].to_string(),
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let text = &state.text;
+        let diagnostic = match state.error {
+            DateTimeError::Format => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "This date-time isn't written in the TOML format."
+                },
+            )
+            .note(markup! {
+                "The date-times are written as "<Emphasis>"1979-05-27T07:32:00Z"</Emphasis>", "<Emphasis>"1979-05-27T07:32:00"</Emphasis>", "<Emphasis>"1979-05-27"</Emphasis>" or "<Emphasis>"07:32:00"</Emphasis>"."
+            }),
+            DateTimeError::Month => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The month "<Emphasis>{text}</Emphasis>" doesn't exist."
+                },
+            )
+            .note(markup! {
+                "The months go from 01 to 12."
+            }),
+            DateTimeError::Day { days_in_month } => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The day "<Emphasis>{text}</Emphasis>" doesn't exist in this month."
+                },
+            )
+            .note(markup! {
+                "This month has "{days_in_month}" days."
+            }),
+            DateTimeError::Hour => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The hour "<Emphasis>{text}</Emphasis>" doesn't exist."
+                },
+            )
+            .note(markup! {
+                "The hours go from 00 to 23."
+            }),
+            DateTimeError::Minute => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The minute "<Emphasis>{text}</Emphasis>" doesn't exist."
+                },
+            )
+            .note(markup! {
+                "The minutes go from 00 to 59."
+            }),
+            DateTimeError::Second => RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The second "<Emphasis>{text}</Emphasis>" doesn't exist."
+                },
+            )
+            .note(markup! {
+                "The seconds go from 00 to 59, or 60 for a leap second."
+            }),
+        };
+        Some(diagnostic)
+    }
+}
+
+/// Checks an offset date-time, a local date-time, a local date or a local time.
+///
+/// Returns the error and the range of the invalid component in `text`.
+fn check_date_time(text: &str) -> Result<(), (DateTimeError, Range<usize>)> {
+    let bytes = text.as_bytes();
+    let format_error = || (DateTimeError::Format, 0..text.len());
+
+    let time_start = if bytes.get(4) == Some(&b'-') {
+        let year = parse_digits(bytes, 0..4).ok_or_else(format_error)?;
+        let month = parse_digits(bytes, 5..7).ok_or_else(format_error)?;
+        let day = parse_digits(bytes, 8..10).ok_or_else(format_error)?;
+        if bytes[7] != b'-' {
+            return Err(format_error());
+        }
+        if !(1..=12).contains(&month) {
+            return Err((DateTimeError::Month, 5..7));
+        }
+        let days_in_month = days_in_month(year, month);
+        if !(1..=days_in_month).contains(&day) {
+            return Err((DateTimeError::Day { days_in_month }, 8..10));
+        }
+
+        match bytes.get(10) {
+            None => return Ok(()),
+            Some(b'T' | b't' | b' ') => 11,
+            Some(_) => return Err(format_error()),
+        }
+    } else {
+        0
+    };
+
+    let hour = parse_digits(bytes, time_start..time_start + 2).ok_or_else(format_error)?;
+    let minute = parse_digits(bytes, time_start + 3..time_start + 5).ok_or_else(format_error)?;
+    let second = parse_digits(bytes, time_start + 6..time_start + 8).ok_or_else(format_error)?;
+    if bytes[time_start + 2] != b':' || bytes[time_start + 5] != b':' {
+        return Err(format_error());
+    }
+    if hour > 23 {
+        return Err((DateTimeError::Hour, time_start..time_start + 2));
+    }
+    if minute > 59 {
+        return Err((DateTimeError::Minute, time_start + 3..time_start + 5));
+    }
+    if second > 60 {
+        return Err((DateTimeError::Second, time_start + 6..time_start + 8));
+    }
+
+    let mut position = time_start + 8;
+    if bytes.get(position) == Some(&b'.') {
+        let digits = bytes[position + 1..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return Err(format_error());
+        }
+        position += 1 + digits;
+    }
+
+    // Only the date-times have an offset
+    match &bytes[position..] {
+        [] => Ok(()),
+        [b'Z' | b'z'] if time_start > 0 => Ok(()),
+        [b'+' | b'-', ..] if time_start > 0 => {
+            let offset_hour =
+                parse_digits(bytes, position + 1..position + 3).ok_or_else(format_error)?;
+            let offset_minute =
+                parse_digits(bytes, position + 4..position + 6).ok_or_else(format_error)?;
+            if bytes[position + 3] != b':' || bytes.len() != position + 6 {
+                return Err(format_error());
+            }
+            if offset_hour > 23 {
+                return Err((DateTimeError::Hour, position + 1..position + 3));
+            }
+            if offset_minute > 59 {
+                return Err((DateTimeError::Minute, position + 4..position + 6));
+            }
+            Ok(())
+        }
+        _ => Err(format_error()),
+    }
+}
+
+/// Parses the digits of `bytes` in `range`, or returns `None` if one of them isn't a digit.
+fn parse_digits(bytes: &[u8], range: Range<usize>) -> Option<u32> {
+    bytes.get(range)?.iter().try_fold(0, |value, byte| {
+        byte.is_ascii_digit()
+            .then(|| value * 10 + u32::from(byte - b'0'))
+    })
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
@@ -0,0 +1,166 @@
+use crate::TomlRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, TextRange,
+};
+use biome_toml_syntax::{
+    AnyTomlKeyValue, TomlArrayOfTables, TomlInlineTable, TomlKey, TomlKeyValue, TomlKeyValueList,
+    TomlRoot, TomlTable,
+};
+
+declare_rule! {
+    /// Enforce the order of the keys of the TOML tables.
+    ///
+    /// The keys of a table are easier to find, and the changes of the table are easier to merge,
+    /// when the keys are sorted, such as the dependencies of a `Cargo.toml` file.
+    /// The keys are sorted in lexicographic order, part by part for the dotted keys.
+    ///
+    /// The key-value pairs of the tables and of the inline tables are checked,
+    /// but the tables themselves keep their order.
+    /// The keys aren't moved by the code action when they have comments.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```toml,expect_diagnostic
+    /// [dependencies]
+    /// serde = "1.0"
+    /// biome = "1.0"
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```toml
+    /// [dependencies]
+    /// biome = "1.0"
+    /// serde = { version = "1.0", features = ["derive"] }
+    /// ```
+    ///
+    pub UseSortedTomlKeys {
+        version: "next",
+        name: "useSortedTomlKeys",
+        language: "toml",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyTomlKeyValueContainer = TomlRoot | TomlTable | TomlArrayOfTables | TomlInlineTable
+}
+
+pub struct UnsortedKeys {
+    /// The indexes of the entries in their sorted order
+    order: Vec<usize>,
+    /// The name of the first entry that isn't at its place
+    misplaced: String,
+    /// The range of the key of the misplaced entry
+    range: TextRange,
+    /// The name of the entry that should come after the misplaced entry
+    before: String,
+}
+
+impl Rule for UseSortedTomlKeys {
+    type Query = Ast<AnyTomlKeyValueContainer>;
+    type State = UnsortedKeys;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let entries = list_entries(ctx.query())?;
+        let keys = entries
+            .iter()
+            .map(|entry| entry.key().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let names = keys
+            .iter()
+            .map(|key| key.names().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let mut order = (0..entries.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| {
+            let a = names[a].iter().map(|name| name.text());
+            let b = names[b].iter().map(|name| name.text());
+            a.cmp(b)
+        });
+        let index = order
+            .iter()
+            .enumerate()
+            .position(|(index, sorted_index)| index != *sorted_index)?;
+        Some(UnsortedKeys {
+            misplaced: key_text(&keys[order[index]]),
+            range: keys[order[index]].range(),
+            before: key_text(&keys[index]),
+            order,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.misplaced;
+        let before = &state.before;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The key "<Emphasis>{name}</Emphasis>" should be placed before "<Emphasis>{before}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The keys are sorted in lexicographic order."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<TomlRuleAction> {
+        let entries = list_entries(ctx.query())?;
+        // Moving an entry with comments could attach the comments to another entry
+        if entries
+            .iter()
+            .any(|entry| entry.syntax().has_comments_direct())
+        {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        for (index, sorted_index) in state.order.iter().enumerate() {
+            if index != *sorted_index {
+                mutation.replace_node(entries[index].clone(), entries[*sorted_index].clone());
+            }
+        }
+        Some(TomlRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Sort the keys." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+/// Returns the key-value pairs of the container, or `None` if one of them is invalid.
+fn list_entries(container: &AnyTomlKeyValueContainer) -> Option<Vec<TomlKeyValue>> {
+    match container {
+        AnyTomlKeyValueContainer::TomlRoot(root) => key_values(root.entries()),
+        AnyTomlKeyValueContainer::TomlTable(table) => key_values(table.entries()),
+        AnyTomlKeyValueContainer::TomlArrayOfTables(table) => key_values(table.entries()),
+        AnyTomlKeyValueContainer::TomlInlineTable(table) => {
+            table.entries().iter().map(Result::ok).collect()
+        }
+    }
+}
+
+fn key_values(list: TomlKeyValueList) -> Option<Vec<TomlKeyValue>> {
+    list.iter()
+        .map(|entry| match entry {
+            AnyTomlKeyValue::TomlKeyValue(key_value) => Some(key_value),
+            AnyTomlKeyValue::TomlBogus(_) => None,
+        })
+        .collect()
+}
+
+fn key_text(key: &TomlKey) -> String {
+    key.syntax().text_trimmed().to_string()
+}
@@ -0,0 +1,10 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type NoDuplicateTomlKeys =
+    <lint::nursery::no_duplicate_toml_keys::NoDuplicateTomlKeys as biome_analyze::Rule>::Options;
+pub type NoInvalidTomlDates =
+    <lint::nursery::no_invalid_toml_dates::NoInvalidTomlDates as biome_analyze::Rule>::Options;
+pub type UseSortedTomlKeys =
+    <lint::nursery::use_sorted_toml_keys::UseSortedTomlKeys as biome_analyze::Rule>::Options;
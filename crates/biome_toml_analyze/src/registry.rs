@@ -0,0 +1,7 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::RegistryVisitor;
+use biome_toml_syntax::TomlLanguage;
+pub fn visit_registry<V: RegistryVisitor<TomlLanguage>>(registry: &mut V) {
+    registry.record_category::<crate::lint::Lint>();
+}
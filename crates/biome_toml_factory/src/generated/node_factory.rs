@@ -0,0 +1,266 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+#![allow(clippy::redundant_closure)]
+#![allow(clippy::too_many_arguments)]
+use biome_rowan::AstNode;
+use biome_toml_syntax::{
+    TomlSyntaxElement as SyntaxElement, TomlSyntaxNode as SyntaxNode,
+    TomlSyntaxToken as SyntaxToken, *,
+};
+pub fn toml_array(
+    l_brack_token: SyntaxToken,
+    elements: TomlArrayElementList,
+    r_brack_token: SyntaxToken,
+) -> TomlArray {
+    TomlArray::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_ARRAY,
+        [
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(elements.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
+        ],
+    ))
+}
+pub fn toml_array_of_tables(
+    l_double_brack_token: SyntaxToken,
+    key: TomlKey,
+    r_double_brack_token: SyntaxToken,
+    entries: TomlKeyValueList,
+) -> TomlArrayOfTables {
+    TomlArrayOfTables::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_ARRAY_OF_TABLES,
+        [
+            Some(SyntaxElement::Token(l_double_brack_token)),
+            Some(SyntaxElement::Node(key.into_syntax())),
+            Some(SyntaxElement::Token(r_double_brack_token)),
+            Some(SyntaxElement::Node(entries.into_syntax())),
+        ],
+    ))
+}
+pub fn toml_bare_key(value_token: SyntaxToken) -> TomlBareKey {
+    TomlBareKey::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_BARE_KEY,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn toml_boolean_value(value_token_token: SyntaxToken) -> TomlBooleanValue {
+    TomlBooleanValue::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_BOOLEAN_VALUE,
+        [Some(SyntaxElement::Token(value_token_token))],
+    ))
+}
+pub fn toml_date_time_value(value_token: SyntaxToken) -> TomlDateTimeValue {
+    TomlDateTimeValue::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_DATE_TIME_VALUE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn toml_float_value(value_token: SyntaxToken) -> TomlFloatValue {
+    TomlFloatValue::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_FLOAT_VALUE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn toml_inline_table(
+    l_curly_token: SyntaxToken,
+    entries: TomlInlineTableEntryList,
+    r_curly_token: SyntaxToken,
+) -> TomlInlineTable {
+    TomlInlineTable::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_INLINE_TABLE,
+        [
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(entries.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
+    ))
+}
+pub fn toml_integer_value(value_token: SyntaxToken) -> TomlIntegerValue {
+    TomlIntegerValue::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_INTEGER_VALUE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn toml_key(parts: TomlKeyPartList) -> TomlKey {
+    TomlKey::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_KEY,
+        [Some(SyntaxElement::Node(parts.into_syntax()))],
+    ))
+}
+pub fn toml_key_value(key: TomlKey, eq_token: SyntaxToken, value: AnyTomlValue) -> TomlKeyValue {
+    TomlKeyValue::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_KEY_VALUE,
+        [
+            Some(SyntaxElement::Node(key.into_syntax())),
+            Some(SyntaxElement::Token(eq_token)),
+            Some(SyntaxElement::Node(value.into_syntax())),
+        ],
+    ))
+}
+pub fn toml_quoted_key(value_token: SyntaxToken) -> TomlQuotedKey {
+    TomlQuotedKey::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_QUOTED_KEY,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn toml_root(
+    entries: TomlKeyValueList,
+    tables: TomlTableList,
+    eof_token: SyntaxToken,
+) -> TomlRootBuilder {
+    TomlRootBuilder {
+        entries,
+        tables,
+        eof_token,
+        bom_token: None,
+    }
+}
+pub struct TomlRootBuilder {
+    entries: TomlKeyValueList,
+    tables: TomlTableList,
+    eof_token: SyntaxToken,
+    bom_token: Option<SyntaxToken>,
+}
+impl TomlRootBuilder {
+    pub fn with_bom_token(mut self, bom_token: SyntaxToken) -> Self {
+        self.bom_token = Some(bom_token);
+        self
+    }
+    pub fn build(self) -> TomlRoot {
+        TomlRoot::unwrap_cast(SyntaxNode::new_detached(
+            TomlSyntaxKind::TOML_ROOT,
+            [
+                self.bom_token.map(|token| SyntaxElement::Token(token)),
+                Some(SyntaxElement::Node(self.entries.into_syntax())),
+                Some(SyntaxElement::Node(self.tables.into_syntax())),
+                Some(SyntaxElement::Token(self.eof_token)),
+            ],
+        ))
+    }
+}
+pub fn toml_string_value(value_token: SyntaxToken) -> TomlStringValue {
+    TomlStringValue::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_STRING_VALUE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn toml_table(
+    l_brack_token: SyntaxToken,
+    key: TomlKey,
+    r_brack_token: SyntaxToken,
+    entries: TomlKeyValueList,
+) -> TomlTable {
+    TomlTable::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_TABLE,
+        [
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(key.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
+            Some(SyntaxElement::Node(entries.into_syntax())),
+        ],
+    ))
+}
+pub fn toml_array_element_list<I, S>(items: I, separators: S) -> TomlArrayElementList
+where
+    I: IntoIterator<Item = AnyTomlValue>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = TomlSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    TomlArrayElementList::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_ARRAY_ELEMENT_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn toml_inline_table_entry_list<I, S>(items: I, separators: S) -> TomlInlineTableEntryList
+where
+    I: IntoIterator<Item = TomlKeyValue>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = TomlSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    TomlInlineTableEntryList::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_INLINE_TABLE_ENTRY_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn toml_key_part_list<I, S>(items: I, separators: S) -> TomlKeyPartList
+where
+    I: IntoIterator<Item = AnyTomlKeyPart>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = TomlSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    TomlKeyPartList::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_KEY_PART_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn toml_key_value_list<I>(items: I) -> TomlKeyValueList
+where
+    I: IntoIterator<Item = AnyTomlKeyValue>,
+    I::IntoIter: ExactSizeIterator,
+{
+    TomlKeyValueList::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_KEY_VALUE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn toml_table_list<I>(items: I) -> TomlTableList
+where
+    I: IntoIterator<Item = AnyTomlTable>,
+    I::IntoIter: ExactSizeIterator,
+{
+    TomlTableList::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_TABLE_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn toml_bogus<I>(slots: I) -> TomlBogus
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    TomlBogus::unwrap_cast(SyntaxNode::new_detached(TomlSyntaxKind::TOML_BOGUS, slots))
+}
+pub fn toml_bogus_value<I>(slots: I) -> TomlBogusValue
+where
+    I: IntoIterator<Item = Option<SyntaxElement>>,
+    I::IntoIter: ExactSizeIterator,
+{
+    TomlBogusValue::unwrap_cast(SyntaxNode::new_detached(
+        TomlSyntaxKind::TOML_BOGUS_VALUE,
+        slots,
+    ))
+}
@@ -0,0 +1,12 @@
+use biome_rowan::TreeBuilder;
+use biome_toml_syntax::TomlLanguage;
+
+mod generated;
+pub mod make;
+pub use crate::generated::TomlSyntaxFactory;
+
+// Re-exported for tests
+#[doc(hidden)]
+pub use biome_toml_syntax as syntax;
+
+pub type TomlSyntaxTreeBuilder = TreeBuilder<'static, TomlLanguage, TomlSyntaxFactory>;
@@ -0,0 +1,47 @@
+use crate::prelude::*;
+use biome_formatter::QuoteStyle;
+use biome_toml_syntax::TomlSyntaxToken;
+
+/// Formats a string value or a quoted key.
+///
+/// The quotes of a single line string are replaced with the preferred quotes
+/// when its value can be written without escapes, since the literal strings (`'...'`) don't support them.
+/// The multi-line strings are printed as is.
+pub(crate) fn format_string(token: &TomlSyntaxToken) -> FormatString {
+    FormatString { token }
+}
+
+pub(crate) struct FormatString<'token> {
+    token: &'token TomlSyntaxToken,
+}
+
+impl Format<TomlFormatContext> for FormatString<'_> {
+    fn fmt(&self, f: &mut Formatter<TomlFormatContext>) -> FormatResult<()> {
+        match normalize_quotes(self.token.text_trimmed(), f.options().quote_style()) {
+            Some(normalized) => format_replaced(
+                self.token,
+                &dynamic_text(&normalized, self.token.text_trimmed_range().start()),
+            )
+            .fmt(f),
+            None => self.token.format().fmt(f),
+        }
+    }
+}
+
+/// Returns the single line string `text` with the `preferred` quotes,
+/// or `None` if it doesn't need to change or if its value contains a backslash or the preferred quote.
+fn normalize_quotes(text: &str, preferred: QuoteStyle) -> Option<String> {
+    if text.starts_with("\"\"\"") || text.starts_with("'''") {
+        return None;
+    }
+
+    let quote = text.chars().next()?;
+    if !matches!(quote, '"' | '\'') || quote == preferred.as_char() {
+        return None;
+    }
+
+    // A backslash starts an escape sequence in a basic string, but it's a regular character in a literal string
+    let content = &text[1..text.len() - 1];
+    (!content.contains(['\\', preferred.as_char()]))
+        .then(|| std::format!("{quote}{content}{quote}", quote = preferred.as_char()))
+}
@@ -0,0 +1,15 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_toml_syntax::AnyTomlTable;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyTomlTable;
+impl FormatRule<AnyTomlTable> for FormatAnyTomlTable {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &AnyTomlTable, f: &mut TomlFormatter) -> FormatResult<()> {
+        match node {
+            AnyTomlTable::TomlArrayOfTables(node) => node.format().fmt(f),
+            AnyTomlTable::TomlTable(node) => node.format().fmt(f),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_toml_syntax::AnyTomlValue;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyTomlValue;
+impl FormatRule<AnyTomlValue> for FormatAnyTomlValue {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &AnyTomlValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        match node {
+            AnyTomlValue::TomlArray(node) => node.format().fmt(f),
+            AnyTomlValue::TomlBogusValue(node) => node.format().fmt(f),
+            AnyTomlValue::TomlBooleanValue(node) => node.format().fmt(f),
+            AnyTomlValue::TomlDateTimeValue(node) => node.format().fmt(f),
+            AnyTomlValue::TomlFloatValue(node) => node.format().fmt(f),
+            AnyTomlValue::TomlInlineTable(node) => node.format().fmt(f),
+            AnyTomlValue::TomlIntegerValue(node) => node.format().fmt(f),
+            AnyTomlValue::TomlStringValue(node) => node.format().fmt(f),
+        }
+    }
+}
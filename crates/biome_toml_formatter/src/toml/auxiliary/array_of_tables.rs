@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlArrayOfTables, TomlArrayOfTablesFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlArrayOfTables;
+
+impl FormatNodeRule<TomlArrayOfTables> for FormatTomlArrayOfTables {
+    fn fmt_fields(&self, node: &TomlArrayOfTables, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlArrayOfTablesFields {
+            l_double_brack_token,
+            key,
+            r_double_brack_token,
+            entries,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [
+                l_double_brack_token.format(),
+                key.format(),
+                r_double_brack_token.format()
+            ]
+        )?;
+
+        // The key-value pairs of the table start on the line after its header
+        if !entries.is_empty() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        write!(f, [entries.format()])
+    }
+}
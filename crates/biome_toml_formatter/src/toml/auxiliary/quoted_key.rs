@@ -0,0 +1,14 @@
+use crate::format_string::format_string;
+use crate::prelude::*;
+use biome_toml_syntax::{TomlQuotedKey, TomlQuotedKeyFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlQuotedKey;
+
+impl FormatNodeRule<TomlQuotedKey> for FormatTomlQuotedKey {
+    fn fmt_fields(&self, node: &TomlQuotedKey, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlQuotedKeyFields { value_token } = node.as_fields();
+
+        format_string(&value_token?).fmt(f)
+    }
+}
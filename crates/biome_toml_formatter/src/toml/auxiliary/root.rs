@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlRoot, TomlRootFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlRoot;
+
+impl FormatNodeRule<TomlRoot> for FormatTomlRoot {
+    fn fmt_fields(&self, node: &TomlRoot, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlRootFields {
+            bom_token,
+            entries,
+            tables,
+            eof_token,
+        } = node.as_fields();
+
+        write!(f, [bom_token.format(), entries.format()])?;
+
+        // The tables are separated from the key-value pairs of the root table by an empty line
+        if !entries.is_empty() && !tables.is_empty() {
+            write!(f, [empty_line()])?;
+        }
+
+        write!(
+            f,
+            [
+                tables.format(),
+                format_dangling_comments(node.syntax()),
+                format_removed(&eof_token?),
+                hard_line_break()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(&self, _: &TomlRoot, _: &mut TomlFormatter) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
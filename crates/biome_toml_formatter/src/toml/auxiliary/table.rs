@@ -0,0 +1,29 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_toml_syntax::{TomlTable, TomlTableFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlTable;
+
+impl FormatNodeRule<TomlTable> for FormatTomlTable {
+    fn fmt_fields(&self, node: &TomlTable, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlTableFields {
+            l_brack_token,
+            key,
+            r_brack_token,
+            entries,
+        } = node.as_fields();
+
+        write!(
+            f,
+            [l_brack_token.format(), key.format(), r_brack_token.format()]
+        )?;
+
+        // The key-value pairs of the table start on the line after its header
+        if !entries.is_empty() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        write!(f, [entries.format()])
+    }
+}
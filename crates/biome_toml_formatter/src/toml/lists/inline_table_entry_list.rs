@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use crate::separated::FormatAstSeparatedListExtension;
+use biome_formatter::separated::TrailingSeparator;
+use biome_toml_syntax::TomlInlineTableEntryList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlInlineTableEntryList;
+
+impl FormatRule<TomlInlineTableEntryList> for FormatTomlInlineTableEntryList {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &TomlInlineTableEntryList, f: &mut TomlFormatter) -> FormatResult<()> {
+        f.join_with(&space())
+            .entries(node.format_separated(",", TrailingSeparator::Disallowed))
+            .finish()
+    }
+}
@@ -0,0 +1,7 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod array_element_list;
+pub(crate) mod inline_table_entry_list;
+pub(crate) mod key_part_list;
+pub(crate) mod key_value_list;
+pub(crate) mod table_list;
@@ -0,0 +1,15 @@
+use crate::prelude::*;
+use biome_toml_syntax::TomlTableList;
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlTableList;
+
+impl FormatRule<TomlTableList> for FormatTomlTableList {
+    type Context = TomlFormatContext;
+    fn fmt(&self, node: &TomlTableList, f: &mut TomlFormatter) -> FormatResult<()> {
+        // The tables are always separated by an empty line
+        f.join_with(&empty_line())
+            .entries(node.iter().formatted())
+            .finish()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_toml_syntax::{TomlBooleanValue, TomlBooleanValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlBooleanValue;
+
+impl FormatNodeRule<TomlBooleanValue> for FormatTomlBooleanValue {
+    fn fmt_fields(&self, node: &TomlBooleanValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlBooleanValueFields { value_token } = node.as_fields();
+
+        value_token.format().fmt(f)
+    }
+}
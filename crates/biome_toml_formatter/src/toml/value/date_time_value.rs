@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_toml_syntax::{TomlDateTimeValue, TomlDateTimeValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlDateTimeValue;
+
+impl FormatNodeRule<TomlDateTimeValue> for FormatTomlDateTimeValue {
+    fn fmt_fields(&self, node: &TomlDateTimeValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlDateTimeValueFields { value_token } = node.as_fields();
+
+        value_token.format().fmt(f)
+    }
+}
@@ -0,0 +1,13 @@
+use crate::prelude::*;
+use biome_toml_syntax::{TomlIntegerValue, TomlIntegerValueFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatTomlIntegerValue;
+
+impl FormatNodeRule<TomlIntegerValue> for FormatTomlIntegerValue {
+    fn fmt_fields(&self, node: &TomlIntegerValue, f: &mut TomlFormatter) -> FormatResult<()> {
+        let TomlIntegerValueFields { value_token } = node.as_fields();
+
+        value_token.format().fmt(f)
+    }
+}
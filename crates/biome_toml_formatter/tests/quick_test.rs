@@ -0,0 +1,41 @@
+use biome_formatter_test::check_reformat::CheckReformat;
+use biome_toml_formatter::context::TomlFormatOptions;
+use biome_toml_formatter::format_node;
+use biome_toml_parser::parse_toml;
+
+mod language {
+    include!("language.rs");
+}
+
+#[ignore]
+#[test]
+// use this test check if your snippet prints as you wish, without using a snapshot
+fn quick_test() {
+    let src = r#"
+key =   'value' # comment
+list = [
+'first',
+{ name = "second" }
+]
+"#;
+    let parse = parse_toml(src);
+    let options = TomlFormatOptions::default();
+    let result = format_node(options.clone(), &parse.syntax())
+        .unwrap()
+        .print()
+        .unwrap();
+
+    let root = &parse.syntax();
+    let language = language::TomlTestFormatLanguage;
+
+    let check_reformat =
+        CheckReformat::new(root, result.as_code(), "quick_test", &language, options);
+    check_reformat.check_reformat();
+
+    assert_eq!(
+        result.as_code(),
+        r#"key = "value" # comment
+list = ["first", { name = "second" }]
+"#
+    );
+}
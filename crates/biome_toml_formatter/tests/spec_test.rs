@@ -0,0 +1,39 @@
+use biome_formatter_test::spec::{SpecSnapshot, SpecTestFile};
+use biome_toml_formatter::context::TomlFormatOptions;
+use std::path::Path;
+
+mod language {
+    include!("language.rs");
+}
+
+/// [insta.rs](https://insta.rs/docs) snapshot testing
+///
+/// For better development workflow, run
+/// `cargo watch -i '*.new' -x 'test -p biome_toml_formatter formatter'`
+///
+/// To review and commit the snapshots, `cargo install cargo-insta`, and run
+/// `cargo insta review` or `cargo insta accept`
+///
+/// The input and the expected output are stored as dedicated files in the `tests/specs` directory where
+/// the input file name is `{spec_name}.toml` and the output file name is `{spec_name}.toml.snap`.
+///
+/// Specs can be grouped in directories by specifying the directory name in the spec name. Examples:
+///
+/// # Examples
+///
+/// * `toml/mapping` -> input: `tests/specs/toml/mapping.toml`, expected output: `tests/specs/toml/mapping.toml.snap`
+/// * `mapping` -> input: `tests/specs/mapping.toml`, expected output: `tests/specs/mapping.toml.snap`
+pub fn run(spec_input_file: &str, _expected_file: &str, test_directory: &str, _file_type: &str) {
+    let root_path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/specs/"));
+
+    let Some(test_file) = SpecTestFile::try_from_file(spec_input_file, root_path) else {
+        return;
+    };
+
+    let options = TomlFormatOptions::default();
+    let language = language::TomlTestFormatLanguage;
+
+    let snapshot = SpecSnapshot::new(test_file, test_directory, language, options);
+
+    snapshot.test()
+}
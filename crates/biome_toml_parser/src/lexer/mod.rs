@@ -0,0 +1,541 @@
+//! A TOML lexer which yields SyntaxKind tokens used by the biome TOML parser.
+#[rustfmt::skip]
+mod tests;
+
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::lexer::{LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, TokenFlags};
+use biome_rowan::{TextLen, TextSize};
+use biome_toml_syntax::{TomlSyntaxKind, TomlSyntaxKind::*, T};
+
+#[derive(Debug)]
+pub(crate) struct TomlLexer<'src> {
+    /// Source text
+    source: &'src str,
+
+    /// The start byte position in the source text of the next token.
+    position: usize,
+
+    /// `true` if there has been a line break between the last non-trivia token and the next non-trivia token.
+    after_newline: bool,
+
+    /// If the source starts with a Unicode BOM, this is the number of bytes for that token.
+    unicode_bom_length: usize,
+
+    /// Byte offset of the current token from the start of the source
+    /// The range of the current token can be computed by
+    /// `self.position - self.current_start`.
+    current_start: TextSize,
+
+    /// The kind of the current token
+    current_kind: TomlSyntaxKind,
+
+    /// Flags for the current token
+    current_flags: TokenFlags,
+
+    /// diagnostics emitted during the parsing phase
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl<'src> Lexer<'src> for TomlLexer<'src> {
+    const NEWLINE: Self::Kind = NEWLINE;
+    const WHITESPACE: Self::Kind = WHITESPACE;
+
+    type Kind = TomlSyntaxKind;
+    type LexContext = TomlLexContext;
+    type ReLexContext = ();
+
+    fn source(&self) -> &'src str {
+        self.source
+    }
+
+    fn current(&self) -> Self::Kind {
+        self.current_kind
+    }
+
+    fn current_start(&self) -> TextSize {
+        self.current_start
+    }
+
+    fn next_token(&mut self, context: Self::LexContext) -> Self::Kind {
+        self.current_start = self.text_position();
+        self.current_flags = TokenFlags::empty();
+
+        let kind = match self.current_byte() {
+            Some(current) => self.consume_token(current, context),
+            None => EOF,
+        };
+
+        self.current_kind = kind;
+
+        // The BOM is part of the first line
+        if !kind.is_trivia() && !kind.is_comments() && kind != UNICODE_BOM {
+            if self.after_newline {
+                self.current_flags
+                    .set(TokenFlags::PRECEDING_LINE_BREAK, true);
+            }
+            self.after_newline = false;
+        }
+
+        kind
+    }
+
+    fn has_preceding_line_break(&self) -> bool {
+        self.current_flags.has_preceding_line_break()
+    }
+
+    fn has_unicode_escape(&self) -> bool {
+        self.current_flags.has_unicode_escape()
+    }
+
+    fn rewind(&mut self, checkpoint: LexerCheckpoint<Self::Kind>) {
+        let LexerCheckpoint {
+            position,
+            current_start,
+            current_flags,
+            current_kind,
+            after_line_break,
+            unicode_bom_length,
+            diagnostics_pos,
+        } = checkpoint;
+
+        self.position = u32::from(position) as usize;
+        self.current_kind = current_kind;
+        self.current_start = current_start;
+        self.current_flags = current_flags;
+        self.after_newline = after_line_break;
+        self.unicode_bom_length = unicode_bom_length;
+        self.diagnostics.truncate(diagnostics_pos as usize);
+    }
+
+    fn finish(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    fn current_flags(&self) -> TokenFlags {
+        self.current_flags
+    }
+
+    fn push_diagnostic(&mut self, diagnostic: ParseDiagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn position(&self) -> usize {
+        self.position
+    }
+
+    #[inline]
+    fn advance_char_unchecked(&mut self) {
+        let c = self.current_char_unchecked();
+        self.position += c.len_utf8();
+    }
+
+    #[inline]
+    fn advance(&mut self, n: usize) {
+        self.position += n;
+    }
+
+    fn consume_newline_or_whitespaces(&mut self) -> TomlSyntaxKind {
+        if self.consume_newline() {
+            self.after_newline = true;
+            NEWLINE
+        } else {
+            while matches!(self.current_byte(), Some(b' ' | b'\t')) {
+                self.advance(1);
+            }
+            WHITESPACE
+        }
+    }
+}
+
+impl<'src> LexerWithCheckpoint<'src> for TomlLexer<'src> {
+    fn checkpoint(&self) -> LexerCheckpoint<Self::Kind> {
+        LexerCheckpoint {
+            position: TextSize::from(self.position as u32),
+            current_start: self.current_start,
+            current_flags: self.current_flags,
+            current_kind: self.current_kind,
+            after_line_break: self.after_newline,
+            unicode_bom_length: self.unicode_bom_length,
+            diagnostics_pos: self.diagnostics.len() as u32,
+        }
+    }
+}
+
+impl<'src> TomlLexer<'src> {
+    /// Make a new lexer from a str, this is safe because strs are valid utf8
+    pub fn from_str(source: &'src str) -> Self {
+        Self {
+            source,
+            position: 0,
+            after_newline: true,
+            unicode_bom_length: 0,
+            current_start: TextSize::from(0),
+            current_kind: TOMBSTONE,
+            current_flags: TokenFlags::empty(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Bumps the current byte and creates a lexed token of the passed in kind
+    fn consume_byte(&mut self, tok: TomlSyntaxKind) -> TomlSyntaxKind {
+        self.advance(1);
+        tok
+    }
+
+    /// Lexes the next token
+    ///
+    /// Guaranteed to not be at the end of the file
+    fn consume_token(&mut self, current: u8, context: TomlLexContext) -> TomlSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
+            b'#' => self.consume_comment(),
+            b'=' => self.consume_byte(T![=]),
+            b'.' => self.consume_byte(T![.]),
+            b',' => self.consume_byte(T![,]),
+            b'{' => self.consume_byte(T!['{']),
+            b'}' => self.consume_byte(T!['}']),
+            // `[[` and `]]` only delimit the headers of the arrays of tables,
+            // nested arrays are lexed in the value context
+            b'[' if context.is_regular() && self.byte_at(1) == Some(b'[') => {
+                self.advance(2);
+                T!["[["]
+            }
+            b']' if context.is_regular() && self.byte_at(1) == Some(b']') => {
+                self.advance(2);
+                T!["]]"]
+            }
+            b'[' => self.consume_byte(T!['[']),
+            b']' => self.consume_byte(T![']']),
+            b'"' | b'\'' => self.consume_string(current),
+            _ if self.position == 0 => {
+                if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
+                    self.unicode_bom_length = bom_size;
+                    return bom;
+                }
+                self.consume_key_or_value(context)
+            }
+            _ => self.consume_key_or_value(context),
+        }
+    }
+
+    fn consume_key_or_value(&mut self, context: TomlLexContext) -> TomlSyntaxKind {
+        match context {
+            TomlLexContext::Regular => self.consume_bare_key(),
+            TomlLexContext::Value => self.consume_value(),
+        }
+    }
+
+    /// Lexes a comment, until the end of the line.
+    fn consume_comment(&mut self) -> TomlSyntaxKind {
+        self.assert_byte(b'#');
+        while let Some(byte) = self.current_byte() {
+            if matches!(byte, b'\n' | b'\r') {
+                break;
+            }
+            self.advance_byte_or_char(byte);
+        }
+        COMMENT
+    }
+
+    /// Consumes the characters allowed in a bare key: ASCII letters, ASCII digits, `_` and `-`.
+    fn consume_bare_key_chars(&mut self) {
+        while self
+            .current_byte()
+            .is_some_and(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'-'))
+        {
+            self.advance(1);
+        }
+    }
+
+    /// Lexes a bare key, such as `name` or `1234`.
+    fn consume_bare_key(&mut self) -> TomlSyntaxKind {
+        self.consume_bare_key_chars();
+        if self.position == usize::from(self.current_start) {
+            return self
+                .consume_unexpected_character("Quote the key if the character is part of it.");
+        }
+        TOML_BARE_KEY_LITERAL
+    }
+
+    /// Lexes a value that isn't a string, an array or an inline table:
+    /// a boolean, a number or a date-time.
+    ///
+    /// A word that isn't a boolean or a special float is lexed as a bare key,
+    /// which lets the parser report the unquoted strings.
+    fn consume_value(&mut self) -> TomlSyntaxKind {
+        let start = self.position;
+        match self.current_byte() {
+            Some(byte) if byte.is_ascii_alphabetic() || byte == b'_' => {
+                self.consume_bare_key_chars();
+                match &self.source[start..self.position] {
+                    "true" => T![true],
+                    "false" => T![false],
+                    "inf" | "nan" => TOML_FLOAT_LITERAL,
+                    _ => TOML_BARE_KEY_LITERAL,
+                }
+            }
+            Some(byte) if byte.is_ascii_digit() || matches!(byte, b'+' | b'-') => {
+                self.consume_number_or_date_time()
+            }
+            _ => self.consume_unexpected_character("Quote the value if it's a string."),
+        }
+    }
+
+    /// Lexes an integer, a float, or a date-time.
+    fn consume_number_or_date_time(&mut self) -> TomlSyntaxKind {
+        let start = self.position;
+        self.consume_number_chars();
+        // The date and the time can be separated by a space: `1979-05-27 07:32:00`
+        if is_date_time(&self.source[start..self.position])
+            && self.current_byte() == Some(b' ')
+            && self.byte_at(1).is_some_and(|byte| byte.is_ascii_digit())
+        {
+            self.advance(1);
+            self.consume_number_chars();
+        }
+
+        let text = &self.source[start..self.position];
+        if is_date_time(text) {
+            TOML_DATE_TIME_LITERAL
+        } else if is_integer(text) {
+            TOML_INTEGER_LITERAL
+        } else if is_float(text) {
+            TOML_FLOAT_LITERAL
+        } else {
+            self.diagnostics.push(
+                ParseDiagnostic::new("Invalid number", start..self.position)
+                    .with_hint("Numbers can't have leading zeros, and their underscores must be between two digits. Quote the value if it's a string."),
+            );
+            ERROR_TOKEN
+        }
+    }
+
+    fn consume_number_chars(&mut self) {
+        while self.current_byte().is_some_and(|byte| {
+            byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'+' | b'-' | b'.' | b':')
+        }) {
+            self.advance(1);
+        }
+    }
+
+    /// Lexes a basic (`"`), a literal (`'`), or a multi-line (`"""` or `'''`) string.
+    fn consume_string(&mut self, quote: u8) -> TomlSyntaxKind {
+        let start = self.text_position();
+        if self.byte_at(1) == Some(quote) && self.byte_at(2) == Some(quote) {
+            self.advance(3);
+            return self.consume_multiline_string(quote);
+        }
+
+        self.advance(1);
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'\n' | b'\r' => {
+                    self.diagnostics.push(
+                        ParseDiagnostic::new("Missing closing quote", start..self.text_position())
+                            .with_hint("The closing quote must be on the same line. Use a multi-line string (`\"\"\"`) if the string spans several lines."),
+                    );
+                    return ERROR_TOKEN;
+                }
+                b'\\' if quote == b'"' => self.consume_escape_sequence(false),
+                _ if byte == quote => {
+                    self.advance(1);
+                    return TOML_STRING_LITERAL;
+                }
+                _ => self.advance_byte_or_char(byte),
+            }
+        }
+        self.diagnostics.push(
+            ParseDiagnostic::new("Missing closing quote", start..self.text_position()).with_detail(
+                self.current_start..self.current_start + TextSize::from(1),
+                "The string starts here",
+            ),
+        );
+        ERROR_TOKEN
+    }
+
+    /// Lexes a multi-line string, after its opening delimiter.
+    fn consume_multiline_string(&mut self, quote: u8) -> TomlSyntaxKind {
+        let start = self.current_start;
+        while let Some(byte) = self.current_byte() {
+            if byte == quote && self.byte_at(1) == Some(quote) && self.byte_at(2) == Some(quote) {
+                self.advance(3);
+                // Up to two quotes can be placed right before the closing delimiter: `"""value"""""`
+                for _ in 0..2 {
+                    if self.current_byte() == Some(quote) {
+                        self.advance(1);
+                    }
+                }
+                return TOML_STRING_LITERAL;
+            }
+            if byte == b'\\' && quote == b'"' {
+                self.consume_escape_sequence(true);
+            } else {
+                self.advance_byte_or_char(byte);
+            }
+        }
+        self.diagnostics.push(
+            ParseDiagnostic::new("Missing closing quotes", start..self.text_position())
+                .with_detail(start..start + TextSize::from(3), "The string starts here"),
+        );
+        ERROR_TOKEN
+    }
+
+    /// Lexes an escape sequence of a basic string.
+    fn consume_escape_sequence(&mut self, is_multiline: bool) {
+        let start = self.text_position();
+        self.assert_byte(b'\\');
+        self.advance(1);
+        let digits = match self.current_byte() {
+            Some(b'u') => 4,
+            Some(b'U') => 8,
+            Some(b'b' | b't' | b'n' | b'f' | b'r' | b'"' | b'\\') => {
+                self.advance(1);
+                return;
+            }
+            // A line ending backslash trims the whitespaces and the line breaks that follow it
+            Some(b' ' | b'\t' | b'\n' | b'\r') if is_multiline => {
+                while matches!(self.current_byte(), Some(b' ' | b'\t')) {
+                    self.advance(1);
+                }
+                if !matches!(self.current_byte(), Some(b'\n' | b'\r')) {
+                    self.diagnostics.push(
+                        ParseDiagnostic::new(
+                            "Invalid escape sequence",
+                            start..self.text_position(),
+                        )
+                        .with_hint("Only whitespaces can follow a line ending backslash."),
+                    );
+                }
+                return;
+            }
+            Some(b'\n' | b'\r') => {
+                self.diagnostics.push(
+                    ParseDiagnostic::new("Invalid escape sequence", start..self.text_position())
+                        .with_hint("A backslash can only end a line in a multi-line string."),
+                );
+                return;
+            }
+            Some(byte) => {
+                self.advance_byte_or_char(byte);
+                self.diagnostics.push(
+                    ParseDiagnostic::new("Invalid escape sequence", start..self.text_position())
+                        .with_hint("Valid escape sequences are: `\\b`, `\\t`, `\\n`, `\\f`, `\\r`, `\\\"`, `\\\\`, `\\uXXXX` and `\\UXXXXXXXX`."),
+                );
+                return;
+            }
+            None => return,
+        };
+        self.advance(1);
+        for _ in 0..digits {
+            if self
+                .current_byte()
+                .is_some_and(|byte| byte.is_ascii_hexdigit())
+            {
+                self.advance(1);
+            } else {
+                self.diagnostics.push(
+                    ParseDiagnostic::new("Invalid escape sequence", start..self.text_position())
+                        .with_hint(format!(
+                            "This escape sequence must be followed by {digits} hexadecimal digits."
+                        )),
+                );
+                return;
+            }
+        }
+    }
+
+    /// Lexes a character that can't start a token here.
+    fn consume_unexpected_character(&mut self, hint: &str) -> TomlSyntaxKind {
+        self.assert_current_char_boundary();
+
+        let char = self.current_char_unchecked();
+        let err = ParseDiagnostic::new(
+            format!("Unexpected character `{char}`."),
+            self.text_position()..self.text_position() + char.text_len(),
+        )
+        .with_hint(hint);
+        self.diagnostics.push(err);
+        self.advance(char.len_utf8());
+
+        ERROR_TOKEN
+    }
+}
+
+/// Returns `true` if `text` starts like a date (`1979-05-27`) or a local time (`07:32:00`).
+///
+/// The validity of the date-time isn't checked by the lexer.
+fn is_date_time(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    let is_date = bytes.len() > 4 && bytes[..4].iter().all(u8::is_ascii_digit) && bytes[4] == b'-';
+    let is_time = bytes.len() > 2 && bytes[..2].iter().all(u8::is_ascii_digit) && bytes[2] == b':';
+    is_date || is_time
+}
+
+/// Returns `true` if `text` is a decimal, a hexadecimal (`0x`), an octal (`0o`), or a binary (`0b`) integer.
+fn is_integer(text: &str) -> bool {
+    let (digits, radix) = if let Some(digits) = text.strip_prefix("0x") {
+        (digits, 16)
+    } else if let Some(digits) = text.strip_prefix("0o") {
+        (digits, 8)
+    } else if let Some(digits) = text.strip_prefix("0b") {
+        (digits, 2)
+    } else {
+        let digits = text.strip_prefix(['+', '-']).unwrap_or(text);
+        // Leading zeros aren't allowed
+        if digits.len() > 1 && digits.starts_with('0') {
+            return false;
+        }
+        (digits, 10)
+    };
+    is_digit_sequence(digits, radix)
+}
+
+/// Returns `true` if `text` is a float: an integer part followed by a fractional part, an exponent, or both.
+fn is_float(text: &str) -> bool {
+    let text = text.strip_prefix(['+', '-']).unwrap_or(text);
+    if matches!(text, "inf" | "nan") {
+        return true;
+    }
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (text, None),
+    };
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+    if fraction.is_none() && exponent.is_none() {
+        return false;
+    }
+    is_digit_sequence(integer, 10)
+        && !(integer.len() > 1 && integer.starts_with('0'))
+        && fraction.map_or(true, |fraction| is_digit_sequence(fraction, 10))
+        && exponent.map_or(true, |exponent| {
+            is_digit_sequence(exponent.strip_prefix(['+', '-']).unwrap_or(exponent), 10)
+        })
+}
+
+/// Returns `true` if `digits` is a non-empty sequence of digits in the given `radix`,
+/// where every underscore is between two digits.
+fn is_digit_sequence(digits: &str, radix: u32) -> bool {
+    digits
+        .split('_')
+        .all(|part| !part.is_empty() && part.chars().all(|c| c.is_digit(radix)))
+}
+
+/// Context in which the lexer should lex the next token
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum TomlLexContext {
+    /// The keys, and the headers of the tables.
+    #[default]
+    Regular,
+    /// The values, after `=` and in the arrays.
+    Value,
+}
+
+impl LexContext for TomlLexContext {
+    /// Returns true if this is [TomlLexContext::Regular]
+    fn is_regular(&self) -> bool {
+        matches!(self, TomlLexContext::Regular)
+    }
+}
@@ -0,0 +1,390 @@
+#![cfg(test)]
+#![allow(unused_mut, unused_variables, unused_assignments)]
+
+use super::{TextSize, TomlLexContext, TomlLexer};
+use biome_parser::lexer::Lexer;
+use biome_toml_syntax::TomlSyntaxKind::{self, EOF};
+use quickcheck_macros::quickcheck;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+// Assert the result of lexing a piece of source code,
+// and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens.
+// The tokens are lexed in the regular context, unless the source is prefixed with `value:`
+macro_rules! assert_lex {
+    (@lex $context:expr, $src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = TomlLexer::from_str($src);
+        let mut idx = 0;
+        let mut tok_idx = TextSize::default();
+
+        let mut new_str = String::with_capacity($src.len());
+        let mut tokens = vec![];
+
+        while lexer.next_token($context) != EOF {
+            tokens.push((lexer.current(), lexer.current_range()));
+        }
+
+        $(
+            assert_eq!(
+                tokens[idx].0,
+                TomlSyntaxKind::$kind,
+                "expected token kind {}, but found {:?}",
+                stringify!($kind),
+                tokens[idx].0,
+            );
+
+            assert_eq!(
+                tokens[idx].1.len(),
+                TextSize::from($len),
+                "expected token length of {}, but found {:?} for token {:?}",
+                $len,
+                tokens[idx].1.len(),
+                tokens[idx].0,
+            );
+
+            new_str.push_str(&$src[tokens[idx].1]);
+            tok_idx += tokens[idx].1.len();
+
+            idx += 1;
+        )*
+
+        if idx < tokens.len() {
+            panic!(
+                "expected {} tokens but lexer returned {}, first unexpected token is '{:?}'",
+                idx,
+                tokens.len(),
+                tokens[idx].0
+            );
+        } else {
+            assert_eq!(idx, tokens.len());
+        }
+
+        assert_eq!($src, new_str, "Failed to reconstruct input");
+    }};
+    (value: $src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(@lex TomlLexContext::Value, $src, $($kind:$len,)*)
+    };
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {
+        assert_lex!(@lex TomlLexContext::Regular, $src, $($kind:$len,)*)
+    };
+}
+
+// This is for testing if the lexer is truly lossless
+// It parses random strings and puts them back together with the produced tokens and compares
+#[quickcheck]
+fn losslessness(string: String) -> bool {
+    // using an mpsc channel allows us to spawn a thread and spawn the lexer there, then if
+    // it takes more than 2 seconds we panic because it is 100% infinite recursion
+    let cloned = string.clone();
+    let (sender, receiver) = channel();
+    thread::spawn(move || {
+        let mut lexer = TomlLexer::from_str(&cloned);
+        let mut tokens = vec![];
+        let mut context = TomlLexContext::Regular;
+
+        while lexer.next_token(context) != EOF {
+            tokens.push(lexer.current_range());
+            context = if lexer.current() == TomlSyntaxKind::EQ {
+                TomlLexContext::Value
+            } else {
+                TomlLexContext::Regular
+            };
+        }
+
+        sender
+            .send(tokens)
+            .expect("Could not send tokens to receiver");
+    });
+    let token_ranges = receiver
+        .recv_timeout(Duration::from_secs(2))
+        .unwrap_or_else(|_| {
+            panic!(
+                "Lexer is infinitely recursing with this code: ->{}<-",
+                string
+            )
+        });
+
+    let mut new_str = String::with_capacity(string.len());
+    let mut idx = TextSize::from(0);
+
+    for range in token_ranges {
+        new_str.push_str(&string[range]);
+        idx += range.len();
+    }
+
+    string == new_str
+}
+
+#[test]
+fn empty() {
+    assert_lex! {
+        "",
+    }
+}
+
+#[test]
+fn keys() {
+    assert_lex! {
+        "name",
+        TOML_BARE_KEY_LITERAL:4
+    }
+
+    assert_lex! {
+        "bare-key_1",
+        TOML_BARE_KEY_LITERAL:10
+    }
+
+    assert_lex! {
+        "1234",
+        TOML_BARE_KEY_LITERAL:4
+    }
+
+    assert_lex! {
+        "a.b . c",
+        TOML_BARE_KEY_LITERAL:1,
+        DOT:1,
+        TOML_BARE_KEY_LITERAL:1,
+        WHITESPACE:1,
+        DOT:1,
+        WHITESPACE:1,
+        TOML_BARE_KEY_LITERAL:1
+    }
+
+    assert_lex! {
+        r#""quoted key" = 'literal'"#,
+        TOML_STRING_LITERAL:12,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_STRING_LITERAL:9
+    }
+
+    assert_lex! {
+        "ké",
+        TOML_BARE_KEY_LITERAL:1,
+        ERROR_TOKEN:2
+    }
+}
+
+#[test]
+fn table_headers() {
+    assert_lex! {
+        "[table]",
+        L_BRACK:1,
+        TOML_BARE_KEY_LITERAL:5,
+        R_BRACK:1
+    }
+
+    assert_lex! {
+        "[[array]]",
+        L_DOUBLE_BRACK:2,
+        TOML_BARE_KEY_LITERAL:5,
+        R_DOUBLE_BRACK:2
+    }
+
+    assert_lex! {
+        value: "[[1], [2]]",
+        L_BRACK:1,
+        L_BRACK:1,
+        TOML_INTEGER_LITERAL:1,
+        R_BRACK:1,
+        COMMA:1,
+        WHITESPACE:1,
+        L_BRACK:1,
+        TOML_INTEGER_LITERAL:1,
+        R_BRACK:1,
+        R_BRACK:1
+    }
+}
+
+#[test]
+fn strings() {
+    assert_lex! {
+        value: r#""basic \"string\" \u00E9""#,
+        TOML_STRING_LITERAL:25
+    }
+
+    assert_lex! {
+        value: r"'C:\Users\nodejs'",
+        TOML_STRING_LITERAL:17
+    }
+
+    assert_lex! {
+        value: "\"\"\"\nmulti\nline\"\"\"",
+        TOML_STRING_LITERAL:17
+    }
+
+    assert_lex! {
+        value: "\"\"\"line \\\n  continuation\"\"\"",
+        TOML_STRING_LITERAL:27
+    }
+
+    assert_lex! {
+        value: "'''quotes '''''",
+        TOML_STRING_LITERAL:15
+    }
+
+    assert_lex! {
+        value: r#""invalid \q escape""#,
+        TOML_STRING_LITERAL:19
+    }
+
+    assert_lex! {
+        value: r#""unterminated"#,
+        ERROR_TOKEN:13
+    }
+
+    assert_lex! {
+        value: "\"line\nbreak\"",
+        ERROR_TOKEN:5,
+        NEWLINE:1,
+        TOML_BARE_KEY_LITERAL:5,
+        ERROR_TOKEN:1
+    }
+}
+
+#[test]
+fn numbers() {
+    assert_lex! {
+        value: "42",
+        TOML_INTEGER_LITERAL:2
+    }
+
+    assert_lex! {
+        value: "-1_000",
+        TOML_INTEGER_LITERAL:6
+    }
+
+    assert_lex! {
+        value: "0xdead_beef",
+        TOML_INTEGER_LITERAL:11
+    }
+
+    assert_lex! {
+        value: "0o755",
+        TOML_INTEGER_LITERAL:5
+    }
+
+    assert_lex! {
+        value: "0b1101",
+        TOML_INTEGER_LITERAL:6
+    }
+
+    assert_lex! {
+        value: "3.14",
+        TOML_FLOAT_LITERAL:4
+    }
+
+    assert_lex! {
+        value: "-6.626e-34",
+        TOML_FLOAT_LITERAL:10
+    }
+
+    assert_lex! {
+        value: "+inf",
+        TOML_FLOAT_LITERAL:4
+    }
+
+    assert_lex! {
+        value: "nan",
+        TOML_FLOAT_LITERAL:3
+    }
+
+    assert_lex! {
+        value: "012",
+        ERROR_TOKEN:3
+    }
+
+    assert_lex! {
+        value: "1__000",
+        ERROR_TOKEN:6
+    }
+
+    assert_lex! {
+        value: "1.",
+        ERROR_TOKEN:2
+    }
+}
+
+#[test]
+fn date_times() {
+    assert_lex! {
+        value: "1979-05-27T07:32:00Z",
+        TOML_DATE_TIME_LITERAL:20
+    }
+
+    assert_lex! {
+        value: "1979-05-27 07:32:00.999999-07:00",
+        TOML_DATE_TIME_LITERAL:32
+    }
+
+    assert_lex! {
+        value: "1979-05-27",
+        TOML_DATE_TIME_LITERAL:10
+    }
+
+    assert_lex! {
+        value: "07:32:00",
+        TOML_DATE_TIME_LITERAL:8
+    }
+
+    assert_lex! {
+        value: "1979-05-27 # comment",
+        TOML_DATE_TIME_LITERAL:10,
+        WHITESPACE:1,
+        COMMENT:9
+    }
+}
+
+#[test]
+fn values() {
+    assert_lex! {
+        value: "true",
+        TRUE_KW:4
+    }
+
+    assert_lex! {
+        value: "false",
+        FALSE_KW:5
+    }
+
+    assert_lex! {
+        value: "unquoted",
+        TOML_BARE_KEY_LITERAL:8
+    }
+
+    assert_lex! {
+        value: "{ a = 1 }",
+        L_CURLY:1,
+        WHITESPACE:1,
+        TOML_BARE_KEY_LITERAL:1,
+        WHITESPACE:1,
+        EQ:1,
+        WHITESPACE:1,
+        TOML_INTEGER_LITERAL:1,
+        WHITESPACE:1,
+        R_CURLY:1
+    }
+
+    assert_lex! {
+        value: "@",
+        ERROR_TOKEN:1
+    }
+}
+
+#[test]
+fn comments() {
+    assert_lex! {
+        "# comment",
+        COMMENT:9
+    }
+
+    assert_lex! {
+        "key # comment\n",
+        TOML_BARE_KEY_LITERAL:3,
+        WHITESPACE:1,
+        COMMENT:9,
+        NEWLINE:1
+    }
+}
@@ -0,0 +1,120 @@
+//! Extremely fast, lossless, and error tolerant TOML Parser.
+
+pub use biome_parser::prelude::*;
+use biome_parser::tree_sink::LosslessTreeSink;
+use biome_rowan::{AstNode, NodeCache};
+use biome_toml_factory::TomlSyntaxFactory;
+use biome_toml_syntax::{TomlLanguage, TomlRoot, TomlSyntaxNode};
+use parser::{parse_root, TomlParser};
+
+mod lexer;
+mod parser;
+mod token_source;
+
+pub(crate) type TomlLosslessTreeSink<'source> =
+    LosslessTreeSink<'source, TomlLanguage, TomlSyntaxFactory>;
+
+pub fn parse_toml(source: &str) -> TomlParse {
+    let mut cache = NodeCache::default();
+    parse_toml_with_cache(source, &mut cache)
+}
+
+/// Parses the provided string as a TOML document using the provided node cache.
+pub fn parse_toml_with_cache(source: &str, cache: &mut NodeCache) -> TomlParse {
+    tracing::debug_span!("Parsing phase").in_scope(move || {
+        let mut parser = TomlParser::new(source);
+
+        parse_root(&mut parser);
+
+        let (events, diagnostics, trivia) = parser.finish();
+
+        let mut tree_sink = TomlLosslessTreeSink::with_cache(source, &trivia, cache);
+        biome_parser::event::process(&mut tree_sink, events, diagnostics);
+        let (green, diagnostics) = tree_sink.finish();
+
+        TomlParse::new(green, diagnostics)
+    })
+}
+
+/// A utility struct for managing the result of a parser job
+#[derive(Debug)]
+pub struct TomlParse {
+    root: TomlSyntaxNode,
+    diagnostics: Vec<ParseDiagnostic>,
+}
+
+impl TomlParse {
+    pub fn new(root: TomlSyntaxNode, diagnostics: Vec<ParseDiagnostic>) -> TomlParse {
+        TomlParse { root, diagnostics }
+    }
+
+    /// The syntax node represented by this Parse result
+    ///
+    /// ```
+    /// # use biome_toml_parser::parse_toml;
+    /// # use biome_toml_syntax::TomlSyntaxKind;
+    /// # use biome_rowan::{AstNode, AstNodeList, SyntaxError};
+    ///
+    /// # fn main() -> Result<(), SyntaxError> {
+    /// use biome_toml_syntax::TomlSyntaxKind;
+    /// let parse = parse_toml("key = \"value\"\n[table]\n");
+    ///
+    /// let entries = parse.tree().entries();
+    ///
+    /// assert_eq!(entries.syntax().kind(), TomlSyntaxKind::TOML_KEY_VALUE_LIST);
+    /// assert_eq!(entries.len(), 1);
+    /// assert_eq!(parse.tree().tables().len(), 1);
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn syntax(&self) -> TomlSyntaxNode {
+        self.root.clone()
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn diagnostics(&self) -> &[ParseDiagnostic] {
+        &self.diagnostics
+    }
+
+    /// Get the diagnostics which occurred when parsing
+    pub fn into_diagnostics(self) -> Vec<ParseDiagnostic> {
+        self.diagnostics
+    }
+
+    /// Returns [true] if the parser encountered some errors during the parsing.
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.is_error())
+    }
+
+    /// Convert this parse result into a typed AST node.
+    ///
+    /// # Panics
+    /// Panics if the node represented by this parse result mismatches.
+    pub fn tree(&self) -> TomlRoot {
+        TomlRoot::unwrap_cast(self.syntax())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_toml;
+
+    #[test]
+    fn parser_smoke_test() {
+        let src = r#"
+[package]
+name = "biome"
+version = "0.0.1"
+keywords = ["toml", "parser"]
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+"#;
+
+        let toml = parse_toml(src);
+        assert!(!toml.has_errors());
+    }
+}
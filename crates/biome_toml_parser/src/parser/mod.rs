@@ -0,0 +1,319 @@
+mod parse_error;
+
+use crate::lexer::TomlLexContext;
+use crate::token_source::TomlTokenSource;
+use biome_parser::diagnostic::merge_diagnostics;
+use biome_parser::event::Event;
+use biome_parser::prelude::{ParsedSyntax::*, *};
+use biome_parser::token_source::Trivia;
+use biome_parser::ParserContext;
+use biome_toml_syntax::{
+    TextRange,
+    TomlSyntaxKind::{self, *},
+    T,
+};
+use parse_error::{
+    expected_key, expected_key_value, expected_line_end, expected_value,
+    inline_table_trailing_comma, unquoted_string,
+};
+
+pub(crate) struct TomlParser<'source> {
+    context: ParserContext<TomlSyntaxKind>,
+    source: TomlTokenSource<'source>,
+}
+
+impl<'source> TomlParser<'source> {
+    pub fn new(source: &'source str) -> Self {
+        Self {
+            context: ParserContext::default(),
+            source: TomlTokenSource::from_str(source),
+        }
+    }
+
+    pub fn finish(
+        self,
+    ) -> (
+        Vec<Event<TomlSyntaxKind>>,
+        Vec<ParseDiagnostic>,
+        Vec<Trivia>,
+    ) {
+        let (trivia, lexer_diagnostics) = self.source.finish();
+        let (events, parse_diagnostics) = self.context.finish();
+
+        let diagnostics = merge_diagnostics(lexer_diagnostics, parse_diagnostics);
+
+        (events, diagnostics, trivia)
+    }
+}
+
+impl<'source> Parser for TomlParser<'source> {
+    type Kind = TomlSyntaxKind;
+    type Source = TomlTokenSource<'source>;
+
+    fn context(&self) -> &ParserContext<Self::Kind> {
+        &self.context
+    }
+
+    fn context_mut(&mut self) -> &mut ParserContext<Self::Kind> {
+        &mut self.context
+    }
+
+    fn source(&self) -> &Self::Source {
+        &self.source
+    }
+
+    fn source_mut(&mut self) -> &mut Self::Source {
+        &mut self.source
+    }
+}
+
+pub(crate) fn parse_root(p: &mut TomlParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.eat(UNICODE_BOM);
+
+    parse_key_value_list(p);
+
+    let tables = p.start();
+    while !p.at(EOF) {
+        parse_table(p);
+    }
+    tables.complete(p, TOML_TABLE_LIST);
+
+    p.expect(EOF);
+
+    m.complete(p, TOML_ROOT)
+}
+
+/// Parses a table, `[table]`, or an array of tables, `[[table]]`, with its key-value pairs.
+fn parse_table(p: &mut TomlParser) -> CompletedMarker {
+    let m = p.start();
+
+    let (kind, closing) = if p.at(T!["[["]) {
+        (TOML_ARRAY_OF_TABLES, T!["]]"])
+    } else {
+        (TOML_TABLE, T![']'])
+    };
+    p.bump_any();
+    parse_key(p).or_add_diagnostic(p, expected_key);
+    p.expect(closing);
+
+    parse_key_value_list(p);
+
+    m.complete(p, kind)
+}
+
+/// Parses the key-value pairs until the next table header or the end of the file.
+fn parse_key_value_list(p: &mut TomlParser) -> CompletedMarker {
+    let m = p.start();
+
+    if !is_at_line_start(p) {
+        // Content after a table header: `[table] key = "value"`
+        let bogus = p.start();
+        let start = p.cur_range().start();
+        bump_until_line_end(p);
+        let range = TextRange::new(start, p.last_end().unwrap_or(start));
+        p.error(expected_line_end(p, range, true));
+        bogus.complete(p, TOML_BOGUS);
+    }
+
+    while !p.at(EOF) && !is_at_table_header(p) {
+        parse_key_value_line(p);
+    }
+
+    m.complete(p, TOML_KEY_VALUE_LIST)
+}
+
+/// Parses a key-value pair that must be alone on its line.
+fn parse_key_value_line(p: &mut TomlParser) {
+    match parse_key_value(p, TomlLexContext::Regular) {
+        Present(key_value) => {
+            if !is_at_line_start(p) {
+                // `first = 1 second = 2`
+                let bogus = key_value.precede(p);
+                let start = p.cur_range().start();
+                bump_until_line_end(p);
+                let range = TextRange::new(start, p.last_end().unwrap_or(start));
+                p.error(expected_line_end(p, range, false));
+                bogus.complete(p, TOML_BOGUS);
+            }
+        }
+        Absent => {
+            let bogus = p.start();
+            let start = p.cur_range().start();
+            // The lexer already reported the invalid tokens
+            let is_error_token = p.at(ERROR_TOKEN);
+            bump_until_line_end(p);
+            if !is_error_token {
+                let range = TextRange::new(start, p.last_end().unwrap_or(start));
+                p.error(expected_key_value(p, range));
+            }
+            bogus.complete(p, TOML_BOGUS);
+        }
+    }
+}
+
+/// Parses a key-value pair: `key = value`.
+///
+/// `context` is the context in which the token that follows the value is lexed.
+fn parse_key_value(p: &mut TomlParser, context: TomlLexContext) -> ParsedSyntax {
+    if !is_at_key_part(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+    parse_key(p).ok();
+    p.expect_with_context(T![=], TomlLexContext::Value);
+
+    if is_at_line_start(p) {
+        // The value must be on the same line as the key
+        let range = p.cur_range();
+        p.error(expected_value(p, range));
+    } else {
+        parse_value(p, context).or_add_diagnostic(p, expected_value);
+    }
+
+    Present(m.complete(p, TOML_KEY_VALUE))
+}
+
+/// Parses a key: one or several bare or quoted keys separated by dots, such as `package.metadata."docs.rs"`.
+fn parse_key(p: &mut TomlParser) -> ParsedSyntax {
+    if !is_at_key_part(p) {
+        return Absent;
+    }
+
+    let m = p.start();
+
+    let parts = p.start();
+    parse_key_part(p).ok();
+    while p.at(T![.]) {
+        p.bump(T![.]);
+        if parse_key_part(p).is_absent() {
+            let range = p.cur_range();
+            p.error(expected_key(p, range));
+            break;
+        }
+    }
+    parts.complete(p, TOML_KEY_PART_LIST);
+
+    Present(m.complete(p, TOML_KEY))
+}
+
+fn parse_key_part(p: &mut TomlParser) -> ParsedSyntax {
+    let kind = match p.cur() {
+        TOML_BARE_KEY_LITERAL => TOML_BARE_KEY,
+        TOML_STRING_LITERAL => TOML_QUOTED_KEY,
+        _ => return Absent,
+    };
+    let m = p.start();
+    p.bump_any();
+    Present(m.complete(p, kind))
+}
+
+/// Parses a value.
+///
+/// `context` is the context in which the token that follows the value is lexed.
+fn parse_value(p: &mut TomlParser, context: TomlLexContext) -> ParsedSyntax {
+    let kind = match p.cur() {
+        TOML_STRING_LITERAL => TOML_STRING_VALUE,
+        TOML_INTEGER_LITERAL => TOML_INTEGER_VALUE,
+        TOML_FLOAT_LITERAL => TOML_FLOAT_VALUE,
+        TOML_DATE_TIME_LITERAL => TOML_DATE_TIME_VALUE,
+        T![true] | T![false] => TOML_BOOLEAN_VALUE,
+        T!['['] => return Present(parse_array(p, context)),
+        T!['{'] => return Present(parse_inline_table(p, context)),
+        TOML_BARE_KEY_LITERAL => {
+            // `name = biome` instead of `name = "biome"`
+            let range = p.cur_range();
+            p.error(unquoted_string(p, range));
+            TOML_BOGUS_VALUE
+        }
+        // The lexer already reported the error
+        ERROR_TOKEN => TOML_BOGUS_VALUE,
+        _ => return Absent,
+    };
+
+    let m = p.start();
+    p.bump_with_context(p.cur(), context);
+    Present(m.complete(p, kind))
+}
+
+/// Parses an array: `[1, 2, 3]`.
+///
+/// The elements can span several lines, and they can be followed by a trailing comma.
+fn parse_array(p: &mut TomlParser, context: TomlLexContext) -> CompletedMarker {
+    let m = p.start();
+    p.bump_with_context(T!['['], TomlLexContext::Value);
+
+    let elements = p.start();
+    while !p.at(EOF) && !p.at(T![']']) {
+        if parse_value(p, TomlLexContext::Value).is_absent() {
+            let range = p.cur_range();
+            p.error(expected_value(p, range));
+            if !p.at(T![,]) {
+                break;
+            }
+        }
+        if p.at(T![']']) || !p.expect_with_context(T![,], TomlLexContext::Value) {
+            break;
+        }
+    }
+    elements.complete(p, TOML_ARRAY_ELEMENT_LIST);
+
+    p.expect_with_context(T![']'], context);
+    m.complete(p, TOML_ARRAY)
+}
+
+/// Parses an inline table: `{ name = "biome", version = "1.0.0" }`.
+///
+/// Unlike the arrays, the inline tables can't have a trailing comma.
+fn parse_inline_table(p: &mut TomlParser, context: TomlLexContext) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['{']);
+
+    let entries = p.start();
+    while !p.at(EOF) && !p.at(T!['}']) {
+        if parse_key_value(p, TomlLexContext::Regular).is_absent() {
+            let range = p.cur_range();
+            p.error(expected_key_value(p, range));
+            if !p.at(T![,]) {
+                break;
+            }
+        }
+        if p.at(T!['}']) {
+            break;
+        }
+        let comma_range = p.cur_range();
+        if !p.expect(T![,]) {
+            break;
+        }
+        if p.at(T!['}']) {
+            p.error(inline_table_trailing_comma(p, comma_range));
+        }
+    }
+    entries.complete(p, TOML_INLINE_TABLE_ENTRY_LIST);
+
+    p.expect_with_context(T!['}'], context);
+    m.complete(p, TOML_INLINE_TABLE)
+}
+
+/// Returns `true` if the current token is at the start of a line, or at the end of the file.
+fn is_at_line_start(p: &mut TomlParser) -> bool {
+    p.at(EOF) || p.has_preceding_line_break()
+}
+
+fn is_at_table_header(p: &mut TomlParser) -> bool {
+    p.at(T!['[']) || p.at(T!["[["])
+}
+
+fn is_at_key_part(p: &mut TomlParser) -> bool {
+    p.at(TOML_BARE_KEY_LITERAL) || p.at(TOML_STRING_LITERAL)
+}
+
+/// Bumps the tokens until the end of the line.
+fn bump_until_line_end(p: &mut TomlParser) {
+    p.bump_any();
+    while !is_at_line_start(p) {
+        p.bump_any();
+    }
+}
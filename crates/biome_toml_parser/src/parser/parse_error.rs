@@ -0,0 +1,41 @@
+use crate::parser::TomlParser;
+use biome_parser::diagnostic::{expected_node, ParseDiagnostic};
+use biome_parser::Parser;
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_key(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("key", range, p)
+}
+
+pub(crate) fn expected_value(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("value", range, p)
+}
+
+pub(crate) fn expected_key_value(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("Expected a key-value pair", range)
+        .with_hint("A key-value pair is a key followed by `=` and a value on the same line, such as `name = \"biome\"`.")
+}
+
+pub(crate) fn expected_line_end(
+    p: &TomlParser,
+    range: TextRange,
+    is_after_header: bool,
+) -> ParseDiagnostic {
+    let diagnostic = p.err_builder("Expected the end of the line", range);
+    if is_after_header {
+        diagnostic
+            .with_hint("The key-value pairs of a table must start on the line after its header.")
+    } else {
+        diagnostic.with_hint("Every key-value pair must be on its own line.")
+    }
+}
+
+pub(crate) fn unquoted_string(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("String values must be quoted", range)
+        .with_hint("Wrap the value in quotes: `\"value\"`.")
+}
+
+pub(crate) fn inline_table_trailing_comma(p: &TomlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("Trailing commas aren't allowed in inline tables", range)
+        .with_hint("Remove the comma.")
+}
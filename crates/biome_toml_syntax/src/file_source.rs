@@ -0,0 +1,97 @@
+use biome_rowan::FileSourceError;
+use std::{ffi::OsStr, path::Path};
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(
+    Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub struct TomlFileSource {
+    // ?? Options
+}
+
+impl TomlFileSource {
+    // Well-known TOML files
+    // This list should be SORTED!
+    // Source: https://github.com/github-linguist/linguist/blob/4ac734c15a96f9e16fd12330d0cb8de82274f700/lib/linguist/languages.yml
+    // Note: we shouldn't include machine generated files, such as `Cargo.lock` or `poetry.lock`
+    const WELL_KNOWN_TOML_FILES: &'static [&'static str] = &["Pipfile"];
+
+    pub fn toml() -> Self {
+        Self {
+            // ?? Options
+        }
+    }
+
+    pub fn is_well_known_toml_file(file_name: &str) -> bool {
+        Self::WELL_KNOWN_TOML_FILES
+            .binary_search(&file_name)
+            .is_ok()
+    }
+
+    /// Try to return the TOML file source corresponding to this file name from well-known files
+    pub fn try_from_well_known(file_name: &str) -> Result<Self, FileSourceError> {
+        if Self::is_well_known_toml_file(file_name) {
+            return Ok(Self::toml());
+        }
+        Err(FileSourceError::UnknownFileName(file_name.into()))
+    }
+
+    /// Try to return the TOML file source corresponding to this file extension
+    pub fn try_from_extension(extension: &str) -> Result<Self, FileSourceError> {
+        match extension {
+            // https://toml.io/en/v1.0.0#filename-extension
+            "toml" => Ok(Self::toml()),
+            _ => Err(FileSourceError::UnknownExtension(
+                Default::default(),
+                extension.into(),
+            )),
+        }
+    }
+
+    /// Try to return the TOML file source corresponding to this language ID
+    ///
+    /// See the [LSP spec] and [VS Code spec] for a list of language identifiers
+    ///
+    /// The language ID for code snippets is registered by [VS Code built-in extensions]
+    ///
+    /// [LSP spec]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentItem
+    /// [VS Code spec]: https://code.visualstudio.com/docs/languages/identifiers
+    pub fn try_from_language_id(language_id: &str) -> Result<Self, FileSourceError> {
+        match language_id {
+            "toml" => Ok(Self::toml()),
+            _ => Err(FileSourceError::UnknownLanguageId(language_id.into())),
+        }
+    }
+}
+
+impl TryFrom<&Path> for TomlFileSource {
+    type Error = FileSourceError;
+
+    fn try_from(path: &Path) -> Result<Self, Self::Error> {
+        let file_name = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .ok_or_else(|| FileSourceError::MissingFileName(path.into()))?;
+
+        if let Ok(file_source) = Self::try_from_well_known(file_name) {
+            return Ok(file_source);
+        }
+
+        // We assume the file extensions are case-insensitive
+        // and we use the lowercase form of them for pattern matching
+        let extension = &path
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(str::to_lowercase)
+            .ok_or_else(|| FileSourceError::MissingFileExtension(path.into()))?;
+
+        Self::try_from_extension(extension)
+    }
+}
+
+#[test]
+fn test_order() {
+    for items in TomlFileSource::WELL_KNOWN_TOML_FILES.windows(2) {
+        assert!(items[0] < items[1], "{} < {}", items[0], items[1]);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::{inner_string_text, AnyTomlKeyPart, TomlKey};
+use biome_rowan::{AstSeparatedList, SyntaxResult, TokenText};
+
+impl AnyTomlKeyPart {
+    /// Returns the name of the key part, without the quotes of a quoted key.
+    pub fn inner_string_text(&self) -> SyntaxResult<TokenText> {
+        match self {
+            Self::TomlBareKey(key) => Ok(key.value_token()?.token_text_trimmed()),
+            Self::TomlQuotedKey(key) => Ok(inner_string_text(&key.value_token()?)),
+        }
+    }
+}
+
+impl TomlKey {
+    /// Returns the names of the parts of the key.
+    ///
+    /// The names of `package.metadata."docs.rs"` are `package`, `metadata` and `docs.rs`.
+    pub fn names(&self) -> SyntaxResult<Vec<TokenText>> {
+        self.parts()
+            .iter()
+            .map(|part| part?.inner_string_text())
+            .collect()
+    }
+}
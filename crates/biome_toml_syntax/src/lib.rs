@@ -0,0 +1,123 @@
+#[macro_use]
+mod generated;
+mod file_source;
+pub mod key_ext;
+mod syntax_node;
+
+pub use self::generated::*;
+use biome_rowan::{RawSyntaxKind, TokenText};
+pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use file_source::TomlFileSource;
+pub use syntax_node::*;
+
+impl From<u16> for TomlSyntaxKind {
+    fn from(d: u16) -> TomlSyntaxKind {
+        assert!(d <= (TomlSyntaxKind::__LAST as u16));
+        unsafe { std::mem::transmute::<u16, TomlSyntaxKind>(d) }
+    }
+}
+
+impl From<TomlSyntaxKind> for u16 {
+    fn from(k: TomlSyntaxKind) -> u16 {
+        k as u16
+    }
+}
+
+impl biome_rowan::SyntaxKind for TomlSyntaxKind {
+    const TOMBSTONE: Self = TomlSyntaxKind::TOMBSTONE;
+    const EOF: Self = TomlSyntaxKind::EOF;
+
+    fn is_bogus(&self) -> bool {
+        matches!(
+            self,
+            TomlSyntaxKind::TOML_BOGUS | TomlSyntaxKind::TOML_BOGUS_VALUE
+        )
+    }
+
+    fn to_bogus(&self) -> Self {
+        match self {
+            TomlSyntaxKind::TOML_BOGUS_VALUE => TomlSyntaxKind::TOML_BOGUS_VALUE,
+            _ => TomlSyntaxKind::TOML_BOGUS,
+        }
+    }
+
+    #[inline]
+    fn to_raw(&self) -> RawSyntaxKind {
+        RawSyntaxKind(*self as u16)
+    }
+
+    #[inline]
+    fn from_raw(raw: RawSyntaxKind) -> Self {
+        Self::from(raw.0)
+    }
+
+    fn is_root(&self) -> bool {
+        matches!(self, TomlSyntaxKind::TOML_ROOT)
+    }
+
+    fn is_list(&self) -> bool {
+        TomlSyntaxKind::is_list(*self)
+    }
+
+    fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            TomlSyntaxKind::NEWLINE | TomlSyntaxKind::WHITESPACE | TomlSyntaxKind::COMMENT
+        )
+    }
+
+    fn to_string(&self) -> Option<&'static str> {
+        TomlSyntaxKind::to_string(self)
+    }
+}
+
+impl TomlSyntaxKind {
+    pub fn is_trivia(self) -> bool {
+        matches!(self, TomlSyntaxKind::NEWLINE | TomlSyntaxKind::WHITESPACE)
+    }
+
+    pub fn is_comments(self) -> bool {
+        matches!(self, TomlSyntaxKind::COMMENT)
+    }
+
+    #[inline]
+    pub const fn is_keyword(self) -> bool {
+        matches!(self, T![true] | T![false])
+    }
+}
+
+impl TryFrom<TomlSyntaxKind> for TriviaPieceKind {
+    type Error = ();
+
+    fn try_from(value: TomlSyntaxKind) -> Result<Self, Self::Error> {
+        if value.is_trivia() {
+            match value {
+                TomlSyntaxKind::NEWLINE => Ok(TriviaPieceKind::Newline),
+                TomlSyntaxKind::WHITESPACE => Ok(TriviaPieceKind::Whitespace),
+                _ => unreachable!("Not Trivia"),
+            }
+        } else if value.is_comments() {
+            match value {
+                TomlSyntaxKind::COMMENT => Ok(TriviaPieceKind::SingleLineComment),
+                _ => unreachable!("Not Comment"),
+            }
+        } else {
+            Err(())
+        }
+    }
+}
+
+/// Returns the text of a string token without its quotes.
+///
+/// The escape sequences of the basic strings are kept as is.
+pub fn inner_string_text(token: &TomlSyntaxToken) -> TokenText {
+    let text = token.token_text_trimmed();
+    let quotes = if text.starts_with("\"\"\"") || text.starts_with("'''") {
+        3
+    } else {
+        1
+    };
+    // SAFETY: the string literals have their delimiters at the start and the end
+    let range = TextRange::new(TextSize::from(quotes), text.len() - TextSize::from(quotes));
+    text.slice(range)
+}
@@ -0,0 +1,14 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use biome_analyze::declare_group;
+
+pub mod use_sorted_yaml_keys;
+
+declare_group! {
+    pub Nursery {
+        name : "nursery" ,
+        rules : [
+            self :: use_sorted_yaml_keys :: UseSortedYamlKeys ,
+        ]
+     }
+}
@@ -0,0 +1,173 @@
+use crate::YamlRuleAction;
+use biome_analyze::{
+    context::RuleContext, declare_rule, ActionCategory, Ast, FixKind, Rule, RuleDiagnostic,
+};
+use biome_console::markup;
+use biome_diagnostics::Applicability;
+use biome_rowan::{
+    declare_node_union, AstNode, AstNodeList, AstSeparatedList, BatchMutationExt, TextRange,
+};
+use biome_yaml_syntax::{
+    AnyYamlNode, YamlBlockMapEntry, YamlBlockMapping, YamlFlowMapEntry, YamlFlowMapping,
+};
+
+declare_rule! {
+    /// Enforce the order of the keys of the YAML mappings.
+    ///
+    /// The keys of a mapping are easier to find, and the changes of the mapping are easier to merge,
+    /// when the keys are sorted.
+    /// The keys are sorted in lexicographic order.
+    ///
+    /// Only the mappings whose keys are all scalars are checked.
+    /// The keys aren't moved by the code action when they have comments.
+    ///
+    /// ## Examples
+    ///
+    /// ### Invalid
+    ///
+    /// ```yaml,expect_diagnostic
+    /// vite: ^5.0.0
+    /// react: ^18.0.0
+    /// ```
+    ///
+    /// ### Valid
+    ///
+    /// ```yaml
+    /// react: ^18.0.0
+    /// vite: ^5.0.0
+    /// ```
+    ///
+    pub UseSortedYamlKeys {
+        version: "next",
+        name: "useSortedYamlKeys",
+        language: "yaml",
+        recommended: false,
+        fix_kind: FixKind::Safe,
+    }
+}
+
+declare_node_union! {
+    pub AnyYamlMapping = YamlBlockMapping | YamlFlowMapping
+}
+
+declare_node_union! {
+    pub AnyYamlMapEntry = YamlBlockMapEntry | YamlFlowMapEntry
+}
+
+impl AnyYamlMapEntry {
+    fn key(&self) -> Option<AnyYamlNode> {
+        match self {
+            Self::YamlBlockMapEntry(entry) => entry.key().ok(),
+            Self::YamlFlowMapEntry(entry) => entry.key().ok(),
+        }
+    }
+}
+
+pub struct UnsortedKeys {
+    /// The indexes of the entries in their sorted order
+    order: Vec<usize>,
+    /// The name of the first entry that isn't at its place
+    misplaced: String,
+    /// The range of the key of the misplaced entry
+    range: TextRange,
+    /// The name of the entry that should come after the misplaced entry
+    before: String,
+}
+
+impl Rule for UseSortedYamlKeys {
+    type Query = Ast<AnyYamlMapping>;
+    type State = UnsortedKeys;
+    type Signals = Option<Self::State>;
+    type Options = ();
+
+    fn run(ctx: &RuleContext<Self>) -> Self::Signals {
+        let entries = mapping_entries(ctx.query())?;
+        let names = entries
+            .iter()
+            .map(|entry| entry.key().as_ref().and_then(key_name))
+            .collect::<Option<Vec<_>>>()?;
+        let mut order = (0..entries.len()).collect::<Vec<_>>();
+        order.sort_by(|&a, &b| names[a].cmp(&names[b]));
+        let index = order
+            .iter()
+            .enumerate()
+            .position(|(index, sorted_index)| index != *sorted_index)?;
+        Some(UnsortedKeys {
+            misplaced: names[order[index]].clone(),
+            range: entries[order[index]].key()?.range(),
+            before: names[index].clone(),
+            order,
+        })
+    }
+
+    fn diagnostic(_ctx: &RuleContext<Self>, state: &Self::State) -> Option<RuleDiagnostic> {
+        let name = &state.misplaced;
+        let before = &state.before;
+        Some(
+            RuleDiagnostic::new(
+                rule_category!(),
+                state.range,
+                markup! {
+                    "The key "<Emphasis>{name}</Emphasis>" should be placed before "<Emphasis>{before}</Emphasis>"."
+                },
+            )
+            .note(markup! {
+                "The keys are sorted in lexicographic order."
+            }),
+        )
+    }
+
+    fn action(ctx: &RuleContext<Self>, state: &Self::State) -> Option<YamlRuleAction> {
+        let entries = mapping_entries(ctx.query())?;
+        // Moving an entry with comments could attach the comments to another entry
+        if entries
+            .iter()
+            .any(|entry| entry.syntax().has_comments_direct())
+        {
+            return None;
+        }
+        let mut mutation = ctx.root().begin();
+        for (index, sorted_index) in state.order.iter().enumerate() {
+            if index != *sorted_index {
+                mutation.replace_node(entries[index].clone(), entries[*sorted_index].clone());
+            }
+        }
+        Some(YamlRuleAction::new(
+            ActionCategory::QuickFix,
+            Applicability::Always,
+            markup! { "Sort the keys." }.to_owned(),
+            mutation,
+        ))
+    }
+}
+
+fn mapping_entries(mapping: &AnyYamlMapping) -> Option<Vec<AnyYamlMapEntry>> {
+    match mapping {
+        AnyYamlMapping::YamlBlockMapping(mapping) => Some(
+            mapping
+                .entries()
+                .iter()
+                .map(AnyYamlMapEntry::from)
+                .collect(),
+        ),
+        AnyYamlMapping::YamlFlowMapping(mapping) => mapping
+            .entries()
+            .iter()
+            .map(|entry| entry.ok().map(AnyYamlMapEntry::from))
+            .collect(),
+    }
+}
+
+/// Returns the value of a scalar key, without its quotes.
+fn key_name(key: &AnyYamlNode) -> Option<String> {
+    let text = match key {
+        AnyYamlNode::YamlPlainScalar(scalar) => {
+            return Some(scalar.value_token().ok()?.text_trimmed().to_string())
+        }
+        AnyYamlNode::YamlDoubleQuotedScalar(scalar) => scalar.value_token().ok()?,
+        AnyYamlNode::YamlSingleQuotedScalar(scalar) => scalar.value_token().ok()?,
+        _ => return None,
+    };
+    let text = text.text_trimmed();
+    text.get(1..text.len() - 1).map(ToString::to_string)
+}
@@ -0,0 +1,6 @@
+//! Generated file, do not edit by hand, see `xtask/codegen`
+
+use crate::lint;
+
+pub type UseSortedYamlKeys =
+    <lint::nursery::use_sorted_yaml_keys::UseSortedYamlKeys as biome_analyze::Rule>::Options;
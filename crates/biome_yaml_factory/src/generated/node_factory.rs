@@ -7,12 +7,240 @@ use biome_yaml_syntax::{
     YamlSyntaxElement as SyntaxElement, YamlSyntaxNode as SyntaxNode,
     YamlSyntaxToken as SyntaxToken, *,
 };
-pub fn yaml_document(content: YamlContentList) -> YamlDocument {
-    YamlDocument::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_DOCUMENT,
-        [Some(SyntaxElement::Node(content.into_syntax()))],
+pub fn yaml_alias_node(value_token: SyntaxToken) -> YamlAliasNode {
+    YamlAliasNode::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_ALIAS_NODE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_anchor_property(value_token: SyntaxToken) -> YamlAnchorProperty {
+    YamlAnchorProperty::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_ANCHOR_PROPERTY,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_block_map_entry(
+    key: AnyYamlNode,
+    colon_token: SyntaxToken,
+) -> YamlBlockMapEntryBuilder {
+    YamlBlockMapEntryBuilder {
+        key,
+        colon_token,
+        value: None,
+    }
+}
+pub struct YamlBlockMapEntryBuilder {
+    key: AnyYamlNode,
+    colon_token: SyntaxToken,
+    value: Option<AnyYamlNode>,
+}
+impl YamlBlockMapEntryBuilder {
+    pub fn with_value(mut self, value: AnyYamlNode) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlBlockMapEntry {
+        YamlBlockMapEntry::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_BLOCK_MAP_ENTRY,
+            [
+                Some(SyntaxElement::Node(self.key.into_syntax())),
+                Some(SyntaxElement::Token(self.colon_token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn yaml_block_mapping(entries: YamlBlockMapEntryList) -> YamlBlockMapping {
+    YamlBlockMapping::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_BLOCK_MAPPING,
+        [Some(SyntaxElement::Node(entries.into_syntax()))],
+    ))
+}
+pub fn yaml_block_scalar(value_token: SyntaxToken) -> YamlBlockScalar {
+    YamlBlockScalar::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_BLOCK_SCALAR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_block_sequence(entries: YamlBlockSequenceEntryList) -> YamlBlockSequence {
+    YamlBlockSequence::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_BLOCK_SEQUENCE,
+        [Some(SyntaxElement::Node(entries.into_syntax()))],
+    ))
+}
+pub fn yaml_block_sequence_entry(dash_token: SyntaxToken) -> YamlBlockSequenceEntryBuilder {
+    YamlBlockSequenceEntryBuilder {
+        dash_token,
+        value: None,
+    }
+}
+pub struct YamlBlockSequenceEntryBuilder {
+    dash_token: SyntaxToken,
+    value: Option<AnyYamlNode>,
+}
+impl YamlBlockSequenceEntryBuilder {
+    pub fn with_value(mut self, value: AnyYamlNode) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlBlockSequenceEntry {
+        YamlBlockSequenceEntry::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY,
+            [
+                Some(SyntaxElement::Token(self.dash_token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn yaml_directive(value_token: SyntaxToken) -> YamlDirective {
+    YamlDirective::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_DIRECTIVE,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_document(directives: YamlDirectiveList) -> YamlDocumentBuilder {
+    YamlDocumentBuilder {
+        directives,
+        doc_start_token: None,
+        node: None,
+        doc_end_token: None,
+    }
+}
+pub struct YamlDocumentBuilder {
+    directives: YamlDirectiveList,
+    doc_start_token: Option<SyntaxToken>,
+    node: Option<AnyYamlNode>,
+    doc_end_token: Option<SyntaxToken>,
+}
+impl YamlDocumentBuilder {
+    pub fn with_doc_start_token(mut self, doc_start_token: SyntaxToken) -> Self {
+        self.doc_start_token = Some(doc_start_token);
+        self
+    }
+    pub fn with_node(mut self, node: AnyYamlNode) -> Self {
+        self.node = Some(node);
+        self
+    }
+    pub fn with_doc_end_token(mut self, doc_end_token: SyntaxToken) -> Self {
+        self.doc_end_token = Some(doc_end_token);
+        self
+    }
+    pub fn build(self) -> YamlDocument {
+        YamlDocument::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_DOCUMENT,
+            [
+                Some(SyntaxElement::Node(self.directives.into_syntax())),
+                self.doc_start_token
+                    .map(|token| SyntaxElement::Token(token)),
+                self.node
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+                self.doc_end_token.map(|token| SyntaxElement::Token(token)),
+            ],
+        ))
+    }
+}
+pub fn yaml_double_quoted_scalar(value_token: SyntaxToken) -> YamlDoubleQuotedScalar {
+    YamlDoubleQuotedScalar::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_DOUBLE_QUOTED_SCALAR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_flow_map_entry(key: AnyYamlNode) -> YamlFlowMapEntryBuilder {
+    YamlFlowMapEntryBuilder {
+        key,
+        colon_token: None,
+        value: None,
+    }
+}
+pub struct YamlFlowMapEntryBuilder {
+    key: AnyYamlNode,
+    colon_token: Option<SyntaxToken>,
+    value: Option<AnyYamlNode>,
+}
+impl YamlFlowMapEntryBuilder {
+    pub fn with_colon_token(mut self, colon_token: SyntaxToken) -> Self {
+        self.colon_token = Some(colon_token);
+        self
+    }
+    pub fn with_value(mut self, value: AnyYamlNode) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlFlowMapEntry {
+        YamlFlowMapEntry::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_FLOW_MAP_ENTRY,
+            [
+                Some(SyntaxElement::Node(self.key.into_syntax())),
+                self.colon_token.map(|token| SyntaxElement::Token(token)),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
+pub fn yaml_flow_mapping(
+    l_curly_token: SyntaxToken,
+    entries: YamlFlowMapEntryList,
+    r_curly_token: SyntaxToken,
+) -> YamlFlowMapping {
+    YamlFlowMapping::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_FLOW_MAPPING,
+        [
+            Some(SyntaxElement::Token(l_curly_token)),
+            Some(SyntaxElement::Node(entries.into_syntax())),
+            Some(SyntaxElement::Token(r_curly_token)),
+        ],
     ))
 }
+pub fn yaml_flow_sequence(
+    l_brack_token: SyntaxToken,
+    entries: YamlFlowSequenceEntryList,
+    r_brack_token: SyntaxToken,
+) -> YamlFlowSequence {
+    YamlFlowSequence::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_FLOW_SEQUENCE,
+        [
+            Some(SyntaxElement::Token(l_brack_token)),
+            Some(SyntaxElement::Node(entries.into_syntax())),
+            Some(SyntaxElement::Token(r_brack_token)),
+        ],
+    ))
+}
+pub fn yaml_plain_scalar(value_token: SyntaxToken) -> YamlPlainScalar {
+    YamlPlainScalar::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_PLAIN_SCALAR,
+        [Some(SyntaxElement::Token(value_token))],
+    ))
+}
+pub fn yaml_properties_node(properties: YamlPropertyList) -> YamlPropertiesNodeBuilder {
+    YamlPropertiesNodeBuilder {
+        properties,
+        value: None,
+    }
+}
+pub struct YamlPropertiesNodeBuilder {
+    properties: YamlPropertyList,
+    value: Option<AnyYamlNode>,
+}
+impl YamlPropertiesNodeBuilder {
+    pub fn with_value(mut self, value: AnyYamlNode) -> Self {
+        self.value = Some(value);
+        self
+    }
+    pub fn build(self) -> YamlPropertiesNode {
+        YamlPropertiesNode::unwrap_cast(SyntaxNode::new_detached(
+            YamlSyntaxKind::YAML_PROPERTIES_NODE,
+            [
+                Some(SyntaxElement::Node(self.properties.into_syntax())),
+                self.value
+                    .map(|token| SyntaxElement::Node(token.into_syntax())),
+            ],
+        ))
+    }
+}
 pub fn yaml_root(documents: YamlDocumentList, eof_token: SyntaxToken) -> YamlRootBuilder {
     YamlRootBuilder {
         documents,
@@ -41,25 +269,49 @@ impl YamlRootBuilder {
         ))
     }
 }
-pub fn yaml_scalar(value_token: SyntaxToken) -> YamlScalar {
-    YamlScalar::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_SCALAR,
+pub fn yaml_single_quoted_scalar(value_token: SyntaxToken) -> YamlSingleQuotedScalar {
+    YamlSingleQuotedScalar::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_SINGLE_QUOTED_SCALAR,
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn yaml_string_literal(value_token: SyntaxToken) -> YamlStringLiteral {
-    YamlStringLiteral::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_STRING_LITERAL,
+pub fn yaml_tag_property(value_token: SyntaxToken) -> YamlTagProperty {
+    YamlTagProperty::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_TAG_PROPERTY,
         [Some(SyntaxElement::Token(value_token))],
     ))
 }
-pub fn yaml_content_list<I>(items: I) -> YamlContentList
+pub fn yaml_block_map_entry_list<I>(items: I) -> YamlBlockMapEntryList
 where
-    I: IntoIterator<Item = AnyYamlContent>,
+    I: IntoIterator<Item = YamlBlockMapEntry>,
     I::IntoIter: ExactSizeIterator,
 {
-    YamlContentList::unwrap_cast(SyntaxNode::new_detached(
-        YamlSyntaxKind::YAML_CONTENT_LIST,
+    YamlBlockMapEntryList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_BLOCK_MAP_ENTRY_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn yaml_block_sequence_entry_list<I>(items: I) -> YamlBlockSequenceEntryList
+where
+    I: IntoIterator<Item = YamlBlockSequenceEntry>,
+    I::IntoIter: ExactSizeIterator,
+{
+    YamlBlockSequenceEntryList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
+pub fn yaml_directive_list<I>(items: I) -> YamlDirectiveList
+where
+    I: IntoIterator<Item = YamlDirective>,
+    I::IntoIter: ExactSizeIterator,
+{
+    YamlDirectiveList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_DIRECTIVE_LIST,
         items
             .into_iter()
             .map(|item| Some(item.into_syntax().into())),
@@ -77,6 +329,60 @@ where
             .map(|item| Some(item.into_syntax().into())),
     ))
 }
+pub fn yaml_flow_map_entry_list<I, S>(items: I, separators: S) -> YamlFlowMapEntryList
+where
+    I: IntoIterator<Item = YamlFlowMapEntry>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = YamlSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    YamlFlowMapEntryList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_FLOW_MAP_ENTRY_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn yaml_flow_sequence_entry_list<I, S>(items: I, separators: S) -> YamlFlowSequenceEntryList
+where
+    I: IntoIterator<Item = AnyYamlNode>,
+    I::IntoIter: ExactSizeIterator,
+    S: IntoIterator<Item = YamlSyntaxToken>,
+    S::IntoIter: ExactSizeIterator,
+{
+    let mut items = items.into_iter();
+    let mut separators = separators.into_iter();
+    let length = items.len() + separators.len();
+    YamlFlowSequenceEntryList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_FLOW_SEQUENCE_ENTRY_LIST,
+        (0..length).map(|index| {
+            if index % 2 == 0 {
+                Some(items.next()?.into_syntax().into())
+            } else {
+                Some(separators.next()?.into())
+            }
+        }),
+    ))
+}
+pub fn yaml_property_list<I>(items: I) -> YamlPropertyList
+where
+    I: IntoIterator<Item = AnyYamlProperty>,
+    I::IntoIter: ExactSizeIterator,
+{
+    YamlPropertyList::unwrap_cast(SyntaxNode::new_detached(
+        YamlSyntaxKind::YAML_PROPERTY_LIST,
+        items
+            .into_iter()
+            .map(|item| Some(item.into_syntax().into())),
+    ))
+}
 pub fn yaml_bogus<I>(slots: I) -> YamlBogus
 where
     I: IntoIterator<Item = Option<SyntaxElement>>,
@@ -0,0 +1,81 @@
+use crate::prelude::*;
+use biome_diagnostics::category;
+use biome_formatter::comments::{
+    CommentKind, CommentPlacement, CommentStyle, Comments, DecoratedComment, SourceComment,
+};
+use biome_formatter::formatter::Formatter;
+use biome_formatter::{write, FormatResult, FormatRule};
+use biome_rowan::SyntaxTriviaPieceComments;
+use biome_suppression::parse_suppression_comment;
+use biome_yaml_syntax::{YamlFlowMapping, YamlFlowSequence, YamlLanguage, YamlSyntaxKind};
+
+pub type YamlComments = Comments<YamlLanguage>;
+
+#[derive(Default)]
+pub struct FormatYamlLeadingComment;
+
+impl FormatRule<SourceComment<YamlLanguage>> for FormatYamlLeadingComment {
+    type Context = YamlFormatContext;
+
+    fn fmt(
+        &self,
+        comment: &SourceComment<YamlLanguage>,
+        f: &mut Formatter<Self::Context>,
+    ) -> FormatResult<()> {
+        write!(f, [comment.piece().as_piece()])
+    }
+}
+
+#[derive(Eq, PartialEq, Copy, Clone, Debug, Default)]
+pub struct YamlCommentStyle;
+
+impl CommentStyle for YamlCommentStyle {
+    type Language = YamlLanguage;
+
+    fn is_suppression(text: &str) -> bool {
+        // `parse_suppression_comment` only understands `//` and `/*` comments,
+        // so `# biome-ignore format: reason` is parsed as a `//` comment
+        let Some(comment) = text.strip_prefix('#') else {
+            return false;
+        };
+        parse_suppression_comment(&std::format!("//{comment}"))
+            .filter_map(Result::ok)
+            .flat_map(|suppression| suppression.categories)
+            .any(|(key, _)| key == category!("format"))
+    }
+
+    fn get_comment_kind(_comment: &SyntaxTriviaPieceComments<Self::Language>) -> CommentKind {
+        CommentKind::Line
+    }
+
+    fn place_comment(
+        &self,
+        comment: DecoratedComment<Self::Language>,
+    ) -> CommentPlacement<Self::Language> {
+        handle_empty_flow_collection_comment(comment)
+    }
+}
+
+fn handle_empty_flow_collection_comment(
+    comment: DecoratedComment<YamlLanguage>,
+) -> CommentPlacement<YamlLanguage> {
+    if !matches!(
+        comment.enclosing_node().kind(),
+        YamlSyntaxKind::YAML_FLOW_MAPPING | YamlSyntaxKind::YAML_FLOW_SEQUENCE,
+    ) {
+        return CommentPlacement::Default(comment);
+    }
+
+    if let Some(sequence) = YamlFlowSequence::cast_ref(comment.enclosing_node()) {
+        if sequence.entries().is_empty() {
+            return CommentPlacement::dangling(comment.enclosing_node().clone(), comment);
+        }
+    }
+    if let Some(mapping) = YamlFlowMapping::cast_ref(comment.enclosing_node()) {
+        if mapping.entries().is_empty() {
+            return CommentPlacement::dangling(comment.enclosing_node().clone(), comment);
+        }
+    }
+
+    CommentPlacement::Default(comment)
+}
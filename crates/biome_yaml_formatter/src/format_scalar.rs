@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use biome_formatter::{write, QuoteStyle};
+use biome_yaml_syntax::{TextLen, TextSize, YamlSyntaxToken};
+
+/// Formats a plain or a quoted scalar.
+///
+/// The quotes of a single line scalar are replaced with the preferred quotes
+/// when its value can be written without escaping them.
+///
+/// A scalar that spans several lines is printed line by line with the indentation of the formatted document:
+/// the indentation of its lines and the spaces at their end aren't part of its value, unlike its empty lines.
+pub(crate) fn format_flow_scalar(token: &YamlSyntaxToken) -> FormatFlowScalar {
+    FormatFlowScalar { token }
+}
+
+pub(crate) struct FormatFlowScalar<'token> {
+    token: &'token YamlSyntaxToken,
+}
+
+impl Format<YamlFormatContext> for FormatFlowScalar<'_> {
+    fn fmt(&self, f: &mut Formatter<YamlFormatContext>) -> FormatResult<()> {
+        let text = self.token.text_trimmed();
+
+        if text.contains(['\n', '\r']) {
+            return format_replaced(
+                self.token,
+                &format_with(|f| write_scalar_lines(self.token, f)),
+            )
+            .fmt(f);
+        }
+
+        match normalize_quotes(text, f.options().quote_style()) {
+            Some(normalized) => format_replaced(
+                self.token,
+                &dynamic_text(&normalized, self.token.text_trimmed_range().start()),
+            )
+            .fmt(f),
+            None => self.token.format().fmt(f),
+        }
+    }
+}
+
+/// Returns the quoted scalar `text` with the `preferred` quotes,
+/// or `None` if it doesn't need to change or if its value contains escapes or the preferred quote.
+fn normalize_quotes(text: &str, preferred: QuoteStyle) -> Option<String> {
+    let quote = text.chars().next()?;
+    if !matches!(quote, '"' | '\'') || quote == preferred.as_char() {
+        return None;
+    }
+
+    let content = &text[1..text.len() - 1];
+    match preferred {
+        QuoteStyle::Single if !content.contains(['\\', '\'']) => Some(std::format!("'{content}'")),
+        QuoteStyle::Double => {
+            // A single quote is escaped with another single quote
+            let content = content.replace("''", "'");
+            (!content.contains(['\\', '"'])).then(|| std::format!("\"{content}\""))
+        }
+        _ => None,
+    }
+}
+
+fn write_scalar_lines(token: &YamlSyntaxToken, f: &mut YamlFormatter) -> FormatResult<()> {
+    let mut line_start = token.text_trimmed_range().start();
+    let mut empty_lines = 0;
+
+    for (index, line) in token.text_trimmed().split('\n').enumerate() {
+        let start = line_start;
+        line_start += line.text_len() + TextSize::from(1);
+
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let content = if index == 0 { line } else { line.trim_start() };
+        // A backslash at the end of a line of a double quoted scalar escapes the spaces and the line break
+        let content = match content.trim_end() {
+            trimmed if trimmed.ends_with('\\') => content,
+            trimmed => trimmed,
+        };
+
+        if content.is_empty() {
+            empty_lines += 1;
+            continue;
+        }
+
+        if index > 0 {
+            write_line_break(f, empty_lines)?;
+            empty_lines = 0;
+        }
+
+        let indentation = line.text_len() - line.trim_start().text_len();
+        let position = if index == 0 {
+            start
+        } else {
+            start + indentation
+        };
+        write!(f, [dynamic_text(content, position)])?;
+    }
+
+    Ok(())
+}
+
+/// Writes a line break followed by `empty_lines` empty lines.
+///
+/// The printer merges consecutive line breaks, so the empty lines of a scalar,
+/// which are part of its value, are written as text.
+pub(crate) fn write_line_break(f: &mut YamlFormatter, empty_lines: usize) -> FormatResult<()> {
+    if empty_lines > 0 {
+        for _ in 0..=empty_lines {
+            write!(f, [text("\n")])?;
+        }
+    }
+    write!(f, [hard_line_break()])
+}
@@ -0,0 +1,1021 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::{
+    AsFormat, FormatBogusNodeRule, FormatNodeRule, IntoFormat, YamlFormatContext, YamlFormatter,
+};
+use biome_formatter::{FormatOwnedWithRule, FormatRefWithRule, FormatResult, FormatRule};
+impl FormatRule<biome_yaml_syntax::YamlAliasNode>
+    for crate::yaml::auxiliary::alias_node::FormatYamlAliasNode
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlAliasNode,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlAliasNode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlAliasNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlAliasNode,
+        crate::yaml::auxiliary::alias_node::FormatYamlAliasNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::alias_node::FormatYamlAliasNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlAliasNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlAliasNode,
+        crate::yaml::auxiliary::alias_node::FormatYamlAliasNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::alias_node::FormatYamlAliasNode::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlAnchorProperty>
+    for crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlAnchorProperty,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlAnchorProperty>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlAnchorProperty {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlAnchorProperty,
+        crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlAnchorProperty {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlAnchorProperty,
+        crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::anchor_property::FormatYamlAnchorProperty::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockMapEntry>
+    for crate::yaml::auxiliary::block_map_entry::FormatYamlBlockMapEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockMapEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockMapEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapEntry,
+        crate::yaml::auxiliary::block_map_entry::FormatYamlBlockMapEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_map_entry::FormatYamlBlockMapEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapEntry,
+        crate::yaml::auxiliary::block_map_entry::FormatYamlBlockMapEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_map_entry::FormatYamlBlockMapEntry::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockMapping>
+    for crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockMapping,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockMapping>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapping {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapping,
+        crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapping {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapping,
+        crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_mapping::FormatYamlBlockMapping::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockScalar>
+    for crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockScalar,
+        crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockScalar,
+        crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_scalar::FormatYamlBlockScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockSequence>
+    for crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockSequence,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockSequence>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequence {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockSequence,
+        crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequence {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockSequence,
+        crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_sequence::FormatYamlBlockSequence::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBlockSequenceEntry>
+    for crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBlockSequenceEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlBlockSequenceEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequenceEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockSequenceEntry,
+        crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequenceEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockSequenceEntry,
+        crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::block_sequence_entry::FormatYamlBlockSequenceEntry::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlDirective>
+    for crate::yaml::auxiliary::directive::FormatYamlDirective
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlDirective,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlDirective>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDirective {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDirective,
+        crate::yaml::auxiliary::directive::FormatYamlDirective,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::directive::FormatYamlDirective::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDirective {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDirective,
+        crate::yaml::auxiliary::directive::FormatYamlDirective,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::directive::FormatYamlDirective::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlDocument>
+    for crate::yaml::auxiliary::document::FormatYamlDocument
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlDocument,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlDocument>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocument {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDocument,
+        crate::yaml::auxiliary::document::FormatYamlDocument,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::document::FormatYamlDocument::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocument {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDocument,
+        crate::yaml::auxiliary::document::FormatYamlDocument,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::document::FormatYamlDocument::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlDoubleQuotedScalar>
+    for crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlDoubleQuotedScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlDoubleQuotedScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDoubleQuotedScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDoubleQuotedScalar,
+        crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDoubleQuotedScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDoubleQuotedScalar,
+        crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::double_quoted_scalar::FormatYamlDoubleQuotedScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowMapEntry>
+    for crate::yaml::auxiliary::flow_map_entry::FormatYamlFlowMapEntry
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowMapEntry,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowMapEntry>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapEntry {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowMapEntry,
+        crate::yaml::auxiliary::flow_map_entry::FormatYamlFlowMapEntry,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_map_entry::FormatYamlFlowMapEntry::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapEntry {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowMapEntry,
+        crate::yaml::auxiliary::flow_map_entry::FormatYamlFlowMapEntry,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_map_entry::FormatYamlFlowMapEntry::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowMapping>
+    for crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowMapping,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowMapping>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapping {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowMapping,
+        crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapping {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowMapping,
+        crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_mapping::FormatYamlFlowMapping::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlFlowSequence>
+    for crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlFlowSequence,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlFlowSequence>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowSequence {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowSequence,
+        crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowSequence {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowSequence,
+        crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::flow_sequence::FormatYamlFlowSequence::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlPlainScalar>
+    for crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlPlainScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlPlainScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlPlainScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlPlainScalar,
+        crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlPlainScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlPlainScalar,
+        crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::plain_scalar::FormatYamlPlainScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlPropertiesNode>
+    for crate::yaml::auxiliary::properties_node::FormatYamlPropertiesNode
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlPropertiesNode,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlPropertiesNode>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlPropertiesNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlPropertiesNode,
+        crate::yaml::auxiliary::properties_node::FormatYamlPropertiesNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::properties_node::FormatYamlPropertiesNode::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlPropertiesNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlPropertiesNode,
+        crate::yaml::auxiliary::properties_node::FormatYamlPropertiesNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::properties_node::FormatYamlPropertiesNode::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlRoot> for crate::yaml::auxiliary::root::FormatYamlRoot {
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_yaml_syntax::YamlRoot, f: &mut YamlFormatter) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlRoot>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlRoot {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlRoot,
+        crate::yaml::auxiliary::root::FormatYamlRoot,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::root::FormatYamlRoot::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlRoot {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlRoot,
+        crate::yaml::auxiliary::root::FormatYamlRoot,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::root::FormatYamlRoot::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlSingleQuotedScalar>
+    for crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlSingleQuotedScalar,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlSingleQuotedScalar>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlSingleQuotedScalar {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlSingleQuotedScalar,
+        crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlSingleQuotedScalar {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlSingleQuotedScalar,
+        crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::single_quoted_scalar::FormatYamlSingleQuotedScalar::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlTagProperty>
+    for crate::yaml::auxiliary::tag_property::FormatYamlTagProperty
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlTagProperty,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatNodeRule::<biome_yaml_syntax::YamlTagProperty>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlTagProperty {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlTagProperty,
+        crate::yaml::auxiliary::tag_property::FormatYamlTagProperty,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::auxiliary::tag_property::FormatYamlTagProperty::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlTagProperty {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlTagProperty,
+        crate::yaml::auxiliary::tag_property::FormatYamlTagProperty,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::auxiliary::tag_property::FormatYamlTagProperty::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockMapEntryList,
+        crate::yaml::lists::block_map_entry_list::FormatYamlBlockMapEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::block_map_entry_list::FormatYamlBlockMapEntryList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockMapEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockMapEntryList,
+        crate::yaml::lists::block_map_entry_list::FormatYamlBlockMapEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::block_map_entry_list::FormatYamlBlockMapEntryList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequenceEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBlockSequenceEntryList,
+        crate::yaml::lists::block_sequence_entry_list::FormatYamlBlockSequenceEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule :: new (self , crate :: yaml :: lists :: block_sequence_entry_list :: FormatYamlBlockSequenceEntryList :: default ())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBlockSequenceEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBlockSequenceEntryList,
+        crate::yaml::lists::block_sequence_entry_list::FormatYamlBlockSequenceEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule :: new (self , crate :: yaml :: lists :: block_sequence_entry_list :: FormatYamlBlockSequenceEntryList :: default ())
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDirectiveList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDirectiveList,
+        crate::yaml::lists::directive_list::FormatYamlDirectiveList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::directive_list::FormatYamlDirectiveList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDirectiveList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDirectiveList,
+        crate::yaml::lists::directive_list::FormatYamlDirectiveList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::directive_list::FormatYamlDirectiveList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocumentList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlDocumentList,
+        crate::yaml::lists::document_list::FormatYamlDocumentList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::document_list::FormatYamlDocumentList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlDocumentList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlDocumentList,
+        crate::yaml::lists::document_list::FormatYamlDocumentList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::document_list::FormatYamlDocumentList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowMapEntryList,
+        crate::yaml::lists::flow_map_entry_list::FormatYamlFlowMapEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::flow_map_entry_list::FormatYamlFlowMapEntryList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowMapEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowMapEntryList,
+        crate::yaml::lists::flow_map_entry_list::FormatYamlFlowMapEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::flow_map_entry_list::FormatYamlFlowMapEntryList::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowSequenceEntryList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlFlowSequenceEntryList,
+        crate::yaml::lists::flow_sequence_entry_list::FormatYamlFlowSequenceEntryList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::flow_sequence_entry_list::FormatYamlFlowSequenceEntryList::default(
+            ),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlFlowSequenceEntryList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlFlowSequenceEntryList,
+        crate::yaml::lists::flow_sequence_entry_list::FormatYamlFlowSequenceEntryList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::flow_sequence_entry_list::FormatYamlFlowSequenceEntryList::default(
+            ),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlPropertyList {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlPropertyList,
+        crate::yaml::lists::property_list::FormatYamlPropertyList,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::lists::property_list::FormatYamlPropertyList::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlPropertyList {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlPropertyList,
+        crate::yaml::lists::property_list::FormatYamlPropertyList,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::lists::property_list::FormatYamlPropertyList::default(),
+        )
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBogus> for crate::yaml::bogus::bogus::FormatYamlBogus {
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(&self, node: &biome_yaml_syntax::YamlBogus, f: &mut YamlFormatter) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_yaml_syntax::YamlBogus>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogus {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBogus,
+        crate::yaml::bogus::bogus::FormatYamlBogus,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::yaml::bogus::bogus::FormatYamlBogus::default())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogus {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBogus,
+        crate::yaml::bogus::bogus::FormatYamlBogus,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::yaml::bogus::bogus::FormatYamlBogus::default())
+    }
+}
+impl FormatRule<biome_yaml_syntax::YamlBogusValue>
+    for crate::yaml::bogus::bogus_value::FormatYamlBogusValue
+{
+    type Context = YamlFormatContext;
+    #[inline(always)]
+    fn fmt(
+        &self,
+        node: &biome_yaml_syntax::YamlBogusValue,
+        f: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        FormatBogusNodeRule::<biome_yaml_syntax::YamlBogusValue>::fmt(self, node, f)
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusValue {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::YamlBogusValue,
+        crate::yaml::bogus::bogus_value::FormatYamlBogusValue,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_value::FormatYamlBogusValue::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::YamlBogusValue {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::YamlBogusValue,
+        crate::yaml::bogus::bogus_value::FormatYamlBogusValue,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::bogus::bogus_value::FormatYamlBogusValue::default(),
+        )
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlNode {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlNode,
+        crate::yaml::any::node::FormatAnyYamlNode,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(self, crate::yaml::any::node::FormatAnyYamlNode::default())
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlNode {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlNode,
+        crate::yaml::any::node::FormatAnyYamlNode,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(self, crate::yaml::any::node::FormatAnyYamlNode::default())
+    }
+}
+impl AsFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlProperty {
+    type Format<'a> = FormatRefWithRule<
+        'a,
+        biome_yaml_syntax::AnyYamlProperty,
+        crate::yaml::any::property::FormatAnyYamlProperty,
+    >;
+    fn format(&self) -> Self::Format<'_> {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatRefWithRule::new(
+            self,
+            crate::yaml::any::property::FormatAnyYamlProperty::default(),
+        )
+    }
+}
+impl IntoFormat<YamlFormatContext> for biome_yaml_syntax::AnyYamlProperty {
+    type Format = FormatOwnedWithRule<
+        biome_yaml_syntax::AnyYamlProperty,
+        crate::yaml::any::property::FormatAnyYamlProperty,
+    >;
+    fn into_format(self) -> Self::Format {
+        #![allow(clippy::default_constructed_unit_structs)]
+        FormatOwnedWithRule::new(
+            self,
+            crate::yaml::any::property::FormatAnyYamlProperty::default(),
+        )
+    }
+}
@@ -0,0 +1,24 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+use crate::prelude::*;
+use biome_yaml_syntax::AnyYamlNode;
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatAnyYamlNode;
+impl FormatRule<AnyYamlNode> for FormatAnyYamlNode {
+    type Context = YamlFormatContext;
+    fn fmt(&self, node: &AnyYamlNode, f: &mut YamlFormatter) -> FormatResult<()> {
+        match node {
+            AnyYamlNode::YamlAliasNode(node) => node.format().fmt(f),
+            AnyYamlNode::YamlBlockMapping(node) => node.format().fmt(f),
+            AnyYamlNode::YamlBlockScalar(node) => node.format().fmt(f),
+            AnyYamlNode::YamlBlockSequence(node) => node.format().fmt(f),
+            AnyYamlNode::YamlBogusValue(node) => node.format().fmt(f),
+            AnyYamlNode::YamlDoubleQuotedScalar(node) => node.format().fmt(f),
+            AnyYamlNode::YamlFlowMapping(node) => node.format().fmt(f),
+            AnyYamlNode::YamlFlowSequence(node) => node.format().fmt(f),
+            AnyYamlNode::YamlPlainScalar(node) => node.format().fmt(f),
+            AnyYamlNode::YamlPropertiesNode(node) => node.format().fmt(f),
+            AnyYamlNode::YamlSingleQuotedScalar(node) => node.format().fmt(f),
+        }
+    }
+}
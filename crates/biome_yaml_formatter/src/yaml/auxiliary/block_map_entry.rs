@@ -0,0 +1,38 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{AnyYamlNode, YamlBlockMapEntry, YamlBlockMapEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockMapEntry;
+
+impl FormatNodeRule<YamlBlockMapEntry> for FormatYamlBlockMapEntry {
+    fn fmt_fields(&self, node: &YamlBlockMapEntry, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockMapEntryFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format(), colon_token.format()])?;
+
+        match value {
+            None => Ok(()),
+            // The nested block collections start on the next line, indented under the key
+            Some(
+                value @ (AnyYamlNode::YamlBlockMapping(_) | AnyYamlNode::YamlBlockSequence(_)),
+            ) => {
+                write!(
+                    f,
+                    [indent(&format_args![hard_line_break(), value.format()])]
+                )
+            }
+            // The closing bracket of a flow collection is aligned with the key
+            Some(value @ (AnyYamlNode::YamlFlowMapping(_) | AnyYamlNode::YamlFlowSequence(_))) => {
+                write!(f, [space(), value.format()])
+            }
+            // The next lines of the other nodes, such as the content of a block scalar,
+            // must be more indented than the key
+            Some(value) => write!(f, [space(), indent(&value.format())]),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use crate::format_scalar::write_line_break;
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{TextLen, TextSize, YamlBlockScalar, YamlBlockScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockScalar;
+
+impl FormatNodeRule<YamlBlockScalar> for FormatYamlBlockScalar {
+    fn fmt_fields(&self, node: &YamlBlockScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockScalarFields { value_token } = node.as_fields();
+        let value_token = value_token?;
+
+        let text = value_token.text_trimmed();
+        let mut lines = text.split('\n');
+        // SAFETY: `split` returns at least one string
+        let header = lines.next().unwrap();
+
+        // The indentation indicator sets the indentation of the content relative to the parent node,
+        // the content is kept as is
+        let has_indentation_indicator = header[1..]
+            .chars()
+            .take_while(|c| matches!(c, '+' | '-' | '1'..='9'))
+            .any(|c| c.is_ascii_digit());
+        if has_indentation_indicator {
+            return write!(f, [value_token.format()]);
+        }
+
+        let content_indent = lines
+            .clone()
+            .map(|line| line.strip_suffix('\r').unwrap_or(line))
+            .find(|line| !line.trim_start_matches([' ', '\t']).is_empty())
+            .map_or(0, |line| line.len() - line.trim_start_matches(' ').len());
+
+        let content = format_with(|f| {
+            let start = value_token.text_trimmed_range().start();
+            write!(f, [dynamic_text(header.trim_end(), start)])?;
+
+            let mut line_start = start + header.text_len() + TextSize::from(1);
+            let mut empty_lines = 0;
+            for line in lines.clone() {
+                let start = line_start;
+                line_start += line.text_len() + TextSize::from(1);
+
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                // The spaces after the indentation of the content are part of the value
+                let indentation = line.len() - line.trim_start_matches(' ').len();
+                let (indentation, content) = line.split_at(indentation.min(content_indent));
+                if content.is_empty() {
+                    empty_lines += 1;
+                    continue;
+                }
+
+                write_line_break(f, empty_lines)?;
+                empty_lines = 0;
+
+                let position = start + indentation.text_len();
+                write!(f, [dynamic_text(content, position)])?;
+            }
+
+            Ok(())
+        });
+
+        write!(f, [format_replaced(&value_token, &content)])
+    }
+}
@@ -0,0 +1,22 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlBlockSequenceEntry, YamlBlockSequenceEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlBlockSequenceEntry;
+
+impl FormatNodeRule<YamlBlockSequenceEntry> for FormatYamlBlockSequenceEntry {
+    fn fmt_fields(&self, node: &YamlBlockSequenceEntry, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlBlockSequenceEntryFields { dash_token, value } = node.as_fields();
+
+        write!(f, [dash_token.format()])?;
+
+        if let Some(value) = value {
+            // The value starts on the line of the dash, even when it's a block collection,
+            // and its next lines are aligned with its first line
+            write!(f, [space(), align(2, &value.format())])?;
+        }
+
+        Ok(())
+    }
+}
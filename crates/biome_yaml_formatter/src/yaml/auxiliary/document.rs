@@ -0,0 +1,39 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlDocument, YamlDocumentFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDocument;
+
+impl FormatNodeRule<YamlDocument> for FormatYamlDocument {
+    fn fmt_fields(&self, node: &YamlDocument, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDocumentFields {
+            directives,
+            doc_start_token,
+            node,
+            doc_end_token,
+        } = node.as_fields();
+
+        write!(f, [directives.format()])?;
+        if !directives.is_empty() {
+            write!(f, [hard_line_break()])?;
+        }
+
+        if let Some(doc_start_token) = doc_start_token {
+            write!(f, [doc_start_token.format()])?;
+            // The node is printed on the next line, where it's indented like the other nodes
+            if node.is_some() || doc_end_token.is_some() {
+                write!(f, [hard_line_break()])?;
+            }
+        }
+
+        if let Some(node) = node {
+            write!(f, [node.format()])?;
+            if doc_end_token.is_some() {
+                write!(f, [hard_line_break()])?;
+            }
+        }
+
+        write!(f, [doc_end_token.format()])
+    }
+}
@@ -0,0 +1,14 @@
+use crate::format_scalar::format_flow_scalar;
+use crate::prelude::*;
+use biome_yaml_syntax::{YamlDoubleQuotedScalar, YamlDoubleQuotedScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlDoubleQuotedScalar;
+
+impl FormatNodeRule<YamlDoubleQuotedScalar> for FormatYamlDoubleQuotedScalar {
+    fn fmt_fields(&self, node: &YamlDoubleQuotedScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlDoubleQuotedScalarFields { value_token } = node.as_fields();
+
+        format_flow_scalar(&value_token?).fmt(f)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{YamlFlowMapEntry, YamlFlowMapEntryFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapEntry;
+
+impl FormatNodeRule<YamlFlowMapEntry> for FormatYamlFlowMapEntry {
+    fn fmt_fields(&self, node: &YamlFlowMapEntry, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowMapEntryFields {
+            key,
+            colon_token,
+            value,
+        } = node.as_fields();
+
+        write!(f, [key.format(), colon_token.format()])?;
+
+        if let Some(value) = value {
+            write!(f, [space(), value.format()])?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::prelude::*;
+use biome_formatter::{format_args, write};
+use biome_yaml_syntax::{YamlFlowMapping, YamlFlowMappingFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlFlowMapping;
+
+impl FormatNodeRule<YamlFlowMapping> for FormatYamlFlowMapping {
+    fn fmt_fields(&self, node: &YamlFlowMapping, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlFlowMappingFields {
+            l_curly_token,
+            entries,
+            r_curly_token,
+        } = node.as_fields();
+
+        let should_expand = entries.syntax().has_leading_newline()
+            || f.comments().has_dangling_comments(node.syntax());
+
+        write!(
+            f,
+            [
+                l_curly_token.format(),
+                group(&soft_space_or_block_indent(&format_args![
+                    entries.format(),
+                    format_dangling_comments(node.syntax())
+                ]))
+                .should_expand(should_expand),
+                line_suffix_boundary(),
+                r_curly_token.format()
+            ]
+        )
+    }
+
+    fn fmt_dangling_comments(
+        &self,
+        _: &YamlFlowMapping,
+        _: &mut YamlFormatter,
+    ) -> FormatResult<()> {
+        // Formatted as part of `fmt_fields`
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+//! This is a generated file. Don't modify it by hand! Run 'cargo codegen formatter' to re-generate the file.
+
+pub(crate) mod alias_node;
+pub(crate) mod anchor_property;
+pub(crate) mod block_map_entry;
+pub(crate) mod block_mapping;
+pub(crate) mod block_scalar;
+pub(crate) mod block_sequence;
+pub(crate) mod block_sequence_entry;
+pub(crate) mod directive;
+pub(crate) mod document;
+pub(crate) mod double_quoted_scalar;
+pub(crate) mod flow_map_entry;
+pub(crate) mod flow_mapping;
+pub(crate) mod flow_sequence;
+pub(crate) mod plain_scalar;
+pub(crate) mod properties_node;
+pub(crate) mod root;
+pub(crate) mod single_quoted_scalar;
+pub(crate) mod tag_property;
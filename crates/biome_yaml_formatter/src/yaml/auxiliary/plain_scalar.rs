@@ -0,0 +1,14 @@
+use crate::format_scalar::format_flow_scalar;
+use crate::prelude::*;
+use biome_yaml_syntax::{YamlPlainScalar, YamlPlainScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlPlainScalar;
+
+impl FormatNodeRule<YamlPlainScalar> for FormatYamlPlainScalar {
+    fn fmt_fields(&self, node: &YamlPlainScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlPlainScalarFields { value_token } = node.as_fields();
+
+        format_flow_scalar(&value_token?).fmt(f)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::prelude::*;
+use biome_formatter::write;
+use biome_yaml_syntax::{AnyYamlNode, YamlPropertiesNode, YamlPropertiesNodeFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlPropertiesNode;
+
+impl FormatNodeRule<YamlPropertiesNode> for FormatYamlPropertiesNode {
+    fn fmt_fields(&self, node: &YamlPropertiesNode, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlPropertiesNodeFields { properties, value } = node.as_fields();
+
+        write!(f, [properties.format()])?;
+
+        match value {
+            None => Ok(()),
+            // A block collection can't start on the line of its properties.
+            // The parent of the node is responsible for its indentation.
+            Some(
+                value @ (AnyYamlNode::YamlBlockMapping(_) | AnyYamlNode::YamlBlockSequence(_)),
+            ) => {
+                write!(f, [hard_line_break(), value.format()])
+            }
+            Some(value) => write!(f, [space(), value.format()]),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::format_scalar::format_flow_scalar;
+use crate::prelude::*;
+use biome_yaml_syntax::{YamlSingleQuotedScalar, YamlSingleQuotedScalarFields};
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct FormatYamlSingleQuotedScalar;
+
+impl FormatNodeRule<YamlSingleQuotedScalar> for FormatYamlSingleQuotedScalar {
+    fn fmt_fields(&self, node: &YamlSingleQuotedScalar, f: &mut YamlFormatter) -> FormatResult<()> {
+        let YamlSingleQuotedScalarFields { value_token } = node.as_fields();
+
+        format_flow_scalar(&value_token?).fmt(f)
+    }
+}
@@ -0,0 +1,52 @@
+use biome_formatter::{FormatResult, Formatted, Printed};
+use biome_formatter_test::TestFormatLanguage;
+use biome_parser::AnyParse;
+use biome_rowan::{SyntaxNode, TextRange};
+use biome_service::settings::{ServiceLanguage, Settings};
+use biome_yaml_formatter::context::{YamlFormatContext, YamlFormatOptions};
+use biome_yaml_formatter::{format_node, format_range, YamlFormatLanguage};
+use biome_yaml_parser::parse_yaml;
+use biome_yaml_syntax::YamlLanguage;
+
+#[derive(Default)]
+pub struct YamlTestFormatLanguage;
+
+impl TestFormatLanguage for YamlTestFormatLanguage {
+    type ServiceLanguage = YamlLanguage;
+    type Context = YamlFormatContext;
+    type FormatLanguage = YamlFormatLanguage;
+
+    fn parse(&self, text: &str) -> AnyParse {
+        let parse = parse_yaml(text);
+
+        AnyParse::new(parse.syntax().as_send().unwrap(), parse.into_diagnostics())
+    }
+
+    fn to_language_settings<'a>(
+        &self,
+        settings: &'a Settings,
+    ) -> &'a <Self::ServiceLanguage as ServiceLanguage>::FormatterSettings {
+        &settings.languages.yaml.formatter
+    }
+
+    fn format_node(
+        &self,
+        options: <Self::ServiceLanguage as ServiceLanguage>::FormatOptions,
+        node: &SyntaxNode<Self::ServiceLanguage>,
+    ) -> FormatResult<Formatted<Self::Context>> {
+        format_node(options, node)
+    }
+
+    fn format_range(
+        &self,
+        options: <Self::ServiceLanguage as ServiceLanguage>::FormatOptions,
+        node: &SyntaxNode<Self::ServiceLanguage>,
+        range: TextRange,
+    ) -> FormatResult<Printed> {
+        format_range(options, node, range)
+    }
+
+    fn default_options(&self) -> <Self::ServiceLanguage as ServiceLanguage>::FormatOptions {
+        YamlFormatOptions::default()
+    }
+}
@@ -1,11 +1,13 @@
-use biome_parser::{
-    diagnostic::ParseDiagnostic,
-    lexer::{LexContext, Lexer, TokenFlags},
-};
-use biome_rowan::{TextRange, TextSize};
-use biome_yaml_syntax::YamlSyntaxKind::*;
-pub use biome_yaml_syntax::*;
+//! An indentation-aware YAML lexer which yields SyntaxKind tokens used by the biome YAML parser.
+#[rustfmt::skip]
+mod tests;
 
+use biome_parser::diagnostic::ParseDiagnostic;
+use biome_parser::lexer::{LexContext, Lexer, LexerCheckpoint, LexerWithCheckpoint, TokenFlags};
+use biome_rowan::{TextLen, TextSize};
+use biome_yaml_syntax::{YamlSyntaxKind, YamlSyntaxKind::*, T};
+
+#[derive(Debug)]
 pub(crate) struct YamlLexer<'src> {
     /// Source text
     source: &'src str,
@@ -17,23 +19,34 @@ pub(crate) struct YamlLexer<'src> {
     after_newline: bool,
 
     /// If the source starts with a Unicode BOM, this is the number of bytes for that token.
-    #[allow(unused)]
     unicode_bom_length: usize,
 
     /// Byte offset of the current token from the start of the source
-    /// The range of the current token can be computed by `self.position - self.current_start`
+    /// The range of the current token can be computed by
+    /// `self.position - self.current_start`.
     current_start: TextSize,
 
     /// The kind of the current token
     current_kind: YamlSyntaxKind,
 
-    /// Byte offset of the current token from the start of the source
-    /// The range of the current token can be computed by
-    /// `self.position - self.current_start`.
-
     /// Flags for the current token
     current_flags: TokenFlags,
 
+    /// The columns of the block collections that contain the next token, from the outermost to the innermost.
+    /// The multiline plain scalars and the block scalars end at the first line
+    /// that isn't more indented than the innermost block collection.
+    indents: Vec<usize>,
+
+    /// The number of flow collections (`[...]` and `{...}`) that contain the next token.
+    flow_level: usize,
+
+    /// The column of the first token of the current line, after the `-` indicators of the block sequences.
+    /// A `:` uses it as the column of the block mapping that it starts.
+    content_column: usize,
+
+    /// The kind of the last non-trivia token
+    last_kind: YamlSyntaxKind,
+
     /// diagnostics emitted during the parsing phase
     diagnostics: Vec<ParseDiagnostic>,
 }
@@ -44,7 +57,7 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
 
     type Kind = YamlSyntaxKind;
     type LexContext = YamlLexContext;
-    type ReLexContext = YamlReLexContext;
+    type ReLexContext = ();
 
     fn source(&self) -> &'src str {
         self.source
@@ -54,23 +67,43 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
         self.current_kind
     }
 
-    fn current_range(&self) -> TextRange {
-        TextRange::new(self.current_start, TextSize::from(self.position as u32))
-    }
-
-    #[inline]
-    fn advance_char_unchecked(&mut self) {
-        let c = self.current_char_unchecked();
-        self.position += c.len_utf8();
-    }
-
-    #[inline]
     fn current_start(&self) -> TextSize {
         self.current_start
     }
 
     fn next_token(&mut self, _context: Self::LexContext) -> Self::Kind {
-        todo!()
+        self.current_start = self.text_position();
+        self.current_flags = TokenFlags::empty();
+
+        let kind = match self.current_byte() {
+            Some(current) => {
+                if self.after_newline && !matches!(current, b' ' | b'\t' | b'\n' | b'\r' | b'#') {
+                    self.unroll_indents();
+                }
+                self.consume_token(current)
+            }
+            None => EOF,
+        };
+
+        self.current_kind = kind;
+
+        if !kind.is_trivia() && !kind.is_comments() && kind != EOF {
+            if self.after_newline {
+                self.current_flags
+                    .set(TokenFlags::PRECEDING_LINE_BREAK, true);
+                self.content_column = self.column(self.current_start.into());
+            } else if self.last_kind == T![-] {
+                self.content_column = self.column(self.current_start.into());
+            }
+            self.after_newline = false;
+            self.update_indents(kind);
+            self.last_kind = kind;
+        } else if kind == EOF && self.after_newline {
+            self.current_flags
+                .set(TokenFlags::PRECEDING_LINE_BREAK, true);
+        }
+
+        kind
     }
 
     fn has_preceding_line_break(&self) -> bool {
@@ -81,8 +114,18 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
         self.current_flags.has_unicode_escape()
     }
 
-    fn rewind(&mut self, _checkpoint: biome_parser::lexer::LexerCheckpoint<Self::Kind>) {
-        todo!()
+    fn rewind(&mut self, checkpoint: LexerCheckpoint<Self::Kind>) {
+        // The checkpoint doesn't hold the indentation and the flow level, they're restored
+        // by lexing the source again until the position of the checkpoint.
+        let mut diagnostics = std::mem::take(&mut self.diagnostics);
+        diagnostics.truncate(checkpoint.diagnostics_pos as usize);
+        let position = u32::from(checkpoint.position) as usize;
+
+        *self = Self::from_str(self.source);
+        while self.position < position {
+            self.next_token(YamlLexContext::Regular);
+        }
+        self.diagnostics = diagnostics;
     }
 
     fn finish(self) -> Vec<ParseDiagnostic> {
@@ -101,25 +144,588 @@ impl<'src> Lexer<'src> for YamlLexer<'src> {
         self.position
     }
 
+    #[inline]
+    fn advance_char_unchecked(&mut self) {
+        let c = self.current_char_unchecked();
+        self.position += c.len_utf8();
+    }
+
+    #[inline]
     fn advance(&mut self, n: usize) {
         self.position += n;
     }
 
-    /// Consume one newline or all whitespace until a non-whitespace or a newline is found.
-    ///
-    /// ## Safety
-    /// Must be called at a valid UT8 char boundary
     fn consume_newline_or_whitespaces(&mut self) -> YamlSyntaxKind {
         if self.consume_newline() {
             self.after_newline = true;
             NEWLINE
         } else {
-            self.consume_whitespaces();
+            while matches!(self.current_byte(), Some(b' ' | b'\t')) {
+                self.advance(1);
+            }
             WHITESPACE
         }
     }
 }
 
+impl<'src> LexerWithCheckpoint<'src> for YamlLexer<'src> {
+    fn checkpoint(&self) -> LexerCheckpoint<Self::Kind> {
+        LexerCheckpoint {
+            position: TextSize::from(self.position as u32),
+            current_start: self.current_start,
+            current_flags: self.current_flags,
+            current_kind: self.current_kind,
+            after_line_break: self.after_newline,
+            unicode_bom_length: self.unicode_bom_length,
+            diagnostics_pos: self.diagnostics.len() as u32,
+        }
+    }
+}
+
+impl<'src> YamlLexer<'src> {
+    /// Make a new lexer from a str, this is safe because strs are valid utf8
+    pub fn from_str(source: &'src str) -> Self {
+        Self {
+            source,
+            position: 0,
+            after_newline: true,
+            unicode_bom_length: 0,
+            current_start: TextSize::from(0),
+            current_kind: TOMBSTONE,
+            current_flags: TokenFlags::empty(),
+            indents: Vec::new(),
+            flow_level: 0,
+            content_column: 0,
+            last_kind: TOMBSTONE,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Returns the column of the byte at `position`.
+    fn column(&self, position: usize) -> usize {
+        let line_start = self.source.as_bytes()[..position]
+            .iter()
+            .rposition(|byte| matches!(byte, b'\n' | b'\r'))
+            .map_or(0, |index| index + 1);
+        position - line_start
+    }
+
+    /// Returns `true` if a line indented by `column` is more indented than the innermost block collection.
+    fn is_more_indented(&self, column: usize) -> bool {
+        self.indents.last().map_or(true, |indent| column > *indent)
+    }
+
+    /// Closes the block collections that are more indented than the first token of a line.
+    fn unroll_indents(&mut self) {
+        if self.flow_level > 0 {
+            return;
+        }
+        let column = self.column(self.position);
+        while self.indents.last().is_some_and(|indent| *indent > column) {
+            self.indents.pop();
+        }
+    }
+
+    /// Opens the block collections started by `kind`.
+    fn update_indents(&mut self, kind: YamlSyntaxKind) {
+        match kind {
+            T![---] | T![...] => {
+                self.indents.clear();
+                self.flow_level = 0;
+            }
+            T![-] if self.flow_level == 0 => {
+                let column = self.column(self.current_start.into());
+                if self.is_more_indented(column) {
+                    self.indents.push(column);
+                }
+            }
+            T![:] if self.flow_level == 0 => {
+                if self.is_more_indented(self.content_column) {
+                    self.indents.push(self.content_column);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Bumps the current byte and creates a lexed token of the passed in kind
+    fn consume_byte(&mut self, tok: YamlSyntaxKind) -> YamlSyntaxKind {
+        self.advance(1);
+        tok
+    }
+
+    /// Returns `true` if the byte at `offset` ends the current token:
+    /// it's a whitespace, a line break, or the end of the file.
+    fn is_separator_at(&self, offset: usize) -> bool {
+        matches!(
+            self.byte_at(offset),
+            None | Some(b' ' | b'\t' | b'\n' | b'\r')
+        )
+    }
+
+    fn is_flow_indicator_at(&self, offset: usize) -> bool {
+        matches!(self.byte_at(offset), Some(b',' | b'[' | b']' | b'{' | b'}'))
+    }
+
+    /// Returns `true` if the lexer is at a document marker, `---` or `...`, at the start of a line.
+    fn is_at_document_marker(&self, marker: u8) -> bool {
+        self.column(self.position) == 0
+            && self.byte_at(0) == Some(marker)
+            && self.byte_at(1) == Some(marker)
+            && self.byte_at(2) == Some(marker)
+            && self.is_separator_at(3)
+    }
+
+    /// Lexes the next token
+    ///
+    /// Guaranteed to not be at the end of the file
+    fn consume_token(&mut self, current: u8) -> YamlSyntaxKind {
+        match current {
+            b'\n' | b'\r' | b'\t' | b' ' => self.consume_newline_or_whitespaces(),
+            b'#' => self.consume_comment(),
+            b'-' if self.is_at_document_marker(b'-') => {
+                self.advance(3);
+                T![---]
+            }
+            b'.' if self.is_at_document_marker(b'.') => {
+                self.advance(3);
+                T![...]
+            }
+            b'-' if self.is_separator_at(1) => self.consume_byte(T![-]),
+            b'?' if self.is_separator_at(1) => self.consume_byte(T![?]),
+            b':' if self.is_separator_at(1)
+                || (self.flow_level > 0
+                    && (self.is_flow_indicator_at(1)
+                        || matches!(
+                            self.last_kind,
+                            YAML_DOUBLE_QUOTED_LITERAL
+                                | YAML_SINGLE_QUOTED_LITERAL
+                                | T![']']
+                                | T!['}']
+                        ))) =>
+            {
+                self.consume_byte(T![:])
+            }
+            b',' => self.consume_byte(T![,]),
+            b'[' => {
+                self.flow_level += 1;
+                self.consume_byte(T!['['])
+            }
+            b'{' => {
+                self.flow_level += 1;
+                self.consume_byte(T!['{'])
+            }
+            b']' => {
+                self.flow_level = self.flow_level.saturating_sub(1);
+                self.consume_byte(T![']'])
+            }
+            b'}' => {
+                self.flow_level = self.flow_level.saturating_sub(1);
+                self.consume_byte(T!['}'])
+            }
+            b'"' => self.consume_double_quoted_scalar(),
+            b'\'' => self.consume_single_quoted_scalar(),
+            b'&' => self.consume_name(YAML_ANCHOR_LITERAL),
+            b'*' => self.consume_name(YAML_ALIAS_LITERAL),
+            b'!' => self.consume_tag(),
+            b'|' | b'>' if self.flow_level == 0 => self.consume_block_scalar(),
+            b'%' if self.column(self.position) == 0 => self.consume_directive(),
+            b'|' | b'>' | b'%' | b'@' | b'`' => self.consume_unexpected_indicator(),
+            _ if self.position == 0 => {
+                if let Some((bom, bom_size)) = self.consume_potential_bom(UNICODE_BOM) {
+                    self.unicode_bom_length = bom_size;
+                    return bom;
+                }
+                self.consume_plain_scalar()
+            }
+            _ => self.consume_plain_scalar(),
+        }
+    }
+
+    /// Lexes a comment, until the end of the line.
+    fn consume_comment(&mut self) -> YamlSyntaxKind {
+        self.assert_byte(b'#');
+        self.consume_until_line_break();
+        COMMENT
+    }
+
+    fn consume_until_line_break(&mut self) {
+        while let Some(byte) = self.current_byte() {
+            if matches!(byte, b'\n' | b'\r') {
+                break;
+            }
+            self.advance_byte_or_char(byte);
+        }
+    }
+
+    /// Lexes a directive, such as `%YAML 1.2`, until a comment or the end of the line.
+    fn consume_directive(&mut self) -> YamlSyntaxKind {
+        self.assert_byte(b'%');
+        let mut end = self.position;
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'\n' | b'\r' => break,
+                b' ' | b'\t' => {
+                    self.advance(1);
+                    if self.current_byte() == Some(b'#') {
+                        break;
+                    }
+                }
+                _ => {
+                    self.advance_byte_or_char(byte);
+                    end = self.position;
+                }
+            }
+        }
+        self.position = end;
+        YAML_DIRECTIVE_LITERAL
+    }
+
+    /// Lexes the name of an anchor (`&anchor`) or of an alias (`*anchor`).
+    fn consume_name(&mut self, kind: YamlSyntaxKind) -> YamlSyntaxKind {
+        let start = self.text_position();
+        self.advance(1);
+        let name_start = self.position;
+        while let Some(byte) = self.current_byte() {
+            if self.is_separator_at(0) || self.is_flow_indicator_at(0) {
+                break;
+            }
+            self.advance_byte_or_char(byte);
+        }
+        if self.position == name_start {
+            let (message, hint) = if kind == YAML_ANCHOR_LITERAL {
+                (
+                    "Missing the name of the anchor.",
+                    "Add a name after `&`, for example `&default`.",
+                )
+            } else {
+                (
+                    "Missing the name of the alias.",
+                    "Add the name of an anchor after `*`, for example `*default`.",
+                )
+            };
+            self.diagnostics
+                .push(ParseDiagnostic::new(message, start..self.text_position()).with_hint(hint));
+            return ERROR_TOKEN;
+        }
+        kind
+    }
+
+    /// Lexes a tag: `!`, `!local`, `!!str`, `!prefix!suffix` or `!<verbatim>`.
+    fn consume_tag(&mut self) -> YamlSyntaxKind {
+        let start = self.text_position();
+        self.assert_byte(b'!');
+        self.advance(1);
+        if self.current_byte() == Some(b'<') {
+            while let Some(byte) = self.current_byte() {
+                if matches!(byte, b'\n' | b'\r') {
+                    break;
+                }
+                self.advance_byte_or_char(byte);
+                if byte == b'>' {
+                    return YAML_TAG_LITERAL;
+                }
+            }
+            self.diagnostics.push(
+                ParseDiagnostic::new("Unterminated verbatim tag.", start..self.text_position())
+                    .with_hint("Add a `>` at the end of the tag."),
+            );
+            return ERROR_TOKEN;
+        }
+        while let Some(byte) = self.current_byte() {
+            if self.is_separator_at(0) || self.is_flow_indicator_at(0) {
+                break;
+            }
+            self.advance_byte_or_char(byte);
+        }
+        YAML_TAG_LITERAL
+    }
+
+    /// Lexes a double-quoted scalar, which can span several lines.
+    fn consume_double_quoted_scalar(&mut self) -> YamlSyntaxKind {
+        let start = self.text_position();
+        self.assert_byte(b'"');
+        self.advance(1);
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'"' => {
+                    self.advance(1);
+                    return YAML_DOUBLE_QUOTED_LITERAL;
+                }
+                b'\\' => self.consume_escape_sequence(),
+                _ => self.advance_byte_or_char(byte),
+            }
+        }
+        self.diagnostics.push(
+            ParseDiagnostic::new("Missing closing quote", start..self.text_position()).with_detail(
+                self.current_start..self.current_start + TextSize::from(1),
+                "The scalar starts here",
+            ),
+        );
+        ERROR_TOKEN
+    }
+
+    /// Lexes an escape sequence of a double-quoted scalar.
+    fn consume_escape_sequence(&mut self) {
+        let start = self.text_position();
+        self.assert_byte(b'\\');
+        self.advance(1);
+        let digits = match self.current_byte() {
+            Some(b'x') => 2,
+            Some(b'u') => 4,
+            Some(b'U') => 8,
+            Some(
+                b'0' | b'a' | b'b' | b't' | b'\t' | b'n' | b'v' | b'f' | b'r' | b'e' | b' ' | b'"'
+                | b'/' | b'\\' | b'N' | b'_' | b'L' | b'P',
+            ) => {
+                self.advance(1);
+                return;
+            }
+            // A line continuation
+            Some(b'\n' | b'\r') => {
+                self.consume_newline();
+                return;
+            }
+            Some(byte) => {
+                self.advance_byte_or_char(byte);
+                self.diagnostics.push(
+                    ParseDiagnostic::new("Invalid escape sequence", start..self.text_position())
+                        .with_hint("Valid escape sequences are: `\\\\`, `\\\"`, `\\/`, `\\0`, `\\a`, `\\b`, `\\t`, `\\n`, `\\v`, `\\f`, `\\r`, `\\e`, `\\ `, `\\N`, `\\_`, `\\L`, `\\P`, `\\xXX`, `\\uXXXX` and `\\UXXXXXXXX`."),
+                );
+                return;
+            }
+            None => return,
+        };
+        self.advance(1);
+        for _ in 0..digits {
+            if self
+                .current_byte()
+                .is_some_and(|byte| byte.is_ascii_hexdigit())
+            {
+                self.advance(1);
+            } else {
+                self.diagnostics.push(
+                    ParseDiagnostic::new("Invalid escape sequence", start..self.text_position())
+                        .with_hint(format!(
+                            "This escape sequence must be followed by {digits} hexadecimal digits."
+                        )),
+                );
+                return;
+            }
+        }
+    }
+
+    /// Lexes a single-quoted scalar, which can span several lines.
+    fn consume_single_quoted_scalar(&mut self) -> YamlSyntaxKind {
+        let start = self.text_position();
+        self.assert_byte(b'\'');
+        self.advance(1);
+        while let Some(byte) = self.current_byte() {
+            if byte == b'\'' {
+                self.advance(1);
+                // `''` is an escaped quote
+                if self.current_byte() != Some(b'\'') {
+                    return YAML_SINGLE_QUOTED_LITERAL;
+                }
+            }
+            self.advance_byte_or_char(byte);
+        }
+        self.diagnostics.push(
+            ParseDiagnostic::new("Missing closing quote", start..self.text_position()).with_detail(
+                self.current_start..self.current_start + TextSize::from(1),
+                "The scalar starts here",
+            ),
+        );
+        ERROR_TOKEN
+    }
+
+    /// Lexes a literal (`|`) or a folded (`>`) block scalar: its header and all its lines.
+    ///
+    /// The lines of the scalar must be more indented than the innermost block collection.
+    /// The trailing empty lines aren't part of the token.
+    fn consume_block_scalar(&mut self) -> YamlSyntaxKind {
+        let start = self.text_position();
+        self.advance(1);
+
+        let mut explicit_indent = None;
+        while let Some(byte) = self.current_byte() {
+            match byte {
+                b'+' | b'-' => self.advance(1),
+                b'1'..=b'9' => {
+                    explicit_indent = Some(usize::from(byte - b'0'));
+                    self.advance(1);
+                }
+                _ => break,
+            }
+        }
+        while matches!(self.current_byte(), Some(b' ' | b'\t')) {
+            self.advance(1);
+        }
+        if !matches!(self.current_byte(), None | Some(b'#' | b'\n' | b'\r')) {
+            let invalid_start = self.text_position();
+            self.consume_until_line_break();
+            self.diagnostics.push(
+                ParseDiagnostic::new(
+                    "Invalid block scalar header",
+                    invalid_start..self.text_position(),
+                )
+                .with_detail(start..start + TextSize::from(1), "The block scalar starts here")
+                .with_hint("The header of a block scalar can only contain the chomping indicator `-` or `+`, an indentation indicator and a comment."),
+            );
+        }
+        self.consume_until_line_break();
+
+        let min_indent = self.indents.last().map_or(0, |indent| indent + 1);
+        let mut content_indent =
+            explicit_indent.map(|indent| self.indents.last().map_or(0, |parent| *parent) + indent);
+        let bytes = self.source.as_bytes();
+        let mut end = self.position;
+        let mut line_start = self.position;
+        loop {
+            // Skip the line break
+            match bytes.get(line_start) {
+                Some(b'\r') if bytes.get(line_start + 1) == Some(&b'\n') => line_start += 2,
+                Some(b'\n' | b'\r') => line_start += 1,
+                _ => break,
+            }
+            let indent = bytes[line_start..]
+                .iter()
+                .take_while(|byte| **byte == b' ')
+                .count();
+            let line_end = bytes[line_start..]
+                .iter()
+                .position(|byte| matches!(byte, b'\n' | b'\r'))
+                .map_or(bytes.len(), |index| line_start + index);
+            let is_blank = bytes[line_start + indent..line_end]
+                .iter()
+                .all(|byte| matches!(byte, b' ' | b'\t'));
+            if !is_blank {
+                let is_document_marker = indent == 0
+                    && (bytes[line_start..].starts_with(b"---")
+                        || bytes[line_start..].starts_with(b"..."))
+                    && matches!(
+                        bytes.get(line_start + 3),
+                        None | Some(b' ' | b'\t' | b'\n' | b'\r')
+                    );
+                let content_indent = *content_indent.get_or_insert(indent);
+                if is_document_marker || indent < min_indent || indent < content_indent {
+                    break;
+                }
+                end = line_end;
+            }
+            line_start = line_end;
+        }
+        self.position = end;
+        YAML_BLOCK_SCALAR_LITERAL
+    }
+
+    /// Lexes a plain scalar. In a block collection, a plain scalar continues on the next lines
+    /// that are more indented than the collection.
+    fn consume_plain_scalar(&mut self) -> YamlSyntaxKind {
+        self.assert_current_char_boundary();
+        let mut end = self.position;
+        loop {
+            while let Some(byte) = self.current_byte() {
+                match byte {
+                    b'\n' | b'\r' => break,
+                    b':' if self.is_separator_at(1)
+                        || (self.flow_level > 0 && self.is_flow_indicator_at(1)) =>
+                    {
+                        break
+                    }
+                    b',' | b'[' | b']' | b'{' | b'}' if self.flow_level > 0 => break,
+                    b' ' | b'\t' => {
+                        self.advance(1);
+                        if self.current_byte() == Some(b'#') {
+                            break;
+                        }
+                    }
+                    _ => {
+                        self.advance_byte_or_char(byte);
+                        end = self.position;
+                    }
+                }
+            }
+            if !matches!(self.current_byte(), Some(b'\n' | b'\r')) {
+                break;
+            }
+            match self.plain_scalar_continuation() {
+                Some(position) => self.position = position,
+                None => break,
+            }
+        }
+        if end == usize::from(self.current_start) {
+            // Only whitespaces, which happens for an indicator that can't start a plain scalar
+            return self.consume_unexpected_indicator();
+        }
+        self.position = end;
+        YAML_PLAIN_LITERAL
+    }
+
+    /// Returns the position of the next line that continues a plain scalar,
+    /// after its indentation, when there is one.
+    fn plain_scalar_continuation(&self) -> Option<usize> {
+        let bytes = self.source.as_bytes();
+        let mut position = self.position;
+        loop {
+            match bytes.get(position) {
+                Some(b'\r') if bytes.get(position + 1) == Some(&b'\n') => position += 2,
+                Some(b'\n' | b'\r') => position += 1,
+                _ => return None,
+            }
+            let line_start = position;
+            while matches!(bytes.get(position), Some(b' ' | b'\t')) {
+                position += 1;
+            }
+            match bytes.get(position) {
+                // An empty line
+                Some(b'\n' | b'\r') => continue,
+                None | Some(b'#') => return None,
+                Some(_) => {
+                    let indent = position - line_start;
+                    let is_document_marker = indent == 0
+                        && (bytes[position..].starts_with(b"---")
+                            || bytes[position..].starts_with(b"..."))
+                        && matches!(
+                            bytes.get(position + 3),
+                            None | Some(b' ' | b'\t' | b'\n' | b'\r')
+                        );
+                    if is_document_marker {
+                        return None;
+                    }
+                    if self.flow_level == 0 && !self.is_more_indented(indent) {
+                        return None;
+                    }
+                    return Some(position);
+                }
+            }
+        }
+    }
+
+    /// Lexes an indicator that can't start a token here.
+    fn consume_unexpected_indicator(&mut self) -> YamlSyntaxKind {
+        self.position = self.current_start.into();
+        self.assert_current_char_boundary();
+
+        let char = self.current_char_unchecked();
+        let message = match char {
+            '@' | '`' => format!("The reserved indicator {char} can't start a plain scalar."),
+            '|' | '>' => format!("A block scalar (`{char}`) can't be used in a flow collection."),
+            '%' => "A directive must be placed at the start of a line.".to_string(),
+            _ => format!("Unexpected character `{char}`."),
+        };
+        let err = ParseDiagnostic::new(
+            message,
+            self.text_position()..self.text_position() + char.text_len(),
+        )
+        .with_hint("Quote the scalar if the character is part of its value.");
+        self.diagnostics.push(err);
+        self.advance(char.len_utf8());
+
+        ERROR_TOKEN
+    }
+}
+
 /// Context in which the lexer should lex the next token
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
 pub enum YamlLexContext {
@@ -133,7 +739,3 @@ impl LexContext for YamlLexContext {
         matches!(self, YamlLexContext::Regular)
     }
 }
-
-/// Context in which the [YamlLexContext]'s current yoken should be re-lexed.
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
-pub enum YamlReLexContext {}
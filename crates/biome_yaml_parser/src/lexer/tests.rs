@@ -0,0 +1,449 @@
+#![cfg(test)]
+#![allow(unused_mut, unused_variables, unused_assignments)]
+
+use super::{TextSize, YamlLexContext, YamlLexer};
+use biome_parser::lexer::Lexer;
+use biome_yaml_syntax::YamlSyntaxKind::{self, EOF};
+use quickcheck_macros::quickcheck;
+use std::sync::mpsc::channel;
+use std::thread;
+use std::time::Duration;
+
+// Assert the result of lexing a piece of source code,
+// and make sure the tokens yielded are fully lossless and the source can be reconstructed from only the tokens
+macro_rules! assert_lex {
+    ($src:expr, $($kind:ident:$len:expr $(,)?)*) => {{
+        let mut lexer = YamlLexer::from_str($src);
+        let mut idx = 0;
+        let mut tok_idx = TextSize::default();
+
+        let mut new_str = String::with_capacity($src.len());
+        let mut tokens = vec![];
+
+        while lexer.next_token(YamlLexContext::default()) != EOF {
+            tokens.push((lexer.current(), lexer.current_range()));
+        }
+
+        $(
+            assert_eq!(
+                tokens[idx].0,
+                YamlSyntaxKind::$kind,
+                "expected token kind {}, but found {:?}",
+                stringify!($kind),
+                tokens[idx].0,
+            );
+
+            assert_eq!(
+                tokens[idx].1.len(),
+                TextSize::from($len),
+                "expected token length of {}, but found {:?} for token {:?}",
+                $len,
+                tokens[idx].1.len(),
+                tokens[idx].0,
+            );
+
+            new_str.push_str(&$src[tokens[idx].1]);
+            tok_idx += tokens[idx].1.len();
+
+            idx += 1;
+        )*
+
+        if idx < tokens.len() {
+            panic!(
+                "expected {} tokens but lexer returned {}, first unexpected token is '{:?}'",
+                idx,
+                tokens.len(),
+                tokens[idx].0
+            );
+        } else {
+            assert_eq!(idx, tokens.len());
+        }
+
+        assert_eq!($src, new_str, "Failed to reconstruct input");
+    }};
+}
+
+// This is for testing if the lexer is truly lossless
+// It parses random strings and puts them back together with the produced tokens and compares
+#[quickcheck]
+fn losslessness(string: String) -> bool {
+    // using an mpsc channel allows us to spawn a thread and spawn the lexer there, then if
+    // it takes more than 2 seconds we panic because it is 100% infinite recursion
+    let cloned = string.clone();
+    let (sender, receiver) = channel();
+    thread::spawn(move || {
+        let mut lexer = YamlLexer::from_str(&cloned);
+        let mut tokens = vec![];
+
+        while lexer.next_token(YamlLexContext::default()) != EOF {
+            tokens.push(lexer.current_range());
+        }
+
+        sender
+            .send(tokens)
+            .expect("Could not send tokens to receiver");
+    });
+    let token_ranges = receiver
+        .recv_timeout(Duration::from_secs(2))
+        .unwrap_or_else(|_| {
+            panic!(
+                "Lexer is infinitely recursing with this code: ->{}<-",
+                string
+            )
+        });
+
+    let mut new_str = String::with_capacity(string.len());
+    let mut idx = TextSize::from(0);
+
+    for range in token_ranges {
+        new_str.push_str(&string[range]);
+        idx += range.len();
+    }
+
+    string == new_str
+}
+
+#[test]
+fn empty() {
+    assert_lex! {
+        "",
+    }
+}
+
+#[test]
+fn plain_scalar() {
+    assert_lex! {
+        "value",
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "multiple words",
+        YAML_PLAIN_LITERAL:14
+    }
+
+    assert_lex! {
+        "key: value",
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "http://example.com",
+        YAML_PLAIN_LITERAL:18
+    }
+
+    assert_lex! {
+        "a:b",
+        YAML_PLAIN_LITERAL:3
+    }
+
+    assert_lex! {
+        "-not-a-dash",
+        YAML_PLAIN_LITERAL:11
+    }
+
+    assert_lex! {
+        "trailing spaces   ",
+        YAML_PLAIN_LITERAL:15,
+        WHITESPACE:3
+    }
+}
+
+#[test]
+fn plain_scalar_multiline() {
+    assert_lex! {
+        "key: first\n  second\nnext: value",
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:14,
+        NEWLINE:1,
+        YAML_PLAIN_LITERAL:4,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "- first\n  second\n- third",
+        DASH:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:14,
+        NEWLINE:1,
+        DASH:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5
+    }
+}
+
+#[test]
+fn quoted_scalars() {
+    assert_lex! {
+        r#""double""#,
+        YAML_DOUBLE_QUOTED_LITERAL:8
+    }
+
+    assert_lex! {
+        "'single'",
+        YAML_SINGLE_QUOTED_LITERAL:8
+    }
+
+    assert_lex! {
+        "'it''s'",
+        YAML_SINGLE_QUOTED_LITERAL:7
+    }
+
+    assert_lex! {
+        r#""escaped \" quote""#,
+        YAML_DOUBLE_QUOTED_LITERAL:18
+    }
+
+    assert_lex! {
+        r#""unterminated"#,
+        ERROR_TOKEN:13
+    }
+
+    assert_lex! {
+        r#""invalid \q escape""#,
+        YAML_DOUBLE_QUOTED_LITERAL:19
+    }
+
+    assert_lex! {
+        "\"multi\n  line\"",
+        YAML_DOUBLE_QUOTED_LITERAL:14
+    }
+}
+
+#[test]
+fn indicators() {
+    assert_lex! {
+        "- item",
+        DASH:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:4
+    }
+
+    assert_lex! {
+        "? key",
+        QUESTION:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:3
+    }
+
+    assert_lex! {
+        "[a, b]",
+        L_BRACK:1,
+        YAML_PLAIN_LITERAL:1,
+        COMMA:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:1,
+        R_BRACK:1
+    }
+
+    assert_lex! {
+        "{a: b}",
+        L_CURLY:1,
+        YAML_PLAIN_LITERAL:1,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:1,
+        R_CURLY:1
+    }
+
+    assert_lex! {
+        r#"{"a":b}"#,
+        L_CURLY:1,
+        YAML_DOUBLE_QUOTED_LITERAL:3,
+        COLON:1,
+        YAML_PLAIN_LITERAL:1,
+        R_CURLY:1
+    }
+
+    assert_lex! {
+        "key:\n  - item",
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        NEWLINE:1,
+        WHITESPACE:2,
+        DASH:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:4
+    }
+}
+
+#[test]
+fn document_markers() {
+    assert_lex! {
+        "---\nkey: value\n...",
+        DOC_START:3,
+        NEWLINE:1,
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5,
+        NEWLINE:1,
+        DOC_END:3
+    }
+
+    assert_lex! {
+        "--- value",
+        DOC_START:3,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "----",
+        YAML_PLAIN_LITERAL:4
+    }
+
+    assert_lex! {
+        "%YAML 1.2\n---",
+        YAML_DIRECTIVE_LITERAL:9,
+        NEWLINE:1,
+        DOC_START:3
+    }
+}
+
+#[test]
+fn comments() {
+    assert_lex! {
+        "# comment",
+        COMMENT:9
+    }
+
+    assert_lex! {
+        "key: value # comment",
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5,
+        WHITESPACE:1,
+        COMMENT:9
+    }
+
+    assert_lex! {
+        "key: value#not a comment",
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:19
+    }
+}
+
+#[test]
+fn anchors_aliases_tags() {
+    assert_lex! {
+        "&anchor value",
+        YAML_ANCHOR_LITERAL:7,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "*alias",
+        YAML_ALIAS_LITERAL:6
+    }
+
+    assert_lex! {
+        "!!str value",
+        YAML_TAG_LITERAL:5,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "!<tag:yaml.org,2002:str> value",
+        YAML_TAG_LITERAL:24,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "[&a b, *a]",
+        L_BRACK:1,
+        YAML_ANCHOR_LITERAL:2,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:1,
+        COMMA:1,
+        WHITESPACE:1,
+        YAML_ALIAS_LITERAL:2,
+        R_BRACK:1
+    }
+
+    assert_lex! {
+        "&",
+        ERROR_TOKEN:1
+    }
+}
+
+#[test]
+fn block_scalars() {
+    assert_lex! {
+        "key: |\n  line\n  other\nnext: value",
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_BLOCK_SCALAR_LITERAL:16,
+        NEWLINE:1,
+        YAML_PLAIN_LITERAL:4,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "key: >-\n  folded\n\n  text\n",
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_BLOCK_SCALAR_LITERAL:19,
+        NEWLINE:1
+    }
+
+    assert_lex! {
+        "- |2\n   indented\n- next",
+        DASH:1,
+        WHITESPACE:1,
+        YAML_BLOCK_SCALAR_LITERAL:14,
+        NEWLINE:1,
+        DASH:1,
+        WHITESPACE:1,
+        YAML_PLAIN_LITERAL:4
+    }
+
+    assert_lex! {
+        "key: |\n  text\n---",
+        YAML_PLAIN_LITERAL:3,
+        COLON:1,
+        WHITESPACE:1,
+        YAML_BLOCK_SCALAR_LITERAL:8,
+        NEWLINE:1,
+        DOC_START:3
+    }
+}
+
+#[test]
+fn reserved_indicators() {
+    assert_lex! {
+        "@value",
+        ERROR_TOKEN:1,
+        YAML_PLAIN_LITERAL:5
+    }
+
+    assert_lex! {
+        "`value`",
+        ERROR_TOKEN:1,
+        YAML_PLAIN_LITERAL:6
+    }
+
+    assert_lex! {
+        "[|]",
+        L_BRACK:1,
+        ERROR_TOKEN:1,
+        R_BRACK:1
+    }
+}
@@ -0,0 +1,232 @@
+use crate::parser::flow::{parse_flow_mapping, parse_flow_sequence};
+use crate::parser::parse_error::{
+    expected_block_mapping_entry, expected_line_end, multiline_implicit_key,
+    unexpected_block_sequence_entry, unsupported_explicit_key,
+};
+use crate::parser::{
+    bump_until_line_end, is_at_document_end, is_at_line_start, parse_property_list, parse_scalar,
+    YamlParser,
+};
+use biome_parser::prelude::ParsedSyntax::*;
+use biome_parser::prelude::*;
+use biome_yaml_syntax::{TextRange, YamlSyntaxKind::*, T};
+
+/// Parses a node in a block context: a block collection, or a node which is on one line.
+///
+/// `parent` is the column of the block collection that contains the node,
+/// and `None` for the node of a document.
+pub(crate) fn parse_block_node(p: &mut YamlParser, parent: Option<usize>) -> ParsedSyntax {
+    if p.at(T![-]) {
+        return Present(parse_block_sequence(p));
+    }
+
+    let column = p.column();
+    if p.at(T![?]) {
+        let entry = parse_explicit_key_entry(p, column);
+        return Present(parse_block_mapping(p, entry, column));
+    }
+
+    let node = parse_inline_node(p, parent);
+    match node {
+        Present(key) if p.at(T![:]) && !p.has_preceding_line_break() => {
+            let entry = parse_block_map_entry_value(p, key, column);
+            Present(parse_block_mapping(p, entry, column))
+        }
+        node => parse_trailing_content(p, node),
+    }
+}
+
+/// Parses a node that starts on the current line: a scalar, an alias, a flow collection,
+/// or a node with properties.
+fn parse_inline_node(p: &mut YamlParser, parent: Option<usize>) -> ParsedSyntax {
+    match p.cur() {
+        T!['['] => Present(parse_flow_sequence(p)),
+        T!['{'] => Present(parse_flow_mapping(p)),
+        YAML_ANCHOR_LITERAL | YAML_TAG_LITERAL => Present(parse_properties_node(p, parent)),
+        _ => parse_scalar(p),
+    }
+}
+
+/// Parses the properties of a node and the node.
+///
+/// When the properties end the line, the node is on the next lines.
+fn parse_properties_node(p: &mut YamlParser, parent: Option<usize>) -> CompletedMarker {
+    let m = p.start();
+    parse_property_list(p);
+    if !p.has_preceding_line_break() {
+        parse_inline_node(p, parent).ok();
+    } else if !is_at_document_end(p) && is_more_indented(p.column(), parent) {
+        parse_block_node(p, parent).ok();
+    }
+    m.complete(p, YAML_PROPERTIES_NODE)
+}
+
+/// Parses a block mapping whose first entry has already been parsed.
+///
+/// ```yaml
+/// key: value
+/// other: value
+/// ```
+fn parse_block_mapping(
+    p: &mut YamlParser,
+    first_entry: CompletedMarker,
+    column: usize,
+) -> CompletedMarker {
+    let entries = first_entry.precede(p);
+    while !is_at_document_end(p) {
+        let entry_column = p.column();
+        if entry_column < column {
+            break;
+        }
+        if entry_column > column || p.at(T![-]) {
+            let bogus = parse_bogus_line(p);
+            p.error(expected_block_mapping_entry(p, bogus.range(p)));
+            bogus.precede(p).complete(p, YAML_BLOCK_MAP_ENTRY);
+            continue;
+        }
+        parse_block_map_entry(p, column);
+    }
+    let entries = entries.complete(p, YAML_BLOCK_MAP_ENTRY_LIST);
+    entries.precede(p).complete(p, YAML_BLOCK_MAPPING)
+}
+
+/// Parses an entry of a block mapping, at the start of a line.
+fn parse_block_map_entry(p: &mut YamlParser, column: usize) -> CompletedMarker {
+    if p.at(T![?]) {
+        return parse_explicit_key_entry(p, column);
+    }
+
+    match parse_inline_node(p, Some(column)) {
+        Present(key) if p.at(T![:]) && !p.has_preceding_line_break() => {
+            parse_block_map_entry_value(p, key, column)
+        }
+        key => {
+            let bogus = match key {
+                Present(key) => key.precede(p),
+                Absent => {
+                    let m = p.start();
+                    p.bump_any();
+                    m
+                }
+            };
+            while !is_at_line_start(p) {
+                p.bump_any();
+            }
+            let bogus = bogus.complete(p, YAML_BOGUS_VALUE);
+            p.error(expected_block_mapping_entry(p, bogus.range(p)));
+            bogus.precede(p).complete(p, YAML_BLOCK_MAP_ENTRY)
+        }
+    }
+}
+
+/// Parses an entry with an explicit key, which isn't supported, as a bogus entry.
+///
+/// ```yaml
+/// ? key
+/// : value
+/// ```
+fn parse_explicit_key_entry(p: &mut YamlParser, column: usize) -> CompletedMarker {
+    let bogus = p.start();
+    bump_until_line_end(p);
+    if !is_at_document_end(p) && p.at(T![:]) && p.column() == column {
+        bump_until_line_end(p);
+    }
+    let bogus = bogus.complete(p, YAML_BOGUS_VALUE);
+    p.error(unsupported_explicit_key(p, bogus.range(p)));
+    bogus.precede(p).complete(p, YAML_BLOCK_MAP_ENTRY)
+}
+
+/// Parses the `:` and the value of an entry of a block mapping.
+fn parse_block_map_entry_value(
+    p: &mut YamlParser,
+    key: CompletedMarker,
+    column: usize,
+) -> CompletedMarker {
+    let key_range = key.range(p);
+    if p.source().text()[key_range].contains(['\n', '\r']) {
+        p.error(multiline_implicit_key(p, key_range));
+    }
+
+    let entry = key.precede(p);
+    p.bump(T![:]);
+
+    if is_at_document_end(p) {
+        // An empty value
+    } else if !p.has_preceding_line_break() {
+        let value = parse_inline_node(p, Some(column));
+        parse_trailing_content(p, value).ok();
+    } else {
+        let value_column = p.column();
+        if value_column > column || (value_column == column && p.at(T![-])) {
+            // The sequence of a value can be at the same indentation as the key
+            parse_block_node(p, Some(column)).ok();
+        }
+    }
+
+    entry.complete(p, YAML_BLOCK_MAP_ENTRY)
+}
+
+/// Parses a block sequence.
+///
+/// ```yaml
+/// - first
+/// - second
+/// ```
+fn parse_block_sequence(p: &mut YamlParser) -> CompletedMarker {
+    let column = p.column();
+    let m = p.start();
+    let entries = p.start();
+    loop {
+        let entry = p.start();
+        p.bump(T![-]);
+        if is_at_document_end(p) {
+            // An empty entry
+        } else if !p.has_preceding_line_break() || p.column() > column {
+            parse_block_node(p, Some(column)).ok();
+        }
+        entry.complete(p, YAML_BLOCK_SEQUENCE_ENTRY);
+
+        // Skips the lines that aren't entries of the sequence
+        while !is_at_document_end(p) && p.column() > column {
+            let bogus = parse_bogus_line(p);
+            p.error(unexpected_block_sequence_entry(p, bogus.range(p)));
+            bogus.precede(p).complete(p, YAML_BLOCK_SEQUENCE_ENTRY);
+        }
+
+        if is_at_document_end(p) || p.column() < column || !p.at(T![-]) {
+            break;
+        }
+    }
+    entries.complete(p, YAML_BLOCK_SEQUENCE_ENTRY_LIST);
+    m.complete(p, YAML_BLOCK_SEQUENCE)
+}
+
+/// Parses the content after a node on the same line, which isn't valid in a block context.
+fn parse_trailing_content(p: &mut YamlParser, node: ParsedSyntax) -> ParsedSyntax {
+    if is_at_line_start(p) {
+        return node;
+    }
+
+    let bogus = match node {
+        Present(node) => node.precede(p),
+        Absent => p.start(),
+    };
+    let start = p.cur_range().start();
+    let is_nested_mapping = p.at(T![:]);
+    bump_until_line_end(p);
+    let range = TextRange::new(start, p.last_end().unwrap_or(start));
+    p.error(expected_line_end(p, range, is_nested_mapping));
+    Present(bogus.complete(p, YAML_BOGUS_VALUE))
+}
+
+/// Parses a line which can't be parsed in its block collection.
+fn parse_bogus_line(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    bump_until_line_end(p);
+    m.complete(p, YAML_BOGUS_VALUE)
+}
+
+/// Returns `true` if a node at `column` is inside the collection at `parent`.
+fn is_more_indented(column: usize, parent: Option<usize>) -> bool {
+    parent.map_or(true, |parent| column > parent)
+}
@@ -0,0 +1,157 @@
+use crate::parser::parse_error::{
+    expected_flow_mapping_entry, expected_flow_sequence_entry, flow_sequence_mapping_entry,
+};
+use crate::parser::{is_at_document_end, parse_property_list, parse_scalar, YamlParser};
+use biome_parser::parse_lists::ParseSeparatedList;
+use biome_parser::parse_recovery::{ParseRecoveryTokenSet, RecoveryResult};
+use biome_parser::prelude::ParsedSyntax::*;
+use biome_parser::prelude::*;
+use biome_yaml_syntax::{YamlSyntaxKind, YamlSyntaxKind::*, T};
+
+const FLOW_RECOVERY_SET: TokenSet<YamlSyntaxKind> =
+    token_set![T![,], T![']'], T!['}'], T![---], T![...]];
+
+/// Parses a flow sequence.
+///
+/// ```yaml
+/// [first, second]
+/// ```
+pub(crate) fn parse_flow_sequence(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['[']);
+    FlowSequenceEntryList.parse_list(p);
+    p.expect(T![']']);
+    m.complete(p, YAML_FLOW_SEQUENCE)
+}
+
+/// Parses a flow mapping.
+///
+/// ```yaml
+/// { key: value, other: value }
+/// ```
+pub(crate) fn parse_flow_mapping(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    p.bump(T!['{']);
+    FlowMapEntryList.parse_list(p);
+    p.expect(T!['}']);
+    m.complete(p, YAML_FLOW_MAPPING)
+}
+
+/// Parses a node in a flow collection, where the line breaks and the indentation don't matter.
+fn parse_flow_node(p: &mut YamlParser) -> ParsedSyntax {
+    match p.cur() {
+        T!['['] => Present(parse_flow_sequence(p)),
+        T!['{'] => Present(parse_flow_mapping(p)),
+        YAML_ANCHOR_LITERAL | YAML_TAG_LITERAL => {
+            let m = p.start();
+            parse_property_list(p);
+            parse_flow_node(p).ok();
+            Present(m.complete(p, YAML_PROPERTIES_NODE))
+        }
+        _ => parse_scalar(p),
+    }
+}
+
+struct FlowSequenceEntryList;
+
+impl ParseSeparatedList for FlowSequenceEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_SEQUENCE_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        let node = parse_flow_node(p);
+        if !p.at(T![:]) {
+            return node;
+        }
+
+        // A single pair mapping such as `[key: value]`
+        let m = match node {
+            Present(node) => node.precede(p),
+            Absent => p.start(),
+        };
+        p.bump(T![:]);
+        parse_flow_node(p).ok();
+        let bogus = m.complete(p, YAML_BOGUS_VALUE);
+        p.error(flow_sequence_mapping_entry(p, bogus.range(p)));
+        Present(bogus)
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T![']']) || is_at_document_end(p)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS_VALUE, FLOW_RECOVERY_SET),
+            expected_flow_sequence_entry,
+        )
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
+
+struct FlowMapEntryList;
+
+impl ParseSeparatedList for FlowMapEntryList {
+    type Kind = YamlSyntaxKind;
+    type Parser<'source> = YamlParser<'source>;
+
+    const LIST_KIND: Self::Kind = YAML_FLOW_MAP_ENTRY_LIST;
+
+    fn parse_element(&mut self, p: &mut Self::Parser<'_>) -> ParsedSyntax {
+        let m = p.start();
+        let key = parse_flow_node(p);
+        if key.is_absent() && !p.at(T![:]) {
+            m.abandon(p);
+            return Absent;
+        }
+        if p.eat(T![:]) {
+            parse_flow_node(p).ok();
+        }
+        Present(m.complete(p, YAML_FLOW_MAP_ENTRY))
+    }
+
+    fn is_at_list_end(&self, p: &mut Self::Parser<'_>) -> bool {
+        p.at(T!['}']) || is_at_document_end(p)
+    }
+
+    fn recover(
+        &mut self,
+        p: &mut Self::Parser<'_>,
+        parsed_element: ParsedSyntax,
+    ) -> RecoveryResult {
+        let is_absent = parsed_element.is_absent();
+        let entry = parsed_element.or_recover_with_token_set(
+            p,
+            &ParseRecoveryTokenSet::new(YAML_BOGUS_VALUE, FLOW_RECOVERY_SET),
+            expected_flow_mapping_entry,
+        )?;
+        if is_absent {
+            // The skipped tokens are the key of an entry
+            Ok(entry.precede(p).complete(p, YAML_FLOW_MAP_ENTRY))
+        } else {
+            Ok(entry)
+        }
+    }
+
+    fn separating_element_kind(&mut self) -> Self::Kind {
+        T![,]
+    }
+
+    fn allow_trailing_separating_element(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,188 @@
+mod block;
+mod flow;
+mod parse_error;
+
+use crate::token_source::YamlTokenSource;
+use biome_parser::diagnostic::merge_diagnostics;
+use biome_parser::event::Event;
+use biome_parser::prelude::{ParsedSyntax::*, *};
+use biome_parser::token_source::Trivia;
+use biome_parser::ParserContext;
+use biome_yaml_syntax::{
+    TextRange,
+    YamlSyntaxKind::{self, *},
+    T,
+};
+use block::parse_block_node;
+use parse_error::expected_document_end;
+
+pub(crate) struct YamlParser<'source> {
+    context: ParserContext<YamlSyntaxKind>,
+    source: YamlTokenSource<'source>,
+}
+
+impl<'source> YamlParser<'source> {
+    pub fn new(source: &'source str) -> Self {
+        Self {
+            context: ParserContext::default(),
+            source: YamlTokenSource::from_str(source),
+        }
+    }
+
+    /// Returns the column of the current token: the number of characters between the start of its line and the token.
+    pub fn column(&self) -> usize {
+        let text = self.source.text();
+        let start = usize::from(self.cur_range().start());
+        text[..start]
+            .rfind(['\n', '\r'])
+            .map_or(start, |line_break| start - line_break - 1)
+    }
+
+    pub fn finish(
+        self,
+    ) -> (
+        Vec<Event<YamlSyntaxKind>>,
+        Vec<ParseDiagnostic>,
+        Vec<Trivia>,
+    ) {
+        let (trivia, lexer_diagnostics) = self.source.finish();
+        let (events, parse_diagnostics) = self.context.finish();
+
+        let diagnostics = merge_diagnostics(lexer_diagnostics, parse_diagnostics);
+
+        (events, diagnostics, trivia)
+    }
+}
+
+impl<'source> Parser for YamlParser<'source> {
+    type Kind = YamlSyntaxKind;
+    type Source = YamlTokenSource<'source>;
+
+    fn context(&self) -> &ParserContext<Self::Kind> {
+        &self.context
+    }
+
+    fn context_mut(&mut self) -> &mut ParserContext<Self::Kind> {
+        &mut self.context
+    }
+
+    fn source(&self) -> &Self::Source {
+        &self.source
+    }
+
+    fn source_mut(&mut self) -> &mut Self::Source {
+        &mut self.source
+    }
+}
+
+pub(crate) fn parse_root(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+
+    p.eat(UNICODE_BOM);
+
+    let documents = p.start();
+    while !p.at(EOF) {
+        parse_document(p);
+    }
+    documents.complete(p, YAML_DOCUMENT_LIST);
+
+    p.expect(EOF);
+
+    m.complete(p, YAML_ROOT)
+}
+
+/// Parses a document: its directives, the optional markers `---` and `...`, and its node.
+fn parse_document(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+
+    let directives = p.start();
+    let mut has_directives = false;
+    while p.at(YAML_DIRECTIVE_LITERAL) {
+        let directive = p.start();
+        p.bump(YAML_DIRECTIVE_LITERAL);
+        directive.complete(p, YAML_DIRECTIVE);
+        has_directives = true;
+    }
+    directives.complete(p, YAML_DIRECTIVE_LIST);
+
+    if has_directives {
+        p.expect(T![---]);
+    } else {
+        p.eat(T![---]);
+    }
+
+    if !is_at_document_end(p) {
+        let node = parse_block_node(p, None);
+        if !is_at_document_end(p) {
+            // The node ended before the content of the document, for example
+            // a block sequence followed by a mapping entry at the same indentation.
+            let bogus = match node {
+                Present(node) => node.precede(p),
+                Absent => p.start(),
+            };
+            let start = p.cur_range().start();
+            while !is_at_document_end(p) {
+                if parse_block_node(p, None).is_absent() {
+                    p.bump_any();
+                }
+            }
+            let range = TextRange::new(start, p.last_end().unwrap_or(start));
+            p.error(expected_document_end(p, range));
+            bogus.complete(p, YAML_BOGUS_VALUE);
+        }
+    }
+
+    p.eat(T![...]);
+
+    m.complete(p, YAML_DOCUMENT)
+}
+
+/// Returns `true` if the parser is at the end of the current document:
+/// a document marker, a directive of the next document, or the end of the file.
+fn is_at_document_end(p: &mut YamlParser) -> bool {
+    p.at(EOF) || p.at(T![---]) || p.at(T![...]) || p.at(YAML_DIRECTIVE_LITERAL)
+}
+
+/// Returns `true` if the current token is at the start of a line, or at the end of the file.
+fn is_at_line_start(p: &mut YamlParser) -> bool {
+    p.at(EOF) || p.has_preceding_line_break()
+}
+
+/// Parses a scalar or an alias.
+fn parse_scalar(p: &mut YamlParser) -> ParsedSyntax {
+    let kind = match p.cur() {
+        YAML_PLAIN_LITERAL => YAML_PLAIN_SCALAR,
+        YAML_DOUBLE_QUOTED_LITERAL => YAML_DOUBLE_QUOTED_SCALAR,
+        YAML_SINGLE_QUOTED_LITERAL => YAML_SINGLE_QUOTED_SCALAR,
+        YAML_BLOCK_SCALAR_LITERAL => YAML_BLOCK_SCALAR,
+        YAML_ALIAS_LITERAL => YAML_ALIAS_NODE,
+        _ => return Absent,
+    };
+    let m = p.start();
+    p.bump_any();
+    Present(m.complete(p, kind))
+}
+
+/// Parses the anchors and the tags of a node.
+fn parse_property_list(p: &mut YamlParser) -> CompletedMarker {
+    let m = p.start();
+    loop {
+        let kind = match p.cur() {
+            YAML_ANCHOR_LITERAL => YAML_ANCHOR_PROPERTY,
+            YAML_TAG_LITERAL => YAML_TAG_PROPERTY,
+            _ => break,
+        };
+        let property = p.start();
+        p.bump_any();
+        property.complete(p, kind);
+    }
+    m.complete(p, YAML_PROPERTY_LIST)
+}
+
+/// Bumps the tokens until the end of the line.
+fn bump_until_line_end(p: &mut YamlParser) {
+    p.bump_any();
+    while !is_at_line_start(p) {
+        p.bump_any();
+    }
+}
@@ -0,0 +1,59 @@
+use crate::parser::YamlParser;
+use biome_parser::diagnostic::{expected_node, ParseDiagnostic};
+use biome_parser::Parser;
+use biome_rowan::TextRange;
+
+pub(crate) fn expected_flow_sequence_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("sequence entry", range, p)
+}
+
+pub(crate) fn expected_flow_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    expected_node("mapping entry", range, p)
+}
+
+pub(crate) fn expected_block_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("Expected a mapping entry", range)
+        .with_hint("A mapping entry is a key followed by `:` and its value, and it must be indented like the other keys of the mapping.")
+}
+
+pub(crate) fn unexpected_block_sequence_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("Unexpected content in the sequence", range)
+        .with_hint("Every entry of a sequence starts with `- `, indented like the other entries.")
+}
+
+pub(crate) fn unsupported_explicit_key(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("Explicit keys aren't supported", range)
+        .with_hint("Use an implicit key instead: `key: value`.")
+}
+
+pub(crate) fn multiline_implicit_key(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("Implicit keys must be on a single line", range)
+        .with_hint("Check the indentation of the previous line, or put the key on a single line.")
+}
+
+pub(crate) fn expected_line_end(
+    p: &YamlParser,
+    range: TextRange,
+    is_nested_mapping: bool,
+) -> ParseDiagnostic {
+    let diagnostic = p.err_builder("Expected the end of the line", range);
+    if is_nested_mapping {
+        diagnostic
+            .with_hint("A nested mapping must start on the next line, more indented than its key.")
+    } else {
+        diagnostic.with_hint("Quote the value if this content is part of it.")
+    }
+}
+
+pub(crate) fn flow_sequence_mapping_entry(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder(
+        "Mapping entries must be wrapped in curly braces in a flow sequence",
+        range,
+    )
+    .with_hint("Wrap the entry in curly braces: `[{ key: value }]`.")
+}
+
+pub(crate) fn expected_document_end(p: &YamlParser, range: TextRange) -> ParseDiagnostic {
+    p.err_builder("Expected the end of the document", range)
+        .with_hint("A document contains a single node. Check the indentation of this content, or start a new document with `---`.")
+}
@@ -1,7 +1,7 @@
 use biome_rowan::FileSourceError;
 use std::{ffi::OsStr, path::Path};
 
-#[cfg_attr(feature = "schema", derive(schemars::YamlSchema))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(
     Debug, Clone, Default, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize,
 )]
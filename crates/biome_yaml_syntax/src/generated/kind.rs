@@ -20,30 +20,47 @@ pub enum YamlSyntaxKind {
     L_BRACK,
     R_BRACK,
     DASH,
-    PERCENT,
-    STAR,
-    HASH,
-    BANG,
-    AT,
-    SHL,
-    AMP,
-    PIPE,
-    R_ANGLE,
-    TILDE,
-    BACKTICK,
+    QUESTION,
     DOC_START,
     DOC_END,
     NULL_KW,
-    YAML_STRING_LITERAL,
-    YAML_SCALAR,
+    YAML_PLAIN_LITERAL,
+    YAML_DOUBLE_QUOTED_LITERAL,
+    YAML_SINGLE_QUOTED_LITERAL,
+    YAML_BLOCK_SCALAR_LITERAL,
+    YAML_ANCHOR_LITERAL,
+    YAML_ALIAS_LITERAL,
+    YAML_TAG_LITERAL,
+    YAML_DIRECTIVE_LITERAL,
+    ERROR_TOKEN,
     NEWLINE,
     WHITESPACE,
-    IDENT,
     COMMENT,
     YAML_ROOT,
     YAML_DOCUMENT_LIST,
     YAML_DOCUMENT,
-    YAML_CONTENT_LIST,
+    YAML_DIRECTIVE_LIST,
+    YAML_DIRECTIVE,
+    YAML_BLOCK_MAPPING,
+    YAML_BLOCK_MAP_ENTRY_LIST,
+    YAML_BLOCK_MAP_ENTRY,
+    YAML_BLOCK_SEQUENCE,
+    YAML_BLOCK_SEQUENCE_ENTRY_LIST,
+    YAML_BLOCK_SEQUENCE_ENTRY,
+    YAML_FLOW_MAPPING,
+    YAML_FLOW_MAP_ENTRY_LIST,
+    YAML_FLOW_MAP_ENTRY,
+    YAML_FLOW_SEQUENCE,
+    YAML_FLOW_SEQUENCE_ENTRY_LIST,
+    YAML_PLAIN_SCALAR,
+    YAML_DOUBLE_QUOTED_SCALAR,
+    YAML_SINGLE_QUOTED_SCALAR,
+    YAML_BLOCK_SCALAR,
+    YAML_ALIAS_NODE,
+    YAML_PROPERTIES_NODE,
+    YAML_PROPERTY_LIST,
+    YAML_ANCHOR_PROPERTY,
+    YAML_TAG_PROPERTY,
     YAML_BOGUS,
     YAML_BOGUS_VALUE,
     #[doc(hidden)]
@@ -53,21 +70,33 @@ use self::YamlSyntaxKind::*;
 impl YamlSyntaxKind {
     pub const fn is_punct(self) -> bool {
         match self {
-            COLON | COMMA | L_CURLY | R_CURLY | L_BRACK | R_BRACK | DASH | PERCENT | STAR
-            | HASH | BANG | AT | SHL | AMP | PIPE | R_ANGLE | TILDE | BACKTICK | DOC_START
+            COLON | COMMA | L_CURLY | R_CURLY | L_BRACK | R_BRACK | DASH | QUESTION | DOC_START
             | DOC_END => true,
             _ => false,
         }
     }
     pub const fn is_literal(self) -> bool {
         match self {
-            YAML_STRING_LITERAL | YAML_SCALAR => true,
+            YAML_PLAIN_LITERAL
+            | YAML_DOUBLE_QUOTED_LITERAL
+            | YAML_SINGLE_QUOTED_LITERAL
+            | YAML_BLOCK_SCALAR_LITERAL
+            | YAML_ANCHOR_LITERAL
+            | YAML_ALIAS_LITERAL
+            | YAML_TAG_LITERAL
+            | YAML_DIRECTIVE_LITERAL => true,
             _ => false,
         }
     }
     pub const fn is_list(self) -> bool {
         match self {
-            YAML_DOCUMENT_LIST | YAML_CONTENT_LIST => true,
+            YAML_DOCUMENT_LIST
+            | YAML_DIRECTIVE_LIST
+            | YAML_BLOCK_MAP_ENTRY_LIST
+            | YAML_BLOCK_SEQUENCE_ENTRY_LIST
+            | YAML_FLOW_MAP_ENTRY_LIST
+            | YAML_FLOW_SEQUENCE_ENTRY_LIST
+            | YAML_PROPERTY_LIST => true,
             _ => false,
         }
     }
@@ -87,21 +116,11 @@ impl YamlSyntaxKind {
             L_BRACK => "[",
             R_BRACK => "]",
             DASH => "-",
-            PERCENT => "%",
-            STAR => "*",
-            HASH => "#",
-            BANG => "!",
-            AT => "@",
-            SHL => "<<",
-            AMP => "&",
-            PIPE => "|",
-            R_ANGLE => ">",
-            TILDE => "~",
-            BACKTICK => "`",
+            QUESTION => "?",
             DOC_START => "---",
             DOC_END => "...",
             NULL_KW => "null",
-            YAML_STRING_LITERAL => "string literal",
+            YAML_DOUBLE_QUOTED_LITERAL | YAML_SINGLE_QUOTED_LITERAL => "string literal",
             _ => return None,
         };
         Some(tok)
@@ -109,4 +128,4 @@ impl YamlSyntaxKind {
 }
 #[doc = r" Utility macro for creating a SyntaxKind through simple macro syntax"]
 #[macro_export]
-macro_rules ! T { [:] => { $ crate :: YamlSyntaxKind :: COLON } ; [,] => { $ crate :: YamlSyntaxKind :: COMMA } ; ['{'] => { $ crate :: YamlSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: YamlSyntaxKind :: R_CURLY } ; ['['] => { $ crate :: YamlSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: YamlSyntaxKind :: R_BRACK } ; [-] => { $ crate :: YamlSyntaxKind :: DASH } ; [%] => { $ crate :: YamlSyntaxKind :: PERCENT } ; [*] => { $ crate :: YamlSyntaxKind :: STAR } ; [#] => { $ crate :: YamlSyntaxKind :: HASH } ; [!] => { $ crate :: YamlSyntaxKind :: BANG } ; [@] => { $ crate :: YamlSyntaxKind :: AT } ; [<<] => { $ crate :: YamlSyntaxKind :: SHL } ; [&] => { $ crate :: YamlSyntaxKind :: AMP } ; [|] => { $ crate :: YamlSyntaxKind :: PIPE } ; [>] => { $ crate :: YamlSyntaxKind :: R_ANGLE } ; [~] => { $ crate :: YamlSyntaxKind :: TILDE } ; ['`'] => { $ crate :: YamlSyntaxKind :: BACKTICK } ; [---] => { $ crate :: YamlSyntaxKind :: DOC_START } ; [...] => { $ crate :: YamlSyntaxKind :: DOC_END } ; [null] => { $ crate :: YamlSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: YamlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: YamlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: YamlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: YamlSyntaxKind :: HASH } ; }
+macro_rules ! T { [:] => { $ crate :: YamlSyntaxKind :: COLON } ; [,] => { $ crate :: YamlSyntaxKind :: COMMA } ; ['{'] => { $ crate :: YamlSyntaxKind :: L_CURLY } ; ['}'] => { $ crate :: YamlSyntaxKind :: R_CURLY } ; ['['] => { $ crate :: YamlSyntaxKind :: L_BRACK } ; [']'] => { $ crate :: YamlSyntaxKind :: R_BRACK } ; [-] => { $ crate :: YamlSyntaxKind :: DASH } ; [?] => { $ crate :: YamlSyntaxKind :: QUESTION } ; [---] => { $ crate :: YamlSyntaxKind :: DOC_START } ; [...] => { $ crate :: YamlSyntaxKind :: DOC_END } ; [null] => { $ crate :: YamlSyntaxKind :: NULL_KW } ; [ident] => { $ crate :: YamlSyntaxKind :: IDENT } ; [EOF] => { $ crate :: YamlSyntaxKind :: EOF } ; [UNICODE_BOM] => { $ crate :: YamlSyntaxKind :: UNICODE_BOM } ; [#] => { $ crate :: YamlSyntaxKind :: HASH } ; }
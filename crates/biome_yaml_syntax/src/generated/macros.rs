@@ -16,20 +16,76 @@ macro_rules! map_syntax_node {
     ($ node : expr , $ pattern : pat => $ body : expr) => {
         match $node {
             node => match $crate::YamlSyntaxNode::kind(&node) {
+                $crate::YamlSyntaxKind::YAML_ALIAS_NODE => {
+                    let $pattern = unsafe { $crate::YamlAliasNode::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_ANCHOR_PROPERTY => {
+                    let $pattern = unsafe { $crate::YamlAnchorProperty::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_BLOCK_MAP_ENTRY => {
+                    let $pattern = unsafe { $crate::YamlBlockMapEntry::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_BLOCK_MAPPING => {
+                    let $pattern = unsafe { $crate::YamlBlockMapping::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_BLOCK_SCALAR => {
+                    let $pattern = unsafe { $crate::YamlBlockScalar::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_BLOCK_SEQUENCE => {
+                    let $pattern = unsafe { $crate::YamlBlockSequence::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY => {
+                    let $pattern = unsafe { $crate::YamlBlockSequenceEntry::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_DIRECTIVE => {
+                    let $pattern = unsafe { $crate::YamlDirective::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_DOCUMENT => {
                     let $pattern = unsafe { $crate::YamlDocument::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_DOUBLE_QUOTED_SCALAR => {
+                    let $pattern = unsafe { $crate::YamlDoubleQuotedScalar::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_FLOW_MAP_ENTRY => {
+                    let $pattern = unsafe { $crate::YamlFlowMapEntry::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_FLOW_MAPPING => {
+                    let $pattern = unsafe { $crate::YamlFlowMapping::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_FLOW_SEQUENCE => {
+                    let $pattern = unsafe { $crate::YamlFlowSequence::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_PLAIN_SCALAR => {
+                    let $pattern = unsafe { $crate::YamlPlainScalar::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_PROPERTIES_NODE => {
+                    let $pattern = unsafe { $crate::YamlPropertiesNode::new_unchecked(node) };
+                    $body
+                }
                 $crate::YamlSyntaxKind::YAML_ROOT => {
                     let $pattern = unsafe { $crate::YamlRoot::new_unchecked(node) };
                     $body
                 }
-                $crate::YamlSyntaxKind::YAML_SCALAR => {
-                    let $pattern = unsafe { $crate::YamlScalar::new_unchecked(node) };
+                $crate::YamlSyntaxKind::YAML_SINGLE_QUOTED_SCALAR => {
+                    let $pattern = unsafe { $crate::YamlSingleQuotedScalar::new_unchecked(node) };
                     $body
                 }
-                $crate::YamlSyntaxKind::YAML_STRING_LITERAL => {
-                    let $pattern = unsafe { $crate::YamlStringLiteral::new_unchecked(node) };
+                $crate::YamlSyntaxKind::YAML_TAG_PROPERTY => {
+                    let $pattern = unsafe { $crate::YamlTagProperty::new_unchecked(node) };
                     $body
                 }
                 $crate::YamlSyntaxKind::YAML_BOGUS => {
@@ -40,14 +96,36 @@ macro_rules! map_syntax_node {
                     let $pattern = unsafe { $crate::YamlBogusValue::new_unchecked(node) };
                     $body
                 }
-                $crate::YamlSyntaxKind::YAML_CONTENT_LIST => {
-                    let $pattern = unsafe { $crate::YamlContentList::new_unchecked(node) };
+                $crate::YamlSyntaxKind::YAML_BLOCK_MAP_ENTRY_LIST => {
+                    let $pattern = unsafe { $crate::YamlBlockMapEntryList::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_BLOCK_SEQUENCE_ENTRY_LIST => {
+                    let $pattern =
+                        unsafe { $crate::YamlBlockSequenceEntryList::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_DIRECTIVE_LIST => {
+                    let $pattern = unsafe { $crate::YamlDirectiveList::new_unchecked(node) };
                     $body
                 }
                 $crate::YamlSyntaxKind::YAML_DOCUMENT_LIST => {
                     let $pattern = unsafe { $crate::YamlDocumentList::new_unchecked(node) };
                     $body
                 }
+                $crate::YamlSyntaxKind::YAML_FLOW_MAP_ENTRY_LIST => {
+                    let $pattern = unsafe { $crate::YamlFlowMapEntryList::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_FLOW_SEQUENCE_ENTRY_LIST => {
+                    let $pattern =
+                        unsafe { $crate::YamlFlowSequenceEntryList::new_unchecked(node) };
+                    $body
+                }
+                $crate::YamlSyntaxKind::YAML_PROPERTY_LIST => {
+                    let $pattern = unsafe { $crate::YamlPropertyList::new_unchecked(node) };
+                    $body
+                }
                 _ => unreachable!(),
             },
         }
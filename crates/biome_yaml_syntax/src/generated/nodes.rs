@@ -25,10 +25,10 @@ use std::fmt::{Debug, Formatter};
 #[allow(dead_code)]
 pub(crate) const SLOT_MAP_EMPTY_VALUE: u8 = u8::MAX;
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct YamlDocument {
+pub struct YamlAliasNode {
     pub(crate) syntax: SyntaxNode,
 }
-impl YamlDocument {
+impl YamlAliasNode {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -38,17 +38,17 @@ impl YamlDocument {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> YamlDocumentFields {
-        YamlDocumentFields {
-            content: self.content(),
+    pub fn as_fields(&self) -> YamlAliasNodeFields {
+        YamlAliasNodeFields {
+            value_token: self.value_token(),
         }
     }
-    pub fn content(&self) -> YamlContentList {
-        support::list(&self.syntax, 0usize)
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for YamlDocument {
+impl Serialize for YamlAliasNode {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -57,14 +57,14 @@ impl Serialize for YamlDocument {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct YamlDocumentFields {
-    pub content: YamlContentList,
+pub struct YamlAliasNodeFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct YamlRoot {
+pub struct YamlAnchorProperty {
     pub(crate) syntax: SyntaxNode,
 }
-impl YamlRoot {
+impl YamlAnchorProperty {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -74,25 +74,61 @@ impl YamlRoot {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> YamlRootFields {
-        YamlRootFields {
-            bom_token: self.bom_token(),
-            documents: self.documents(),
-            eof_token: self.eof_token(),
+    pub fn as_fields(&self) -> YamlAnchorPropertyFields {
+        YamlAnchorPropertyFields {
+            value_token: self.value_token(),
         }
     }
-    pub fn bom_token(&self) -> Option<SyntaxToken> {
-        support::token(&self.syntax, 0usize)
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    pub fn documents(&self) -> YamlDocumentList {
-        support::list(&self.syntax, 1usize)
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlAnchorProperty {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 2usize)
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlAnchorPropertyFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlBlockMapEntry {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlBlockMapEntry {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlBlockMapEntryFields {
+        YamlBlockMapEntryFields {
+            key: self.key(),
+            colon_token: self.colon_token(),
+            value: self.value(),
+        }
+    }
+    pub fn key(&self) -> SyntaxResult<AnyYamlNode> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn colon_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> Option<AnyYamlNode> {
+        support::node(&self.syntax, 2usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for YamlRoot {
+impl Serialize for YamlBlockMapEntry {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -101,16 +137,16 @@ impl Serialize for YamlRoot {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct YamlRootFields {
-    pub bom_token: Option<SyntaxToken>,
-    pub documents: YamlDocumentList,
-    pub eof_token: SyntaxResult<SyntaxToken>,
+pub struct YamlBlockMapEntryFields {
+    pub key: SyntaxResult<AnyYamlNode>,
+    pub colon_token: SyntaxResult<SyntaxToken>,
+    pub value: Option<AnyYamlNode>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct YamlScalar {
+pub struct YamlBlockMapping {
     pub(crate) syntax: SyntaxNode,
 }
-impl YamlScalar {
+impl YamlBlockMapping {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -120,17 +156,17 @@ impl YamlScalar {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> YamlScalarFields {
-        YamlScalarFields {
-            value_token: self.value_token(),
+    pub fn as_fields(&self) -> YamlBlockMappingFields {
+        YamlBlockMappingFields {
+            entries: self.entries(),
         }
     }
-    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
-        support::required_token(&self.syntax, 0usize)
+    pub fn entries(&self) -> YamlBlockMapEntryList {
+        support::list(&self.syntax, 0usize)
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for YamlScalar {
+impl Serialize for YamlBlockMapping {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -139,14 +175,14 @@ impl Serialize for YamlScalar {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct YamlScalarFields {
-    pub value_token: SyntaxResult<SyntaxToken>,
+pub struct YamlBlockMappingFields {
+    pub entries: YamlBlockMapEntryList,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-pub struct YamlStringLiteral {
+pub struct YamlBlockScalar {
     pub(crate) syntax: SyntaxNode,
 }
-impl YamlStringLiteral {
+impl YamlBlockScalar {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -156,8 +192,8 @@ impl YamlStringLiteral {
     pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
         Self { syntax }
     }
-    pub fn as_fields(&self) -> YamlStringLiteralFields {
-        YamlStringLiteralFields {
+    pub fn as_fields(&self) -> YamlBlockScalarFields {
+        YamlBlockScalarFields {
             value_token: self.value_token(),
         }
     }
@@ -166,7 +202,7 @@ impl YamlStringLiteral {
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for YamlStringLiteral {
+impl Serialize for YamlBlockScalar {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -175,159 +211,1760 @@ impl Serialize for YamlStringLiteral {
     }
 }
 #[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct YamlStringLiteralFields {
+pub struct YamlBlockScalarFields {
     pub value_token: SyntaxResult<SyntaxToken>,
 }
 #[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub enum AnyYamlContent {
-    YamlBogusValue(YamlBogusValue),
-    YamlScalar(YamlScalar),
+pub struct YamlBlockSequence {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AnyYamlContent {
-    pub fn as_yaml_bogus_value(&self) -> Option<&YamlBogusValue> {
-        match &self {
-            AnyYamlContent::YamlBogusValue(item) => Some(item),
-            _ => None,
-        }
+impl YamlBlockSequence {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    pub fn as_yaml_scalar(&self) -> Option<&YamlScalar> {
-        match &self {
-            AnyYamlContent::YamlScalar(item) => Some(item),
-            _ => None,
+    pub fn as_fields(&self) -> YamlBlockSequenceFields {
+        YamlBlockSequenceFields {
+            entries: self.entries(),
         }
     }
+    pub fn entries(&self) -> YamlBlockSequenceEntryList {
+        support::list(&self.syntax, 0usize)
+    }
 }
-impl AstNode for YamlDocument {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DOCUMENT as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == YAML_DOCUMENT
+#[cfg(feature = "serde")]
+impl Serialize for YamlBlockSequence {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlBlockSequenceFields {
+    pub entries: YamlBlockSequenceEntryList,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlBlockSequenceEntry {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlBlockSequenceEntry {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlBlockSequenceEntryFields {
+        YamlBlockSequenceEntryFields {
+            dash_token: self.dash_token(),
+            value: self.value(),
         }
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    pub fn dash_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    pub fn value(&self) -> Option<AnyYamlNode> {
+        support::node(&self.syntax, 1usize)
     }
 }
-impl std::fmt::Debug for YamlDocument {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("YamlDocument")
-            .field("content", &self.content())
-            .finish()
+#[cfg(feature = "serde")]
+impl Serialize for YamlBlockSequenceEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-impl From<YamlDocument> for SyntaxNode {
-    fn from(n: YamlDocument) -> SyntaxNode {
-        n.syntax
-    }
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlBlockSequenceEntryFields {
+    pub dash_token: SyntaxResult<SyntaxToken>,
+    pub value: Option<AnyYamlNode>,
 }
-impl From<YamlDocument> for SyntaxElement {
-    fn from(n: YamlDocument) -> SyntaxElement {
-        n.syntax.into()
-    }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlDirective {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for YamlRoot {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_ROOT as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == YAML_ROOT
+impl YamlDirective {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
+    pub fn as_fields(&self) -> YamlDirectiveFields {
+        YamlDirectiveFields {
+            value_token: self.value_token(),
         }
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
-    }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
     }
 }
-impl std::fmt::Debug for YamlRoot {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("YamlRoot")
-            .field(
-                "bom_token",
-                &support::DebugOptionalElement(self.bom_token()),
-            )
-            .field("documents", &self.documents())
-            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
-            .finish()
+#[cfg(feature = "serde")]
+impl Serialize for YamlDirective {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-impl From<YamlRoot> for SyntaxNode {
-    fn from(n: YamlRoot) -> SyntaxNode {
-        n.syntax
-    }
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlDirectiveFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
 }
-impl From<YamlRoot> for SyntaxElement {
-    fn from(n: YamlRoot) -> SyntaxElement {
-        n.syntax.into()
-    }
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlDocument {
+    pub(crate) syntax: SyntaxNode,
 }
-impl AstNode for YamlScalar {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_SCALAR as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == YAML_SCALAR
+impl YamlDocument {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
-        } else {
-            None
+    pub fn as_fields(&self) -> YamlDocumentFields {
+        YamlDocumentFields {
+            directives: self.directives(),
+            doc_start_token: self.doc_start_token(),
+            node: self.node(),
+            doc_end_token: self.doc_end_token(),
         }
     }
-    fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+    pub fn directives(&self) -> YamlDirectiveList {
+        support::list(&self.syntax, 0usize)
     }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    pub fn doc_start_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
     }
-}
-impl std::fmt::Debug for YamlScalar {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("YamlScalar")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
-            .finish()
+    pub fn node(&self) -> Option<AnyYamlNode> {
+        support::node(&self.syntax, 2usize)
     }
-}
-impl From<YamlScalar> for SyntaxNode {
-    fn from(n: YamlScalar) -> SyntaxNode {
-        n.syntax
+    pub fn doc_end_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 3usize)
     }
 }
-impl From<YamlScalar> for SyntaxElement {
-    fn from(n: YamlScalar) -> SyntaxElement {
-        n.syntax.into()
+#[cfg(feature = "serde")]
+impl Serialize for YamlDocument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
     }
 }
-impl AstNode for YamlStringLiteral {
-    type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_STRING_LITERAL as u16));
-    fn can_cast(kind: SyntaxKind) -> bool {
-        kind == YAML_STRING_LITERAL
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlDocumentFields {
+    pub directives: YamlDirectiveList,
+    pub doc_start_token: Option<SyntaxToken>,
+    pub node: Option<AnyYamlNode>,
+    pub doc_end_token: Option<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlDoubleQuotedScalar {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlDoubleQuotedScalar {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    pub fn as_fields(&self) -> YamlDoubleQuotedScalarFields {
+        YamlDoubleQuotedScalarFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlDoubleQuotedScalar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlDoubleQuotedScalarFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlFlowMapEntry {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlFlowMapEntry {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlFlowMapEntryFields {
+        YamlFlowMapEntryFields {
+            key: self.key(),
+            colon_token: self.colon_token(),
+            value: self.value(),
+        }
+    }
+    pub fn key(&self) -> SyntaxResult<AnyYamlNode> {
+        support::required_node(&self.syntax, 0usize)
+    }
+    pub fn colon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 1usize)
+    }
+    pub fn value(&self) -> Option<AnyYamlNode> {
+        support::node(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlFlowMapEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlFlowMapEntryFields {
+    pub key: SyntaxResult<AnyYamlNode>,
+    pub colon_token: Option<SyntaxToken>,
+    pub value: Option<AnyYamlNode>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlFlowMapping {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlFlowMapping {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlFlowMappingFields {
+        YamlFlowMappingFields {
+            l_curly_token: self.l_curly_token(),
+            entries: self.entries(),
+            r_curly_token: self.r_curly_token(),
+        }
+    }
+    pub fn l_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn entries(&self) -> YamlFlowMapEntryList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_curly_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlFlowMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlFlowMappingFields {
+    pub l_curly_token: SyntaxResult<SyntaxToken>,
+    pub entries: YamlFlowMapEntryList,
+    pub r_curly_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlFlowSequence {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlFlowSequence {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlFlowSequenceFields {
+        YamlFlowSequenceFields {
+            l_brack_token: self.l_brack_token(),
+            entries: self.entries(),
+            r_brack_token: self.r_brack_token(),
+        }
+    }
+    pub fn l_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+    pub fn entries(&self) -> YamlFlowSequenceEntryList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn r_brack_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlFlowSequence {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlFlowSequenceFields {
+    pub l_brack_token: SyntaxResult<SyntaxToken>,
+    pub entries: YamlFlowSequenceEntryList,
+    pub r_brack_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlPlainScalar {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlPlainScalar {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlPlainScalarFields {
+        YamlPlainScalarFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlPlainScalar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlPlainScalarFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlPropertiesNode {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlPropertiesNode {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlPropertiesNodeFields {
+        YamlPropertiesNodeFields {
+            properties: self.properties(),
+            value: self.value(),
+        }
+    }
+    pub fn properties(&self) -> YamlPropertyList {
+        support::list(&self.syntax, 0usize)
+    }
+    pub fn value(&self) -> Option<AnyYamlNode> {
+        support::node(&self.syntax, 1usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlPropertiesNode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlPropertiesNodeFields {
+    pub properties: YamlPropertyList,
+    pub value: Option<AnyYamlNode>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlRoot {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlRoot {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlRootFields {
+        YamlRootFields {
+            bom_token: self.bom_token(),
+            documents: self.documents(),
+            eof_token: self.eof_token(),
+        }
+    }
+    pub fn bom_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, 0usize)
+    }
+    pub fn documents(&self) -> YamlDocumentList {
+        support::list(&self.syntax, 1usize)
+    }
+    pub fn eof_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 2usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlRoot {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlRootFields {
+    pub bom_token: Option<SyntaxToken>,
+    pub documents: YamlDocumentList,
+    pub eof_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlSingleQuotedScalar {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlSingleQuotedScalar {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlSingleQuotedScalarFields {
+        YamlSingleQuotedScalarFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlSingleQuotedScalar {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlSingleQuotedScalarFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct YamlTagProperty {
+    pub(crate) syntax: SyntaxNode,
+}
+impl YamlTagProperty {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn as_fields(&self) -> YamlTagPropertyFields {
+        YamlTagPropertyFields {
+            value_token: self.value_token(),
+        }
+    }
+    pub fn value_token(&self) -> SyntaxResult<SyntaxToken> {
+        support::required_token(&self.syntax, 0usize)
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlTagProperty {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.as_fields().serialize(serializer)
+    }
+}
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlTagPropertyFields {
+    pub value_token: SyntaxResult<SyntaxToken>,
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyYamlNode {
+    YamlAliasNode(YamlAliasNode),
+    YamlBlockMapping(YamlBlockMapping),
+    YamlBlockScalar(YamlBlockScalar),
+    YamlBlockSequence(YamlBlockSequence),
+    YamlBogusValue(YamlBogusValue),
+    YamlDoubleQuotedScalar(YamlDoubleQuotedScalar),
+    YamlFlowMapping(YamlFlowMapping),
+    YamlFlowSequence(YamlFlowSequence),
+    YamlPlainScalar(YamlPlainScalar),
+    YamlPropertiesNode(YamlPropertiesNode),
+    YamlSingleQuotedScalar(YamlSingleQuotedScalar),
+}
+impl AnyYamlNode {
+    pub fn as_yaml_alias_node(&self) -> Option<&YamlAliasNode> {
+        match &self {
+            AnyYamlNode::YamlAliasNode(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_block_mapping(&self) -> Option<&YamlBlockMapping> {
+        match &self {
+            AnyYamlNode::YamlBlockMapping(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_block_scalar(&self) -> Option<&YamlBlockScalar> {
+        match &self {
+            AnyYamlNode::YamlBlockScalar(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_block_sequence(&self) -> Option<&YamlBlockSequence> {
+        match &self {
+            AnyYamlNode::YamlBlockSequence(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_bogus_value(&self) -> Option<&YamlBogusValue> {
+        match &self {
+            AnyYamlNode::YamlBogusValue(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_double_quoted_scalar(&self) -> Option<&YamlDoubleQuotedScalar> {
+        match &self {
+            AnyYamlNode::YamlDoubleQuotedScalar(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_flow_mapping(&self) -> Option<&YamlFlowMapping> {
+        match &self {
+            AnyYamlNode::YamlFlowMapping(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_flow_sequence(&self) -> Option<&YamlFlowSequence> {
+        match &self {
+            AnyYamlNode::YamlFlowSequence(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_plain_scalar(&self) -> Option<&YamlPlainScalar> {
+        match &self {
+            AnyYamlNode::YamlPlainScalar(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_properties_node(&self) -> Option<&YamlPropertiesNode> {
+        match &self {
+            AnyYamlNode::YamlPropertiesNode(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_single_quoted_scalar(&self) -> Option<&YamlSingleQuotedScalar> {
+        match &self {
+            AnyYamlNode::YamlSingleQuotedScalar(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum AnyYamlProperty {
+    YamlAnchorProperty(YamlAnchorProperty),
+    YamlTagProperty(YamlTagProperty),
+}
+impl AnyYamlProperty {
+    pub fn as_yaml_anchor_property(&self) -> Option<&YamlAnchorProperty> {
+        match &self {
+            AnyYamlProperty::YamlAnchorProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+    pub fn as_yaml_tag_property(&self) -> Option<&YamlTagProperty> {
+        match &self {
+            AnyYamlProperty::YamlTagProperty(item) => Some(item),
+            _ => None,
+        }
+    }
+}
+impl AstNode for YamlAliasNode {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_ALIAS_NODE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_ALIAS_NODE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlAliasNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlAliasNode")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlAliasNode> for SyntaxNode {
+    fn from(n: YamlAliasNode) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlAliasNode> for SyntaxElement {
+    fn from(n: YamlAliasNode) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlAnchorProperty {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_ANCHOR_PROPERTY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_ANCHOR_PROPERTY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlAnchorProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlAnchorProperty")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlAnchorProperty> for SyntaxNode {
+    fn from(n: YamlAnchorProperty) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlAnchorProperty> for SyntaxElement {
+    fn from(n: YamlAnchorProperty) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlBlockMapEntry {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BLOCK_MAP_ENTRY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BLOCK_MAP_ENTRY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlBlockMapEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlBlockMapEntry")
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field(
+                "colon_token",
+                &support::DebugSyntaxResult(self.colon_token()),
+            )
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .finish()
+    }
+}
+impl From<YamlBlockMapEntry> for SyntaxNode {
+    fn from(n: YamlBlockMapEntry) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlBlockMapEntry> for SyntaxElement {
+    fn from(n: YamlBlockMapEntry) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlBlockMapping {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BLOCK_MAPPING as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BLOCK_MAPPING
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlBlockMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlBlockMapping")
+            .field("entries", &self.entries())
+            .finish()
+    }
+}
+impl From<YamlBlockMapping> for SyntaxNode {
+    fn from(n: YamlBlockMapping) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlBlockMapping> for SyntaxElement {
+    fn from(n: YamlBlockMapping) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlBlockScalar {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BLOCK_SCALAR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BLOCK_SCALAR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlBlockScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlBlockScalar")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlBlockScalar> for SyntaxNode {
+    fn from(n: YamlBlockScalar) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlBlockScalar> for SyntaxElement {
+    fn from(n: YamlBlockScalar) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlBlockSequence {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BLOCK_SEQUENCE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BLOCK_SEQUENCE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlBlockSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlBlockSequence")
+            .field("entries", &self.entries())
+            .finish()
+    }
+}
+impl From<YamlBlockSequence> for SyntaxNode {
+    fn from(n: YamlBlockSequence) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlBlockSequence> for SyntaxElement {
+    fn from(n: YamlBlockSequence) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlBlockSequenceEntry {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BLOCK_SEQUENCE_ENTRY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BLOCK_SEQUENCE_ENTRY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlBlockSequenceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlBlockSequenceEntry")
+            .field("dash_token", &support::DebugSyntaxResult(self.dash_token()))
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .finish()
+    }
+}
+impl From<YamlBlockSequenceEntry> for SyntaxNode {
+    fn from(n: YamlBlockSequenceEntry) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlBlockSequenceEntry> for SyntaxElement {
+    fn from(n: YamlBlockSequenceEntry) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlDirective {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DIRECTIVE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_DIRECTIVE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlDirective")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlDirective> for SyntaxNode {
+    fn from(n: YamlDirective) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlDirective> for SyntaxElement {
+    fn from(n: YamlDirective) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlDocument {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DOCUMENT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_DOCUMENT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlDocument")
+            .field("directives", &self.directives())
+            .field(
+                "doc_start_token",
+                &support::DebugOptionalElement(self.doc_start_token()),
+            )
+            .field("node", &support::DebugOptionalElement(self.node()))
+            .field(
+                "doc_end_token",
+                &support::DebugOptionalElement(self.doc_end_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlDocument> for SyntaxNode {
+    fn from(n: YamlDocument) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlDocument> for SyntaxElement {
+    fn from(n: YamlDocument) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlDoubleQuotedScalar {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DOUBLE_QUOTED_SCALAR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_DOUBLE_QUOTED_SCALAR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlDoubleQuotedScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlDoubleQuotedScalar")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlDoubleQuotedScalar> for SyntaxNode {
+    fn from(n: YamlDoubleQuotedScalar) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlDoubleQuotedScalar> for SyntaxElement {
+    fn from(n: YamlDoubleQuotedScalar) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlFlowMapEntry {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_FLOW_MAP_ENTRY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_FLOW_MAP_ENTRY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlFlowMapEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlFlowMapEntry")
+            .field("key", &support::DebugSyntaxResult(self.key()))
+            .field(
+                "colon_token",
+                &support::DebugOptionalElement(self.colon_token()),
+            )
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .finish()
+    }
+}
+impl From<YamlFlowMapEntry> for SyntaxNode {
+    fn from(n: YamlFlowMapEntry) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlFlowMapEntry> for SyntaxElement {
+    fn from(n: YamlFlowMapEntry) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlFlowMapping {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_FLOW_MAPPING as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_FLOW_MAPPING
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlFlowMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlFlowMapping")
+            .field(
+                "l_curly_token",
+                &support::DebugSyntaxResult(self.l_curly_token()),
+            )
+            .field("entries", &self.entries())
+            .field(
+                "r_curly_token",
+                &support::DebugSyntaxResult(self.r_curly_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlFlowMapping> for SyntaxNode {
+    fn from(n: YamlFlowMapping) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlFlowMapping> for SyntaxElement {
+    fn from(n: YamlFlowMapping) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlFlowSequence {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_FLOW_SEQUENCE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_FLOW_SEQUENCE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlFlowSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlFlowSequence")
+            .field(
+                "l_brack_token",
+                &support::DebugSyntaxResult(self.l_brack_token()),
+            )
+            .field("entries", &self.entries())
+            .field(
+                "r_brack_token",
+                &support::DebugSyntaxResult(self.r_brack_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlFlowSequence> for SyntaxNode {
+    fn from(n: YamlFlowSequence) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlFlowSequence> for SyntaxElement {
+    fn from(n: YamlFlowSequence) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlPlainScalar {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_PLAIN_SCALAR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_PLAIN_SCALAR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlPlainScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlPlainScalar")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlPlainScalar> for SyntaxNode {
+    fn from(n: YamlPlainScalar) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlPlainScalar> for SyntaxElement {
+    fn from(n: YamlPlainScalar) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlPropertiesNode {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_PROPERTIES_NODE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_PROPERTIES_NODE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlPropertiesNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlPropertiesNode")
+            .field("properties", &self.properties())
+            .field("value", &support::DebugOptionalElement(self.value()))
+            .finish()
+    }
+}
+impl From<YamlPropertiesNode> for SyntaxNode {
+    fn from(n: YamlPropertiesNode) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlPropertiesNode> for SyntaxElement {
+    fn from(n: YamlPropertiesNode) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlRoot {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_ROOT as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_ROOT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlRoot")
+            .field(
+                "bom_token",
+                &support::DebugOptionalElement(self.bom_token()),
+            )
+            .field("documents", &self.documents())
+            .field("eof_token", &support::DebugSyntaxResult(self.eof_token()))
+            .finish()
+    }
+}
+impl From<YamlRoot> for SyntaxNode {
+    fn from(n: YamlRoot) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlRoot> for SyntaxElement {
+    fn from(n: YamlRoot) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlSingleQuotedScalar {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_SINGLE_QUOTED_SCALAR as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_SINGLE_QUOTED_SCALAR
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlSingleQuotedScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlSingleQuotedScalar")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlSingleQuotedScalar> for SyntaxNode {
+    fn from(n: YamlSingleQuotedScalar) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlSingleQuotedScalar> for SyntaxElement {
+    fn from(n: YamlSingleQuotedScalar) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl AstNode for YamlTagProperty {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_TAG_PROPERTY as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_TAG_PROPERTY
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlTagProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlTagProperty")
+            .field(
+                "value_token",
+                &support::DebugSyntaxResult(self.value_token()),
+            )
+            .finish()
+    }
+}
+impl From<YamlTagProperty> for SyntaxNode {
+    fn from(n: YamlTagProperty) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlTagProperty> for SyntaxElement {
+    fn from(n: YamlTagProperty) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+impl From<YamlAliasNode> for AnyYamlNode {
+    fn from(node: YamlAliasNode) -> AnyYamlNode {
+        AnyYamlNode::YamlAliasNode(node)
+    }
+}
+impl From<YamlBlockMapping> for AnyYamlNode {
+    fn from(node: YamlBlockMapping) -> AnyYamlNode {
+        AnyYamlNode::YamlBlockMapping(node)
+    }
+}
+impl From<YamlBlockScalar> for AnyYamlNode {
+    fn from(node: YamlBlockScalar) -> AnyYamlNode {
+        AnyYamlNode::YamlBlockScalar(node)
+    }
+}
+impl From<YamlBlockSequence> for AnyYamlNode {
+    fn from(node: YamlBlockSequence) -> AnyYamlNode {
+        AnyYamlNode::YamlBlockSequence(node)
+    }
+}
+impl From<YamlBogusValue> for AnyYamlNode {
+    fn from(node: YamlBogusValue) -> AnyYamlNode {
+        AnyYamlNode::YamlBogusValue(node)
+    }
+}
+impl From<YamlDoubleQuotedScalar> for AnyYamlNode {
+    fn from(node: YamlDoubleQuotedScalar) -> AnyYamlNode {
+        AnyYamlNode::YamlDoubleQuotedScalar(node)
+    }
+}
+impl From<YamlFlowMapping> for AnyYamlNode {
+    fn from(node: YamlFlowMapping) -> AnyYamlNode {
+        AnyYamlNode::YamlFlowMapping(node)
+    }
+}
+impl From<YamlFlowSequence> for AnyYamlNode {
+    fn from(node: YamlFlowSequence) -> AnyYamlNode {
+        AnyYamlNode::YamlFlowSequence(node)
+    }
+}
+impl From<YamlPlainScalar> for AnyYamlNode {
+    fn from(node: YamlPlainScalar) -> AnyYamlNode {
+        AnyYamlNode::YamlPlainScalar(node)
+    }
+}
+impl From<YamlPropertiesNode> for AnyYamlNode {
+    fn from(node: YamlPropertiesNode) -> AnyYamlNode {
+        AnyYamlNode::YamlPropertiesNode(node)
+    }
+}
+impl From<YamlSingleQuotedScalar> for AnyYamlNode {
+    fn from(node: YamlSingleQuotedScalar) -> AnyYamlNode {
+        AnyYamlNode::YamlSingleQuotedScalar(node)
+    }
+}
+impl AstNode for AnyYamlNode {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> = YamlAliasNode::KIND_SET
+        .union(YamlBlockMapping::KIND_SET)
+        .union(YamlBlockScalar::KIND_SET)
+        .union(YamlBlockSequence::KIND_SET)
+        .union(YamlBogusValue::KIND_SET)
+        .union(YamlDoubleQuotedScalar::KIND_SET)
+        .union(YamlFlowMapping::KIND_SET)
+        .union(YamlFlowSequence::KIND_SET)
+        .union(YamlPlainScalar::KIND_SET)
+        .union(YamlPropertiesNode::KIND_SET)
+        .union(YamlSingleQuotedScalar::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(
+            kind,
+            YAML_ALIAS_NODE
+                | YAML_BLOCK_MAPPING
+                | YAML_BLOCK_SCALAR
+                | YAML_BLOCK_SEQUENCE
+                | YAML_BOGUS_VALUE
+                | YAML_DOUBLE_QUOTED_SCALAR
+                | YAML_FLOW_MAPPING
+                | YAML_FLOW_SEQUENCE
+                | YAML_PLAIN_SCALAR
+                | YAML_PROPERTIES_NODE
+                | YAML_SINGLE_QUOTED_SCALAR
+        )
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            YAML_ALIAS_NODE => AnyYamlNode::YamlAliasNode(YamlAliasNode { syntax }),
+            YAML_BLOCK_MAPPING => AnyYamlNode::YamlBlockMapping(YamlBlockMapping { syntax }),
+            YAML_BLOCK_SCALAR => AnyYamlNode::YamlBlockScalar(YamlBlockScalar { syntax }),
+            YAML_BLOCK_SEQUENCE => AnyYamlNode::YamlBlockSequence(YamlBlockSequence { syntax }),
+            YAML_BOGUS_VALUE => AnyYamlNode::YamlBogusValue(YamlBogusValue { syntax }),
+            YAML_DOUBLE_QUOTED_SCALAR => {
+                AnyYamlNode::YamlDoubleQuotedScalar(YamlDoubleQuotedScalar { syntax })
+            }
+            YAML_FLOW_MAPPING => AnyYamlNode::YamlFlowMapping(YamlFlowMapping { syntax }),
+            YAML_FLOW_SEQUENCE => AnyYamlNode::YamlFlowSequence(YamlFlowSequence { syntax }),
+            YAML_PLAIN_SCALAR => AnyYamlNode::YamlPlainScalar(YamlPlainScalar { syntax }),
+            YAML_PROPERTIES_NODE => AnyYamlNode::YamlPropertiesNode(YamlPropertiesNode { syntax }),
+            YAML_SINGLE_QUOTED_SCALAR => {
+                AnyYamlNode::YamlSingleQuotedScalar(YamlSingleQuotedScalar { syntax })
+            }
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyYamlNode::YamlAliasNode(it) => &it.syntax,
+            AnyYamlNode::YamlBlockMapping(it) => &it.syntax,
+            AnyYamlNode::YamlBlockScalar(it) => &it.syntax,
+            AnyYamlNode::YamlBlockSequence(it) => &it.syntax,
+            AnyYamlNode::YamlBogusValue(it) => &it.syntax,
+            AnyYamlNode::YamlDoubleQuotedScalar(it) => &it.syntax,
+            AnyYamlNode::YamlFlowMapping(it) => &it.syntax,
+            AnyYamlNode::YamlFlowSequence(it) => &it.syntax,
+            AnyYamlNode::YamlPlainScalar(it) => &it.syntax,
+            AnyYamlNode::YamlPropertiesNode(it) => &it.syntax,
+            AnyYamlNode::YamlSingleQuotedScalar(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyYamlNode::YamlAliasNode(it) => it.syntax,
+            AnyYamlNode::YamlBlockMapping(it) => it.syntax,
+            AnyYamlNode::YamlBlockScalar(it) => it.syntax,
+            AnyYamlNode::YamlBlockSequence(it) => it.syntax,
+            AnyYamlNode::YamlBogusValue(it) => it.syntax,
+            AnyYamlNode::YamlDoubleQuotedScalar(it) => it.syntax,
+            AnyYamlNode::YamlFlowMapping(it) => it.syntax,
+            AnyYamlNode::YamlFlowSequence(it) => it.syntax,
+            AnyYamlNode::YamlPlainScalar(it) => it.syntax,
+            AnyYamlNode::YamlPropertiesNode(it) => it.syntax,
+            AnyYamlNode::YamlSingleQuotedScalar(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyYamlNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyYamlNode::YamlAliasNode(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlBlockMapping(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlBlockScalar(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlBlockSequence(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlBogusValue(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlDoubleQuotedScalar(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlFlowMapping(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlFlowSequence(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlPlainScalar(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlPropertiesNode(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlNode::YamlSingleQuotedScalar(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyYamlNode> for SyntaxNode {
+    fn from(n: AnyYamlNode) -> SyntaxNode {
+        match n {
+            AnyYamlNode::YamlAliasNode(it) => it.into(),
+            AnyYamlNode::YamlBlockMapping(it) => it.into(),
+            AnyYamlNode::YamlBlockScalar(it) => it.into(),
+            AnyYamlNode::YamlBlockSequence(it) => it.into(),
+            AnyYamlNode::YamlBogusValue(it) => it.into(),
+            AnyYamlNode::YamlDoubleQuotedScalar(it) => it.into(),
+            AnyYamlNode::YamlFlowMapping(it) => it.into(),
+            AnyYamlNode::YamlFlowSequence(it) => it.into(),
+            AnyYamlNode::YamlPlainScalar(it) => it.into(),
+            AnyYamlNode::YamlPropertiesNode(it) => it.into(),
+            AnyYamlNode::YamlSingleQuotedScalar(it) => it.into(),
+        }
+    }
+}
+impl From<AnyYamlNode> for SyntaxElement {
+    fn from(n: AnyYamlNode) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl From<YamlAnchorProperty> for AnyYamlProperty {
+    fn from(node: YamlAnchorProperty) -> AnyYamlProperty {
+        AnyYamlProperty::YamlAnchorProperty(node)
+    }
+}
+impl From<YamlTagProperty> for AnyYamlProperty {
+    fn from(node: YamlTagProperty) -> AnyYamlProperty {
+        AnyYamlProperty::YamlTagProperty(node)
+    }
+}
+impl AstNode for AnyYamlProperty {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        YamlAnchorProperty::KIND_SET.union(YamlTagProperty::KIND_SET);
+    fn can_cast(kind: SyntaxKind) -> bool {
+        matches!(kind, YAML_ANCHOR_PROPERTY | YAML_TAG_PROPERTY)
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        let res = match syntax.kind() {
+            YAML_ANCHOR_PROPERTY => {
+                AnyYamlProperty::YamlAnchorProperty(YamlAnchorProperty { syntax })
+            }
+            YAML_TAG_PROPERTY => AnyYamlProperty::YamlTagProperty(YamlTagProperty { syntax }),
+            _ => return None,
+        };
+        Some(res)
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            AnyYamlProperty::YamlAnchorProperty(it) => &it.syntax,
+            AnyYamlProperty::YamlTagProperty(it) => &it.syntax,
+        }
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        match self {
+            AnyYamlProperty::YamlAnchorProperty(it) => it.syntax,
+            AnyYamlProperty::YamlTagProperty(it) => it.syntax,
+        }
+    }
+}
+impl std::fmt::Debug for AnyYamlProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnyYamlProperty::YamlAnchorProperty(it) => std::fmt::Debug::fmt(it, f),
+            AnyYamlProperty::YamlTagProperty(it) => std::fmt::Debug::fmt(it, f),
+        }
+    }
+}
+impl From<AnyYamlProperty> for SyntaxNode {
+    fn from(n: AnyYamlProperty) -> SyntaxNode {
+        match n {
+            AnyYamlProperty::YamlAnchorProperty(it) => it.into(),
+            AnyYamlProperty::YamlTagProperty(it) => it.into(),
+        }
+    }
+}
+impl From<AnyYamlProperty> for SyntaxElement {
+    fn from(n: AnyYamlProperty) -> SyntaxElement {
+        let node: SyntaxNode = n.into();
+        node.into()
+    }
+}
+impl std::fmt::Display for AnyYamlNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for AnyYamlProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlAliasNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlAnchorProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlBlockMapEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlBlockMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlBlockScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlBlockSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlBlockSequenceEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlDirective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlDoubleQuotedScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlFlowMapEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlFlowMapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlFlowSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlPlainScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlPropertiesNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlRoot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlSingleQuotedScalar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+impl std::fmt::Display for YamlTagProperty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.syntax(), f)
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlBogus {
+    syntax: SyntaxNode,
+}
+impl YamlBogus {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for YamlBogus {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BOGUS as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BOGUS
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
         if Self::can_cast(syntax.kind()) {
             Some(Self { syntax })
         } else {
@@ -337,233 +1974,504 @@ impl AstNode for YamlStringLiteral {
     fn syntax(&self) -> &SyntaxNode {
         &self.syntax
     }
-    fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlBogus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlBogus")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<YamlBogus> for SyntaxNode {
+    fn from(n: YamlBogus) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlBogus> for SyntaxElement {
+    fn from(n: YamlBogus) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct YamlBogusValue {
+    syntax: SyntaxNode,
+}
+impl YamlBogusValue {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self { syntax }
+    }
+    pub fn items(&self) -> SyntaxElementChildren {
+        support::elements(&self.syntax)
+    }
+}
+impl AstNode for YamlBogusValue {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BOGUS_VALUE as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BOGUS_VALUE
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        if Self::can_cast(syntax.kind()) {
+            Some(Self { syntax })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax
+    }
+}
+impl std::fmt::Debug for YamlBogusValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("YamlBogusValue")
+            .field("items", &DebugSyntaxElementChildren(self.items()))
+            .finish()
+    }
+}
+impl From<YamlBogusValue> for SyntaxNode {
+    fn from(n: YamlBogusValue) -> SyntaxNode {
+        n.syntax
+    }
+}
+impl From<YamlBogusValue> for SyntaxElement {
+    fn from(n: YamlBogusValue) -> SyntaxElement {
+        n.syntax.into()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlBlockMapEntryList {
+    syntax_list: SyntaxList,
+}
+impl YamlBlockMapEntryList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for YamlBlockMapEntryList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BLOCK_MAP_ENTRY_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BLOCK_MAP_ENTRY_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<YamlBlockMapEntryList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(YamlBlockMapEntryList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlBlockMapEntryList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for YamlBlockMapEntryList {
+    type Language = Language;
+    type Node = YamlBlockMapEntry;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
+    }
+}
+impl Debug for YamlBlockMapEntryList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlBlockMapEntryList ")?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl IntoIterator for &YamlBlockMapEntryList {
+    type Item = YamlBlockMapEntry;
+    type IntoIter = AstNodeListIterator<Language, YamlBlockMapEntry>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for YamlBlockMapEntryList {
+    type Item = YamlBlockMapEntry;
+    type IntoIter = AstNodeListIterator<Language, YamlBlockMapEntry>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlBlockSequenceEntryList {
+    syntax_list: SyntaxList,
+}
+impl YamlBlockSequenceEntryList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
+    }
+}
+impl AstNode for YamlBlockSequenceEntryList {
+    type Language = Language;
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BLOCK_SEQUENCE_ENTRY_LIST as u16));
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == YAML_BLOCK_SEQUENCE_ENTRY_LIST
+    }
+    fn cast(syntax: SyntaxNode) -> Option<YamlBlockSequenceEntryList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(YamlBlockSequenceEntryList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        self.syntax_list.node()
+    }
+    fn into_syntax(self) -> SyntaxNode {
+        self.syntax_list.into_node()
+    }
+}
+#[cfg(feature = "serde")]
+impl Serialize for YamlBlockSequenceEntryList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
+    }
+}
+impl AstNodeList for YamlBlockSequenceEntryList {
+    type Language = Language;
+    type Node = YamlBlockSequenceEntry;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl std::fmt::Debug for YamlStringLiteral {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("YamlStringLiteral")
-            .field(
-                "value_token",
-                &support::DebugSyntaxResult(self.value_token()),
-            )
-            .finish()
+impl Debug for YamlBlockSequenceEntryList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlBlockSequenceEntryList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl From<YamlStringLiteral> for SyntaxNode {
-    fn from(n: YamlStringLiteral) -> SyntaxNode {
-        n.syntax
+impl IntoIterator for &YamlBlockSequenceEntryList {
+    type Item = YamlBlockSequenceEntry;
+    type IntoIter = AstNodeListIterator<Language, YamlBlockSequenceEntry>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
-impl From<YamlStringLiteral> for SyntaxElement {
-    fn from(n: YamlStringLiteral) -> SyntaxElement {
-        n.syntax.into()
+impl IntoIterator for YamlBlockSequenceEntryList {
+    type Item = YamlBlockSequenceEntry;
+    type IntoIter = AstNodeListIterator<Language, YamlBlockSequenceEntry>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
-impl From<YamlBogusValue> for AnyYamlContent {
-    fn from(node: YamlBogusValue) -> AnyYamlContent {
-        AnyYamlContent::YamlBogusValue(node)
-    }
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlDirectiveList {
+    syntax_list: SyntaxList,
 }
-impl From<YamlScalar> for AnyYamlContent {
-    fn from(node: YamlScalar) -> AnyYamlContent {
-        AnyYamlContent::YamlScalar(node)
+impl YamlDirectiveList {
+    #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
+    #[doc = r""]
+    #[doc = r" # Safety"]
+    #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
+    #[doc = r" or a match on [SyntaxNode::kind]"]
+    #[inline]
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for AnyYamlContent {
+impl AstNode for YamlDirectiveList {
     type Language = Language;
-    const KIND_SET: SyntaxKindSet<Language> = YamlBogusValue::KIND_SET.union(YamlScalar::KIND_SET);
+    const KIND_SET: SyntaxKindSet<Language> =
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DIRECTIVE_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        matches!(kind, YAML_BOGUS_VALUE | YAML_SCALAR)
+        kind == YAML_DIRECTIVE_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
-        let res = match syntax.kind() {
-            YAML_BOGUS_VALUE => AnyYamlContent::YamlBogusValue(YamlBogusValue { syntax }),
-            YAML_SCALAR => AnyYamlContent::YamlScalar(YamlScalar { syntax }),
-            _ => return None,
-        };
-        Some(res)
+    fn cast(syntax: SyntaxNode) -> Option<YamlDirectiveList> {
+        if Self::can_cast(syntax.kind()) {
+            Some(YamlDirectiveList {
+                syntax_list: syntax.into_list(),
+            })
+        } else {
+            None
+        }
     }
     fn syntax(&self) -> &SyntaxNode {
-        match self {
-            AnyYamlContent::YamlBogusValue(it) => &it.syntax,
-            AnyYamlContent::YamlScalar(it) => &it.syntax,
-        }
+        self.syntax_list.node()
     }
     fn into_syntax(self) -> SyntaxNode {
-        match self {
-            AnyYamlContent::YamlBogusValue(it) => it.syntax,
-            AnyYamlContent::YamlScalar(it) => it.syntax,
-        }
-    }
-}
-impl std::fmt::Debug for AnyYamlContent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AnyYamlContent::YamlBogusValue(it) => std::fmt::Debug::fmt(it, f),
-            AnyYamlContent::YamlScalar(it) => std::fmt::Debug::fmt(it, f),
-        }
+        self.syntax_list.into_node()
     }
 }
-impl From<AnyYamlContent> for SyntaxNode {
-    fn from(n: AnyYamlContent) -> SyntaxNode {
-        match n {
-            AnyYamlContent::YamlBogusValue(it) => it.into(),
-            AnyYamlContent::YamlScalar(it) => it.into(),
+#[cfg(feature = "serde")]
+impl Serialize for YamlDirectiveList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
         }
+        seq.end()
     }
 }
-impl From<AnyYamlContent> for SyntaxElement {
-    fn from(n: AnyYamlContent) -> SyntaxElement {
-        let node: SyntaxNode = n.into();
-        node.into()
-    }
-}
-impl std::fmt::Display for AnyYamlContent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl AstNodeList for YamlDirectiveList {
+    type Language = Language;
+    type Node = YamlDirective;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
     }
-}
-impl std::fmt::Display for YamlDocument {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl std::fmt::Display for YamlRoot {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl Debug for YamlDirectiveList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlDirectiveList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-impl std::fmt::Display for YamlScalar {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl IntoIterator for &YamlDirectiveList {
+    type Item = YamlDirective;
+    type IntoIter = AstNodeListIterator<Language, YamlDirective>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
-impl std::fmt::Display for YamlStringLiteral {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        std::fmt::Display::fmt(self.syntax(), f)
+impl IntoIterator for YamlDirectiveList {
+    type Item = YamlDirective;
+    type IntoIter = AstNodeListIterator<Language, YamlDirective>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct YamlBogus {
-    syntax: SyntaxNode,
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlDocumentList {
+    syntax_list: SyntaxList,
 }
-impl YamlBogus {
+impl YamlDocumentList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn items(&self) -> SyntaxElementChildren {
-        support::elements(&self.syntax)
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for YamlBogus {
+impl AstNode for YamlDocumentList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BOGUS as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DOCUMENT_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == YAML_BOGUS
+        kind == YAML_DOCUMENT_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    fn cast(syntax: SyntaxNode) -> Option<YamlDocumentList> {
         if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
+            Some(YamlDocumentList {
+                syntax_list: syntax.into_list(),
+            })
         } else {
             None
         }
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        self.syntax_list.node()
     }
     fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+        self.syntax_list.into_node()
     }
 }
-impl std::fmt::Debug for YamlBogus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("YamlBogus")
-            .field("items", &DebugSyntaxElementChildren(self.items()))
-            .finish()
+#[cfg(feature = "serde")]
+impl Serialize for YamlDocumentList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
     }
 }
-impl From<YamlBogus> for SyntaxNode {
-    fn from(n: YamlBogus) -> SyntaxNode {
-        n.syntax
+impl AstNodeList for YamlDocumentList {
+    type Language = Language;
+    type Node = YamlDocument;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl From<YamlBogus> for SyntaxElement {
-    fn from(n: YamlBogus) -> SyntaxElement {
-        n.syntax.into()
+impl Debug for YamlDocumentList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlDocumentList ")?;
+        f.debug_list().entries(self.iter()).finish()
     }
 }
-#[derive(Clone, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(Serialize))]
-pub struct YamlBogusValue {
-    syntax: SyntaxNode,
+impl IntoIterator for &YamlDocumentList {
+    type Item = YamlDocument;
+    type IntoIter = AstNodeListIterator<Language, YamlDocument>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
-impl YamlBogusValue {
+impl IntoIterator for YamlDocumentList {
+    type Item = YamlDocument;
+    type IntoIter = AstNodeListIterator<Language, YamlDocument>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct YamlFlowMapEntryList {
+    syntax_list: SyntaxList,
+}
+impl YamlFlowMapEntryList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
     #[doc = r" This function must be guarded with a call to [AstNode::can_cast]"]
     #[doc = r" or a match on [SyntaxNode::kind]"]
     #[inline]
-    pub const unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
-        Self { syntax }
-    }
-    pub fn items(&self) -> SyntaxElementChildren {
-        support::elements(&self.syntax)
+    pub unsafe fn new_unchecked(syntax: SyntaxNode) -> Self {
+        Self {
+            syntax_list: syntax.into_list(),
+        }
     }
 }
-impl AstNode for YamlBogusValue {
+impl AstNode for YamlFlowMapEntryList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_BOGUS_VALUE as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_FLOW_MAP_ENTRY_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == YAML_BOGUS_VALUE
+        kind == YAML_FLOW_MAP_ENTRY_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<Self> {
+    fn cast(syntax: SyntaxNode) -> Option<YamlFlowMapEntryList> {
         if Self::can_cast(syntax.kind()) {
-            Some(Self { syntax })
+            Some(YamlFlowMapEntryList {
+                syntax_list: syntax.into_list(),
+            })
         } else {
             None
         }
     }
     fn syntax(&self) -> &SyntaxNode {
-        &self.syntax
+        self.syntax_list.node()
     }
     fn into_syntax(self) -> SyntaxNode {
-        self.syntax
+        self.syntax_list.into_node()
     }
 }
-impl std::fmt::Debug for YamlBogusValue {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("YamlBogusValue")
-            .field("items", &DebugSyntaxElementChildren(self.items()))
-            .finish()
+#[cfg(feature = "serde")]
+impl Serialize for YamlFlowMapEntryList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for e in self.iter() {
+            seq.serialize_element(&e)?;
+        }
+        seq.end()
     }
 }
-impl From<YamlBogusValue> for SyntaxNode {
-    fn from(n: YamlBogusValue) -> SyntaxNode {
-        n.syntax
+impl AstSeparatedList for YamlFlowMapEntryList {
+    type Language = Language;
+    type Node = YamlFlowMapEntry;
+    fn syntax_list(&self) -> &SyntaxList {
+        &self.syntax_list
+    }
+    fn into_syntax_list(self) -> SyntaxList {
+        self.syntax_list
     }
 }
-impl From<YamlBogusValue> for SyntaxElement {
-    fn from(n: YamlBogusValue) -> SyntaxElement {
-        n.syntax.into()
+impl Debug for YamlFlowMapEntryList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("YamlFlowMapEntryList ")?;
+        f.debug_list().entries(self.elements()).finish()
+    }
+}
+impl IntoIterator for YamlFlowMapEntryList {
+    type Item = SyntaxResult<YamlFlowMapEntry>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, YamlFlowMapEntry>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl IntoIterator for &YamlFlowMapEntryList {
+    type Item = SyntaxResult<YamlFlowMapEntry>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, YamlFlowMapEntry>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct YamlContentList {
+pub struct YamlFlowSequenceEntryList {
     syntax_list: SyntaxList,
 }
-impl YamlContentList {
+impl YamlFlowSequenceEntryList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -576,16 +2484,16 @@ impl YamlContentList {
         }
     }
 }
-impl AstNode for YamlContentList {
+impl AstNode for YamlFlowSequenceEntryList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_CONTENT_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_FLOW_SEQUENCE_ENTRY_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == YAML_CONTENT_LIST
+        kind == YAML_FLOW_SEQUENCE_ENTRY_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<YamlContentList> {
+    fn cast(syntax: SyntaxNode) -> Option<YamlFlowSequenceEntryList> {
         if Self::can_cast(syntax.kind()) {
-            Some(YamlContentList {
+            Some(YamlFlowSequenceEntryList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -600,7 +2508,7 @@ impl AstNode for YamlContentList {
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for YamlContentList {
+impl Serialize for YamlFlowSequenceEntryList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -612,9 +2520,9 @@ impl Serialize for YamlContentList {
         seq.end()
     }
 }
-impl AstNodeList for YamlContentList {
+impl AstSeparatedList for YamlFlowSequenceEntryList {
     type Language = Language;
-    type Node = AnyYamlContent;
+    type Node = AnyYamlNode;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -622,31 +2530,31 @@ impl AstNodeList for YamlContentList {
         self.syntax_list
     }
 }
-impl Debug for YamlContentList {
+impl Debug for YamlFlowSequenceEntryList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("YamlContentList ")?;
-        f.debug_list().entries(self.iter()).finish()
+        f.write_str("YamlFlowSequenceEntryList ")?;
+        f.debug_list().entries(self.elements()).finish()
     }
 }
-impl IntoIterator for &YamlContentList {
-    type Item = AnyYamlContent;
-    type IntoIter = AstNodeListIterator<Language, AnyYamlContent>;
+impl IntoIterator for YamlFlowSequenceEntryList {
+    type Item = SyntaxResult<AnyYamlNode>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlNode>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for YamlContentList {
-    type Item = AnyYamlContent;
-    type IntoIter = AstNodeListIterator<Language, AnyYamlContent>;
+impl IntoIterator for &YamlFlowSequenceEntryList {
+    type Item = SyntaxResult<AnyYamlNode>;
+    type IntoIter = AstSeparatedListNodesIterator<Language, AnyYamlNode>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
 #[derive(Clone, Eq, PartialEq, Hash)]
-pub struct YamlDocumentList {
+pub struct YamlPropertyList {
     syntax_list: SyntaxList,
 }
-impl YamlDocumentList {
+impl YamlPropertyList {
     #[doc = r" Create an AstNode from a SyntaxNode without checking its kind"]
     #[doc = r""]
     #[doc = r" # Safety"]
@@ -659,16 +2567,16 @@ impl YamlDocumentList {
         }
     }
 }
-impl AstNode for YamlDocumentList {
+impl AstNode for YamlPropertyList {
     type Language = Language;
     const KIND_SET: SyntaxKindSet<Language> =
-        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_DOCUMENT_LIST as u16));
+        SyntaxKindSet::from_raw(RawSyntaxKind(YAML_PROPERTY_LIST as u16));
     fn can_cast(kind: SyntaxKind) -> bool {
-        kind == YAML_DOCUMENT_LIST
+        kind == YAML_PROPERTY_LIST
     }
-    fn cast(syntax: SyntaxNode) -> Option<YamlDocumentList> {
+    fn cast(syntax: SyntaxNode) -> Option<YamlPropertyList> {
         if Self::can_cast(syntax.kind()) {
-            Some(YamlDocumentList {
+            Some(YamlPropertyList {
                 syntax_list: syntax.into_list(),
             })
         } else {
@@ -683,7 +2591,7 @@ impl AstNode for YamlDocumentList {
     }
 }
 #[cfg(feature = "serde")]
-impl Serialize for YamlDocumentList {
+impl Serialize for YamlPropertyList {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -695,9 +2603,9 @@ impl Serialize for YamlDocumentList {
         seq.end()
     }
 }
-impl AstNodeList for YamlDocumentList {
+impl AstNodeList for YamlPropertyList {
     type Language = Language;
-    type Node = YamlDocument;
+    type Node = AnyYamlProperty;
     fn syntax_list(&self) -> &SyntaxList {
         &self.syntax_list
     }
@@ -705,22 +2613,22 @@ impl AstNodeList for YamlDocumentList {
         self.syntax_list
     }
 }
-impl Debug for YamlDocumentList {
+impl Debug for YamlPropertyList {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("YamlDocumentList ")?;
+        f.write_str("YamlPropertyList ")?;
         f.debug_list().entries(self.iter()).finish()
     }
 }
-impl IntoIterator for &YamlDocumentList {
-    type Item = YamlDocument;
-    type IntoIter = AstNodeListIterator<Language, YamlDocument>;
+impl IntoIterator for &YamlPropertyList {
+    type Item = AnyYamlProperty;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlProperty>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
 }
-impl IntoIterator for YamlDocumentList {
-    type Item = YamlDocument;
-    type IntoIter = AstNodeListIterator<Language, YamlDocument>;
+impl IntoIterator for YamlPropertyList {
+    type Item = AnyYamlProperty;
+    type IntoIter = AstNodeListIterator<Language, AnyYamlProperty>;
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
     }
@@ -1,10 +1,12 @@
 #[macro_use]
 mod generated;
+mod file_source;
 mod syntax_node;
 
 pub use self::generated::*;
 use biome_rowan::RawSyntaxKind;
 pub use biome_rowan::{TextLen, TextRange, TextSize, TokenAtOffset, TriviaPieceKind, WalkEvent};
+pub use file_source::YamlFileSource;
 pub use syntax_node::*;
 
 impl From<u16> for YamlSyntaxKind {
@@ -47,6 +47,14 @@ export interface PartialConfiguration {
 	 * The configuration of the formatter
 	 */
 	formatter?: PartialFormatterConfiguration;
+	/**
+	 * Specific configuration for the GraphQL language
+	 */
+	graphql?: PartialGraphqlConfiguration;
+	/**
+	 * Specific configuration for the HTML language
+	 */
+	html?: PartialHtmlConfiguration;
 	/**
 	 * Specific configuration for the JavaScript language
 	 */
@@ -59,6 +67,10 @@ export interface PartialConfiguration {
 	 * The configuration for the linter
 	 */
 	linter?: PartialLinterConfiguration;
+	/**
+	 * Specific configuration for the Markdown language
+	 */
+	markdown?: PartialMarkdownConfiguration;
 	/**
 	 * The configuration of the import sorting
 	 */
@@ -67,10 +79,18 @@ export interface PartialConfiguration {
 	 * A list of granular patterns that should be applied only to a sub set of files
 	 */
 	overrides?: Overrides;
+	/**
+	 * Specific configuration for the TOML language
+	 */
+	toml?: PartialTomlConfiguration;
 	/**
 	 * The configuration of the VCS integration
 	 */
 	vcs?: PartialVcsConfiguration;
+	/**
+	 * Specific configuration for the YAML language
+	 */
+	yaml?: PartialYamlConfiguration;
 }
 /**
  * Options applied to CSS files
@@ -148,6 +168,36 @@ export interface PartialFormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * How many consecutive empty lines are kept. Defaults to 1.
+	 */
+	maxEmptyLines?: MaxEmptyLines;
+	/**
+	 * Whether to read the formatter options from the `.editorconfig` file of the project. The options of the configuration file take precedence. Defaults to false.
+	 */
+	useEditorconfig?: boolean;
+}
+/**
+ * Options applied to GraphQL files
+ */
+export interface PartialGraphqlConfiguration {
+	/**
+	 * Formatting options
+	 */
+	formatter?: PartialGraphqlFormatter;
+	/**
+	 * Linting options
+	 */
+	linter?: PartialGraphqlLinter;
+}
+/**
+ * Options applied to HTML files
+ */
+export interface PartialHtmlConfiguration {
+	/**
+	 * Formatting options
+	 */
+	formatter?: PartialHtmlFormatter;
 }
 /**
  * A set of options applied to the JavaScript files
@@ -185,6 +235,10 @@ export interface PartialJsonConfiguration {
 	 * Parsing options
 	 */
 	parser?: PartialJsonParser;
+	/**
+	 * The JSON schemas that validate the files matching their patterns
+	 */
+	schemas?: JsonSchemas;
 }
 export interface PartialLinterConfiguration {
 	/**
@@ -204,6 +258,15 @@ export interface PartialLinterConfiguration {
 	 */
 	rules?: Rules;
 }
+/**
+ * Options applied to Markdown files
+ */
+export interface PartialMarkdownConfiguration {
+	/**
+	 * Formatting options
+	 */
+	formatter?: PartialMarkdownFormatter;
+}
 export interface PartialOrganizeImports {
 	/**
 	 * Enables the organization of imports
@@ -219,6 +282,15 @@ export interface PartialOrganizeImports {
 	include?: StringSet;
 }
 export type Overrides = OverridePattern[];
+/**
+ * Options applied to TOML files
+ */
+export interface PartialTomlConfiguration {
+	/**
+	 * Formatting options
+	 */
+	formatter?: PartialTomlFormatter;
+}
 /**
  * Set of properties to integrate Biome with a VCS software.
  */
@@ -246,7 +318,20 @@ If Biome can't find the configuration, it will attempt to use the current workin
 	 */
 	useIgnoreFile?: boolean;
 }
+/**
+ * Options applied to YAML files
+ */
+export interface PartialYamlConfiguration {
+	/**
+	 * Formatting options
+	 */
+	formatter?: PartialYamlFormatter;
+}
 export interface PartialCssFormatter {
+	/**
+	 * Whether to keep the rules that have a single declaration on one line, such as `a { color: red; }`. Defaults to false.
+	 */
+	collapseSingleDeclarations?: boolean;
 	/**
 	 * Control the formatter for CSS (and its super languages) files.
 	 */
@@ -281,6 +366,10 @@ export interface PartialCssParser {
 	 * Allow comments to appear on incorrect lines in `.css` files
 	 */
 	allowWrongLineComments?: boolean;
+	/**
+	 * Enables the directives and the functions of Tailwind CSS, such as `@apply` or `theme()`
+	 */
+	tailwind?: boolean;
 }
 export type AttributePosition = "auto" | "multiline";
 export type PlainIndentStyle = "tab" | "space";
@@ -291,6 +380,87 @@ export type LineEnding = "lf" | "crlf" | "cr";
 The allowed range of values is 1..=320 
 	 */
 export type LineWidth = number;
+/**
+	* Validated value for the `max_empty_lines` formatter options
+
+The allowed range of values is 0..=10 
+	 */
+export type MaxEmptyLines = number;
+/**
+ * Options that changes how the GraphQL formatter behaves
+ */
+export interface PartialGraphqlFormatter {
+	/**
+	 * How the arguments and the variable definitions that don't fit on a line are printed: indented like the selections of a selection set, or aligned after the opening parenthesis. Defaults to "indent".
+	 */
+	argumentsIndent?: ArgumentsIndent;
+	/**
+	 * Whether the definitions are separated by a blank line. Defaults to "preserve".
+	 */
+	blankLinesBetweenDefinitions?: BlankLinesBetweenDefinitions;
+	/**
+	 * The type of quotes used by the descriptions. Defaults to "preserve".
+	 */
+	descriptionQuoteStyle?: DescriptionQuoteStyle;
+	/**
+	 * Control the formatter for GraphQL files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The indent style applied to GraphQL files.
+	 */
+	indentStyle?: PlainIndentStyle;
+	/**
+	 * The size of the indentation applied to GraphQL files. Default to 2.
+	 */
+	indentWidth?: number;
+	/**
+	 * The type of line ending applied to GraphQL files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to GraphQL files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
+/**
+ * Options that changes how the GraphQL linter behaves
+ */
+export interface PartialGraphqlLinter {
+	/**
+	 * The path of the schema that the operations are validated against, relative to the configuration file. The files with the extension `.json` contain the result of an introspection query, the other files contain a document written in the Schema Definition Language.
+	 */
+	schema?: string;
+}
+/**
+ * Options that changes how the HTML formatter behaves
+ */
+export interface PartialHtmlFormatter {
+	/**
+	 * The attribute position style in HTML elements. Defaults to auto.
+	 */
+	attributePosition?: AttributePosition;
+	/**
+	 * Control the formatter for HTML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The indent style applied to HTML files.
+	 */
+	indentStyle?: PlainIndentStyle;
+	/**
+	 * The size of the indentation applied to HTML files. Default to 2.
+	 */
+	indentWidth?: number;
+	/**
+	 * The type of line ending applied to HTML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to HTML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+}
 /**
  * Formatting options specific to the JavaScript files
  */
@@ -311,10 +481,22 @@ export interface PartialJavascriptFormatter {
 	 * Whether to insert spaces around brackets in object literals. Defaults to true.
 	 */
 	bracketSpacing?: boolean;
+	/**
+	 * Where to print decorators. Defaults to "preserve".
+	 */
+	decoratorPlacement?: DecoratorPlacement;
+	/**
+	 * The tags of the template literals whose content is formatted with the formatter of another language, such as the CSS of `` styled.div`color: red;` ``.
+	 */
+	embeddedLanguageTags?: EmbeddedLanguageTags;
 	/**
 	 * Control the formatter for JavaScript (and its super languages) files.
 	 */
 	enabled?: boolean;
+	/**
+	 * Whether to format the JSDoc comments. Defaults to false.
+	 */
+	formatJsdoc?: boolean;
 	/**
 	 * The size of the indentation applied to JavaScript (and its super languages) files. Default to 2.
 	 */
@@ -327,6 +509,10 @@ export interface PartialJavascriptFormatter {
 	 * The size of the indentation applied to JavaScript (and its super languages) files. Default to 2.
 	 */
 	indentWidth?: number;
+	/**
+	 * Keep the member chains that fit inline. Defaults to false.
+	 */
+	inlineShortMemberChains?: boolean;
 	/**
 	 * The type of quotes used in JSX. Defaults to double.
 	 */
@@ -339,6 +525,18 @@ export interface PartialJavascriptFormatter {
 	 * What's the max width of a line applied to JavaScript (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Minimum number of calls to break a member chain. Defaults to 0.
+	 */
+	memberChainMinCalls?: number;
+	/**
+	 * How to wrap object literals. Defaults to "preserve".
+	 */
+	objectWrap?: ObjectWrap;
+	/**
+	 * Where to print the binary operators. Defaults to "end".
+	 */
+	operatorPosition?: OperatorPosition;
 	/**
 	 * When properties in objects are quoted. Defaults to asNeeded.
 	 */
@@ -351,6 +549,14 @@ export interface PartialJavascriptFormatter {
 	 * Whether the formatter prints semicolons for all statements or only in for statements where it is necessary because of ASI.
 	 */
 	semicolons?: Semicolons;
+	/**
+	 * Whether each JSX attribute goes on its own line. Defaults to false.
+	 */
+	singleAttributePerLine?: boolean;
+	/**
+	 * How to lay out the nested ternaries. Defaults to "nested".
+	 */
+	ternaryStyle?: TernaryStyle;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
 	 */
@@ -364,7 +570,26 @@ export interface PartialJavascriptFormatter {
  * Indicates the type of runtime or transformation used for interpreting JSX.
  */
 export type JsxRuntime = "transparent" | "reactClassic";
-export interface PartialJavascriptOrganizeImports {}
+export interface PartialJavascriptOrganizeImports {
+	/**
+	 * Requires a blank line between two groups of imports, and removes the blank lines between the imports of the same group.
+	 */
+	blankLinesBetweenGroups?: boolean;
+	/**
+	* The groups of imports, in the order in which they are sorted.
+
+Each group is a list of matchers. A matcher is either a predefined group, such as `:NODE:`, `:BUN:`, `:URL:`, `:PACKAGE:`, `:ALIAS:` or `:PATH:`, or a pattern of import sources where `*` matches any sequence of characters, such as `*.css`. An import belongs to the first group that matches its source. The imports that don't match any group are placed after all the groups. 
+	 */
+	groups?: ImportGroups;
+	/**
+	 * Merges the imports of the same source in a single import. Defaults to `true`.
+	 */
+	mergeImports?: boolean;
+	/**
+	 * Merges the type-only imports in the value imports of the same source, as inline `type` specifiers. Defaults to `false`.
+	 */
+	mergeTypeImports?: boolean;
+}
 /**
  * Options that changes how the JavaScript parser behaves
  */
@@ -377,6 +602,10 @@ These decorators belong to an old proposal, and they are subject to change.
 	unsafeParameterDecoratorsEnabled?: boolean;
 }
 export interface PartialJsonFormatter {
+	/**
+	 * How to wrap arrays. Defaults to "auto".
+	 */
+	arrayWrap?: ArrayWrap;
 	/**
 	 * Control the formatter for JSON (and its super languages) files.
 	 */
@@ -401,6 +630,10 @@ export interface PartialJsonFormatter {
 	 * What's the max width of a line applied to JSON (and its super languages) files. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * Whether to sort the keys of `package.json`.
+	 */
+	sortPackageJson?: boolean;
 	/**
 	 * Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "none".
 	 */
@@ -419,6 +652,7 @@ export interface PartialJsonParser {
 	 */
 	allowTrailingCommas?: boolean;
 }
+export type JsonSchemas = JsonSchemaMapping[];
 export interface Rules {
 	a11y?: A11y;
 	/**
@@ -437,6 +671,29 @@ export interface Rules {
 	style?: Style;
 	suspicious?: Suspicious;
 }
+/**
+	* Options that changes how the Markdown formatter behaves.
+
+There's no indent style: the content of the list items is always aligned with spaces. 
+	 */
+export interface PartialMarkdownFormatter {
+	/**
+	 * Control the formatter for Markdown files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The type of line ending applied to Markdown files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to Markdown files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * How the lines of the paragraphs are wrapped. Defaults to "preserve".
+	 */
+	proseWrap?: ProseWrap;
+}
 export interface OverridePattern {
 	/**
 	 * Specific configuration for the Css language
@@ -471,16 +728,116 @@ export interface OverridePattern {
 	 */
 	organizeImports?: OverrideOrganizeImportsConfiguration;
 }
+/**
+ * Options that changes how the TOML formatter behaves
+ */
+export interface PartialTomlFormatter {
+	/**
+	 * Control the formatter for TOML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The indent style applied to TOML files.
+	 */
+	indentStyle?: PlainIndentStyle;
+	/**
+	 * The size of the indentation applied to TOML files. Default to 2.
+	 */
+	indentWidth?: number;
+	/**
+	 * The type of line ending applied to TOML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to TOML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * The type of quotes used by the strings and the quoted keys of TOML files. Defaults to double.
+	 */
+	quoteStyle?: QuoteStyle;
+}
 export type VcsClientKind = "git";
+/**
+	* Options that changes how the YAML formatter behaves.
+
+There's no indent style: YAML files are always indented with spaces. 
+	 */
+export interface PartialYamlFormatter {
+	/**
+	 * Control the formatter for YAML files.
+	 */
+	enabled?: boolean;
+	/**
+	 * The size of the indentation applied to YAML files. Default to 2.
+	 */
+	indentWidth?: number;
+	/**
+	 * The type of line ending applied to YAML files.
+	 */
+	lineEnding?: LineEnding;
+	/**
+	 * What's the max width of a line applied to YAML files. Defaults to 80.
+	 */
+	lineWidth?: LineWidth;
+	/**
+	 * The type of quotes used by the quoted scalars of YAML files. Defaults to double.
+	 */
+	quoteStyle?: QuoteStyle;
+}
 export type QuoteStyle = "double" | "single";
+export type ArgumentsIndent = "indent" | "align";
+export type BlankLinesBetweenDefinitions = "preserve" | "always" | "never";
+export type DescriptionQuoteStyle = "preserve" | "block" | "double";
 export type ArrowParentheses = "always" | "asNeeded";
+export type DecoratorPlacement = "preserve" | "ownLine" | "inline";
+/**
+	* The tags of the template literals whose content is formatted as another language.
+
+A tag is either the code of the tag, such as `css`, or a pattern where `*` matches any sequence of characters, such as `styled.*`. 
+	 */
+export interface EmbeddedLanguageTags {
+	/**
+	 * The tags of the CSS templates. Defaults to `css`, `keyframes`, `createGlobalStyle`, `injectGlobal`, `styled.*` and `styled(*)`.
+	 */
+	css?: string[];
+	/**
+	 * The tags of the GraphQL templates. Defaults to `graphql` and `gql`.
+	 */
+	graphql?: string[];
+	/**
+	 * The tags of the HTML templates. Defaults to `html`.
+	 */
+	html?: string[];
+}
+export type ObjectWrap = "preserve" | "collapse";
+export type OperatorPosition = "start" | "end";
 export type QuoteProperties = "asNeeded" | "preserve";
 export type Semicolons = "always" | "asNeeded";
+export type TernaryStyle = "nested" | "chain";
 /**
  * Print trailing commas wherever possible in multi-line comma-separated syntactic structures.
  */
 export type TrailingCommas = "all" | "es5" | "none";
+/**
+ * The groups of imports used to organize the imports.
+ */
+export type ImportGroups = string[][];
+export type ArrayWrap = "auto" | "fill" | "onePerLine";
 export type TrailingCommas2 = "none" | "all";
+/**
+ * A JSON schema and the files that it validates
+ */
+export interface JsonSchemaMapping {
+	/**
+	 * A list of Unix shell style patterns. The schema validates the files that match these patterns.
+	 */
+	include?: StringSet;
+	/**
+	 * The path of the schema, relative to the configuration file.
+	 */
+	path?: string;
+}
 /**
  * A list of rules that belong to this group
  */
@@ -912,10 +1269,26 @@ export interface Nursery {
 	 * It enables ALL rules for this group.
 	 */
 	all?: boolean;
+	/**
+	 * Disallow asynchronous actions in Vue computed properties.
+	 */
+	noAsyncInComputed?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow await inside loops.
+	 */
+	noAwaitInLoop?: RuleConfiguration_for_NoAwaitInLoopOptions;
 	/**
 	 * WIP: This rule hasn't been implemented yet.
 	 */
 	noColorInvalidHex?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow conditional logic in tests.
+	 */
+	noConditionalTests?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the compiler options that contradict each other.
+	 */
+	noConflictingTsconfigOptions?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow the use of console.
 	 */
@@ -928,6 +1301,26 @@ export interface Nursery {
 	 * Disallow CSS empty blocks.
 	 */
 	noCssEmptyBlock?: RuleConfiguration_for_NoCssEmptyBlockOptions;
+	/**
+	 * Disallow the use of deprecated APIs.
+	 */
+	noDeprecatedApis?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the compiler options deprecated by TypeScript.
+	 */
+	noDeprecatedTsconfigOptions?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the use of the fields and the enum values that are deprecated.
+	 */
+	noDeprecatedUsage?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow direct mutations of props, state, and store values in React components and hooks.
+	 */
+	noDirectStateMutation?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow direct use of document.cookie.
+	 */
+	noDocumentCookie?: RuleConfiguration_for_NoDocumentCookieOptions;
 	/**
 	 * Disallow using a callback in asynchronous tests and hooks.
 	 */
@@ -944,50 +1337,178 @@ export interface Nursery {
 	 * Disallow duplicate names within font families.
 	 */
 	noDuplicateFontNames?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the fragments whose name is already used by another fragment of the project.
+	 */
+	noDuplicateFragmentName?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow two keys with the same name inside a JSON object.
 	 */
 	noDuplicateJsonKeys?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the operations whose name is already used by another operation of the project.
+	 */
+	noDuplicateOperationName?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the packages listed both in dependencies and devDependencies.
+	 */
+	noDuplicatePackageJsonDependencies?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow duplicate selectors within keyframe blocks.
 	 */
 	noDuplicateSelectorsKeyframeBlock?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow tests and test suites with the same title in the same test suite.
+	 */
+	noDuplicateTestTitles?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the keys and the tables that are defined more than once in a TOML document.
+	 */
+	noDuplicateTomlKeys?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow variables from evolving into any type through reassignments.
 	 */
 	noEvolvingAny?: RuleConfiguration_for_Null;
 	/**
-	 * Disallow to use unnecessary callback on flatMap.
+	 * Disallow rules that are nested too deeply.
 	 */
-	noFlatMapIdentity?: RuleConfiguration_for_Null;
+	noExcessiveNestingDepth?: RuleConfiguration_for_NoExcessiveNestingDepthOptions;
 	/**
-	 * Disallow invalid !important within keyframe declarations
+	 * Enforce a maximum number of parameters in a function.
 	 */
-	noImportantInKeyframe?: RuleConfiguration_for_Null;
+	noExcessiveParameters?: RuleConfiguration_for_NoExcessiveParametersOptions;
 	/**
-	 * Disallow the use of @import at-rules in invalid positions.
+	 * Enforce a maximum number of lines in a function.
 	 */
-	noInvalidPositionAtImportRule?: RuleConfiguration_for_Null;
+	noExcessivelyLongFunctions?: RuleConfiguration_for_NoExcessivelyLongFunctionsOptions;
 	/**
-	 * Checks that the assertion function, for example expect, is placed inside an it() function call.
+	 * Disallow to use unnecessary callback on flatMap.
 	 */
-	noMisplacedAssertion?: RuleConfiguration_for_Null;
+	noFlatMapIdentity?: RuleConfiguration_for_Null;
 	/**
-	 * Forbid the use of Node.js builtin modules.
+	 * Disallow the use of __dirname and __filename in ECMAScript modules.
 	 */
-	noNodejsModules?: RuleConfiguration_for_Null;
+	noGlobalDirnameFilename?: RuleConfiguration_for_Null;
 	/**
-	 * Prevents React-specific JSX properties from being used.
+	 * Disallow hardcoded colors in declarations.
 	 */
-	noReactSpecificProps?: RuleConfiguration_for_Null;
+	noHardcodedColors?: RuleConfiguration_for_NoHardcodedColorsOptions;
 	/**
-	 * Disallow specified modules when loaded by import or require.
+	 * Prevent usage of \<head> element in a Next.js project.
+	 */
+	noHeadElement?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow selectors whose specificity is higher than a maximum.
+	 */
+	noHighSpecificitySelectors?: RuleConfiguration_for_NoHighSpecificitySelectorsOptions;
+	/**
+	 * Prevent usage of \<img> element in a Next.js project.
+	 */
+	noImgElement?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow shorthand type conversions.
+	 */
+	noImplicitCoercion?: RuleConfiguration_for_NoImplicitCoercionOptions;
+	/**
+	 * Disallow invalid !important within keyframe declarations
+	 */
+	noImportantInKeyframe?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the use of @import at-rules in invalid positions.
+	 */
+	noInvalidPositionAtImportRule?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow invalid utilities in the @apply directive of Tailwind CSS.
+	 */
+	noInvalidTailwindApply?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the date-times that don't exist or that aren't written in the TOML format.
+	 */
+	noInvalidTomlDates?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the values that don't match the JSON Schema of the document.
+	 */
+	noJsonSchemaViolations?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow values that may render 0 or NaN in JSX conditional rendering.
+	 */
+	noLeakedConditionalRendering?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow magic numbers.
+	 */
+	noMagicNumbers?: RuleConfiguration_for_NoMagicNumbersOptions;
+	/**
+	 * Checks that the assertion function, for example expect, is placed inside an it() function call.
+	 */
+	noMisplacedAssertion?: RuleConfiguration_for_Null;
+	/**
+	 * Forbid the use of Node.js builtin modules.
+	 */
+	noNodejsModules?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow updating a variable of a Svelte component that the markup uses, when the variable isn't reactive.
+	 */
+	noNonReactiveUpdates?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow imports of the internal files of the other packages of the workspace.
+	 */
+	noPackageInternalImports?: RuleConfiguration_for_Null;
+	/**
+	 * Prevents React-specific JSX properties from being used.
+	 */
+	noReactSpecificProps?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow story names that are equal to the names that Storybook generates.
+	 */
+	noRedundantStoryName?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow specified modules when loaded by import or require.
 	 */
 	noRestrictedImports?: RuleConfiguration_for_RestrictedImportsOptions;
+	/**
+	 * Disallow destructuring props in Solid components.
+	 */
+	noSolidDestructuredProps?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow reading Solid signals without calling them.
+	 */
+	noSolidUncalledSignals?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow reading reactive values outside a tracking scope in Solid components.
+	 */
+	noSolidUntrackedReads?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow using expect outside of tests.
+	 */
+	noStandaloneExpect?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the mouse and keyboard event handlers on the static elements without a role.
+	 */
+	noStaticElementInteractions?: RuleConfiguration_for_Null;
+	/**
+	 * Prevent the usage of synchronous scripts.
+	 */
+	noSyncScripts?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow class names that aren't declared by the stylesheets of the project.
+	 */
+	noUndeclaredClassNames?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow the use of dependencies that aren't specified in the package.json.
 	 */
 	noUndeclaredDependencies?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the reference links whose label doesn't match a link definition.
+	 */
+	noUndefinedMarkdownReferences?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the arguments that aren't defined by the field or the directive.
+	 */
+	noUnknownArgument?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the selection of fields that don't exist on the type.
+	 */
+	noUnknownField?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown CSS value functions.
 	 */
@@ -1004,6 +1525,10 @@ export interface Nursery {
 	 * Disallow unknown pseudo-element selectors.
 	 */
 	noUnknownSelectorPseudoElement?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the compiler options that TypeScript doesn't know.
+	 */
+	noUnknownTsconfigOptions?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unknown CSS units.
 	 */
@@ -1012,34 +1537,122 @@ export interface Nursery {
 	 * Disallow unmatchable An+B selectors.
 	 */
 	noUnmatchableAnbSelector?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow conditions that are always truthy, always falsy, or never nullish according to their types.
+	 */
+	noUnnecessaryCondition?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the configuration files extended by extends that don't exist.
+	 */
+	noUnresolvedTsconfigExtends?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow type assertions that widen a value or cross unrelated types.
+	 */
+	noUnsafeTypeAssertion?: RuleConfiguration_for_NoUnsafeTypeAssertionOptions;
+	/**
+	 * Disallow defining components inside other components.
+	 */
+	noUnstableNestedComponents?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow props that are declared in the props type of a component but never used.
+	 */
+	noUnusedComponentProps?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow the ref attributes of a Vue template that are never used.
+	 */
+	noUnusedRefs?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow class and identifier selectors that aren't referenced by the markup of the project.
+	 */
+	noUnusedSelectors?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow discarding the unsubscriber of a Svelte store subscription.
+	 */
+	noUnusedStoreSubscriptions?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow unnecessary concatenation of string or template literals.
 	 */
 	noUselessStringConcat?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow useless undefined.
+	 */
+	noUselessUndefined?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow initializing variables to undefined.
 	 */
 	noUselessUndefinedInitialization?: RuleConfiguration_for_Null;
+	/**
+	 * Disallow vendor prefixes on properties and values that have a standard equivalent.
+	 */
+	noVendorPrefix?: RuleConfiguration_for_NoVendorPrefixOptions;
 	/**
 	 * It enables the recommended rules for this group
 	 */
 	recommended?: boolean;
+	/**
+	 * Enforce the length units allowed in the declarations.
+	 */
+	useAllowedLengthUnits?: RuleConfiguration_for_UseAllowedLengthUnitsOptions;
+	/**
+	 * Enforce that ARIA properties are supported by the role of the element.
+	 */
+	useAriaPropsSupportedByRole?: RuleConfiguration_for_Null;
 	/**
 	 * Disallow Array constructors.
 	 */
 	useArrayLiterals?: RuleConfiguration_for_Null;
+	/**
+	 * Use at() instead of integer index access.
+	 */
+	useAtIndex?: RuleConfiguration_for_UseAtIndexOptions;
+	/**
+	 * Enforce awaiting the interactions of the play functions of stories.
+	 */
+	useAwaitStoryInteractions?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce that await is only applied to values that may be thenables.
+	 */
+	useAwaitThenable?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce a sufficient contrast between the text color and the background color of the inline styles.
+	 */
+	useColorContrast?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce the use of new for all builtins, except String, Number, Boolean, Symbol and BigInt.
 	 */
 	useConsistentBuiltinInstantiation?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce a consistent use of curly braces around the string props and the string children of JSX elements.
+	 */
+	useConsistentCurlyBraces?: RuleConfiguration_for_UseConsistentCurlyBracesOptions;
+	/**
+	 * Enforce using the same function, it or test, to declare tests.
+	 */
+	useConsistentTestFunction?: RuleConfiguration_for_ConsistentTestFunctionOptions;
+	/**
+	 * Enforce naming conventions for the class names, the keyframes names, and the custom properties.
+	 */
+	useCssNamingConvention?: RuleConfiguration_for_CssNamingConventionOptions;
 	/**
 	 * Require the default clause in switch statements.
 	 */
 	useDefaultSwitchClause?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce declaring defineProps and defineEmits at the top of Vue \<script setup>.
+	 */
+	useDefinePropsOrder?: RuleConfiguration_for_Null;
+	/**
+	 * Require explicit return types on functions and class methods.
+	 */
+	useExplicitFunctionReturnType?: RuleConfiguration_for_ExplicitFunctionReturnTypeOptions;
 	/**
 	 * Enforce explicitly comparing the length, size, byteLength or byteOffset property of a value.
 	 */
 	useExplicitLengthCheck?: RuleConfiguration_for_Null;
+	/**
+	 * Require or disallow the accessibility modifiers on the class members.
+	 */
+	useExplicitMemberAccessibility?: RuleConfiguration_for_ExplicitMemberAccessibilityOptions;
 	/**
 	 * Elements with an interactive role and interaction handlers must be focusable.
 	 */
@@ -1048,14 +1661,82 @@ export interface Nursery {
 	 * Disallow a missing generic family keyword within font families.
 	 */
 	useGenericFontNames?: RuleConfiguration_for_Null;
+	/**
+	 * Ensure the preconnect attribute is used when using Google Fonts.
+	 */
+	useGoogleFontPreconnect?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce naming conventions for the names defined in GraphQL documents.
+	 */
+	useGraphqlNamingConvention?: RuleConfiguration_for_GraphqlNamingConventionOptions;
 	/**
 	 * Disallows package private imports.
 	 */
 	useImportRestrictions?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the use of logical properties and values instead of physical ones.
+	 */
+	useLogicalProperties?: RuleConfiguration_for_UseLogicalPropertiesOptions;
+	/**
+	 * Require the operations to have a name.
+	 */
+	useNamedOperation?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the use of numeric separators in long numeric literals.
+	 */
+	useNumericSeparators?: RuleConfiguration_for_NumericSeparatorsOptions;
+	/**
+	 * Prefer object spread over Object.assign() when constructing a new object.
+	 */
+	useObjectSpread?: RuleConfiguration_for_Null;
+	/**
+	 * Require private class members that are never reassigned to be marked as readonly.
+	 */
+	useReadonlyClassMembers?: RuleConfiguration_for_Null;
+	/**
+	 * Require the arguments that the field or the directive can't omit.
+	 */
+	useRequiredArguments?: RuleConfiguration_for_Null;
+	/**
+	 * Require the fields of package.json that describe how a package can be used.
+	 */
+	useRequiredPackageJsonFields?: RuleConfiguration_for_UseRequiredPackageJsonFieldsOptions;
+	/**
+	 * Enforce the use of shorthand properties instead of their four longhand properties.
+	 */
+	useShorthandProperties?: RuleConfiguration_for_Null;
 	/**
 	 * Enforce the sorting of CSS utility classes.
 	 */
 	useSortedClasses?: RuleConfiguration_for_UtilityClassSortingOptions;
+	/**
+	 * Enforce the order of the keys of the objects.
+	 */
+	useSortedKeys?: RuleConfiguration_for_UseSortedKeysOptions;
+	/**
+	 * Enforce a conventional order of the fields of package.json, and sort the dependencies.
+	 */
+	useSortedPackageJsonKeys?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the order of the declarations of a block.
+	 */
+	useSortedProperties?: RuleConfiguration_for_UseSortedPropertiesOptions;
+	/**
+	 * Enforce the order of the keys of the TOML tables.
+	 */
+	useSortedTomlKeys?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the order of the keys of the YAML mappings.
+	 */
+	useSortedYamlKeys?: RuleConfiguration_for_Null;
+	/**
+	 * Require a default export in stories files.
+	 */
+	useStoryDefaultExport?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce PascalCase for the names of the stories.
+	 */
+	useStoryNamePascalCase?: RuleConfiguration_for_Null;
 	/**
 	 * Require new when throwing an error.
 	 */
@@ -1064,6 +1745,30 @@ export interface Nursery {
 	 * Require all regex literals to be declared at the top level.
 	 */
 	useTopLevelRegex?: RuleConfiguration_for_Null;
+	/**
+	 * Prefer the path aliases of the tsconfig.json over deep relative imports.
+	 */
+	useTsconfigPathAliases?: RuleConfiguration_for_UseTsconfigPathAliasesOptions;
+	/**
+	 * Enforce that the autocomplete attribute has a valid value.
+	 */
+	useValidAutocomplete?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce valid semantic versions and version ranges in package.json.
+	 */
+	useValidPackageJsonVersions?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce the valid usage of Svelte runes.
+	 */
+	useValidRunes?: RuleConfiguration_for_Null;
+	/**
+	 * Require the variables to be declared by the operation, with a type allowed where they're used.
+	 */
+	useValidVariables?: RuleConfiguration_for_Null;
+	/**
+	 * Enforce valid usage of the built-in directives of a Vue template.
+	 */
+	useValidVueDirectives?: RuleConfiguration_for_Null;
 }
 /**
  * A list of rules that belong to this group
@@ -1537,6 +2242,10 @@ export interface Suspicious {
 	 */
 	useValidTypeof?: RuleConfiguration_for_Null;
 }
+/**
+ * How the lines of the paragraphs are wrapped.
+ */
+export type ProseWrap = "preserve" | "always" | "never";
 export interface OverrideFormatterConfiguration {
 	/**
 	 * The attribute position style.
@@ -1567,6 +2276,10 @@ export interface OverrideFormatterConfiguration {
 	 * What's the max width of a line. Defaults to 80.
 	 */
 	lineWidth?: LineWidth;
+	/**
+	 * How many consecutive empty lines are kept. Defaults to 1.
+	 */
+	maxEmptyLines?: MaxEmptyLines;
 }
 export interface OverrideLinterConfiguration {
 	/**
@@ -1599,15 +2312,90 @@ export type RuleConfiguration_for_HooksOptions =
 export type RuleConfiguration_for_DeprecatedHooksOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_DeprecatedHooksOptions;
+export type RuleConfiguration_for_NoAwaitInLoopOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoAwaitInLoopOptions;
 export type RuleConfiguration_for_NoCssEmptyBlockOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_NoCssEmptyBlockOptions;
+export type RuleConfiguration_for_NoDocumentCookieOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoDocumentCookieOptions;
+export type RuleConfiguration_for_NoExcessiveNestingDepthOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoExcessiveNestingDepthOptions;
+export type RuleConfiguration_for_NoExcessiveParametersOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoExcessiveParametersOptions;
+export type RuleConfiguration_for_NoExcessivelyLongFunctionsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoExcessivelyLongFunctionsOptions;
+export type RuleConfiguration_for_NoHardcodedColorsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoHardcodedColorsOptions;
+export type RuleConfiguration_for_NoHighSpecificitySelectorsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoHighSpecificitySelectorsOptions;
+export type RuleConfiguration_for_NoImplicitCoercionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoImplicitCoercionOptions;
+export type RuleConfiguration_for_NoMagicNumbersOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoMagicNumbersOptions;
 export type RuleConfiguration_for_RestrictedImportsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedImportsOptions;
+export type RuleConfiguration_for_NoUnsafeTypeAssertionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoUnsafeTypeAssertionOptions;
+export type RuleConfiguration_for_NoVendorPrefixOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NoVendorPrefixOptions;
+export type RuleConfiguration_for_UseAllowedLengthUnitsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseAllowedLengthUnitsOptions;
+export type RuleConfiguration_for_UseAtIndexOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseAtIndexOptions;
+export type RuleConfiguration_for_UseConsistentCurlyBracesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseConsistentCurlyBracesOptions;
+export type RuleConfiguration_for_ConsistentTestFunctionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_ConsistentTestFunctionOptions;
+export type RuleConfiguration_for_CssNamingConventionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_CssNamingConventionOptions;
+export type RuleConfiguration_for_ExplicitFunctionReturnTypeOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_ExplicitFunctionReturnTypeOptions;
+export type RuleConfiguration_for_ExplicitMemberAccessibilityOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_ExplicitMemberAccessibilityOptions;
+export type RuleConfiguration_for_GraphqlNamingConventionOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_GraphqlNamingConventionOptions;
+export type RuleConfiguration_for_UseLogicalPropertiesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseLogicalPropertiesOptions;
+export type RuleConfiguration_for_NumericSeparatorsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_NumericSeparatorsOptions;
+export type RuleConfiguration_for_UseRequiredPackageJsonFieldsOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseRequiredPackageJsonFieldsOptions;
 export type RuleConfiguration_for_UtilityClassSortingOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_UtilityClassSortingOptions;
+export type RuleConfiguration_for_UseSortedKeysOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseSortedKeysOptions;
+export type RuleConfiguration_for_UseSortedPropertiesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseSortedPropertiesOptions;
+export type RuleConfiguration_for_UseTsconfigPathAliasesOptions =
+	| RulePlainConfiguration
+	| RuleWithOptions_for_UseTsconfigPathAliasesOptions;
 export type RuleConfiguration_for_RestrictedGlobalsOptions =
 	| RulePlainConfiguration
 	| RuleWithOptions_for_RestrictedGlobalsOptions;
@@ -1633,9 +2421,331 @@ export interface RuleWithOptions_for_Null {
 	/**
 	 * Rule's options
 	 */
-	options: null;
+	options: null;
+}
+export interface RuleWithOptions_for_ValidAriaRoleOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ValidAriaRoleOptions;
+}
+export interface RuleWithOptions_for_ComplexityOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ComplexityOptions;
+}
+export interface RuleWithOptions_for_HooksOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: HooksOptions;
+}
+export interface RuleWithOptions_for_DeprecatedHooksOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: DeprecatedHooksOptions;
+}
+export interface RuleWithOptions_for_NoAwaitInLoopOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoAwaitInLoopOptions;
+}
+export interface RuleWithOptions_for_NoCssEmptyBlockOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoCssEmptyBlockOptions;
+}
+export interface RuleWithOptions_for_NoDocumentCookieOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoDocumentCookieOptions;
+}
+export interface RuleWithOptions_for_NoExcessiveNestingDepthOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoExcessiveNestingDepthOptions;
+}
+export interface RuleWithOptions_for_NoExcessiveParametersOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoExcessiveParametersOptions;
+}
+export interface RuleWithOptions_for_NoExcessivelyLongFunctionsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoExcessivelyLongFunctionsOptions;
+}
+export interface RuleWithOptions_for_NoHardcodedColorsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoHardcodedColorsOptions;
+}
+export interface RuleWithOptions_for_NoHighSpecificitySelectorsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoHighSpecificitySelectorsOptions;
+}
+export interface RuleWithOptions_for_NoImplicitCoercionOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoImplicitCoercionOptions;
+}
+export interface RuleWithOptions_for_NoMagicNumbersOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoMagicNumbersOptions;
+}
+export interface RuleWithOptions_for_RestrictedImportsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: RestrictedImportsOptions;
+}
+export interface RuleWithOptions_for_NoUnsafeTypeAssertionOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoUnsafeTypeAssertionOptions;
+}
+export interface RuleWithOptions_for_NoVendorPrefixOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: NoVendorPrefixOptions;
+}
+export interface RuleWithOptions_for_UseAllowedLengthUnitsOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseAllowedLengthUnitsOptions;
+}
+export interface RuleWithOptions_for_UseAtIndexOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseAtIndexOptions;
+}
+export interface RuleWithOptions_for_UseConsistentCurlyBracesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseConsistentCurlyBracesOptions;
+}
+export interface RuleWithOptions_for_ConsistentTestFunctionOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ConsistentTestFunctionOptions;
+}
+export interface RuleWithOptions_for_CssNamingConventionOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: CssNamingConventionOptions;
+}
+export interface RuleWithOptions_for_ExplicitFunctionReturnTypeOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: ExplicitFunctionReturnTypeOptions;
 }
-export interface RuleWithOptions_for_ValidAriaRoleOptions {
+export interface RuleWithOptions_for_ExplicitMemberAccessibilityOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -1647,9 +2757,9 @@ export interface RuleWithOptions_for_ValidAriaRoleOptions {
 	/**
 	 * Rule's options
 	 */
-	options: ValidAriaRoleOptions;
+	options: ExplicitMemberAccessibilityOptions;
 }
-export interface RuleWithOptions_for_ComplexityOptions {
+export interface RuleWithOptions_for_GraphqlNamingConventionOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -1661,9 +2771,9 @@ export interface RuleWithOptions_for_ComplexityOptions {
 	/**
 	 * Rule's options
 	 */
-	options: ComplexityOptions;
+	options: GraphqlNamingConventionOptions;
 }
-export interface RuleWithOptions_for_HooksOptions {
+export interface RuleWithOptions_for_UseLogicalPropertiesOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -1675,9 +2785,9 @@ export interface RuleWithOptions_for_HooksOptions {
 	/**
 	 * Rule's options
 	 */
-	options: HooksOptions;
+	options: UseLogicalPropertiesOptions;
 }
-export interface RuleWithOptions_for_DeprecatedHooksOptions {
+export interface RuleWithOptions_for_NumericSeparatorsOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -1689,9 +2799,9 @@ export interface RuleWithOptions_for_DeprecatedHooksOptions {
 	/**
 	 * Rule's options
 	 */
-	options: DeprecatedHooksOptions;
+	options: NumericSeparatorsOptions;
 }
-export interface RuleWithOptions_for_NoCssEmptyBlockOptions {
+export interface RuleWithOptions_for_UseRequiredPackageJsonFieldsOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -1703,9 +2813,9 @@ export interface RuleWithOptions_for_NoCssEmptyBlockOptions {
 	/**
 	 * Rule's options
 	 */
-	options: NoCssEmptyBlockOptions;
+	options: UseRequiredPackageJsonFieldsOptions;
 }
-export interface RuleWithOptions_for_RestrictedImportsOptions {
+export interface RuleWithOptions_for_UtilityClassSortingOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -1717,9 +2827,9 @@ export interface RuleWithOptions_for_RestrictedImportsOptions {
 	/**
 	 * Rule's options
 	 */
-	options: RestrictedImportsOptions;
+	options: UtilityClassSortingOptions;
 }
-export interface RuleWithOptions_for_UtilityClassSortingOptions {
+export interface RuleWithOptions_for_UseSortedKeysOptions {
 	/**
 	 * The kind of the code actions emitted by the rule
 	 */
@@ -1731,7 +2841,35 @@ export interface RuleWithOptions_for_UtilityClassSortingOptions {
 	/**
 	 * Rule's options
 	 */
-	options: UtilityClassSortingOptions;
+	options: UseSortedKeysOptions;
+}
+export interface RuleWithOptions_for_UseSortedPropertiesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseSortedPropertiesOptions;
+}
+export interface RuleWithOptions_for_UseTsconfigPathAliasesOptions {
+	/**
+	 * The kind of the code actions emitted by the rule
+	 */
+	fix?: FixKind;
+	/**
+	 * The severity of the emitted diagnostics by the rule
+	 */
+	level: RulePlainConfiguration;
+	/**
+	 * Rule's options
+	 */
+	options: UseTsconfigPathAliasesOptions;
 }
 export interface RuleWithOptions_for_RestrictedGlobalsOptions {
 	/**
@@ -1819,9 +2957,114 @@ export interface HooksOptions {
  * Options for the `useHookAtTopLevel` rule have been deprecated, since we now use the React hook naming convention to determine whether a function is a hook.
  */
 export interface DeprecatedHooksOptions {}
+/**
+ * Options for the rule `noAwaitInLoop`.
+ */
+export interface NoAwaitInLoopOptions {
+	/**
+	 * Ignore the `await` expressions whose result is used in the condition of the loop.
+	 */
+	ignoreConditionDependencies: boolean;
+}
 export interface NoCssEmptyBlockOptions {
 	allowComments: boolean;
 }
+/**
+ * Options for the rule `noDocumentCookie`.
+ */
+export interface NoDocumentCookieOptions {
+	/**
+	 * A list of paths of files where `document.cookie` can be used.
+	 */
+	allowedFiles: string[];
+}
+/**
+ * Options for the rule `noExcessiveNestingDepth`.
+ */
+export interface NoExcessiveNestingDepthOptions {
+	/**
+	 * The maximum nesting depth of a rule.
+	 */
+	maxDepth: number;
+}
+/**
+ * Options for the rule `noExcessiveParameters`.
+ */
+export interface NoExcessiveParametersOptions {
+	/**
+	 * The maximum number of parameters allowed in a function.
+	 */
+	maxParameters: number;
+}
+/**
+ * Options for the rule `noExcessivelyLongFunctions`.
+ */
+export interface NoExcessivelyLongFunctionsOptions {
+	/**
+	 * The maximum number of logical lines allowed in a function.
+	 */
+	maxLines: number;
+}
+/**
+ * Options for the rule `noHardcodedColors`.
+ */
+export interface NoHardcodedColorsOptions {
+	/**
+	 * The colors that can be written as literals.
+	 */
+	allow: string[];
+	/**
+	 * The custom properties of the palette, with their color.
+	 */
+	palette: {};
+}
+/**
+ * Options for the rule `noHighSpecificitySelectors`.
+ */
+export interface NoHighSpecificitySelectorsOptions {
+	/**
+	 * The highest specificity that is allowed, such as `0,4,0`.
+	 */
+	maxSpecificity: Specificity;
+}
+/**
+ * Options for the rule `noImplicitCoercion`.
+ */
+export interface NoImplicitCoercionOptions {
+	/**
+	 * The operators that are allowed.
+	 */
+	allow: CoercionOperator[];
+	/**
+	 * Report the conversions to booleans: `!!x` and `~x.indexOf(y)`.
+	 */
+	boolean: boolean;
+	/**
+	 * Report the conversions to numbers: `+x`.
+	 */
+	number: boolean;
+	/**
+	 * Report the conversions to strings: `"" + x`.
+	 */
+	string: boolean;
+}
+/**
+ * Options for the rule `noMagicNumbers`.
+ */
+export interface NoMagicNumbersOptions {
+	/**
+	 * The integers that are allowed everywhere.
+	 */
+	ignore: number[];
+	/**
+	 * Allow numbers as default values of parameters and destructured bindings.
+	 */
+	ignoreDefaultValues: boolean;
+	/**
+	 * Allow numbers as initializers of the members of TypeScript enums.
+	 */
+	ignoreEnums: boolean;
+}
 /**
  * Options for the rule `noRestrictedImports`.
  */
@@ -1831,6 +3074,148 @@ export interface RestrictedImportsOptions {
 	 */
 	paths: {};
 }
+/**
+ * Options for the rule `noUnsafeTypeAssertion`.
+ */
+export interface NoUnsafeTypeAssertionOptions {
+	/**
+	 * If `false`, `as const` assertions are reported.
+	 */
+	allowAsConst?: boolean;
+	/**
+	 * If `true`, assertions inside test calls are ignored.
+	 */
+	allowInTests?: boolean;
+}
+/**
+ * Options for the rule `noVendorPrefix`.
+ */
+export interface NoVendorPrefixOptions {
+	/**
+	 * The prefixed properties and values that are allowed.
+	 */
+	allow: string[];
+}
+/**
+ * Options for the rule `useAllowedLengthUnits`.
+ */
+export interface UseAllowedLengthUnitsOptions {
+	/**
+	 * The length units allowed in a property. A name that ends with `*` matches all the properties that start with this name.
+	 */
+	properties: {};
+	/**
+	 * The size of the root font in pixels, used to convert `px` to `rem`.
+	 */
+	rootFontSize: number;
+}
+/**
+ * Options for the rule `useAtIndex`.
+ */
+export interface UseAtIndexOptions {
+	/**
+	 * Report all the accesses with an integer index.
+	 */
+	checkAllIndexAccess: boolean;
+	/**
+	 * The version of ECMAScript of the runtimes that the code runs on.
+	 */
+	target: EcmaVersion;
+}
+/**
+ * Options for the rule `useConsistentCurlyBraces`.
+ */
+export interface UseConsistentCurlyBracesOptions {
+	/**
+	 * The policy for the string children.
+	 */
+	children: CurlyBracesPolicy;
+	/**
+	 * The policy for the string props.
+	 */
+	props: CurlyBracesPolicy;
+}
+/**
+ * Options for the rule `useConsistentTestFunction`.
+ */
+export interface ConsistentTestFunctionOptions {
+	/**
+	 * The function to use for the tests that aren't in a test suite.
+	 */
+	function: TestFunction;
+	/**
+	 * The function to use for the tests in a test suite.
+	 */
+	withinDescribe: TestFunction;
+}
+/**
+ * Rule's options.
+ */
+export interface CssNamingConventionOptions {
+	/**
+	 * Separators of the BEM format.
+	 */
+	bem: BemSeparators;
+	/**
+	 * Custom conventions.
+	 */
+	conventions: CssConvention[];
+}
+/**
+ * Options for the rule `useExplicitFunctionReturnType`.
+ */
+export interface ExplicitFunctionReturnTypeOptions {
+	/**
+	 * If `true`, only the functions that are part of the public API of the module are checked.
+	 */
+	onlyExported: boolean;
+}
+/**
+ * Options for the rule `useExplicitMemberAccessibility`.
+ */
+export interface ExplicitMemberAccessibilityOptions {
+	accessibility: MemberAccessibility;
+}
+/**
+ * Rule's options.
+ */
+export interface GraphqlNamingConventionOptions {
+	/**
+	 * Custom conventions.
+	 */
+	conventions: GraphqlConvention[];
+	/**
+	 * If `false`, then consecutive uppercase are allowed in _camel_ and _pascal_ cases. This does not affect other [Case].
+	 */
+	strictCase: boolean;
+}
+/**
+ * Options for the rule `useLogicalProperties`.
+ */
+export interface UseLogicalPropertiesOptions {
+	/**
+	 * The properties that the rule ignores.
+	 */
+	ignore: string[];
+}
+/**
+ * Options for the rule `useNumericSeparators`.
+ */
+export interface NumericSeparatorsOptions {
+	/**
+	 * The minimum number of digits from which separators are required.
+	 */
+	minimumDigits: number;
+}
+/**
+ * Options for the rule `useRequiredPackageJsonFields`.
+ */
+export interface UseRequiredPackageJsonFieldsOptions {
+	/**
+	 * The fields that each `package.json` must have.
+	 */
+	fields: string[];
+}
 export interface UtilityClassSortingOptions {
 	/**
 	 * Additional attributes that will be sorted.
@@ -1841,6 +3226,41 @@ export interface UtilityClassSortingOptions {
 	 */
 	functions?: string[];
 }
+/**
+ * Options for the rule `useSortedKeys`.
+ */
+export interface UseSortedKeysOptions {
+	/**
+	 * The keys that are placed before the other keys, in the order of the list.
+	 */
+	priorityKeys: string[];
+	/**
+	 * The order of the keys.
+	 */
+	sortOrder: KeySortOrder;
+}
+/**
+ * Options for the rule `useSortedProperties`.
+ */
+export interface UseSortedPropertiesOptions {
+	/**
+	 * The order of the properties when `order` is `custom`.
+	 */
+	customOrder: string[];
+	/**
+	 * The order of the declarations.
+	 */
+	order: PropertyOrder;
+}
+/**
+ * Options for the rule `useTsconfigPathAliases`.
+ */
+export interface UseTsconfigPathAliasesOptions {
+	/**
+	 * The number of parent directories that a relative import can go up without being reported.
+	 */
+	maxParentDirectories: number;
+}
 /**
  * Options for the rule `noRestrictedGlobals`.
  */
@@ -1861,6 +3281,10 @@ export interface FilenamingConventionOptions {
 	 * Allowed cases for file names.
 	 */
 	filenameCases: FilenameCases;
+	/**
+	 * If `true`, then the filename of a module with a named default export must match the name of the default export in one of the allowed cases.
+	 */
+	matchDefaultExport: boolean;
 	/**
 	 * If `false`, then non-ASCII characters are allowed.
 	 */
@@ -1917,6 +3341,71 @@ For example, for React's `useRef()` hook the value would be `true`, while for `u
 	 */
 	stableResult: StableHookResult;
 }
+export type Specificity = string;
+export type CoercionOperator = "!!" | "~" | "+";
+/**
+ * A version of ECMAScript.
+ */
+export type EcmaVersion =
+	| "es5"
+	| "es2015"
+	| "es2016"
+	| "es2017"
+	| "es2018"
+	| "es2019"
+	| "es2020"
+	| "es2021"
+	| "es2022"
+	| "es2023"
+	| "es2024"
+	| "esnext";
+export type CurlyBracesPolicy = "never" | "always" | "ignore";
+export type TestFunction = "test" | "it";
+export interface BemSeparators {
+	/**
+	 * Separator between the block and the element
+	 */
+	element?: string;
+	/**
+	 * Separator before the modifier
+	 */
+	modifier?: string;
+}
+export interface CssConvention {
+	/**
+	 * String case to enforce
+	 */
+	format?: CssFormat;
+	/**
+	 * Regular expression to enforce
+	 */
+	match?: Regex;
+	/**
+	 * Message displayed when the convention is not fulfilled
+	 */
+	message?: string;
+	/**
+	 * Names concerned by this convention
+	 */
+	selector: CssSelector;
+}
+export type MemberAccessibility = "explicit" | "noPublic";
+export interface GraphqlConvention {
+	/**
+	 * String cases to enforce
+	 */
+	formats: GraphqlFormats;
+	/**
+	 * Message displayed when the convention is not fulfilled
+	 */
+	message?: string;
+	/**
+	 * Declarations concerned by this convention
+	 */
+	selector: GraphqlSelector;
+}
+export type KeySortOrder = "lexicographic" | "natural";
+export type PropertyOrder = "alphabetical" | "smacss" | "custom";
 export type ConsistentArrayType = "shorthand" | "generic";
 export type FilenameCases = FilenameCase[];
 export interface Convention {
@@ -1928,6 +3417,10 @@ export interface Convention {
 	 * Regular expression to enforce
 	 */
 	match?: Regex;
+	/**
+	 * Message displayed when the convention is not fulfilled
+	 */
+	message?: string;
 	/**
 	 * Declarations concerned by this convention
 	 */
@@ -1942,6 +3435,21 @@ export type Format =
 	| "PascalCase"
 	| "snake_case";
 export type StableHookResult = boolean | number[];
+export type CssFormat = "kebab-case" | "camelCase" | "snake_case" | "BEM";
+export type Regex = string;
+export interface CssSelector {
+	/**
+	 * Name kind
+	 */
+	kind: CssKind;
+}
+export type GraphqlFormats = GraphqlFormat[];
+export interface GraphqlSelector {
+	/**
+	 * Declaration kind
+	 */
+	kind: GraphqlKind;
+}
 /**
  * Supported cases for file names.
  */
@@ -1952,7 +3460,6 @@ export type FilenameCase =
 	| "PascalCase"
 	| "snake_case";
 export type Formats = Format[];
-export type Regex = string;
 export interface Selector {
 	/**
 	 * Declaration kind
@@ -1967,6 +3474,29 @@ export interface Selector {
 	 */
 	scope: Scope;
 }
+export type CssKind = "any" | "class" | "keyframes" | "customProperty";
+export type GraphqlFormat =
+	| "camelCase"
+	| "CONSTANT_CASE"
+	| "PascalCase"
+	| "snake_case";
+export type GraphqlKind =
+	| "objectType"
+	| "interfaceType"
+	| "unionType"
+	| "enumType"
+	| "scalarType"
+	| "inputObjectType"
+	| "enumValue"
+	| "directive"
+	| "operation"
+	| "fragment"
+	| "variable"
+	| "any"
+	| "type"
+	| "field"
+	| "inputField"
+	| "argument";
 export type Kind =
 	| "class"
 	| "enum"
@@ -2021,6 +3551,18 @@ export interface RegisterProjectFolderParams {
 export type ProjectKey = string;
 export interface UpdateProjectParams {
 	path: BiomePath;
+	/**
+	 * The path of the Tailwind CSS configuration file of the project, if any. The file must be opened with [Workspace::open_project].
+	 */
+	tailwind_config_path?: BiomePath;
+	/**
+	 * The path of the `tsconfig.json` file of the project, if any. The file must be opened with [Workspace::open_project].
+	 */
+	tsconfig_path?: BiomePath;
+	/**
+	 * The paths of the `package.json` files of the other packages of the workspace that the project depends on. The files must be opened with [Workspace::open_project].
+	 */
+	workspace_package_paths: BiomePath[];
 }
 export interface OpenProjectParams {
 	content: string;
@@ -2037,7 +3579,12 @@ export type DocumentFileSource =
 	| "Unknown"
 	| { Js: JsFileSource }
 	| { Json: JsonFileSource }
-	| { Css: CssFileSource };
+	| { Css: CssFileSource }
+	| { Yaml: YamlFileSource }
+	| { Toml: TomlFileSource }
+	| { Markdown: MarkdownFileSource }
+	| { Graphql: GraphqlFileSource }
+	| { Html: HtmlFileSource };
 export interface JsFileSource {
 	/**
 	 * Used to mark if the source is being used for an Astro, Svelte or Vue file
@@ -2050,11 +3597,21 @@ export interface JsFileSource {
 }
 export interface JsonFileSource {
 	allow_comments: boolean;
+	allow_json5: boolean;
 	allow_trailing_commas: boolean;
 }
 export interface CssFileSource {
 	variant: CssVariant;
 }
+export interface YamlFileSource {}
+export interface TomlFileSource {}
+export interface MarkdownFileSource {
+	variant: MarkdownVariant;
+}
+export interface GraphqlFileSource {}
+export interface HtmlFileSource {
+	variant: HtmlVariant;
+}
 export type EmbeddingKind = "Astro" | "Vue" | "Svelte" | "None";
 export type Language =
 	| "JavaScript"
@@ -2073,9 +3630,11 @@ export type LanguageVersion = "ES2022" | "ESNext";
 /**
 	* The style of CSS contained in the file.
 
-Currently, Biome only supports plain CSS, and aims to be compatible with the latest Recommendation level standards. 
+Biome aims to be compatible with the latest Recommendation level standards of plain CSS, and it partially supports the syntax of SCSS and Less. 
 	 */
-export type CssVariant = "Standard";
+export type CssVariant = "Standard" | "Scss" | "Less";
+export type MarkdownVariant = "Standard" | "Mdx";
+export type HtmlVariant = "Standard" | "Astro";
 export interface ChangeFileParams {
 	content: string;
 	path: BiomePath;
@@ -2240,46 +3799,133 @@ export type Category =
 	| "lint/correctness/useValidForDirection"
 	| "lint/correctness/useYield"
 	| "lint/nursery/colorNoInvalidHex"
+	| "lint/nursery/noAsyncInComputed"
+	| "lint/nursery/noAwaitInLoop"
 	| "lint/nursery/noColorInvalidHex"
+	| "lint/nursery/noConditionalTests"
+	| "lint/nursery/noConflictingTsconfigOptions"
 	| "lint/nursery/noConsole"
 	| "lint/nursery/noConstantMathMinMaxClamp"
 	| "lint/nursery/noCssEmptyBlock"
+	| "lint/nursery/noDeprecatedApis"
+	| "lint/nursery/noDeprecatedTsconfigOptions"
+	| "lint/nursery/noDeprecatedUsage"
+	| "lint/nursery/noDirectStateMutation"
+	| "lint/nursery/noDocumentCookie"
 	| "lint/nursery/noDoneCallback"
 	| "lint/nursery/noDuplicateAtImportRules"
 	| "lint/nursery/noDuplicateElseIf"
 	| "lint/nursery/noDuplicateFontNames"
+	| "lint/nursery/noDuplicateFragmentName"
 	| "lint/nursery/noDuplicateJsonKeys"
+	| "lint/nursery/noDuplicateOperationName"
+	| "lint/nursery/noDuplicatePackageJsonDependencies"
 	| "lint/nursery/noDuplicateSelectorsKeyframeBlock"
+	| "lint/nursery/noDuplicateTestTitles"
+	| "lint/nursery/noDuplicateTomlKeys"
 	| "lint/nursery/noEvolvingAny"
+	| "lint/nursery/noExcessiveNestingDepth"
+	| "lint/nursery/noExcessiveParameters"
+	| "lint/nursery/noExcessivelyLongFunctions"
 	| "lint/nursery/noFlatMapIdentity"
+	| "lint/nursery/noGlobalDirnameFilename"
+	| "lint/nursery/noHardcodedColors"
+	| "lint/nursery/noHeadElement"
+	| "lint/nursery/noHighSpecificitySelectors"
+	| "lint/nursery/noImgElement"
+	| "lint/nursery/noImplicitCoercion"
 	| "lint/nursery/noImportantInKeyframe"
 	| "lint/nursery/noInvalidPositionAtImportRule"
+	| "lint/nursery/noInvalidTailwindApply"
+	| "lint/nursery/noInvalidTomlDates"
+	| "lint/nursery/noJsonSchemaViolations"
+	| "lint/nursery/noLeakedConditionalRendering"
+	| "lint/nursery/noMagicNumbers"
 	| "lint/nursery/noMisplacedAssertion"
 	| "lint/nursery/noMissingGenericFamilyKeyword"
 	| "lint/nursery/noNodejsModules"
+	| "lint/nursery/noNonReactiveUpdates"
+	| "lint/nursery/noPackageInternalImports"
 	| "lint/nursery/noReactSpecificProps"
+	| "lint/nursery/noRedundantStoryName"
 	| "lint/nursery/noRestrictedImports"
+	| "lint/nursery/noSolidDestructuredProps"
+	| "lint/nursery/noSolidUncalledSignals"
+	| "lint/nursery/noSolidUntrackedReads"
+	| "lint/nursery/noStandaloneExpect"
+	| "lint/nursery/noStaticElementInteractions"
+	| "lint/nursery/noSyncScripts"
 	| "lint/nursery/noTypeOnlyImportAttributes"
+	| "lint/nursery/noUndeclaredClassNames"
 	| "lint/nursery/noUndeclaredDependencies"
+	| "lint/nursery/noUndefinedMarkdownReferences"
+	| "lint/nursery/noUnknownArgument"
+	| "lint/nursery/noUnknownField"
 	| "lint/nursery/noUnknownFunction"
 	| "lint/nursery/noUnknownMediaFeatureName"
 	| "lint/nursery/noUnknownProperty"
 	| "lint/nursery/noUnknownSelectorPseudoElement"
+	| "lint/nursery/noUnknownTsconfigOptions"
 	| "lint/nursery/noUnknownUnit"
 	| "lint/nursery/noUnmatchableAnbSelector"
+	| "lint/nursery/noUnnecessaryCondition"
+	| "lint/nursery/noUnresolvedTsconfigExtends"
+	| "lint/nursery/noUnsafeTypeAssertion"
+	| "lint/nursery/noUnstableNestedComponents"
+	| "lint/nursery/noUnusedComponentProps"
+	| "lint/nursery/noUnusedRefs"
+	| "lint/nursery/noUnusedSelectors"
+	| "lint/nursery/noUnusedStoreSubscriptions"
 	| "lint/nursery/noUselessStringConcat"
+	| "lint/nursery/noUselessUndefined"
 	| "lint/nursery/noUselessUndefinedInitialization"
+	| "lint/nursery/noVendorPrefix"
+	| "lint/nursery/useAllowedLengthUnits"
+	| "lint/nursery/useAriaPropsSupportedByRole"
 	| "lint/nursery/useArrayLiterals"
+	| "lint/nursery/useAtIndex"
+	| "lint/nursery/useAwaitStoryInteractions"
+	| "lint/nursery/useAwaitThenable"
 	| "lint/nursery/useBiomeSuppressionComment"
+	| "lint/nursery/useColorContrast"
 	| "lint/nursery/useConsistentBuiltinInstantiation"
+	| "lint/nursery/useConsistentCurlyBraces"
+	| "lint/nursery/useConsistentTestFunction"
+	| "lint/nursery/useCssNamingConvention"
 	| "lint/nursery/useDefaultSwitchClause"
+	| "lint/nursery/useDefinePropsOrder"
+	| "lint/nursery/useExplicitFunctionReturnType"
 	| "lint/nursery/useExplicitLengthCheck"
+	| "lint/nursery/useExplicitMemberAccessibility"
 	| "lint/nursery/useFocusableInteractive"
 	| "lint/nursery/useGenericFontNames"
+	| "lint/nursery/useGoogleFontPreconnect"
+	| "lint/nursery/useGraphqlNamingConvention"
 	| "lint/nursery/useImportRestrictions"
+	| "lint/nursery/useLogicalProperties"
+	| "lint/nursery/useNamedOperation"
+	| "lint/nursery/useNumericSeparators"
+	| "lint/nursery/useObjectSpread"
+	| "lint/nursery/useReadonlyClassMembers"
+	| "lint/nursery/useRequiredArguments"
+	| "lint/nursery/useRequiredPackageJsonFields"
+	| "lint/nursery/useShorthandProperties"
 	| "lint/nursery/useSortedClasses"
+	| "lint/nursery/useSortedKeys"
+	| "lint/nursery/useSortedPackageJsonKeys"
+	| "lint/nursery/useSortedTomlKeys"
+	| "lint/nursery/useSortedYamlKeys"
+	| "lint/nursery/useSortedProperties"
+	| "lint/nursery/useStoryDefaultExport"
+	| "lint/nursery/useStoryNamePascalCase"
 	| "lint/nursery/useThrowNewError"
 	| "lint/nursery/useTopLevelRegex"
+	| "lint/nursery/useTsconfigPathAliases"
+	| "lint/nursery/useValidAutocomplete"
+	| "lint/nursery/useValidPackageJsonVersions"
+	| "lint/nursery/useValidRunes"
+	| "lint/nursery/useValidVariables"
+	| "lint/nursery/useValidVueDirectives"
 	| "lint/performance/noAccumulatingSpread"
 	| "lint/performance/noBarrelFile"
 	| "lint/performance/noDelete"
@@ -2595,12 +4241,23 @@ export interface FormatOnTypeParams {
 export interface FixFileParams {
 	fix_file_mode: FixFileMode;
 	path: BiomePath;
+	/**
+	 * Fixes only the given rule or rule group
+	 */
+	rule?: RuleCode;
 	should_format: boolean;
+	/**
+	 * The explanation of the suppression comments, when the suppressions are applied
+	 */
+	suppression_reason?: string;
 }
 /**
  * Which fixes should be applied during the analyzing phase
  */
-export type FixFileMode = "SafeFixes" | "SafeAndUnsafeFixes";
+export type FixFileMode =
+	| "SafeFixes"
+	| "SafeAndUnsafeFixes"
+	| "ApplySuppressions";
 export interface FixFileResult {
 	/**
 	 * List of all the code actions applied to the file
@@ -179,6 +179,11 @@ impl Analyze {
                     &options,
                     JsFileSource::default(),
                     None,
+                    None,
+                    None,
+                    Vec::new(),
+                    None,
+                    None,
                     |event| {
                         black_box(event.diagnostic());
                         black_box(event.actions());
@@ -192,7 +197,7 @@ impl Analyze {
                     ..AnalysisFilter::default()
                 };
                 let options = AnalyzerOptions::default();
-                biome_css_analyze::analyze(root, filter, &options, |event| {
+                biome_css_analyze::analyze(root, filter, &options, None, |event| {
                     black_box(event.diagnostic());
                     black_box(event.actions());
                     ControlFlow::<Never>::Continue(())
@@ -40,6 +40,7 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         ("%=", "PERCENTEQ"),
         ("@", "AT"),
         ("$=", "DOLLAR_EQ"),
+        ("$", "DOLLAR"),
         ("~=", "TILDE_EQ"),
         ("-->", "CDC"),
         ("<!--", "CDO"),
@@ -237,6 +238,13 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "domain",
         "media-document",
         "regexp",
+        // Less
+        "when",
+        // Tailwind CSS
+        "tailwind",
+        "apply",
+        "screen",
+        "theme",
         //
         "font-face",
         // Don't add to the end of this list, add new keywords above the "HERE"
@@ -446,6 +454,28 @@ pub const CSS_KINDS_SRC: KindsSrc = KindsSrc {
         "CSS_DOCUMENT_AT_RULE",
         "CSS_DOCUMENT_MATCHER_LIST",
         "CSS_DOCUMENT_CUSTOM_MATCHER",
+        // SCSS
+        "SCSS_IDENTIFIER",
+        "SCSS_DECLARATION",
+        "SCSS_VARIABLE_MODIFIER",
+        "SCSS_VARIABLE_MODIFIER_LIST",
+        "SCSS_KEYWORD_ARGUMENT",
+        "SCSS_AT_RULE",
+        // Less
+        "LESS_IDENTIFIER",
+        "LESS_DECLARATION",
+        "LESS_KEYWORD_ARGUMENT",
+        "LESS_PARENTHESIZED_VALUE",
+        "LESS_MIXIN_ARGUMENTS",
+        "LESS_MIXIN_GUARD",
+        "LESS_MIXIN_DEFINITION",
+        "LESS_MIXIN_CALL",
+        // Tailwind CSS
+        "TW_TAILWIND_AT_RULE",
+        "TW_APPLY_AT_RULE",
+        "TW_APPLY_CLASS_LIST",
+        "TW_SCREEN_AT_RULE",
+        "TW_FUNCTION",
         // Bogus nodes
         "CSS_BOGUS",
         "CSS_BOGUS_BLOCK",
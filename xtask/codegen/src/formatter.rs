@@ -556,7 +556,18 @@ enum NodeDialect {
     Jsx,
     Json,
     Css,
+    Scss,
+    Less,
+    Tailwind,
     Grit,
+    Graphql,
+    Yaml,
+    Toml,
+    Markdown,
+    Html,
+    Svelte,
+    Astro,
+    Mdx,
 }
 
 impl NodeDialect {
@@ -567,7 +578,18 @@ impl NodeDialect {
             NodeDialect::Jsx,
             NodeDialect::Json,
             NodeDialect::Css,
+            NodeDialect::Scss,
+            NodeDialect::Less,
+            NodeDialect::Tailwind,
             NodeDialect::Grit,
+            NodeDialect::Graphql,
+            NodeDialect::Yaml,
+            NodeDialect::Toml,
+            NodeDialect::Markdown,
+            NodeDialect::Html,
+            NodeDialect::Svelte,
+            NodeDialect::Astro,
+            NodeDialect::Mdx,
         ]
     }
 
@@ -582,7 +604,18 @@ impl NodeDialect {
             NodeDialect::Jsx => "jsx",
             NodeDialect::Json => "json",
             NodeDialect::Css => "css",
+            NodeDialect::Scss => "scss",
+            NodeDialect::Less => "less",
+            NodeDialect::Tailwind => "tailwind",
             NodeDialect::Grit => "grit",
+            NodeDialect::Graphql => "graphql",
+            NodeDialect::Yaml => "yaml",
+            NodeDialect::Toml => "toml",
+            NodeDialect::Markdown => "markdown",
+            NodeDialect::Html => "html",
+            NodeDialect::Svelte => "svelte",
+            NodeDialect::Astro => "astro",
+            NodeDialect::Mdx => "mdx",
         }
     }
 
@@ -593,7 +626,18 @@ impl NodeDialect {
             "Ts" => NodeDialect::Ts,
             "Json" => NodeDialect::Json,
             "Css" => NodeDialect::Css,
+            "Scss" => NodeDialect::Scss,
+            "Less" => NodeDialect::Less,
+            "Tw" => NodeDialect::Tailwind,
             "Grit" => NodeDialect::Grit,
+            "Graphql" => NodeDialect::Graphql,
+            "Yaml" => NodeDialect::Yaml,
+            "Toml" => NodeDialect::Toml,
+            "Markdown" => NodeDialect::Markdown,
+            "Html" => NodeDialect::Html,
+            "Svelte" => NodeDialect::Svelte,
+            "Astro" => NodeDialect::Astro,
+            "Mdx" => NodeDialect::Mdx,
             _ => {
                 eprintln!("missing prefix {}", name);
                 NodeDialect::Js
@@ -633,6 +677,10 @@ enum NodeConcept {
     // GritQL
     Pattern,
     Predicate,
+
+    // GraphQL
+    Definition,
+    Extension,
 }
 
 impl NodeConcept {
@@ -659,6 +707,8 @@ impl NodeConcept {
             NodeConcept::Property => "properties",
             NodeConcept::Pattern => "patterns",
             NodeConcept::Predicate => "predicates",
+            NodeConcept::Definition => "definitions",
+            NodeConcept::Extension => "extensions",
         }
     }
 }
@@ -789,8 +839,13 @@ fn get_node_concept(
                 _ => NodeConcept::Auxiliary,
             },
 
-            // TODO: implement formatter
-            LanguageKind::Graphql => NodeConcept::Auxiliary,
+            LanguageKind::Graphql => match name {
+                _ if name.contains("Extension") => NodeConcept::Extension,
+                _ if name.ends_with("Definition") => NodeConcept::Definition,
+                _ if name.ends_with("Value") => NodeConcept::Value,
+                _ if name.ends_with("Type") && name != "OperationType" => NodeConcept::Type,
+                _ => NodeConcept::Auxiliary,
+            },
 
             LanguageKind::Grit => match name {
                 _ if name.contains("Operation") || name.contains("Pattern") => NodeConcept::Pattern,
@@ -807,6 +862,15 @@ fn get_node_concept(
 
             // TODO: implement formatter
             LanguageKind::Yaml => NodeConcept::Auxiliary,
+
+            LanguageKind::Toml => match name {
+                "KeyValue" => NodeConcept::Auxiliary,
+                "Array" | "InlineTable" => NodeConcept::Value,
+                _ if name.ends_with("Value") => NodeConcept::Value,
+                _ => NodeConcept::Auxiliary,
+            },
+
+            LanguageKind::Markdown => NodeConcept::Auxiliary,
         }
     }
 }
@@ -872,6 +936,8 @@ impl LanguageKind {
             LanguageKind::Grit => "GritFormatter",
             LanguageKind::Html => "HtmlFormatter",
             LanguageKind::Yaml => "YamlFormatter",
+            LanguageKind::Toml => "TomlFormatter",
+            LanguageKind::Markdown => "MarkdownFormatter",
         };
 
         Ident::new(name, Span::call_site())
@@ -886,6 +952,8 @@ impl LanguageKind {
             LanguageKind::Grit => "GritFormatContext",
             LanguageKind::Html => "HtmlFormatContext",
             LanguageKind::Yaml => "YamlFormatContext",
+            LanguageKind::Toml => "TomlFormatContext",
+            LanguageKind::Markdown => "MarkdownFormatContext",
         };
 
         Ident::new(name, Span::call_site())
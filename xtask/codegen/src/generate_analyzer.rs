@@ -11,6 +11,11 @@ pub fn generate_analyzer() -> Result<()> {
     generate_js_analyzer()?;
     generate_json_analyzer()?;
     generate_css_analyzer()?;
+    generate_graphql_analyzer()?;
+    generate_yaml_analyzer()?;
+    generate_toml_analyzer()?;
+    generate_markdown_analyzer()?;
+    generate_html_analyzer()?;
     Ok(())
 }
 
@@ -47,6 +52,46 @@ fn generate_css_analyzer() -> Result<()> {
     update_css_registry_builder(analyzers)
 }
 
+fn generate_graphql_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_graphql_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("lint", &mut analyzers, &base_path)?;
+    generate_options(&base_path)?;
+    update_graphql_registry_builder(analyzers)
+}
+
+fn generate_yaml_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_yaml_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("lint", &mut analyzers, &base_path)?;
+    generate_options(&base_path)?;
+    update_yaml_registry_builder(analyzers)
+}
+
+fn generate_toml_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_toml_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("lint", &mut analyzers, &base_path)?;
+    generate_options(&base_path)?;
+    update_toml_registry_builder(analyzers)
+}
+
+fn generate_markdown_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_markdown_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("lint", &mut analyzers, &base_path)?;
+    generate_options(&base_path)?;
+    update_markdown_registry_builder(analyzers)
+}
+
+fn generate_html_analyzer() -> Result<()> {
+    let base_path = project_root().join("crates/biome_html_analyze/src");
+    let mut analyzers = BTreeMap::new();
+    generate_category("lint", &mut analyzers, &base_path)?;
+    generate_options(&base_path)?;
+    update_html_registry_builder(analyzers)
+}
+
 fn generate_options(base_path: &Path) -> Result<()> {
     let mut rules_options = BTreeMap::new();
     let nl = Punct::new('\n', Spacing::Alone);
@@ -274,6 +319,101 @@ fn update_css_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -
     Ok(())
 }
 
+fn update_graphql_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/biome_graphql_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = xtask::reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_graphql_syntax::GraphqlLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<GraphqlLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
+
+fn update_yaml_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/biome_yaml_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = xtask::reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_yaml_syntax::YamlLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<YamlLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
+
+fn update_toml_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/biome_toml_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = xtask::reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_toml_syntax::TomlLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<TomlLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
+
+fn update_markdown_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/biome_markdown_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = xtask::reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_markdown_syntax::MarkdownLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<MarkdownLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
+
+fn update_html_registry_builder(analyzers: BTreeMap<&'static str, TokenStream>) -> Result<()> {
+    let path = project_root().join("crates/biome_html_analyze/src/registry.rs");
+
+    let categories = analyzers.into_values();
+
+    let tokens = xtask::reformat(quote! {
+        use biome_analyze::RegistryVisitor;
+        use biome_html_syntax::HtmlLanguage;
+
+        pub fn visit_registry<V: RegistryVisitor<HtmlLanguage>>(registry: &mut V) {
+            #( #categories )*
+        }
+    })?;
+
+    fs2::write(path, tokens)?;
+
+    Ok(())
+}
+
 /// Returns file paths of the given directory.
 fn list_entry_paths(dir: &Path) -> Result<impl Iterator<Item = PathBuf>> {
     Ok(fs2::read_dir(dir)
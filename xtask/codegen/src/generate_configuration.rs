@@ -2,9 +2,14 @@ use biome_analyze::{
     GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup, RuleMetadata,
 };
 use biome_css_syntax::CssLanguage;
+use biome_graphql_syntax::GraphqlLanguage;
+use biome_html_syntax::HtmlLanguage;
 use biome_js_syntax::JsLanguage;
 use biome_json_syntax::JsonLanguage;
+use biome_markdown_syntax::MarkdownLanguage;
 use biome_string_case::Case;
+use biome_toml_syntax::TomlLanguage;
+use biome_yaml_syntax::YamlLanguage;
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 use pulldown_cmark::{Event, Parser, Tag};
 use quote::quote;
@@ -81,10 +86,117 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         }
     }
 
+    impl RegistryVisitor<GraphqlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = GraphqlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = GraphqlLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA);
+        }
+    }
+
+    impl RegistryVisitor<YamlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = YamlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = YamlLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA);
+        }
+    }
+
+    impl RegistryVisitor<TomlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = TomlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = TomlLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA);
+        }
+    }
+
+    impl RegistryVisitor<MarkdownLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = MarkdownLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = MarkdownLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA);
+        }
+    }
+
+    impl RegistryVisitor<HtmlLanguage> for LintRulesVisitor {
+        fn record_category<C: GroupCategory<Language = HtmlLanguage>>(&mut self) {
+            if matches!(C::CATEGORY, RuleCategory::Lint) {
+                C::record_groups(self);
+            }
+        }
+
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule + 'static,
+            R::Query: Queryable<Language = HtmlLanguage>,
+            <R::Query as Queryable>::Output: Clone,
+        {
+            self.groups
+                .entry(<R::Group as RuleGroup>::NAME)
+                .or_insert_with(BTreeMap::new)
+                .insert(R::METADATA.name, R::METADATA);
+        }
+    }
+
     let mut visitor = LintRulesVisitor::default();
+    // The HTML rules share their names with the JSX rules, such as `useAltText`:
+    // they are visited first, so the metadata of the JavaScript rules is the one that is kept
+    biome_html_analyze::visit_registry(&mut visitor);
     biome_js_analyze::visit_registry(&mut visitor);
     biome_json_analyze::visit_registry(&mut visitor);
     biome_css_analyze::visit_registry(&mut visitor);
+    biome_graphql_analyze::visit_registry(&mut visitor);
+    biome_yaml_analyze::visit_registry(&mut visitor);
+    biome_toml_analyze::visit_registry(&mut visitor);
+    biome_markdown_analyze::visit_registry(&mut visitor);
 
     let LintRulesVisitor { groups } = visitor;
 
@@ -140,6 +252,10 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
         use biome_js_analyze::options::*;
         use biome_json_analyze::options::*;
         use biome_css_analyze::options::*;
+        use biome_graphql_analyze::options::*;
+        use biome_yaml_analyze::options::*;
+        use biome_toml_analyze::options::*;
+        use biome_markdown_analyze::options::*;
         use biome_rowan::TextRange;
         use indexmap::IndexSet;
         use serde::{Deserialize, Serialize};
@@ -307,6 +423,17 @@ pub(crate) fn generate_rules_configuration(mode: Mode) -> Result<()> {
 
                 enabled_rules.difference(&disabled_rules).copied().collect()
             }
+
+            /// It returns the rules that are explicitly disabled by the configuration.
+            pub fn as_disabled_rules(&self) -> IndexSet<RuleFilter> {
+                let mut disabled_rules = IndexSet::new();
+                #(
+                    if let Some(group) = self.#group_idents.as_ref() {
+                        disabled_rules.extend(&group.get_disabled_rules());
+                    }
+                )*
+                disabled_rules
+            }
         }
 
         #( #struct_groups )*
@@ -422,6 +549,13 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
         let rule_position = Literal::u8_unsuffixed(index as u8);
         let rule_identifier = Ident::new(&Case::Snake.convert(rule), Span::call_site());
         let rule_name = Ident::new(&to_capitalized(rule), Span::call_site());
+        // The options of the HTML rules aren't imported with the other options,
+        // since most of them share their names with the JSX rules
+        let rule_options = if metadata.language == "html" {
+            quote! { biome_html_analyze::options::#rule_name }
+        } else {
+            quote! { #rule_name }
+        };
         if metadata.recommended {
             lines_recommended_rule_as_filter.push(quote! {
                 RuleFilter::Rule(Self::GROUP_NAME, Self::GROUP_RULES[#rule_position])
@@ -440,7 +574,7 @@ fn generate_struct(group: &str, rules: &BTreeMap<&'static str, RuleMetadata>) ->
         schema_lines_rules.push(quote! {
             #[doc = #summary]
             #[serde(skip_serializing_if = "Option::is_none")]
-            pub #rule_identifier: Option<RuleConfiguration<#rule_name>>
+            pub #rule_identifier: Option<RuleConfiguration<#rule_options>>
         });
 
         rule_enabled_check_line.push(quote! {
@@ -5,6 +5,8 @@ use crate::html_kinds_src::HTML_KINDS_SRC;
 use crate::js_kinds_src::{AstNodeSrc, AstSrc, Field, TokenKind, JS_KINDS_SRC};
 use crate::json_kinds_src::JSON_KINDS_SRC;
 use crate::language_kind::LanguageKind;
+use crate::markdown_kinds_src::MARKDOWN_KINDS_SRC;
+use crate::toml_kinds_src::TOML_KINDS_SRC;
 use crate::yaml_kinds_src::YAML_KINDS_SRC;
 use biome_string_case::Case;
 use proc_macro2::{Literal, TokenStream};
@@ -971,6 +973,8 @@ pub(crate) fn token_kind_to_code(name: &str, language_kind: LanguageKind) -> Tok
         LanguageKind::Html => HTML_KINDS_SRC,
         LanguageKind::Graphql => GRAPHQL_KINDS_SRC,
         LanguageKind::Yaml => YAML_KINDS_SRC,
+        LanguageKind::Toml => TOML_KINDS_SRC,
+        LanguageKind::Markdown => MARKDOWN_KINDS_SRC,
     };
     if kind_source.literals.contains(&kind_variant_name.as_str())
         || kind_source.tokens.contains(&kind_variant_name.as_str())
@@ -992,8 +996,9 @@ pub(crate) fn token_kind_to_code(name: &str, language_kind: LanguageKind) -> Tok
         quote! { T![#token] }
     } else {
         // $ is valid syntax in rust and it's part of macros,
-        // so we need to decorate the tokens with quotes
-        if matches!(name, "$=" | "$_") {
+        // so we need to decorate the tokens with quotes.
+        // The unbalanced brackets can't be parsed as a token stream either.
+        if matches!(name, "$=" | "$_" | "[[" | "]]" | "{#" | "{:" | "{/") {
             let token = Literal::string(name);
             quote! { T![#token] }
         } else {
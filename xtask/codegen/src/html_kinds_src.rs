@@ -8,8 +8,19 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         ("=", "EQ"),
         ("!", "BANG"),
         ("-", "MINUS"),
+        ("{", "L_CURLY"),
+        ("}", "R_CURLY"),
+        ("(", "L_PAREN"),
+        (")", "R_PAREN"),
+        (",", "COMMA"),
+        ("{#", "SV_CURLY_HASH"),
+        ("{:", "SV_CURLY_COLON"),
+        ("{/", "SV_CURLY_SLASH"),
+        ("---", "FENCE"),
+    ],
+    keywords: &[
+        "null", "true", "false", "doctype", "html", "if", "else", "each", "as",
     ],
-    keywords: &["null", "true", "false", "doctype", "html"],
     literals: &["HTML_STRING_LITERAL", "HTML_LITERAL"],
     tokens: &[
         "ERROR_TOKEN",
@@ -34,6 +45,22 @@ pub const HTML_KINDS_SRC: KindsSrc = KindsSrc {
         "HTML_ELEMENT_LIST",
         "HTML_ATTRIBUTE_LIST",
         "HTML_CONTENT",
+        "HTML_SINGLE_TEXT_EXPRESSION",
+        "HTML_TEXT_EXPRESSION",
+        // Svelte
+        "SVELTE_IF_BLOCK",
+        "SVELTE_IF_OPENING_BLOCK",
+        "SVELTE_ELSE_IF_CLAUSE_LIST",
+        "SVELTE_ELSE_IF_CLAUSE",
+        "SVELTE_ELSE_CLAUSE",
+        "SVELTE_IF_CLOSING_BLOCK",
+        "SVELTE_EACH_BLOCK",
+        "SVELTE_EACH_OPENING_BLOCK",
+        "SVELTE_EACH_INDEX",
+        "SVELTE_EACH_KEY",
+        "SVELTE_EACH_CLOSING_BLOCK",
+        // Astro
+        "ASTRO_FRONTMATTER_ELEMENT",
         // Bogus nodes
         "HTML_BOGUS",
         "HTML_BOGUS_ELEMENT",
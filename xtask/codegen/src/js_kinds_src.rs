@@ -625,6 +625,8 @@ impl Field {
                     ("')'", _) => "r_paren",
                     ("'['", _) => "l_brack",
                     ("']'", _) => "r_brack",
+                    ("[[", _) => "l_double_brack",
+                    ("]]", _) => "r_double_brack",
                     ("'`'", _) => "backtick",
                     ("<", _) => "l_angle",
                     (">", _) => "r_angle",
@@ -680,7 +682,7 @@ impl Field {
                     ("||", _) => "logical_or",
                     ("&&", _) => "logical_and",
                     ("$=", _) => "suffix",
-                    ("$", LanguageKind::Graphql) => "dollar",
+                    ("$", LanguageKind::Graphql | LanguageKind::Css) => "dollar",
                     ("~=", _) => "whitespace_like",
                     (",", _) => "comma",
                     _ => name,
@@ -5,13 +5,25 @@ use crate::html_kinds_src::HTML_KINDS_SRC;
 use crate::js_kinds_src::JS_KINDS_SRC;
 use crate::json_kinds_src::JSON_KINDS_SRC;
 use crate::kind_src::KindsSrc;
+use crate::markdown_kinds_src::MARKDOWN_KINDS_SRC;
+use crate::toml_kinds_src::TOML_KINDS_SRC;
 use crate::yaml_kinds_src::YAML_KINDS_SRC;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use std::str::FromStr;
 
-pub const LANGUAGE_PREFIXES: [&str; 9] = [
-    "js_", "ts_", "jsx_", "tsx_", "css_", "json_", "grit_", "html_", "yaml_",
+pub const LANGUAGE_PREFIXES: [&str; 11] = [
+    "js_",
+    "ts_",
+    "jsx_",
+    "tsx_",
+    "css_",
+    "json_",
+    "grit_",
+    "html_",
+    "yaml_",
+    "toml_",
+    "markdown_",
 ];
 
 #[derive(Debug, Eq, Copy, Clone, PartialEq)]
@@ -23,6 +35,8 @@ pub enum LanguageKind {
     Grit,
     Html,
     Yaml,
+    Toml,
+    Markdown,
 }
 
 impl std::fmt::Display for LanguageKind {
@@ -35,11 +49,13 @@ impl std::fmt::Display for LanguageKind {
             LanguageKind::Grit => write!(f, "grit"),
             LanguageKind::Html => write!(f, "html"),
             LanguageKind::Yaml => write!(f, "yaml"),
+            LanguageKind::Toml => write!(f, "toml"),
+            LanguageKind::Markdown => write!(f, "markdown"),
         }
     }
 }
 
-pub const ALL_LANGUAGE_KIND: [LanguageKind; 7] = [
+pub const ALL_LANGUAGE_KIND: [LanguageKind; 9] = [
     LanguageKind::Js,
     LanguageKind::Css,
     LanguageKind::Json,
@@ -47,6 +63,8 @@ pub const ALL_LANGUAGE_KIND: [LanguageKind; 7] = [
     LanguageKind::Grit,
     LanguageKind::Html,
     LanguageKind::Yaml,
+    LanguageKind::Toml,
+    LanguageKind::Markdown,
 ];
 
 impl FromStr for LanguageKind {
@@ -61,8 +79,10 @@ impl FromStr for LanguageKind {
             "grit" => Ok(LanguageKind::Grit),
             "html" => Ok(LanguageKind::Html),
             "yaml" => Ok(LanguageKind::Yaml),
+            "toml" => Ok(LanguageKind::Toml),
+            "markdown" => Ok(LanguageKind::Markdown),
             _ => Err(format!(
-                "Language {} not supported, please use: `js`, `css`, `json`, `grit`, `graphql`, `html`, `yaml` or yml",
+                "Language {} not supported, please use: `js`, `css`, `json`, `grit`, `graphql`, `html`, `yaml`, `toml` or `markdown`",
                 kind
             )),
         }
@@ -83,6 +103,8 @@ impl LanguageKind {
             LanguageKind::Grit => quote! { GritSyntaxKind },
             LanguageKind::Html => quote! { HtmlSyntaxKind },
             LanguageKind::Yaml => quote! { YamlSyntaxKind },
+            LanguageKind::Toml => quote! { TomlSyntaxKind },
+            LanguageKind::Markdown => quote! { MarkdownSyntaxKind },
         }
     }
     pub(crate) fn syntax_factory(&self) -> TokenStream {
@@ -94,6 +116,8 @@ impl LanguageKind {
             LanguageKind::Grit => quote! { GritSyntaxFactory },
             LanguageKind::Html => quote! { HtmlSyntaxFactory },
             LanguageKind::Yaml => quote! { YamlSyntaxFactory },
+            LanguageKind::Toml => quote! { TomlSyntaxFactory },
+            LanguageKind::Markdown => quote! { MarkdownSyntaxFactory },
         }
     }
 
@@ -106,6 +130,8 @@ impl LanguageKind {
             LanguageKind::Grit => quote! { GritSyntaxNode },
             LanguageKind::Html => quote! { HtmlSyntaxNode },
             LanguageKind::Yaml => quote! { YamlSyntaxNode },
+            LanguageKind::Toml => quote! { TomlSyntaxNode },
+            LanguageKind::Markdown => quote! { MarkdownSyntaxNode },
         }
     }
 
@@ -118,6 +144,8 @@ impl LanguageKind {
             LanguageKind::Grit => quote! { GritSyntaxElement },
             LanguageKind::Html => quote! { HtmlSyntaxElement },
             LanguageKind::Yaml => quote! { YamlSyntaxElement },
+            LanguageKind::Toml => quote! { TomlSyntaxElement },
+            LanguageKind::Markdown => quote! { MarkdownSyntaxElement },
         }
     }
 
@@ -130,6 +158,8 @@ impl LanguageKind {
             LanguageKind::Grit => quote! { GritSyntaxToken },
             LanguageKind::Html => quote! { HtmlSyntaxToken },
             LanguageKind::Yaml => quote! { YamlSyntaxToken },
+            LanguageKind::Toml => quote! { TomlSyntaxToken },
+            LanguageKind::Markdown => quote! { MarkdownSyntaxToken },
         }
     }
 
@@ -142,6 +172,8 @@ impl LanguageKind {
             LanguageKind::Grit => quote! { GritSyntaxElementChildren },
             LanguageKind::Html => quote! { HtmlSyntaxElementChildren },
             LanguageKind::Yaml => quote! { YamlSyntaxElementChildren },
+            LanguageKind::Toml => quote! { TomlSyntaxElementChildren },
+            LanguageKind::Markdown => quote! { MarkdownSyntaxElementChildren },
         }
     }
 
@@ -154,6 +186,8 @@ impl LanguageKind {
             LanguageKind::Grit => quote! { GritSyntaxList },
             LanguageKind::Html => quote! { HtmlSyntaxList },
             LanguageKind::Yaml => quote! { YamlSyntaxList },
+            LanguageKind::Toml => quote! { TomlSyntaxList },
+            LanguageKind::Markdown => quote! { MarkdownSyntaxList },
         }
     }
 
@@ -166,6 +200,8 @@ impl LanguageKind {
             LanguageKind::Grit => quote! { GritLanguage },
             LanguageKind::Html => quote! { HtmlLanguage },
             LanguageKind::Yaml => quote! { YamlLanguage },
+            LanguageKind::Toml => quote! { TomlLanguage },
+            LanguageKind::Markdown => quote! { MarkdownLanguage },
         }
     }
 
@@ -190,6 +226,8 @@ impl LanguageKind {
             LanguageKind::Grit => GRIT_KINDS_SRC,
             LanguageKind::Html => HTML_KINDS_SRC,
             LanguageKind::Yaml => YAML_KINDS_SRC,
+            LanguageKind::Toml => TOML_KINDS_SRC,
+            LanguageKind::Markdown => MARKDOWN_KINDS_SRC,
         }
     }
 
@@ -202,6 +240,8 @@ impl LanguageKind {
             LanguageKind::Grit => include_str!("../gritql.ungram"),
             LanguageKind::Html => include_str!("../html.ungram"),
             LanguageKind::Yaml => include_str!("../yaml.ungram"),
+            LanguageKind::Toml => include_str!("../toml.ungram"),
+            LanguageKind::Markdown => include_str!("../markdown.ungram"),
         }
     }
 }
@@ -15,6 +15,8 @@ mod graphql_kind_src;
 mod grit_kinds_src;
 mod js_kinds_src;
 mod json_kinds_src;
+mod markdown_kinds_src;
+mod toml_kinds_src;
 mod yaml_kinds_src;
 
 mod generate_crate;
@@ -0,0 +1,48 @@
+use crate::kind_src::KindsSrc;
+
+pub const MARKDOWN_KINDS_SRC: KindsSrc = KindsSrc {
+    punct: &[("-", "MINUS"), ("*", "STAR"), ("+", "PLUS")],
+    keywords: &["null"],
+    literals: &[
+        "MARKDOWN_FRONT_MATTER_LITERAL",
+        "MARKDOWN_HASH_LITERAL",
+        "MARKDOWN_TEXTUAL_LITERAL",
+        "MARKDOWN_SETEXT_UNDERLINE_LITERAL",
+        "MARKDOWN_THEMATIC_BREAK_LITERAL",
+        "MARKDOWN_FENCE_LITERAL",
+        "MARKDOWN_INFO_STRING_LITERAL",
+        "MARKDOWN_CODE_CONTENT_LITERAL",
+        "MARKDOWN_INDENTED_CODE_LITERAL",
+        "MARKDOWN_HTML_LITERAL",
+        "MARKDOWN_QUOTE_LITERAL",
+        "MARKDOWN_LINK_DEFINITION_LITERAL",
+        "MARKDOWN_ORDERED_MARKER_LITERAL",
+        "MDX_ESM_LITERAL",
+        "MDX_JSX_LITERAL",
+    ],
+    tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE"],
+    nodes: &[
+        "MARKDOWN_DOCUMENT",
+        "MARKDOWN_FRONT_MATTER",
+        "MARKDOWN_BLOCK_LIST",
+        "MARKDOWN_HEADING",
+        "MARKDOWN_SETEXT_HEADING",
+        "MARKDOWN_TEXTUAL",
+        "MARKDOWN_THEMATIC_BREAK",
+        "MARKDOWN_PARAGRAPH",
+        "MARKDOWN_TEXTUAL_LIST",
+        "MARKDOWN_FENCED_CODE_BLOCK",
+        "MARKDOWN_INDENTED_CODE_BLOCK",
+        "MARKDOWN_HTML_BLOCK",
+        "MARKDOWN_QUOTE",
+        "MARKDOWN_LINK_DEFINITION",
+        "MARKDOWN_LIST",
+        "MARKDOWN_LIST_ITEM_LIST",
+        "MARKDOWN_LIST_ITEM",
+        "MDX_ESM_BLOCK",
+        "MDX_JSX_BLOCK",
+        // Bogus nodes
+        "MARKDOWN_BOGUS",
+        "MARKDOWN_BOGUS_BLOCK",
+    ],
+};
@@ -17,6 +17,11 @@ const KNOWN_PATHS: &[&str] = &[
     "crates/biome_js_analyze/src/lint",
     "crates/biome_css_analyze/src/lint",
     "crates/biome_json_analyze/src/lint",
+    "crates/biome_graphql_analyze/src/lint",
+    "crates/biome_yaml_analyze/src/lint",
+    "crates/biome_toml_analyze/src/lint",
+    "crates/biome_markdown_analyze/src/lint",
+    "crates/biome_html_analyze/src/lint",
 ];
 pub fn promote_rule(rule_name: &str, new_group: &str) {
     let current_dir = env::current_dir().ok().unwrap();
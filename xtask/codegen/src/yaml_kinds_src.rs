@@ -9,28 +9,48 @@ pub const YAML_KINDS_SRC: KindsSrc = KindsSrc {
         ("[", "L_BRACK"),
         ("]", "R_BRACK"),
         ("-", "DASH"),
-        ("%", "PERCENT"),
-        ("*", "STAR"),
-        ("#", "HASH"),
-        ("!", "BANG"),
-        ("@", "AT"),
-        ("<<", "SHL"),
-        ("&", "AMP"),
-        ("|", "PIPE"),
-        (">", "R_ANGLE"),
-        ("~", "TILDE"),
-        ("`", "BACKTICK"),
+        ("?", "QUESTION"),
         ("---", "DOC_START"),
         ("...", "DOC_END"),
     ],
     keywords: &["null"],
-    literals: &["YAML_STRING_LITERAL", "YAML_SCALAR"],
-    tokens: &["NEWLINE", "WHITESPACE", "IDENT", "COMMENT"],
+    literals: &[
+        "YAML_PLAIN_LITERAL",
+        "YAML_DOUBLE_QUOTED_LITERAL",
+        "YAML_SINGLE_QUOTED_LITERAL",
+        "YAML_BLOCK_SCALAR_LITERAL",
+        "YAML_ANCHOR_LITERAL",
+        "YAML_ALIAS_LITERAL",
+        "YAML_TAG_LITERAL",
+        "YAML_DIRECTIVE_LITERAL",
+    ],
+    tokens: &["ERROR_TOKEN", "NEWLINE", "WHITESPACE", "COMMENT"],
     nodes: &[
         "YAML_ROOT",
         "YAML_DOCUMENT_LIST",
         "YAML_DOCUMENT",
-        "YAML_CONTENT_LIST",
+        "YAML_DIRECTIVE_LIST",
+        "YAML_DIRECTIVE",
+        "YAML_BLOCK_MAPPING",
+        "YAML_BLOCK_MAP_ENTRY_LIST",
+        "YAML_BLOCK_MAP_ENTRY",
+        "YAML_BLOCK_SEQUENCE",
+        "YAML_BLOCK_SEQUENCE_ENTRY_LIST",
+        "YAML_BLOCK_SEQUENCE_ENTRY",
+        "YAML_FLOW_MAPPING",
+        "YAML_FLOW_MAP_ENTRY_LIST",
+        "YAML_FLOW_MAP_ENTRY",
+        "YAML_FLOW_SEQUENCE",
+        "YAML_FLOW_SEQUENCE_ENTRY_LIST",
+        "YAML_PLAIN_SCALAR",
+        "YAML_DOUBLE_QUOTED_SCALAR",
+        "YAML_SINGLE_QUOTED_SCALAR",
+        "YAML_BLOCK_SCALAR",
+        "YAML_ALIAS_NODE",
+        "YAML_PROPERTIES_NODE",
+        "YAML_PROPERTY_LIST",
+        "YAML_ANCHOR_PROPERTY",
+        "YAML_TAG_PROPERTY",
         // Bogus nodes
         "YAML_BOGUS",
         "YAML_BOGUS_VALUE",